@@ -0,0 +1,58 @@
+//! The `mantra_dex_sdk` Python extension module
+//!
+//! The classes live in `mantra_sdk::python`; this crate only exports them as
+//! a cdylib, so the SDK itself stays a plain Rust library.
+//!
+//! ```bash
+//! cd python
+//! maturin develop                    # Install into the active virtualenv
+//! maturin build --release --features evm
+//! ```
+
+use pyo3::prelude::*;
+
+/// The `mantra_dex_sdk` Python module
+#[pymodule]
+fn mantra_dex_sdk(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    mantra_sdk::python::register(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mantra_sdk::python::SdkError;
+
+    #[test]
+    fn test_module_derives_wallets_and_raises_sdk_errors() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = pyo3::wrap_pymodule!(mantra_dex_sdk)(py);
+            let module = module.bind(py);
+            let wallet_class = module.getattr("Wallet").unwrap();
+            assert!(module.getattr("MantraClient").is_ok());
+
+            let (wallet, mnemonic): (Bound<'_, PyAny>, String) = wallet_class
+                .call_method0("generate")
+                .unwrap()
+                .extract()
+                .unwrap();
+            let address: String = wallet.getattr("address").unwrap().extract().unwrap();
+            assert!(address.starts_with("mantra1"));
+
+            let restored = wallet_class
+                .call_method1("from_mnemonic", (mnemonic,))
+                .unwrap();
+            let restored: String = restored.getattr("address").unwrap().extract().unwrap();
+            assert_eq!(restored, address);
+
+            let err = wallet_class
+                .call_method1("from_mnemonic", ("not a mnemonic",))
+                .unwrap_err();
+            assert!(err.is_instance_of::<SdkError>(py));
+            assert!(module
+                .getattr("SdkError")
+                .unwrap()
+                .is(&py.get_type_bound::<SdkError>()));
+        });
+    }
+}
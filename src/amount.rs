@@ -0,0 +1,581 @@
+//! Fixed-point token amounts
+//!
+//! Tokens are counted on chain in integer base units: `1.5` OM is `1500000`
+//! uom, `1.5` ETH-style tokens are `1.5 * 10^18` wei. [`parse_units`] turns a
+//! human-readable amount into base units without going through floats, and
+//! [`format_units`] turns base units back into the shortest exact decimal.
+//! Both work on [`Uint256`], which holds any Cosmos `Uint128` and any EVM
+//! `uint256`; the `_u128` variants check the narrower Cosmos range and, with
+//! the `evm` feature, the `_evm` variants convert to alloy's `U256`.
+//!
+//! [`Amount`] pairs a value with its token so callers can write `1.5 OM` or
+//! `100 USDC` instead of base units, resolving symbols and decimals through a
+//! [`DenomRegistry`] built from the pools.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use cosmwasm_std::{Coin, Uint128, Uint256};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::error::Error;
+
+/// Why an amount could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AmountError {
+    #[error("amount is empty")]
+    Empty,
+    #[error("invalid character {0:?} in amount")]
+    InvalidCharacter(char),
+    #[error("amount has more than one decimal point")]
+    MultipleDecimalPoints,
+    #[error("too many decimal places (max {max})")]
+    TooManyDecimals { max: u8 },
+    #[error("amount does not fit in {bits} bits")]
+    Overflow { bits: u16 },
+    #[error("amount has no token, write it as e.g. \"1.5 OM\"")]
+    MissingToken,
+    #[error("unknown token {0:?}")]
+    UnknownToken(String),
+    #[error("token {0:?} matches several denoms, use the full denom")]
+    AmbiguousToken(String),
+    #[error("amount is in {found}, expected {expected}")]
+    DenomMismatch { expected: String, found: String },
+}
+
+impl From<AmountError> for Error {
+    fn from(e: AmountError) -> Self {
+        Error::Other(format!("Invalid amount: {}", e))
+    }
+}
+
+/// Base units of a decimal amount such as `1.5`, `0.0`, `.25` or `10.`
+///
+/// Fractional digits beyond `decimals` are accepted only when they are zeros,
+/// so no amount is silently rounded. Signs, exponents and separators are
+/// rejected.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<Uint256, AmountError> {
+    let amount = amount.trim();
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((_, fraction)) if fraction.contains('.') => {
+            return Err(AmountError::MultipleDecimalPoints)
+        }
+        Some((whole, fraction)) => (whole, fraction),
+        None => (amount, ""),
+    };
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(AmountError::Empty);
+    }
+    if let Some(c) = whole
+        .chars()
+        .chain(fraction.chars())
+        .find(|c| !c.is_ascii_digit())
+    {
+        return Err(AmountError::InvalidCharacter(c));
+    }
+
+    let fraction = if fraction.len() > decimals as usize {
+        fraction.trim_end_matches('0')
+    } else {
+        fraction
+    };
+    if fraction.len() > decimals as usize {
+        return Err(AmountError::TooManyDecimals { max: decimals });
+    }
+
+    let overflow = AmountError::Overflow { bits: 256 };
+    let ten = Uint256::from(10u8);
+    let mut value = Uint256::zero();
+    for digit in whole.bytes().chain(fraction.bytes()) {
+        value = value
+            .checked_mul(ten)
+            .and_then(|v| v.checked_add(Uint256::from(digit - b'0')))
+            .map_err(|_| overflow.clone())?;
+    }
+    for _ in fraction.len()..decimals as usize {
+        value = value.checked_mul(ten).map_err(|_| overflow.clone())?;
+    }
+    Ok(value)
+}
+
+/// [`parse_units`] for Cosmos amounts, which must fit in a `Uint128`
+pub fn parse_units_u128(amount: &str, decimals: u8) -> Result<Uint128, AmountError> {
+    Uint128::try_from(parse_units(amount, decimals)?)
+        .map_err(|_| AmountError::Overflow { bits: 128 })
+}
+
+/// Shortest exact decimal of `value` base units, without trailing zeros
+pub fn format_units(value: Uint256, decimals: u8) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+
+    let digits = format!("{:0>width$}", digits, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+/// [`format_units`] for Cosmos amounts
+pub fn format_units_u128(value: Uint128, decimals: u8) -> String {
+    format_units(value.into(), decimals)
+}
+
+/// [`parse_units`] as an EVM `uint256`
+#[cfg(feature = "evm")]
+pub fn parse_units_evm(amount: &str, decimals: u8) -> Result<alloy_primitives::U256, AmountError> {
+    parse_units(amount, decimals)
+        .map(|value| alloy_primitives::U256::from_be_bytes(value.to_be_bytes()))
+}
+
+/// [`format_units`] of an EVM `uint256`
+#[cfg(feature = "evm")]
+pub fn format_units_evm(value: alloy_primitives::U256, decimals: u8) -> String {
+    format_units(Uint256::from_be_bytes(value.to_be_bytes::<32>()), decimals)
+}
+
+/// A token amounts can be written in: its base denom, the symbol people use
+/// for it and how many decimals separate the two
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Denomination {
+    pub denom: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl Denomination {
+    pub fn new(denom: impl Into<String>, symbol: impl Into<String>, decimals: u8) -> Self {
+        Self {
+            denom: denom.into(),
+            symbol: symbol.into(),
+            decimals,
+        }
+    }
+}
+
+/// Display symbol of a base denom: `uom` is `OM`, `factory/<creator>/uUSDC`
+/// is `USDC` and `uusdc` is `USDC`; IBC denoms keep their full path
+pub fn symbol_for_denom(denom: &str) -> String {
+    if denom.starts_with("ibc/") {
+        return denom.to_string();
+    }
+    let last = denom.rsplit('/').next().unwrap_or(denom);
+    match last.strip_prefix('u') {
+        Some(rest) if !rest.is_empty() => rest.to_uppercase(),
+        _ => last.to_string(),
+    }
+}
+
+/// Denominations amounts are resolved against
+///
+/// Tokens are looked up by exact base denom first, then by a case-insensitive
+/// symbol that only one denom carries, so `OM`, `om` and `uom` all name the
+/// native token while two tokens both called `USDC` must be written out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomRegistry {
+    denominations: BTreeMap<String, Denomination>,
+}
+
+impl DenomRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the native token (`uom`, `OM`, 6 decimals)
+    pub fn native(native_denom: &str) -> Self {
+        let mut registry = Self::new();
+        registry.insert(Denomination::new(
+            native_denom,
+            symbol_for_denom(native_denom),
+            6,
+        ));
+        registry
+    }
+
+    /// Registry of every asset in `pools`, with the decimals the pools record
+    pub fn from_pools(native_denom: &str, pools: &[PoolInfoResponse]) -> Self {
+        let mut registry = Self::native(native_denom);
+        for pool in pools {
+            let info = &pool.pool_info;
+            for (asset, decimals) in info.assets.iter().zip(&info.asset_decimals) {
+                registry.insert_denom(&asset.denom, *decimals);
+            }
+        }
+        registry
+    }
+
+    /// Registry of denoms with known decimals, symbols derived from the denoms
+    pub fn from_decimals<'a>(
+        native_denom: &str,
+        decimals: impl IntoIterator<Item = (&'a String, &'a u8)>,
+    ) -> Self {
+        let mut registry = Self::native(native_denom);
+        for (denom, decimals) in decimals {
+            registry.insert_denom(denom, *decimals);
+        }
+        registry
+    }
+
+    /// Add or replace a denomination
+    pub fn insert(&mut self, denomination: Denomination) {
+        self.denominations
+            .insert(denomination.denom.clone(), denomination);
+    }
+
+    fn insert_denom(&mut self, denom: &str, decimals: u8) {
+        self.insert(Denomination::new(denom, symbol_for_denom(denom), decimals));
+    }
+
+    pub fn get(&self, denom: &str) -> Option<&Denomination> {
+        self.denominations.get(denom)
+    }
+
+    pub fn decimals(&self, denom: &str) -> Option<u8> {
+        self.get(denom).map(|d| d.decimals)
+    }
+
+    /// Denomination named by a base denom or an unambiguous symbol
+    pub fn resolve(&self, token: &str) -> Result<&Denomination, AmountError> {
+        let token = token.trim();
+        if let Some(denomination) = self.denominations.get(token) {
+            return Ok(denomination);
+        }
+        let mut matches = self
+            .denominations
+            .values()
+            .filter(|d| d.symbol.eq_ignore_ascii_case(token));
+        match (matches.next(), matches.next()) {
+            (Some(denomination), None) => Ok(denomination),
+            (Some(_), Some(_)) => Err(AmountError::AmbiguousToken(token.to_string())),
+            _ => Err(AmountError::UnknownToken(token.to_string())),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Denomination> {
+        self.denominations.values()
+    }
+}
+
+/// An on-chain amount together with the token it counts
+///
+/// Parsed from what a person would type: `1.5 OM` or `1.5om` is one and a half
+/// OM, `100 USDC` is a hundred USDC, whatever their decimals. Written with the
+/// base denom, as in the Cosmos coin notation `1500000uom`, the number is
+/// already in base units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Amount {
+    pub amount: Uint128,
+    pub denomination: Denomination,
+}
+
+impl Amount {
+    /// Parse `<number> <token>`, the token being a denom or symbol in `registry`
+    pub fn parse(input: &str, registry: &DenomRegistry) -> Result<Self, AmountError> {
+        let (number, token) = split_amount(input);
+        if token.is_empty() {
+            return Err(AmountError::MissingToken);
+        }
+        Self::parse_parts(number, token, registry)
+    }
+
+    /// Parse an amount of `denom`, where the token may be left out
+    ///
+    /// A bare number is in display units of `denom`, so `1.5` with `uom` is
+    /// `1500000`. A written token must name `denom`.
+    pub fn parse_as(
+        input: &str,
+        denom: &str,
+        registry: &DenomRegistry,
+    ) -> Result<Self, AmountError> {
+        let (number, token) = split_amount(input);
+        let amount = if token.is_empty() {
+            let denomination = registry
+                .get(denom)
+                .cloned()
+                .ok_or_else(|| AmountError::UnknownToken(denom.to_string()))?;
+            Self {
+                amount: parse_units_u128(number, denomination.decimals)?,
+                denomination,
+            }
+        } else {
+            Self::parse_parts(number, token, registry)?
+        };
+        if amount.denomination.denom != denom {
+            return Err(AmountError::DenomMismatch {
+                expected: denom.to_string(),
+                found: amount.denomination.denom,
+            });
+        }
+        Ok(amount)
+    }
+
+    /// Amount of a coin whose denom is in `registry`
+    pub fn from_coin(coin: &Coin, registry: &DenomRegistry) -> Result<Self, AmountError> {
+        let denomination = registry
+            .get(&coin.denom)
+            .cloned()
+            .ok_or_else(|| AmountError::UnknownToken(coin.denom.clone()))?;
+        Ok(Self {
+            amount: coin.amount,
+            denomination,
+        })
+    }
+
+    pub fn denom(&self) -> &str {
+        &self.denomination.denom
+    }
+
+    pub fn to_coin(&self) -> Coin {
+        Coin {
+            denom: self.denomination.denom.clone(),
+            amount: self.amount,
+        }
+    }
+
+    fn parse_parts(
+        number: &str,
+        token: &str,
+        registry: &DenomRegistry,
+    ) -> Result<Self, AmountError> {
+        let denomination = registry.resolve(token)?.clone();
+        // `1500000uom` is a coin string, counted in base units
+        let decimals = if token == denomination.denom && denomination.denom != denomination.symbol {
+            0
+        } else {
+            denomination.decimals
+        };
+        Ok(Self {
+            amount: parse_units_u128(number, decimals)?,
+            denomination,
+        })
+    }
+}
+
+impl From<Amount> for Coin {
+    fn from(amount: Amount) -> Self {
+        amount.to_coin()
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            format_units_u128(self.amount, self.denomination.decimals),
+            self.denomination.symbol
+        )
+    }
+}
+
+/// Split `1.5 OM` or `1.5OM` into the number and the token
+fn split_amount(input: &str) -> (&str, &str) {
+    let input = input.trim();
+    let end = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+    let (number, token) = input.split_at(end);
+    (number, token.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Values spread over every magnitude, not only huge ones
+    fn any_value() -> impl Strategy<Value = Uint256> {
+        (0usize..=32, any::<[u8; 32]>()).prop_map(|(len, random)| {
+            let mut bytes = [0u8; 32];
+            bytes[32 - len..].copy_from_slice(&random[32 - len..]);
+            Uint256::from_be_bytes(bytes)
+        })
+    }
+
+    /// Decimals of real tokens and well beyond
+    fn any_decimals() -> impl Strategy<Value = u8> {
+        0u8..=80
+    }
+
+    #[test]
+    fn test_parse_units_edge_cases() {
+        let parse = |amount: &str, decimals| parse_units(amount, decimals).map(|v| v.to_string());
+        assert_eq!(parse("1.5", 6).unwrap(), "1500000");
+        assert_eq!(parse("0.0", 6).unwrap(), "0");
+        assert_eq!(parse("0.0", 0).unwrap(), "0");
+        assert_eq!(parse(".25", 2).unwrap(), "25");
+        assert_eq!(parse("10.", 1).unwrap(), "100");
+        assert_eq!(parse(" 007 ", 0).unwrap(), "7");
+        assert_eq!(parse("1.500000000", 6).unwrap(), "1500000");
+        assert_eq!(
+            parse("340282366920938463463374607431768211456", 18).unwrap(),
+            "340282366920938463463374607431768211456000000000000000000"
+        );
+
+        assert_eq!(parse("", 6), Err(AmountError::Empty));
+        assert_eq!(parse(".", 6), Err(AmountError::Empty));
+        assert_eq!(parse("-1", 6), Err(AmountError::InvalidCharacter('-')));
+        assert_eq!(parse("1e6", 6), Err(AmountError::InvalidCharacter('e')));
+        assert_eq!(parse("1,000", 6), Err(AmountError::InvalidCharacter(',')));
+        assert_eq!(parse("1.2.3", 6), Err(AmountError::MultipleDecimalPoints));
+        assert_eq!(
+            parse("1.0000001", 6),
+            Err(AmountError::TooManyDecimals { max: 6 })
+        );
+
+        // 2^256 base units, and a whole part that only overflows once scaled
+        let max = Uint256::MAX.to_string();
+        assert_eq!(
+            parse(&max, 0).unwrap(),
+            max,
+            "the largest uint256 still parses"
+        );
+        assert_eq!(
+            parse(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+                0
+            ),
+            Err(AmountError::Overflow { bits: 256 })
+        );
+        assert_eq!(parse(&max, 1), Err(AmountError::Overflow { bits: 256 }));
+        assert_eq!(
+            parse_units_u128("340282366920938463463.374607431768211456", 18),
+            Err(AmountError::Overflow { bits: 128 })
+        );
+        assert_eq!(
+            parse_units_u128("340282366920938463463.374607431768211455", 18).unwrap(),
+            Uint128::MAX
+        );
+    }
+
+    #[test]
+    fn test_amount_parses_symbols_and_denoms() {
+        let usdc = "factory/mantra1creator/uUSDC";
+        let mut registry = DenomRegistry::native("uom");
+        registry.insert(Denomination::new(usdc, "USDC", 6));
+        registry.insert(Denomination::new("aeth", "ETH", 18));
+
+        let amount = Amount::parse("1.5 OM", &registry).unwrap();
+        assert_eq!(amount.to_coin(), Coin::new(1_500_000u128, "uom"));
+        assert_eq!(amount.to_string(), "1.5 OM");
+        assert_eq!(
+            Amount::parse("1.5om", &registry).unwrap().amount.u128(),
+            1_500_000
+        );
+        assert_eq!(
+            Amount::parse("100 USDC", &registry).unwrap().to_coin(),
+            Coin::new(100_000_000u128, usdc)
+        );
+        assert_eq!(
+            Amount::parse("0.25 eth", &registry).unwrap().amount.u128(),
+            250_000_000_000_000_000
+        );
+        // The base denom means base units, as in coin strings
+        assert_eq!(
+            Amount::parse("1500000uom", &registry)
+                .unwrap()
+                .amount
+                .u128(),
+            1_500_000
+        );
+        assert_eq!(
+            Amount::parse("1.5 uom", &registry),
+            Err(AmountError::TooManyDecimals { max: 0 })
+        );
+
+        assert_eq!(
+            Amount::parse("1.5", &registry),
+            Err(AmountError::MissingToken)
+        );
+        assert_eq!(
+            Amount::parse("1 ATOM", &registry),
+            Err(AmountError::UnknownToken("ATOM".to_string()))
+        );
+        registry.insert(Denomination::new("ibc/ABC", "USDC", 6));
+        assert_eq!(
+            Amount::parse("1 USDC", &registry),
+            Err(AmountError::AmbiguousToken("USDC".to_string()))
+        );
+
+        assert_eq!(
+            Amount::parse_as("2", "uom", &registry)
+                .unwrap()
+                .amount
+                .u128(),
+            2_000_000
+        );
+        assert_eq!(
+            Amount::parse_as("2 ETH", "uom", &registry),
+            Err(AmountError::DenomMismatch {
+                expected: "uom".to_string(),
+                found: "aeth".to_string()
+            })
+        );
+        assert_eq!(symbol_for_denom("uusdc"), "USDC");
+        assert_eq!(symbol_for_denom(usdc), "USDC");
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2_000))]
+
+        #[test]
+        fn test_units_round_trip(value in any_value(), decimals in any_decimals()) {
+            let formatted = format_units(value, decimals);
+            prop_assert_eq!(parse_units(&formatted, decimals), Ok(value), "{}", formatted);
+            // Padding with zeros keeps the value
+            let padded = if formatted.contains('.') {
+                format!("0{}000", formatted)
+            } else {
+                format!("{}.000", formatted)
+            };
+            prop_assert_eq!(parse_units(&padded, decimals), Ok(value), "{}", padded);
+        }
+
+        #[test]
+        fn test_parsing_preserves_order(
+            a in any_value(),
+            b in any_value(),
+            decimals in any_decimals(),
+        ) {
+            let a_parsed = parse_units(&format_units(a, decimals), decimals).unwrap();
+            let b_parsed = parse_units(&format_units(b, decimals), decimals).unwrap();
+            prop_assert_eq!(a_parsed.cmp(&b_parsed), a.cmp(&b));
+        }
+
+        #[test]
+        fn test_parse_units_never_panics(
+            input in "[0-9. +eE,_x-]{0,100}",
+            decimals in any::<u8>(),
+        ) {
+            if let Ok(value) = parse_units(&input, decimals) {
+                // Whatever parses formats back to the same value
+                prop_assert_eq!(
+                    parse_units(&format_units(value, decimals), decimals),
+                    Ok(value)
+                );
+            }
+        }
+
+        #[test]
+        fn test_whole_parts_past_uint256_overflow(
+            value in any_value(),
+            decimals in 1u8..=80,
+        ) {
+            // Scaling any non-zero value by 10^78 exceeds 2^256
+            prop_assume!(!value.is_zero());
+            let huge = format!("{}{}", value, "0".repeat(78));
+            prop_assert_eq!(
+                parse_units(&huge, decimals),
+                Err(AmountError::Overflow { bits: 256 })
+            );
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Node, block, validator and mempool state of a Cosmos chain
+//!
+//! When a transaction does not land, the reason is usually visible from the
+//! node it was broadcast to: the node is still catching up, blocks have
+//! stopped, the mempool is backed up, or the gas price is below the minimum
+//! the node accepts. [`stuck_transaction_hints`] reads those off a
+//! [`NodeStatus`], a [`MempoolStatus`] and the [`ChainParams`].
+
+use crate::error::Error;
+use crate::tx_queue::cosmos_tx_hash;
+use chrono::{DateTime, Utc};
+use cosmrs::rpc::endpoint::{block, status};
+use cosmrs::tendermint::{validator, Time};
+use serde::{Deserialize, Serialize};
+
+/// Seconds without a new block after which the chain is reported as stalled
+pub const STALLED_BLOCK_SECS: i64 = 60;
+
+/// Unconfirmed transactions above which the mempool is reported as busy
+pub const BUSY_MEMPOOL_TXS: u64 = 500;
+
+/// What a node reports about itself and how far it has synced
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeStatus {
+    pub moniker: String,
+    pub node_id: String,
+    /// Chain ID the node is on
+    pub network: String,
+    /// CometBFT version
+    pub version: String,
+    pub latest_block_height: u64,
+    pub latest_block_hash: String,
+    pub latest_block_time: DateTime<Utc>,
+    /// Oldest block the node still has, above 1 on pruned nodes
+    pub earliest_block_height: u64,
+    /// Whether the node is still syncing
+    pub catching_up: bool,
+    /// Voting power of the node, zero unless it is a validator
+    pub voting_power: u64,
+}
+
+impl NodeStatus {
+    pub fn from_rpc(response: &status::Response) -> Self {
+        let sync = &response.sync_info;
+        Self {
+            moniker: response.node_info.moniker.to_string(),
+            node_id: response.node_info.id.to_string(),
+            network: response.node_info.network.to_string(),
+            version: response.node_info.version.to_string(),
+            latest_block_height: sync.latest_block_height.value(),
+            latest_block_hash: sync.latest_block_hash.to_string(),
+            latest_block_time: to_datetime(sync.latest_block_time),
+            earliest_block_height: sync.earliest_block_height.value(),
+            catching_up: sync.catching_up,
+            voting_power: response.validator_info.power(),
+        }
+    }
+
+    /// Seconds between the latest block and `now`
+    pub fn block_age_secs(&self, now: DateTime<Utc>) -> i64 {
+        (now - self.latest_block_time).num_seconds()
+    }
+}
+
+/// Header and transactions of one block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockSummary {
+    pub height: u64,
+    pub hash: String,
+    pub time: DateTime<Utc>,
+    pub chain_id: String,
+    /// Consensus address of the validator that proposed the block
+    pub proposer: String,
+    pub tx_count: usize,
+    /// Hashes of the block's transactions, as `tx` queries take them
+    pub tx_hashes: Vec<String>,
+}
+
+impl BlockSummary {
+    pub fn from_rpc(response: &block::Response) -> Self {
+        let header = &response.block.header;
+        Self {
+            height: header.height.value(),
+            hash: response.block_id.hash.to_string(),
+            time: to_datetime(header.time),
+            chain_id: header.chain_id.to_string(),
+            proposer: header.proposer_address.to_string(),
+            tx_count: response.block.data.len(),
+            tx_hashes: response
+                .block
+                .data
+                .iter()
+                .map(|tx| cosmos_tx_hash(tx))
+                .collect(),
+        }
+    }
+}
+
+/// One validator of a validator set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorSummary {
+    /// Consensus address
+    pub address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub voting_power: u64,
+    pub proposer_priority: i64,
+    /// Share of the set's voting power, in percent
+    pub voting_power_percent: f64,
+}
+
+/// Validators of one block, by voting power
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidatorSet {
+    pub height: u64,
+    pub total_voting_power: u64,
+    /// Fewest validators holding a third of the voting power, enough to halt
+    /// the chain
+    pub halting_validators: usize,
+    pub validators: Vec<ValidatorSummary>,
+}
+
+impl ValidatorSet {
+    pub fn from_rpc(height: u64, validators: &[validator::Info]) -> Self {
+        let total_voting_power: u64 = validators.iter().map(|v| v.power()).sum();
+        let mut validators: Vec<ValidatorSummary> = validators
+            .iter()
+            .map(|v| ValidatorSummary {
+                address: v.address.to_string(),
+                name: v.name.clone(),
+                voting_power: v.power(),
+                proposer_priority: v.proposer_priority.value(),
+                voting_power_percent: if total_voting_power == 0 {
+                    0.0
+                } else {
+                    v.power() as f64 * 100.0 / total_voting_power as f64
+                },
+            })
+            .collect();
+        validators.sort_by_key(|v| std::cmp::Reverse(v.voting_power));
+
+        let mut power = 0u64;
+        let halting_validators = validators
+            .iter()
+            .take_while(|v| {
+                let halted = power * 3 >= total_voting_power;
+                power += v.voting_power;
+                !halted
+            })
+            .count();
+
+        Self {
+            height,
+            total_voting_power,
+            halting_validators,
+            validators,
+        }
+    }
+}
+
+/// Limits and gas prices transactions must meet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChainParams {
+    pub chain_id: String,
+    /// Gas a block may use; none when unlimited
+    pub max_block_gas: Option<u64>,
+    pub max_block_bytes: u64,
+    pub native_denom: String,
+    /// Gas price the client pays, in the native denom
+    pub gas_price: f64,
+    /// Minimum gas prices the node accepts, e.g. `0.01uom`; none when the
+    /// node does not say
+    pub node_minimum_gas_price: Option<String>,
+}
+
+impl ChainParams {
+    /// Minimum gas price the node accepts in the native denom
+    pub fn node_minimum_native_gas_price(&self) -> Option<f64> {
+        self.node_minimum_gas_price
+            .as_deref()?
+            .split(',')
+            .map(str::trim)
+            .find_map(|price| {
+                price
+                    .strip_suffix(self.native_denom.as_str())
+                    .and_then(|amount| amount.parse().ok())
+            })
+    }
+}
+
+/// Transactions waiting in a node's mempool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MempoolStatus {
+    /// Unconfirmed transactions
+    pub n_txs: u64,
+    pub total_bytes: u64,
+}
+
+impl MempoolStatus {
+    /// Read the result of the `num_unconfirmed_txs` RPC
+    pub fn from_rpc_json(response: &serde_json::Value) -> Result<Self, Error> {
+        let result = response.get("result").unwrap_or(response);
+        // CometBFT encodes these integers as strings
+        let field = |name: &str| -> Result<u64, Error> {
+            let value = &result[name];
+            value
+                .as_u64()
+                .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+                .ok_or_else(|| Error::Rpc(format!("num_unconfirmed_txs has no {}", name)))
+        };
+        Ok(Self {
+            n_txs: field("n_txs")?,
+            total_bytes: field("total_bytes")?,
+        })
+    }
+}
+
+/// Reasons a transaction broadcast to the node may not be included
+pub fn stuck_transaction_hints(
+    status: &NodeStatus,
+    mempool: Option<&MempoolStatus>,
+    params: Option<&ChainParams>,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    let mut hints = Vec::new();
+    if status.catching_up {
+        hints.push(format!(
+            "The node is still syncing at height {}; it may not see recent transactions",
+            status.latest_block_height
+        ));
+    }
+    let age = status.block_age_secs(now);
+    if age > STALLED_BLOCK_SECS {
+        hints.push(format!(
+            "No block for {}s since height {}; the chain or the node has stalled",
+            age, status.latest_block_height
+        ));
+    }
+    if let Some(mempool) = mempool.filter(|m| m.n_txs > BUSY_MEMPOOL_TXS) {
+        hints.push(format!(
+            "{} transactions are waiting in the mempool; low-fee transactions may wait several blocks",
+            mempool.n_txs
+        ));
+    }
+    if let Some(params) = params {
+        if let Some(minimum) = params.node_minimum_native_gas_price() {
+            if params.gas_price < minimum {
+                hints.push(format!(
+                    "The gas price {}{} is below the node's minimum of {}{}; the node rejects such transactions",
+                    params.gas_price, params.native_denom, minimum, params.native_denom
+                ));
+            }
+        }
+    }
+    hints
+}
+
+fn to_datetime(time: Time) -> DateTime<Utc> {
+    DateTime::from_timestamp(time.unix_timestamp(), 0).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(catching_up: bool, block_time: DateTime<Utc>) -> NodeStatus {
+        NodeStatus {
+            moniker: "node".to_string(),
+            node_id: "id".to_string(),
+            network: "mantra-1".to_string(),
+            version: "0.38.0".to_string(),
+            latest_block_height: 100,
+            latest_block_hash: "AB".to_string(),
+            latest_block_time: block_time,
+            earliest_block_height: 1,
+            catching_up,
+            voting_power: 0,
+        }
+    }
+
+    #[test]
+    fn test_stuck_transaction_hints() {
+        let now = Utc::now();
+        let mempool = MempoolStatus::from_rpc_json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "result": {"n_txs": "1200", "total": "1200", "total_bytes": "450000", "txs": null}
+        }))
+        .unwrap();
+        assert_eq!(mempool.n_txs, 1200);
+        let params = ChainParams {
+            chain_id: "mantra-1".to_string(),
+            max_block_gas: Some(60_000_000),
+            max_block_bytes: 22_020_096,
+            native_denom: "uom".to_string(),
+            gas_price: 0.01,
+            node_minimum_gas_price: Some("0.5ibc/ABC,0.02uom".to_string()),
+        };
+        assert_eq!(params.node_minimum_native_gas_price(), Some(0.02));
+
+        let healthy = status(false, now);
+        assert!(stuck_transaction_hints(&healthy, None, None, now).is_empty());
+        let hints = stuck_transaction_hints(
+            &status(true, now - chrono::Duration::seconds(300)),
+            Some(&mempool),
+            Some(&params),
+            now,
+        );
+        assert_eq!(hints.len(), 4, "{:?}", hints);
+    }
+}
@@ -2,19 +2,27 @@
 ///
 /// This is the main entry point for interacting with the MANTRA blockchain.
 /// It provides access to all supported protocols through a unified interface.
-use crate::config::{ConfigurationManager, ContractType, MantraNetworkConfig, ProtocolId};
+use crate::config::{
+    ConfigurationManager, ContractType, MantraNetworkConfig, NetworkConfigLoader, ProtocolId,
+};
+use crate::endpoints::{CircuitBreakerConfig, EndpointHealth, EndpointPool};
 use crate::error::Error;
 #[cfg(feature = "evm")]
 use crate::protocols::evm::EvmProtocol;
 use crate::protocols::{
     claimdrop::{ClaimdropFactoryClient, ClaimdropProtocol},
     dex::{DexProtocol, MantraDexClient},
+    discovery::ContractDiscovery,
     skip::SkipProtocol,
-    Protocol, ProtocolRegistry,
+    Protocol,
 };
+use crate::retry::RetryPolicy;
+use crate::transport::SharedTransport;
+use crate::tx_tag::TxTagging;
 use crate::wallet::MantraWallet;
-use cosmrs::{rpc::HttpClient, AccountId};
-use std::{str::FromStr, sync::Arc};
+use cosmrs::AccountId;
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+use tokio::sync::OnceCell;
 use tracing::warn;
 
 /// Validate a contract address using cosmrs AccountId parsing
@@ -37,10 +45,79 @@ pub struct ConfigurationChanges {
     pub claimdrop_config_changed: bool,
 }
 
+impl ConfigurationChanges {
+    /// Changes that move a client from the `old` network configuration to `new`
+    ///
+    /// Switching chains affects every protocol. Otherwise only the protocols
+    /// whose contracts, or whose fee settings, differ are marked as changed.
+    pub fn between(old: &MantraNetworkConfig, new: &MantraNetworkConfig) -> Self {
+        let chain_changed = old.chain_id != new.chain_id || old.network_name != new.network_name;
+        let fees_changed = old.gas_price != new.gas_price
+            || old.gas_adjustment != new.gas_adjustment
+            || old.native_denom != new.native_denom;
+        let (old_contracts, new_contracts) = (&old.contracts, &new.contracts);
+        let dex_contracts_changed = old_contracts.pool_manager != new_contracts.pool_manager
+            || old_contracts.farm_manager != new_contracts.farm_manager
+            || old_contracts.fee_collector != new_contracts.fee_collector
+            || old_contracts.epoch_manager != new_contracts.epoch_manager;
+        let skip_contracts_changed = old_contracts.skip_entry_point
+            != new_contracts.skip_entry_point
+            || old_contracts.skip_ibc_hooks_adapter != new_contracts.skip_ibc_hooks_adapter
+            || old_contracts.skip_mantra_dex_adapter != new_contracts.skip_mantra_dex_adapter;
+
+        Self {
+            rpc_url: (old.rpc_url != new.rpc_url).then(|| new.rpc_url.clone()),
+            dex_config_changed: chain_changed
+                || fees_changed
+                || dex_contracts_changed
+                || old.grpc_url != new.grpc_url,
+            skip_config_changed: chain_changed || fees_changed || skip_contracts_changed,
+            claimdrop_config_changed: chain_changed || fees_changed,
+        }
+    }
+
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.rpc_url.is_none()
+            && !self.dex_config_changed
+            && !self.skip_config_changed
+            && !self.claimdrop_config_changed
+    }
+}
+
+/// Protocol instance, created on first use
+type ProtocolCell<T> = OnceCell<Arc<T>>;
+
+/// Client of the network's RPC endpoint: the Tendermint RPC client natively,
+/// the Fetch API in the browser
+#[cfg(not(target_arch = "wasm32"))]
+type RpcClient = cosmrs::rpc::HttpClient;
+#[cfg(target_arch = "wasm32")]
+type RpcClient = crate::transport::FetchTransport;
+
+/// Connect to the RPC endpoint at `rpc_url`
+#[cfg(not(target_arch = "wasm32"))]
+fn connect(rpc_url: &str) -> Result<Arc<RpcClient>, Error> {
+    RpcClient::new(rpc_url)
+        .map(Arc::new)
+        .map_err(|e| Error::Rpc(e.to_string()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn connect(rpc_url: &str) -> Result<Arc<RpcClient>, Error> {
+    Ok(Arc::new(RpcClient::new(rpc_url)))
+}
+
 /// Main MANTRA SDK client that provides access to all protocols
+///
+/// Protocols are initialized lazily: constructing a client only connects the
+/// RPC client, and each protocol is set up, with its contract addresses
+/// checked, the first time it is used. A network that lacks an optional
+/// protocol's contracts therefore still yields a client; only using that
+/// protocol fails.
 pub struct MantraClient {
     /// RPC client for blockchain communication
-    rpc_client: Arc<HttpClient>,
+    rpc_client: Arc<RpcClient>,
 
     /// Unified configuration manager
     config_manager: ConfigurationManager,
@@ -51,21 +128,62 @@ pub struct MantraClient {
     /// Optional wallet for signing transactions
     wallet: Option<Arc<MantraWallet>>,
 
-    /// Protocol registry containing all available protocols
-    protocol_registry: ProtocolRegistry,
+    /// Protocols enabled or disabled regardless of the configuration
+    protocol_overrides: HashMap<ProtocolId, bool>,
+
+    /// Skip entry point replacing the configured one
+    skip_contract: Option<String>,
+
+    /// ClaimDrop factory replacing the configured one
+    claimdrop_factory: Option<String>,
 
     /// DEX protocol instance
-    dex_protocol: Option<Arc<DexProtocol>>,
+    dex_protocol: ProtocolCell<DexProtocol>,
 
     /// Skip protocol instance
-    skip_protocol: Option<Arc<SkipProtocol>>,
+    skip_protocol: ProtocolCell<SkipProtocol>,
 
     /// ClaimDrop protocol instance
-    claimdrop_protocol: Option<Arc<ClaimdropProtocol>>,
+    claimdrop_protocol: ProtocolCell<ClaimdropProtocol>,
 
     /// EVM protocol instance
     #[cfg(feature = "evm")]
-    evm_protocol: Option<Arc<EvmProtocol>>,
+    evm_protocol: ProtocolCell<EvmProtocol>,
+
+    /// Retries of transient failures, given to every protocol client
+    retry_policy: RetryPolicy,
+
+    /// Circuit breakers of the Cosmos RPC endpoints, shared by every DEX client
+    endpoints: Arc<EndpointPool>,
+
+    /// Attribution tags stamped on the transactions of every protocol client
+    tx_tagging: TxTagging,
+
+    /// Circuit breakers of the EVM RPC endpoints, shared by every EVM client
+    #[cfg(feature = "evm")]
+    evm_endpoints: Arc<EndpointPool>,
+}
+
+/// Every protocol the client knows, in initialization order
+fn all_protocols() -> Vec<ProtocolId> {
+    vec![
+        ProtocolId::Dex,
+        #[cfg(feature = "evm")]
+        ProtocolId::Evm,
+        ProtocolId::Skip,
+        ProtocolId::ClaimDrop,
+    ]
+}
+
+/// Name a protocol registers under
+fn protocol_name(protocol_id: &ProtocolId) -> &'static str {
+    match protocol_id {
+        ProtocolId::Dex => "dex",
+        #[cfg(feature = "evm")]
+        ProtocolId::Evm => "evm",
+        ProtocolId::Skip => "skip",
+        ProtocolId::ClaimDrop => "claimdrop",
+    }
 }
 
 impl MantraClient {
@@ -76,105 +194,13 @@ impl MantraClient {
     ) -> Result<Self, Error> {
         // Get legacy network config for backward compatibility
         let network_config = config_manager.get_legacy_network_config();
-
-        // Create RPC client
-        let rpc_client = Arc::new(
-            HttpClient::new(network_config.rpc_url.as_str())
-                .map_err(|e| Error::Rpc(e.to_string()))?,
-        );
-
-        // Create protocol registry
-        let mut protocol_registry = ProtocolRegistry::new();
-
-        // Initialize protocols based on configuration
-        let mut dex_protocol = None;
-        #[cfg(feature = "evm")]
-        let mut evm_protocol = None;
-        let mut skip_protocol = None;
-        let mut claimdrop_protocol = None;
-
-        // Initialize DEX protocol if enabled
-        if config_manager.is_protocol_enabled(&ProtocolId::Dex) {
-            let mut dex = DexProtocol::new();
-            dex.initialize(rpc_client.clone()).await?;
-            let dex_arc = Arc::new(dex);
-            protocol_registry.register(dex_arc.clone());
-            dex_protocol = Some(dex_arc);
-        }
-
-        // Initialize EVM protocol if enabled
-        #[cfg(feature = "evm")]
-        if config_manager.is_protocol_enabled(&ProtocolId::Evm) {
-            let mut evm = crate::protocols::evm::EvmProtocol::new();
-
-            // Set RPC endpoint and chain ID from configuration if available
-            // TODO: Add configuration support for EVM RPC endpoints and chain IDs
-            // For now, this is a placeholder - actual config loading would be added
-
-            evm.initialize(rpc_client.clone()).await?;
-            let evm_arc = Arc::new(evm);
-            protocol_registry.register(evm_arc.clone());
-            evm_protocol = Some(evm_arc);
-        }
-
-        // Initialize Skip protocol if enabled
-        if config_manager.is_protocol_enabled(&ProtocolId::Skip) {
-            let mut skip = SkipProtocol::new();
-            skip.initialize(rpc_client.clone()).await?;
-
-            // Set contract addresses from configuration with validation
-            if let Ok(entry_point_addr) =
-                config_manager.get_contract_address(&ContractType::SkipEntryPoint)
-            {
-                // Validate the contract address before setting it
-                if let Err(e) = validate_contract_address(&entry_point_addr) {
-                    warn!(
-                        contract_address = %entry_point_addr,
-                        error = %e,
-                        "Invalid Skip entry point contract address, skipping"
-                    );
-                } else {
-                    skip.set_contract_address(entry_point_addr);
-                }
-            }
-
-            let skip_arc = Arc::new(skip);
-            protocol_registry.register(skip_arc.clone());
-            skip_protocol = Some(skip_arc);
-        }
-
-        // Initialize ClaimDrop protocol if enabled
-        if config_manager.is_protocol_enabled(&ProtocolId::ClaimDrop) {
-            let mut claimdrop = ClaimdropProtocol::new();
-            claimdrop.initialize(rpc_client.clone()).await?;
-
-            // Set factory address from configuration if available
-            if let Ok(factory_addr) =
-                config_manager.get_contract_address(&ContractType::ClaimdropFactory)
-            {
-                claimdrop.set_factory_address(factory_addr);
-            }
-
-            let claimdrop_arc = Arc::new(claimdrop);
-            protocol_registry.register(claimdrop_arc.clone());
-            claimdrop_protocol = Some(claimdrop_arc);
-        }
-
-        Ok(Self {
-            rpc_client,
-            config_manager,
-            network_config,
-            wallet,
-            protocol_registry,
-            dex_protocol,
-            skip_protocol,
-            claimdrop_protocol,
-            #[cfg(feature = "evm")]
-            evm_protocol,
-        })
+        Self::from_parts(config_manager, network_config, wallet)
     }
 
     /// Create a new MANTRA client (legacy method for backward compatibility)
+    ///
+    /// Endpoints and contract addresses are taken from `network_config`;
+    /// protocol settings from the configuration of the network it names.
     pub async fn new(
         network_config: MantraNetworkConfig,
         wallet: Option<Arc<MantraWallet>>,
@@ -183,7 +209,10 @@ impl MantraClient {
         let mut config_manager = ConfigurationManager::default();
 
         // Try to set the active network based on network config
-        if let Err(_) = config_manager.set_active_network(network_config.network_name.clone()) {
+        if config_manager
+            .set_active_network(network_config.network_name.clone())
+            .is_err()
+        {
             // If network not found, use default but log the issue
             warn!(
                 network_name = %network_config.network_name,
@@ -191,20 +220,69 @@ impl MantraClient {
             );
         }
 
-        Self::new_with_config(config_manager, wallet).await
+        Self::from_parts(config_manager, network_config, wallet)
+    }
+
+    fn from_parts(
+        config_manager: ConfigurationManager,
+        network_config: MantraNetworkConfig,
+        wallet: Option<Arc<MantraWallet>>,
+    ) -> Result<Self, Error> {
+        // Create RPC client
+        let rpc_client = connect(&network_config.rpc_url)?;
+
+        let endpoints = Arc::new(EndpointPool::new(
+            network_config.rpc_urls(),
+            CircuitBreakerConfig::default(),
+        ));
+        #[cfg(feature = "evm")]
+        let evm_endpoints = Arc::new(EndpointPool::new(
+            network_config.evm_rpc_urls(),
+            CircuitBreakerConfig::default(),
+        ));
+
+        Ok(Self {
+            rpc_client,
+            config_manager,
+            network_config,
+            wallet,
+            protocol_overrides: HashMap::new(),
+            skip_contract: None,
+            claimdrop_factory: None,
+            dex_protocol: OnceCell::new(),
+            skip_protocol: OnceCell::new(),
+            claimdrop_protocol: OnceCell::new(),
+            #[cfg(feature = "evm")]
+            evm_protocol: OnceCell::new(),
+            retry_policy: RetryPolicy::default(),
+            endpoints,
+            tx_tagging: TxTagging::default(),
+            #[cfg(feature = "evm")]
+            evm_endpoints,
+        })
     }
 
     /// Get the RPC client
-    pub fn rpc_client(&self) -> &Arc<HttpClient> {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn rpc_client(&self) -> &Arc<cosmrs::rpc::HttpClient> {
         &self.rpc_client
     }
 
+    /// ABCI query transport of the RPC client, shared by the protocol clients
+    pub fn transport(&self) -> SharedTransport {
+        self.rpc_client.clone()
+    }
+
     /// Get the configuration manager
     pub fn config_manager(&self) -> &ConfigurationManager {
         &self.config_manager
     }
 
     /// Get mutable reference to configuration manager
+    ///
+    /// Protocols already initialized keep their settings until
+    /// [`update_config_selective`](Self::update_config_selective) or
+    /// [`switch_network`](Self::switch_network) reinitializes them.
     pub fn config_manager_mut(&mut self) -> &mut ConfigurationManager {
         &mut self.config_manager
     }
@@ -229,9 +307,21 @@ impl MantraClient {
         self.config_manager.get_contract_address(contract_type)
     }
 
-    /// Check if a protocol is enabled
+    /// Check if a protocol is enabled, by the client or else by the configuration
     pub fn is_protocol_enabled(&self, protocol_id: &ProtocolId) -> bool {
-        self.config_manager.is_protocol_enabled(protocol_id)
+        self.protocol_overrides
+            .get(protocol_id)
+            .copied()
+            .unwrap_or_else(|| self.config_manager.is_protocol_enabled(protocol_id))
+    }
+
+    /// Enable or disable a protocol regardless of the configuration
+    ///
+    /// A disabled protocol's instance is dropped; an enabled one is
+    /// initialized on first use.
+    pub fn set_protocol_enabled(&mut self, protocol_id: ProtocolId, enabled: bool) {
+        self.reset_protocol(&protocol_id);
+        self.protocol_overrides.insert(protocol_id, enabled);
     }
 
     /// Get protocol configuration
@@ -252,13 +342,13 @@ impl MantraClient {
         self.network_config = self.config_manager.get_legacy_network_config();
 
         // Recreate RPC client with new endpoint
-        self.rpc_client = Arc::new(
-            HttpClient::new(self.network_config.rpc_url.as_str())
-                .map_err(|e| Error::Rpc(e.to_string()))?,
-        );
+        self.rpc_client = connect(&self.network_config.rpc_url)?;
 
-        // Reinitialize protocols
-        self.reinitialize_protocols().await
+        // Protocols are set up again for the new network on next use
+        for protocol_id in all_protocols() {
+            self.reset_protocol(&protocol_id);
+        }
+        Ok(())
     }
 
     /// Update configuration selectively without full reinitialization
@@ -290,216 +380,222 @@ impl MantraClient {
 
         // Update RPC client if needed
         if requires_rpc_restart {
-            self.rpc_client = Arc::new(
-                HttpClient::new(self.network_config.rpc_url.as_str())
-                    .map_err(|e| Error::Rpc(e.to_string()))?,
-            );
+            self.rpc_client = connect(&self.network_config.rpc_url)?;
             // If RPC changes, all protocols need updating
-            protocols_to_update = vec![ProtocolId::Dex, ProtocolId::Skip, ProtocolId::ClaimDrop];
+            protocols_to_update = all_protocols();
         }
 
-        // Selectively update only changed protocols
+        // Selectively reinitialize only changed protocols that were in use
         for protocol_id in protocols_to_update {
-            self.update_protocol(&protocol_id).await?;
+            let was_initialized = self.is_protocol_initialized(&protocol_id);
+            self.reset_protocol(&protocol_id);
+            if was_initialized && self.is_protocol_enabled(&protocol_id) {
+                self.init_protocol(&protocol_id).await?;
+            }
         }
 
         Ok(())
     }
 
-    /// Update a specific protocol with new configuration
-    async fn update_protocol(&mut self, protocol_id: &ProtocolId) -> Result<(), Error> {
+    /// Drop a protocol's instance so that its next use initializes it again
+    fn reset_protocol(&mut self, protocol_id: &ProtocolId) {
         match protocol_id {
-            ProtocolId::Dex => {
-                if self.config_manager.is_protocol_enabled(protocol_id) {
-                    if self.dex_protocol.is_some() {
-                        let mut dex = DexProtocol::new();
-                        dex.initialize(self.rpc_client.clone()).await?;
-                        let dex_arc = Arc::new(dex);
-
-                        // Update in registry
-                        self.protocol_registry.register(dex_arc.clone());
-                        self.dex_protocol = Some(dex_arc);
-                    }
-                } else {
-                    self.dex_protocol = None;
-                }
-            }
-            ProtocolId::Skip => {
-                if self.config_manager.is_protocol_enabled(protocol_id) {
-                    if self.skip_protocol.is_some() {
-                        let mut skip = SkipProtocol::new();
-                        skip.initialize(self.rpc_client.clone()).await?;
-
-                        // Update contract addresses with validation
-                        if let Ok(entry_point_addr) = self
-                            .config_manager
-                            .get_contract_address(&ContractType::SkipEntryPoint)
-                        {
-                            if let Err(e) = validate_contract_address(&entry_point_addr) {
-                                warn!(
-                                    contract_address = %entry_point_addr,
-                                    error = %e,
-                                    "Invalid Skip entry point contract address during update, skipping"
-                                );
-                            } else {
-                                skip.set_contract_address(entry_point_addr);
-                            }
-                        }
-
-                        let skip_arc = Arc::new(skip);
-                        self.protocol_registry.register(skip_arc.clone());
-                        self.skip_protocol = Some(skip_arc);
-                    }
-                } else {
-                    self.skip_protocol = None;
-                }
-            }
-            ProtocolId::ClaimDrop => {
-                if self.config_manager.is_protocol_enabled(protocol_id) {
-                    if self.claimdrop_protocol.is_some() {
-                        let mut claimdrop = ClaimdropProtocol::new();
-                        claimdrop.initialize(self.rpc_client.clone()).await?;
-
-                        // Update factory address with validation
-                        if let Ok(factory_addr) = self
-                            .config_manager
-                            .get_contract_address(&ContractType::ClaimdropFactory)
-                        {
-                            if let Err(e) = validate_contract_address(&factory_addr) {
-                                warn!(
-                                    contract_address = %factory_addr,
-                                    error = %e,
-                                    "Invalid ClaimDrop factory address during update, skipping"
-                                );
-                            } else {
-                                claimdrop.set_factory_address(factory_addr);
-                            }
-                        }
-
-                        let claimdrop_arc = Arc::new(claimdrop);
-                        self.protocol_registry.register(claimdrop_arc.clone());
-                        self.claimdrop_protocol = Some(claimdrop_arc);
-                    }
-                } else {
-                    self.claimdrop_protocol = None;
-                }
-            }
+            ProtocolId::Dex => self.dex_protocol = OnceCell::new(),
+            ProtocolId::Skip => self.skip_protocol = OnceCell::new(),
+            ProtocolId::ClaimDrop => self.claimdrop_protocol = OnceCell::new(),
             #[cfg(feature = "evm")]
-            &ProtocolId::Evm => {
-                if self.config_manager.is_protocol_enabled(protocol_id) {
-                    if self.evm_protocol.is_some() {
-                        let mut evm = crate::protocols::evm::EvmProtocol::new();
-                        evm.initialize(self.rpc_client.clone()).await?;
-                        let evm_arc = Arc::new(evm);
-                        self.protocol_registry.register(evm_arc.clone());
-                        self.evm_protocol = Some(evm_arc);
-                    }
-                } else {
-                    self.evm_protocol = None;
-                }
-            }
+            ProtocolId::Evm => self.evm_protocol = OnceCell::new(),
         }
-        Ok(())
     }
 
-    /// Reinitialize all protocols with current configuration
-    async fn reinitialize_protocols(&mut self) -> Result<(), Error> {
-        // Clear protocol references first to avoid dangling references in registry
-        if !self.config_manager.is_protocol_enabled(&ProtocolId::Dex) {
-            self.dex_protocol = None;
-        }
-        if !self.config_manager.is_protocol_enabled(&ProtocolId::Skip) {
-            self.skip_protocol = None;
-        }
-        if !self
-            .config_manager
-            .is_protocol_enabled(&ProtocolId::ClaimDrop)
-        {
-            self.claimdrop_protocol = None;
+    fn is_protocol_initialized(&self, protocol_id: &ProtocolId) -> bool {
+        match protocol_id {
+            ProtocolId::Dex => self.dex_protocol.initialized(),
+            ProtocolId::Skip => self.skip_protocol.initialized(),
+            ProtocolId::ClaimDrop => self.claimdrop_protocol.initialized(),
+            #[cfg(feature = "evm")]
+            ProtocolId::Evm => self.evm_protocol.initialized(),
         }
+    }
 
-        // Now clear existing protocol registry safely
-        self.protocol_registry = ProtocolRegistry::new();
+    /// Initialize a protocol unless it already is
+    async fn init_protocol(&self, protocol_id: &ProtocolId) -> Result<Arc<dyn Protocol>, Error> {
+        Ok(match protocol_id {
+            ProtocolId::Dex => self.dex_protocol().await?,
+            ProtocolId::Skip => self.skip_protocol().await?,
+            ProtocolId::ClaimDrop => self.claimdrop_protocol().await?,
+            #[cfg(feature = "evm")]
+            ProtocolId::Evm => self.evm_protocol().await?,
+        })
+    }
 
-        // Reinitialize DEX protocol if enabled
-        if self.config_manager.is_protocol_enabled(&ProtocolId::Dex) && self.dex_protocol.is_some()
-        {
-            let mut dex = DexProtocol::new();
-            dex.initialize(self.rpc_client.clone()).await?;
-            let dex_arc = Arc::new(dex);
-            self.protocol_registry.register(dex_arc.clone());
-            self.dex_protocol = Some(dex_arc);
+    fn ensure_enabled(&self, protocol_id: &ProtocolId) -> Result<(), Error> {
+        if self.is_protocol_enabled(protocol_id) {
+            Ok(())
+        } else {
+            Err(Error::Config(format!(
+                "Protocol '{}' is disabled",
+                protocol_id
+            )))
         }
+    }
 
-        // Reinitialize Skip protocol if enabled
-        if self.config_manager.is_protocol_enabled(&ProtocolId::Skip)
-            && self.skip_protocol.is_some()
-        {
-            let mut skip = SkipProtocol::new();
-            skip.initialize(self.rpc_client.clone()).await?;
-
-            // Update contract addresses with validation
-            if let Ok(entry_point_addr) = self
-                .config_manager
-                .get_contract_address(&ContractType::SkipEntryPoint)
-            {
-                if let Err(e) = validate_contract_address(&entry_point_addr) {
-                    warn!(
-                        contract_address = %entry_point_addr,
-                        error = %e,
-                        "Invalid Skip entry point contract address during reinit, skipping"
-                    );
-                } else {
-                    skip.set_contract_address(entry_point_addr);
-                }
-            }
-
-            let skip_arc = Arc::new(skip);
-            self.protocol_registry.register(skip_arc.clone());
-            self.skip_protocol = Some(skip_arc);
-        }
+    fn protocol_unavailable(&self, protocol_id: &ProtocolId, reason: &str) -> Error {
+        Error::Config(format!(
+            "Protocol '{}' is not available on network '{}': {}",
+            protocol_id, self.network_config.network_name, reason
+        ))
+    }
 
-        // Reinitialize ClaimDrop protocol if enabled
-        if self
+    /// Configured address of a protocol contract, checked to be a valid
+    /// address, and the code ID the contract registry records for it
+    fn protocol_contract(
+        &self,
+        protocol_id: &ProtocolId,
+        contract_type: &ContractType,
+        address: Option<String>,
+    ) -> Result<(String, Option<u64>), Error> {
+        let address = address
+            .filter(|address| !address.is_empty())
+            .or_else(|| self.config_manager.get_contract_address(contract_type).ok())
+            .ok_or_else(|| {
+                self.protocol_unavailable(
+                    protocol_id,
+                    &format!("no {:?} contract configured", contract_type),
+                )
+            })?;
+        validate_contract_address(&address)
+            .map_err(|e| self.protocol_unavailable(protocol_id, &e.to_string()))?;
+        let code_id = self
             .config_manager
-            .is_protocol_enabled(&ProtocolId::ClaimDrop)
-            && self.claimdrop_protocol.is_some()
-        {
-            let mut claimdrop = ClaimdropProtocol::new();
-            claimdrop.initialize(self.rpc_client.clone()).await?;
-
-            // Update factory address with validation
-            if let Ok(factory_addr) = self
-                .config_manager
-                .get_contract_address(&ContractType::ClaimdropFactory)
-            {
-                if let Err(e) = validate_contract_address(&factory_addr) {
-                    warn!(
-                        contract_address = %factory_addr,
-                        error = %e,
-                        "Invalid ClaimDrop factory address during reinit, skipping"
-                    );
-                } else {
-                    claimdrop.set_factory_address(factory_addr);
-                }
-            }
+            .contract_registry
+            .get_contract_info(contract_type)
+            .ok()
+            .filter(|info| info.address == address)
+            .and_then(|info| info.code_id);
+        Ok((address, code_id))
+    }
 
-            let claimdrop_arc = Arc::new(claimdrop);
-            self.protocol_registry.register(claimdrop_arc.clone());
-            self.claimdrop_protocol = Some(claimdrop_arc);
+    /// Fail unless initialization found the protocol's contract on chain
+    fn ensure_deployed(
+        &self,
+        protocol_id: &ProtocolId,
+        deployment: Option<&ContractDiscovery>,
+    ) -> Result<(), Error> {
+        match deployment.and_then(ContractDiscovery::problem) {
+            Some(problem) => Err(self.protocol_unavailable(protocol_id, &problem)),
+            None => Ok(()),
         }
+    }
 
-        Ok(())
+    /// DEX protocol, initialized on first use
+    ///
+    /// Fails when the DEX is disabled or the network has no pool manager
+    /// deployed.
+    pub async fn dex_protocol(&self) -> Result<Arc<DexProtocol>, Error> {
+        self.ensure_enabled(&ProtocolId::Dex)?;
+        self.dex_protocol
+            .get_or_try_init(|| async {
+                let (pool_manager, code_id) = self.protocol_contract(
+                    &ProtocolId::Dex,
+                    &ContractType::PoolManager,
+                    Some(self.network_config.contracts.pool_manager.clone()),
+                )?;
+                let mut dex = DexProtocol::new();
+                dex.set_pool_manager(pool_manager);
+                dex.set_expected_code_id(code_id);
+                dex.initialize(self.transport()).await?;
+                self.ensure_deployed(&ProtocolId::Dex, dex.deployment())?;
+                Ok(Arc::new(dex))
+            })
+            .await
+            .cloned()
+    }
+
+    /// Skip protocol, initialized on first use
+    ///
+    /// Fails when Skip is disabled or the network has no Skip entry point
+    /// contract deployed.
+    pub async fn skip_protocol(&self) -> Result<Arc<SkipProtocol>, Error> {
+        self.ensure_enabled(&ProtocolId::Skip)?;
+        self.skip_protocol
+            .get_or_try_init(|| async {
+                let (entry_point, code_id) = self.protocol_contract(
+                    &ProtocolId::Skip,
+                    &ContractType::SkipEntryPoint,
+                    self.skip_contract
+                        .clone()
+                        .or_else(|| self.network_config.contracts.skip_entry_point.clone()),
+                )?;
+                let mut skip = SkipProtocol::new();
+                skip.set_contract_address(entry_point);
+                skip.set_expected_code_id(code_id);
+                skip.initialize(self.transport()).await?;
+                self.ensure_deployed(&ProtocolId::Skip, skip.deployment())?;
+                Ok(Arc::new(skip))
+            })
+            .await
+            .cloned()
+    }
+
+    /// ClaimDrop protocol, initialized on first use
+    ///
+    /// Fails when ClaimDrop is disabled or the network has no factory
+    /// contract deployed.
+    pub async fn claimdrop_protocol(&self) -> Result<Arc<ClaimdropProtocol>, Error> {
+        self.ensure_enabled(&ProtocolId::ClaimDrop)?;
+        self.claimdrop_protocol
+            .get_or_try_init(|| async {
+                let (factory, code_id) = self.protocol_contract(
+                    &ProtocolId::ClaimDrop,
+                    &ContractType::ClaimdropFactory,
+                    self.claimdrop_factory.clone(),
+                )?;
+                let mut claimdrop = ClaimdropProtocol::new();
+                claimdrop.set_factory_address(factory);
+                claimdrop.set_expected_code_id(code_id);
+                claimdrop.initialize(self.transport()).await?;
+                self.ensure_deployed(&ProtocolId::ClaimDrop, claimdrop.deployment())?;
+                Ok(Arc::new(claimdrop))
+            })
+            .await
+            .cloned()
     }
 
-    /// List all available protocols
+    /// EVM protocol, initialized on first use
+    ///
+    /// Fails when EVM is disabled or the network has no EVM RPC endpoint.
+    #[cfg(feature = "evm")]
+    pub async fn evm_protocol(&self) -> Result<Arc<EvmProtocol>, Error> {
+        self.ensure_enabled(&ProtocolId::Evm)?;
+        self.evm_protocol
+            .get_or_try_init(|| async {
+                if self.network_config.evm_rpc_url.is_none() {
+                    return Err(
+                        self.protocol_unavailable(&ProtocolId::Evm, "no EVM RPC URL configured")
+                    );
+                }
+                let mut evm = EvmProtocol::new();
+                evm.initialize(self.transport()).await?;
+                Ok(Arc::new(evm))
+            })
+            .await
+            .cloned()
+    }
+
+    /// List all enabled protocols, initialized or not
     pub fn list_protocols(&self) -> Vec<&str> {
-        self.protocol_registry.list()
+        all_protocols()
+            .iter()
+            .filter(|protocol_id| self.is_protocol_enabled(protocol_id))
+            .map(protocol_name)
+            .collect()
     }
 
-    /// Check if a protocol is available
+    /// Check if a protocol has been initialized successfully
     pub fn is_protocol_available(&self, protocol_name: &str) -> bool {
-        self.protocol_registry.get(protocol_name).is_some()
+        ProtocolId::from_str(protocol_name)
+            .is_ok_and(|protocol_id| self.is_protocol_initialized(&protocol_id))
     }
 
     // ============ Protocol-specific accessors ============
@@ -507,19 +603,25 @@ impl MantraClient {
     /// Get DEX client for DEX operations
     pub async fn dex(&self) -> Result<MantraDexClient, Error> {
         // Create a DEX client with the current configuration
-        let client = MantraDexClient::new(self.network_config.clone()).await?;
+        let client = MantraDexClient::new(self.network_config.clone())
+            .await?
+            .with_retry_policy(self.retry_policy.clone())
+            .with_endpoint_pool(self.endpoints.clone())
+            .with_tx_tagging(&self.tx_tagging);
 
         // Return client (wallet will be set when transactions are performed)
         Ok(client)
     }
 
     /// Get Skip client for cross-chain operations
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn skip(&self) -> Result<crate::protocols::skip::SkipClient, Error> {
         // Create a Skip client with the current configuration
         let mut client = crate::protocols::skip::SkipClient::new(self.wallet.clone()).await?;
+        client.set_retry_policy(self.retry_policy.clone());
 
-        // Set adapter contract address if available
-        if let Some(skip_protocol) = &self.skip_protocol {
+        // Set adapter contract address if the network has one
+        if let Ok(skip_protocol) = self.skip_protocol().await {
             if let Some(contract_addr) = skip_protocol.contract_address() {
                 client.set_adapter_contract(contract_addr.to_string());
             }
@@ -531,19 +633,15 @@ impl MantraClient {
     /// Get EVM client for Ethereum Virtual Machine operations
     #[cfg(feature = "evm")]
     pub async fn evm(&self) -> Result<crate::protocols::evm::client::EvmClient, Error> {
-        // Get EVM configuration from ConfigurationManager
-        let evm_rpc_url = self.config_manager
-            .env_config
-            .network
+        // Get EVM configuration from the network configuration
+        let evm_rpc_url = self.network_config
             .evm_rpc_url
             .clone()
             .ok_or_else(|| Error::Config(
                 "EVM RPC URL not configured. Set MANTRA_NETWORK_EVM_RPC_URL environment variable or configure in network.toml".to_string()
             ))?;
 
-        let evm_chain_id = self.config_manager
-            .env_config
-            .network
+        let evm_chain_id = self.network_config
             .evm_chain_id
             .ok_or_else(|| Error::Config(
                 "EVM chain ID not configured. Set MANTRA_NETWORK_EVM_CHAIN_ID environment variable or configure in network.toml".to_string()
@@ -555,16 +653,20 @@ impl MantraClient {
             evm_chain_id
         );
 
-        let client =
-            crate::protocols::evm::client::EvmClient::new(&evm_rpc_url, evm_chain_id).await?;
+        let client = crate::protocols::evm::client::EvmClient::new(&evm_rpc_url, evm_chain_id)
+            .await?
+            .with_retry_policy(self.retry_policy.clone())
+            .with_fallback_rpc_urls(&self.network_config.evm_rpc_fallback_urls)?
+            .with_endpoint_pool(self.evm_endpoints.clone())
+            .with_tx_tagging(&self.tx_tagging);
         Ok(client)
     }
 
     /// Get ClaimDrop factory client
     pub fn claimdrop_factory(&self, factory_address: String) -> ClaimdropFactoryClient {
-        use tokio::sync::Mutex;
         ClaimdropFactoryClient::new(
-            Arc::new(Mutex::new((*self.rpc_client).clone())),
+            self.transport(),
+            self.network_config.chain_id.clone(),
             factory_address,
             self.wallet.clone(),
         )
@@ -575,64 +677,108 @@ impl MantraClient {
         &self,
         campaign_address: String,
     ) -> crate::protocols::claimdrop::ClaimdropClient {
-        use tokio::sync::Mutex;
         crate::protocols::claimdrop::ClaimdropClient::new(
-            Arc::new(Mutex::new((*self.rpc_client).clone())),
+            self.transport(),
             campaign_address,
             self.wallet.clone(),
         )
     }
 
-    /// Get Skip protocol configuration
+    /// Get Skip protocol configuration, once the protocol has been used
     pub fn skip_config(&self) -> Option<serde_json::Value> {
-        self.skip_protocol
-            .as_ref()
-            .and_then(|p| p.get_config().ok())
+        self.skip_protocol.get().and_then(|p| p.get_config().ok())
     }
 
     /// Set Skip adapter contract address
+    ///
+    /// Takes effect when the Skip protocol is next initialized, which is
+    /// forced here.
     pub fn set_skip_contract(&mut self, address: String) {
-        if let Some(skip_arc) = &mut self.skip_protocol {
-            if let Some(skip) = Arc::get_mut(skip_arc) {
-                skip.set_contract_address(address);
-            } else {
-                // If there are multiple references, create a new instance
-                warn!(
-                    "Cannot mutate Skip protocol due to multiple references, recreating protocol"
-                );
-                let mut new_skip = (**skip_arc).clone();
-                new_skip.set_contract_address(address);
-                *skip_arc = Arc::new(new_skip);
-            }
+        self.skip_contract = Some(address);
+        self.reset_protocol(&ProtocolId::Skip);
+    }
+
+    /// Set how protocol clients retry transient failures
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Retry policy given to the protocol clients
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Set the memo pattern and EVM calldata tag of the protocol clients'
+    /// transactions
+    pub fn set_tx_tagging(&mut self, tagging: TxTagging) -> Result<(), Error> {
+        tagging.validate()?;
+        self.tx_tagging = tagging;
+        Ok(())
+    }
+
+    /// Attribution tags stamped on the protocol clients' transactions
+    pub fn tx_tagging(&self) -> &TxTagging {
+        &self.tx_tagging
+    }
+
+    /// Set when an RPC endpoint's circuit opens and for how long
+    ///
+    /// Endpoint health recorded so far is reset.
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) {
+        self.endpoints = Arc::new(EndpointPool::new(
+            self.network_config.rpc_urls(),
+            config.clone(),
+        ));
+        #[cfg(feature = "evm")]
+        {
+            self.evm_endpoints = Arc::new(EndpointPool::new(
+                self.network_config.evm_rpc_urls(),
+                config,
+            ));
         }
     }
 
+    /// Health of each Cosmos RPC endpoint, the primary first
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    /// Health of each EVM RPC endpoint, the primary first
+    #[cfg(feature = "evm")]
+    pub fn evm_endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.evm_endpoints.health()
+    }
+
     /// Set ClaimDrop factory address
+    ///
+    /// Takes effect when the ClaimDrop protocol is next initialized, which is
+    /// forced here.
     pub fn set_claimdrop_factory(&mut self, address: String) {
-        if let Some(claimdrop_arc) = &mut self.claimdrop_protocol {
-            if let Some(claimdrop) = Arc::get_mut(claimdrop_arc) {
-                claimdrop.set_factory_address(address);
-            } else {
-                // If there are multiple references, create a new instance
-                warn!("Cannot mutate ClaimDrop protocol due to multiple references, recreating protocol");
-                let mut new_claimdrop = (**claimdrop_arc).clone();
-                new_claimdrop.set_factory_address(address);
-                *claimdrop_arc = Arc::new(new_claimdrop);
-            }
-        }
+        self.claimdrop_factory = Some(address);
+        self.reset_protocol(&ProtocolId::ClaimDrop);
     }
 
     // ============ Utility methods ============
 
-    /// Check connectivity to all configured protocols
+    /// Check connectivity to all enabled protocols, initializing them
+    ///
+    /// A protocol that cannot be initialized on this network is reported as
+    /// unavailable.
     pub async fn check_connectivity(&self) -> Result<Vec<(String, bool)>, Error> {
         let mut results = Vec::new();
 
-        for protocol_name in self.list_protocols() {
-            if let Some(protocol) = self.protocol_registry.get(protocol_name) {
-                let available = protocol.is_available(&self.rpc_client).await?;
-                results.push((protocol_name.to_string(), available));
+        for protocol_id in all_protocols() {
+            if !self.is_protocol_enabled(&protocol_id) {
+                continue;
             }
+            let available = match self.init_protocol(&protocol_id).await {
+                Ok(protocol) => protocol.is_available(&self.transport()).await?,
+                Err(e) => {
+                    warn!(protocol = %protocol_id, error = %e, "Protocol unavailable");
+                    false
+                }
+            };
+            results.push((protocol_name(&protocol_id).to_string(), available));
         }
 
         Ok(results)
@@ -655,9 +801,14 @@ impl MantraClient {
 pub struct MantraClientBuilder {
     config_manager: Option<ConfigurationManager>,
     network_config: Option<MantraNetworkConfig>, // Legacy support
+    config_loader: Option<NetworkConfigLoader>,
     wallet: Option<Arc<MantraWallet>>,
     skip_contract: Option<String>,
     claimdrop_factory: Option<String>,
+    retry_policy: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    tx_tagging: Option<TxTagging>,
+    protocols: Vec<(ProtocolId, bool)>,
 }
 
 impl MantraClientBuilder {
@@ -666,12 +817,42 @@ impl MantraClientBuilder {
         Self {
             config_manager: None,
             network_config: None,
+            config_loader: None,
             wallet: None,
             skip_contract: None,
             claimdrop_factory: None,
+            retry_policy: None,
+            circuit_breaker: None,
+            tx_tagging: None,
+            protocols: Vec::new(),
         }
     }
 
+    /// Connect to MANTRA mainnet (the `mainnet` network profile)
+    pub fn mainnet(self) -> Self {
+        self.with_profile("mainnet")
+    }
+
+    /// Connect to the Dukong testnet (the `dukong` network profile)
+    pub fn testnet(self) -> Self {
+        self.with_profile("dukong")
+    }
+
+    /// Connect to a local node (the `local` network profile)
+    pub fn local(self) -> Self {
+        self.with_profile("local")
+    }
+
+    /// Start from the network profile called `name`
+    ///
+    /// The config file, environment and any loader overrides still apply on
+    /// top of the profile.
+    pub fn with_profile(mut self, name: impl Into<String>) -> Self {
+        let loader = self.config_loader.take().unwrap_or_default();
+        self.config_loader = Some(loader.with_profile(name));
+        self
+    }
+
     /// Create a new builder with the modern configuration system
     pub fn with_config_manager(mut self, config_manager: ConfigurationManager) -> Self {
         self.config_manager = Some(config_manager);
@@ -684,6 +865,13 @@ impl MantraClientBuilder {
         self
     }
 
+    /// Load the network configuration in layers: profile defaults, config
+    /// file, environment, then the loader's own overrides
+    pub fn with_config_loader(mut self, loader: NetworkConfigLoader) -> Self {
+        self.config_loader = Some(loader);
+        self
+    }
+
     /// Set the wallet
     pub fn with_wallet(mut self, wallet: Arc<MantraWallet>) -> Self {
         self.wallet = Some(wallet);
@@ -702,7 +890,41 @@ impl MantraClientBuilder {
         self
     }
 
+    /// Set how protocol clients retry transient failures
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Set when an RPC endpoint's circuit opens and for how long
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Stamp transactions with a memo pattern such as
+    /// `mantra-sdk/{version} via my-app`, and optionally tag EVM calldata
+    pub fn with_tx_tagging(mut self, tagging: TxTagging) -> Self {
+        self.tx_tagging = Some(tagging);
+        self
+    }
+
+    /// Enable a protocol regardless of the configuration
+    pub fn enable_protocol(mut self, protocol_id: ProtocolId) -> Self {
+        self.protocols.push((protocol_id, true));
+        self
+    }
+
+    /// Disable a protocol regardless of the configuration
+    pub fn disable_protocol(mut self, protocol_id: ProtocolId) -> Self {
+        self.protocols.push((protocol_id, false));
+        self
+    }
+
     /// Build the MantraClient using the modern configuration system
+    ///
+    /// Protocols are not initialized yet, so building succeeds on networks
+    /// lacking an optional protocol's contracts.
     pub async fn build(self) -> Result<MantraClient, Error> {
         let mut client = if let Some(config_manager) = self.config_manager {
             // Use modern configuration system
@@ -710,6 +932,8 @@ impl MantraClientBuilder {
         } else if let Some(network_config) = self.network_config {
             // Use legacy configuration system
             MantraClient::new(network_config, self.wallet).await?
+        } else if let Some(loader) = self.config_loader {
+            MantraClient::new(loader.load()?, self.wallet).await?
         } else {
             // Use default configuration
             let config_manager = ConfigurationManager::default();
@@ -725,12 +949,31 @@ impl MantraClientBuilder {
             client.set_claimdrop_factory(claimdrop_factory);
         }
 
+        if let Some(retry_policy) = self.retry_policy {
+            client.set_retry_policy(retry_policy);
+        }
+
+        if let Some(circuit_breaker) = self.circuit_breaker {
+            client.set_circuit_breaker(circuit_breaker);
+        }
+
+        if let Some(tx_tagging) = self.tx_tagging {
+            client.set_tx_tagging(tx_tagging)?;
+        }
+
+        for (protocol_id, enabled) in self.protocols {
+            client.set_protocol_enabled(protocol_id, enabled);
+        }
+
         Ok(client)
     }
 
     /// Build the MantraClient with automatic configuration discovery
     pub async fn build_auto(self) -> Result<MantraClient, Error> {
-        if self.config_manager.is_none() && self.network_config.is_none() {
+        if self.config_manager.is_none()
+            && self.network_config.is_none()
+            && self.config_loader.is_none()
+        {
             // Try to load configuration automatically
             match ConfigurationManager::new() {
                 Ok(config_manager) => self.with_config_manager(config_manager).build().await,
@@ -750,3 +993,59 @@ impl Default for MantraClientBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_presets_and_lazily_initialized_protocols() {
+        let loader = NetworkConfigLoader::new()
+            .without_config_file()
+            .without_env()
+            .with_pool_manager("mantra1kz0gcs8n0qa9rje5zdrlwqccxlwu8zttzmdtxhdq0jpk3efjs37qr4s2sv");
+        let client = MantraClientBuilder::new()
+            .with_config_loader(loader)
+            .local()
+            .enable_protocol(ProtocolId::Dex)
+            .enable_protocol(ProtocolId::Skip)
+            .disable_protocol(ProtocolId::ClaimDrop)
+            .with_skip_contract("not-an-address".to_string())
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.network_config().chain_id, "mantra-local-1");
+        assert!(!client.list_protocols().contains(&"claimdrop"));
+
+        // Nothing is initialized until it is used, and a protocol that could
+        // not be found on chain is not kept
+        assert!(!client.is_protocol_available("dex"));
+        assert!(client.dex_protocol().await.is_err());
+        assert!(!client.is_protocol_available("dex"));
+
+        // A protocol missing its contracts fails only when used
+        let err = client.skip_protocol().await.err().unwrap().to_string();
+        assert!(err.contains("not available"), "{}", err);
+        assert!(!client.is_protocol_available("skip"));
+        let err = client.claimdrop_protocol().await.err().unwrap().to_string();
+        assert!(err.contains("disabled"), "{}", err);
+    }
+
+    #[test]
+    fn test_configuration_changes_between_networks() {
+        let old = MantraNetworkConfig::default();
+        assert!(ConfigurationChanges::between(&old, &old).is_empty());
+
+        let mut new = old.clone();
+        new.native_denom = "uusdc".to_string();
+        let changes = ConfigurationChanges::between(&old, &new);
+        assert!(changes.rpc_url.is_none());
+        assert!(changes.dex_config_changed && changes.claimdrop_config_changed);
+
+        let mut new = old.clone();
+        new.rpc_url = "https://rpc.example.com/".to_string();
+        let changes = ConfigurationChanges::between(&old, &new);
+        assert_eq!(changes.rpc_url.as_deref(), Some("https://rpc.example.com/"));
+        assert!(!changes.dex_config_changed && !changes.skip_config_changed);
+    }
+}
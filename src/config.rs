@@ -6,11 +6,15 @@
 // Modular configuration system
 pub mod contracts;
 pub mod env;
+pub mod loader;
+pub mod profiles;
 pub mod protocols;
 
 // Re-export key types from modular system
 pub use contracts::{ContractInfo, ContractRegistry, ContractType, NetworkContracts};
 pub use env::{EnvironmentConfig, LoggingEnvConfig, McpEnvConfig, NetworkEnvConfig};
+pub use loader::{ConfigFileSource, NetworkConfigLoader, SdkConfigFile};
+pub use profiles::{NetworkProfile, ProfileContracts};
 pub use protocols::{
     FeeConfig, HealthConfig, ProtocolConfig, ProtocolId, ProtocolParameters, ProtocolRegistry,
     RateLimitConfig,
@@ -23,6 +27,7 @@ use std::collections::HashMap;
 use std::env as std_env;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use crate::error::Error;
 
@@ -186,6 +191,13 @@ pub struct MantraNetworkConfig {
     pub chain_id: String,
     /// RPC endpoint URL
     pub rpc_url: String,
+    /// RPC endpoints failed over to when `rpc_url` is unhealthy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpc_fallback_urls: Vec<String>,
+    /// gRPC endpoint (port 9090 on a node); when set, bank, wasm, auth and tx
+    /// queries go over gRPC and fall back to RPC when it is unreachable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grpc_url: Option<String>,
     /// Gas price in native token
     pub gas_price: f64,
     /// Gas adjustment for transactions
@@ -200,6 +212,10 @@ pub struct MantraNetworkConfig {
     /// EVM chain ID (optional)
     #[cfg(feature = "evm")]
     pub evm_chain_id: Option<u64>,
+    /// EVM RPC endpoints failed over to when `evm_rpc_url` is unhealthy
+    #[cfg(feature = "evm")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evm_rpc_fallback_urls: Vec<String>,
 }
 
 impl MantraNetworkConfig {
@@ -219,6 +235,8 @@ impl MantraNetworkConfig {
             network_name: constants.network_name.clone(),
             chain_id: constants.chain_id.clone(),
             rpc_url: constants.default_rpc.clone(),
+            rpc_fallback_urls: Vec::new(),
+            grpc_url: None,
             gas_price: constants.default_gas_price,
             gas_adjustment: constants.default_gas_adjustment,
             native_denom: constants.native_denom.clone(),
@@ -227,6 +245,8 @@ impl MantraNetworkConfig {
             evm_rpc_url: None, // Will be populated from env config or network.toml
             #[cfg(feature = "evm")]
             evm_chain_id: None, // Will be populated from env config or network.toml
+            #[cfg(feature = "evm")]
+            evm_rpc_fallback_urls: Vec::new(),
         })
     }
 
@@ -234,16 +254,156 @@ impl MantraNetworkConfig {
     pub fn from_env_config(env_config: &EnvironmentConfig) -> Result<Self, Error> {
         let constants = NetworkConstants::from(env_config);
         let mut config = Self::from_constants(&constants)?;
+        config
+            .rpc_fallback_urls
+            .clone_from(&env_config.network.rpc_fallback_urls);
 
         #[cfg(feature = "evm")]
         {
             config.evm_rpc_url = env_config.network.evm_rpc_url.clone();
             config.evm_chain_id = env_config.network.evm_chain_id;
+            config
+                .evm_rpc_fallback_urls
+                .clone_from(&env_config.network.evm_rpc_fallback_urls);
         }
 
         Ok(config)
     }
 
+    /// RPC endpoints in failover order: `rpc_url`, then the fallbacks
+    pub fn rpc_urls(&self) -> Vec<String> {
+        std::iter::once(self.rpc_url.clone())
+            .chain(self.rpc_fallback_urls.iter().cloned())
+            .collect()
+    }
+
+    /// EVM RPC endpoints in failover order, empty without an `evm_rpc_url`
+    #[cfg(feature = "evm")]
+    pub fn evm_rpc_urls(&self) -> Vec<String> {
+        self.evm_rpc_url
+            .iter()
+            .chain(self.evm_rpc_fallback_urls.iter())
+            .cloned()
+            .collect()
+    }
+
+    /// Every problem with the configuration: malformed RPC URLs, chain IDs and
+    /// contract addresses, and non-positive gas settings
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if let Err(problem) = check_chain_id(&self.chain_id) {
+            problems.push(format!("chain_id: {}", problem));
+        }
+        for (field, url) in std::iter::once(("rpc_url", &self.rpc_url)).chain(
+            self.rpc_fallback_urls
+                .iter()
+                .map(|url| ("rpc_fallback_urls", url)),
+        ) {
+            if let Err(problem) = check_rpc_url(url) {
+                problems.push(format!("{}: {}", field, problem));
+            }
+        }
+        if let Some(url) = &self.grpc_url {
+            if let Err(problem) = check_rpc_url(url) {
+                problems.push(format!("grpc_url: {}", problem));
+            }
+        }
+        #[cfg(feature = "evm")]
+        {
+            for (field, url) in self
+                .evm_rpc_url
+                .iter()
+                .map(|url| ("evm_rpc_url", url))
+                .chain(
+                    self.evm_rpc_fallback_urls
+                        .iter()
+                        .map(|url| ("evm_rpc_fallback_urls", url)),
+                )
+            {
+                if let Err(problem) = check_rpc_url(url) {
+                    problems.push(format!("{}: {}", field, problem));
+                }
+            }
+            if self.evm_rpc_url.is_none() && !self.evm_rpc_fallback_urls.is_empty() {
+                problems.push("evm_rpc_fallback_urls: set without an evm_rpc_url".to_string());
+            }
+            if self.evm_chain_id == Some(0) {
+                problems.push("evm_chain_id: must be greater than 0".to_string());
+            }
+        }
+        if !(self.gas_price.is_finite() && self.gas_price > 0.0) {
+            problems.push(format!(
+                "gas_price: must be positive, got {}",
+                self.gas_price
+            ));
+        }
+        if !(self.gas_adjustment.is_finite() && self.gas_adjustment > 0.0) {
+            problems.push(format!(
+                "gas_adjustment: must be positive, got {}",
+                self.gas_adjustment
+            ));
+        }
+        if self.native_denom.trim().is_empty() {
+            problems.push("native_denom: is empty".to_string());
+        }
+
+        let contracts = &self.contracts;
+        let mut prefixes = std::collections::BTreeSet::new();
+        for (field, address) in [
+            ("pool_manager", Some(&contracts.pool_manager)),
+            ("farm_manager", contracts.farm_manager.as_ref()),
+            ("fee_collector", contracts.fee_collector.as_ref()),
+            ("epoch_manager", contracts.epoch_manager.as_ref()),
+            ("skip_entry_point", contracts.skip_entry_point.as_ref()),
+            (
+                "skip_ibc_hooks_adapter",
+                contracts.skip_ibc_hooks_adapter.as_ref(),
+            ),
+            (
+                "skip_mantra_dex_adapter",
+                contracts.skip_mantra_dex_adapter.as_ref(),
+            ),
+        ] {
+            // An unset pool manager suits read-only queries
+            let Some(address) = address.filter(|address| !address.is_empty()) else {
+                continue;
+            };
+            match cosmrs::AccountId::from_str(address) {
+                Ok(account) => {
+                    prefixes.insert(account.prefix().to_string());
+                }
+                Err(e) => problems.push(format!(
+                    "contracts.{}: '{}' is not a bech32 address: {}",
+                    field, address, e
+                )),
+            }
+        }
+        if prefixes.len() > 1 {
+            problems.push(format!(
+                "contracts: addresses mix bech32 prefixes ({})",
+                prefixes.into_iter().collect::<Vec<_>>().join(", ")
+            ));
+        }
+
+        problems
+    }
+
+    /// Check the configuration, reporting every problem at once
+    pub fn validate(&self) -> Result<(), Error> {
+        let problems = self.problems();
+        if problems.is_empty() {
+            return Ok(());
+        }
+        Err(Error::Config(format!(
+            "Invalid network configuration for '{}' ({} problem{}): {}",
+            self.network_name,
+            problems.len(),
+            if problems.len() == 1 { "" } else { "s" },
+            problems.join("; ")
+        )))
+    }
+
     /// Load contract addresses for the given network from the contracts configuration file.
     /// Legacy method for backward compatibility.
     fn load_contract_addresses(network: &str) -> Result<ContractAddresses, Error> {
@@ -296,6 +456,38 @@ impl MantraNetworkConfig {
     }
 }
 
+/// Check a Cosmos chain ID such as `mantra-dukong-1`
+fn check_chain_id(chain_id: &str) -> Result<(), String> {
+    if chain_id.is_empty() {
+        return Err("is empty".to_string());
+    }
+    if chain_id.len() > 50 {
+        return Err(format!("'{}' is longer than 50 characters", chain_id));
+    }
+    if !chain_id
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(format!(
+            "'{}' may only contain letters, digits, '-', '_' and '.'",
+            chain_id
+        ));
+    }
+    Ok(())
+}
+
+/// Check an RPC endpoint URL
+fn check_rpc_url(url: &str) -> Result<(), String> {
+    let parsed = url::Url::parse(url).map_err(|e| format!("'{}' is not a URL: {}", url, e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("'{}' must use http:// or https://", url));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(format!("'{}' has no host", url));
+    }
+    Ok(())
+}
+
 impl Default for MantraNetworkConfig {
     fn default() -> Self {
         match NetworkConstants::default_dukong() {
@@ -303,6 +495,8 @@ impl Default for MantraNetworkConfig {
                 network_name: constants.network_name,
                 chain_id: constants.chain_id,
                 rpc_url: constants.default_rpc,
+                rpc_fallback_urls: Vec::new(),
+                grpc_url: None,
                 gas_price: constants.default_gas_price,
                 gas_adjustment: constants.default_gas_adjustment,
                 native_denom: constants.native_denom,
@@ -311,11 +505,15 @@ impl Default for MantraNetworkConfig {
                 evm_rpc_url: None,
                 #[cfg(feature = "evm")]
                 evm_chain_id: None,
+                #[cfg(feature = "evm")]
+                evm_rpc_fallback_urls: Vec::new(),
             }),
             Err(_) => Self {
                 network_name: "mantra-dukong".to_string(),
                 chain_id: "mantra-dukong-1".to_string(),
                 rpc_url: "https://rpc.dukong.mantrachain.io:443".to_string(),
+                rpc_fallback_urls: Vec::new(),
+                grpc_url: None,
                 gas_price: 0.01,
                 gas_adjustment: 1.5,
                 native_denom: "uom".to_string(),
@@ -324,6 +522,8 @@ impl Default for MantraNetworkConfig {
                 evm_rpc_url: None,
                 #[cfg(feature = "evm")]
                 evm_chain_id: None,
+                #[cfg(feature = "evm")]
+                evm_rpc_fallback_urls: Vec::new(),
             },
         }
     }
@@ -342,6 +542,25 @@ pub struct TokenInfo {
     pub logo: Option<String>,
 }
 
+/// User preferences stored alongside the network configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// Slippage tolerance new swaps and liquidity changes start with, in percent
+    pub default_slippage: String,
+    /// Color theme of the TUI
+    pub theme: String,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            default_slippage: "1.0".to_string(),
+            theme: "default".to_string(),
+        }
+    }
+}
+
 /// Complete legacy configuration with wallet info
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -351,6 +570,9 @@ pub struct Config {
     pub mnemonic: Option<String>,
     /// Known tokens and their metadata
     pub tokens: HashMap<String, TokenInfo>,
+    /// Defaults applied by the interfaces; files without them get the defaults
+    #[serde(default)]
+    pub preferences: Preferences,
 }
 
 impl Default for Config {
@@ -366,6 +588,7 @@ impl Config {
             network: MantraNetworkConfig::default(),
             mnemonic: None,
             tokens: HashMap::new(),
+            preferences: Preferences::default(),
         }
     }
 
@@ -408,6 +631,7 @@ impl Config {
             network: network_config,
             mnemonic: None,
             tokens: HashMap::new(),
+            preferences: Preferences::default(),
         })
     }
 }
@@ -434,7 +658,10 @@ impl ConfigurationManager {
 
         // Set active network
         let active_network = env_config.get_network_name();
-        if let Err(_) = contract_registry.set_active_network(&active_network) {
+        if contract_registry
+            .set_active_network(&active_network)
+            .is_err()
+        {
             // Network not found in contract registry, that's okay
         }
         protocol_registry.set_active_network(&active_network);
@@ -455,7 +682,7 @@ impl ConfigurationManager {
     /// Set the active network
     pub fn set_active_network(&mut self, network: String) -> Result<(), Error> {
         // Validate network exists in contract registry
-        if let Err(_) = self.contract_registry.set_active_network(&network) {
+        if self.contract_registry.set_active_network(&network).is_err() {
             // Network not found in contract registry, but we can still set it
         }
 
@@ -495,7 +722,7 @@ impl ConfigurationManager {
         self.env_config.validate()?;
         self.protocol_registry.validate_all()?;
         if let Some(ref network) = self.active_network {
-            if let Ok(_) = self.contract_registry.get_network(network) {
+            if self.contract_registry.get_network(network).is_ok() {
                 self.contract_registry.validate_active_network()?;
             }
         }
@@ -510,7 +737,7 @@ impl ConfigurationManager {
 
         // Restore active network
         if let Some(ref network) = self.active_network {
-            if let Err(_) = self.contract_registry.set_active_network(network) {
+            if self.contract_registry.set_active_network(network).is_err() {
                 // Network not found, that's okay
             }
             self.protocol_registry.set_active_network(network);
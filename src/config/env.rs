@@ -42,6 +42,10 @@ pub struct NetworkEnvConfig {
     /// EVM chain ID (optional)
     #[cfg(feature = "evm")]
     pub evm_chain_id: Option<u64>,
+    /// Alternative EVM RPC endpoints for fallback
+    #[cfg(feature = "evm")]
+    #[serde(default)]
+    pub evm_rpc_fallback_urls: Vec<String>,
 }
 
 /// MCP server configuration from environment/files
@@ -273,6 +277,16 @@ impl EnvironmentConfig {
                     self.network.evm_chain_id = Some(evm_chain_id);
                 }
             }
+
+            if let Ok(fallback_urls) =
+                env::var(format!("{}_EVM_RPC_FALLBACK_URLS", ENV_NETWORK_PREFIX))
+            {
+                self.network.evm_rpc_fallback_urls = fallback_urls
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+            }
         }
 
         Ok(())
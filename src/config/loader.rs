@@ -0,0 +1,364 @@
+//! Layered network configuration
+//!
+//! A [`NetworkConfigLoader`] builds a [`MantraNetworkConfig`] from four layers,
+//! each overriding the settings the one before it sets:
+//!
+//! 1. the built-in profile (`dukong` unless another is chosen),
+//! 2. the config file, `~/.mantra-sdk/config.toml` or the file named by
+//!    `MANTRA_SDK_CONFIG`,
+//! 3. `MANTRA_NETWORK_*` and `MANTRA_CONTRACT_*` environment variables,
+//! 4. overrides set on the loader itself.
+//!
+//! The result is validated as a whole, so a misconfigured network reports all
+//! of its problems at once rather than the first one found.
+//!
+//! ```toml
+//! # ~/.mantra-sdk/config.toml
+//! profile = "mainnet"
+//!
+//! [network]
+//! rpc_url = "https://rpc.example.com:443"
+//! rpc_fallback_urls = ["https://rpc.mantrachain.io:443"]
+//!
+//! [network.contracts]
+//! pool_manager = "mantra1..."
+//!
+//! # Profiles of its own, or replacing built-in ones
+//! [profiles.devnet]
+//! chain_id = "mantra-devnet-1"
+//! rpc_url = "http://devnet:26657"
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use super::{MantraNetworkConfig, NetworkProfile};
+use crate::error::Error;
+
+/// Environment variable naming the config file, replacing the default one
+pub const CONFIG_FILE_ENV: &str = "MANTRA_SDK_CONFIG";
+
+/// Environment variable choosing the profile the configuration starts from
+pub const PROFILE_ENV: &str = "MANTRA_PROFILE";
+
+/// Profile used when none is chosen
+pub const DEFAULT_PROFILE: &str = "dukong";
+
+/// Contents of the config file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SdkConfigFile {
+    /// Profile the configuration starts from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Settings replacing the profile's
+    #[serde(default)]
+    pub network: NetworkProfile,
+    /// Profiles added to the built-in ones, replacing those of the same name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, NetworkProfile>,
+}
+
+impl SdkConfigFile {
+    /// Default config file, `~/.mantra-sdk/config.toml`
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::home_dir().map(|home| home.join(".mantra-sdk").join("config.toml"))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        toml::from_str(content).map_err(|e| Error::Config(format!("Invalid config file: {}", e)))
+    }
+
+    pub fn load(path: &PathBuf) -> Result<Self, Error> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::parse(&content).map_err(|e| Error::Config(format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// Which config file a loader reads
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ConfigFileSource {
+    /// The file named by `MANTRA_SDK_CONFIG`, else `~/.mantra-sdk/config.toml`
+    /// if it exists
+    #[default]
+    Default,
+    /// This file, which must exist
+    Path(PathBuf),
+    /// No file
+    None,
+}
+
+/// Builds a network configuration from defaults, a config file, the
+/// environment and explicit overrides
+#[derive(Debug, Clone)]
+pub struct NetworkConfigLoader {
+    profile: Option<String>,
+    file: ConfigFileSource,
+    read_env: bool,
+    overrides: NetworkProfile,
+}
+
+impl Default for NetworkConfigLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetworkConfigLoader {
+    /// Loader reading the default config file and the environment
+    pub fn new() -> Self {
+        Self {
+            profile: None,
+            file: ConfigFileSource::Default,
+            read_env: true,
+            overrides: NetworkProfile::default(),
+        }
+    }
+
+    /// Start from the profile called `name`, whatever the file or environment choose
+    pub fn with_profile(mut self, name: impl Into<String>) -> Self {
+        self.profile = Some(name.into());
+        self
+    }
+
+    /// Read `path` instead of the default config file
+    pub fn with_config_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.file = ConfigFileSource::Path(path.into());
+        self
+    }
+
+    /// Read no config file
+    pub fn without_config_file(mut self) -> Self {
+        self.file = ConfigFileSource::None;
+        self
+    }
+
+    /// Ignore `MANTRA_*` environment variables
+    pub fn without_env(mut self) -> Self {
+        self.read_env = false;
+        self
+    }
+
+    /// Settings replacing those of every other layer
+    pub fn with_overrides(mut self, overrides: NetworkProfile) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    pub fn with_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.overrides.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: impl Into<String>) -> Self {
+        self.overrides.chain_id = Some(chain_id.into());
+        self
+    }
+
+    pub fn with_pool_manager(mut self, address: impl Into<String>) -> Self {
+        self.overrides.contracts.pool_manager = Some(address.into());
+        self
+    }
+
+    /// The config file to read, if any
+    pub fn config_file(&self) -> Result<Option<SdkConfigFile>, Error> {
+        match &self.file {
+            ConfigFileSource::None => Ok(None),
+            ConfigFileSource::Path(path) => SdkConfigFile::load(path).map(Some),
+            ConfigFileSource::Default => {
+                let named = self
+                    .read_env
+                    .then(|| env::var(CONFIG_FILE_ENV).ok())
+                    .flatten()
+                    .filter(|path| !path.is_empty());
+                match named {
+                    Some(path) => SdkConfigFile::load(&PathBuf::from(path)).map(Some),
+                    None => match SdkConfigFile::default_path().filter(|path| path.exists()) {
+                        Some(path) => SdkConfigFile::load(&path).map(Some),
+                        None => Ok(None),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Build and validate the network configuration
+    ///
+    /// Fails on an unreadable config file, an unknown profile, or with every
+    /// problem of the resulting configuration, including environment variables
+    /// that do not parse.
+    pub fn load(&self) -> Result<MantraNetworkConfig, Error> {
+        let file = self.config_file()?.unwrap_or_default();
+        let (env_layer, mut problems) = if self.read_env {
+            env_layer()
+        } else {
+            (NetworkProfile::default(), Vec::new())
+        };
+
+        let name = self
+            .profile
+            .clone()
+            .or_else(|| {
+                self.read_env
+                    .then(|| env::var(PROFILE_ENV).ok())
+                    .flatten()
+                    .filter(|name| !name.is_empty())
+            })
+            .or_else(|| file.profile.clone())
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        let mut profiles = NetworkProfile::builtin();
+        profiles.extend(file.profiles.clone());
+        let profile = profiles.get(&name).ok_or_else(|| {
+            Error::Config(format!(
+                "Unknown network profile '{}' (available profiles: {})",
+                name,
+                profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+            ))
+        })?;
+
+        let mut config = profile.base_config(&name)?;
+        for layer in [profile, &file.network, &env_layer, &self.overrides] {
+            layer.apply(&mut config);
+        }
+
+        problems.extend(config.problems());
+        if problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(Error::Config(format!(
+                "Invalid network configuration for profile '{}' ({} problem{}): {}",
+                name,
+                problems.len(),
+                if problems.len() == 1 { "" } else { "s" },
+                problems.join("; ")
+            )))
+        }
+    }
+}
+
+/// Settings of the `MANTRA_NETWORK_*` and `MANTRA_CONTRACT_*` environment
+/// variables, and the variables that do not parse
+fn env_layer() -> (NetworkProfile, Vec<String>) {
+    let mut layer = NetworkProfile::default();
+    let mut problems = Vec::new();
+
+    let text = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+    let list = |name: &str| {
+        text(name)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|url| url.trim().to_string())
+                    .filter(|url| !url.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    fn number<T: FromStr>(name: &str, problems: &mut Vec<String>) -> Option<T>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let value = env::var(name).ok().filter(|value| !value.is_empty())?;
+        match value.parse() {
+            Ok(number) => Some(number),
+            Err(e) => {
+                problems.push(format!("{}: '{}' does not parse: {}", name, value, e));
+                None
+            }
+        }
+    }
+
+    layer.chain_id = text("MANTRA_NETWORK_CHAIN_ID");
+    layer.rpc_url = text("MANTRA_NETWORK_RPC_URL");
+    layer.rpc_fallback_urls = list("MANTRA_NETWORK_RPC_FALLBACK_URLS");
+    layer.grpc_url = text("MANTRA_NETWORK_GRPC_URL");
+    layer.native_denom = text("MANTRA_NETWORK_NATIVE_DENOM");
+    layer.gas_price = number("MANTRA_NETWORK_GAS_PRICE", &mut problems);
+    layer.gas_adjustment = number("MANTRA_NETWORK_GAS_ADJUSTMENT", &mut problems);
+    layer.evm_rpc_url = text("MANTRA_NETWORK_EVM_RPC_URL");
+    layer.evm_rpc_fallback_urls = list("MANTRA_NETWORK_EVM_RPC_FALLBACK_URLS");
+    layer.evm_chain_id = number("MANTRA_NETWORK_EVM_CHAIN_ID", &mut problems);
+
+    let contracts = &mut layer.contracts;
+    contracts.pool_manager = text("MANTRA_CONTRACT_POOL_MANAGER");
+    contracts.farm_manager = text("MANTRA_CONTRACT_FARM_MANAGER");
+    contracts.fee_collector = text("MANTRA_CONTRACT_FEE_COLLECTOR");
+    contracts.epoch_manager = text("MANTRA_CONTRACT_EPOCH_MANAGER");
+    contracts.skip_entry_point = text("MANTRA_CONTRACT_SKIP_ENTRY_POINT");
+    contracts.skip_ibc_hooks_adapter = text("MANTRA_CONTRACT_SKIP_IBC_HOOKS_ADAPTER");
+    contracts.skip_mantra_dex_adapter = text("MANTRA_CONTRACT_SKIP_MANTRA_DEX_ADAPTER");
+
+    (layer, problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layers_override_in_order_and_problems_are_reported_together() {
+        let dir = std::env::temp_dir().join(format!("mantra-loader-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(
+            &path,
+            r#"
+profile = "devnet"
+
+[network]
+gas_price = 0.02
+
+[profiles.devnet]
+chain_id = "mantra-devnet-1"
+rpc_url = "http://devnet:26657"
+"#,
+        )
+        .unwrap();
+
+        // The file picks its own profile and overrides it; the loader overrides both
+        let config = NetworkConfigLoader::new()
+            .with_config_file(&path)
+            .without_env()
+            .with_rpc_url("https://rpc.devnet.example:443")
+            .load()
+            .unwrap();
+        assert_eq!(config.chain_id, "mantra-devnet-1");
+        assert_eq!(config.rpc_url, "https://rpc.devnet.example:443");
+        assert_eq!(config.gas_price, 0.02);
+
+        // Every problem is reported at once
+        let err = NetworkConfigLoader::new()
+            .with_config_file(&path)
+            .without_env()
+            .with_chain_id("mantra devnet")
+            .with_rpc_url("ftp://devnet")
+            .with_pool_manager("not-an-address")
+            .load()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("3 problems"), "{}", err);
+        assert!(err.contains("chain_id"));
+        assert!(err.contains("rpc_url"));
+        assert!(err.contains("contracts.pool_manager"));
+
+        assert!(NetworkConfigLoader::new()
+            .without_config_file()
+            .without_env()
+            .with_profile("nowhere")
+            .load()
+            .is_err());
+        assert!(SdkConfigFile::parse("unknown_key = 1").is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,266 @@
+//! Named network profiles
+//!
+//! A profile bundles everything needed to talk to one network: the Cosmos and
+//! EVM RPC endpoints, contract addresses and gas settings. Profiles either
+//! start from a network described in `config/network.toml` (or the
+//! environment) and override some of its settings, or describe the network
+//! completely, which suits local chains.
+//!
+//! The built-in profiles are `mainnet`, `dukong` and `local`; configured
+//! profiles are added to them and replace built-in ones of the same name.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::{ContractAddresses, EnvironmentConfig, MantraNetworkConfig, NetworkConstants};
+use crate::error::Error;
+
+/// Contract addresses a profile sets, each replacing the base network's
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileContracts {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_manager: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub farm_manager: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_collector: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub epoch_manager: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_entry_point: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_ibc_hooks_adapter: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_mantra_dex_adapter: Option<String>,
+}
+
+impl ProfileContracts {
+    fn apply(&self, contracts: &mut ContractAddresses) {
+        if let Some(pool_manager) = &self.pool_manager {
+            contracts.pool_manager = pool_manager.clone();
+        }
+        for (address, field) in [
+            (&self.farm_manager, &mut contracts.farm_manager),
+            (&self.fee_collector, &mut contracts.fee_collector),
+            (&self.epoch_manager, &mut contracts.epoch_manager),
+            (&self.skip_entry_point, &mut contracts.skip_entry_point),
+            (
+                &self.skip_ibc_hooks_adapter,
+                &mut contracts.skip_ibc_hooks_adapter,
+            ),
+            (
+                &self.skip_mantra_dex_adapter,
+                &mut contracts.skip_mantra_dex_adapter,
+            ),
+        ] {
+            if address.is_some() {
+                field.clone_from(address);
+            }
+        }
+    }
+}
+
+/// Network settings selected by name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkProfile {
+    /// Network in `config/network.toml` or the environment the profile starts
+    /// from; without one the profile must give the chain ID, RPC endpoint and
+    /// pool manager itself
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_url: Option<String>,
+    /// RPC endpoints failed over to, replacing the network's when set
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rpc_fallback_urls: Vec<String>,
+    /// gRPC endpoint queries prefer over RPC
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grpc_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_rpc_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evm_rpc_fallback_urls: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_chain_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_denom: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_adjustment: Option<f64>,
+    #[serde(default)]
+    pub contracts: ProfileContracts,
+}
+
+impl NetworkProfile {
+    /// The `mainnet`, `dukong` and `local` profiles
+    ///
+    /// Mainnet contract addresses are read from `config/contracts.toml` under
+    /// `mainnet`, or must be configured on the profile.
+    pub fn builtin() -> BTreeMap<String, NetworkProfile> {
+        let mut profiles = BTreeMap::new();
+        profiles.insert(
+            "mainnet".to_string(),
+            NetworkProfile {
+                chain_id: Some("mantra-1".to_string()),
+                rpc_url: Some("https://rpc.mantrachain.io:443".to_string()),
+                evm_rpc_url: Some("https://evm.mantrachain.io".to_string()),
+                evm_chain_id: Some(5888),
+                ..Default::default()
+            },
+        );
+        profiles.insert(
+            "dukong".to_string(),
+            NetworkProfile {
+                network: Some("mantra-dukong".to_string()),
+                evm_rpc_url: Some("https://evm.dukong.mantrachain.io".to_string()),
+                evm_chain_id: Some(5887),
+                ..Default::default()
+            },
+        );
+        profiles.insert(
+            "local".to_string(),
+            NetworkProfile {
+                chain_id: Some("mantra-local-1".to_string()),
+                rpc_url: Some("http://localhost:26657".to_string()),
+                evm_rpc_url: Some("http://localhost:8545".to_string()),
+                ..Default::default()
+            },
+        );
+        profiles
+    }
+
+    /// Network configuration of the profile called `name`
+    ///
+    /// Fails when the base network cannot be loaded or the result lacks a
+    /// chain ID, RPC endpoint or pool manager.
+    pub fn resolve(&self, name: &str) -> Result<MantraNetworkConfig, Error> {
+        let config = self.resolve_for_queries(name)?;
+        if config.contracts.pool_manager.is_empty() {
+            return Err(Error::Config(format!(
+                "Network profile '{}' has no contracts.pool_manager",
+                name
+            )));
+        }
+        Ok(config)
+    }
+
+    /// Network configuration of the profile called `name`, which may lack a
+    /// pool manager
+    ///
+    /// Suits read-only queries such as balances that only need the chain ID
+    /// and RPC endpoint.
+    pub fn resolve_for_queries(&self, name: &str) -> Result<MantraNetworkConfig, Error> {
+        let mut config = self.base_config(name)?;
+        self.apply(&mut config);
+
+        for (value, field) in [(&config.chain_id, "chain_id"), (&config.rpc_url, "rpc_url")] {
+            if value.is_empty() {
+                return Err(Error::Config(format!(
+                    "Network profile '{}' has no {}",
+                    name, field
+                )));
+            }
+        }
+        Ok(config)
+    }
+
+    pub(super) fn base_config(&self, name: &str) -> Result<MantraNetworkConfig, Error> {
+        let Some(network) = &self.network else {
+            // Contract addresses may still be kept in config/contracts.toml
+            return MantraNetworkConfig::from_constants(&NetworkConstants {
+                network_name: name.to_string(),
+                chain_id: String::new(),
+                default_rpc: String::new(),
+                default_gas_price: 0.01,
+                default_gas_adjustment: 1.5,
+                native_denom: "uom".to_string(),
+            });
+        };
+
+        if let Ok(env_config) = EnvironmentConfig::load() {
+            if &env_config.get_network_name() == network {
+                return MantraNetworkConfig::from_env_config(&env_config);
+            }
+        }
+        let constants = NetworkConstants::load(network).map_err(|e| {
+            Error::Config(format!(
+                "Network profile '{}': failed to load network '{}': {}",
+                name, network, e
+            ))
+        })?;
+        MantraNetworkConfig::from_constants(&constants)
+    }
+
+    /// Replace the settings of `config` this profile sets
+    pub fn apply(&self, config: &mut MantraNetworkConfig) {
+        if let Some(chain_id) = &self.chain_id {
+            config.chain_id = chain_id.clone();
+        }
+        if let Some(rpc_url) = &self.rpc_url {
+            config.rpc_url = rpc_url.clone();
+        }
+        if !self.rpc_fallback_urls.is_empty() {
+            config.rpc_fallback_urls.clone_from(&self.rpc_fallback_urls);
+        }
+        if self.grpc_url.is_some() {
+            config.grpc_url.clone_from(&self.grpc_url);
+        }
+        if let Some(native_denom) = &self.native_denom {
+            config.native_denom = native_denom.clone();
+        }
+        if let Some(gas_price) = self.gas_price {
+            config.gas_price = gas_price;
+        }
+        if let Some(gas_adjustment) = self.gas_adjustment {
+            config.gas_adjustment = gas_adjustment;
+        }
+        #[cfg(feature = "evm")]
+        {
+            if self.evm_rpc_url.is_some() {
+                config.evm_rpc_url.clone_from(&self.evm_rpc_url);
+            }
+            if self.evm_chain_id.is_some() {
+                config.evm_chain_id = self.evm_chain_id;
+            }
+            if !self.evm_rpc_fallback_urls.is_empty() {
+                config
+                    .evm_rpc_fallback_urls
+                    .clone_from(&self.evm_rpc_fallback_urls);
+            }
+        }
+        self.contracts.apply(&mut config.contracts);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_resolution() {
+        let profiles = NetworkProfile::builtin();
+        assert_eq!(
+            profiles.keys().collect::<Vec<_>>(),
+            ["dukong", "local", "mainnet"]
+        );
+
+        // A standalone profile needs its own pool manager
+        let mut local = profiles["local"].clone();
+        let err = local.resolve("local").unwrap_err();
+        assert!(err.to_string().contains("contracts.pool_manager"));
+
+        local.contracts.pool_manager = Some("mantra1pool".to_string());
+        local.gas_price = Some(0.5);
+        let config = local.resolve("local").unwrap();
+        assert_eq!(config.network_name, "local");
+        assert_eq!(config.chain_id, "mantra-local-1");
+        assert_eq!(config.rpc_url, "http://localhost:26657");
+        assert_eq!(config.contracts.pool_manager, "mantra1pool");
+        assert_eq!(config.gas_price, 0.5);
+        assert_eq!(config.gas_adjustment, 1.5);
+        assert_eq!(config.native_denom, "uom");
+    }
+}
@@ -329,7 +329,7 @@ impl ProtocolRegistry {
         let mut registry = Self::new();
 
         // Try to load from configuration files
-        if let Err(_) = registry.load_from_config() {
+        if registry.load_from_config().is_err() {
             // If config loading fails, use defaults
             registry.load_defaults()?;
         }
@@ -629,7 +629,7 @@ impl ProtocolRegistry {
             .collect();
 
         // Sort by priority (descending)
-        enabled.sort_by(|a, b| b.1.priority.cmp(&a.1.priority));
+        enabled.sort_by_key(|e| std::cmp::Reverse(e.1.priority));
         enabled
     }
 
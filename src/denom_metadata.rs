@@ -0,0 +1,355 @@
+//! Symbol, name and decimals of Cosmos denoms
+//!
+//! Balances and swap results come back in base units of denoms such as
+//! `factory/mantra1.../uUSDC` or `ibc/27394F...`, which say little about the
+//! token or how many decimals it has. A [`DenomMetadataService`] caches what is
+//! known about each denom, resolved from, in order of preference:
+//!
+//! 1. the bank module's denom metadata, whose display unit gives the decimals;
+//! 2. an asset registry such as Skip's asset list;
+//! 3. the IBC denom trace of an `ibc/` denom, naming its base denom;
+//! 4. the decimals the DEX pools record for the denom;
+//! 5. the denom itself, with the 6 decimals Cosmos micro-denominations use.
+//!
+//! [`MantraDexClient::denom_metadata`] runs the queries and fills the cache;
+//! the cache is shared between clones, so a service handed to several clients
+//! resolves each denom once.
+//!
+//! [`MantraDexClient::denom_metadata`]: crate::protocols::dex::MantraDexClient::denom_metadata
+
+use cosmos_sdk_proto::cosmos::bank::v1beta1::Metadata;
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use crate::amount::{format_units_u128, symbol_for_denom, DenomRegistry, Denomination};
+use crate::protocols::skip::types::ChainAsset;
+
+/// Decimals of a denom nothing else is known about
+pub const DEFAULT_DECIMALS: u8 = 6;
+
+/// Where a denom's metadata came from, least to most authoritative
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DenomSource {
+    /// Derived from the denom alone
+    Fallback,
+    /// Decimals recorded by a DEX pool holding the denom
+    Pool,
+    /// IBC denom trace of an `ibc/` denom
+    IbcTrace,
+    /// Asset registry, such as Skip's asset list
+    AssetRegistry,
+    /// Bank module denom metadata
+    Bank,
+}
+
+/// What is known about one denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomMetadata {
+    pub denom: String,
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+    pub source: DenomSource,
+    /// IBC path and base denom of an `ibc/` denom, e.g. `transfer/channel-0/uusdc`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ibc_trace: Option<String>,
+}
+
+impl DenomMetadata {
+    /// Metadata derived from the denom alone, with `decimals` when a pool
+    /// records them
+    pub fn fallback(denom: &str, decimals: Option<u8>) -> Self {
+        let symbol = symbol_for_denom(denom);
+        Self {
+            denom: denom.to_string(),
+            name: symbol.clone(),
+            symbol,
+            decimals: decimals.unwrap_or(DEFAULT_DECIMALS),
+            source: if decimals.is_some() {
+                DenomSource::Pool
+            } else {
+                DenomSource::Fallback
+            },
+            ibc_trace: None,
+        }
+    }
+
+    /// Metadata of `denom` from the bank module, if it names a display unit
+    /// or a symbol
+    ///
+    /// The decimals are the exponent of the display unit, or the largest
+    /// exponent when the display unit is not listed.
+    pub fn from_bank(denom: &str, metadata: &Metadata) -> Option<Self> {
+        let exponent = metadata
+            .denom_units
+            .iter()
+            .find(|unit| unit.denom == metadata.display)
+            .or_else(|| metadata.denom_units.iter().max_by_key(|unit| unit.exponent))
+            .map(|unit| unit.exponent)
+            .filter(|exponent| *exponent > 0);
+        if exponent.is_none() && metadata.symbol.is_empty() {
+            return None;
+        }
+
+        let symbol = if !metadata.symbol.is_empty() {
+            metadata.symbol.clone()
+        } else if !metadata.display.is_empty() && metadata.display != denom {
+            metadata.display.to_uppercase()
+        } else {
+            symbol_for_denom(denom)
+        };
+        Some(Self {
+            denom: denom.to_string(),
+            name: if metadata.name.is_empty() {
+                symbol.clone()
+            } else {
+                metadata.name.clone()
+            },
+            symbol,
+            decimals: exponent
+                .and_then(|exponent| u8::try_from(exponent).ok())
+                .unwrap_or(DEFAULT_DECIMALS),
+            source: DenomSource::Bank,
+            ibc_trace: None,
+        })
+    }
+
+    /// Metadata of an asset registry entry
+    pub fn from_registry(asset: &ChainAsset) -> Self {
+        Self {
+            denom: asset.denom.clone(),
+            symbol: asset.symbol.clone(),
+            name: asset.symbol.clone(),
+            decimals: asset.decimals,
+            source: DenomSource::AssetRegistry,
+            ibc_trace: asset.trace.clone(),
+        }
+    }
+
+    /// Metadata of `denom` whose IBC trace is `path` over `base_denom`
+    ///
+    /// The symbol is that of the base denom; the decimals are the pools' when
+    /// they record them.
+    pub fn from_ibc_trace(denom: &str, path: &str, base_denom: &str, decimals: Option<u8>) -> Self {
+        let symbol = symbol_for_denom(base_denom);
+        Self {
+            denom: denom.to_string(),
+            name: symbol.clone(),
+            symbol,
+            decimals: decimals.unwrap_or(DEFAULT_DECIMALS),
+            source: DenomSource::IbcTrace,
+            ibc_trace: Some(if path.is_empty() {
+                base_denom.to_string()
+            } else {
+                format!("{}/{}", path, base_denom)
+            }),
+        }
+    }
+
+    /// `amount` base units in whole tokens, e.g. `1.5`
+    pub fn format_amount(&self, amount: Uint128) -> String {
+        format_units_u128(amount, self.decimals)
+    }
+
+    /// `coin` in whole tokens with the symbol, e.g. `1.5 OM`
+    pub fn format_coin(&self, coin: &Coin) -> String {
+        format!("{} {}", self.format_amount(coin.amount), self.symbol)
+    }
+
+    pub fn to_denomination(&self) -> Denomination {
+        Denomination::new(&self.denom, &self.symbol, self.decimals)
+    }
+}
+
+/// Cache of denom metadata, shared between clones
+#[derive(Debug, Clone, Default)]
+pub struct DenomMetadataService {
+    inner: Arc<RwLock<Inner>>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: HashMap<String, DenomMetadata>,
+    /// Chains whose asset registry has been loaded
+    registry_chains: HashSet<String>,
+}
+
+impl DenomMetadataService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cached metadata of `denom`
+    pub fn get(&self, denom: &str) -> Option<DenomMetadata> {
+        self.inner.read().ok()?.entries.get(denom).cloned()
+    }
+
+    /// Cache `metadata`, unless the denom's cached metadata comes from a more
+    /// authoritative source
+    pub fn insert(&self, metadata: DenomMetadata) {
+        if let Ok(mut inner) = self.inner.write() {
+            match inner.entries.get(&metadata.denom) {
+                Some(cached) if cached.source > metadata.source => {}
+                _ => {
+                    inner.entries.insert(metadata.denom.clone(), metadata);
+                }
+            }
+        }
+    }
+
+    /// Cache the asset registry of `chain_id`
+    pub fn add_registry_assets(&self, chain_id: &str, assets: &[ChainAsset]) {
+        for asset in assets {
+            self.insert(DenomMetadata::from_registry(asset));
+        }
+        if let Ok(mut inner) = self.inner.write() {
+            inner.registry_chains.insert(chain_id.to_string());
+        }
+    }
+
+    /// Whether the asset registry of `chain_id` has been loaded
+    pub fn has_registry(&self, chain_id: &str) -> bool {
+        self.inner
+            .read()
+            .map(|inner| inner.registry_chains.contains(chain_id))
+            .unwrap_or(false)
+    }
+
+    /// Cached metadata of `denom`, or what the denom alone says
+    pub fn get_or_fallback(&self, denom: &str) -> DenomMetadata {
+        self.get(denom)
+            .unwrap_or_else(|| DenomMetadata::fallback(denom, None))
+    }
+
+    /// `coin` in whole tokens with its symbol, from the cache
+    pub fn format_coin(&self, coin: &Coin) -> String {
+        self.get_or_fallback(&coin.denom).format_coin(coin)
+    }
+
+    /// Replace the denominations of `registry` with cached metadata, so
+    /// symbols from the bank module or an asset registry are used
+    pub fn apply_to(&self, registry: &mut DenomRegistry) {
+        if let Ok(inner) = self.inner.read() {
+            for metadata in inner.entries.values() {
+                if metadata.source > DenomSource::Pool {
+                    registry.insert(metadata.to_denomination());
+                }
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .map(|inner| inner.entries.len())
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget every cached denom and registry
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.inner.write() {
+            inner.entries.clear();
+            inner.registry_chains.clear();
+        }
+    }
+}
+
+/// `ibc.applications.transfer.v1.QueryDenomTraceRequest`
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct QueryDenomTraceRequest {
+    /// Hash of the trace, with or without the `ibc/` prefix
+    #[prost(string, tag = "1")]
+    pub hash: String,
+}
+
+/// `ibc.applications.transfer.v1.QueryDenomTraceResponse`
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct QueryDenomTraceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub denom_trace: Option<DenomTrace>,
+}
+
+/// `ibc.applications.transfer.v1.DenomTrace`
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct DenomTrace {
+    #[prost(string, tag = "1")]
+    pub path: String,
+    #[prost(string, tag = "2")]
+    pub base_denom: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::bank::v1beta1::DenomUnit;
+
+    fn unit(denom: &str, exponent: u32) -> DenomUnit {
+        DenomUnit {
+            denom: denom.to_string(),
+            exponent,
+            aliases: vec![],
+        }
+    }
+
+    #[test]
+    fn test_metadata_sources_rank_and_format() {
+        let usdc = "factory/mantra1abc/uUSDC";
+        let bank = Metadata {
+            denom_units: vec![unit(usdc, 0), unit("USDC", 6)],
+            base: usdc.to_string(),
+            display: "USDC".to_string(),
+            name: "USD Coin".to_string(),
+            ..Default::default()
+        };
+        let metadata = DenomMetadata::from_bank(usdc, &bank).unwrap();
+        assert_eq!((metadata.symbol.as_str(), metadata.decimals), ("USDC", 6));
+        assert_eq!(metadata.name, "USD Coin");
+        assert_eq!(
+            metadata.format_coin(&Coin::new(1_500_000u128, usdc)),
+            "1.5 USDC"
+        );
+        // Metadata without a display unit or symbol says nothing
+        assert!(DenomMetadata::from_bank(usdc, &Metadata::default()).is_none());
+
+        let ibc =
+            DenomMetadata::from_ibc_trace("ibc/ABC", "transfer/channel-0", "aevmos", Some(18));
+        assert_eq!(ibc.symbol, "aevmos");
+        assert_eq!(ibc.ibc_trace.as_deref(), Some("transfer/channel-0/aevmos"));
+
+        let service = DenomMetadataService::new();
+        service.insert(metadata);
+        // A registry entry does not override the bank's metadata
+        service.add_registry_assets(
+            "mantra-1",
+            &[ChainAsset {
+                denom: usdc.to_string(),
+                symbol: "usdc.axl".to_string(),
+                decimals: 8,
+                is_native: false,
+                contract_address: None,
+                trace: None,
+            }],
+        );
+        assert_eq!(service.get(usdc).unwrap().source, DenomSource::Bank);
+        assert!(service.has_registry("mantra-1"));
+
+        // Clones share the cache
+        service.clone().insert(ibc);
+        assert_eq!(service.len(), 2);
+        assert_eq!(
+            service.format_coin(&Coin::new(1_000_000u128, "uom")),
+            "1 OM"
+        );
+
+        let mut registry = DenomRegistry::native("uom");
+        service.apply_to(&mut registry);
+        assert_eq!(registry.resolve("usdc").unwrap().denom, usdc);
+    }
+}
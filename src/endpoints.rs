@@ -0,0 +1,330 @@
+//! RPC endpoint failover behind per-endpoint circuit breakers
+//!
+//! A network may list several RPC endpoints. An [`EndpointPool`] spreads calls
+//! over them round-robin and keeps a circuit breaker for each: after
+//! `failure_threshold` consecutive failures the endpoint's circuit opens and
+//! calls skip it for `cooldown_secs`. Once the cooldown is over the circuit is
+//! half-open and lets calls through again; the first success closes it, the
+//! first failure opens it for another cooldown. When every circuit is open the
+//! endpoint closest to the end of its cooldown is used anyway.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use web_time::Instant;
+
+/// When an endpoint's circuit opens and for how long
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+    /// Seconds an open circuit keeps calls away from its endpoint
+    #[serde(default = "default_cooldown_secs")]
+    pub cooldown_secs: u64,
+}
+
+fn default_failure_threshold() -> u32 {
+    5
+}
+
+fn default_cooldown_secs() -> u64 {
+    30
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_failure_threshold(),
+            cooldown_secs: default_cooldown_secs(),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Check the breaker's settings
+    pub fn validate(&self) -> Result<(), String> {
+        if self.failure_threshold == 0 {
+            return Err("Circuit breaker failure_threshold must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// State of an endpoint's circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls go through
+    Closed,
+    /// Calls skip the endpoint until the cooldown is over
+    Open,
+    /// Cooldown over; the next call decides whether the circuit closes
+    HalfOpen,
+}
+
+/// Health of one endpoint
+#[derive(Debug, Clone, Serialize)]
+pub struct EndpointHealth {
+    pub url: String,
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    pub successes: u64,
+    pub failures: u64,
+    /// Error of the last failed call
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Time left until an open circuit turns half-open
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_in_ms: Option<u64>,
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    url: String,
+    consecutive_failures: u32,
+    successes: u64,
+    failures: u64,
+    last_error: Option<String>,
+    opened_at: Option<Instant>,
+}
+
+/// RPC endpoints of one network and their circuit breakers
+#[derive(Debug)]
+pub struct EndpointPool {
+    config: CircuitBreakerConfig,
+    endpoints: Mutex<Vec<Endpoint>>,
+    next: AtomicUsize,
+}
+
+impl EndpointPool {
+    /// Pool of `urls`, the primary endpoint first; duplicates are dropped
+    pub fn new(urls: impl IntoIterator<Item = String>, config: CircuitBreakerConfig) -> Self {
+        let mut endpoints: Vec<Endpoint> = Vec::new();
+        for url in urls {
+            if url.is_empty() || endpoints.iter().any(|endpoint| endpoint.url == url) {
+                continue;
+            }
+            endpoints.push(Endpoint {
+                url,
+                consecutive_failures: 0,
+                successes: 0,
+                failures: 0,
+                last_error: None,
+                opened_at: None,
+            });
+        }
+        Self {
+            config,
+            endpoints: Mutex::new(endpoints),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// Endpoint URLs, the primary first
+    pub fn urls(&self) -> Vec<String> {
+        self.lock()
+            .iter()
+            .map(|endpoint| endpoint.url.clone())
+            .collect()
+    }
+
+    /// First endpoint, in configured order, whose circuit is not open
+    ///
+    /// Suits connections that stay on one endpoint until it fails.
+    pub fn preferred(&self) -> Option<String> {
+        let endpoints = self.lock();
+        let now = Instant::now();
+        endpoints
+            .iter()
+            .find(|endpoint| self.state(endpoint, now) != CircuitState::Open)
+            .or_else(|| Self::soonest_to_reopen(&endpoints))
+            .map(|endpoint| endpoint.url.clone())
+    }
+
+    /// Next endpoint whose circuit is not open, round-robin
+    pub fn next(&self) -> Option<String> {
+        let endpoints = self.lock();
+        let now = Instant::now();
+        let available: Vec<&Endpoint> = endpoints
+            .iter()
+            .filter(|endpoint| self.state(endpoint, now) != CircuitState::Open)
+            .collect();
+        if available.is_empty() {
+            return Self::soonest_to_reopen(&endpoints).map(|endpoint| endpoint.url.clone());
+        }
+        let turn = self.next.fetch_add(1, Ordering::Relaxed);
+        Some(available[turn % available.len()].url.clone())
+    }
+
+    /// Record a call to `url` that succeeded, closing its circuit
+    pub fn record_success(&self, url: &str) {
+        let mut endpoints = self.lock();
+        if let Some(endpoint) = endpoints.iter_mut().find(|endpoint| endpoint.url == url) {
+            if endpoint.opened_at.take().is_some() {
+                tracing::info!("RPC endpoint {} recovered; circuit closed", url);
+            }
+            endpoint.consecutive_failures = 0;
+            endpoint.successes += 1;
+        }
+    }
+
+    /// Record a call to `url` that failed, returning whether its circuit
+    /// opened because of it
+    pub fn record_failure(&self, url: &str, error: &str) -> bool {
+        let mut endpoints = self.lock();
+        let now = Instant::now();
+        let Some(index) = endpoints.iter().position(|endpoint| endpoint.url == url) else {
+            return false;
+        };
+        let state = self.state(&endpoints[index], now);
+        let endpoint = &mut endpoints[index];
+        endpoint.consecutive_failures += 1;
+        endpoint.failures += 1;
+        endpoint.last_error = Some(error.to_string());
+
+        let opens = match state {
+            CircuitState::HalfOpen => true,
+            CircuitState::Closed => endpoint.consecutive_failures >= self.config.failure_threshold,
+            CircuitState::Open => false,
+        };
+        if opens {
+            endpoint.opened_at = Some(now);
+            tracing::warn!(
+                "RPC endpoint {} failed {} times in a row; circuit open for {}s: {}",
+                url,
+                endpoint.consecutive_failures,
+                self.config.cooldown_secs,
+                error
+            );
+        }
+        opens
+    }
+
+    /// Health of every endpoint, the primary first
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let endpoints = self.lock();
+        let now = Instant::now();
+        endpoints
+            .iter()
+            .map(|endpoint| {
+                let state = self.state(endpoint, now);
+                EndpointHealth {
+                    url: endpoint.url.clone(),
+                    state,
+                    consecutive_failures: endpoint.consecutive_failures,
+                    successes: endpoint.successes,
+                    failures: endpoint.failures,
+                    last_error: endpoint.last_error.clone(),
+                    retry_in_ms: endpoint
+                        .opened_at
+                        .filter(|_| state == CircuitState::Open)
+                        .map(|opened_at| {
+                            (opened_at + self.cooldown())
+                                .saturating_duration_since(now)
+                                .as_millis() as u64
+                        }),
+                }
+            })
+            .collect()
+    }
+
+    /// Endpoints whose circuit is not open
+    pub fn available_count(&self) -> usize {
+        let now = Instant::now();
+        self.lock()
+            .iter()
+            .filter(|endpoint| self.state(endpoint, now) != CircuitState::Open)
+            .count()
+    }
+
+    fn cooldown(&self) -> Duration {
+        Duration::from_secs(self.config.cooldown_secs)
+    }
+
+    fn state(&self, endpoint: &Endpoint, now: Instant) -> CircuitState {
+        match endpoint.opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if now.duration_since(opened_at) < self.cooldown() => {
+                CircuitState::Open
+            }
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    fn soonest_to_reopen(endpoints: &[Endpoint]) -> Option<&Endpoint> {
+        endpoints.iter().min_by_key(|endpoint| endpoint.opened_at)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Endpoint>> {
+        self.endpoints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_opens_after_consecutive_failures_and_fails_over() {
+        let pool = EndpointPool::new(
+            [
+                "https://a".to_string(),
+                "https://b".to_string(),
+                "https://a".to_string(),
+            ],
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                cooldown_secs: 3600,
+            },
+        );
+        assert_eq!(pool.urls(), ["https://a", "https://b"]);
+
+        // Healthy endpoints take turns
+        let first = pool.next().unwrap();
+        let second = pool.next().unwrap();
+        assert_ne!(first, second);
+
+        // A success in between resets the count
+        assert!(!pool.record_failure("https://a", "connection refused"));
+        pool.record_success("https://a");
+        assert!(!pool.record_failure("https://a", "connection refused"));
+        assert!(pool.record_failure("https://a", "connection refused"));
+
+        assert_eq!(pool.preferred().as_deref(), Some("https://b"));
+        assert_eq!(pool.next().as_deref(), Some("https://b"));
+        assert_eq!(pool.next().as_deref(), Some("https://b"));
+        assert_eq!(pool.available_count(), 1);
+
+        let health = pool.health();
+        assert_eq!(health[0].state, CircuitState::Open);
+        assert!(health[0].retry_in_ms.is_some());
+        assert_eq!(health[1].state, CircuitState::Closed);
+
+        // With every circuit open the one opened first is still used
+        pool.record_failure("https://b", "timeout");
+        pool.record_failure("https://b", "timeout");
+        assert_eq!(pool.preferred().as_deref(), Some("https://a"));
+
+        // A half-open circuit closes on success and reopens on failure
+        let pool = EndpointPool::new(
+            ["https://c".to_string()],
+            CircuitBreakerConfig {
+                failure_threshold: 1,
+                cooldown_secs: 0,
+            },
+        );
+        assert!(pool.record_failure("https://c", "timeout"));
+        assert_eq!(pool.health()[0].state, CircuitState::HalfOpen);
+        assert!(pool.record_failure("https://c", "timeout"));
+        pool.record_success("https://c");
+        assert_eq!(pool.health()[0].state, CircuitState::Closed);
+    }
+}
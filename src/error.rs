@@ -1,3 +1,4 @@
+#[cfg(not(target_arch = "wasm32"))]
 use cosmrs::rpc::endpoint::broadcast::tx_sync::Response as SyncTxResponse;
 use thiserror::Error;
 
@@ -16,6 +17,7 @@ pub enum Error {
     Rpc(String),
 
     /// Transaction broadcast error with response - occurs when transaction submission fails
+    #[cfg(not(target_arch = "wasm32"))]
     #[error("Transaction broadcast error: {0:?}")]
     TxBroadcast(SyncTxResponse),
 
@@ -83,4 +85,238 @@ pub enum Error {
     /// EVM protocol error - occurs when EVM blockchain operations fail
     #[error("EVM error: {0}")]
     Evm(String),
+
+    /// Insufficient funds - occurs when an account cannot cover what a transaction
+    /// spends; amounts carry their denom (e.g. `5uom`) when the chain reports them
+    #[error("Insufficient funds{}", funds_detail(.needed, .available))]
+    InsufficientFunds {
+        needed: Option<String>,
+        available: Option<String>,
+    },
+
+    /// Slippage exceeded - occurs when a swap would return less than the
+    /// slippage or spread limit allows
+    #[error("Slippage exceeded: {reason}")]
+    SlippageExceeded { reason: String },
+
+    /// Account sequence mismatch - occurs when a transaction is signed with a
+    /// stale sequence number, usually because another one was sent meanwhile
+    #[error("Account sequence mismatch{}", sequence_detail(.expected, .got))]
+    AccountSequenceMismatch {
+        expected: Option<u64>,
+        got: Option<u64>,
+    },
+
+    /// EVM revert - occurs when an EVM call or transaction is reverted by the contract
+    #[error("EVM execution reverted: {reason}")]
+    EvmRevert { reason: String },
+
+    /// Transaction expired - occurs when the chain reaches a transaction's
+    /// timeout height before including it
+    #[error("Transaction expired{}", expiry_detail(.timeout_height))]
+    TxExpired { timeout_height: Option<u64> },
+
+    /// RPC timeout - occurs when an RPC endpoint does not answer in time
+    #[error("RPC endpoint {endpoint} timed out")]
+    RpcTimeout {
+        endpoint: String,
+        #[source]
+        source: Option<Box<Error>>,
+    },
+    /// Rate limited - occurs when a service such as the faucet refuses to
+    /// serve a request until `retry_after_secs` have passed
+    #[error("Rate limited: {reason}")]
+    RateLimited {
+        reason: String,
+        retry_after_secs: u64,
+    },
+}
+
+impl Error {
+    /// The typed form of an error whose message names a known chain failure
+    ///
+    /// Chains report insufficient funds, sequence mismatches, expiries,
+    /// slippage and reverts as log text, which the RPC, transaction and contract errors
+    /// carry as strings. Errors naming none of them are returned unchanged.
+    pub fn refine(self) -> Self {
+        let message = match &self {
+            Error::Rpc(message)
+            | Error::TxSimulation(message)
+            | Error::Contract(message)
+            | Error::Tx(message)
+            | Error::Wallet(message)
+            | Error::Other(message)
+            | Error::Skip(message)
+            | Error::Evm(message) => message.clone(),
+            #[cfg(not(target_arch = "wasm32"))]
+            Error::TxBroadcast(response) => response.log.clone(),
+            _ => return self,
+        };
+        let lower = message.to_lowercase();
+
+        if lower.contains("insufficient funds") || lower.contains("insufficient balance") {
+            let (available, needed) = match smaller_than(&message) {
+                Some((available, needed)) => (Some(available), Some(needed)),
+                None => (None, None),
+            };
+            Error::InsufficientFunds { needed, available }
+        } else if lower.contains("account sequence mismatch")
+            || lower.contains("incorrect account sequence")
+        {
+            Error::AccountSequenceMismatch {
+                expected: number_after(&lower, "expected"),
+                got: number_after(&lower, "got"),
+            }
+        } else if lower.contains("tx timeout height") {
+            Error::TxExpired {
+                timeout_height: number_after(&lower, "timeout height"),
+            }
+        } else if lower.contains("slippage") || lower.contains("max spread") {
+            Error::SlippageExceeded { reason: message }
+        } else if let Some(index) = lower.find("reverted") {
+            let reason = message[index + "reverted".len()..]
+                .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+                .to_string();
+            Error::EvmRevert {
+                reason: if reason.is_empty() {
+                    "no reason given".to_string()
+                } else {
+                    reason
+                },
+            }
+        } else {
+            self
+        }
+    }
+
+    /// `self` as a timeout of `endpoint` when it reports one
+    pub fn at_endpoint(self, endpoint: &str) -> Self {
+        let lower = self.to_string().to_lowercase();
+        let timed_out = matches!(self, Error::Timeout(_))
+            || lower.contains("timed out")
+            || lower.contains("timeout");
+        if timed_out && !matches!(self, Error::RpcTimeout { .. } | Error::TxExpired { .. }) {
+            Error::RpcTimeout {
+                endpoint: endpoint.to_string(),
+                source: Some(Box::new(self)),
+            }
+        } else {
+            self
+        }
+    }
+}
+
+fn funds_detail(needed: &Option<String>, available: &Option<String>) -> String {
+    match (needed, available) {
+        (Some(needed), Some(available)) => format!(": need {}, have {}", needed, available),
+        (Some(needed), None) => format!(": need {}", needed),
+        (None, Some(available)) => format!(": have {}", available),
+        (None, None) => String::new(),
+    }
+}
+
+fn expiry_detail(timeout_height: &Option<u64>) -> String {
+    match timeout_height {
+        Some(height) => format!(" at timeout height {}", height),
+        None => String::new(),
+    }
+}
+
+fn sequence_detail(expected: &Option<u64>, got: &Option<u64>) -> String {
+    match (expected, got) {
+        (Some(expected), Some(got)) => format!(": expected {}, got {}", expected, got),
+        _ => String::new(),
+    }
+}
+
+/// The amounts of the Cosmos SDK's "`<available>` is smaller than `<needed>`"
+fn smaller_than(message: &str) -> Option<(String, String)> {
+    let (before, after) = message.split_once(" is smaller than ")?;
+    let available = before.split_whitespace().last()?;
+    let needed = after
+        .split(|c: char| c.is_whitespace() || c == ':' || c == ',')
+        .next()?;
+    Some((available.to_string(), needed.to_string()))
+}
+
+/// The number following `word` in `message`, as in "expected 7"
+fn number_after(message: &str, word: &str) -> Option<u64> {
+    let (_, rest) = message.split_once(word)?;
+    let digits: String = rest
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_chain_failures_refine_into_typed_errors() {
+        let error = Error::Contract(
+            "Transaction check failed: spendable balance 5uom is smaller than 10uom: insufficient funds"
+                .to_string(),
+        )
+        .refine();
+        assert!(matches!(
+            &error,
+            Error::InsufficientFunds { needed: Some(needed), available: Some(available) }
+                if needed == "10uom" && available == "5uom"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Insufficient funds: need 10uom, have 5uom"
+        );
+
+        assert!(matches!(
+            Error::Rpc(
+                "account sequence mismatch, expected 7, got 6: incorrect account sequence"
+                    .to_string()
+            )
+            .refine(),
+            Error::AccountSequenceMismatch {
+                expected: Some(7),
+                got: Some(6)
+            }
+        ));
+        assert!(matches!(
+            Error::Contract("Transaction execution failed: max spread assertion".to_string())
+                .refine(),
+            Error::SlippageExceeded { .. }
+        ));
+        assert!(matches!(
+            Error::Evm("RPC error: execution reverted: sale closed".to_string()).refine(),
+            Error::EvmRevert { reason } if reason == "sale closed"
+        ));
+        let expired = Error::Contract(
+            "Transaction check failed: block height: 105, timeout height: 100: tx timeout height"
+                .to_string(),
+        )
+        .refine();
+        assert!(matches!(
+            expired,
+            Error::TxExpired {
+                timeout_height: Some(100)
+            }
+        ));
+        assert!(matches!(
+            expired.at_endpoint("https://rpc-1"),
+            Error::TxExpired { .. }
+        ));
+        assert!(matches!(
+            Error::Config("bad".to_string()).refine(),
+            Error::Config(_)
+        ));
+
+        // A timeout keeps the error it came from as its source
+        let error = Error::Rpc("request timed out".to_string()).at_endpoint("https://rpc-1");
+        assert_eq!(error.to_string(), "RPC endpoint https://rpc-1 timed out");
+        assert!(error
+            .source()
+            .is_some_and(|source| source.to_string().contains("request timed out")));
+    }
 }
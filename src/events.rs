@@ -0,0 +1,440 @@
+//! Chain Event Subscriptions
+//!
+//! An [`EventSubscriber`] holds a Tendermint WebSocket connection and
+//! subscribes to the transactions that touch the watched contracts, such as
+//! the pool manager and ClaimDrop campaigns. Each transaction's `wasm` events
+//! are turned into typed [`ChainEvent`]s and delivered over an async stream,
+//! so TUI screens and trading bots react to swaps and claims as they are
+//! committed instead of polling.
+//!
+//! The Tendermint query language has no `OR`, so a filter watching several
+//! contracts holds one subscription per contract and merges their streams.
+//! Turning events into [`ChainEvent`]s needs no connection and is always
+//! available; the subscriber itself needs the `events` feature.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::protocols::cosmos_analyzer::{CosmosProtocol, CosmosTxEvent};
+
+/// Contracts whose transactions are watched
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventFilter {
+    /// Contract addresses and the protocol each belongs to; all wasm events
+    /// when empty
+    contracts: Vec<(String, CosmosProtocol)>,
+}
+
+impl EventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Watch swaps and liquidity changes of the pool manager at `address`
+    pub fn pool_manager(self, address: impl Into<String>) -> Self {
+        self.contract(address, CosmosProtocol::Dex)
+    }
+
+    /// Watch claims of the ClaimDrop campaign at `address`
+    pub fn claimdrop(self, address: impl Into<String>) -> Self {
+        self.contract(address, CosmosProtocol::ClaimDrop)
+    }
+
+    /// Watch the contract at `address`, belonging to `protocol`
+    pub fn contract(mut self, address: impl Into<String>, protocol: CosmosProtocol) -> Self {
+        let address = address.into();
+        if !self
+            .contracts
+            .iter()
+            .any(|(watched, _)| *watched == address)
+        {
+            self.contracts.push((address, protocol));
+        }
+        self
+    }
+
+    pub fn contracts(&self) -> &[(String, CosmosProtocol)] {
+        &self.contracts
+    }
+
+    /// Protocol of the watched contract at `address`, `None` if not watched
+    pub fn protocol_of(&self, address: &str) -> Option<CosmosProtocol> {
+        if self.contracts.is_empty() {
+            return Some(CosmosProtocol::Other);
+        }
+        self.contracts
+            .iter()
+            .find(|(watched, _)| watched == address)
+            .map(|(_, protocol)| *protocol)
+    }
+
+    /// Tendermint queries subscribing to the watched transactions, one per
+    /// contract
+    pub fn queries(&self) -> Vec<String> {
+        if self.contracts.is_empty() {
+            return vec!["tm.event = 'Tx'".to_string()];
+        }
+        self.contracts
+            .iter()
+            .map(|(address, _)| {
+                format!("tm.event = 'Tx' AND wasm._contract_address = '{}'", address)
+            })
+            .collect()
+    }
+}
+
+/// What a watched contract did
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ContractEvent {
+    /// A pool manager swap
+    Swap {
+        sender: Option<String>,
+        pool_identifier: Option<String>,
+        offer_denom: Option<String>,
+        offer_amount: Option<String>,
+        ask_denom: Option<String>,
+        return_amount: Option<String>,
+    },
+    /// Liquidity provided to or withdrawn from a pool, as the event's action
+    /// tells
+    Liquidity {
+        sender: Option<String>,
+        pool_identifier: Option<String>,
+    },
+    /// A ClaimDrop claim
+    Claim {
+        receiver: Option<String>,
+        amount: Option<String>,
+    },
+    /// Any other action; its attributes are on the [`ChainEvent`]
+    Other,
+}
+
+/// A `wasm` event of a watched contract in a committed transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainEvent {
+    pub height: u64,
+    pub tx_hash: String,
+    pub contract: String,
+    pub protocol: CosmosProtocol,
+    pub action: String,
+    #[serde(flatten)]
+    pub detail: ContractEvent,
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl ChainEvent {
+    /// Account that sent the swap or liquidity change, or received the claim
+    pub fn account(&self) -> Option<&str> {
+        match &self.detail {
+            ContractEvent::Swap { sender, .. } | ContractEvent::Liquidity { sender, .. } => {
+                sender.as_deref()
+            }
+            ContractEvent::Claim { receiver, .. } => receiver.as_deref(),
+            ContractEvent::Other => None,
+        }
+    }
+
+    /// One line describing the event
+    pub fn summary(&self) -> String {
+        let or = |value: &Option<String>| value.clone().unwrap_or_else(|| "?".to_string());
+        match &self.detail {
+            ContractEvent::Swap {
+                pool_identifier,
+                offer_denom,
+                offer_amount,
+                ask_denom,
+                return_amount,
+                ..
+            } => format!(
+                "swap of {} {} for {} {} in pool {}",
+                or(offer_amount),
+                or(offer_denom),
+                or(return_amount),
+                or(ask_denom),
+                or(pool_identifier)
+            ),
+            ContractEvent::Liquidity {
+                pool_identifier, ..
+            } => format!("{} in pool {}", self.action, or(pool_identifier)),
+            ContractEvent::Claim { receiver, amount } => format!(
+                "claim of {} by {} from {}",
+                or(amount),
+                or(receiver),
+                self.contract
+            ),
+            ContractEvent::Other => format!("{} on {}", self.action, self.contract),
+        }
+    }
+}
+
+/// Typed events of the watched contracts among a transaction's `events`
+pub fn chain_events(
+    height: u64,
+    tx_hash: &str,
+    events: &[CosmosTxEvent],
+    filter: &EventFilter,
+) -> Vec<ChainEvent> {
+    events
+        .iter()
+        .filter(|event| event.kind == "wasm")
+        .filter_map(|event| {
+            let contract = event.attribute("_contract_address")?;
+            let protocol = filter.protocol_of(contract)?;
+            let attributes: BTreeMap<String, String> = event.attributes.iter().cloned().collect();
+            let action = event.attribute("action").unwrap_or_default().to_string();
+            let get = |key: &str| event.attribute(key).map(str::to_string);
+
+            let detail = match action.as_str() {
+                "swap" => ContractEvent::Swap {
+                    sender: get("sender"),
+                    pool_identifier: get("pool_identifier"),
+                    offer_denom: get("offer_denom"),
+                    offer_amount: get("offer_amount"),
+                    ask_denom: get("ask_denom"),
+                    return_amount: get("return_amount"),
+                },
+                "provide_liquidity" | "withdraw_liquidity" => ContractEvent::Liquidity {
+                    sender: get("sender"),
+                    pool_identifier: get("pool_identifier"),
+                },
+                "claim" => ContractEvent::Claim {
+                    receiver: get("receiver"),
+                    amount: get("claimed_amount").or_else(|| get("amount")),
+                },
+                _ => ContractEvent::Other,
+            };
+            Some(ChainEvent {
+                height,
+                tx_hash: tx_hash.to_string(),
+                contract: contract.to_string(),
+                protocol,
+                action,
+                detail,
+                attributes,
+            })
+        })
+        .collect()
+}
+
+/// WebSocket endpoint of the Tendermint RPC at `rpc_url`
+pub fn websocket_url(rpc_url: &str) -> String {
+    let url = rpc_url.trim_end_matches('/');
+    let url = if let Some(rest) = url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        url.to_string()
+    };
+    if url.ends_with("/websocket") {
+        url
+    } else {
+        format!("{}/websocket", url)
+    }
+}
+
+#[cfg(feature = "events")]
+pub use subscriber::EventSubscriber;
+
+#[cfg(feature = "events")]
+mod subscriber {
+    use std::sync::Arc;
+
+    use cosmrs::rpc::event::{Event, EventData};
+    use cosmrs::rpc::query::Query;
+    use cosmrs::rpc::{SubscriptionClient, WebSocketClient};
+    use futures::stream::{self, Stream, StreamExt};
+    use tokio::task::JoinHandle;
+
+    use super::{chain_events, websocket_url, ChainEvent, EventFilter};
+    use crate::error::Error;
+    use crate::protocols::cosmos_analyzer::CosmosTxEvent;
+
+    /// Tendermint WebSocket connection delivering [`ChainEvent`]s
+    #[derive(Debug)]
+    pub struct EventSubscriber {
+        url: String,
+        client: WebSocketClient,
+        driver: JoinHandle<()>,
+    }
+
+    impl EventSubscriber {
+        /// Connect to the WebSocket endpoint of the Tendermint RPC at `rpc_url`
+        pub async fn connect(rpc_url: &str) -> Result<Self, Error> {
+            let url = websocket_url(rpc_url);
+            let (client, driver) = WebSocketClient::new(url.as_str())
+                .await
+                .map_err(|e| Error::Network(format!("Failed to connect to {}: {}", url, e)))?;
+            let endpoint = url.clone();
+            let driver = tokio::spawn(async move {
+                if let Err(e) = driver.run().await {
+                    tracing::warn!("WebSocket connection to {} closed: {}", endpoint, e);
+                }
+            });
+            Ok(Self {
+                url,
+                client,
+                driver,
+            })
+        }
+
+        pub fn url(&self) -> &str {
+            &self.url
+        }
+
+        /// Events of the contracts `filter` watches, as they are committed
+        ///
+        /// The stream ends when the connection closes.
+        pub async fn subscribe(
+            &self,
+            filter: EventFilter,
+        ) -> Result<impl Stream<Item = Result<ChainEvent, Error>> + Send + 'static, Error> {
+            let mut subscriptions = Vec::new();
+            for query in filter.queries() {
+                let query: Query = query
+                    .parse()
+                    .map_err(|e| Error::Other(format!("Invalid event query {}: {}", query, e)))?;
+                let subscription = self.client.subscribe(query).await.map_err(|e| {
+                    Error::Network(format!("Failed to subscribe on {}: {}", self.url, e))
+                })?;
+                subscriptions.push(subscription);
+            }
+
+            let filter = Arc::new(filter);
+            Ok(stream::select_all(subscriptions).flat_map(move |event| {
+                let events: Vec<Result<ChainEvent, Error>> = match event {
+                    Ok(event) => tx_chain_events(&event, &filter)
+                        .into_iter()
+                        .map(Ok)
+                        .collect(),
+                    Err(e) => vec![Err(Error::Network(format!(
+                        "Event subscription failed: {}",
+                        e
+                    )))],
+                };
+                stream::iter(events)
+            }))
+        }
+
+        /// Close the connection, ending every subscription
+        pub async fn close(mut self) -> Result<(), Error> {
+            // The client is a handle on the driver's command channel; a clone
+            // sends the same terminate command, and `self` stays whole for `Drop`
+            self.client
+                .clone()
+                .close()
+                .map_err(|e| Error::Network(format!("Failed to close {}: {}", self.url, e)))?;
+            let _ = (&mut self.driver).await;
+            Ok(())
+        }
+    }
+
+    /// A subscriber dropped without [`EventSubscriber::close`] stops its
+    /// connection rather than leaving it pinging the node
+    impl Drop for EventSubscriber {
+        fn drop(&mut self) {
+            self.driver.abort();
+        }
+    }
+
+    /// Typed events of a transaction event
+    fn tx_chain_events(event: &Event, filter: &EventFilter) -> Vec<ChainEvent> {
+        let EventData::Tx { tx_result } = &event.data else {
+            return Vec::new();
+        };
+        let tx_hash = event
+            .events
+            .as_ref()
+            .and_then(|events| events.get("tx.hash"))
+            .and_then(|hashes| hashes.first())
+            .cloned()
+            .unwrap_or_default();
+        let events: Vec<CosmosTxEvent> = tx_result
+            .result
+            .events
+            .iter()
+            .map(|event| CosmosTxEvent {
+                kind: event.kind.clone(),
+                attributes: event
+                    .attributes
+                    .iter()
+                    .filter_map(|attribute| {
+                        Some((
+                            attribute.key_str().ok()?.to_string(),
+                            attribute.value_str().ok()?.to_string(),
+                        ))
+                    })
+                    .collect(),
+            })
+            .collect();
+        chain_events(tx_result.height as u64, &tx_hash, &events, filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wasm(attributes: &[(&str, &str)]) -> CosmosTxEvent {
+        CosmosTxEvent {
+            kind: "wasm".to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_watched_contract_events_become_typed_events() {
+        let filter = EventFilter::new()
+            .pool_manager("mantra1pm")
+            .claimdrop("mantra1campaign");
+        assert_eq!(
+            filter.queries()[1],
+            "tm.event = 'Tx' AND wasm._contract_address = 'mantra1campaign'"
+        );
+
+        let events = vec![
+            wasm(&[
+                ("_contract_address", "mantra1pm"),
+                ("action", "swap"),
+                ("pool_identifier", "o.uom.uusdc"),
+                ("offer_denom", "uom"),
+                ("offer_amount", "1000"),
+                ("ask_denom", "uusdc"),
+                ("return_amount", "990"),
+            ]),
+            wasm(&[("_contract_address", "mantra1other"), ("action", "swap")]),
+            wasm(&[
+                ("_contract_address", "mantra1campaign"),
+                ("action", "claim"),
+                ("receiver", "mantra1me"),
+                ("amount", "5uom"),
+            ]),
+        ];
+        let typed = chain_events(42, "ABC", &events, &filter);
+        assert_eq!(typed.len(), 2);
+        assert_eq!(typed[0].protocol, CosmosProtocol::Dex);
+        assert_eq!(
+            typed[0].summary(),
+            "swap of 1000 uom for 990 uusdc in pool o.uom.uusdc"
+        );
+        assert_eq!(
+            typed[1].detail,
+            ContractEvent::Claim {
+                receiver: Some("mantra1me".to_string()),
+                amount: Some("5uom".to_string())
+            }
+        );
+        assert_eq!(serde_json::to_value(&typed[1]).unwrap()["event"], "claim");
+
+        assert_eq!(
+            websocket_url("https://rpc.dukong.mantrachain.io:443/"),
+            "wss://rpc.dukong.mantrachain.io:443/websocket"
+        );
+    }
+}
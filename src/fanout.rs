@@ -0,0 +1,293 @@
+//! The same query against several networks at once
+//!
+//! Aggregate views show testnet and mainnet side by side: a wallet's balances
+//! on each, or one pool as each network sees it. A [`NetworkFanout`] runs a
+//! query against every network concurrently, each under its own timeout, and
+//! reports what every network returned. A network that fails or is too slow
+//! leaves the others' results intact, so the report may be partial.
+
+use cosmwasm_std::Coin;
+use futures::future::join_all;
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::config::{MantraNetworkConfig, NetworkProfile};
+use crate::error::Error;
+use crate::protocols::dex::MantraDexClient;
+
+/// Time a network has to answer unless it is given its own timeout
+pub const DEFAULT_NETWORK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What one network returned
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum NetworkOutcome<T> {
+    Ok { value: T },
+    Failed { error: String },
+    TimedOut { timeout_ms: u64 },
+}
+
+/// Result of a query on one network
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkResult<T> {
+    /// Profile or network name the query ran against
+    pub network: String,
+    pub chain_id: String,
+    pub elapsed_ms: u64,
+    #[serde(flatten)]
+    pub outcome: NetworkOutcome<T>,
+}
+
+impl<T> NetworkResult<T> {
+    pub fn value(&self) -> Option<&T> {
+        match &self.outcome {
+            NetworkOutcome::Ok { value } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Why the network has no value, if it has none
+    pub fn error(&self) -> Option<String> {
+        match &self.outcome {
+            NetworkOutcome::Ok { .. } => None,
+            NetworkOutcome::Failed { error } => Some(error.clone()),
+            NetworkOutcome::TimedOut { timeout_ms } => {
+                Some(format!("No answer within {} ms", timeout_ms))
+            }
+        }
+    }
+}
+
+/// Results of a query on every network, in the order the networks were given
+#[derive(Debug, Clone, Serialize)]
+pub struct FanoutReport<T> {
+    pub results: Vec<NetworkResult<T>>,
+}
+
+impl<T> FanoutReport<T> {
+    /// Values of the networks that answered, by network
+    pub fn values(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.results
+            .iter()
+            .filter_map(|result| Some((result.network.as_str(), result.value()?)))
+    }
+
+    /// Networks without a value and why
+    pub fn failures(&self) -> impl Iterator<Item = (&str, String)> {
+        self.results
+            .iter()
+            .filter_map(|result| Some((result.network.as_str(), result.error()?)))
+    }
+
+    /// Whether every network answered
+    pub fn is_complete(&self) -> bool {
+        self.results.iter().all(|result| result.value().is_some())
+    }
+}
+
+/// Runs queries against several networks concurrently
+#[derive(Debug, Clone)]
+pub struct NetworkFanout {
+    /// Each network's configuration, or why it could not be resolved
+    networks: Vec<(String, Result<MantraNetworkConfig, String>)>,
+    timeout: Duration,
+    network_timeouts: HashMap<String, Duration>,
+}
+
+impl NetworkFanout {
+    /// Fan-out over named network configurations
+    pub fn new(networks: Vec<(String, MantraNetworkConfig)>) -> Self {
+        Self {
+            networks: networks
+                .into_iter()
+                .map(|(name, config)| (name, Ok(config)))
+                .collect(),
+            timeout: DEFAULT_NETWORK_TIMEOUT,
+            network_timeouts: HashMap::new(),
+        }
+    }
+
+    /// Fan-out over the profiles called `names`
+    ///
+    /// Profiles need not have a pool manager. Fails when a name is not among
+    /// `profiles`; a profile that cannot be resolved is reported as a failed
+    /// network by every query.
+    pub fn from_profiles(
+        profiles: &BTreeMap<String, NetworkProfile>,
+        names: &[String],
+    ) -> Result<Self, Error> {
+        let networks = names
+            .iter()
+            .map(|name| {
+                let profile = profiles.get(name).ok_or_else(|| {
+                    Error::Config(format!(
+                        "Unknown network profile '{}' (available profiles: {})",
+                        name,
+                        profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+                    ))
+                })?;
+                let config = profile.resolve_for_queries(name).map_err(|e| e.to_string());
+                Ok((name.clone(), config))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self {
+            networks,
+            timeout: DEFAULT_NETWORK_TIMEOUT,
+            network_timeouts: HashMap::new(),
+        })
+    }
+
+    /// Time every network without its own timeout has to answer
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Time network `network` has to answer
+    pub fn with_network_timeout(mut self, network: &str, timeout: Duration) -> Self {
+        self.network_timeouts.insert(network.to_string(), timeout);
+        self
+    }
+
+    /// Names of the networks queried, in order
+    pub fn networks(&self) -> Vec<String> {
+        self.networks.iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Run `query` against every network at once
+    pub async fn run<T, F, Fut>(&self, query: F) -> FanoutReport<T>
+    where
+        F: Fn(MantraNetworkConfig) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let queries = self.networks.iter().map(|(network, config)| {
+            let timeout = self
+                .network_timeouts
+                .get(network)
+                .copied()
+                .unwrap_or(self.timeout);
+            let query = config.clone().map(&query);
+            async move {
+                let started = Instant::now();
+                let query = match query {
+                    Ok(query) => query,
+                    Err(error) => {
+                        return NetworkResult {
+                            network: network.clone(),
+                            chain_id: String::new(),
+                            elapsed_ms: 0,
+                            outcome: NetworkOutcome::Failed { error },
+                        };
+                    }
+                };
+                let outcome = match tokio::time::timeout(timeout, query).await {
+                    Ok(Ok(value)) => NetworkOutcome::Ok { value },
+                    Ok(Err(e)) => NetworkOutcome::Failed {
+                        error: e.to_string(),
+                    },
+                    Err(_) => NetworkOutcome::TimedOut {
+                        timeout_ms: timeout.as_millis() as u64,
+                    },
+                };
+                NetworkResult {
+                    network: network.clone(),
+                    chain_id: config
+                        .as_ref()
+                        .map(|config| config.chain_id.clone())
+                        .unwrap_or_default(),
+                    elapsed_ms: started.elapsed().as_millis() as u64,
+                    outcome,
+                }
+            }
+        });
+
+        FanoutReport {
+            results: join_all(queries).await,
+        }
+    }
+
+    /// Balances of `address` on every network
+    pub async fn balances(&self, address: &str) -> FanoutReport<Vec<Coin>> {
+        self.run(|config| async move {
+            MantraDexClient::new(config)
+                .await?
+                .get_balances_for_address(address)
+                .await
+        })
+        .await
+    }
+
+    /// Pool `pool_id` as every network sees it
+    pub async fn pool(&self, pool_id: &str) -> FanoutReport<PoolInfoResponse> {
+        self.run(
+            |config| async move { MantraDexClient::new(config).await?.get_pool(pool_id).await },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network(chain_id: &str) -> MantraNetworkConfig {
+        MantraNetworkConfig {
+            chain_id: chain_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_and_failing_networks_leave_a_partial_report() {
+        let fanout = NetworkFanout::new(vec![
+            ("mainnet".to_string(), network("mantra-1")),
+            ("dukong".to_string(), network("mantra-dukong-1")),
+            ("local".to_string(), network("mantra-local-1")),
+        ])
+        .with_timeout(Duration::from_secs(5))
+        .with_network_timeout("local", Duration::from_millis(20));
+
+        let report = fanout
+            .run(|config| async move {
+                match config.chain_id.as_str() {
+                    "mantra-1" => Ok(1),
+                    "mantra-dukong-1" => Err(Error::Rpc("connection refused".to_string())),
+                    _ => {
+                        tokio::time::sleep(Duration::from_secs(60)).await;
+                        Ok(3)
+                    }
+                }
+            })
+            .await;
+
+        let networks: Vec<&str> = report.results.iter().map(|r| r.network.as_str()).collect();
+        assert_eq!(networks, ["mainnet", "dukong", "local"]);
+        assert_eq!(report.values().collect::<Vec<_>>(), [("mainnet", &1)]);
+        assert!(!report.is_complete());
+        assert!(matches!(
+            report.results[2].outcome,
+            NetworkOutcome::TimedOut { timeout_ms: 20 }
+        ));
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 2);
+        assert!(failures[0].1.contains("connection refused"));
+
+        // A profile that does not resolve fails on its own
+        let profiles = BTreeMap::from([(
+            "broken".to_string(),
+            NetworkProfile {
+                chain_id: Some("mantra-broken-1".to_string()),
+                ..Default::default()
+            },
+        )]);
+        assert!(NetworkFanout::from_profiles(&profiles, &["missing".to_string()]).is_err());
+        let report = NetworkFanout::from_profiles(&profiles, &["broken".to_string()])
+            .unwrap()
+            .run(|_| async { Ok(()) })
+            .await;
+        assert!(report.results[0].error().unwrap().contains("rpc_url"));
+    }
+}
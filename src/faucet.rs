@@ -0,0 +1,391 @@
+//! Testnet faucet
+//!
+//! Funding a test wallet used to mean leaving the SDK for the faucet web
+//! page. [`Faucet::request`] asks the faucet to send tokens to an address and
+//! then polls the address's balance until they arrive, so a test can spend
+//! them right away.
+//!
+//! The faucet speaks the cosmjs faucet protocol: `POST /credit` with the
+//! address and denom. It limits how often one address may ask. A request
+//! made before the address's cooldown ends is refused without being sent
+//! with [`Error::RateLimited`], naming when the address may ask again. The
+//! cooldown is reserved before a request is sent, so concurrent requests for
+//! one address reach the faucet once, and released if the request fails. A
+//! rate limit reported by the faucet itself (HTTP 429, with `Retry-After`
+//! when given) starts the same cooldown.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::Uint128;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::protocols::dex::MantraDexClient;
+use crate::wallet::address::{require_address_kind, DEFAULT_ACCOUNT_PREFIX};
+use crate::wallet::AddressKind;
+
+/// Faucet of the Dukong testnet
+pub const DUKONG_FAUCET_URL: &str = "https://faucet.dukong.mantrachain.io";
+
+/// Environment variable naming another faucet for [`Faucet::global`]
+pub const FAUCET_URL_ENV: &str = "MANTRA_FAUCET_URL";
+
+/// Time an address waits between two requests, unless the faucet says
+/// otherwise
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How long the balance is polled for after the faucet accepted a request
+pub const DEFAULT_BALANCE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time between two balance polls
+pub const BALANCE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Chain the faucet is never asked for, since its tokens have value
+const MAINNET_CHAIN_ID: &str = "mantra-1";
+
+/// Where the faucet is and how it is used
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaucetConfig {
+    pub url: String,
+    /// Denom asked for
+    pub denom: String,
+    pub cooldown: Duration,
+    pub balance_timeout: Duration,
+}
+
+impl Default for FaucetConfig {
+    fn default() -> Self {
+        Self {
+            url: DUKONG_FAUCET_URL.to_string(),
+            denom: "uom".to_string(),
+            cooldown: DEFAULT_COOLDOWN,
+            balance_timeout: DEFAULT_BALANCE_TIMEOUT,
+        }
+    }
+}
+
+impl FaucetConfig {
+    /// Default configuration, at the URL of [`FAUCET_URL_ENV`] when set
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(url) = std::env::var(FAUCET_URL_ENV) {
+            if !url.trim().is_empty() {
+                config.url = url.trim().to_string();
+            }
+        }
+        config
+    }
+}
+
+/// Outcome of a faucet request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FaucetReceipt {
+    pub address: String,
+    pub denom: String,
+    pub balance_before: Uint128,
+    pub balance_after: Uint128,
+    /// Whether the balance rose before the polling timed out; when not, the
+    /// faucet accepted the request but the tokens had not arrived yet
+    pub funded: bool,
+    pub requested_at: DateTime<Utc>,
+    /// When the address may ask again
+    pub next_request_at: DateTime<Utc>,
+}
+
+impl FaucetReceipt {
+    /// Amount received from the faucet
+    pub fn received(&self) -> Uint128 {
+        self.balance_after.saturating_sub(self.balance_before)
+    }
+}
+
+/// Client of a faucet, remembering when each address may ask again
+#[derive(Debug)]
+pub struct Faucet {
+    config: FaucetConfig,
+    http: reqwest::Client,
+    next_allowed: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl Default for Faucet {
+    fn default() -> Self {
+        Self::new(FaucetConfig::default())
+    }
+}
+
+impl Faucet {
+    pub fn new(config: FaucetConfig) -> Self {
+        Self {
+            config,
+            http: reqwest::Client::new(),
+            next_allowed: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Faucet shared by every client in the process
+    pub fn global() -> &'static Faucet {
+        static GLOBAL: OnceLock<Faucet> = OnceLock::new();
+        GLOBAL.get_or_init(|| Faucet::new(FaucetConfig::from_env()))
+    }
+
+    pub fn config(&self) -> &FaucetConfig {
+        &self.config
+    }
+
+    /// When `address` may ask again, if its cooldown has not ended
+    pub fn next_request_at(&self, address: &str) -> Option<DateTime<Utc>> {
+        self.lock()
+            .get(address)
+            .copied()
+            .filter(|next| *next > Utc::now())
+    }
+
+    /// Ask the faucet to send tokens to `address` on `client`'s chain and
+    /// wait for its balance to rise
+    pub async fn request(
+        &self,
+        client: &MantraDexClient,
+        address: &str,
+    ) -> Result<FaucetReceipt, Error> {
+        let chain_id = &client.config().chain_id;
+        if chain_id == MAINNET_CHAIN_ID {
+            return Err(Error::Config(format!(
+                "The faucet only funds testnet wallets, not {}",
+                chain_id
+            )));
+        }
+        require_address_kind(address, AddressKind::Cosmos, DEFAULT_ACCOUNT_PREFIX)?;
+
+        let denom = &self.config.denom;
+        let balance_before = balance_of(client, address, denom).await?;
+        let requested_at = Utc::now();
+        self.credit(address).await?;
+
+        let deadline = tokio::time::Instant::now() + self.config.balance_timeout;
+        let mut balance_after = balance_before;
+        while balance_after <= balance_before && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(BALANCE_POLL_INTERVAL).await;
+            match balance_of(client, address, denom).await {
+                Ok(balance) => balance_after = balance,
+                Err(e) => tracing::debug!("Polling the balance of {} failed: {}", address, e),
+            }
+        }
+
+        Ok(FaucetReceipt {
+            address: address.to_string(),
+            denom: denom.clone(),
+            balance_before,
+            balance_after,
+            funded: balance_after > balance_before,
+            requested_at,
+            next_request_at: self.next_request_at(address).unwrap_or(requested_at),
+        })
+    }
+
+    /// Send the faucet's credit request for `address`, without waiting for
+    /// the tokens
+    pub async fn credit(&self, address: &str) -> Result<(), Error> {
+        let previous = self.reserve_cooldown(address)?;
+        let result = self.send_credit(address).await;
+        if let Err(error) = &result {
+            // A refusal by the faucet keeps the cooldown it started
+            if !matches!(error, Error::RateLimited { .. }) {
+                self.release_cooldown(address, previous);
+            }
+        }
+        result
+    }
+
+    async fn send_credit(&self, address: &str) -> Result<(), Error> {
+        let url = format!("{}/credit", self.config.url.trim_end_matches('/'));
+        let response = self
+            .http
+            .post(&url)
+            .timeout(Duration::from_secs(30))
+            .json(&serde_json::json!({
+                "address": address,
+                "denom": self.config.denom,
+            }))
+            .send()
+            .await
+            .map_err(|e| Error::Network(format!("Faucet request to {} failed: {}", url, e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let wait = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(retry_after)
+                .unwrap_or(self.config.cooldown);
+            let next = self.start_cooldown(address, wait);
+            return Err(rate_limited(address, next));
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::Network(format!(
+                "Faucet refused the request for {}: {} {}",
+                address,
+                status,
+                body.trim()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Start `address`'s cooldown unless it is running, returning the end of
+    /// its previous one
+    fn reserve_cooldown(&self, address: &str) -> Result<Option<DateTime<Utc>>, Error> {
+        let mut next_allowed = self.lock();
+        let previous = next_allowed.get(address).copied();
+        if let Some(next) = previous.filter(|next| *next > Utc::now()) {
+            return Err(rate_limited(address, next));
+        }
+        next_allowed.insert(address.to_string(), cooldown_end(self.config.cooldown));
+        Ok(previous)
+    }
+
+    /// Undo [`Faucet::reserve_cooldown`] after a request that failed
+    fn release_cooldown(&self, address: &str, previous: Option<DateTime<Utc>>) {
+        let mut next_allowed = self.lock();
+        match previous {
+            Some(previous) => next_allowed.insert(address.to_string(), previous),
+            None => next_allowed.remove(address),
+        };
+    }
+
+    /// Keep `address` from asking for `wait`, returning when it may again
+    fn start_cooldown(&self, address: &str, wait: Duration) -> DateTime<Utc> {
+        let next = cooldown_end(wait);
+        self.lock().insert(address.to_string(), next);
+        next
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, DateTime<Utc>>> {
+        self.next_allowed.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Wait of a `Retry-After` header given in seconds
+fn retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+/// When a cooldown of `wait` starting now ends
+fn cooldown_end(wait: Duration) -> DateTime<Utc> {
+    Utc::now() + chrono::Duration::from_std(wait).unwrap_or(chrono::Duration::days(365))
+}
+
+fn rate_limited(address: &str, next: DateTime<Utc>) -> Error {
+    Error::RateLimited {
+        reason: format!(
+            "the faucet lets {} ask again at {}",
+            address,
+            next.to_rfc3339()
+        ),
+        retry_after_secs: (next - Utc::now()).num_seconds().max(0) as u64,
+    }
+}
+
+async fn balance_of(
+    client: &MantraDexClient,
+    address: &str,
+    denom: &str,
+) -> Result<Uint128, Error> {
+    Ok(client
+        .get_balances_for_address(address)
+        .await?
+        .into_iter()
+        .find(|coin| coin.denom == denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cooldown_refuses_without_asking_the_faucet() {
+        // Nothing listens there, so only a request that is sent fails with
+        // a network error
+        let faucet = Faucet::new(FaucetConfig {
+            url: "http://127.0.0.1:9".to_string(),
+            ..FaucetConfig::default()
+        });
+        let address = "mantra1test";
+        assert_eq!(faucet.next_request_at(address), None);
+        assert!(matches!(
+            faucet.credit(address).await,
+            Err(Error::Network(_))
+        ));
+
+        // The failed request gave its reserved cooldown back
+        assert_eq!(faucet.next_request_at(address), None);
+
+        let next = faucet.start_cooldown(address, Duration::from_secs(120));
+        assert_eq!(faucet.next_request_at(address), Some(next));
+        assert!(matches!(
+            faucet.credit(address).await,
+            Err(Error::RateLimited {
+                retry_after_secs: 100..=120,
+                ..
+            })
+        ));
+        assert_eq!(faucet.next_request_at("mantra1other"), None);
+
+        faucet.start_cooldown(address, Duration::ZERO);
+        assert_eq!(faucet.next_request_at(address), None);
+
+        assert_eq!(retry_after(" 30 "), Some(Duration::from_secs(30)));
+        assert_eq!(retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+
+        let receipt = FaucetReceipt {
+            address: address.to_string(),
+            denom: "uom".to_string(),
+            balance_before: Uint128::new(5),
+            balance_after: Uint128::new(10_000_005),
+            funded: true,
+            requested_at: Utc::now(),
+            next_request_at: next,
+        };
+        assert_eq!(receipt.received(), Uint128::new(10_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_reach_the_faucet_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let served = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                served.fetch_add(1, Ordering::SeqCst);
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer).await;
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                let _ = stream
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                    .await;
+            }
+        });
+
+        let faucet = Faucet::new(FaucetConfig {
+            url,
+            ..FaucetConfig::default()
+        });
+        let (first, second) =
+            tokio::join!(faucet.credit("mantra1test"), faucet.credit("mantra1test"));
+        assert!(first.is_ok(), "{:?}", first);
+        assert!(matches!(second, Err(Error::RateLimited { .. })));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(faucet.next_request_at("mantra1test").is_some());
+    }
+}
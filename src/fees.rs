@@ -0,0 +1,379 @@
+//! Cost previews of transactions before anything is signed
+//!
+//! [`estimate_transaction_cost`] prices an operation the way the SDK would
+//! send it. Cosmos operations are simulated with the client's wallet and pay
+//! for the whole [`DEFAULT_GAS_LIMIT`] at the network's quoted gas price
+//! (see [`crate::gas_price`]); without a
+//! wallet nothing can be simulated and the limit stands in for the gas used.
+//! EVM operations are estimated with `eth_estimateGas` and priced at the
+//! suggested EIP-1559 maximum fee, so their fee is an upper bound. Either way
+//! the fee is paid in the native token, which has 6 decimals on the Cosmos
+//! side and 18 on the EVM side, and is valued in dollars through the pools'
+//! [`UsdPrices`].
+
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::amount::format_units_u128;
+use crate::error::Error;
+use crate::portfolio::UsdPrices;
+use crate::protocols::dex::client::DEFAULT_GAS_LIMIT;
+use crate::protocols::dex::MantraDexClient;
+use crate::tx_queue::TxVm;
+
+/// Decimals of the native token as the EVM counts it
+pub const EVM_NATIVE_DECIMALS: u8 = 18;
+
+/// Operation to price
+///
+/// EVM addresses and amounts are strings so the operation can be described
+/// without the `evm` feature; amounts are in the token's base units.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum CostOperation {
+    Swap {
+        pool_id: String,
+        offer: Coin,
+        ask_denom: String,
+    },
+    /// ClaimDrop claim of everything available
+    Claim { campaign: String },
+    Erc20Transfer {
+        token: String,
+        to: String,
+        amount: String,
+    },
+    /// PrimarySale investment of `amount` of the payment `token`
+    Invest {
+        sale: String,
+        token: String,
+        amount: String,
+    },
+}
+
+impl CostOperation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CostOperation::Swap { .. } => "swap",
+            CostOperation::Claim { .. } => "claim",
+            CostOperation::Erc20Transfer { .. } => "erc20_transfer",
+            CostOperation::Invest { .. } => "invest",
+        }
+    }
+
+    pub fn vm(&self) -> TxVm {
+        match self {
+            CostOperation::Swap { .. } | CostOperation::Claim { .. } => TxVm::Cosmos,
+            CostOperation::Erc20Transfer { .. } | CostOperation::Invest { .. } => TxVm::Evm,
+        }
+    }
+}
+
+/// Where the gas figure of an estimate comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GasSource {
+    /// The chain simulated the signed transaction
+    Simulated,
+    /// `eth_estimateGas`
+    Estimated,
+    /// No simulation was possible; the gas limit is assumed
+    Default,
+}
+
+/// Expected cost of an operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostEstimate {
+    pub operation: String,
+    pub vm: TxVm,
+    /// Gas the transaction is expected to use
+    pub gas_used: u64,
+    /// Gas the transaction is sent with
+    pub gas_limit: u64,
+    pub gas_source: GasSource,
+    /// Price of one gas in base units of the fee: `uom` on Cosmos, wei on
+    /// the EVM
+    pub gas_price: String,
+    pub fee_denom: String,
+    pub fee_decimals: u8,
+    /// Network fee in base units
+    pub fee_amount: Uint128,
+    /// Network fee in whole native tokens
+    pub fee: String,
+    pub fee_usd: Option<f64>,
+    /// Fees the operation itself charges, such as a swap's pool fees
+    pub protocol_fees: Vec<Coin>,
+    pub protocol_fees_usd: Option<f64>,
+    /// Network and protocol fees together, when all of them have a price
+    pub total_usd: Option<f64>,
+}
+
+impl CostEstimate {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        operation: &CostOperation,
+        gas_used: u64,
+        gas_limit: u64,
+        gas_source: GasSource,
+        gas_price: String,
+        fee_denom: &str,
+        fee_decimals: u8,
+        fee_amount: Uint128,
+        protocol_fees: Vec<Coin>,
+        prices: &UsdPrices,
+    ) -> Self {
+        let fee_usd = prices
+            .price(fee_denom)
+            .map(|price| fee_amount.u128() as f64 / 10f64.powi(i32::from(fee_decimals)) * price);
+        let protocol_fees_usd = protocol_fees
+            .iter()
+            .map(|coin| prices.usd_value(coin))
+            .sum::<Option<f64>>();
+        let total_usd = fee_usd.zip(protocol_fees_usd).map(|(fee, fees)| fee + fees);
+        Self {
+            operation: operation.name().to_string(),
+            vm: operation.vm(),
+            gas_used,
+            gas_limit,
+            gas_source,
+            gas_price,
+            fee_denom: fee_denom.to_string(),
+            fee_decimals,
+            fee_amount,
+            fee: format_units_u128(fee_amount, fee_decimals),
+            fee_usd,
+            protocol_fees,
+            protocol_fees_usd,
+            total_usd,
+        }
+    }
+}
+
+/// Fee of `gas_limit` at `gas_price` scaled by `adjustment`, as wallets
+/// compute it
+pub fn cosmos_fee(gas_limit: u64, gas_price: f64, adjustment: f64) -> Uint128 {
+    Uint128::new((gas_limit as f64 * gas_price * adjustment) as u128)
+}
+
+/// Estimate the gas, fee and dollar cost of `operation`
+///
+/// # Errors
+///
+/// Returns error if the pools cannot be fetched, the operation's inputs are
+/// malformed, the simulation or gas estimate fails (which usually means the
+/// transaction itself would fail), or an EVM operation is priced without an
+/// EVM endpoint or the `evm` feature
+pub async fn estimate_transaction_cost(
+    client: &MantraDexClient,
+    operation: &CostOperation,
+) -> Result<CostEstimate, Error> {
+    let prices = UsdPrices::from_pools(&client.get_all_pools().await?);
+    match operation.vm() {
+        TxVm::Cosmos => estimate_cosmos_cost(client, operation, &prices).await,
+        TxVm::Evm => estimate_evm_cost(client, operation, &prices).await,
+    }
+}
+
+async fn estimate_cosmos_cost(
+    client: &MantraDexClient,
+    operation: &CostOperation,
+    prices: &UsdPrices,
+) -> Result<CostEstimate, Error> {
+    let (msg, protocol_fees) = match operation {
+        CostOperation::Swap {
+            pool_id,
+            offer,
+            ask_denom,
+        } => {
+            let simulation = client
+                .simulate_swap(pool_id, offer.clone(), ask_denom)
+                .await?;
+            let fees = simulation.swap_fee_amount
+                + simulation.protocol_fee_amount
+                + simulation.burn_fee_amount
+                + simulation.extra_fees_amount;
+            let msg = mantra_dex_std::pool_manager::ExecuteMsg::Swap {
+                pool_identifier: pool_id.clone(),
+                belief_price: None,
+                receiver: None,
+                ask_asset_denom: ask_denom.clone(),
+                max_slippage: None,
+            };
+            let execute = client.wallet().ok().map(|_| {
+                client.execute_contract_msg(
+                    &client.config().contracts.pool_manager,
+                    &msg,
+                    vec![offer.clone()],
+                )
+            });
+            (execute, vec![Coin::new(fees, ask_denom.as_str())])
+        }
+        CostOperation::Claim { campaign } => {
+            let msg = mantra_claimdrop_std::msg::ExecuteMsg::Claim {
+                amount: None,
+                receiver: None,
+            };
+            let execute = client
+                .wallet()
+                .ok()
+                .map(|_| client.execute_contract_msg(campaign, &msg, vec![]));
+            (execute, Vec::new())
+        }
+        _ => unreachable!("EVM operations are priced by estimate_evm_cost"),
+    };
+
+    let (gas_used, gas_source) = match msg {
+        Some(msg) => {
+            let response = client.simulate_msgs(vec![msg?]).await?;
+            (response.gas_used as u64, GasSource::Simulated)
+        }
+        None => (DEFAULT_GAS_LIMIT, GasSource::Default),
+    };
+
+    let quote = client.gas_price_quote().await;
+    let fee_amount = Uint128::new(quote.fee_amount(DEFAULT_GAS_LIMIT));
+    Ok(CostEstimate::new(
+        operation,
+        gas_used,
+        DEFAULT_GAS_LIMIT,
+        gas_source,
+        quote.gas_price.to_string(),
+        &quote.denom,
+        6,
+        fee_amount,
+        protocol_fees,
+        prices,
+    ))
+}
+
+#[cfg(feature = "evm")]
+async fn estimate_evm_cost(
+    client: &MantraDexClient,
+    operation: &CostOperation,
+    prices: &UsdPrices,
+) -> Result<CostEstimate, Error> {
+    use alloy_primitives::U256;
+    use alloy_sol_types::SolCall;
+    use std::str::FromStr;
+
+    use crate::protocols::evm::client::EvmClient;
+    use crate::protocols::evm::contracts::{IPrimarySale, IERC20};
+    use crate::protocols::evm::types::{EthAddress, EvmTransactionRequest};
+
+    let config = client.config();
+    let (Some(rpc_url), Some(chain_id)) = (&config.evm_rpc_url, config.evm_chain_id) else {
+        return Err(Error::Config(
+            "EVM RPC URL and chain ID must be configured to price EVM operations".to_string(),
+        ));
+    };
+    let amount = |amount: &str| {
+        U256::from_str(amount)
+            .map_err(|e| Error::Other(format!("Invalid amount {}: {}", amount, e)))
+    };
+    let (to, data) = match operation {
+        CostOperation::Erc20Transfer {
+            token,
+            to,
+            amount: value,
+        } => (
+            EthAddress::from_str(token)?,
+            IERC20::transferCall {
+                to: EthAddress::from_str(to)?.0,
+                amount: amount(value)?,
+            }
+            .abi_encode(),
+        ),
+        CostOperation::Invest {
+            sale,
+            token,
+            amount: value,
+        } => (
+            EthAddress::from_str(sale)?,
+            IPrimarySale::investCall {
+                token: EthAddress::from_str(token)?.0,
+                amount: amount(value)?,
+            }
+            .abi_encode(),
+        ),
+        _ => unreachable!("Cosmos operations are priced by estimate_cosmos_cost"),
+    };
+
+    let evm = EvmClient::new(rpc_url, chain_id).await?;
+    let from = match client.wallet() {
+        Ok(wallet) => Some(EthAddress(wallet.ethereum_address()?)),
+        Err(_) => None,
+    };
+    let request = EvmTransactionRequest::new(chain_id).to(to).data(data);
+    let gas_used = evm.estimate_gas_with_options(request, from, None).await?;
+    let max_fee = evm.fee_suggestion().await?.max_fee_per_gas;
+    let fee_amount = u128::try_from(max_fee.saturating_mul(U256::from(gas_used)))
+        .map(Uint128::new)
+        .map_err(|_| Error::Evm(format!("Fee at {} wei per gas overflows", max_fee)))?;
+
+    Ok(CostEstimate::new(
+        operation,
+        gas_used,
+        gas_used,
+        GasSource::Estimated,
+        max_fee.to_string(),
+        &config.native_denom,
+        EVM_NATIVE_DECIMALS,
+        fee_amount,
+        Vec::new(),
+        prices,
+    ))
+}
+
+#[cfg(not(feature = "evm"))]
+async fn estimate_evm_cost(
+    _client: &MantraDexClient,
+    operation: &CostOperation,
+    _prices: &UsdPrices,
+) -> Result<CostEstimate, Error> {
+    Err(Error::Config(format!(
+        "Pricing {} needs the evm feature",
+        operation.name()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_prices_fees_through_pools() {
+        let operation: CostOperation = serde_json::from_value(serde_json::json!({
+            "operation": "swap",
+            "pool_id": "o.uom.uusdc",
+            "offer": { "denom": "uom", "amount": "1000000" },
+            "ask_denom": "uusdc"
+        }))
+        .unwrap();
+        assert_eq!(operation.vm(), TxVm::Cosmos);
+
+        let fee = cosmos_fee(DEFAULT_GAS_LIMIT, 0.01, 1.5);
+        assert_eq!(fee.u128(), 30_000);
+
+        let prices = UsdPrices::default();
+        let estimate = CostEstimate::new(
+            &operation,
+            180_000,
+            DEFAULT_GAS_LIMIT,
+            GasSource::Simulated,
+            "0.015".to_string(),
+            "uom",
+            6,
+            fee,
+            vec![Coin::new(3_000u128, "uusdc")],
+            &prices,
+        );
+        assert_eq!(estimate.fee, "0.03");
+        assert_eq!(estimate.fee_usd, None);
+        assert_eq!(estimate.total_usd, None);
+
+        let json = serde_json::to_value(&estimate).unwrap();
+        assert_eq!(json["vm"], "cosmos");
+        assert_eq!(json["gas_source"], "simulated");
+        assert_eq!(json["fee_amount"], "30000");
+    }
+}
@@ -0,0 +1,192 @@
+//! Gas price of each network's Cosmos transactions
+//!
+//! Fees used to be priced at the Dukong constants whatever network the
+//! client was on. A [`GasPriceQuote`] prices them for one network instead:
+//! it starts from the network's configured gas price, raises it to the
+//! minimum the connected node accepts, and adds a surcharge when recent
+//! blocks are more than [`BUSY_UTILIZATION`] full. [`GasPriceOracle`] keeps
+//! each chain's quote for [`GAS_PRICE_TTL`], so consecutive transactions do
+//! not query the node again.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+use web_time::Instant;
+
+use chrono::{DateTime, Utc};
+use cosmrs::tx::Fee;
+use cosmrs::{Coin as CosmosCoin, Denom};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// How long a quote is reused before the node is asked again
+pub const GAS_PRICE_TTL: Duration = Duration::from_secs(30);
+
+/// Recent blocks whose gas use is averaged
+pub const UTILIZATION_BLOCKS: u64 = 5;
+
+/// Share of the block gas limit above which blocks count as busy
+pub const BUSY_UTILIZATION: f64 = 0.5;
+
+/// Multiplier of the gas price when recent blocks are full
+pub const MAX_SURGE: f64 = 2.0;
+
+/// Gas price of one network and what it was derived from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GasPriceQuote {
+    pub chain_id: String,
+    /// Denom fees are paid in
+    pub denom: String,
+    /// Gas price of the network configuration
+    pub configured_gas_price: f64,
+    /// Minimum gas price the node accepts in `denom`, when it says
+    pub node_minimum_gas_price: Option<f64>,
+    /// Average share of the block gas limit used by recent blocks, when the
+    /// chain has a limit and the blocks could be read
+    pub utilization: Option<f64>,
+    pub gas_adjustment: f64,
+    /// Price per unit of gas fees are paid at, adjustment included
+    pub gas_price: f64,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl GasPriceQuote {
+    pub fn new(
+        chain_id: impl Into<String>,
+        denom: impl Into<String>,
+        configured_gas_price: f64,
+        gas_adjustment: f64,
+        node_minimum_gas_price: Option<f64>,
+        utilization: Option<f64>,
+    ) -> Self {
+        let base = configured_gas_price.max(node_minimum_gas_price.unwrap_or_default());
+        let surge = utilization.map_or(1.0, surge_multiplier);
+        Self {
+            chain_id: chain_id.into(),
+            denom: denom.into(),
+            configured_gas_price,
+            node_minimum_gas_price,
+            utilization,
+            gas_adjustment,
+            gas_price: base * surge * gas_adjustment,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    /// Fee of `gas_limit` in the quote's denom, rounded up so it never falls
+    /// below the node's minimum
+    pub fn fee_amount(&self, gas_limit: u64) -> u128 {
+        let amount = gas_limit as f64 * self.gas_price;
+        // Float noise such as 3000.0000000000005 is not a unit to round up
+        ((amount * 1e6).round() / 1e6).ceil() as u128
+    }
+
+    /// Fee paying for all of `gas_limit`
+    pub fn fee(&self, gas_limit: u64) -> Result<Fee, Error> {
+        let denom = Denom::from_str(&self.denom)
+            .map_err(|e| Error::Wallet(format!("Invalid denom: {}", e)))?;
+        let coin = CosmosCoin {
+            amount: self.fee_amount(gas_limit),
+            denom,
+        };
+        Ok(Fee::from_amount_and_gas(coin, gas_limit))
+    }
+}
+
+/// Multiplier of the gas price at `utilization`: 1 up to
+/// [`BUSY_UTILIZATION`], rising linearly to [`MAX_SURGE`] at full blocks
+pub fn surge_multiplier(utilization: f64) -> f64 {
+    let utilization = utilization.clamp(0.0, 1.0);
+    if utilization <= BUSY_UTILIZATION {
+        return 1.0;
+    }
+    1.0 + (MAX_SURGE - 1.0) * (utilization - BUSY_UTILIZATION) / (1.0 - BUSY_UTILIZATION)
+}
+
+/// Recent gas price quotes, by chain ID
+#[derive(Debug)]
+pub struct GasPriceOracle {
+    ttl: Duration,
+    quotes: RwLock<HashMap<String, (Instant, GasPriceQuote)>>,
+}
+
+impl Default for GasPriceOracle {
+    fn default() -> Self {
+        Self::new(GAS_PRICE_TTL)
+    }
+}
+
+impl GasPriceOracle {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            quotes: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Oracle shared by every client in the process
+    pub fn global() -> &'static GasPriceOracle {
+        static GLOBAL: OnceLock<GasPriceOracle> = OnceLock::new();
+        GLOBAL.get_or_init(GasPriceOracle::default)
+    }
+
+    /// Quote of `chain_id`, unless it is older than the TTL
+    pub fn cached(&self, chain_id: &str) -> Option<GasPriceQuote> {
+        let quotes = self.quotes.read().unwrap_or_else(|e| e.into_inner());
+        quotes
+            .get(chain_id)
+            .filter(|(stored, _)| stored.elapsed() < self.ttl)
+            .map(|(_, quote)| quote.clone())
+    }
+
+    /// Keep `quote` for its chain
+    pub fn store(&self, quote: GasPriceQuote) {
+        self.quotes
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(quote.chain_id.clone(), (Instant::now(), quote));
+    }
+
+    /// Forget the quote of `chain_id`, so the next fee queries the node
+    pub fn invalidate(&self, chain_id: &str) {
+        self.quotes
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(chain_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quotes_follow_the_node_and_block_use() {
+        // The node's minimum wins over a lower configured price
+        let quote = GasPriceQuote::new("mantra-1", "uom", 0.01, 1.5, Some(0.02), Some(0.3));
+        assert!((quote.gas_price - 0.03).abs() < 1e-12);
+        assert_eq!(quote.fee_amount(100_000), 3_000);
+        let fee = quote.fee(100_000).unwrap();
+        assert_eq!(fee.gas_limit, 100_000);
+        assert_eq!(fee.amount[0].denom.to_string(), "uom");
+
+        // Full blocks double the price, half-full ones leave it alone
+        assert_eq!(surge_multiplier(0.5), 1.0);
+        assert_eq!(surge_multiplier(1.0), MAX_SURGE);
+        let busy = GasPriceQuote::new("mantra-1", "uom", 0.01, 1.0, None, Some(0.75));
+        assert!((busy.gas_price - 0.015).abs() < 1e-12);
+
+        let oracle = GasPriceOracle::new(Duration::from_secs(60));
+        oracle.store(quote.clone());
+        assert_eq!(oracle.cached("mantra-1"), Some(quote));
+        assert_eq!(oracle.cached("mantra-dukong-1"), None);
+        oracle.invalidate("mantra-1");
+        assert_eq!(oracle.cached("mantra-1"), None);
+
+        let expired = GasPriceOracle::new(Duration::ZERO);
+        expired.store(busy);
+        assert_eq!(expired.cached("mantra-1"), None);
+    }
+}
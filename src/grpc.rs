@@ -0,0 +1,331 @@
+//! gRPC query transport
+//!
+//! Nodes serve the bank, wasm, auth and tx queries over gRPC (usually port
+//! 9090) as well as over Tendermint RPC's `abci_query`. gRPC takes pagination
+//! keys, so [`GrpcClient`] can walk every balance or every key of a
+//! contract's state a page at a time, as a stream, and it skips the JSON and
+//! hex round trip RPC puts large contract state responses through.
+//!
+//! A network opts in by setting `grpc_url`. Clients then try gRPC first and
+//! fall back to RPC when the gRPC endpoint is unreachable, which
+//! [`falls_back`] tells apart from a query the node rejected. `https://`
+//! endpoints need the `grpc-tls` feature.
+
+use std::time::{Duration, Instant};
+
+use cosmos_sdk_proto::cosmos::auth::v1beta1::{
+    query_client::QueryClient as AuthQueryClient, BaseAccount, QueryAccountRequest,
+};
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+    query_client::QueryClient as BankQueryClient, QueryAllBalancesRequest,
+};
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::cosmos::base::query::v1beta1::{
+    PageRequest as ProtoPageRequest, PageResponse,
+};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{
+    service_client::ServiceClient as TxServiceClient, GetTxRequest, SimulateRequest,
+    SimulateResponse,
+};
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    query_client::QueryClient as WasmQueryClient, QueryAllContractStateRequest,
+    QuerySmartContractStateRequest,
+};
+use cosmwasm_std::{Coin, Uint128};
+use futures::{Stream, TryStreamExt};
+use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Code, Status};
+
+use crate::error::Error;
+use crate::pagination::{Page, PageRequest, Paginator, DEFAULT_PAGE_SIZE};
+use crate::trace;
+
+/// Time a single gRPC call may take
+const GRPC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Key and value of one contract state entry
+pub type StateEntry = (Vec<u8>, Vec<u8>);
+
+/// Whether a failed gRPC call should be retried over RPC
+///
+/// Only an unreachable or unsupporting gRPC endpoint falls back; a query the
+/// node rejected would fail the same way over RPC.
+pub fn falls_back(error: &Error) -> bool {
+    matches!(error, Error::Network(_))
+}
+
+/// gRPC query client of one node
+#[derive(Debug, Clone)]
+pub struct GrpcClient {
+    url: String,
+    channel: Channel,
+}
+
+impl GrpcClient {
+    /// Client of the gRPC endpoint at `url`, connecting on first use
+    ///
+    /// Must be called within a tokio runtime.
+    pub fn new(url: &str) -> Result<Self, Error> {
+        let endpoint = Endpoint::from_shared(url.to_string())
+            .map_err(|e| Error::Config(format!("Invalid gRPC endpoint {}: {}", url, e)))?
+            .timeout(GRPC_TIMEOUT);
+
+        #[cfg(feature = "grpc-tls")]
+        let endpoint = if url.starts_with("https://") {
+            endpoint
+                .tls_config(tonic::transport::ClientTlsConfig::new().with_native_roots())
+                .map_err(|e| Error::Config(format!("Invalid TLS for {}: {}", url, e)))?
+        } else {
+            endpoint
+        };
+        #[cfg(not(feature = "grpc-tls"))]
+        if url.starts_with("https://") {
+            return Err(Error::Config(format!(
+                "gRPC endpoint {} uses TLS, which needs the grpc-tls feature",
+                url
+            )));
+        }
+
+        Ok(Self {
+            url: url.to_string(),
+            channel: endpoint.connect_lazy(),
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Record the call in the current trace and turn its status into an error
+    fn finish<T>(
+        &self,
+        method: &str,
+        started: Instant,
+        result: Result<tonic::Response<T>, Status>,
+    ) -> Result<T, Error> {
+        let result = result
+            .map(tonic::Response::into_inner)
+            .map_err(|status| status_error(method, status));
+        trace::record_rpc(
+            method,
+            &self.url,
+            started,
+            result.as_ref().err().map(|e| e.to_string()),
+        );
+        result
+    }
+
+    // ============ Bank ============
+
+    /// One page of the balances of `address`
+    pub async fn balances_page(
+        &self,
+        address: &str,
+        page: PageRequest<Vec<u8>>,
+    ) -> Result<Page<Coin, Vec<u8>>, Error> {
+        let request = QueryAllBalancesRequest {
+            address: address.to_string(),
+            pagination: Some(proto_page(&page)),
+            resolve_denom: false,
+        };
+        let started = Instant::now();
+        let result = BankQueryClient::new(self.channel.clone())
+            .all_balances(request)
+            .await;
+        let response = self.finish("grpc/bank/AllBalances", started, result)?;
+        let items = response
+            .balances
+            .into_iter()
+            .map(|coin| Coin {
+                denom: coin.denom,
+                amount: coin.amount.parse().unwrap_or(Uint128::zero()),
+            })
+            .collect();
+        Ok(Page {
+            items,
+            next_cursor: next_key(response.pagination),
+        })
+    }
+
+    /// Every balance of `address`
+    pub async fn all_balances(&self, address: &str) -> Result<Vec<Coin>, Error> {
+        Paginator::new(DEFAULT_PAGE_SIZE * 4)
+            .stream(|page| self.balances_page(address, page))
+            .try_collect()
+            .await
+    }
+
+    // ============ Wasm ============
+
+    /// Run a smart query against `contract_addr`
+    pub async fn smart_query<Q: Serialize, R: DeserializeOwned>(
+        &self,
+        contract_addr: &str,
+        query_msg: &Q,
+    ) -> Result<R, Error> {
+        let request = QuerySmartContractStateRequest {
+            address: contract_addr.to_string(),
+            query_data: serde_json::to_vec(query_msg)?,
+        };
+        let started = Instant::now();
+        let result = WasmQueryClient::new(self.channel.clone())
+            .smart_contract_state(request)
+            .await;
+        let response = self
+            .finish("grpc/wasm/SmartContractState", started, result)
+            .map_err(|e| match e {
+                Error::Rpc(message) => {
+                    Error::Contract(format!("Contract query failed: {}", message))
+                }
+                other => other,
+            })?;
+        serde_json::from_slice(&response.data).map_err(Into::into)
+    }
+
+    /// One page of the raw state of `contract_addr`
+    pub async fn contract_state_page(
+        &self,
+        contract_addr: &str,
+        page: PageRequest<Vec<u8>>,
+    ) -> Result<Page<StateEntry, Vec<u8>>, Error> {
+        let request = QueryAllContractStateRequest {
+            address: contract_addr.to_string(),
+            pagination: Some(proto_page(&page)),
+        };
+        let started = Instant::now();
+        let result = WasmQueryClient::new(self.channel.clone())
+            .all_contract_state(request)
+            .await;
+        let response = self.finish("grpc/wasm/AllContractState", started, result)?;
+        Ok(Page {
+            items: response
+                .models
+                .into_iter()
+                .map(|model| (model.key, model.value))
+                .collect(),
+            next_cursor: next_key(response.pagination),
+        })
+    }
+
+    /// Every entry of the raw state of `contract_addr`, fetched `page_size`
+    /// at a time as the stream is polled
+    pub fn contract_state_stream<'a>(
+        &'a self,
+        contract_addr: &'a str,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<StateEntry, Error>> + 'a {
+        Paginator::new(page_size).stream(move |page| self.contract_state_page(contract_addr, page))
+    }
+
+    // ============ Auth ============
+
+    /// Account number and sequence of `address`
+    pub async fn account(&self, address: &str) -> Result<BaseAccount, Error> {
+        let request = QueryAccountRequest {
+            address: address.to_string(),
+        };
+        let started = Instant::now();
+        let result = AuthQueryClient::new(self.channel.clone())
+            .account(request)
+            .await;
+        let account = self
+            .finish("grpc/auth/Account", started, result)?
+            .account
+            .ok_or_else(|| Error::Rpc(format!("Account {} not found", address)))?;
+        BaseAccount::decode(account.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode BaseAccount: {}", e)))
+    }
+
+    // ============ Tx ============
+
+    /// A transaction and its result by hash
+    pub async fn get_tx(&self, tx_hash: &str) -> Result<TxResponse, Error> {
+        let request = GetTxRequest {
+            hash: tx_hash.trim_start_matches("0x").to_uppercase(),
+        };
+        let started = Instant::now();
+        let result = TxServiceClient::new(self.channel.clone())
+            .get_tx(request)
+            .await;
+        self.finish("grpc/tx/GetTx", started, result)?
+            .tx_response
+            .ok_or_else(|| Error::Rpc(format!("Transaction {} not found", tx_hash)))
+    }
+
+    /// Gas a signed transaction would use
+    pub async fn simulate(&self, tx_bytes: Vec<u8>) -> Result<SimulateResponse, Error> {
+        #[allow(deprecated)]
+        let request = SimulateRequest { tx: None, tx_bytes };
+        let started = Instant::now();
+        let result = TxServiceClient::new(self.channel.clone())
+            .simulate(request)
+            .await;
+        self.finish("grpc/tx/Simulate", started, result)
+    }
+}
+
+fn proto_page(page: &PageRequest<Vec<u8>>) -> ProtoPageRequest {
+    ProtoPageRequest {
+        key: page.cursor.clone().unwrap_or_default(),
+        offset: 0,
+        limit: page.limit as u64,
+        count_total: false,
+        reverse: false,
+    }
+}
+
+/// Cursor of the next page; nodes send an empty key after the last one
+fn next_key(pagination: Option<PageResponse>) -> Option<Vec<u8>> {
+    pagination
+        .map(|pagination| pagination.next_key)
+        .filter(|key| !key.is_empty())
+}
+
+/// Error of a failed gRPC call; unreachable endpoints and nodes without the
+/// service become [`Error::Network`], everything else [`Error::Rpc`]
+fn status_error(method: &str, status: Status) -> Error {
+    match status.code() {
+        Code::Unavailable
+        | Code::DeadlineExceeded
+        | Code::Cancelled
+        | Code::ResourceExhausted
+        | Code::Unimplemented => Error::Network(format!("{} failed: {}", method, status.message())),
+        _ => Error::Rpc(status.message().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unreachable_endpoints_fall_back_to_rpc() {
+        assert!(GrpcClient::new("not a url").is_err());
+        #[cfg(not(feature = "grpc-tls"))]
+        assert!(GrpcClient::new("https://grpc.example.com:443")
+            .unwrap_err()
+            .to_string()
+            .contains("grpc-tls"));
+
+        // Nothing listens on the discard port, so the call cannot connect
+        let client = GrpcClient::new("http://127.0.0.1:9").unwrap();
+        let err = client.all_balances("mantra1abc").await.unwrap_err();
+        assert!(falls_back(&err), "{}", err);
+
+        let rejected = status_error(
+            "grpc/wasm/SmartContractState",
+            Status::unknown("no such pool"),
+        );
+        assert!(!falls_back(&rejected));
+        assert_eq!(
+            next_key(Some(PageResponse {
+                next_key: Vec::new(),
+                total: 0
+            })),
+            None
+        );
+        assert_eq!(proto_page(&PageRequest::after(vec![1], 50)).key, vec![1]);
+    }
+}
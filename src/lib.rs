@@ -1,23 +1,91 @@
+// Browser builds (wasm32-unknown-unknown) query over the Fetch API; modules
+// that need tokio's runtime, the filesystem or the Tendermint RPC client are
+// native only
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("wasm32 targets need the `wasm` feature");
+
+pub mod amount;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod chain_info;
 pub mod client;
 pub mod config;
+pub mod denom_metadata;
+pub mod endpoints;
 pub mod error;
+pub mod events;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fanout;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod faucet;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fees;
+pub mod gas_price;
+pub mod pagination;
+pub mod performance;
+pub mod portfolio;
 pub mod protocols;
+pub mod reorg;
+pub mod retry;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod signer_queue;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod signing_log;
+pub mod trace;
+pub mod transport;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tx_history;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tx_queue;
+pub mod tx_tag;
 pub mod wallet;
 
+// gRPC query transport - optional via "grpc" feature
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
 // DEX TUI module - optional via "tui-dex" feature
 #[cfg(feature = "tui-dex")]
 pub mod tui_dex;
 
 // MCP module - optional via "mcp" feature
-#[cfg(feature = "mcp")]
+#[cfg(all(feature = "mcp", not(target_arch = "wasm32")))]
 pub mod mcp;
+
+// Python bindings - optional via "python" feature; pyo3 0.22's macros expand
+// a `gil-refs` feature check and `PyErr` conversions into this crate
+#[cfg(feature = "python")]
+#[allow(unexpected_cfgs, clippy::useless_conversion)]
+pub mod python;
+
+// Mock chain and fixtures for tests - optional via "testing" feature
+#[cfg(feature = "testing")]
+pub mod testing;
 // Re-export mantra-dex-std for user convenience
 pub use mantra_dex_std;
 
 // Main client exports
 pub use client::{ConfigurationChanges, MantraClient, MantraClientBuilder};
 pub use config::{MantraNetworkConfig, NetworkConstants};
+pub use denom_metadata::{DenomMetadata, DenomMetadataService, DenomSource};
+pub use endpoints::{CircuitBreakerConfig, CircuitState, EndpointHealth, EndpointPool};
 pub use error::Error;
+#[cfg(feature = "events")]
+pub use events::EventSubscriber;
+pub use events::{ChainEvent, ContractEvent, EventFilter};
+#[cfg(not(target_arch = "wasm32"))]
+pub use fanout::{FanoutReport, NetworkFanout};
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcClient;
+pub use pagination::{Page, PageRequest, Paginator};
+pub use portfolio::{Portfolio, PortfolioCategory, PortfolioEntry, UsdPrices};
+pub use retry::{Classify, ErrorClass, RetryPolicy};
+pub use trace::{TraceContext, TraceEvent, TraceEventKind};
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub use transport::FetchTransport;
+pub use transport::{AbciResponse, AbciTransport};
+#[cfg(not(target_arch = "wasm32"))]
+pub use tx_history::{TxHistory, TxHistoryEntry};
+pub use tx_tag::TxTagging;
 pub use wallet::MantraWallet;
 
 // Protocol exports
@@ -37,9 +105,10 @@ pub use protocols::skip::{
 // ClaimDrop protocol exports
 pub use protocols::claimdrop::{
     AggregatedRewards, Allocation, AllocationsResponse, BlacklistAction, CampaignAction,
-    CampaignInfo, CampaignParams, CampaignStats, CampaignsResponse, ClaimParams, ClaimdropClient,
-    ClaimdropFactoryClient, ClaimdropOperationResult, ClaimdropProtocol, UserRewards,
-    UserRewardsResponse,
+    CampaignBuilder, CampaignInfo, CampaignParams, CampaignStats, CampaignsResponse, ClaimParams,
+    ClaimdropClient, ClaimdropFactoryClient, ClaimdropOperationResult, ClaimdropProtocol,
+    EligibilityAttestation, GlobalCampaignStats, MerkleProof, MerkleTree, SignedAttestation,
+    UserRewards, UserRewardsResponse, VestingSchedule,
 };
 
 // Re-export DEX TUI entry point when feature is enabled
@@ -47,7 +116,7 @@ pub use protocols::claimdrop::{
 pub use tui_dex::run_tui;
 
 // Re-export MCP server types when feature is enabled
-#[cfg(feature = "mcp")]
+#[cfg(all(feature = "mcp", not(target_arch = "wasm32")))]
 pub use mcp::{
     create_http_server, create_mcp_server, create_stdio_server, ConnectionPoolConfig,
     MantraDexMcpServer, McpResult, McpSdkAdapter, McpServerConfig, McpServerError, MCP_SERVER_NAME,
@@ -0,0 +1,438 @@
+//! Operator Approval of Write Tool Calls
+//!
+//! When approval is required, a write tool call is parked in the
+//! [`ApprovalQueue`] until an operator approves or rejects it, or until the
+//! approval timeout expires. Operators act on the queue over the remote control
+//! channel, so a headless server can keep an agent running while a human
+//! signs off on every transaction it wants to broadcast.
+//!
+//! Calls of the tools listed in the [`TransactionQueueConfig`] are not held
+//! open. They are simulated, parked in the [`TransactionQueue`] with the
+//! simulation as their summary, and the caller gets the proposal id right
+//! away. The transaction is only broadcast once someone approves it with the
+//! `approve_transaction` tool.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+use super::authz::matches_pattern;
+use super::server::{McpResult, McpServerError};
+
+/// How long a write call waits for an operator by default
+pub const DEFAULT_APPROVAL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// A write call waiting for an operator
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingApproval {
+    pub id: String,
+    pub tool: String,
+    pub session_id: String,
+    pub arguments: Value,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// Operator decision on a pending call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalDecision {
+    Approved { operator: String },
+    Rejected { operator: String, reason: String },
+}
+
+type PendingEntry = (PendingApproval, oneshot::Sender<ApprovalDecision>);
+
+/// Write calls waiting for an operator decision
+#[derive(Debug)]
+pub struct ApprovalQueue {
+    required: bool,
+    timeout: Duration,
+    pending: Mutex<HashMap<String, PendingEntry>>,
+}
+
+/// Removes the entry if the waiting call is dropped before a decision
+struct PendingGuard<'a> {
+    queue: &'a ApprovalQueue,
+    id: String,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.queue.take(&self.id);
+    }
+}
+
+impl ApprovalQueue {
+    /// Create a queue; when `required` is unset, calls are admitted immediately
+    pub fn new(required: bool, timeout: Duration) -> Self {
+        Self {
+            required,
+            timeout,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether write calls wait for an operator
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    fn take(&self, id: &str) -> Option<PendingEntry> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(id)
+    }
+
+    /// Wait until an operator approves the call
+    ///
+    /// Returns `McpServerError::ApprovalRejected` if the operator rejects it or
+    /// no decision arrives within the timeout.
+    pub async fn await_approval(
+        &self,
+        tool: &str,
+        session_id: &str,
+        arguments: &Value,
+    ) -> McpResult<()> {
+        if !self.required {
+            return Ok(());
+        }
+
+        let approval = PendingApproval {
+            id: Uuid::new_v4().to_string(),
+            tool: tool.to_string(),
+            session_id: session_id.to_string(),
+            arguments: arguments.clone(),
+            requested_at: Utc::now(),
+        };
+        let id = approval.id.clone();
+        let (sender, receiver) = oneshot::channel();
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(id.clone(), (approval, sender));
+        let _guard = PendingGuard { queue: self, id };
+
+        match tokio::time::timeout(self.timeout, receiver).await {
+            Ok(Ok(ApprovalDecision::Approved { .. })) => Ok(()),
+            Ok(Ok(ApprovalDecision::Rejected { operator, reason })) => {
+                Err(McpServerError::ApprovalRejected(format!(
+                    "{} rejected by operator {}: {}",
+                    tool, operator, reason
+                )))
+            }
+            Ok(Err(_)) => Err(McpServerError::ApprovalRejected(format!(
+                "{} was dropped from the approval queue",
+                tool
+            ))),
+            Err(_) => Err(McpServerError::ApprovalRejected(format!(
+                "{} was not approved within {}s",
+                tool,
+                self.timeout.as_secs()
+            ))),
+        }
+    }
+
+    /// Calls waiting for a decision, oldest first
+    pub fn list(&self) -> Vec<PendingApproval> {
+        let pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        let mut approvals: Vec<_> = pending
+            .values()
+            .map(|(approval, _)| approval.clone())
+            .collect();
+        approvals.sort_by_key(|approval| approval.requested_at);
+        approvals
+    }
+
+    /// Hand a decision to a waiting call
+    pub fn resolve(&self, id: &str, decision: ApprovalDecision) -> McpResult<PendingApproval> {
+        let (approval, sender) = self.take(id).ok_or_else(|| {
+            McpServerError::InvalidArguments(format!("No pending approval with id {}", id))
+        })?;
+        // The waiting call may have timed out in the meantime; nothing to undo then
+        let _ = sender.send(decision);
+        Ok(approval)
+    }
+
+    /// Approval queue snapshot for health and status output
+    pub fn snapshot(&self) -> Value {
+        serde_json::json!({
+            "required": self.required,
+            "timeout_secs": self.timeout.as_secs(),
+            "pending": self.pending.lock().unwrap_or_else(|e| e.into_inner()).len(),
+        })
+    }
+}
+
+impl Default for ApprovalQueue {
+    fn default() -> Self {
+        Self::new(false, DEFAULT_APPROVAL_TIMEOUT)
+    }
+}
+
+/// Write tools proposed for approval instead of executed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionQueueConfig {
+    /// Tools whose calls are queued; a trailing `*` matches a prefix
+    #[serde(default)]
+    pub tools: Vec<String>,
+    /// Seconds a proposal stays open before it expires
+    #[serde(default = "default_proposal_ttl_secs")]
+    pub ttl_secs: u64,
+    /// Authenticated HTTP clients allowed to approve or reject proposals;
+    /// anyone who may call the approval tools when empty
+    #[serde(default)]
+    pub approvers: Vec<String>,
+}
+
+fn default_proposal_ttl_secs() -> u64 {
+    3600
+}
+
+impl Default for TransactionQueueConfig {
+    fn default() -> Self {
+        Self {
+            tools: Vec::new(),
+            ttl_secs: default_proposal_ttl_secs(),
+            approvers: Vec::new(),
+        }
+    }
+}
+
+/// A write call waiting in the transaction queue
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingTransaction {
+    pub id: String,
+    pub tool: String,
+    pub session_id: String,
+    /// Authenticated client that proposed the call, if any
+    pub proposed_by: Option<String>,
+    /// Tool arguments without `_meta`
+    pub arguments: Value,
+    /// What the call does, including the result of simulating it
+    pub summary: Value,
+    pub proposed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Proposed write calls waiting for approval
+#[derive(Debug, Default)]
+pub struct TransactionQueue {
+    config: TransactionQueueConfig,
+    pending: Mutex<HashMap<String, PendingTransaction>>,
+}
+
+impl TransactionQueue {
+    pub fn new(config: TransactionQueueConfig) -> Self {
+        Self {
+            config,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Queue configuration
+    pub fn config(&self) -> &TransactionQueueConfig {
+        &self.config
+    }
+
+    /// Whether calls of `tool` are proposed instead of executed
+    pub fn queues(&self, tool: &str) -> bool {
+        self.config
+            .tools
+            .iter()
+            .any(|pattern| matches_pattern(pattern, tool))
+    }
+
+    /// Park a call until it is approved, rejected or expires
+    pub fn propose(
+        &self,
+        tool: &str,
+        session_id: &str,
+        proposed_by: Option<String>,
+        arguments: Value,
+        summary: Value,
+    ) -> PendingTransaction {
+        let proposed_at = Utc::now();
+        let transaction = PendingTransaction {
+            id: Uuid::new_v4().to_string(),
+            tool: tool.to_string(),
+            session_id: session_id.to_string(),
+            proposed_by,
+            arguments,
+            summary,
+            proposed_at,
+            expires_at: proposed_at + chrono::Duration::seconds(self.config.ttl_secs as i64),
+        };
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.retain(|_, transaction| transaction.expires_at > proposed_at);
+        pending.insert(transaction.id.clone(), transaction.clone());
+        transaction
+    }
+
+    /// Open proposals, oldest first
+    pub fn list(&self) -> Vec<PendingTransaction> {
+        let now = Utc::now();
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.retain(|_, transaction| transaction.expires_at > now);
+        let mut transactions: Vec<_> = pending.values().cloned().collect();
+        transactions.sort_by_key(|transaction| transaction.proposed_at);
+        transactions
+    }
+
+    /// Remove proposal `id` so `decided_by` can approve or reject it
+    ///
+    /// Fails if the proposal is unknown or expired, or if approvers are
+    /// configured and `decided_by` is not one of them.
+    pub fn take(&self, id: &str, decided_by: Option<&str>) -> McpResult<PendingTransaction> {
+        if !self.config.approvers.is_empty()
+            && !decided_by.is_some_and(|name| self.config.approvers.iter().any(|a| a == name))
+        {
+            return Err(McpServerError::AuthorizationDenied(format!(
+                "'{}' may not decide on queued transactions",
+                decided_by.unwrap_or("unauthenticated client")
+            )));
+        }
+
+        let transaction = self
+            .pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(id)
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments(format!("No queued transaction with id {}", id))
+            })?;
+        if transaction.expires_at <= Utc::now() {
+            return Err(McpServerError::ApprovalRejected(format!(
+                "Queued transaction {} expired at {}",
+                id,
+                transaction.expires_at.to_rfc3339()
+            )));
+        }
+        Ok(transaction)
+    }
+
+    /// Queue snapshot for health and status output
+    pub fn snapshot(&self) -> Value {
+        serde_json::json!({
+            "tools": self.config.tools,
+            "ttl_secs": self.config.ttl_secs,
+            "approvers": self.config.approvers.len(),
+            "pending": self.pending.lock().unwrap_or_else(|e| e.into_inner()).len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_operator_decisions_release_waiting_calls() {
+        let queue = Arc::new(ApprovalQueue::new(true, Duration::from_secs(5)));
+
+        let waiting = {
+            let queue = Arc::clone(&queue);
+            tokio::spawn(async move {
+                queue
+                    .await_approval("dex_execute_swap", "a", &serde_json::json!({}))
+                    .await
+            })
+        };
+        while queue.list().is_empty() {
+            tokio::task::yield_now().await;
+        }
+        let id = queue.list()[0].id.clone();
+        queue
+            .resolve(
+                &id,
+                ApprovalDecision::Rejected {
+                    operator: "op".to_string(),
+                    reason: "too large".to_string(),
+                },
+            )
+            .unwrap();
+
+        assert!(matches!(
+            waiting.await.unwrap(),
+            Err(McpServerError::ApprovalRejected(_))
+        ));
+        assert!(queue.list().is_empty());
+        assert!(queue
+            .resolve(
+                &id,
+                ApprovalDecision::Approved {
+                    operator: "op".to_string()
+                }
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_transaction_queue_approvers_and_expiry() {
+        let queue = TransactionQueue::new(TransactionQueueConfig {
+            tools: vec!["dex_*".to_string()],
+            ttl_secs: 60,
+            approvers: vec!["ops".to_string()],
+        });
+        assert!(queue.queues("dex_execute_swap"));
+        assert!(!queue.queues("skip_execute_transfer"));
+
+        let proposed = queue.propose(
+            "dex_execute_swap",
+            "a",
+            Some("agent".to_string()),
+            serde_json::json!({"pool_id": "p1"}),
+            serde_json::json!({}),
+        );
+        assert_eq!(queue.list().len(), 1);
+
+        // Only listed approvers decide, and a refused attempt leaves it queued
+        assert!(matches!(
+            queue.take(&proposed.id, Some("agent")),
+            Err(McpServerError::AuthorizationDenied(_))
+        ));
+        assert!(matches!(
+            queue.take(&proposed.id, None),
+            Err(McpServerError::AuthorizationDenied(_))
+        ));
+        assert_eq!(
+            queue.take(&proposed.id, Some("ops")).unwrap().tool,
+            "dex_execute_swap"
+        );
+        assert!(queue.list().is_empty());
+        assert!(queue.take(&proposed.id, Some("ops")).is_err());
+
+        let expiring = TransactionQueue::new(TransactionQueueConfig {
+            tools: vec!["*".to_string()],
+            ttl_secs: 0,
+            approvers: Vec::new(),
+        });
+        let proposed = expiring.propose("dex_zap_in", "a", None, Value::Null, Value::Null);
+        assert!(matches!(
+            expiring.take(&proposed.id, None),
+            Err(McpServerError::ApprovalRejected(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unanswered_calls_time_out() {
+        let queue = ApprovalQueue::new(true, Duration::from_millis(10));
+        let result = queue
+            .await_approval("dex_zap_in", "a", &serde_json::json!({}))
+            .await;
+        assert!(matches!(result, Err(McpServerError::ApprovalRejected(_))));
+        assert!(queue.list().is_empty());
+
+        // Nothing is parked when approval is not required
+        ApprovalQueue::default()
+            .await_approval("dex_zap_in", "a", &serde_json::json!({}))
+            .await
+            .unwrap();
+    }
+}
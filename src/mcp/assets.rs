@@ -0,0 +1,309 @@
+//! Asset Resolution Across Cosmos and EVM
+//!
+//! Agents name assets by symbol ("USDC") while tools take chain-specific
+//! identifiers: a native denom, an `ibc/` hash, a `factory/` denom or an ERC-20
+//! address. An [`AssetResolver`] indexes the assets known to the registries
+//! (the network's native token, tokens traded in pools, Skip's asset lists and
+//! the ERC-20 registry) and maps any of these representations to every matching
+//! asset, with its decimals where known.
+//!
+//! A query can be a symbol (case-insensitive), a denom, an ERC-20 address or
+//! an IBC path such as `transfer/channel-0/uusdc`, which is hashed to the
+//! `ibc/` denom it arrives as.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::response_format::MarkdownRender;
+
+/// How an asset is represented on its chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    /// Bank denom issued by the chain itself, e.g. `uom`
+    Native,
+    /// `ibc/<hash>` voucher of an asset from another chain
+    Ibc,
+    /// `factory/<creator>/<subdenom>` token factory denom
+    Factory,
+    /// CW20 contract token
+    Cw20,
+    /// ERC-20 contract on an EVM chain
+    Erc20,
+}
+
+impl AssetKind {
+    /// Kind of a Cosmos bank denom, from its prefix
+    pub fn of_denom(denom: &str) -> Self {
+        if denom.starts_with("ibc/") {
+            Self::Ibc
+        } else if denom.starts_with("factory/") {
+            Self::Factory
+        } else if denom.starts_with("cw20:") {
+            Self::Cw20
+        } else {
+            Self::Native
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Native => "native",
+            Self::Ibc => "ibc",
+            Self::Factory => "factory",
+            Self::Cw20 => "cw20",
+            Self::Erc20 => "erc20",
+        }
+    }
+}
+
+/// One representation of an asset on one chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetRecord {
+    pub symbol: String,
+    pub kind: AssetKind,
+    /// Cosmos chain ID, or the EVM chain ID in decimal for ERC-20 tokens
+    pub chain_id: String,
+    /// Bank denom; unset for ERC-20 tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denom: Option<String>,
+    /// ERC-20 or CW20 contract address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /// IBC path and base denom of an `ibc/` denom
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ibc_trace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
+    /// Registry the record came from: "network", "pools", "skip" or "erc20_registry"
+    pub source: String,
+}
+
+impl AssetRecord {
+    /// Identifier of the asset on its chain, used to merge duplicate records
+    fn key(&self) -> (String, String) {
+        let id = self
+            .denom
+            .clone()
+            .or_else(|| self.address.as_ref().map(|a| a.to_lowercase()))
+            .unwrap_or_default();
+        (self.chain_id.clone(), id)
+    }
+}
+
+/// `ibc/<HASH>` denom an asset arrives as over the IBC path `trace`,
+/// e.g. `transfer/channel-0/uusdc`
+pub fn ibc_denom(trace: &str) -> String {
+    format!(
+        "ibc/{}",
+        hex::encode_upper(Sha256::digest(trace.as_bytes()))
+    )
+}
+
+/// Index of known assets, searchable by any of their representations
+#[derive(Debug, Clone, Default)]
+pub struct AssetResolver {
+    records: BTreeMap<(String, String), AssetRecord>,
+}
+
+impl AssetResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `record`, completing a record of the same asset added before
+    ///
+    /// Fields the earlier record lacks are taken from `record`; a symbol that
+    /// is only the denom is replaced by a real one.
+    pub fn add(&mut self, record: AssetRecord) {
+        match self.records.get_mut(&record.key()) {
+            Some(existing) => {
+                if existing.denom.as_deref() == Some(existing.symbol.as_str())
+                    && record.denom.as_deref() != Some(record.symbol.as_str())
+                {
+                    existing.symbol = record.symbol;
+                }
+                existing.address = existing.address.take().or(record.address);
+                existing.ibc_trace = existing.ibc_trace.take().or(record.ibc_trace);
+                existing.decimals = existing.decimals.or(record.decimals);
+            }
+            None => {
+                self.records.insert(record.key(), record);
+            }
+        }
+    }
+
+    /// Add a Cosmos bank denom
+    pub fn add_denom(
+        &mut self,
+        chain_id: &str,
+        denom: &str,
+        symbol: Option<&str>,
+        decimals: Option<u8>,
+        source: &str,
+    ) {
+        self.add(AssetRecord {
+            symbol: symbol.unwrap_or(denom).to_string(),
+            kind: AssetKind::of_denom(denom),
+            chain_id: chain_id.to_string(),
+            denom: Some(denom.to_string()),
+            address: None,
+            ibc_trace: None,
+            decimals,
+            source: source.to_string(),
+        });
+    }
+
+    /// Add an ERC-20 token of EVM chain `chain_id`
+    pub fn add_erc20(&mut self, chain_id: u64, address: &str, symbol: &str, decimals: u8) {
+        self.add(AssetRecord {
+            symbol: symbol.to_string(),
+            kind: AssetKind::Erc20,
+            chain_id: chain_id.to_string(),
+            denom: None,
+            address: Some(address.to_string()),
+            ibc_trace: None,
+            decimals: Some(decimals),
+            source: "erc20_registry".to_string(),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Assets matching `query`, on `chain_id` only when given
+    ///
+    /// Symbols compare case-insensitively, as do `0x` addresses; denoms must
+    /// match exactly. A query containing `/` other than an `ibc/` denom is
+    /// also tried as an IBC path.
+    pub fn resolve(&self, query: &str, chain_id: Option<&str>) -> Vec<AssetRecord> {
+        let query = query.trim();
+        let traced = (query.contains('/') && AssetKind::of_denom(query) != AssetKind::Ibc)
+            .then(|| ibc_denom(query));
+
+        self.records
+            .values()
+            .filter(|record| chain_id.is_none_or(|chain_id| record.chain_id == chain_id))
+            .filter(|record| {
+                record.symbol.eq_ignore_ascii_case(query)
+                    || record.denom.as_deref() == Some(query)
+                    || record
+                        .address
+                        .as_deref()
+                        .is_some_and(|address| address.eq_ignore_ascii_case(query))
+                    || record.ibc_trace.as_deref() == Some(query)
+                    || traced.is_some() && record.denom == traced
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Result of the `resolve_asset` tool
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolveAssetResponse {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<String>,
+    pub matches: Vec<AssetRecord>,
+    /// `ibc/` denom of the query read as an IBC path, when it contains `/`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ibc_denom: Option<String>,
+    /// Registries that could not be read; matches from the others are still listed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl MarkdownRender for ResolveAssetResponse {
+    fn to_markdown(&self) -> String {
+        let mut text = format!("## Assets matching `{}`\n\n", self.query);
+        if let Some(ibc_denom) = &self.ibc_denom {
+            text.push_str(&format!("**As an IBC path:** `{}`\n\n", ibc_denom));
+        }
+        if self.matches.is_empty() {
+            text.push_str("No known asset matches.\n");
+        }
+        for record in &self.matches {
+            let id = record
+                .denom
+                .as_deref()
+                .or(record.address.as_deref())
+                .unwrap_or_default();
+            text.push_str(&format!(
+                "- **{}** ({}) on `{}`: `{}`",
+                record.symbol,
+                record.kind.as_str(),
+                record.chain_id,
+                id
+            ));
+            if let Some(decimals) = record.decimals {
+                text.push_str(&format!(", {} decimals", decimals));
+            }
+            if let Some(trace) = &record.ibc_trace {
+                text.push_str(&format!(", via `{}`", trace));
+            }
+            text.push('\n');
+        }
+        for warning in &self.warnings {
+            text.push_str(&format!("\n⚠️ {}", warning));
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolves_symbols_denoms_traces_and_addresses() {
+        let usdc_ibc = ibc_denom("transfer/channel-0/uusdc");
+        let mut resolver = AssetResolver::new();
+        resolver.add_denom("mantra-1", "uom", Some("OM"), Some(6), "network");
+        resolver.add_denom("mantra-1", &usdc_ibc, None, Some(6), "pools");
+        resolver.add(AssetRecord {
+            symbol: "USDC".to_string(),
+            kind: AssetKind::Ibc,
+            chain_id: "mantra-1".to_string(),
+            denom: Some(usdc_ibc.clone()),
+            address: None,
+            ibc_trace: Some("transfer/channel-0/uusdc".to_string()),
+            decimals: None,
+            source: "skip".to_string(),
+        });
+        resolver.add_erc20(
+            5888,
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "USDC",
+            6,
+        );
+        assert_eq!(resolver.len(), 3);
+
+        // The pool record is completed by Skip's symbol and trace
+        let usdc = resolver.resolve("usdc", None);
+        assert_eq!(usdc.len(), 2);
+        let ibc = usdc.iter().find(|r| r.kind == AssetKind::Ibc).unwrap();
+        assert_eq!(ibc.decimals, Some(6));
+        assert_eq!(ibc.source, "pools");
+
+        assert_eq!(resolver.resolve("USDC", Some("5888")).len(), 1);
+        assert_eq!(
+            resolver.resolve("transfer/channel-0/uusdc", None),
+            vec![ibc.clone()]
+        );
+        assert_eq!(resolver.resolve(&usdc_ibc, None), vec![ibc.clone()]);
+        assert_eq!(
+            resolver.resolve("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48", None)[0].kind,
+            AssetKind::Erc20
+        );
+        assert_eq!(resolver.resolve("uom", None)[0].symbol, "OM");
+        assert!(resolver.resolve("ATOM", None).is_empty());
+    }
+}
@@ -0,0 +1,448 @@
+//! Audit Log of State-Changing MCP Operations
+//!
+//! Every call of a write tool is recorded once it completes, whether it went
+//! through or was refused by a policy, quota or operator: the tool, a SHA-256
+//! digest of its arguments, the session and authenticated caller, the wallet,
+//! the transaction hash and the outcome. Arguments themselves are not stored,
+//! so mnemonics and other secrets passed to tools never reach the log.
+//!
+//! A log opened with a path appends one JSON record per line. When the file
+//! would grow past `max_file_bytes` it is rotated to `<path>.1`, older files
+//! shift up by one and the oldest beyond `max_files` is deleted. The most
+//! recent records are also kept in memory for [`AuditLog::query`].
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::error::Error;
+
+/// Records kept in memory for queries
+pub const MAX_MEMORY_RECORDS: usize = 10_000;
+
+/// Audit log settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogConfig {
+    /// JSONL file the log is written to; kept in memory only when unset
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Size at which the file is rotated
+    #[serde(default = "default_max_file_bytes")]
+    pub max_file_bytes: u64,
+    /// Rotated files kept besides the current one
+    #[serde(default = "default_max_files")]
+    pub max_files: usize,
+}
+
+fn default_max_file_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+fn default_max_files() -> usize {
+    5
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            path: None,
+            max_file_bytes: default_max_file_bytes(),
+            max_files: default_max_files(),
+        }
+    }
+}
+
+/// How an audited call ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+/// One audited tool call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Position in the log, starting at 1 and continuing across rotations
+    pub sequence: u64,
+    pub timestamp: DateTime<Utc>,
+    pub tool: String,
+    /// Hex SHA-256 of the arguments without `_meta`
+    pub args_sha256: String,
+    pub session_id: String,
+    /// Authenticated HTTP caller, if any
+    pub principal: Option<String>,
+    pub wallet: Option<String>,
+    pub tx_hash: Option<String>,
+    pub dry_run: bool,
+    pub outcome: AuditOutcome,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Call to record; the log assigns the sequence and timestamp
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub tool: String,
+    pub args_sha256: String,
+    pub session_id: String,
+    pub principal: Option<String>,
+    pub wallet: Option<String>,
+    pub tx_hash: Option<String>,
+    pub dry_run: bool,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Filters of [`AuditLog::query`]; unset filters match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuditQuery {
+    pub tool: Option<String>,
+    pub wallet: Option<String>,
+    pub session_id: Option<String>,
+    pub principal: Option<String>,
+    pub outcome: Option<AuditOutcome>,
+    /// Only records after this sequence number
+    pub since_sequence: Option<u64>,
+    /// Only records at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Most records to return, newest first
+    pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+    fn matches(&self, record: &AuditRecord) -> bool {
+        fn eq(filter: &Option<String>, value: Option<&str>) -> bool {
+            filter.as_deref().is_none_or(|filter| Some(filter) == value)
+        }
+        eq(&self.tool, Some(&record.tool))
+            && eq(&self.wallet, record.wallet.as_deref())
+            && eq(&self.session_id, Some(&record.session_id))
+            && eq(&self.principal, record.principal.as_deref())
+            && self.outcome.is_none_or(|outcome| outcome == record.outcome)
+            && self
+                .since_sequence
+                .is_none_or(|sequence| record.sequence > sequence)
+            && self.since.is_none_or(|since| record.timestamp >= since)
+    }
+}
+
+/// Hex SHA-256 of `arguments` without the `_meta` object
+pub fn arguments_digest(arguments: &Value) -> String {
+    let mut arguments = arguments.clone();
+    if let Some(map) = arguments.as_object_mut() {
+        map.remove("_meta");
+    }
+    hex::encode(Sha256::digest(arguments.to_string().as_bytes()))
+}
+
+/// First transaction hash in a tool result
+///
+/// Tool results carry their payload as JSON text inside `content`, so text
+/// fields are parsed and searched as well.
+pub fn find_tx_hash(result: &Value) -> Option<String> {
+    match result {
+        Value::Object(map) => ["txhash", "tx_hash", "transaction_hash"]
+            .iter()
+            .find_map(|key| map.get(*key).and_then(|hash| hash.as_str()))
+            .filter(|hash| !hash.is_empty())
+            .map(str::to_string)
+            .or_else(|| map.values().find_map(find_tx_hash)),
+        Value::Array(items) => items.iter().find_map(find_tx_hash),
+        Value::String(text) if text.trim_start().starts_with('{') => {
+            serde_json::from_str::<Value>(text)
+                .ok()
+                .and_then(|parsed| find_tx_hash(&parsed))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Default)]
+struct AuditLogState {
+    recent: VecDeque<AuditRecord>,
+    next_sequence: u64,
+    file_bytes: u64,
+}
+
+/// Append-only audit log with size-based rotation
+#[derive(Debug)]
+pub struct AuditLog {
+    config: AuditLogConfig,
+    state: Mutex<AuditLogState>,
+}
+
+impl AuditLog {
+    /// Log kept in memory only
+    pub fn in_memory() -> Self {
+        Self {
+            config: AuditLogConfig::default(),
+            state: Mutex::new(AuditLogState {
+                next_sequence: 1,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Open the log configured in `config`, loading the current file's records
+    ///
+    /// Sequence numbers continue from the last record of the current file, or
+    /// of the newest rotated file when the current one is empty.
+    pub fn open(config: AuditLogConfig) -> Result<Self, Error> {
+        let Some(path) = config.path.clone() else {
+            return Ok(Self {
+                config,
+                ..Self::in_memory()
+            });
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let recent = read_records(&path)?;
+        let last_sequence = match recent.back() {
+            Some(record) => record.sequence,
+            None => read_records(&rotated_path(&path, 1))?
+                .back()
+                .map(|record| record.sequence)
+                .unwrap_or(0),
+        };
+        let file_bytes = fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+
+        Ok(Self {
+            config,
+            state: Mutex::new(AuditLogState {
+                recent,
+                next_sequence: last_sequence + 1,
+                file_bytes,
+            }),
+        })
+    }
+
+    /// Log configuration
+    pub fn config(&self) -> &AuditLogConfig {
+        &self.config
+    }
+
+    /// Append `entry`, rotating the file first if it would grow too large
+    pub fn record(&self, entry: AuditEntry) -> Result<AuditRecord, Error> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let record = AuditRecord {
+            sequence: state.next_sequence,
+            timestamp: Utc::now(),
+            outcome: if entry.error.is_some() {
+                AuditOutcome::Failure
+            } else {
+                AuditOutcome::Success
+            },
+            tool: entry.tool,
+            args_sha256: entry.args_sha256,
+            session_id: entry.session_id,
+            principal: entry.principal,
+            wallet: entry.wallet,
+            tx_hash: entry.tx_hash,
+            dry_run: entry.dry_run,
+            error: entry.error,
+            duration_ms: entry.duration_ms,
+        };
+
+        if let Some(path) = &self.config.path {
+            let mut line = serde_json::to_string(&record)?;
+            line.push('\n');
+            let line_bytes = line.len() as u64;
+            if state.file_bytes > 0 && state.file_bytes + line_bytes > self.config.max_file_bytes {
+                self.rotate(path)?;
+                state.file_bytes = 0;
+            }
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            file.write_all(line.as_bytes())?;
+            file.sync_data()?;
+            state.file_bytes += line_bytes;
+        }
+
+        state.next_sequence += 1;
+        if state.recent.len() == MAX_MEMORY_RECORDS {
+            state.recent.pop_front();
+        }
+        state.recent.push_back(record.clone());
+        Ok(record)
+    }
+
+    /// Shift `<path>.N` to `<path>.N+1`, dropping the oldest, then move the
+    /// current file to `<path>.1`
+    fn rotate(&self, path: &Path) -> Result<(), Error> {
+        if self.config.max_files == 0 {
+            fs::remove_file(path)?;
+            return Ok(());
+        }
+        let oldest = rotated_path(path, self.config.max_files);
+        if oldest.exists() {
+            fs::remove_file(&oldest)?;
+        }
+        for index in (1..self.config.max_files).rev() {
+            let from = rotated_path(path, index);
+            if from.exists() {
+                fs::rename(&from, rotated_path(path, index + 1))?;
+            }
+        }
+        fs::rename(path, rotated_path(path, 1))?;
+        Ok(())
+    }
+
+    /// Records in memory matching `query`, newest first
+    pub fn query(&self, query: &AuditQuery) -> Vec<AuditRecord> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .recent
+            .iter()
+            .rev()
+            .filter(|record| query.matches(record))
+            .take(query.limit.unwrap_or(100))
+            .cloned()
+            .collect()
+    }
+
+    /// Record counts and file details for health and diagnostics output
+    pub fn snapshot(&self) -> Value {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        serde_json::json!({
+            "path": self.config.path,
+            "records_in_memory": state.recent.len(),
+            "last_sequence": state.next_sequence.saturating_sub(1),
+            "file_bytes": state.file_bytes,
+            "max_file_bytes": self.config.max_file_bytes,
+            "max_files": self.config.max_files,
+        })
+    }
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{}", index));
+    PathBuf::from(rotated)
+}
+
+/// Last [`MAX_MEMORY_RECORDS`] records of the file at `path`
+///
+/// Lines that do not parse, such as a write torn by a crash, are skipped.
+fn read_records(path: &Path) -> Result<VecDeque<AuditRecord>, Error> {
+    let mut records = VecDeque::new();
+    if !path.exists() {
+        return Ok(records);
+    }
+    for (index, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<AuditRecord>(&line) {
+            Ok(record) => {
+                if records.len() == MAX_MEMORY_RECORDS {
+                    records.pop_front();
+                }
+                records.push_back(record);
+            }
+            Err(e) => warn!(
+                "Skipping unreadable audit record on line {} of {}: {}",
+                index + 1,
+                path.display(),
+                e
+            ),
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(tool: &str, wallet: &str, error: Option<&str>) -> AuditEntry {
+        AuditEntry {
+            tool: tool.to_string(),
+            args_sha256: arguments_digest(&serde_json::json!({"pool_id": "p1"})),
+            session_id: "default".to_string(),
+            principal: None,
+            wallet: Some(wallet.to_string()),
+            tx_hash: None,
+            dry_run: false,
+            error: error.map(str::to_string),
+            duration_ms: 5,
+        }
+    }
+
+    #[test]
+    fn test_query_filters_newest_first() {
+        let log = AuditLog::in_memory();
+        log.record(entry("dex_execute_swap", "mantra1a", None))
+            .unwrap();
+        log.record(entry("dex_execute_swap", "mantra1b", Some("slippage")))
+            .unwrap();
+        log.record(entry("dex_zap_in", "mantra1a", None)).unwrap();
+
+        let swaps = log.query(&AuditQuery {
+            tool: Some("dex_execute_swap".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            swaps.iter().map(|r| r.sequence).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        let failures = log.query(&AuditQuery {
+            outcome: Some(AuditOutcome::Failure),
+            ..Default::default()
+        });
+        assert_eq!(failures[0].error.as_deref(), Some("slippage"));
+        let recent_a = log.query(&AuditQuery {
+            wallet: Some("mantra1a".to_string()),
+            since_sequence: Some(1),
+            ..Default::default()
+        });
+        assert_eq!(recent_a.len(), 1);
+        assert_eq!(recent_a[0].tool, "dex_zap_in");
+
+        // The digest ignores request metadata
+        assert_eq!(
+            arguments_digest(&serde_json::json!({"pool_id": "p1", "_meta": {"nonce": 4}})),
+            arguments_digest(&serde_json::json!({"pool_id": "p1"}))
+        );
+        let result =
+            serde_json::json!({"content": [{"type": "text", "text": "{\"tx_hash\": \"ABC\"}"}]});
+        assert_eq!(find_tx_hash(&result).as_deref(), Some("ABC"));
+    }
+
+    #[test]
+    fn test_rotation_keeps_sequence_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let config = AuditLogConfig {
+            path: Some(path.clone()),
+            max_file_bytes: 400,
+            max_files: 2,
+        };
+
+        let log = AuditLog::open(config.clone()).unwrap();
+        for _ in 0..8 {
+            log.record(entry("dex_execute_swap", "mantra1a", None))
+                .unwrap();
+        }
+        assert!(rotated_path(&path, 1).exists());
+        assert!(rotated_path(&path, 2).exists());
+        assert!(!rotated_path(&path, 3).exists());
+        assert!(fs::metadata(&path).unwrap().len() <= 400);
+
+        let reopened = AuditLog::open(config).unwrap();
+        let record = reopened
+            .record(entry("dex_zap_in", "mantra1a", None))
+            .unwrap();
+        assert_eq!(record.sequence, 9);
+    }
+}
@@ -0,0 +1,386 @@
+//! Authentication for the MCP HTTP Transport
+//!
+//! With authentication configured, every HTTP request must carry a static API
+//! key (`X-Api-Key: <key>` or `Authorization: Bearer <key>`) or an HS256 JSON
+//! Web Token (`Authorization: Bearer <jwt>`). Keys are held as SHA-256 digests,
+//! so the configuration file can list `key_sha256` instead of the key itself.
+//! Each key, and each token through its `tools` claim, may be limited to a set
+//! of tools; a trailing `*` matches a prefix.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Accepted API key
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiKeyEntry {
+    /// Name the key is audited under
+    pub name: String,
+    /// The key itself
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Hex SHA-256 digest of the key, instead of `key`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_sha256: Option<String>,
+    /// Tools the key may call, every tool when empty
+    #[serde(default)]
+    pub tools: Vec<String>,
+}
+
+/// HS256 JSON Web Token validation settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JwtConfig {
+    /// Shared HMAC secret
+    pub secret: String,
+    /// Required `iss` claim
+    #[serde(default)]
+    pub issuer: Option<String>,
+    /// Required `aud` claim
+    #[serde(default)]
+    pub audience: Option<String>,
+    /// Clock skew allowed on `exp` and `nbf`, in seconds
+    #[serde(default)]
+    pub leeway_secs: u64,
+}
+
+/// HTTP authentication configuration; off when no key or JWT secret is set
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpAuthConfig {
+    #[serde(default)]
+    pub api_keys: Vec<ApiKeyEntry>,
+    #[serde(default)]
+    pub jwt: Option<JwtConfig>,
+}
+
+impl HttpAuthConfig {
+    /// Whether requests must authenticate
+    pub fn is_enabled(&self) -> bool {
+        !self.api_keys.is_empty() || self.jwt.is_some()
+    }
+
+    /// Check every key names exactly one key form and the JWT secret is usable
+    pub fn validate(&self) -> Result<(), String> {
+        for entry in &self.api_keys {
+            if entry.name.trim().is_empty() {
+                return Err("API keys need a name".to_string());
+            }
+            match (&entry.key, &entry.key_sha256) {
+                (Some(key), None) if !key.is_empty() => {}
+                (None, Some(digest)) if decode_digest(digest).is_some() => {}
+                (None, Some(_)) => {
+                    return Err(format!(
+                        "API key '{}' has an invalid key_sha256; expected 64 hex characters",
+                        entry.name
+                    ))
+                }
+                _ => {
+                    return Err(format!(
+                        "API key '{}' must set exactly one of key or key_sha256",
+                        entry.name
+                    ))
+                }
+            }
+        }
+        if let Some(jwt) = &self.jwt {
+            if jwt.secret.len() < 32 {
+                return Err("JWT secret must be at least 32 bytes".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decode_digest(digest: &str) -> Option<[u8; 32]> {
+    hex::decode(digest.trim()).ok()?.try_into().ok()
+}
+
+/// How a caller authenticated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthMethod {
+    ApiKey,
+    Jwt,
+}
+
+/// Authenticated caller of the HTTP transport
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Principal {
+    /// Key name or JWT subject
+    pub name: String,
+    pub method: AuthMethod,
+    /// Tools the caller may call, every tool when empty
+    pub tools: Vec<String>,
+}
+
+impl Principal {
+    /// Whether the caller may call `tool_name`
+    pub fn may_call(&self, tool_name: &str) -> bool {
+        self.tools.is_empty()
+            || self
+                .tools
+                .iter()
+                .any(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => tool_name.starts_with(prefix),
+                    None => pattern == tool_name,
+                })
+    }
+}
+
+#[derive(Debug)]
+struct KnownKey {
+    name: String,
+    digest: [u8; 32],
+    tools: Vec<String>,
+}
+
+/// Validates the credentials of HTTP requests
+#[derive(Debug, Default)]
+pub struct HttpAuthenticator {
+    keys: Vec<KnownKey>,
+    jwt: Option<JwtConfig>,
+}
+
+impl HttpAuthenticator {
+    /// Create an authenticator; entries failing [`HttpAuthConfig::validate`] are skipped
+    pub fn new(config: &HttpAuthConfig) -> Self {
+        let keys = config
+            .api_keys
+            .iter()
+            .filter_map(|entry| {
+                let digest = match (&entry.key, &entry.key_sha256) {
+                    (Some(key), None) => Sha256::digest(key.as_bytes()).into(),
+                    (None, Some(digest)) => decode_digest(digest)?,
+                    _ => return None,
+                };
+                Some(KnownKey {
+                    name: entry.name.clone(),
+                    digest,
+                    tools: entry.tools.clone(),
+                })
+            })
+            .collect();
+        Self {
+            keys,
+            jwt: config.jwt.clone(),
+        }
+    }
+
+    /// Whether requests must authenticate
+    pub fn is_enabled(&self) -> bool {
+        !self.keys.is_empty() || self.jwt.is_some()
+    }
+
+    /// Caller identified by the `Authorization` or `X-Api-Key` header
+    ///
+    /// Returns the reason authentication failed.
+    pub fn authenticate(
+        &self,
+        authorization: Option<&str>,
+        api_key: Option<&str>,
+        now_secs: u64,
+    ) -> Result<Principal, String> {
+        let bearer = authorization
+            .map(str::trim)
+            .and_then(|value| {
+                value
+                    .strip_prefix("Bearer ")
+                    .or_else(|| value.strip_prefix("bearer "))
+            })
+            .map(str::trim);
+        let credential = api_key
+            .map(str::trim)
+            .or(bearer)
+            .filter(|credential| !credential.is_empty())
+            .ok_or_else(|| "Missing API key or bearer token".to_string())?;
+
+        if let Some(jwt) = &self.jwt {
+            if api_key.is_none() && credential.matches('.').count() == 2 {
+                return validate_jwt(jwt, credential, now_secs);
+            }
+        }
+
+        let digest: [u8; 32] = Sha256::digest(credential.as_bytes()).into();
+        self.keys
+            .iter()
+            .find(|key| key.digest == digest)
+            .map(|key| Principal {
+                name: key.name.clone(),
+                method: AuthMethod::ApiKey,
+                tools: key.tools.clone(),
+            })
+            .ok_or_else(|| "Unknown API key".to_string())
+    }
+}
+
+/// Check the signature and claims of an HS256 token
+fn validate_jwt(config: &JwtConfig, token: &str, now_secs: u64) -> Result<Principal, String> {
+    let mut parts = token.split('.');
+    let (Some(header), Some(claims), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err("Malformed token".to_string());
+    };
+    let decode = |part: &str| -> Result<Value, String> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(part)
+            .map_err(|_| "Malformed token".to_string())?;
+        serde_json::from_slice(&bytes).map_err(|_| "Malformed token".to_string())
+    };
+
+    if decode(header)?.get("alg").and_then(|alg| alg.as_str()) != Some("HS256") {
+        return Err("Only HS256 tokens are accepted".to_string());
+    }
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| "Malformed token".to_string())?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(config.secret.as_bytes())
+        .map_err(|e| format!("Invalid JWT secret: {}", e))?;
+    mac.update(format!("{}.{}", header, claims).as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| "Invalid token signature".to_string())?;
+
+    let claims = decode(claims)?;
+    let time_claim = |name: &str| claims.get(name).and_then(|value| value.as_u64());
+    match time_claim("exp") {
+        Some(exp) if now_secs > exp.saturating_add(config.leeway_secs) => {
+            return Err("Token has expired".to_string())
+        }
+        Some(_) => {}
+        None => return Err("Token has no exp claim".to_string()),
+    }
+    if time_claim("nbf").is_some_and(|nbf| now_secs.saturating_add(config.leeway_secs) < nbf) {
+        return Err("Token is not valid yet".to_string());
+    }
+    if let Some(issuer) = &config.issuer {
+        if claims.get("iss").and_then(|iss| iss.as_str()) != Some(issuer) {
+            return Err("Token has the wrong issuer".to_string());
+        }
+    }
+    if let Some(audience) = &config.audience {
+        let matches = match claims.get("aud") {
+            Some(Value::String(aud)) => aud == audience,
+            Some(Value::Array(auds)) => auds.iter().any(|aud| aud.as_str() == Some(audience)),
+            _ => false,
+        };
+        if !matches {
+            return Err("Token has the wrong audience".to_string());
+        }
+    }
+
+    Ok(Principal {
+        name: claims
+            .get("sub")
+            .and_then(|sub| sub.as_str())
+            .unwrap_or("jwt")
+            .to_string(),
+        method: AuthMethod::Jwt,
+        tools: claims
+            .get("tools")
+            .and_then(|tools| tools.as_array())
+            .map(|tools| {
+                tools
+                    .iter()
+                    .filter_map(|tool| tool.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn token(claims: Value) -> String {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(claims.to_string());
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(format!("{}.{}", header, claims).as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+        format!("{}.{}.{}", header, claims, signature)
+    }
+
+    #[test]
+    fn test_api_keys_by_value_and_digest() {
+        let config = HttpAuthConfig {
+            api_keys: vec![
+                ApiKeyEntry {
+                    name: "agent".to_string(),
+                    key: Some("agent-key".to_string()),
+                    tools: vec!["dex_get_*".to_string()],
+                    ..Default::default()
+                },
+                ApiKeyEntry {
+                    name: "ops".to_string(),
+                    key_sha256: Some(hex::encode(Sha256::digest(b"ops-key"))),
+                    ..Default::default()
+                },
+            ],
+            jwt: None,
+        };
+        assert!(config.validate().is_ok());
+        let auth = HttpAuthenticator::new(&config);
+
+        let agent = auth
+            .authenticate(Some("Bearer agent-key"), None, 0)
+            .unwrap();
+        assert_eq!(agent.name, "agent");
+        assert!(agent.may_call("dex_get_pools"));
+        assert!(!agent.may_call("dex_execute_swap"));
+
+        let ops = auth.authenticate(None, Some("ops-key"), 0).unwrap();
+        assert!(ops.may_call("primary_sale_cancel"));
+
+        assert!(auth.authenticate(Some("Bearer nope"), None, 0).is_err());
+        assert!(auth.authenticate(None, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_jwt_signature_and_claims() {
+        let auth = HttpAuthenticator::new(&HttpAuthConfig {
+            api_keys: vec![],
+            jwt: Some(JwtConfig {
+                secret: SECRET.to_string(),
+                issuer: Some("ops".to_string()),
+                audience: Some("mantra-mcp".to_string()),
+                leeway_secs: 0,
+            }),
+        });
+        let bearer = |claims: Value| format!("Bearer {}", token(claims));
+
+        let principal = auth
+            .authenticate(
+                Some(&bearer(serde_json::json!({
+                    "sub": "trader", "iss": "ops", "aud": ["mantra-mcp"],
+                    "exp": 2_000, "tools": ["dex_*"]
+                }))),
+                None,
+                1_000,
+            )
+            .unwrap();
+        assert_eq!(principal.name, "trader");
+        assert_eq!(principal.method, AuthMethod::Jwt);
+        assert!(!principal.may_call("wallet_switch"));
+
+        let expired = serde_json::json!({"iss": "ops", "aud": "mantra-mcp", "exp": 999});
+        assert!(auth
+            .authenticate(Some(&bearer(expired)), None, 1_000)
+            .is_err());
+        let wrong_issuer = serde_json::json!({"iss": "x", "aud": "mantra-mcp", "exp": 2_000});
+        assert!(auth
+            .authenticate(Some(&bearer(wrong_issuer)), None, 1_000)
+            .is_err());
+
+        let mut tampered =
+            token(serde_json::json!({"iss": "ops", "aud": "mantra-mcp", "exp": 2_000}));
+        tampered.push('A');
+        assert!(auth
+            .authenticate(Some(&format!("Bearer {}", tampered)), None, 1_000)
+            .is_err());
+    }
+}
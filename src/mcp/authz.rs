@@ -0,0 +1,554 @@
+//! Per-Tool Authorization Policy
+//!
+//! Every tool call passes the policy before it is admitted. Tools are classified
+//! as read-only, state-changing or admin; the policy caps the class callers may
+//! use and applies allow and deny lists on top. Calls spending the native denom
+//! are held to a per-transaction limit and a daily volume, and a spend above the
+//! confirmation threshold is rejected with a one-time token. Repeating the
+//! identical call with `_meta.confirmation_token` set to that token lets it
+//! through, so an agent has to go back to the user before a large transaction
+//! is broadcast.
+
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::server::{is_write_tool, McpResult, McpServerError};
+
+/// Seconds a confirmation token stays valid
+pub const CONFIRMATION_TTL_SECS: u64 = 300;
+
+/// Tools that administer wallets, pools, campaigns or sales, or read the
+/// audit log of other callers
+pub const ADMIN_TOOLS: &[&str] = &[
+    "wallet_add_from_mnemonic",
+    "wallet_discover",
+    "wallet_remove",
+    "wallet_switch",
+    "switch_network",
+    "address_book_add",
+    "address_book_remove",
+    "restricted_wallets_add",
+    "restricted_wallets_remove",
+    "restricted_wallets_import",
+    "dex_create_pool",
+    "claimdrop_create_campaign",
+    "claimdrop_add_allocations",
+    "claimdrop_build_campaign",
+    "claimdrop_close_campaign",
+    "claimdrop_withdraw_remaining_funds",
+    "claimdrop_end_of_life_campaign",
+    "primary_sale_activate",
+    "primary_sale_end_sale",
+    "primary_sale_settle_and_distribute",
+    "primary_sale_top_up_refunds",
+    "primary_sale_cancel",
+    "primary_sale_pause",
+    "primary_sale_unpause",
+    "primary_sale_emergency_withdraw",
+    "get_audit_log",
+    "get_signing_history",
+    "get_recent_traces",
+    "list_pending_transactions",
+    "approve_transaction",
+    "reject_transaction",
+];
+
+/// Flat denom and amount argument pairs counted as spends
+pub(crate) const FLAT_SPEND_FIELDS: &[(&str, &str)] = &[
+    ("asset_denom", "amount"),
+    ("source_asset_denom", "source_asset_amount"),
+    ("reward_denom", "total_reward"),
+];
+
+/// Privilege a tool needs, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolClass {
+    ReadOnly,
+    StateChanging,
+    Admin,
+}
+
+impl ToolClass {
+    /// Class of `tool_name`
+    pub fn of(tool_name: &str) -> Self {
+        if ADMIN_TOOLS.contains(&tool_name) {
+            Self::Admin
+        } else if is_write_tool(tool_name) {
+            Self::StateChanging
+        } else {
+            Self::ReadOnly
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReadOnly => "read_only",
+            Self::StateChanging => "state_changing",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for ToolClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "read_only" => Ok(Self::ReadOnly),
+            "state_changing" => Ok(Self::StateChanging),
+            "admin" => Ok(Self::Admin),
+            other => Err(format!(
+                "Unknown tool class '{}' (expected read_only, state_changing or admin)",
+                other
+            )),
+        }
+    }
+}
+
+/// Authorization policy settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationConfig {
+    /// Most privileged tool class callers may use
+    #[serde(default = "default_max_class")]
+    pub max_class: ToolClass,
+    /// Tools callers may use, every tool when empty; a trailing `*` matches a prefix
+    #[serde(default)]
+    pub allow_tools: Vec<String>,
+    /// Tools callers may never use, checked before `allow_tools`
+    #[serde(default)]
+    pub deny_tools: Vec<String>,
+    /// Denom the spend limits are counted in
+    #[serde(default = "default_spend_denom")]
+    pub spend_denom: String,
+    /// Largest spend of a single call, in base units of `spend_denom`
+    #[serde(default)]
+    pub max_spend_per_tx: Option<u64>,
+    /// Largest total spend per UTC day, in base units of `spend_denom`
+    #[serde(default)]
+    pub max_daily_spend: Option<u64>,
+    /// Spends above this many base units need a confirmation token
+    #[serde(default)]
+    pub confirm_above: Option<u64>,
+}
+
+fn default_max_class() -> ToolClass {
+    ToolClass::Admin
+}
+
+fn default_spend_denom() -> String {
+    "uom".to_string()
+}
+
+impl Default for AuthorizationConfig {
+    fn default() -> Self {
+        Self {
+            max_class: default_max_class(),
+            allow_tools: Vec::new(),
+            deny_tools: Vec::new(),
+            spend_denom: default_spend_denom(),
+            max_spend_per_tx: None,
+            max_daily_spend: None,
+            confirm_above: None,
+        }
+    }
+}
+
+/// Whether `tool_name` matches `pattern`; a trailing `*` matches a prefix
+pub(crate) fn matches_pattern(pattern: &str, tool_name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => tool_name.starts_with(prefix),
+        None => pattern == tool_name,
+    }
+}
+
+impl AuthorizationConfig {
+    /// Check the class and the allow and deny lists for `tool_name`
+    ///
+    /// Returns the reason the tool is refused.
+    pub fn permits_tool(&self, tool_name: &str) -> Result<(), String> {
+        if self
+            .deny_tools
+            .iter()
+            .any(|pattern| matches_pattern(pattern, tool_name))
+        {
+            return Err(format!("tool '{}' is denied by policy", tool_name));
+        }
+        if !self.allow_tools.is_empty()
+            && !self
+                .allow_tools
+                .iter()
+                .any(|pattern| matches_pattern(pattern, tool_name))
+        {
+            return Err(format!("tool '{}' is not in the allow list", tool_name));
+        }
+        let class = ToolClass::of(tool_name);
+        if class > self.max_class {
+            return Err(format!(
+                "tool '{}' is {} but policy allows at most {}",
+                tool_name,
+                class.as_str(),
+                self.max_class.as_str()
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn amount_of(value: &Value) -> u128 {
+    match value {
+        Value::String(amount) => amount.trim().parse().unwrap_or(0),
+        Value::Number(amount) => amount.as_u64().map(u128::from).unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Base units of `denom` a call with `arguments` spends
+///
+/// Counts `{denom, amount}` objects anywhere in the arguments (offer assets,
+/// liquidity assets) and the flat denom and amount pairs of zaps, Skip transfers
+/// and campaign funding. Amounts whose denom the arguments do not name, such as
+/// primary sale investments, are not counted.
+pub fn spend_in_denom(arguments: &Value, denom: &str) -> u128 {
+    spends_by_denom(arguments).get(denom).copied().unwrap_or(0)
+}
+
+/// Base units a call with `arguments` spends, by denom, counted as in
+/// [`spend_in_denom`]
+pub fn spends_by_denom(arguments: &Value) -> BTreeMap<String, u128> {
+    let mut spends = BTreeMap::new();
+    collect_spends(arguments, &mut spends);
+    spends.retain(|_, amount| *amount > 0);
+    spends
+}
+
+fn collect_spends(arguments: &Value, spends: &mut BTreeMap<String, u128>) {
+    match arguments {
+        Value::Object(map) => {
+            if let (Some(Value::String(denom)), Some(amount)) =
+                (map.get("denom"), map.get("amount"))
+            {
+                *spends.entry(denom.clone()).or_default() += amount_of(amount);
+            }
+            for (denom_field, amount_field) in FLAT_SPEND_FIELDS {
+                if let Some(denom) = map.get(*denom_field).and_then(Value::as_str) {
+                    *spends.entry(denom.to_string()).or_default() +=
+                        map.get(*amount_field).map(amount_of).unwrap_or(0);
+                }
+            }
+            for (key, value) in map {
+                if key != "_meta" {
+                    collect_spends(value, spends);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_spends(item, spends);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Tool name and arguments a confirmation token is bound to
+fn fingerprint(tool_name: &str, arguments: &Value) -> String {
+    let mut arguments = arguments.clone();
+    if let Some(map) = arguments.as_object_mut() {
+        map.remove("_meta");
+    }
+    format!("{}:{}", tool_name, arguments)
+}
+
+/// Admission granted by [`ToolAuthorizer::authorize`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Authorization {
+    pub class: ToolClass,
+    /// Spend reserved against the daily volume
+    pub spend: u128,
+}
+
+#[derive(Debug)]
+struct PendingConfirmation {
+    fingerprint: String,
+    expires_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct SpendState {
+    day: Option<NaiveDate>,
+    spent: u128,
+    confirmations: HashMap<String, PendingConfirmation>,
+}
+
+/// Enforces an [`AuthorizationConfig`] across all sessions
+#[derive(Debug)]
+pub struct ToolAuthorizer {
+    config: AuthorizationConfig,
+    state: Mutex<SpendState>,
+}
+
+impl ToolAuthorizer {
+    /// Create an authorizer from configuration
+    pub fn new(config: AuthorizationConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(SpendState::default()),
+        }
+    }
+
+    /// Policy configuration
+    pub fn config(&self) -> &AuthorizationConfig {
+        &self.config
+    }
+
+    /// Admit a call of `tool_name` with `arguments`
+    ///
+    /// The call's spend is reserved against today's volume; hand the returned
+    /// [`Authorization`] to [`Self::release`] if the call fails. Returns
+    /// `McpServerError::AuthorizationDenied` if the policy refuses the call and
+    /// `McpServerError::ConfirmationRequired` if it needs a confirmation token.
+    pub fn authorize(&self, tool_name: &str, arguments: &Value) -> McpResult<Authorization> {
+        self.authorize_on(tool_name, arguments, Utc::now().date_naive())
+    }
+
+    fn authorize_on(
+        &self,
+        tool_name: &str,
+        arguments: &Value,
+        today: NaiveDate,
+    ) -> McpResult<Authorization> {
+        self.config
+            .permits_tool(tool_name)
+            .map_err(McpServerError::AuthorizationDenied)?;
+
+        let class = ToolClass::of(tool_name);
+        // Dry runs broadcast nothing, so they spend nothing
+        let dry_run = arguments
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let spend = if class == ToolClass::ReadOnly || dry_run {
+            0
+        } else {
+            spend_in_denom(arguments, &self.config.spend_denom)
+        };
+        if spend == 0 {
+            return Ok(Authorization { class, spend });
+        }
+
+        let denom = &self.config.spend_denom;
+        if let Some(max) = self.config.max_spend_per_tx {
+            if spend > u128::from(max) {
+                return Err(McpServerError::AuthorizationDenied(format!(
+                    "{} spends {}{}, above the per-transaction limit of {}{}",
+                    tool_name, spend, denom, max, denom
+                )));
+            }
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.day != Some(today) {
+            state.day = Some(today);
+            state.spent = 0;
+        }
+        if let Some(max) = self.config.max_daily_spend {
+            if state.spent + spend > u128::from(max) {
+                return Err(McpServerError::AuthorizationDenied(format!(
+                    "{} spends {}{} but only {}{} of the daily limit of {}{} is left",
+                    tool_name,
+                    spend,
+                    denom,
+                    u128::from(max).saturating_sub(state.spent),
+                    denom,
+                    max,
+                    denom
+                )));
+            }
+        }
+        if let Some(threshold) = self.config.confirm_above {
+            if spend > u128::from(threshold) {
+                Self::confirm(&mut state, tool_name, arguments, spend, threshold, denom)?;
+            }
+        }
+
+        state.spent += spend;
+        Ok(Authorization { class, spend })
+    }
+
+    /// Consume the call's confirmation token, or issue one
+    fn confirm(
+        state: &mut SpendState,
+        tool_name: &str,
+        arguments: &Value,
+        spend: u128,
+        threshold: u64,
+        denom: &str,
+    ) -> McpResult<()> {
+        let now = Instant::now();
+        state
+            .confirmations
+            .retain(|_, pending| pending.expires_at > now);
+
+        let fingerprint = fingerprint(tool_name, arguments);
+        let presented = arguments
+            .get("_meta")
+            .and_then(|meta| meta.get("confirmation_token"))
+            .and_then(|token| token.as_str());
+        if let Some(token) = presented {
+            if state
+                .confirmations
+                .get(token)
+                .is_some_and(|pending| pending.fingerprint == fingerprint)
+            {
+                state.confirmations.remove(token);
+                return Ok(());
+            }
+        }
+
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        state.confirmations.insert(
+            token.clone(),
+            PendingConfirmation {
+                fingerprint,
+                expires_at: now + Duration::from_secs(CONFIRMATION_TTL_SECS),
+            },
+        );
+        Err(McpServerError::ConfirmationRequired {
+            reason: format!(
+                "{} spends {}{}, above the confirmation threshold of {}{}{}",
+                tool_name,
+                spend,
+                denom,
+                threshold,
+                denom,
+                if presented.is_some() {
+                    "; the presented token is unknown, expired or for other arguments"
+                } else {
+                    ""
+                }
+            ),
+            token,
+        })
+    }
+
+    /// Return the spend of a call that failed to today's volume
+    pub fn release(&self, authorization: &Authorization) {
+        if authorization.spend > 0 {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.spent = state.spent.saturating_sub(authorization.spend);
+        }
+    }
+
+    /// Policy and usage snapshot for health and diagnostics output
+    pub fn snapshot(&self) -> Value {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let today = Utc::now().date_naive();
+        serde_json::json!({
+            "max_class": self.config.max_class,
+            "allow_tools": self.config.allow_tools,
+            "deny_tools": self.config.deny_tools,
+            "spend_denom": self.config.spend_denom,
+            "max_spend_per_tx": self.config.max_spend_per_tx,
+            "max_daily_spend": self.config.max_daily_spend,
+            "confirm_above": self.config.confirm_above,
+            "spent_today": if state.day == Some(today) { state.spent } else { 0 }.to_string(),
+            "pending_confirmations": state.confirmations.len(),
+        })
+    }
+}
+
+impl Default for ToolAuthorizer {
+    fn default() -> Self {
+        Self::new(AuthorizationConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(amount: &str) -> Value {
+        serde_json::json!({
+            "pool_id": "o.uom.uusdc",
+            "offer_asset": { "denom": "uom", "amount": amount },
+            "ask_asset_denom": "uusdc",
+            "_meta": { "session_id": "s1" }
+        })
+    }
+
+    #[test]
+    fn test_class_and_tool_lists() {
+        assert_eq!(ToolClass::of("dex_get_pools"), ToolClass::ReadOnly);
+        assert_eq!(ToolClass::of("dex_execute_swap"), ToolClass::StateChanging);
+        assert_eq!(ToolClass::of("primary_sale_cancel"), ToolClass::Admin);
+
+        let config = AuthorizationConfig {
+            max_class: ToolClass::StateChanging,
+            allow_tools: vec!["dex_*".to_string(), "wallet_get_balances".to_string()],
+            deny_tools: vec!["dex_zap_*".to_string()],
+            ..Default::default()
+        };
+        assert!(config.permits_tool("dex_execute_swap").is_ok());
+        assert!(config.permits_tool("wallet_get_balances").is_ok());
+        assert!(config.permits_tool("dex_zap_in").is_err());
+        assert!(config.permits_tool("skip_execute_transfer").is_err());
+        // Admin tools stay refused even when allowed by name
+        assert!(config.permits_tool("dex_create_pool").is_err());
+    }
+
+    #[test]
+    fn test_spend_limits_and_confirmation() {
+        let authorizer = ToolAuthorizer::new(AuthorizationConfig {
+            max_spend_per_tx: Some(1_000),
+            max_daily_spend: Some(1_500),
+            confirm_above: Some(500),
+            ..Default::default()
+        });
+        let day = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+
+        assert!(matches!(
+            authorizer.authorize_on("dex_execute_swap", &swap("1001"), day),
+            Err(McpServerError::AuthorizationDenied(_))
+        ));
+        let small = authorizer
+            .authorize_on("dex_execute_swap", &swap("400"), day)
+            .unwrap();
+        assert_eq!(small.spend, 400);
+
+        let token = match authorizer.authorize_on("dex_execute_swap", &swap("900"), day) {
+            Err(McpServerError::ConfirmationRequired { token, .. }) => token,
+            other => panic!("expected a confirmation request, got {:?}", other),
+        };
+        // The token is bound to the arguments it was issued for
+        let mut other = swap("800");
+        other["_meta"]["confirmation_token"] = Value::String(token.clone());
+        assert!(authorizer
+            .authorize_on("dex_execute_swap", &other, day)
+            .is_err());
+        let mut confirmed = swap("900");
+        confirmed["_meta"]["confirmation_token"] = Value::String(token);
+        authorizer
+            .authorize_on("dex_execute_swap", &confirmed, day)
+            .unwrap();
+
+        // 1300 of 1500 spent; a released reservation frees its share
+        assert!(authorizer
+            .authorize_on("dex_execute_swap", &swap("300"), day)
+            .is_err());
+        authorizer.release(&small);
+        assert!(authorizer
+            .authorize_on("dex_execute_swap", &swap("300"), day)
+            .is_ok());
+        // The volume starts over the next day
+        assert!(authorizer
+            .authorize_on("dex_execute_swap", &swap("500"), day.succ_opt().unwrap())
+            .is_ok());
+    }
+}
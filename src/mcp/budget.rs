@@ -0,0 +1,538 @@
+//! Per-Wallet Spending Budgets
+//!
+//! A [`BudgetManager`] keeps a rolling record of what each wallet sent out
+//! through write tools and refuses a call that would take the wallet past its
+//! limit for the window: a cap in base units of the native denom, a cap in US
+//! dollars, or both. Dollar values come from the [`PriceOracle`], which prices
+//! denoms from configured quotes; while a dollar cap applies, spends of a denom
+//! the oracle cannot price are refused unless `block_unpriced` is off.
+//!
+//! The authorization policy in [`super::authz`] caps the server as a whole;
+//! budgets hold each wallet an agent controls to its own allowance, which is
+//! what keeps an autonomous agent from draining a wallet through many small
+//! transactions.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::authz::{spends_by_denom, ToolClass};
+use super::response_format::MarkdownRender;
+use super::server::{McpResult, McpServerError};
+
+/// USD quote of a denom
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UsdPrice {
+    /// Dollars per whole token
+    pub usd: f64,
+    /// Decimals of the base unit, e.g. 6 for `uom`
+    #[serde(default = "default_decimals")]
+    pub decimals: u8,
+}
+
+fn default_decimals() -> u8 {
+    6
+}
+
+/// Limits of one wallet, each replacing the default limit it sets
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WalletBudget {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_native: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_usd: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_secs: Option<u64>,
+}
+
+/// Spending budget settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetConfig {
+    /// Denom `max_native` is counted in
+    #[serde(default = "default_native_denom")]
+    pub native_denom: String,
+    /// Length of the rolling window spends are summed over
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+    /// Largest native spend of a wallet per window, in base units
+    #[serde(default)]
+    pub max_native: Option<u64>,
+    /// Largest dollar value a wallet may spend per window
+    #[serde(default)]
+    pub max_usd: Option<f64>,
+    /// Limits of particular wallets, by address
+    #[serde(default)]
+    pub wallets: BTreeMap<String, WalletBudget>,
+    /// Quotes the oracle prices spends with, by denom
+    #[serde(default)]
+    pub usd_prices: BTreeMap<String, UsdPrice>,
+    /// Refuse spends the oracle cannot price while a dollar limit applies
+    #[serde(default = "default_block_unpriced")]
+    pub block_unpriced: bool,
+}
+
+fn default_native_denom() -> String {
+    "uom".to_string()
+}
+
+fn default_window_secs() -> u64 {
+    86_400
+}
+
+fn default_block_unpriced() -> bool {
+    true
+}
+
+impl Default for BudgetConfig {
+    fn default() -> Self {
+        Self {
+            native_denom: default_native_denom(),
+            window_secs: default_window_secs(),
+            max_native: None,
+            max_usd: None,
+            wallets: BTreeMap::new(),
+            usd_prices: BTreeMap::new(),
+            block_unpriced: default_block_unpriced(),
+        }
+    }
+}
+
+impl BudgetConfig {
+    /// Limits that apply to `wallet`
+    pub fn limits_for(&self, wallet: &str) -> WalletBudget {
+        let overrides = self.wallets.get(wallet).cloned().unwrap_or_default();
+        WalletBudget {
+            max_native: overrides.max_native.or(self.max_native),
+            max_usd: overrides.max_usd.or(self.max_usd),
+            window_secs: Some(overrides.window_secs.unwrap_or(self.window_secs)),
+        }
+    }
+}
+
+/// Dollar values of denom amounts
+#[derive(Debug, Clone, Default)]
+pub struct PriceOracle {
+    prices: BTreeMap<String, UsdPrice>,
+}
+
+impl PriceOracle {
+    pub fn new(prices: BTreeMap<String, UsdPrice>) -> Self {
+        Self { prices }
+    }
+
+    pub fn price(&self, denom: &str) -> Option<UsdPrice> {
+        self.prices.get(denom).copied()
+    }
+
+    /// Dollar value of `amount` base units of `denom`, if it has a quote
+    pub fn usd_value(&self, denom: &str, amount: u128) -> Option<f64> {
+        self.price(denom)
+            .map(|price| amount as f64 / 10f64.powi(i32::from(price.decimals)) * price.usd)
+    }
+}
+
+/// Spend held against a wallet's budget by [`BudgetManager::reserve`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetReservation {
+    pub wallet: String,
+    id: u64,
+}
+
+#[derive(Debug, Clone)]
+struct SpendRecord {
+    id: u64,
+    at: DateTime<Utc>,
+    native: u128,
+    usd: f64,
+    unpriced: Vec<String>,
+}
+
+/// Spending of one wallet in its current window
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletSpending {
+    pub wallet: String,
+    pub window_secs: u64,
+    pub transactions: usize,
+    /// Native base units spent in the window
+    pub spent_native: String,
+    /// Dollar value of the priced spends in the window
+    pub spent_usd: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_native: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_usd: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_native: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_usd: Option<f64>,
+    /// Denoms spent in the window that have no USD quote
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unpriced_denoms: Vec<String>,
+    /// RFC 3339 time the oldest spend leaves the window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oldest_expires_at: Option<String>,
+}
+
+/// Result of the `get_spending_summary` tool
+#[derive(Debug, Clone, Serialize)]
+pub struct SpendingSummaryResponse {
+    pub native_denom: String,
+    pub wallets: Vec<WalletSpending>,
+}
+
+impl MarkdownRender for SpendingSummaryResponse {
+    fn to_markdown(&self) -> String {
+        let mut text = "## Spending Summary\n\n".to_string();
+        if self.wallets.is_empty() {
+            text.push_str("No wallet has spent anything yet.\n");
+        }
+        for wallet in &self.wallets {
+            text.push_str(&format!(
+                "### `{}`\n\n**Window:** {}s, {} transactions\n**Spent:** {}{} (${:.2})\n",
+                wallet.wallet,
+                wallet.window_secs,
+                wallet.transactions,
+                wallet.spent_native,
+                self.native_denom,
+                wallet.spent_usd
+            ));
+            if let (Some(max), Some(remaining)) = (&wallet.max_native, &wallet.remaining_native) {
+                text.push_str(&format!(
+                    "**Native Limit:** {}{}, {}{} left\n",
+                    max, self.native_denom, remaining, self.native_denom
+                ));
+            }
+            if let (Some(max), Some(remaining)) = (wallet.max_usd, wallet.remaining_usd) {
+                text.push_str(&format!(
+                    "**USD Limit:** ${:.2}, ${:.2} left\n",
+                    max, remaining
+                ));
+            }
+            if !wallet.unpriced_denoms.is_empty() {
+                text.push_str(&format!(
+                    "⚠️ No USD price for {}\n",
+                    wallet.unpriced_denoms.join(", ")
+                ));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Tracks and limits what each wallet spends per window
+#[derive(Debug)]
+pub struct BudgetManager {
+    config: BudgetConfig,
+    oracle: PriceOracle,
+    records: Mutex<HashMap<String, VecDeque<SpendRecord>>>,
+    next_id: AtomicU64,
+}
+
+impl BudgetManager {
+    /// Create a manager from configuration, pricing with its quotes
+    pub fn new(config: BudgetConfig) -> Self {
+        let oracle = PriceOracle::new(config.usd_prices.clone());
+        Self {
+            config,
+            oracle,
+            records: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    pub fn config(&self) -> &BudgetConfig {
+        &self.config
+    }
+
+    /// Base units a call of `tool_name` with `arguments` spends, by denom;
+    /// empty for read-only tools and dry runs
+    pub fn spends_of(&self, tool_name: &str, arguments: &Value) -> BTreeMap<String, u128> {
+        let dry_run = arguments
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if ToolClass::of(tool_name) == ToolClass::ReadOnly || dry_run {
+            return BTreeMap::new();
+        }
+        spends_by_denom(arguments)
+    }
+
+    /// Hold `spends` of a `tool_name` call against the budget of `wallet`
+    ///
+    /// Hand the reservation to [`Self::release`] if the call fails. Returns
+    /// `McpServerError::AuthorizationDenied` if the spend would take the wallet
+    /// past a limit.
+    pub fn reserve(
+        &self,
+        wallet: &str,
+        tool_name: &str,
+        spends: &BTreeMap<String, u128>,
+    ) -> McpResult<BudgetReservation> {
+        self.reserve_at(wallet, tool_name, spends, Utc::now())
+    }
+
+    fn reserve_at(
+        &self,
+        wallet: &str,
+        tool_name: &str,
+        spends: &BTreeMap<String, u128>,
+        now: DateTime<Utc>,
+    ) -> McpResult<BudgetReservation> {
+        let limits = self.config.limits_for(wallet);
+        let native = spends.get(&self.config.native_denom).copied().unwrap_or(0);
+        let mut usd = 0.0;
+        let mut unpriced = Vec::new();
+        for (denom, amount) in spends {
+            match self.oracle.usd_value(denom, *amount) {
+                Some(value) => usd += value,
+                None => unpriced.push(denom.clone()),
+            }
+        }
+        if limits.max_usd.is_some() && self.config.block_unpriced && !unpriced.is_empty() {
+            return Err(McpServerError::AuthorizationDenied(format!(
+                "{} spends {} with no USD price, so wallet {}'s dollar budget cannot be checked",
+                tool_name,
+                unpriced.join(", "),
+                wallet
+            )));
+        }
+
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        let history = records.entry(wallet.to_string()).or_default();
+        Self::expire(history, &limits, now);
+        let (spent_native, spent_usd) = Self::totals(history);
+        let native_denom = &self.config.native_denom;
+        if let Some(max) = limits.max_native {
+            if spent_native + native > u128::from(max) {
+                return Err(McpServerError::AuthorizationDenied(format!(
+                    "{} spends {}{} but wallet {} has only {}{} of its {}{} budget left",
+                    tool_name,
+                    native,
+                    native_denom,
+                    wallet,
+                    u128::from(max).saturating_sub(spent_native),
+                    native_denom,
+                    max,
+                    native_denom
+                )));
+            }
+        }
+        if let Some(max) = limits.max_usd {
+            if spent_usd + usd > max {
+                return Err(McpServerError::AuthorizationDenied(format!(
+                    "{} spends ${:.2} but wallet {} has only ${:.2} of its ${:.2} budget left",
+                    tool_name,
+                    usd,
+                    wallet,
+                    (max - spent_usd).max(0.0),
+                    max
+                )));
+            }
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        history.push_back(SpendRecord {
+            id,
+            at: now,
+            native,
+            usd,
+            unpriced,
+        });
+        Ok(BudgetReservation {
+            wallet: wallet.to_string(),
+            id,
+        })
+    }
+
+    /// Return the spend of a call that failed to its wallet's budget
+    pub fn release(&self, reservation: &BudgetReservation) {
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(history) = records.get_mut(&reservation.wallet) {
+            history.retain(|record| record.id != reservation.id);
+        }
+    }
+
+    /// Spending of `wallet` in its current window
+    pub fn summary(&self, wallet: &str) -> WalletSpending {
+        self.summary_at(wallet, Utc::now())
+    }
+
+    fn summary_at(&self, wallet: &str, now: DateTime<Utc>) -> WalletSpending {
+        let limits = self.config.limits_for(wallet);
+        let window_secs = limits.window_secs.unwrap_or(self.config.window_secs);
+        let mut records = self.records.lock().unwrap_or_else(|e| e.into_inner());
+        let empty = VecDeque::new();
+        let history = match records.get_mut(wallet) {
+            Some(history) => {
+                Self::expire(history, &limits, now);
+                &*history
+            }
+            None => &empty,
+        };
+        let (spent_native, spent_usd) = Self::totals(history);
+        let unpriced_denoms: BTreeSet<String> = history
+            .iter()
+            .flat_map(|record| record.unpriced.iter().cloned())
+            .collect();
+
+        WalletSpending {
+            wallet: wallet.to_string(),
+            window_secs,
+            transactions: history.len(),
+            spent_native: spent_native.to_string(),
+            spent_usd,
+            max_native: limits.max_native.map(|max| max.to_string()),
+            max_usd: limits.max_usd,
+            remaining_native: limits
+                .max_native
+                .map(|max| u128::from(max).saturating_sub(spent_native).to_string()),
+            remaining_usd: limits.max_usd.map(|max| (max - spent_usd).max(0.0)),
+            unpriced_denoms: unpriced_denoms.into_iter().collect(),
+            oldest_expires_at: history.front().map(|record| {
+                (record.at + ChronoDuration::seconds(window_secs as i64)).to_rfc3339()
+            }),
+        }
+    }
+
+    /// Spending of every wallet with spends in its window, and of `include`
+    pub fn summaries(&self, include: Option<&str>) -> SpendingSummaryResponse {
+        let mut wallets: BTreeSet<String> = self
+            .records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        wallets.extend(include.map(str::to_string));
+
+        SpendingSummaryResponse {
+            native_denom: self.config.native_denom.clone(),
+            wallets: wallets
+                .iter()
+                .map(|wallet| self.summary(wallet))
+                .filter(|summary| {
+                    summary.transactions > 0 || Some(summary.wallet.as_str()) == include
+                })
+                .collect(),
+        }
+    }
+
+    /// Drop spends older than the wallet's window
+    fn expire(history: &mut VecDeque<SpendRecord>, limits: &WalletBudget, now: DateTime<Utc>) {
+        let window = ChronoDuration::seconds(limits.window_secs.unwrap_or_default() as i64);
+        while history
+            .front()
+            .is_some_and(|record| record.at + window <= now)
+        {
+            history.pop_front();
+        }
+    }
+
+    fn totals(history: &VecDeque<SpendRecord>) -> (u128, f64) {
+        history.iter().fold((0, 0.0), |(native, usd), record| {
+            (native + record.native, usd + record.usd)
+        })
+    }
+}
+
+impl Default for BudgetManager {
+    fn default() -> Self {
+        Self::new(BudgetConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: &str = "mantra1wallet";
+
+    fn swap(denom: &str, amount: &str) -> Value {
+        serde_json::json!({
+            "pool_id": "o.uom.uusdc",
+            "offer_asset": { "denom": denom, "amount": amount },
+            "ask_asset_denom": "uusdc"
+        })
+    }
+
+    #[test]
+    fn test_native_and_usd_limits_per_window() {
+        let mut usd_prices = BTreeMap::new();
+        usd_prices.insert(
+            "uom".to_string(),
+            UsdPrice {
+                usd: 2.0,
+                decimals: 6,
+            },
+        );
+        let mut wallets = BTreeMap::new();
+        wallets.insert(
+            "mantra1big".to_string(),
+            WalletBudget {
+                max_native: Some(100_000_000),
+                ..Default::default()
+            },
+        );
+        let budget = BudgetManager::new(BudgetConfig {
+            window_secs: 3_600,
+            max_native: Some(10_000_000),
+            max_usd: Some(15.0),
+            wallets,
+            usd_prices,
+            ..Default::default()
+        });
+        let start = Utc::now();
+
+        // Read-only tools and dry runs spend nothing
+        assert!(budget
+            .spends_of("dex_simulate_swap", &swap("uom", "1"))
+            .is_empty());
+        let mut dry_run = swap("uom", "1");
+        dry_run["dry_run"] = Value::Bool(true);
+        assert!(budget.spends_of("dex_execute_swap", &dry_run).is_empty());
+
+        // 5 OM is $10; a further 3 OM would pass the native cap but not the dollar cap
+        let spends = budget.spends_of("dex_execute_swap", &swap("uom", "5000000"));
+        let first = budget
+            .reserve_at(WALLET, "dex_execute_swap", &spends, start)
+            .unwrap();
+        let more = budget.spends_of("dex_execute_swap", &swap("uom", "3000000"));
+        assert!(matches!(
+            budget.reserve_at(WALLET, "dex_execute_swap", &more, start),
+            Err(McpServerError::AuthorizationDenied(_))
+        ));
+        // Unpriced denoms cannot be held to the dollar limit
+        let unpriced = budget.spends_of("dex_execute_swap", &swap("uusdc", "1"));
+        assert!(budget
+            .reserve_at(WALLET, "dex_execute_swap", &unpriced, start)
+            .is_err());
+
+        let summary = budget.summary_at(WALLET, start);
+        assert_eq!(summary.transactions, 1);
+        assert_eq!(summary.spent_native, "5000000");
+        assert_eq!(summary.remaining_native.as_deref(), Some("5000000"));
+        assert_eq!(summary.remaining_usd, Some(5.0));
+
+        // A failed call gives its spend back, and spends leave the window
+        budget.release(&first);
+        assert_eq!(budget.summary_at(WALLET, start).transactions, 0);
+        budget
+            .reserve_at(WALLET, "dex_execute_swap", &spends, start)
+            .unwrap();
+        let later = start + ChronoDuration::seconds(3_600);
+        assert!(budget
+            .reserve_at(WALLET, "dex_execute_swap", &more, later)
+            .is_ok());
+
+        // Wallet overrides keep the defaults they do not set
+        let limits = budget.config().limits_for("mantra1big");
+        assert_eq!(limits.max_native, Some(100_000_000));
+        assert_eq!(limits.max_usd, Some(15.0));
+        assert_eq!(limits.window_secs, Some(3_600));
+    }
+}
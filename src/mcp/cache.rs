@@ -0,0 +1,583 @@
+//! Adapter Cache
+//!
+//! The SDK adapter keeps pool listings, token metadata, ABIs and similar
+//! lookups in an [`AdapterCache`]. Entries expire after a TTL, or never when
+//! inserted without one, and the cache is bounded by `max_entries` and
+//! `max_bytes`: inserting past either limit evicts the least recently used
+//! entries first. Sizes are measured as the length of the serialized value.
+//!
+//! With the `sqlite` backend every insert and removal is written through to a
+//! [`CacheStore`] on disk, and the entries still fresh when the cache is opened
+//! are loaded back, so warm data survives a restart. The SQLite store needs the
+//! `sqlite-cache` feature; other stores plug in through [`AdapterCache::with_store`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use crate::error::Error;
+
+/// Where cache entries are kept
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheBackend {
+    /// In memory only, lost on restart
+    #[default]
+    Memory,
+    /// In memory, written through to a SQLite database at `path`
+    Sqlite,
+}
+
+impl CacheBackend {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Memory => "memory",
+            Self::Sqlite => "sqlite",
+        }
+    }
+}
+
+impl std::str::FromStr for CacheBackend {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "memory" => Ok(Self::Memory),
+            "sqlite" => Ok(Self::Sqlite),
+            other => Err(Error::Config(format!(
+                "Unknown cache backend '{}', expected 'memory' or 'sqlite'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Adapter cache settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheConfig {
+    #[serde(default)]
+    pub backend: CacheBackend,
+    /// Database file of the `sqlite` backend
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Entries kept before the least recently used are evicted
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+    /// Total size of the serialized values kept before eviction
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: usize,
+}
+
+fn default_max_entries() -> usize {
+    10_000
+}
+
+fn default_max_bytes() -> usize {
+    64 * 1024 * 1024
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            backend: CacheBackend::default(),
+            path: None,
+            max_entries: default_max_entries(),
+            max_bytes: default_max_bytes(),
+        }
+    }
+}
+
+/// Cached value with its expiry
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheEntry {
+    pub value: Value,
+    /// Unix time in milliseconds after which the entry is stale; never when unset
+    pub expires_at_ms: Option<i64>,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, now_ms: i64) -> bool {
+        self.expires_at_ms.is_none_or(|expires| now_ms < expires)
+    }
+}
+
+/// Durable storage the cache writes through to
+pub trait CacheStore: Send + Sync + fmt::Debug {
+    /// All stored entries
+    fn load(&self) -> Result<Vec<(String, CacheEntry)>, Error>;
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error>;
+    fn remove(&self, key: &str) -> Result<(), Error>;
+    fn clear(&self) -> Result<(), Error>;
+}
+
+/// Entry counts and size of an [`AdapterCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    pub entries: usize,
+    pub fresh: usize,
+    pub bytes: usize,
+    pub evictions: u64,
+}
+
+#[derive(Debug)]
+struct Slot {
+    entry: CacheEntry,
+    bytes: usize,
+    tick: u64,
+}
+
+#[derive(Debug, Default)]
+struct CacheInner {
+    entries: HashMap<String, Slot>,
+    /// Keys by the tick of their last use, oldest first
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    bytes: usize,
+    evictions: u64,
+}
+
+impl CacheInner {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &str) -> Option<Slot> {
+        let slot = self.entries.remove(key)?;
+        self.recency.remove(&slot.tick);
+        self.bytes -= slot.bytes;
+        Some(slot)
+    }
+
+    /// Evict least recently used entries until both limits hold
+    fn evict(&mut self, max_entries: usize, max_bytes: usize) -> Vec<String> {
+        let mut evicted = Vec::new();
+        while self.entries.len() > max_entries || self.bytes > max_bytes {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            if let Some(slot) = self.entries.remove(&key) {
+                self.bytes -= slot.bytes;
+            }
+            self.evictions += 1;
+            evicted.push(key);
+        }
+        evicted
+    }
+}
+
+/// Bounded LRU cache of JSON values with optional write-through persistence
+#[derive(Debug)]
+pub struct AdapterCache {
+    max_entries: usize,
+    max_bytes: usize,
+    ttl: Duration,
+    inner: Mutex<CacheInner>,
+    store: Option<Box<dyn CacheStore>>,
+}
+
+impl AdapterCache {
+    /// Cache kept in memory only, whatever backend `config` names
+    pub fn in_memory(config: &CacheConfig, ttl: Duration) -> Self {
+        Self {
+            max_entries: config.max_entries,
+            max_bytes: config.max_bytes,
+            ttl,
+            inner: Mutex::new(CacheInner::default()),
+            store: None,
+        }
+    }
+
+    /// Open the cache on the backend `config` names
+    pub fn open(config: &CacheConfig, ttl: Duration) -> Result<Self, Error> {
+        match config.backend {
+            CacheBackend::Memory => Ok(Self::in_memory(config, ttl)),
+            #[cfg(feature = "sqlite-cache")]
+            CacheBackend::Sqlite => {
+                let path = config.path.as_ref().ok_or_else(|| {
+                    Error::Config("The sqlite cache backend needs a path".to_string())
+                })?;
+                let store = SqliteCacheStore::open(path)?;
+                Self::with_store(config, ttl, Box::new(store))
+            }
+            #[cfg(not(feature = "sqlite-cache"))]
+            CacheBackend::Sqlite => Err(Error::Config(
+                "The sqlite cache backend needs the sqlite-cache feature".to_string(),
+            )),
+        }
+    }
+
+    /// Cache writing through to `store`, warmed with its fresh entries
+    pub fn with_store(
+        config: &CacheConfig,
+        ttl: Duration,
+        store: Box<dyn CacheStore>,
+    ) -> Result<Self, Error> {
+        let mut stored = store.load()?;
+        let now = now_ms();
+        let (fresh, stale): (Vec<_>, Vec<_>) =
+            stored.drain(..).partition(|(_, entry)| entry.is_fresh(now));
+        for (key, _) in &stale {
+            store.remove(key)?;
+        }
+
+        let cache = Self {
+            store: Some(store),
+            ..Self::in_memory(config, ttl)
+        };
+        let loaded = fresh.len();
+        for (key, entry) in fresh {
+            cache.insert_entry(key, entry, false);
+        }
+        debug!(
+            "Loaded {} cached entries, dropped {} stale ones",
+            loaded,
+            stale.len()
+        );
+        Ok(cache)
+    }
+
+    /// Whether entries are written to durable storage
+    pub fn is_persistent(&self) -> bool {
+        self.store.is_some()
+    }
+
+    /// Fresh value under `key`, marking it as recently used
+    pub fn get(&self, key: &str) -> Option<Value> {
+        let now = now_ms();
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let fresh = inner.entries.get(key)?.entry.is_fresh(now);
+        if !fresh {
+            inner.remove(key);
+            drop(inner);
+            self.store_op(|store| store.remove(key));
+            return None;
+        }
+
+        let tick = inner.next_tick();
+        let slot = inner.entries.get_mut(key)?;
+        let previous = std::mem::replace(&mut slot.tick, tick);
+        let value = slot.entry.value.clone();
+        inner.recency.remove(&previous);
+        inner.recency.insert(tick, key.to_string());
+        Some(value)
+    }
+
+    /// Insert `value` expiring after the cache TTL
+    pub fn insert(&self, key: String, value: Value) {
+        self.insert_for(key, value, Some(self.ttl));
+    }
+
+    /// Insert `value` expiring after `ttl`, or never when `None`
+    pub fn insert_for(&self, key: String, value: Value, ttl: Option<Duration>) {
+        let expires_at_ms = ttl.map(|ttl| now_ms().saturating_add(ttl.as_millis() as i64));
+        self.insert_entry(
+            key,
+            CacheEntry {
+                value,
+                expires_at_ms,
+            },
+            true,
+        );
+    }
+
+    fn insert_entry(&self, key: String, entry: CacheEntry, persist: bool) {
+        let bytes = key.len() + entry.value.to_string().len();
+        if bytes > self.max_bytes {
+            debug!("Not caching {}: {} bytes exceed the cache size", key, bytes);
+            return;
+        }
+
+        let evicted = {
+            let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+            inner.remove(&key);
+            let tick = inner.next_tick();
+            inner.recency.insert(tick, key.clone());
+            inner.bytes += bytes;
+            inner.entries.insert(
+                key.clone(),
+                Slot {
+                    entry: entry.clone(),
+                    bytes,
+                    tick,
+                },
+            );
+            inner.evict(self.max_entries, self.max_bytes)
+        };
+
+        if persist {
+            self.store_op(|store| store.put(&key, &entry));
+        }
+        for key in evicted {
+            self.store_op(|store| store.remove(&key));
+        }
+    }
+
+    /// Remove the entry under `key`
+    pub fn remove(&self, key: &str) {
+        self.inner
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(key);
+        self.store_op(|store| store.remove(key));
+    }
+
+    /// Remove every entry, from durable storage as well
+    pub fn clear(&self) {
+        self.unload();
+        self.store_op(|store| store.clear());
+    }
+
+    /// Drop every entry from memory, keeping durable storage intact
+    pub fn unload(&self) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.entries.clear();
+        inner.recency.clear();
+        inner.bytes = 0;
+    }
+
+    /// Remove expired entries, returning how many there were
+    pub fn purge_expired(&self) -> usize {
+        let now = now_ms();
+        let expired: Vec<String> = {
+            let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+            let expired: Vec<String> = inner
+                .entries
+                .iter()
+                .filter(|(_, slot)| !slot.entry.is_fresh(now))
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in &expired {
+                inner.remove(key);
+            }
+            expired
+        };
+        for key in &expired {
+            self.store_op(|store| store.remove(key));
+        }
+        expired.len()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let now = now_ms();
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        CacheStats {
+            entries: inner.entries.len(),
+            fresh: inner
+                .entries
+                .values()
+                .filter(|slot| slot.entry.is_fresh(now))
+                .count(),
+            bytes: inner.bytes,
+            evictions: inner.evictions,
+        }
+    }
+
+    /// Failures of durable storage are logged; the in-memory cache stays authoritative
+    fn store_op(&self, op: impl FnOnce(&dyn CacheStore) -> Result<(), Error>) {
+        if let Some(store) = &self.store {
+            if let Err(err) = op(store.as_ref()) {
+                warn!("Failed to update the persistent cache: {}", err);
+            }
+        }
+    }
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// Cache store in a SQLite database
+#[cfg(feature = "sqlite-cache")]
+#[derive(Debug)]
+pub struct SqliteCacheStore {
+    conn: Mutex<rusqlite::Connection>,
+}
+
+#[cfg(feature = "sqlite-cache")]
+impl SqliteCacheStore {
+    /// Open or create the database at `path`
+    pub fn open(path: &std::path::Path) -> Result<Self, Error> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = rusqlite::Connection::open(path).map_err(sqlite_error)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             CREATE TABLE IF NOT EXISTS adapter_cache (
+                 key TEXT PRIMARY KEY,
+                 value TEXT NOT NULL,
+                 expires_at_ms INTEGER
+             );",
+        )
+        .map_err(sqlite_error)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+}
+
+#[cfg(feature = "sqlite-cache")]
+fn sqlite_error(err: rusqlite::Error) -> Error {
+    Error::Other(format!("Cache database error: {}", err))
+}
+
+#[cfg(feature = "sqlite-cache")]
+impl CacheStore for SqliteCacheStore {
+    fn load(&self) -> Result<Vec<(String, CacheEntry)>, Error> {
+        let conn = self.conn.lock().unwrap_or_else(|e| e.into_inner());
+        let mut statement = conn
+            .prepare("SELECT key, value, expires_at_ms FROM adapter_cache")
+            .map_err(sqlite_error)?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<i64>>(2)?,
+                ))
+            })
+            .map_err(sqlite_error)?;
+
+        let mut entries = Vec::new();
+        for row in rows {
+            let (key, value, expires_at_ms) = row.map_err(sqlite_error)?;
+            match serde_json::from_str(&value) {
+                Ok(value) => entries.push((
+                    key,
+                    CacheEntry {
+                        value,
+                        expires_at_ms,
+                    },
+                )),
+                Err(err) => warn!("Skipping unreadable cache entry {}: {}", key, err),
+            }
+        }
+        Ok(entries)
+    }
+
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+        self.conn
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .execute(
+                "INSERT OR REPLACE INTO adapter_cache (key, value, expires_at_ms) VALUES (?1, ?2, ?3)",
+                rusqlite::params![key, entry.value.to_string(), entry.expires_at_ms],
+            )
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Error> {
+        self.conn
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .execute("DELETE FROM adapter_cache WHERE key = ?1", [key])
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), Error> {
+        self.conn
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .execute("DELETE FROM adapter_cache", [])
+            .map_err(sqlite_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_lru_eviction_and_write_through() {
+        #[derive(Debug, Default)]
+        struct MemoryStore(Mutex<BTreeMap<String, CacheEntry>>);
+
+        impl CacheStore for std::sync::Arc<MemoryStore> {
+            fn load(&self) -> Result<Vec<(String, CacheEntry)>, Error> {
+                let entries = self.0.lock().unwrap();
+                Ok(entries
+                    .iter()
+                    .map(|(k, e)| (k.clone(), e.clone()))
+                    .collect())
+            }
+            fn put(&self, key: &str, entry: &CacheEntry) -> Result<(), Error> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .insert(key.to_string(), entry.clone());
+                Ok(())
+            }
+            fn remove(&self, key: &str) -> Result<(), Error> {
+                self.0.lock().unwrap().remove(key);
+                Ok(())
+            }
+            fn clear(&self) -> Result<(), Error> {
+                self.0.lock().unwrap().clear();
+                Ok(())
+            }
+        }
+
+        let config = CacheConfig {
+            max_entries: 2,
+            ..CacheConfig::default()
+        };
+        let ttl = Duration::from_secs(60);
+        let store = std::sync::Arc::new(MemoryStore::default());
+        store
+            .put(
+                "stale",
+                &CacheEntry {
+                    value: json!(0),
+                    expires_at_ms: Some(now_ms() - 1),
+                },
+            )
+            .unwrap();
+
+        let cache = AdapterCache::with_store(&config, ttl, Box::new(store.clone())).unwrap();
+        assert_eq!(cache.stats().entries, 0);
+        assert!(store.load().unwrap().is_empty());
+
+        cache.insert("a".to_string(), json!(1));
+        cache.insert_for("b".to_string(), json!(2), None);
+        assert_eq!(cache.get("a"), Some(json!(1)));
+        cache.insert("c".to_string(), json!(3));
+
+        // "b" was least recently used
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.stats().evictions, 1);
+        let stored: Vec<String> = store.load().unwrap().into_iter().map(|(k, _)| k).collect();
+        assert_eq!(stored, vec!["a", "c"]);
+
+        // A reopened cache starts warm; unloading keeps durable entries
+        cache.unload();
+        let reopened = AdapterCache::with_store(&config, ttl, Box::new(store.clone())).unwrap();
+        assert_eq!(reopened.get("c"), Some(json!(3)));
+        reopened.clear();
+        assert!(store.load().unwrap().is_empty());
+
+        let tiny = CacheConfig {
+            max_bytes: 8,
+            ..CacheConfig::default()
+        };
+        let cache = AdapterCache::in_memory(&tiny, ttl);
+        cache.insert("k".to_string(), json!("too large to keep"));
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
@@ -383,6 +383,8 @@ impl Default for McpClientWrapper {
                 network_name: testnet_constants.network_name.clone(),
                 chain_id: testnet_constants.chain_id.clone(),
                 rpc_url: testnet_constants.default_rpc.clone(),
+                rpc_fallback_urls: Vec::new(),
+                grpc_url: None,
                 gas_price: testnet_constants.default_gas_price,
                 gas_adjustment: testnet_constants.default_gas_adjustment,
                 native_denom: testnet_constants.native_denom.clone(),
@@ -391,6 +393,8 @@ impl Default for McpClientWrapper {
                 evm_rpc_url: None,
                 #[cfg(feature = "evm")]
                 evm_chain_id: None,
+                #[cfg(feature = "evm")]
+                evm_rpc_fallback_urls: Vec::new(),
             }
         });
 
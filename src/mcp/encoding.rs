@@ -0,0 +1,171 @@
+//! Response Encoding for the MCP HTTP Transport
+//!
+//! The HTTP transport speaks JSON-RPC, but high-throughput programmatic consumers
+//! can ask for a binary encoding of the response body through the `Accept` header:
+//!
+//! - `application/json` (default)
+//! - `application/msgpack` / `application/x-msgpack`
+//! - `application/cbor`
+//!
+//! Request bodies are always JSON; only the response envelope is re-encoded.
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::warn;
+
+use super::server::{McpResult, McpServerError};
+
+/// Wire encoding negotiated for an HTTP response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseEncoding {
+    /// JSON (`application/json`)
+    #[default]
+    Json,
+    /// MessagePack (`application/msgpack`)
+    MessagePack,
+    /// CBOR (`application/cbor`)
+    Cbor,
+}
+
+impl ResponseEncoding {
+    /// Pick an encoding from an `Accept` header value.
+    ///
+    /// Media ranges are honoured in order of their `q` weight; unknown or
+    /// wildcard ranges fall back to JSON.
+    pub fn from_accept(accept: &str) -> Self {
+        let mut candidates: Vec<(f32, usize, Self)> = accept
+            .split(',')
+            .enumerate()
+            .filter_map(|(position, range)| {
+                let mut parts = range.split(';');
+                let media_type = parts.next()?.trim().to_ascii_lowercase();
+                let quality = parts
+                    .filter_map(|param| param.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                if quality <= 0.0 {
+                    return None;
+                }
+                Self::from_media_type(&media_type).map(|encoding| (quality, position, encoding))
+            })
+            .collect();
+
+        // Highest quality first; ties resolved by header order
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+        candidates
+            .first()
+            .map(|(_, _, encoding)| *encoding)
+            .unwrap_or_default()
+    }
+
+    /// Pick an encoding from request headers, defaulting to JSON
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .map(Self::from_accept)
+            .unwrap_or_default()
+    }
+
+    /// Map a single media type to an encoding
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type {
+            "application/json" | "application/*" | "*/*" => Some(Self::Json),
+            "application/msgpack" | "application/x-msgpack" | "application/vnd.msgpack" => {
+                Some(Self::MessagePack)
+            }
+            "application/cbor" => Some(Self::Cbor),
+            _ => None,
+        }
+    }
+
+    /// Content-Type header value for this encoding
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::MessagePack => "application/msgpack",
+            Self::Cbor => "application/cbor",
+        }
+    }
+
+    /// Serialize a value into this encoding
+    pub fn encode<T: Serialize>(&self, value: &T) -> McpResult<Vec<u8>> {
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(McpServerError::Serialization),
+            Self::MessagePack => rmp_serde::to_vec_named(value).map_err(|e| {
+                McpServerError::Internal(format!("MessagePack serialization failed: {}", e))
+            }),
+            Self::Cbor => {
+                let mut buffer = Vec::new();
+                ciborium::into_writer(value, &mut buffer).map_err(|e| {
+                    McpServerError::Internal(format!("CBOR serialization failed: {}", e))
+                })?;
+                Ok(buffer)
+            }
+        }
+    }
+
+    /// Build an HTTP response carrying `value` in this encoding
+    pub fn into_response<T: Serialize>(self, value: &T) -> Response {
+        match self.encode(value) {
+            Ok(body) => (
+                [(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(self.content_type()),
+                )],
+                body,
+            )
+                .into_response(),
+            Err(e) => {
+                warn!("Failed to encode HTTP response as {:?}: {}", self, e);
+                (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_accept_negotiation() {
+        assert_eq!(
+            ResponseEncoding::from_accept("application/json"),
+            ResponseEncoding::Json
+        );
+        assert_eq!(
+            ResponseEncoding::from_accept("application/msgpack"),
+            ResponseEncoding::MessagePack
+        );
+        assert_eq!(
+            ResponseEncoding::from_accept("application/cbor, application/json;q=0.5"),
+            ResponseEncoding::Cbor
+        );
+        assert_eq!(
+            ResponseEncoding::from_accept("application/json;q=0.2, application/x-msgpack"),
+            ResponseEncoding::MessagePack
+        );
+        assert_eq!(
+            ResponseEncoding::from_accept("text/html, application/cbor;q=0"),
+            ResponseEncoding::Json
+        );
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let value = json!({"jsonrpc": "2.0", "id": 1, "result": {"pools": [1, 2, 3]}});
+
+        let msgpack = ResponseEncoding::MessagePack.encode(&value).unwrap();
+        let decoded: serde_json::Value = rmp_serde::from_slice(&msgpack).unwrap();
+        assert_eq!(decoded, value);
+
+        let cbor = ResponseEncoding::Cbor.encode(&value).unwrap();
+        let decoded: serde_json::Value = ciborium::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(decoded, value);
+    }
+}
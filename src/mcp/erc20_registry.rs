@@ -1,6 +1,6 @@
 use crate::error::Error;
 use crate::protocols::evm::types::EthAddress;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::env;
@@ -42,6 +42,14 @@ pub struct Erc20TokenInfo {
     pub chain_id: u64,
     pub last_refreshed: Option<Instant>,
     pub source: TokenSource,
+    /// Fingerprint of the code the metadata was read from, see
+    /// `code_fingerprint`; unset until it is read from the chain
+    pub code_fingerprint: Option<B256>,
+    /// Implementation the token delegates to when it is an EIP-1967 proxy;
+    /// unset until its code is read from the chain
+    pub implementation: Option<Address>,
+    /// When a tool call last used the token
+    pub last_used: Option<Instant>,
 }
 
 impl Erc20TokenInfo {
@@ -355,12 +363,52 @@ impl Erc20Registry {
         Ok(false)
     }
 
+    pub fn is_custom(&self, chain_id: u64, address: &Address) -> bool {
+        self.custom_tokens
+            .contains(&TokenKey::new(chain_id, *address))
+    }
+
     pub fn mark_refreshed(&mut self, chain_id: u64, address: &Address) {
         if let Some(info) = self.get_mut(chain_id, address) {
             info.last_refreshed = Some(Instant::now());
         }
     }
 
+    /// Note that a tool call used the token, so background refreshes reach
+    /// it first
+    pub fn mark_used(&mut self, chain_id: u64, address: &Address) {
+        if let Some(info) = self.get_mut(chain_id, address) {
+            info.last_used = Some(Instant::now());
+        }
+    }
+
+    /// Forget when the metadata of a token was fetched and the code it was
+    /// read from, so it is fetched again
+    pub fn invalidate(&mut self, chain_id: u64, address: &Address) -> bool {
+        match self.get_mut(chain_id, address) {
+            Some(info) => {
+                info.last_refreshed = None;
+                info.code_fingerprint = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Tokens of `chain_id` in the order background refreshes visit them:
+    /// the most recently used first, then the others, least recently
+    /// refreshed first
+    pub fn refresh_order(&self, chain_id: u64) -> Vec<Erc20TokenInfo> {
+        let mut tokens = self.list_for_chain(chain_id);
+        tokens.sort_by(|a, b| match (a.last_used, b.last_used) {
+            (Some(a), Some(b)) => b.cmp(&a),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.last_refreshed.cmp(&b.last_refreshed),
+        });
+        tokens
+    }
+
     pub fn is_stale(&self, chain_id: u64, address: &Address) -> bool {
         self.get(chain_id, address)
             .map(|info| info.needs_refresh(self.ttl))
@@ -467,6 +515,9 @@ impl TokenEntry {
             chain_id,
             last_refreshed: None,
             source,
+            code_fingerprint: None,
+            implementation: None,
+            last_used: None,
         })
     }
 }
@@ -476,3 +527,67 @@ impl Erc20Registry {
         EthAddress(key.address)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(
+        byte: u8,
+        last_used: Option<Instant>,
+        last_refreshed: Option<Instant>,
+    ) -> Erc20TokenInfo {
+        Erc20TokenInfo {
+            address: Address::repeat_byte(byte),
+            symbol: format!("T{}", byte),
+            name: None,
+            decimals: 18,
+            chain_id: 5887,
+            last_refreshed,
+            source: TokenSource::Discovered,
+            code_fingerprint: Some(B256::repeat_byte(byte)),
+            implementation: None,
+            last_used,
+        }
+    }
+
+    #[test]
+    fn test_recently_used_tokens_are_refreshed_first() {
+        let now = Instant::now();
+        let earlier = now - Duration::from_secs(60);
+        let mut registry = Erc20Registry {
+            ttl: Duration::from_secs(600),
+            ..Default::default()
+        };
+        registry.upsert_runtime(token(1, None, Some(now)));
+        registry.upsert_runtime(token(2, Some(earlier), Some(now)));
+        registry.upsert_runtime(token(3, None, None));
+        registry.upsert_runtime(token(4, Some(now), Some(now)));
+
+        let order: Vec<u8> = registry
+            .refresh_order(5887)
+            .iter()
+            .map(|token| token.address.0[0])
+            .collect();
+        assert_eq!(order, vec![4, 2, 3, 1]);
+
+        registry.mark_used(5887, &Address::repeat_byte(1));
+        assert_eq!(
+            registry.refresh_order(5887)[0].address,
+            Address::repeat_byte(1)
+        );
+
+        // An invalidated token is stale and has no code to compare
+        assert!(!registry.is_stale(5887, &Address::repeat_byte(4)));
+        assert!(registry.invalidate(5887, &Address::repeat_byte(4)));
+        assert!(registry.is_stale(5887, &Address::repeat_byte(4)));
+        assert_eq!(
+            registry
+                .get(5887, &Address::repeat_byte(4))
+                .unwrap()
+                .code_fingerprint,
+            None
+        );
+        assert!(!registry.invalidate(5887, &Address::repeat_byte(9)));
+    }
+}
@@ -0,0 +1,249 @@
+//! Health and Readiness of the MCP Server
+//!
+//! A [`HealthReport`] collects the status of each component the server depends
+//! on: the Cosmos RPC endpoint, the EVM RPC endpoint when one is configured,
+//! the active wallet and the connection pools. Chain components carry the
+//! latest block height and its lag behind the wall clock; a chain lagging more
+//! than `max_block_lag_secs` is degraded.
+//!
+//! The overall status is the worst component status, except that a
+//! non-critical component (EVM RPC, wallet) that is down only degrades the
+//! server. The server is alive unless it is down, and ready only when every
+//! critical component is ok. `/healthz` and `/readyz` answer `503` otherwise.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::response_format::MarkdownRender;
+
+/// Health probe settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthConfig {
+    /// Seconds the latest block may be behind the wall clock before the chain is degraded
+    #[serde(default = "default_max_block_lag_secs")]
+    pub max_block_lag_secs: u64,
+    /// Time each RPC probe is given before its endpoint counts as down
+    #[serde(default = "default_probe_timeout_ms")]
+    pub probe_timeout_ms: u64,
+}
+
+fn default_max_block_lag_secs() -> u64 {
+    60
+}
+
+fn default_probe_timeout_ms() -> u64 {
+    5_000
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            max_block_lag_secs: default_max_block_lag_secs(),
+            probe_timeout_ms: default_probe_timeout_ms(),
+        }
+    }
+}
+
+/// Status of a component or the whole server, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    Ok,
+    Degraded,
+    Down,
+}
+
+impl ComponentStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Degraded => "degraded",
+            Self::Down => "down",
+        }
+    }
+}
+
+/// Probe result of one component
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentHealth {
+    pub status: ComponentStatus,
+    /// Whether the server is unusable while this component is down
+    pub critical: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    /// Latest block height seen by a chain endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// Seconds the latest block is behind the wall clock
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lag_secs: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ComponentHealth {
+    pub fn new(status: ComponentStatus, critical: bool) -> Self {
+        Self {
+            status,
+            critical,
+            latency_ms: None,
+            height: None,
+            lag_secs: None,
+            detail: None,
+        }
+    }
+
+    /// Unreachable or failing component
+    pub fn down(critical: bool, detail: impl Into<String>) -> Self {
+        Self::new(ComponentStatus::Down, critical).with_detail(detail)
+    }
+
+    /// Chain endpoint that returned block `height` produced at `block_time`
+    /// (unix seconds), degraded when it lags more than `max_lag_secs`
+    pub fn chain(
+        critical: bool,
+        height: u64,
+        block_time: i64,
+        now: i64,
+        max_lag_secs: u64,
+    ) -> Self {
+        let lag_secs = (now - block_time).max(0);
+        let status = if lag_secs as u64 > max_lag_secs {
+            ComponentStatus::Degraded
+        } else {
+            ComponentStatus::Ok
+        };
+        let mut health = Self::new(status, critical);
+        health.height = Some(height);
+        health.lag_secs = Some(lag_secs);
+        if status == ComponentStatus::Degraded {
+            health.detail = Some(format!(
+                "latest block is {}s old (limit {}s)",
+                lag_secs, max_lag_secs
+            ));
+        }
+        health
+    }
+
+    pub fn with_latency_ms(mut self, latency_ms: u64) -> Self {
+        self.latency_ms = Some(latency_ms);
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// Status of every probed component and the verdicts derived from them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub status: ComponentStatus,
+    /// Whether every critical component is ok
+    pub ready: bool,
+    pub timestamp: DateTime<Utc>,
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+impl HealthReport {
+    pub fn new(components: BTreeMap<String, ComponentHealth>, timestamp: DateTime<Utc>) -> Self {
+        let status = components
+            .values()
+            .map(|component| match component.status {
+                ComponentStatus::Down if !component.critical => ComponentStatus::Degraded,
+                status => status,
+            })
+            .max()
+            .unwrap_or(ComponentStatus::Ok);
+        let ready = components
+            .values()
+            .filter(|component| component.critical)
+            .all(|component| component.status == ComponentStatus::Ok);
+
+        Self {
+            status,
+            ready,
+            timestamp,
+            components,
+        }
+    }
+
+    /// Whether the server should be left running
+    pub fn is_alive(&self) -> bool {
+        self.status != ComponentStatus::Down
+    }
+}
+
+impl MarkdownRender for HealthReport {
+    fn to_markdown(&self) -> String {
+        let mut text = format!(
+            "## Server Health\n\n**Status:** {}\n**Ready:** {}\n\n",
+            self.status.as_str(),
+            if self.ready { "yes" } else { "no" }
+        );
+        for (name, component) in &self.components {
+            text.push_str(&format!("- **{}**: {}", name, component.status.as_str()));
+            if let Some(height) = component.height {
+                text.push_str(&format!(", height {}", height));
+            }
+            if let Some(lag_secs) = component.lag_secs {
+                text.push_str(&format!(", {}s behind", lag_secs));
+            }
+            if let Some(latency_ms) = component.latency_ms {
+                text.push_str(&format!(", {} ms", latency_ms));
+            }
+            if let Some(detail) = &component.detail {
+                text.push_str(&format!(" ({})", detail));
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_and_readiness_from_components() {
+        let now = 1_700_000_000;
+        let mut components = BTreeMap::new();
+        components.insert(
+            "cosmos_rpc".to_string(),
+            ComponentHealth::chain(true, 100, now - 5, now, 60),
+        );
+        components.insert(
+            "evm_rpc".to_string(),
+            ComponentHealth::down(false, "connection refused"),
+        );
+        let report = HealthReport::new(components.clone(), Utc::now());
+        assert_eq!(report.status, ComponentStatus::Degraded);
+        assert!(report.ready);
+        assert!(report.is_alive());
+
+        // A stalled chain keeps the server alive but takes it out of rotation
+        components.insert(
+            "cosmos_rpc".to_string(),
+            ComponentHealth::chain(true, 100, now - 600, now, 60),
+        );
+        let report = HealthReport::new(components.clone(), Utc::now());
+        assert_eq!(report.components["cosmos_rpc"].lag_secs, Some(600));
+        assert!(!report.ready);
+        assert!(report.is_alive());
+
+        components.insert(
+            "cosmos_rpc".to_string(),
+            ComponentHealth::down(true, "timed out"),
+        );
+        let report = HealthReport::new(components, Utc::now());
+        assert_eq!(report.status, ComponentStatus::Down);
+        assert!(!report.is_alive());
+        assert!(report
+            .to_markdown()
+            .contains("- **cosmos_rpc**: down (timed out)"));
+    }
+}
@@ -0,0 +1,289 @@
+//! Idempotency Keys for Write Tool Calls
+//!
+//! A write tool call may carry an `idempotency_key` argument. The first call
+//! with a key runs as usual and its result, with the transaction hash it
+//! carries, is remembered; a later call with the same key gets that result
+//! back instead of broadcasting again. An agent that timed out waiting for a
+//! swap can therefore retry it without risking a second swap.
+//!
+//! Keys are scoped to the caller's principal. Reusing a key for another tool
+//! or other arguments is refused, and so is a retry while the first call is
+//! still running. A call that fails forgets its key, so it can be retried with
+//! the same key once the cause is fixed; a failure after broadcast, such as a
+//! confirmation timeout, should be checked on chain before retrying.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use super::audit_log::{arguments_digest, find_tx_hash};
+use super::server::{McpResult, McpServerError};
+
+/// Results are replayed for this long after their call completed
+pub const IDEMPOTENCY_KEY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Longest idempotency key accepted
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// Extract the `idempotency_key` argument of a tool call
+///
+/// Returns `Ok(None)` when there is none and an error when it is not a
+/// non-empty string of at most [`MAX_IDEMPOTENCY_KEY_LEN`] bytes.
+pub fn idempotency_key_from_arguments(arguments: &Value) -> McpResult<Option<String>> {
+    match arguments.get("idempotency_key") {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(key)) if !key.is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LEN => {
+            Ok(Some(key.clone()))
+        }
+        Some(_) => Err(McpServerError::InvalidArguments(format!(
+            "idempotency_key must be a string of 1 to {} bytes",
+            MAX_IDEMPOTENCY_KEY_LEN
+        ))),
+    }
+}
+
+#[derive(Debug, Clone)]
+enum KeyState {
+    InFlight,
+    Completed {
+        result: Value,
+        tx_hash: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct KeyEntry {
+    tool: String,
+    args_sha256: String,
+    state: KeyState,
+    updated_at: Instant,
+}
+
+/// What to do with a call carrying an idempotency key
+pub enum Admission<'a> {
+    /// First call with the key; run it and complete the guard with its result
+    New(IdempotentCall<'a>),
+    /// The key's call already completed with this result
+    Replay(Value),
+}
+
+/// A call admitted under an idempotency key
+///
+/// Dropping it without [`IdempotentCall::complete`], as when the call is
+/// refused or cancelled, forgets the key.
+pub struct IdempotentCall<'a> {
+    store: &'a IdempotencyStore,
+    key: String,
+    completed: bool,
+}
+
+impl IdempotentCall<'_> {
+    /// Remember a successful result for replay; a failure forgets the key
+    pub fn complete(mut self, result: &McpResult<Value>) {
+        self.completed = true;
+        let mut entries = self.store.lock();
+        match result {
+            Ok(result) => {
+                if let Some(entry) = entries.get_mut(&self.key) {
+                    entry.state = KeyState::Completed {
+                        result: result.clone(),
+                        tx_hash: find_tx_hash(result),
+                    };
+                    entry.updated_at = Instant::now();
+                }
+            }
+            Err(_) => {
+                entries.remove(&self.key);
+            }
+        }
+    }
+}
+
+impl Drop for IdempotentCall<'_> {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.store.lock().remove(&self.key);
+        }
+    }
+}
+
+/// Results of write calls by idempotency key
+#[derive(Debug, Default)]
+pub struct IdempotencyStore {
+    entries: Mutex<HashMap<String, KeyEntry>>,
+}
+
+impl IdempotencyStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Admit a `tool` call with `arguments` under `key`, scoped to `principal`
+    pub fn begin(
+        &self,
+        principal: Option<&str>,
+        key: &str,
+        tool: &str,
+        arguments: &Value,
+    ) -> McpResult<Admission<'_>> {
+        let scoped_key = format!("{}\u{0}{}", principal.unwrap_or_default(), key);
+        let args_sha256 = arguments_digest(&without_key(arguments));
+        let now = Instant::now();
+        let mut entries = self.lock();
+        // Forget completed calls past their TTL so the map doesn't grow without bound
+        entries.retain(|_, entry| {
+            matches!(entry.state, KeyState::InFlight)
+                || now - entry.updated_at < IDEMPOTENCY_KEY_TTL
+        });
+
+        if let Some(entry) = entries.get(&scoped_key) {
+            if entry.tool != tool || entry.args_sha256 != args_sha256 {
+                return Err(McpServerError::InvalidArguments(format!(
+                    "idempotency_key '{}' was already used for a different {} call; use a new key for a new operation",
+                    key, entry.tool
+                )));
+            }
+            return match &entry.state {
+                KeyState::InFlight => Err(McpServerError::ReplayRejected(format!(
+                    "the call with idempotency_key '{}' is still in progress; retry once it completes",
+                    key
+                ))),
+                KeyState::Completed { result, .. } => Ok(Admission::Replay(result.clone())),
+            };
+        }
+
+        entries.insert(
+            scoped_key.clone(),
+            KeyEntry {
+                tool: tool.to_string(),
+                args_sha256,
+                state: KeyState::InFlight,
+                updated_at: now,
+            },
+        );
+        Ok(Admission::New(IdempotentCall {
+            store: self,
+            key: scoped_key,
+            completed: false,
+        }))
+    }
+
+    /// Transaction hash recorded for `key`, once its call has completed
+    pub fn tx_hash(&self, principal: Option<&str>, key: &str) -> Option<String> {
+        let scoped_key = format!("{}\u{0}{}", principal.unwrap_or_default(), key);
+        match &self.lock().get(&scoped_key)?.state {
+            KeyState::Completed { tx_hash, .. } => tx_hash.clone(),
+            KeyState::InFlight => None,
+        }
+    }
+
+    /// Key store snapshot for health and diagnostics output
+    pub fn snapshot(&self) -> Value {
+        let entries = self.lock();
+        let in_flight = entries
+            .values()
+            .filter(|entry| matches!(entry.state, KeyState::InFlight))
+            .count();
+        serde_json::json!({
+            "keys": entries.len(),
+            "in_flight": in_flight,
+            "ttl_secs": IDEMPOTENCY_KEY_TTL.as_secs(),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, KeyEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// `arguments` without the idempotency key, which is not part of the operation
+fn without_key(arguments: &Value) -> Value {
+    let mut arguments = arguments.clone();
+    if let Some(map) = arguments.as_object_mut() {
+        map.remove("idempotency_key");
+    }
+    arguments
+}
+
+/// Mark a tool result as replayed for an idempotency key in its `_meta`
+pub fn mark_idempotent_replay(mut result: Value) -> Value {
+    if let Some(map) = result.as_object_mut() {
+        let meta = map.entry("_meta").or_insert_with(|| serde_json::json!({}));
+        if let Some(meta) = meta.as_object_mut() {
+            meta.insert("idempotent_replay".to_string(), Value::Bool(true));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(amount: &str) -> Value {
+        serde_json::json!({
+            "amount": amount,
+            "idempotency_key": "swap-1",
+            "_meta": { "session_id": "a" }
+        })
+    }
+
+    fn admit<'a>(store: &'a IdempotencyStore, arguments: &Value) -> McpResult<Admission<'a>> {
+        store.begin(None, "swap-1", "dex_execute_swap", arguments)
+    }
+
+    #[test]
+    fn test_replays_completed_calls_and_refuses_conflicts() {
+        let store = IdempotencyStore::new();
+        let Admission::New(call) = admit(&store, &swap("5")).unwrap() else {
+            panic!("first call must run");
+        };
+        // A retry while the first call runs is refused
+        assert!(matches!(
+            admit(&store, &swap("5")),
+            Err(McpServerError::ReplayRejected(_))
+        ));
+        let result =
+            serde_json::json!({ "content": [{ "type": "text", "text": "{\"tx_hash\":\"ABC\"}" }] });
+        call.complete(&Ok(result.clone()));
+
+        match admit(&store, &swap("5")).unwrap() {
+            Admission::Replay(replayed) => assert_eq!(replayed, result),
+            Admission::New(_) => panic!("completed call must be replayed"),
+        }
+        assert_eq!(store.tx_hash(None, "swap-1").as_deref(), Some("ABC"));
+        assert!(matches!(
+            admit(&store, &swap("6")),
+            Err(McpServerError::InvalidArguments(_))
+        ));
+        // Keys are scoped to the principal
+        assert!(matches!(
+            store.begin(Some("bob"), "swap-1", "dex_execute_swap", &swap("6")),
+            Ok(Admission::New(_))
+        ));
+    }
+
+    #[test]
+    fn test_failed_and_dropped_calls_forget_the_key() {
+        let store = IdempotencyStore::new();
+        let Admission::New(call) = admit(&store, &swap("5")).unwrap() else {
+            panic!("first call must run");
+        };
+        call.complete(&Err(McpServerError::Other("out of gas".to_string())));
+        let Admission::New(call) = admit(&store, &swap("5")).unwrap() else {
+            panic!("failed call must run again");
+        };
+        drop(call);
+        assert!(matches!(admit(&store, &swap("5")), Ok(Admission::New(_))));
+
+        assert!(
+            idempotency_key_from_arguments(&serde_json::json!({ "idempotency_key": 7 })).is_err()
+        );
+        assert_eq!(
+            idempotency_key_from_arguments(&serde_json::json!({})).unwrap(),
+            None
+        );
+    }
+}
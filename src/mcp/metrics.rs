@@ -0,0 +1,357 @@
+//! Prometheus Metrics of the MCP Server
+//!
+//! Tool calls, their latencies and errors, transaction broadcasts and cache
+//! lookups are counted as they happen. Connection pool figures are read when
+//! the metrics are rendered. The HTTP transport serves the result on
+//! `/metrics` in the Prometheus text exposition format.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds, in seconds, of the tool latency histogram buckets
+pub const LATENCY_BUCKETS_SECS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+/// Content type of the Prometheus text exposition format
+pub const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Observations per bucket of [`LATENCY_BUCKETS_SECS`], not cumulative
+    buckets: Vec<u64>,
+    count: u64,
+    sum_secs: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, secs: f64) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; LATENCY_BUCKETS_SECS.len()];
+        }
+        if let Some(index) = LATENCY_BUCKETS_SECS.iter().position(|&bound| secs <= bound) {
+            self.buckets[index] += 1;
+        }
+        self.count += 1;
+        self.sum_secs += secs;
+    }
+}
+
+#[derive(Debug, Default)]
+struct MetricsInner {
+    /// Calls by tool and outcome
+    tool_calls: BTreeMap<(String, &'static str), u64>,
+    /// Failed calls by tool and error type
+    tool_errors: BTreeMap<(String, String), u64>,
+    tool_latency: BTreeMap<String, Histogram>,
+    /// Broadcasting write calls by tool and outcome
+    broadcasts: BTreeMap<(String, &'static str), u64>,
+    /// Lookups by cache and result
+    cache_lookups: BTreeMap<(&'static str, &'static str), u64>,
+}
+
+/// Sizes of a network's connection pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolGauges {
+    pub connections: usize,
+    pub healthy: usize,
+    pub available_permits: usize,
+    /// Configured RPC endpoints
+    pub endpoints: usize,
+    /// RPC endpoints whose circuit is not open
+    pub endpoints_available: usize,
+}
+
+/// Counters and histograms of the MCP server
+#[derive(Debug, Default)]
+pub struct McpMetrics {
+    inner: Mutex<MetricsInner>,
+}
+
+impl McpMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a finished tool call; `error_type` is set for failed calls
+    pub fn record_tool_call(&self, tool: &str, elapsed: Duration, error_type: Option<&str>) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let outcome = if error_type.is_some() {
+            "error"
+        } else {
+            "success"
+        };
+        *inner
+            .tool_calls
+            .entry((tool.to_string(), outcome))
+            .or_default() += 1;
+        if let Some(error_type) = error_type {
+            *inner
+                .tool_errors
+                .entry((tool.to_string(), error_type.to_string()))
+                .or_default() += 1;
+        }
+        inner
+            .tool_latency
+            .entry(tool.to_string())
+            .or_default()
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Count a write call that was admitted to broadcast a transaction
+    pub fn record_broadcast(&self, tool: &str, success: bool) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let outcome = if success { "success" } else { "failure" };
+        *inner
+            .broadcasts
+            .entry((tool.to_string(), outcome))
+            .or_default() += 1;
+    }
+
+    /// Count a lookup in the cache named `cache`
+    pub fn record_cache_lookup(&self, cache: &'static str, hit: bool) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let result = if hit { "hit" } else { "miss" };
+        *inner.cache_lookups.entry((cache, result)).or_default() += 1;
+    }
+
+    /// Render every metric in the Prometheus text format
+    ///
+    /// `pools` holds the connection pool sizes by network and `cache_entries`
+    /// the number of entries in each cache.
+    pub fn render(
+        &self,
+        pools: &HashMap<String, PoolGauges>,
+        cache_entries: &[(&'static str, usize)],
+    ) -> String {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let mut out = String::new();
+
+        header(
+            &mut out,
+            "mantra_mcp_tool_calls_total",
+            "counter",
+            "Tool calls by tool and outcome",
+        );
+        for ((tool, outcome), count) in &inner.tool_calls {
+            sample(
+                &mut out,
+                "mantra_mcp_tool_calls_total",
+                &[("tool", tool), ("outcome", outcome)],
+                *count as f64,
+            );
+        }
+
+        header(
+            &mut out,
+            "mantra_mcp_tool_errors_total",
+            "counter",
+            "Failed tool calls by tool and error type",
+        );
+        for ((tool, error_type), count) in &inner.tool_errors {
+            sample(
+                &mut out,
+                "mantra_mcp_tool_errors_total",
+                &[("tool", tool), ("error_type", error_type)],
+                *count as f64,
+            );
+        }
+
+        header(
+            &mut out,
+            "mantra_mcp_tool_duration_seconds",
+            "histogram",
+            "Tool call latency",
+        );
+        for (tool, histogram) in &inner.tool_latency {
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS_SECS.iter().zip(&histogram.buckets) {
+                cumulative += count;
+                sample(
+                    &mut out,
+                    "mantra_mcp_tool_duration_seconds_bucket",
+                    &[("tool", tool), ("le", &bound.to_string())],
+                    cumulative as f64,
+                );
+            }
+            sample(
+                &mut out,
+                "mantra_mcp_tool_duration_seconds_bucket",
+                &[("tool", tool), ("le", "+Inf")],
+                histogram.count as f64,
+            );
+            sample(
+                &mut out,
+                "mantra_mcp_tool_duration_seconds_sum",
+                &[("tool", tool)],
+                histogram.sum_secs,
+            );
+            sample(
+                &mut out,
+                "mantra_mcp_tool_duration_seconds_count",
+                &[("tool", tool)],
+                histogram.count as f64,
+            );
+        }
+
+        header(
+            &mut out,
+            "mantra_mcp_broadcasts_total",
+            "counter",
+            "Admitted transaction-producing tool calls by tool and outcome",
+        );
+        for ((tool, outcome), count) in &inner.broadcasts {
+            sample(
+                &mut out,
+                "mantra_mcp_broadcasts_total",
+                &[("tool", tool), ("outcome", outcome)],
+                *count as f64,
+            );
+        }
+
+        header(
+            &mut out,
+            "mantra_mcp_cache_lookups_total",
+            "counter",
+            "Cache lookups by cache and result",
+        );
+        for ((cache, result), count) in &inner.cache_lookups {
+            sample(
+                &mut out,
+                "mantra_mcp_cache_lookups_total",
+                &[("cache", cache), ("result", result)],
+                *count as f64,
+            );
+        }
+
+        header(
+            &mut out,
+            "mantra_mcp_cache_entries",
+            "gauge",
+            "Entries held by each cache",
+        );
+        for (cache, entries) in cache_entries {
+            sample(
+                &mut out,
+                "mantra_mcp_cache_entries",
+                &[("cache", cache)],
+                *entries as f64,
+            );
+        }
+
+        let mut networks: Vec<_> = pools.iter().collect();
+        networks.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, help, value) in [
+            (
+                "mantra_mcp_connection_pool_connections",
+                "Pooled RPC connections by network",
+                (|gauges: &PoolGauges| gauges.connections) as fn(&PoolGauges) -> usize,
+            ),
+            (
+                "mantra_mcp_connection_pool_healthy_connections",
+                "Healthy pooled RPC connections by network",
+                |gauges| gauges.healthy,
+            ),
+            (
+                "mantra_mcp_connection_pool_available_permits",
+                "Connections that may still be opened by network",
+                |gauges| gauges.available_permits,
+            ),
+            (
+                "mantra_mcp_rpc_endpoints",
+                "Configured RPC endpoints by network",
+                |gauges| gauges.endpoints,
+            ),
+            (
+                "mantra_mcp_rpc_endpoints_available",
+                "RPC endpoints whose circuit breaker is not open by network",
+                |gauges| gauges.endpoints_available,
+            ),
+        ] {
+            header(&mut out, name, "gauge", help);
+            for (network, gauges) in &networks {
+                sample(
+                    &mut out,
+                    name,
+                    &[("network", network)],
+                    value(gauges) as f64,
+                );
+            }
+        }
+
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn sample(out: &mut String, name: &str, labels: &[(&str, &str)], value: f64) {
+    let labels: Vec<String> = labels
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+        .collect();
+    let _ = writeln!(out, "{}{{{}}} {}", name, labels.join(","), value);
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_counts_histograms_and_gauges() {
+        let metrics = McpMetrics::new();
+        metrics.record_tool_call("dex_execute_swap", Duration::from_millis(20), None);
+        metrics.record_tool_call(
+            "dex_execute_swap",
+            Duration::from_secs(3),
+            Some("TxBroadcast"),
+        );
+        metrics.record_broadcast("dex_execute_swap", false);
+        metrics.record_cache_lookup("adapter", true);
+        metrics.record_cache_lookup("adapter", false);
+
+        let pools = HashMap::from([(
+            "mantra-dukong".to_string(),
+            PoolGauges {
+                connections: 2,
+                healthy: 1,
+                available_permits: 8,
+                endpoints: 2,
+                endpoints_available: 1,
+            },
+        )]);
+        let text = metrics.render(&pools, &[("adapter", 3)]);
+
+        for line in [
+            "mantra_mcp_tool_calls_total{tool=\"dex_execute_swap\",outcome=\"error\"} 1",
+            "mantra_mcp_tool_calls_total{tool=\"dex_execute_swap\",outcome=\"success\"} 1",
+            "mantra_mcp_tool_errors_total{tool=\"dex_execute_swap\",error_type=\"TxBroadcast\"} 1",
+            "mantra_mcp_tool_duration_seconds_bucket{tool=\"dex_execute_swap\",le=\"0.025\"} 1",
+            "mantra_mcp_tool_duration_seconds_bucket{tool=\"dex_execute_swap\",le=\"2.5\"} 1",
+            "mantra_mcp_tool_duration_seconds_bucket{tool=\"dex_execute_swap\",le=\"5\"} 2",
+            "mantra_mcp_tool_duration_seconds_bucket{tool=\"dex_execute_swap\",le=\"+Inf\"} 2",
+            "mantra_mcp_tool_duration_seconds_count{tool=\"dex_execute_swap\"} 2",
+            "mantra_mcp_broadcasts_total{tool=\"dex_execute_swap\",outcome=\"failure\"} 1",
+            "mantra_mcp_cache_lookups_total{cache=\"adapter\",result=\"hit\"} 1",
+            "mantra_mcp_cache_entries{cache=\"adapter\"} 3",
+            "mantra_mcp_connection_pool_healthy_connections{network=\"mantra-dukong\"} 1",
+            "mantra_mcp_rpc_endpoints_available{network=\"mantra-dukong\"} 1",
+            "# TYPE mantra_mcp_tool_duration_seconds histogram",
+        ] {
+            assert!(text.lines().any(|l| l == line), "missing {}", line);
+        }
+        assert_eq!(escape_label("a\"b\\c"), "a\\\"b\\\\c");
+    }
+}
@@ -51,6 +51,72 @@ pub mod erc20_registry;
 // MCP client wrapper
 pub mod client_wrapper;
 
+// Response encoding negotiation for the HTTP transport
+pub mod encoding;
+
+// In-flight quotas for write operations
+pub mod quotas;
+
+// Nonce-based replay protection for write operations
+pub mod nonces;
+
+// Idempotency keys replaying the results of retried write operations
+pub mod idempotency;
+
+// Event-sourced adapter state
+pub mod state_store;
+
+// Operator approval of write operations
+pub mod approvals;
+
+// API key and JWT authentication of HTTP clients
+pub mod auth;
+
+// Per-tool authorization policy and spend limits
+pub mod authz;
+
+// Per-wallet spending budgets over rolling windows
+pub mod budget;
+
+// Per-client and global rate limits of the HTTP transport
+pub mod rate_limit;
+
+// Encrypted remote control channel for headless servers
+pub mod remote_control;
+
+// Paginated data resources (pools, tokens, campaigns, active wallet)
+pub mod resources;
+
+// Prompt templates for multi-step DeFi workflows
+pub mod prompts;
+
+// Rotating audit log of state-changing tool calls
+pub mod audit_log;
+
+// Prometheus metrics served by the HTTP transport
+pub mod metrics;
+
+// Progress notifications of long-running requests
+pub mod progress;
+
+// Correlation IDs and traces of recent tool calls
+pub mod traces;
+
+// JSON or markdown text of typed tool responses
+pub mod response_format;
+
+// Declarative tool arguments and input schema validation
+pub mod tool_args;
+
+// Bounded adapter cache with optional on-disk persistence
+pub mod cache;
+
+// Health and readiness reports of the server and its dependencies
+pub mod health;
+
+// Symbol, denom, IBC path and ERC-20 address resolution
+pub mod assets;
+
 // Re-export main types for easy access
 pub use server::{
     create_http_server, create_mcp_server, create_stdio_server, MantraDexMcpServer, McpResult,
@@ -63,6 +129,39 @@ pub use sdk_adapter::{ConnectionPoolConfig, McpSdkAdapter};
 // Re-export client wrapper types
 pub use client_wrapper::McpClientWrapper;
 
+// Re-export response encoding types
+pub use encoding::ResponseEncoding;
+
+// Re-export quota types
+pub use quotas::{WriteQuotaConfig, WriteQuotas};
+pub use rate_limit::{HttpRateLimitConfig, HttpRateLimiter};
+
+// Re-export replay protection types
+pub use nonces::NonceTracker;
+
+// Re-export state event log types
+pub use state_store::{AdapterState, EventStore, RecordedEvent, StateEvent};
+
+// Re-export operator approval and remote control types
+pub use approvals::{
+    ApprovalDecision, ApprovalQueue, PendingApproval, PendingTransaction, TransactionQueue,
+    TransactionQueueConfig,
+};
+pub use assets::{AssetKind, AssetRecord, AssetResolver};
+pub use audit_log::{AuditLog, AuditLogConfig, AuditQuery, AuditRecord};
+pub use auth::{HttpAuthConfig, HttpAuthenticator, Principal};
+pub use authz::{AuthorizationConfig, ToolAuthorizer, ToolClass};
+pub use cache::{AdapterCache, CacheBackend, CacheConfig, CacheStats, CacheStore};
+pub use health::{ComponentHealth, ComponentStatus, HealthConfig, HealthReport};
+pub use metrics::McpMetrics;
+pub use progress::{report_partial_result, report_progress, ProgressReporter};
+pub use remote_control::{
+    NoiseKeypair, RemoteControlClient, RemoteControlConfig, RemoteControlServer,
+};
+pub use response_format::{MarkdownRender, ResponseFormat};
+pub use tool_args::{input_schema, parse_arguments, validate_arguments, ArgumentError};
+pub use traces::{ToolCallTrace, TraceLog, TraceLogConfig, TraceQuery};
+
 // TODO: Add these modules as they are implemented in subsequent tasks
 // pub mod tools;
 // pub mod resources;
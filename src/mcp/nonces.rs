@@ -0,0 +1,167 @@
+//! Replay Protection for Write Tool Calls
+//!
+//! Clients may attach a monotonically increasing nonce to write tool calls in
+//! `_meta.nonce`. The server remembers the highest nonce accepted per session and
+//! rejects any call whose nonce is not greater, so an agent that retries a call
+//! after a transport hiccup cannot broadcast the same operation twice.
+//!
+//! A nonce is consumed as soon as the call is admitted, even if the tool later
+//! fails: a failed broadcast may still have reached the chain, so the client must
+//! check the outcome and use a fresh nonce for a deliberate retry.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tokio::sync::RwLock;
+
+use super::server::{McpResult, McpServerError};
+
+/// Sessions idle for longer than this are forgotten
+pub const NONCE_SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Extract the nonce from the `_meta` object carried in tool arguments
+///
+/// Returns `Ok(None)` when no nonce is present and an error when `_meta.nonce`
+/// is not an unsigned integer.
+pub fn nonce_from_arguments(arguments: &Value) -> McpResult<Option<u64>> {
+    match arguments.get("_meta").and_then(|meta| meta.get("nonce")) {
+        None | Some(Value::Null) => Ok(None),
+        Some(nonce) => nonce.as_u64().map(Some).ok_or_else(|| {
+            McpServerError::InvalidArguments(
+                "_meta.nonce must be a non-negative integer".to_string(),
+            )
+        }),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SessionNonce {
+    last: u64,
+    seen_at: Instant,
+}
+
+/// Tracks the highest accepted nonce per session
+#[derive(Debug)]
+pub struct NonceTracker {
+    /// Reject write calls that carry no nonce
+    required: bool,
+    sessions: RwLock<HashMap<String, SessionNonce>>,
+}
+
+impl NonceTracker {
+    /// Create a tracker; when `required` is set, write calls without a nonce are rejected
+    pub fn new(required: bool) -> Self {
+        Self {
+            required,
+            sessions: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Whether write calls must carry a nonce
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    /// Admit a write call for `session_id` carrying `nonce`
+    ///
+    /// Returns `McpServerError::ReplayRejected` if the nonce is not greater than
+    /// the last one accepted for the session.
+    pub async fn check_and_record(&self, session_id: &str, nonce: Option<u64>) -> McpResult<()> {
+        let Some(nonce) = nonce else {
+            if self.required {
+                return Err(McpServerError::ReplayRejected(
+                    "write tools require _meta.nonce".to_string(),
+                ));
+            }
+            return Ok(());
+        };
+
+        let mut sessions = self.sessions.write().await;
+        let now = Instant::now();
+        if !sessions.contains_key(session_id) {
+            // Drop idle sessions so the map doesn't grow without bound
+            sessions.retain(|_, entry| now - entry.seen_at < NONCE_SESSION_IDLE_TIMEOUT);
+        }
+
+        match sessions.get_mut(session_id) {
+            Some(entry) if nonce <= entry.last => Err(McpServerError::ReplayRejected(format!(
+                "nonce {} for session '{}' is not greater than the last accepted nonce {}",
+                nonce, session_id, entry.last
+            ))),
+            Some(entry) => {
+                entry.last = nonce;
+                entry.seen_at = now;
+                Ok(())
+            }
+            None => {
+                sessions.insert(
+                    session_id.to_string(),
+                    SessionNonce {
+                        last: nonce,
+                        seen_at: now,
+                    },
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Last accepted nonce for a session
+    pub async fn last_nonce(&self, session_id: &str) -> Option<u64> {
+        self.sessions
+            .read()
+            .await
+            .get(session_id)
+            .map(|entry| entry.last)
+    }
+
+    /// Nonce tracking snapshot for health and diagnostics output
+    pub async fn snapshot(&self) -> serde_json::Value {
+        serde_json::json!({
+            "required": self.required,
+            "tracked_sessions": self.sessions.read().await.len(),
+        })
+    }
+}
+
+impl Default for NonceTracker {
+    fn default() -> Self {
+        Self::new(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rejects_repeated_and_out_of_order_nonces() {
+        let tracker = NonceTracker::default();
+
+        tracker.check_and_record("a", Some(1)).await.unwrap();
+        tracker.check_and_record("a", Some(5)).await.unwrap();
+        for replay in [5, 3] {
+            assert!(matches!(
+                tracker.check_and_record("a", Some(replay)).await,
+                Err(McpServerError::ReplayRejected(_))
+            ));
+        }
+        assert_eq!(tracker.last_nonce("a").await, Some(5));
+
+        // Sessions are independent and calls without a nonce pass by default
+        tracker.check_and_record("b", Some(1)).await.unwrap();
+        tracker.check_and_record("a", None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_required_nonce_and_argument_parsing() {
+        let tracker = NonceTracker::new(true);
+        assert!(tracker.check_and_record("a", None).await.is_err());
+
+        let args = serde_json::json!({ "_meta": { "nonce": 7 } });
+        assert_eq!(nonce_from_arguments(&args).unwrap(), Some(7));
+        assert_eq!(nonce_from_arguments(&serde_json::json!({})).unwrap(), None);
+        assert!(nonce_from_arguments(&serde_json::json!({ "_meta": { "nonce": "7" } })).is_err());
+    }
+}
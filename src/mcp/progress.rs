@@ -0,0 +1,129 @@
+//! MCP Progress Notifications
+//!
+//! A client that sends `_meta.progressToken` with a request gets
+//! `notifications/progress` messages while the request runs. The transport
+//! runs the request inside [`ProgressReporter::scope`], and long-running tools
+//! call [`report_progress`] or [`report_partial_result`] as they go. Outside a
+//! scope both are no-ops, so tools report unconditionally.
+//!
+//! Over HTTP the notifications are streamed as Server-Sent Events ahead of the
+//! response; over stdio they are written as lines ahead of it.
+
+use std::future::Future;
+
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+tokio::task_local! {
+    static PROGRESS: ProgressReporter;
+}
+
+/// Method of MCP progress notifications
+pub const PROGRESS_NOTIFICATION: &str = "notifications/progress";
+
+/// Progress token a request carries in `params._meta.progressToken`
+pub fn progress_token(params: Option<&Value>) -> Option<Value> {
+    params
+        .and_then(|params| params.get("_meta"))
+        .and_then(|meta| meta.get("progressToken"))
+        .filter(|token| token.is_string() || token.is_number())
+        .cloned()
+}
+
+/// Sends the progress notifications of one request to its transport
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    token: Value,
+    sink: mpsc::UnboundedSender<Value>,
+}
+
+impl ProgressReporter {
+    pub fn new(token: Value, sink: mpsc::UnboundedSender<Value>) -> Self {
+        Self { token, sink }
+    }
+
+    /// Run `future` with its progress reported through this reporter
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        PROGRESS.scope(self, future).await
+    }
+
+    /// JSON-RPC notification of `progress` out of `total`
+    ///
+    /// Partial results ride along in the notification's `_meta`.
+    fn notification(
+        &self,
+        progress: u64,
+        total: Option<u64>,
+        message: &str,
+        partial_result: Option<Value>,
+    ) -> Value {
+        let mut params = serde_json::json!({
+            "progressToken": self.token,
+            "progress": progress,
+            "message": message,
+        });
+        if let Some(total) = total {
+            params["total"] = total.into();
+        }
+        if let Some(partial_result) = partial_result {
+            params["_meta"] = serde_json::json!({ "partialResult": partial_result });
+        }
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": PROGRESS_NOTIFICATION,
+            "params": params,
+        })
+    }
+
+    fn send(&self, notification: Value) {
+        // The transport stops listening once the client goes away
+        let _ = self.sink.send(notification);
+    }
+}
+
+/// Report that the current request has done `progress` of `total` steps
+pub fn report_progress(progress: u64, total: Option<u64>, message: &str) {
+    let _ = PROGRESS
+        .try_with(|reporter| reporter.send(reporter.notification(progress, total, message, None)));
+}
+
+/// Report progress together with the part of the result produced so far
+pub fn report_partial_result(progress: u64, total: Option<u64>, message: &str, partial: Value) {
+    let _ = PROGRESS.try_with(|reporter| {
+        reporter.send(reporter.notification(progress, total, message, Some(partial)))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scoped_reports_become_notifications() {
+        // Outside a scope reports go nowhere
+        report_progress(1, Some(2), "ignored");
+
+        let params = serde_json::json!({ "name": "x", "_meta": { "progressToken": "t1" } });
+        let token = progress_token(Some(&params)).unwrap();
+        let (sink, mut notifications) = mpsc::unbounded_channel();
+        ProgressReporter::new(token, sink)
+            .scope(async {
+                report_progress(1, Some(2), "first");
+                report_partial_result(2, None, "second", serde_json::json!(["a"]));
+            })
+            .await;
+
+        let first = notifications.recv().await.unwrap();
+        assert_eq!(first["method"], PROGRESS_NOTIFICATION);
+        assert_eq!(first["params"]["progressToken"], "t1");
+        assert_eq!(first["params"]["progress"], 1);
+        assert_eq!(first["params"]["total"], 2);
+        let second = notifications.recv().await.unwrap();
+        assert!(second["params"].get("total").is_none());
+        assert_eq!(second["params"]["_meta"]["partialResult"][0], "a");
+        assert!(notifications.try_recv().is_err());
+
+        assert!(progress_token(Some(&serde_json::json!({ "_meta": {} }))).is_none());
+        assert!(progress_token(None).is_none());
+    }
+}
@@ -0,0 +1,272 @@
+//! Prompt templates for common DeFi workflows
+//!
+//! Each prompt walks the user through a multi-step workflow as a numbered
+//! list of tool calls. Arguments fill `{slot}` placeholders in the steps, and
+//! every step names the server tool that carries it out, so an agent client
+//! can follow the prompt without guessing tool names.
+
+use serde_json::{Map, Value};
+
+/// Argument a prompt accepts
+#[derive(Debug, Clone, Copy)]
+pub struct PromptArgument {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Value used when the client leaves an optional argument out
+    pub default: Option<&'static str>,
+}
+
+impl PromptArgument {
+    const fn required(name: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            description,
+            default: None,
+        }
+    }
+
+    const fn optional(
+        name: &'static str,
+        description: &'static str,
+        default: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            default: Some(default),
+        }
+    }
+}
+
+/// One step of a workflow, carried out by `tool`
+#[derive(Debug, Clone, Copy)]
+pub struct PromptStep {
+    pub tool: &'static str,
+    /// What to do with the tool; may contain `{slot}` placeholders
+    pub instruction: &'static str,
+}
+
+/// Workflow prompt offered through `prompts/list` and `prompts/get`
+#[derive(Debug, Clone, Copy)]
+pub struct PromptTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Goal of the workflow; may contain `{slot}` placeholders
+    pub goal: &'static str,
+    pub arguments: &'static [PromptArgument],
+    pub steps: &'static [PromptStep],
+}
+
+const fn step(tool: &'static str, instruction: &'static str) -> PromptStep {
+    PromptStep { tool, instruction }
+}
+
+static PROMPTS: &[PromptTemplate] = &[
+    PromptTemplate {
+        name: "swap-with-slippage-review",
+        description: "Swap on the DEX after reviewing pool depth and the slippage limit with the user",
+        goal: "Swap {amount} {offer_denom} for {ask_denom} with at most {max_slippage}% slippage.",
+        arguments: &[
+            PromptArgument::required("offer_denom", "Denom of the asset to sell"),
+            PromptArgument::required("amount", "Amount to sell, in base units"),
+            PromptArgument::required("ask_denom", "Denom of the asset to buy"),
+            PromptArgument::optional("max_slippage", "Maximum slippage in percent", "1"),
+        ],
+        steps: &[
+            step("wallet_get_balances", "Confirm the active wallet holds at least {amount} {offer_denom} plus fees."),
+            step("dex_get_pools", "Find the pools trading {offer_denom} against {ask_denom}; prefer enabled pools with the largest reserves."),
+            step("dex_get_pool_depth", "Check the chosen pool's price impact for {amount} {offer_denom}. If it exceeds {max_slippage}%, tell the user and suggest a smaller amount before going on."),
+            step("dex_execute_swap", "Only after the user confirms the pool, the amount and the {max_slippage}% limit, swap with offer_asset {amount} {offer_denom}, ask_asset_denom {ask_denom} and max_slippage {max_slippage}."),
+            step("wallet_get_balances", "Report the {ask_denom} received and the {offer_denom} left."),
+        ],
+    },
+    PromptTemplate {
+        name: "provide-liquidity-checklist",
+        description: "Provide liquidity to a pool after checking its status, balances and expected share",
+        goal: "Provide {amount_a} {denom_a} and {amount_b} {denom_b} to pool {pool_id}.",
+        arguments: &[
+            PromptArgument::required("pool_id", "Pool to provide liquidity to"),
+            PromptArgument::required("denom_a", "Denom of the first asset"),
+            PromptArgument::required("amount_a", "Amount of the first asset, in base units"),
+            PromptArgument::required("denom_b", "Denom of the second asset"),
+            PromptArgument::required("amount_b", "Amount of the second asset, in base units"),
+            PromptArgument::optional("max_slippage", "Maximum slippage in percent", "1"),
+        ],
+        steps: &[
+            step("dex_get_pools", "Check that pool {pool_id} trades {denom_a} and {denom_b} and that deposits are enabled."),
+            step("wallet_get_balances", "Check the wallet holds {amount_a} {denom_a} and {amount_b} {denom_b} plus fees."),
+            step("dex_get_pool_depth", "Compare the pool's reserve ratio with {amount_a}:{amount_b}; if they differ, warn that the excess side is lost to slippage or suggest dex_zap_in instead."),
+            step("dex_provide_liquidity", "After the user confirms, provide {amount_a} {denom_a} and {amount_b} {denom_b} to pool {pool_id} with max_slippage {max_slippage}."),
+            step("dex_get_lp_token_balance", "Report the LP tokens received for pool {pool_id}."),
+        ],
+    },
+    PromptTemplate {
+        name: "settle-primary-sale-runbook",
+        description: "End a primary sale, settle it and distribute tokens to investors",
+        goal: "Settle primary sale {contract_address}, distributing {asset_token} from {asset_owner}.",
+        arguments: &[
+            PromptArgument::required("contract_address", "Primary sale contract"),
+            PromptArgument::required("asset_token", "Denom of the token distributed to investors"),
+            PromptArgument::required("asset_owner", "Address holding the tokens to distribute"),
+            PromptArgument::optional("max_loop", "Investors settled per transaction", "50"),
+        ],
+        steps: &[
+            step("primary_sale_get_sale_info", "Read the state of {contract_address}; stop if the sale is cancelled or already settled."),
+            step("primary_sale_end_sale", "If the sale is still open and past its end time, end it."),
+            step("primary_sale_get_all_investors", "List the investors of {contract_address} and total their commitments, so the user can check {asset_owner} holds enough {asset_token}."),
+            step("primary_sale_settle_and_distribute", "After the user confirms, settle with asset_token {asset_token}, asset_owner {asset_owner} and max_loop {max_loop}; repeat until no investors remain unsettled."),
+            step("primary_sale_get_sale_info", "Report the final sale state and the amount distributed."),
+        ],
+    },
+    PromptTemplate {
+        name: "airdrop-campaign-setup",
+        description: "Validate, dry-run and deploy a claimdrop campaign with its allocations",
+        goal: "Create an airdrop of {total_reward} {reward_denom} paid out as {schedule} through factory {factory_address}.",
+        arguments: &[
+            PromptArgument::required("factory_address", "Claimdrop factory contract"),
+            PromptArgument::required("reward_denom", "Denom of the reward"),
+            PromptArgument::required("total_reward", "Total reward, in base units"),
+            PromptArgument::optional("schedule", "Distribution schedule: lump_sum or linear_vesting", "lump_sum"),
+        ],
+        steps: &[
+            step("wallet_get_balances", "Confirm the wallet holds {total_reward} {reward_denom} to fund the campaign."),
+            step("claimdrop_query_campaigns", "List the campaigns of {factory_address} so the new one does not duplicate a running campaign."),
+            step("claimdrop_build_campaign", "Collect the name, description, start and end times and the allocations (JSON or CSV) from the user, then call with a single {schedule} distribution_type entry at percentage 1 and dry_run true and show the validation report."),
+            step("claimdrop_build_campaign", "After the user approves the dry run, repeat the call with dry_run false to deploy the campaign and upload its allocations."),
+            step("claimdrop_query_campaigns", "Report the new campaign's address."),
+        ],
+    },
+];
+
+/// All registered prompt templates
+pub fn prompt_templates() -> &'static [PromptTemplate] {
+    PROMPTS
+}
+
+/// Template named `name`
+pub fn find_prompt(name: &str) -> Option<&'static PromptTemplate> {
+    PROMPTS.iter().find(|prompt| prompt.name == name)
+}
+
+impl PromptTemplate {
+    /// Entry for `prompts/list`
+    pub fn descriptor(&self) -> Value {
+        serde_json::json!({
+            "name": self.name,
+            "description": self.description,
+            "arguments": self.arguments.iter().map(|argument| {
+                serde_json::json!({
+                    "name": argument.name,
+                    "description": argument.description,
+                    "required": argument.default.is_none(),
+                })
+            }).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Tools the workflow calls, in order of first use
+    pub fn tools(&self) -> Vec<&'static str> {
+        let mut tools: Vec<&'static str> = Vec::new();
+        for step in self.steps {
+            if !tools.contains(&step.tool) {
+                tools.push(step.tool);
+            }
+        }
+        tools
+    }
+
+    /// `prompts/get` result with `arguments` bound into the steps
+    ///
+    /// Returns an error naming the first missing required argument.
+    pub fn render(&self, arguments: &Map<String, Value>) -> Result<Value, String> {
+        let mut values = Vec::with_capacity(self.arguments.len());
+        for argument in self.arguments {
+            let value = match arguments.get(argument.name) {
+                Some(Value::String(value)) if !value.trim().is_empty() => value.trim().to_string(),
+                Some(Value::Number(value)) => value.to_string(),
+                _ => argument
+                    .default
+                    .map(str::to_string)
+                    .ok_or_else(|| format!("Missing required argument '{}'", argument.name))?,
+            };
+            values.push((argument.name, value));
+        }
+        let bind = |text: &str| {
+            values.iter().fold(text.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{}}}", name), value)
+            })
+        };
+
+        let mut text = format!(
+            "{}\n\nWork through these steps in order:\n",
+            bind(self.goal)
+        );
+        for (index, step) in self.steps.iter().enumerate() {
+            text.push_str(&format!(
+                "{}. `{}`: {}\n",
+                index + 1,
+                step.tool,
+                bind(step.instruction)
+            ));
+        }
+        text.push_str(
+            "\nDo not call a tool that moves funds until the user has confirmed the step.",
+        );
+
+        Ok(serde_json::json!({
+            "description": self.description,
+            "messages": [{
+                "role": "user",
+                "content": { "type": "text", "text": text }
+            }],
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::server::{MantraDexMcpServer, McpServerConfig, McpToolProvider};
+
+    #[test]
+    fn test_render_binds_arguments_and_defaults() {
+        let prompt = find_prompt("swap-with-slippage-review").unwrap();
+        let arguments = serde_json::json!({
+            "offer_denom": "uom",
+            "amount": 1_000_000,
+            "ask_denom": "uusdc"
+        });
+        let rendered = prompt.render(arguments.as_object().unwrap()).unwrap();
+        let text = rendered["messages"][0]["content"]["text"].as_str().unwrap();
+        assert!(text.starts_with("Swap 1000000 uom for uusdc with at most 1% slippage."));
+        assert!(text.contains("`dex_execute_swap`"));
+        assert!(!text.contains('{'));
+
+        let missing = serde_json::json!({ "offer_denom": "uom", "amount": "" });
+        assert_eq!(
+            prompt.render(missing.as_object().unwrap()).unwrap_err(),
+            "Missing required argument 'amount'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prompts_reference_registered_tools() {
+        let server = MantraDexMcpServer::new(McpServerConfig::default());
+        let tools: Vec<String> = server
+            .get_available_tools()
+            .iter()
+            .filter_map(|tool| tool.get("name")?.as_str().map(str::to_string))
+            .collect();
+
+        for prompt in prompt_templates() {
+            for tool in prompt.tools() {
+                assert!(
+                    tools.iter().any(|name| name == tool),
+                    "{} uses unknown tool {}",
+                    prompt.name,
+                    tool
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Concurrency Quotas for State-Changing MCP Operations
+//!
+//! Write tools (swaps, liquidity changes, claims, transfers, ...) are admitted through
+//! two limits: a per-session cap and a global cap across all sessions. Admission is
+//! non-blocking - when a limit is reached the call is rejected immediately instead of
+//! being queued, so a runaway agent loop cannot pile up broadcasts behind a slow one.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
+
+use super::server::{McpResult, McpServerError};
+
+/// Write-operation quota configuration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteQuotaConfig {
+    /// Maximum in-flight write operations for a single session
+    pub max_in_flight_per_session: usize,
+    /// Maximum in-flight write operations across all sessions
+    pub max_in_flight_global: usize,
+}
+
+impl Default for WriteQuotaConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight_per_session: 2,
+            max_in_flight_global: 8,
+        }
+    }
+}
+
+/// Permit held for the duration of a write operation
+///
+/// Both slots are released when the permit is dropped.
+#[derive(Debug)]
+pub struct WriteQuotaPermit {
+    _session: OwnedSemaphorePermit,
+    _global: OwnedSemaphorePermit,
+}
+
+/// Tracks in-flight write operations per session and globally
+#[derive(Debug)]
+pub struct WriteQuotas {
+    config: WriteQuotaConfig,
+    global: Arc<Semaphore>,
+    sessions: RwLock<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl WriteQuotas {
+    /// Create quotas from configuration
+    pub fn new(config: WriteQuotaConfig) -> Self {
+        Self {
+            global: Arc::new(Semaphore::new(config.max_in_flight_global)),
+            sessions: RwLock::new(HashMap::new()),
+            config,
+        }
+    }
+
+    /// Quota configuration
+    pub fn config(&self) -> WriteQuotaConfig {
+        self.config
+    }
+
+    /// Try to admit a write operation for `session_id`
+    ///
+    /// Returns `McpServerError::QuotaExceeded` if either limit is already reached.
+    pub async fn try_acquire(&self, session_id: &str) -> McpResult<WriteQuotaPermit> {
+        let session_semaphore = self.session_semaphore(session_id).await;
+
+        let session = session_semaphore.try_acquire_owned().map_err(|_| {
+            McpServerError::QuotaExceeded(format!(
+                "session '{}' already has {} write operation(s) in flight",
+                session_id, self.config.max_in_flight_per_session
+            ))
+        })?;
+
+        let global = self.global.clone().try_acquire_owned().map_err(|_| {
+            McpServerError::QuotaExceeded(format!(
+                "server already has {} write operation(s) in flight",
+                self.config.max_in_flight_global
+            ))
+        })?;
+
+        Ok(WriteQuotaPermit {
+            _session: session,
+            _global: global,
+        })
+    }
+
+    /// Number of write operations currently in flight for a session
+    pub async fn in_flight_for_session(&self, session_id: &str) -> usize {
+        match self.sessions.read().await.get(session_id) {
+            Some(semaphore) => {
+                self.config.max_in_flight_per_session - semaphore.available_permits()
+            }
+            None => 0,
+        }
+    }
+
+    /// Number of write operations currently in flight across all sessions
+    pub fn in_flight_global(&self) -> usize {
+        self.config.max_in_flight_global - self.global.available_permits()
+    }
+
+    /// Quota usage snapshot for health and diagnostics output
+    pub async fn snapshot(&self) -> serde_json::Value {
+        let sessions = self.sessions.read().await;
+        let busy_sessions: HashMap<&String, usize> = sessions
+            .iter()
+            .map(|(id, semaphore)| {
+                (
+                    id,
+                    self.config.max_in_flight_per_session - semaphore.available_permits(),
+                )
+            })
+            .filter(|(_, in_flight)| *in_flight > 0)
+            .collect();
+
+        serde_json::json!({
+            "max_in_flight_per_session": self.config.max_in_flight_per_session,
+            "max_in_flight_global": self.config.max_in_flight_global,
+            "in_flight_global": self.in_flight_global(),
+            "busy_sessions": busy_sessions,
+        })
+    }
+
+    async fn session_semaphore(&self, session_id: &str) -> Arc<Semaphore> {
+        if let Some(semaphore) = self.sessions.read().await.get(session_id) {
+            return semaphore.clone();
+        }
+
+        let mut sessions = self.sessions.write().await;
+        // Drop idle sessions so the map doesn't grow without bound
+        let max = self.config.max_in_flight_per_session;
+        sessions.retain(|_, semaphore| {
+            Arc::strong_count(semaphore) > 1 || semaphore.available_permits() < max
+        });
+        sessions
+            .entry(session_id.to_string())
+            .or_insert_with(|| Arc::new(Semaphore::new(max)))
+            .clone()
+    }
+}
+
+impl Default for WriteQuotas {
+    fn default() -> Self {
+        Self::new(WriteQuotaConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_session_quota_is_enforced() {
+        let quotas = WriteQuotas::new(WriteQuotaConfig {
+            max_in_flight_per_session: 1,
+            max_in_flight_global: 4,
+        });
+
+        let permit = quotas.try_acquire("a").await.unwrap();
+        assert!(matches!(
+            quotas.try_acquire("a").await,
+            Err(McpServerError::QuotaExceeded(_))
+        ));
+        // Other sessions are unaffected
+        let _other = quotas.try_acquire("b").await.unwrap();
+        assert_eq!(quotas.in_flight_global(), 2);
+
+        drop(permit);
+        assert!(quotas.try_acquire("a").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_global_quota_is_enforced() {
+        let quotas = WriteQuotas::new(WriteQuotaConfig {
+            max_in_flight_per_session: 2,
+            max_in_flight_global: 2,
+        });
+
+        let _a = quotas.try_acquire("a").await.unwrap();
+        let _b = quotas.try_acquire("b").await.unwrap();
+        assert!(matches!(
+            quotas.try_acquire("c").await,
+            Err(McpServerError::QuotaExceeded(_))
+        ));
+        // A rejected global admission must not leak the session slot
+        assert_eq!(quotas.in_flight_for_session("c").await, 0);
+    }
+}
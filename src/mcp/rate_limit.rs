@@ -0,0 +1,473 @@
+//! Rate Limiting for the MCP HTTP Transport
+//!
+//! Requests are admitted through token buckets, one per client and one shared by
+//! all clients, plus a cap on requests in flight. Calls to expensive tools take
+//! more tokens than other requests. A request over any limit is answered with
+//! `429 Too Many Requests` and a `Retry-After` header instead of being queued,
+//! so a public deployment cannot be tied up by a flood of slow calls.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Tools whose calls take `expensive_tool_cost` tokens
+pub const EXPENSIVE_TOOLS: &[&str] = &[
+    "evm_analyze_transaction_history",
+    "analyze_wallet_activity",
+    "get_multi_network_portfolio",
+    "dex_get_pool_depth",
+    "estimate_transaction_cost",
+    "dex_sweep_dust",
+    "claimdrop_build_campaign",
+    "claimdrop_get_global_stats",
+    "primary_sale_get_all_investors",
+    "erc20_get_allowances",
+    "skip_get_route",
+    "network_performance",
+];
+
+/// HTTP rate limit configuration; a limit of 0 is off
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpRateLimitConfig {
+    /// Tokens a client regains per minute
+    #[serde(default = "default_per_client_per_minute")]
+    pub per_client_per_minute: u32,
+    /// Tokens a client can spend at once
+    #[serde(default = "default_per_client_burst")]
+    pub per_client_burst: u32,
+    /// Tokens all clients together regain per minute
+    #[serde(default = "default_global_per_minute")]
+    pub global_per_minute: u32,
+    /// Tokens all clients together can spend at once
+    #[serde(default = "default_global_burst")]
+    pub global_burst: u32,
+    /// Requests processed at the same time
+    #[serde(default = "default_max_in_flight")]
+    pub max_in_flight: usize,
+    /// Tokens taken by a call to one of the [`EXPENSIVE_TOOLS`]
+    #[serde(default = "default_expensive_tool_cost")]
+    pub expensive_tool_cost: u32,
+    /// Identify clients by the last `X-Forwarded-For` address, the one the
+    /// proxy appended; only safe behind a proxy that sets the header
+    #[serde(default)]
+    pub trust_forwarded_for: bool,
+}
+
+fn default_per_client_per_minute() -> u32 {
+    120
+}
+
+fn default_per_client_burst() -> u32 {
+    30
+}
+
+fn default_global_per_minute() -> u32 {
+    1200
+}
+
+fn default_global_burst() -> u32 {
+    200
+}
+
+fn default_max_in_flight() -> usize {
+    64
+}
+
+fn default_expensive_tool_cost() -> u32 {
+    10
+}
+
+impl Default for HttpRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            per_client_per_minute: default_per_client_per_minute(),
+            per_client_burst: default_per_client_burst(),
+            global_per_minute: default_global_per_minute(),
+            global_burst: default_global_burst(),
+            max_in_flight: default_max_in_flight(),
+            expensive_tool_cost: default_expensive_tool_cost(),
+            trust_forwarded_for: false,
+        }
+    }
+}
+
+impl HttpRateLimitConfig {
+    /// Tokens a JSON-RPC request with `method` and `params` takes
+    pub fn request_cost(&self, method: &str, params: Option<&Value>) -> u32 {
+        let tool = params
+            .and_then(|params| params.get("name"))
+            .and_then(|name| name.as_str());
+        match tool {
+            Some(tool) if method == "tools/call" && EXPENSIVE_TOOLS.contains(&tool) => {
+                self.expensive_tool_cost.max(1)
+            }
+            _ => 1,
+        }
+    }
+}
+
+/// Limit a request ran into
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleReason {
+    Client,
+    Global,
+    InFlight,
+}
+
+/// Rejection of a request over a limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Throttled {
+    pub reason: ThrottleReason,
+    pub retry_after: Duration,
+}
+
+impl Throttled {
+    /// Whole seconds for the `Retry-After` header, at least 1
+    pub fn retry_after_secs(&self) -> u64 {
+        self.retry_after.as_secs_f64().ceil().max(1.0) as u64
+    }
+}
+
+impl fmt::Display for Throttled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = match self.reason {
+            ThrottleReason::Client => "client rate limit",
+            ThrottleReason::Global => "server rate limit",
+            ThrottleReason::InFlight => "in-flight request limit",
+        };
+        write!(
+            f,
+            "{} reached, retry after {}s",
+            limit,
+            self.retry_after_secs()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn full(capacity: u32, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(capacity),
+            updated: now,
+        }
+    }
+
+    /// Refill up to `capacity`, then check `cost` is available
+    ///
+    /// Returns the wait until it is otherwise.
+    fn check(
+        &mut self,
+        capacity: u32,
+        per_minute: u32,
+        cost: u32,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let per_sec = f64::from(per_minute) / 60.0;
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_sec).min(f64::from(capacity));
+        self.updated = now;
+
+        // A request costing more than the bucket holds waits for a full bucket
+        let cost = f64::from(cost.min(capacity));
+        if self.tokens >= cost {
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((cost - self.tokens) / per_sec))
+        }
+    }
+
+    fn is_full(&self, capacity: u32) -> bool {
+        self.tokens >= f64::from(capacity)
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    global: TokenBucket,
+    clients: HashMap<String, TokenBucket>,
+}
+
+/// Permit held while a request is processed
+#[derive(Debug)]
+pub struct RequestPermit {
+    _in_flight: Option<OwnedSemaphorePermit>,
+}
+
+/// Admits HTTP requests per client and globally
+#[derive(Debug)]
+pub struct HttpRateLimiter {
+    config: HttpRateLimitConfig,
+    buckets: Mutex<Buckets>,
+    in_flight: Arc<Semaphore>,
+    admitted: AtomicU64,
+    throttled_client: AtomicU64,
+    throttled_global: AtomicU64,
+    throttled_in_flight: AtomicU64,
+}
+
+impl HttpRateLimiter {
+    /// Create a limiter from configuration
+    pub fn new(config: HttpRateLimitConfig) -> Self {
+        Self {
+            buckets: Mutex::new(Buckets {
+                global: TokenBucket::full(config.global_burst, Instant::now()),
+                clients: HashMap::new(),
+            }),
+            in_flight: Arc::new(Semaphore::new(config.max_in_flight.max(1))),
+            config,
+            admitted: AtomicU64::new(0),
+            throttled_client: AtomicU64::new(0),
+            throttled_global: AtomicU64::new(0),
+            throttled_in_flight: AtomicU64::new(0),
+        }
+    }
+
+    /// Rate limit configuration
+    pub fn config(&self) -> &HttpRateLimitConfig {
+        &self.config
+    }
+
+    /// Key identifying the client of a request from `peer`
+    ///
+    /// `forwarded_for` is the `X-Forwarded-For` header, used only when the
+    /// configuration trusts it. Its rightmost address is the one the trusted
+    /// proxy saw; the entries before it come from the client and may be forged.
+    pub fn client_key(&self, peer: IpAddr, forwarded_for: Option<&str>) -> String {
+        forwarded_for
+            .filter(|_| self.config.trust_forwarded_for)
+            .and_then(|header| header.rsplit(',').next())
+            .map(str::trim)
+            .and_then(|address| address.parse::<IpAddr>().ok())
+            .unwrap_or(peer)
+            .to_string()
+    }
+
+    /// Admit a request from `client` taking `cost` tokens
+    ///
+    /// The returned permit holds the request's in-flight slot until dropped.
+    pub fn admit(&self, client: &str, cost: u32) -> Result<RequestPermit, Throttled> {
+        self.admit_at(client, cost, Instant::now())
+    }
+
+    fn admit_at(&self, client: &str, cost: u32, now: Instant) -> Result<RequestPermit, Throttled> {
+        let in_flight = if self.config.max_in_flight > 0 {
+            match self.in_flight.clone().try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                Err(_) => {
+                    return Err(self.throttle(ThrottleReason::InFlight, Duration::from_secs(1)))
+                }
+            }
+        } else {
+            None
+        };
+
+        let config = &self.config;
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let client_limited = config.per_client_per_minute > 0 && config.per_client_burst > 0;
+        let global_limited = config.global_per_minute > 0 && config.global_burst > 0;
+
+        if client_limited && !buckets.clients.contains_key(client) {
+            // Full buckets belong to idle clients; drop them so the map stays small
+            buckets
+                .clients
+                .retain(|_, bucket| !bucket.is_full(config.per_client_burst));
+            buckets.clients.insert(
+                client.to_string(),
+                TokenBucket::full(config.per_client_burst, now),
+            );
+        }
+
+        // Check both buckets before taking from either, so a refused request costs nothing
+        if client_limited {
+            let bucket = buckets
+                .clients
+                .get_mut(client)
+                .expect("bucket inserted above");
+            if let Err(wait) = bucket.check(
+                config.per_client_burst,
+                config.per_client_per_minute,
+                cost,
+                now,
+            ) {
+                return Err(self.throttle(ThrottleReason::Client, wait));
+            }
+        }
+        if global_limited {
+            if let Err(wait) =
+                buckets
+                    .global
+                    .check(config.global_burst, config.global_per_minute, cost, now)
+            {
+                return Err(self.throttle(ThrottleReason::Global, wait));
+            }
+            buckets.global.tokens -= f64::from(cost.min(config.global_burst));
+        }
+        if client_limited {
+            if let Some(bucket) = buckets.clients.get_mut(client) {
+                bucket.tokens -= f64::from(cost.min(config.per_client_burst));
+            }
+        }
+
+        self.admitted.fetch_add(1, Ordering::Relaxed);
+        Ok(RequestPermit {
+            _in_flight: in_flight,
+        })
+    }
+
+    fn throttle(&self, reason: ThrottleReason, retry_after: Duration) -> Throttled {
+        let counter = match reason {
+            ThrottleReason::Client => &self.throttled_client,
+            ThrottleReason::Global => &self.throttled_global,
+            ThrottleReason::InFlight => &self.throttled_in_flight,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        Throttled {
+            reason,
+            retry_after,
+        }
+    }
+
+    /// Limits and throttling counters for health and diagnostics output
+    pub fn snapshot(&self) -> Value {
+        let tracked_clients = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clients
+            .len();
+        let in_flight = if self.config.max_in_flight > 0 {
+            self.config.max_in_flight - self.in_flight.available_permits()
+        } else {
+            0
+        };
+
+        serde_json::json!({
+            "config": self.config,
+            "in_flight": in_flight,
+            "tracked_clients": tracked_clients,
+            "admitted": self.admitted.load(Ordering::Relaxed),
+            "throttled": {
+                "client": self.throttled_client.load(Ordering::Relaxed),
+                "global": self.throttled_global.load(Ordering::Relaxed),
+                "in_flight": self.throttled_in_flight.load(Ordering::Relaxed),
+            },
+        })
+    }
+}
+
+impl Default for HttpRateLimiter {
+    fn default() -> Self {
+        Self::new(HttpRateLimitConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(config: HttpRateLimitConfig) -> HttpRateLimiter {
+        HttpRateLimiter::new(HttpRateLimitConfig {
+            max_in_flight: 0,
+            ..config
+        })
+    }
+
+    #[test]
+    fn test_client_bucket_throttles_and_refills() {
+        let limiter = limiter(HttpRateLimitConfig {
+            per_client_per_minute: 60,
+            per_client_burst: 2,
+            ..Default::default()
+        });
+        let start = Instant::now();
+
+        assert!(limiter.admit_at("a", 1, start).is_ok());
+        assert!(limiter.admit_at("a", 1, start).is_ok());
+        let throttled = limiter.admit_at("a", 1, start).unwrap_err();
+        assert_eq!(throttled.reason, ThrottleReason::Client);
+        assert_eq!(throttled.retry_after_secs(), 1);
+        // Other clients keep their own bucket
+        assert!(limiter.admit_at("b", 1, start).is_ok());
+
+        assert!(limiter
+            .admit_at("a", 1, start + Duration::from_secs(1))
+            .is_ok());
+        assert_eq!(limiter.snapshot()["throttled"]["client"], 1);
+    }
+
+    #[test]
+    fn test_global_limit_and_expensive_tools() {
+        let config = HttpRateLimitConfig {
+            global_per_minute: 60,
+            global_burst: 10,
+            ..Default::default()
+        };
+        let cost = config.request_cost(
+            "tools/call",
+            Some(&serde_json::json!({"name": "evm_analyze_transaction_history"})),
+        );
+        assert_eq!(cost, 10);
+        assert_eq!(
+            config.request_cost(
+                "tools/call",
+                Some(&serde_json::json!({"name": "dex_get_pools"}))
+            ),
+            1
+        );
+
+        let limiter = limiter(config);
+        let start = Instant::now();
+        assert!(limiter.admit_at("a", cost, start).is_ok());
+        let throttled = limiter.admit_at("b", 1, start).unwrap_err();
+        assert_eq!(throttled.reason, ThrottleReason::Global);
+        // The refused request took nothing from client b
+        assert!(limiter
+            .admit_at("b", 1, start + Duration::from_secs(1))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_in_flight_cap_and_client_key() {
+        let limiter = HttpRateLimiter::new(HttpRateLimitConfig {
+            max_in_flight: 1,
+            ..Default::default()
+        });
+        let permit = limiter.admit("a", 1).unwrap();
+        assert_eq!(
+            limiter.admit("b", 1).unwrap_err().reason,
+            ThrottleReason::InFlight
+        );
+        drop(permit);
+        assert!(limiter.admit("b", 1).is_ok());
+
+        let peer: IpAddr = "10.0.0.1".parse().unwrap();
+        assert_eq!(limiter.client_key(peer, Some("203.0.113.7")), "10.0.0.1");
+        let proxied = HttpRateLimiter::new(HttpRateLimitConfig {
+            trust_forwarded_for: true,
+            ..Default::default()
+        });
+        assert_eq!(proxied.client_key(peer, Some("203.0.113.7")), "203.0.113.7");
+        // A client cannot pick its bucket with a spoofed leading entry
+        assert_eq!(
+            proxied.client_key(peer, Some("198.51.100.1, 203.0.113.7")),
+            "203.0.113.7"
+        );
+        assert_eq!(
+            proxied.client_key(peer, Some("198.51.100.2, 203.0.113.7")),
+            "203.0.113.7"
+        );
+    }
+}
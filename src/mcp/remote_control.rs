@@ -0,0 +1,824 @@
+//! Encrypted Remote Control Channel
+//!
+//! Headless servers can expose a small control channel instead of the full
+//! HTTP API. An operator UI connects over TCP and runs a Noise XX handshake
+//! (`Noise_XX_25519_ChaChaPoly_BLAKE2s`): both sides prove possession of a
+//! static X25519 key, the server only accepts operator keys listed in its
+//! configuration, and the client only proceeds if the server presents the key
+//! it has pinned. Everything after the handshake is encrypted and
+//! authenticated end to end, so the channel can cross the internet without TLS
+//! termination or a certificate authority.
+//!
+//! Over the channel the operator can read server status, list write calls
+//! waiting in the [`ApprovalQueue`](super::approvals::ApprovalQueue), and
+//! approve or reject them. Requests and responses are JSON objects:
+//!
+//! ```json
+//! {"id": 1, "method": "approve", "params": {"approval_id": "..."}}
+//! {"id": 1, "result": {...}}
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use snow::{Builder, HandshakeState, TransportState};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+use zeroize::Zeroizing;
+
+use super::approvals::ApprovalDecision;
+use super::server::{McpResult, McpServerError, McpServerStateData};
+
+/// Noise protocol used by the channel
+pub const NOISE_PARAMS: &str = "Noise_XX_25519_ChaChaPoly_BLAKE2s";
+
+/// Bound into the handshake so keys cannot be replayed against another protocol
+const NOISE_PROLOGUE: &[u8] = b"mantra-remote-control/1";
+
+/// Largest Noise message, ciphertext included
+const MAX_NOISE_MESSAGE_LEN: usize = 65535;
+
+/// Authentication tag appended to every transport message
+const NOISE_TAG_LEN: usize = 16;
+
+/// Largest control message accepted from a peer
+const MAX_CONTROL_MESSAGE_LEN: usize = 1024 * 1024;
+
+/// Peers must complete the handshake within this time
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default seconds a write call waits for an operator decision
+pub const DEFAULT_APPROVAL_TIMEOUT_SECS: u64 = 300;
+
+/// Remote control channel settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteControlConfig {
+    /// Address to listen on, e.g. `0.0.0.0:7443`; the channel is off when unset
+    #[serde(default)]
+    pub listen_addr: Option<String>,
+    /// File holding the server's static key, generated on first start
+    #[serde(default)]
+    pub key_path: Option<PathBuf>,
+    /// Hex-encoded X25519 public keys of operators allowed to connect
+    #[serde(default)]
+    pub authorized_operators: Vec<String>,
+    /// Hold write tool calls until an operator approves them
+    #[serde(default)]
+    pub require_approval: bool,
+    /// Seconds a write call waits for a decision before it is rejected
+    #[serde(default = "default_approval_timeout_secs")]
+    pub approval_timeout_secs: u64,
+}
+
+fn default_approval_timeout_secs() -> u64 {
+    DEFAULT_APPROVAL_TIMEOUT_SECS
+}
+
+impl Default for RemoteControlConfig {
+    fn default() -> Self {
+        Self {
+            listen_addr: None,
+            key_path: None,
+            authorized_operators: Vec::new(),
+            require_approval: false,
+            approval_timeout_secs: default_approval_timeout_secs(),
+        }
+    }
+}
+
+impl RemoteControlConfig {
+    /// Whether the channel should be started
+    pub fn is_enabled(&self) -> bool {
+        self.listen_addr.is_some()
+    }
+}
+
+fn noise_error(context: &str, err: snow::Error) -> McpServerError {
+    McpServerError::Network(format!("{}: {}", context, err))
+}
+
+fn io_error(context: &str, err: std::io::Error) -> McpServerError {
+    McpServerError::Network(format!("{}: {}", context, err))
+}
+
+fn noise_builder<'a>() -> Builder<'a> {
+    Builder::new(
+        NOISE_PARAMS
+            .parse()
+            .expect("NOISE_PARAMS is a valid Noise protocol name"),
+    )
+}
+
+/// Decode a hex X25519 public key
+pub fn parse_public_key(hex_key: &str) -> McpResult<Vec<u8>> {
+    let key = hex::decode(hex_key.trim()).map_err(|e| {
+        McpServerError::InvalidArguments(format!("Invalid public key {}: {}", hex_key, e))
+    })?;
+    if key.len() != 32 {
+        return Err(McpServerError::InvalidArguments(format!(
+            "Public key {} must be 32 bytes, got {}",
+            hex_key,
+            key.len()
+        )));
+    }
+    Ok(key)
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredKeypair {
+    private_key: String,
+    public_key: String,
+}
+
+/// Static X25519 key identifying one end of the channel
+pub struct NoiseKeypair {
+    private: Zeroizing<Vec<u8>>,
+    public: Vec<u8>,
+}
+
+impl std::fmt::Debug for NoiseKeypair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NoiseKeypair")
+            .field("public", &hex::encode(&self.public))
+            .finish_non_exhaustive()
+    }
+}
+
+impl NoiseKeypair {
+    /// Generate a fresh keypair
+    pub fn generate() -> McpResult<Self> {
+        let keypair = noise_builder()
+            .generate_keypair()
+            .map_err(|e| noise_error("Failed to generate Noise keypair", e))?;
+        Ok(Self {
+            private: Zeroizing::new(keypair.private),
+            public: keypair.public,
+        })
+    }
+
+    /// Load the keypair stored at `path`, generating and storing one if missing
+    pub fn load_or_generate(path: &Path) -> McpResult<Self> {
+        if path.exists() {
+            let contents = Zeroizing::new(std::fs::read_to_string(path).map_err(|e| {
+                McpServerError::Internal(format!(
+                    "Failed to read remote control key {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?);
+            let stored: StoredKeypair = serde_json::from_str(&contents)?;
+            let private = Zeroizing::new(hex::decode(&stored.private_key).map_err(|e| {
+                McpServerError::Internal(format!(
+                    "Invalid private key in {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?);
+            return Ok(Self {
+                private,
+                public: parse_public_key(&stored.public_key)?,
+            });
+        }
+
+        let keypair = Self::generate()?;
+        let stored = StoredKeypair {
+            private_key: hex::encode(keypair.private.as_slice()),
+            public_key: keypair.public_key_hex(),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| io_error("Failed to create remote control key directory", e))?;
+        }
+        let contents = Zeroizing::new(serde_json::to_string_pretty(&stored)?);
+        // Owner-only from the start, so the key is never readable by others
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options
+            .open(path)
+            .map_err(|e| io_error("Failed to create remote control key", e))?;
+        std::io::Write::write_all(&mut file, contents.as_bytes())
+            .map_err(|e| io_error("Failed to write remote control key", e))?;
+        info!("Generated remote control key at {}", path.display());
+        Ok(keypair)
+    }
+
+    /// Hex-encoded public key to share with the other side
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(&self.public)
+    }
+}
+
+async fn write_frame<S: AsyncWrite + Unpin>(stream: &mut S, frame: &[u8]) -> McpResult<()> {
+    let len = u16::try_from(frame.len())
+        .map_err(|_| McpServerError::Internal("Noise frame too large".to_string()))?;
+    stream
+        .write_all(&len.to_be_bytes())
+        .await
+        .map_err(|e| io_error("Failed to write frame", e))?;
+    stream
+        .write_all(frame)
+        .await
+        .map_err(|e| io_error("Failed to write frame", e))?;
+    stream
+        .flush()
+        .await
+        .map_err(|e| io_error("Failed to write frame", e))
+}
+
+/// Read one frame; `None` when the peer closed the connection between frames
+async fn read_frame<S: AsyncRead + Unpin>(stream: &mut S) -> McpResult<Option<Vec<u8>>> {
+    let mut len = [0u8; 2];
+    match stream.read_exact(&mut len).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(io_error("Failed to read frame", e)),
+    }
+    let mut frame = vec![0u8; u16::from_be_bytes(len) as usize];
+    stream
+        .read_exact(&mut frame)
+        .await
+        .map_err(|e| io_error("Failed to read frame", e))?;
+    Ok(Some(frame))
+}
+
+async fn expect_frame<S: AsyncRead + Unpin>(stream: &mut S) -> McpResult<Vec<u8>> {
+    read_frame(stream).await?.ok_or_else(|| {
+        McpServerError::Network("Peer closed the connection during the handshake".to_string())
+    })
+}
+
+async fn send_handshake<S: AsyncWrite + Unpin>(
+    stream: &mut S,
+    handshake: &mut HandshakeState,
+) -> McpResult<()> {
+    let mut buf = vec![0u8; MAX_NOISE_MESSAGE_LEN];
+    let len = handshake
+        .write_message(&[], &mut buf)
+        .map_err(|e| noise_error("Noise handshake failed", e))?;
+    write_frame(stream, &buf[..len]).await
+}
+
+async fn receive_handshake<S: AsyncRead + Unpin>(
+    stream: &mut S,
+    handshake: &mut HandshakeState,
+) -> McpResult<()> {
+    let frame = expect_frame(stream).await?;
+    let mut payload = vec![0u8; MAX_NOISE_MESSAGE_LEN];
+    handshake
+        .read_message(&frame, &mut payload)
+        .map_err(|e| noise_error("Noise handshake failed", e))?;
+    Ok(())
+}
+
+fn remote_static(handshake: &HandshakeState) -> McpResult<Vec<u8>> {
+    handshake
+        .get_remote_static()
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| McpServerError::Network("Peer sent no static key".to_string()))
+}
+
+/// Encrypted, mutually authenticated message stream
+pub struct NoiseChannel<S> {
+    stream: S,
+    transport: TransportState,
+    remote_public: Vec<u8>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> NoiseChannel<S> {
+    /// Run the responder side of the handshake, admitting only `authorized` keys
+    pub async fn accept(
+        mut stream: S,
+        keypair: &NoiseKeypair,
+        authorized: &[Vec<u8>],
+    ) -> McpResult<Self> {
+        let mut handshake = noise_builder()
+            .local_private_key(&keypair.private)
+            .prologue(NOISE_PROLOGUE)
+            .build_responder()
+            .map_err(|e| noise_error("Failed to start Noise handshake", e))?;
+
+        receive_handshake(&mut stream, &mut handshake).await?;
+        send_handshake(&mut stream, &mut handshake).await?;
+        receive_handshake(&mut stream, &mut handshake).await?;
+
+        let remote_public = remote_static(&handshake)?;
+        if !authorized.contains(&remote_public) {
+            return Err(McpServerError::Validation(format!(
+                "Operator key {} is not authorized",
+                hex::encode(&remote_public)
+            )));
+        }
+
+        let transport = handshake
+            .into_transport_mode()
+            .map_err(|e| noise_error("Failed to finish Noise handshake", e))?;
+        Ok(Self {
+            stream,
+            transport,
+            remote_public,
+        })
+    }
+
+    /// Run the initiator side of the handshake against a server with a pinned key
+    ///
+    /// The connection is dropped before the client reveals its own static key
+    /// if the server presents any other key.
+    pub async fn connect(
+        mut stream: S,
+        keypair: &NoiseKeypair,
+        server_public_key: &[u8],
+    ) -> McpResult<Self> {
+        let mut handshake = noise_builder()
+            .local_private_key(&keypair.private)
+            .prologue(NOISE_PROLOGUE)
+            .build_initiator()
+            .map_err(|e| noise_error("Failed to start Noise handshake", e))?;
+
+        send_handshake(&mut stream, &mut handshake).await?;
+        receive_handshake(&mut stream, &mut handshake).await?;
+
+        let remote_public = remote_static(&handshake)?;
+        if remote_public != server_public_key {
+            return Err(McpServerError::Validation(format!(
+                "Server key {} does not match the pinned key {}",
+                hex::encode(&remote_public),
+                hex::encode(server_public_key)
+            )));
+        }
+        send_handshake(&mut stream, &mut handshake).await?;
+
+        let transport = handshake
+            .into_transport_mode()
+            .map_err(|e| noise_error("Failed to finish Noise handshake", e))?;
+        Ok(Self {
+            stream,
+            transport,
+            remote_public,
+        })
+    }
+
+    /// Hex-encoded static key of the peer
+    pub fn remote_public_key_hex(&self) -> String {
+        hex::encode(&self.remote_public)
+    }
+
+    /// Encrypt and send a message, split across as many Noise frames as needed
+    pub async fn send<T: Serialize>(&mut self, message: &T) -> McpResult<()> {
+        let body = serde_json::to_vec(message)?;
+        let mut plaintext = Vec::with_capacity(body.len() + 4);
+        plaintext.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        plaintext.extend_from_slice(&body);
+
+        let mut buf = vec![0u8; MAX_NOISE_MESSAGE_LEN];
+        for chunk in plaintext.chunks(MAX_NOISE_MESSAGE_LEN - NOISE_TAG_LEN) {
+            let len = self
+                .transport
+                .write_message(chunk, &mut buf)
+                .map_err(|e| noise_error("Failed to encrypt message", e))?;
+            write_frame(&mut self.stream, &buf[..len]).await?;
+        }
+        Ok(())
+    }
+
+    /// Receive and decrypt the next message; `None` once the peer disconnects
+    pub async fn recv<T: for<'de> Deserialize<'de>>(&mut self) -> McpResult<Option<T>> {
+        let mut plaintext = Vec::new();
+        let mut buf = vec![0u8; MAX_NOISE_MESSAGE_LEN];
+        let mut expected = None;
+
+        loop {
+            let frame = match read_frame(&mut self.stream).await? {
+                Some(frame) => frame,
+                None if plaintext.is_empty() => return Ok(None),
+                None => {
+                    return Err(McpServerError::Network(
+                        "Peer closed the connection mid-message".to_string(),
+                    ))
+                }
+            };
+            let len = self
+                .transport
+                .read_message(&frame, &mut buf)
+                .map_err(|e| noise_error("Failed to decrypt message", e))?;
+            plaintext.extend_from_slice(&buf[..len]);
+
+            if expected.is_none() && plaintext.len() >= 4 {
+                let body_len =
+                    u32::from_be_bytes([plaintext[0], plaintext[1], plaintext[2], plaintext[3]])
+                        as usize;
+                if body_len > MAX_CONTROL_MESSAGE_LEN {
+                    return Err(McpServerError::Validation(format!(
+                        "Control message of {} bytes exceeds the {} byte limit",
+                        body_len, MAX_CONTROL_MESSAGE_LEN
+                    )));
+                }
+                expected = Some(body_len + 4);
+            }
+            if let Some(total) = expected {
+                if plaintext.len() >= total {
+                    return Ok(Some(serde_json::from_slice(&plaintext[4..total])?));
+                }
+            }
+        }
+    }
+}
+
+/// Request sent by an operator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlRequest {
+    pub id: u64,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Reply to a [`ControlRequest`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Listener serving the remote control channel for one MCP server
+pub struct RemoteControlServer {
+    listen_addr: String,
+    keypair: Arc<NoiseKeypair>,
+    operators: Arc<Vec<Vec<u8>>>,
+    state: Arc<McpServerStateData>,
+}
+
+impl RemoteControlServer {
+    /// Build the server from configuration; the key is ephemeral without `key_path`
+    pub fn new(config: &RemoteControlConfig, state: Arc<McpServerStateData>) -> McpResult<Self> {
+        let listen_addr = config.listen_addr.clone().ok_or_else(|| {
+            McpServerError::InvalidArguments("Remote control listen address not set".to_string())
+        })?;
+        let operators = config
+            .authorized_operators
+            .iter()
+            .map(|key| parse_public_key(key))
+            .collect::<McpResult<Vec<_>>>()?;
+        if operators.is_empty() {
+            return Err(McpServerError::InvalidArguments(
+                "Remote control requires at least one authorized operator key".to_string(),
+            ));
+        }
+        let keypair = match &config.key_path {
+            Some(path) => NoiseKeypair::load_or_generate(path)?,
+            None => {
+                warn!("No remote control key path set, using a key that changes on restart");
+                NoiseKeypair::generate()?
+            }
+        };
+
+        Ok(Self {
+            listen_addr,
+            keypair: Arc::new(keypair),
+            operators: Arc::new(operators),
+            state,
+        })
+    }
+
+    /// Public key operators must pin
+    pub fn public_key_hex(&self) -> String {
+        self.keypair.public_key_hex()
+    }
+
+    /// Accept operator connections until the listener fails
+    pub async fn serve(self) -> McpResult<()> {
+        let listener = TcpListener::bind(&self.listen_addr).await.map_err(|e| {
+            io_error(
+                &format!("Failed to bind remote control on {}", self.listen_addr),
+                e,
+            )
+        })?;
+        info!(
+            "Remote control listening on {} with server key {}",
+            self.listen_addr,
+            self.public_key_hex()
+        );
+
+        let server = Arc::new(self);
+        loop {
+            let (stream, peer) = listener
+                .accept()
+                .await
+                .map_err(|e| io_error("Remote control accept failed", e))?;
+            let server = Arc::clone(&server);
+            tokio::spawn(async move {
+                if let Err(e) = server.handle_connection(stream).await {
+                    warn!("Remote control connection from {} ended: {}", peer, e);
+                }
+            });
+        }
+    }
+
+    async fn handle_connection(&self, stream: TcpStream) -> McpResult<()> {
+        let mut channel = tokio::time::timeout(
+            HANDSHAKE_TIMEOUT,
+            NoiseChannel::accept(stream, &self.keypair, &self.operators),
+        )
+        .await
+        .map_err(|_| McpServerError::Network("Noise handshake timed out".to_string()))??;
+        let operator = channel.remote_public_key_hex();
+        info!("Remote operator {} connected", operator);
+
+        while let Some(request) = channel.recv::<ControlRequest>().await? {
+            debug!("Remote operator {} called {}", operator, request.method);
+            let response = match self.dispatch(&operator, &request).await {
+                Ok(result) => ControlResponse {
+                    id: request.id,
+                    result: Some(result),
+                    error: None,
+                },
+                Err(e) => ControlResponse {
+                    id: request.id,
+                    result: None,
+                    error: Some(e.to_string()),
+                },
+            };
+            channel.send(&response).await?;
+        }
+
+        info!("Remote operator {} disconnected", operator);
+        Ok(())
+    }
+
+    async fn dispatch(&self, operator: &str, request: &ControlRequest) -> McpResult<Value> {
+        let approvals = &self.state.approvals;
+        match request.method.as_str() {
+            "status" => self.status().await,
+            "list_pending" => Ok(serde_json::json!({ "pending": approvals.list() })),
+            "approve" => {
+                let approval = approvals.resolve(
+                    approval_id(&request.params)?,
+                    ApprovalDecision::Approved {
+                        operator: operator.to_string(),
+                    },
+                )?;
+                info!(
+                    "Operator {} approved {} ({})",
+                    operator, approval.tool, approval.id
+                );
+                Ok(serde_json::json!({ "approved": approval }))
+            }
+            "reject" => {
+                let reason = request
+                    .params
+                    .get("reason")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("rejected by operator")
+                    .to_string();
+                let approval = approvals.resolve(
+                    approval_id(&request.params)?,
+                    ApprovalDecision::Rejected {
+                        operator: operator.to_string(),
+                        reason,
+                    },
+                )?;
+                info!(
+                    "Operator {} rejected {} ({})",
+                    operator, approval.tool, approval.id
+                );
+                Ok(serde_json::json!({ "rejected": approval }))
+            }
+            other => Err(McpServerError::InvalidArguments(format!(
+                "Unknown remote control method: {}",
+                other
+            ))),
+        }
+    }
+
+    async fn status(&self) -> McpResult<Value> {
+        let state = &self.state;
+        let active_wallet = state
+            .sdk_adapter
+            .get_active_wallet_info()
+            .await?
+            .map(|wallet| wallet.address);
+        let active_monitors = state
+            .transaction_monitor_manager
+            .list_monitors_filtered(false)
+            .await
+            .len();
+
+        Ok(serde_json::json!({
+            "server": state.config.name,
+            "version": state.config.version,
+            "network": state.network_config().await.chain_id,
+            "active_wallet": active_wallet,
+            "active_monitors": active_monitors,
+            "approvals": state.approvals.snapshot(),
+            "write_quotas": state.write_quotas.snapshot().await,
+            "write_nonces": state.write_nonces.snapshot().await,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+}
+
+fn approval_id(params: &Value) -> McpResult<&str> {
+    params
+        .get("approval_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| McpServerError::InvalidArguments("approval_id is required".to_string()))
+}
+
+/// Operator side of the channel
+pub struct RemoteControlClient {
+    channel: NoiseChannel<TcpStream>,
+    next_id: u64,
+}
+
+impl RemoteControlClient {
+    /// Connect to `addr`, refusing any server that does not present `server_public_key`
+    pub async fn connect(
+        addr: &str,
+        keypair: &NoiseKeypair,
+        server_public_key: &str,
+    ) -> McpResult<Self> {
+        let server_key = parse_public_key(server_public_key)?;
+        let stream = TcpStream::connect(addr)
+            .await
+            .map_err(|e| io_error(&format!("Failed to connect to {}", addr), e))?;
+        let channel = tokio::time::timeout(
+            HANDSHAKE_TIMEOUT,
+            NoiseChannel::connect(stream, keypair, &server_key),
+        )
+        .await
+        .map_err(|_| McpServerError::Network("Noise handshake timed out".to_string()))??;
+        Ok(Self {
+            channel,
+            next_id: 1,
+        })
+    }
+
+    /// Call a control method and return its result
+    pub async fn call(&mut self, method: &str, params: Value) -> McpResult<Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.channel
+            .send(&ControlRequest {
+                id,
+                method: method.to_string(),
+                params,
+            })
+            .await?;
+
+        let response: ControlResponse = self.channel.recv().await?.ok_or_else(|| {
+            McpServerError::Network("Remote control server closed the connection".to_string())
+        })?;
+        if response.id != id {
+            return Err(McpServerError::Network(format!(
+                "Expected response {} but got {}",
+                id, response.id
+            )));
+        }
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(McpServerError::Other(error)),
+            (Some(result), None) => Ok(result),
+            (None, None) => Ok(Value::Null),
+        }
+    }
+
+    /// Server status
+    pub async fn status(&mut self) -> McpResult<Value> {
+        self.call("status", Value::Null).await
+    }
+
+    /// Write calls waiting for a decision
+    pub async fn list_pending(&mut self) -> McpResult<Value> {
+        self.call("list_pending", Value::Null).await
+    }
+
+    /// Approve a pending write call
+    pub async fn approve(&mut self, approval_id: &str) -> McpResult<Value> {
+        self.call("approve", serde_json::json!({ "approval_id": approval_id }))
+            .await
+    }
+
+    /// Reject a pending write call
+    pub async fn reject(&mut self, approval_id: &str, reason: &str) -> McpResult<Value> {
+        self.call(
+            "reject",
+            serde_json::json!({ "approval_id": approval_id, "reason": reason }),
+        )
+        .await
+    }
+}
+
+/// Start the remote control channel in the background if it is configured
+pub fn spawn_remote_control(
+    state: Arc<McpServerStateData>,
+) -> McpResult<Option<tokio::task::JoinHandle<()>>> {
+    let config = &state.config.remote_control;
+    if !config.is_enabled() {
+        return Ok(None);
+    }
+
+    let server = RemoteControlServer::new(config, Arc::clone(&state))?;
+    Ok(Some(tokio::spawn(async move {
+        if let Err(e) = server.serve().await {
+            warn!("Remote control channel stopped: {}", e);
+        }
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn pair(
+        operator: &NoiseKeypair,
+        server: &NoiseKeypair,
+        pinned: &[u8],
+        authorized: Vec<Vec<u8>>,
+    ) -> (
+        McpResult<NoiseChannel<tokio::io::DuplexStream>>,
+        McpResult<NoiseChannel<tokio::io::DuplexStream>>,
+    ) {
+        let (client_io, server_io) = tokio::io::duplex(MAX_NOISE_MESSAGE_LEN);
+        tokio::join!(
+            NoiseChannel::connect(client_io, operator, pinned),
+            NoiseChannel::accept(server_io, server, &authorized)
+        )
+    }
+
+    #[tokio::test]
+    async fn test_channel_round_trips_large_messages() {
+        let operator = NoiseKeypair::generate().unwrap();
+        let server = NoiseKeypair::generate().unwrap();
+        let (client, accepted) = pair(
+            &operator,
+            &server,
+            &server.public,
+            vec![operator.public.clone()],
+        )
+        .await;
+        let (mut client, mut accepted) = (client.unwrap(), accepted.unwrap());
+        assert_eq!(accepted.remote_public_key_hex(), operator.public_key_hex());
+
+        // Larger than a single Noise message
+        let message = serde_json::json!({ "blob": "x".repeat(3 * MAX_NOISE_MESSAGE_LEN) });
+        let (sent, received) = tokio::join!(client.send(&message), accepted.recv::<Value>());
+        sent.unwrap();
+        assert_eq!(received.unwrap(), Some(message));
+
+        drop(client);
+        assert!(accepted.recv::<Value>().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handshake_rejects_unknown_keys() {
+        let operator = NoiseKeypair::generate().unwrap();
+        let server = NoiseKeypair::generate().unwrap();
+        let other = NoiseKeypair::generate().unwrap();
+
+        // Server does not know the operator
+        let (_, accepted) = pair(
+            &operator,
+            &server,
+            &server.public,
+            vec![other.public.clone()],
+        )
+        .await;
+        assert!(matches!(accepted, Err(McpServerError::Validation(_))));
+
+        // Operator pinned a different server key
+        let (client, _) = pair(
+            &operator,
+            &server,
+            &other.public,
+            vec![operator.public.clone()],
+        )
+        .await;
+        assert!(matches!(client, Err(McpServerError::Validation(_))));
+    }
+
+    #[test]
+    fn test_keypair_is_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("remote_control.key");
+        let generated = NoiseKeypair::load_or_generate(&path).unwrap();
+        let loaded = NoiseKeypair::load_or_generate(&path).unwrap();
+        assert_eq!(generated.public_key_hex(), loaded.public_key_hex());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert!(parse_public_key("abcd").is_err());
+    }
+}
@@ -0,0 +1,232 @@
+//! Readable MCP data resources
+//!
+//! Pools, tokens, claimdrop campaigns and the active wallet are exposed as
+//! resources so clients can read them without a tool call. List resources are
+//! paginated through `cursor` and `limit` query parameters on the URI, e.g.
+//! `mcp://pools?cursor=50&limit=25`, and every page links to the next one.
+
+use serde_json::Value;
+
+/// Items per page when the URI names no limit
+pub const DEFAULT_RESOURCE_PAGE_SIZE: usize = 50;
+
+/// Largest page a client may ask for
+pub const MAX_RESOURCE_PAGE_SIZE: usize = 500;
+
+/// URI scheme of the data resources
+pub const RESOURCE_SCHEME: &str = "mcp://";
+
+/// Data resource named by a URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataResource {
+    /// `mcp://pools`
+    Pools,
+    /// `mcp://tokens/{chain_id}`
+    Tokens(String),
+    /// `mcp://campaigns`
+    Campaigns,
+    /// `mcp://wallet/active`
+    ActiveWallet,
+}
+
+/// Window of a paginated resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourcePage {
+    /// Index of the first item
+    pub cursor: usize,
+    pub limit: usize,
+}
+
+impl Default for ResourcePage {
+    fn default() -> Self {
+        Self {
+            cursor: 0,
+            limit: DEFAULT_RESOURCE_PAGE_SIZE,
+        }
+    }
+}
+
+impl DataResource {
+    /// Resource and page named by `uri`
+    ///
+    /// Returns `None` for URIs outside the `mcp://` data resources and an
+    /// error message for malformed pagination parameters.
+    pub fn parse(uri: &str) -> Option<Result<(Self, ResourcePage), String>> {
+        let rest = uri.strip_prefix(RESOURCE_SCHEME)?;
+        let (path, query) = match rest.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (rest, None),
+        };
+
+        let resource = match path.trim_end_matches('/').split('/').collect::<Vec<_>>()[..] {
+            ["pools"] => Self::Pools,
+            ["tokens", chain_id] if !chain_id.is_empty() => Self::Tokens(chain_id.to_string()),
+            ["campaigns"] => Self::Campaigns,
+            ["wallet", "active"] => Self::ActiveWallet,
+            _ => return None,
+        };
+
+        Some(Self::parse_page(query).map(|page| (resource, page)))
+    }
+
+    fn parse_page(query: Option<&str>) -> Result<ResourcePage, String> {
+        let mut page = ResourcePage::default();
+        for pair in query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|p| !p.is_empty())
+        {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let number = || {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid {} '{}'", key, value))
+            };
+            match key {
+                "cursor" => page.cursor = number()?,
+                "limit" => page.limit = number()?.clamp(1, MAX_RESOURCE_PAGE_SIZE),
+                _ => return Err(format!("Unknown resource parameter '{}'", key)),
+            }
+        }
+        Ok(page)
+    }
+
+    /// URI of the resource without pagination parameters
+    pub fn uri(&self) -> String {
+        match self {
+            Self::Pools => format!("{}pools", RESOURCE_SCHEME),
+            Self::Tokens(chain_id) => format!("{}tokens/{}", RESOURCE_SCHEME, chain_id),
+            Self::Campaigns => format!("{}campaigns", RESOURCE_SCHEME),
+            Self::ActiveWallet => format!("{}wallet/active", RESOURCE_SCHEME),
+        }
+    }
+
+    /// Adapter cache key of the resource's unpaginated items
+    pub fn cache_key(&self) -> String {
+        format!("resource:{}", self.uri())
+    }
+}
+
+/// One page of `items`, with the URI of the next page if there is one
+pub fn paginate(resource: &DataResource, items: &[Value], page: ResourcePage) -> Value {
+    let end = page.cursor.saturating_add(page.limit).min(items.len());
+    let window = items.get(page.cursor..end).unwrap_or_default();
+    let next_cursor = (end < items.len()).then_some(end);
+
+    serde_json::json!({
+        "items": window,
+        "total": items.len(),
+        "cursor": page.cursor,
+        "limit": page.limit,
+        "next_cursor": next_cursor,
+        "next_uri": next_cursor.map(|cursor| {
+            format!("{}?cursor={}&limit={}", resource.uri(), cursor, page.limit)
+        }),
+    })
+}
+
+/// `resources/read` result carrying `body` as JSON text
+pub fn render_contents(uri: &str, body: &Value) -> Value {
+    serde_json::json!({
+        "contents": [{
+            "uri": uri,
+            "mimeType": "application/json",
+            "text": body.to_string(),
+        }]
+    })
+}
+
+/// Entries of the fixed data resources for `resources/list`
+pub fn resource_descriptors() -> Vec<Value> {
+    vec![
+        serde_json::json!({
+            "uri": DataResource::Pools.uri(),
+            "name": "Pools",
+            "description": "Liquidity pools on the active network, paginated with ?cursor=&limit=",
+            "mimeType": "application/json"
+        }),
+        serde_json::json!({
+            "uri": DataResource::Campaigns.uri(),
+            "name": "Claimdrop Campaigns",
+            "description": "Campaigns of the configured claimdrop factory, paginated with ?cursor=&limit=",
+            "mimeType": "application/json"
+        }),
+        serde_json::json!({
+            "uri": DataResource::ActiveWallet.uri(),
+            "name": "Active Wallet",
+            "description": "Address and balances of the active wallet",
+            "mimeType": "application/json"
+        }),
+    ]
+}
+
+/// Entries of the parameterized data resources for `resources/templates/list`
+pub fn resource_templates() -> Vec<Value> {
+    vec![serde_json::json!({
+        "uriTemplate": format!("{}tokens/{{chain_id}}", RESOURCE_SCHEME),
+        "name": "Chain Tokens",
+        "description": "Tokens known on a chain: pool assets on the active network, Skip assets elsewhere",
+        "mimeType": "application/json"
+    })]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_resource_uris() {
+        let (resource, page) = DataResource::parse("mcp://pools").unwrap().unwrap();
+        assert_eq!(resource, DataResource::Pools);
+        assert_eq!(page, ResourcePage::default());
+
+        let (resource, page) = DataResource::parse("mcp://tokens/osmosis-1?cursor=20&limit=5000")
+            .unwrap()
+            .unwrap();
+        assert_eq!(resource, DataResource::Tokens("osmosis-1".to_string()));
+        assert_eq!(page.cursor, 20);
+        assert_eq!(page.limit, MAX_RESOURCE_PAGE_SIZE);
+
+        assert!(DataResource::parse("mcp://pools?cursor=abc")
+            .unwrap()
+            .is_err());
+        assert!(DataResource::parse("mcp://tokens/").is_none());
+        assert!(DataResource::parse("trades://history").is_none());
+    }
+
+    #[test]
+    fn test_paginate_links_next_page() {
+        let items: Vec<Value> = (0..5).map(|i| serde_json::json!(i)).collect();
+        let first = paginate(
+            &DataResource::Pools,
+            &items,
+            ResourcePage {
+                cursor: 0,
+                limit: 2,
+            },
+        );
+        assert_eq!(first["items"], serde_json::json!([0, 1]));
+        assert_eq!(first["next_uri"], "mcp://pools?cursor=2&limit=2");
+
+        let last = paginate(
+            &DataResource::Pools,
+            &items,
+            ResourcePage {
+                cursor: 4,
+                limit: 2,
+            },
+        );
+        assert_eq!(last["items"], serde_json::json!([4]));
+        assert!(last["next_cursor"].is_null());
+
+        let past_end = paginate(
+            &DataResource::Pools,
+            &items,
+            ResourcePage {
+                cursor: 9,
+                limit: 2,
+            },
+        );
+        assert_eq!(past_end["items"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,86 @@
+//! Response Formats of Tool Results
+//!
+//! Wallet and balance tools return typed responses. By default the text
+//! content of their result is that response as JSON, so agents can read it
+//! without re-parsing prose. A call with `"response_format": "markdown"` gets
+//! the response rendered for people instead, through [`MarkdownRender`].
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::server::McpResult;
+
+/// Format of the text content of a tool result: `json` for the typed response,
+/// `markdown` for it rendered through [`MarkdownRender`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    Markdown,
+}
+
+impl ResponseFormat {
+    /// Render `response` in this format
+    pub fn render<T: Serialize + MarkdownRender>(self, response: &T) -> McpResult<String> {
+        match self {
+            Self::Json => Ok(serde_json::to_string_pretty(response)?),
+            Self::Markdown => Ok(response.to_markdown()),
+        }
+    }
+}
+
+/// Rendering of a typed tool response for people
+pub trait MarkdownRender {
+    fn to_markdown(&self) -> String;
+}
+
+/// MCP tool result with `response` rendered in `format`
+pub fn tool_result<T: Serialize + MarkdownRender>(
+    format: ResponseFormat,
+    response: &T,
+) -> McpResult<Value> {
+    let text = format.render(response)?;
+    Ok(serde_json::json!({
+        "content": [
+            {
+                "type": "text",
+                "text": text
+            }
+        ]
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Balance {
+        amount: String,
+    }
+
+    impl MarkdownRender for Balance {
+        fn to_markdown(&self) -> String {
+            format!("**Balance:** {}\n", self.amount)
+        }
+    }
+
+    #[test]
+    fn test_json_by_default_and_markdown_on_request() {
+        let balance = Balance {
+            amount: "1.5".to_string(),
+        };
+
+        let result = tool_result(ResponseFormat::default(), &balance).unwrap();
+        let text = result["content"][0]["text"].as_str().unwrap();
+        let parsed: Value = serde_json::from_str(text).unwrap();
+        assert_eq!(parsed["amount"], "1.5");
+
+        let format: ResponseFormat = serde_json::from_value(serde_json::json!("markdown")).unwrap();
+        let result = tool_result(format, &balance).unwrap();
+        assert_eq!(result["content"][0]["text"], "**Balance:** 1.5\n");
+        assert!(serde_json::from_value::<ResponseFormat>(serde_json::json!("xml")).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! Wallet activity across the Cosmos and EVM sides of the chain
+
+use super::*;
+use crate::mcp::response_format::MarkdownRender;
+use crate::protocols::cosmos_analyzer::{
+    sequential_narrative, AnalyzedCosmosTx, CosmosTxAnalyzer, CosmosTxEvent,
+};
+
+/// Most Cosmos transactions `analyze_wallet_activity` fetches at once
+pub const MAX_ACTIVITY_TRANSACTIONS: u8 = 50;
+
+/// Result of `analyze_wallet_activity`
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletActivityResponse {
+    /// Cosmos wallet whose recent transactions were searched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<String>,
+    /// Both sides told in order, Cosmos first
+    pub narrative: String,
+    /// Decoded Cosmos transactions, oldest first
+    pub cosmos_transactions: Vec<AnalyzedCosmosTx>,
+    /// Result of the EVM transaction analysis, when EVM hashes were given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm: Option<Value>,
+    /// Transactions that could not be fetched or decoded
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+impl MarkdownRender for WalletActivityResponse {
+    fn to_markdown(&self) -> String {
+        let mut text = match &self.wallet {
+            Some(wallet) => format!("## Activity of `{}`\n\n", wallet),
+            None => "## Wallet Activity\n\n".to_string(),
+        };
+        text.push_str(&self.narrative);
+        text.push('\n');
+        for error in &self.errors {
+            text.push_str(&format!("\n⚠️ {}", error));
+        }
+        text
+    }
+}
+
+impl McpSdkAdapter {
+    /// Decode and narrate a wallet's Cosmos transactions and the given EVM
+    /// transactions together
+    ///
+    /// Cosmos transactions are those in `cosmos_hashes`, or else the `limit`
+    /// most recent ones signed by `wallet_address` (the active wallet when
+    /// unset). Transactions that failed on chain are left out unless
+    /// `include_failed` is set.
+    pub async fn analyze_wallet_activity(
+        &self,
+        wallet_address: Option<String>,
+        cosmos_hashes: Vec<String>,
+        evm_hashes: Vec<String>,
+        limit: u8,
+        include_failed: bool,
+    ) -> McpResult<WalletActivityResponse> {
+        let network_config = self.get_default_network_config().await?;
+        let active_wallet = self
+            .get_active_wallet_info()
+            .await
+            .ok()
+            .flatten()
+            .map(|info| info.address);
+        let wallet = wallet_address.or_else(|| active_wallet.clone());
+        if cosmos_hashes.is_empty() && evm_hashes.is_empty() && wallet.is_none() {
+            return Err(McpServerError::InvalidArguments(
+                "Give a wallet_address or transaction hashes, or activate a wallet".to_string(),
+            ));
+        }
+
+        let client = self.get_client(&network_config).await?;
+        let mut errors = Vec::new();
+        let responses = if !cosmos_hashes.is_empty() {
+            let mut responses = Vec::new();
+            for hash in &cosmos_hashes {
+                match client.get_tx(hash).await {
+                    Ok(response) => responses.push(response),
+                    Err(e) => errors.push(format!("Transaction {}: {}", hash, e)),
+                }
+            }
+            responses
+        } else if let Some(wallet) = &wallet {
+            report_progress(0, None, &format!("Searching transactions of {}", wallet));
+            client
+                .search_txs_by_sender(wallet, limit.clamp(1, MAX_ACTIVITY_TRANSACTIONS))
+                .await
+                .map_err(McpServerError::Sdk)?
+        } else {
+            Vec::new()
+        };
+
+        let analyzer = CosmosTxAnalyzer::new(&network_config.contracts)
+            .with_active_wallet(active_wallet)
+            .with_address_labels(self.cosmos_address_labels().await);
+        let total = responses.len() as u64;
+        let mut cosmos_transactions = Vec::new();
+        for (i, response) in responses.iter().enumerate() {
+            let hash = response.hash.to_string();
+            report_progress(
+                i as u64 + 1,
+                Some(total),
+                &format!("Decoding transaction {}", hash),
+            );
+            let code = response.tx_result.code.value();
+            if code != 0 && !include_failed {
+                continue;
+            }
+            let events: Vec<CosmosTxEvent> = response
+                .tx_result
+                .events
+                .iter()
+                .map(|event| CosmosTxEvent {
+                    kind: event.kind.clone(),
+                    attributes: event
+                        .attributes
+                        .iter()
+                        .map(|attr| {
+                            (
+                                attr.key_str().unwrap_or("").to_string(),
+                                attr.value_str().unwrap_or("").to_string(),
+                            )
+                        })
+                        .collect(),
+                })
+                .collect();
+            match analyzer.analyze(&hash, response.height.value(), code, &response.tx, &events) {
+                Ok(analyzed) => cosmos_transactions.push(analyzed),
+                Err(e) => errors.push(format!("Transaction {}: {}", hash, e)),
+            }
+        }
+        cosmos_transactions.sort_by_key(|tx| tx.height);
+
+        let cosmos_narrative = sequential_narrative(
+            &cosmos_transactions
+                .iter()
+                .map(|tx| tx.narrative.clone())
+                .collect::<Vec<_>>(),
+        );
+        let evm = self
+            .evm_activity(evm_hashes, include_failed, &mut errors)
+            .await;
+        let narrative = match evm
+            .as_ref()
+            .and_then(|evm| evm.get("narrative"))
+            .and_then(Value::as_str)
+        {
+            Some(evm_narrative) => format!(
+                "Cosmos:\n{}\n\nEVM:\n{}",
+                cosmos_narrative.trim_end(),
+                evm_narrative.trim_end()
+            ),
+            None => cosmos_narrative,
+        };
+
+        Ok(WalletActivityResponse {
+            wallet,
+            narrative,
+            cosmos_transactions,
+            evm,
+            errors,
+        })
+    }
+
+    /// EVM transaction analysis of `hashes`, if any were given
+    #[cfg(feature = "evm")]
+    async fn evm_activity(
+        &self,
+        hashes: Vec<String>,
+        include_failed: bool,
+        errors: &mut Vec<String>,
+    ) -> Option<Value> {
+        if hashes.is_empty() {
+            return None;
+        }
+        let args = serde_json::json!({
+            "transaction_hashes": hashes,
+            "include_failed": include_failed,
+        });
+        match self.evm_analyze_transaction_history(args).await {
+            Ok(analysis) => Some(analysis),
+            Err(e) => {
+                errors.push(format!("EVM transactions: {}", e));
+                None
+            }
+        }
+    }
+
+    #[cfg(not(feature = "evm"))]
+    async fn evm_activity(
+        &self,
+        hashes: Vec<String>,
+        _include_failed: bool,
+        errors: &mut Vec<String>,
+    ) -> Option<Value> {
+        if !hashes.is_empty() {
+            errors.push("EVM transactions need the evm feature".to_string());
+        }
+        None
+    }
+}
@@ -0,0 +1,267 @@
+//! Address book methods
+
+use super::*;
+use crate::mcp::response_format::MarkdownRender;
+use crate::wallet::address::{inspect_address, AddressInfo, DEFAULT_ACCOUNT_PREFIX};
+use crate::wallet::{AddressEntry, AddressKind};
+
+/// Result of `address_book_list`
+#[derive(Debug, Clone, Serialize)]
+pub struct AddressBookResponse {
+    pub entries: Vec<AddressEntry>,
+    /// File the book is kept in; unset when it is kept in memory only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Result of `address_book_add` and `address_book_remove`
+#[derive(Debug, Clone, Serialize)]
+pub struct AddressBookChangeResponse {
+    /// "added", "replaced", "removed" or "not_found"
+    pub action: String,
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry: Option<AddressEntry>,
+    /// Address the label stood for before a replacement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_address: Option<String>,
+}
+
+impl MarkdownRender for AddressBookResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!("📒 **Address Book** ({} entries)\n\n", self.entries.len());
+        if self.entries.is_empty() {
+            response.push_str("No labels yet; add one with `address_book_add`.\n");
+        }
+        for entry in &self.entries {
+            response.push_str(&format!(
+                "- **{}** ({}): `{}`",
+                entry.label,
+                entry.kind.as_str(),
+                entry.address
+            ));
+            if let Some(note) = &entry.note {
+                response.push_str(&format!(" - {}", note));
+            }
+            response.push('\n');
+        }
+        response
+    }
+}
+
+impl MarkdownRender for AddressBookChangeResponse {
+    fn to_markdown(&self) -> String {
+        match (&self.entry, self.action.as_str()) {
+            (_, "not_found") => format!("No address is labelled **{}**.\n", self.label),
+            (Some(entry), "removed") => format!(
+                "🗑️ **Label Removed:** **{}** no longer stands for `{}`\n",
+                entry.label, entry.address
+            ),
+            (Some(entry), _) => {
+                let mut response = format!(
+                    "✅ **Label Saved:** **{}** → `{}` ({})\n",
+                    entry.label,
+                    entry.address,
+                    entry.kind.as_str()
+                );
+                if let Some(previous) = &self.previous_address {
+                    response.push_str(&format!("**Replaced:** `{}`\n", previous));
+                }
+                response
+            }
+            (None, action) => format!("**{}**: {}\n", self.label, action),
+        }
+    }
+}
+
+/// Result of `address_convert`
+#[derive(Debug, Clone, Serialize)]
+pub struct AddressConversionResponse {
+    #[serde(flatten)]
+    pub info: AddressInfo,
+    /// Both addresses of the loaded wallet the address belongs to; the
+    /// wallet's Cosmos and EVM addresses are different accounts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wallet: Option<WalletAddressPair>,
+}
+
+/// Cosmos and EVM address of one wallet
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletAddressPair {
+    pub cosmos: String,
+    pub evm: String,
+}
+
+impl MarkdownRender for AddressConversionResponse {
+    fn to_markdown(&self) -> String {
+        let info = &self.info;
+        let mut response = if info.valid {
+            format!(
+                "✅ **Valid {} Address**\n\n",
+                info.kind.map(AddressKind::as_str).unwrap_or("unknown")
+            )
+        } else {
+            "❌ **Invalid Address**\n\n".to_string()
+        };
+        response.push_str(&format!("**Input:** `{}`\n", info.input));
+        if let Some(bech32) = &info.bech32 {
+            response.push_str(&format!("**Bech32:** `{}`\n", bech32));
+        }
+        if let Some(evm) = &info.evm {
+            response.push_str(&format!("**EVM:** `{}`\n", evm));
+        }
+        if let Some(error) = &info.error {
+            response.push_str(&format!("**Error:** {}\n", error));
+        }
+        if let Some(wallet) = &self.wallet {
+            response.push_str(&format!(
+                "\n**Loaded wallet:** Cosmos `{}`, EVM `{}`\n",
+                wallet.cosmos, wallet.evm
+            ));
+        }
+        for warning in &info.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
+impl McpSdkAdapter {
+    /// Validate `address` and convert it between its bech32 and EVM forms
+    ///
+    /// `expected` is the VM of the operation the address is meant for; an
+    /// address written for the other VM is flagged. When the address is one
+    /// of a loaded wallet's, the wallet's other address is reported too.
+    pub async fn convert_address(
+        &self,
+        address: &str,
+        expected: Option<AddressKind>,
+        prefix: Option<&str>,
+    ) -> McpResult<AddressConversionResponse> {
+        let info = inspect_address(address, prefix.unwrap_or(DEFAULT_ACCOUNT_PREFIX), expected);
+        let wallet = if info.valid {
+            self.wallet_address_pair(&info).await
+        } else {
+            None
+        };
+        Ok(AddressConversionResponse { info, wallet })
+    }
+
+    /// Addresses of the loaded wallet `info` is one of
+    #[cfg(feature = "evm")]
+    async fn wallet_address_pair(&self, info: &AddressInfo) -> Option<WalletAddressPair> {
+        let wallets = self.get_all_wallets().await.ok()?;
+        for address in wallets.keys() {
+            let Ok((cosmos, evm)) = self.get_wallet_evm_address(Some(address.clone())).await else {
+                continue;
+            };
+            let matches = match info.kind {
+                Some(AddressKind::Cosmos) => Some(&cosmos) == info.bech32.as_ref(),
+                Some(AddressKind::Evm) => info
+                    .evm
+                    .as_ref()
+                    .is_some_and(|form| form.eq_ignore_ascii_case(&evm)),
+                None => false,
+            };
+            if matches {
+                return Some(WalletAddressPair { cosmos, evm });
+            }
+        }
+        None
+    }
+
+    #[cfg(not(feature = "evm"))]
+    async fn wallet_address_pair(&self, _info: &AddressInfo) -> Option<WalletAddressPair> {
+        None
+    }
+
+    /// Label `address`, replacing what the label stood for before
+    pub async fn address_book_add(
+        &self,
+        label: &str,
+        address: &str,
+        note: Option<String>,
+    ) -> McpResult<AddressBookChangeResponse> {
+        let mut book = self.address_book.write().await;
+        let previous_address = book.get(label).map(|entry| entry.address.clone());
+        let entry = book
+            .add(label, address, note)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+        info!("Address book: {} -> {}", entry.label, entry.address);
+
+        Ok(AddressBookChangeResponse {
+            action: if previous_address.is_some() {
+                "replaced"
+            } else {
+                "added"
+            }
+            .to_string(),
+            label: entry.label.clone(),
+            entry: Some(entry),
+            previous_address,
+        })
+    }
+
+    /// Forget `label`
+    pub async fn address_book_remove(&self, label: &str) -> McpResult<AddressBookChangeResponse> {
+        let removed =
+            self.address_book.write().await.remove(label).map_err(|e| {
+                McpServerError::Internal(format!("Failed to save address book: {}", e))
+            })?;
+
+        Ok(AddressBookChangeResponse {
+            action: if removed.is_some() {
+                "removed"
+            } else {
+                "not_found"
+            }
+            .to_string(),
+            label: label.to_string(),
+            entry: removed,
+            previous_address: None,
+        })
+    }
+
+    pub async fn address_book_list(&self) -> AddressBookResponse {
+        let book = self.address_book.read().await;
+        AddressBookResponse {
+            entries: book.list(),
+            path: book.path().map(|path| path.display().to_string()),
+        }
+    }
+
+    /// Replace address book labels in the address fields of tool `arguments`,
+    /// returning the labels resolved
+    pub async fn resolve_address_labels(&self, arguments: &mut Value) -> Vec<String> {
+        self.address_book.read().await.resolve_labels_in(arguments)
+    }
+
+    /// Labels of the address book addresses `text` mentions, by address
+    pub async fn address_labels_in(&self, text: &str) -> BTreeMap<String, String> {
+        self.address_book.read().await.labels_in_text(text)
+    }
+
+    /// Labels of the Cosmos addresses in the address book, by address
+    pub(crate) async fn cosmos_address_labels(&self) -> HashMap<String, String> {
+        self.address_book
+            .read()
+            .await
+            .list()
+            .into_iter()
+            .filter(|entry| entry.kind == AddressKind::Cosmos)
+            .map(|entry| (entry.address, entry.label))
+            .collect()
+    }
+
+    /// Labels of the EVM addresses in the address book
+    pub(crate) async fn evm_address_labels(&self) -> HashMap<Address, String> {
+        self.address_book
+            .read()
+            .await
+            .list()
+            .into_iter()
+            .filter(|entry| entry.kind == AddressKind::Evm)
+            .filter_map(|entry| Some((Address::from_str(&entry.address).ok()?, entry.label)))
+            .collect()
+    }
+}
@@ -0,0 +1,122 @@
+//! Asset resolution backed by the adapter's registries
+
+use super::*;
+use crate::mcp::assets::{ibc_denom, AssetKind, AssetRecord, AssetResolver, ResolveAssetResponse};
+use crate::mcp::resources::DataResource;
+
+impl McpSdkAdapter {
+    /// Resolve `query` (symbol, denom, IBC path or ERC-20 address) to the known
+    /// assets it names, on `chain_id` only when given
+    ///
+    /// Registries that cannot be read are reported as warnings rather than
+    /// failing the lookup.
+    pub async fn resolve_asset(
+        &self,
+        query: &str,
+        chain_id: Option<&str>,
+    ) -> McpResult<ResolveAssetResponse> {
+        let network_config = self.get_default_network_config().await?;
+        let (resolver, warnings) = self.asset_resolver(&network_config, chain_id).await;
+
+        Ok(ResolveAssetResponse {
+            query: query.to_string(),
+            chain_id: chain_id.map(str::to_string),
+            matches: resolver.resolve(query, chain_id),
+            ibc_denom: (query.contains('/') && AssetKind::of_denom(query) != AssetKind::Ibc)
+                .then(|| ibc_denom(query.trim())),
+            warnings,
+        })
+    }
+
+    /// Index of the assets known on the active network, its EVM chain and
+    /// `extra_chain`, with a warning for each registry that failed
+    async fn asset_resolver(
+        &self,
+        network_config: &MantraNetworkConfig,
+        extra_chain: Option<&str>,
+    ) -> (AssetResolver, Vec<String>) {
+        let mut resolver = AssetResolver::new();
+        let mut warnings = Vec::new();
+        let chain_id = network_config.chain_id.as_str();
+
+        // The native denom is micro-denominated, e.g. uom for OM
+        let native_denom = network_config.native_denom.as_str();
+        let native_symbol = native_denom
+            .strip_prefix('u')
+            .unwrap_or(native_denom)
+            .to_uppercase();
+        resolver.add_denom(
+            chain_id,
+            native_denom,
+            Some(&native_symbol),
+            Some(6),
+            "network",
+        );
+
+        // Skip's asset lists name symbols and IBC paths, so they go before
+        // the pool tokens, which only know denoms and decimals
+        match MantraClient::new(network_config.clone(), None).await {
+            Ok(client) => match client.skip().await {
+                Ok(mut skip_client) => {
+                    self.configure_skip_client(&mut skip_client);
+                    let mut chains = vec![chain_id];
+                    chains.extend(extra_chain.filter(|extra| *extra != chain_id));
+                    for chain in chains {
+                        match self.cached_skip_chain_assets(&skip_client, chain).await {
+                            Ok((assets, _)) => {
+                                for asset in assets {
+                                    resolver.add(AssetRecord {
+                                        symbol: asset.symbol,
+                                        kind: AssetKind::of_denom(&asset.denom),
+                                        chain_id: chain.to_string(),
+                                        denom: Some(asset.denom),
+                                        address: asset.contract_address,
+                                        ibc_trace: asset.trace,
+                                        decimals: Some(asset.decimals),
+                                        source: "skip".to_string(),
+                                    });
+                                }
+                            }
+                            Err(e) => warnings.push(format!("Skip assets of {}: {}", chain, e)),
+                        }
+                    }
+                }
+                Err(e) => warnings.push(format!("Skip assets: {}", e)),
+            },
+            Err(e) => warnings.push(format!("Skip assets: {}", e)),
+        }
+
+        let tokens = DataResource::Tokens(chain_id.to_string());
+        let items = match self.cache_get(&tokens.cache_key()).await {
+            Some(Value::Array(items)) => Ok(items),
+            _ => self.load_resource_items(&tokens).await,
+        };
+        match items {
+            Ok(items) => {
+                for item in items {
+                    if let Some(denom) = item.get("denom").and_then(|d| d.as_str()) {
+                        let decimals = item
+                            .get("decimals")
+                            .and_then(|d| d.as_u64())
+                            .map(|d| d as u8);
+                        resolver.add_denom(chain_id, denom, None, decimals, "pools");
+                    }
+                }
+            }
+            Err(e) => warnings.push(format!("Pool tokens: {}", e)),
+        }
+
+        if let Some(evm_chain_id) = network_config.evm_chain_id {
+            for token in self.list_registry_tokens(evm_chain_id).await {
+                resolver.add_erc20(
+                    evm_chain_id,
+                    &token.checksummed_address(),
+                    &token.symbol,
+                    token.decimals,
+                );
+            }
+        }
+
+        (resolver, warnings)
+    }
+}
@@ -1,6 +1,18 @@
 //! ClaimDrop protocol methods
 
 use super::*;
+use crate::protocols::claimdrop::builder::{
+    parse_allocations_csv, CampaignBuilder, DEFAULT_ALLOCATION_CHUNK_SIZE,
+};
+use crate::protocols::claimdrop::closure::{
+    EndOfLifeReport, SweepOutcome, DEFAULT_CLAIM_GRACE_PERIOD_SECS,
+};
+use crate::protocols::claimdrop::stats::DEFAULT_STATS_CACHE_TTL;
+use crate::protocols::claimdrop::types::ClaimdropOperationResult;
+use crate::protocols::claimdrop::vesting::DEFAULT_TIMELINE_POINTS;
+use crate::protocols::claimdrop::{
+    ClaimdropClient, ClaimdropFactoryClient, MerkleTree, SignedAttestation,
+};
 
 impl McpSdkAdapter {
     // ClaimDrop Protocol Methods
@@ -21,6 +33,842 @@ impl McpSdkAdapter {
                 McpServerError::InvalidArguments("factory_address is required".to_string())
             })?;
 
+        let campaign_params = Self::parse_claimdrop_campaign_params(&args)?;
+
+        // Get factory client and create campaign
+        let factory_client = self.signed_factory_client(factory_address).await?;
+
+        let result = factory_client
+            .create_campaign(campaign_params)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "create_campaign",
+            "factory_address": factory_address,
+            "result": result,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Claim rewards from a claimdrop campaign
+    pub async fn claimdrop_claim(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Claiming from ClaimDrop campaign with args: {:?}",
+            args
+        );
+
+        // Parse required parameters
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+
+        // Parse optional parameters
+        let amount = args
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .and_then(|s| Uint128::from_str(s).ok());
+
+        let receiver = args
+            .get("receiver")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        // Get ClaimDrop client for the campaign
+        let claimdrop_client = self.signed_campaign_client(campaign_address).await?;
+
+        let result = claimdrop_client
+            .claim(amount, receiver)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "claim",
+            "campaign_address": campaign_address,
+            "result": result,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Query user rewards from a claimdrop campaign
+    pub async fn claimdrop_query_rewards(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Querying ClaimDrop rewards with args: {:?}",
+            args
+        );
+
+        // Parse required parameters
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+
+        let receiver_address = args
+            .get("receiver")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("receiver is required".to_string()))?;
+
+        // Get network config and create MantraClient
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        // Get ClaimDrop client for the campaign
+        let claimdrop_client = client.claimdrop_campaign(campaign_address.to_string());
+
+        let rewards = claimdrop_client
+            .query_rewards(receiver_address)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "query_rewards",
+            "campaign_address": campaign_address,
+            "receiver": receiver_address,
+            "rewards": rewards,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Query an address's vesting schedule in a claimdrop campaign
+    ///
+    /// Returns the typed schedule with claimable-now and, when `at_timestamp` is
+    /// given, claimable-at projections plus a timeline over the vesting period.
+    pub async fn claimdrop_get_vesting_schedule(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Querying ClaimDrop vesting schedule with args: {:?}",
+            args
+        );
+
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+
+        // Default to the active wallet when no address is given
+        let address = match args.get("address").and_then(|v| v.as_str()) {
+            Some(address) => address.to_string(),
+            None => self
+                .get_active_wallet_info()
+                .await?
+                .map(|info| info.address)
+                .ok_or_else(|| {
+                    McpServerError::InvalidArguments(
+                        "address is required when no wallet is active".to_string(),
+                    )
+                })?,
+        };
+
+        let at_timestamp = args.get("at_timestamp").and_then(|v| v.as_u64());
+        let timeline_points = args
+            .get("timeline_points")
+            .and_then(|v| v.as_u64())
+            .map(|points| points as usize)
+            .unwrap_or(DEFAULT_TIMELINE_POINTS);
+
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let schedule = client
+            .claimdrop_campaign(campaign_address.to_string())
+            .query_vesting_schedule(&address)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "get_vesting_schedule",
+            "campaign_address": campaign_address,
+            "address": address,
+            "schedule": schedule,
+            "claimable_now": schedule.claimable_at(now),
+            "locked_now": schedule.locked_at(now),
+            "next_unlock": schedule.next_unlock_after(now),
+            "fully_vested_at": schedule.fully_vested_at(),
+            "projection": at_timestamp.map(|at| serde_json::json!({
+                "timestamp": at,
+                "vested": schedule.vested_at(at),
+                "claimable": schedule.claimable_at(at),
+            })),
+            "timeline": schedule.timeline(timeline_points),
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Query campaigns from factory
+    pub async fn claimdrop_query_campaigns(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Querying ClaimDrop campaigns with args: {:?}",
+            args
+        );
+
+        // Parse required parameters
+        let factory_address = args
+            .get("factory_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("factory_address is required".to_string())
+            })?;
+
+        // Parse optional parameters
+        let start_after = args.get("start_after").and_then(|v| v.as_str());
+
+        let limit = args.get("limit").and_then(|v| v.as_u64()).map(|l| l as u16);
+
+        // Get network config and create MantraClient
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        // Get factory client
+        let factory_client = client.claimdrop_factory(factory_address.to_string());
+
+        let campaigns = factory_client
+            .query_campaigns(start_after, limit)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "query_campaigns",
+            "factory_address": factory_address,
+            "campaigns": campaigns,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Export a signed attestation of an address's allocations across campaigns
+    pub async fn claimdrop_export_eligibility_proof(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Exporting ClaimDrop eligibility proof with args: {:?}",
+            args
+        );
+
+        let factory_address = args
+            .get("factory_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("factory_address is required".to_string())
+            })?;
+
+        // The active wallet signs the attestation
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let address = match args.get("address").and_then(|v| v.as_str()) {
+            Some(address) => address.to_string(),
+            None => wallet.address().map_err(McpServerError::Sdk)?.to_string(),
+        };
+
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let attestation = client
+            .claimdrop_factory(factory_address.to_string())
+            .collect_eligibility(&address)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let signed = attestation.sign(&wallet).map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "export_eligibility_proof",
+            "factory_address": factory_address,
+            "address": address,
+            "campaigns": signed.attestation.campaigns.len(),
+            "proof": signed,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Verify the signature of an exported eligibility proof
+    pub async fn claimdrop_verify_eligibility_proof(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Verifying ClaimDrop eligibility proof with args: {:?}",
+            args
+        );
+
+        let proof = args
+            .get("proof")
+            .cloned()
+            .ok_or_else(|| McpServerError::InvalidArguments("proof is required".to_string()))?;
+        let signed: SignedAttestation = serde_json::from_value(proof)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid proof: {}", e)))?;
+
+        let verification = signed.verify();
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "verify_eligibility_proof",
+            "valid": verification.is_ok(),
+            "error": verification.err().map(|e| e.to_string()),
+            "signer": signed.signer,
+            "address": signed.attestation.address,
+            "chain_id": signed.attestation.chain_id,
+            "block_height": signed.attestation.block_height,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Get statistics aggregated across every campaign of a factory
+    pub async fn claimdrop_get_global_stats(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Getting ClaimDrop global stats with args: {:?}",
+            args
+        );
+
+        let factory_address = args
+            .get("factory_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("factory_address is required".to_string())
+            })?;
+
+        let max_age = args
+            .get("max_age_secs")
+            .and_then(|v| v.as_u64())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_STATS_CACHE_TTL);
+        let force_refresh = args
+            .get("force_refresh")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let include_campaigns = args
+            .get("include_campaigns")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let mut stats = client
+            .claimdrop_factory(factory_address.to_string())
+            .get_global_stats(max_age, force_refresh)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        if !include_campaigns {
+            stats.campaigns.clear();
+        }
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "get_global_stats",
+            "factory_address": factory_address,
+            "stats": stats,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Add allocations to a claimdrop campaign
+    pub async fn claimdrop_add_allocations(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Adding ClaimDrop allocations with args: {:?}",
+            args
+        );
+
+        // Parse required parameters
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+
+        let allocations_array = args
+            .get("allocations")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("allocations is required".to_string())
+            })?;
+
+        // Parse allocations
+        let allocations = Self::parse_claimdrop_allocations(allocations_array)?;
+
+        // Get ClaimDrop client for the campaign
+        let claimdrop_client = self.signed_campaign_client(campaign_address).await?;
+
+        let result = claimdrop_client
+            .add_allocations(allocations)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "add_allocations",
+            "campaign_address": campaign_address,
+            "result": result,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Claim from a merkle-root claimdrop campaign
+    ///
+    /// Accepts either a precomputed `proof` or the full `allocations` list, in which
+    /// case the tree is rebuilt locally and the proof for the active wallet is derived.
+    pub async fn claimdrop_claim_with_proof(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Claiming with merkle proof from ClaimDrop campaign with args: {:?}",
+            args
+        );
+
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+
+        let receiver = args
+            .get("receiver")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let wallet_address = wallet.address().map_err(McpServerError::Sdk)?.to_string();
+
+        let (amount, proof, root) = if let Some(allocations) =
+            args.get("allocations").and_then(|v| v.as_array())
+        {
+            let allocations = Self::parse_claimdrop_allocations(allocations)?;
+            let tree = MerkleTree::from_allocations(&allocations).map_err(McpServerError::Sdk)?;
+            let merkle_proof = tree.proof(&wallet_address).ok_or_else(|| {
+                McpServerError::Validation(format!(
+                    "Active wallet {} has no allocation in the provided list",
+                    wallet_address
+                ))
+            })?;
+            (
+                merkle_proof.amount,
+                merkle_proof.proof,
+                Some(merkle_proof.root),
+            )
+        } else {
+            let amount_str = args.get("amount").and_then(|v| v.as_str()).ok_or_else(|| {
+                McpServerError::InvalidArguments(
+                    "amount is required when allocations are not provided".to_string(),
+                )
+            })?;
+            let amount = Uint128::from_str(amount_str)
+                .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)))?;
+            let proof = args
+                .get("proof")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| {
+                    McpServerError::InvalidArguments(
+                        "proof is required when allocations are not provided".to_string(),
+                    )
+                })?
+                .iter()
+                .map(|p| {
+                    p.as_str().map(|s| s.to_string()).ok_or_else(|| {
+                        McpServerError::InvalidArguments(
+                            "proof entries must be hex strings".to_string(),
+                        )
+                    })
+                })
+                .collect::<McpResult<Vec<String>>>()?;
+            (amount, proof, None)
+        };
+
+        // Reject proofs that don't resolve to the expected root before paying gas
+        if let Some(expected_root) = args.get("merkle_root").and_then(|v| v.as_str()) {
+            if !MerkleTree::verify(expected_root, &wallet_address, amount, &proof) {
+                return Err(McpServerError::Validation(
+                    "Merkle proof does not match the provided merkle_root".to_string(),
+                ));
+            }
+        }
+
+        let claimdrop_client = self.signed_campaign_client(campaign_address).await?;
+        let result = claimdrop_client
+            .claim_with_proof(amount, proof.clone(), receiver)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        ensure_executed("Claim with proof", &result)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "claim_with_proof",
+            "campaign_address": campaign_address,
+            "amount": amount.to_string(),
+            "proof": proof,
+            "merkle_root": root,
+            "result": result,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Claim from several claimdrop campaigns in one transaction
+    ///
+    /// With `dry_run` set, only the claim plan is returned and nothing is broadcast.
+    pub async fn claimdrop_claim_all(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Claiming from all ClaimDrop campaigns with args: {:?}",
+            args
+        );
+
+        let campaign_addresses: Vec<String> = args
+            .get("campaign_addresses")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_addresses is required".to_string())
+            })?
+            .iter()
+            .map(|v| {
+                v.as_str().map(|s| s.to_string()).ok_or_else(|| {
+                    McpServerError::InvalidArguments(
+                        "campaign_addresses entries must be strings".to_string(),
+                    )
+                })
+            })
+            .collect::<McpResult<_>>()?;
+
+        if campaign_addresses.is_empty() {
+            return Err(McpServerError::InvalidArguments(
+                "campaign_addresses must not be empty".to_string(),
+            ));
+        }
+
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let network_config = self.get_default_network_config().await?;
+        let wallet = self.get_active_wallet_with_validation().await?;
+        // The DEX client signs and broadcasts the batch
+        let dex_client = Arc::new(self.get_client_with_wallet(&network_config, wallet).await?);
+        let client = MantraClient::new(network_config.clone(), dex_client.shared_wallet())
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        // Any campaign works as the entry point; claim_all fans out to the others
+        let mut claimdrop_client = client.claimdrop_campaign(campaign_addresses[0].clone());
+        claimdrop_client.set_dex_client(dex_client);
+
+        if dry_run {
+            let plan = claimdrop_client
+                .plan_claim_all(&campaign_addresses)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            return Ok(serde_json::json!({
+                "status": "success",
+                "operation": "claim_all",
+                "dry_run": true,
+                "totals": plan.totals(),
+                "plan": plan,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        let (plan, result) = claimdrop_client
+            .claim_all(&campaign_addresses)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": claim_all_status(result.as_ref())?,
+            "operation": "claim_all",
+            "dry_run": false,
+            "totals": plan.totals(),
+            "plan": plan,
+            "result": result,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Close a campaign after checking it has ended and its claim grace window passed
+    ///
+    /// With `dry_run` set, only the closure readiness checks are returned.
+    pub async fn claimdrop_close_campaign(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Closing ClaimDrop campaign with args: {:?}",
+            args
+        );
+
+        let (campaign_address, grace_period_secs, dry_run) = Self::parse_closure_args(&args)?;
+        let claimdrop_client = self.signed_campaign_client(&campaign_address).await?;
+
+        let readiness = claimdrop_client
+            .closure_readiness(grace_period_secs)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        if dry_run || !readiness.is_ready() {
+            return Ok(serde_json::json!({
+                "status": if readiness.is_ready() { "ready" } else { "blocked" },
+                "operation": "close_campaign",
+                "dry_run": dry_run,
+                "campaign_address": campaign_address,
+                "readiness": readiness,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        let result = claimdrop_client
+            .close_campaign()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        ensure_executed("Closing the campaign", &result)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "close_campaign",
+            "dry_run": false,
+            "campaign_address": campaign_address,
+            "readiness": readiness,
+            "result": result,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Sweep non-reward tokens left in a closed campaign back to the owner
+    pub async fn claimdrop_withdraw_remaining_funds(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Withdrawing remaining ClaimDrop campaign funds with args: {:?}",
+            args
+        );
+
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+        let claimdrop_client = self.signed_campaign_client(campaign_address).await?;
+
+        let sweeps = claimdrop_client
+            .withdraw_remaining_funds()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        ensure_swept(&sweeps)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "withdraw_remaining_funds",
+            "campaign_address": campaign_address,
+            "sweeps": sweeps,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Close a campaign, sweep leftover tokens and report the recovered amounts
+    ///
+    /// With `dry_run` set, only the closure readiness checks are returned.
+    pub async fn claimdrop_end_of_life_campaign(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Running ClaimDrop end-of-life workflow with args: {:?}",
+            args
+        );
+
+        let (campaign_address, grace_period_secs, dry_run) = Self::parse_closure_args(&args)?;
+        let claimdrop_client = self.signed_campaign_client(&campaign_address).await?;
+
+        if dry_run {
+            let readiness = claimdrop_client
+                .closure_readiness(grace_period_secs)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            return Ok(serde_json::json!({
+                "status": if readiness.is_ready() { "ready" } else { "blocked" },
+                "operation": "end_of_life_campaign",
+                "dry_run": true,
+                "campaign_address": campaign_address,
+                "readiness": readiness,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        let report = claimdrop_client
+            .end_of_life_campaign(grace_period_secs)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": end_of_life_status(&report)?,
+            "operation": "end_of_life_campaign",
+            "dry_run": false,
+            "campaign_address": campaign_address,
+            "recovered": report.recovered,
+            "report": report,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Validate a campaign with its allocations and deploy it in one go
+    ///
+    /// Allocations come from either an `allocations` array or `allocations_csv`
+    /// text. With `dry_run` set, only the validation report and upload plan are
+    /// returned.
+    pub async fn claimdrop_build_campaign(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Building ClaimDrop campaign with args: {:?}",
+            args
+        );
+
+        let factory_address = args
+            .get("factory_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("factory_address is required".to_string())
+            })?;
+
+        let campaign_params = Self::parse_claimdrop_campaign_params(&args)?;
+
+        let allocations = match (
+            args.get("allocations").and_then(|v| v.as_array()),
+            args.get("allocations_csv").and_then(|v| v.as_str()),
+        ) {
+            (Some(array), None) => Self::parse_claimdrop_allocations(array)?,
+            (None, Some(csv)) => parse_allocations_csv(csv)
+                .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?,
+            (None, None) => Vec::new(),
+            (Some(_), Some(_)) => {
+                return Err(McpServerError::InvalidArguments(
+                    "Provide either allocations or allocations_csv, not both".to_string(),
+                ))
+            }
+        };
+
+        let chunk_size = args
+            .get("chunk_size")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .unwrap_or(DEFAULT_ALLOCATION_CHUNK_SIZE);
+
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let builder = CampaignBuilder::new(campaign_params)
+            .allocations(allocations)
+            .chunk_size(chunk_size);
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        let validation = builder.validate(now);
+        let chunks: Vec<usize> = builder.allocation_chunks().iter().map(Vec::len).collect();
+
+        if dry_run || !validation.is_valid() {
+            return Ok(serde_json::json!({
+                "status": if validation.is_valid() { "valid" } else { "invalid" },
+                "operation": "build_campaign",
+                "dry_run": dry_run,
+                "factory_address": factory_address,
+                "validation": validation,
+                "allocation_chunks": chunks,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        let factory_client = self.signed_factory_client(factory_address).await?;
+
+        let mut progress = Vec::new();
+        let deployment = builder
+            .deploy(&factory_client, now, |update| {
+                info!(
+                    "ClaimDrop allocation upload: chunk {}/{} ({}/{} allocations)",
+                    update.chunk, update.total_chunks, update.uploaded, update.total
+                );
+                report_partial_result(
+                    update.chunk as u64,
+                    Some(update.total_chunks as u64),
+                    &format!("Uploaded {}/{} allocations", update.uploaded, update.total),
+                    serde_json::to_value(update).unwrap_or_default(),
+                );
+                progress.push(update.clone());
+            })
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let complete = deployment
+            .upload
+            .as_ref()
+            .map(|upload| upload.is_complete())
+            .unwrap_or(deployment.creation.success);
+
+        Ok(serde_json::json!({
+            "status": if complete { "success" } else { "incomplete" },
+            "operation": "build_campaign",
+            "dry_run": false,
+            "factory_address": factory_address,
+            "validation": validation,
+            "deployment": deployment,
+            "progress": progress,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Parse arguments shared by the campaign closure tools
+    fn parse_closure_args(args: &Value) -> McpResult<(String, u64, bool)> {
+        let campaign_address = args
+            .get("campaign_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("campaign_address is required".to_string())
+            })?;
+        let grace_period_secs = args
+            .get("grace_period_secs")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_CLAIM_GRACE_PERIOD_SECS);
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        Ok((campaign_address.to_string(), grace_period_secs, dry_run))
+    }
+
+    /// MantraClient and DEX client signing with the active wallet
+    ///
+    /// ClaimDrop executions are signed and broadcast by the DEX client, at the
+    /// network's gas price.
+    async fn claimdrop_signer(&self) -> McpResult<(MantraClient, Arc<MantraDexClient>)> {
+        let network_config = self.get_default_network_config().await?;
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let dex_client = Arc::new(self.get_client_with_wallet(&network_config, wallet).await?);
+        let client = MantraClient::new(network_config, dex_client.shared_wallet())
+            .await
+            .map_err(McpServerError::Sdk)?;
+        Ok((client, dex_client))
+    }
+
+    /// Campaign client signing through a DEX client with the active wallet
+    async fn signed_campaign_client(&self, campaign_address: &str) -> McpResult<ClaimdropClient> {
+        let (client, dex_client) = self.claimdrop_signer().await?;
+        let mut claimdrop_client = client.claimdrop_campaign(campaign_address.to_string());
+        claimdrop_client.set_dex_client(dex_client);
+        Ok(claimdrop_client)
+    }
+
+    /// Factory client signing through a DEX client with the active wallet
+    async fn signed_factory_client(
+        &self,
+        factory_address: &str,
+    ) -> McpResult<ClaimdropFactoryClient> {
+        let (client, dex_client) = self.claimdrop_signer().await?;
+        let mut factory_client = client.claimdrop_factory(factory_address.to_string());
+        factory_client.set_dex_client(dex_client);
+        Ok(factory_client)
+    }
+
+    /// Parse campaign parameters shared by campaign creation tools
+    pub(crate) fn parse_claimdrop_campaign_params(
+        args: &Value,
+    ) -> McpResult<mantra_claimdrop_std::msg::CampaignParams> {
         let name = args
             .get("name")
             .and_then(|v| v.as_str())
@@ -159,17 +1007,7 @@ impl McpSdkAdapter {
             }
         }
 
-        // Get network config and active wallet
-        let network_config = self.get_default_network_config().await?;
-        let wallet = self.get_active_wallet_with_validation().await?;
-
-        // Create MantraClient with wallet
-        let client = MantraClient::new(network_config.clone(), Some(Arc::new(wallet)))
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        // Create campaign parameters
-        let campaign_params = mantra_claimdrop_std::msg::CampaignParams {
+        Ok(mantra_claimdrop_std::msg::CampaignParams {
             name: name.to_string(),
             description: description.to_string(),
             ty: campaign_type.to_string(),
@@ -180,205 +1018,13 @@ impl McpSdkAdapter {
             distribution_type: parsed_distributions,
             start_time,
             end_time,
-        };
-
-        // Get factory client and create campaign
-        let factory_client = client.claimdrop_factory(factory_address.to_string());
-
-        // Use default fee for now
-        let fee = cosmrs::tx::Fee::from_amount_and_gas(
-            cosmrs::Coin {
-                denom: cosmrs::Denom::from_str("uom").unwrap(),
-                amount: 5000u64.into(),
-            },
-            200_000u64,
-        );
-
-        let result = factory_client
-            .create_campaign(campaign_params, fee)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        Ok(serde_json::json!({
-            "status": "success",
-            "operation": "create_campaign",
-            "factory_address": factory_address,
-            "result": result,
-            "timestamp": chrono::Utc::now().to_rfc3339()
-        }))
-    }
-
-    /// Claim rewards from a claimdrop campaign
-    pub async fn claimdrop_claim(&self, args: Value) -> McpResult<Value> {
-        debug!(
-            "SDK Adapter: Claiming from ClaimDrop campaign with args: {:?}",
-            args
-        );
-
-        // Parse required parameters
-        let campaign_address = args
-            .get("campaign_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("campaign_address is required".to_string())
-            })?;
-
-        // Parse optional parameters
-        let amount = args
-            .get("amount")
-            .and_then(|v| v.as_str())
-            .and_then(|s| Uint128::from_str(s).ok());
-
-        let receiver = args
-            .get("receiver")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        // Get network config and active wallet
-        let network_config = self.get_default_network_config().await?;
-        let wallet = self.get_active_wallet_with_validation().await?;
-
-        // Create MantraClient with wallet
-        let client = MantraClient::new(network_config.clone(), Some(Arc::new(wallet)))
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        // Get ClaimDrop client for the campaign
-        let claimdrop_client = client.claimdrop_campaign(campaign_address.to_string());
-
-        // Use default fee
-        let fee = cosmrs::tx::Fee::from_amount_and_gas(
-            cosmrs::Coin {
-                denom: cosmrs::Denom::from_str("uom").unwrap(),
-                amount: 5000u64.into(),
-            },
-            150_000u64,
-        );
-
-        let result = claimdrop_client
-            .claim(amount, receiver, fee)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        Ok(serde_json::json!({
-            "status": "success",
-            "operation": "claim",
-            "campaign_address": campaign_address,
-            "result": result,
-            "timestamp": chrono::Utc::now().to_rfc3339()
-        }))
-    }
-
-    /// Query user rewards from a claimdrop campaign
-    pub async fn claimdrop_query_rewards(&self, args: Value) -> McpResult<Value> {
-        debug!(
-            "SDK Adapter: Querying ClaimDrop rewards with args: {:?}",
-            args
-        );
-
-        // Parse required parameters
-        let campaign_address = args
-            .get("campaign_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("campaign_address is required".to_string())
-            })?;
-
-        let receiver_address = args
-            .get("receiver")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| McpServerError::InvalidArguments("receiver is required".to_string()))?;
-
-        // Get network config and create MantraClient
-        let network_config = self.get_default_network_config().await?;
-        let client = MantraClient::new(network_config.clone(), None)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        // Get ClaimDrop client for the campaign
-        let claimdrop_client = client.claimdrop_campaign(campaign_address.to_string());
-
-        let rewards = claimdrop_client
-            .query_rewards(receiver_address)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        Ok(serde_json::json!({
-            "status": "success",
-            "operation": "query_rewards",
-            "campaign_address": campaign_address,
-            "receiver": receiver_address,
-            "rewards": rewards,
-            "timestamp": chrono::Utc::now().to_rfc3339()
-        }))
-    }
-
-    /// Query campaigns from factory
-    pub async fn claimdrop_query_campaigns(&self, args: Value) -> McpResult<Value> {
-        debug!(
-            "SDK Adapter: Querying ClaimDrop campaigns with args: {:?}",
-            args
-        );
-
-        // Parse required parameters
-        let factory_address = args
-            .get("factory_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("factory_address is required".to_string())
-            })?;
-
-        // Parse optional parameters
-        let start_after = args.get("start_after").and_then(|v| v.as_str());
-
-        let limit = args.get("limit").and_then(|v| v.as_u64()).map(|l| l as u16);
-
-        // Get network config and create MantraClient
-        let network_config = self.get_default_network_config().await?;
-        let client = MantraClient::new(network_config.clone(), None)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        // Get factory client
-        let factory_client = client.claimdrop_factory(factory_address.to_string());
-
-        let campaigns = factory_client
-            .query_campaigns(start_after, limit)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        Ok(serde_json::json!({
-            "status": "success",
-            "operation": "query_campaigns",
-            "factory_address": factory_address,
-            "campaigns": campaigns,
-            "timestamp": chrono::Utc::now().to_rfc3339()
-        }))
+        })
     }
 
-    /// Add allocations to a claimdrop campaign
-    pub async fn claimdrop_add_allocations(&self, args: Value) -> McpResult<Value> {
-        debug!(
-            "SDK Adapter: Adding ClaimDrop allocations with args: {:?}",
-            args
-        );
-
-        // Parse required parameters
-        let campaign_address = args
-            .get("campaign_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("campaign_address is required".to_string())
-            })?;
-
-        let allocations_array = args
-            .get("allocations")
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("allocations is required".to_string())
-            })?;
-
-        // Parse allocations
+    /// Parse an `allocations` JSON array of `{user, allocated_amount}` objects
+    pub(crate) fn parse_claimdrop_allocations(
+        allocations_array: &[Value],
+    ) -> McpResult<Vec<crate::protocols::claimdrop::types::Allocation>> {
         let mut allocations = Vec::new();
         for allocation in allocations_array {
             let user = allocation
@@ -405,41 +1051,134 @@ impl McpSdkAdapter {
                 allocated_amount: amount,
             });
         }
+        Ok(allocations)
+    }
 
-        // Get network config and active wallet
-        let network_config = self.get_default_network_config().await?;
-        let wallet = self.get_active_wallet_with_validation().await?;
+    // =============================================================================
+}
 
-        // Create MantraClient with wallet
-        let client = MantraClient::new(network_config.clone(), Some(Arc::new(wallet)))
-            .await
-            .map_err(McpServerError::Sdk)?;
+/// Status of a `claimdrop_claim_all` call, failing when its transaction failed
+fn claim_all_status(result: Option<&ClaimdropOperationResult>) -> McpResult<&'static str> {
+    match result {
+        None => Ok("nothing_to_claim"),
+        Some(result) => ensure_executed("Batch claim", result).map(|()| "success"),
+    }
+}
 
-        // Get ClaimDrop client for the campaign
-        let claimdrop_client = client.claimdrop_campaign(campaign_address.to_string());
+/// Status of a `claimdrop_end_of_life_campaign` call, failing when its close
+/// or sweep transactions failed
+fn end_of_life_status(report: &EndOfLifeReport) -> McpResult<&'static str> {
+    let Some(close) = &report.close else {
+        return Ok("blocked");
+    };
+    ensure_executed("Closing the campaign", close)?;
+    ensure_swept(&report.sweeps).map(|()| "success")
+}
 
-        // Use default fee
-        let fee = cosmrs::tx::Fee::from_amount_and_gas(
-            cosmrs::Coin {
-                denom: cosmrs::Denom::from_str("uom").unwrap(),
-                amount: 5000u64.into(),
-            },
-            200_000u64,
-        );
+/// Fail on the first sweep whose transaction failed
+fn ensure_swept(sweeps: &[SweepOutcome]) -> McpResult<()> {
+    sweeps
+        .iter()
+        .try_for_each(|sweep| ensure_executed(&format!("Sweeping {}", sweep.denom), &sweep.result))
+}
 
-        let result = claimdrop_client
-            .add_allocations(allocations, fee)
-            .await
-            .map_err(McpServerError::Sdk)?;
+/// Fail with the transaction's message unless `result` succeeded
+fn ensure_executed(operation: &str, result: &ClaimdropOperationResult) -> McpResult<()> {
+    if result.success {
+        return Ok(());
+    }
+    Err(McpServerError::Sdk(crate::error::Error::Tx(format!(
+        "{} failed{}: {}",
+        operation,
+        result
+            .tx_hash
+            .as_deref()
+            .map(|hash| format!(" in {}", hash))
+            .unwrap_or_default(),
+        result.message
+    ))))
+}
 
-        Ok(serde_json::json!({
-            "status": "success",
-            "operation": "add_allocations",
-            "campaign_address": campaign_address,
-            "result": result,
-            "timestamp": chrono::Utc::now().to_rfc3339()
-        }))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::claimdrop::closure::ClosureReadiness;
+
+    fn result(success: bool) -> ClaimdropOperationResult {
+        ClaimdropOperationResult {
+            success,
+            tx_hash: Some("ABC".to_string()),
+            message: "Transaction failed with code 5: insufficient funds".to_string(),
+            campaign_address: None,
+            data: None,
+        }
     }
 
-    // =============================================================================
+    #[test]
+    fn test_claim_all_status_reports_failed_transactions_as_errors() {
+        assert_eq!(claim_all_status(None).unwrap(), "nothing_to_claim");
+        assert_eq!(claim_all_status(Some(&result(true))).unwrap(), "success");
+
+        let error = claim_all_status(Some(&result(false))).unwrap_err();
+        assert!(
+            error.to_string().contains("insufficient funds"),
+            "{}",
+            error
+        );
+    }
+    #[test]
+    fn test_ensure_executed_names_the_operation_and_transaction() {
+        assert!(ensure_executed("Claim with proof", &result(true)).is_ok());
+
+        let error = ensure_executed("Claim with proof", &result(false)).unwrap_err();
+        let message = error.to_string();
+        assert!(
+            message.contains("Claim with proof failed in ABC"),
+            "{}",
+            message
+        );
+        assert!(message.contains("insufficient funds"), "{}", message);
+    }
+    #[test]
+    fn test_end_of_life_status_fails_unless_every_transaction_succeeded() {
+        let report = |close: Option<bool>, swept: &[bool]| EndOfLifeReport {
+            readiness: ClosureReadiness {
+                campaign_address: "mantra1campaign".to_string(),
+                checked_at: 2_000,
+                end_time: 1_000,
+                claim_window_ends_at: 1_500,
+                closed_at: None,
+                authorized: true,
+                reward_denom: "uom".to_string(),
+                unclaimed: Uint128::zero(),
+                balances: vec![],
+                blockers: vec![],
+            },
+            close: close.map(result),
+            sweeps: swept
+                .iter()
+                .map(|&success| SweepOutcome {
+                    denom: "uusdc".to_string(),
+                    amount: Uint128::new(5),
+                    result: result(success),
+                })
+                .collect(),
+            balances_after: vec![],
+            recovered: vec![],
+        };
+
+        assert_eq!(end_of_life_status(&report(None, &[])).unwrap(), "blocked");
+        assert_eq!(
+            end_of_life_status(&report(Some(true), &[true])).unwrap(),
+            "success"
+        );
+        let error = end_of_life_status(&report(Some(false), &[])).unwrap_err();
+        assert!(
+            error.to_string().contains("Closing the campaign"),
+            "{}",
+            error
+        );
+        let error = end_of_life_status(&report(Some(true), &[true, false])).unwrap_err();
+        assert!(error.to_string().contains("Sweeping uusdc"), "{}", error);
+    }
 }
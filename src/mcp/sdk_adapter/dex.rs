@@ -1,8 +1,104 @@
 //! DEX protocol methods
 
 use super::*;
+use crate::amount::{Amount, DenomRegistry};
+use crate::denom_metadata::DenomMetadata;
+use crate::fees::CostOperation;
+use crate::protocols::dex::dust::DustReport;
 
 impl McpSdkAdapter {
+    /// Base units of a tool's amount argument for `denom`
+    ///
+    /// A plain integer is taken as base units, as tools always have. Anything
+    /// else is a human-readable amount of `denom`: `1.5`, `1.5 OM` or
+    /// `100 USDC`, with symbols and decimals resolved from the pools.
+    pub async fn parse_amount_arg(&self, input: &str, denom: &str) -> McpResult<Uint128> {
+        let input = input.trim();
+        if !input.is_empty() && input.bytes().all(|b| b.is_ascii_digit()) {
+            return Uint128::from_str(input)
+                .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)));
+        }
+
+        let registry = self.denom_registry().await?;
+        Amount::parse_as(input, denom, &registry)
+            .map(|amount| amount.amount)
+            .map_err(|e| {
+                McpServerError::InvalidArguments(format!("Invalid amount {:?}: {}", input, e))
+            })
+    }
+
+    /// Replace human-readable amounts in tool `arguments` with base units
+    ///
+    /// Covers the `{denom, amount}` objects and flat denom and amount pairs
+    /// spend limits count, so limits and budgets always see base units.
+    /// Returns how many amounts were rewritten.
+    pub async fn normalize_amount_arguments(&self, arguments: &mut Value) -> McpResult<usize> {
+        let mut amounts = Vec::new();
+        collect_readable_amounts(arguments, String::new(), &mut amounts);
+        for (pointer, denom, input) in &amounts {
+            let amount = self.parse_amount_arg(input, denom).await?;
+            if let Some(value) = arguments.pointer_mut(pointer) {
+                *value = Value::String(amount.to_string());
+            }
+        }
+        Ok(amounts.len())
+    }
+
+    /// Denominations of the default network's pool assets, cached
+    pub async fn denom_registry(&self) -> McpResult<DenomRegistry> {
+        let network_config = self.get_default_network_config().await?;
+        let cache_key = format!("denom_registry:{}", network_config.chain_id);
+        if let Some(registry) = self
+            .cache_get(&cache_key)
+            .await
+            .and_then(|cached| serde_json::from_value(cached).ok())
+        {
+            return Ok(registry);
+        }
+
+        let client = self.get_client(&network_config).await?;
+        let registry = client.denom_registry().await.map_err(McpServerError::Sdk)?;
+        self.cache_set(cache_key, serde_json::to_value(&registry)?)
+            .await;
+        Ok(registry)
+    }
+
+    /// Symbols and decimals of `denoms` on the network of `network_config`
+    ///
+    /// The chain's Skip asset list is loaded into the cache the first time,
+    /// so registry symbols are known before the chain is queried.
+    pub async fn resolve_denom_metadata(
+        &self,
+        network_config: &MantraNetworkConfig,
+        denoms: &[String],
+    ) -> McpResult<Vec<DenomMetadata>> {
+        let client = self.get_client(network_config).await?;
+        let service = client.denom_metadata_service();
+        let chain_id = network_config.chain_id.as_str();
+        if !service.has_registry(chain_id) {
+            let assets = match MantraClient::new(network_config.clone(), None).await {
+                Ok(mantra) => match mantra.skip().await {
+                    Ok(mut skip_client) => {
+                        self.configure_skip_client(&mut skip_client);
+                        self.cached_skip_chain_assets(&skip_client, chain_id)
+                            .await
+                            .map(|(assets, _)| assets)
+                    }
+                    Err(e) => Err(McpServerError::Sdk(e)),
+                },
+                Err(e) => Err(McpServerError::Sdk(e)),
+            };
+            // A registry that cannot be read is not retried on every call;
+            // the chain's own metadata still applies
+            let assets = assets.unwrap_or_else(|e| {
+                warn!("Asset registry of {} unavailable: {}", chain_id, e);
+                Vec::new()
+            });
+            service.add_registry_assets(chain_id, &assets);
+        }
+        Ok(client.resolve_denom_metadata(denoms).await)
+    }
+
     pub async fn get_first_available_pool_id(&self) -> McpResult<String> {
         // Get network config and client
         let network_config = self.get_default_network_config().await?;
@@ -40,8 +136,7 @@ impl McpSdkAdapter {
         );
 
         // Parse amount
-        let offer_amount = Uint128::from_str(&amount)
-            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)))?;
+        let offer_amount = self.parse_amount_arg(&amount, &from_asset).await?;
 
         // Parse slippage with explicit error handling and validation
         let max_slippage = match Decimal::from_str(&slippage) {
@@ -456,31 +551,7 @@ impl McpSdkAdapter {
         let pools_result = client.get_pools(limit).await.map_err(McpServerError::Sdk)?;
 
         // Convert pools to JSON format
-        let pools_json: Vec<Value> = pools_result
-            .into_iter()
-            .map(|pool| {
-                serde_json::json!({
-                    "pool_id": pool.pool_info.pool_identifier,
-                    "pool_type": match pool.pool_info.pool_type {
-                        mantra_dex_std::pool_manager::PoolType::ConstantProduct => "constant_product",
-                        mantra_dex_std::pool_manager::PoolType::StableSwap { .. } => "stable_swap",
-                    },
-                    "assets": pool.pool_info.assets.iter().map(|asset| {
-                        serde_json::json!({
-                            "denom": asset.denom,
-                            "amount": asset.amount.to_string()
-                        })
-                    }).collect::<Vec<_>>(),
-                    "lp_denom": pool.pool_info.lp_denom,
-                    "status": {
-                        "swaps_enabled": pool.pool_info.status.swaps_enabled,
-                        "deposits_enabled": pool.pool_info.status.deposits_enabled,
-                        "withdrawals_enabled": pool.pool_info.status.withdrawals_enabled
-                    },
-                    "total_share": pool.total_share.to_string()
-                })
-            })
-            .collect();
+        let pools_json: Vec<Value> = pools_result.iter().map(pool_json).collect();
 
         info!("Successfully retrieved {} pools", pools_json.len());
 
@@ -700,9 +771,7 @@ impl McpSdkAdapter {
                     McpServerError::InvalidArguments("asset.amount is required".to_string())
                 })?;
 
-            let amount = Uint128::from_str(amount_str).map_err(|e| {
-                McpServerError::InvalidArguments(format!("Invalid asset amount: {}", e))
-            })?;
+            let amount = self.parse_amount_arg(amount_str, denom).await?;
 
             assets.push(Coin {
                 denom: denom.to_string(),
@@ -845,6 +914,213 @@ impl McpSdkAdapter {
         }))
     }
 
+    /// Preview withdrawing LP tokens: assets returned, fees and the move of
+    /// the pool's spot price
+    pub async fn simulate_withdraw_liquidity(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Simulating liquidity withdrawal with args: {:?}",
+            args
+        );
+
+        let pool_id = args
+            .get("pool_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("pool_id is required".to_string()))?;
+
+        let amount_str = args
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("amount is required".to_string()))?;
+
+        let lp_amount = Uint128::from_str(amount_str)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid LP amount: {}", e)))?;
+
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        let preview = client
+            .simulate_withdraw_liquidity(pool_id, lp_amount)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "simulate_withdraw_liquidity",
+            "preview": preview,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Provide liquidity from a single asset by swapping part of it into the
+    /// other pool asset in the same transaction
+    ///
+    /// With `dry_run` set, only the simulated preview is returned.
+    pub async fn zap_in(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Zapping into pool with args: {:?}", args);
+
+        let pool_id = args
+            .get("pool_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("pool_id is required".to_string()))?;
+
+        let denom = args
+            .get("asset_denom")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("asset_denom is required".to_string())
+            })?;
+
+        let amount_str = args
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("amount is required".to_string()))?;
+
+        let amount = self.parse_amount_arg(amount_str, denom).await?;
+
+        let max_slippage = parse_optional_slippage(&args, "max_slippage")?;
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let offer = Coin {
+            denom: denom.to_string(),
+            amount,
+        };
+        let network_config = self.get_default_network_config().await?;
+
+        if dry_run {
+            let client = self.get_client(&network_config).await?;
+            let preview = client
+                .simulate_zap_in(pool_id, offer, max_slippage)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            return Ok(serde_json::json!({
+                "status": "success",
+                "operation": "zap_in",
+                "dry_run": true,
+                "preview": preview,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let client = self.get_client_with_wallet(&network_config, wallet).await?;
+        let (preview, result) = client
+            .zap_in(pool_id, offer, max_slippage)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        info!(
+            "Successfully zapped into pool {} with tx hash: {}",
+            pool_id, result.txhash
+        );
+
+        let touched: Vec<String> = preview
+            .liquidity_assets
+            .iter()
+            .map(|coin| coin.denom.clone())
+            .collect();
+        let dust = self.settle_dust_after(&client, &touched).await;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "zap_in",
+            "dry_run": false,
+            "transaction_hash": result.txhash,
+            "preview": preview,
+            "dust": dust,
+            "gas_used": result.gas_used,
+            "gas_wanted": result.gas_wanted,
+            "block_height": result.height,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "events": result.events
+        }))
+    }
+
+    /// Withdraw liquidity and swap the withdrawn assets into a single asset in
+    /// the same transaction
+    ///
+    /// With `dry_run` set, only the simulated preview is returned.
+    pub async fn zap_out(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Zapping out of pool with args: {:?}", args);
+
+        let pool_id = args
+            .get("pool_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("pool_id is required".to_string()))?;
+
+        let amount_str = args
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("amount is required".to_string()))?;
+
+        let lp_amount = Uint128::from_str(amount_str)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid LP amount: {}", e)))?;
+
+        let target_denom = args
+            .get("target_denom")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("target_denom is required".to_string())
+            })?;
+
+        let max_slippage = parse_optional_slippage(&args, "max_slippage")?;
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let network_config = self.get_default_network_config().await?;
+
+        if dry_run {
+            let client = self.get_client(&network_config).await?;
+            let preview = client
+                .simulate_zap_out(pool_id, lp_amount, target_denom, max_slippage)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            return Ok(serde_json::json!({
+                "status": "success",
+                "operation": "zap_out",
+                "dry_run": true,
+                "preview": preview,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let client = self.get_client_with_wallet(&network_config, wallet).await?;
+        let (preview, result) = client
+            .zap_out(pool_id, lp_amount, target_denom, max_slippage)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        info!(
+            "Successfully zapped out of pool {} with tx hash: {}",
+            pool_id, result.txhash
+        );
+
+        let touched: Vec<String> = preview
+            .withdrawn
+            .iter()
+            .map(|coin| coin.denom.clone())
+            .collect();
+        let dust = self.settle_dust_after(&client, &touched).await;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "zap_out",
+            "dry_run": false,
+            "transaction_hash": result.txhash,
+            "preview": preview,
+            "dust": dust,
+            "gas_used": result.gas_used,
+            "gas_wanted": result.gas_wanted,
+            "block_height": result.height,
+            "timestamp": chrono::Utc::now().to_rfc3339(),
+            "events": result.events
+        }))
+    }
+
     pub async fn get_liquidity_positions(&self, args: Value) -> McpResult<Value> {
         debug!(
             "SDK Adapter: Getting liquidity positions with args: {:?}",
@@ -961,9 +1237,7 @@ impl McpSdkAdapter {
                 McpServerError::InvalidArguments("offer_asset.amount is required".to_string())
             })?;
 
-        let offer_amount = Uint128::from_str(offer_amount_str).map_err(|e| {
-            McpServerError::InvalidArguments(format!("Invalid offer amount: {}", e))
-        })?;
+        let offer_amount = self.parse_amount_arg(offer_amount_str, offer_denom).await?;
 
         let offer_coin = Coin {
             denom: offer_denom.to_string(),
@@ -996,6 +1270,23 @@ impl McpSdkAdapter {
         let network_config = self.get_default_network_config().await?;
         let client = self.get_client_with_wallet(&network_config, wallet).await?;
 
+        // Flag swaps that move the price a lot or drain an imbalanced stable pool
+        let impact_check = match client
+            .check_swap_impact(pool_id, &offer_coin, ask_asset_denom, None)
+            .await
+        {
+            Ok(check) => {
+                for warning in &check.warnings {
+                    warn!("{}", warning);
+                }
+                Some(check)
+            }
+            Err(e) => {
+                warn!("Could not estimate price impact in pool {}: {}", pool_id, e);
+                None
+            }
+        };
+
         // Execute the swap directly (without retry for now due to client not being Clone)
         let swap_result = client
             .swap(pool_id, offer_coin, ask_asset_denom, max_slippage)
@@ -1007,6 +1298,13 @@ impl McpSdkAdapter {
             pool_id, swap_result.txhash
         );
 
+        let dust = self
+            .settle_dust_after(
+                &client,
+                &[offer_denom.to_string(), ask_asset_denom.to_string()],
+            )
+            .await;
+
         // Format the response
         Ok(serde_json::json!({
             "status": "success",
@@ -1023,12 +1321,191 @@ impl McpSdkAdapter {
                 "gas_used": swap_result.gas_used,
                 "gas_wanted": swap_result.gas_wanted
             },
+            "price_impact": impact_check,
+            "dust": dust,
             "block_height": swap_result.height,
             "timestamp": chrono::Utc::now().to_rfc3339(),
             "events": swap_result.events
         }))
     }
 
+    /// Apply the dust policy after a completed operation
+    ///
+    /// The operation already went through, so a failure here is logged rather
+    /// than returned.
+    async fn settle_dust_after(
+        &self,
+        client: &MantraDexClient,
+        denoms: &[String],
+    ) -> Option<DustReport> {
+        match client.settle_dust(denoms).await {
+            Ok(report) => {
+                if !report.dust.is_empty() {
+                    info!(
+                        "Dust left after operation ({:?}): {:?}",
+                        report.action, report.dust
+                    );
+                }
+                Some(report)
+            }
+            Err(e) => {
+                warn!("Failed to apply dust policy: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Swap residual balances below the dust threshold into the native token
+    ///
+    /// With `dry_run` set, only the planned conversions are returned.
+    pub async fn sweep_dust(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Sweeping dust with args: {:?}", args);
+
+        let denoms = args
+            .get("denoms")
+            .and_then(|v| v.as_array())
+            .map(|denoms| {
+                denoms
+                    .iter()
+                    .map(|denom| {
+                        denom.as_str().map(str::to_string).ok_or_else(|| {
+                            McpServerError::InvalidArguments(format!(
+                                "Invalid denom {}: expected a string",
+                                denom
+                            ))
+                        })
+                    })
+                    .collect::<McpResult<Vec<String>>>()
+            })
+            .transpose()?;
+
+        let mut policy = self.dust_policy.clone();
+        if let Some(threshold_str) = args.get("threshold").and_then(|v| v.as_str()) {
+            policy.default_threshold = Uint128::from_str(threshold_str).map_err(|e| {
+                McpServerError::InvalidArguments(format!("Invalid threshold: {}", e))
+            })?;
+        }
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let network_config = self.get_default_network_config().await?;
+        let client = self
+            .get_client_with_wallet(&network_config, wallet)
+            .await?
+            .with_dust_policy(policy);
+
+        let report = client
+            .sweep_dust(denoms.as_deref(), dry_run)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        if let Some(tx_hash) = &report.tx_hash {
+            info!(
+                "Swept {} dust balances into {} with tx hash: {}",
+                report.converted.len(),
+                report.native_denom,
+                tx_hash
+            );
+        }
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "sweep_dust",
+            "dry_run": dry_run,
+            "sweep": report,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Report pool depth at several price impact levels and stable pool balance
+    pub async fn get_pool_depth(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Getting pool depth with args: {:?}", args);
+
+        let pool_id = args
+            .get("pool_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("pool_id is required".to_string()))?;
+
+        let impact_levels = args
+            .get("impact_levels")
+            .and_then(|v| v.as_array())
+            .map(|levels| {
+                levels
+                    .iter()
+                    .map(|level| {
+                        level
+                            .as_str()
+                            .and_then(|s| Decimal::from_str(s).ok())
+                            .filter(|d| !d.is_zero() && *d < Decimal::one())
+                            .ok_or_else(|| {
+                                McpServerError::InvalidArguments(format!(
+                                    "Invalid impact level {}: expected a decimal between 0 and 1",
+                                    level
+                                ))
+                            })
+                    })
+                    .collect::<McpResult<Vec<Decimal>>>()
+            })
+            .transpose()?;
+
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        let report = client
+            .get_pool_depth(pool_id, impact_levels)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "get_pool_depth",
+            "depth": report,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Gas, network fee and dollar cost of a described operation
+    ///
+    /// Cosmos operations are simulated with `wallet_address` or the active
+    /// wallet when there is one; EVM gas is estimated from its EVM address.
+    pub async fn estimate_transaction_cost(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Estimating transaction cost with args: {:?}",
+            args
+        );
+
+        let operation: CostOperation = serde_json::from_value(args.clone())
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid operation: {}", e)))?;
+
+        let wallet = match args.get("wallet_address").and_then(|v| v.as_str()) {
+            Some(address) => Some(self.get_wallet_by_address(address).await?.ok_or_else(|| {
+                McpServerError::InvalidArguments(format!(
+                    "Wallet with address {} not found",
+                    address
+                ))
+            })?),
+            None => self.get_active_wallet().await?,
+        };
+        let network_config = self.get_default_network_config().await?;
+        let client = match wallet {
+            Some(wallet) => self.get_client_with_wallet(&network_config, wallet).await?,
+            None => self.get_client(&network_config).await?,
+        };
+        let estimate = client
+            .estimate_transaction_cost(&operation)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "estimate_transaction_cost",
+            "estimate": estimate,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
     pub async fn get_lp_token_balance(&self, args: Value) -> McpResult<Value> {
         debug!(
             "SDK Adapter: Getting LP token balance with args: {:?}",
@@ -1510,3 +1987,79 @@ impl McpSdkAdapter {
         }))
     }
 }
+
+/// Parse an optional decimal slippage argument, rejecting malformed values
+fn parse_optional_slippage(args: &Value, key: &str) -> McpResult<Option<Decimal>> {
+    args.get(key)
+        .and_then(|v| v.as_str())
+        .map(|s| {
+            Decimal::from_str(s)
+                .map_err(|e| McpServerError::InvalidArguments(format!("Invalid {}: {}", key, e)))
+        })
+        .transpose()
+}
+
+/// JSON form of a pool in pool listings
+pub(crate) fn pool_json(pool: &mantra_dex_std::pool_manager::PoolInfoResponse) -> Value {
+    serde_json::json!({
+        "pool_id": pool.pool_info.pool_identifier,
+        "pool_type": match pool.pool_info.pool_type {
+            mantra_dex_std::pool_manager::PoolType::ConstantProduct => "constant_product",
+            mantra_dex_std::pool_manager::PoolType::StableSwap { .. } => "stable_swap",
+        },
+        "assets": pool.pool_info.assets.iter().map(|asset| {
+            serde_json::json!({
+                "denom": asset.denom,
+                "amount": asset.amount.to_string()
+            })
+        }).collect::<Vec<_>>(),
+        "lp_denom": pool.pool_info.lp_denom,
+        "status": {
+            "swaps_enabled": pool.pool_info.status.swaps_enabled,
+            "deposits_enabled": pool.pool_info.status.deposits_enabled,
+            "withdrawals_enabled": pool.pool_info.status.withdrawals_enabled
+        },
+        "total_share": pool.total_share.to_string()
+    })
+}
+
+/// JSON pointer, denom and text of each amount in `value` that is not a plain
+/// integer of base units
+fn collect_readable_amounts(
+    value: &Value,
+    pointer: String,
+    found: &mut Vec<(String, String, String)>,
+) {
+    let is_readable = |amount: &str| !amount.trim().bytes().all(|b| b.is_ascii_digit());
+    match value {
+        Value::Object(map) => {
+            let mut pairs = vec![("denom", "amount")];
+            pairs.extend(crate::mcp::authz::FLAT_SPEND_FIELDS);
+            for (denom_field, amount_field) in pairs {
+                if let (Some(Value::String(denom)), Some(Value::String(amount))) =
+                    (map.get(denom_field), map.get(amount_field))
+                {
+                    if is_readable(amount) {
+                        found.push((
+                            format!("{}/{}", pointer, amount_field),
+                            denom.clone(),
+                            amount.clone(),
+                        ));
+                    }
+                }
+            }
+            for (key, value) in map {
+                if key != "_meta" {
+                    let key = key.replace('~', "~0").replace('/', "~1");
+                    collect_readable_amounts(value, format!("{}/{}", pointer, key), found);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_readable_amounts(item, format!("{}/{}", pointer, i), found);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,204 @@
+//! Background refresh of ERC-20 registry metadata
+//!
+//! Registry entries expire after the registry TTL, but were only fetched
+//! again when a tool call happened to need them. The refresh task refetches
+//! stale entries ahead of use, the tokens tool calls used most recently
+//! first. It also compares the code of recently used tokens with the code
+//! their metadata was read from, so a token whose contract or EIP-1967
+//! implementation was swapped out is invalidated and fetched again instead
+//! of keeping the old symbol and decimals until the TTL runs out.
+
+use super::*;
+use crate::protocols::evm::proxy::{slot_address, EIP1967_IMPLEMENTATION_SLOT};
+use alloy_primitives::{keccak256, Bytes, B256};
+use std::sync::Weak;
+
+/// Tokens a pass reads from the chain at most; the others wait for the next
+pub const ERC20_REFRESH_BATCH: usize = 50;
+
+/// How long after a tool call a token's code is checked on every pass
+pub const RECENT_USE: Duration = Duration::from_secs(60 * 60);
+
+/// What one refresh pass did
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Erc20RefreshReport {
+    /// Tokens read from the chain
+    pub checked: usize,
+    /// Tokens whose metadata was fetched again
+    pub refreshed: usize,
+    /// Tokens whose code changed since their metadata was read
+    pub invalidated: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Fingerprint of the code at an address: its deployed bytecode and, for an
+/// EIP-1967 proxy, the implementation it delegates to
+pub(crate) fn code_fingerprint(code: &[u8], implementation_slot: U256) -> B256 {
+    let mut data = code.to_vec();
+    data.extend_from_slice(&implementation_slot.to_be_bytes::<32>());
+    keccak256(data)
+}
+
+/// Code at `address`, its [`code_fingerprint`] and the implementation it
+/// delegates to when it is an EIP-1967 proxy
+pub(crate) async fn read_code_fingerprint(
+    evm_client: &EvmClient,
+    address: Address,
+) -> Result<(Bytes, B256, Option<Address>), crate::error::Error> {
+    let code = evm_client
+        .get_code(crate::protocols::evm::types::EthAddress(address), None)
+        .await?;
+    let implementation_slot = if code.is_empty() {
+        U256::ZERO
+    } else {
+        evm_client
+            .get_storage_at(
+                crate::protocols::evm::types::EthAddress(address),
+                U256::from_be_bytes(EIP1967_IMPLEMENTATION_SLOT.0),
+                None,
+            )
+            .await?
+    };
+    let fingerprint = code_fingerprint(&code, implementation_slot);
+    Ok((code, fingerprint, slot_address(implementation_slot)))
+}
+
+impl McpSdkAdapter {
+    /// Refetch stale registry tokens of the configured EVM chain and
+    /// invalidate recently used ones whose code changed
+    pub async fn refresh_erc20_registry(&self) -> McpResult<Erc20RefreshReport> {
+        let mut report = Erc20RefreshReport::default();
+        let network_config = self.get_default_network_config().await?;
+        if network_config.evm_rpc_url.is_none() {
+            return Ok(report);
+        }
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+
+        let (ttl, tokens) = {
+            let registry_arc = self.erc20_registry();
+            let registry = registry_arc.read().await;
+            (registry.ttl(), registry.refresh_order(chain_id))
+        };
+
+        for token in tokens {
+            if report.checked >= ERC20_REFRESH_BATCH {
+                break;
+            }
+            let stale = token.needs_refresh(ttl);
+            let recently_used = token
+                .last_used
+                .is_some_and(|used| used.elapsed() < RECENT_USE);
+            let watched = recently_used && token.code_fingerprint.is_some();
+            if !stale && !watched {
+                continue;
+            }
+            report.checked += 1;
+
+            if !stale {
+                match read_code_fingerprint(&evm_client, token.address).await {
+                    Ok((_, fingerprint, _)) if Some(fingerprint) == token.code_fingerprint => {
+                        continue
+                    }
+                    Ok(_) => {
+                        warn!(
+                            "Code of ERC-20 token {} ({:#x}) changed, fetching its metadata again",
+                            token.symbol, token.address
+                        );
+                        self.erc20_registry()
+                            .write()
+                            .await
+                            .invalidate(chain_id, &token.address);
+                        report.invalidated.push(format!("{:#x}", token.address));
+                    }
+                    Err(e) => {
+                        report.errors.push(format!("{:#x}: {}", token.address, e));
+                        continue;
+                    }
+                }
+            }
+
+            let previous = stale.then_some(token.code_fingerprint).flatten();
+            let address = token.address;
+            match self
+                .fetch_token_metadata(&evm_client, chain_id, address, Some(token), false)
+                .await
+            {
+                Ok(info) => {
+                    report.refreshed += 1;
+                    if previous.is_some() && info.code_fingerprint != previous {
+                        report.invalidated.push(format!("{:#x}", address));
+                    }
+                }
+                Err(e) => report.errors.push(format!("{:#x}: {}", address, e)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Run [`Self::refresh_erc20_registry`] every `interval` in the background
+    ///
+    /// The task holds a weak reference, so it ends once the adapter is dropped.
+    /// Calling this again replaces the running task.
+    pub async fn start_erc20_refresh(self: &Arc<Self>, interval: Duration) {
+        let adapter: Weak<Self> = Arc::downgrade(self);
+
+        let handle = tokio::spawn(async move {
+            let mut interval_timer = tokio::time::interval(interval);
+            // The first tick completes at once; metadata was just loaded
+            interval_timer.tick().await;
+
+            loop {
+                interval_timer.tick().await;
+                let Some(adapter) = adapter.upgrade() else {
+                    break;
+                };
+
+                match adapter.refresh_erc20_registry().await {
+                    Ok(report) if report.errors.is_empty() => debug!(
+                        "Checked {} ERC-20 tokens, refreshed {}, invalidated {}",
+                        report.checked,
+                        report.refreshed,
+                        report.invalidated.len()
+                    ),
+                    Ok(report) => warn!(
+                        "ERC-20 registry refresh incomplete: {}",
+                        report.errors.join("; ")
+                    ),
+                    Err(e) => warn!("ERC-20 registry refresh failed: {}", e),
+                }
+            }
+        });
+
+        if let Some(previous) = self.erc20_refresh_handle.lock().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop the background registry refresh task
+    pub async fn stop_erc20_refresh(&self) {
+        if let Some(handle) = self.erc20_refresh_handle.lock().await.take() {
+            handle.abort();
+            debug!("Stopped ERC-20 registry refresh");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_follows_code_and_implementation() {
+        let code = [0x60, 0x80, 0x60, 0x40];
+        let plain = code_fingerprint(&code, U256::ZERO);
+        assert_eq!(plain, code_fingerprint(&code, U256::ZERO));
+        assert_ne!(plain, code_fingerprint(&[0x60, 0x80], U256::ZERO));
+
+        // An upgraded proxy keeps its code but points at another implementation
+        let before = code_fingerprint(&code, U256::from(0x1111));
+        let after = code_fingerprint(&code, U256::from(0x2222));
+        assert_ne!(before, after);
+        assert_ne!(before, plain);
+    }
+}
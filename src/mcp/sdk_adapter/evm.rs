@@ -2,6 +2,50 @@
 
 #[cfg(feature = "evm")]
 use super::*;
+#[cfg(feature = "evm")]
+use crate::amount::{format_units_evm as format_units, parse_units_evm as parse_units};
+#[cfg(feature = "evm")]
+use crate::mcp::response_format::MarkdownRender;
+#[cfg(feature = "evm")]
+use crate::protocols::evm::allowance::{
+    self, is_unlimited_approval, DEFAULT_APPROVAL_SCAN_BLOCKS, DEFAULT_APPROVAL_SCAN_CHUNK_BLOCKS,
+};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::contracts::primary_sale_eligibility::{status_name, InvestmentSnapshot};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::contracts::primary_sale_export::ExportOptions;
+#[cfg(feature = "evm")]
+use crate::protocols::evm::contracts::primary_sale_projection::InvestorOutcome;
+#[cfg(feature = "evm")]
+use crate::protocols::evm::interfaces::{
+    self, ContractInspection, ContractInterface, DetectedInterface,
+};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::proxy::{self, ProxyInfo, ProxyKind};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::transaction_decoder::{DecodedCall, TransactionDecoder};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::types::EthAddress;
+#[cfg(feature = "evm")]
+use crate::wallet::address::{require_address_kind, DEFAULT_ACCOUNT_PREFIX};
+#[cfg(feature = "evm")]
+use crate::wallet::restricted_wallets::plan_settle_batch;
+#[cfg(feature = "evm")]
+use crate::wallet::AddressKind;
+#[cfg(feature = "evm")]
+use alloy_primitives::B256;
+#[cfg(feature = "evm")]
+use alloy_rpc_types_eth::{BlockId, BlockNumberOrTag, BlockTransactions};
+
+/// Seconds `primary_sale_approve_and_invest` waits for its approval to be
+/// mined when the call does not say
+#[cfg(feature = "evm")]
+const DEFAULT_APPROVAL_CONFIRMATION_SECS: u64 = 120;
+
+/// Seconds `evm_deploy_erc20` waits for its deployment to be mined when the
+/// call does not say
+#[cfg(feature = "evm")]
+const DEFAULT_DEPLOYMENT_CONFIRMATION_SECS: u64 = 120;
 
 #[cfg(feature = "evm")]
 impl McpSdkAdapter {
@@ -337,24 +381,16 @@ impl McpSdkAdapter {
             ));
         }
 
-        // Get EVM client
-        let client = MantraClient::new(self.get_default_network_config().await?, None)
-            .await
-            .map_err(McpServerError::Sdk)?;
-
-        let evm_client = client.evm().await.map_err(McpServerError::Sdk)?;
+        // Get EVM client, which reuses the logs earlier queries scanned
+        let (evm_client, _) = self.get_evm_client().await?;
 
         // Parse contract address
         let contract_addr = alloy_primitives::Address::from_str(contract_address).map_err(|e| {
             McpServerError::InvalidArguments(format!("Invalid contract_address: {}", e))
         })?;
 
-        // Build event topics
-        let mut event_topics = vec![{
-            let mut bytes = [0u8; 32];
-            bytes[12..].copy_from_slice(contract_addr.0.as_slice());
-            alloy_primitives::B256::from(bytes)
-        }];
+        // Build event topics: the event signature, then the indexed arguments
+        let mut event_topics = vec![None];
 
         if let Some(sig) = event_signature {
             if !sig.starts_with("0x") {
@@ -365,7 +401,7 @@ impl McpSdkAdapter {
             let topic_hash = alloy_primitives::B256::from_str(sig).map_err(|e| {
                 McpServerError::InvalidArguments(format!("Invalid event_signature: {}", e))
             })?;
-            event_topics.insert(0, topic_hash);
+            event_topics[0] = Some(topic_hash);
         }
 
         if let Some(topics_array) = topics {
@@ -379,7 +415,7 @@ impl McpSdkAdapter {
                                     topic_str, e
                                 ))
                             })?;
-                        event_topics.push(topic_hash);
+                        event_topics.push(Some(topic_hash));
                     }
                 }
             }
@@ -394,16 +430,11 @@ impl McpSdkAdapter {
             filter = filter.block_range(Some(from.to_string()), to_block.map(|s| s.to_string()));
         }
 
-        // Set topics (simplified - in practice, this would be more complex)
-        let mut topics_vec = Vec::new();
-        for topic in event_topics.into_iter().skip(1) {
-            topics_vec.push(Some(topic));
-        }
-        filter.topics = topics_vec;
+        filter.topics = event_topics;
 
         // Query logs
         let logs = evm_client
-            .get_logs(filter)
+            .get_logs_cached(filter)
             .await
             .map_err(McpServerError::Sdk)?;
 
@@ -529,17 +560,25 @@ impl McpSdkAdapter {
         // Parse optional parameters
         let abi_file_path = args.get("abi_file_path").and_then(|v| v.as_str());
 
-        // TODO: Implement ABI loading and caching
-        // For now, return a placeholder response
+        let abi: alloy_json_abi::JsonAbi = serde_json::from_str(abi_json)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid ABI JSON: {}", e)))?;
+        let functions_loaded = abi.functions().count();
+        let events_loaded = abi.events().count();
+
+        // ABIs do not change, so they are kept until evicted and survive
+        // restarts when the cache is persistent
+        self.cache_set_permanent(format!("abi:{}", abi_key), serde_json::to_value(&abi)?)
+            .await;
+
         Ok(serde_json::json!({
             "status": "success",
             "operation": "evm_load_abi",
             "abi_key": abi_key,
             "abi_json_length": abi_json.len(),
             "abi_file_path": abi_file_path,
-            "functions_loaded": 0, // Placeholder
-            "events_loaded": 0, // Placeholder
-            "note": "ABI loading not yet fully implemented",
+            "functions_loaded": functions_loaded,
+            "events_loaded": events_loaded,
+            "persistent": self.cache.is_persistent(),
             "timestamp": chrono::Utc::now().to_rfc3339()
         }))
     }
@@ -549,7 +588,7 @@ impl McpSdkAdapter {
     pub async fn get_native_evm_balance(
         &self,
         wallet_address: Option<String>,
-    ) -> McpResult<String> {
+    ) -> McpResult<NativeEvmBalanceResponse> {
         let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
 
         // Get EVM client
@@ -563,7 +602,11 @@ impl McpSdkAdapter {
 
         let evm_client = crate::protocols::evm::client::EvmClient::new(evm_rpc_url, evm_chain_id)
             .await
-            .map_err(McpServerError::Sdk)?;
+            .map_err(McpServerError::Sdk)?
+            .with_retry_policy(self.retry_policy.clone())
+            .with_fallback_rpc_urls(&network_config.evm_rpc_fallback_urls)
+            .map_err(McpServerError::Sdk)?
+            .with_endpoint_pool(self.evm_endpoint_pool(&network_config, evm_chain_id).await);
 
         // Query balance
         let evm_address = alloy_primitives::Address::from_str(&evm_addr)
@@ -574,14 +617,13 @@ impl McpSdkAdapter {
             .await
             .map_err(McpServerError::Sdk)?;
 
-        // Format response
-        let balance_om = format_units(balance, 18);
-        let mut response = "🔍 **Native EVM Balance**\n\n".to_string();
-        response.push_str(&format!("**Cosmos Address:** `{}`\n", cosmos_addr));
-        response.push_str(&format!("**EVM Address:** `{}`\n", evm_addr));
-        response.push_str(&format!("**Balance:** {} OM\n", balance_om));
-
-        Ok(response)
+        Ok(NativeEvmBalanceResponse {
+            cosmos_address: cosmos_addr,
+            evm_address: evm_addr,
+            symbol: "OM".to_string(),
+            raw_balance: balance.to_string(),
+            formatted_balance: format_units(balance, 18),
+        })
     }
 
     /// Get ERC-20 token balance
@@ -590,7 +632,7 @@ impl McpSdkAdapter {
         &self,
         token_address: &str,
         wallet_address: Option<String>,
-    ) -> McpResult<String> {
+    ) -> McpResult<Erc20BalanceResponse> {
         let (_, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
         let wallet_addr = Address::from_str(&evm_addr).map_err(|e| {
             McpServerError::InvalidArguments(format!("Invalid wallet address: {}", e))
@@ -621,24 +663,12 @@ impl McpSdkAdapter {
             }
         })?;
 
-        let formatted = format_units(balance, metadata.decimals);
-
-        // Format response
-        let mut response = "🔍 **ERC-20 Token Balance**\n\n".to_string();
-        response.push_str(&format!("**Token:** {}\n", metadata.symbol));
-        if let Some(name) = &metadata.name {
-            response.push_str(&format!("**Name:** {}\n", name));
-        }
-        response.push_str(&format!(
-            "**Contract:** `{}`\n",
-            format!("{:#x}", token_addr)
-        ));
-        response.push_str(&format!("**Decimals:** {}\n\n", metadata.decimals));
-        response.push_str(&format!("**Wallet:** `{}`\n", evm_addr));
-        response.push_str(&format!("**Balance:** {} {}\n", formatted, metadata.symbol));
-        response.push_str(&format!("**Raw Balance:** {}\n", balance));
-
-        Ok(response)
+        Ok(Erc20BalanceResponse {
+            token: token_view(&metadata),
+            wallet_address: format!("{:#x}", wallet_addr),
+            raw_balance: balance.to_string(),
+            formatted_balance: format_units(balance, metadata.decimals),
+        })
     }
 
     /// Get all EVM balances (native + ERC-20 tokens)
@@ -710,7 +740,9 @@ impl McpSdkAdapter {
     /// * `gas_buffer_percent` - Gas estimate buffer (20 for simple, 30 for complex)
     ///
     /// # Returns
-    /// Transaction hash on success
+    /// Transaction hash on success. In a dry run the transaction is checked
+    /// with `eth_call` after gas estimation instead of being signed and sent,
+    /// and the zero hash is returned.
     #[cfg(feature = "evm")]
     async fn build_sign_and_broadcast_transaction(
         &self,
@@ -720,6 +752,7 @@ impl McpSdkAdapter {
         cosmos_addr: &str,
         gas_buffer_percent: u64,
     ) -> McpResult<alloy_primitives::B256> {
+        use crate::protocols::evm::client::complete_signing_intent;
         use crate::protocols::evm::tx::{Eip1559Transaction, SignedEip1559Transaction};
         use alloy_primitives::Bytes;
 
@@ -734,11 +767,17 @@ impl McpSdkAdapter {
         // 2. Get EVM client and chain ID
         let (evm_client, chain_id) = self.get_evm_client().await?;
 
-        // 3. Get nonce
-        let nonce = evm_client
-            .get_pending_nonce(crate::protocols::evm::types::EthAddress(from_addr))
-            .await
-            .map_err(McpServerError::Sdk)?;
+        // 3. Get nonce, holding the sender's signing slot until the
+        // broadcast is answered so concurrent calls cannot reuse it
+        let mut slot = evm_client
+            .signer_slot(&crate::protocols::evm::types::EthAddress(from_addr))
+            .await;
+        let nonce = slot.nonce(
+            evm_client
+                .get_pending_nonce(crate::protocols::evm::types::EthAddress(from_addr))
+                .await
+                .map_err(McpServerError::Sdk)?,
+        );
 
         // 4. Get fee suggestion
         let fee_suggestion = evm_client
@@ -768,29 +807,57 @@ impl McpSdkAdapter {
         let gas_limit = (gas_estimate * (100 + gas_buffer_percent)) / 100;
         tx = tx.gas_limit(gas_limit);
 
-        // 7. Sign transaction
+        if Self::is_dry_run() {
+            evm_client
+                .simulate_eip1559(
+                    crate::protocols::evm::types::EthAddress(from_addr),
+                    &tx,
+                    None,
+                )
+                .await
+                .map_err(McpServerError::Sdk)?;
+            info!(
+                "Dry run: simulated EVM call to {:#x} with gas limit {}, not broadcast",
+                contract_addr, gas_limit
+            );
+            slot.release();
+            return Ok(alloy_primitives::B256::ZERO);
+        }
+
+        // 7. Sign transaction, recording what it does first
+        let intent = evm_client.begin_signing_intent(
+            &crate::protocols::evm::types::EthAddress(from_addr),
+            Some(contract_addr),
+            &call_data,
+            value,
+        );
         let tx_hash = tx.signature_hash();
         let (sig, recid) = multivm_wallet
             .sign_ethereum_tx(tx_hash.as_ref())
+            .inspect_err(|e| complete_signing_intent(intent, nonce, None, Err(e)))
             .map_err(McpServerError::Sdk)?;
 
         // 8. Construct alloy Signature from k256 signature components
         // 9. Create signed transaction
         #[allow(deprecated)]
-        let signed_tx = {
+        let (signed_tx, signature) = {
             use alloy_primitives::Signature;
 
             // Convert k256 signature directly to alloy Signature
             let alloy_sig = Signature::from((sig, recid));
             let raw_bytes = tx.encode_signed(&alloy_sig);
-            SignedEip1559Transaction::new(tx.into_signed(alloy_sig), raw_bytes)
+            (
+                SignedEip1559Transaction::new(tx.into_signed(alloy_sig), raw_bytes),
+                alloy_sig,
+            )
         };
 
         // 10. Broadcast
-        evm_client
-            .send_raw_transaction(&signed_tx)
-            .await
-            .map_err(McpServerError::Sdk)
+        let sent = evm_client.send_raw_transaction(&signed_tx).await;
+        complete_signing_intent(intent, nonce, Some(&signature), sent.as_ref());
+        let tx_hash = sent.map_err(McpServerError::Sdk)?;
+        slot.commit();
+        Ok(tx_hash)
     }
 
     // =============================================================================
@@ -839,12 +906,15 @@ impl McpSdkAdapter {
         recipient: &str,
         amount: &str,
         wallet_address: Option<String>,
-    ) -> McpResult<String> {
+    ) -> McpResult<Erc20TransferResponse> {
         // 1. Parse and validate inputs
         let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
         let token_addr = Address::from_str(token_address).map_err(|e| {
             McpServerError::InvalidArguments(format!("Invalid token address: {}", e))
         })?;
+        // A mantra1 recipient is a Cosmos account; name its EVM form instead
+        require_address_kind(recipient, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid recipient: {}", e)))?;
         let to_addr = Address::from_str(recipient)
             .map_err(|e| McpServerError::InvalidArguments(format!("Invalid recipient: {}", e)))?;
 
@@ -871,27 +941,183 @@ impl McpSdkAdapter {
             )
             .await?;
 
-        // 5. Format response
-        let formatted_amount = format_units(amount_u256, metadata.decimals);
-        let mut response = "✅ **ERC-20 Transfer Submitted**\n\n".to_string();
-        response.push_str(&format!(
-            "**Token:** {} ({})\n",
-            metadata.symbol,
-            metadata.name.as_deref().unwrap_or("Unknown")
-        ));
-        response.push_str(&format!("**Contract:** `{:#x}`\n", token_addr));
-        response.push_str(&format!(
-            "**From:** `{}` (Cosmos: `{}`)\n",
-            evm_addr, cosmos_addr
-        ));
-        response.push_str(&format!("**To:** `{:#x}`\n", to_addr));
-        response.push_str(&format!(
-            "**Amount:** {} {}\n",
-            formatted_amount, metadata.symbol
-        ));
-        response.push_str(&format!("**Transaction Hash:** `{:#x}`\n", tx_hash));
+        Ok(Erc20TransferResponse {
+            token: token_view(&metadata),
+            cosmos_address: cosmos_addr,
+            from: evm_addr,
+            to: format!("{:#x}", to_addr),
+            raw_amount: amount_u256.to_string(),
+            formatted_amount: format_units(amount_u256, metadata.decimals),
+            tx_hash: format!("{:#x}", tx_hash),
+        })
+    }
 
-        Ok(response)
+    /// Deploy the standard mintable and burnable ERC-20 for a testnet
+    /// scenario and register it in the ERC-20 registry
+    ///
+    /// The initial supply is in whole tokens and minted to `owner`, the
+    /// signing wallet by default, which is also the only minter.
+    #[cfg(feature = "evm")]
+    pub async fn evm_deploy_erc20(&self, args: Value) -> McpResult<Value> {
+        use crate::protocols::evm::contracts::{StandardErc20Artifact, StandardErc20Params};
+
+        const STEPS: u64 = 3;
+
+        let name = args
+            .get("name")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| McpServerError::InvalidArguments("Missing name".to_string()))?;
+        let symbol = args
+            .get("symbol")
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| McpServerError::InvalidArguments("Missing symbol".to_string()))?;
+        let decimals = args.get("decimals").and_then(|v| v.as_u64()).unwrap_or(18);
+        let decimals = u8::try_from(decimals)
+            .ok()
+            .filter(|d| *d <= 36)
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments(format!(
+                    "decimals must be between 0 and 36, got {}",
+                    decimals
+                ))
+            })?;
+        let initial_supply = args
+            .get("initial_supply")
+            .and_then(|v| v.as_str())
+            .unwrap_or("0");
+        let owner = args.get("owner").and_then(|v| v.as_str());
+        let wallet_address = args
+            .get("wallet_address")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        let timeout = Duration::from_secs(
+            args.get("confirmation_timeout_secs")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_DEPLOYMENT_CONFIRMATION_SECS),
+        );
+
+        // 1. Load the audited bytecode and build the constructor arguments
+        report_progress(0, Some(STEPS), "Loading ERC-20 artifact");
+        let artifact_path = match &self.erc20_artifact_path {
+            Some(path) => path.clone(),
+            None => StandardErc20Artifact::default_path().map_err(McpServerError::Sdk)?,
+        };
+        let pinned_sha256 = self.erc20_artifact_sha256.as_deref().ok_or_else(|| {
+            McpServerError::Other(
+                "No sha256 is pinned for the ERC-20 artifact; set erc20_artifact_sha256 \
+                to the hash of the audited StandardErc20 creation bytecode"
+                    .to_string(),
+            )
+        })?;
+        let artifact = StandardErc20Artifact::load(&artifact_path, pinned_sha256).map_err(|e| {
+            McpServerError::Other(format!(
+                "{}. Place the compiled StandardErc20 artifact at {} or point \
+                erc20_artifact_path at it.",
+                e,
+                artifact_path.display()
+            ))
+        })?;
+
+        let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
+        let from_addr = Address::from_str(&evm_addr).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid wallet address: {}", e))
+        })?;
+        let owner_addr = match owner {
+            Some(owner) => {
+                require_address_kind(owner, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX).map_err(
+                    |e| McpServerError::InvalidArguments(format!("Invalid owner: {}", e)),
+                )?;
+                Address::from_str(owner).map_err(|e| {
+                    McpServerError::InvalidArguments(format!("Invalid owner: {}", e))
+                })?
+            }
+            None => from_addr,
+        };
+        let supply_u256 = parse_units(initial_supply, decimals).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid initial_supply: {}", e))
+        })?;
+        let params = StandardErc20Params {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            decimals,
+            initial_supply: supply_u256,
+            owner: owner_addr,
+        };
+
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+
+        if Self::is_dry_run() {
+            let request = crate::protocols::evm::types::EvmTransactionRequest::new(chain_id)
+                .data(params.init_code(&artifact.bytecode));
+            let gas_estimate = evm_client
+                .estimate_gas_with_options(request, Some(EthAddress(from_addr)), None)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            return Ok(serde_json::json!({
+                "status": "dry_run",
+                "operation": "evm_deploy_erc20",
+                "estimated_gas": gas_estimate,
+                "name": name,
+                "symbol": symbol,
+                "decimals": decimals,
+                "initial_supply": format_units(supply_u256, decimals),
+                "initial_supply_raw": supply_u256.to_string(),
+                "owner": format!("{:#x}", owner_addr),
+                "artifact": artifact_path.display().to_string(),
+                "artifact_sha256": artifact.sha256,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        // 2. Deploy and wait for the contract address
+        report_progress(1, Some(STEPS), &format!("Deploying {}", symbol));
+        let wallet = self
+            .get_multivm_wallet_by_address(&cosmos_addr)
+            .await?
+            .ok_or_else(|| McpServerError::Other("Wallet not found for signing".to_string()))?;
+        let (token_addr, tx_hash) = evm_client
+            .deploy_standard_erc20(&artifact, &params, &wallet, Some(timeout))
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        // 3. Register the token from what the deployed contract reports
+        report_progress(
+            2,
+            Some(STEPS),
+            &format!("Registering {} at {:#x}", symbol, token_addr),
+        );
+        let info = self
+            .ensure_token_metadata(&evm_client, chain_id, token_addr)
+            .await?;
+        if info.symbol != params.symbol || info.decimals != params.decimals {
+            warn!(
+                "Token deployed at {:#x} reports {} with {} decimals, expected {} with {}; \
+                check the artifact at {}",
+                token_addr,
+                info.symbol,
+                info.decimals,
+                params.symbol,
+                params.decimals,
+                artifact_path.display()
+            );
+        }
+        report_progress(STEPS, Some(STEPS), "Token deployed");
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "evm_deploy_erc20",
+            "contract_address": format!("{:#x}", token_addr),
+            "transaction_hash": format!("{:#x}", tx_hash),
+            "chain_id": chain_id,
+            "initial_supply": format_units(supply_u256, decimals),
+            "initial_supply_raw": supply_u256.to_string(),
+            "owner": format!("{:#x}", owner_addr),
+            "token": token_view(&info),
+            "artifact": artifact_path.display().to_string(),
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
     }
 
     /// Approve ERC-20 token spending
@@ -902,12 +1128,14 @@ impl McpSdkAdapter {
         spender: &str,
         amount: &str,
         wallet_address: Option<String>,
-    ) -> McpResult<String> {
+    ) -> McpResult<Erc20ApprovalResponse> {
         // 1. Parse and validate inputs
         let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
         let token_addr = Address::from_str(token_address).map_err(|e| {
             McpServerError::InvalidArguments(format!("Invalid token address: {}", e))
         })?;
+        require_address_kind(spender, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid spender: {}", e)))?;
         let spender_addr = Address::from_str(spender)
             .map_err(|e| McpServerError::InvalidArguments(format!("Invalid spender: {}", e)))?;
 
@@ -916,8 +1144,21 @@ impl McpSdkAdapter {
         let metadata = self
             .ensure_token_metadata(&evm_client, chain_id, token_addr)
             .await?;
-        let amount_u256 = parse_units(amount, metadata.decimals)
-            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)))?;
+        let amount_u256 = if matches!(
+            amount.trim().to_ascii_lowercase().as_str(),
+            "max" | "unlimited"
+        ) {
+            U256::MAX
+        } else {
+            parse_units(amount, metadata.decimals)
+                .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)))?
+        };
+        let warnings: Vec<String> = self
+            .infinite_approval_policy
+            .check(spender_addr, amount_u256)
+            .map_err(|e| McpServerError::AuthorizationDenied(e.to_string()))?
+            .into_iter()
+            .collect();
 
         // 3. Encode approve call
         let erc20 = SdkErc20::new(evm_client.clone(), token_addr);
@@ -934,31 +1175,171 @@ impl McpSdkAdapter {
             )
             .await?;
 
-        // 5. Format response
-        let formatted_amount = format_units(amount_u256, metadata.decimals);
-        let mut response = "✅ **ERC-20 Approval Submitted**\n\n".to_string();
-        response.push_str(&format!(
-            "**Token:** {} ({})\n",
-            metadata.symbol,
-            metadata.name.as_deref().unwrap_or("Unknown")
-        ));
-        response.push_str(&format!("**Contract:** `{:#x}`\n", token_addr));
-        response.push_str(&format!(
-            "**Owner:** `{}` (Cosmos: `{}`)\n",
-            evm_addr, cosmos_addr
-        ));
-        response.push_str(&format!("**Spender:** `{:#x}`\n", spender_addr));
-        response.push_str(&format!(
-            "**Amount:** {} {}\n",
-            formatted_amount, metadata.symbol
-        ));
-        response.push_str(&format!("**Transaction Hash:** `{:#x}`\n", tx_hash));
-        response.push_str(&format!(
-            "\n**Explorer:** https://mantrascan.io/dukong/tx/{:#x}\n",
-            tx_hash
-        ));
+        Ok(Erc20ApprovalResponse {
+            token: token_view(&metadata),
+            cosmos_address: cosmos_addr,
+            owner: evm_addr,
+            spender: format!("{:#x}", spender_addr),
+            raw_amount: amount_u256.to_string(),
+            formatted_amount: format_units(amount_u256, metadata.decimals),
+            unlimited: is_unlimited_approval(amount_u256),
+            tx_hash: format!("{:#x}", tx_hash),
+            explorer_url: format!("https://mantrascan.io/dukong/tx/{:#x}", tx_hash),
+            warnings,
+        })
+    }
 
-        Ok(response)
+    /// Spenders the wallet has approved and what each may still spend
+    ///
+    /// Spenders are found from the `Approval` events naming the wallet since
+    /// `from_block` (the last 200,000 blocks by default), of `token_address`
+    /// only when given; each allowance is then read from the token itself.
+    /// Allowances already spent or revoked are left out unless
+    /// `include_zero` is set.
+    #[cfg(feature = "evm")]
+    pub async fn erc20_get_allowances(
+        &self,
+        token_address: Option<&str>,
+        wallet_address: Option<String>,
+        from_block: Option<u64>,
+        include_zero: bool,
+    ) -> McpResult<Erc20AllowancesResponse> {
+        let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
+        let owner = Address::from_str(&evm_addr)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid owner: {}", e)))?;
+        let token = token_address
+            .map(|token| {
+                Address::from_str(token).map_err(|e| {
+                    McpServerError::InvalidArguments(format!("Invalid token address: {}", e))
+                })
+            })
+            .transpose()?;
+
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let to_block = evm_client
+            .get_block_number()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let from_block =
+            from_block.unwrap_or_else(|| to_block.saturating_sub(DEFAULT_APPROVAL_SCAN_BLOCKS));
+        let events = allowance::scan_approvals(
+            &evm_client,
+            owner,
+            token,
+            from_block,
+            to_block,
+            DEFAULT_APPROVAL_SCAN_CHUNK_BLOCKS,
+        )
+        .await
+        .map_err(McpServerError::Sdk)?;
+
+        let mut allowances = Vec::new();
+        let mut warnings = Vec::new();
+        for event in events {
+            let metadata = match self
+                .ensure_token_metadata(&evm_client, chain_id, event.token)
+                .await
+            {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    warnings.push(format!("Skipped {:#x}: {}", event.token, e));
+                    continue;
+                }
+            };
+            let current = SdkErc20::new(evm_client.clone(), event.token)
+                .allowance(owner, event.spender)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            if current.is_zero() && !include_zero {
+                continue;
+            }
+            allowances.push(Erc20AllowanceEntry {
+                token: token_view(&metadata),
+                spender: format!("{:#x}", event.spender),
+                raw_allowance: current.to_string(),
+                formatted_allowance: format_units(current, metadata.decimals),
+                unlimited: is_unlimited_approval(current),
+                last_approved_block: event.block_number,
+                last_approval_tx: event.tx_hash.map(|hash| format!("{:#x}", hash)),
+            });
+        }
+        let unlimited = allowances.iter().filter(|entry| entry.unlimited).count();
+        if unlimited > 0 {
+            warnings.push(format!(
+                "{} unlimited allowance(s); revoke those no longer needed with erc20_revoke_approval",
+                unlimited
+            ));
+        }
+
+        Ok(Erc20AllowancesResponse {
+            cosmos_address: cosmos_addr,
+            owner: evm_addr,
+            from_block,
+            to_block,
+            allowances,
+            warnings,
+        })
+    }
+
+    /// Set the wallet's allowance of `spender` on `token_address` back to zero
+    ///
+    /// Nothing is sent when the allowance already is zero.
+    #[cfg(feature = "evm")]
+    pub async fn erc20_revoke_approval(
+        &self,
+        token_address: &str,
+        spender: &str,
+        wallet_address: Option<String>,
+    ) -> McpResult<Erc20RevokeResponse> {
+        let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
+        let owner = Address::from_str(&evm_addr)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid owner: {}", e)))?;
+        let token_addr = Address::from_str(token_address).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid token address: {}", e))
+        })?;
+        require_address_kind(spender, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid spender: {}", e)))?;
+        let spender_addr = Address::from_str(spender)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid spender: {}", e)))?;
+
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let metadata = self
+            .ensure_token_metadata(&evm_client, chain_id, token_addr)
+            .await?;
+        let erc20 = SdkErc20::new(evm_client.clone(), token_addr);
+        let previous = erc20
+            .allowance(owner, spender_addr)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let tx_hash = if previous.is_zero() {
+            None
+        } else {
+            let call_data = erc20.encode_approve(spender_addr, U256::ZERO);
+            Some(
+                self.build_sign_and_broadcast_transaction(
+                    token_addr,
+                    call_data,
+                    U256::ZERO,
+                    &cosmos_addr,
+                    GAS_BUFFER_SIMPLE_PERCENT,
+                )
+                .await?,
+            )
+        };
+
+        Ok(Erc20RevokeResponse {
+            token: token_view(&metadata),
+            cosmos_address: cosmos_addr,
+            owner: evm_addr,
+            spender: format!("{:#x}", spender_addr),
+            previous_raw_allowance: previous.to_string(),
+            previous_formatted_allowance: format_units(previous, metadata.decimals),
+            revoked: tx_hash.is_some(),
+            tx_hash: tx_hash.map(|hash| format!("{:#x}", hash)),
+            explorer_url: tx_hash
+                .map(|hash| format!("https://mantrascan.io/dukong/tx/{:#x}", hash)),
+        })
     }
 
     // =============================================================================
@@ -1307,56 +1688,276 @@ impl McpSdkAdapter {
         }))
     }
 
-    /// Claim refund from a failed or cancelled sale (v2.0 - claims ALL tokens)
+    /// Approve the sale to spend the investment if the allowance falls short,
+    /// wait for the approval to be mined, then invest
     ///
-    /// # Behavior Change (v2.0)
-    /// In v2.0, this operation automatically claims refunds for ALL accepted tokens
-    /// in a single transaction. The contract loops through all tokens internally.
+    /// The allowance is read before anything is sent, so an allowance that
+    /// already covers the amount goes straight to the investment. The
+    /// approval is for the investment amount unless `approval_amount` asks
+    /// for more or for `"unlimited"`, which the infinite approval policy
+    /// checks. An investment that fails after its approval was mined comes
+    /// back with how to retry it or revoke the approval.
     ///
     /// # Returns
     /// ```json
     /// {
     ///   "status": "success",
-    ///   "operation": "primary_sale_claim_refund",
-    ///   "transaction_hash": "0x...",
-    ///   "contract_address": "0x...",
-    ///   "investor": "0x...",
-    ///   "refund_amount_normalized": "1000.5",
-    ///   "refund_amount_raw": "1000500000000000000000",
-    ///   "note": "Refund claimed for all accepted tokens in single transaction",
+    ///   "operation": "primary_sale_approve_and_invest",
+    ///   "approval": { "transaction_hash": "0x...", "amount": "1000.5", "block_number": 123 },
+    ///   "investment": { "operation": "primary_sale_invest", "transaction_hash": "0x..." },
     ///   "timestamp": "2025-01-01T00:00:00Z"
     /// }
     /// ```
     #[cfg(feature = "evm")]
-    pub async fn primary_sale_claim_refund(&self, args: Value) -> McpResult<Value> {
+    pub async fn primary_sale_approve_and_invest(&self, args: Value) -> McpResult<Value> {
+        const STEPS: u64 = 4;
+
         let contract_address = args
             .get("contract_address")
             .and_then(|v| v.as_str())
             .ok_or_else(|| {
                 McpServerError::InvalidArguments("Missing contract_address".to_string())
             })?;
-
+        let token_str = args
+            .get("token")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("Missing token".to_string()))?;
+        let amount_str = args
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("Missing amount".to_string()))?;
+        let approval_amount = args.get("approval_amount").and_then(|v| v.as_str());
         let wallet_address = args
             .get("wallet_address")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
+        let timeout = Duration::from_secs(
+            args.get("confirmation_timeout_secs")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_APPROVAL_CONFIRMATION_SECS),
+        );
 
-        // Get wallet
-        let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
+        // 1. Read the allowance the sale already has
+        report_progress(0, Some(STEPS), "Checking allowance");
+        let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address.clone()).await?;
         let from_addr = Address::from_str(&evm_addr).map_err(|e| {
             McpServerError::InvalidArguments(format!("Invalid wallet address: {}", e))
         })?;
-
-        // Parse contract address
         let contract_addr = Address::from_str(contract_address).map_err(|e| {
             McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
         })?;
+        let token_addr = Address::from_str(token_str).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid token address: {}", e))
+        })?;
 
-        // Get EVM client
         let (evm_client, _chain_id) = self.get_evm_client().await?;
-        let primary_sale = evm_client.primary_sale(contract_addr);
+        let token = evm_client.erc20(token_addr);
+        let decimals = token.decimals().await.map_err(McpServerError::Sdk)?;
+        let amount_u256 = parse_units(amount_str, decimals)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)))?;
+        let current_allowance = token
+            .allowance(from_addr, contract_addr)
+            .await
+            .map_err(McpServerError::Sdk)?;
 
-        // Check if refund has already been claimed
+        // 2. Approve the sale and wait for the approval to be mined
+        let approval = if current_allowance >= amount_u256 {
+            report_progress(
+                2,
+                Some(STEPS),
+                "Allowance already covers the investment, no approval needed",
+            );
+            None
+        } else {
+            let approve_u256 = match approval_amount.map(|s| s.trim().to_ascii_lowercase()) {
+                None => amount_u256,
+                Some(s) if s == "max" || s == "unlimited" => U256::MAX,
+                Some(s) => parse_units(&s, decimals).map_err(|e| {
+                    McpServerError::InvalidArguments(format!("Invalid approval_amount: {}", e))
+                })?,
+            };
+            if approve_u256 < amount_u256 {
+                return Err(McpServerError::InvalidArguments(format!(
+                    "approval_amount {} is below the investment amount {}",
+                    format_units(approve_u256, decimals),
+                    amount_str
+                )));
+            }
+            let warnings: Vec<String> = self
+                .infinite_approval_policy
+                .check(contract_addr, approve_u256)
+                .map_err(|e| McpServerError::AuthorizationDenied(e.to_string()))?
+                .into_iter()
+                .collect();
+
+            report_progress(
+                1,
+                Some(STEPS),
+                &format!(
+                    "Approving {} of {:#x} for the sale",
+                    format_units(approve_u256, decimals),
+                    token_addr
+                ),
+            );
+            let call_data = SdkErc20::new(evm_client.clone(), token_addr)
+                .encode_approve(contract_addr, approve_u256);
+            let approval_hash = self
+                .build_sign_and_broadcast_transaction(
+                    token_addr,
+                    call_data,
+                    U256::ZERO,
+                    &cosmos_addr,
+                    GAS_BUFFER_SIMPLE_PERCENT,
+                )
+                .await?;
+
+            if Self::is_dry_run() {
+                // The investment's transferFrom needs the approval on chain,
+                // so it cannot be simulated yet
+                return Ok(serde_json::json!({
+                    "status": "dry_run",
+                    "operation": "primary_sale_approve_and_invest",
+                    "approval": {
+                        "simulated": true,
+                        "amount": format_units(approve_u256, decimals),
+                        "amount_raw": approve_u256.to_string(),
+                        "warnings": warnings,
+                    },
+                    "investment": {
+                        "simulated": false,
+                        "reason": "The investment can only be simulated once the approval is on chain",
+                    },
+                    "timestamp": chrono::Utc::now().to_rfc3339()
+                }));
+            }
+
+            report_progress(
+                2,
+                Some(STEPS),
+                &format!("Waiting for approval {:#x} to be mined", approval_hash),
+            );
+            let receipt = evm_client
+                .wait_for_receipt(approval_hash, 1, Some(timeout))
+                .await
+                .map_err(|e| {
+                    McpServerError::Other(format!(
+                        "Approval {:#x} was sent but not confirmed: {}\n\
+                        Nothing was invested. Check the approval with \
+                        evm_get_transaction; once it is mined, invest with \
+                        primary_sale_invest, or call this tool again, which skips the \
+                        approval when the allowance covers the amount.",
+                        approval_hash, e
+                    ))
+                })?;
+            if !receipt.status() {
+                return Err(McpServerError::Other(format!(
+                    "Approval {:#x} reverted; the allowance is unchanged and nothing was invested",
+                    approval_hash
+                )));
+            }
+
+            Some(serde_json::json!({
+                "transaction_hash": format!("{:#x}", approval_hash),
+                "spender": format!("{:#x}", contract_addr),
+                "amount": format_units(approve_u256, decimals),
+                "amount_raw": approve_u256.to_string(),
+                "unlimited": is_unlimited_approval(approve_u256),
+                "block_number": receipt.block_number,
+                "warnings": warnings,
+            }))
+        };
+
+        // 3. Invest, now that the allowance is on chain
+        report_progress(3, Some(STEPS), "Submitting investment");
+        let invest_args = serde_json::json!({
+            "contract_address": contract_address,
+            "token": token_str,
+            "amount": amount_str,
+            "wallet_address": wallet_address,
+        });
+        let investment = match self.primary_sale_invest(invest_args).await {
+            Ok(investment) => investment,
+            Err(e) => {
+                let Some(approval) = &approval else {
+                    return Err(e);
+                };
+                return Err(McpServerError::Other(format!(
+                    "Investment failed after approval {} was mined: {}\n\
+                    \n\
+                    The sale {:#x} may still spend {} of {:#x}. To retry, call \
+                    primary_sale_invest with the same token and amount. To undo the \
+                    approval, call erc20_revoke_approval with token_address {:#x} and spender {:#x}.",
+                    approval["transaction_hash"].as_str().unwrap_or_default(),
+                    e,
+                    contract_addr,
+                    approval["amount"].as_str().unwrap_or_default(),
+                    token_addr,
+                    token_addr,
+                    contract_addr
+                )));
+            }
+        };
+        report_progress(STEPS, Some(STEPS), "Investment submitted");
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "primary_sale_approve_and_invest",
+            "approval": approval,
+            "investment": investment,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Claim refund from a failed or cancelled sale (v2.0 - claims ALL tokens)
+    ///
+    /// # Behavior Change (v2.0)
+    /// In v2.0, this operation automatically claims refunds for ALL accepted tokens
+    /// in a single transaction. The contract loops through all tokens internally.
+    ///
+    /// # Returns
+    /// ```json
+    /// {
+    ///   "status": "success",
+    ///   "operation": "primary_sale_claim_refund",
+    ///   "transaction_hash": "0x...",
+    ///   "contract_address": "0x...",
+    ///   "investor": "0x...",
+    ///   "refund_amount_normalized": "1000.5",
+    ///   "refund_amount_raw": "1000500000000000000000",
+    ///   "note": "Refund claimed for all accepted tokens in single transaction",
+    ///   "timestamp": "2025-01-01T00:00:00Z"
+    /// }
+    /// ```
+    #[cfg(feature = "evm")]
+    pub async fn primary_sale_claim_refund(&self, args: Value) -> McpResult<Value> {
+        let contract_address = args
+            .get("contract_address")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("Missing contract_address".to_string())
+            })?;
+
+        let wallet_address = args
+            .get("wallet_address")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        // Get wallet
+        let (cosmos_addr, evm_addr) = self.get_wallet_evm_address(wallet_address).await?;
+        let from_addr = Address::from_str(&evm_addr).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid wallet address: {}", e))
+        })?;
+
+        // Parse contract address
+        let contract_addr = Address::from_str(contract_address).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
+        })?;
+
+        // Get EVM client
+        let (evm_client, _chain_id) = self.get_evm_client().await?;
+        let primary_sale = evm_client.primary_sale(contract_addr);
+
+        // Check if refund has already been claimed
         let already_refunded = primary_sale
             .refunded(from_addr)
             .await
@@ -1502,6 +2103,401 @@ impl McpSdkAdapter {
         Ok(response)
     }
 
+    /// Check every requirement of investing `amount` of `token` in a sale,
+    /// listing all that are not met
+    #[cfg(feature = "evm")]
+    pub async fn primary_sale_check_eligibility(
+        &self,
+        contract_address: &str,
+        token: &str,
+        amount: &str,
+        investor_address: Option<&str>,
+    ) -> McpResult<PrimarySaleEligibilityResponse> {
+        let contract_addr = Address::from_str(contract_address).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
+        })?;
+        let token_addr = Address::from_str(token).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid token address: {}", e))
+        })?;
+        let investor = match investor_address {
+            Some(investor) => investor.to_string(),
+            None => self.get_wallet_evm_address(None).await?.1,
+        };
+        require_address_kind(&investor, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid investor: {}", e)))?;
+        let investor_addr = Address::from_str(&investor).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid investor address: {}", e))
+        })?;
+
+        let (evm_client, _chain_id) = self.get_evm_client().await?;
+        let decimals = evm_client
+            .erc20(token_addr)
+            .decimals()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let amount_raw = parse_units(amount, decimals)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid amount: {}", e)))?;
+        let snapshot = InvestmentSnapshot::fetch(
+            &evm_client,
+            contract_addr,
+            investor_addr,
+            token_addr,
+            amount_raw,
+        )
+        .await
+        .map_err(McpServerError::Sdk)?;
+        let issues = snapshot.issues(chrono::Utc::now().timestamp().max(0) as u64);
+
+        Ok(PrimarySaleEligibilityResponse {
+            contract_address: format!("{:#x}", contract_addr),
+            investor: format!("{:#x}", investor_addr),
+            token: format!("{:#x}", token_addr),
+            eligible: issues.is_empty(),
+            issues: issues
+                .into_iter()
+                .map(|issue| PrimarySaleEligibilityIssue {
+                    code: issue.kind.to_string(),
+                    message: issue.message,
+                    fix: issue.fix,
+                })
+                .collect(),
+            sale_status: status_name(snapshot.status).to_string(),
+            amount: format_units(snapshot.amount, decimals),
+            amount_raw: snapshot.amount.to_string(),
+            amount_normalized: snapshot.normalized_amount().to_string(),
+            balance: format_units(snapshot.balance, decimals),
+            allowance: format_units(snapshot.allowance, decimals),
+            remaining_capacity: (!snapshot.hard_cap.is_zero())
+                .then(|| format_units(snapshot.remaining_capacity, 18)),
+            min_step: format_units(snapshot.min_step, 18),
+            is_allowlisted: snapshot.is_allowlisted,
+            token_accepted: snapshot.token_accepted,
+        })
+    }
+
+    /// Project settling a sale with its current contributions: commission,
+    /// issuer proceeds, allocations and refunds
+    ///
+    /// Without `restricted_wallets`, the wallets of the restricted wallet list
+    /// are treated as restricted, as settlement would.
+    #[cfg(feature = "evm")]
+    pub async fn primary_sale_project_settlement(
+        &self,
+        contract_address: &str,
+        restricted_wallets: &[String],
+    ) -> McpResult<PrimarySaleSettlementProjectionResponse> {
+        let contract_addr = Address::from_str(contract_address).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
+        })?;
+        let from_list = restricted_wallets.is_empty();
+        let listed: Vec<String> = if from_list {
+            self.restricted_wallets
+                .read()
+                .await
+                .list()
+                .into_iter()
+                .map(|entry| entry.address)
+                .collect()
+        } else {
+            restricted_wallets.to_vec()
+        };
+        let restricted = listed
+            .iter()
+            .map(|wallet| {
+                Address::from_str(wallet).map_err(|e| {
+                    McpServerError::InvalidArguments(format!(
+                        "Invalid restricted wallet {}: {}",
+                        wallet, e
+                    ))
+                })
+            })
+            .collect::<McpResult<std::collections::HashSet<Address>>>()?;
+
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let primary_sale = evm_client.primary_sale(contract_addr);
+        let status = primary_sale.status().await.map_err(McpServerError::Sdk)?;
+        let (export, projection) = primary_sale
+            .project_settlement(&restricted, ExportOptions::default())
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let mut warnings = Vec::new();
+        if !matches!(status_name(status), "Active" | "Ended") {
+            warnings.push(format!(
+                "The sale is {}; settlement only runs on an Ended sale",
+                status_name(status)
+            ));
+        }
+        if export
+            .investors
+            .iter()
+            .any(|investor| investor.has_received_settlement)
+        {
+            warnings.push(
+                "Settlement has already processed some investors; the projection ignores that"
+                    .to_string(),
+            );
+        }
+        // A sanctions feed lists far more wallets than invested; only flag
+        // wallets named for this projection
+        let unknown: Vec<String> = restricted
+            .iter()
+            .filter(|wallet| !export.investors.iter().any(|i| i.address == **wallet))
+            .map(|wallet| format!("{:#x}", wallet))
+            .collect();
+        if !from_list && !unknown.is_empty() {
+            warnings.push(format!(
+                "Not investors of the sale, so ignored: {}",
+                unknown.join(", ")
+            ));
+        }
+
+        let mut decimals = HashMap::new();
+        for &token in &export.accepted_tokens {
+            if let Ok(info) = self
+                .ensure_token_metadata(&evm_client, chain_id, token)
+                .await
+            {
+                decimals.insert(token, (info.symbol, info.decimals));
+            }
+        }
+        let format_amount = |token: &Address, amount: U256| match decimals.get(token) {
+            Some((_, decimals)) => format_units(amount, *decimals),
+            None => amount.to_string(),
+        };
+
+        Ok(PrimarySaleSettlementProjectionResponse {
+            contract_address: format!("{:#x}", contract_addr),
+            sale_status: status_name(status).to_string(),
+            soft_cap: format_units(projection.soft_cap, 18),
+            total_contributed: format_units(projection.total_contributed_normalized, 18),
+            soft_cap_met: projection.soft_cap_met,
+            commission_bps: projection.commission_bps,
+            tokens: projection
+                .tokens
+                .iter()
+                .map(|proceeds| PrimarySaleTokenProceeds {
+                    token: format!("{:#x}", proceeds.token),
+                    symbol: decimals
+                        .get(&proceeds.token)
+                        .map(|(symbol, _)| symbol.clone()),
+                    total_contributed: format_amount(&proceeds.token, proceeds.total_contributed),
+                    refunded: format_amount(&proceeds.token, proceeds.refunded),
+                    net: format_amount(&proceeds.token, proceeds.net),
+                    commission_to_mantra: format_amount(&proceeds.token, proceeds.commission),
+                    proceeds_to_issuer: format_amount(&proceeds.token, proceeds.issuer_proceeds),
+                })
+                .collect(),
+            investors_distributed: projection.count(InvestorOutcome::Distribute),
+            investors_refunded: projection
+                .investors
+                .iter()
+                .filter(|investor| investor.outcome.is_refund())
+                .count(),
+            tokens_distributed: projection.tokens_distributed.to_string(),
+            tokens_returned_to_issuer: projection.tokens_returned_to_issuer.to_string(),
+            investors: projection
+                .investors
+                .iter()
+                .map(|investor| PrimarySaleInvestorProjection {
+                    address: format!("{:#x}", investor.address),
+                    outcome: investor.outcome.as_str().to_string(),
+                    tokens_allocated: investor.tokens_allocated.to_string(),
+                    refunds_by_token: investor
+                        .refunds_by_token
+                        .iter()
+                        .map(|(token, amount)| {
+                            (format!("{:#x}", token), format_amount(token, *amount))
+                        })
+                        .collect(),
+                })
+                .collect(),
+            warnings,
+        })
+    }
+
+    /// Check the restricted wallet list against a sale's investors
+    ///
+    /// Investors are settled in the order they invested, so a listed investor
+    /// before the settlement's progress has already received its allocation.
+    #[cfg(feature = "evm")]
+    pub async fn restricted_wallets_validate(
+        &self,
+        contract_address: &str,
+    ) -> McpResult<RestrictedWalletValidationResponse> {
+        let contract_addr = Address::from_str(contract_address).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
+        })?;
+        let (evm_client, _chain_id) = self.get_evm_client().await?;
+        let primary_sale = evm_client.primary_sale(contract_addr);
+        let (investors, (processed, _, _, _)) = futures::try_join!(
+            primary_sale.get_investors_list(),
+            primary_sale.get_settlement_progress(),
+        )
+        .map_err(McpServerError::Sdk)?;
+        let investors: Vec<String> = investors
+            .iter()
+            .map(|investor| format!("{:#x}", investor))
+            .collect();
+
+        let list = self.restricted_wallets.read().await;
+        let validation = list.validate_against(investors.iter().map(String::as_str));
+        let processed = processed.saturating_to::<usize>();
+        let already_settled: Vec<String> = investors[..processed.min(investors.len())]
+            .iter()
+            .filter(|investor| list.contains(investor))
+            .cloned()
+            .collect();
+
+        let mut warnings = Vec::new();
+        if !already_settled.is_empty() {
+            warnings.push(format!(
+                "{} restricted investors were settled before being listed and received their allocation",
+                already_settled.len()
+            ));
+        }
+        Ok(RestrictedWalletValidationResponse {
+            contract_address: format!("{:#x}", contract_addr),
+            listed: list.len(),
+            investor_count: investors.len(),
+            restricted_investors: validation.investors,
+            not_investors: validation.not_investors.len(),
+            already_settled,
+            warnings,
+        })
+    }
+
+    /// Export every investor of a sale with a reconciliation of their
+    /// contributions against the sale's totals
+    #[cfg(feature = "evm")]
+    pub async fn primary_sale_export_investors(
+        &self,
+        contract_address: &str,
+        csv: bool,
+        options: ExportOptions,
+    ) -> McpResult<PrimarySaleExportResponse> {
+        let contract_addr = Address::from_str(contract_address).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
+        })?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let export = evm_client
+            .primary_sale(contract_addr)
+            .export_investors(options)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let mut tokens = HashMap::new();
+        for &token in &export.accepted_tokens {
+            match self
+                .ensure_token_metadata(&evm_client, chain_id, token)
+                .await
+            {
+                Ok(info) => {
+                    tokens.insert(token, info);
+                }
+                Err(e) => debug!("No metadata for accepted token {:#x}: {}", token, e),
+            }
+        }
+        let symbol = |token: &Address| tokens.get(token).map(|info| info.symbol.clone());
+        let format_amount = |token: &Address, amount: U256| {
+            tokens
+                .get(token)
+                .map(|info| format_units(amount, info.decimals))
+        };
+
+        let reconciliation = &export.reconciliation;
+        let mut warnings = Vec::new();
+        if !export.failed.is_empty() {
+            warnings.push(format!(
+                "{} investors could not be fetched; the sums leave them out",
+                export.failed.len()
+            ));
+        }
+        if U256::from(reconciliation.investors_exported + reconciliation.investors_failed)
+            != reconciliation.investor_count
+        {
+            warnings.push(format!(
+                "The sale reports {} investors but {} were listed; investors joined during the export",
+                reconciliation.investor_count,
+                reconciliation.investors_exported + reconciliation.investors_failed
+            ));
+        }
+        if export
+            .investors
+            .iter()
+            .any(|investor| investor.has_claimed_refund)
+        {
+            warnings.push(
+                "Some investors claimed refunds; differences may come from refunded contributions"
+                    .to_string(),
+            );
+        }
+
+        Ok(PrimarySaleExportResponse {
+            contract_address: format!("{:#x}", export.contract),
+            investor_count: export.investors.len(),
+            csv: csv.then(|| export.to_csv()),
+            investors: (!csv).then(|| {
+                export
+                    .investors
+                    .iter()
+                    .map(|investor| PrimarySaleInvestorRow {
+                        address: format!("{:#x}", investor.address),
+                        contribution_normalized: investor.contribution_normalized.to_string(),
+                        contribution: format_units(investor.contribution_normalized, 18),
+                        contributions_by_token: investor
+                            .contributions_by_token
+                            .iter()
+                            .map(|(token, amount)| (format!("{:#x}", token), amount.to_string()))
+                            .collect(),
+                        tokens_allocated: investor.tokens_allocated.to_string(),
+                        is_kyc_approved: investor.is_kyc_approved,
+                        has_received_settlement: investor.has_received_settlement,
+                        has_claimed_refund: investor.has_claimed_refund,
+                    })
+                    .collect()
+            }),
+            failed: export
+                .failed
+                .iter()
+                .map(|failure| PrimarySaleExportFailure {
+                    address: format!("{:#x}", failure.address),
+                    error: failure.error.clone(),
+                })
+                .collect(),
+            reconciliation: PrimarySaleReconciliationView {
+                balanced: reconciliation.balanced,
+                investor_count: reconciliation.investor_count.to_string(),
+                investors_exported: reconciliation.investors_exported,
+                investors_failed: reconciliation.investors_failed,
+                sum_contributions_normalized: reconciliation
+                    .sum_contributions_normalized
+                    .to_string(),
+                total_contributed_normalized: reconciliation
+                    .total_contributed_normalized
+                    .to_string(),
+                sum_contributions: format_units(reconciliation.sum_contributions_normalized, 18),
+                total_contributed: format_units(reconciliation.total_contributed_normalized, 18),
+                difference_normalized: reconciliation.difference_normalized.clone(),
+                tokens: reconciliation
+                    .tokens
+                    .iter()
+                    .map(|token| PrimarySaleTokenReconciliationView {
+                        token: format!("{:#x}", token.token),
+                        symbol: symbol(&token.token),
+                        investor_sum: token.investor_sum.to_string(),
+                        contract_total: token.contract_total.to_string(),
+                        investor_sum_formatted: format_amount(&token.token, token.investor_sum),
+                        contract_total_formatted: format_amount(&token.token, token.contract_total),
+                        matches: token.matches,
+                    })
+                    .collect(),
+            },
+            warnings,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+
     /// Activate a primary sale (admin only)
     ///
     /// # Returns
@@ -2027,6 +3023,10 @@ impl McpSdkAdapter {
     /// Processes a batch of investors (distributes tokens or refunds). This operation is
     /// idempotent and can be called multiple times to process all investors in batches.
     ///
+    /// Without a `restricted_wallets` argument, the listed restricted wallets among the
+    /// batch's investors are passed instead. When more than 50 of them fall in one batch,
+    /// the batch is cut short before the 51st and the next call continues from there.
+    ///
     /// # Returns
     /// ```json
     /// {
@@ -2036,6 +3036,7 @@ impl McpSdkAdapter {
     ///   "contract_address": "0x...",
     ///   "batch_size": "100",
     ///   "restricted_wallets_count": "2",
+    ///   "restricted_wallets_source": "restricted_wallet_list",
     ///   "processed": "100",
     ///   "total": "150",
     ///   "progress_percentage": "66.67",
@@ -2057,15 +3058,14 @@ impl McpSdkAdapter {
             .and_then(|v| v.as_u64())
             .unwrap_or(100);
 
-        let restricted_wallets: Vec<String> = args
+        let inline_restricted_wallets: Option<Vec<String>> = args
             .get("restricted_wallets")
             .and_then(|v| v.as_array())
             .map(|arr| {
                 arr.iter()
                     .filter_map(|v| v.as_str().map(|s| s.to_string()))
                     .collect()
-            })
-            .unwrap_or_default();
+            });
 
         let wallet_address = args
             .get("wallet_address")
@@ -2081,6 +3081,46 @@ impl McpSdkAdapter {
             McpServerError::InvalidArguments(format!("Invalid contract address: {}", e))
         })?;
 
+        // Get EVM client and settlement progress
+        let (evm_client, _chain_id) = self.get_evm_client().await?;
+        let primary_sale = evm_client.primary_sale(contract_addr);
+
+        let (processed, total, _, is_complete) = primary_sale
+            .get_settlement_progress()
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        // Inline restricted wallets win; otherwise the listed ones in this batch
+        let (batch_size, restricted_wallets, restricted_wallets_source) =
+            match inline_restricted_wallets {
+                Some(wallets) => (batch_size, wallets, "inline"),
+                None if self.restricted_wallets.read().await.is_empty() => {
+                    (batch_size, Vec::new(), "none")
+                }
+                None => {
+                    let end = processed.saturating_add(U256::from(batch_size)).min(total);
+                    let (batch, ..) = primary_sale
+                        .get_investor_distribution_batch(processed, end)
+                        .await
+                        .map_err(McpServerError::Sdk)?;
+                    let batch: Vec<String> = batch
+                        .iter()
+                        .map(|investor| format!("{:#x}", investor))
+                        .collect();
+                    let plan = plan_settle_batch(&*self.restricted_wallets.read().await, &batch);
+                    let batch_size = if plan.batch_size < batch.len() {
+                        plan.batch_size as u64
+                    } else {
+                        batch_size
+                    };
+                    (
+                        batch_size,
+                        plan.restricted_wallets,
+                        "restricted_wallet_list",
+                    )
+                }
+            };
+
         // Parse restricted wallet addresses
         let restricted_addrs: Vec<Address> = restricted_wallets
             .iter()
@@ -2094,14 +3134,11 @@ impl McpSdkAdapter {
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        // Get EVM client and settlement progress
-        let (evm_client, _chain_id) = self.get_evm_client().await?;
-        let primary_sale = evm_client.primary_sale(contract_addr);
-
-        let (processed, total, _, is_complete) = primary_sale
-            .get_settlement_progress()
-            .await
-            .map_err(McpServerError::Sdk)?;
+        report_progress(
+            processed.saturating_to::<u64>(),
+            Some(total.saturating_to::<u64>()),
+            &format!("Settling up to {} more investors", batch_size),
+        );
 
         // Encode settleBatch call
         use crate::protocols::evm::contracts::primary_sale::IPrimarySale;
@@ -2137,6 +3174,8 @@ impl McpSdkAdapter {
             "caller": format!("{:#x}", from_addr),
             "batch_size": batch_size,
             "restricted_wallets_count": restricted_addrs.len(),
+            "restricted_wallets_source": restricted_wallets_source,
+            "restricted_wallets": restricted_wallets,
             "processed": processed.to_string(),
             "total": total.to_string(),
             "progress_percentage": format!("{:.2}", progress_percentage),
@@ -2703,7 +3742,7 @@ impl McpSdkAdapter {
         }
 
         // Get EVM client
-        let (evm_client, _chain_id) = self.get_evm_client().await?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
 
         // Get active wallet address for narrative context
         let active_wallet = match self.get_active_wallet().await {
@@ -2711,13 +3750,51 @@ impl McpSdkAdapter {
             _ => None,
         };
 
+        // Fetching counts as the first step, then one step per transaction
+        let total_steps = tx_hashes.len() as u64 + 1;
+        report_progress(
+            0,
+            Some(total_steps),
+            &format!("Fetching {} transactions and receipts", tx_hashes.len()),
+        );
+
         // Fetch transactions and receipts in parallel
         let transactions_results = evm_client.get_transactions_batch(&tx_hashes).await;
         let receipts_results = evm_client.get_transaction_receipts_batch(&tx_hashes).await;
 
+        // The interfaces of called contracts guide decoding, and those that are
+        // EIP-1967 proxies are named with their implementation
+        let called: std::collections::BTreeSet<Address> = transactions_results
+            .iter()
+            .filter_map(|result| result.as_ref().ok().and_then(|tx| tx.as_ref()))
+            .filter_map(|tx| tx.to)
+            .collect();
+        let mut contract_interfaces: HashMap<Address, Vec<ContractInterface>> = HashMap::new();
+        let mut proxy_implementations = HashMap::new();
+        for contract in called {
+            match self
+                .contract_inspection(&evm_client, chain_id, contract)
+                .await
+            {
+                Ok(inspection) => {
+                    if let Some(proxy) = &inspection.proxy {
+                        proxy_implementations.insert(contract, proxy.implementation);
+                    }
+                    contract_interfaces.insert(contract, inspection.interface_list());
+                }
+                Err(e) => debug!("Failed to inspect contract {:#x}: {}", contract, e),
+            }
+        }
+
         // Create decoder and narrative generator with EVM client for token metadata queries
         let decoder = TransactionDecoder::new();
-        let generator = NarrativeGenerator::new_with_client(active_wallet, evm_client.clone());
+        let generator = NarrativeGenerator::new_with_client(active_wallet, evm_client.clone())
+            .with_address_labels(self.evm_address_labels().await)
+            .with_proxy_implementations(proxy_implementations.clone());
+        let implementation_of = |to: Option<Address>| {
+            to.and_then(|to| proxy_implementations.get(&to))
+                .map(|implementation| format!("{:?}", implementation))
+        };
 
         // Process each transaction
         let mut narratives = Vec::new();
@@ -2725,6 +3802,12 @@ impl McpSdkAdapter {
         let mut errors = Vec::new();
 
         for (i, hash) in tx_hashes.iter().enumerate() {
+            report_progress(
+                i as u64 + 1,
+                Some(total_steps),
+                &format!("Decoding transaction {:#x}", hash),
+            );
+
             // Get transaction and receipt
             let tx_result = &transactions_results[i];
             let receipt_result = &receipts_results[i];
@@ -2823,7 +3906,12 @@ impl McpSdkAdapter {
                         continue;
                     }
 
-                    let decoded = match decoder.decode(input, to) {
+                    let interfaces_of_to = to
+                        .and_then(|to| contract_interfaces.get(&to))
+                        .map(Vec::as_slice)
+                        .unwrap_or_default();
+                    let decoded = match decoder.decode_with_interfaces(input, to, interfaces_of_to)
+                    {
                         Ok(d) => d,
                         Err(_) => {
                             // Failed to decode, create unknown transaction narrative
@@ -2848,10 +3936,16 @@ impl McpSdkAdapter {
                                 } else {
                                     to_str
                                 };
-                                format!(
-                                    "{} called contract at {} [tx: {}]",
-                                    from_abbrev, to_abbrev, hash_abbrev
-                                )
+                                match implementation_of(to) {
+                                    Some(implementation) => format!(
+                                        "{} called contract at {} (a proxy of implementation {}) [tx: {}]",
+                                        from_abbrev, to_abbrev, implementation, hash_abbrev
+                                    ),
+                                    None => format!(
+                                        "{} called contract at {} [tx: {}]",
+                                        from_abbrev, to_abbrev, hash_abbrev
+                                    ),
+                                }
                             } else {
                                 format!("{} deployed contract [tx: {}]", from_abbrev, hash_abbrev)
                             };
@@ -2861,6 +3955,7 @@ impl McpSdkAdapter {
                                 "hash": format!("{:?}", hash),
                                 "from": format!("{:?}", from),
                                 "to": to.map(|t| format!("{:?}", t)),
+                                "implementation": implementation_of(to),
                                 "success": success,
                                 "status": status,
                                 "decoded": false
@@ -2880,6 +3975,7 @@ impl McpSdkAdapter {
                         "hash": format!("{:?}", hash),
                         "from": format!("{:?}", from),
                         "to": to.map(|t| format!("{:?}", t)),
+                        "implementation": implementation_of(to),
                         "function": decoded.function_name,
                         "contract_type": format!("{:?}", decoded.contract_type),
                         "parameters": decoded.parameters,
@@ -2962,62 +4058,334 @@ impl McpSdkAdapter {
 
         Ok(response)
     }
-}
 
-/// Helper function to parse token amounts with proper decimals
-#[cfg(feature = "evm")]
-fn parse_units(amount: &str, decimals: u8) -> Result<alloy_primitives::U256, String> {
-    let parts: Vec<&str> = amount.split('.').collect();
+    // EVM Introspection
+    // =============================================================================
+
+    /// Get a block by number, tag or hash
+    ///
+    /// `block` is `latest` (the default), another tag such as `finalized`, a
+    /// block number in decimal or hex, or a block hash. With `full_transactions`
+    /// the sender, recipient, value and decoded function of each transaction
+    /// are included, otherwise only their hashes.
+    #[cfg(feature = "evm")]
+    pub async fn evm_get_block(
+        &self,
+        block: Option<&str>,
+        full_transactions: bool,
+    ) -> McpResult<EvmBlockResponse> {
+        let block = block.unwrap_or("latest");
+        let block_id = parse_block_id(block)?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let block = evm_client
+            .get_block(block_id, full_transactions)
+            .await
+            .map_err(McpServerError::Sdk)?
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments(format!("Block {} not found", block))
+            })?;
 
-    if parts.len() > 2 {
-        return Err("Invalid number format".to_string());
+        let decoder = TransactionDecoder::new();
+        let transactions: Vec<EvmBlockTransaction> = match &block.transactions {
+            BlockTransactions::Full(transactions) => transactions
+                .iter()
+                .map(|tx| EvmBlockTransaction {
+                    hash: format!("{:#x}", tx.hash),
+                    from: Some(format!("{:#x}", tx.from)),
+                    to: tx.to.map(|to| format!("{:#x}", to)),
+                    value: Some(tx.value.to_string()),
+                    function: decode_function(&decoder, tx.input.as_ref(), tx.to),
+                })
+                .collect(),
+            BlockTransactions::Hashes(hashes) => hashes
+                .iter()
+                .map(|hash| EvmBlockTransaction {
+                    hash: format!("{:#x}", hash),
+                    from: None,
+                    to: None,
+                    value: None,
+                    function: None,
+                })
+                .collect(),
+            BlockTransactions::Uncle => Vec::new(),
+        };
+
+        let header = &block.header;
+        Ok(EvmBlockResponse {
+            chain_id,
+            number: header.number,
+            hash: format!("{:#x}", header.hash),
+            parent_hash: format!("{:#x}", header.parent_hash),
+            timestamp: header.timestamp,
+            miner: format!("{:#x}", header.miner),
+            gas_used: header.gas_used.to_string(),
+            gas_limit: header.gas_limit.to_string(),
+            base_fee_per_gas: header.base_fee_per_gas.map(|fee| fee.to_string()),
+            transaction_count: transactions.len(),
+            transactions,
+        })
     }
 
-    let whole = parts[0]
-        .parse::<u128>()
-        .map_err(|e| format!("Invalid whole number: {}", e))?;
-    let multiplier = alloy_primitives::U256::from(10).pow(alloy_primitives::U256::from(decimals));
+    /// Get a transaction by hash with its receipt and decoded calldata
+    ///
+    /// A transaction without a receipt is still pending.
+    #[cfg(feature = "evm")]
+    pub async fn evm_get_transaction(&self, tx_hash: &str) -> McpResult<EvmTransactionResponse> {
+        let hash = B256::from_str(tx_hash).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid transaction hash: {}", e))
+        })?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let tx = evm_client
+            .get_transaction(hash)
+            .await
+            .map_err(McpServerError::Sdk)?
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments(format!("Transaction {:#x} not found", hash))
+            })?;
+        let receipt = evm_client
+            .get_transaction_receipt(hash)
+            .await
+            .map_err(McpServerError::Sdk)?;
 
-    let mut result = alloy_primitives::U256::from(whole) * multiplier;
+        let input = tx.input.as_ref();
+        let decoded = match tx.to {
+            Some(to) if input.len() >= 4 => {
+                let interfaces = match self.contract_inspection(&evm_client, chain_id, to).await {
+                    Ok(inspection) => inspection.interface_list(),
+                    Err(e) => {
+                        debug!("Failed to inspect contract {:#x}: {}", to, e);
+                        Vec::new()
+                    }
+                };
+                TransactionDecoder::new()
+                    .decode_with_interfaces(input, tx.to, &interfaces)
+                    .ok()
+            }
+            _ => None,
+        };
+        let status = match &receipt {
+            Some(receipt) if receipt.status() => "success",
+            Some(_) => "failed",
+            None => "pending",
+        };
 
-    if parts.len() == 2 {
-        let fractional = parts[1];
-        if fractional.len() > decimals as usize {
-            return Err(format!("Too many decimal places (max {})", decimals));
-        }
+        Ok(EvmTransactionResponse {
+            chain_id,
+            hash: format!("{:#x}", hash),
+            status: status.to_string(),
+            block_number: tx.block_number,
+            from: format!("{:#x}", tx.from),
+            to: tx.to.map(|to| format!("{:#x}", to)),
+            raw_value: tx.value.to_string(),
+            formatted_value: format_units(tx.value, 18),
+            nonce: tx.nonce,
+            gas_limit: tx.gas.to_string(),
+            gas_used: receipt.as_ref().map(|receipt| receipt.gas_used.to_string()),
+            effective_gas_price: receipt
+                .as_ref()
+                .map(|receipt| receipt.effective_gas_price.to_string()),
+            contract_address: receipt
+                .as_ref()
+                .and_then(|receipt| receipt.contract_address)
+                .map(|address| format!("{:#x}", address)),
+            input_size: input.len(),
+            decoded,
+            explorer_url: format!("https://mantrascan.io/dukong/tx/{:#x}", hash),
+        })
+    }
 
-        let fractional_value = fractional
-            .parse::<u128>()
-            .map_err(|e| format!("Invalid fractional part: {}", e))?;
-        let fractional_multiplier = alloy_primitives::U256::from(10).pow(
-            alloy_primitives::U256::from(decimals - fractional.len() as u8),
-        );
-        result += alloy_primitives::U256::from(fractional_value) * fractional_multiplier;
+    /// Get the bytecode deployed at `address`
+    ///
+    /// At the latest block, a contract that is an EIP-1967 proxy is reported
+    /// with the implementation it delegates to.
+    #[cfg(feature = "evm")]
+    pub async fn evm_get_code(
+        &self,
+        address: &str,
+        block: Option<&str>,
+    ) -> McpResult<EvmCodeResponse> {
+        let contract = Address::from_str(address)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid address: {}", e)))?;
+        let block = block.map(block_number_param).transpose()?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let code = evm_client
+            .get_code(EthAddress(contract), block.clone())
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let proxy = if block.is_none() && !code.is_empty() {
+            proxy::detect_proxy(&evm_client, contract)
+                .await
+                .map_err(McpServerError::Sdk)?
+        } else {
+            None
+        };
+
+        Ok(EvmCodeResponse {
+            chain_id,
+            address: format!("{:#x}", contract),
+            block: block.unwrap_or_else(|| "latest".to_string()),
+            is_contract: !code.is_empty(),
+            size_bytes: code.len(),
+            code: format!("{}", code),
+            proxy,
+        })
     }
 
-    Ok(result)
-}
+    /// Read storage `slot` of `address`
+    ///
+    /// The slot is a decimal or `0x` hex number.
+    #[cfg(feature = "evm")]
+    pub async fn evm_get_storage_at(
+        &self,
+        address: &str,
+        slot: &str,
+        block: Option<&str>,
+    ) -> McpResult<EvmStorageResponse> {
+        let contract = Address::from_str(address)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid address: {}", e)))?;
+        let slot = U256::from_str(slot.trim()).map_err(|e| {
+            McpServerError::InvalidArguments(format!("Invalid storage slot '{}': {}", slot, e))
+        })?;
+        let block = block.map(block_number_param).transpose()?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let value = evm_client
+            .get_storage_at(EthAddress(contract), slot, block.clone())
+            .await
+            .map_err(McpServerError::Sdk)?;
 
-/// Helper function to format token amounts with proper decimals
-#[cfg(feature = "evm")]
-fn format_units(value: alloy_primitives::U256, decimals: u8) -> String {
-    let divisor = alloy_primitives::U256::from(10).pow(alloy_primitives::U256::from(decimals));
-    let whole = value / divisor;
-    let remainder = value % divisor;
-
-    if remainder.is_zero() {
-        whole.to_string()
-    } else {
-        let remainder_str = remainder.to_string();
-        let padded_remainder = format!("{:0>width$}", remainder_str, width = decimals as usize);
-
-        // Remove trailing zeros
-        let trimmed = padded_remainder.trim_end_matches('0');
-        if trimmed.is_empty() {
-            whole.to_string()
+        Ok(EvmStorageResponse {
+            chain_id,
+            address: format!("{:#x}", contract),
+            slot: format!("{:#x}", slot),
+            block: block.unwrap_or_else(|| "latest".to_string()),
+            value: format!("{:#x}", B256::from(value)),
+            value_decimal: value.to_string(),
+        })
+    }
+
+    /// What the contract at `address` is: the interfaces it implements, the
+    /// implementation behind it when it is a proxy, and its token metadata
+    /// when it is an ERC-20 token
+    #[cfg(feature = "evm")]
+    pub async fn evm_inspect_contract(
+        &self,
+        address: &str,
+    ) -> McpResult<EvmInspectContractResponse> {
+        let contract = Address::from_str(address)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid address: {}", e)))?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let inspection = self
+            .contract_inspection(&evm_client, chain_id, contract)
+            .await?;
+
+        let token = if inspection.supports(ContractInterface::Erc20) {
+            match self
+                .ensure_token_metadata(&evm_client, chain_id, contract)
+                .await
+            {
+                Ok(info) => Some(token_view(&info)),
+                Err(e) => {
+                    debug!("Failed to read token metadata of {:#x}: {}", contract, e);
+                    None
+                }
+            }
         } else {
-            format!("{}.{}", whole, trimmed)
+            None
+        };
+
+        Ok(EvmInspectContractResponse {
+            chain_id,
+            address: format!("{:#x}", contract),
+            summary: inspection.summary(),
+            is_contract: inspection.is_contract,
+            interfaces: inspection.interfaces,
+            proxy: inspection.proxy,
+            code_address: format!("{:#x}", inspection.code_address),
+            code_size: inspection.code_size,
+            token,
+        })
+    }
+
+    /// Interfaces of the contract at `address`, kept in the adapter cache
+    ///
+    /// Entries expire with the cache TTL, since a proxy may be upgraded to an
+    /// implementation with other interfaces.
+    #[cfg(feature = "evm")]
+    pub(crate) async fn contract_inspection(
+        &self,
+        evm_client: &EvmClient,
+        chain_id: u64,
+        address: Address,
+    ) -> McpResult<ContractInspection> {
+        let cache_key = format!("interfaces:{}:{:#x}", chain_id, address);
+        if let Some(cached) = self.cache_get(&cache_key).await {
+            if let Ok(inspection) = serde_json::from_value(cached) {
+                return Ok(inspection);
+            }
         }
+
+        let inspection = interfaces::inspect_contract(evm_client, address)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        self.cache_set(cache_key, serde_json::to_value(&inspection)?)
+            .await;
+        Ok(inspection)
+    }
+
+    /// Chain ID, latest block, base fee and sync status of the EVM node
+    ///
+    /// The chain ID the node reports is compared against the configured one,
+    /// so a misconfigured RPC URL is caught before anything is signed for it.
+    #[cfg(feature = "evm")]
+    pub async fn evm_chain_info(&self) -> McpResult<EvmChainInfoResponse> {
+        let network_config = self.get_default_network_config().await?;
+        let (evm_client, chain_id) = self.get_evm_client().await?;
+        let node_chain_id = evm_client
+            .get_node_chain_id()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let (latest_block, latest_block_time) = evm_client
+            .get_latest_block_time()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let (base_fee, priority_fee) = evm_client
+            .get_fee_data()
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let sync = evm_client
+            .get_sync_status()
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let mut warnings = Vec::new();
+        if node_chain_id != chain_id {
+            warnings.push(format!(
+                "The node reports chain ID {} but {} is configured; check the EVM RPC URL",
+                node_chain_id, chain_id
+            ));
+        }
+        let block_age_seconds = chrono::Utc::now().timestamp() - latest_block_time;
+        if sync.syncing {
+            warnings.push("The node is still syncing; recent state may be missing".to_string());
+        }
+
+        Ok(EvmChainInfoResponse {
+            network: network_config.network_name.clone(),
+            rpc_url: network_config.evm_rpc_url.clone().unwrap_or_default(),
+            configured_chain_id: chain_id,
+            node_chain_id,
+            latest_block,
+            latest_block_time: chrono::DateTime::from_timestamp(latest_block_time, 0)
+                .map(|time| time.to_rfc3339())
+                .unwrap_or_default(),
+            block_age_seconds,
+            base_fee_per_gas: base_fee.to_string(),
+            priority_fee_per_gas: priority_fee.to_string(),
+            syncing: sync.syncing,
+            sync_current_block: sync.current_block,
+            sync_highest_block: sync.highest_block,
+            warnings,
+        })
     }
 }
 
@@ -3030,6 +4398,9 @@ pub struct Erc20TokenView {
     pub name: Option<String>,
     pub decimals: u8,
     pub source: String,
+    /// Implementation behind the token when its address is an EIP-1967 proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub implementation: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -3048,6 +4419,878 @@ pub struct EvmBalancesResponse {
     pub tokens: Vec<Erc20BalanceResponse>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct NativeEvmBalanceResponse {
+    pub cosmos_address: String,
+    pub evm_address: String,
+    pub symbol: String,
+    pub raw_balance: String,
+    pub formatted_balance: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Erc20TransferResponse {
+    pub token: Erc20TokenView,
+    pub cosmos_address: String,
+    pub from: String,
+    pub to: String,
+    pub raw_amount: String,
+    pub formatted_amount: String,
+    pub tx_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Erc20ApprovalResponse {
+    pub token: Erc20TokenView,
+    pub cosmos_address: String,
+    pub owner: String,
+    pub spender: String,
+    pub raw_amount: String,
+    pub formatted_amount: String,
+    /// Whether the approval is effectively unlimited
+    pub unlimited: bool,
+    pub tx_hash: String,
+    pub explorer_url: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Current allowance of one spender
+#[derive(Debug, Clone, Serialize)]
+pub struct Erc20AllowanceEntry {
+    pub token: Erc20TokenView,
+    pub spender: String,
+    pub raw_allowance: String,
+    pub formatted_allowance: String,
+    pub unlimited: bool,
+    /// Block of the latest `Approval` event of this spender
+    pub last_approved_block: Option<u64>,
+    pub last_approval_tx: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Erc20AllowancesResponse {
+    pub cosmos_address: String,
+    pub owner: String,
+    /// Blocks scanned for `Approval` events
+    pub from_block: u64,
+    pub to_block: u64,
+    pub allowances: Vec<Erc20AllowanceEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Erc20RevokeResponse {
+    pub token: Erc20TokenView,
+    pub cosmos_address: String,
+    pub owner: String,
+    pub spender: String,
+    pub previous_raw_allowance: String,
+    pub previous_formatted_allowance: String,
+    /// Whether a transaction was sent; false when nothing was approved
+    pub revoked: bool,
+    pub tx_hash: Option<String>,
+    pub explorer_url: Option<String>,
+}
+
+/// Transaction of a block; only the hash unless full transactions were asked for
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmBlockTransaction {
+    pub hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    /// Value in wei
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Decoded function name, when the selector is known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmBlockResponse {
+    pub chain_id: u64,
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+    /// Unix seconds
+    pub timestamp: u64,
+    pub miner: String,
+    pub gas_used: String,
+    pub gas_limit: String,
+    pub base_fee_per_gas: Option<String>,
+    pub transaction_count: usize,
+    pub transactions: Vec<EvmBlockTransaction>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmTransactionResponse {
+    pub chain_id: u64,
+    pub hash: String,
+    /// `success`, `failed` or `pending`
+    pub status: String,
+    pub block_number: Option<u64>,
+    pub from: String,
+    /// None for a contract creation
+    pub to: Option<String>,
+    pub raw_value: String,
+    pub formatted_value: String,
+    pub nonce: u64,
+    pub gas_limit: String,
+    pub gas_used: Option<String>,
+    pub effective_gas_price: Option<String>,
+    /// Contract deployed by the transaction
+    pub contract_address: Option<String>,
+    /// Calldata length in bytes
+    pub input_size: usize,
+    pub decoded: Option<DecodedCall>,
+    pub explorer_url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmCodeResponse {
+    pub chain_id: u64,
+    pub address: String,
+    pub block: String,
+    /// Whether any bytecode is deployed at the address
+    pub is_contract: bool,
+    pub size_bytes: usize,
+    /// Bytecode as 0x hex
+    pub code: String,
+    /// Implementation behind the contract when it is an EIP-1967 proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmStorageResponse {
+    pub chain_id: u64,
+    pub address: String,
+    pub slot: String,
+    pub block: String,
+    /// 32-byte word as 0x hex
+    pub value: String,
+    pub value_decimal: String,
+}
+
+/// Result of `evm_inspect_contract`
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmInspectContractResponse {
+    pub chain_id: u64,
+    pub address: String,
+    /// What the address is, in one line
+    pub summary: String,
+    /// Whether any bytecode is deployed at the address
+    pub is_contract: bool,
+    pub interfaces: Vec<DetectedInterface>,
+    /// Implementation behind the contract when it is an EIP-1967 proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyInfo>,
+    /// Contract whose bytecode was searched for function selectors
+    pub code_address: String,
+    pub code_size: usize,
+    /// Token metadata when the contract is an ERC-20 token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<Erc20TokenView>,
+}
+
+/// Result of `primary_sale_check_eligibility`
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleEligibilityResponse {
+    pub contract_address: String,
+    pub investor: String,
+    pub token: String,
+    /// Whether the investment meets every requirement
+    pub eligible: bool,
+    pub issues: Vec<PrimarySaleEligibilityIssue>,
+    pub sale_status: String,
+    pub amount: String,
+    pub amount_raw: String,
+    /// Amount in the sale's 18-decimal units
+    pub amount_normalized: String,
+    pub balance: String,
+    pub allowance: String,
+    /// Normalized amount left before the hard cap; none without a hard cap
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_capacity: Option<String>,
+    /// Normalized amount investments must be a multiple of
+    pub min_step: String,
+    pub is_allowlisted: bool,
+    pub token_accepted: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleEligibilityIssue {
+    /// e.g. `not_allowlisted` or `insufficient_allowance`
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+/// Result of `primary_sale_project_settlement`
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleSettlementProjectionResponse {
+    pub contract_address: String,
+    pub sale_status: String,
+    pub soft_cap: String,
+    pub total_contributed: String,
+    /// Whether the sale would settle; below the soft cap it fails and
+    /// refunds everyone
+    pub soft_cap_met: bool,
+    pub commission_bps: u16,
+    pub tokens: Vec<PrimarySaleTokenProceeds>,
+    pub investors_distributed: usize,
+    pub investors_refunded: usize,
+    /// Raw asset token amounts
+    pub tokens_distributed: String,
+    pub tokens_returned_to_issuer: String,
+    pub investors: Vec<PrimarySaleInvestorProjection>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Projected proceeds of one payment token
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleTokenProceeds {
+    pub token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    pub total_contributed: String,
+    pub refunded: String,
+    pub net: String,
+    pub commission_to_mantra: String,
+    pub proceeds_to_issuer: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleInvestorProjection {
+    pub address: String,
+    /// `distribute`, `refund_kyc_delisted`, `refund_restricted` or
+    /// `refund_sale_failed`
+    pub outcome: String,
+    pub tokens_allocated: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub refunds_by_token: BTreeMap<String, String>,
+}
+
+/// Result of `restricted_wallets_validate`
+#[derive(Debug, Clone, Serialize)]
+pub struct RestrictedWalletValidationResponse {
+    pub contract_address: String,
+    /// Wallets on the restricted wallet list
+    pub listed: usize,
+    pub investor_count: usize,
+    /// Listed wallets that invested; settlement refunds them to the multisig
+    pub restricted_investors: Vec<String>,
+    /// Listed wallets that did not invest in this sale
+    pub not_investors: usize,
+    /// Restricted investors settlement has already processed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub already_settled: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Result of `primary_sale_export_investors`
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleExportResponse {
+    pub contract_address: String,
+    pub investor_count: usize,
+    /// Investors as JSON, unless CSV was asked for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investors: Option<Vec<PrimarySaleInvestorRow>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<PrimarySaleExportFailure>,
+    pub reconciliation: PrimarySaleReconciliationView,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleInvestorRow {
+    pub address: String,
+    /// 18 decimals, summed over all tokens
+    pub contribution_normalized: String,
+    pub contribution: String,
+    /// Raw amount in each token's own decimals
+    pub contributions_by_token: BTreeMap<String, String>,
+    pub tokens_allocated: String,
+    pub is_kyc_approved: bool,
+    pub has_received_settlement: bool,
+    pub has_claimed_refund: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleExportFailure {
+    pub address: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleReconciliationView {
+    /// Whether every investor was exported and every sum matches its total
+    pub balanced: bool,
+    pub investor_count: String,
+    pub investors_exported: usize,
+    pub investors_failed: usize,
+    pub sum_contributions_normalized: String,
+    pub total_contributed_normalized: String,
+    pub sum_contributions: String,
+    pub total_contributed: String,
+    /// Total less the investors' sum, in 18 decimals
+    pub difference_normalized: String,
+    pub tokens: Vec<PrimarySaleTokenReconciliationView>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimarySaleTokenReconciliationView {
+    pub token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    pub investor_sum: String,
+    pub contract_total: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investor_sum_formatted: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_total_formatted: Option<String>,
+    pub matches: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EvmChainInfoResponse {
+    pub network: String,
+    pub rpc_url: String,
+    pub configured_chain_id: u64,
+    /// Chain ID reported by the node
+    pub node_chain_id: u64,
+    pub latest_block: u64,
+    pub latest_block_time: String,
+    /// Seconds since the latest block
+    pub block_age_seconds: i64,
+    pub base_fee_per_gas: String,
+    pub priority_fee_per_gas: String,
+    pub syncing: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_current_block: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_highest_block: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl MarkdownRender for NativeEvmBalanceResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔍 **Native EVM Balance**\n\n".to_string();
+        response.push_str(&format!("**Cosmos Address:** `{}`\n", self.cosmos_address));
+        response.push_str(&format!("**EVM Address:** `{}`\n", self.evm_address));
+        response.push_str(&format!(
+            "**Balance:** {} {}\n",
+            self.formatted_balance, self.symbol
+        ));
+        response
+    }
+}
+
+impl MarkdownRender for Erc20BalanceResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔍 **ERC-20 Token Balance**\n\n".to_string();
+        response.push_str(&format!("**Token:** {}\n", self.token.symbol));
+        if let Some(name) = &self.token.name {
+            response.push_str(&format!("**Name:** {}\n", name));
+        }
+        response.push_str(&format!("**Contract:** `{}`\n", self.token.address));
+        response.push_str(&format!("**Decimals:** {}\n\n", self.token.decimals));
+        response.push_str(&format!("**Wallet:** `{}`\n", self.wallet_address));
+        response.push_str(&format!(
+            "**Balance:** {} {}\n",
+            self.formatted_balance, self.token.symbol
+        ));
+        response.push_str(&format!("**Raw Balance:** {}\n", self.raw_balance));
+        response
+    }
+}
+
+impl MarkdownRender for EvmBalancesResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔍 **EVM Balances**\n\n".to_string();
+        response.push_str(&format!("**Cosmos Address:** `{}`\n", self.cosmos_address));
+        response.push_str(&format!("**EVM Address:** `{}`\n", self.evm_address));
+        response.push_str(&format!("**Native Balance:** {} OM\n", self.native_balance));
+        if !self.tokens.is_empty() {
+            response.push_str("\n### 💰 ERC-20 Tokens:\n\n");
+            for token in &self.tokens {
+                response.push_str(&format!(
+                    "- **{}**: {}\n",
+                    token.token.symbol, token.formatted_balance
+                ));
+                response.push_str(&format!("  - **Contract:** `{}`\n", token.token.address));
+            }
+        }
+        response
+    }
+}
+
+impl MarkdownRender for Erc20TransferResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "✅ **ERC-20 Transfer Submitted**\n\n".to_string();
+        response.push_str(&format!(
+            "**Token:** {} ({})\n",
+            self.token.symbol,
+            self.token.name.as_deref().unwrap_or("Unknown")
+        ));
+        response.push_str(&format!("**Contract:** `{}`\n", self.token.address));
+        response.push_str(&format!(
+            "**From:** `{}` (Cosmos: `{}`)\n",
+            self.from, self.cosmos_address
+        ));
+        response.push_str(&format!("**To:** `{}`\n", self.to));
+        response.push_str(&format!(
+            "**Amount:** {} {}\n",
+            self.formatted_amount, self.token.symbol
+        ));
+        response.push_str(&format!("**Transaction Hash:** `{}`\n", self.tx_hash));
+        response
+    }
+}
+
+impl MarkdownRender for Erc20ApprovalResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "✅ **ERC-20 Approval Submitted**\n\n".to_string();
+        response.push_str(&format!(
+            "**Token:** {} ({})\n",
+            self.token.symbol,
+            self.token.name.as_deref().unwrap_or("Unknown")
+        ));
+        response.push_str(&format!("**Contract:** `{}`\n", self.token.address));
+        response.push_str(&format!(
+            "**Owner:** `{}` (Cosmos: `{}`)\n",
+            self.owner, self.cosmos_address
+        ));
+        response.push_str(&format!("**Spender:** `{}`\n", self.spender));
+        if self.unlimited {
+            response.push_str(&format!("**Amount:** unlimited {}\n", self.token.symbol));
+        } else {
+            response.push_str(&format!(
+                "**Amount:** {} {}\n",
+                self.formatted_amount, self.token.symbol
+            ));
+        }
+        response.push_str(&format!("**Transaction Hash:** `{}`\n", self.tx_hash));
+        response.push_str(&format!("\n**Explorer:** {}\n", self.explorer_url));
+        for warning in &self.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for Erc20AllowancesResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔐 **ERC-20 Allowances**\n\n".to_string();
+        response.push_str(&format!(
+            "**Owner:** `{}` (Cosmos: `{}`)\n",
+            self.owner, self.cosmos_address
+        ));
+        response.push_str(&format!(
+            "**Blocks scanned:** {} to {}\n\n",
+            self.from_block, self.to_block
+        ));
+        if self.allowances.is_empty() {
+            response.push_str("No open allowances.\n");
+        }
+        for entry in &self.allowances {
+            let amount = if entry.unlimited {
+                "unlimited".to_string()
+            } else {
+                entry.formatted_allowance.clone()
+            };
+            response.push_str(&format!(
+                "- **{}** `{}`: {} to `{}`\n",
+                entry.token.symbol, entry.token.address, amount, entry.spender
+            ));
+        }
+        for warning in &self.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for Erc20RevokeResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = if self.revoked {
+            "✅ **ERC-20 Approval Revoked**\n\n".to_string()
+        } else {
+            "ℹ️ **Nothing To Revoke**\n\n".to_string()
+        };
+        response.push_str(&format!(
+            "**Token:** {} (`{}`)\n",
+            self.token.symbol, self.token.address
+        ));
+        response.push_str(&format!("**Owner:** `{}`\n", self.owner));
+        response.push_str(&format!("**Spender:** `{}`\n", self.spender));
+        response.push_str(&format!(
+            "**Previous Allowance:** {} {}\n",
+            self.previous_formatted_allowance, self.token.symbol
+        ));
+        if let (Some(tx_hash), Some(explorer_url)) = (&self.tx_hash, &self.explorer_url) {
+            response.push_str(&format!("**Transaction Hash:** `{}`\n", tx_hash));
+            response.push_str(&format!("\n**Explorer:** {}\n", explorer_url));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for EvmBlockResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!("🧱 **EVM Block {}**\n\n", self.number);
+        response.push_str(&format!("**Hash:** `{}`\n", self.hash));
+        response.push_str(&format!("**Parent:** `{}`\n", self.parent_hash));
+        response.push_str(&format!(
+            "**Time:** {}\n",
+            chrono::DateTime::from_timestamp(self.timestamp as i64, 0)
+                .map(|time| time.to_rfc3339())
+                .unwrap_or_else(|| self.timestamp.to_string())
+        ));
+        response.push_str(&format!("**Miner:** `{}`\n", self.miner));
+        response.push_str(&format!(
+            "**Gas:** {} of {}\n",
+            self.gas_used, self.gas_limit
+        ));
+        if let Some(base_fee) = &self.base_fee_per_gas {
+            response.push_str(&format!("**Base Fee:** {} wei\n", base_fee));
+        }
+        response.push_str(&format!(
+            "\n**Transactions ({}):**\n",
+            self.transaction_count
+        ));
+        for tx in &self.transactions {
+            response.push_str(&format!("- `{}`", tx.hash));
+            if let Some(function) = &tx.function {
+                response.push_str(&format!(" {}", function));
+            }
+            if let (Some(from), Some(to)) = (&tx.from, &tx.to) {
+                response.push_str(&format!(" `{}` → `{}`", from, to));
+            }
+            response.push('\n');
+        }
+        response
+    }
+}
+
+impl MarkdownRender for EvmTransactionResponse {
+    fn to_markdown(&self) -> String {
+        let icon = match self.status.as_str() {
+            "success" => "✅",
+            "failed" => "❌",
+            _ => "⏳",
+        };
+        let mut response = format!("{} **EVM Transaction ({})**\n\n", icon, self.status);
+        response.push_str(&format!("**Hash:** `{}`\n", self.hash));
+        if let Some(block_number) = self.block_number {
+            response.push_str(&format!("**Block:** {}\n", block_number));
+        }
+        response.push_str(&format!("**From:** `{}`\n", self.from));
+        match (&self.to, &self.contract_address) {
+            (Some(to), _) => response.push_str(&format!("**To:** `{}`\n", to)),
+            (None, Some(contract)) => response.push_str(&format!("**Deployed:** `{}`\n", contract)),
+            (None, None) => response.push_str("**To:** contract creation\n"),
+        }
+        response.push_str(&format!("**Value:** {} OM\n", self.formatted_value));
+        response.push_str(&format!("**Nonce:** {}\n", self.nonce));
+        if let Some(gas_used) = &self.gas_used {
+            response.push_str(&format!(
+                "**Gas Used:** {} of {}\n",
+                gas_used, self.gas_limit
+            ));
+        }
+        if let Some(decoded) = &self.decoded {
+            response.push_str(&format!(
+                "\n**Call:** {} (`{}`)\n```json\n{}\n```\n",
+                decoded.function_name,
+                decoded.selector,
+                serde_json::to_string_pretty(&decoded.parameters).unwrap_or_default()
+            ));
+        }
+        response.push_str(&format!("\n**Explorer:** {}\n", self.explorer_url));
+        response
+    }
+}
+
+impl MarkdownRender for EvmCodeResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "📜 **EVM Code**\n\n".to_string();
+        response.push_str(&format!("**Address:** `{}`\n", self.address));
+        response.push_str(&format!("**Block:** {}\n", self.block));
+        if self.is_contract {
+            response.push_str(&format!("**Contract:** {} bytes\n", self.size_bytes));
+            if let Some(proxy) = &self.proxy {
+                let kind = match proxy.kind {
+                    ProxyKind::Eip1967 => "EIP-1967 proxy",
+                    ProxyKind::Eip1967Beacon => "EIP-1967 beacon proxy",
+                };
+                response.push_str(&format!(
+                    "**Proxy:** {} of implementation `{:#x}`\n",
+                    kind, proxy.implementation
+                ));
+                if let Some(beacon) = proxy.beacon {
+                    response.push_str(&format!("**Beacon:** `{:#x}`\n", beacon));
+                }
+                if let Some(admin) = proxy.admin {
+                    response.push_str(&format!("**Proxy admin:** `{:#x}`\n", admin));
+                }
+            }
+        } else {
+            response.push_str("No code deployed; this is an externally owned account.\n");
+        }
+        response
+    }
+}
+
+impl MarkdownRender for EvmInspectContractResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔎 **Contract Inspection**\n\n".to_string();
+        response.push_str(&format!("**Address:** `{}`\n", self.address));
+        response.push_str(&format!("**What it is:** {}\n", self.summary));
+        if !self.is_contract {
+            return response;
+        }
+        if let Some(proxy) = &self.proxy {
+            response.push_str(&format!(
+                "**Implementation:** `{:#x}`\n",
+                proxy.implementation
+            ));
+        }
+        if let Some(token) = &self.token {
+            response.push_str(&format!(
+                "**Token:** {} ({} decimals)\n",
+                token.symbol, token.decimals
+            ));
+        }
+        if self.interfaces.is_empty() {
+            response.push_str("\nNo known interface detected.\n");
+        } else {
+            response.push_str("\n**Interfaces:**\n");
+            for detected in &self.interfaces {
+                let source = match detected.source {
+                    interfaces::DetectionSource::Erc165 => "declared via ERC-165",
+                    interfaces::DetectionSource::Bytecode => "found in bytecode",
+                };
+                response.push_str(&format!("- {} ({})\n", detected.interface.name(), source));
+            }
+        }
+        response
+    }
+}
+
+impl MarkdownRender for EvmStorageResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🗄️ **EVM Storage**\n\n".to_string();
+        response.push_str(&format!("**Address:** `{}`\n", self.address));
+        response.push_str(&format!("**Slot:** `{}`\n", self.slot));
+        response.push_str(&format!("**Block:** {}\n", self.block));
+        response.push_str(&format!(
+            "**Value:** `{}` ({})\n",
+            self.value, self.value_decimal
+        ));
+        response
+    }
+}
+
+impl MarkdownRender for EvmChainInfoResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "⛓️ **EVM Chain Info**\n\n".to_string();
+        response.push_str(&format!("**Network:** {}\n", self.network));
+        response.push_str(&format!("**RPC:** {}\n", self.rpc_url));
+        response.push_str(&format!(
+            "**Chain ID:** {} (configured {})\n",
+            self.node_chain_id, self.configured_chain_id
+        ));
+        response.push_str(&format!(
+            "**Latest Block:** {} at {} ({}s ago)\n",
+            self.latest_block, self.latest_block_time, self.block_age_seconds
+        ));
+        response.push_str(&format!(
+            "**Base Fee:** {} wei (priority {} wei)\n",
+            self.base_fee_per_gas, self.priority_fee_per_gas
+        ));
+        match (
+            self.syncing,
+            self.sync_current_block,
+            self.sync_highest_block,
+        ) {
+            (false, _, _) => response.push_str("**Sync:** caught up\n"),
+            (true, Some(current), Some(highest)) => response.push_str(&format!(
+                "**Sync:** syncing, block {} of {}\n",
+                current, highest
+            )),
+            (true, _, _) => response.push_str("**Sync:** syncing\n"),
+        }
+        for warning in &self.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for PrimarySaleEligibilityResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = if self.eligible {
+            "✅ **Eligible to Invest**\n\n".to_string()
+        } else {
+            format!(
+                "❌ **Not Eligible to Invest** ({} issues)\n\n",
+                self.issues.len()
+            )
+        };
+        response.push_str(&format!(
+            "**Sale:** `{}` ({})\n",
+            self.contract_address, self.sale_status
+        ));
+        response.push_str(&format!("**Investor:** `{}`\n", self.investor));
+        response.push_str(&format!(
+            "**Amount:** {} of `{}` (balance {}, allowance {})\n",
+            self.amount, self.token, self.balance, self.allowance
+        ));
+        for issue in &self.issues {
+            response.push_str(&format!("\n- **{}**: {}", issue.code, issue.message));
+            if let Some(fix) = &issue.fix {
+                response.push_str(&format!("\n  → {}", fix));
+            }
+        }
+        response.push('\n');
+        response
+    }
+}
+
+impl MarkdownRender for PrimarySaleSettlementProjectionResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!(
+            "🧮 **Settlement Projection** `{}` ({})\n\n",
+            self.contract_address, self.sale_status
+        );
+        response.push_str(&format!(
+            "**Raised:** {} of a {} soft cap{}\n",
+            self.total_contributed,
+            self.soft_cap,
+            if self.soft_cap_met {
+                ""
+            } else {
+                " — the sale would fail and refund everyone"
+            }
+        ));
+        response.push_str(&format!(
+            "**Investors:** {} receive tokens, {} refunded\n",
+            self.investors_distributed, self.investors_refunded
+        ));
+        response.push_str(&format!(
+            "**Asset tokens:** {} distributed, {} returned to the issuer\n\n",
+            self.tokens_distributed, self.tokens_returned_to_issuer
+        ));
+        response.push_str(&format!(
+            "| Token | Contributed | Refunded | Commission ({} bps) | To issuer |\n|---|---|---|---|---|\n",
+            self.commission_bps
+        ));
+        for token in &self.tokens {
+            response.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                token.symbol.as_deref().unwrap_or(&token.token),
+                token.total_contributed,
+                token.refunded,
+                token.commission_to_mantra,
+                token.proceeds_to_issuer
+            ));
+        }
+        for warning in &self.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for RestrictedWalletValidationResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!(
+            "🚫 **Restricted Wallets** vs `{}`\n\n**Listed:** {}\n**Investors:** {}\n**Restricted investors:** {}\n**Listed but not investors:** {}\n",
+            self.contract_address,
+            self.listed,
+            self.investor_count,
+            self.restricted_investors.len(),
+            self.not_investors
+        );
+        for investor in &self.restricted_investors {
+            let settled = if self.already_settled.contains(investor) {
+                " (already settled)"
+            } else {
+                ""
+            };
+            response.push_str(&format!("- `{}`{}\n", investor, settled));
+        }
+        for warning in &self.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for PrimarySaleExportResponse {
+    fn to_markdown(&self) -> String {
+        let recon = &self.reconciliation;
+        let mut response = format!(
+            "📋 **PrimarySale Investor Export** `{}`\n\n",
+            self.contract_address
+        );
+        response.push_str(&format!(
+            "**Investors:** {} exported of {}",
+            recon.investors_exported, recon.investor_count
+        ));
+        if recon.investors_failed > 0 {
+            response.push_str(&format!(", {} failed", recon.investors_failed));
+        }
+        response.push_str(&format!(
+            "\n**Contributions:** {} summed, {} on the contract (difference {} in 18-decimal units)\n",
+            recon.sum_contributions, recon.total_contributed, recon.difference_normalized
+        ));
+        for token in &recon.tokens {
+            response.push_str(&format!(
+                "- {} {}: {} summed, {} on the contract\n",
+                if token.matches { "✅" } else { "❌" },
+                token.symbol.as_deref().unwrap_or(&token.token),
+                token
+                    .investor_sum_formatted
+                    .as_deref()
+                    .unwrap_or(&token.investor_sum),
+                token
+                    .contract_total_formatted
+                    .as_deref()
+                    .unwrap_or(&token.contract_total)
+            ));
+        }
+        response.push_str(if recon.balanced {
+            "\n✅ **Reconciled**\n"
+        } else {
+            "\n❌ **Not reconciled**\n"
+        });
+        for failure in &self.failed {
+            response.push_str(&format!("\n- `{}`: {}", failure.address, failure.error));
+        }
+        if let Some(csv) = &self.csv {
+            response.push_str(&format!("\n```csv\n{}```\n", csv));
+        }
+        for warning in &self.warnings {
+            response.push_str(&format!("\n⚠️ {}\n", warning));
+        }
+        response
+    }
+}
+
 #[cfg(feature = "evm")]
 fn token_source_label(source: &TokenSource) -> &'static str {
     match source {
@@ -3066,7 +5309,65 @@ fn token_view(info: &Erc20TokenInfo) -> Erc20TokenView {
         name: info.name.clone(),
         decimals: info.decimals,
         source: token_source_label(&info.source).to_string(),
+        implementation: info
+            .implementation
+            .map(|implementation| format!("{:#x}", implementation)),
+    }
+}
+
+/// Block number, tag or hash of an introspection tool argument
+#[cfg(feature = "evm")]
+fn parse_block_id(block: &str) -> McpResult<BlockId> {
+    let block = block.trim();
+    if block.len() == 66 && block.starts_with("0x") {
+        let hash = B256::from_str(block)
+            .map_err(|e| McpServerError::InvalidArguments(format!("Invalid block hash: {}", e)))?;
+        return Ok(BlockId::from(hash));
+    }
+    Ok(BlockId::Number(parse_block_number(block)?))
+}
+
+/// Block number or tag, where numbers may be decimal or `0x` hex
+#[cfg(feature = "evm")]
+fn parse_block_number(block: &str) -> McpResult<BlockNumberOrTag> {
+    let block = block.trim();
+    if let Ok(number) = block.parse::<u64>() {
+        return Ok(BlockNumberOrTag::Number(number));
+    }
+    block.parse::<BlockNumberOrTag>().map_err(|_| {
+        McpServerError::InvalidArguments(format!(
+            "Invalid block '{}': expected a number, a block hash, or latest, pending, earliest, safe or finalized",
+            block
+        ))
+    })
+}
+
+/// Block argument as the hex number or tag `eth_getCode` and
+/// `eth_getStorageAt` take
+#[cfg(feature = "evm")]
+fn block_number_param(block: &str) -> McpResult<String> {
+    // Numbers display as hex, tags by name
+    Ok(parse_block_number(block)?.to_string())
+}
+
+/// Name of the function `input` calls, when its selector is known
+#[cfg(feature = "evm")]
+fn decode_function(
+    decoder: &TransactionDecoder,
+    input: &[u8],
+    to: Option<Address>,
+) -> Option<String> {
+    if to.is_none() {
+        return Some("contract creation".to_string());
+    }
+    if input.len() < 4 {
+        return None;
     }
+    decoder
+        .decode(input, to)
+        .ok()
+        .filter(|decoded| decoded.function_name != "unknown")
+        .map(|decoded| decoded.function_name)
 }
 
 #[cfg(test)]
@@ -3104,4 +5405,25 @@ mod tests {
         let value = alloy_primitives::U256::from(1u128); // 0.000000000000000001 ether
         assert_eq!(format_units(value, 18), "0.000000000000000001");
     }
+
+    #[test]
+    fn test_parse_block_arguments() {
+        assert_eq!(
+            parse_block_id("latest").unwrap(),
+            BlockId::Number(BlockNumberOrTag::Latest)
+        );
+        assert_eq!(
+            parse_block_id("1024").unwrap(),
+            BlockId::Number(BlockNumberOrTag::Number(1024))
+        );
+        assert_eq!(block_number_param("1024").unwrap(), "0x400");
+        assert_eq!(block_number_param("0x400").unwrap(), "0x400");
+        assert_eq!(block_number_param("finalized").unwrap(), "finalized");
+        let hash = format!("{:#x}", B256::repeat_byte(0xab));
+        assert_eq!(
+            parse_block_id(&hash).unwrap(),
+            BlockId::from(B256::repeat_byte(0xab))
+        );
+        assert!(parse_block_id("yesterday").is_err());
+    }
 }
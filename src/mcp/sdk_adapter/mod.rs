@@ -4,7 +4,8 @@
 //! It handles async runtime integration, error mapping, connection pooling, and provides
 //! an MCP-friendly interface to the underlying SDK functionality.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -19,25 +20,77 @@ use tracing::{debug, error, info, warn};
 
 use crate::client::MantraClient;
 use crate::config::MantraNetworkConfig;
+use crate::denom_metadata::DenomMetadataService;
+use crate::endpoints::{CircuitBreakerConfig, EndpointHealth, EndpointPool};
+use crate::performance::ConfirmationTracker;
+use crate::protocols::dex::client::DEFAULT_TIMEOUT_BLOCKS;
+use crate::protocols::dex::dust::DustPolicy;
 use crate::protocols::dex::MantraDexClient;
+use crate::protocols::evm::allowance::InfiniteApprovalPolicy;
 use crate::protocols::evm::client::EvmClient;
 use crate::protocols::evm::contracts::Erc20 as SdkErc20;
-use crate::wallet::{MantraWallet, MultiVMWallet, WalletInfo};
+use crate::protocols::evm::log_cache::LogCache;
+use crate::protocols::skip::{SkipAffiliateConfig, SkipClient};
+use crate::retry::RetryPolicy;
+use crate::tx_tag::TxTagging;
+use crate::wallet::{
+    AddressBook, DerivationOptions, MantraWallet, MultiVMWallet, RestrictedWalletList, WalletInfo,
+};
 use alloy_primitives::{Address, U256};
 
-use super::erc20_registry::{Erc20Registry, Erc20TokenInfo, TokenSource};
+use super::cache::{AdapterCache, CacheConfig};
+use super::erc20_registry::{Erc20Registry, Erc20TokenInfo, TokenKey, TokenSource};
+use super::metrics::{McpMetrics, PoolGauges};
+use super::progress::{report_partial_result, report_progress};
 
 use super::server::{McpResult, McpServerError};
+use super::state_store::{EventStore, StateEvent};
 
 // Module declarations - methods are added to McpSdkAdapter via impl blocks
+mod activity;
+mod address_book;
+mod assets;
 mod claimdrop;
 mod dex;
+mod erc20_refresh;
 #[cfg(feature = "evm")]
 mod evm;
 mod network;
+mod prefetch;
+mod resources;
+mod restricted_wallets;
+mod session;
 mod skip;
+mod state;
+mod tx_queue;
 mod wallet;
 
+pub use activity::WalletActivityResponse;
+pub use address_book::{AddressBookChangeResponse, AddressBookResponse};
+use erc20_refresh::read_code_fingerprint;
+pub use erc20_refresh::Erc20RefreshReport;
+pub use network::{MultiNetworkPortfolioResponse, SwitchNetworkResponse};
+pub use prefetch::PrefetchReport;
+pub use restricted_wallets::{
+    RestrictedWalletChangeResponse, RestrictedWalletImportResponse, RestrictedWalletListResponse,
+};
+use session::SessionContext;
+pub use wallet::{
+    ActiveWalletResponse, TokenBalance, WalletAddedResponse, WalletAddressesResponse,
+    WalletBalancesResponse, WalletDiscoveryResponse, WalletEntry, WalletListResponse,
+    WalletRemovedResponse, WalletSwitchResponse,
+};
+
+tokio::task_local! {
+    /// Set while a dry-run tool call is dispatched
+    static DRY_RUN: bool;
+    /// Memo the transactions of the tool call being dispatched carry
+    static MEMO: String;
+    /// Blocks the Cosmos transactions of the tool call being dispatched are
+    /// valid for
+    static TIMEOUT_BLOCKS: u64;
+}
+
 /// Configuration for connection pooling
 #[derive(Debug, Clone)]
 pub struct ConnectionPoolConfig {
@@ -57,6 +110,11 @@ pub struct ConnectionPoolConfig {
     pub health_check_interval_secs: u64,
     /// Maximum derivation index to search when looking for wallets
     pub max_wallet_derivation_index: u32,
+    /// Minimum endpoint quality score (see [`ConfirmationTracker::score`]) for
+    /// pooled connections to stay healthy
+    pub min_endpoint_score: f64,
+    /// When an RPC endpoint's circuit opens and for how long
+    pub circuit_breaker: CircuitBreakerConfig,
 }
 
 impl Default for ConnectionPoolConfig {
@@ -70,6 +128,8 @@ impl Default for ConnectionPoolConfig {
             max_idle_time_secs: 60,           // 1 minute
             health_check_interval_secs: 30,   // 30 seconds
             max_wallet_derivation_index: 100, // Search up to index 100
+            min_endpoint_score: 0.5,
+            circuit_breaker: CircuitBreakerConfig::default(),
         }
     }
 }
@@ -166,6 +226,8 @@ pub(crate) struct NetworkConnectionPool {
     creation_semaphore: Semaphore,
     /// Pool configuration
     config: ConnectionPoolConfig,
+    /// Circuit breakers of the network's RPC endpoints, shared by its clients
+    endpoints: Arc<EndpointPool>,
 }
 
 impl NetworkConnectionPool {
@@ -174,6 +236,10 @@ impl NetworkConnectionPool {
         Self {
             connections: Vec::new(),
             creation_semaphore: Semaphore::new(config.max_connections_per_network),
+            endpoints: Arc::new(EndpointPool::new(
+                network_config.rpc_urls(),
+                config.circuit_breaker.clone(),
+            )),
             network_config,
             config,
         }
@@ -213,7 +279,8 @@ impl NetworkConnectionPool {
         let pooled_conn = PooledConnection::new(
             MantraDexClient::new(self.network_config.clone())
                 .await
-                .map_err(McpServerError::Sdk)?,
+                .map_err(McpServerError::Sdk)?
+                .with_endpoint_pool(self.endpoints.clone()),
         );
 
         self.connections.push(pooled_conn);
@@ -240,7 +307,7 @@ impl NetworkConnectionPool {
                     "Successfully created DEX client for network: {}",
                     self.network_config.chain_id
                 );
-                Ok(client)
+                Ok(client.with_endpoint_pool(self.endpoints.clone()))
             }
             Err(e) => {
                 error!(
@@ -271,13 +338,35 @@ impl NetworkConnectionPool {
         }
     }
 
+    /// Quality score of this pool's RPC endpoint from recent broadcast confirmations
+    fn endpoint_score(&self) -> f64 {
+        ConfirmationTracker::global()
+            .score(&self.network_config.chain_id, &self.network_config.rpc_url)
+    }
+
     /// Perform health checks on all connections
+    ///
+    /// A connection is healthy when the endpoint answers and its confirmation
+    /// score is above the configured minimum; unhealthy connections are dropped
+    /// on the next cleanup so fresh ones are established.
     async fn health_check(&mut self) {
+        let score = self.endpoint_score();
+        let score_ok = score >= self.config.min_endpoint_score;
+        if !score_ok {
+            warn!(
+                "Endpoint {} for network {} is below the minimum quality score ({:.2} < {:.2})",
+                self.network_config.rpc_url,
+                self.network_config.chain_id,
+                score,
+                self.config.min_endpoint_score
+            );
+        }
+
         for pooled_conn in &mut self.connections {
             // Simple health check - try to get the latest block height
             match pooled_conn.client.get_last_block_height().await {
                 Ok(_) => {
-                    pooled_conn.set_health(true);
+                    pooled_conn.set_health(score_ok);
                 }
                 Err(e) => {
                     warn!(
@@ -307,21 +396,71 @@ pub struct McpSdkAdapter {
     /// Connection pool configuration
     pub(crate) config: ConnectionPoolConfig,
     /// Cache for frequently accessed data
-    pub(crate) cache: Arc<RwLock<HashMap<String, (Value, Instant)>>>,
+    pub(crate) cache: Arc<AdapterCache>,
     /// Cache TTL
     pub(crate) cache_ttl: Duration,
     /// Health check task handle
     pub(crate) health_check_handle: Option<tokio::task::JoinHandle<()>>,
+    /// Background metadata prefetch task handle
+    pub(crate) prefetch_handle: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    /// Background ERC-20 registry refresh task handle
+    pub(crate) erc20_refresh_handle: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    /// Background transaction queue monitor task handle
+    pub(crate) tx_queue_handle: Mutex<Option<tokio::task::JoinHandle<()>>>,
     /// Loaded wallets (address -> wallet info)
     pub(crate) wallets: Arc<RwLock<HashMap<String, WalletInfo>>>,
     /// Current active wallet address
     pub(crate) active_wallet: Arc<Mutex<Option<String>>>,
     /// Current active wallet instance (if available)
     pub(crate) active_wallet_instance: Arc<Mutex<Option<MantraWallet>>>,
+    /// Wallet context of each MCP session other than the default one
+    pub(crate) sessions: Arc<RwLock<HashMap<String, SessionContext>>>,
+    /// Network profile switched to at runtime and its configuration, used
+    /// instead of the environment's network
+    pub(crate) network_profile: RwLock<Option<(String, MantraNetworkConfig)>>,
     /// Cache for wallet address to derivation index mappings
     pub(crate) wallet_derivation_cache: Arc<RwLock<HashMap<String, u32>>>,
+    /// Passphrase and HD path of wallets not derived the standard way, kept
+    /// in memory only so a passphrase never reaches the state log
+    pub(crate) wallet_derivation_options: Arc<RwLock<HashMap<String, DerivationOptions>>>,
     /// ERC-20 metadata registry and cache
     pub(crate) erc20_registry: Arc<RwLock<Erc20Registry>>,
+    /// Dust policy attached to every DEX client handed out
+    pub(crate) dust_policy: DustPolicy,
+    /// Affiliate paid out of Skip swaps
+    pub(crate) skip_affiliate: Option<SkipAffiliateConfig>,
+    /// RPC endpoints of Skip destination chains, by chain id
+    pub(crate) skip_chain_rpcs: BTreeMap<String, String>,
+    /// Claimdrop factory listed by the campaigns resource
+    pub(crate) claimdrop_factory: Option<String>,
+    /// Event log the wallet and custom token state is projected from
+    pub(crate) state_store: Arc<Mutex<EventStore>>,
+    /// Signed transactions persisted before broadcast, shared by every client
+    pub(crate) tx_queue: crate::tx_queue::TxQueue,
+    /// Labels accepted in place of addresses in tool arguments
+    pub(crate) address_book: Arc<RwLock<AddressBook>>,
+    /// Wallets settlement refunds instead of distributing to
+    pub(crate) restricted_wallets: Arc<RwLock<RestrictedWalletList>>,
+    /// Metrics cache lookups are counted in
+    pub(crate) metrics: Arc<McpMetrics>,
+    /// Retries of transient failures, given to every client handed out
+    pub(crate) retry_policy: RetryPolicy,
+    /// Circuit breakers of the EVM RPC endpoints, by EVM chain id
+    pub(crate) evm_endpoints: RwLock<HashMap<u64, Arc<EndpointPool>>>,
+    /// Memo pattern and EVM calldata tag of every transaction sent
+    pub(crate) tx_tagging: TxTagging,
+    /// Blocks past the latest one Cosmos transactions are valid for
+    pub(crate) tx_timeout_blocks: u64,
+    /// Denom symbols and decimals, by chain ID, shared by every client
+    pub(crate) denom_metadata: RwLock<HashMap<String, DenomMetadataService>>,
+    /// Whether unlimited ERC-20 approvals are allowed, warned about or refused
+    pub(crate) infinite_approval_policy: InfiniteApprovalPolicy,
+    /// EVM logs already scanned, shared by every EVM client
+    pub(crate) log_cache: Arc<LogCache>,
+    /// Artifact `evm_deploy_erc20` deploys; the default path when unset
+    pub(crate) erc20_artifact_path: Option<PathBuf>,
+    /// Sha256 the artifact's bytecode must have; nothing is deployed unset
+    pub(crate) erc20_artifact_sha256: Option<String>,
 }
 
 impl McpSdkAdapter {
@@ -334,18 +473,160 @@ impl McpSdkAdapter {
                 Erc20Registry::default()
             }
         };
+        let retry_policy = RetryPolicy {
+            max_retries: config.max_retries,
+            base_delay_ms: config.retry_base_delay_ms,
+            ..RetryPolicy::default()
+        };
 
         Self {
             connection_pools: Arc::new(RwLock::new(HashMap::new())),
             cache_ttl: Duration::from_secs(config.connection_ttl_secs),
+            cache: Arc::new(AdapterCache::in_memory(
+                &CacheConfig::default(),
+                Duration::from_secs(config.connection_ttl_secs),
+            )),
             config,
-            cache: Arc::new(RwLock::new(HashMap::new())),
             health_check_handle: None,
+            prefetch_handle: Mutex::new(None),
+            erc20_refresh_handle: Mutex::new(None),
+            tx_queue_handle: Mutex::new(None),
             wallets: Arc::new(RwLock::new(HashMap::new())),
             active_wallet: Arc::new(Mutex::new(None)),
             active_wallet_instance: Arc::new(Mutex::new(None)),
+            sessions: Arc::new(RwLock::new(HashMap::new())),
+            network_profile: RwLock::new(None),
             wallet_derivation_cache: Arc::new(RwLock::new(HashMap::new())),
+            wallet_derivation_options: Arc::new(RwLock::new(HashMap::new())),
             erc20_registry: Arc::new(RwLock::new(registry)),
+            dust_policy: DustPolicy::default(),
+            skip_affiliate: None,
+            skip_chain_rpcs: BTreeMap::new(),
+            claimdrop_factory: None,
+            state_store: Arc::new(Mutex::new(EventStore::in_memory())),
+            tx_queue: crate::tx_queue::TxQueue::in_memory(),
+            address_book: Arc::new(RwLock::new(AddressBook::in_memory())),
+            restricted_wallets: Arc::new(RwLock::new(RestrictedWalletList::in_memory())),
+            metrics: Arc::new(McpMetrics::new()),
+            retry_policy,
+            evm_endpoints: RwLock::new(HashMap::new()),
+            tx_tagging: TxTagging::default(),
+            tx_timeout_blocks: DEFAULT_TIMEOUT_BLOCKS,
+            denom_metadata: RwLock::new(HashMap::new()),
+            infinite_approval_policy: InfiniteApprovalPolicy::default(),
+            log_cache: Arc::new(LogCache::in_memory()),
+            erc20_artifact_path: None,
+            erc20_artifact_sha256: None,
+        }
+    }
+
+    /// Set the dust policy applied after swaps and zaps
+    pub fn with_dust_policy(mut self, policy: DustPolicy) -> Self {
+        self.dust_policy = policy;
+        self
+    }
+
+    /// Set the affiliate paid out of Skip swaps and route quotes
+    pub fn with_skip_affiliate(mut self, affiliate: Option<SkipAffiliateConfig>) -> Self {
+        self.skip_affiliate = affiliate;
+        self
+    }
+
+    /// Set the RPC endpoints Skip deliveries are verified against
+    pub fn with_skip_chain_rpcs(mut self, chain_rpcs: BTreeMap<String, String>) -> Self {
+        self.skip_chain_rpcs = chain_rpcs;
+        self
+    }
+
+    /// Set the cache frequently accessed data is kept in
+    pub fn with_cache(mut self, cache: AdapterCache) -> Self {
+        self.cache = Arc::new(cache);
+        self
+    }
+
+    /// Set the address book labels are resolved against
+    pub fn with_address_book(mut self, address_book: AddressBook) -> Self {
+        self.address_book = Arc::new(RwLock::new(address_book));
+        self
+    }
+
+    /// Set the restricted wallets settlement injects into `settleBatch` calls
+    pub fn with_restricted_wallets(mut self, restricted_wallets: RestrictedWalletList) -> Self {
+        self.restricted_wallets = Arc::new(RwLock::new(restricted_wallets));
+        self
+    }
+
+    /// Set the metrics cache lookups are counted in
+    pub fn with_metrics(mut self, metrics: Arc<McpMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Set the claimdrop factory whose campaigns are exposed as a resource
+    pub fn with_claimdrop_factory(mut self, factory_address: Option<String>) -> Self {
+        self.claimdrop_factory = factory_address;
+        self
+    }
+
+    /// Set how transient failures are retried, replacing the connection pool
+    /// configuration's retry settings
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set when an RPC endpoint's circuit opens and for how long
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.config.circuit_breaker = config;
+        self
+    }
+
+    /// Set the memo pattern and EVM calldata tag of the transactions sent
+    pub fn with_tx_tagging(mut self, tagging: TxTagging) -> Self {
+        self.tx_tagging = tagging;
+        self
+    }
+
+    /// Set how many blocks past the latest one Cosmos transactions are
+    /// valid for; 0 signs them without a timeout height
+    pub fn with_tx_timeout_blocks(mut self, blocks: u64) -> Self {
+        self.tx_timeout_blocks = blocks;
+        self
+    }
+
+    /// Keep scanned EVM logs in `cache` instead of memory
+    pub fn with_log_cache(mut self, cache: LogCache) -> Self {
+        self.log_cache = Arc::new(cache);
+        self
+    }
+
+    /// Deploy standard ERC-20 tokens from the artifact at `path`
+    pub fn with_erc20_artifact_path(mut self, path: PathBuf) -> Self {
+        self.erc20_artifact_path = Some(path);
+        self
+    }
+
+    /// Only deploy standard ERC-20 bytecode whose sha256 is `sha256`
+    pub fn with_erc20_artifact_sha256(mut self, sha256: String) -> Self {
+        self.erc20_artifact_sha256 = Some(sha256);
+        self
+    }
+
+    /// Set what `wallet_approve_erc20` does with unlimited approvals
+    pub fn with_infinite_approval_policy(mut self, policy: InfiniteApprovalPolicy) -> Self {
+        self.infinite_approval_policy = policy;
+        self
+    }
+
+    /// Attach the configured affiliate, chain endpoints and retry policy to a
+    /// Skip client
+    pub(crate) fn configure_skip_client(&self, skip_client: &mut SkipClient) {
+        skip_client.set_retry_policy(self.retry_policy.clone());
+        if let Some(affiliate) = &self.skip_affiliate {
+            skip_client.set_affiliate(affiliate.clone());
+        }
+        for (chain_id, rpc_url) in &self.skip_chain_rpcs {
+            skip_client.set_chain_rpc(chain_id.clone(), rpc_url.clone());
         }
     }
 
@@ -387,10 +668,23 @@ impl McpSdkAdapter {
         Arc::clone(&self.erc20_registry)
     }
 
+    /// Tokens of `chain_id`, including the current session's custom tokens
     pub async fn list_registry_tokens(&self, chain_id: u64) -> Vec<Erc20TokenInfo> {
-        let registry_arc = self.erc20_registry();
-        let registry = registry_arc.read().await;
-        registry.list_for_chain(chain_id)
+        let mut tokens = {
+            let registry_arc = self.erc20_registry();
+            let registry = registry_arc.read().await;
+            registry.list_for_chain(chain_id)
+        };
+        if let Some(context) = self.session_context().await {
+            tokens.retain(|token| !context.custom_tokens.contains_key(&token.key()));
+            tokens.extend(
+                context
+                    .custom_tokens
+                    .into_values()
+                    .filter(|token| token.chain_id == chain_id),
+            );
+        }
+        tokens
     }
 
     pub async fn get_registry_token(
@@ -398,25 +692,65 @@ impl McpSdkAdapter {
         chain_id: u64,
         address: Address,
     ) -> Option<Erc20TokenInfo> {
+        if let Some(info) = self.session_custom_token(chain_id, address).await {
+            return Some(info);
+        }
         let registry_arc = self.erc20_registry();
         let registry = registry_arc.read().await;
         registry.get(chain_id, &address).cloned()
     }
 
-    pub async fn add_custom_token(&self, info: Erc20TokenInfo) -> McpResult<()> {
-        let registry = self.erc20_registry();
-        let mut guard = registry.write().await;
-        guard
-            .upsert_custom(info)
-            .map_err(|e| McpServerError::Internal(e.to_string()))
+    /// Add a custom token, for the current session only outside the default one
+    pub async fn add_custom_token(&self, mut info: Erc20TokenInfo) -> McpResult<()> {
+        info.source = TokenSource::Custom;
+        let session_info = info.clone();
+        let added = self
+            .update_session(|context| {
+                context
+                    .custom_tokens
+                    .insert(session_info.key(), session_info)
+            })
+            .await;
+        if added.is_some() {
+            return Ok(());
+        }
+
+        self.apply_state_event(StateEvent::CustomTokenAdded {
+            token: (&info).into(),
+        })
+        .await
     }
 
+    /// Remove a custom token of the current session, or a server-wide one in
+    /// the default session
     pub async fn remove_custom_token(&self, chain_id: u64, address: Address) -> McpResult<bool> {
-        let registry = self.erc20_registry();
-        let mut guard = registry.write().await;
-        guard
-            .remove_custom(chain_id, &address)
-            .map_err(|e| McpServerError::Internal(e.to_string()))
+        let removed = self
+            .update_session(|context| {
+                context
+                    .custom_tokens
+                    .remove(&TokenKey::new(chain_id, address))
+                    .is_some()
+            })
+            .await;
+        if let Some(removed) = removed {
+            return Ok(removed);
+        }
+
+        let is_custom = self
+            .erc20_registry()
+            .read()
+            .await
+            .is_custom(chain_id, &address);
+        if !is_custom {
+            return Ok(false);
+        }
+
+        self.apply_state_event(StateEvent::CustomTokenRemoved {
+            chain_id,
+            address: format!("{:#x}", address),
+        })
+        .await?;
+        Ok(true)
     }
 
     /// Check if an address is a precompile address
@@ -441,13 +775,19 @@ impl McpSdkAdapter {
         chain_id: u64,
         token_address: Address,
     ) -> McpResult<Erc20TokenInfo> {
+        if let Some(info) = self.session_custom_token(chain_id, token_address).await {
+            return Ok(info);
+        }
         {
             let registry_arc = self.erc20_registry();
-            let registry = registry_arc.read().await;
-            if let Some(info) = registry.get(chain_id, &token_address) {
-                if !info.needs_refresh(registry.ttl()) {
-                    return Ok(info.clone());
-                }
+            let mut registry = registry_arc.write().await;
+            let fresh = registry
+                .get(chain_id, &token_address)
+                .filter(|info| !info.needs_refresh(registry.ttl()))
+                .cloned();
+            if let Some(info) = fresh {
+                registry.mark_used(chain_id, &token_address);
+                return Ok(info);
             }
         }
 
@@ -456,14 +796,54 @@ impl McpSdkAdapter {
         let existing = guard.get(chain_id, &token_address).cloned();
         drop(guard);
 
-        // Check if contract exists (has code)
-        let code = evm_client
-            .get_code(
-                crate::protocols::evm::types::EthAddress(token_address),
-                None,
-            )
+        // Metadata discovered before a restart is kept in the adapter cache
+        let cache_key = format!("erc20:{}:{:#x}", chain_id, token_address);
+        if existing.is_none() {
+            if let Some(cached) = self.cache_get(&cache_key).await {
+                let symbol = cached.get("symbol").and_then(|v| v.as_str());
+                let decimals = cached.get("decimals").and_then(|v| v.as_u64());
+                if let (Some(symbol), Some(decimals)) = (symbol, decimals) {
+                    let info = Erc20TokenInfo {
+                        address: token_address,
+                        symbol: symbol.to_string(),
+                        name: cached
+                            .get("name")
+                            .and_then(|v| v.as_str())
+                            .map(str::to_string),
+                        decimals: decimals as u8,
+                        chain_id,
+                        last_refreshed: Some(Instant::now()),
+                        source: TokenSource::Discovered,
+                        code_fingerprint: None,
+                        implementation: None,
+                        last_used: Some(Instant::now()),
+                    };
+                    registry.write().await.upsert_runtime(info.clone());
+                    return Ok(info);
+                }
+            }
+        }
+
+        self.fetch_token_metadata(evm_client, chain_id, token_address, existing, true)
             .await
-            .map_err(McpServerError::Sdk)?;
+    }
+
+    /// Read the metadata of a token from its contract and keep it in the
+    /// registry and the adapter cache, with the fingerprint of the code it
+    /// was read from; `used` marks it as used by a tool call
+    pub(crate) async fn fetch_token_metadata(
+        &self,
+        evm_client: &EvmClient,
+        chain_id: u64,
+        token_address: Address,
+        existing: Option<Erc20TokenInfo>,
+        used: bool,
+    ) -> McpResult<Erc20TokenInfo> {
+        // Check if contract exists (has code)
+        let (code, code_fingerprint, implementation) =
+            read_code_fingerprint(evm_client, token_address)
+                .await
+                .map_err(McpServerError::Sdk)?;
 
         if code.is_empty() && !Self::is_precompile_address(token_address) {
             return Err(McpServerError::InvalidArguments(format!(
@@ -512,6 +892,14 @@ impl McpSdkAdapter {
             .as_ref()
             .map(|info| info.source.clone())
             .unwrap_or(TokenSource::Discovered);
+        if let Some(previous) = existing.as_ref().and_then(|info| info.code_fingerprint) {
+            if previous != code_fingerprint {
+                warn!(
+                    "Code of ERC-20 token {:#x} changed since its metadata was read; now {} with {} decimals",
+                    token_address, symbol, decimals
+                );
+            }
+        }
 
         let info = Erc20TokenInfo {
             address: token_address,
@@ -521,8 +909,26 @@ impl McpSdkAdapter {
             chain_id,
             last_refreshed: Some(Instant::now()),
             source,
+            code_fingerprint: Some(code_fingerprint),
+            implementation,
+            last_used: if used {
+                Some(Instant::now())
+            } else {
+                existing.and_then(|info| info.last_used)
+            },
         };
 
+        let cache_key = format!("erc20:{}:{:#x}", chain_id, token_address);
+        self.cache_set_permanent(
+            cache_key,
+            serde_json::json!({
+                "symbol": info.symbol,
+                "name": info.name,
+                "decimals": info.decimals,
+            }),
+        )
+        .await;
+
         let registry = self.erc20_registry();
         let mut guard = registry.write().await;
         guard.upsert_runtime(info.clone());
@@ -541,17 +947,60 @@ impl McpSdkAdapter {
 
         let client = EvmClient::new(evm_rpc_url, chain_id)
             .await
-            .map_err(McpServerError::Sdk)?;
+            .map_err(McpServerError::Sdk)?
+            .with_retry_policy(self.retry_policy.clone())
+            .with_fallback_rpc_urls(&network_config.evm_rpc_fallback_urls)
+            .map_err(McpServerError::Sdk)?
+            .with_endpoint_pool(self.evm_endpoint_pool(&network_config, chain_id).await)
+            .with_tx_queue(self.tx_queue.clone())
+            .with_tx_tagging(&self.tx_tagging)
+            .with_log_cache(self.log_cache.clone());
 
         Ok((client, chain_id))
     }
 
+    /// Circuit breakers of the EVM RPC endpoints of `network_config`, shared
+    /// by every EVM client of chain `chain_id`
+    pub(crate) async fn evm_endpoint_pool(
+        &self,
+        network_config: &MantraNetworkConfig,
+        chain_id: u64,
+    ) -> Arc<EndpointPool> {
+        let urls = network_config.evm_rpc_urls();
+        if let Some(pool) = self.evm_endpoints.read().await.get(&chain_id) {
+            if pool.urls() == urls {
+                return pool.clone();
+            }
+        }
+        let pool = Arc::new(EndpointPool::new(urls, self.config.circuit_breaker.clone()));
+        self.evm_endpoints
+            .write()
+            .await
+            .insert(chain_id, pool.clone());
+        pool
+    }
+
+    /// Denom metadata cache of `network_config`'s chain, shared by every
+    /// client of it
+    pub(crate) async fn denom_metadata_service(
+        &self,
+        network_config: &MantraNetworkConfig,
+    ) -> DenomMetadataService {
+        self.denom_metadata
+            .write()
+            .await
+            .entry(network_config.chain_id.clone())
+            .or_default()
+            .clone()
+    }
+
     /// Get a client connection for the specified network
     pub async fn get_client(
         &self,
         network_config: &MantraNetworkConfig,
     ) -> McpResult<MantraDexClient> {
         let network_id = network_config.chain_id.clone();
+        let denom_metadata = self.denom_metadata_service(network_config).await;
 
         // Get or create the network pool
         {
@@ -571,20 +1020,88 @@ impl McpSdkAdapter {
             McpServerError::Internal(format!("Network pool not found: {}", network_id))
         })?;
 
-        pool.get_connection().await
+        let client = pool
+            .get_connection()
+            .await?
+            .with_dust_policy(self.dust_policy.clone())
+            .with_retry_policy(self.retry_policy.clone())
+            .with_tx_queue(self.tx_queue.clone())
+            .with_tx_tagging(&self.tx_tagging)
+            .with_timeout_blocks(self.tx_timeout_blocks)
+            .with_denom_metadata(denom_metadata);
+        Ok(match &self.skip_affiliate {
+            Some(affiliate) => client.with_skip_affiliate(affiliate.clone()),
+            None => client,
+        })
     }
 
     /// Get a client with wallet attached
+    ///
+    /// During a dry run the client simulates its transactions instead of
+    /// broadcasting them.
     pub async fn get_client_with_wallet(
         &self,
         network_config: &MantraNetworkConfig,
         wallet: MantraWallet,
     ) -> McpResult<MantraDexClient> {
-        let base_client = self.get_client(network_config).await?;
-        Ok(base_client.with_wallet(wallet))
+        let mut client = self
+            .get_client(network_config)
+            .await?
+            .with_wallet(wallet)
+            .with_simulation(Self::is_dry_run());
+        if let Some(memo) = Self::memo_override() {
+            client = client.with_memo(Some(memo));
+        }
+        if let Some(blocks) = Self::timeout_blocks_override() {
+            client = client.with_timeout_blocks(blocks);
+        }
+        Ok(client)
+    }
+
+    /// Run `operation` as a dry run
+    ///
+    /// Transactions signed while it runs are simulated (Cosmos simulate, EVM
+    /// `eth_call` and gas estimation) and never broadcast.
+    pub async fn dry_run<F: std::future::Future>(operation: F) -> F::Output {
+        DRY_RUN.scope(true, operation).await
+    }
+
+    /// Whether the current operation runs as a dry run
+    pub fn is_dry_run() -> bool {
+        DRY_RUN.try_with(|dry_run| *dry_run).unwrap_or(false)
+    }
+
+    /// Run `operation` with its Cosmos transactions carrying `memo` instead
+    /// of the configured memo
+    pub async fn with_memo<F: std::future::Future>(memo: String, operation: F) -> F::Output {
+        MEMO.scope(memo, operation).await
+    }
+
+    /// Memo the current operation's transactions carry instead of the
+    /// configured one
+    pub fn memo_override() -> Option<String> {
+        MEMO.try_with(|memo| memo.clone()).ok()
+    }
+
+    /// Run `operation` with its Cosmos transactions valid for `blocks` past
+    /// the latest block instead of the configured number
+    pub async fn with_timeout_blocks<F: std::future::Future>(
+        blocks: u64,
+        operation: F,
+    ) -> F::Output {
+        TIMEOUT_BLOCKS.scope(blocks, operation).await
+    }
+
+    /// Blocks the current operation's transactions are valid for instead of
+    /// the configured number
+    pub fn timeout_blocks_override() -> Option<u64> {
+        TIMEOUT_BLOCKS.try_with(|blocks| *blocks).ok()
     }
 
     /// Execute with retry logic
+    ///
+    /// Only errors classified as retryable are retried, under the adapter's
+    /// retry policy.
     pub async fn execute_with_retry<F, T>(&self, operation: F) -> McpResult<T>
     where
         F: Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = McpResult<T>> + Send>>
@@ -592,46 +1109,15 @@ impl McpSdkAdapter {
             + 'static,
         T: Send + 'static,
     {
-        let mut last_error = None;
-
-        for attempt in 0..=self.config.max_retries {
-            match operation().await {
-                Ok(result) => return Ok(result),
-                Err(e) => {
-                    last_error = Some(e);
-
-                    if attempt < self.config.max_retries {
-                        let delay = Duration::from_millis(
-                            self.config.retry_base_delay_ms * (2_u64.pow(attempt)),
-                        );
-                        warn!(
-                            "Operation failed (attempt {}), retrying in {:?}",
-                            attempt + 1,
-                            delay
-                        );
-                        tokio::time::sleep(delay).await;
-                    }
-                }
-            }
-        }
-
-        Err(last_error
-            .unwrap_or_else(|| McpServerError::Internal("Unknown retry error".to_string())))
+        self.retry_policy.run("Operation", operation).await
     }
 
     /// Clean up expired cache entries and connection pools
     pub async fn cleanup(&self) -> McpResult<()> {
         // Clean cache
-        {
-            let mut cache = self.cache.write().await;
-            let original_count = cache.len();
-
-            cache.retain(|_, (_, timestamp)| timestamp.elapsed() < self.cache_ttl);
-
-            let removed_count = original_count - cache.len();
-            if removed_count > 0 {
-                debug!("Cleaned {} expired cache entries", removed_count);
-            }
+        let removed_count = self.cache.purge_expired();
+        if removed_count > 0 {
+            debug!("Cleaned {} expired cache entries", removed_count);
         }
 
         // Clean connection pools
@@ -648,26 +1134,24 @@ impl McpSdkAdapter {
 
     /// Get cached value
     pub async fn cache_get(&self, key: &str) -> Option<Value> {
-        let cache = self.cache.read().await;
-        cache.get(key).and_then(|(value, timestamp)| {
-            if timestamp.elapsed() < self.cache_ttl {
-                Some(value.clone())
-            } else {
-                None
-            }
-        })
+        let value = self.cache.get(key);
+        self.metrics.record_cache_lookup("adapter", value.is_some());
+        value
     }
 
     /// Set cached value
     pub async fn cache_set(&self, key: String, value: Value) {
-        let mut cache = self.cache.write().await;
-        cache.insert(key, (value, Instant::now()));
+        self.cache.insert(key, value);
+    }
+
+    /// Set cached value that never expires, for data that does not change
+    pub async fn cache_set_permanent(&self, key: String, value: Value) {
+        self.cache.insert_for(key, value, None);
     }
 
     /// Clear all cached values
     pub async fn cache_clear(&self) {
-        let mut cache = self.cache.write().await;
-        cache.clear();
+        self.cache.clear();
         debug!("Cleared all cache entries");
     }
 
@@ -679,24 +1163,75 @@ impl McpSdkAdapter {
             .collect()
     }
 
+    /// Connection pool sizes and RPC endpoints by network, for metrics
+    pub async fn get_pool_gauges(&self) -> HashMap<String, PoolGauges> {
+        let pools = self.connection_pools.read().await;
+        pools
+            .iter()
+            .map(|(network_id, pool)| {
+                let (connections, healthy, available_permits) = pool.get_stats();
+                (
+                    network_id.clone(),
+                    PoolGauges {
+                        connections,
+                        healthy,
+                        available_permits,
+                        endpoints: pool.endpoints.urls().len(),
+                        endpoints_available: pool.endpoints.available_count(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Circuit breaker state of each pooled network's RPC endpoints
+    pub async fn get_pool_endpoint_health(&self) -> HashMap<String, Vec<EndpointHealth>> {
+        let pools = self.connection_pools.read().await;
+        pools
+            .iter()
+            .map(|(network_id, pool)| (network_id.clone(), pool.endpoints.health()))
+            .collect()
+    }
+
+    /// Circuit breaker state of the EVM RPC endpoints, by EVM chain id
+    pub async fn get_evm_endpoint_health(&self) -> BTreeMap<u64, Vec<EndpointHealth>> {
+        self.evm_endpoints
+            .read()
+            .await
+            .iter()
+            .map(|(chain_id, pool)| (*chain_id, pool.health()))
+            .collect()
+    }
+
+    /// RPC endpoint and its quality score for each pooled network
+    pub async fn get_pool_endpoint_scores(&self) -> HashMap<String, (String, f64)> {
+        let pools = self.connection_pools.read().await;
+        pools
+            .iter()
+            .map(|(network_id, pool)| {
+                (
+                    network_id.clone(),
+                    (pool.network_config.rpc_url.clone(), pool.endpoint_score()),
+                )
+            })
+            .collect()
+    }
+
     pub async fn get_cache_stats(&self) -> (usize, usize) {
-        let cache = self.cache.read().await;
-        let total = cache.len();
-        let valid = cache
-            .values()
-            .filter(|(_, timestamp)| timestamp.elapsed() < self.cache_ttl)
-            .count();
-        (total, valid)
+        let stats = self.cache.stats();
+        (stats.entries, stats.fresh)
     }
 
     pub async fn shutdown(&mut self) -> McpResult<()> {
         info!("Shutting down MCP SDK Adapter...");
 
-        // Stop health checks
+        // Stop health checks and prefetching
         self.stop_health_checks().await;
+        self.stop_prefetch().await;
+        self.stop_erc20_refresh().await;
 
-        // Clear cache
-        self.cache_clear().await;
+        // Drop cached entries from memory; persisted ones are kept for the next start
+        self.cache.unload();
 
         // Clear connection pools
         {
@@ -730,6 +1265,50 @@ mod tests {
         assert!(pool_stats.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_dry_run_scope() {
+        assert!(!McpSdkAdapter::is_dry_run());
+        let inside = McpSdkAdapter::dry_run(async { McpSdkAdapter::is_dry_run() }).await;
+        assert!(inside);
+        assert!(!McpSdkAdapter::is_dry_run());
+    }
+
+    #[tokio::test]
+    async fn test_session_wallet_isolation() {
+        let adapter = McpSdkAdapter::default();
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let first = adapter
+            .add_wallet(MantraWallet::from_mnemonic(mnemonic, 0).unwrap())
+            .await
+            .unwrap();
+        let second = adapter
+            .add_wallet(MantraWallet::from_mnemonic(mnemonic, 1).unwrap())
+            .await
+            .unwrap();
+        adapter.switch_active_wallet(&first).await.unwrap();
+
+        McpSdkAdapter::in_session("agent-b".to_string(), async {
+            adapter.switch_active_wallet(&second).await.unwrap();
+            let active = adapter.get_active_wallet_info().await.unwrap().unwrap();
+            assert_eq!(active.address, second);
+        })
+        .await;
+
+        // Neither the server nor other sessions follow agent-b's switch
+        assert_eq!(adapter.active_wallet_address().await, Some(first.clone()));
+        McpSdkAdapter::in_session("agent-a".to_string(), async {
+            assert_eq!(adapter.active_wallet_address().await, Some(first.clone()));
+        })
+        .await;
+
+        // A removed wallet falls back to the server's in every session
+        adapter.remove_wallet(&second).await.unwrap();
+        McpSdkAdapter::in_session("agent-b".to_string(), async {
+            assert_eq!(adapter.active_wallet_address().await, Some(first.clone()));
+        })
+        .await;
+    }
+
     #[tokio::test]
     async fn test_cache_operations() {
         let adapter = McpSdkAdapter::default();
@@ -783,6 +1362,8 @@ mod tests {
             max_idle_time_secs: 120,
             health_check_interval_secs: 45,
             max_wallet_derivation_index: 100,
+            min_endpoint_score: 0.5,
+            circuit_breaker: CircuitBreakerConfig::default(),
         };
 
         let adapter = McpSdkAdapter::new(config.clone());
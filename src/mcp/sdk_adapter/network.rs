@@ -1,11 +1,321 @@
 //! Network validation and configuration methods
 
 use super::*;
+use crate::chain_info::{
+    stuck_transaction_hints, BlockSummary, ChainParams, MempoolStatus, NodeStatus, ValidatorSet,
+};
+use crate::fanout::{FanoutReport, NetworkFanout, NetworkResult};
+use crate::mcp::response_format::MarkdownRender;
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+
+/// Result of the `switch_network` tool
+#[derive(Debug, Clone, Serialize)]
+pub struct SwitchNetworkResponse {
+    pub profile: String,
+    /// Profile active before the switch; unset when the environment's network was
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_profile: Option<String>,
+    pub network: String,
+    pub chain_id: String,
+    pub rpc_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_rpc_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_chain_id: Option<u64>,
+    pub pool_manager: String,
+    /// Cached entries dropped with the previous network
+    pub cache_entries_cleared: usize,
+    pub available_profiles: Vec<String>,
+}
+
+impl MarkdownRender for SwitchNetworkResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "✅ **Network Switched Successfully**\n\n".to_string();
+        response.push_str(&format!("**Profile:** `{}`", self.profile));
+        if let Some(previous) = &self.previous_profile {
+            response.push_str(&format!(" (was `{}`)", previous));
+        }
+        response.push_str(&format!(
+            "\n**Network:** {} (`{}`)\n**RPC:** {}\n",
+            self.network, self.chain_id, self.rpc_url
+        ));
+        if let Some(evm_rpc_url) = &self.evm_rpc_url {
+            response.push_str(&format!("**EVM RPC:** {}", evm_rpc_url));
+            if let Some(evm_chain_id) = self.evm_chain_id {
+                response.push_str(&format!(" (chain {})", evm_chain_id));
+            }
+            response.push('\n');
+        }
+        response.push_str(&format!(
+            "**Pool Manager:** `{}`\n**Cache Entries Cleared:** {}\n**Available Profiles:** {}\n",
+            self.pool_manager,
+            self.cache_entries_cleared,
+            self.available_profiles.join(", ")
+        ));
+        response
+    }
+}
+
+/// Result of `get_multi_network_portfolio`
+#[derive(Debug, Clone, Serialize)]
+pub struct MultiNetworkPortfolioResponse {
+    pub address: String,
+    /// Profiles queried, in the order asked for
+    pub networks: Vec<String>,
+    /// Balances of the address on each network
+    pub balances: FanoutReport<Vec<Coin>>,
+    /// Each pool asked for as every network sees it, by pool identifier
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub pools: BTreeMap<String, FanoutReport<PoolInfoResponse>>,
+    /// Whether every network answered every query
+    pub complete: bool,
+}
+
+impl MarkdownRender for MultiNetworkPortfolioResponse {
+    fn to_markdown(&self) -> String {
+        fn status<T>(result: &NetworkResult<T>) -> String {
+            match result.error() {
+                Some(error) => format!("⚠️ {}", error),
+                None => format!("{} ms", result.elapsed_ms),
+            }
+        }
+
+        let mut response = format!(
+            "🌐 **Portfolio of `{}` across {}**\n\n",
+            self.address,
+            self.networks.join(", ")
+        );
+        for result in &self.balances.results {
+            response.push_str(&format!(
+                "### {} (`{}`) - {}\n",
+                result.network,
+                result.chain_id,
+                status(result)
+            ));
+            match result.value() {
+                Some(balances) if balances.is_empty() => response.push_str("No balances\n"),
+                Some(balances) => {
+                    for coin in balances {
+                        response.push_str(&format!("- {} {}\n", coin.amount, coin.denom));
+                    }
+                }
+                None => {}
+            }
+            response.push('\n');
+        }
+        for (pool_id, report) in &self.pools {
+            response.push_str(&format!("### Pool `{}`\n", pool_id));
+            for result in &report.results {
+                match result.value() {
+                    Some(pool) => response.push_str(&format!(
+                        "- **{}**: {}\n",
+                        result.network,
+                        pool.pool_info
+                            .assets
+                            .iter()
+                            .map(|asset| format!("{} {}", asset.amount, asset.denom))
+                            .collect::<Vec<_>>()
+                            .join(" / ")
+                    )),
+                    None => response.push_str(&format!(
+                        "- **{}**: {}\n",
+                        result.network,
+                        status(result)
+                    )),
+                }
+            }
+            response.push('\n');
+        }
+        if !self.complete {
+            response.push_str("Some networks did not answer; the results above are partial.\n");
+        }
+        response
+    }
+}
+
+/// Result of `network_node_status`
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeStatusResponse {
+    pub network: String,
+    pub chain_id: String,
+    pub node: NodeStatus,
+    /// Seconds since the node's latest block
+    pub block_age_seconds: i64,
+    /// Unconfirmed transactions; none when the node does not serve the count
+    pub mempool: Option<MempoolStatus>,
+    /// Reasons a broadcast transaction may not be included
+    pub hints: Vec<String>,
+}
+
+impl MarkdownRender for NodeStatusResponse {
+    fn to_markdown(&self) -> String {
+        let node = &self.node;
+        let mut response = format!(
+            "🩺 **Node Status: {}** (`{}`)\n\n",
+            self.network, self.chain_id
+        );
+        response.push_str(&format!(
+            "**Node:** {} (`{}`, CometBFT {})\n",
+            node.moniker, node.node_id, node.version
+        ));
+        response.push_str(&format!(
+            "**Latest Block:** {} at {} ({}s ago)\n",
+            node.latest_block_height,
+            node.latest_block_time.to_rfc3339(),
+            self.block_age_seconds
+        ));
+        response.push_str(&format!(
+            "**Earliest Block:** {}\n**Syncing:** {}\n",
+            node.earliest_block_height,
+            if node.catching_up { "yes" } else { "no" }
+        ));
+        if let Some(mempool) = &self.mempool {
+            response.push_str(&format!(
+                "**Mempool:** {} transactions ({} bytes)\n",
+                mempool.n_txs, mempool.total_bytes
+            ));
+        }
+        if self.hints.is_empty() {
+            response.push_str("\nNothing on the node would hold back a transaction.\n");
+        }
+        for hint in &self.hints {
+            response.push_str(&format!("\n⚠️ {}\n", hint));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for BlockSummary {
+    fn to_markdown(&self) -> String {
+        let mut response = format!("🧱 **Block {}** (`{}`)\n\n", self.height, self.chain_id);
+        response.push_str(&format!("**Hash:** `{}`\n", self.hash));
+        response.push_str(&format!("**Time:** {}\n", self.time.to_rfc3339()));
+        response.push_str(&format!("**Proposer:** `{}`\n", self.proposer));
+        response.push_str(&format!("\n**Transactions ({}):**\n", self.tx_count));
+        for hash in &self.tx_hashes {
+            response.push_str(&format!("- `{}`\n", hash));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for ValidatorSet {
+    fn to_markdown(&self) -> String {
+        let mut response = format!(
+            "🛡️ **Validator Set at Height {}**\n\n**Validators:** {}\n**Total Voting Power:** {}\n**Validators Able To Halt The Chain:** {}\n\n",
+            self.height,
+            self.validators.len(),
+            self.total_voting_power,
+            self.halting_validators
+        );
+        for validator in &self.validators {
+            response.push_str(&format!(
+                "- `{}`{}: {} ({:.2}%)\n",
+                validator.address,
+                validator
+                    .name
+                    .as_ref()
+                    .map(|name| format!(" {}", name))
+                    .unwrap_or_default(),
+                validator.voting_power,
+                validator.voting_power_percent
+            ));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for ChainParams {
+    fn to_markdown(&self) -> String {
+        let mut response = format!("⚙️ **Chain Parameters** (`{}`)\n\n", self.chain_id);
+        response.push_str(&format!(
+            "**Max Block Gas:** {}\n",
+            self.max_block_gas
+                .map(|gas| gas.to_string())
+                .unwrap_or_else(|| "unlimited".to_string())
+        ));
+        response.push_str(&format!(
+            "**Max Block Size:** {} bytes\n",
+            self.max_block_bytes
+        ));
+        response.push_str(&format!(
+            "**Client Gas Price:** {}{}\n",
+            self.gas_price, self.native_denom
+        ));
+        response.push_str(&format!(
+            "**Node Minimum Gas Price:** {}\n",
+            self.node_minimum_gas_price
+                .as_deref()
+                .unwrap_or("not reported")
+        ));
+        response
+    }
+}
+
+impl MarkdownRender for MempoolStatus {
+    fn to_markdown(&self) -> String {
+        format!(
+            "📥 **Mempool**\n\n**Unconfirmed Transactions:** {}\n**Total Size:** {} bytes\n",
+            self.n_txs, self.total_bytes
+        )
+    }
+}
 
 impl McpSdkAdapter {
+    /// Balances of a wallet, and pools `pool_ids`, on every network of `fanout`
+    ///
+    /// The wallet is `wallet_address`, or the active wallet when unset. All
+    /// queries run at once, and networks that fail or time out are reported
+    /// alongside the ones that answered.
+    pub async fn multi_network_portfolio(
+        &self,
+        fanout: &NetworkFanout,
+        wallet_address: Option<String>,
+        pool_ids: Vec<String>,
+    ) -> McpResult<MultiNetworkPortfolioResponse> {
+        let address = match wallet_address {
+            Some(address) => address,
+            None => self
+                .get_active_wallet_info()
+                .await?
+                .map(|info| info.address)
+                .ok_or_else(|| {
+                    McpServerError::InvalidArguments(
+                        "Give a wallet_address or activate a wallet".to_string(),
+                    )
+                })?,
+        };
+
+        let (balances, pools) = futures::join!(
+            fanout.balances(&address),
+            futures::future::join_all(pool_ids.iter().map(|pool_id| fanout.pool(pool_id)))
+        );
+        let pools: BTreeMap<String, FanoutReport<PoolInfoResponse>> =
+            pool_ids.into_iter().zip(pools).collect();
+        let complete = balances.is_complete() && pools.values().all(FanoutReport::is_complete);
+
+        Ok(MultiNetworkPortfolioResponse {
+            address,
+            networks: fanout.networks(),
+            balances,
+            pools,
+            complete,
+        })
+    }
+
     /// Get the default network configuration
     /// This is a temporary method until proper network configuration management is implemented
+    ///
+    /// A session that picked its own network gets that network's configuration,
+    /// and the network profile switched to comes before the environment's network.
     pub(crate) async fn get_default_network_config(&self) -> McpResult<MantraNetworkConfig> {
+        if let Some(network_config) = self.session_network_config().await {
+            return network_config;
+        }
+        if let Some((_, network_config)) = self.network_profile.read().await.as_ref() {
+            return Ok(network_config.clone());
+        }
+
         // Load environment configuration which includes EVM settings
         use crate::config::env::EnvironmentConfig;
 
@@ -19,6 +329,61 @@ impl McpSdkAdapter {
         Ok(network_config)
     }
 
+    /// Name of the network profile switched to, if any
+    pub async fn active_network_profile(&self) -> Option<String> {
+        self.network_profile
+            .read()
+            .await
+            .as_ref()
+            .map(|(profile, _)| profile.clone())
+    }
+
+    /// Make `network_config` of profile `profile` the server-wide network
+    ///
+    /// The new network is connected to first, so an unreachable one leaves the
+    /// current network in place. The connection pools are then rebuilt and the
+    /// cache emptied while the pools are locked, so no call mixes the new
+    /// network with connections or data of the previous one. Sessions that
+    /// picked their own network keep it.
+    ///
+    /// Returns the previous profile and the number of cache entries dropped.
+    pub async fn switch_network_profile(
+        &self,
+        profile: &str,
+        network_config: MantraNetworkConfig,
+    ) -> McpResult<(Option<String>, usize)> {
+        let client = MantraDexClient::new(network_config.clone())
+            .await
+            .map_err(McpServerError::Sdk)?;
+        client.get_last_block_height().await.map_err(|e| {
+            McpServerError::Network(format!(
+                "Network profile '{}' is unreachable at {}: {}",
+                profile, network_config.rpc_url, e
+            ))
+        })?;
+
+        let mut pools = self.connection_pools.write().await;
+        let mut active = self.network_profile.write().await;
+
+        pools.clear();
+        let mut pool = NetworkConnectionPool::new(network_config.clone(), self.config.clone());
+        let client = client.with_endpoint_pool(pool.endpoints.clone());
+        pool.connections.push(PooledConnection::new(client));
+        pools.insert(network_config.chain_id.clone(), pool);
+
+        let cleared = self.cache.stats().entries;
+        self.cache.clear();
+
+        let previous = active
+            .replace((profile.to_string(), network_config))
+            .map(|(previous, _)| previous);
+        info!(
+            "Switched network profile from {:?} to {} ({} cache entries cleared)",
+            previous, profile, cleared
+        );
+        Ok((previous, cleared))
+    }
+
     /// Validate network connectivity (for script execution)
     pub async fn validate_network_connectivity(&self) -> McpResult<Value> {
         debug!("SDK Adapter: Validating network connectivity");
@@ -46,6 +411,58 @@ impl McpSdkAdapter {
         }
     }
 
+    /// Summarize recent broadcast confirmation times and endpoint reliability
+    ///
+    /// Optional arguments: `network` (chain id, defaults to every tracked network)
+    /// and `window_secs` to limit statistics to recent samples.
+    pub async fn network_performance(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Summarizing network performance");
+
+        let network = args.get("network").and_then(|v| v.as_str());
+        let window = args
+            .get("window_secs")
+            .and_then(|v| v.as_u64())
+            .map(Duration::from_secs);
+
+        let tracker = ConfirmationTracker::global();
+        let networks = match network {
+            Some(network) => tracker.network_stats(network, window).into_iter().collect(),
+            None => tracker.all_network_stats(window),
+        };
+
+        let pool_stats = self.get_pool_stats().await;
+        let mut endpoint_health = self.get_pool_endpoint_health().await;
+        let pools: Vec<Value> = self
+            .get_pool_endpoint_scores()
+            .await
+            .into_iter()
+            .filter(|(network_id, _)| network.is_none_or(|n| n == network_id))
+            .map(|(network_id, (endpoint, score))| {
+                let (total, healthy, _) = pool_stats.get(&network_id).copied().unwrap_or_default();
+                serde_json::json!({
+                    "network": network_id,
+                    "endpoint": endpoint,
+                    "score": score,
+                    "healthy": score >= self.config.min_endpoint_score,
+                    "connections": total,
+                    "healthy_connections": healthy,
+                    "endpoints": endpoint_health.remove(&network_id).unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "sla_ms": tracker.sla().as_millis() as u64,
+            "min_endpoint_score": self.config.min_endpoint_score,
+            "window_secs": window.map(|w| w.as_secs()),
+            "networks": networks,
+            "pools": pools,
+            "evm_endpoints": self.get_evm_endpoint_health().await,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
     /// Get contract addresses (for script execution)
     pub async fn get_contract_addresses(&self) -> McpResult<Value> {
         debug!("SDK Adapter: Getting contract addresses");
@@ -67,6 +484,81 @@ impl McpSdkAdapter {
         }))
     }
 
+    /// Status of the connected node, with the reasons a transaction
+    /// broadcast to it may be stuck
+    ///
+    /// The mempool count and chain parameters feed the hints when the node
+    /// serves them.
+    pub async fn node_status(&self) -> McpResult<NodeStatusResponse> {
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        let (status, mempool, params) = futures::join!(
+            client.node_status(),
+            client.mempool_status(),
+            client.chain_params()
+        );
+        let node = status.map_err(McpServerError::Sdk)?;
+        let mempool = mempool
+            .inspect_err(|e| debug!("Mempool status unavailable: {}", e))
+            .ok();
+        let params = params
+            .inspect_err(|e| debug!("Chain params unavailable: {}", e))
+            .ok();
+
+        let now = chrono::Utc::now();
+        Ok(NodeStatusResponse {
+            network: network_config.network_name.clone(),
+            chain_id: network_config.chain_id.clone(),
+            block_age_seconds: node.block_age_secs(now),
+            hints: stuck_transaction_hints(&node, mempool.as_ref(), params.as_ref(), now),
+            node,
+            mempool,
+        })
+    }
+
+    /// Block at `height`, or the latest block
+    pub async fn block_summary(&self, height: Option<u64>) -> McpResult<BlockSummary> {
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        client
+            .block_summary(height)
+            .await
+            .map_err(McpServerError::Sdk)
+    }
+
+    /// Validator set at `height`, or at the latest block, keeping the
+    /// `limit` with the most voting power when given
+    pub async fn validator_set(
+        &self,
+        height: Option<u64>,
+        limit: Option<usize>,
+    ) -> McpResult<ValidatorSet> {
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        let mut validators = client
+            .validator_set(height)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        if let Some(limit) = limit {
+            validators.validators.truncate(limit);
+        }
+        Ok(validators)
+    }
+
+    /// Block limits and gas prices of the active network
+    pub async fn chain_params(&self) -> McpResult<ChainParams> {
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        client.chain_params().await.map_err(McpServerError::Sdk)
+    }
+
+    /// Unconfirmed transactions in the connected node's mempool
+    pub async fn mempool_status(&self) -> McpResult<MempoolStatus> {
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        client.mempool_status().await.map_err(McpServerError::Sdk)
+    }
+
     /// Monitor a transaction by hash with timeout (for script execution)
     pub async fn monitor_transaction(
         &self,
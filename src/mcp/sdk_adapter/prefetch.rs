@@ -0,0 +1,108 @@
+//! Background warmup of pool and token metadata
+
+use super::*;
+use crate::mcp::resources::DataResource;
+use std::sync::Weak;
+
+/// What one prefetch pass loaded
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PrefetchReport {
+    pub pools: usize,
+    pub tokens: usize,
+    pub erc20_tokens: usize,
+    /// Steps that failed; the others are still cached
+    pub errors: Vec<String>,
+}
+
+impl McpSdkAdapter {
+    /// Load the pool list, the tokens of the active network and the metadata of
+    /// registry ERC-20 tokens into the cache
+    ///
+    /// Each step runs even when an earlier one fails, so an unreachable EVM
+    /// endpoint does not keep pools cold.
+    pub async fn prefetch(&self) -> PrefetchReport {
+        let mut report = PrefetchReport::default();
+
+        match self.prefetch_resource(&DataResource::Pools).await {
+            Ok(count) => report.pools = count,
+            Err(e) => report.errors.push(format!("pools: {}", e)),
+        }
+
+        match self.get_default_network_config().await {
+            Ok(network_config) => {
+                let tokens = DataResource::Tokens(network_config.chain_id);
+                match self.prefetch_resource(&tokens).await {
+                    Ok(count) => report.tokens = count,
+                    Err(e) => report.errors.push(format!("tokens: {}", e)),
+                }
+            }
+            Err(e) => report.errors.push(format!("tokens: {}", e)),
+        }
+
+        match self.prefetch_erc20_metadata().await {
+            Ok(count) => report.erc20_tokens = count,
+            Err(e) => report.errors.push(format!("erc20: {}", e)),
+        }
+
+        report
+    }
+
+    /// Query a list resource and cache its items, returning how many there are
+    async fn prefetch_resource(&self, resource: &DataResource) -> McpResult<usize> {
+        let items = self.load_resource_items(resource).await?;
+        let count = items.len();
+        self.cache_set(resource.cache_key(), Value::Array(items))
+            .await;
+        Ok(count)
+    }
+
+    /// Refresh the metadata of registry tokens on the configured EVM chain
+    async fn prefetch_erc20_metadata(&self) -> McpResult<usize> {
+        Ok(self.refresh_erc20_registry().await?.refreshed)
+    }
+
+    /// Run [`Self::prefetch`] now and then every `interval` in the background
+    ///
+    /// The task holds a weak reference, so it ends once the adapter is dropped.
+    /// Calling this again replaces the running task.
+    pub async fn start_prefetch(self: &Arc<Self>, interval: Duration) {
+        let adapter: Weak<Self> = Arc::downgrade(self);
+
+        let handle = tokio::spawn(async move {
+            let mut interval_timer = tokio::time::interval(interval);
+
+            loop {
+                interval_timer.tick().await;
+                let Some(adapter) = adapter.upgrade() else {
+                    break;
+                };
+
+                let started = Instant::now();
+                let report = adapter.prefetch().await;
+                if report.errors.is_empty() {
+                    debug!(
+                        "Prefetched {} pools, {} tokens and {} ERC-20 tokens in {:?}",
+                        report.pools,
+                        report.tokens,
+                        report.erc20_tokens,
+                        started.elapsed()
+                    );
+                } else {
+                    warn!("Prefetch incomplete: {}", report.errors.join("; "));
+                }
+            }
+        });
+
+        if let Some(previous) = self.prefetch_handle.lock().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop the background prefetch task
+    pub async fn stop_prefetch(&self) {
+        if let Some(handle) = self.prefetch_handle.lock().await.take() {
+            handle.abort();
+            debug!("Stopped metadata prefetch");
+        }
+    }
+}
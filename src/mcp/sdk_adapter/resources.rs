@@ -0,0 +1,195 @@
+//! Data resources rendered from adapter queries
+
+use super::*;
+use crate::mcp::resources::{self, DataResource, ResourcePage};
+use std::collections::BTreeSet;
+
+/// Campaigns requested per factory query
+const CAMPAIGN_PAGE_SIZE: u16 = 30;
+
+impl McpSdkAdapter {
+    /// Read one page of a data resource
+    ///
+    /// The unpaginated items are cached for the adapter's cache TTL, so paging
+    /// through a resource queries the chain once.
+    pub async fn read_data_resource(
+        &self,
+        resource: &DataResource,
+        page: ResourcePage,
+    ) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Reading resource {} from {}",
+            resource.uri(),
+            page.cursor
+        );
+
+        if *resource == DataResource::ActiveWallet {
+            return self.active_wallet_resource().await;
+        }
+
+        let cache_key = resource.cache_key();
+        let cached = self
+            .cache_get(&cache_key)
+            .await
+            .and_then(|items| items.as_array().cloned());
+        let (items, from_cache) = match cached {
+            Some(items) => (items, true),
+            None => {
+                let items = self.load_resource_items(resource).await?;
+                self.cache_set(cache_key, Value::Array(items.clone())).await;
+                (items, false)
+            }
+        };
+
+        let mut body = resources::paginate(resource, &items, page);
+        body["uri"] = Value::String(resource.uri());
+        body["cached"] = Value::Bool(from_cache);
+        body["timestamp"] = Value::String(chrono::Utc::now().to_rfc3339());
+        Ok(body)
+    }
+
+    /// Query the items of a list resource
+    pub(super) async fn load_resource_items(
+        &self,
+        resource: &DataResource,
+    ) -> McpResult<Vec<Value>> {
+        let network_config = self.get_default_network_config().await?;
+        match resource {
+            DataResource::Pools => {
+                let client = self.get_client(&network_config).await?;
+                let pools = client.get_all_pools().await.map_err(McpServerError::Sdk)?;
+                Ok(pools.iter().map(super::dex::pool_json).collect())
+            }
+            // Tokens on the active network are the assets traded in its pools
+            DataResource::Tokens(chain_id) if *chain_id == network_config.chain_id => {
+                let client = self.get_client(&network_config).await?;
+                let pools = client.get_all_pools().await.map_err(McpServerError::Sdk)?;
+                let mut pool_counts: BTreeMap<String, usize> = BTreeMap::new();
+                let mut decimals: BTreeMap<String, u8> = BTreeMap::new();
+                for pool in &pools {
+                    for (i, asset) in pool.pool_info.assets.iter().enumerate() {
+                        *pool_counts.entry(asset.denom.clone()).or_default() += 1;
+                        if let Some(asset_decimals) = pool.pool_info.asset_decimals.get(i) {
+                            decimals.insert(asset.denom.clone(), *asset_decimals);
+                        }
+                    }
+                }
+                pool_counts
+                    .entry(network_config.native_denom.clone())
+                    .or_default();
+                Ok(pool_counts
+                    .into_iter()
+                    .map(|(denom, pool_count)| {
+                        serde_json::json!({
+                            "denom": denom,
+                            "is_native": denom == network_config.native_denom,
+                            "decimals": decimals.get(&denom),
+                            "pool_count": pool_count,
+                            "source": "pools"
+                        })
+                    })
+                    .collect())
+            }
+            DataResource::Tokens(chain_id) => {
+                let client = MantraClient::new(network_config.clone(), None)
+                    .await
+                    .map_err(McpServerError::Sdk)?;
+                let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+                self.configure_skip_client(&mut skip_client);
+                let assets = skip_client
+                    .list_chain_assets(chain_id)
+                    .await
+                    .map_err(McpServerError::Sdk)?;
+                assets
+                    .into_iter()
+                    .map(|asset| -> McpResult<Value> {
+                        let mut item = serde_json::to_value(asset)?;
+                        item["source"] = Value::String("skip".to_string());
+                        Ok(item)
+                    })
+                    .collect()
+            }
+            DataResource::Campaigns => {
+                let factory_address = self.claimdrop_factory.clone().ok_or_else(|| {
+                    McpServerError::Validation(
+                        "No claimdrop factory configured; set MCP_CLAIMDROP_FACTORY_ADDRESS"
+                            .to_string(),
+                    )
+                })?;
+                let client = MantraClient::new(network_config.clone(), None)
+                    .await
+                    .map_err(McpServerError::Sdk)?;
+                let factory = client.claimdrop_factory(factory_address.clone());
+
+                let mut campaigns = BTreeSet::new();
+                let mut start_after: Option<String> = None;
+                loop {
+                    let page = factory
+                        .query_campaigns(start_after.as_deref(), Some(CAMPAIGN_PAGE_SIZE))
+                        .await
+                        .map_err(McpServerError::Sdk)?;
+                    let page_len = page.campaigns.len();
+                    start_after = page.campaigns.last().cloned();
+                    campaigns.extend(page.campaigns);
+                    if page_len < CAMPAIGN_PAGE_SIZE as usize {
+                        break;
+                    }
+                }
+
+                Ok(campaigns
+                    .into_iter()
+                    .map(|address| {
+                        serde_json::json!({
+                            "address": address,
+                            "factory_address": factory_address
+                        })
+                    })
+                    .collect())
+            }
+            // Not a list; rendered by active_wallet_resource
+            DataResource::ActiveWallet => Ok(vec![]),
+        }
+    }
+
+    /// Address and balances of the active wallet, cached per address
+    async fn active_wallet_resource(&self) -> McpResult<Value> {
+        let uri = DataResource::ActiveWallet.uri();
+        let wallet = match self.get_active_wallet_info().await? {
+            Some(wallet) => wallet,
+            None => {
+                return Ok(serde_json::json!({
+                    "uri": uri,
+                    "active": false,
+                    "timestamp": chrono::Utc::now().to_rfc3339()
+                }))
+            }
+        };
+
+        let cache_key = format!(
+            "{}:{}",
+            DataResource::ActiveWallet.cache_key(),
+            wallet.address
+        );
+        if let Some(mut cached) = self.cache_get(&cache_key).await {
+            cached["cached"] = Value::Bool(true);
+            return Ok(cached);
+        }
+
+        let network_config = self.get_default_network_config().await?;
+        let balances = self
+            .get_balances_for_address_direct(&network_config, &wallet.address)
+            .await?;
+        let body = serde_json::json!({
+            "uri": uri,
+            "active": true,
+            "address": wallet.address,
+            "public_key": wallet.public_key,
+            "network": network_config.chain_id,
+            "balances": balances.get("balances").cloned().unwrap_or_default(),
+            "cached": false,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        });
+        self.cache_set(cache_key, body.clone()).await;
+        Ok(body)
+    }
+}
@@ -0,0 +1,178 @@
+//! Restricted wallet list methods
+
+use super::*;
+use crate::mcp::response_format::MarkdownRender;
+use crate::wallet::restricted_wallets::CsvImport;
+use crate::wallet::RestrictedWallet;
+
+/// Result of `restricted_wallets_list`
+#[derive(Debug, Clone, Serialize)]
+pub struct RestrictedWalletListResponse {
+    pub entries: Vec<RestrictedWallet>,
+    /// File the list is kept in; unset when it is kept in memory only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Result of `restricted_wallets_add` and `restricted_wallets_remove`
+#[derive(Debug, Clone, Serialize)]
+pub struct RestrictedWalletChangeResponse {
+    /// "added", "updated", "removed" or "not_found"
+    pub action: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry: Option<RestrictedWallet>,
+}
+
+/// Result of `restricted_wallets_import`
+#[derive(Debug, Clone, Serialize)]
+pub struct RestrictedWalletImportResponse {
+    #[serde(flatten)]
+    pub import: CsvImport,
+    /// Wallets listed after the import
+    pub total: usize,
+}
+
+impl MarkdownRender for RestrictedWalletListResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!(
+            "🚫 **Restricted Wallets** ({} entries)\n\n",
+            self.entries.len()
+        );
+        if self.entries.is_empty() {
+            response.push_str(
+                "No restricted wallets; add one with `restricted_wallets_add` or import a feed with `restricted_wallets_import`.\n",
+            );
+        }
+        for entry in &self.entries {
+            response.push_str(&format!("- `{}`", entry.address));
+            if let Some(reason) = &entry.reason {
+                response.push_str(&format!(" - {}", reason));
+            }
+            if let Some(source) = &entry.source {
+                response.push_str(&format!(" ({})", source));
+            }
+            response.push('\n');
+        }
+        response
+    }
+}
+
+impl MarkdownRender for RestrictedWalletChangeResponse {
+    fn to_markdown(&self) -> String {
+        match self.action.as_str() {
+            "not_found" => format!("`{}` is not a restricted wallet.\n", self.address),
+            "removed" => format!("🗑️ **Restriction Lifted:** `{}`\n", self.address),
+            action => {
+                let mut response = format!(
+                    "✅ **Wallet Restricted ({}):** `{}`\n",
+                    action, self.address
+                );
+                if let Some(reason) = self.entry.as_ref().and_then(|e| e.reason.as_ref()) {
+                    response.push_str(&format!("**Reason:** {}\n", reason));
+                }
+                response
+            }
+        }
+    }
+}
+
+impl MarkdownRender for RestrictedWalletImportResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!(
+            "📥 **Restricted Wallets Imported**\n\n**Added:** {}\n**Updated:** {}\n**Total listed:** {}\n",
+            self.import.added, self.import.updated, self.total
+        );
+        if !self.import.skipped.is_empty() {
+            response.push_str(&format!(
+                "\n**Skipped {} lines:**\n",
+                self.import.skipped.len()
+            ));
+            for skipped in &self.import.skipped {
+                response.push_str(&format!("- {}\n", skipped));
+            }
+        }
+        response
+    }
+}
+
+impl McpSdkAdapter {
+    /// Restrict `address`, replacing its reason and source when it already is
+    pub async fn restricted_wallets_add(
+        &self,
+        address: &str,
+        reason: Option<String>,
+        source: Option<String>,
+    ) -> McpResult<RestrictedWalletChangeResponse> {
+        let (entry, replaced) = self
+            .restricted_wallets
+            .write()
+            .await
+            .add(address, reason, source)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+        info!("Restricted wallet listed: {}", entry.address);
+
+        Ok(RestrictedWalletChangeResponse {
+            action: if replaced { "updated" } else { "added" }.to_string(),
+            address: entry.address.clone(),
+            entry: Some(entry),
+        })
+    }
+
+    /// Lift the restriction of `address`
+    pub async fn restricted_wallets_remove(
+        &self,
+        address: &str,
+    ) -> McpResult<RestrictedWalletChangeResponse> {
+        let removed = self
+            .restricted_wallets
+            .write()
+            .await
+            .remove(address)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+
+        Ok(RestrictedWalletChangeResponse {
+            action: if removed.is_some() {
+                "removed"
+            } else {
+                "not_found"
+            }
+            .to_string(),
+            address: removed
+                .as_ref()
+                .map(|entry| entry.address.clone())
+                .unwrap_or_else(|| address.to_string()),
+            entry: removed,
+        })
+    }
+
+    /// Restrict every address of a CSV feed, one `address[,reason]` per line
+    pub async fn restricted_wallets_import(
+        &self,
+        csv: &str,
+        source: Option<&str>,
+    ) -> McpResult<RestrictedWalletImportResponse> {
+        let mut list = self.restricted_wallets.write().await;
+        let import = list.import_csv(csv, source).map_err(|e| {
+            McpServerError::Internal(format!("Failed to save restricted wallets: {}", e))
+        })?;
+        info!(
+            "Restricted wallets imported: {} added, {} updated, {} skipped",
+            import.added,
+            import.updated,
+            import.skipped.len()
+        );
+        Ok(RestrictedWalletImportResponse {
+            import,
+            total: list.len(),
+        })
+    }
+
+    pub async fn restricted_wallets_list(&self) -> RestrictedWalletListResponse {
+        let list = self.restricted_wallets.read().await;
+        RestrictedWalletListResponse {
+            entries: list.list(),
+            path: list.path().map(|path| path.display().to_string()),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Session-scoped wallet context methods
+//!
+//! Tool calls are dispatched inside [`McpSdkAdapter::in_session`] with the
+//! caller's MCP session id. Every session other than the default one keeps its
+//! own active wallet, network and custom ERC-20 tokens, so agents sharing a
+//! server do not switch each other's wallet. Whatever a session has not chosen
+//! comes from the server-wide state, which is also the default session's.
+
+use std::future::Future;
+
+use super::*;
+use crate::config::env::EnvironmentConfig;
+use crate::config::NetworkConstants;
+use crate::mcp::server::DEFAULT_SESSION_ID;
+use crate::mcp::state_store::CustomTokenRecord;
+
+tokio::task_local! {
+    /// MCP session of the tool call being dispatched
+    static SESSION: String;
+}
+
+/// Sessions unused for this long are forgotten
+pub(crate) const SESSION_IDLE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Wallet context of one MCP session
+#[derive(Debug, Clone)]
+pub(crate) struct SessionContext {
+    /// Active wallet address, if the session picked one
+    pub(crate) active_wallet: Option<String>,
+    /// Network name, if the session picked one
+    pub(crate) network: Option<String>,
+    /// Custom tokens only this session sees
+    pub(crate) custom_tokens: HashMap<TokenKey, Erc20TokenInfo>,
+    pub(crate) last_used: Instant,
+}
+
+impl SessionContext {
+    fn new() -> Self {
+        Self {
+            active_wallet: None,
+            network: None,
+            custom_tokens: HashMap::new(),
+            last_used: Instant::now(),
+        }
+    }
+}
+
+impl McpSdkAdapter {
+    /// Run `future` as a call from MCP session `session_id`
+    pub async fn in_session<F: Future>(session_id: String, future: F) -> F::Output {
+        SESSION.scope(session_id, future).await
+    }
+
+    /// Session of the current call, unless it is the default session
+    pub(crate) fn current_session() -> Option<String> {
+        SESSION
+            .try_with(|session| session.clone())
+            .ok()
+            .filter(|session| session != DEFAULT_SESSION_ID)
+    }
+
+    /// Context of the current session, if it has one
+    pub(crate) async fn session_context(&self) -> Option<SessionContext> {
+        let session = Self::current_session()?;
+        self.sessions.read().await.get(&session).cloned()
+    }
+
+    /// Change the current session's context, creating it on first use
+    ///
+    /// Returns `None` without calling `update` in the default session, whose
+    /// context is the server-wide state.
+    pub(crate) async fn update_session<R>(
+        &self,
+        update: impl FnOnce(&mut SessionContext) -> R,
+    ) -> Option<R> {
+        let session = Self::current_session()?;
+        let now = Instant::now();
+        let mut sessions = self.sessions.write().await;
+        sessions.retain(|_, context| now.duration_since(context.last_used) < SESSION_IDLE_TTL);
+        let context = sessions.entry(session).or_insert_with(SessionContext::new);
+        context.last_used = now;
+        Some(update(context))
+    }
+
+    /// Active wallet address of the current session
+    pub(crate) async fn active_wallet_address(&self) -> Option<String> {
+        match self
+            .session_context()
+            .await
+            .and_then(|context| context.active_wallet)
+        {
+            Some(address) => Some(address),
+            None => self.active_wallet.lock().await.clone(),
+        }
+    }
+
+    /// Forget `address` as the active wallet of every session
+    pub(crate) async fn clear_session_wallet(&self, address: &str) {
+        for context in self.sessions.write().await.values_mut() {
+            if context.active_wallet.as_deref() == Some(address) {
+                context.active_wallet = None;
+            }
+        }
+    }
+
+    /// Network configuration the current session picked, if any
+    ///
+    /// EVM settings come from the environment only when the session's network
+    /// is the one configured there.
+    pub(crate) async fn session_network_config(&self) -> Option<McpResult<MantraNetworkConfig>> {
+        let network = self.session_context().await?.network?;
+        Some(Self::network_config_for(&network))
+    }
+
+    fn network_config_for(network: &str) -> McpResult<MantraNetworkConfig> {
+        if let Ok(env_config) = EnvironmentConfig::load() {
+            if env_config.get_network_name() == network {
+                return MantraNetworkConfig::from_env_config(&env_config).map_err(|e| {
+                    McpServerError::Internal(format!("Failed to create network config: {}", e))
+                });
+            }
+        }
+
+        let constants = NetworkConstants::load(network).map_err(|e| {
+            McpServerError::Network(format!("Failed to load network '{}': {}", network, e))
+        })?;
+        MantraNetworkConfig::from_constants(&constants)
+            .map_err(|e| McpServerError::Network(format!("Failed to create network config: {}", e)))
+    }
+
+    /// Custom token the current session added at `chain_id`/`address`
+    pub(crate) async fn session_custom_token(
+        &self,
+        chain_id: u64,
+        address: Address,
+    ) -> Option<Erc20TokenInfo> {
+        self.session_context()
+            .await?
+            .custom_tokens
+            .get(&TokenKey::new(chain_id, address))
+            .cloned()
+    }
+
+    /// Describe the current session's wallet context
+    pub async fn get_session_context(&self, _args: Value) -> McpResult<Value> {
+        let session = Self::current_session();
+        let context = self.session_context().await;
+        let session_wallet = context
+            .as_ref()
+            .and_then(|context| context.active_wallet.clone());
+        let wallet_source = if session_wallet.is_some() {
+            "session"
+        } else {
+            "server"
+        };
+        let network = self.get_default_network_config().await?;
+        let custom_tokens: Vec<CustomTokenRecord> = context
+            .as_ref()
+            .map(|context| context.custom_tokens.values().map(Into::into).collect())
+            .unwrap_or_default();
+        let network_source = if context.and_then(|context| context.network).is_some() {
+            "session"
+        } else {
+            "server"
+        };
+
+        Ok(serde_json::json!({
+            "session_id": session.as_deref().unwrap_or(DEFAULT_SESSION_ID),
+            "isolated": session.is_some(),
+            "active_wallet": self.active_wallet_address().await,
+            "wallet_source": wallet_source,
+            "network": network.network_name,
+            "chain_id": network.chain_id,
+            "network_source": network_source,
+            "custom_tokens": custom_tokens,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Pick the network of the current session, or go back to the server's
+    /// network when `network` is omitted
+    pub async fn set_session_network(&self, args: Value) -> McpResult<Value> {
+        let network = args
+            .get("network")
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        if let Some(network) = &network {
+            Self::network_config_for(network)?;
+        }
+
+        self.update_session(|context| context.network = network.clone())
+            .await
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments(
+                    "The default session uses the server's network; identify a session with the \
+                     Mcp-Session-Id header or _meta.session_id to pick another"
+                        .to_string(),
+                )
+            })?;
+        info!(
+            "Session {:?} switched network to {:?}",
+            Self::current_session(),
+            network
+        );
+
+        self.get_session_context(Value::Null).await
+    }
+}
@@ -1,6 +1,8 @@
 //! Skip protocol methods for cross-chain operations
 
 use super::*;
+use crate::protocols::skip::execution::{IbcTimeout, DEFAULT_CROSS_CHAIN_TIMEOUT_SECS};
+use crate::protocols::skip::{ChainAsset, ChainRegistry, SkipClient, SupportedChain};
 
 impl McpSdkAdapter {
     // Skip Protocol Tools
@@ -55,6 +57,13 @@ impl McpSdkAdapter {
             .get("smart_relay")
             .and_then(|v| v.as_bool())
             .unwrap_or(true);
+        use crate::protocols::skip::estimation::{rank_routes, RoutePreference};
+        let preference = args
+            .get("preference")
+            .and_then(|v| v.as_str())
+            .map(RoutePreference::from_str)
+            .transpose()
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
 
         // Parse amount
         let amount = Uint128::from_str(source_asset_amount).map_err(|e| {
@@ -70,7 +79,8 @@ impl McpSdkAdapter {
             .map_err(McpServerError::Sdk)?;
 
         // Get Skip client
-        let skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
 
         // Create source and target assets
         use crate::protocols::skip::types::CrossChainAsset;
@@ -100,10 +110,30 @@ impl McpSdkAdapter {
         });
 
         // Get routes
-        let routes = skip_client
+        let mut routes = skip_client
             .get_route(&source_asset, &target_asset, options)
             .await
             .map_err(McpServerError::Sdk)?;
+        if let Some(preference) = preference {
+            rank_routes(&mut routes, preference);
+        }
+
+        // Indices into `routes` of the cheapest and fastest options
+        let cheapest_route_index = routes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.breakdown.as_ref()?.total_usd.map(|usd| (usd, i)))
+            .min()
+            .map(|(_, i)| i);
+        let fastest_route_index = routes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| {
+                let breakdown = r.breakdown.as_ref()?;
+                Some((breakdown.estimated_completion_seconds, i))
+            })
+            .min()
+            .map(|(_, i)| i);
 
         Ok(serde_json::json!({
             "status": "success",
@@ -119,6 +149,9 @@ impl McpSdkAdapter {
             },
             "routes": routes,
             "route_count": routes.len(),
+            "preference": preference,
+            "cheapest_route_index": cheapest_route_index,
+            "fastest_route_index": fastest_route_index,
             "timestamp": chrono::Utc::now().to_rfc3339()
         }))
     }
@@ -180,17 +213,36 @@ impl McpSdkAdapter {
             McpServerError::InvalidArguments(format!("Invalid source_asset_amount: {}", e))
         })?;
 
+        let dry_run = args
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
         // Get network config and wallet
         let network_config = self.get_default_network_config().await?;
         let wallet = self.get_active_wallet_with_validation().await?;
 
-        // Create MantraClient with wallet
-        let client = MantraClient::new(network_config.clone(), Some(Arc::new(wallet)))
+        // The DEX client signs the source chain transaction
+        let dex_client = self.get_client_with_wallet(&network_config, wallet).await?;
+        let client = MantraClient::new(network_config.clone(), None)
             .await
             .map_err(McpServerError::Sdk)?;
 
         // Get Skip client
-        let skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
+        skip_client.set_dex_client(Arc::new(dex_client));
+        if let Some(entry_points) = args.get("entry_points").and_then(|v| v.as_object()) {
+            for (chain_id, address) in entry_points {
+                let address = address.as_str().ok_or_else(|| {
+                    McpServerError::InvalidArguments(format!(
+                        "Entry point for {} must be a string",
+                        chain_id
+                    ))
+                })?;
+                skip_client.set_entry_point(chain_id.clone(), address.to_string());
+            }
+        }
 
         // Create transfer request
         use crate::protocols::skip::types::{CrossChainAsset, TransferRequest};
@@ -219,9 +271,40 @@ impl McpSdkAdapter {
             route: None, // Let the client find the best route
         };
 
-        // Execute transfer
+        // Reject unknown chains and assets before planning anything
+        let validate_targets = args
+            .get("validate_targets")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        if validate_targets {
+            self.skip_registry_for(&skip_client, &transfer_request)
+                .await?
+                .validate_transfer(&transfer_request)
+                .map_err(|e| McpServerError::Validation(e.to_string()))?;
+        }
+
+        if dry_run {
+            let timeout = IbcTimeout::after_seconds(
+                chrono::Utc::now().timestamp() as u64,
+                timeout_seconds.unwrap_or(DEFAULT_CROSS_CHAIN_TIMEOUT_SECS),
+            );
+            let plan = skip_client
+                .plan_cross_chain_swap(&transfer_request, timeout)
+                .await
+                .map_err(McpServerError::Sdk)?;
+            return Ok(serde_json::json!({
+                "status": "success",
+                "operation": "execute_transfer",
+                "dry_run": true,
+                "plan": plan,
+                "recipient": recipient,
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            }));
+        }
+
+        // Execute the swap, transfers and destination swap in one transaction
         let result = skip_client
-            .execute_cross_chain_transfer(&transfer_request)
+            .execute_cross_chain_swap(&transfer_request)
             .await
             .map_err(McpServerError::Sdk)?;
 
@@ -229,6 +312,7 @@ impl McpSdkAdapter {
             "status": "success",
             "operation": "execute_transfer",
             "transfer_id": result.transfer_id,
+            "dry_run": false,
             "transfer_status": result.status,
             "source_tx_hash": result.source_tx_hash,
             "recipient": recipient,
@@ -257,11 +341,13 @@ impl McpSdkAdapter {
             .map_err(McpServerError::Sdk)?;
 
         // Get Skip client
-        let skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
 
         // Track transfer
+        let chain_id = args.get("chain_id").and_then(|v| v.as_str());
         let result = skip_client
-            .track_transfer(transfer_id)
+            .get_transfer_status(transfer_id, chain_id)
             .await
             .map_err(McpServerError::Sdk)?;
 
@@ -276,6 +362,71 @@ impl McpSdkAdapter {
             "error_message": result.error_message,
             "initiated_at": result.initiated_at,
             "completed_at": result.completed_at,
+            "verification": result.verification,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Poll a transfer until it settles, reporting every status transition
+    pub async fn skip_watch_transfer(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Watching Skip transfer with args: {:?}", args);
+
+        let tx_hash = args
+            .get("tx_hash")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("tx_hash is required".to_string()))?;
+
+        use crate::protocols::skip::TransferTrackingOptions;
+        let defaults = TransferTrackingOptions::default();
+        let timeout_secs = args
+            .get("timeout_secs")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(120)
+            .clamp(1, 600);
+        let poll_interval_secs = args
+            .get("poll_interval_secs")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(defaults.poll_interval.as_secs())
+            .max(1);
+        let options = TransferTrackingOptions {
+            chain_id: args
+                .get("chain_id")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            poll_interval: Duration::from_secs(poll_interval_secs),
+            timeout: Duration::from_secs(timeout_secs),
+            ..defaults
+        };
+
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
+
+        let tracking = skip_client
+            .track_transfer_with(tx_hash, options, |transition| {
+                info!(
+                    "Transfer {} moved {:?} -> {:?}",
+                    transition.tx_hash, transition.from, transition.to
+                );
+            })
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "watch_transfer",
+            "tx_hash": tx_hash,
+            "transfer_status": tracking.result.status,
+            "settled": tracking.result.status.is_terminal(),
+            "timed_out": tracking.timed_out,
+            "dest_tx_hash": tracking.result.dest_tx_hash,
+            "error_message": tracking.result.error_message,
+            "verification": tracking.result.verification,
+            "transitions": tracking.transitions,
+            "polls": tracking.polls,
             "timestamp": chrono::Utc::now().to_rfc3339()
         }))
     }
@@ -299,7 +450,8 @@ impl McpSdkAdapter {
             .map_err(McpServerError::Sdk)?;
 
         // Get Skip client
-        let skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
 
         // Get supported chains
         let mut chains = skip_client
@@ -477,7 +629,8 @@ impl McpSdkAdapter {
             .map_err(McpServerError::Sdk)?;
 
         // Get Skip client
-        let skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
 
         // Create transfer request for fee estimation
         use crate::protocols::skip::types::{CrossChainAsset, TransferRequest};
@@ -530,5 +683,242 @@ impl McpSdkAdapter {
         }))
     }
 
+    /// Report fees accrued by the configured Skip affiliate
+    pub async fn skip_get_affiliate_fees(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Getting affiliate fees with args: {:?}", args);
+
+        if self.skip_affiliate.is_none() {
+            return Err(McpServerError::Validation(
+                "No Skip affiliate configured; set MCP_SKIP_AFFILIATE_ADDRESS and MCP_SKIP_AFFILIATE_BPS"
+                    .to_string(),
+            ));
+        }
+
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        let report = client
+            .get_affiliate_fees()
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "get_affiliate_fees",
+            "affiliate": report.affiliate,
+            "accrued": report.accrued,
+            "balances": report.balances,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Recover a cross-chain transfer whose packet timed out
+    pub async fn skip_recover_transfer(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Recovering Skip transfer with args: {:?}",
+            args
+        );
+
+        let transfer_id = args
+            .get("transfer_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                McpServerError::InvalidArguments("transfer_id is required".to_string())
+            })?;
+        let retry = args.get("retry").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        // The DEX client reads the refund and signs any retry
+        let network_config = self.get_default_network_config().await?;
+        let wallet = self.get_active_wallet_with_validation().await?;
+        let dex_client = self.get_client_with_wallet(&network_config, wallet).await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
+        skip_client.set_dex_client(Arc::new(dex_client));
+        if let Some(entry_points) = args.get("entry_points").and_then(|v| v.as_object()) {
+            for (chain_id, address) in entry_points {
+                let address = address.as_str().ok_or_else(|| {
+                    McpServerError::InvalidArguments(format!(
+                        "Entry point for {} must be a string",
+                        chain_id
+                    ))
+                })?;
+                skip_client.set_entry_point(chain_id.clone(), address.to_string());
+            }
+        }
+
+        let recovery = skip_client
+            .recover_transfer(transfer_id, retry)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "recover_transfer",
+            "transfer_id": recovery.transfer_id,
+            "source_tx_hash": recovery.source_tx_hash,
+            "recovery_state": recovery.state,
+            "transfer_status": recovery.transfer_status,
+            "past_timeout": recovery.past_timeout,
+            "refund": recovery.refund,
+            "retry": recovery.retry,
+            "attempt": recovery.attempt,
+            "message": recovery.message,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// List chains Skip can route between, for chain pickers
+    pub async fn skip_list_chains(&self, args: Value) -> McpResult<Value> {
+        debug!("SDK Adapter: Listing Skip chains with args: {:?}", args);
+
+        let only_available = args
+            .get("only_available")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
+
+        let (mut chains, cached) = self.cached_skip_chains(&skip_client).await?;
+        if only_available {
+            chains.retain(|chain| chain.is_available);
+        }
+
+        let chains: Vec<Value> = chains
+            .iter()
+            .map(|chain| {
+                serde_json::json!({
+                    "chain_id": chain.chain_id,
+                    "chain_name": chain.chain_name,
+                    "chain_type": chain.chain_type,
+                    "is_available": chain.is_available,
+                })
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "list_chains",
+            "chains": chains,
+            "chain_count": chains.len(),
+            "only_available": only_available,
+            "cached": cached,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// List the assets Skip knows on a chain, for asset pickers
+    pub async fn skip_list_chain_assets(&self, args: Value) -> McpResult<Value> {
+        debug!(
+            "SDK Adapter: Listing Skip chain assets with args: {:?}",
+            args
+        );
+
+        let chain_id = args
+            .get("chain_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("chain_id is required".to_string()))?;
+        let search = args
+            .get("search")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_lowercase());
+
+        let network_config = self.get_default_network_config().await?;
+        let client = MantraClient::new(network_config.clone(), None)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let mut skip_client = client.skip().await.map_err(McpServerError::Sdk)?;
+        self.configure_skip_client(&mut skip_client);
+
+        let (mut assets, cached) = self
+            .cached_skip_chain_assets(&skip_client, chain_id)
+            .await?;
+        if let Some(search) = &search {
+            assets.retain(|asset| {
+                asset.symbol.to_lowercase().contains(search)
+                    || asset.denom.to_lowercase().contains(search)
+            });
+        }
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "list_chain_assets",
+            "chain_id": chain_id,
+            "assets": assets,
+            "asset_count": assets.len(),
+            "search": search,
+            "cached": cached,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+
+    /// Supported chains, from the cache when fresh; the flag tells whether
+    /// the cache answered
+    async fn cached_skip_chains(
+        &self,
+        skip_client: &SkipClient,
+    ) -> McpResult<(Vec<SupportedChain>, bool)> {
+        let cache_key = "skip:chains".to_string();
+        if let Some(cached) = self.cache_get(&cache_key).await {
+            if let Ok(chains) = serde_json::from_value(cached) {
+                return Ok((chains, true));
+            }
+        }
+
+        let chains = skip_client
+            .list_supported_chains(false)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        self.cache_set(cache_key, serde_json::to_value(&chains)?)
+            .await;
+        Ok((chains, false))
+    }
+
+    /// Assets of `chain_id`, from the cache when fresh
+    pub(super) async fn cached_skip_chain_assets(
+        &self,
+        skip_client: &SkipClient,
+        chain_id: &str,
+    ) -> McpResult<(Vec<ChainAsset>, bool)> {
+        let cache_key = format!("skip:assets:{}", chain_id);
+        if let Some(cached) = self.cache_get(&cache_key).await {
+            if let Ok(assets) = serde_json::from_value(cached) {
+                return Ok((assets, true));
+            }
+        }
+
+        let assets = skip_client
+            .list_chain_assets(chain_id)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        self.cache_set(cache_key, serde_json::to_value(&assets)?)
+            .await;
+        Ok((assets, false))
+    }
+
+    /// Cached registry covering both ends of `request`
+    async fn skip_registry_for(
+        &self,
+        skip_client: &SkipClient,
+        request: &crate::protocols::skip::TransferRequest,
+    ) -> McpResult<ChainRegistry> {
+        let (chains, _) = self.cached_skip_chains(skip_client).await?;
+        let mut registry = ChainRegistry::new(chains);
+        for chain_id in [&request.source_asset.chain, &request.target_asset.chain] {
+            if registry.chain(chain_id).is_some() && !registry.assets.contains_key(chain_id) {
+                let (assets, _) = self.cached_skip_chain_assets(skip_client, chain_id).await?;
+                registry = registry.with_assets(chain_id.clone(), assets);
+            }
+        }
+        Ok(registry)
+    }
+
     // =============================================================================
 }
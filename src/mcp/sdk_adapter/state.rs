@@ -0,0 +1,194 @@
+//! Event-sourced state methods
+
+use super::*;
+
+use crate::mcp::state_store::{AdapterState, CustomTokenRecord, EventStore, StateEvent};
+
+/// Events returned by the audit tool when no limit is given
+const DEFAULT_AUDIT_EVENT_LIMIT: usize = 100;
+
+impl McpSdkAdapter {
+    /// Replace the state store and rebuild the adapter state from its log
+    pub fn with_state_store(mut self, store: EventStore) -> Self {
+        let state = store.state().clone();
+
+        self.wallets = Arc::new(RwLock::new(state.wallets.into_iter().collect()));
+        self.wallet_derivation_cache =
+            Arc::new(RwLock::new(state.derivation_indices.into_iter().collect()));
+        self.active_wallet = Arc::new(Mutex::new(state.active_wallet));
+        self.active_wallet_instance = Arc::new(Mutex::new(None));
+
+        if let Some(registry) = Arc::get_mut(&mut self.erc20_registry) {
+            let registry = registry.get_mut();
+            for token in state.custom_tokens.values() {
+                match custom_token_info(token) {
+                    Ok(info) => {
+                        if let Err(e) = registry.upsert_custom(info) {
+                            warn!("Failed to persist restored custom token: {}", e);
+                        }
+                    }
+                    Err(e) => warn!("Skipping restored custom token: {}", e),
+                }
+            }
+        }
+
+        info!(
+            "Restored adapter state from {} events",
+            store.head_sequence()
+        );
+        self.state_store = Arc::new(Mutex::new(store));
+        self
+    }
+
+    /// Record a state change and apply it to the live state
+    ///
+    /// The event is persisted first, so a crash after this returns can always
+    /// be recovered by replaying the log.
+    pub(crate) async fn apply_state_event(&self, event: StateEvent) -> McpResult<()> {
+        // Holding the store lock serializes changes in log order
+        let mut store = self.state_store.lock().await;
+        let recorded = store.append(event).map_err(|e| {
+            McpServerError::Internal(format!("Failed to record state event: {}", e))
+        })?;
+        debug!("Recorded state event {}", recorded.sequence);
+
+        match recorded.event {
+            StateEvent::WalletAdded {
+                wallet,
+                derivation_index,
+            } => {
+                if let Some(index) = derivation_index {
+                    self.wallet_derivation_cache
+                        .write()
+                        .await
+                        .insert(wallet.address.clone(), index);
+                }
+                self.wallets
+                    .write()
+                    .await
+                    .insert(wallet.address.clone(), wallet);
+            }
+            StateEvent::WalletRemoved { address } => {
+                self.wallets.write().await.remove(&address);
+                self.wallet_derivation_cache.write().await.remove(&address);
+                self.wallet_derivation_options
+                    .write()
+                    .await
+                    .remove(&address);
+                let mut active_wallet = self.active_wallet.lock().await;
+                if active_wallet.as_deref() == Some(address.as_str()) {
+                    *active_wallet = None;
+                    *self.active_wallet_instance.lock().await = None;
+                }
+                self.clear_session_wallet(&address).await;
+            }
+            StateEvent::ActiveWalletChanged { address } => {
+                *self.active_wallet.lock().await = address;
+                // The instance belonged to the previous wallet and is recreated on demand
+                *self.active_wallet_instance.lock().await = None;
+            }
+            StateEvent::CustomTokenAdded { token } => {
+                let info = custom_token_info(&token)?;
+                self.erc20_registry
+                    .write()
+                    .await
+                    .upsert_custom(info)
+                    .map_err(|e| McpServerError::Internal(e.to_string()))?;
+            }
+            StateEvent::CustomTokenRemoved { chain_id, address } => {
+                let address = parse_token_address(&address)?;
+                self.erc20_registry
+                    .write()
+                    .await
+                    .remove_custom(chain_id, &address)
+                    .map_err(|e| McpServerError::Internal(e.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the live state matches a replay of the event log
+    async fn live_state_matches(&self, state: &AdapterState) -> bool {
+        let wallets = self.wallets.read().await;
+        let derivation_cache = self.wallet_derivation_cache.read().await;
+        let active_wallet = self.active_wallet.lock().await.clone();
+
+        wallets.len() == state.wallets.len()
+            && state.wallets.keys().all(|address| wallets.contains_key(address))
+            // The cache may also hold indices discovered by address lookups
+            && state
+                .derivation_indices
+                .iter()
+                .all(|(address, index)| derivation_cache.get(address) == Some(index))
+            && active_wallet == state.active_wallet
+    }
+
+    /// List recorded state events and the state they replay to
+    pub async fn state_audit(&self, args: Value) -> McpResult<Value> {
+        debug!("Auditing adapter state with args: {:?}", args);
+
+        let since = args.get("since").and_then(|v| v.as_u64()).unwrap_or(0);
+        let limit = args
+            .get("limit")
+            .and_then(|v| v.as_u64())
+            .map(|v| v as usize)
+            .unwrap_or(DEFAULT_AUDIT_EVENT_LIMIT);
+        let as_of = args.get("as_of").and_then(|v| v.as_u64());
+
+        let store = self.state_store.lock().await;
+        let head_sequence = store.head_sequence();
+        let events = store.events_since(since, limit).to_vec();
+        let replayed = match as_of {
+            Some(sequence) => store.state_at(sequence),
+            None => store.state().clone(),
+        };
+        let consistent = self.live_state_matches(store.state()).await;
+        let log_path = store.path().map(|path| path.display().to_string());
+        drop(store);
+
+        Ok(serde_json::json!({
+            "status": "success",
+            "operation": "state_audit",
+            "head_sequence": head_sequence,
+            "log_path": log_path,
+            "events": events,
+            "state": replayed,
+            "as_of": as_of.unwrap_or(head_sequence),
+            "consistent": consistent,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+}
+
+fn parse_token_address(address: &str) -> McpResult<Address> {
+    Address::from_str(address).map_err(|e| {
+        McpServerError::InvalidArguments(format!("Invalid token address {}: {}", address, e))
+    })
+}
+
+fn custom_token_info(token: &CustomTokenRecord) -> McpResult<Erc20TokenInfo> {
+    Ok(Erc20TokenInfo {
+        address: parse_token_address(&token.address)?,
+        symbol: token.symbol.clone(),
+        name: token.name.clone(),
+        decimals: token.decimals,
+        chain_id: token.chain_id,
+        last_refreshed: None,
+        source: TokenSource::Custom,
+        code_fingerprint: None,
+        implementation: None,
+        last_used: None,
+    })
+}
+
+impl From<&Erc20TokenInfo> for CustomTokenRecord {
+    fn from(info: &Erc20TokenInfo) -> Self {
+        Self {
+            chain_id: info.chain_id,
+            address: info.checksummed_address(),
+            symbol: info.symbol.clone(),
+            name: info.name.clone(),
+            decimals: info.decimals,
+        }
+    }
+}
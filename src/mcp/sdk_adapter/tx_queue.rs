@@ -0,0 +1,132 @@
+//! Monitoring of the transactions persisted before broadcast
+
+use super::*;
+use crate::reorg::ReorgLog;
+use crate::tx_queue::{QueuedTxStatus, TxQueue, TxQueueChange, TxQueuePolicy, TxQueueUpdate};
+use std::sync::Weak;
+
+impl McpSdkAdapter {
+    /// Persist signed transactions to `queue` instead of keeping them in memory
+    pub fn with_tx_queue(mut self, queue: TxQueue) -> Self {
+        self.tx_queue = queue;
+        self
+    }
+
+    /// Queued transactions, newest first
+    pub fn queued_transactions(&self, unfinished_only: bool) -> Value {
+        let mut entries = if unfinished_only {
+            self.tx_queue.unfinished()
+        } else {
+            self.tx_queue.entries()
+        };
+        entries.sort_by_key(|e| std::cmp::Reverse(e.queued_at));
+        let stuck = entries
+            .iter()
+            .filter(|tx| tx.status == QueuedTxStatus::Stuck)
+            .count();
+
+        serde_json::json!({
+            "count": entries.len(),
+            "stuck": stuck,
+            "path": self.tx_queue.path().map(|path| path.display().to_string()),
+            "transactions": entries,
+            "reorgs": ReorgLog::global().recent(),
+        })
+    }
+
+    /// Look up the unfinished transactions of the active network on both VMs,
+    /// resending or escalating the missing ones
+    pub async fn poll_tx_queue(&self, policy: &TxQueuePolicy) -> McpResult<Vec<TxQueueUpdate>> {
+        if self.tx_queue.unfinished().is_empty()
+            && self
+                .tx_queue
+                .recent_confirmations(policy.reorg_window)
+                .is_empty()
+        {
+            return Ok(Vec::new());
+        }
+        let network_config = self.get_default_network_config().await?;
+
+        let client = self.get_client(&network_config).await?;
+        let mut updates = self
+            .tx_queue
+            .poll(&client, policy)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        if network_config.evm_rpc_url.is_some() {
+            let (evm_client, _) = self.get_evm_client().await?;
+            updates.extend(
+                self.tx_queue
+                    .poll(&evm_client, policy)
+                    .await
+                    .map_err(McpServerError::Sdk)?,
+            );
+        }
+        Ok(updates)
+    }
+
+    /// Run [`Self::poll_tx_queue`] every `policy.poll_interval` in the background
+    ///
+    /// Transactions left unfinished by a previous process are picked up on the
+    /// first poll. The task holds a weak reference and is replaced when this
+    /// is called again, like [`Self::start_prefetch`].
+    pub async fn start_tx_queue_monitor(self: &Arc<Self>, policy: TxQueuePolicy) {
+        let adapter: Weak<Self> = Arc::downgrade(self);
+
+        let handle = tokio::spawn(async move {
+            let mut interval_timer = tokio::time::interval(policy.poll_interval);
+
+            loop {
+                interval_timer.tick().await;
+                let Some(adapter) = adapter.upgrade() else {
+                    break;
+                };
+
+                match adapter.poll_tx_queue(&policy).await {
+                    Ok(updates) => updates.iter().for_each(log_tx_queue_update),
+                    Err(e) => debug!("Transaction queue poll failed: {}", e),
+                }
+            }
+        });
+
+        if let Some(previous) = self.tx_queue_handle.lock().await.replace(handle) {
+            previous.abort();
+        }
+    }
+
+    /// Stop the background transaction queue monitor
+    pub async fn stop_tx_queue_monitor(&self) {
+        if let Some(handle) = self.tx_queue_handle.lock().await.take() {
+            handle.abort();
+            debug!("Stopped transaction queue monitor");
+        }
+    }
+}
+
+fn log_tx_queue_update(update: &TxQueueUpdate) {
+    let tx = &update.tx;
+    match update.change {
+        TxQueueChange::Confirmed => info!("Queued transaction {} confirmed", tx.hash),
+        TxQueueChange::Failed => match &tx.status {
+            QueuedTxStatus::Failed { reason } => {
+                warn!("Queued transaction {} failed: {}", tx.hash, reason)
+            }
+            _ => warn!("Queued transaction {} failed", tx.hash),
+        },
+        TxQueueChange::Resent => info!(
+            "Resent queued transaction {} (attempt {})",
+            tx.hash, tx.attempts
+        ),
+        TxQueueChange::Stuck => warn!(
+            "Transaction {} ({}) is stuck after {} broadcasts and will not be resent",
+            tx.hash, tx.label, tx.attempts
+        ),
+        TxQueueChange::Reorged => warn!(
+            "Confirmed transaction {} ({}) is pending again: {}",
+            tx.hash,
+            tx.label,
+            tx.last_error.as_deref().unwrap_or("its block was replaced")
+        ),
+    }
+}
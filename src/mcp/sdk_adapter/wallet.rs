@@ -1,6 +1,7 @@
 //! Wallet management methods
 
 use super::*;
+use crate::mcp::response_format::MarkdownRender;
 
 impl McpSdkAdapter {
     pub async fn get_active_wallet(&self) -> McpResult<Option<MantraWallet>> {
@@ -8,7 +9,7 @@ impl McpSdkAdapter {
         use std::env;
 
         // Check if we have an active wallet address
-        let active_address = self.active_wallet.lock().await.clone();
+        let active_address = self.active_wallet_address().await;
         if active_address.is_none() {
             return Ok(None);
         }
@@ -20,7 +21,12 @@ impl McpSdkAdapter {
                     // Check cache for derivation index
                     let cache = self.wallet_derivation_cache.read().await;
                     if let Some(&derivation_index) = cache.get(active_addr) {
-                        match MantraWallet::from_mnemonic(&mnemonic, derivation_index) {
+                        let options = self.derivation_options(active_addr).await;
+                        match MantraWallet::from_mnemonic_with_options(
+                            &mnemonic,
+                            derivation_index,
+                            &options,
+                        ) {
                             Ok(wallet) => {
                                 debug!("Recreated active wallet instance from WALLET_MNEMONIC using cached index {}", derivation_index);
                                 return Ok(Some(wallet));
@@ -45,8 +51,13 @@ impl McpSdkAdapter {
             }
         }
 
-        // Fall back to stored instance if available (though this will consume it)
-        let wallet = self.active_wallet_instance.lock().await.take();
+        // Fall back to stored instance if available (though this will consume it).
+        // It is the server-wide wallet, so sessions that picked another skip it.
+        let mut instance = self.active_wallet_instance.lock().await;
+        if instance.as_ref().map(|wallet| wallet.info().address) != active_address {
+            return Ok(None);
+        }
+        let wallet = instance.take();
         if wallet.is_some() {
             debug!("Using stored wallet instance (will be consumed)");
         }
@@ -55,7 +66,7 @@ impl McpSdkAdapter {
 
     /// Get the currently active wallet info
     pub async fn get_active_wallet_info(&self) -> McpResult<Option<WalletInfo>> {
-        let active_address = self.active_wallet.lock().await.clone();
+        let active_address = self.active_wallet_address().await;
         if let Some(address) = active_address {
             let wallets = self.wallets.read().await;
             Ok(wallets.get(&address).cloned())
@@ -72,11 +83,17 @@ impl McpSdkAdapter {
         wallet_info: WalletInfo,
     ) -> McpResult<()> {
         // Store the wallet info and set as active
-        self.wallets
-            .write()
-            .await
-            .insert(address.clone(), wallet_info);
-        *self.active_wallet.lock().await = Some(address.clone());
+        if !self.wallet_exists(&address).await {
+            self.apply_state_event(StateEvent::WalletAdded {
+                wallet: wallet_info,
+                derivation_index: None,
+            })
+            .await?;
+        }
+        self.apply_state_event(StateEvent::ActiveWalletChanged {
+            address: Some(address.clone()),
+        })
+        .await?;
 
         info!("Set active wallet: {}", address);
         Ok(())
@@ -87,14 +104,18 @@ impl McpSdkAdapter {
         let wallet_info = wallet.info();
         let address = wallet_info.address.clone();
 
-        // Store the wallet info
-        self.wallets
-            .write()
-            .await
-            .insert(address.clone(), wallet_info);
-
-        // Set as active
-        *self.active_wallet.lock().await = Some(address.clone());
+        // Store the wallet info and set as active
+        if !self.wallet_exists(&address).await {
+            self.apply_state_event(StateEvent::WalletAdded {
+                wallet: wallet_info,
+                derivation_index: None,
+            })
+            .await?;
+        }
+        self.apply_state_event(StateEvent::ActiveWalletChanged {
+            address: Some(address.clone()),
+        })
+        .await?;
 
         // Store the wallet instance
         *self.active_wallet_instance.lock().await = Some(wallet);
@@ -122,11 +143,11 @@ impl McpSdkAdapter {
         let wallet_info = wallet.info();
         let address = wallet_info.address.clone();
 
-        // Store the wallet info
-        self.wallets
-            .write()
-            .await
-            .insert(address.clone(), wallet_info);
+        self.apply_state_event(StateEvent::WalletAdded {
+            wallet: wallet_info,
+            derivation_index: None,
+        })
+        .await?;
 
         info!("Added new wallet: {}", address);
         Ok(address)
@@ -141,17 +162,12 @@ impl McpSdkAdapter {
         let wallet_info = wallet.info();
         let address = wallet_info.address.clone();
 
-        // Store the wallet info
-        self.wallets
-            .write()
-            .await
-            .insert(address.clone(), wallet_info);
-
-        // Cache the derivation index for efficient wallet recreation
-        {
-            let mut cache = self.wallet_derivation_cache.write().await;
-            cache.insert(address.clone(), derivation_index);
-        }
+        // The derivation index is cached for efficient wallet recreation
+        self.apply_state_event(StateEvent::WalletAdded {
+            wallet: wallet_info,
+            derivation_index: Some(derivation_index),
+        })
+        .await?;
 
         info!(
             "Added new wallet: {} with derivation index: {}",
@@ -160,49 +176,166 @@ impl McpSdkAdapter {
         Ok(address)
     }
 
-    /// Remove a wallet from the collection
-    pub async fn remove_wallet(&self, address: &str) -> McpResult<()> {
-        let mut wallets = self.wallets.write().await;
-
-        if wallets.remove(address).is_some() {
-            // Clear derivation cache entry
-            {
-                let mut cache = self.wallet_derivation_cache.write().await;
-                cache.remove(address);
+    /// Find the used derivation indices of `mnemonic`, derived with
+    /// `options`, on the default network
+    ///
+    /// Each index's Cosmos balances and sequence are looked up, and its EVM
+    /// balance and nonce when an EVM endpoint is configured. With `import`,
+    /// the discovered wallets missing from the collection are added to it.
+    pub async fn discover_wallets(
+        &self,
+        mnemonic: &str,
+        options: DerivationOptions,
+        gap_limit: u32,
+        import: bool,
+    ) -> McpResult<WalletDiscoveryResponse> {
+        let network_config = self.get_default_network_config().await?;
+        let client = self.get_client(&network_config).await?;
+        let evm_client = self.get_evm_client().await.ok().map(|(client, _)| client);
+        let (client, evm_client) = (&client, evm_client.as_ref());
+
+        let probe = move |addresses: crate::wallet::multivm::DerivedAddresses| async move {
+            let (balances, cosmos_sequence) = futures::try_join!(
+                client.get_balances_for_address(&addresses.cosmos_address),
+                client.get_account_sequence(&addresses.cosmos_address),
+            )?;
+            let mut activity = crate::wallet::discovery::AccountActivity {
+                balances: balances
+                    .into_iter()
+                    .filter(|coin| !coin.amount.is_zero())
+                    .collect(),
+                cosmos_sequence: cosmos_sequence.unwrap_or_default(),
+                ..Default::default()
+            };
+            if let (Some(evm_client), Some(evm_address)) = (evm_client, &addresses.evm_address) {
+                let evm_address = crate::protocols::evm::types::EthAddress(
+                    Address::from_str(evm_address)
+                        .map_err(|e| crate::Error::Wallet(format!("Invalid EVM address: {}", e)))?,
+                );
+                let (balance, nonce) = futures::try_join!(
+                    evm_client.get_balance(evm_address.clone(), None),
+                    evm_client.get_transaction_count(evm_address, None),
+                )?;
+                activity.evm_balance = Some(balance.to_string());
+                activity.evm_nonce = nonce;
             }
+            Ok(activity)
+        };
+        let discovery = crate::wallet::discover_wallets(mnemonic, &options, gap_limit, probe)
+            .await
+            .map_err(|e| match e {
+                crate::Error::Wallet(message) => McpServerError::InvalidArguments(message),
+                e => McpServerError::Sdk(e),
+            })?;
+        info!(
+            "Discovered {} used wallets in {} derivation indices",
+            discovery.wallets.len(),
+            discovery.scanned
+        );
 
-            // If this was the active wallet, clear the active wallet
-            let mut active_wallet = self.active_wallet.lock().await;
-            if active_wallet.as_ref() == Some(&address.to_string()) {
-                *active_wallet = None;
-                *self.active_wallet_instance.lock().await = None;
+        let mut imported = Vec::new();
+        if import {
+            for wallet in &discovery.wallets {
+                if self.wallet_exists(&wallet.cosmos_address).await {
+                    continue;
+                }
+                let instance =
+                    MantraWallet::from_mnemonic_with_options(mnemonic, wallet.index, &options)
+                        .map_err(McpServerError::Sdk)?;
+                imported.push(
+                    self.add_wallet_with_derivation_options(
+                        instance,
+                        wallet.index,
+                        options.clone(),
+                    )
+                    .await?,
+                );
             }
-            info!("Removed wallet: {}", address);
-            Ok(())
+        }
+
+        Ok(WalletDiscoveryResponse {
+            discovery,
+            imported,
+            network: network_config.chain_id,
+        })
+    }
+
+    /// Add a wallet derived with a passphrase or a custom HD path
+    ///
+    /// The options are remembered for this process only, so the wallet is
+    /// derived the same way when it signs.
+    pub async fn add_wallet_with_derivation_options(
+        &self,
+        wallet: MantraWallet,
+        derivation_index: u32,
+        options: DerivationOptions,
+    ) -> McpResult<String> {
+        let address = self
+            .add_wallet_with_derivation_index(wallet, derivation_index)
+            .await?;
+        let mut all_options = self.wallet_derivation_options.write().await;
+        if options.is_default() {
+            all_options.remove(&address);
         } else {
-            Err(McpServerError::InvalidArguments(format!(
+            all_options.insert(address.clone(), options);
+        }
+        Ok(address)
+    }
+
+    /// Passphrase and HD path `address` was derived with
+    async fn derivation_options(&self, address: &str) -> DerivationOptions {
+        self.wallet_derivation_options
+            .read()
+            .await
+            .get(address)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Remove a wallet from the collection
+    pub async fn remove_wallet(&self, address: &str) -> McpResult<()> {
+        if !self.wallet_exists(address).await {
+            return Err(McpServerError::InvalidArguments(format!(
                 "Wallet not found: {}",
                 address
-            )))
+            )));
         }
+
+        // Also clears the derivation cache entry and the active wallet if it was active
+        self.apply_state_event(StateEvent::WalletRemoved {
+            address: address.to_string(),
+        })
+        .await?;
+        info!("Removed wallet: {}", address);
+        Ok(())
     }
 
     /// Switch active wallet to a different address
+    ///
+    /// Outside the default session only the current session switches.
     pub async fn switch_active_wallet(&self, address: &str) -> McpResult<()> {
-        let wallets = self.wallets.read().await;
-
-        if let Some(_wallet_info) = wallets.get(address) {
-            *self.active_wallet.lock().await = Some(address.to_string());
-            // Clear the wallet instance - will be recreated when needed
-            *self.active_wallet_instance.lock().await = None;
-            info!("Switched active wallet to: {}", address);
-            Ok(())
-        } else {
-            Err(McpServerError::InvalidArguments(format!(
+        if !self.wallet_exists(address).await {
+            return Err(McpServerError::InvalidArguments(format!(
                 "Wallet not found: {}",
                 address
-            )))
+            )));
+        }
+
+        let switched = self
+            .update_session(|context| context.active_wallet = Some(address.to_string()))
+            .await;
+        if switched.is_some() {
+            info!("Switched session active wallet to: {}", address);
+            return Ok(());
         }
+
+        // The wallet instance is cleared and recreated when needed
+        self.apply_state_event(StateEvent::ActiveWalletChanged {
+            address: Some(address.to_string()),
+        })
+        .await?;
+        info!("Switched active wallet to: {}", address);
+        Ok(())
     }
 
     /// Get wallet info by address
@@ -245,7 +378,12 @@ impl McpSdkAdapter {
         {
             let cache = self.wallet_derivation_cache.read().await;
             if let Some(&derivation_index) = cache.get(address) {
-                match MultiVMWallet::from_mnemonic(&mnemonic, derivation_index) {
+                let options = self.derivation_options(address).await;
+                match MultiVMWallet::from_mnemonic_with_options(
+                    &mnemonic,
+                    derivation_index,
+                    options,
+                ) {
                     Ok(wallet) => {
                         // Verify the Cosmos address matches
                         if let Ok(cosmos_addr) = wallet.cosmos_address() {
@@ -334,7 +472,12 @@ impl McpSdkAdapter {
         {
             let cache = self.wallet_derivation_cache.read().await;
             if let Some(&derivation_index) = cache.get(address) {
-                match MantraWallet::from_mnemonic(&mnemonic, derivation_index) {
+                let options = self.derivation_options(address).await;
+                match MantraWallet::from_mnemonic_with_options(
+                    &mnemonic,
+                    derivation_index,
+                    &options,
+                ) {
                     Ok(wallet) => {
                         if wallet.info().address == address {
                             debug!(
@@ -484,13 +627,22 @@ impl McpSdkAdapter {
             wallet_address
         );
 
+        // Symbols and decimals of each denom, for human-readable amounts
+        let denoms: Vec<String> = balances.iter().map(|coin| coin.denom.clone()).collect();
+        let metadata = self.resolve_denom_metadata(network_config, &denoms).await?;
+
         // Convert to JSON format
         let balance_json: Vec<Value> = balances
             .into_iter()
-            .map(|coin| {
+            .zip(metadata)
+            .map(|(coin, metadata)| {
                 serde_json::json!({
                     "denom": coin.denom,
-                    "amount": coin.amount.to_string()
+                    "amount": coin.amount.to_string(),
+                    "symbol": metadata.symbol,
+                    "name": metadata.name,
+                    "decimals": metadata.decimals,
+                    "formatted_amount": metadata.format_amount(coin.amount),
                 })
             })
             .collect();
@@ -540,4 +692,280 @@ impl McpSdkAdapter {
         self.get_balances_for_address_direct(network_config, &address)
             .await
     }
+
+    /// Fund a wallet from the testnet faucet and wait for its balance to
+    /// rise
+    ///
+    /// # Arguments
+    ///
+    /// * `network_config` - Network the wallet is funded on
+    /// * `wallet_address` - Optional specific wallet address, uses active wallet if None
+    pub async fn faucet_request(
+        &self,
+        network_config: &MantraNetworkConfig,
+        wallet_address: Option<String>,
+    ) -> McpResult<Value> {
+        let address = match wallet_address {
+            Some(address) => address,
+            None => match self.get_active_wallet_info().await? {
+                Some(wallet_info) => wallet_info.address,
+                None => return Err(McpServerError::WalletNotConfigured),
+            },
+        };
+
+        info!("Requesting faucet funds for address: {}", address);
+        let client = self.get_client(network_config).await?;
+        let receipt = client
+            .faucet_request(&address)
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        Ok(serde_json::json!({
+            "status": if receipt.funded { "funded" } else { "pending" },
+            "address": receipt.address,
+            "denom": receipt.denom,
+            "received": receipt.received().to_string(),
+            "balance_before": receipt.balance_before.to_string(),
+            "balance_after": receipt.balance_after.to_string(),
+            "next_request_at": receipt.next_request_at.to_rfc3339(),
+            "network": network_config.chain_id,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        }))
+    }
+}
+
+/// Balance of one denom held by a wallet
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenBalance {
+    pub token: String,
+    pub amount: String,
+    pub denom: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletBalancesResponse {
+    pub address: String,
+    pub network: String,
+    pub balances: Vec<TokenBalance>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_om: Option<f64>,
+}
+
+/// Wallet of the collection and whether it is the active one
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletEntry {
+    pub address: String,
+    pub public_key: String,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletListResponse {
+    pub active_wallet: Option<String>,
+    pub wallets: Vec<WalletEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveWalletResponse {
+    pub active_wallet: Option<WalletInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletSwitchResponse {
+    pub active_wallet: String,
+    pub public_key: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletAddedResponse {
+    pub address: String,
+    pub public_key: String,
+    pub derivation_index: u32,
+    pub active: bool,
+}
+
+/// Result of `wallet_discover`
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletDiscoveryResponse {
+    #[serde(flatten)]
+    pub discovery: crate::wallet::WalletDiscovery,
+    /// Addresses added to the wallet collection
+    pub imported: Vec<String>,
+    pub network: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletRemovedResponse {
+    pub address: String,
+    pub public_key: Option<String>,
+    pub was_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletAddressesResponse {
+    pub cosmos_address: String,
+    pub evm_address: String,
+}
+
+impl MarkdownRender for WalletBalancesResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🏦 **Wallet Balances**\n\n".to_string();
+        response.push_str(&format!("**Address:** `{}`\n", self.address));
+        response.push_str(&format!("**Network:** {}\n", self.network));
+        response.push_str(&format!("**Total Tokens:** {}\n\n", self.balances.len()));
+
+        if self.balances.is_empty() {
+            response.push_str("No tokens found in wallet.\n");
+            return response;
+        }
+
+        response.push_str("### 💰 Token Holdings:\n\n");
+        for balance in &self.balances {
+            response.push_str(&format!("- **{}**: {}\n", balance.token, balance.amount));
+            response.push_str(&format!("  - **Full Denom:** `{}`\n", balance.denom));
+        }
+        if let Some(total_om) = self.total_om {
+            response.push_str(&format!("\n**Total OM Value:** {:.2} OM\n", total_om));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for WalletListResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "📱 **Wallet Management**\n\n".to_string();
+        if self.wallets.is_empty() {
+            response.push_str("No wallets found in collection.\n");
+            return response;
+        }
+
+        response.push_str(&format!("**Total Wallets:** {}\n", self.wallets.len()));
+        match &self.active_wallet {
+            Some(address) => response.push_str(&format!("**Active Wallet:** `{}`\n\n", address)),
+            None => response.push_str("**Active Wallet:** None\n\n"),
+        }
+
+        response.push_str("### 💼 Available Wallets:\n\n");
+        for wallet in &self.wallets {
+            let active_indicator = if wallet.active { " (ACTIVE)" } else { "" };
+            response.push_str(&format!(
+                "- **Address:** `{}`{}\n",
+                wallet.address, active_indicator
+            ));
+            response.push_str(&format!("  - **Public Key:** `{}`\n\n", wallet.public_key));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for ActiveWalletResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔍 **Active Wallet Information**\n\n".to_string();
+        match &self.active_wallet {
+            Some(wallet_info) => {
+                response.push_str(&format!("**Address:** `{}`\n", wallet_info.address));
+                response.push_str(&format!("**Public Key:** `{}`\n", wallet_info.public_key));
+                response.push_str("\n**Status:** Active and ready for use\n");
+            }
+            None => {
+                response.push_str("**Status:** No active wallet configured\n");
+                response.push_str("Please add a wallet using `add_wallet_from_mnemonic` or switch to an existing wallet.\n");
+            }
+        }
+        response
+    }
+}
+
+impl MarkdownRender for WalletSwitchResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "✅ **Wallet Switched Successfully**\n\n".to_string();
+        response.push_str(&format!(
+            "**New Active Wallet:** `{}`\n",
+            self.active_wallet
+        ));
+        response.push_str(&format!("**Public Key:** `{}`\n", self.public_key));
+        response
+    }
+}
+
+impl MarkdownRender for WalletAddedResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "✅ **Wallet Added Successfully**\n\n".to_string();
+        response.push_str(&format!("**Address:** `{}`\n", self.address));
+        response.push_str(&format!("**Public Key:** `{}`\n", self.public_key));
+        response.push_str(&format!(
+            "**Derivation Index:** {}\n",
+            self.derivation_index
+        ));
+        response.push_str(&format!(
+            "**Set as Active:** {}\n",
+            if self.active { "Yes" } else { "No" }
+        ));
+        response
+    }
+}
+
+impl MarkdownRender for WalletDiscoveryResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = format!(
+            "🔎 **Wallet Discovery** ({})\n\n**Indices Scanned:** {} (gap limit {})\n**Used Wallets:** {}\n\n",
+            self.network,
+            self.discovery.scanned,
+            self.discovery.gap_limit,
+            self.discovery.wallets.len()
+        );
+        if self.discovery.wallets.is_empty() {
+            response.push_str(
+                "No derivation index of this mnemonic holds funds or has signed a transaction.\n",
+            );
+        }
+        for wallet in &self.discovery.wallets {
+            response.push_str(&format!(
+                "- **{}** (index {}): `{}`",
+                wallet.label, wallet.index, wallet.cosmos_address
+            ));
+            if let Some(evm_address) = &wallet.evm_address {
+                response.push_str(&format!(" / `{}`", evm_address));
+            }
+            response.push('\n');
+            for coin in &wallet.activity.balances {
+                response.push_str(&format!("  - {} {}\n", coin.amount, coin.denom));
+            }
+            response.push_str(&format!(
+                "  - Transactions: {} Cosmos, {} EVM\n",
+                wallet.activity.cosmos_sequence, wallet.activity.evm_nonce
+            ));
+        }
+        if !self.imported.is_empty() {
+            response.push_str(&format!(
+                "\n✅ Imported {} wallets into the collection\n",
+                self.imported.len()
+            ));
+        }
+        response
+    }
+}
+
+impl MarkdownRender for WalletRemovedResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "✅ **Wallet Removed Successfully**\n\n".to_string();
+        response.push_str(&format!("**Removed Address:** `{}`\n", self.address));
+        if let Some(public_key) = &self.public_key {
+            response.push_str(&format!("**Public Key:** `{}`\n", public_key));
+        }
+        if self.was_active {
+            response.push_str("\n**Note:** This was the active wallet. You'll need to switch to another wallet or add a new one.\n");
+        }
+        response
+    }
+}
+
+impl MarkdownRender for WalletAddressesResponse {
+    fn to_markdown(&self) -> String {
+        let mut response = "🔍 **Wallet Addresses**\n\n".to_string();
+        response.push_str(&format!("**Cosmos Address:** `{}`\n", self.cosmos_address));
+        response.push_str(&format!("**EVM Address:** `{}`\n", self.evm_address));
+        response.push_str("\n*Both addresses are derived from the same private key*\n");
+        response
+    }
 }
@@ -1,18 +1,30 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::fs;
 use std::net::SocketAddr;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
-use axum::{extract::State, http::StatusCode, response::Json, routing::post, Router};
+use axum::{
+    extract::{ConnectInfo, State},
+    http::{
+        header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER},
+        HeaderMap, HeaderValue, StatusCode,
+    },
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Json, Response,
+    },
+    routing::{get, post},
+    Router,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::net::TcpListener;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::time::sleep;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, warn, Instrument};
 use uuid::Uuid;
 
 // Configuration support
@@ -23,14 +35,76 @@ use config::{Config, ConfigError, Environment, File, FileFormat};
 // The server implements MCP protocol manually using standard HTTP/JSON-RPC
 // until the rust-mcp-sdk API stabilizes in future versions
 
-use crate::config::{MantraNetworkConfig, NetworkConstants};
+use crate::config::{MantraNetworkConfig, NetworkConstants, NetworkProfile};
+use crate::endpoints::CircuitBreakerConfig;
 use crate::error::Error as SdkError;
+use crate::events::{EventFilter, EventSubscriber};
+use crate::fanout::NetworkFanout;
+use crate::protocols::cosmos_analyzer::CosmosProtocol;
+use crate::protocols::dex::dust::DustPolicy;
 use crate::protocols::dex::MantraDexClient;
-use crate::wallet::WalletInfo;
-
+use crate::protocols::evm::allowance::InfiniteApprovalPolicy;
+use crate::protocols::evm::contracts::primary_sale_export::ExportOptions;
+use crate::protocols::evm::log_cache::LogCache;
+use crate::protocols::skip::SkipAffiliateConfig;
+use crate::retry::{classify_message, Classify, ErrorClass, RetryPolicy};
+use crate::signing_log::{SigningLog, SigningQuery};
+use crate::trace::TraceContext;
+use crate::tx_tag::TxTagging;
+use crate::wallet::{AddressBook, AddressKind, RestrictedWalletList, WalletInfo};
+
+use super::approvals::{ApprovalQueue, TransactionQueue, TransactionQueueConfig};
+use super::audit_log::{
+    arguments_digest, find_tx_hash, AuditEntry, AuditLog, AuditLogConfig, AuditQuery,
+};
+use super::auth::{ApiKeyEntry, HttpAuthConfig, HttpAuthenticator, JwtConfig, Principal};
+use super::authz::{AuthorizationConfig, ToolAuthorizer, ToolClass};
+use super::budget::{BudgetConfig, BudgetManager, BudgetReservation};
+use super::cache::{AdapterCache, CacheBackend, CacheConfig};
 use super::client_wrapper::McpClientWrapper;
+use super::encoding::ResponseEncoding;
+use super::health::{ComponentHealth, ComponentStatus, HealthConfig, HealthReport};
+use super::idempotency::{
+    idempotency_key_from_arguments, mark_idempotent_replay, Admission, IdempotencyStore,
+};
 use super::logging::{LoggingConfig, McpLogger};
-use super::sdk_adapter::McpSdkAdapter;
+use super::metrics::{McpMetrics, METRICS_CONTENT_TYPE};
+use super::nonces::{nonce_from_arguments, NonceTracker};
+use super::progress::{progress_token, report_partial_result, ProgressReporter};
+use super::prompts;
+use super::quotas::{WriteQuotaConfig, WriteQuotas};
+use super::rate_limit::{HttpRateLimitConfig, HttpRateLimiter, RequestPermit, Throttled};
+use super::remote_control::{spawn_remote_control, RemoteControlConfig};
+use super::resources::{self as data_resources, DataResource};
+use super::response_format::tool_result;
+#[cfg(feature = "evm")]
+use super::sdk_adapter::WalletAddressesResponse;
+use super::sdk_adapter::{
+    ActiveWalletResponse, AddressBookChangeResponse, AddressBookResponse, McpSdkAdapter,
+    RestrictedWalletListResponse, SwitchNetworkResponse, TokenBalance, WalletAddedResponse,
+    WalletBalancesResponse, WalletEntry, WalletListResponse, WalletRemovedResponse,
+    WalletSwitchResponse,
+};
+use super::state_store::EventStore;
+use super::tool_args::{
+    input_schema, invalid_arguments, parse_arguments, validate_arguments, AddWalletArgs,
+    AddressBookAddArgs, AddressBookRemoveArgs, AddressConvertArgs, AnalyzeWalletActivityArgs,
+    BlockArgs, DiscoverWalletsArgs, FaucetRequestArgs, FormatArgs, GetBalancesArgs,
+    GetMultiNetworkPortfolioArgs, GetSpendingSummaryArgs, RemoveWalletArgs, ResolveAssetArgs,
+    RestrictedWalletsAddArgs, RestrictedWalletsImportArgs, RestrictedWalletsRemoveArgs,
+    SwitchNetworkArgs, SwitchWalletArgs, ValidatorSetArgs, WatchChainEventsArgs,
+};
+#[cfg(feature = "evm")]
+use super::tool_args::{
+    Erc20AllowancesArgs, Erc20ApproveArgs, Erc20BalanceArgs, Erc20RevokeArgs, Erc20TransferArgs,
+    EvmBalancesArgs, EvmGetBlockArgs, EvmGetCodeArgs, EvmGetStorageAtArgs, EvmGetTransactionArgs,
+    EvmInspectContractArgs, PrimarySaleCheckEligibilityArgs, PrimarySaleExportInvestorsArgs,
+    PrimarySaleProjectSettlementArgs, RestrictedWalletsValidateArgs, WalletArgs,
+};
+use super::traces::{
+    correlation_id_from_arguments, ToolCallTrace, TraceLog, TraceLogConfig, TraceQuery,
+};
+use crate::tx_queue::{TxQueue, TxQueuePolicy};
 
 // =============================================================================
 // Transaction Monitoring Types
@@ -568,6 +642,12 @@ const NETWORK_CONNECTION_FAILED: i32 = -32002;
 const VALIDATION_ERROR: i32 = -32003;
 const CONFIGURATION_ERROR: i32 = -32004;
 const RESOURCE_NOT_FOUND: i32 = -32005;
+const QUOTA_EXCEEDED: i32 = -32006;
+const REPLAY_REJECTED: i32 = -32007;
+const APPROVAL_REJECTED: i32 = -32008;
+const AUTHORIZATION_DENIED: i32 = -32009;
+const CONFIRMATION_REQUIRED: i32 = -32010;
+const AUTHENTICATION_FAILED: i32 = -32011;
 
 // SDK-specific error codes
 const BLOCKCHAIN_RPC_ERROR: i32 = -32100;
@@ -583,6 +663,279 @@ const TOOL_EXECUTION_FAILED: i32 = -32109;
 const FEE_VALIDATION_FAILED: i32 = -32110;
 const TIMEOUT_ERROR: i32 = -32111;
 const IO_ERROR: i32 = -32112;
+const ACCOUNT_SEQUENCE_MISMATCH: i32 = -32113;
+const EVM_EXECUTION_REVERTED: i32 = -32114;
+const RPC_TIMEOUT: i32 = -32115;
+const TRANSACTION_EXPIRED: i32 = -32116;
+
+// =============================================================================
+// Tool Classification and Request Metadata
+// =============================================================================
+
+/// Tools that sign and broadcast transactions or otherwise change on-chain state
+pub const WRITE_TOOLS: &[&str] = &[
+    "faucet_request",
+    "wallet_transfer_erc20",
+    "wallet_approve_erc20",
+    "erc20_revoke_approval",
+    "evm_deploy_erc20",
+    "dex_execute_swap",
+    "dex_provide_liquidity",
+    "dex_provide_liquidity_unchecked",
+    "dex_withdraw_liquidity",
+    "dex_zap_in",
+    "dex_zap_out",
+    "dex_sweep_dust",
+    "dex_create_pool",
+    "claimdrop_create_campaign",
+    "claimdrop_claim",
+    "claimdrop_add_allocations",
+    "claimdrop_claim_with_proof",
+    "claimdrop_claim_all",
+    "claimdrop_build_campaign",
+    "claimdrop_close_campaign",
+    "claimdrop_withdraw_remaining_funds",
+    "claimdrop_end_of_life_campaign",
+    "skip_execute_transfer",
+    "primary_sale_invest",
+    "primary_sale_approve_and_invest",
+    "primary_sale_claim_refund",
+    "primary_sale_activate",
+    "primary_sale_end_sale",
+    "primary_sale_settle_and_distribute",
+    "primary_sale_top_up_refunds",
+    "primary_sale_cancel",
+    "primary_sale_pause",
+    "primary_sale_unpause",
+    "primary_sale_emergency_withdraw",
+];
+
+/// Check whether a tool changes on-chain state
+pub fn is_write_tool(tool_name: &str) -> bool {
+    WRITE_TOOLS.contains(&tool_name)
+}
+
+/// Session used when the client does not identify one (e.g. a single stdio client)
+pub const DEFAULT_SESSION_ID: &str = "default";
+
+/// Extract the session id from the `_meta` object carried in tool arguments
+///
+/// Accepts `_meta.session_id` or `_meta.sessionId`, falling back to [`DEFAULT_SESSION_ID`].
+pub fn session_id_from_arguments(arguments: &Value) -> String {
+    arguments
+        .get("_meta")
+        .and_then(|meta| meta.get("session_id").or_else(|| meta.get("sessionId")))
+        .and_then(|id| id.as_str())
+        .filter(|id| !id.is_empty())
+        .unwrap_or(DEFAULT_SESSION_ID)
+        .to_string()
+}
+
+/// Put a call's correlation ID in its tool result's `_meta.correlation_id`
+fn with_correlation_id(mut result: Value, correlation_id: &str) -> Value {
+    if let Some(map) = result.as_object_mut() {
+        let meta = map.entry("_meta").or_insert_with(|| serde_json::json!({}));
+        if let Some(meta) = meta.as_object_mut() {
+            meta.insert(
+                "correlation_id".to_string(),
+                Value::String(correlation_id.to_string()),
+            );
+        }
+    }
+    result
+}
+
+/// Copy a `tools/call` request's `params._meta` into its `arguments._meta`
+///
+/// Keys the client sent in `arguments._meta` are kept, except those the server
+/// derives itself: `principal` is replaced by the one in `params._meta`, or
+/// removed if there is none, and `session_id` by the one in `params._meta`.
+fn merge_request_meta(params: &Value, arguments: &mut Value) {
+    let Some(args) = arguments.as_object_mut() else {
+        return;
+    };
+    let args_meta = args.entry("_meta").or_insert_with(|| serde_json::json!({}));
+    if !args_meta.is_object() {
+        *args_meta = serde_json::json!({});
+    }
+    let Some(args_meta) = args_meta.as_object_mut() else {
+        return;
+    };
+
+    args_meta.remove("principal");
+    if let Some(meta) = params.get("_meta").and_then(|meta| meta.as_object()) {
+        for (key, value) in meta {
+            if key == "principal" || key == "session_id" {
+                args_meta.insert(key.clone(), value.clone());
+            } else {
+                args_meta
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+    }
+    if args_meta.is_empty() {
+        args.remove("_meta");
+    }
+}
+
+/// Extract the authenticated HTTP client recorded in `_meta.principal`
+pub fn principal_from_arguments(arguments: &Value) -> Option<String> {
+    arguments
+        .get("_meta")
+        .and_then(|meta| meta.get("principal"))
+        .and_then(|principal| principal.as_str())
+        .map(str::to_string)
+}
+
+/// Add the `dry_run` argument to a write tool's schema if it lacks one
+///
+/// Every write tool can run as a dry run; tools without a preview of their own
+/// simulate their transactions.
+fn with_dry_run_argument(mut tool: Value) -> Value {
+    let is_write = tool
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(is_write_tool);
+    if !is_write {
+        return tool;
+    }
+    if let Some(properties) = tool
+        .get_mut("inputSchema")
+        .and_then(|schema| schema.as_object_mut())
+        .and_then(|schema| {
+            schema
+                .entry("properties")
+                .or_insert_with(|| serde_json::json!({}))
+                .as_object_mut()
+        })
+    {
+        properties.entry("dry_run").or_insert_with(|| {
+            serde_json::json!({
+                "type": "boolean",
+                "description": "Simulate the transaction and return the expected outcome and gas without broadcasting (optional, defaults to false)."
+            })
+        });
+    }
+    tool
+}
+
+/// Add the `memo` argument to a write tool's schema if it lacks one
+fn with_memo_argument(mut tool: Value) -> Value {
+    let is_write = tool
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(is_write_tool);
+    if !is_write {
+        return tool;
+    }
+    if let Some(properties) = tool
+        .get_mut("inputSchema")
+        .and_then(|schema| schema.get_mut("properties"))
+        .and_then(|properties| properties.as_object_mut())
+    {
+        properties.entry("memo").or_insert_with(|| {
+            serde_json::json!({
+                "type": "string",
+                "maxLength": crate::tx_tag::MAX_MEMO_BYTES,
+                "description": "Memo of the Cosmos transactions this call sends, instead of the server's configured memo (optional)."
+            })
+        });
+    }
+    tool
+}
+
+/// Add the `timeout_blocks` argument to a write tool's schema if it lacks one
+fn with_timeout_blocks_argument(mut tool: Value) -> Value {
+    let is_write = tool
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(is_write_tool);
+    if !is_write {
+        return tool;
+    }
+    if let Some(properties) = tool
+        .get_mut("inputSchema")
+        .and_then(|schema| schema.get_mut("properties"))
+        .and_then(|properties| properties.as_object_mut())
+    {
+        properties.entry("timeout_blocks").or_insert_with(|| {
+            serde_json::json!({
+                "type": "integer",
+                "minimum": 0,
+                "description": "Blocks past the latest one the Cosmos transactions this call sends stay valid for, instead of the server's configured number; 0 for no timeout (optional)."
+            })
+        });
+    }
+    tool
+}
+
+/// Add the `idempotency_key` argument to a write tool's schema if it lacks one
+fn with_idempotency_key_argument(mut tool: Value) -> Value {
+    let is_write = tool
+        .get("name")
+        .and_then(|name| name.as_str())
+        .is_some_and(is_write_tool);
+    if !is_write {
+        return tool;
+    }
+    if let Some(properties) = tool
+        .get_mut("inputSchema")
+        .and_then(|schema| schema.get_mut("properties"))
+        .and_then(|properties| properties.as_object_mut())
+    {
+        properties.entry("idempotency_key").or_insert_with(|| {
+            serde_json::json!({
+                "type": "string",
+                "minLength": 1,
+                "maxLength": super::idempotency::MAX_IDEMPOTENCY_KEY_LEN,
+                "description": "Unique key of this operation (optional). A retry with the same key and arguments returns the first call's result instead of broadcasting again."
+            })
+        });
+    }
+    tool
+}
+
+/// Memo a write call asked its transactions to carry
+fn memo_from_arguments(arguments: &Value) -> Option<String> {
+    arguments
+        .get("memo")
+        .and_then(|memo| memo.as_str())
+        .map(str::to_string)
+}
+
+/// Timeout a write call asked its transactions to be signed with, in blocks
+fn timeout_blocks_from_arguments(arguments: &Value) -> Option<u64> {
+    arguments
+        .get("timeout_blocks")
+        .and_then(|blocks| blocks.as_u64())
+}
+
+/// Mark a tool result as coming from a dry run in its `_meta`
+fn mark_dry_run(mut result: Value) -> Value {
+    if let Some(map) = result.as_object_mut() {
+        let meta = map.entry("_meta").or_insert_with(|| serde_json::json!({}));
+        if let Some(meta) = meta.as_object_mut() {
+            meta.insert("dry_run".to_string(), Value::Bool(true));
+        }
+    }
+    result
+}
+
+/// The JSON a tool reported in its first text content item
+fn tool_result_payload(result: &Value) -> Value {
+    let text = result
+        .get("content")
+        .and_then(|content| content.get(0))
+        .and_then(|item| item.get("text"))
+        .and_then(|text| text.as_str());
+    match text {
+        Some(text) => {
+            serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()))
+        }
+        None => Value::Null,
+    }
+}
 
 // =============================================================================
 // MCP Server Trait Definitions
@@ -666,6 +1019,11 @@ pub trait McpResourceProvider: Send + Sync {
     /// Read a resource by its URI
     async fn handle_resource_read(&self, uri: &str) -> McpResult<serde_json::Value>;
 
+    /// Get templates of parameterized resources
+    fn get_resource_templates(&self) -> Vec<serde_json::Value> {
+        Vec::new()
+    }
+
     /// Validate resource URI format
     fn validate_resource_uri(&self, uri: &str) -> McpResult<()> {
         // Default implementation - basic URI validation
@@ -694,6 +1052,33 @@ pub trait McpResourceProvider: Send + Sync {
     }
 }
 
+/// MCP prompt provider trait
+///
+/// Defines the interface for servers that offer prompt templates.
+/// Prompts walk clients through multi-step workflows built from the server's tools.
+#[async_trait::async_trait]
+pub trait McpPromptProvider: Send + Sync {
+    /// Get list of available prompts with their arguments
+    fn get_available_prompts(&self) -> Vec<serde_json::Value> {
+        prompts::prompt_templates()
+            .iter()
+            .map(|prompt| prompt.descriptor())
+            .collect()
+    }
+
+    /// Render a prompt with its arguments bound
+    async fn get_prompt(
+        &self,
+        name: &str,
+        arguments: &serde_json::Map<String, serde_json::Value>,
+    ) -> McpResult<serde_json::Value> {
+        prompts::find_prompt(name)
+            .ok_or_else(|| McpServerError::InvalidArguments(format!("Unknown prompt: {}", name)))?
+            .render(arguments)
+            .map_err(McpServerError::InvalidArguments)
+    }
+}
+
 /// MCP server state management trait
 ///
 /// Defines the interface for servers that manage internal state.
@@ -725,7 +1110,11 @@ pub trait McpServerStateManager: Send + Sync {
 /// Servers should implement this trait to provide full MCP functionality.
 #[async_trait::async_trait]
 pub trait McpServer:
-    McpServerLifecycle + McpToolProvider + McpResourceProvider + McpServerStateManager
+    McpServerLifecycle
+    + McpToolProvider
+    + McpResourceProvider
+    + McpPromptProvider
+    + McpServerStateManager
 {
     /// Handle incoming MCP requests with proper routing
     async fn handle_request(
@@ -745,11 +1134,14 @@ pub trait McpServer:
                             McpServerError::InvalidArguments("Missing tool name".to_string())
                         })?;
 
-                    let arguments = params
+                    let mut arguments = params
                         .get("arguments")
                         .cloned()
                         .unwrap_or(serde_json::json!({}));
 
+                    // Carry request metadata (session id, etc.) through to the dispatcher
+                    merge_request_meta(&params, &mut arguments);
+
                     self.handle_tool_call(tool_name, arguments).await
                 } else {
                     Err(McpServerError::InvalidArguments(
@@ -761,6 +1153,10 @@ pub trait McpServer:
                 let resources = self.get_available_resources();
                 Ok(serde_json::json!({ "resources": resources }))
             }
+            "resources/templates/list" => {
+                let templates = self.get_resource_templates();
+                Ok(serde_json::json!({ "resourceTemplates": templates }))
+            }
             "resources/read" => {
                 if let Some(params) = params {
                     let uri = params.get("uri").and_then(|u| u.as_str()).ok_or_else(|| {
@@ -774,6 +1170,28 @@ pub trait McpServer:
                     ))
                 }
             }
+            "prompts/list" => {
+                let prompts = self.get_available_prompts();
+                Ok(serde_json::json!({ "prompts": prompts }))
+            }
+            "prompts/get" => {
+                if let Some(params) = params {
+                    let name = params.get("name").and_then(|n| n.as_str()).ok_or_else(|| {
+                        McpServerError::InvalidArguments("Missing prompt name".to_string())
+                    })?;
+                    let arguments = params
+                        .get("arguments")
+                        .and_then(|a| a.as_object())
+                        .cloned()
+                        .unwrap_or_default();
+
+                    self.get_prompt(name, &arguments).await
+                } else {
+                    Err(McpServerError::InvalidArguments(
+                        "Missing parameters for prompt get".to_string(),
+                    ))
+                }
+            }
             "initialize" => {
                 let mut response = serde_json::Map::new();
                 response.insert(
@@ -796,6 +1214,7 @@ pub trait McpServer:
             "capabilities": self.get_capabilities(),
             "tools": self.get_available_tools().len(),
             "resources": self.get_available_resources().len(),
+            "prompts": self.get_available_prompts().len(),
             "health": self.get_health_status().await
         })
     }
@@ -879,6 +1298,24 @@ pub enum McpServerError {
 
     #[error("Other error: {0}")]
     Other(String),
+
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+
+    #[error("Replay rejected: {0}")]
+    ReplayRejected(String),
+
+    #[error("Approval rejected: {0}")]
+    ApprovalRejected(String),
+
+    #[error("Authorization denied: {0}")]
+    AuthorizationDenied(String),
+
+    #[error("Confirmation required: {reason}")]
+    ConfirmationRequired { reason: String, token: String },
+
+    #[error("Authentication failed: {0}")]
+    AuthenticationFailed(String),
 }
 
 impl McpServerError {
@@ -905,6 +1342,12 @@ impl McpServerError {
             McpServerError::Internal(_) => INTERNAL_ERROR,
             McpServerError::Config(_) => CONFIGURATION_ERROR,
             McpServerError::Other(_) => INTERNAL_ERROR,
+            McpServerError::QuotaExceeded(_) => QUOTA_EXCEEDED,
+            McpServerError::ReplayRejected(_) => REPLAY_REJECTED,
+            McpServerError::ApprovalRejected(_) => APPROVAL_REJECTED,
+            McpServerError::AuthorizationDenied(_) => AUTHORIZATION_DENIED,
+            McpServerError::ConfirmationRequired { .. } => CONFIRMATION_REQUIRED,
+            McpServerError::AuthenticationFailed(_) => AUTHENTICATION_FAILED,
         }
     }
 
@@ -972,6 +1415,48 @@ impl McpServerError {
             SdkError::NotImplemented(_) => TOOL_EXECUTION_FAILED,
             SdkError::WalletNotSet => WALLET_NOT_CONFIGURED,
             SdkError::Skip(_) => BLOCKCHAIN_RPC_ERROR,
+
+            // Typed chain failures
+            SdkError::InsufficientFunds { .. } => INSUFFICIENT_FUNDS,
+            SdkError::SlippageExceeded { .. } => SWAP_SLIPPAGE_EXCEEDED,
+            SdkError::AccountSequenceMismatch { .. } => ACCOUNT_SEQUENCE_MISMATCH,
+            SdkError::EvmRevert { .. } => EVM_EXECUTION_REVERTED,
+            SdkError::RpcTimeout { .. } => RPC_TIMEOUT,
+            SdkError::TxExpired { .. } => TRANSACTION_EXPIRED,
+            SdkError::RateLimited { .. } => QUOTA_EXCEEDED,
+        }
+    }
+
+    /// Fields of the typed SDK errors, for clients to act on without parsing
+    /// the message
+    fn get_sdk_error_details(sdk_error: &SdkError) -> Option<serde_json::Value> {
+        match sdk_error {
+            SdkError::InsufficientFunds { needed, available } => Some(serde_json::json!({
+                "needed": needed,
+                "available": available,
+            })),
+            SdkError::SlippageExceeded { reason } | SdkError::EvmRevert { reason } => {
+                Some(serde_json::json!({ "reason": reason }))
+            }
+            SdkError::AccountSequenceMismatch { expected, got } => Some(serde_json::json!({
+                "expected": expected,
+                "got": got,
+            })),
+            SdkError::TxExpired { timeout_height } => Some(serde_json::json!({
+                "timeout_height": timeout_height,
+            })),
+            SdkError::RpcTimeout { endpoint, source } => Some(serde_json::json!({
+                "endpoint": endpoint,
+                "cause": source.as_ref().map(|source| source.to_string()),
+            })),
+            SdkError::RateLimited {
+                reason,
+                retry_after_secs,
+            } => Some(serde_json::json!({
+                "reason": reason,
+                "retry_after_secs": retry_after_secs,
+            })),
+            _ => None,
         }
     }
 
@@ -986,6 +1471,7 @@ impl McpServerError {
                 "error_code": Self::sdk_error_to_code(sdk_error),
                 "recovery_suggestions": Self::get_recovery_suggestions(sdk_error),
                 "severity": Self::get_error_severity(sdk_error),
+                "details": Self::get_sdk_error_details(sdk_error),
                 "timestamp": chrono::Utc::now().to_rfc3339()
             })),
 
@@ -1036,6 +1522,56 @@ impl McpServerError {
                 "timestamp": chrono::Utc::now().to_rfc3339()
             })),
 
+            McpServerError::QuotaExceeded(msg) => Some(serde_json::json!({
+                "quota_error": msg,
+                "category": "quota",
+                "severity": "medium",
+                "recovery_suggestions": ["Wait for in-flight operations to complete", "Retry after a short delay"],
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            })),
+
+            McpServerError::ReplayRejected(msg) => Some(serde_json::json!({
+                "replay_error": msg,
+                "category": "replay",
+                "severity": "medium",
+                "recovery_suggestions": ["Check whether the original call already succeeded", "Use a nonce greater than the last accepted one for a deliberate retry"],
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            })),
+
+            McpServerError::ApprovalRejected(msg) => Some(serde_json::json!({
+                "approval_error": msg,
+                "category": "approval",
+                "severity": "medium",
+                "recovery_suggestions": ["Ask the operator why the call was rejected", "Retry with a fresh nonce once the operator is available"],
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            })),
+
+            McpServerError::AuthorizationDenied(msg) => Some(serde_json::json!({
+                "authorization_error": msg,
+                "category": "authorization",
+                "severity": "medium",
+                "recovery_suggestions": ["Use a tool and amount the server policy allows", "Ask the operator to adjust the authorization policy"],
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            })),
+
+            McpServerError::ConfirmationRequired { reason, token } => Some(serde_json::json!({
+                "confirmation_error": reason,
+                "confirmation_token": token,
+                "expires_in_secs": super::authz::CONFIRMATION_TTL_SECS,
+                "category": "authorization",
+                "severity": "low",
+                "recovery_suggestions": ["Confirm the transaction with the user", "Repeat the identical call with _meta.confirmation_token set to the token"],
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            })),
+
+            McpServerError::AuthenticationFailed(msg) => Some(serde_json::json!({
+                "authentication_error": msg,
+                "category": "authentication",
+                "severity": "high",
+                "recovery_suggestions": ["Send an API key in the X-Api-Key header", "Send an API key or JWT as Authorization: Bearer <token>"],
+                "timestamp": chrono::Utc::now().to_rfc3339()
+            })),
+
             _ => None,
         }
     }
@@ -1121,6 +1657,35 @@ impl McpServerError {
                 "Verify cross-chain route availability",
                 "Check network connectivity to Skip API",
             ],
+            SdkError::InsufficientFunds { .. } => vec![
+                "Check wallet balance",
+                "Reduce the amount or fund the wallet",
+                "Leave enough balance for gas fees",
+            ],
+            SdkError::SlippageExceeded { .. } => vec![
+                "Increase the slippage tolerance",
+                "Reduce the swap amount",
+                "Simulate the swap again for a fresh quote",
+            ],
+            SdkError::AccountSequenceMismatch { .. } => vec![
+                "Retry the transaction",
+                "Wait for pending transactions from this wallet to be included",
+            ],
+            SdkError::EvmRevert { .. } => vec![
+                "Check the revert reason",
+                "Verify call parameters and contract state",
+                "Simulate the call before sending it",
+            ],
+            SdkError::TxExpired { .. } => vec![
+                "Retry the transaction, which signs it with a new timeout height",
+                "Raise the timeout_blocks argument if the chain is congested",
+            ],
+            SdkError::RpcTimeout { .. } => vec![
+                "Retry with exponential backoff",
+                "Configure fallback RPC endpoints",
+                "Check the endpoint's health",
+            ],
+            SdkError::RateLimited { .. } => vec!["Retry after retry_after_secs have passed"],
         }
     }
 
@@ -1141,6 +1706,13 @@ impl McpServerError {
             SdkError::NotImplemented(_) => "low",
             SdkError::WalletNotSet => "high",
             SdkError::Skip(_) => "medium",
+            SdkError::InsufficientFunds { .. } => "high",
+            SdkError::SlippageExceeded { .. } => "medium",
+            SdkError::AccountSequenceMismatch { .. } => "low",
+            SdkError::EvmRevert { .. } => "high",
+            SdkError::RpcTimeout { .. } => "low",
+            SdkError::TxExpired { .. } => "low",
+            SdkError::RateLimited { .. } => "low",
         }
     }
 
@@ -1165,6 +1737,37 @@ impl McpServerError {
             SdkError::NotImplemented(_) => "NotImplemented",
             SdkError::WalletNotSet => "WalletNotSet",
             SdkError::Skip(_) => "SkipProtocol",
+            SdkError::InsufficientFunds { .. } => "InsufficientFunds",
+            SdkError::SlippageExceeded { .. } => "SlippageExceeded",
+            SdkError::AccountSequenceMismatch { .. } => "AccountSequenceMismatch",
+            SdkError::EvmRevert { .. } => "EvmRevert",
+            SdkError::RpcTimeout { .. } => "RpcTimeout",
+            SdkError::TxExpired { .. } => "TxExpired",
+            SdkError::RateLimited { .. } => "RateLimited",
+        }
+    }
+
+    /// Short name of the error kind, used to label error metrics
+    pub fn error_type(&self) -> &'static str {
+        match self {
+            McpServerError::Sdk(sdk_error) => Self::get_sdk_error_type_name(sdk_error),
+            McpServerError::Mcp(_) => "Mcp",
+            McpServerError::WalletNotConfigured => "WalletNotConfigured",
+            McpServerError::InvalidArguments(_) => "InvalidArguments",
+            McpServerError::Serialization(_) => "Serialization",
+            McpServerError::Network(_) => "Network",
+            McpServerError::Validation(_) => "Validation",
+            McpServerError::UnknownTool(_) => "UnknownTool",
+            McpServerError::Internal(_) => "Internal",
+            McpServerError::UnknownResource(_) => "UnknownResource",
+            McpServerError::Config(_) => "Config",
+            McpServerError::Other(_) => "Other",
+            McpServerError::QuotaExceeded(_) => "QuotaExceeded",
+            McpServerError::ReplayRejected(_) => "ReplayRejected",
+            McpServerError::ApprovalRejected(_) => "ApprovalRejected",
+            McpServerError::AuthorizationDenied(_) => "AuthorizationDenied",
+            McpServerError::ConfirmationRequired { .. } => "ConfirmationRequired",
+            McpServerError::AuthenticationFailed(_) => "AuthenticationFailed",
         }
     }
 
@@ -1178,16 +1781,14 @@ impl McpServerError {
     }
 
     /// Check if error is recoverable and suggests retry strategy
+    ///
+    /// Errors are recoverable when [`Classify`] deems them retryable, and quota
+    /// and rate limit rejections once the quota frees up.
     pub fn is_recoverable(&self) -> bool {
-        match self {
-            McpServerError::Sdk(sdk_error) => match sdk_error {
-                SdkError::Network(_) | SdkError::Timeout(_) | SdkError::Rpc(_) => true,
-                SdkError::TxBroadcast(_) => true, // Transaction might succeed on retry
-                _ => false,
-            },
-            McpServerError::Network(_) => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            McpServerError::QuotaExceeded(_) | McpServerError::Sdk(SdkError::RateLimited { .. })
+        ) || self.is_retryable()
     }
 
     /// Get suggested retry delay in seconds for recoverable errors
@@ -1198,6 +1799,12 @@ impl McpServerError {
                 McpServerError::Sdk(SdkError::Timeout(_)) => Some(10),
                 McpServerError::Sdk(SdkError::Rpc(_)) => Some(3),
                 McpServerError::Sdk(SdkError::TxBroadcast(_)) => Some(15),
+                McpServerError::Sdk(SdkError::RpcTimeout { .. }) => Some(5),
+                McpServerError::Sdk(SdkError::AccountSequenceMismatch { .. }) => Some(2),
+                McpServerError::Sdk(SdkError::TxExpired { .. }) => Some(2),
+                McpServerError::Sdk(SdkError::RateLimited {
+                    retry_after_secs, ..
+                }) => Some(*retry_after_secs),
                 McpServerError::Network(_) => Some(5),
                 _ => Some(1),
             }
@@ -1207,6 +1814,23 @@ impl McpServerError {
     }
 }
 
+impl Classify for McpServerError {
+    fn error_class(&self) -> ErrorClass {
+        match self {
+            McpServerError::Sdk(e) => e.error_class(),
+            McpServerError::Network(message) => {
+                classify_message(message).unwrap_or(ErrorClass::Retryable)
+            }
+            McpServerError::Mcp(message)
+            | McpServerError::Internal(message)
+            | McpServerError::Other(message) => {
+                classify_message(message).unwrap_or(ErrorClass::NonRetryable)
+            }
+            _ => ErrorClass::NonRetryable,
+        }
+    }
+}
+
 /// MCP server result type
 pub type McpResult<T> = std::result::Result<T, McpServerError>;
 
@@ -1233,6 +1857,146 @@ pub struct McpServerConfig {
     pub cache_ttl_secs: u64,
     /// Whether to auto-load .env file
     pub auto_load_env: bool,
+    /// Maximum in-flight write operations per session
+    #[serde(default = "default_max_write_ops_per_session")]
+    pub max_write_ops_per_session: usize,
+    /// Maximum in-flight write operations across all sessions
+    #[serde(default = "default_max_write_ops_global")]
+    pub max_write_ops_global: usize,
+    /// Reject write tool calls that carry no `_meta.nonce`
+    #[serde(default)]
+    pub require_write_nonces: bool,
+    /// Run every write tool call as a dry run, whatever its `dry_run` argument
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Load pools, tokens and ERC-20 metadata into the adapter cache at startup
+    #[serde(default)]
+    pub prefetch: bool,
+    /// Seconds between refreshes of the prefetched metadata
+    #[serde(default = "default_prefetch_interval_secs")]
+    pub prefetch_interval_secs: u64,
+    /// Seconds between background refreshes of stale ERC-20 registry metadata
+    /// (0 disables them)
+    #[serde(default = "default_erc20_refresh_interval_secs")]
+    pub erc20_refresh_interval_secs: u64,
+    /// Handling of residual balances left after swaps and zaps
+    #[serde(default)]
+    pub dust_policy: DustPolicy,
+    /// File the adapter state event log is kept in; in memory only when unset
+    #[serde(default)]
+    pub state_log_path: Option<PathBuf>,
+    /// File signed transactions are persisted to before broadcast;
+    /// `mcp_tx_queue.json` next to the TUI's queue when unset
+    #[serde(default)]
+    pub tx_queue_path: Option<PathBuf>,
+    /// Address book file; the one shared with the TUI
+    /// (`~/.mantra_dex/address_book.json`) when unset
+    #[serde(default)]
+    pub address_book_path: Option<PathBuf>,
+    /// Restricted wallet list injected into settlement batches;
+    /// `~/.mantra_dex/restricted_wallets.json` when unset
+    #[serde(default)]
+    pub restricted_wallets_path: Option<PathBuf>,
+    /// JSONL file the intents of signed transactions are appended to; kept
+    /// in memory only when unset
+    #[serde(default)]
+    pub signing_log_path: Option<PathBuf>,
+    /// Directory scanned EVM logs are cached in;
+    /// `~/.mantra_dex/log_cache` when unset
+    #[serde(default)]
+    pub evm_log_cache_dir: Option<PathBuf>,
+    /// Compiled standard ERC-20 artifact `evm_deploy_erc20` deploys;
+    /// `~/.mantra_dex/contracts/StandardErc20.json` when unset
+    #[serde(default)]
+    pub erc20_artifact_path: Option<PathBuf>,
+    /// Sha256 (hex) of the audited artifact's creation bytecode;
+    /// `evm_deploy_erc20` refuses any other bytecode, and deploys nothing
+    /// when unset
+    #[serde(default)]
+    pub erc20_artifact_sha256: Option<String>,
+    /// Encrypted operator channel for headless deployments
+    #[serde(default)]
+    pub remote_control: RemoteControlConfig,
+    /// Tool classes, allow and deny lists, and spend limits enforced on tool calls
+    #[serde(default)]
+    pub authorization: AuthorizationConfig,
+    /// Per-wallet spending limits over a rolling window
+    #[serde(default)]
+    pub budget: BudgetConfig,
+    /// Per-client and global request limits of the HTTP transport
+    #[serde(default)]
+    pub http_rate_limit: HttpRateLimitConfig,
+    /// API keys and JWT validation required of HTTP clients
+    #[serde(default)]
+    pub http_auth: HttpAuthConfig,
+    /// Where and how state-changing tool calls are audited
+    #[serde(default)]
+    pub audit_log: AuditLogConfig,
+    /// How many completed tool call traces are kept for `get_recent_traces`
+    #[serde(default)]
+    pub traces: TraceLogConfig,
+    /// Size limits and backend of the SDK adapter cache
+    #[serde(default)]
+    pub adapter_cache: CacheConfig,
+    /// Block lag and probe timeout of the health and readiness checks
+    #[serde(default)]
+    pub health: HealthConfig,
+    /// Network profiles added to, or replacing, the built-in mainnet, dukong
+    /// and local profiles
+    #[serde(default)]
+    pub network_profiles: BTreeMap<String, NetworkProfile>,
+    /// Network profile switched to at startup; the configured network when unset
+    #[serde(default)]
+    pub network_profile: Option<String>,
+    /// Write tools proposed for approval instead of broadcast
+    #[serde(default)]
+    pub transaction_queue: TransactionQueueConfig,
+    /// Affiliate paid out of Skip swaps executed by the server
+    #[serde(default)]
+    pub skip_affiliate: Option<SkipAffiliateConfig>,
+    /// RPC endpoints of Skip destination chains, used to verify deliveries
+    #[serde(default)]
+    pub skip_chain_rpcs: BTreeMap<String, String>,
+    /// Claimdrop factory whose campaigns are exposed as the campaigns resource
+    #[serde(default)]
+    pub claimdrop_factory_address: Option<String>,
+    /// Retries of transient Cosmos RPC, EVM RPC and HTTP failures
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Circuit breakers of the Cosmos and EVM RPC endpoints
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+    /// Memo pattern and EVM calldata tag of every transaction sent
+    #[serde(default)]
+    pub tx_tagging: TxTagging,
+    /// Blocks past the latest one Cosmos transactions are valid for; 0 lets
+    /// them wait in the mempool indefinitely
+    #[serde(default = "default_tx_timeout_blocks")]
+    pub tx_timeout_blocks: u64,
+    /// Whether `wallet_approve_erc20` allows, warns about or refuses
+    /// unlimited approvals
+    #[serde(default)]
+    pub infinite_approval_policy: InfiniteApprovalPolicy,
+}
+
+fn default_max_write_ops_per_session() -> usize {
+    WriteQuotaConfig::default().max_in_flight_per_session
+}
+
+fn default_max_write_ops_global() -> usize {
+    WriteQuotaConfig::default().max_in_flight_global
+}
+
+fn default_prefetch_interval_secs() -> u64 {
+    240
+}
+
+fn default_erc20_refresh_interval_secs() -> u64 {
+    300
+}
+
+fn default_tx_timeout_blocks() -> u64 {
+    crate::protocols::dex::client::DEFAULT_TIMEOUT_BLOCKS
 }
 
 impl Default for McpServerConfig {
@@ -1248,6 +2012,42 @@ impl Default for McpServerConfig {
             request_timeout_secs: 30,
             cache_ttl_secs: 300,
             auto_load_env: true,
+            max_write_ops_per_session: default_max_write_ops_per_session(),
+            max_write_ops_global: default_max_write_ops_global(),
+            require_write_nonces: false,
+            dry_run: false,
+            prefetch: false,
+            prefetch_interval_secs: default_prefetch_interval_secs(),
+            erc20_refresh_interval_secs: default_erc20_refresh_interval_secs(),
+            dust_policy: DustPolicy::default(),
+            state_log_path: None,
+            tx_queue_path: None,
+            address_book_path: None,
+            restricted_wallets_path: None,
+            signing_log_path: None,
+            evm_log_cache_dir: None,
+            erc20_artifact_path: None,
+            erc20_artifact_sha256: None,
+            remote_control: RemoteControlConfig::default(),
+            authorization: AuthorizationConfig::default(),
+            budget: BudgetConfig::default(),
+            http_rate_limit: HttpRateLimitConfig::default(),
+            http_auth: HttpAuthConfig::default(),
+            audit_log: AuditLogConfig::default(),
+            traces: TraceLogConfig::default(),
+            adapter_cache: CacheConfig::default(),
+            health: HealthConfig::default(),
+            network_profiles: BTreeMap::new(),
+            network_profile: None,
+            transaction_queue: TransactionQueueConfig::default(),
+            skip_affiliate: None,
+            skip_chain_rpcs: BTreeMap::new(),
+            claimdrop_factory_address: None,
+            retry: RetryPolicy::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            tx_tagging: TxTagging::default(),
+            tx_timeout_blocks: default_tx_timeout_blocks(),
+            infinite_approval_policy: InfiniteApprovalPolicy::default(),
         }
     }
 }
@@ -1262,9 +2062,78 @@ impl McpServerConfig {
     /// - MCP_MAX_CONCURRENT_OPS: Maximum concurrent operations
     /// - MCP_HTTP_PORT: HTTP server port
     /// - MCP_HTTP_HOST: HTTP server host
+    /// - MCP_HTTP_RATE_LIMIT_PER_CLIENT: Requests a client may make per minute (0 disables)
+    /// - MCP_HTTP_RATE_LIMIT_BURST: Requests a client may make at once
+    /// - MCP_HTTP_RATE_LIMIT_GLOBAL: Requests all clients may make per minute (0 disables)
+    /// - MCP_HTTP_RATE_LIMIT_GLOBAL_BURST: Requests all clients may make at once
+    /// - MCP_HTTP_MAX_IN_FLIGHT: HTTP requests processed at the same time (0 disables)
+    /// - MCP_HTTP_EXPENSIVE_TOOL_COST: Requests a call to an expensive tool counts as
+    /// - MCP_HTTP_TRUST_FORWARDED_FOR: Identify HTTP clients by X-Forwarded-For (true/false)
+    /// - MCP_HTTP_API_KEYS: Comma-separated `name=key` pairs accepted from HTTP clients
+    /// - MCP_HTTP_JWT_SECRET: HMAC secret of HS256 tokens accepted from HTTP clients
+    /// - MCP_HTTP_JWT_ISSUER: Required `iss` claim of HTTP client tokens
+    /// - MCP_HTTP_JWT_AUDIENCE: Required `aud` claim of HTTP client tokens
+    /// - MCP_AUDIT_LOG_PATH: JSONL file state-changing tool calls are audited to
+    /// - MCP_AUDIT_LOG_MAX_BYTES: Size at which the audit log file is rotated
+    /// - MCP_AUDIT_LOG_MAX_FILES: Rotated audit log files kept
+    /// - MCP_TRACE_CAPACITY: Completed tool call traces kept (0 keeps none)
+    /// - MCP_CACHE_BACKEND: Adapter cache backend, "memory" or "sqlite"
+    /// - MCP_CACHE_PATH: Database file of the sqlite adapter cache
+    /// - MCP_CACHE_MAX_ENTRIES: Adapter cache entries kept before eviction
+    /// - MCP_CACHE_MAX_BYTES: Adapter cache size kept before eviction
+    /// - MCP_HEALTH_MAX_BLOCK_LAG_SECS: Block age past which a chain is reported degraded
+    /// - MCP_HEALTH_PROBE_TIMEOUT_MS: Time each health probe of an RPC endpoint is given
+    /// - MCP_QUEUE_TOOLS: Comma-separated write tools proposed for approval instead of broadcast
+    /// - MCP_QUEUE_TTL_SECS: Seconds a proposed transaction waits for approval
+    /// - MCP_QUEUE_APPROVERS: Comma-separated HTTP clients allowed to approve or reject proposals
     /// - MCP_REQUEST_TIMEOUT_SECS: Request timeout in seconds
     /// - MCP_CACHE_TTL_SECS: Cache TTL in seconds
     /// - MCP_AUTO_LOAD_ENV: Auto-load .env file (true/false)
+    /// - MCP_MAX_WRITE_OPS_PER_SESSION: Maximum in-flight write operations per session
+    /// - MCP_MAX_WRITE_OPS_GLOBAL: Maximum in-flight write operations across all sessions
+    /// - MCP_REQUIRE_WRITE_NONCES: Reject write tool calls without a nonce (true/false)
+    /// - MCP_DRY_RUN: Simulate every write tool call instead of broadcasting (true/false)
+    /// - MCP_PREFETCH: Warm the adapter cache with pool and token metadata at startup (true/false)
+    /// - MCP_PREFETCH_INTERVAL_SECS: Seconds between refreshes of the prefetched metadata
+    /// - MCP_ERC20_REFRESH_INTERVAL_SECS: Seconds between refreshes of stale ERC-20 registry metadata (0 disables them)
+    /// - MCP_NETWORK_PROFILE: Network profile (mainnet/dukong/local or a configured one) to start on
+    /// - MCP_DUST_ACTION: Dust handling after swaps and zaps (ignore/convert_to_native/accumulate)
+    /// - MCP_DUST_THRESHOLD: Balances below this many base units are dust
+    /// - MCP_STATE_LOG_PATH: File the wallet and custom token state is event-sourced to
+    /// - MCP_TX_QUEUE_PATH: File signed transactions are persisted to before broadcast
+    /// - MCP_ADDRESS_BOOK_PATH: Address book file, instead of the one shared with the TUI
+    /// - MCP_RESTRICTED_WALLETS_PATH: Restricted wallet list file used by sale settlement
+    /// - MCP_SIGNING_LOG_PATH: File the decoded intents of signed transactions are appended to
+    /// - MCP_EVM_LOG_CACHE_DIR: Directory scanned EVM logs are cached in
+    /// - MCP_ERC20_ARTIFACT_PATH: Standard ERC-20 artifact deployed by evm_deploy_erc20
+    /// - MCP_REMOTE_CONTROL_ADDR: Address the encrypted remote control channel listens on
+    /// - MCP_REMOTE_CONTROL_KEY_PATH: File holding the remote control server key
+    /// - MCP_REMOTE_CONTROL_OPERATORS: Comma-separated hex public keys of authorized operators
+    /// - MCP_REQUIRE_OPERATOR_APPROVAL: Hold write tool calls until an operator approves them (true/false)
+    /// - MCP_APPROVAL_TIMEOUT_SECS: Seconds a write call waits for an operator decision
+    /// - MCP_SKIP_AFFILIATE_ADDRESS: Address receiving the affiliate fee of Skip swaps
+    /// - MCP_SKIP_AFFILIATE_BPS: Affiliate fee in basis points of the swap output
+    /// - MCP_TX_MEMO: Memo pattern of every Cosmos transaction; `{version}` and `{chain_id}` are expanded
+    /// - MCP_EVM_DATA_SUFFIX: Append the expanded memo to the calldata of EVM transactions (true/false)
+    /// - MCP_TX_TIMEOUT_BLOCKS: Blocks past the latest one Cosmos transactions are valid for (0 for no timeout)
+    /// - MCP_INFINITE_APPROVAL_POLICY: What wallet_approve_erc20 does with unlimited approvals (allow/warn/block)
+    /// - MCP_SKIP_CHAIN_RPCS: Comma-separated `chain_id=rpc_url` pairs used to verify Skip deliveries
+    /// - MCP_CLAIMDROP_FACTORY_ADDRESS: Claimdrop factory listed by the mcp://campaigns resource
+    /// - MCP_AUTHZ_MAX_CLASS: Most privileged tool class callers may use (read_only/state_changing/admin)
+    /// - MCP_AUTHZ_ALLOW_TOOLS: Comma-separated tools callers may use; a trailing `*` matches a prefix
+    /// - MCP_AUTHZ_DENY_TOOLS: Comma-separated tools callers may never use
+    /// - MCP_AUTHZ_MAX_SPEND_PER_TX: Largest native denom spend of one call, in base units
+    /// - MCP_AUTHZ_MAX_DAILY_SPEND: Largest native denom spend per UTC day, in base units
+    /// - MCP_AUTHZ_CONFIRM_ABOVE: Spends above this many base units need a confirmation token
+    /// - MCP_BUDGET_WINDOW_SECS: Rolling window wallet budgets are counted over
+    /// - MCP_BUDGET_MAX_NATIVE: Largest native denom spend of a wallet per window, in base units
+    /// - MCP_BUDGET_MAX_USD: Largest dollar value a wallet may spend per window
+    /// - MCP_RETRY_MAX_RETRIES: Retries of a transient failure (0 disables retrying)
+    /// - MCP_RETRY_BASE_DELAY_MS: Backoff before the first retry, doubled for each further one
+    /// - MCP_RETRY_MAX_DELAY_MS: Longest backoff between retries
+    /// - MCP_RETRY_JITTER: Fraction of each backoff drawn at random (0 to 1)
+    /// - MCP_CIRCUIT_FAILURE_THRESHOLD: Consecutive failures that take an RPC endpoint out of rotation
+    /// - MCP_CIRCUIT_COOLDOWN_SECS: Seconds an RPC endpoint stays out of rotation
     /// - MANTRA_NETWORK: Network name (mainnet/testnet)
     pub fn from_env() -> McpResult<Self> {
         // Load .env file if auto-load is enabled (check env var first)
@@ -1309,104 +2178,677 @@ impl McpServerConfig {
             config.http_host = host;
         }
 
-        if let Ok(timeout_str) = env::var("MCP_REQUEST_TIMEOUT_SECS") {
-            config.request_timeout_secs = timeout_str.parse().unwrap_or(30);
+        let rate_limit = &mut config.http_rate_limit;
+        for (var, limit) in [
+            (
+                "MCP_HTTP_RATE_LIMIT_PER_CLIENT",
+                &mut rate_limit.per_client_per_minute,
+            ),
+            (
+                "MCP_HTTP_RATE_LIMIT_BURST",
+                &mut rate_limit.per_client_burst,
+            ),
+            (
+                "MCP_HTTP_RATE_LIMIT_GLOBAL",
+                &mut rate_limit.global_per_minute,
+            ),
+            (
+                "MCP_HTTP_RATE_LIMIT_GLOBAL_BURST",
+                &mut rate_limit.global_burst,
+            ),
+            (
+                "MCP_HTTP_EXPENSIVE_TOOL_COST",
+                &mut rate_limit.expensive_tool_cost,
+            ),
+        ] {
+            if let Ok(value) = env::var(var) {
+                match value.parse() {
+                    Ok(value) => *limit = value,
+                    Err(e) => warn!("Ignoring {}: {}", var, e),
+                }
+            }
         }
 
-        if let Ok(cache_ttl_str) = env::var("MCP_CACHE_TTL_SECS") {
-            config.cache_ttl_secs = cache_ttl_str.parse().unwrap_or(300);
+        if let Ok(max_in_flight_str) = env::var("MCP_HTTP_MAX_IN_FLIGHT") {
+            match max_in_flight_str.parse() {
+                Ok(max_in_flight) => rate_limit.max_in_flight = max_in_flight,
+                Err(e) => warn!("Ignoring MCP_HTTP_MAX_IN_FLIGHT: {}", e),
+            }
         }
 
-        config.auto_load_env = auto_load_env;
+        if let Ok(trust_str) = env::var("MCP_HTTP_TRUST_FORWARDED_FOR") {
+            rate_limit.trust_forwarded_for = trust_str.parse().unwrap_or(false);
+        }
 
-        // Load network configuration
-        if let Ok(network_name) = env::var("MANTRA_NETWORK") {
-            match network_name.as_str() {
-                "mantra-dukong" => {
-                    if let Ok(constants) = NetworkConstants::load("mantra-dukong") {
-                        match MantraNetworkConfig::from_constants(&constants) {
-                            Ok(network_config) => {
-                                config.network_config = network_config;
-                            }
-                            Err(e) => {
-                                warn!("Failed to create network config: {}, using default", e);
-                            }
-                        }
-                    } else {
-                        warn!("Could not load mantra-dukong constants, using default");
-                    }
-                }
-                _ => {
-                    warn!("Unknown network '{}', using default", network_name);
+        if let Ok(api_keys) = env::var("MCP_HTTP_API_KEYS") {
+            for entry in api_keys.split(',').filter(|entry| !entry.trim().is_empty()) {
+                match entry.split_once('=') {
+                    Some((name, key)) => config.http_auth.api_keys.push(ApiKeyEntry {
+                        name: name.trim().to_string(),
+                        key: Some(key.trim().to_string()),
+                        ..Default::default()
+                    }),
+                    None => warn!("Ignoring MCP_HTTP_API_KEYS entry without '='"),
                 }
             }
         }
 
-        info!("MCP Server configuration loaded from environment");
-        debug!("Config: {:?}", config);
+        if let Ok(secret) = env::var("MCP_HTTP_JWT_SECRET") {
+            if !secret.is_empty() {
+                config.http_auth.jwt = Some(JwtConfig {
+                    secret,
+                    issuer: env::var("MCP_HTTP_JWT_ISSUER").ok(),
+                    audience: env::var("MCP_HTTP_JWT_AUDIENCE").ok(),
+                    leeway_secs: 0,
+                });
+            }
+        }
 
-        Ok(config)
-    }
+        if let Ok(path) = env::var("MCP_AUDIT_LOG_PATH") {
+            if !path.is_empty() {
+                config.audit_log.path = Some(PathBuf::from(path));
+            }
+        }
 
-    /// Validate configuration values
-    pub fn validate(&self) -> McpResult<()> {
-        if self.name.is_empty() {
-            return Err(McpServerError::Validation(
-                "Server name cannot be empty".to_string(),
-            ));
+        if let Ok(max_bytes_str) = env::var("MCP_AUDIT_LOG_MAX_BYTES") {
+            match max_bytes_str.parse() {
+                Ok(max_bytes) => config.audit_log.max_file_bytes = max_bytes,
+                Err(e) => warn!("Ignoring MCP_AUDIT_LOG_MAX_BYTES: {}", e),
+            }
         }
 
-        if self.version.is_empty() {
-            return Err(McpServerError::Validation(
-                "Server version cannot be empty".to_string(),
-            ));
+        if let Ok(max_files_str) = env::var("MCP_AUDIT_LOG_MAX_FILES") {
+            match max_files_str.parse() {
+                Ok(max_files) => config.audit_log.max_files = max_files,
+                Err(e) => warn!("Ignoring MCP_AUDIT_LOG_MAX_FILES: {}", e),
+            }
         }
 
-        if self.max_concurrent_ops == 0 {
-            return Err(McpServerError::Validation(
-                "Maximum concurrent operations must be greater than 0".to_string(),
-            ));
+        if let Ok(capacity_str) = env::var("MCP_TRACE_CAPACITY") {
+            match capacity_str.parse() {
+                Ok(capacity) => config.traces.capacity = capacity,
+                Err(e) => warn!("Ignoring MCP_TRACE_CAPACITY: {}", e),
+            }
         }
 
-        if self.http_port == 0 {
-            return Err(McpServerError::Validation(
-                "HTTP port must be greater than 0".to_string(),
-            ));
+        if let Ok(backend_str) = env::var("MCP_CACHE_BACKEND") {
+            match backend_str.parse::<CacheBackend>() {
+                Ok(backend) => config.adapter_cache.backend = backend,
+                Err(e) => warn!("Ignoring MCP_CACHE_BACKEND: {}", e),
+            }
         }
 
-        if self.http_host.is_empty() {
-            return Err(McpServerError::Validation(
-                "HTTP host cannot be empty".to_string(),
-            ));
+        if let Ok(path) = env::var("MCP_CACHE_PATH") {
+            if !path.is_empty() {
+                config.adapter_cache.path = Some(PathBuf::from(path));
+            }
         }
 
-        if self.request_timeout_secs == 0 {
-            return Err(McpServerError::Validation(
-                "Request timeout must be greater than 0".to_string(),
-            ));
+        if let Ok(max_entries_str) = env::var("MCP_CACHE_MAX_ENTRIES") {
+            match max_entries_str.parse() {
+                Ok(max_entries) => config.adapter_cache.max_entries = max_entries,
+                Err(e) => warn!("Ignoring MCP_CACHE_MAX_ENTRIES: {}", e),
+            }
         }
 
-        if self.cache_ttl_secs == 0 {
-            return Err(McpServerError::Validation(
-                "Cache TTL must be greater than 0".to_string(),
-            ));
+        if let Ok(max_bytes_str) = env::var("MCP_CACHE_MAX_BYTES") {
+            match max_bytes_str.parse() {
+                Ok(max_bytes) => config.adapter_cache.max_bytes = max_bytes,
+                Err(e) => warn!("Ignoring MCP_CACHE_MAX_BYTES: {}", e),
+            }
         }
 
-        Ok(())
-    }
+        if let Ok(max_lag_str) = env::var("MCP_HEALTH_MAX_BLOCK_LAG_SECS") {
+            match max_lag_str.parse() {
+                Ok(max_lag) => config.health.max_block_lag_secs = max_lag,
+                Err(e) => warn!("Ignoring MCP_HEALTH_MAX_BLOCK_LAG_SECS: {}", e),
+            }
+        }
 
-    /// Create configuration with network override
-    pub fn with_network(network: &str) -> McpResult<Self> {
-        let mut config = Self::from_env()?;
+        if let Ok(timeout_str) = env::var("MCP_HEALTH_PROBE_TIMEOUT_MS") {
+            match timeout_str.parse() {
+                Ok(timeout) => config.health.probe_timeout_ms = timeout,
+                Err(e) => warn!("Ignoring MCP_HEALTH_PROBE_TIMEOUT_MS: {}", e),
+            }
+        }
 
-        match network {
-            "mantra-dukong" => {
-                if let Ok(constants) = NetworkConstants::load("mantra-dukong") {
-                    match MantraNetworkConfig::from_constants(&constants) {
-                        Ok(network_config) => {
-                            config.network_config = network_config;
-                        }
-                        Err(e) => {
+        if let Ok(tools) = env::var("MCP_QUEUE_TOOLS") {
+            config.transaction_queue.tools = tools
+                .split(',')
+                .map(|tool| tool.trim().to_string())
+                .filter(|tool| !tool.is_empty())
+                .collect();
+        }
+
+        if let Ok(ttl_str) = env::var("MCP_QUEUE_TTL_SECS") {
+            match ttl_str.parse() {
+                Ok(ttl) => config.transaction_queue.ttl_secs = ttl,
+                Err(e) => warn!("Ignoring MCP_QUEUE_TTL_SECS: {}", e),
+            }
+        }
+
+        if let Ok(approvers) = env::var("MCP_QUEUE_APPROVERS") {
+            config.transaction_queue.approvers = approvers
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+                .collect();
+        }
+
+        if let Ok(timeout_str) = env::var("MCP_REQUEST_TIMEOUT_SECS") {
+            config.request_timeout_secs = timeout_str.parse().unwrap_or(30);
+        }
+
+        if let Ok(cache_ttl_str) = env::var("MCP_CACHE_TTL_SECS") {
+            config.cache_ttl_secs = cache_ttl_str.parse().unwrap_or(300);
+        }
+
+        if let Ok(per_session_str) = env::var("MCP_MAX_WRITE_OPS_PER_SESSION") {
+            config.max_write_ops_per_session = per_session_str
+                .parse()
+                .unwrap_or_else(|_| default_max_write_ops_per_session());
+        }
+
+        if let Ok(global_str) = env::var("MCP_MAX_WRITE_OPS_GLOBAL") {
+            config.max_write_ops_global = global_str
+                .parse()
+                .unwrap_or_else(|_| default_max_write_ops_global());
+        }
+
+        if let Ok(require_nonces_str) = env::var("MCP_REQUIRE_WRITE_NONCES") {
+            config.require_write_nonces = require_nonces_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(dry_run_str) = env::var("MCP_DRY_RUN") {
+            config.dry_run = dry_run_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(prefetch_str) = env::var("MCP_PREFETCH") {
+            config.prefetch = prefetch_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(interval_str) = env::var("MCP_PREFETCH_INTERVAL_SECS") {
+            match interval_str.parse() {
+                Ok(interval) => config.prefetch_interval_secs = interval,
+                Err(e) => warn!("Ignoring MCP_PREFETCH_INTERVAL_SECS: {}", e),
+            }
+        }
+
+        if let Ok(interval_str) = env::var("MCP_ERC20_REFRESH_INTERVAL_SECS") {
+            match interval_str.parse() {
+                Ok(interval) => config.erc20_refresh_interval_secs = interval,
+                Err(e) => warn!("Ignoring MCP_ERC20_REFRESH_INTERVAL_SECS: {}", e),
+            }
+        }
+
+        if let Ok(profile) = env::var("MCP_NETWORK_PROFILE") {
+            config.network_profile = Some(profile).filter(|profile| !profile.is_empty());
+        }
+
+        if let Ok(dust_action_str) = env::var("MCP_DUST_ACTION") {
+            match dust_action_str.parse() {
+                Ok(action) => config.dust_policy.action = action,
+                Err(e) => warn!("Ignoring MCP_DUST_ACTION: {}", e),
+            }
+        }
+
+        if let Ok(dust_threshold_str) = env::var("MCP_DUST_THRESHOLD") {
+            match dust_threshold_str.parse() {
+                Ok(threshold) => config.dust_policy.default_threshold = threshold,
+                Err(e) => warn!("Ignoring MCP_DUST_THRESHOLD: {}", e),
+            }
+        }
+
+        if let Ok(state_log_path) = env::var("MCP_STATE_LOG_PATH") {
+            if !state_log_path.trim().is_empty() {
+                config.state_log_path = Some(PathBuf::from(state_log_path));
+            }
+        }
+
+        if let Ok(tx_queue_path) = env::var("MCP_TX_QUEUE_PATH") {
+            if !tx_queue_path.trim().is_empty() {
+                config.tx_queue_path = Some(PathBuf::from(tx_queue_path));
+            }
+        }
+
+        if let Ok(address_book_path) = env::var("MCP_ADDRESS_BOOK_PATH") {
+            if !address_book_path.trim().is_empty() {
+                config.address_book_path = Some(PathBuf::from(address_book_path));
+            }
+        }
+
+        if let Ok(restricted_wallets_path) = env::var("MCP_RESTRICTED_WALLETS_PATH") {
+            if !restricted_wallets_path.trim().is_empty() {
+                config.restricted_wallets_path = Some(PathBuf::from(restricted_wallets_path));
+            }
+        }
+
+        if let Ok(signing_log_path) = env::var("MCP_SIGNING_LOG_PATH") {
+            if !signing_log_path.trim().is_empty() {
+                config.signing_log_path = Some(PathBuf::from(signing_log_path));
+            }
+        }
+
+        if let Ok(evm_log_cache_dir) = env::var("MCP_EVM_LOG_CACHE_DIR") {
+            if !evm_log_cache_dir.trim().is_empty() {
+                config.evm_log_cache_dir = Some(PathBuf::from(evm_log_cache_dir));
+            }
+        }
+
+        if let Ok(erc20_artifact_path) = env::var("MCP_ERC20_ARTIFACT_PATH") {
+            if !erc20_artifact_path.trim().is_empty() {
+                config.erc20_artifact_path = Some(PathBuf::from(erc20_artifact_path));
+            }
+        }
+
+        if let Ok(erc20_artifact_sha256) = env::var("MCP_ERC20_ARTIFACT_SHA256") {
+            if !erc20_artifact_sha256.trim().is_empty() {
+                config.erc20_artifact_sha256 = Some(erc20_artifact_sha256);
+            }
+        }
+
+        if let Ok(addr) = env::var("MCP_REMOTE_CONTROL_ADDR") {
+            if !addr.trim().is_empty() {
+                config.remote_control.listen_addr = Some(addr);
+            }
+        }
+
+        if let Ok(key_path) = env::var("MCP_REMOTE_CONTROL_KEY_PATH") {
+            if !key_path.trim().is_empty() {
+                config.remote_control.key_path = Some(PathBuf::from(key_path));
+            }
+        }
+
+        if let Ok(operators) = env::var("MCP_REMOTE_CONTROL_OPERATORS") {
+            config.remote_control.authorized_operators = operators
+                .split(',')
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty())
+                .collect();
+        }
+
+        if let Ok(require_approval_str) = env::var("MCP_REQUIRE_OPERATOR_APPROVAL") {
+            config.remote_control.require_approval = require_approval_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(timeout_str) = env::var("MCP_APPROVAL_TIMEOUT_SECS") {
+            match timeout_str.parse() {
+                Ok(timeout) => config.remote_control.approval_timeout_secs = timeout,
+                Err(e) => warn!("Ignoring MCP_APPROVAL_TIMEOUT_SECS: {}", e),
+            }
+        }
+
+        if let Ok(address) = env::var("MCP_SKIP_AFFILIATE_ADDRESS") {
+            let bps = env::var("MCP_SKIP_AFFILIATE_BPS").unwrap_or_default();
+            let affiliate = bps
+                .parse::<u16>()
+                .map_err(|e| SdkError::Config(format!("Invalid MCP_SKIP_AFFILIATE_BPS: {}", e)))
+                .and_then(|bps| SkipAffiliateConfig::new(address, bps));
+            match affiliate {
+                Ok(affiliate) => config.skip_affiliate = Some(affiliate),
+                Err(e) => warn!("Ignoring Skip affiliate configuration: {}", e),
+            }
+        }
+
+        if let Ok(memo) = env::var("MCP_TX_MEMO") {
+            let tagging = TxTagging {
+                memo: Some(memo).filter(|memo| !memo.is_empty()),
+                ..config.tx_tagging.clone()
+            };
+            match tagging.validate() {
+                Ok(()) => config.tx_tagging = tagging,
+                Err(e) => warn!("Ignoring MCP_TX_MEMO: {}", e),
+            }
+        }
+
+        if let Ok(suffix_str) = env::var("MCP_EVM_DATA_SUFFIX") {
+            config.tx_tagging.evm_data_suffix = suffix_str.parse().unwrap_or(false);
+        }
+
+        if let Ok(blocks_str) = env::var("MCP_TX_TIMEOUT_BLOCKS") {
+            match blocks_str.parse() {
+                Ok(blocks) => config.tx_timeout_blocks = blocks,
+                Err(e) => warn!("Ignoring MCP_TX_TIMEOUT_BLOCKS: {}", e),
+            }
+        }
+
+        if let Ok(policy_str) = env::var("MCP_INFINITE_APPROVAL_POLICY") {
+            match policy_str.parse() {
+                Ok(policy) => config.infinite_approval_policy = policy,
+                Err(e) => warn!("Ignoring MCP_INFINITE_APPROVAL_POLICY: {}", e),
+            }
+        }
+
+        if let Ok(chain_rpcs) = env::var("MCP_SKIP_CHAIN_RPCS") {
+            for entry in chain_rpcs
+                .split(',')
+                .filter(|entry| !entry.trim().is_empty())
+            {
+                match entry.split_once('=') {
+                    Some((chain_id, rpc_url)) => {
+                        config
+                            .skip_chain_rpcs
+                            .insert(chain_id.trim().to_string(), rpc_url.trim().to_string());
+                    }
+                    None => warn!("Ignoring MCP_SKIP_CHAIN_RPCS entry without '=': {}", entry),
+                }
+            }
+        }
+
+        if let Ok(factory_address) = env::var("MCP_CLAIMDROP_FACTORY_ADDRESS") {
+            let factory_address = factory_address.trim();
+            if !factory_address.is_empty() {
+                config.claimdrop_factory_address = Some(factory_address.to_string());
+            }
+        }
+
+        if let Ok(max_class) = env::var("MCP_AUTHZ_MAX_CLASS") {
+            match max_class.parse() {
+                Ok(max_class) => config.authorization.max_class = max_class,
+                Err(e) => warn!("Ignoring MCP_AUTHZ_MAX_CLASS: {}", e),
+            }
+        }
+
+        let tool_list = |list: String| -> Vec<String> {
+            list.split(',')
+                .map(|tool| tool.trim().to_string())
+                .filter(|tool| !tool.is_empty())
+                .collect()
+        };
+        if let Ok(allow_tools) = env::var("MCP_AUTHZ_ALLOW_TOOLS") {
+            config.authorization.allow_tools = tool_list(allow_tools);
+        }
+        if let Ok(deny_tools) = env::var("MCP_AUTHZ_DENY_TOOLS") {
+            config.authorization.deny_tools = tool_list(deny_tools);
+        }
+
+        for (var, limit) in [
+            (
+                "MCP_AUTHZ_MAX_SPEND_PER_TX",
+                &mut config.authorization.max_spend_per_tx,
+            ),
+            (
+                "MCP_AUTHZ_MAX_DAILY_SPEND",
+                &mut config.authorization.max_daily_spend,
+            ),
+            (
+                "MCP_AUTHZ_CONFIRM_ABOVE",
+                &mut config.authorization.confirm_above,
+            ),
+        ] {
+            if let Ok(value) = env::var(var) {
+                match value.parse() {
+                    Ok(value) => *limit = Some(value),
+                    Err(e) => warn!("Ignoring {}: {}", var, e),
+                }
+            }
+        }
+
+        if let Ok(window_secs) = env::var("MCP_BUDGET_WINDOW_SECS") {
+            match window_secs.parse() {
+                Ok(window_secs) => config.budget.window_secs = window_secs,
+                Err(e) => warn!("Ignoring MCP_BUDGET_WINDOW_SECS: {}", e),
+            }
+        }
+        if let Ok(max_native) = env::var("MCP_BUDGET_MAX_NATIVE") {
+            match max_native.parse() {
+                Ok(max_native) => config.budget.max_native = Some(max_native),
+                Err(e) => warn!("Ignoring MCP_BUDGET_MAX_NATIVE: {}", e),
+            }
+        }
+        if let Ok(max_usd) = env::var("MCP_BUDGET_MAX_USD") {
+            match max_usd.parse() {
+                Ok(max_usd) => config.budget.max_usd = Some(max_usd),
+                Err(e) => warn!("Ignoring MCP_BUDGET_MAX_USD: {}", e),
+            }
+        }
+
+        if let Ok(max_retries) = env::var("MCP_RETRY_MAX_RETRIES") {
+            match max_retries.parse() {
+                Ok(max_retries) => config.retry.max_retries = max_retries,
+                Err(e) => warn!("Ignoring MCP_RETRY_MAX_RETRIES: {}", e),
+            }
+        }
+        for (var, delay_ms) in [
+            ("MCP_RETRY_BASE_DELAY_MS", &mut config.retry.base_delay_ms),
+            ("MCP_RETRY_MAX_DELAY_MS", &mut config.retry.max_delay_ms),
+        ] {
+            if let Ok(value) = env::var(var) {
+                match value.parse() {
+                    Ok(value) => *delay_ms = value,
+                    Err(e) => warn!("Ignoring {}: {}", var, e),
+                }
+            }
+        }
+        if let Ok(jitter) = env::var("MCP_RETRY_JITTER") {
+            match jitter.parse() {
+                Ok(jitter) => config.retry.jitter = jitter,
+                Err(e) => warn!("Ignoring MCP_RETRY_JITTER: {}", e),
+            }
+        }
+
+        if let Ok(threshold) = env::var("MCP_CIRCUIT_FAILURE_THRESHOLD") {
+            match threshold.parse() {
+                Ok(threshold) => config.circuit_breaker.failure_threshold = threshold,
+                Err(e) => warn!("Ignoring MCP_CIRCUIT_FAILURE_THRESHOLD: {}", e),
+            }
+        }
+        if let Ok(cooldown_secs) = env::var("MCP_CIRCUIT_COOLDOWN_SECS") {
+            match cooldown_secs.parse() {
+                Ok(cooldown_secs) => config.circuit_breaker.cooldown_secs = cooldown_secs,
+                Err(e) => warn!("Ignoring MCP_CIRCUIT_COOLDOWN_SECS: {}", e),
+            }
+        }
+
+        config.auto_load_env = auto_load_env;
+
+        // Load network configuration
+        if let Ok(network_name) = env::var("MANTRA_NETWORK") {
+            match network_name.as_str() {
+                "mantra-dukong" => {
+                    if let Ok(constants) = NetworkConstants::load("mantra-dukong") {
+                        match MantraNetworkConfig::from_constants(&constants) {
+                            Ok(network_config) => {
+                                config.network_config = network_config;
+                            }
+                            Err(e) => {
+                                warn!("Failed to create network config: {}, using default", e);
+                            }
+                        }
+                    } else {
+                        warn!("Could not load mantra-dukong constants, using default");
+                    }
+                }
+                _ => {
+                    warn!("Unknown network '{}', using default", network_name);
+                }
+            }
+        }
+
+        info!("MCP Server configuration loaded from environment");
+        debug!("Config: {:?}", config);
+
+        Ok(config)
+    }
+
+    /// Validate configuration values
+    pub fn validate(&self) -> McpResult<()> {
+        if self.name.is_empty() {
+            return Err(McpServerError::Validation(
+                "Server name cannot be empty".to_string(),
+            ));
+        }
+
+        if self.version.is_empty() {
+            return Err(McpServerError::Validation(
+                "Server version cannot be empty".to_string(),
+            ));
+        }
+
+        if self.max_concurrent_ops == 0 {
+            return Err(McpServerError::Validation(
+                "Maximum concurrent operations must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.http_port == 0 {
+            return Err(McpServerError::Validation(
+                "HTTP port must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.http_host.is_empty() {
+            return Err(McpServerError::Validation(
+                "HTTP host cannot be empty".to_string(),
+            ));
+        }
+
+        if self.request_timeout_secs == 0 {
+            return Err(McpServerError::Validation(
+                "Request timeout must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.cache_ttl_secs == 0 {
+            return Err(McpServerError::Validation(
+                "Cache TTL must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_write_ops_per_session == 0 || self.max_write_ops_global == 0 {
+            return Err(McpServerError::Validation(
+                "Write operation quotas must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_write_ops_per_session > self.max_write_ops_global {
+            return Err(McpServerError::Validation(
+                "Per-session write quota cannot exceed the global write quota".to_string(),
+            ));
+        }
+
+        if self.remote_control.require_approval && !self.remote_control.is_enabled() {
+            return Err(McpServerError::Validation(
+                "Operator approval requires the remote control channel to be enabled".to_string(),
+            ));
+        }
+
+        if self.remote_control.is_enabled() && self.remote_control.authorized_operators.is_empty() {
+            return Err(McpServerError::Validation(
+                "Remote control requires at least one authorized operator key".to_string(),
+            ));
+        }
+
+        self.http_auth
+            .validate()
+            .map_err(McpServerError::Validation)?;
+
+        if self.audit_log.path.is_some() && self.audit_log.max_file_bytes == 0 {
+            return Err(McpServerError::Validation(
+                "Audit log max_file_bytes must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.health.probe_timeout_ms == 0 {
+            return Err(McpServerError::Validation(
+                "Health probe_timeout_ms must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.prefetch && self.prefetch_interval_secs == 0 {
+            return Err(McpServerError::Validation(
+                "Prefetch interval must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.adapter_cache.max_entries == 0 || self.adapter_cache.max_bytes == 0 {
+            return Err(McpServerError::Validation(
+                "Adapter cache max_entries and max_bytes must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.adapter_cache.backend == CacheBackend::Sqlite {
+            if self.adapter_cache.path.is_none() {
+                return Err(McpServerError::Validation(
+                    "The sqlite adapter cache requires a path".to_string(),
+                ));
+            }
+            if !cfg!(feature = "sqlite-cache") {
+                return Err(McpServerError::Validation(
+                    "The sqlite adapter cache requires the sqlite-cache feature".to_string(),
+                ));
+            }
+        }
+
+        if !self.transaction_queue.approvers.is_empty() && !self.http_auth.is_enabled() {
+            return Err(McpServerError::Validation(
+                "Transaction queue approvers require HTTP authentication".to_string(),
+            ));
+        }
+
+        if let (Some(per_tx), Some(daily)) = (
+            self.authorization.max_spend_per_tx,
+            self.authorization.max_daily_spend,
+        ) {
+            if per_tx > daily {
+                return Err(McpServerError::Validation(
+                    "Per-transaction spend limit cannot exceed the daily spend limit".to_string(),
+                ));
+            }
+        }
+
+        if self.budget.window_secs == 0
+            || self
+                .budget
+                .wallets
+                .values()
+                .any(|wallet| wallet.window_secs == Some(0))
+        {
+            return Err(McpServerError::Validation(
+                "Budget window must be at least one second".to_string(),
+            ));
+        }
+        if self
+            .budget
+            .usd_prices
+            .values()
+            .any(|price| !price.usd.is_finite() || price.usd < 0.0)
+            || std::iter::once(self.budget.max_usd)
+                .chain(self.budget.wallets.values().map(|wallet| wallet.max_usd))
+                .flatten()
+                .any(|max| !max.is_finite() || max < 0.0)
+        {
+            return Err(McpServerError::Validation(
+                "Budget prices and dollar limits must be non-negative numbers".to_string(),
+            ));
+        }
+
+        if let Some(affiliate) = &self.skip_affiliate {
+            affiliate
+                .validate()
+                .map_err(|e| McpServerError::Validation(e.to_string()))?;
+        }
+
+        self.retry.validate().map_err(McpServerError::Validation)?;
+        self.circuit_breaker
+            .validate()
+            .map_err(McpServerError::Validation)?;
+
+        Ok(())
+    }
+
+    /// Create configuration with network override
+    pub fn with_network(network: &str) -> McpResult<Self> {
+        let mut config = Self::from_env()?;
+
+        match network {
+            "mantra-dukong" => {
+                if let Ok(constants) = NetworkConstants::load("mantra-dukong") {
+                    match MantraNetworkConfig::from_constants(&constants) {
+                        Ok(network_config) => {
+                            config.network_config = network_config;
+                        }
+                        Err(e) => {
                             return Err(McpServerError::Network(format!(
                                 "Failed to create network config: {}",
                                 e
@@ -1647,6 +3089,36 @@ max_concurrent_ops = {}
 request_timeout_secs = {}
 cache_ttl_secs = {}
 
+# Write operation quotas (in-flight swaps, claims, transfers, ...)
+max_write_ops_per_session = {}
+max_write_ops_global = {}
+
+# Replay protection: reject write tool calls without a per-session `_meta.nonce`
+require_write_nonces = {}
+
+# Simulate every write tool call instead of broadcasting it, as if each call
+# passed dry_run = true
+dry_run = {}
+
+# ERC-20 approvals of 2^255 or more: "allow", "warn" (default, the result
+# carries a warning) or "block"
+infinite_approval_policy = "{}"
+
+# Blocks past the latest one Cosmos transactions are valid for before the chain
+# drops them (0 lets them wait in the mempool indefinitely); write tools take a
+# `timeout_blocks` argument replacing it
+tx_timeout_blocks = {}
+
+# Load the pool list, pool tokens and registry ERC-20 metadata into the cache at
+# startup and refresh them every prefetch_interval_secs
+prefetch = {}
+prefetch_interval_secs = {}
+
+# Refetch stale registry ERC-20 metadata in the background every
+# erc20_refresh_interval_secs, recently used tokens first, and fetch again the
+# metadata of tokens whose contract code changed (0 disables it)
+erc20_refresh_interval_secs = {}
+
 # HTTP transport settings (used when running with --transport http)
 http_host = "{}"
 http_port = {}
@@ -1654,6 +3126,197 @@ http_port = {}
 # Environment file loading
 auto_load_env = {}
 
+# Event log of wallet and custom token state, replayed on startup (optional)
+# state_log_path = "mcp_state.jsonl"
+
+# Signed transactions are persisted here before broadcast and monitored again
+# after a restart (optional, defaults to mcp_tx_queue.json in the config directory)
+# tx_queue_path = "mcp_tx_queue.json"
+
+# Labelled addresses accepted in place of addresses in tool arguments; the TUI
+# shares the default file ~/.mantra_dex/address_book.json (optional)
+# address_book_path = "address_book.json"
+
+# Wallets PrimarySale settlement refunds to the multisig instead of distributing
+# to, injected into every settlement batch (optional, defaults to
+# ~/.mantra_dex/restricted_wallets.json)
+# restricted_wallets_path = "restricted_wallets.json"
+
+# File the decoded intent, signature and hash of every signed transaction are
+# appended to (optional, kept in memory only when unset)
+# signing_log_path = "signing_log.jsonl"
+
+# Directory the logs evm_get_logs and approval scans fetched are cached in, so
+# later queries only fetch new blocks (optional, defaults to
+# ~/.mantra_dex/log_cache)
+# evm_log_cache_dir = "log_cache"
+
+# Compiled standard mintable/burnable ERC-20 artifact (bytecode in hex, or a
+# Foundry or Hardhat JSON artifact) deployed by evm_deploy_erc20 (optional,
+# defaults to ~/.mantra_dex/contracts/StandardErc20.json)
+# erc20_artifact_path = "contracts/StandardErc20.json"
+
+# Sha256 of the audited artifact's creation bytecode; evm_deploy_erc20 deploys
+# nothing else, and nothing at all while unset
+# erc20_artifact_sha256 = "..."
+
+# Claimdrop factory whose campaigns are readable as the mcp://campaigns resource
+# claimdrop_factory_address = "mantra1..."
+
+# Network profile to start on instead of the network above; switch_network
+# changes it at runtime (optional)
+# network_profile = "dukong"
+
+# Encrypted remote control channel for headless servers (Noise XX with pinned keys)
+# Operators connect with their own key and must present the server key logged at startup
+[remote_control]
+# listen_addr = "0.0.0.0:7443"
+# key_path = "remote_control.key"
+# authorized_operators = ["<operator public key hex>"]
+# Hold write tool calls until an operator approves them over the channel
+require_approval = {}
+approval_timeout_secs = {}
+
+# HTTP request limits; a limit of 0 is off. Requests over a limit get a 429
+# with Retry-After. Calls to expensive tools count as expensive_tool_cost requests
+[http_rate_limit]
+per_client_per_minute = {}
+per_client_burst = {}
+global_per_minute = {}
+global_burst = {}
+max_in_flight = {}
+expensive_tool_cost = {}
+# Only behind a proxy that sets X-Forwarded-For
+trust_forwarded_for = {}
+
+# HTTP client authentication; off unless a key or JWT secret is set. Keys
+# may be limited to tools (a trailing * matches a prefix), tokens through
+# their "tools" claim
+# [[http_auth.api_keys]]
+# name = "agent"
+# key_sha256 = "<hex sha256 of the key>"
+# tools = ["dex_get_*", "wallet_get_balances"]
+# [http_auth.jwt]
+# secret = "<at least 32 bytes>"
+# issuer = "ops"
+# audience = "mantra-mcp"
+
+# Audit log of state-changing tool calls, kept in memory only without a path.
+# The file is rotated to <path>.1 at max_file_bytes; max_files rotations are kept
+[audit_log]
+# path = "/var/log/mantra-mcp/audit.jsonl"
+max_file_bytes = {}
+max_files = {}
+
+# Traces of completed tool calls (RPC requests and retries under each call's
+# correlation ID), listed by get_recent_traces; 0 keeps none
+[traces]
+capacity = {}
+
+# Cache of pools, token metadata and ABIs; LRU entries are evicted past either
+# limit. The "sqlite" backend (sqlite-cache feature) keeps entries across restarts
+[adapter_cache]
+backend = "{}"
+# path = "/var/lib/mantra-mcp/cache.db"
+max_entries = {}
+max_bytes = {}
+
+# Health (/healthz) and readiness (/readyz) checks: a chain whose latest block
+# is older than max_block_lag_secs is degraded, an RPC endpoint that does not
+# answer within probe_timeout_ms is down
+[health]
+max_block_lag_secs = {}
+probe_timeout_ms = {}
+
+# Write tools whose calls are simulated and queued for approval instead of
+# broadcast (a trailing * matches a prefix). Proposals are approved with
+# approve_transaction, by one of the approvers if any are listed
+[transaction_queue]
+# tools = ["dex_execute_swap", "skip_*"]
+ttl_secs = {}
+# approvers = ["treasury-ops"]
+
+# Per-tool authorization policy
+# Tool classes from least to most privileged: "read_only", "state_changing", "admin"
+[authorization]
+max_class = "{}"
+# allow_tools = ["dex_*", "wallet_get_balances"]
+# deny_tools = ["primary_sale_emergency_withdraw"]
+# Spend limits in base units of spend_denom; spends above confirm_above are
+# rejected with a token the caller repeats the call with once the user agrees
+spend_denom = "{}"
+# max_spend_per_tx = 1000000000
+# max_daily_spend = 10000000000
+# confirm_above = 100000000
+
+# Per-wallet spending budgets over a rolling window, in base units of
+# native_denom and in US dollars priced from usd_prices. While max_usd is set,
+# spends of denoms without a price are refused unless block_unpriced = false
+[budget]
+native_denom = "{}"
+window_secs = {}
+# max_native = 5000000000
+# max_usd = 500.0
+# [budget.usd_prices]
+# uom = {{ usd = 0.25, decimals = 6 }}
+# [budget.wallets."mantra1..."]
+# max_usd = 5000.0
+
+# Retries of transient failures (timeouts, HTTP 429, unavailable gateways,
+# account sequence mismatches) on Cosmos RPC, EVM RPC and HTTP calls. Invalid
+# arguments, reverts and insufficient funds are never retried. Waits double
+# from base_delay_ms up to max_delay_ms, less a random fraction up to jitter
+[retry]
+max_retries = {}
+base_delay_ms = {}
+max_delay_ms = {}
+jitter = {}
+
+# Circuit breaker of each RPC endpoint. After failure_threshold consecutive
+# failures an endpoint is left out for cooldown_secs and calls fail over to the
+# network's other endpoints (rpc_fallback_urls, evm_rpc_fallback_urls)
+[circuit_breaker]
+failure_threshold = {}
+cooldown_secs = {}
+
+# Dust handling after swaps and zaps: "ignore", "convert_to_native" or "accumulate"
+# Balances below default_threshold base units, other than native_denom, are dust
+[dust_policy]
+action = "{}"
+default_threshold = "{}"
+native_denom = "{}"
+
+# Affiliate paid out of every Skip swap, taken from the swap output (optional)
+# [skip_affiliate]
+# address = "mantra1..."
+# basis_points_fee = 50
+
+# Attribution tag of every transaction sent (optional). The memo pattern is
+# stamped on Cosmos transactions, with {{version}} and {{chain_id}} expanded; write
+# tools take a `memo` argument replacing it. evm_data_suffix appends the same
+# tag to EVM calldata
+# [tx_tagging]
+# memo = "mantra-sdk/{{version}} via mcp"
+# evm_data_suffix = false
+
+# Destination chain RPC endpoints; Skip deliveries to these chains are checked
+# against the recipient's balance once they settle
+# [skip_chain_rpcs]
+# "osmosis-1" = "https://rpc.osmosis.zone"
+
+# Network profiles for switch_network, added to or replacing the built-in
+# mainnet, dukong and local profiles. A profile starts from `network` in
+# config/network.toml when given; every other setting overrides it
+# [network_profiles.local]
+# chain_id = "mantra-local-1"
+# rpc_url = "http://localhost:26657"
+# evm_rpc_url = "http://localhost:8545"
+# evm_chain_id = 1337
+# gas_price = 0.01
+# gas_adjustment = 1.5
+# [network_profiles.local.contracts]
+# pool_manager = "mantra1..."
+
 # Async runtime configuration
 [runtime_config]
 # Runtime flavor: "CurrentThread" or "MultiThread"
@@ -1680,9 +3343,49 @@ thread_keep_alive = 10
             example_config.max_concurrent_ops,
             example_config.request_timeout_secs,
             example_config.cache_ttl_secs,
+            example_config.max_write_ops_per_session,
+            example_config.max_write_ops_global,
+            example_config.require_write_nonces,
+            example_config.dry_run,
+            example_config.infinite_approval_policy,
+            example_config.tx_timeout_blocks,
+            example_config.prefetch,
+            example_config.prefetch_interval_secs,
+            example_config.erc20_refresh_interval_secs,
             example_config.http_host,
             example_config.http_port,
-            example_config.auto_load_env
+            example_config.auto_load_env,
+            example_config.remote_control.require_approval,
+            example_config.remote_control.approval_timeout_secs,
+            example_config.http_rate_limit.per_client_per_minute,
+            example_config.http_rate_limit.per_client_burst,
+            example_config.http_rate_limit.global_per_minute,
+            example_config.http_rate_limit.global_burst,
+            example_config.http_rate_limit.max_in_flight,
+            example_config.http_rate_limit.expensive_tool_cost,
+            example_config.http_rate_limit.trust_forwarded_for,
+            example_config.audit_log.max_file_bytes,
+            example_config.audit_log.max_files,
+            example_config.traces.capacity,
+            example_config.adapter_cache.backend.as_str(),
+            example_config.adapter_cache.max_entries,
+            example_config.adapter_cache.max_bytes,
+            example_config.health.max_block_lag_secs,
+            example_config.health.probe_timeout_ms,
+            example_config.transaction_queue.ttl_secs,
+            example_config.authorization.max_class.as_str(),
+            example_config.authorization.spend_denom,
+            example_config.budget.native_denom,
+            example_config.budget.window_secs,
+            example_config.retry.max_retries,
+            example_config.retry.base_delay_ms,
+            example_config.retry.max_delay_ms,
+            example_config.retry.jitter,
+            example_config.circuit_breaker.failure_threshold,
+            example_config.circuit_breaker.cooldown_secs,
+            example_config.dust_policy.action,
+            example_config.dust_policy.default_threshold,
+            example_config.dust_policy.native_denom
         )
     }
 
@@ -1798,11 +3501,116 @@ pub struct McpServerStateData {
     pub logger: Arc<McpLogger>,
     /// Transaction monitor manager
     pub transaction_monitor_manager: Arc<TransactionMonitorManager>,
+    /// In-flight write operation quotas
+    pub write_quotas: Arc<WriteQuotas>,
+    /// Per-session nonces for write tool replay protection
+    pub write_nonces: Arc<NonceTracker>,
+    /// Results of write calls by idempotency key
+    pub idempotency: Arc<IdempotencyStore>,
+    /// Write calls waiting for operator approval
+    pub approvals: Arc<ApprovalQueue>,
+    /// Proposed write calls waiting for `approve_transaction`
+    pub transaction_queue: Arc<TransactionQueue>,
+    /// Per-tool authorization policy
+    pub authorizer: Arc<ToolAuthorizer>,
+    /// Per-wallet spending budgets
+    pub budget: Arc<BudgetManager>,
+    /// Request limits of the HTTP transport
+    pub rate_limiter: Arc<HttpRateLimiter>,
+    /// Credential checks of the HTTP transport
+    pub authenticator: Arc<HttpAuthenticator>,
+    /// Record of state-changing tool calls
+    pub audit_log: Arc<AuditLog>,
+    /// Traces of the most recent tool calls
+    pub traces: Arc<TraceLog>,
+    /// Counters and histograms served on `/metrics`
+    pub metrics: Arc<McpMetrics>,
 }
 
 impl McpServerStateData {
     pub fn new(config: McpServerConfig) -> Self {
-        let sdk_adapter = Arc::new(McpSdkAdapter::default());
+        let metrics = Arc::new(McpMetrics::new());
+        let traces = Arc::new(TraceLog::new(&config.traces));
+        let mut sdk_adapter = McpSdkAdapter::default()
+            .with_metrics(metrics.clone())
+            .with_dust_policy(config.dust_policy.clone())
+            .with_skip_affiliate(config.skip_affiliate.clone())
+            .with_skip_chain_rpcs(config.skip_chain_rpcs.clone())
+            .with_claimdrop_factory(config.claimdrop_factory_address.clone())
+            .with_retry_policy(config.retry.clone())
+            .with_circuit_breaker(config.circuit_breaker.clone())
+            .with_tx_tagging(config.tx_tagging.clone())
+            .with_tx_timeout_blocks(config.tx_timeout_blocks)
+            .with_infinite_approval_policy(config.infinite_approval_policy);
+        if let Some(path) = &config.state_log_path {
+            match EventStore::open(path) {
+                Ok(store) => sdk_adapter = sdk_adapter.with_state_store(store),
+                Err(e) => warn!(
+                    "Failed to open state event log {}, keeping state in memory: {}",
+                    path.display(),
+                    e
+                ),
+            }
+        }
+        let tx_queue_path = config
+            .tx_queue_path
+            .clone()
+            .unwrap_or_else(|| TxQueue::default_path().with_file_name("mcp_tx_queue.json"));
+        match TxQueue::open(&tx_queue_path) {
+            Ok(queue) => sdk_adapter = sdk_adapter.with_tx_queue(queue),
+            Err(e) => warn!(
+                "Failed to open transaction queue {}, keeping it in memory: {}",
+                tx_queue_path.display(),
+                e
+            ),
+        }
+        let address_book = match &config.address_book_path {
+            Some(path) => AddressBook::open(path),
+            None => AddressBook::open_default(),
+        };
+        match address_book {
+            Ok(address_book) => sdk_adapter = sdk_adapter.with_address_book(address_book),
+            Err(e) => warn!("Failed to open address book, keeping it in memory: {}", e),
+        }
+        let restricted_wallets = match &config.restricted_wallets_path {
+            Some(path) => RestrictedWalletList::open(path),
+            None => RestrictedWalletList::open_default(),
+        };
+        match restricted_wallets {
+            Ok(list) => sdk_adapter = sdk_adapter.with_restricted_wallets(list),
+            Err(e) => warn!(
+                "Failed to open restricted wallet list, keeping it in memory: {}",
+                e
+            ),
+        }
+        if let Some(path) = &config.signing_log_path {
+            if let Err(e) = SigningLog::global().persist_to(path) {
+                warn!("Failed to open signing log, keeping it in memory: {}", e);
+            }
+        }
+        let log_cache = match &config.evm_log_cache_dir {
+            Some(dir) => LogCache::open(dir),
+            None => LogCache::default_dir().and_then(LogCache::open),
+        };
+        match log_cache {
+            Ok(cache) => sdk_adapter = sdk_adapter.with_log_cache(cache),
+            Err(e) => warn!("Failed to open EVM log cache, keeping it in memory: {}", e),
+        }
+        if let Some(path) = &config.erc20_artifact_path {
+            sdk_adapter = sdk_adapter.with_erc20_artifact_path(path.clone());
+        }
+        if let Some(sha256) = &config.erc20_artifact_sha256 {
+            sdk_adapter = sdk_adapter.with_erc20_artifact_sha256(sha256.clone());
+        }
+        let cache_ttl = sdk_adapter.cache_ttl;
+        let adapter_cache = match AdapterCache::open(&config.adapter_cache, cache_ttl) {
+            Ok(cache) => cache,
+            Err(e) => {
+                warn!("Failed to open adapter cache, keeping it in memory: {}", e);
+                AdapterCache::in_memory(&config.adapter_cache, cache_ttl)
+            }
+        };
+        let sdk_adapter = Arc::new(sdk_adapter.with_cache(adapter_cache));
 
         // Initialize logging infrastructure
         let logging_config = LoggingConfig::default();
@@ -1811,6 +3619,29 @@ impl McpServerStateData {
         // Initialize transaction monitor manager
         let transaction_monitor_manager = Arc::new(TransactionMonitorManager::new());
 
+        let write_quotas = Arc::new(WriteQuotas::new(WriteQuotaConfig {
+            max_in_flight_per_session: config.max_write_ops_per_session,
+            max_in_flight_global: config.max_write_ops_global,
+        }));
+        let write_nonces = Arc::new(NonceTracker::new(config.require_write_nonces));
+        let idempotency = Arc::new(IdempotencyStore::new());
+        let approvals = Arc::new(ApprovalQueue::new(
+            config.remote_control.require_approval,
+            Duration::from_secs(config.remote_control.approval_timeout_secs),
+        ));
+        let transaction_queue = Arc::new(TransactionQueue::new(config.transaction_queue.clone()));
+        let authorizer = Arc::new(ToolAuthorizer::new(config.authorization.clone()));
+        let budget = Arc::new(BudgetManager::new(config.budget.clone()));
+        let rate_limiter = Arc::new(HttpRateLimiter::new(config.http_rate_limit.clone()));
+        let authenticator = Arc::new(HttpAuthenticator::new(&config.http_auth));
+        let audit_log = Arc::new(match AuditLog::open(config.audit_log.clone()) {
+            Ok(audit_log) => audit_log,
+            Err(e) => {
+                warn!("Failed to open audit log, keeping it in memory: {}", e);
+                AuditLog::in_memory()
+            }
+        });
+
         Self {
             client: Arc::new(Mutex::new(None)),
             config,
@@ -1821,6 +3652,18 @@ impl McpServerStateData {
             client_wrapper: Arc::new(Mutex::new(None)),
             logger,
             transaction_monitor_manager,
+            write_quotas,
+            write_nonces,
+            idempotency,
+            approvals,
+            transaction_queue,
+            authorizer,
+            budget,
+            rate_limiter,
+            authenticator,
+            audit_log,
+            traces,
+            metrics,
         }
     }
 
@@ -1888,7 +3731,9 @@ impl McpServerStateData {
 
     /// Get cached value
     pub async fn cache_get(&self, key: &str) -> Option<Value> {
-        self.cache.read().await.get(key).cloned()
+        let value = self.cache.read().await.get(key).cloned();
+        self.metrics.record_cache_lookup("server", value.is_some());
+        value
     }
 
     /// Clear cache
@@ -1896,33 +3741,95 @@ impl McpServerStateData {
         self.cache.write().await.clear();
     }
 
-    /// Switch to a different network configuration
-    pub async fn switch_network(&self, network_name: &str) -> McpResult<()> {
-        use crate::config::{MantraNetworkConfig, NetworkConstants};
+    /// Network profiles the server can switch to: the built-in ones, replaced
+    /// or extended by the configured ones
+    pub fn network_profiles(&self) -> BTreeMap<String, NetworkProfile> {
+        let mut profiles = NetworkProfile::builtin();
+        profiles.extend(self.config.network_profiles.clone());
+        profiles
+    }
 
-        // Load network constants for the requested network
-        let constants = NetworkConstants::load(network_name).map_err(|e| {
-            McpServerError::Network(format!("Failed to load network '{}': {}", network_name, e))
-        })?;
+    /// Network the server is on: the profile switched to, or the configured network
+    pub async fn network_config(&self) -> MantraNetworkConfig {
+        match self.sdk_adapter.network_profile.read().await.as_ref() {
+            Some((_, network_config)) => network_config.clone(),
+            None => self.config.network_config.clone(),
+        }
+    }
 
-        // Create new network config
-        let _new_network_config = MantraNetworkConfig::from_constants(&constants).map_err(|e| {
-            McpServerError::Network(format!("Failed to create network config: {}", e))
-        })?;
+    /// Switch the server to network profile `profile` without restarting
+    ///
+    /// A name that is not a profile is looked up as a network in
+    /// `config/network.toml`. The DEX client, the adapter's connection pools and
+    /// both caches are replaced while the client is locked; when the new network
+    /// cannot be reached the server stays where it was.
+    pub async fn switch_network(&self, profile: &str) -> McpResult<SwitchNetworkResponse> {
+        let profiles = self.network_profiles();
+        let available_profiles: Vec<String> = profiles.keys().cloned().collect();
+        let network_config = profiles
+            .get(profile)
+            .cloned()
+            .unwrap_or_else(|| NetworkProfile {
+                network: Some(profile.to_string()),
+                ..Default::default()
+            })
+            .resolve(profile)
+            .map_err(|e| {
+                McpServerError::InvalidArguments(format!(
+                    "{} (available profiles: {})",
+                    e,
+                    available_profiles.join(", ")
+                ))
+            })?;
 
-        // Clear existing client and cache
-        {
-            let mut client = self.client.lock().await;
-            *client = None;
+        let client = MantraDexClient::new(network_config.clone())
+            .await
+            .map_err(McpServerError::Sdk)?;
+
+        let mut client_guard = self.client.lock().await;
+        let mut wrapper_guard = self.client_wrapper.lock().await;
+        let (previous_profile, adapter_entries) = self
+            .sdk_adapter
+            .switch_network_profile(profile, network_config.clone())
+            .await?;
+        *client_guard = Some(client);
+        if wrapper_guard.is_some() {
+            *wrapper_guard = Some(McpClientWrapper::new(
+                self.sdk_adapter.clone(),
+                network_config.clone(),
+            ));
         }
-        self.cache_clear().await;
+        let server_entries = {
+            let mut cache = self.cache.write().await;
+            let entries = cache.len();
+            cache.clear();
+            entries
+        };
+        drop(wrapper_guard);
+        drop(client_guard);
 
-        // Update the network configuration in the server config
-        // Note: This is a bit tricky since config is not mutable. We need to create a new config.
-        // For now, we'll store the network config separately or update it through the client initialization
+        if self.config.prefetch {
+            self.sdk_adapter
+                .start_prefetch(Duration::from_secs(self.config.prefetch_interval_secs))
+                .await;
+        }
 
-        info!("Successfully switched to network: {}", network_name);
-        Ok(())
+        info!(
+            "Switched to network profile {} ({})",
+            profile, network_config.chain_id
+        );
+        Ok(SwitchNetworkResponse {
+            profile: profile.to_string(),
+            previous_profile,
+            network: network_config.network_name,
+            chain_id: network_config.chain_id,
+            rpc_url: network_config.rpc_url,
+            evm_rpc_url: network_config.evm_rpc_url,
+            evm_chain_id: network_config.evm_chain_id,
+            pool_manager: network_config.contracts.pool_manager,
+            cache_entries_cleared: adapter_entries + server_entries,
+            available_profiles,
+        })
     }
 
     /// Initialize client with specific network configuration
@@ -1956,6 +3863,8 @@ impl McpServerStateData {
 pub struct MantraDexMcpServer {
     /// Server state
     state: Arc<McpServerStateData>,
+    /// Input schemas by tool name, taken from the tool list on first use
+    tool_schemas: OnceLock<HashMap<String, Value>>,
 }
 
 impl MantraDexMcpServer {
@@ -1963,6 +3872,7 @@ impl MantraDexMcpServer {
     pub fn new(config: McpServerConfig) -> Self {
         Self {
             state: Arc::new(McpServerStateData::new(config)),
+            tool_schemas: OnceLock::new(),
         }
     }
 
@@ -1970,6 +3880,9 @@ impl MantraDexMcpServer {
     pub async fn initialize(&self) -> McpResult<()> {
         info!("Initializing Mantra DEX MCP Server");
         self.state.initialize_client().await?;
+        if let Some(profile) = &self.state.config.network_profile {
+            self.state.switch_network(profile).await?;
+        }
 
         // Auto-load wallet from environment if available
         self.auto_load_wallet_from_env().await?;
@@ -1977,10 +3890,33 @@ impl MantraDexMcpServer {
         // Initialize client wrapper
         let wrapper = McpClientWrapper::new(
             self.state.sdk_adapter.clone(),
-            self.state.config.network_config.clone(),
+            self.state.network_config().await,
         );
         *self.state.client_wrapper.lock().await = Some(wrapper);
 
+        self.state
+            .sdk_adapter
+            .start_tx_queue_monitor(TxQueuePolicy::default())
+            .await;
+
+        if self.state.config.prefetch {
+            self.state
+                .sdk_adapter
+                .start_prefetch(Duration::from_secs(
+                    self.state.config.prefetch_interval_secs,
+                ))
+                .await;
+        }
+
+        if self.state.config.erc20_refresh_interval_secs > 0 {
+            self.state
+                .sdk_adapter
+                .start_erc20_refresh(Duration::from_secs(
+                    self.state.config.erc20_refresh_interval_secs,
+                ))
+                .await;
+        }
+
         info!("Server initialization complete");
         Ok(())
     }
@@ -2073,6 +4009,9 @@ impl MantraDexMcpServer {
                 "list_changed": true,
                 "subscribe": false
             },
+            "prompts": {
+                "list_changed": false
+            },
             "logging": {},
             "experimental": {}
         })
@@ -2099,6 +4038,9 @@ impl McpServerLifecycle for MantraDexMcpServer {
 
     async fn shutdown(&self) -> McpResult<()> {
         info!("Shutting down Mantra DEX MCP Server");
+        self.state.sdk_adapter.stop_prefetch().await;
+        self.state.sdk_adapter.stop_erc20_refresh().await;
+        self.state.sdk_adapter.stop_tx_queue_monitor().await;
         Ok(())
     }
 }
@@ -2106,7 +4048,7 @@ impl McpServerLifecycle for MantraDexMcpServer {
 #[async_trait::async_trait]
 impl McpResourceProvider for MantraDexMcpServer {
     fn get_available_resources(&self) -> Vec<serde_json::Value> {
-        vec![
+        let mut resources = vec![
             serde_json::json!({
                 "uri": "trades://history",
                 "name": "Trading History",
@@ -2125,10 +4067,26 @@ impl McpResourceProvider for MantraDexMcpServer {
                 "description": "Current and historical liquidity positions",
                 "mimeType": "application/json"
             }),
-        ]
+        ];
+        resources.extend(data_resources::resource_descriptors());
+        resources
+    }
+
+    fn get_resource_templates(&self) -> Vec<serde_json::Value> {
+        data_resources::resource_templates()
     }
 
     async fn handle_resource_read(&self, uri: &str) -> McpResult<serde_json::Value> {
+        if let Some(parsed) = DataResource::parse(uri) {
+            let (resource, page) = parsed.map_err(McpServerError::Validation)?;
+            let body = self
+                .state
+                .sdk_adapter
+                .read_data_resource(&resource, page)
+                .await?;
+            return Ok(data_resources::render_contents(uri, &body));
+        }
+
         match uri {
             "trades://history" => Ok(serde_json::json!({
                 "trades": [],
@@ -2150,10 +4108,13 @@ impl McpResourceProvider for MantraDexMcpServer {
     }
 
     fn validate_resource_uri(&self, uri: &str) -> McpResult<()> {
+        if let Some(parsed) = DataResource::parse(uri) {
+            return parsed.map(|_| ()).map_err(McpServerError::Validation);
+        }
         match uri {
             "trades://history" | "trades://pending" | "liquidity://positions" => Ok(()),
             _ => Err(McpServerError::Validation(format!(
-                "Invalid resource URI: {}. Available resources: trades://history, trades://pending, liquidity://positions",
+                "Invalid resource URI: {}. Available resources: trades://history, trades://pending, liquidity://positions, mcp://pools, mcp://tokens/{{chain_id}}, mcp://campaigns, mcp://wallet/active",
                 uri
             ))),
         }
@@ -2250,14 +4211,16 @@ impl McpResourceProvider for MantraDexMcpServer {
 #[async_trait::async_trait]
 impl McpServerStateManager for MantraDexMcpServer {
     async fn get_config(&self) -> serde_json::Value {
+        let network_config = self.state.network_config().await;
         serde_json::json!({
             "server_name": self.state.config.name,
             "server_version": self.state.config.version,
             "network": {
-                "chain_id": self.state.config.network_config.chain_id,
-                "rpc_endpoint": self.state.config.network_config.rpc_url,
-                "lcd_endpoint": self.state.config.network_config.rpc_url,
-                "grpc_endpoint": self.state.config.network_config.rpc_url
+                "profile": self.state.sdk_adapter.active_network_profile().await,
+                "chain_id": network_config.chain_id,
+                "rpc_endpoint": network_config.rpc_url,
+                "lcd_endpoint": network_config.rpc_url,
+                "grpc_endpoint": network_config.rpc_url
             },
             "settings": {
                 "debug": self.state.config.debug,
@@ -2333,7 +4296,7 @@ impl McpServerStateManager for MantraDexMcpServer {
             "components": {
                 "client": {
                     "status": client_status,
-                    "network": self.state.config.network_config.chain_id
+                    "network": self.state.network_config().await.chain_id
                 },
                 "wallet": {
                     "active": active_wallet,
@@ -2344,7 +4307,25 @@ impl McpServerStateManager for MantraDexMcpServer {
                 },
                 "transaction_monitoring": {
                     "active_monitors": active_monitors
-                }
+                },
+                "write_quotas": self.state.write_quotas.snapshot().await,
+                "write_nonces": self.state.write_nonces.snapshot().await,
+                "idempotency": self.state.idempotency.snapshot(),
+                "approvals": self.state.approvals.snapshot(),
+                "transaction_queue": self.state.transaction_queue.snapshot(),
+                "authorization": self.state.authorizer.snapshot(),
+                "budget": self.state.budget.config(),
+                "retry": self.state.sdk_adapter.retry_policy,
+                "circuit_breaker": self.state.sdk_adapter.config.circuit_breaker,
+                "http_rate_limit": self.state.rate_limiter.snapshot(),
+                "audit_log": self.state.audit_log.snapshot(),
+                "signing_log": SigningLog::global().snapshot(),
+                "evm_log_cache": self.state.sdk_adapter.log_cache.snapshot(),
+                "traces": {
+                    "capacity": self.state.traces.capacity(),
+                    "kept": self.state.traces.len()
+                },
+                "adapter_cache": self.state.sdk_adapter.cache.stats()
             }
         })
     }
@@ -2353,7 +4334,7 @@ impl McpServerStateManager for MantraDexMcpServer {
 #[async_trait::async_trait]
 impl McpToolProvider for MantraDexMcpServer {
     fn get_available_tools(&self) -> Vec<serde_json::Value> {
-        vec![
+        let tools = vec![
             // Network Tools
             serde_json::json!({
                 "name": "network_get_contract_addresses",
@@ -2369,6 +4350,53 @@ impl McpToolProvider for MantraDexMcpServer {
                     }
                 }
             }),
+            serde_json::json!({
+                "name": "get_server_health",
+                "description": "Report Cosmos and EVM RPC reachability, chain heights and their lag behind the wall clock, wallet availability and connection pool health, with the overall status and readiness",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "network_node_status",
+                "description": "Report the connected node's sync state, latest block and its age, and mempool size, with the likely reasons a transaction is stuck: a syncing node, stalled blocks, a busy mempool or a gas price below the node's minimum",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "network_get_block",
+                "description": "Get a block by height (latest by default) with its proposer and transaction hashes",
+                "inputSchema": input_schema::<BlockArgs>()
+            }),
+            serde_json::json!({
+                "name": "network_get_validators",
+                "description": "Get the validator set at a height (latest by default) by voting power, with how few validators could halt the chain",
+                "inputSchema": input_schema::<ValidatorSetArgs>()
+            }),
+            serde_json::json!({
+                "name": "network_get_chain_params",
+                "description": "Get the block gas and size limits, the gas price the client pays and the minimum gas price the node accepts",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "network_get_mempool",
+                "description": "Count the unconfirmed transactions in the connected node's mempool",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "network_performance",
+                "description": "Summarize recent transaction confirmation times, SLA compliance, RPC endpoint quality scores and the circuit breaker state of each Cosmos and EVM RPC endpoint used for failover",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "network": {
+                            "type": "string",
+                            "description": "Chain ID to summarize (optional, defaults to all tracked networks)"
+                        },
+                        "window_secs": {
+                            "type": "integer",
+                            "description": "Only include confirmations from the last N seconds (optional)"
+                        }
+                    }
+                }
+            }),
             serde_json::json!({
                 "name": "network_validate_connectivity",
                 "description": "Validate network connectivity and blockchain access",
@@ -2405,209 +4433,364 @@ impl McpToolProvider for MantraDexMcpServer {
                     }
                 }
             }),
+            serde_json::json!({
+                "name": "resolve_asset",
+                "description": "Resolve a symbol, denom, IBC path or ERC-20 address to the matching assets across Cosmos and EVM: native, IBC (with trace), factory and ERC-20 representations with their decimals",
+                "inputSchema": input_schema::<ResolveAssetArgs>()
+            }),
             // Wallet and Balance Tools
             serde_json::json!({
                 "name": "wallet_get_balances",
                 "description": "Get wallet balances for all assets",
-                "inputSchema": {
-                    "type": "object",
-                    "properties": {
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Wallet address to query balances for (optional, uses active wallet if not provided)"
-                        },
-                        "include_zero_balances": {
-                            "type": "boolean",
-                            "description": "Whether to include assets with zero balance (default: false)",
-                            "default": false
-                        }
-                    }
-                }
+                "inputSchema": input_schema::<GetBalancesArgs>()
+            }),
+            serde_json::json!({
+                "name": "faucet_request",
+                "description": "Fund a testnet wallet from the faucet and wait for its balance to rise. Each address may ask once per cooldown; a request made too soon is refused with the time it may ask again",
+                "inputSchema": input_schema::<FaucetRequestArgs>()
             }),
             serde_json::json!({
                 "name": "wallet_list",
                 "description": "List all available wallets with their addresses and information",
-                "inputSchema": {
-                    "type": "object",
-                    "properties": {}
-                }
+                "inputSchema": input_schema::<FormatArgs>()
             }),
             serde_json::json!({
                 "name": "wallet_switch",
                 "description": "Switch to a different active wallet",
-                "inputSchema": {
-                    "type": "object",
-                    "properties": {
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "The wallet address to switch to"
-                        }
-                    },
-                    "required": ["wallet_address"]
-                }
+                "inputSchema": input_schema::<SwitchWalletArgs>()
             }),
             serde_json::json!({
                 "name": "wallet_get_active",
                 "description": "Get current active wallet information",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "session_get_context",
+                "description": "Shows the wallet context of the calling MCP session: active wallet, network and custom ERC-20 tokens, and whether each was picked by the session or comes from the server. Sessions are identified by the Mcp-Session-Id header or _meta.session_id.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {}
                 }
             }),
             serde_json::json!({
-                "name": "wallet_add_from_mnemonic",
-                "description": "Add a new wallet from mnemonic phrase",
+                "name": "switch_network",
+                "description": "Switches the whole server to a named network profile (mainnet, dukong, local or a configured one) without restarting: RPC and EVM endpoints, contract addresses and gas settings change together, connection pools are rebuilt and caches cleared. Sessions that picked their own network keep it.",
+                "inputSchema": input_schema::<SwitchNetworkArgs>()
+            }),
+            serde_json::json!({
+                "name": "get_multi_network_portfolio",
+                "description": "Shows a wallet's balances, and optionally pools, on several network profiles side by side (mainnet and dukong by default). Networks are queried concurrently, each with its own timeout; networks that fail or time out are reported without hiding the others' results.",
+                "inputSchema": input_schema::<GetMultiNetworkPortfolioArgs>()
+            }),
+            serde_json::json!({
+                "name": "session_set_network",
+                "description": "Picks the network of the calling MCP session without affecting other sessions. Omit 'network' to go back to the server's network.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "mnemonic": {
+                        "network": {
                             "type": "string",
-                            "description": "The mnemonic phrase to import the wallet from"
-                        },
-                        "derivation_index": {
-                            "type": "integer",
-                            "description": "The derivation index for the wallet (default: 0)",
-                            "default": 0,
-                            "minimum": 0
+                            "description": "Network name, e.g. mantra-dukong (optional)"
                         }
-                    },
-                    "required": ["mnemonic"]
+                    }
                 }
             }),
+            serde_json::json!({
+                "name": "address_book_add",
+                "description": "Label a Cosmos or EVM address, e.g. 'treasury'. Labels are accepted in place of the address in any tool argument that takes one, and tool results list the labels of the addresses they mention under _meta.address_labels. Adding an existing label replaces its address.",
+                "inputSchema": input_schema::<AddressBookAddArgs>()
+            }),
+            serde_json::json!({
+                "name": "address_book_remove",
+                "description": "Forget an address book label",
+                "inputSchema": input_schema::<AddressBookRemoveArgs>()
+            }),
+            serde_json::json!({
+                "name": "address_book_list",
+                "description": "List the labelled addresses of the address book shared with the TUI",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "address_convert",
+                "description": "Validate a Cosmos or EVM address and convert it between its bech32 (mantra1...) and EIP-55 checksummed hex (0x...) forms, which name the same account. Flags an address that is valid but for the other VM than the operation needs, and reports both addresses of a loaded wallet it belongs to",
+                "inputSchema": input_schema::<AddressConvertArgs>()
+            }),
+            serde_json::json!({
+                "name": "restricted_wallets_add",
+                "description": "Restrict a wallet: PrimarySale settlement refunds its contribution to the multisig instead of distributing tokens to it. Listed wallets are passed to every settlement batch they fall in automatically",
+                "inputSchema": input_schema::<RestrictedWalletsAddArgs>()
+            }),
+            serde_json::json!({
+                "name": "restricted_wallets_remove",
+                "description": "Lift a wallet's restriction",
+                "inputSchema": input_schema::<RestrictedWalletsRemoveArgs>()
+            }),
+            serde_json::json!({
+                "name": "restricted_wallets_import",
+                "description": "Restrict every address of a CSV sanctions feed, one 'address[,reason]' per line. Invalid lines are reported and skipped",
+                "inputSchema": input_schema::<RestrictedWalletsImportArgs>()
+            }),
+            serde_json::json!({
+                "name": "restricted_wallets_list",
+                "description": "List the restricted wallets PrimarySale settlement refunds instead of distributing to",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
+            serde_json::json!({
+                "name": "get_spending_summary",
+                "description": "Shows what each wallet spent through write tools in its rolling budget window, in native base units and US dollars, with the limits and what is left. Calls that would exceed a wallet's budget are refused.",
+                "inputSchema": input_schema::<GetSpendingSummaryArgs>()
+            }),
+            serde_json::json!({
+                "name": "analyze_wallet_activity",
+                "description": "Tells a wallet's recent activity as a narrative across both VMs: Cosmos transactions (pool manager swaps and liquidity, farm positions, ClaimDrop claims and campaign administration, Skip swaps and transfers, bank sends) are found by sender or given by hash, and EVM transactions given by hash are decoded alongside.",
+                "inputSchema": input_schema::<AnalyzeWalletActivityArgs>()
+            }),
+            serde_json::json!({
+                "name": "wallet_add_from_mnemonic",
+                "description": "Add a new wallet from mnemonic phrase",
+                "inputSchema": input_schema::<AddWalletArgs>()
+            }),
+            serde_json::json!({
+                "name": "wallet_discover",
+                "description": "Finds the derivation indices of a mnemonic that hold funds or have signed transactions, for onboarding a restored wallet. Indices are scanned from 0 with their Cosmos balances and sequence and EVM balance and nonce looked up concurrently, stopping after 'gap_limit' consecutive unused ones. Set 'import' to add the discovered wallets to the collection.",
+                "inputSchema": input_schema::<DiscoverWalletsArgs>()
+            }),
             serde_json::json!({
                 "name": "wallet_remove",
                 "description": "Remove a wallet from the collection",
+                "inputSchema": input_schema::<RemoveWalletArgs>()
+            }),
+            serde_json::json!({
+                "name": "state_audit_log",
+                "description": "Lists the recorded changes to wallet and custom token state and the state they replay to. Use 'as_of' to see the state right after a given event, and check 'consistent' to confirm the live state matches the log.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "The wallet address to remove"
-                        }
-                    },
-                    "required": ["wallet_address"]
+                        "since": { "type": "integer", "description": "Only list events after this sequence number (optional, defaults to 0)." },
+                        "limit": { "type": "integer", "description": "Maximum number of events to list (optional, defaults to 100)." },
+                        "as_of": { "type": "integer", "description": "Replay the state up to and including this sequence number (optional, defaults to the latest event)." }
+                    }
                 }
             }),
             serde_json::json!({
-                "name": "wallet_get_evm_address",
-                "description": "Get the EVM address for a wallet",
+                "name": "get_audit_log",
+                "description": "Lists audited calls of state-changing tools, newest first: tool, argument digest, session, caller, wallet, transaction hash, outcome and duration. Refused and failed calls are included.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Wallet address to get EVM address for (optional, uses active wallet if not provided)"
-                        }
+                        "tool": { "type": "string", "description": "Only list calls of this tool (optional)." },
+                        "wallet": { "type": "string", "description": "Only list calls made with this wallet address (optional)." },
+                        "session_id": { "type": "string", "description": "Only list calls from this MCP session (optional)." },
+                        "principal": { "type": "string", "description": "Only list calls from this authenticated HTTP client (optional)." },
+                        "outcome": { "type": "string", "enum": ["success", "failure"], "description": "Only list calls with this outcome (optional)." },
+                        "since_sequence": { "type": "integer", "description": "Only list records after this sequence number (optional)." },
+                        "since": { "type": "string", "description": "Only list records at or after this RFC 3339 time (optional)." },
+                        "limit": { "type": "integer", "description": "Maximum number of records to list (optional, defaults to 100)." }
                     }
                 }
             }),
-            // EVM Balance Tools
-            #[cfg(feature = "evm")]
             serde_json::json!({
-                "name": "wallet_get_native_evm_balance",
-                "description": "Get native token (OM) balance on EVM",
+                "name": "get_signing_history",
+                "description": "Lists the transactions this server signed, newest first, each with the intent recorded before signing (decoded messages, contracts, recipients and amounts) and the resulting nonce, signature, transaction hash or error.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Optional wallet address (uses active if not provided)"
-                        }
+                        "signer": { "type": "string", "description": "Only list transactions signed by this address (optional)." },
+                        "vm": { "type": "string", "enum": ["cosmos", "evm"], "description": "Only list transactions of this VM (optional)." },
+                        "chain_id": { "type": "string", "description": "Only list transactions for this chain ID (optional)." },
+                        "status": { "type": "string", "enum": ["pending", "broadcast", "failed"], "description": "Only list transactions with this status (optional)." },
+                        "since_id": { "type": "integer", "description": "Only list records after this id (optional)." },
+                        "limit": { "type": "integer", "description": "Maximum number of records to list (optional, defaults to 100)." }
                     }
                 }
             }),
-            #[cfg(feature = "evm")]
             serde_json::json!({
-                "name": "wallet_get_erc20_balance",
-                "description": "Get ERC-20 token balance",
+                "name": "watch_chain_events",
+                "description": "Watches swaps, liquidity changes and claims of the network's contracts over the node's Tendermint WebSocket as they are committed, for up to duration_secs or until max_events have arrived. Each event is sent as a progress notification when the call carries a progressToken; the result lists them all.",
+                "inputSchema": input_schema::<WatchChainEventsArgs>()
+            }),
+            serde_json::json!({
+                "name": "get_recent_traces",
+                "description": "Lists traces of recent tool calls, newest first: the call's correlation ID, tool, session, outcome and duration, and the RPC requests and retries it made in order. Every tool result carries its correlation ID in _meta.correlation_id; clients may choose it by passing _meta.correlationId.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "token_address": {
-                            "type": "string",
-                            "description": "ERC-20 contract address"
-                        },
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Optional wallet address"
-                        }
-                    },
-                    "required": ["token_address"]
+                        "correlation_id": { "type": "string", "description": "Only list the call with this correlation ID (optional)." },
+                        "tool": { "type": "string", "description": "Only list calls of this tool (optional)." },
+                        "session_id": { "type": "string", "description": "Only list calls from this MCP session (optional)." },
+                        "failed_only": { "type": "boolean", "description": "Only list calls that failed (optional, defaults to false)." },
+                        "limit": { "type": "integer", "description": "Maximum number of traces to list (optional, defaults to 20)." }
+                    }
                 }
             }),
-            #[cfg(feature = "evm")]
             serde_json::json!({
-                "name": "wallet_get_all_evm_balances",
-                "description": "Get all EVM balances (native + ERC-20 tokens)",
+                "name": "list_queued_transactions",
+                "description": "Lists signed transactions persisted before broadcast, newest first, with their status (queued, broadcast, confirmed, failed or stuck), broadcast attempts and last error. Unconfirmed ones are resent and confirmed in the background, including after a restart. EVM confirmations whose block a reorg replaced go back to pending and are listed under reorgs.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Optional wallet address"
-                        },
-                        "token_addresses": {
-                            "type": "array",
-                            "items": { "type": "string" },
-                            "description": "Optional list of ERC-20 tokens to query"
-                        }
+                        "unfinished_only": { "type": "boolean", "description": "Only list transactions whose outcome is not known yet (optional, defaults to false)." }
                     }
                 }
             }),
-            #[cfg(feature = "evm")]
             serde_json::json!({
-                "name": "wallet_transfer_erc20",
-                "description": "Transfer ERC-20 tokens to another address",
+                "name": "list_pending_transactions",
+                "description": "Lists proposed transactions waiting for approval, oldest first, with the tool, its arguments and a summary of the simulated transaction.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {}
+                }
+            }),
+            serde_json::json!({
+                "name": "approve_transaction",
+                "description": "Approves a proposed transaction and broadcasts it. Returns the result of the original tool.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "token_address": {
-                            "type": "string",
-                            "description": "ERC-20 contract address"
-                        },
-                        "recipient": {
-                            "type": "string",
-                            "description": "Recipient EVM address (0x...)"
-                        },
-                        "amount": {
-                            "type": "string",
-                            "description": "Amount to transfer (will be converted using token decimals)"
-                        },
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Optional wallet address (uses active if not provided)"
-                        }
+                        "id": { "type": "string", "description": "Id of the proposed transaction, from list_pending_transactions" }
+                    },
+                    "required": ["id"]
+                }
+            }),
+            serde_json::json!({
+                "name": "reject_transaction",
+                "description": "Rejects a proposed transaction so it is never broadcast.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "string", "description": "Id of the proposed transaction, from list_pending_transactions" },
+                        "reason": { "type": "string", "description": "Why the transaction was rejected (optional, recorded in the audit log)" }
                     },
-                    "required": ["token_address", "recipient", "amount"]
+                    "required": ["id"]
                 }
             }),
+            serde_json::json!({
+                "name": "wallet_get_evm_address",
+                "description": "Get the EVM address for a wallet",
+                "inputSchema": input_schema::<WalletArgs>()
+            }),
+            // EVM Balance Tools
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "wallet_get_native_evm_balance",
+                "description": "Get native token (OM) balance on EVM",
+                "inputSchema": input_schema::<WalletArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "wallet_get_erc20_balance",
+                "description": "Get ERC-20 token balance",
+                "inputSchema": input_schema::<Erc20BalanceArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "wallet_get_all_evm_balances",
+                "description": "Get all EVM balances (native + ERC-20 tokens)",
+                "inputSchema": input_schema::<EvmBalancesArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "wallet_transfer_erc20",
+                "description": "Transfer ERC-20 tokens to another address",
+                "inputSchema": input_schema::<Erc20TransferArgs>()
+            }),
             #[cfg(feature = "evm")]
             serde_json::json!({
                 "name": "wallet_approve_erc20",
-                "description": "Approve ERC-20 token spending for another address or contract",
+                "description": "Approve ERC-20 token spending for another address or contract. An amount of 'unlimited' approves 2^256-1; the server's infinite approval policy may warn about or refuse such approvals",
+                "inputSchema": input_schema::<Erc20ApproveArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "erc20_get_allowances",
+                "description": "List the spenders the wallet has approved and what each may still spend, found from the wallet's Approval events and read from each token. Unlimited allowances are flagged",
+                "inputSchema": input_schema::<Erc20AllowancesArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "erc20_revoke_approval",
+                "description": "Set the wallet's ERC-20 allowance of a spender back to zero; nothing is sent when it already is zero",
+                "inputSchema": input_schema::<Erc20RevokeArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_deploy_erc20",
+                "description": "Deploy a standard mintable and burnable ERC-20 for testnet scenarios from the audited artifact, wait for it to be mined and register it in the ERC-20 registry. The initial supply is minted to the owner, who alone can mint more",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "token_address": {
+                        "name": {
                             "type": "string",
-                            "description": "ERC-20 contract address"
+                            "description": "Token name"
                         },
-                        "spender": {
+                        "symbol": {
                             "type": "string",
-                            "description": "Spender EVM address (0x...) - contract or address authorized to spend"
+                            "description": "Token symbol"
                         },
-                        "amount": {
+                        "decimals": {
+                            "type": "integer",
+                            "description": "Token decimals (default: 18)",
+                            "minimum": 0,
+                            "maximum": 36
+                        },
+                        "initial_supply": {
                             "type": "string",
-                            "description": "Amount to approve (will be converted using token decimals)"
+                            "description": "Supply minted on deployment, in whole tokens (default: 0)"
+                        },
+                        "owner": {
+                            "type": "string",
+                            "description": "Account receiving the initial supply and allowed to mint (0x..., default: the deploying wallet)"
+                        },
+                        "confirmation_timeout_secs": {
+                            "type": "integer",
+                            "description": "Seconds to wait for the deployment to be mined (default: 120)",
+                            "minimum": 1
                         },
                         "wallet_address": {
                             "type": "string",
-                            "description": "Optional wallet address (uses active if not provided)"
+                            "description": "Wallet address to use (optional, uses active if not provided)"
                         }
                     },
-                    "required": ["token_address", "spender", "amount"]
+                    "required": ["name", "symbol"]
                 }
             }),
+            // EVM introspection tools
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_get_block",
+                "description": "Get an EVM block by number, hash or tag (latest by default) with its transaction hashes, or with each transaction's sender, recipient, value and decoded function",
+                "inputSchema": input_schema::<EvmGetBlockArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_get_transaction",
+                "description": "Get an EVM transaction by hash with its status, gas used and decoded calldata",
+                "inputSchema": input_schema::<EvmGetTransactionArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_get_code",
+                "description": "Get the bytecode deployed at an EVM address, telling contracts from externally owned accounts and naming the implementation behind EIP-1967 proxies",
+                "inputSchema": input_schema::<EvmGetCodeArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_inspect_contract",
+                "description": "Tell what an EVM address is: the token and role interfaces it implements (ERC-20, ERC-721, ERC-1155, AccessControl, Pausable) from ERC-165 and its bytecode, the implementation behind a proxy, and its token metadata",
+                "inputSchema": input_schema::<EvmInspectContractArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_get_storage_at",
+                "description": "Read one 32-byte storage slot of an EVM contract",
+                "inputSchema": input_schema::<EvmGetStorageAtArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_chain_info",
+                "description": "Get the EVM node's chain ID (checked against the configured one), latest block and its age, base fee and sync status",
+                "inputSchema": input_schema::<FormatArgs>()
+            }),
             // Pool Query Tools
             serde_json::json!({
                 "name": "dex_get_pools",
@@ -2628,9 +4811,57 @@ impl McpToolProvider for MantraDexMcpServer {
                     }
                 }
             }),
+            serde_json::json!({
+                "name": "estimate_transaction_cost",
+                "description": "Previews what an operation would cost before anything is signed: the gas, the network fee in the native token, pool fees for swaps, and their US dollar value priced through the DEX pools. Cosmos operations (swap, claim) are simulated with the wallet and pay for the full gas limit; EVM operations (erc20_transfer, invest) use eth_estimateGas and the maximum EIP-1559 fee, an upper bound in 18-decimal native units.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "operation": {
+                            "type": "string",
+                            "enum": ["swap", "claim", "erc20_transfer", "invest"],
+                            "description": "Operation to price."
+                        },
+                        "pool_id": { "type": "string", "description": "Pool to swap in (swap)." },
+                        "offer": {
+                            "type": "object",
+                            "properties": {
+                                "denom": { "type": "string" },
+                                "amount": { "type": "string", "description": "Base units, or a human-readable amount such as '1.5 OM'." }
+                            },
+                            "required": ["denom", "amount"],
+                            "description": "Asset to swap (swap)."
+                        },
+                        "ask_denom": { "type": "string", "description": "Denom to receive (swap)." },
+                        "campaign": { "type": "string", "description": "ClaimDrop campaign address (claim)." },
+                        "token": { "type": "string", "description": "ERC-20 token address (erc20_transfer), or the payment token (invest)." },
+                        "to": { "type": "string", "description": "Recipient EVM address (erc20_transfer)." },
+                        "sale": { "type": "string", "description": "PrimarySale contract address (invest)." },
+                        "amount": { "type": "string", "description": "Token amount in base units (erc20_transfer, invest)." },
+                        "wallet_address": { "type": "string", "description": "Wallet to simulate as (optional, uses the active wallet if not provided)." }
+                    },
+                    "required": ["operation"]
+                }
+            }),
+            serde_json::json!({
+                "name": "dex_get_pool_depth",
+                "description": "Reports how much can be swapped in each direction of a pool at several price impact levels (0.5%, 1% and 2% by default) and whether a stable pool's reserves are heavily imbalanced.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": { "type": "string", "description": "The ID of the pool to analyze." },
+                        "impact_levels": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Price impact levels as decimals, e.g. ['0.005', '0.01'] (optional)."
+                        }
+                    },
+                    "required": ["pool_id"]
+                }
+            }),
             serde_json::json!({
                 "name": "dex_execute_swap",
-                "description": "Executes a token swap in a specified pool with slippage protection.",
+                "description": "Executes a token swap in a specified pool with slippage protection. The response includes the estimated price impact and warnings for large swaps.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
@@ -2639,7 +4870,7 @@ impl McpToolProvider for MantraDexMcpServer {
                             "type": "object",
                             "properties": {
                                 "denom": { "type": "string" },
-                                "amount": { "type": "string" }
+                                "amount": { "type": "string", "description": "Base units, or a human-readable amount such as '1.5' or '1.5 OM'." }
                             },
                             "required": ["denom", "amount"]
                         },
@@ -2663,7 +4894,7 @@ impl McpToolProvider for MantraDexMcpServer {
                                 "type": "object",
                                 "properties": {
                                     "denom": { "type": "string" },
-                                    "amount": { "type": "string" }
+                                    "amount": { "type": "string", "description": "Base units, or a human-readable amount such as '1.5' or '1.5 OM'." }
                                 },
                                 "required": ["denom", "amount"]
                             },
@@ -2672,20 +4903,78 @@ impl McpToolProvider for MantraDexMcpServer {
                         "max_slippage": { "type": "string", "description": "Maximum allowed slippage percentage. Defaults to 1%." },
                         "wallet_address": { "type": "string", "description": "Wallet address to use for providing liquidity (optional, uses active wallet if not provided)" }
                     },
-                    "required": ["pool_id", "assets"]
+                    "required": ["pool_id", "assets"]
+                }
+            }),
+            serde_json::json!({
+                "name": "dex_withdraw_liquidity",
+                "description": "Withdraws liquidity from a specified pool.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": { "type": "string", "description": "The ID of the pool to withdraw from." },
+                        "amount": { "type": "string", "description": "The amount of LP tokens to withdraw." },
+                        "wallet_address": { "type": "string", "description": "Wallet address to use for withdrawing liquidity (optional, uses active wallet if not provided)" }
+                    },
+                    "required": ["pool_id", "amount"]
+                }
+            }),
+            serde_json::json!({
+                "name": "dex_simulate_withdraw_liquidity",
+                "description": "Previews withdrawing LP tokens from a pool without broadcasting: the exact assets returned, fees, and how far the pool's spot price moves. Stable pools are valued along their StableSwap curve.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": { "type": "string", "description": "The ID of the pool to withdraw from." },
+                        "amount": { "type": "string", "description": "The amount of LP tokens to withdraw, in base units." }
+                    },
+                    "required": ["pool_id", "amount"]
+                }
+            }),
+            serde_json::json!({
+                "name": "dex_zap_in",
+                "description": "Provides liquidity from a single asset. Part of the asset is swapped into the other pool asset and both are deposited in one atomic transaction.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "pool_id": { "type": "string", "description": "The ID of a two-asset pool." },
+                        "asset_denom": { "type": "string", "description": "Denomination of the asset to deposit (must be one of the pool assets)." },
+                        "amount": { "type": "string", "description": "Amount of the asset to deposit, in base units or human-readable such as '1.5 OM'." },
+                        "max_slippage": { "type": "string", "description": "Maximum slippage tolerance as a decimal, e.g. '0.01' for 1% (optional, defaults to 1%)." },
+                        "dry_run": { "type": "boolean", "description": "Only simulate and return the preview without broadcasting (optional, defaults to false)." }
+                    },
+                    "required": ["pool_id", "asset_denom", "amount"]
                 }
             }),
             serde_json::json!({
-                "name": "dex_withdraw_liquidity",
-                "description": "Withdraws liquidity from a specified pool.",
+                "name": "dex_zap_out",
+                "description": "Withdraws liquidity and swaps the withdrawn assets into a single asset in one atomic transaction.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "pool_id": { "type": "string", "description": "The ID of the pool to withdraw from." },
+                        "pool_id": { "type": "string", "description": "The ID of a two-asset pool." },
                         "amount": { "type": "string", "description": "The amount of LP tokens to withdraw." },
-                        "wallet_address": { "type": "string", "description": "Wallet address to use for withdrawing liquidity (optional, uses active wallet if not provided)" }
+                        "target_denom": { "type": "string", "description": "Pool asset to receive everything in." },
+                        "max_slippage": { "type": "string", "description": "Maximum slippage tolerance as a decimal, e.g. '0.01' for 1% (optional, defaults to 1%)." },
+                        "dry_run": { "type": "boolean", "description": "Only simulate and return the preview without broadcasting (optional, defaults to false)." }
                     },
-                    "required": ["pool_id", "amount"]
+                    "required": ["pool_id", "amount", "target_denom"]
+                }
+            }),
+            serde_json::json!({
+                "name": "dex_sweep_dust",
+                "description": "Swaps residual wallet balances below the dust threshold into the native token in one transaction, using the deepest pool pairing each asset with it. Balances without such a pool are reported as skipped.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "denoms": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Only sweep these denominations (optional, defaults to every dust balance)."
+                        },
+                        "threshold": { "type": "string", "description": "Balances below this amount in base units are dust (optional, defaults to the server's dust policy)." },
+                        "dry_run": { "type": "boolean", "description": "Only simulate and return the planned conversions without broadcasting (optional, defaults to false)." }
+                    }
                 }
             }),
             serde_json::json!({
@@ -2858,6 +5147,32 @@ impl McpToolProvider for MantraDexMcpServer {
                     "required": ["campaign_address"]
                 }
             }),
+            serde_json::json!({
+                "name": "claimdrop_get_vesting_schedule",
+                "description": "Get an address's vesting schedule in a ClaimDrop campaign with claimable-now and claimable-at projections",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "campaign_address": {
+                            "type": "string",
+                            "description": "Campaign contract address"
+                        },
+                        "address": {
+                            "type": "string",
+                            "description": "Address to get the schedule for (optional, defaults to the active wallet)"
+                        },
+                        "at_timestamp": {
+                            "type": "integer",
+                            "description": "Unix timestamp (seconds) to project claimable amounts at (optional)"
+                        },
+                        "timeline_points": {
+                            "type": "integer",
+                            "description": "Number of evenly spaced timeline samples (optional, default 12)"
+                        }
+                    },
+                    "required": ["campaign_address"]
+                }
+            }),
             serde_json::json!({
                 "name": "claimdrop_query_rewards",
                 "description": "Query user rewards from a campaign or factory",
@@ -2902,6 +5217,122 @@ impl McpToolProvider for MantraDexMcpServer {
                     "required": ["factory_address"]
                 }
             }),
+            serde_json::json!({
+                "name": "claimdrop_get_global_stats",
+                "description": "Get allocated, claimed and remaining totals, unique claimants and completion across all campaigns of a factory (cached)",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "factory_address": {
+                            "type": "string",
+                            "description": "ClaimDrop factory contract address"
+                        },
+                        "max_age_secs": {
+                            "type": "integer",
+                            "description": "Maximum age in seconds of cached stats to accept (optional, default 60)"
+                        },
+                        "force_refresh": {
+                            "type": "boolean",
+                            "description": "Recompute stats even if a fresh cached result exists (optional, default false)"
+                        },
+                        "include_campaigns": {
+                            "type": "boolean",
+                            "description": "Include the per-campaign breakdown (optional, default true)"
+                        }
+                    },
+                    "required": ["factory_address"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_export_eligibility_proof",
+                "description": "Export a signed attestation of an address's allocations, vesting and claim status across all factory campaigns, verifiable by third-party frontends",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "factory_address": {
+                            "type": "string",
+                            "description": "ClaimDrop factory contract address"
+                        },
+                        "address": {
+                            "type": "string",
+                            "description": "Address to attest (optional, defaults to the active wallet)"
+                        }
+                    },
+                    "required": ["factory_address"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_verify_eligibility_proof",
+                "description": "Verify the signature and signer of an exported eligibility proof",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "proof": {
+                            "type": "object",
+                            "description": "Signed proof as returned by claimdrop_export_eligibility_proof"
+                        }
+                    },
+                    "required": ["proof"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_close_campaign",
+                "description": "Close a campaign and return unclaimed rewards to the owner, once it has ended and the claim grace window has passed (admin only)",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "campaign_address": {
+                            "type": "string",
+                            "description": "Campaign contract address"
+                        },
+                        "grace_period_secs": {
+                            "type": "integer",
+                            "description": "Seconds after the campaign end during which late claims are protected (optional, default 604800)"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only run the closure safety checks (optional, default false)"
+                        }
+                    },
+                    "required": ["campaign_address"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_withdraw_remaining_funds",
+                "description": "Sweep non-reward tokens left in a closed campaign back to the owner (admin only)",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "campaign_address": {
+                            "type": "string",
+                            "description": "Campaign contract address"
+                        }
+                    },
+                    "required": ["campaign_address"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_end_of_life_campaign",
+                "description": "Close a campaign, sweep leftover tokens and report the recovered amounts (admin only)",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "campaign_address": {
+                            "type": "string",
+                            "description": "Campaign contract address"
+                        },
+                        "grace_period_secs": {
+                            "type": "integer",
+                            "description": "Seconds after the campaign end during which late claims are protected (optional, default 604800)"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only run the closure safety checks (optional, default false)"
+                        }
+                    },
+                    "required": ["campaign_address"]
+                }
+            }),
             serde_json::json!({
                 "name": "claimdrop_add_allocations",
                 "description": "Add allocations to a campaign (admin only, before campaign starts)",
@@ -2928,10 +5359,134 @@ impl McpToolProvider for MantraDexMcpServer {
                     "required": ["campaign_address", "allocations"]
                 }
             }),
+            serde_json::json!({
+                "name": "claimdrop_claim_with_proof",
+                "description": "Claim from a merkle-root campaign using an allocation proof. Provide either amount + proof, or the full allocation list to derive the proof for the active wallet",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "campaign_address": {
+                            "type": "string",
+                            "description": "Campaign contract address"
+                        },
+                        "amount": {
+                            "type": "string",
+                            "description": "Allocated amount the proof was generated for (required with proof)"
+                        },
+                        "proof": {
+                            "type": "array",
+                            "description": "Hex-encoded sibling hashes from leaf to root",
+                            "items": { "type": "string" }
+                        },
+                        "allocations": {
+                            "type": "array",
+                            "description": "Full campaign allocation list used to build the merkle tree (alternative to amount + proof)",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "user": { "type": "string" },
+                                    "allocated_amount": { "type": "string" }
+                                },
+                                "required": ["user", "allocated_amount"]
+                            }
+                        },
+                        "merkle_root": {
+                            "type": "string",
+                            "description": "Expected hex-encoded merkle root; the proof is verified locally before submitting (optional)"
+                        },
+                        "receiver": {
+                            "type": "string",
+                            "description": "Receiver address (optional, defaults to sender)"
+                        }
+                    },
+                    "required": ["campaign_address"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_claim_all",
+                "description": "Claim everything available to the active wallet across multiple campaigns in a single transaction. Campaigns with nothing claimable are skipped; at most 10 may have rewards to claim",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "campaign_addresses": {
+                            "type": "array",
+                            "description": "Campaign contract addresses to claim from",
+                            "items": { "type": "string" }
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only return the claim plan without broadcasting (default: false)"
+                        }
+                    },
+                    "required": ["campaign_addresses"]
+                }
+            }),
+            serde_json::json!({
+                "name": "claimdrop_build_campaign",
+                "description": "Validate and deploy a campaign together with its allocations. Checks timing, distribution, funding against allocations and duplicate users, then creates the campaign and uploads allocations in chunks",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "factory_address": {
+                            "type": "string",
+                            "description": "ClaimDrop factory contract address"
+                        },
+                        "name": { "type": "string", "description": "Campaign name" },
+                        "description": { "type": "string", "description": "Campaign description" },
+                        "type": { "type": "string", "description": "Campaign type label (e.g. airdrop)" },
+                        "start_time": {
+                            "type": "integer",
+                            "description": "Campaign start time (Unix timestamp)"
+                        },
+                        "end_time": {
+                            "type": "integer",
+                            "description": "Campaign end time (Unix timestamp)"
+                        },
+                        "reward_denom": {
+                            "type": "string",
+                            "description": "Token denomination for rewards"
+                        },
+                        "total_reward": {
+                            "type": "string",
+                            "description": "Total reward amount funding the campaign"
+                        },
+                        "distribution_type": {
+                            "type": "array",
+                            "description": "Distribution schedule entries ({type: lump_sum|linear_vesting, percentage, start_time, end_time?, cliff_duration?}); percentages must sum to 1",
+                            "items": { "type": "object" }
+                        },
+                        "allocations": {
+                            "type": "array",
+                            "description": "List of user allocations (alternative to allocations_csv)",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "user": { "type": "string" },
+                                    "allocated_amount": { "type": "string" }
+                                },
+                                "required": ["user", "allocated_amount"]
+                            }
+                        },
+                        "allocations_csv": {
+                            "type": "string",
+                            "description": "Allocations as CSV text with address,amount rows; a header row is allowed"
+                        },
+                        "chunk_size": {
+                            "type": "integer",
+                            "description": "Allocations per add_allocations transaction (default: 500)"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only validate and return the upload plan without broadcasting (default: false)"
+                        }
+                    },
+                    "required": ["factory_address", "name", "description", "type", "start_time", "end_time", "reward_denom", "total_reward", "distribution_type"]
+                }
+            }),
             // Skip Protocol Tools
             serde_json::json!({
                 "name": "skip_get_route",
-                "description": "Find optimal cross-chain routes between assets",
+                "description": "Find cross-chain routes between assets with per-leg bridge, swap and relayer fees and estimated completion time",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
@@ -2962,6 +5517,11 @@ impl McpToolProvider for MantraDexMcpServer {
                         "smart_relay": {
                             "type": "boolean",
                             "description": "Use smart relay optimization (optional)"
+                        },
+                        "preference": {
+                            "type": "string",
+                            "enum": ["cheapest", "fastest"],
+                            "description": "Order routes by lowest USD fees or shortest estimated completion time (optional)"
                         }
                     },
                     "required": ["source_asset_denom", "source_asset_amount", "source_chain", "target_asset_denom", "target_chain"]
@@ -2969,7 +5529,7 @@ impl McpToolProvider for MantraDexMcpServer {
             }),
             serde_json::json!({
                 "name": "skip_execute_transfer",
-                "description": "Execute cross-chain asset transfers",
+                "description": "Execute a cross-chain transfer or swap in one transaction. The source chain swap, IBC transfers and destination chain swap are composed from the route, with forwarding and IBC hooks instructions in the packet memo.",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
@@ -2991,52 +5551,150 @@ impl McpToolProvider for MantraDexMcpServer {
                         },
                         "target_chain": {
                             "type": "string",
-                            "description": "Target chain identifier"
+                            "description": "Target chain identifier"
+                        },
+                        "recipient": {
+                            "type": "string",
+                            "description": "Recipient address on target chain"
+                        },
+                        "timeout_seconds": {
+                            "type": "integer",
+                            "description": "Transfer timeout in seconds (optional)"
+                        },
+                        "slippage_tolerance": {
+                            "type": "string",
+                            "description": "Slippage tolerance as a decimal, e.g. '0.01' for 1% (optional, defaults to 1%)"
+                        },
+                        "entry_points": {
+                            "type": "object",
+                            "additionalProperties": { "type": "string" },
+                            "description": "Skip entry point contract per chain id, required for chains the route swaps on after bridging (optional)"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only return the planned messages and memo without broadcasting (optional, defaults to false)"
+                        },
+                        "validate_targets": {
+                            "type": "boolean",
+                            "description": "Check both chains and assets against the cached Skip registry before submitting (optional, defaults to true)"
+                        }
+                    },
+                    "required": ["source_asset_denom", "source_asset_amount", "source_chain", "target_asset_denom", "target_chain", "recipient"]
+                }
+            }),
+            serde_json::json!({
+                "name": "skip_track_transfer",
+                "description": "Get the current status of a cross-chain transfer",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "transfer_id": {
+                            "type": "string",
+                            "description": "Source transaction hash or transfer ID to look up"
+                        },
+                        "chain_id": {
+                            "type": "string",
+                            "description": "Source chain ID of the transaction (optional)"
+                        }
+                    },
+                    "required": ["transfer_id"]
+                }
+            }),
+            serde_json::json!({
+                "name": "skip_watch_transfer",
+                "description": "Poll a cross-chain transfer through its IBC packet lifecycle until it completes, fails or the timeout passes, returning every status transition and the destination tx hash",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "tx_hash": {
+                            "type": "string",
+                            "description": "Source chain transaction hash"
                         },
-                        "recipient": {
+                        "chain_id": {
                             "type": "string",
-                            "description": "Recipient address on target chain"
+                            "description": "Source chain ID; registers the transaction with Skip before polling (optional)"
                         },
-                        "timeout_seconds": {
+                        "timeout_secs": {
                             "type": "integer",
-                            "description": "Transfer timeout in seconds (optional)"
+                            "description": "Stop waiting after this many seconds (optional, default 120, max 600)"
                         },
-                        "slippage_tolerance": {
+                        "poll_interval_secs": {
+                            "type": "integer",
+                            "description": "Seconds between status polls (optional, default 5)"
+                        }
+                    },
+                    "required": ["tx_hash"]
+                }
+            }),
+            serde_json::json!({
+                "name": "skip_get_supported_chains",
+                "description": "List available chains and their configurations",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "filter": {
                             "type": "string",
-                            "description": "Slippage tolerance (optional)"
+                            "description": "Filter chains by name or ID (optional)"
                         }
                     },
-                    "required": ["source_asset_denom", "source_asset_amount", "source_chain", "target_asset_denom", "target_chain", "recipient"]
+                    "required": []
                 }
             }),
             serde_json::json!({
-                "name": "skip_track_transfer",
-                "description": "Monitor transfer status and progress",
+                "name": "skip_recover_transfer",
+                "description": "Recover a cross-chain transfer past its IBC timeout: confirm the refund on the source chain and optionally resubmit it along a fresh route",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
                         "transfer_id": {
                             "type": "string",
-                            "description": "Transfer ID to track"
+                            "description": "Transfer ID or source transaction hash returned by skip_execute_transfer"
+                        },
+                        "retry": {
+                            "type": "boolean",
+                            "description": "Resubmit the refunded tokens to the original target once the refund is confirmed (optional, defaults to false)"
+                        },
+                        "entry_points": {
+                            "type": "object",
+                            "additionalProperties": { "type": "string" },
+                            "description": "Skip entry point contract per chain id for the retried route (optional)"
                         }
                     },
                     "required": ["transfer_id"]
                 }
             }),
             serde_json::json!({
-                "name": "skip_get_supported_chains",
-                "description": "List available chains and their configurations",
+                "name": "skip_list_chains",
+                "description": "List chains Skip can route between, cached for chain pickers",
                 "inputSchema": {
                     "type": "object",
                     "properties": {
-                        "filter": {
-                            "type": "string",
-                            "description": "Filter chains by name or ID (optional)"
+                        "only_available": {
+                            "type": "boolean",
+                            "description": "Leave out chains Skip has disabled (optional, defaults to true)"
                         }
                     },
                     "required": []
                 }
             }),
+            serde_json::json!({
+                "name": "skip_list_chain_assets",
+                "description": "List the assets Skip supports on a chain, cached for asset pickers",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "chain_id": {
+                            "type": "string",
+                            "description": "Chain to list assets for"
+                        },
+                        "search": {
+                            "type": "string",
+                            "description": "Filter assets by symbol or denom (optional)"
+                        }
+                    },
+                    "required": ["chain_id"]
+                }
+            }),
             serde_json::json!({
                 "name": "skip_verify_assets",
                 "description": "Validate assets across different chains",
@@ -3096,6 +5754,14 @@ impl McpToolProvider for MantraDexMcpServer {
                     "required": ["source_asset_denom", "source_asset_amount", "source_chain", "target_asset_denom", "target_chain", "recipient"]
                 }
             }),
+            serde_json::json!({
+                "name": "skip_get_affiliate_fees",
+                "description": "Report the affiliate fees the configured Skip affiliate has accrued: fees owed by swaps this server executed and the affiliate address's current balances",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {}
+                }
+            }),
             // PrimarySale Protocol Tools
             #[cfg(feature = "evm")]
             serde_json::json!({
@@ -3155,6 +5821,42 @@ impl McpToolProvider for MantraDexMcpServer {
                 }
             }),
             #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "primary_sale_approve_and_invest",
+                "description": "Invest in a primary sale, first approving the sale to spend the token and waiting for the approval to be mined when the allowance falls short. Progress is reported for each step. An investment that fails after its approval was mined returns how to retry it or revoke the approval.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "contract_address": {
+                            "type": "string",
+                            "description": "PrimarySale contract address (0x...)"
+                        },
+                        "token": {
+                            "type": "string",
+                            "description": "Accepted token to invest with (0x...)"
+                        },
+                        "amount": {
+                            "type": "string",
+                            "description": "Amount to invest (in human-readable units)"
+                        },
+                        "approval_amount": {
+                            "type": "string",
+                            "description": "Amount to approve when an approval is needed, at least the investment, or \"unlimited\" (default: the investment amount)"
+                        },
+                        "confirmation_timeout_secs": {
+                            "type": "integer",
+                            "description": "Seconds to wait for the approval to be mined (default: 120)",
+                            "minimum": 1
+                        },
+                        "wallet_address": {
+                            "type": "string",
+                            "description": "Wallet address to use (optional, uses active if not provided)"
+                        }
+                    },
+                    "required": ["contract_address", "token", "amount"]
+                }
+            }),
+            #[cfg(feature = "evm")]
             serde_json::json!({
                 "name": "primary_sale_claim_refund",
                 "description": "Claim refund from a failed or cancelled sale",
@@ -3202,6 +5904,30 @@ impl McpToolProvider for MantraDexMcpServer {
                 }
             }),
             #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "primary_sale_check_eligibility",
+                "description": "Check in one call whether an investor can invest an amount of a token in a primary sale: sale status and window, KYC allowlist membership, token acceptance, minimum step, remaining capacity under the hard cap, token balance, allowance and gas balance. Returns every blocking issue with how to fix it, instead of failing on the first",
+                "inputSchema": input_schema::<PrimarySaleCheckEligibilityArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "primary_sale_export_investors",
+                "description": "Export every investor of a primary sale with their contributions by token, allocation, KYC, settlement and refund status, as JSON or CSV, and reconcile the summed contributions against the sale's normalized and per-token totals. Fetches investors concurrently under a request rate limit; for issuer reporting and audits",
+                "inputSchema": input_schema::<PrimarySaleExportInvestorsArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "primary_sale_project_settlement",
+                "description": "Preview settling a primary sale with its current contributions (read-only): commission to MANTRA and proceeds to the issuer per payment token, each investor's token allocation or refund (KYC-delisted and restricted investors are refunded), and whether the soft cap is met",
+                "inputSchema": input_schema::<PrimarySaleProjectSettlementArgs>()
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "restricted_wallets_validate",
+                "description": "Check the restricted wallet list against a primary sale's investors (read-only): which listed wallets invested and will be refunded, how many did not invest, and which restricted investors settlement has already processed",
+                "inputSchema": input_schema::<RestrictedWalletsValidateArgs>()
+            }),
+            #[cfg(feature = "evm")]
             serde_json::json!({
                 "name": "primary_sale_activate",
                 "description": "Activate a primary sale (admin only, transitions from Pending to Active)",
@@ -3351,65 +6077,656 @@ impl McpToolProvider for MantraDexMcpServer {
                     },
                     "required": ["contract_address"]
                 }
-            }),
-            #[cfg(feature = "evm")]
-            serde_json::json!({
-                "name": "primary_sale_emergency_withdraw",
-                "description": "Emergency withdraw stuck ERC-20 tokens (admin only, only when Cancelled)",
-                "inputSchema": {
-                    "type": "object",
-                    "properties": {
-                        "contract_address": {
-                            "type": "string",
-                            "description": "PrimarySale contract address (0x...)"
-                        },
-                        "token_address": {
-                            "type": "string",
-                            "description": "ERC-20 token contract address to withdraw (0x...)"
-                        },
-                        "recipient": {
-                            "type": "string",
-                            "description": "Recipient address for withdrawn tokens (0x...)"
-                        },
-                        "amount": {
-                            "type": "string",
-                            "description": "Amount to withdraw (in human-readable units)"
-                        },
-                        "wallet_address": {
-                            "type": "string",
-                            "description": "Admin wallet address (optional, uses active wallet if not provided)"
-                        }
-                    },
-                    "required": ["contract_address", "token_address", "recipient", "amount"]
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "primary_sale_emergency_withdraw",
+                "description": "Emergency withdraw stuck ERC-20 tokens (admin only, only when Cancelled)",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "contract_address": {
+                            "type": "string",
+                            "description": "PrimarySale contract address (0x...)"
+                        },
+                        "token_address": {
+                            "type": "string",
+                            "description": "ERC-20 token contract address to withdraw (0x...)"
+                        },
+                        "recipient": {
+                            "type": "string",
+                            "description": "Recipient address for withdrawn tokens (0x...)"
+                        },
+                        "amount": {
+                            "type": "string",
+                            "description": "Amount to withdraw (in human-readable units)"
+                        },
+                        "wallet_address": {
+                            "type": "string",
+                            "description": "Admin wallet address (optional, uses active wallet if not provided)"
+                        }
+                    },
+                    "required": ["contract_address", "token_address", "recipient", "amount"]
+                }
+            }),
+            #[cfg(feature = "evm")]
+            serde_json::json!({
+                "name": "evm_analyze_transaction_history",
+                "description": "Analyze EVM transaction history and generate human-readable narrative. Fetches transactions, decodes their input data, and creates a sequential story of on-chain actions.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "transaction_hashes": {
+                            "type": "array",
+                            "items": { "type": "string" },
+                            "description": "Array of transaction hashes (0x...) to analyze",
+                            "minItems": 1,
+                            "maxItems": 20
+                        },
+                        "include_failed": {
+                            "type": "boolean",
+                            "description": "Include failed transactions in narrative (default: false)",
+                            "default": false
+                        }
+                    },
+                    "required": ["transaction_hashes"]
+                }
+            }),
+        ];
+
+        // Tools the authorization policy refuses are not offered
+        let policy = self.state.authorizer.config();
+        tools
+            .into_iter()
+            .filter(|tool| {
+                tool.get("name")
+                    .and_then(|name| name.as_str())
+                    .is_some_and(|name| policy.permits_tool(name).is_ok())
+            })
+            .map(with_dry_run_argument)
+            .map(with_memo_argument)
+            .map(with_timeout_blocks_argument)
+            .map(with_idempotency_key_argument)
+            .collect()
+    }
+
+    /// Check a call against its tool's input schema, reporting every
+    /// missing or invalid field at once
+    fn validate_tool_arguments(&self, tool_name: &str, arguments: &Value) -> McpResult<()> {
+        let schemas = self.tool_schemas.get_or_init(|| {
+            self.get_available_tools()
+                .into_iter()
+                .filter_map(|tool| {
+                    let name = tool.get("name")?.as_str()?.to_string();
+                    Some((name, tool.get("inputSchema")?.clone()))
+                })
+                .collect()
+        });
+        // Unknown and refused tools are reported by the dispatcher and the policy
+        let Some(schema) = schemas.get(tool_name) else {
+            return Ok(());
+        };
+        let errors = validate_arguments(schema, arguments);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid_arguments(tool_name, &errors))
+        }
+    }
+
+    async fn handle_tool_call(
+        &self,
+        tool_name: &str,
+        mut arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        // A server-wide dry run is passed on as the call's own flag, so tools
+        // with their own preview return it
+        if self.state.config.dry_run && is_write_tool(tool_name) {
+            if let Some(map) = arguments.as_object_mut() {
+                map.insert("dry_run".to_string(), Value::Bool(true));
+            }
+        }
+
+        // Address book labels stand for their addresses in any address argument
+        let labels = self
+            .state
+            .sdk_adapter
+            .resolve_address_labels(&mut arguments)
+            .await;
+        if !labels.is_empty() {
+            debug!(?labels, "Resolved address labels for {}", tool_name);
+        }
+
+        // Write calls are audited whether they go through or are refused
+        let audited_arguments = is_write_tool(tool_name).then(|| arguments.clone());
+        let session_id = session_id_from_arguments(&arguments);
+        let trace = correlation_id_from_arguments(&arguments)
+            .map(TraceContext::with_correlation_id)
+            .unwrap_or_default();
+        let started_at = chrono::Utc::now();
+        let started = Instant::now();
+
+        // Arguments are checked, then the policy runs, so a malformed or refused
+        // call consumes no quota slot or nonce
+        let call = async {
+            // Amounts such as "1.5 OM" become base units before limits count them
+            McpSdkAdapter::in_session(
+                session_id.clone(),
+                self.state
+                    .sdk_adapter
+                    .normalize_amount_arguments(&mut arguments),
+            )
+            .await?;
+            self.validate_tool_arguments(tool_name, &arguments)?;
+            // A retried call already passed the policy and spent its budget
+            let idempotent_call = match self.begin_idempotent_call(tool_name, &arguments)? {
+                Some(Admission::Replay(result)) => return Ok(mark_idempotent_replay(result)),
+                Some(Admission::New(call)) => Some(call),
+                None => None,
+            };
+            match self.state.authorizer.authorize(tool_name, &arguments) {
+                Ok(authorization) => {
+                    // The adapter resolves wallet, network and custom tokens for the session
+                    let result = McpSdkAdapter::in_session(session_id.clone(), async {
+                        let reservation = self.reserve_budget(tool_name, &arguments).await?;
+                        // Boxed: the dispatch future holds every tool's state and
+                        // is too deep to lay out inline in this one
+                        let result = Box::pin(self.admit_and_dispatch(tool_name, arguments)).await;
+                        if let (Err(_), Some(reservation)) = (&result, &reservation) {
+                            self.state.budget.release(reservation);
+                        }
+                        result
+                    })
+                    .await;
+                    if result.is_err() {
+                        self.state.authorizer.release(&authorization);
+                    }
+                    if let Some(call) = idempotent_call {
+                        call.complete(&result);
+                    }
+                    result
+                }
+                Err(e) => Err(e),
+            }
+        };
+        let result = trace
+            .scope(call.instrument(tracing::info_span!("tool_call", tool = tool_name)))
+            .await;
+        let result = match result {
+            Ok(result) => Ok(with_correlation_id(
+                self.label_addresses(result).await,
+                trace.correlation_id(),
+            )),
+            Err(e) => {
+                debug!(
+                    correlation_id = trace.correlation_id(),
+                    "Tool {} failed: {}", tool_name, e
+                );
+                Err(e)
+            }
+        };
+
+        let elapsed = started.elapsed();
+        self.state.traces.record(ToolCallTrace::new(
+            &trace,
+            tool_name,
+            session_id.clone(),
+            started_at,
+            elapsed.as_millis() as u64,
+            result.as_ref().err().map(|e| e.to_string()),
+        ));
+        self.state.metrics.record_tool_call(
+            tool_name,
+            elapsed,
+            result.as_ref().err().map(|e| e.error_type()),
+        );
+        if let Some(arguments) = audited_arguments {
+            McpSdkAdapter::in_session(
+                session_id,
+                self.audit_tool_call(tool_name, &arguments, &result, elapsed),
+            )
+            .await;
+        }
+        result
+    }
+}
+
+impl McpPromptProvider for MantraDexMcpServer {}
+
+impl MantraDexMcpServer {
+    /// Admit a write call carrying an `idempotency_key`
+    ///
+    /// Returns `None` for calls without a key, read tools and dry runs, which
+    /// broadcast nothing to protect.
+    fn begin_idempotent_call(
+        &self,
+        tool_name: &str,
+        arguments: &Value,
+    ) -> McpResult<Option<Admission<'_>>> {
+        let dry_run = arguments
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !is_write_tool(tool_name) || dry_run {
+            return Ok(None);
+        }
+        let Some(key) = idempotency_key_from_arguments(arguments)? else {
+            return Ok(None);
+        };
+        let principal = principal_from_arguments(arguments);
+        self.state
+            .idempotency
+            .begin(principal.as_deref(), &key, tool_name, arguments)
+            .map(Some)
+    }
+
+    /// Hold what a call spends against its wallet's budget
+    ///
+    /// The wallet is the call's `wallet_address`, or the session's active
+    /// wallet; calls that spend nothing or have no wallet reserve nothing.
+    async fn reserve_budget(
+        &self,
+        tool_name: &str,
+        arguments: &Value,
+    ) -> McpResult<Option<BudgetReservation>> {
+        let spends = self.state.budget.spends_of(tool_name, arguments);
+        if spends.is_empty() {
+            return Ok(None);
+        }
+        let wallet = match arguments.get("wallet_address").and_then(|v| v.as_str()) {
+            Some(address) => Some(address.to_string()),
+            None => self
+                .state
+                .sdk_adapter
+                .get_active_wallet_info()
+                .await
+                .ok()
+                .flatten()
+                .map(|info| info.address),
+        };
+        match wallet {
+            Some(wallet) => self
+                .state
+                .budget
+                .reserve(&wallet, tool_name, &spends)
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// List the labels of the address book addresses a tool result mentions
+    /// under `_meta.address_labels`
+    async fn label_addresses(&self, mut result: Value) -> Value {
+        let Some(content) = result.get("content") else {
+            return result;
+        };
+        let labels = self
+            .state
+            .sdk_adapter
+            .address_labels_in(&content.to_string())
+            .await;
+        if labels.is_empty() {
+            return result;
+        }
+        if let Some(map) = result.as_object_mut() {
+            let meta = map.entry("_meta").or_insert_with(|| serde_json::json!({}));
+            if let Some(meta) = meta.as_object_mut() {
+                meta.insert(
+                    "address_labels".to_string(),
+                    serde_json::to_value(labels).unwrap_or_default(),
+                );
+            }
+        }
+        result
+    }
+}
+
+// Implement the main McpServer trait that combines all sub-traits
+impl McpServer for MantraDexMcpServer {}
+
+impl MantraDexMcpServer {
+    /// Probe the RPC endpoints, wallet and connection pools the server depends on
+    pub async fn check_health(&self) -> HealthReport {
+        let health = &self.state.config.health;
+        let timeout = Duration::from_millis(health.probe_timeout_ms);
+        let now = chrono::Utc::now();
+        let adapter = &self.state.sdk_adapter;
+        let network_config = adapter
+            .get_default_network_config()
+            .await
+            .unwrap_or_else(|_| self.state.config.network_config.clone());
+        let mut components = BTreeMap::new();
+
+        let started = Instant::now();
+        let probe = tokio::time::timeout(timeout, async {
+            let client = adapter.get_client(&network_config).await?;
+            client
+                .get_latest_block_time()
+                .await
+                .map_err(McpServerError::Sdk)
+        })
+        .await;
+        let cosmos_rpc = match probe {
+            Ok(Ok((height, block_time))) => ComponentHealth::chain(
+                true,
+                height,
+                block_time,
+                now.timestamp(),
+                health.max_block_lag_secs,
+            ),
+            Ok(Err(e)) => ComponentHealth::down(true, e.to_string()),
+            Err(_) => ComponentHealth::down(
+                true,
+                format!("no response within {} ms", health.probe_timeout_ms),
+            ),
+        };
+        components.insert(
+            "cosmos_rpc".to_string(),
+            cosmos_rpc.with_latency_ms(started.elapsed().as_millis() as u64),
+        );
+
+        if network_config.evm_rpc_url.is_some() {
+            let started = Instant::now();
+            let probe = tokio::time::timeout(timeout, async {
+                let (client, _) = adapter.get_evm_client().await?;
+                client
+                    .get_latest_block_time()
+                    .await
+                    .map_err(McpServerError::Sdk)
+            })
+            .await;
+            let evm_rpc = match probe {
+                Ok(Ok((height, block_time))) => ComponentHealth::chain(
+                    false,
+                    height,
+                    block_time,
+                    now.timestamp(),
+                    health.max_block_lag_secs,
+                ),
+                Ok(Err(e)) => ComponentHealth::down(false, e.to_string()),
+                Err(_) => ComponentHealth::down(
+                    false,
+                    format!("no response within {} ms", health.probe_timeout_ms),
+                ),
+            };
+            components.insert(
+                "evm_rpc".to_string(),
+                evm_rpc.with_latency_ms(started.elapsed().as_millis() as u64),
+            );
+        }
+
+        let wallet = match adapter.get_active_wallet_info().await {
+            Ok(Some(info)) => {
+                ComponentHealth::new(ComponentStatus::Ok, false).with_detail(info.address)
+            }
+            Ok(None) => ComponentHealth::new(ComponentStatus::Degraded, false)
+                .with_detail("no active wallet; write tools are unavailable"),
+            Err(e) => ComponentHealth::down(false, e.to_string()),
+        };
+        components.insert("wallet".to_string(), wallet);
+
+        let gauges = adapter.get_pool_gauges().await;
+        let (connections, healthy) = gauges.values().fold((0, 0), |(total, healthy), pool| {
+            (total + pool.connections, healthy + pool.healthy)
+        });
+        let (endpoints, available) = gauges.values().fold((0, 0), |(total, available), pool| {
+            (total + pool.endpoints, available + pool.endpoints_available)
+        });
+        let pool_status = if healthy == connections && available == endpoints {
+            ComponentStatus::Ok
+        } else if healthy == 0 || available == 0 {
+            ComponentStatus::Down
+        } else {
+            ComponentStatus::Degraded
+        };
+        components.insert(
+            "connection_pool".to_string(),
+            ComponentHealth::new(pool_status, true).with_detail(format!(
+                "{} of {} connections healthy, {} of {} RPC endpoints available",
+                healthy, connections, available, endpoints
+            )),
+        );
+
+        HealthReport::new(components, now)
+    }
+
+    /// Metrics in the Prometheus text format, as served on `/metrics`
+    pub async fn render_metrics(&self) -> String {
+        let pools = self.state.sdk_adapter.get_pool_gauges().await;
+        let (adapter_entries, _) = self.state.sdk_adapter.get_cache_stats().await;
+        let server_entries = self.state.cache.read().await.len();
+        self.state.metrics.render(
+            &pools,
+            &[("adapter", adapter_entries), ("server", server_entries)],
+        )
+    }
+
+    /// Append a finished write call to the audit log
+    ///
+    /// A call that cannot be recorded is logged rather than failed, since the
+    /// transaction may already be on chain.
+    async fn audit_tool_call(
+        &self,
+        tool_name: &str,
+        arguments: &Value,
+        result: &McpResult<Value>,
+        elapsed: Duration,
+    ) {
+        let wallet = match arguments.get("wallet_address").and_then(|v| v.as_str()) {
+            Some(address) => Some(address.to_string()),
+            None => self
+                .state
+                .sdk_adapter
+                .get_active_wallet_info()
+                .await
+                .ok()
+                .flatten()
+                .map(|info| info.address),
+        };
+        let entry = AuditEntry {
+            tool: tool_name.to_string(),
+            args_sha256: arguments_digest(arguments),
+            session_id: session_id_from_arguments(arguments),
+            principal: principal_from_arguments(arguments),
+            wallet,
+            tx_hash: result.as_ref().ok().and_then(find_tx_hash),
+            dry_run: arguments
+                .get("dry_run")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            error: result.as_ref().err().map(|e| e.to_string()),
+            duration_ms: elapsed.as_millis() as u64,
+        };
+        if let Err(e) = self.state.audit_log.record(entry) {
+            error!(tool = tool_name, "Failed to write audit record: {}", e);
+        }
+    }
+
+    /// Simulate a write call and queue it for `approve_transaction`
+    ///
+    /// A call whose simulation fails is refused rather than queued.
+    async fn propose_transaction(
+        &self,
+        tool_name: &str,
+        session_id: &str,
+        mut arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        let proposed_by = principal_from_arguments(&arguments);
+        if let Some(map) = arguments.as_object_mut() {
+            map.remove("_meta");
+        }
+
+        let mut preview = arguments.clone();
+        if let Some(map) = preview.as_object_mut() {
+            map.insert("dry_run".to_string(), Value::Bool(true));
+        }
+        let simulation =
+            McpSdkAdapter::dry_run(self.dispatch_tool_call(tool_name, preview)).await?;
+        let summary = serde_json::json!({
+            "action": tool_name,
+            "class": ToolClass::of(tool_name).as_str(),
+            "arguments": arguments,
+            "simulation": tool_result_payload(&simulation),
+        });
+
+        let proposal = self.state.transaction_queue.propose(
+            tool_name,
+            session_id,
+            proposed_by,
+            arguments,
+            summary,
+        );
+        info!(id = %proposal.id, tool = tool_name, "Queued transaction for approval");
+        let result = serde_json::json!({
+            "status": "pending_approval",
+            "id": proposal.id,
+            "tool": proposal.tool,
+            "summary": proposal.summary,
+            "expires_at": proposal.expires_at.to_rfc3339(),
+        });
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    /// Broadcast a queued transaction once it is approved
+    ///
+    /// The call runs in its proposer's session, through that session's write
+    /// quota, and is audited under the approver.
+    async fn approve_queued_transaction(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling approve_transaction tool call");
+        let id = arguments
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("id is required".to_string()))?;
+        let approver = principal_from_arguments(&arguments);
+        let proposal = self.state.transaction_queue.take(id, approver.as_deref())?;
+
+        let mut call_arguments = proposal.arguments;
+        if let Some(map) = call_arguments.as_object_mut() {
+            map.insert(
+                "_meta".to_string(),
+                serde_json::json!({
+                    "session_id": proposal.session_id,
+                    "principal": approver,
+                }),
+            );
+        }
+        let audited_arguments = call_arguments.clone();
+
+        let _write_permit = self
+            .state
+            .write_quotas
+            .try_acquire(&proposal.session_id)
+            .await?;
+        // The call runs with the proposer's wallet context
+        let tool = proposal.tool;
+        McpSdkAdapter::in_session(proposal.session_id, async move {
+            let started = Instant::now();
+            let result = self.dispatch_tool_call(&tool, call_arguments).await;
+            self.state.metrics.record_broadcast(&tool, result.is_ok());
+            self.audit_tool_call(&tool, &audited_arguments, &result, started.elapsed())
+                .await;
+            result
+        })
+        .await
+    }
+
+    /// Admit a write call through quotas, nonces and approval, then dispatch it
+    async fn admit_and_dispatch(
+        &self,
+        tool_name: &str,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        // Approving a queued call dispatches it, so it is routed here rather
+        // than through dispatch_tool_call
+        if tool_name == "approve_transaction" {
+            return self.approve_queued_transaction(arguments).await;
+        }
+
+        let session_id = session_id_from_arguments(&arguments);
+
+        // Write tools are admitted through the in-flight quotas; the permit is held
+        // until the call completes. The nonce is checked after the quota so a call
+        // rejected for quota can be retried with the same nonce.
+        let write_tool = is_write_tool(tool_name);
+        let dry_run = arguments
+            .get("dry_run")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let _write_permit = if write_tool {
+            let permit = self.state.write_quotas.try_acquire(&session_id).await?;
+            self.state
+                .write_nonces
+                .check_and_record(&session_id, nonce_from_arguments(&arguments)?)
+                .await?;
+            // Dry runs broadcast nothing, so they skip the operator and the queue
+            if !dry_run {
+                if self.state.transaction_queue.queues(tool_name) {
+                    return self
+                        .propose_transaction(tool_name, &session_id, arguments)
+                        .await;
                 }
-            }),
-            #[cfg(feature = "evm")]
-            serde_json::json!({
-                "name": "evm_analyze_transaction_history",
-                "description": "Analyze EVM transaction history and generate human-readable narrative. Fetches transactions, decodes their input data, and creates a sequential story of on-chain actions.",
-                "inputSchema": {
-                    "type": "object",
-                    "properties": {
-                        "transaction_hashes": {
-                            "type": "array",
-                            "items": { "type": "string" },
-                            "description": "Array of transaction hashes (0x...) to analyze",
-                            "minItems": 1,
-                            "maxItems": 20
-                        },
-                        "include_failed": {
-                            "type": "boolean",
-                            "description": "Include failed transactions in narrative (default: false)",
-                            "default": false
-                        }
-                    },
-                    "required": ["transaction_hashes"]
+                self.state
+                    .approvals
+                    .await_approval(tool_name, &session_id, &arguments)
+                    .await?;
+            }
+            Some(permit)
+        } else {
+            None
+        };
+
+        if write_tool && dry_run {
+            return McpSdkAdapter::dry_run(self.dispatch_tool_call(tool_name, arguments))
+                .await
+                .map(mark_dry_run);
+        }
+
+        let result = self.dispatch_tool_call(tool_name, arguments).await;
+        if write_tool {
+            self.state
+                .metrics
+                .record_broadcast(tool_name, result.is_ok());
+        }
+        result
+    }
+
+    /// Route a tool call to its handler, with the call's own memo and
+    /// timeout on the transactions of write tools
+    async fn dispatch_tool_call(
+        &self,
+        tool_name: &str,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        let memo = memo_from_arguments(&arguments).filter(|_| is_write_tool(tool_name));
+        let timeout_blocks =
+            timeout_blocks_from_arguments(&arguments).filter(|_| is_write_tool(tool_name));
+        let call = async move {
+            match memo {
+                Some(memo) => {
+                    McpSdkAdapter::with_memo(memo, self.route_tool_call(tool_name, arguments)).await
                 }
-            }),
-        ]
+                None => self.route_tool_call(tool_name, arguments).await,
+            }
+        };
+        match timeout_blocks {
+            Some(blocks) => McpSdkAdapter::with_timeout_blocks(blocks, call).await,
+            None => call.await,
+        }
     }
 
-    async fn handle_tool_call(
+    async fn route_tool_call(
         &self,
         tool_name: &str,
         arguments: serde_json::Value,
@@ -3420,16 +6737,52 @@ impl McpToolProvider for MantraDexMcpServer {
             "network_validate_connectivity" => {
                 self.handle_validate_network_connectivity(arguments).await
             }
+            "network_performance" => self.handle_network_performance(arguments).await,
+            "network_node_status" => self.handle_network_node_status(arguments).await,
+            "network_get_block" => self.handle_network_get_block(arguments).await,
+            "network_get_validators" => self.handle_network_get_validators(arguments).await,
+            "network_get_chain_params" => self.handle_network_get_chain_params(arguments).await,
+            "network_get_mempool" => self.handle_network_get_mempool(arguments).await,
+            "get_server_health" => self.handle_get_server_health(arguments).await,
+            "resolve_asset" => self.handle_resolve_asset(arguments).await,
 
             // Wallet tools
             "wallet_get_balances" => self.handle_get_balances(arguments).await,
+            "faucet_request" => self.handle_faucet_request(arguments).await,
             "wallet_list" => self.handle_list_wallets(arguments).await,
             "wallet_switch" => self.handle_switch_wallet(arguments).await,
             "wallet_get_active" => self.handle_get_active_wallet(arguments).await,
+            "session_get_context" => self.handle_session_get_context(arguments).await,
+            "session_set_network" => self.handle_session_set_network(arguments).await,
+            "switch_network" => self.handle_switch_network(arguments).await,
+            "get_multi_network_portfolio" => {
+                self.handle_get_multi_network_portfolio(arguments).await
+            }
+            "address_book_add" => self.handle_address_book_add(arguments).await,
+            "address_book_remove" => self.handle_address_book_remove(arguments).await,
+            "address_book_list" => self.handle_address_book_list(arguments).await,
+            "address_convert" => self.handle_address_convert(arguments).await,
+            "restricted_wallets_add" => self.handle_restricted_wallets_add(arguments).await,
+            "restricted_wallets_remove" => self.handle_restricted_wallets_remove(arguments).await,
+            "restricted_wallets_import" => self.handle_restricted_wallets_import(arguments).await,
+            "restricted_wallets_list" => self.handle_restricted_wallets_list(arguments).await,
+            "get_spending_summary" => self.handle_get_spending_summary(arguments).await,
+            "analyze_wallet_activity" => self.handle_analyze_wallet_activity(arguments).await,
             "wallet_get_evm_address" => self.handle_get_evm_address(arguments).await,
             "wallet_add_from_mnemonic" => self.handle_add_wallet_from_mnemonic(arguments).await,
+            "wallet_discover" => self.handle_discover_wallets(arguments).await,
             "wallet_remove" => self.handle_remove_wallet(arguments).await,
 
+            // State tools
+            "state_audit_log" => self.handle_state_audit_log(arguments).await,
+            "get_audit_log" => self.handle_get_audit_log(arguments).await,
+            "get_signing_history" => self.handle_get_signing_history(arguments).await,
+            "get_recent_traces" => self.handle_get_recent_traces(arguments).await,
+            "watch_chain_events" => self.handle_watch_chain_events(arguments).await,
+            "list_pending_transactions" => self.handle_list_pending_transactions(arguments).await,
+            "list_queued_transactions" => self.handle_list_queued_transactions(arguments).await,
+            "reject_transaction" => self.handle_reject_transaction(arguments).await,
+
             // EVM Balance tools
             #[cfg(feature = "evm")]
             "wallet_get_native_evm_balance" => self.handle_get_native_evm_balance(arguments).await,
@@ -3441,6 +6794,24 @@ impl McpToolProvider for MantraDexMcpServer {
             "wallet_transfer_erc20" => self.handle_transfer_erc20(arguments).await,
             #[cfg(feature = "evm")]
             "wallet_approve_erc20" => self.handle_approve_erc20(arguments).await,
+            #[cfg(feature = "evm")]
+            "erc20_get_allowances" => self.handle_erc20_get_allowances(arguments).await,
+            #[cfg(feature = "evm")]
+            "erc20_revoke_approval" => self.handle_erc20_revoke_approval(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_deploy_erc20" => self.handle_evm_deploy_erc20(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_get_block" => self.handle_evm_get_block(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_get_transaction" => self.handle_evm_get_transaction(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_get_code" => self.handle_evm_get_code(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_inspect_contract" => self.handle_evm_inspect_contract(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_get_storage_at" => self.handle_evm_get_storage_at(arguments).await,
+            #[cfg(feature = "evm")]
+            "evm_chain_info" => self.handle_evm_chain_info(arguments).await,
 
             // DEX tools
             "dex_get_pools" => self.handle_get_pools(arguments).await,
@@ -3450,6 +6821,14 @@ impl McpToolProvider for MantraDexMcpServer {
                 self.handle_provide_liquidity_unchecked(arguments).await
             }
             "dex_withdraw_liquidity" => self.handle_withdraw_liquidity(arguments).await,
+            "estimate_transaction_cost" => self.handle_estimate_transaction_cost(arguments).await,
+            "dex_get_pool_depth" => self.handle_get_pool_depth(arguments).await,
+            "dex_zap_in" => self.handle_zap_in(arguments).await,
+            "dex_zap_out" => self.handle_zap_out(arguments).await,
+            "dex_simulate_withdraw_liquidity" => {
+                self.handle_simulate_withdraw_liquidity(arguments).await
+            }
+            "dex_sweep_dust" => self.handle_sweep_dust(arguments).await,
             "dex_create_pool" => self.handle_create_pool(arguments).await,
             "dex_monitor_swap_transaction" => self.handle_monitor_swap_transaction(arguments).await,
             "dex_get_lp_token_balance" => self.handle_get_lp_token_balance(arguments).await,
@@ -3464,16 +6843,44 @@ impl McpToolProvider for MantraDexMcpServer {
             "claimdrop_create_campaign" => self.handle_claimdrop_create_campaign(arguments).await,
             "claimdrop_claim" => self.handle_claimdrop_claim(arguments).await,
             "claimdrop_query_rewards" => self.handle_claimdrop_query_rewards(arguments).await,
+            "claimdrop_get_vesting_schedule" => {
+                self.handle_claimdrop_get_vesting_schedule(arguments).await
+            }
             "claimdrop_query_campaigns" => self.handle_claimdrop_query_campaigns(arguments).await,
+            "claimdrop_get_global_stats" => self.handle_claimdrop_get_global_stats(arguments).await,
+            "claimdrop_export_eligibility_proof" => {
+                self.handle_claimdrop_export_eligibility_proof(arguments)
+                    .await
+            }
+            "claimdrop_verify_eligibility_proof" => {
+                self.handle_claimdrop_verify_eligibility_proof(arguments)
+                    .await
+            }
+            "claimdrop_close_campaign" => self.handle_claimdrop_close_campaign(arguments).await,
+            "claimdrop_withdraw_remaining_funds" => {
+                self.handle_claimdrop_withdraw_remaining_funds(arguments)
+                    .await
+            }
+            "claimdrop_end_of_life_campaign" => {
+                self.handle_claimdrop_end_of_life_campaign(arguments).await
+            }
             "claimdrop_add_allocations" => self.handle_claimdrop_add_allocations(arguments).await,
+            "claimdrop_claim_with_proof" => self.handle_claimdrop_claim_with_proof(arguments).await,
+            "claimdrop_claim_all" => self.handle_claimdrop_claim_all(arguments).await,
+            "claimdrop_build_campaign" => self.handle_claimdrop_build_campaign(arguments).await,
 
             // Skip protocol tools
             "skip_get_route" => self.handle_skip_get_route(arguments).await,
             "skip_execute_transfer" => self.handle_skip_execute_transfer(arguments).await,
             "skip_track_transfer" => self.handle_skip_track_transfer(arguments).await,
+            "skip_watch_transfer" => self.handle_skip_watch_transfer(arguments).await,
             "skip_get_supported_chains" => self.handle_skip_get_supported_chains(arguments).await,
+            "skip_recover_transfer" => self.handle_skip_recover_transfer(arguments).await,
+            "skip_list_chains" => self.handle_skip_list_chains(arguments).await,
+            "skip_list_chain_assets" => self.handle_skip_list_chain_assets(arguments).await,
             "skip_verify_assets" => self.handle_skip_verify_assets(arguments).await,
             "skip_estimate_fees" => self.handle_skip_estimate_fees(arguments).await,
+            "skip_get_affiliate_fees" => self.handle_skip_get_affiliate_fees(arguments).await,
 
             // PrimarySale protocol tools
             #[cfg(feature = "evm")]
@@ -3485,12 +6892,32 @@ impl McpToolProvider for MantraDexMcpServer {
             #[cfg(feature = "evm")]
             "primary_sale_invest" => self.handle_primary_sale_invest(arguments).await,
             #[cfg(feature = "evm")]
+            "primary_sale_approve_and_invest" => {
+                self.handle_primary_sale_approve_and_invest(arguments).await
+            }
+            #[cfg(feature = "evm")]
             "primary_sale_claim_refund" => self.handle_primary_sale_claim_refund(arguments).await,
             #[cfg(feature = "evm")]
             "primary_sale_get_all_investors" => {
                 self.handle_primary_sale_get_all_investors(arguments).await
             }
             #[cfg(feature = "evm")]
+            "primary_sale_check_eligibility" => {
+                self.handle_primary_sale_check_eligibility(arguments).await
+            }
+            #[cfg(feature = "evm")]
+            "primary_sale_export_investors" => {
+                self.handle_primary_sale_export_investors(arguments).await
+            }
+            #[cfg(feature = "evm")]
+            "primary_sale_project_settlement" => {
+                self.handle_primary_sale_project_settlement(arguments).await
+            }
+            #[cfg(feature = "evm")]
+            "restricted_wallets_validate" => {
+                self.handle_restricted_wallets_validate(arguments).await
+            }
+            #[cfg(feature = "evm")]
             "primary_sale_activate" => self.handle_primary_sale_activate(arguments).await,
             #[cfg(feature = "evm")]
             "primary_sale_end_sale" => self.handle_primary_sale_end_sale(arguments).await,
@@ -3522,12 +6949,7 @@ impl McpToolProvider for MantraDexMcpServer {
             _ => Err(McpServerError::UnknownTool(tool_name.to_string())),
         }
     }
-}
-
-// Implement the main McpServer trait that combines all sub-traits
-impl McpServer for MantraDexMcpServer {}
 
-impl MantraDexMcpServer {
     /// Handle get_contract_addresses tool
     async fn handle_get_contract_addresses(
         &self,
@@ -3540,11 +6962,12 @@ impl MantraDexMcpServer {
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
 
-        let contracts = &self.state.config.network_config.contracts;
+        let network_config = self.state.network_config().await;
+        let contracts = &network_config.contracts;
 
         let mut result = serde_json::json!({
-            "network": self.state.config.network_config.network_name,
-            "chain_id": self.state.config.network_config.chain_id,
+            "network": network_config.network_name,
+            "chain_id": network_config.chain_id,
             "contracts": {
                 "pool_manager": contracts.pool_manager
             }
@@ -3585,49 +7008,166 @@ impl MantraDexMcpServer {
 
         // Create formatted response text
         let mut response_text = "📄 **Contract Addresses**\n\n".to_string();
-        response_text.push_str(&format!(
-            "**Network:** {}\n",
-            self.state.config.network_config.network_name
-        ));
-        response_text.push_str(&format!(
-            "**Chain ID:** {}\n\n",
-            self.state.config.network_config.chain_id
-        ));
+        response_text.push_str(&format!("**Network:** {}\n", network_config.network_name));
+        response_text.push_str(&format!("**Chain ID:** {}\n\n", network_config.chain_id));
+
+        response_text.push_str("### 🏗️ Core Contracts:\n\n");
+        response_text.push_str(&format!("**Pool Manager:** `{}`\n", contracts.pool_manager));
+
+        if let Some(farm_manager) = &contracts.farm_manager {
+            response_text.push_str(&format!("**Farm Manager:** `{}`\n", farm_manager));
+        }
+
+        if let Some(fee_collector) = &contracts.fee_collector {
+            response_text.push_str(&format!("**Fee Collector:** `{}`\n", fee_collector));
+        }
+
+        if let Some(epoch_manager) = &contracts.epoch_manager {
+            response_text.push_str(&format!("**Epoch Manager:** `{}`\n", epoch_manager));
+        }
+
+        if include_metadata {
+            response_text.push_str("\n### 📋 Contract Functions:\n\n");
+            response_text.push_str("**Pool Manager:**\n");
+            response_text
+                .push_str("- create_pool, swap, provide_liquidity, withdraw_liquidity\n\n");
+            response_text.push_str("**Farm Manager:**\n");
+            response_text.push_str("- stake_lp_tokens, unstake_lp_tokens, claim_rewards\n\n");
+            response_text.push_str("**Fee Collector:**\n");
+            response_text.push_str("- collect_fees, distribute_fees\n\n");
+            response_text.push_str("**Epoch Manager:**\n");
+            response_text.push_str("- create_epoch, finalize_epoch, claim_epoch_rewards\n");
+        }
+
+        // Return proper MCP response format
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": response_text
+                }
+            ]
+        }))
+    }
+
+    /// Handle get_server_health tool
+    async fn handle_get_server_health(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_server_health tool call");
+        let args: FormatArgs = parse_arguments("get_server_health", &arguments)?;
+        let report = self.check_health().await;
+        tool_result(args.response_format, &report)
+    }
+
+    /// Handle resolve_asset tool
+    async fn handle_resolve_asset(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling resolve_asset tool call");
+        let args: ResolveAssetArgs = parse_arguments("resolve_asset", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .resolve_asset(&args.query, args.chain_id.as_deref())
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle network_node_status tool
+    async fn handle_network_node_status(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling network_node_status tool call");
+
+        let args: FormatArgs = parse_arguments("network_node_status", &arguments)?;
+
+        let result = self.state.sdk_adapter.node_status().await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle network_get_block tool
+    async fn handle_network_get_block(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling network_get_block tool call");
+
+        let args: BlockArgs = parse_arguments("network_get_block", &arguments)?;
+
+        let result = self.state.sdk_adapter.block_summary(args.height).await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle network_get_validators tool
+    async fn handle_network_get_validators(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling network_get_validators tool call");
+
+        let args: ValidatorSetArgs = parse_arguments("network_get_validators", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .validator_set(args.height, args.limit)
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle network_get_chain_params tool
+    async fn handle_network_get_chain_params(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling network_get_chain_params tool call");
+
+        let args: FormatArgs = parse_arguments("network_get_chain_params", &arguments)?;
 
-        response_text.push_str("### 🏗️ Core Contracts:\n\n");
-        response_text.push_str(&format!("**Pool Manager:** `{}`\n", contracts.pool_manager));
+        let result = self.state.sdk_adapter.chain_params().await?;
 
-        if let Some(farm_manager) = &contracts.farm_manager {
-            response_text.push_str(&format!("**Farm Manager:** `{}`\n", farm_manager));
-        }
+        tool_result(args.response_format, &result)
+    }
 
-        if let Some(fee_collector) = &contracts.fee_collector {
-            response_text.push_str(&format!("**Fee Collector:** `{}`\n", fee_collector));
-        }
+    /// Handle network_get_mempool tool
+    async fn handle_network_get_mempool(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling network_get_mempool tool call");
 
-        if let Some(epoch_manager) = &contracts.epoch_manager {
-            response_text.push_str(&format!("**Epoch Manager:** `{}`\n", epoch_manager));
-        }
+        let args: FormatArgs = parse_arguments("network_get_mempool", &arguments)?;
 
-        if include_metadata {
-            response_text.push_str("\n### 📋 Contract Functions:\n\n");
-            response_text.push_str("**Pool Manager:**\n");
-            response_text
-                .push_str("- create_pool, swap, provide_liquidity, withdraw_liquidity\n\n");
-            response_text.push_str("**Farm Manager:**\n");
-            response_text.push_str("- stake_lp_tokens, unstake_lp_tokens, claim_rewards\n\n");
-            response_text.push_str("**Fee Collector:**\n");
-            response_text.push_str("- collect_fees, distribute_fees\n\n");
-            response_text.push_str("**Epoch Manager:**\n");
-            response_text.push_str("- create_epoch, finalize_epoch, claim_epoch_rewards\n");
-        }
+        let result = self.state.sdk_adapter.mempool_status().await?;
 
-        // Return proper MCP response format
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle network_performance tool
+    async fn handle_network_performance(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling network_performance tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .network_performance(arguments)
+            .await?;
+
+        // Format as MCP response
         Ok(serde_json::json!({
             "content": [
                 {
                     "type": "text",
-                    "text": response_text
+                    "text": serde_json::to_string_pretty(&result)?
                 }
             ]
         }))
@@ -3643,6 +7183,8 @@ impl MantraDexMcpServer {
             "Handling validate_network_connectivity tool call"
         );
 
+        let network_config = self.state.network_config().await;
+
         let check_rpc = arguments
             .get("check_rpc")
             .and_then(|v| v.as_bool())
@@ -3682,7 +7224,7 @@ impl MantraDexMcpServer {
 
         // Contract validation check
         if check_contracts {
-            let contracts = &self.state.config.network_config.contracts;
+            let contracts = &network_config.contracts;
             let pool_manager_valid =
                 contracts.pool_manager.starts_with("mantra1") && contracts.pool_manager.len() >= 39;
 
@@ -3711,14 +7253,8 @@ impl MantraDexMcpServer {
 
         // Create formatted response text
         let mut response_text = "🌐 **Network Connectivity Check**\n\n".to_string();
-        response_text.push_str(&format!(
-            "**Network:** {}\n",
-            self.state.config.network_config.network_name
-        ));
-        response_text.push_str(&format!(
-            "**RPC URL:** {}\n",
-            self.state.config.network_config.rpc_url
-        ));
+        response_text.push_str(&format!("**Network:** {}\n", network_config.network_name));
+        response_text.push_str(&format!("**RPC URL:** {}\n", network_config.rpc_url));
         response_text.push_str(&format!(
             "**Overall Status:** {}\n\n",
             if all_passed {
@@ -3758,6 +7294,34 @@ impl MantraDexMcpServer {
         }))
     }
 
+    /// Handle faucet_request tool
+    async fn handle_faucet_request(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling faucet_request tool call");
+
+        let args: FaucetRequestArgs = parse_arguments("faucet_request", &arguments)?;
+        let network_config = self.state.network_config().await;
+        let result = self
+            .state
+            .sdk_adapter
+            .faucet_request(&network_config, args.wallet_address)
+            .await?;
+
+        let response_text =
+            serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string());
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": response_text
+                }
+            ]
+        }))
+    }
+
     /// Handle get_balances tool
     async fn handle_get_balances(
         &self,
@@ -3765,22 +7329,15 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling get_balances tool call");
 
-        // Parse arguments
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let args: GetBalancesArgs = parse_arguments("wallet_get_balances", &arguments)?;
 
-        let include_zero_balances = arguments
-            .get("include_zero_balances")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+        let network_config = self.state.network_config().await;
 
         // Get balances using the SDK adapter
         let result = self
             .state
             .sdk_adapter
-            .get_balances(&self.state.config.network_config, wallet_address)
+            .get_balances(&network_config, args.wallet_address)
             .await?;
 
         // Extract balance data for processing
@@ -3799,7 +7356,7 @@ impl MantraDexMcpServer {
             .unwrap_or("Unknown");
 
         // Filter out zero balances if requested
-        let filtered_balances: Vec<_> = if !include_zero_balances {
+        let filtered_balances: Vec<_> = if !args.include_zero_balances {
             balances_array
                 .iter()
                 .filter(|balance| {
@@ -3816,8 +7373,8 @@ impl MantraDexMcpServer {
         };
 
         // Format balances for human readability - SIMPLIFIED
-        let mut formatted_balances = Vec::new();
-        let mut total_om_value = 0.0;
+        let mut balances = Vec::new();
+        let mut total_om = None;
 
         for balance in &filtered_balances {
             let denom = balance
@@ -3832,68 +7389,37 @@ impl MantraDexMcpServer {
             // Parse amount
             let raw_amount: u128 = amount_str.parse().unwrap_or(0);
 
-            // Simplified formatting
-            let (token_name, formatted_amount) = self.format_token_simple(denom, raw_amount);
+            // Symbol and whole-token amount, as resolved by the adapter
+            let token = balance
+                .get("symbol")
+                .and_then(|s| s.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| crate::amount::symbol_for_denom(denom));
+            let amount = balance
+                .get("formatted_amount")
+                .and_then(|a| a.as_str())
+                .unwrap_or(amount_str)
+                .to_string();
 
-            formatted_balances.push(serde_json::json!({
-                "token": token_name,
-                "amount": formatted_amount,
-                "denom": denom
-            }));
+            balances.push(TokenBalance {
+                token,
+                amount,
+                denom: denom.to_string(),
+            });
 
             // Calculate OM value for estimate
-            if denom == "uom" {
-                total_om_value = (raw_amount as f64) / 1_000_000.0;
-            }
-        }
-
-        // Create simple, clean response
-
-        // Create formatted response text
-        let mut response_text = "🏦 **Wallet Balances**\n\n".to_string();
-        response_text.push_str(&format!("**Address:** `{}`\n", address));
-        response_text.push_str(&format!("**Network:** {}\n", network));
-        response_text.push_str(&format!(
-            "**Total Tokens:** {}\n\n",
-            filtered_balances.len()
-        ));
-
-        if !formatted_balances.is_empty() {
-            response_text.push_str("### 💰 Token Holdings:\n\n");
-            for balance in &formatted_balances {
-                let token = balance
-                    .get("token")
-                    .and_then(|t| t.as_str())
-                    .unwrap_or("Unknown");
-                let amount = balance
-                    .get("amount")
-                    .and_then(|a| a.as_str())
-                    .unwrap_or("0");
-                let denom = balance
-                    .get("denom")
-                    .and_then(|d| d.as_str())
-                    .unwrap_or("unknown");
-                response_text.push_str(&format!("- **{}**: {}\n", token, amount));
-                response_text.push_str(&format!("  - **Full Denom:** `{}`\n", denom));
-            }
-
-            if total_om_value > 0.0 {
-                response_text
-                    .push_str(&format!("\n**Total OM Value:** {:.2} OM\n", total_om_value));
+            if denom == "uom" && raw_amount > 0 {
+                total_om = Some((raw_amount as f64) / 1_000_000.0);
             }
-        } else {
-            response_text.push_str("No tokens found in wallet.\n");
         }
 
-        // Return proper MCP response format
-        Ok(serde_json::json!({
-            "content": [
-                {
-                    "type": "text",
-                    "text": response_text
-                }
-            ]
-        }))
+        let response = WalletBalancesResponse {
+            address: address.to_string(),
+            network: network.to_string(),
+            balances,
+            total_om,
+        };
+        tool_result(args.response_format, &response)
     }
 
     /// Handle list_wallets tool
@@ -3902,6 +7428,7 @@ impl MantraDexMcpServer {
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling list_wallets tool call");
+        let args: FormatArgs = parse_arguments("wallet_list", &arguments)?;
 
         // Get all wallets using the SDK adapter
         let wallets = self.state.sdk_adapter.get_all_wallets().await?;
@@ -3912,47 +7439,18 @@ impl MantraDexMcpServer {
             None => None,
         };
 
-        // Create formatted response text
-        let mut response_text = "📱 **Wallet Management**\n\n".to_string();
-
-        if wallets.is_empty() {
-            response_text.push_str("No wallets found in collection.\n");
-        } else {
-            response_text.push_str(&format!("**Total Wallets:** {}\n", wallets.len()));
-
-            if let Some(active_addr) = &active_address {
-                response_text.push_str(&format!("**Active Wallet:** `{}`\n\n", active_addr));
-            } else {
-                response_text.push_str("**Active Wallet:** None\n\n");
-            }
-
-            response_text.push_str("### 💼 Available Wallets:\n\n");
-
-            for (address, wallet_info) in wallets.iter() {
-                let is_active = active_address.as_ref() == Some(address);
-                let active_indicator = if is_active { " (ACTIVE)" } else { "" };
-
-                response_text.push_str(&format!(
-                    "- **Address:** `{}`{}\n",
-                    address, active_indicator
-                ));
-                response_text.push_str(&format!(
-                    "  - **Public Key:** `{}`\n",
-                    wallet_info.public_key
-                ));
-                response_text.push('\n');
-            }
-        }
-
-        // Return proper MCP response format
-        Ok(serde_json::json!({
-            "content": [
-                {
-                    "type": "text",
-                    "text": response_text
-                }
-            ]
-        }))
+        let response = WalletListResponse {
+            wallets: wallets
+                .iter()
+                .map(|(address, wallet_info)| WalletEntry {
+                    address: address.clone(),
+                    public_key: wallet_info.public_key.clone(),
+                    active: active_address.as_ref() == Some(address),
+                })
+                .collect(),
+            active_wallet: active_address,
+        };
+        tool_result(args.response_format, &response)
     }
 
     /// Handle switch_wallet tool
@@ -3962,13 +7460,8 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling switch_wallet tool call");
 
-        // Parse arguments
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("wallet_address is required".to_string())
-            })?;
+        let args: SwitchWalletArgs = parse_arguments("wallet_switch", &arguments)?;
+        let wallet_address = args.wallet_address.as_str();
 
         // Switch wallet using the SDK adapter
         self.state
@@ -3986,56 +7479,278 @@ impl MantraDexMcpServer {
                 McpServerError::InvalidArguments("Wallet not found after switch".to_string())
             })?;
 
-        // Create formatted response text
-        let mut response_text = "✅ **Wallet Switched Successfully**\n\n".to_string();
-        response_text.push_str(&format!("**New Active Wallet:** `{}`\n", wallet_address));
-        response_text.push_str(&format!("**Public Key:** `{}`\n", wallet_info.public_key));
+        let response = WalletSwitchResponse {
+            active_wallet: wallet_address.to_string(),
+            public_key: wallet_info.public_key,
+        };
+        tool_result(args.response_format, &response)
+    }
 
-        // Return proper MCP response format
+    /// Handle get_active_wallet tool
+    async fn handle_get_active_wallet(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_active_wallet tool call");
+        let args: FormatArgs = parse_arguments("wallet_get_active", &arguments)?;
+
+        // Get active wallet info using the SDK adapter
+        let active_wallet = self.state.sdk_adapter.get_active_wallet_info().await?;
+
+        tool_result(
+            args.response_format,
+            &ActiveWalletResponse { active_wallet },
+        )
+    }
+
+    async fn handle_session_get_context(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling session_get_context tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .get_session_context(arguments)
+            .await?;
+
+        // Format as MCP response
         Ok(serde_json::json!({
             "content": [
                 {
                     "type": "text",
-                    "text": response_text
+                    "text": serde_json::to_string_pretty(&result)?
                 }
             ]
         }))
     }
 
-    /// Handle get_active_wallet tool
-    async fn handle_get_active_wallet(
+    async fn handle_session_set_network(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling session_set_network tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .set_session_network(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    /// Handle switch_network tool
+    async fn handle_switch_network(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling switch_network tool call");
+        let args: SwitchNetworkArgs = parse_arguments("switch_network", &arguments)?;
+        let response = self.state.switch_network(&args.profile).await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle get_multi_network_portfolio tool
+    async fn handle_get_multi_network_portfolio(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_multi_network_portfolio tool call");
+        let args: GetMultiNetworkPortfolioArgs =
+            parse_arguments("get_multi_network_portfolio", &arguments)?;
+        if args.networks.is_empty() {
+            return Err(McpServerError::InvalidArguments(
+                "Give at least one network profile".to_string(),
+            ));
+        }
+        let fanout = NetworkFanout::from_profiles(&self.state.network_profiles(), &args.networks)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?
+            .with_timeout(Duration::from_secs(args.timeout_secs.clamp(1, 120)));
+        let response = self
+            .state
+            .sdk_adapter
+            .multi_network_portfolio(&fanout, args.wallet_address, args.pool_ids)
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle address_book_add tool
+    async fn handle_address_book_add(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling address_book_add tool call");
+        let args: AddressBookAddArgs = parse_arguments("address_book_add", &arguments)?;
+        let response: AddressBookChangeResponse = self
+            .state
+            .sdk_adapter
+            .address_book_add(&args.label, &args.address, args.note)
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle address_book_remove tool
+    async fn handle_address_book_remove(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling address_book_remove tool call");
+        let args: AddressBookRemoveArgs = parse_arguments("address_book_remove", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .address_book_remove(&args.label)
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle address_book_list tool
+    async fn handle_address_book_list(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling address_book_list tool call");
+        let args: FormatArgs = parse_arguments("address_book_list", &arguments)?;
+        let response: AddressBookResponse = self.state.sdk_adapter.address_book_list().await;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle restricted_wallets_add tool
+    async fn handle_restricted_wallets_add(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling restricted_wallets_add tool call");
+        let args: RestrictedWalletsAddArgs = parse_arguments("restricted_wallets_add", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .restricted_wallets_add(&args.address, args.reason, args.source)
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle restricted_wallets_remove tool
+    async fn handle_restricted_wallets_remove(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling restricted_wallets_remove tool call");
+        let args: RestrictedWalletsRemoveArgs =
+            parse_arguments("restricted_wallets_remove", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .restricted_wallets_remove(&args.address)
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle restricted_wallets_import tool
+    async fn handle_restricted_wallets_import(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!("Handling restricted_wallets_import tool call");
+        let args: RestrictedWalletsImportArgs =
+            parse_arguments("restricted_wallets_import", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .restricted_wallets_import(&args.csv, args.source.as_deref())
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle restricted_wallets_list tool
+    async fn handle_restricted_wallets_list(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling restricted_wallets_list tool call");
+        let args: FormatArgs = parse_arguments("restricted_wallets_list", &arguments)?;
+        let response: RestrictedWalletListResponse =
+            self.state.sdk_adapter.restricted_wallets_list().await;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle address_convert tool
+    async fn handle_address_convert(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling address_convert tool call");
+        let args: AddressConvertArgs = parse_arguments("address_convert", &arguments)?;
+        let expected = args
+            .expected_vm
+            .as_deref()
+            .map(str::parse::<AddressKind>)
+            .transpose()
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+        let response = self
+            .state
+            .sdk_adapter
+            .convert_address(&args.address, expected, args.prefix.as_deref())
+            .await?;
+        tool_result(args.response_format, &response)
+    }
+
+    /// Handle get_spending_summary tool
+    async fn handle_get_spending_summary(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling get_active_wallet tool call");
-
-        // Get active wallet info using the SDK adapter
-        let active_wallet = self.state.sdk_adapter.get_active_wallet_info().await?;
-
-        // Create formatted response text
-        let mut response_text = "🔍 **Active Wallet Information**\n\n".to_string();
-
-        match active_wallet {
-            Some(wallet_info) => {
-                response_text.push_str(&format!("**Address:** `{}`\n", wallet_info.address));
-                response_text.push_str(&format!("**Public Key:** `{}`\n", wallet_info.public_key));
-                response_text.push_str("\n**Status:** Active and ready for use\n");
-            }
-            None => {
-                response_text.push_str("**Status:** No active wallet configured\n");
-                response_text.push_str("Please add a wallet using `add_wallet_from_mnemonic` or switch to an existing wallet.\n");
-            }
+        info!(?arguments, "Handling get_spending_summary tool call");
+        let args: GetSpendingSummaryArgs = parse_arguments("get_spending_summary", &arguments)?;
+        let explicit = args.wallet_address.is_some();
+        let wallet = match args.wallet_address {
+            Some(wallet) => Some(wallet),
+            None => self
+                .state
+                .sdk_adapter
+                .get_active_wallet_info()
+                .await
+                .ok()
+                .flatten()
+                .map(|info| info.address),
+        };
+        let mut response = self.state.budget.summaries(wallet.as_deref());
+        if explicit {
+            response
+                .wallets
+                .retain(|summary| Some(&summary.wallet) == wallet.as_ref());
         }
+        tool_result(args.response_format, &response)
+    }
 
-        // Return proper MCP response format
-        Ok(serde_json::json!({
-            "content": [
-                {
-                    "type": "text",
-                    "text": response_text
-                }
-            ]
-        }))
+    /// Handle analyze_wallet_activity tool
+    async fn handle_analyze_wallet_activity(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling analyze_wallet_activity tool call");
+        let args: AnalyzeWalletActivityArgs =
+            parse_arguments("analyze_wallet_activity", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .analyze_wallet_activity(
+                args.wallet_address,
+                args.cosmos_transaction_hashes,
+                args.evm_transaction_hashes,
+                args.limit,
+                args.include_failed,
+            )
+            .await?;
+        tool_result(args.response_format, &response)
     }
 
     /// Handle get_evm_address tool
@@ -4047,33 +7762,20 @@ impl MantraDexMcpServer {
         info!(?arguments, "Handling get_evm_address tool call");
 
         // Get wallet address from arguments or use active wallet
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let args: WalletArgs = parse_arguments("wallet_get_evm_address", &arguments)?;
 
         // Get the EVM address using the SDK adapter
         let (cosmos_address, evm_address) = self
             .state
             .sdk_adapter
-            .get_wallet_evm_address(wallet_address)
+            .get_wallet_evm_address(args.wallet_address)
             .await?;
 
-        // Create formatted response text
-        let mut response_text = "🔍 **Wallet Addresses**\n\n".to_string();
-        response_text.push_str(&format!("**Cosmos Address:** `{}`\n", cosmos_address));
-        response_text.push_str(&format!("**EVM Address:** `{}`\n", evm_address));
-        response_text.push_str("\n*Both addresses are derived from the same private key*\n");
-
-        // Return proper MCP response format
-        Ok(serde_json::json!({
-            "content": [
-                {
-                    "type": "text",
-                    "text": response_text
-                }
-            ]
-        }))
+        let response = WalletAddressesResponse {
+            cosmos_address,
+            evm_address,
+        };
+        tool_result(args.response_format, &response)
     }
 
     #[cfg(not(feature = "evm"))]
@@ -4094,23 +7796,15 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling get_native_evm_balance tool call");
 
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let args: WalletArgs = parse_arguments("wallet_get_native_evm_balance", &arguments)?;
 
         let result = self
             .state
             .sdk_adapter
-            .get_native_evm_balance(wallet_address)
+            .get_native_evm_balance(args.wallet_address)
             .await?;
 
-        Ok(serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": result
-            }]
-        }))
+        tool_result(args.response_format, &result)
     }
 
     /// Handle get_erc20_balance tool
@@ -4121,30 +7815,15 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling get_erc20_balance tool call");
 
-        let token_address = arguments
-            .get("token_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("token_address is required".to_string())
-            })?;
-
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let args: Erc20BalanceArgs = parse_arguments("wallet_get_erc20_balance", &arguments)?;
 
         let result = self
             .state
             .sdk_adapter
-            .get_erc20_balance(token_address, wallet_address)
+            .get_erc20_balance(&args.token_address, args.wallet_address)
             .await?;
 
-        Ok(serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": result
-            }]
-        }))
+        tool_result(args.response_format, &result)
     }
 
     /// Handle get_all_evm_balances tool
@@ -4155,32 +7834,15 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling get_all_evm_balances tool call");
 
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-        let token_addresses = arguments
-            .get("token_addresses")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                    .collect::<Vec<String>>()
-            });
+        let args: EvmBalancesArgs = parse_arguments("wallet_get_all_evm_balances", &arguments)?;
 
         let result = self
             .state
             .sdk_adapter
-            .get_all_evm_balances(wallet_address, token_addresses)
+            .get_all_evm_balances(args.wallet_address, args.token_addresses)
             .await?;
 
-        Ok(serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": result
-            }]
-        }))
+        tool_result(args.response_format, &result)
     }
 
     /// Handle transfer_erc20 tool
@@ -4191,40 +7853,20 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling transfer_erc20 tool call");
 
-        let token_address = arguments
-            .get("token_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("token_address is required".to_string())
-            })?;
-
-        let recipient = arguments
-            .get("recipient")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| McpServerError::InvalidArguments("recipient is required".to_string()))?;
-
-        let amount = arguments
-            .get("amount")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| McpServerError::InvalidArguments("amount is required".to_string()))?;
-
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+        let args: Erc20TransferArgs = parse_arguments("wallet_transfer_erc20", &arguments)?;
 
         let result = self
             .state
             .sdk_adapter
-            .transfer_erc20(token_address, recipient, amount, wallet_address)
+            .transfer_erc20(
+                &args.token_address,
+                &args.recipient,
+                &args.amount,
+                args.wallet_address,
+            )
             .await?;
 
-        Ok(serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": result
-            }]
-        }))
+        tool_result(args.response_format, &result)
     }
 
     /// Handle approve_erc20 tool
@@ -4235,42 +7877,197 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling approve_erc20 tool call");
 
-        let token_address = arguments
-            .get("token_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("token_address is required".to_string())
-            })?;
+        let args: Erc20ApproveArgs = parse_arguments("wallet_approve_erc20", &arguments)?;
 
-        let spender = arguments
-            .get("spender")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| McpServerError::InvalidArguments("spender is required".to_string()))?;
+        let result = self
+            .state
+            .sdk_adapter
+            .approve_erc20(
+                &args.token_address,
+                &args.spender,
+                &args.amount,
+                args.wallet_address,
+            )
+            .await?;
 
-        let amount = arguments
-            .get("amount")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| McpServerError::InvalidArguments("amount is required".to_string()))?;
+        tool_result(args.response_format, &result)
+    }
 
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+    /// Handle erc20_get_allowances tool
+    #[cfg(feature = "evm")]
+    async fn handle_erc20_get_allowances(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling erc20_get_allowances tool call");
+
+        let args: Erc20AllowancesArgs = parse_arguments("erc20_get_allowances", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .erc20_get_allowances(
+                args.token_address.as_deref(),
+                args.wallet_address,
+                args.from_block,
+                args.include_zero,
+            )
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle erc20_revoke_approval tool
+    #[cfg(feature = "evm")]
+    async fn handle_erc20_revoke_approval(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling erc20_revoke_approval tool call");
+
+        let args: Erc20RevokeArgs = parse_arguments("erc20_revoke_approval", &arguments)?;
 
         let result = self
             .state
             .sdk_adapter
-            .approve_erc20(token_address, spender, amount, wallet_address)
+            .erc20_revoke_approval(&args.token_address, &args.spender, args.wallet_address)
             .await?;
 
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle evm_deploy_erc20 tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_deploy_erc20(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_deploy_erc20 tool call");
+        let result = self.state.sdk_adapter.evm_deploy_erc20(arguments).await?;
+
+        let response_text =
+            serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string());
+
         Ok(serde_json::json!({
-            "content": [{
-                "type": "text",
-                "text": result
-            }]
+            "content": [
+                {
+                    "type": "text",
+                    "text": response_text
+                }
+            ]
         }))
     }
 
+    /// Handle evm_get_block tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_get_block(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_get_block tool call");
+
+        let args: EvmGetBlockArgs = parse_arguments("evm_get_block", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .evm_get_block(args.block.as_deref(), args.full_transactions)
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle evm_get_transaction tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_get_transaction(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_get_transaction tool call");
+
+        let args: EvmGetTransactionArgs = parse_arguments("evm_get_transaction", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .evm_get_transaction(&args.tx_hash)
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle evm_get_code tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_get_code(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_get_code tool call");
+
+        let args: EvmGetCodeArgs = parse_arguments("evm_get_code", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .evm_get_code(&args.address, args.block.as_deref())
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle evm_inspect_contract tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_inspect_contract(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_inspect_contract tool call");
+
+        let args: EvmInspectContractArgs = parse_arguments("evm_inspect_contract", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .evm_inspect_contract(&args.address)
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle evm_get_storage_at tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_get_storage_at(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_get_storage_at tool call");
+
+        let args: EvmGetStorageAtArgs = parse_arguments("evm_get_storage_at", &arguments)?;
+
+        let result = self
+            .state
+            .sdk_adapter
+            .evm_get_storage_at(&args.address, &args.slot, args.block.as_deref())
+            .await?;
+
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle evm_chain_info tool
+    #[cfg(feature = "evm")]
+    async fn handle_evm_chain_info(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling evm_chain_info tool call");
+
+        let args: FormatArgs = parse_arguments("evm_chain_info", &arguments)?;
+
+        let result = self.state.sdk_adapter.evm_chain_info().await?;
+
+        tool_result(args.response_format, &result)
+    }
+
     /// Handle add_wallet_from_mnemonic tool
     async fn handle_add_wallet_from_mnemonic(
         &self,
@@ -4278,30 +8075,23 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling add_wallet_from_mnemonic tool call");
 
-        // Parse arguments
-        let mnemonic = arguments
-            .get("mnemonic")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| McpServerError::InvalidArguments("mnemonic is required".to_string()))?;
-
-        let derivation_index = arguments
-            .get("derivation_index")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(0) as u32;
-
-        let set_as_active = arguments
-            .get("set_as_active")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(true);
+        let args: AddWalletArgs = parse_arguments("wallet_add_from_mnemonic", &arguments)?;
+        let derivation_index = args.derivation_index;
+        let set_as_active = args.set_as_active;
+        let options = args.derivation_options();
 
         // Create wallet from mnemonic
-        let wallet = crate::wallet::MantraWallet::from_mnemonic(mnemonic, derivation_index)
-            .map_err(|e| {
-                McpServerError::InvalidArguments(format!(
-                    "Failed to create wallet from mnemonic: {}",
-                    e
-                ))
-            })?;
+        let wallet = crate::wallet::MantraWallet::from_mnemonic_with_options(
+            &args.mnemonic,
+            derivation_index,
+            &options,
+        )
+        .map_err(|e| {
+            McpServerError::InvalidArguments(format!(
+                "Failed to create wallet from mnemonic: {}",
+                e
+            ))
+        })?;
 
         let wallet_info = wallet.info();
         let wallet_address = wallet_info.address.clone();
@@ -4309,7 +8099,7 @@ impl MantraDexMcpServer {
         // Add wallet using the SDK adapter with derivation index for caching
         self.state
             .sdk_adapter
-            .add_wallet_with_derivation_index(wallet, derivation_index)
+            .add_wallet_with_derivation_options(wallet, derivation_index, options)
             .await?;
 
         // Set as active wallet if requested
@@ -4320,25 +8110,34 @@ impl MantraDexMcpServer {
                 .await?;
         }
 
-        // Create formatted response text
-        let mut response_text = "✅ **Wallet Added Successfully**\n\n".to_string();
-        response_text.push_str(&format!("**Address:** `{}`\n", wallet_address));
-        response_text.push_str(&format!("**Public Key:** `{}`\n", wallet_info.public_key));
-        response_text.push_str(&format!("**Derivation Index:** {}\n", derivation_index));
-        response_text.push_str(&format!(
-            "**Set as Active:** {}\n",
-            if set_as_active { "Yes" } else { "No" }
-        ));
+        let response = WalletAddedResponse {
+            address: wallet_address,
+            public_key: wallet_info.public_key,
+            derivation_index,
+            active: set_as_active,
+        };
+        tool_result(args.response_format, &response)
+    }
 
-        // Return proper MCP response format
-        Ok(serde_json::json!({
-            "content": [
-                {
-                    "type": "text",
-                    "text": response_text
-                }
-            ]
-        }))
+    /// Handle wallet_discover tool
+    async fn handle_discover_wallets(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!("Handling wallet_discover tool call");
+
+        let args: DiscoverWalletsArgs = parse_arguments("wallet_discover", &arguments)?;
+        let response = self
+            .state
+            .sdk_adapter
+            .discover_wallets(
+                &args.mnemonic,
+                args.derivation_options(),
+                args.gap_limit,
+                args.import,
+            )
+            .await?;
+        tool_result(args.response_format, &response)
     }
 
     /// Handle remove_wallet tool
@@ -4348,13 +8147,8 @@ impl MantraDexMcpServer {
     ) -> McpResult<serde_json::Value> {
         info!(?arguments, "Handling remove_wallet tool call");
 
-        // Parse arguments
-        let wallet_address = arguments
-            .get("wallet_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                McpServerError::InvalidArguments("wallet_address is required".to_string())
-            })?;
+        let args: RemoveWalletArgs = parse_arguments("wallet_remove", &arguments)?;
+        let wallet_address = args.wallet_address.as_str();
 
         // Get wallet info before removal
         let wallet_info = self
@@ -4366,61 +8160,18 @@ impl MantraDexMcpServer {
         // Check if this is the active wallet
         let was_active = match self.state.sdk_adapter.get_active_wallet_info().await? {
             Some(active_info) => active_info.address == wallet_address,
-            None => false,
-        };
-
-        // Remove wallet using the SDK adapter
-        self.state.sdk_adapter.remove_wallet(wallet_address).await?;
-
-        // Create formatted response text
-        let mut response_text = "✅ **Wallet Removed Successfully**\n\n".to_string();
-        response_text.push_str(&format!("**Removed Address:** `{}`\n", wallet_address));
-
-        if let Some(info) = wallet_info {
-            response_text.push_str(&format!("**Public Key:** `{}`\n", info.public_key));
-        }
-
-        if was_active {
-            response_text.push_str("\n**Note:** This was the active wallet. You'll need to switch to another wallet or add a new one.\n");
-        }
-
-        // Return proper MCP response format
-        Ok(serde_json::json!({
-            "content": [
-                {
-                    "type": "text",
-                    "text": response_text
-                }
-            ]
-        }))
-    }
+            None => false,
+        };
 
-    /// Simplified token formatting without emojis or complex logic
-    fn format_token_simple(&self, denom: &str, raw_amount: u128) -> (String, String) {
-        match denom {
-            "uom" => {
-                let om_amount = (raw_amount as f64) / 1_000_000.0;
-                ("OM".to_string(), format!("{:.6}", om_amount))
-            }
-            d if d.contains("/uUSDC") => {
-                let usdc_amount = (raw_amount as f64) / 1_000_000.0;
-                ("USDC".to_string(), format!("{:.6}", usdc_amount))
-            }
-            d if d.contains("/uUSDY") => {
-                let usdy_amount = (raw_amount as f64) / 1_000_000.0;
-                ("USDY".to_string(), format!("{:.6}", usdy_amount))
-            }
-            d if d.contains("/aUSDY") => ("aUSDY".to_string(), raw_amount.to_string()),
-            d if d.contains(".LP") => {
-                let lp_amount = (raw_amount as f64) / 1_000_000.0;
-                ("LP Token".to_string(), format!("{:.6}", lp_amount))
-            }
-            d if d.starts_with("ibc/") => {
-                let ibc_amount = (raw_amount as f64) / 1_000_000.0;
-                ("USDT".to_string(), format!("{:.6}", ibc_amount))
-            }
-            _ => ("Unknown Token".to_string(), raw_amount.to_string()),
-        }
+        // Remove wallet using the SDK adapter
+        self.state.sdk_adapter.remove_wallet(wallet_address).await?;
+
+        let response = WalletRemovedResponse {
+            address: wallet_address.to_string(),
+            public_key: wallet_info.map(|info| info.public_key),
+            was_active,
+        };
+        tool_result(args.response_format, &response)
     }
 
     /// Handle get_pools tool
@@ -4448,7 +8199,7 @@ impl MantraDexMcpServer {
             .and_then(|p| p.as_array())
             .unwrap_or(&empty_vec);
         let count = result.get("count").and_then(|c| c.as_u64()).unwrap_or(0);
-        let network = &self.state.config.network_config.network_name;
+        let network = self.state.network_config().await.network_name;
 
         // Create formatted response text
         let mut response_text = "🏊 **Liquidity Pools**\n\n".to_string();
@@ -4501,104 +8252,410 @@ impl MantraDexMcpServer {
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false);
 
-                response_text.push_str(&format!(
-                    "**{}. Pool {}** ({})\n",
-                    i + 1,
-                    pool_id,
-                    pool_type
-                ));
+                response_text.push_str(&format!(
+                    "**{}. Pool {}** ({})\n",
+                    i + 1,
+                    pool_id,
+                    pool_type
+                ));
+
+                // Show assets
+                if let Some(assets) = pool.get("assets").and_then(|a| a.as_array()) {
+                    response_text.push_str("   **Assets:**\n");
+                    for asset in assets {
+                        let denom = asset
+                            .get("denom")
+                            .and_then(|d| d.as_str())
+                            .unwrap_or("Unknown");
+                        let amount = asset.get("amount").and_then(|a| a.as_str()).unwrap_or("0");
+
+                        // Format asset name
+                        let asset_name = if denom == "uom" {
+                            "OM".to_string()
+                        } else if denom.contains("/uUSDC") {
+                            "USDC".to_string()
+                        } else if denom.contains("/uUSDY") {
+                            "USDY".to_string()
+                        } else if denom.contains("/aUSDY") {
+                            "aUSDY".to_string()
+                        } else if denom.starts_with("ibc/") {
+                            "USDT".to_string()
+                        } else {
+                            denom.to_string()
+                        };
+
+                        // Format amount
+                        let formatted_amount = if denom == "uom"
+                            || denom.contains("/uUSDC")
+                            || denom.contains("/uUSDY")
+                        {
+                            let amount_num: u128 = amount.parse().unwrap_or(0);
+                            format!("{:.2}", (amount_num as f64) / 1_000_000.0)
+                        } else {
+                            amount.to_string()
+                        };
+
+                        response_text
+                            .push_str(&format!("     - {}: {}\n", asset_name, formatted_amount));
+                    }
+                }
+
+                // Show status
+                response_text.push_str("   **Status:**");
+                if swaps_enabled && deposits_enabled && withdrawals_enabled {
+                    response_text.push_str(" ✅ Fully Operational\n");
+                } else {
+                    response_text.push_str(" ⚠️ Limited Operations (");
+                    let mut ops = Vec::new();
+                    if swaps_enabled {
+                        ops.push("Swaps");
+                    }
+                    if deposits_enabled {
+                        ops.push("Deposits");
+                    }
+                    if withdrawals_enabled {
+                        ops.push("Withdrawals");
+                    }
+                    response_text.push_str(&ops.join(", "));
+                    response_text.push_str(")\n");
+                }
+
+                // Show LP token info
+                response_text.push_str(&format!("   **LP Token:** `{}`\n", lp_denom));
+                response_text.push_str(&format!("   **Total Shares:** {}\n\n", total_share));
+            }
+        } else {
+            response_text.push_str("No pools found matching the criteria.\n");
+        }
+
+        response_text.push_str(&format!(
+            "**Query Time:** {}\n",
+            chrono::Utc::now().to_rfc3339()
+        ));
+
+        // Return proper MCP response format
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": response_text
+                }
+            ]
+        }))
+    }
+
+    async fn handle_execute_swap(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling execute_swap tool call");
+        let result = self.state.sdk_adapter.execute_swap(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_provide_liquidity(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling provide_liquidity tool call");
+        let result = self.state.sdk_adapter.provide_liquidity(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_provide_liquidity_unchecked(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling provide_liquidity_unchecked tool call");
+        self.state
+            .sdk_adapter
+            .provide_liquidity_unchecked(arguments)
+            .await
+    }
+
+    async fn handle_withdraw_liquidity(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling withdraw_liquidity tool call");
+        let result = self.state.sdk_adapter.withdraw_liquidity(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_estimate_transaction_cost(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling estimate_transaction_cost tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .estimate_transaction_cost(arguments)
+            .await?;
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_get_pool_depth(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_pool_depth tool call");
+        let result = self.state.sdk_adapter.get_pool_depth(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_zap_in(&self, arguments: serde_json::Value) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling zap_in tool call");
+        let result = self.state.sdk_adapter.zap_in(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_zap_out(&self, arguments: serde_json::Value) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling zap_out tool call");
+        let result = self.state.sdk_adapter.zap_out(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_simulate_withdraw_liquidity(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling simulate_withdraw_liquidity tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .simulate_withdraw_liquidity(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_state_audit_log(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling state_audit_log tool call");
+        let result = self.state.sdk_adapter.state_audit(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_get_recent_traces(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_recent_traces tool call");
+        let mut filters = arguments;
+        if let Some(map) = filters.as_object_mut() {
+            map.remove("_meta");
+        }
+        let query: TraceQuery = serde_json::from_value(filters)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+        let traces = self.state.traces.recent(&query);
+        let result = serde_json::json!({
+            "count": traces.len(),
+            "traces": traces,
+            "capacity": self.state.traces.capacity(),
+        });
 
-                // Show assets
-                if let Some(assets) = pool.get("assets").and_then(|a| a.as_array()) {
-                    response_text.push_str("   **Assets:**\n");
-                    for asset in assets {
-                        let denom = asset
-                            .get("denom")
-                            .and_then(|d| d.as_str())
-                            .unwrap_or("Unknown");
-                        let amount = asset.get("amount").and_then(|a| a.as_str()).unwrap_or("0");
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
 
-                        // Format asset name
-                        let asset_name = if denom == "uom" {
-                            "OM".to_string()
-                        } else if denom.contains("/uUSDC") {
-                            "USDC".to_string()
-                        } else if denom.contains("/uUSDY") {
-                            "USDY".to_string()
-                        } else if denom.contains("/aUSDY") {
-                            "aUSDY".to_string()
-                        } else if denom.starts_with("ibc/") {
-                            "USDT".to_string()
-                        } else {
-                            denom.to_string()
-                        };
+    async fn handle_watch_chain_events(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        use futures::StreamExt;
 
-                        // Format amount
-                        let formatted_amount = if denom == "uom"
-                            || denom.contains("/uUSDC")
-                            || denom.contains("/uUSDY")
-                        {
-                            let amount_num: u128 = amount.parse().unwrap_or(0);
-                            format!("{:.2}", (amount_num as f64) / 1_000_000.0)
-                        } else {
-                            amount.to_string()
-                        };
+        info!(?arguments, "Handling watch_chain_events tool call");
+        let args: WatchChainEventsArgs = parse_arguments("watch_chain_events", &arguments)?;
+        let network_config = self.state.network_config().await;
 
-                        response_text
-                            .push_str(&format!("     - {}: {}\n", asset_name, formatted_amount));
-                    }
-                }
+        let mut filter = EventFilter::new();
+        if args.pool_manager {
+            filter = filter.pool_manager(network_config.contracts.pool_manager.clone());
+        }
+        for campaign in &args.claimdrop_campaigns {
+            filter = filter.claimdrop(campaign.clone());
+        }
+        for contract in &args.contracts {
+            filter = filter.contract(contract.clone(), CosmosProtocol::Other);
+        }
+        if filter.contracts().is_empty() {
+            return Err(McpServerError::InvalidArguments(
+                "watch_chain_events needs at least one contract to watch".to_string(),
+            ));
+        }
+        let watched: Vec<String> = filter
+            .contracts()
+            .iter()
+            .map(|(address, _)| address.clone())
+            .collect();
 
-                // Show status
-                response_text.push_str("   **Status:**");
-                if swaps_enabled && deposits_enabled && withdrawals_enabled {
-                    response_text.push_str(" ✅ Fully Operational\n");
-                } else {
-                    response_text.push_str(" ⚠️ Limited Operations (");
-                    let mut ops = Vec::new();
-                    if swaps_enabled {
-                        ops.push("Swaps");
-                    }
-                    if deposits_enabled {
-                        ops.push("Deposits");
-                    }
-                    if withdrawals_enabled {
-                        ops.push("Withdrawals");
-                    }
-                    response_text.push_str(&ops.join(", "));
-                    response_text.push_str(")\n");
-                }
+        let subscriber = EventSubscriber::connect(&network_config.rpc_url)
+            .await
+            .map_err(McpServerError::Sdk)?;
+        let mut stream = Box::pin(
+            subscriber
+                .subscribe(filter)
+                .await
+                .map_err(McpServerError::Sdk)?,
+        );
 
-                // Show LP token info
-                response_text.push_str(&format!("   **LP Token:** `{}`\n", lp_denom));
-                response_text.push_str(&format!("   **Total Shares:** {}\n\n", total_share));
+        let deadline = sleep(Duration::from_secs(args.duration_secs));
+        tokio::pin!(deadline);
+        let mut events = Vec::new();
+        let ended_by = loop {
+            tokio::select! {
+                _ = &mut deadline => break "timeout".to_string(),
+                next = stream.next() => match next {
+                    Some(Ok(event)) => {
+                        let value = serde_json::to_value(&event)?;
+                        events.push(value.clone());
+                        report_partial_result(
+                            events.len() as u64,
+                            Some(args.max_events as u64),
+                            &event.summary(),
+                            value,
+                        );
+                        if events.len() >= args.max_events {
+                            break "max_events".to_string();
+                        }
+                    }
+                    Some(Err(e)) => break format!("error: {}", e),
+                    None => break "closed".to_string(),
+                },
             }
-        } else {
-            response_text.push_str("No pools found matching the criteria.\n");
+        };
+        drop(stream);
+        if let Err(e) = subscriber.close().await {
+            warn!("Failed to close event subscription: {}", e);
         }
 
-        response_text.push_str(&format!(
-            "**Query Time:** {}\n",
-            chrono::Utc::now().to_rfc3339()
-        ));
+        let result = serde_json::json!({
+            "network": network_config.chain_id,
+            "watched": watched,
+            "duration_secs": args.duration_secs,
+            "count": events.len(),
+            "events": events,
+            "ended_by": ended_by,
+        });
 
-        // Return proper MCP response format
+        // Format as MCP response
         Ok(serde_json::json!({
             "content": [
                 {
                     "type": "text",
-                    "text": response_text
+                    "text": serde_json::to_string_pretty(&result)?
                 }
             ]
         }))
     }
 
-    async fn handle_execute_swap(
+    async fn handle_get_signing_history(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling execute_swap tool call");
-        let result = self.state.sdk_adapter.execute_swap(arguments).await?;
+        info!(?arguments, "Handling get_signing_history tool call");
+        let mut filters = arguments;
+        if let Some(map) = filters.as_object_mut() {
+            map.remove("_meta");
+        }
+        let query: SigningQuery = serde_json::from_value(filters)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+        let log = SigningLog::global();
+        let records = log.history(&query);
+        let result = serde_json::json!({
+            "count": records.len(),
+            "records": records,
+            "log": log.snapshot(),
+        });
 
         // Format as MCP response
         Ok(serde_json::json!({
@@ -4611,12 +8668,23 @@ impl MantraDexMcpServer {
         }))
     }
 
-    async fn handle_provide_liquidity(
+    async fn handle_get_audit_log(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling provide_liquidity tool call");
-        let result = self.state.sdk_adapter.provide_liquidity(arguments).await?;
+        info!(?arguments, "Handling get_audit_log tool call");
+        let mut filters = arguments;
+        if let Some(map) = filters.as_object_mut() {
+            map.remove("_meta");
+        }
+        let query: AuditQuery = serde_json::from_value(filters)
+            .map_err(|e| McpServerError::InvalidArguments(e.to_string()))?;
+        let records = self.state.audit_log.query(&query);
+        let result = serde_json::json!({
+            "count": records.len(),
+            "records": records,
+            "log": self.state.audit_log.snapshot(),
+        });
 
         // Format as MCP response
         Ok(serde_json::json!({
@@ -4629,23 +8697,117 @@ impl MantraDexMcpServer {
         }))
     }
 
-    async fn handle_provide_liquidity_unchecked(
+    async fn handle_list_pending_transactions(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling provide_liquidity_unchecked tool call");
-        self.state
-            .sdk_adapter
-            .provide_liquidity_unchecked(arguments)
-            .await
+        info!(?arguments, "Handling list_pending_transactions tool call");
+        let transactions = self.state.transaction_queue.list();
+        let result = serde_json::json!({
+            "count": transactions.len(),
+            "transactions": transactions,
+        });
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
     }
 
-    async fn handle_withdraw_liquidity(
+    async fn handle_list_queued_transactions(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling withdraw_liquidity tool call");
-        let result = self.state.sdk_adapter.withdraw_liquidity(arguments).await?;
+        info!(?arguments, "Handling list_queued_transactions tool call");
+        let unfinished_only = arguments
+            .get("unfinished_only")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let result = self.state.sdk_adapter.queued_transactions(unfinished_only);
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_reject_transaction(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling reject_transaction tool call");
+        let id = arguments
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| McpServerError::InvalidArguments("id is required".to_string()))?;
+        let reason = arguments
+            .get("reason")
+            .and_then(|v| v.as_str())
+            .unwrap_or("rejected without a reason");
+        let rejected_by = principal_from_arguments(&arguments);
+        let proposal = self
+            .state
+            .transaction_queue
+            .take(id, rejected_by.as_deref())?;
+
+        let mut audited_arguments = proposal.arguments;
+        if let Some(map) = audited_arguments.as_object_mut() {
+            map.insert(
+                "_meta".to_string(),
+                serde_json::json!({
+                    "session_id": proposal.session_id,
+                    "principal": rejected_by,
+                }),
+            );
+        }
+        let rejection: McpResult<Value> = Err(McpServerError::ApprovalRejected(format!(
+            "Queued transaction {} rejected: {}",
+            proposal.id, reason
+        )));
+        McpSdkAdapter::in_session(
+            proposal.session_id.clone(),
+            self.audit_tool_call(
+                &proposal.tool,
+                &audited_arguments,
+                &rejection,
+                Duration::ZERO,
+            ),
+        )
+        .await;
+
+        let result = serde_json::json!({
+            "status": "rejected",
+            "id": proposal.id,
+            "tool": proposal.tool,
+            "reason": reason,
+        });
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_sweep_dust(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling sweep_dust tool call");
+        let result = self.state.sdk_adapter.sweep_dust(arguments).await?;
 
         // Format as MCP response
         Ok(serde_json::json!({
@@ -4811,6 +8973,7 @@ impl MantraDexMcpServer {
 pub async fn create_mcp_server(config: McpServerConfig) -> McpResult<MantraDexMcpServer> {
     let server = MantraDexMcpServer::new(config);
     server.initialize().await?;
+    spawn_remote_control(server.state())?;
     Ok(server)
 }
 
@@ -4839,11 +9002,98 @@ struct HttpJsonRpcRequest {
 }
 
 /// HTTP handler for JSON-RPC requests
+///
+/// The response body is encoded according to the request's `Accept` header
+/// (JSON, MessagePack or CBOR). Tool calls from clients accepting
+/// `text/event-stream` are answered with an event stream instead.
 async fn handle_jsonrpc_request(
     State(server): State<Arc<MantraDexMcpServer>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(request): Json<HttpJsonRpcRequest>,
-) -> Result<Json<JsonRpcResponse>, StatusCode> {
+) -> Response {
     debug!("HTTP JSON-RPC request: {:?}", request);
+    let encoding = ResponseEncoding::from_headers(&headers);
+
+    // The permit holds the request's in-flight slot until the response is built
+    let limiter = &server.state.rate_limiter;
+    let client = limiter.client_key(
+        peer.ip(),
+        headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok()),
+    );
+    let cost = limiter
+        .config()
+        .request_cost(&request.method, request.params.as_ref());
+    let permit = match limiter.admit(&client, cost) {
+        Ok(permit) => permit,
+        Err(throttled) => {
+            warn!(
+                client = %client,
+                method = %request.method,
+                "Throttled HTTP request: {}",
+                throttled
+            );
+            return throttled_response(encoding, request.id, &throttled);
+        }
+    };
+
+    let mut request = request;
+    attach_session_header(&headers, &mut request);
+
+    let authenticator = &server.state.authenticator;
+    if !authenticator.is_enabled() {
+        attach_principal(None, request.params.as_mut());
+    } else {
+        let authorization = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+        let api_key = headers
+            .get("x-api-key")
+            .and_then(|value| value.to_str().ok());
+        let now_secs = chrono::Utc::now().timestamp().max(0) as u64;
+        let principal = match authenticator.authenticate(authorization, api_key, now_secs) {
+            Ok(principal) => principal,
+            Err(reason) => {
+                warn!(client = %client, "Rejected HTTP request: {}", reason);
+                return http_error_response(
+                    encoding,
+                    request.id,
+                    StatusCode::UNAUTHORIZED,
+                    McpServerError::AuthenticationFailed(reason),
+                );
+            }
+        };
+
+        if let Some(tool) = tool_call_name(&request) {
+            if !principal.may_call(&tool) {
+                warn!(
+                    principal = %principal.name,
+                    tool = %tool,
+                    "Refused tool outside key permissions"
+                );
+                let error = McpServerError::AuthorizationDenied(format!(
+                    "'{}' may not call tool '{}'",
+                    principal.name, tool
+                ));
+                return http_error_response(encoding, request.id, StatusCode::FORBIDDEN, error);
+            }
+            info!(
+                target: "mcp_audit",
+                principal = %principal.name,
+                method = ?principal.method,
+                client = %client,
+                tool = %tool,
+                "Authenticated tool call"
+            );
+        }
+        attach_principal(Some(&principal), request.params.as_mut());
+    }
+
+    if request.method == "tools/call" && accepts_event_stream(&headers) {
+        return stream_jsonrpc_request(server, request, permit);
+    }
 
     // Convert HTTP JSON-RPC to MCP format and process
     let response = match process_mcp_request(&server, &request).await {
@@ -4851,8 +9101,248 @@ async fn handle_jsonrpc_request(
         Err(error) => JsonRpcResponse::error(request.id.clone(), error.to_json_rpc_error()),
     };
 
-    debug!("HTTP JSON-RPC response: {:?}", response);
-    Ok(Json(response))
+    debug!("HTTP JSON-RPC response ({:?}): {:?}", encoding, response);
+    encoding.into_response(&response)
+}
+
+/// Whether the `Accept` header lists `text/event-stream`
+fn accepts_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get(ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| {
+            accept.split(',').any(|range| {
+                range.split(';').next().is_some_and(|media_type| {
+                    media_type.trim().eq_ignore_ascii_case("text/event-stream")
+                })
+            })
+        })
+}
+
+/// Answer a request with a Server-Sent Events stream
+///
+/// Progress notifications are sent as `message` events while the request runs,
+/// followed by the JSON-RPC response, after which the stream ends. The request
+/// keeps its in-flight slot until then.
+fn stream_jsonrpc_request(
+    server: Arc<MantraDexMcpServer>,
+    request: HttpJsonRpcRequest,
+    permit: RequestPermit,
+) -> Response {
+    let (sink, messages) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let _permit = permit;
+        let processing = process_mcp_request(&server, &request);
+        let result = match progress_token(request.params.as_ref()) {
+            Some(token) => {
+                ProgressReporter::new(token, sink.clone())
+                    .scope(processing)
+                    .await
+            }
+            None => processing.await,
+        };
+        let response = match result {
+            Ok(result) => JsonRpcResponse::success(request.id.clone(), result),
+            Err(error) => JsonRpcResponse::error(request.id.clone(), error.to_json_rpc_error()),
+        };
+        match serde_json::to_value(&response) {
+            Ok(response) => {
+                let _ = sink.send(response);
+            }
+            Err(e) => error!("Failed to serialize streamed response: {}", e),
+        }
+    });
+
+    let events = futures::stream::unfold(messages, |mut messages| async move {
+        let message = messages.recv().await?;
+        Some((
+            Event::default().event("message").json_data(message),
+            messages,
+        ))
+    });
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Check the credentials of a request to an HTTP endpoint outside JSON-RPC
+fn authenticate_headers(server: &MantraDexMcpServer, headers: &HeaderMap) -> Result<(), String> {
+    let authenticator = &server.state.authenticator;
+    if !authenticator.is_enabled() {
+        return Ok(());
+    }
+    let authorization = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let api_key = headers
+        .get("x-api-key")
+        .and_then(|value| value.to_str().ok());
+    let now_secs = chrono::Utc::now().timestamp().max(0) as u64;
+    authenticator
+        .authenticate(authorization, api_key, now_secs)
+        .map(|_| ())
+}
+
+/// Serve the Prometheus metrics
+///
+/// With authentication on, scrapers present credentials like JSON-RPC clients.
+async fn handle_metrics_request(
+    State(server): State<Arc<MantraDexMcpServer>>,
+    headers: HeaderMap,
+) -> Response {
+    if let Err(reason) = authenticate_headers(&server, &headers) {
+        warn!("Rejected metrics scrape: {}", reason);
+        return (StatusCode::UNAUTHORIZED, reason).into_response();
+    }
+
+    (
+        [(CONTENT_TYPE, HeaderValue::from_static(METRICS_CONTENT_TYPE))],
+        server.render_metrics().await,
+    )
+        .into_response()
+}
+
+/// Liveness probe: `503` once a critical component is down
+async fn handle_healthz_request(
+    State(server): State<Arc<MantraDexMcpServer>>,
+    headers: HeaderMap,
+) -> Response {
+    let report = server.check_health().await;
+    health_response(&server, &headers, report.is_alive(), &report)
+}
+
+/// Readiness probe: `503` unless every critical component is ok
+async fn handle_readyz_request(
+    State(server): State<Arc<MantraDexMcpServer>>,
+    headers: HeaderMap,
+) -> Response {
+    let report = server.check_health().await;
+    health_response(&server, &headers, report.ready, &report)
+}
+
+/// Health probe response; component details (endpoint errors, the wallet
+/// address) are only shown to authenticated callers
+fn health_response(
+    server: &MantraDexMcpServer,
+    headers: &HeaderMap,
+    passing: bool,
+    report: &HealthReport,
+) -> Response {
+    let status = if passing {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let body = if authenticate_headers(server, headers).is_ok() {
+        serde_json::to_value(report).unwrap_or_default()
+    } else {
+        serde_json::json!({
+            "status": report.status,
+            "ready": report.ready,
+            "timestamp": report.timestamp
+        })
+    };
+    (status, Json(body)).into_response()
+}
+
+/// HTTP error `status` carrying `error` as a JSON-RPC error
+fn http_error_response(
+    encoding: ResponseEncoding,
+    id: Option<Value>,
+    status: StatusCode,
+    error: McpServerError,
+) -> Response {
+    let mut response =
+        encoding.into_response(&JsonRpcResponse::error(id, error.to_json_rpc_error()));
+    *response.status_mut() = status;
+    response
+}
+
+/// `429 Too Many Requests` carrying a JSON-RPC quota error and `Retry-After`
+fn throttled_response(
+    encoding: ResponseEncoding,
+    id: Option<Value>,
+    throttled: &Throttled,
+) -> Response {
+    let mut response = http_error_response(
+        encoding,
+        id,
+        StatusCode::TOO_MANY_REQUESTS,
+        McpServerError::QuotaExceeded(throttled.to_string()),
+    );
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(throttled.retry_after_secs()));
+    response
+}
+
+/// Name of the tool a `tools/call` request calls
+fn tool_call_name(request: &HttpJsonRpcRequest) -> Option<String> {
+    if request.method != "tools/call" {
+        return None;
+    }
+    request
+        .params
+        .as_ref()
+        .and_then(|params| params.get("name"))
+        .and_then(|name| name.as_str())
+        .map(str::to_string)
+}
+
+/// Record the authenticated caller in `params._meta.principal`
+///
+/// Any principal the client put there itself is overwritten, or removed when
+/// the request is unauthenticated; [`merge_request_meta`] then replaces the one
+/// in `arguments._meta`.
+fn attach_principal(principal: Option<&Principal>, params: Option<&mut Value>) {
+    let Some(params) = params.and_then(|p| p.as_object_mut()) else {
+        return;
+    };
+    match principal {
+        Some(principal) => {
+            let meta = params
+                .entry("_meta")
+                .or_insert_with(|| serde_json::json!({}));
+            if let Some(meta) = meta.as_object_mut() {
+                meta.insert(
+                    "principal".to_string(),
+                    Value::String(principal.name.clone()),
+                );
+            }
+        }
+        None => {
+            if let Some(meta) = params.get_mut("_meta").and_then(|m| m.as_object_mut()) {
+                meta.remove("principal");
+            }
+        }
+    }
+}
+
+/// Header carrying the MCP session id on HTTP requests
+const SESSION_ID_HEADER: &str = "mcp-session-id";
+
+/// Copy the `Mcp-Session-Id` header into `params._meta.session_id` for tool calls
+///
+/// The header wins over any session id the client put in `_meta` itself.
+fn attach_session_header(headers: &HeaderMap, request: &mut HttpJsonRpcRequest) {
+    let Some(session_id) = headers
+        .get(SESSION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return;
+    };
+
+    if let Some(params) = request.params.as_mut().and_then(|p| p.as_object_mut()) {
+        let meta = params
+            .entry("_meta")
+            .or_insert_with(|| serde_json::json!({}));
+        if let Some(meta) = meta.as_object_mut() {
+            meta.insert(
+                "session_id".to_string(),
+                Value::String(session_id.to_string()),
+            );
+        }
+    }
 }
 
 /// Process MCP request and return result
@@ -4870,17 +9360,27 @@ async fn process_mcp_request(
 pub async fn create_http_server(config: McpServerConfig) -> McpResult<MantraDexMcpServer> {
     let http_host = config.http_host.clone();
     let http_port = config.http_port;
+    let auth_enabled = config.http_auth.is_enabled();
     let server = create_mcp_server(config).await?;
 
     info!(
         "Starting MCP server with HTTP transport on {}:{}",
         http_host, http_port
     );
+    if !auth_enabled {
+        warn!(
+            "HTTP authentication is off; any client reaching {}:{} can use the active wallet",
+            http_host, http_port
+        );
+    }
 
     // Create HTTP server with JSON-RPC endpoint
     let app = Router::new()
         .route("/", post(handle_jsonrpc_request))
         .route("/jsonrpc", post(handle_jsonrpc_request))
+        .route("/metrics", get(handle_metrics_request))
+        .route("/healthz", get(handle_healthz_request))
+        .route("/readyz", get(handle_readyz_request))
         .with_state(Arc::new(server.clone()));
 
     // Bind to address
@@ -4896,6 +9396,7 @@ pub async fn create_http_server(config: McpServerConfig) -> McpResult<MantraDexM
 
     // Start the HTTP server
     tokio::spawn(async move {
+        let app = app.into_make_service_with_connect_info::<SocketAddr>();
         if let Err(e) = axum::serve(listener, app).await {
             error!("HTTP server error: {}", e);
         }
@@ -4975,83 +9476,314 @@ impl MantraDexMcpServer {
         Ok(balance_result)
     }
 
-    /// Handle get_all_lp_token_balances tool
-    async fn handle_get_all_lp_token_balances(
+    /// Handle get_all_lp_token_balances tool
+    async fn handle_get_all_lp_token_balances(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling get_all_lp_token_balances tool call");
+
+        // Get wallet address (use active wallet if not provided)
+        let wallet_address =
+            if let Some(addr) = arguments.get("wallet_address").and_then(|v| v.as_str()) {
+                addr.to_string()
+            } else {
+                match self.state.get_active_wallet().await? {
+                    Some(wallet) => wallet.address,
+                    None => {
+                        return Err(McpServerError::WalletNotConfigured);
+                    }
+                }
+            };
+
+        let include_zero_balances = arguments
+            .get("include_zero_balances")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        // Cache key for all LP token balances
+        let cache_key = format!(
+            "all_lp_balances:{}:zero={}",
+            wallet_address, include_zero_balances
+        );
+
+        if let Some(cached_balances) = self.state.cache_get(&cache_key).await {
+            info!(wallet_address, "Returning cached LP token balances");
+            return Ok(cached_balances);
+        }
+
+        info!(
+            wallet_address,
+            "Querying all LP token balances from blockchain"
+        );
+
+        // For now, return a placeholder response
+        // In a real implementation, this would:
+        // 1. Query all pools from the blockchain
+        // 2. For each pool, check if the wallet has LP tokens
+        // 3. Calculate share percentages and values
+        let balances_result = serde_json::json!({
+            "wallet_address": wallet_address,
+            "lp_positions": [],
+            "total_positions": 0,
+            "total_value_usd": "0.00",
+            "include_zero_balances": include_zero_balances,
+            "last_updated": chrono::Utc::now().to_rfc3339(),
+            "note": "LP token balance querying is not yet fully implemented. This is a placeholder response."
+        });
+
+        // Cache the result
+        self.state
+            .cache_set(cache_key, balances_result.clone())
+            .await;
+
+        Ok(balances_result)
+    }
+
+    // =============================================================================
+    // ClaimDrop Tool Handlers
+    // =============================================================================
+
+    async fn handle_claimdrop_create_campaign(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling claimdrop_create_campaign tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_create_campaign(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_claim(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling claimdrop_claim tool call");
+        let result = self.state.sdk_adapter.claimdrop_claim(arguments).await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_query_rewards(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling claimdrop_query_rewards tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_query_rewards(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_get_vesting_schedule(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling claimdrop_get_vesting_schedule tool call"
+        );
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_get_vesting_schedule(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_query_campaigns(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling claimdrop_query_campaigns tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_query_campaigns(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_get_global_stats(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling claimdrop_get_global_stats tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_get_global_stats(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_export_eligibility_proof(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling claimdrop_export_eligibility_proof tool call"
+        );
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_export_eligibility_proof(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_verify_eligibility_proof(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling claimdrop_verify_eligibility_proof tool call"
+        );
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_verify_eligibility_proof(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_close_campaign(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling claimdrop_close_campaign tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_close_campaign(arguments)
+            .await?;
+
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
+    }
+
+    async fn handle_claimdrop_withdraw_remaining_funds(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling get_all_lp_token_balances tool call");
-
-        // Get wallet address (use active wallet if not provided)
-        let wallet_address =
-            if let Some(addr) = arguments.get("wallet_address").and_then(|v| v.as_str()) {
-                addr.to_string()
-            } else {
-                match self.state.get_active_wallet().await? {
-                    Some(wallet) => wallet.address,
-                    None => {
-                        return Err(McpServerError::WalletNotConfigured);
-                    }
-                }
-            };
-
-        let include_zero_balances = arguments
-            .get("include_zero_balances")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
-
-        // Cache key for all LP token balances
-        let cache_key = format!(
-            "all_lp_balances:{}:zero={}",
-            wallet_address, include_zero_balances
-        );
-
-        if let Some(cached_balances) = self.state.cache_get(&cache_key).await {
-            info!(wallet_address, "Returning cached LP token balances");
-            return Ok(cached_balances);
-        }
-
         info!(
-            wallet_address,
-            "Querying all LP token balances from blockchain"
+            ?arguments,
+            "Handling claimdrop_withdraw_remaining_funds tool call"
         );
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_withdraw_remaining_funds(arguments)
+            .await?;
 
-        // For now, return a placeholder response
-        // In a real implementation, this would:
-        // 1. Query all pools from the blockchain
-        // 2. For each pool, check if the wallet has LP tokens
-        // 3. Calculate share percentages and values
-        let balances_result = serde_json::json!({
-            "wallet_address": wallet_address,
-            "lp_positions": [],
-            "total_positions": 0,
-            "total_value_usd": "0.00",
-            "include_zero_balances": include_zero_balances,
-            "last_updated": chrono::Utc::now().to_rfc3339(),
-            "note": "LP token balance querying is not yet fully implemented. This is a placeholder response."
-        });
-
-        // Cache the result
-        self.state
-            .cache_set(cache_key, balances_result.clone())
-            .await;
-
-        Ok(balances_result)
+        // Format as MCP response
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": serde_json::to_string_pretty(&result)?
+                }
+            ]
+        }))
     }
 
-    // =============================================================================
-    // ClaimDrop Tool Handlers
-    // =============================================================================
-
-    async fn handle_claimdrop_create_campaign(
+    async fn handle_claimdrop_end_of_life_campaign(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling claimdrop_create_campaign tool call");
+        info!(
+            ?arguments,
+            "Handling claimdrop_end_of_life_campaign tool call"
+        );
         let result = self
             .state
             .sdk_adapter
-            .claimdrop_create_campaign(arguments)
+            .claimdrop_end_of_life_campaign(arguments)
             .await?;
 
         // Format as MCP response
@@ -5065,12 +9797,16 @@ impl MantraDexMcpServer {
         }))
     }
 
-    async fn handle_claimdrop_claim(
+    async fn handle_claimdrop_add_allocations(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling claimdrop_claim tool call");
-        let result = self.state.sdk_adapter.claimdrop_claim(arguments).await?;
+        info!(?arguments, "Handling claimdrop_add_allocations tool call");
+        let result = self
+            .state
+            .sdk_adapter
+            .claimdrop_add_allocations(arguments)
+            .await?;
 
         // Format as MCP response
         Ok(serde_json::json!({
@@ -5083,15 +9819,15 @@ impl MantraDexMcpServer {
         }))
     }
 
-    async fn handle_claimdrop_query_rewards(
+    async fn handle_claimdrop_claim_with_proof(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling claimdrop_query_rewards tool call");
+        info!(?arguments, "Handling claimdrop_claim_with_proof tool call");
         let result = self
             .state
             .sdk_adapter
-            .claimdrop_query_rewards(arguments)
+            .claimdrop_claim_with_proof(arguments)
             .await?;
 
         // Format as MCP response
@@ -5105,15 +9841,15 @@ impl MantraDexMcpServer {
         }))
     }
 
-    async fn handle_claimdrop_query_campaigns(
+    async fn handle_claimdrop_claim_all(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling claimdrop_query_campaigns tool call");
+        info!(?arguments, "Handling claimdrop_claim_all tool call");
         let result = self
             .state
             .sdk_adapter
-            .claimdrop_query_campaigns(arguments)
+            .claimdrop_claim_all(arguments)
             .await?;
 
         // Format as MCP response
@@ -5127,15 +9863,15 @@ impl MantraDexMcpServer {
         }))
     }
 
-    async fn handle_claimdrop_add_allocations(
+    async fn handle_claimdrop_build_campaign(
         &self,
         arguments: serde_json::Value,
     ) -> McpResult<serde_json::Value> {
-        info!(?arguments, "Handling claimdrop_add_allocations tool call");
+        info!(?arguments, "Handling claimdrop_build_campaign tool call");
         let result = self
             .state
             .sdk_adapter
-            .claimdrop_add_allocations(arguments)
+            .claimdrop_build_campaign(arguments)
             .await?;
 
         // Format as MCP response
@@ -5181,6 +9917,15 @@ impl MantraDexMcpServer {
         self.state.sdk_adapter.skip_track_transfer(arguments).await
     }
 
+    /// Handle skip_watch_transfer tool
+    async fn handle_skip_watch_transfer(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling skip_watch_transfer tool call");
+        self.state.sdk_adapter.skip_watch_transfer(arguments).await
+    }
+
     /// Handle skip_get_supported_chains tool
     async fn handle_skip_get_supported_chains(
         &self,
@@ -5193,6 +9938,39 @@ impl MantraDexMcpServer {
             .await
     }
 
+    /// Handle skip_recover_transfer tool
+    async fn handle_skip_recover_transfer(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling skip_recover_transfer tool call");
+        self.state
+            .sdk_adapter
+            .skip_recover_transfer(arguments)
+            .await
+    }
+
+    /// Handle skip_list_chains tool
+    async fn handle_skip_list_chains(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling skip_list_chains tool call");
+        self.state.sdk_adapter.skip_list_chains(arguments).await
+    }
+
+    /// Handle skip_list_chain_assets tool
+    async fn handle_skip_list_chain_assets(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling skip_list_chain_assets tool call");
+        self.state
+            .sdk_adapter
+            .skip_list_chain_assets(arguments)
+            .await
+    }
+
     /// Handle skip_verify_assets tool
     async fn handle_skip_verify_assets(
         &self,
@@ -5211,6 +9989,18 @@ impl MantraDexMcpServer {
         self.state.sdk_adapter.skip_estimate_fees(arguments).await
     }
 
+    /// Handle skip_get_affiliate_fees tool
+    async fn handle_skip_get_affiliate_fees(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling skip_get_affiliate_fees tool call");
+        self.state
+            .sdk_adapter
+            .skip_get_affiliate_fees(arguments)
+            .await
+    }
+
     // =============================================================================
     // PrimarySale Protocol Handlers
     // =============================================================================
@@ -5274,6 +10064,35 @@ impl MantraDexMcpServer {
         }))
     }
 
+    /// Handle primary_sale_approve_and_invest tool
+    #[cfg(feature = "evm")]
+    async fn handle_primary_sale_approve_and_invest(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling primary_sale_approve_and_invest tool call"
+        );
+        let result = self
+            .state
+            .sdk_adapter
+            .primary_sale_approve_and_invest(arguments)
+            .await?;
+
+        let response_text =
+            serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string());
+
+        Ok(serde_json::json!({
+            "content": [
+                {
+                    "type": "text",
+                    "text": response_text
+                }
+            ]
+        }))
+    }
+
     /// Handle primary_sale_invest tool
     #[cfg(feature = "evm")]
     async fn handle_primary_sale_invest(
@@ -5361,6 +10180,96 @@ impl MantraDexMcpServer {
         }))
     }
 
+    /// Handle primary_sale_check_eligibility tool
+    #[cfg(feature = "evm")]
+    async fn handle_primary_sale_check_eligibility(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling primary_sale_check_eligibility tool call"
+        );
+        let args: PrimarySaleCheckEligibilityArgs =
+            parse_arguments("primary_sale_check_eligibility", &arguments)?;
+        let result = self
+            .state
+            .sdk_adapter
+            .primary_sale_check_eligibility(
+                &args.contract_address,
+                &args.token,
+                &args.amount,
+                args.investor_address.as_deref(),
+            )
+            .await?;
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle primary_sale_project_settlement tool
+    #[cfg(feature = "evm")]
+    async fn handle_primary_sale_project_settlement(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling primary_sale_project_settlement tool call"
+        );
+        let args: PrimarySaleProjectSettlementArgs =
+            parse_arguments("primary_sale_project_settlement", &arguments)?;
+        let result = self
+            .state
+            .sdk_adapter
+            .primary_sale_project_settlement(&args.contract_address, &args.restricted_wallets)
+            .await?;
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle restricted_wallets_validate tool
+    #[cfg(feature = "evm")]
+    async fn handle_restricted_wallets_validate(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(?arguments, "Handling restricted_wallets_validate tool call");
+        let args: RestrictedWalletsValidateArgs =
+            parse_arguments("restricted_wallets_validate", &arguments)?;
+        let result = self
+            .state
+            .sdk_adapter
+            .restricted_wallets_validate(&args.contract_address)
+            .await?;
+        tool_result(args.response_format, &result)
+    }
+
+    /// Handle primary_sale_export_investors tool
+    #[cfg(feature = "evm")]
+    async fn handle_primary_sale_export_investors(
+        &self,
+        arguments: serde_json::Value,
+    ) -> McpResult<serde_json::Value> {
+        info!(
+            ?arguments,
+            "Handling primary_sale_export_investors tool call"
+        );
+        let args: PrimarySaleExportInvestorsArgs =
+            parse_arguments("primary_sale_export_investors", &arguments)?;
+        let defaults = ExportOptions::default();
+        let options = ExportOptions {
+            page_size: args.page_size.unwrap_or(defaults.page_size),
+            concurrency: args.concurrency.unwrap_or(defaults.concurrency),
+            requests_per_second: args
+                .requests_per_second
+                .unwrap_or(defaults.requests_per_second),
+        };
+        let result = self
+            .state
+            .sdk_adapter
+            .primary_sale_export_investors(&args.contract_address, args.csv, options)
+            .await?;
+        tool_result(args.response_format, &result)
+    }
+
     /// Handle primary_sale_activate tool
     #[cfg(feature = "evm")]
     async fn handle_primary_sale_activate(
@@ -5663,7 +10572,12 @@ async fn start_stdio_transport(server: MantraDexMcpServer) -> McpResult<()> {
 
                 // Parse JSON-RPC request
                 let response_opt = match serde_json::from_str::<serde_json::Value>(trimmed) {
-                    Ok(request) => handle_json_rpc_request(&server, request).await,
+                    Ok(request) => match progress_token(request.get("params")) {
+                        Some(token) => {
+                            handle_with_progress(&server, request, token, &mut stdout).await
+                        }
+                        None => handle_json_rpc_request(&server, request).await,
+                    },
                     Err(e) => {
                         warn!("Failed to parse JSON-RPC request: {}", e);
                         Some(JsonRpcResponse::error(
@@ -5721,6 +10635,49 @@ async fn start_stdio_transport(server: MantraDexMcpServer) -> McpResult<()> {
     Ok(())
 }
 
+/// Handle a stdio request, writing its progress notifications as they arrive
+async fn handle_with_progress(
+    server: &MantraDexMcpServer,
+    request: serde_json::Value,
+    token: Value,
+    stdout: &mut tokio::io::Stdout,
+) -> Option<JsonRpcResponse> {
+    let (sink, mut notifications) = mpsc::unbounded_channel();
+    let handling =
+        ProgressReporter::new(token, sink).scope(handle_json_rpc_request(server, request));
+    tokio::pin!(handling);
+
+    loop {
+        tokio::select! {
+            response = &mut handling => {
+                // Notifications sent just before the response still go first
+                while let Ok(notification) = notifications.try_recv() {
+                    write_stdio_message(stdout, &notification).await;
+                }
+                return response;
+            }
+            Some(notification) = notifications.recv() => {
+                write_stdio_message(stdout, &notification).await;
+            }
+        }
+    }
+}
+
+/// Write one JSON-RPC message to stdout as a line
+async fn write_stdio_message(stdout: &mut tokio::io::Stdout, message: &Value) {
+    use tokio::io::AsyncWriteExt;
+
+    let mut line = message.to_string();
+    line.push('\n');
+    if let Err(e) = stdout.write_all(line.as_bytes()).await {
+        warn!("Failed to write notification to stdout: {}", e);
+        return;
+    }
+    if let Err(e) = stdout.flush().await {
+        warn!("Failed to flush stdout: {}", e);
+    }
+}
+
 /// Handle a JSON-RPC request and return a JSON-RPC response
 async fn handle_json_rpc_request(
     server: &MantraDexMcpServer,
@@ -5744,7 +10701,9 @@ async fn handle_json_rpc_request(
         }
     };
 
-    let params = request.get("params").cloned();
+    let mut params = request.get("params").cloned();
+    // Stdio has no authentication, so no caller is a principal
+    attach_principal(None, params.as_mut());
 
     debug!("Handling MCP method: {}", method);
 
@@ -5783,6 +10742,78 @@ impl Clone for MantraDexMcpServer {
     fn clone(&self) -> Self {
         Self {
             state: self.state.clone(),
+            tool_schemas: self.tool_schemas.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::auth::AuthMethod;
+
+    fn call(principal: Option<&Principal>, mut params: Value) -> Value {
+        attach_principal(principal, Some(&mut params));
+        let mut arguments = params["arguments"].clone();
+        merge_request_meta(&params, &mut arguments);
+        arguments
+    }
+
+    #[test]
+    fn test_forged_principal_is_replaced_by_authenticated_one() {
+        let principal = Principal {
+            name: "alice".to_string(),
+            method: AuthMethod::ApiKey,
+            tools: Vec::new(),
+        };
+        let params = serde_json::json!({
+            "name": "approve_transaction",
+            "_meta": { "principal": "approver" },
+            "arguments": { "_meta": { "principal": "approver" } }
+        });
+
+        let arguments = call(Some(&principal), params);
+        assert_eq!(
+            principal_from_arguments(&arguments).as_deref(),
+            Some("alice")
+        );
+    }
+
+    #[test]
+    fn test_forged_principal_is_removed_without_authentication() {
+        let params = serde_json::json!({
+            "name": "approve_transaction",
+            "_meta": { "principal": "approver" },
+            "arguments": { "id": "1", "_meta": { "principal": "approver" } }
+        });
+
+        let arguments = call(None, params);
+        assert_eq!(principal_from_arguments(&arguments), None);
+        assert_eq!(arguments, serde_json::json!({ "id": "1" }));
+    }
+
+    #[test]
+    fn test_session_header_overrides_client_session_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SESSION_ID_HEADER,
+            HeaderValue::from_static("header-session"),
+        );
+        let mut request = HttpJsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "tools/call".to_string(),
+            params: Some(serde_json::json!({
+                "name": "execute_swap",
+                "_meta": { "session_id": "other-session" },
+                "arguments": { "_meta": { "session_id": "other-session" } }
+            })),
+            id: None,
+        };
+        attach_session_header(&headers, &mut request);
+
+        let params = request.params.unwrap();
+        let mut arguments = params["arguments"].clone();
+        merge_request_meta(&params, &mut arguments);
+        assert_eq!(session_id_from_arguments(&arguments), "header-session");
+    }
+}
@@ -0,0 +1,354 @@
+//! Event-Sourced Adapter State
+//!
+//! Every change to the adapter's mutable state (loaded wallets, the active
+//! wallet, custom ERC-20 tokens) is appended to an [`EventStore`] before it is
+//! applied. The live maps are a projection of that log: replaying the events in
+//! sequence order rebuilds the same state, which is how the adapter recovers
+//! after a crash and how the audit trail can show the state at any point.
+//!
+//! A store opened with a path writes one JSON event per line and syncs it to
+//! disk before the change takes effect. Secrets never enter the log; wallet
+//! instances are recreated from the mnemonic and the recorded derivation index.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::Error;
+use crate::wallet::WalletInfo;
+
+/// Custom ERC-20 token as recorded in the event log
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomTokenRecord {
+    pub chain_id: u64,
+    /// Checksummed contract address
+    pub address: String,
+    pub symbol: String,
+    pub name: Option<String>,
+    pub decimals: u8,
+}
+
+impl CustomTokenRecord {
+    fn key(&self) -> String {
+        token_key(self.chain_id, &self.address)
+    }
+}
+
+fn token_key(chain_id: u64, address: &str) -> String {
+    format!("{}:{}", chain_id, address.to_ascii_lowercase())
+}
+
+/// A change to the adapter state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum StateEvent {
+    /// A wallet was loaded or its derivation index became known
+    WalletAdded {
+        wallet: WalletInfo,
+        derivation_index: Option<u32>,
+    },
+    /// A wallet was unloaded; clears the active wallet if it was active
+    WalletRemoved { address: String },
+    /// The active wallet changed
+    ActiveWalletChanged { address: Option<String> },
+    /// A custom token was registered or updated
+    CustomTokenAdded { token: CustomTokenRecord },
+    /// A custom token was removed
+    CustomTokenRemoved { chain_id: u64, address: String },
+}
+
+/// An event with its position in the log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Position in the log, starting at 1
+    pub sequence: u64,
+    pub timestamp: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: StateEvent,
+}
+
+/// Adapter state rebuilt from the event log
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AdapterState {
+    pub wallets: BTreeMap<String, WalletInfo>,
+    pub derivation_indices: BTreeMap<String, u32>,
+    pub active_wallet: Option<String>,
+    /// Custom tokens keyed by `chain_id:address`
+    pub custom_tokens: BTreeMap<String, CustomTokenRecord>,
+}
+
+impl AdapterState {
+    /// Rebuild the state by applying `events` in order
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a StateEvent>) -> Self {
+        let mut state = Self::default();
+        for event in events {
+            state.apply(event);
+        }
+        state
+    }
+
+    /// Apply a single event
+    pub fn apply(&mut self, event: &StateEvent) {
+        match event {
+            StateEvent::WalletAdded {
+                wallet,
+                derivation_index,
+            } => {
+                if let Some(index) = derivation_index {
+                    self.derivation_indices
+                        .insert(wallet.address.clone(), *index);
+                }
+                self.wallets.insert(wallet.address.clone(), wallet.clone());
+            }
+            StateEvent::WalletRemoved { address } => {
+                self.wallets.remove(address);
+                self.derivation_indices.remove(address);
+                if self.active_wallet.as_deref() == Some(address.as_str()) {
+                    self.active_wallet = None;
+                }
+            }
+            StateEvent::ActiveWalletChanged { address } => {
+                self.active_wallet = address.clone();
+            }
+            StateEvent::CustomTokenAdded { token } => {
+                self.custom_tokens.insert(token.key(), token.clone());
+            }
+            StateEvent::CustomTokenRemoved { chain_id, address } => {
+                self.custom_tokens.remove(&token_key(*chain_id, address));
+            }
+        }
+    }
+}
+
+/// Append-only log of state events, optionally backed by a JSONL file
+#[derive(Debug, Default)]
+pub struct EventStore {
+    events: Vec<RecordedEvent>,
+    state: AdapterState,
+    path: Option<PathBuf>,
+}
+
+impl EventStore {
+    /// Store kept in memory only; state is lost on restart
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Open the log at `path`, creating it if needed, and replay it
+    ///
+    /// A malformed final line is a write torn by a crash and is dropped; a
+    /// malformed line anywhere else or a gap in the sequence numbers is an error.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let mut events = Vec::new();
+
+        if path.exists() {
+            let lines = BufReader::new(File::open(&path)?)
+                .lines()
+                .collect::<Result<Vec<_>, _>>()?;
+            let last = lines.len().saturating_sub(1);
+
+            for (index, line) in lines.iter().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match serde_json::from_str::<RecordedEvent>(line) {
+                    Ok(event) => events.push(event),
+                    Err(e) if index == last => {
+                        warn!(
+                            "Dropping incomplete last event in {}: {}",
+                            path.display(),
+                            e
+                        );
+                    }
+                    Err(e) => {
+                        return Err(Error::Config(format!(
+                            "Corrupt state event on line {} of {}: {}",
+                            index + 1,
+                            path.display(),
+                            e
+                        )))
+                    }
+                }
+            }
+
+            for (expected, event) in (1u64..).zip(&events) {
+                if event.sequence != expected {
+                    return Err(Error::Config(format!(
+                        "State event log {} expected sequence {} but found {}",
+                        path.display(),
+                        expected,
+                        event.sequence
+                    )));
+                }
+            }
+
+            // Rewrite without the torn line so new events start on a clean line
+            Self::rewrite(&path, &events)?;
+        } else if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let state = AdapterState::replay(events.iter().map(|recorded| &recorded.event));
+        Ok(Self {
+            events,
+            state,
+            path: Some(path),
+        })
+    }
+
+    fn rewrite(path: &Path, events: &[RecordedEvent]) -> Result<(), Error> {
+        let mut contents = String::new();
+        for event in events {
+            contents.push_str(&serde_json::to_string(event)?);
+            contents.push('\n');
+        }
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Append an event, persisting it before it is applied
+    pub fn append(&mut self, event: StateEvent) -> Result<RecordedEvent, Error> {
+        let recorded = RecordedEvent {
+            sequence: self.head_sequence() + 1,
+            timestamp: Utc::now(),
+            event,
+        };
+
+        if let Some(path) = &self.path {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            let mut line = serde_json::to_string(&recorded)?;
+            line.push('\n');
+            file.write_all(line.as_bytes())?;
+            file.sync_data()?;
+        }
+
+        self.state.apply(&recorded.event);
+        self.events.push(recorded.clone());
+        Ok(recorded)
+    }
+
+    /// State after every recorded event
+    pub fn state(&self) -> &AdapterState {
+        &self.state
+    }
+
+    /// State as it was right after event `sequence`
+    pub fn state_at(&self, sequence: u64) -> AdapterState {
+        AdapterState::replay(
+            self.events
+                .iter()
+                .take_while(|recorded| recorded.sequence <= sequence)
+                .map(|recorded| &recorded.event),
+        )
+    }
+
+    /// Events recorded after `sequence`, at most `limit` of them
+    pub fn events_since(&self, sequence: u64, limit: usize) -> &[RecordedEvent] {
+        let start = self.events.partition_point(|e| e.sequence <= sequence);
+        let end = start.saturating_add(limit).min(self.events.len());
+        &self.events[start..end]
+    }
+
+    /// Sequence number of the latest event, 0 when the log is empty
+    pub fn head_sequence(&self) -> u64 {
+        self.events.last().map(|e| e.sequence).unwrap_or(0)
+    }
+
+    /// File backing the store, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(address: &str) -> WalletInfo {
+        WalletInfo {
+            address: address.to_string(),
+            public_key: format!("{}-pk", address),
+        }
+    }
+
+    #[test]
+    fn test_replay_tracks_wallets_and_active_wallet() {
+        let mut store = EventStore::in_memory();
+        store
+            .append(StateEvent::WalletAdded {
+                wallet: wallet("mantra1a"),
+                derivation_index: Some(0),
+            })
+            .unwrap();
+        store
+            .append(StateEvent::WalletAdded {
+                wallet: wallet("mantra1b"),
+                derivation_index: Some(3),
+            })
+            .unwrap();
+        store
+            .append(StateEvent::ActiveWalletChanged {
+                address: Some("mantra1b".to_string()),
+            })
+            .unwrap();
+        store
+            .append(StateEvent::WalletRemoved {
+                address: "mantra1b".to_string(),
+            })
+            .unwrap();
+
+        let state = store.state();
+        assert_eq!(state.wallets.len(), 1);
+        assert_eq!(state.active_wallet, None);
+        assert!(!state.derivation_indices.contains_key("mantra1b"));
+
+        let before_removal = store.state_at(3);
+        assert_eq!(before_removal.active_wallet.as_deref(), Some("mantra1b"));
+        assert_eq!(before_removal.derivation_indices.get("mantra1b"), Some(&3));
+        assert_eq!(store.events_since(2, 10).len(), 2);
+    }
+
+    #[test]
+    fn test_reopen_replays_log_and_drops_torn_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.jsonl");
+
+        {
+            let mut store = EventStore::open(&path).unwrap();
+            store
+                .append(StateEvent::WalletAdded {
+                    wallet: wallet("mantra1a"),
+                    derivation_index: None,
+                })
+                .unwrap();
+            store
+                .append(StateEvent::ActiveWalletChanged {
+                    address: Some("mantra1a".to_string()),
+                })
+                .unwrap();
+        }
+
+        // Simulate a crash in the middle of writing the third event
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"sequence\":3,\"timest").unwrap();
+        drop(file);
+
+        let mut store = EventStore::open(&path).unwrap();
+        assert_eq!(store.head_sequence(), 2);
+        assert_eq!(store.state().active_wallet.as_deref(), Some("mantra1a"));
+
+        let next = store
+            .append(StateEvent::ActiveWalletChanged { address: None })
+            .unwrap();
+        assert_eq!(next.sequence, 3);
+        assert_eq!(EventStore::open(&path).unwrap().head_sequence(), 3);
+    }
+}
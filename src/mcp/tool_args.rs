@@ -0,0 +1,942 @@
+//! Declarative Tool Arguments
+//!
+//! Tools declare their arguments as a struct deriving `Deserialize` and
+//! `JsonSchema`. The schema generated from the struct is what `tools/list`
+//! advertises as the tool's `inputSchema`, and [`parse_arguments`] checks a
+//! call against it before deserializing.
+//!
+//! Every call is also checked against the schema its tool advertises, whether
+//! it was written by hand or generated, by [`validate_arguments`]. All missing
+//! and invalid fields are reported at once, each with a hint drawn from the
+//! schema, so an agent can fix a call in one round trip.
+
+use std::fmt;
+
+use schemars::gen::SchemaSettings;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::response_format::ResponseFormat;
+use super::server::{McpResult, McpServerError};
+use crate::wallet::DerivationOptions;
+
+/// A missing or invalid field of a tool call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentError {
+    /// Path of the field, e.g. `amount` or `token_addresses[1]`
+    pub field: String,
+    pub problem: String,
+    /// What the schema says the field should hold
+    pub hint: Option<String>,
+}
+
+impl fmt::Display for ArgumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.problem)?;
+        if let Some(hint) = &self.hint {
+            write!(f, " ({})", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Input schema of a tool whose arguments are `T`
+pub fn input_schema<T: JsonSchema>() -> Value {
+    let generator = SchemaSettings::draft07()
+        .with(|settings| {
+            settings.option_add_null_type = false;
+            settings.inline_subschemas = true;
+        })
+        .into_generator();
+    let mut schema =
+        serde_json::to_value(generator.into_root_schema_for::<T>()).unwrap_or_default();
+    if let Some(map) = schema.as_object_mut() {
+        map.remove("$schema");
+        map.remove("title");
+        map.remove("description");
+        map.entry("properties")
+            .or_insert_with(|| serde_json::json!({}));
+    }
+    schema
+}
+
+/// Check `arguments` of `tool` against its schema, then deserialize them
+pub fn parse_arguments<T: DeserializeOwned + JsonSchema>(
+    tool: &str,
+    arguments: &Value,
+) -> McpResult<T> {
+    let errors = validate_arguments(&input_schema::<T>(), arguments);
+    if !errors.is_empty() {
+        return Err(invalid_arguments(tool, &errors));
+    }
+    // Nulls stand for omitted fields, as in validation
+    let mut arguments = arguments.clone();
+    if let Some(map) = arguments.as_object_mut() {
+        map.retain(|_, value| !value.is_null());
+    }
+    serde_json::from_value(arguments).map_err(|e| {
+        McpServerError::InvalidArguments(format!("Invalid arguments for {}: {}", tool, e))
+    })
+}
+
+/// Error listing every problem found in a call of `tool`
+pub fn invalid_arguments(tool: &str, errors: &[ArgumentError]) -> McpServerError {
+    let problems: Vec<String> = errors.iter().map(|error| format!("- {}", error)).collect();
+    McpServerError::InvalidArguments(format!(
+        "Invalid arguments for {} ({} problem{}):\n{}",
+        tool,
+        errors.len(),
+        if errors.len() == 1 { "" } else { "s" },
+        problems.join("\n")
+    ))
+}
+
+/// Every way `arguments` fail to match `schema`
+///
+/// The subset of JSON Schema the tools use is checked: `type`, `required`,
+/// `properties`, `items`, `enum`, `minimum`, `maximum`, `maxLength`,
+/// `minItems`, `maxItems` and `anyOf`/`oneOf`. Properties the schema does not list are
+/// allowed, since calls carry `_meta`, `nonce` and similar extras.
+pub fn validate_arguments(schema: &Value, arguments: &Value) -> Vec<ArgumentError> {
+    let mut errors = Vec::new();
+    if !arguments.is_object() {
+        errors.push(ArgumentError {
+            field: "arguments".to_string(),
+            problem: format!("expected an object, got {}", type_name(arguments)),
+            hint: None,
+        });
+        return errors;
+    }
+    check_value(schema, arguments, "", &mut errors);
+    errors
+}
+
+fn check_value(schema: &Value, value: &Value, path: &str, errors: &mut Vec<ArgumentError>) {
+    let hint = || {
+        schema
+            .get("description")
+            .and_then(|description| description.as_str())
+            .map(str::to_string)
+    };
+
+    if let Some(types) = schema.get("type") {
+        let allowed: Vec<&str> = match types {
+            Value::String(name) => vec![name.as_str()],
+            Value::Array(names) => names.iter().filter_map(|name| name.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.is_empty() && !allowed.iter().any(|name| has_type(value, name)) {
+            errors.push(ArgumentError {
+                field: field_name(path),
+                problem: format!(
+                    "expected {}, got {}",
+                    allowed.join(" or "),
+                    type_name(value)
+                ),
+                hint: hint(),
+            });
+            return;
+        }
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(|allowed| allowed.as_array()) {
+        if !allowed.contains(value) {
+            let choices: Vec<String> = allowed.iter().map(|choice| choice.to_string()).collect();
+            errors.push(ArgumentError {
+                field: field_name(path),
+                problem: format!("{} is not one of {}", value, choices.join(", ")),
+                hint: hint(),
+            });
+        }
+    }
+
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(branches) = schema.get(keyword).and_then(|branches| branches.as_array()) {
+            let matches = branches.iter().any(|branch| {
+                let mut branch_errors = Vec::new();
+                check_value(branch, value, path, &mut branch_errors);
+                branch_errors.is_empty()
+            });
+            if !matches {
+                errors.push(ArgumentError {
+                    field: field_name(path),
+                    problem: "does not match any of the accepted forms".to_string(),
+                    hint: hint(),
+                });
+            }
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(|minimum| minimum.as_f64()) {
+            if number < minimum {
+                errors.push(ArgumentError {
+                    field: field_name(path),
+                    problem: format!("{} is below the minimum of {}", value, minimum),
+                    hint: hint(),
+                });
+            }
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(|maximum| maximum.as_f64()) {
+            if number > maximum {
+                errors.push(ArgumentError {
+                    field: field_name(path),
+                    problem: format!("{} is above the maximum of {}", value, maximum),
+                    hint: hint(),
+                });
+            }
+        }
+    }
+
+    if let (Some(text), Some(max)) = (
+        value.as_str(),
+        schema.get("maxLength").and_then(|max| max.as_u64()),
+    ) {
+        let length = text.chars().count();
+        if length as u64 > max {
+            errors.push(ArgumentError {
+                field: field_name(path),
+                problem: format!("is {} characters long, at most {} allowed", length, max),
+                hint: hint(),
+            });
+        }
+    }
+
+    if let Some(items) = value.as_array() {
+        if let Some(min) = schema.get("minItems").and_then(|min| min.as_u64()) {
+            if (items.len() as u64) < min {
+                errors.push(ArgumentError {
+                    field: field_name(path),
+                    problem: format!("has {} items, at least {} required", items.len(), min),
+                    hint: hint(),
+                });
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(|max| max.as_u64()) {
+            if items.len() as u64 > max {
+                errors.push(ArgumentError {
+                    field: field_name(path),
+                    problem: format!("has {} items, at most {} allowed", items.len(), max),
+                    hint: hint(),
+                });
+            }
+        }
+        if let Some(item_schema) = schema.get("items").filter(|items| items.is_object()) {
+            for (index, item) in items.iter().enumerate() {
+                check_value(item_schema, item, &format!("{}[{}]", path, index), errors);
+            }
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        let properties = schema
+            .get("properties")
+            .and_then(|properties| properties.as_object());
+        if let Some(required) = schema
+            .get("required")
+            .and_then(|required| required.as_array())
+        {
+            for name in required.iter().filter_map(|name| name.as_str()) {
+                if object.get(name).is_none_or(Value::is_null) {
+                    errors.push(ArgumentError {
+                        field: join_path(path, name),
+                        problem: "missing required field".to_string(),
+                        hint: properties
+                            .and_then(|properties| properties.get(name))
+                            .and_then(|property| property.get("description"))
+                            .and_then(|description| description.as_str())
+                            .map(str::to_string),
+                    });
+                }
+            }
+        }
+        if let Some(properties) = properties {
+            for (name, property) in properties {
+                match object.get(name) {
+                    // An explicit null stands for an omitted optional field
+                    None | Some(Value::Null) => {}
+                    Some(field) => check_value(property, field, &join_path(path, name), errors),
+                }
+            }
+        }
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "string" => value.is_string(),
+        "integer" => {
+            value.is_i64()
+                || value.is_u64()
+                || value.as_f64().is_some_and(|number| number.fract() == 0.0)
+        }
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+fn field_name(path: &str) -> String {
+    if path.is_empty() {
+        "arguments".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+// =============================================================================
+// Wallet tools
+// =============================================================================
+
+/// Arguments of tools that only choose the response format
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FormatArgs {
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `network_get_block`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BlockArgs {
+    /// Block height (optional, the latest block if not provided)
+    pub height: Option<u64>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `network_get_validators`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ValidatorSetArgs {
+    /// Block height (optional, the latest block if not provided)
+    pub height: Option<u64>,
+    /// Validators to list, those with the most voting power first (optional, all if not provided)
+    pub limit: Option<usize>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_get_balances`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetBalancesArgs {
+    /// Wallet address to query balances for (optional, uses active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// Whether to include assets with zero balance (default: false)
+    #[serde(default)]
+    pub include_zero_balances: bool,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `faucet_request`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct FaucetRequestArgs {
+    /// Cosmos address (mantra1...) to fund (optional, uses active wallet if not provided)
+    pub wallet_address: Option<String>,
+}
+
+/// Arguments of `resolve_asset`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ResolveAssetArgs {
+    /// Symbol (e.g. 'USDC'), denom ('uom', 'ibc/...', 'factory/...'), IBC path ('transfer/channel-0/uusdc') or ERC-20 address
+    pub query: String,
+    /// Only list assets on this chain: a Cosmos chain ID or an EVM chain ID in decimal (optional)
+    pub chain_id: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `analyze_wallet_activity`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AnalyzeWalletActivityArgs {
+    /// Cosmos wallet whose recent transactions are analyzed; the active wallet when omitted
+    pub wallet_address: Option<String>,
+    /// Cosmos transaction hashes to analyze instead of the wallet's recent transactions
+    #[serde(default)]
+    pub cosmos_transaction_hashes: Vec<String>,
+    /// EVM transaction hashes (0x...) to analyze alongside, at most 20
+    #[serde(default)]
+    pub evm_transaction_hashes: Vec<String>,
+    /// Number of recent Cosmos transactions to fetch, 1 to 50 (default 10)
+    #[serde(default = "default_activity_limit")]
+    #[schemars(range(min = 1, max = 50))]
+    pub limit: u8,
+    /// Include transactions that failed on chain (default false)
+    #[serde(default)]
+    pub include_failed: bool,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+fn default_activity_limit() -> u8 {
+    10
+}
+
+/// Arguments of `get_spending_summary`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetSpendingSummaryArgs {
+    /// Wallet to summarize; every wallet that spent in its window, and the active wallet, when omitted
+    pub wallet_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `switch_network`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SwitchNetworkArgs {
+    /// Network profile to switch the server to: 'mainnet', 'dukong', 'local' or a configured one
+    pub profile: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `get_multi_network_portfolio`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetMultiNetworkPortfolioArgs {
+    /// Network profiles to query side by side (default: 'mainnet' and 'dukong')
+    #[serde(default = "default_portfolio_networks")]
+    pub networks: Vec<String>,
+    /// Wallet address to show the balances of (default: the active wallet)
+    #[serde(default)]
+    pub wallet_address: Option<String>,
+    /// Pool identifiers to look up on every network as well
+    #[serde(default)]
+    pub pool_ids: Vec<String>,
+    /// Seconds each network has to answer before it is reported as timed out
+    #[serde(default = "default_network_timeout_secs")]
+    #[schemars(range(min = 1, max = 120))]
+    pub timeout_secs: u64,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+fn default_portfolio_networks() -> Vec<String> {
+    vec!["mainnet".to_string(), "dukong".to_string()]
+}
+
+fn default_network_timeout_secs() -> u64 {
+    crate::fanout::DEFAULT_NETWORK_TIMEOUT.as_secs()
+}
+
+/// Arguments of `watch_chain_events`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WatchChainEventsArgs {
+    /// Watch swaps and liquidity changes of the network's pool manager (default: true)
+    #[serde(default = "default_true")]
+    pub pool_manager: bool,
+    /// ClaimDrop campaign addresses whose claims to watch
+    #[serde(default)]
+    pub claimdrop_campaigns: Vec<String>,
+    /// Other contract addresses whose wasm events to watch
+    #[serde(default)]
+    pub contracts: Vec<String>,
+    /// Seconds to watch before returning
+    #[serde(default = "default_watch_secs")]
+    #[schemars(range(min = 1, max = 300))]
+    pub duration_secs: u64,
+    /// Return once this many events have arrived
+    #[serde(default = "default_watch_max_events")]
+    #[schemars(range(min = 1, max = 1000))]
+    pub max_events: usize,
+}
+
+fn default_watch_secs() -> u64 {
+    30
+}
+
+fn default_watch_max_events() -> usize {
+    50
+}
+
+/// Arguments of `address_book_add`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddressBookAddArgs {
+    /// Label to accept in place of the address, e.g. 'treasury' (letters, digits, '-', '_', '.')
+    pub label: String,
+    /// Cosmos (mantra1...) or EVM (0x...) address the label stands for
+    pub address: String,
+    /// What the address is, shown when listing the book (optional)
+    pub note: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `address_convert`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddressConvertArgs {
+    /// Cosmos (mantra1...) or EVM (0x...) address, or an address book label
+    pub address: String,
+    /// VM the address is for, 'cosmos' or 'evm' (optional); an address for the other VM is flagged
+    pub expected_vm: Option<String>,
+    /// Bech32 prefix of the converted address (optional, 'mantra' if not provided)
+    pub prefix: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `address_book_remove`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddressBookRemoveArgs {
+    /// Label to forget
+    pub label: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `restricted_wallets_add`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RestrictedWalletsAddArgs {
+    /// EVM (0x...) or 20-byte Cosmos (mantra1...) address to restrict
+    pub address: String,
+    /// Why the wallet is restricted, e.g. 'OFAC SDN' (optional)
+    pub reason: Option<String>,
+    /// Feed or list the restriction comes from (optional)
+    pub source: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `restricted_wallets_remove`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RestrictedWalletsRemoveArgs {
+    /// Address whose restriction to lift
+    pub address: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `restricted_wallets_import`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RestrictedWalletsImportArgs {
+    /// CSV text with one 'address[,reason]' per line; a header line, blank lines and '#' comments are skipped
+    pub csv: String,
+    /// Name of the feed, recorded on every imported wallet (optional)
+    pub source: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_switch`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SwitchWalletArgs {
+    /// The wallet address to switch to
+    pub wallet_address: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_add_from_mnemonic`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddWalletArgs {
+    /// The mnemonic phrase to import the wallet from
+    pub mnemonic: String,
+    /// The derivation index for the wallet (default: 0)
+    #[serde(default)]
+    pub derivation_index: u32,
+    /// Whether to make the new wallet the active one (default: true)
+    #[serde(default = "default_true")]
+    pub set_as_active: bool,
+    /// BIP-39 passphrase ("25th word") the mnemonic was protected with (optional)
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    /// SLIP-44 coin type of the HD path, for wallets created under another coin type such as 60 (default: 118 for Cosmos keys, 60 for EVM keys)
+    #[serde(default)]
+    pub coin_type: Option<u32>,
+    /// Account component of the HD path m/44'/coin'/account'/change/index (default: 0)
+    #[serde(default)]
+    pub account: u32,
+    /// Change component of the HD path (default: 0)
+    #[serde(default)]
+    pub change: u32,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_discover`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct DiscoverWalletsArgs {
+    /// The mnemonic phrase whose derivation indices to scan
+    pub mnemonic: String,
+    /// Consecutive unused indices after which scanning stops (default: 20, maximum 100)
+    #[serde(default = "default_gap_limit")]
+    pub gap_limit: u32,
+    /// Whether to add the discovered wallets to the collection (default: false)
+    #[serde(default)]
+    pub import: bool,
+    /// BIP-39 passphrase ("25th word") the mnemonic was protected with (optional)
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    /// SLIP-44 coin type of the HD path, for wallets created under another coin type such as 60 (default: 118 for Cosmos keys, 60 for EVM keys)
+    #[serde(default)]
+    pub coin_type: Option<u32>,
+    /// Account component of the HD path m/44'/coin'/account'/change/index (default: 0)
+    #[serde(default)]
+    pub account: u32,
+    /// Change component of the HD path (default: 0)
+    #[serde(default)]
+    pub change: u32,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+impl AddWalletArgs {
+    /// Passphrase and HD path to derive the wallet with
+    pub fn derivation_options(&self) -> DerivationOptions {
+        derivation_options(&self.passphrase, self.coin_type, self.account, self.change)
+    }
+}
+
+impl DiscoverWalletsArgs {
+    /// Passphrase and HD path to derive the scanned wallets with
+    pub fn derivation_options(&self) -> DerivationOptions {
+        derivation_options(&self.passphrase, self.coin_type, self.account, self.change)
+    }
+}
+
+fn derivation_options(
+    passphrase: &Option<String>,
+    coin_type: Option<u32>,
+    account: u32,
+    change: u32,
+) -> DerivationOptions {
+    let mut options = DerivationOptions::new()
+        .with_passphrase(passphrase.clone().unwrap_or_default())
+        .with_account(account)
+        .with_change(change);
+    if let Some(coin_type) = coin_type {
+        options = options.with_coin_type(coin_type);
+    }
+    options
+}
+
+fn default_gap_limit() -> u32 {
+    crate::wallet::discovery::DEFAULT_GAP_LIMIT
+}
+
+/// Arguments of `wallet_remove`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RemoveWalletArgs {
+    /// The wallet address to remove
+    pub wallet_address: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of wallet tools that act on one, optional, wallet
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct WalletArgs {
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_get_erc20_balance`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct Erc20BalanceArgs {
+    /// ERC-20 contract address
+    pub token_address: String,
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_get_all_evm_balances`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EvmBalancesArgs {
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// ERC-20 tokens to query besides the native balance (optional)
+    pub token_addresses: Option<Vec<String>>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_transfer_erc20`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct Erc20TransferArgs {
+    /// ERC-20 contract address
+    pub token_address: String,
+    /// Recipient EVM address (0x...)
+    pub recipient: String,
+    /// Amount to transfer (will be converted using token decimals)
+    pub amount: String,
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `wallet_approve_erc20`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct Erc20ApproveArgs {
+    /// ERC-20 contract address
+    pub token_address: String,
+    /// Spender EVM address (0x...) - contract or address authorized to spend
+    pub spender: String,
+    /// Amount to approve (will be converted using token decimals), or 'unlimited'
+    pub amount: String,
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `erc20_get_allowances`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct Erc20AllowancesArgs {
+    /// ERC-20 contract address (optional, every token the wallet approved if not provided)
+    pub token_address: Option<String>,
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// First block scanned for Approval events (optional, the last 200000 blocks if not provided)
+    pub from_block: Option<u64>,
+    /// Also list spenders whose allowance is spent or revoked
+    #[serde(default)]
+    pub include_zero: bool,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `erc20_revoke_approval`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct Erc20RevokeArgs {
+    /// ERC-20 contract address
+    pub token_address: String,
+    /// Spender EVM address (0x...) whose allowance is set to zero
+    pub spender: String,
+    /// Wallet address (optional, uses the active wallet if not provided)
+    pub wallet_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `evm_get_block`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EvmGetBlockArgs {
+    /// Block number (decimal or 0x hex), block hash, or a tag: latest (default), pending, earliest, safe or finalized
+    pub block: Option<String>,
+    /// Include the sender, recipient, value and decoded function of each transaction instead of only their hashes
+    #[serde(default)]
+    pub full_transactions: bool,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `evm_get_transaction`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EvmGetTransactionArgs {
+    /// Transaction hash (0x followed by 64 hex characters)
+    pub tx_hash: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `evm_get_code`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EvmGetCodeArgs {
+    /// EVM address (0x...)
+    pub address: String,
+    /// Block number (decimal or 0x hex) or tag (optional, latest if not provided)
+    pub block: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `evm_inspect_contract`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EvmInspectContractArgs {
+    /// EVM address (0x...)
+    pub address: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `evm_get_storage_at`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EvmGetStorageAtArgs {
+    /// Contract address (0x...)
+    pub address: String,
+    /// Storage slot, decimal or 0x hex
+    pub slot: String,
+    /// Block number (decimal or 0x hex) or tag (optional, latest if not provided)
+    pub block: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `primary_sale_check_eligibility`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PrimarySaleCheckEligibilityArgs {
+    /// PrimarySale contract address (0x...)
+    pub contract_address: String,
+    /// Payment token address (0x...) to invest with
+    pub token: String,
+    /// Amount to invest, in human-readable units of the token
+    pub amount: String,
+    /// Investor EVM address (optional, uses the active wallet if not provided)
+    pub investor_address: Option<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `primary_sale_project_settlement`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PrimarySaleProjectSettlementArgs {
+    /// PrimarySale contract address (0x...)
+    pub contract_address: String,
+    /// Investor EVM addresses that would be passed to settlement as restricted; their contributions go to the multisig (optional, the restricted wallet list if not provided)
+    #[serde(default)]
+    pub restricted_wallets: Vec<String>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `primary_sale_export_investors`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PrimarySaleExportInvestorsArgs {
+    /// PrimarySale contract address (0x...)
+    pub contract_address: String,
+    /// Return the investors as CSV text, one row per investor and one column per accepted token, instead of a JSON array
+    #[serde(default)]
+    pub csv: bool,
+    /// Investors fetched per batch query (optional, 100 if not provided)
+    pub page_size: Option<usize>,
+    /// Investors whose details are fetched at once (optional, 8 if not provided)
+    pub concurrency: Option<usize>,
+    /// Contract calls per second, 0 for no limit (optional, 20 if not provided)
+    pub requests_per_second: Option<u32>,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+/// Arguments of `restricted_wallets_validate`
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct RestrictedWalletsValidateArgs {
+    /// PrimarySale contract address (0x...) whose investors the list is checked against
+    pub contract_address: String,
+    /// Format of the result: 'json' (default) for the typed response, 'markdown' for a human-readable summary
+    #[serde(default)]
+    pub response_format: ResponseFormat,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_problems_reported_at_once() {
+        let schema = input_schema::<Erc20TransferArgs>();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(
+            schema["properties"]["recipient"]["description"],
+            "Recipient EVM address (0x...)"
+        );
+        assert_eq!(
+            schema["properties"]["response_format"]["enum"],
+            serde_json::json!(["json", "markdown"])
+        );
+
+        let errors = validate_arguments(
+            &schema,
+            &serde_json::json!({ "token_address": 7, "response_format": "xml", "_meta": {} }),
+        );
+        let fields: Vec<&str> = errors.iter().map(|error| error.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            ["amount", "recipient", "response_format", "token_address"]
+        );
+        assert_eq!(errors[1].problem, "missing required field");
+        assert_eq!(
+            errors[1].hint.as_deref(),
+            Some("Recipient EVM address (0x...)")
+        );
+        assert_eq!(errors[3].problem, "expected string, got integer");
+
+        let error = parse_arguments::<Erc20TransferArgs>(
+            "wallet_transfer_erc20",
+            &serde_json::json!({ "token_address": "0x1" }),
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("(2 problems)"));
+
+        let args: AddWalletArgs = parse_arguments(
+            "wallet_add_from_mnemonic",
+            &serde_json::json!({ "mnemonic": "abandon", "derivation_index": null }),
+        )
+        .unwrap();
+        assert_eq!(args.derivation_index, 0);
+        assert!(args.set_as_active);
+        assert_eq!(args.response_format, ResponseFormat::Json);
+
+        let errors = validate_arguments(
+            &input_schema::<AddWalletArgs>(),
+            &serde_json::json!({ "mnemonic": "abandon", "derivation_index": -1 }),
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "derivation_index");
+    }
+}
@@ -0,0 +1,240 @@
+//! Traces of Recent Tool Calls
+//!
+//! Every tool call runs under a correlation ID: the one the client passes in
+//! `_meta.correlationId`, or a fresh one. The ID is on every log line of the
+//! call and is returned in the result's `_meta.correlation_id`. Once the call
+//! completes its trace, the RPC requests and retries it made in order, is kept
+//! in a bounded in-memory log for the `get_recent_traces` tool.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::trace::{TraceContext, TraceEvent};
+
+/// Trace log settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceLogConfig {
+    /// Completed tool calls kept; 0 keeps none
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+}
+
+fn default_capacity() -> usize {
+    200
+}
+
+impl Default for TraceLogConfig {
+    fn default() -> Self {
+        Self {
+            capacity: default_capacity(),
+        }
+    }
+}
+
+/// Correlation ID a client chose for a call, from `_meta.correlationId`
+pub fn correlation_id_from_arguments(arguments: &Value) -> Option<String> {
+    arguments
+        .get("_meta")
+        .and_then(|meta| meta.get("correlationId"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.len() <= 128)
+        .map(str::to_string)
+}
+
+/// Trace of one completed tool call
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolCallTrace {
+    pub correlation_id: String,
+    pub tool: String,
+    pub session_id: String,
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// RPC requests and retries, in the order they started
+    pub events: Vec<TraceEvent>,
+    /// Events beyond the per-trace limit that were not kept
+    #[serde(skip_serializing_if = "is_zero")]
+    pub dropped_events: usize,
+}
+
+fn is_zero(count: &usize) -> bool {
+    *count == 0
+}
+
+impl ToolCallTrace {
+    /// Trace of a call to `tool` that ran under `context`
+    pub fn new(
+        context: &TraceContext,
+        tool: &str,
+        session_id: String,
+        started_at: DateTime<Utc>,
+        duration_ms: u64,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            correlation_id: context.correlation_id().to_string(),
+            tool: tool.to_string(),
+            session_id,
+            started_at,
+            duration_ms,
+            error,
+            events: context.events(),
+            dropped_events: context.dropped_events(),
+        }
+    }
+}
+
+/// Filters of [`TraceLog::recent`]; unset filters match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TraceQuery {
+    pub correlation_id: Option<String>,
+    pub tool: Option<String>,
+    pub session_id: Option<String>,
+    /// Only calls that failed
+    #[serde(default)]
+    pub failed_only: bool,
+    /// Most traces to return, newest first
+    pub limit: Option<usize>,
+}
+
+impl TraceQuery {
+    fn matches(&self, trace: &ToolCallTrace) -> bool {
+        self.correlation_id
+            .as_ref()
+            .is_none_or(|id| *id == trace.correlation_id)
+            && self.tool.as_ref().is_none_or(|tool| *tool == trace.tool)
+            && self
+                .session_id
+                .as_ref()
+                .is_none_or(|id| *id == trace.session_id)
+            && (!self.failed_only || trace.error.is_some())
+    }
+}
+
+/// Most recent tool call traces, oldest dropped first
+#[derive(Debug)]
+pub struct TraceLog {
+    capacity: usize,
+    traces: Mutex<VecDeque<ToolCallTrace>>,
+}
+
+impl TraceLog {
+    pub fn new(config: &TraceLogConfig) -> Self {
+        Self {
+            capacity: config.capacity,
+            traces: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn record(&self, trace: ToolCallTrace) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut traces = self.lock();
+        while traces.len() >= self.capacity {
+            traces.pop_front();
+        }
+        traces.push_back(trace);
+    }
+
+    /// Traces matching `query`, newest first
+    pub fn recent(&self, query: &TraceQuery) -> Vec<ToolCallTrace> {
+        self.lock()
+            .iter()
+            .rev()
+            .filter(|trace| query.matches(trace))
+            .take(query.limit.unwrap_or(20))
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<ToolCallTrace>> {
+        self.traces
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_recent_traces_are_filtered_newest_first() {
+        let log = TraceLog::new(&TraceLogConfig { capacity: 2 });
+        for (id, tool, error) in [
+            ("a", "dex_get_pools", None),
+            (
+                "b",
+                "dex_execute_swap",
+                Some("Slippage exceeded: max spread"),
+            ),
+            ("c", "dex_get_pools", None),
+        ] {
+            let context = TraceContext::with_correlation_id(id);
+            context
+                .scope(async {
+                    crate::trace::record_rpc(
+                        "wasm/SmartContractState",
+                        "https://rpc",
+                        Instant::now(),
+                        None,
+                    );
+                })
+                .await;
+            log.record(ToolCallTrace::new(
+                &context,
+                tool,
+                "s1".to_string(),
+                Utc::now(),
+                3,
+                error.map(str::to_string),
+            ));
+        }
+
+        // The oldest trace made room for the newest
+        let all = log.recent(&TraceQuery::default());
+        let ids: Vec<&str> = all.iter().map(|t| t.correlation_id.as_str()).collect();
+        assert_eq!(ids, ["c", "b"]);
+        assert_eq!(all[0].events.len(), 1);
+
+        let failed = log.recent(&TraceQuery {
+            failed_only: true,
+            ..Default::default()
+        });
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].tool, "dex_execute_swap");
+        assert!(log
+            .recent(&TraceQuery {
+                correlation_id: Some("a".to_string()),
+                ..Default::default()
+            })
+            .is_empty());
+
+        let arguments = serde_json::json!({ "_meta": { "correlationId": " flow-7 " } });
+        assert_eq!(
+            correlation_id_from_arguments(&arguments).as_deref(),
+            Some("flow-7")
+        );
+        assert!(correlation_id_from_arguments(&serde_json::json!({})).is_none());
+    }
+}
@@ -0,0 +1,185 @@
+//! Limit and cursor pagination shared by list queries
+//!
+//! Contracts and RPC endpoints hand out long lists a page at a time: pool
+//! manager pools after a pool identifier, ClaimDrop allocations after an
+//! address, EVM logs over a block range. Each list query takes a
+//! [`PageRequest`] and returns a [`Page`] carrying the cursor of the page after
+//! it, if any. A [`Paginator`] turns such a page query into a stream of every
+//! item, fetching the next page only once the previous one is consumed.
+
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+
+use crate::error::Error;
+
+/// Page size used when a caller does not choose one
+pub const DEFAULT_PAGE_SIZE: u32 = 30;
+
+/// Which page to fetch: up to `limit` items after `cursor`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest<C> {
+    /// Position the page starts after; the start of the list when unset
+    pub cursor: Option<C>,
+    /// Most items to return
+    pub limit: u32,
+}
+
+impl<C> PageRequest<C> {
+    /// First page of up to `limit` items
+    pub fn first(limit: u32) -> Self {
+        Self {
+            cursor: None,
+            limit,
+        }
+    }
+
+    /// Page of up to `limit` items after `cursor`
+    pub fn after(cursor: C, limit: u32) -> Self {
+        Self {
+            cursor: Some(cursor),
+            limit,
+        }
+    }
+}
+
+impl<C> Default for PageRequest<C> {
+    fn default() -> Self {
+        Self::first(DEFAULT_PAGE_SIZE)
+    }
+}
+
+/// One page of a list and the cursor of the page after it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T, C> {
+    pub items: Vec<T>,
+    /// Cursor to request the next page with; unset on the last page
+    pub next_cursor: Option<C>,
+}
+
+impl<T, C> Page<T, C> {
+    /// Page of a list keyed by its items, such as pools after a pool identifier
+    ///
+    /// A full page may have more after it, so the cursor is the key of its last
+    /// item; a short page is the last.
+    pub fn keyed(items: Vec<T>, limit: u32, key: impl Fn(&T) -> C) -> Self {
+        let next_cursor = if items.len() >= limit as usize {
+            items.last().map(key)
+        } else {
+            None
+        };
+        Self { items, next_cursor }
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.next_cursor.is_none()
+    }
+}
+
+/// Fetches every page of a list query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Paginator {
+    page_size: u32,
+}
+
+impl Paginator {
+    /// Paginator fetching `page_size` items at a time
+    pub fn new(page_size: u32) -> Self {
+        Self {
+            page_size: page_size.max(1),
+        }
+    }
+
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    /// Every item of the list `fetch` returns a page of, in order
+    ///
+    /// Pages are fetched as the stream is polled, and the stream ends after the
+    /// first error.
+    pub fn stream<'a, T, C, F, Fut>(
+        &self,
+        mut fetch: F,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a
+    where
+        T: 'a,
+        C: 'a,
+        F: FnMut(PageRequest<C>) -> Fut + 'a,
+        Fut: Future<Output = Result<Page<T, C>, Error>> + 'a,
+    {
+        let limit = self.page_size;
+        stream::try_unfold(Some(PageRequest::first(limit)), move |request| {
+            let page = request.map(&mut fetch);
+            async move {
+                let Some(page) = page else {
+                    return Ok::<_, Error>(None);
+                };
+                let page = page.await?;
+                let next = page
+                    .next_cursor
+                    .map(|cursor| PageRequest::after(cursor, limit));
+                Ok(Some((page.items, next)))
+            }
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Every item of the list `fetch` returns a page of, collected
+    pub async fn collect<T, C, F, Fut>(&self, fetch: F) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(PageRequest<C>) -> Fut,
+        Fut: Future<Output = Result<Page<T, C>, Error>>,
+    {
+        let stream = self.stream(fetch);
+        futures::pin_mut!(stream);
+        let mut items = Vec::new();
+        while let Some(item) = stream.next().await {
+            items.push(item?);
+        }
+        Ok(items)
+    }
+}
+
+impl Default for Paginator {
+    fn default() -> Self {
+        Self::new(DEFAULT_PAGE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn test_pages_follow_cursors_until_a_short_page() {
+        let list: Vec<u32> = (1..=7).collect();
+        let fetches = Cell::new(0);
+        let fetch = |request: PageRequest<u32>| {
+            fetches.set(fetches.get() + 1);
+            let items: Vec<u32> = list
+                .iter()
+                .copied()
+                .filter(|item| request.cursor.is_none_or(|cursor| *item > cursor))
+                .take(request.limit as usize)
+                .collect();
+            async move { Ok(Page::keyed(items, request.limit, |item| *item)) }
+        };
+
+        let paginator = Paginator::new(3);
+        assert_eq!(paginator.collect(fetch).await.unwrap(), list);
+        assert_eq!(fetches.get(), 3);
+
+        // Only the pages consumed are fetched
+        fetches.set(0);
+        let first_four: Vec<u32> = paginator.stream(fetch).take(4).try_collect().await.unwrap();
+        assert_eq!(first_four, [1, 2, 3, 4]);
+        assert_eq!(fetches.get(), 2);
+
+        let failing = |_: PageRequest<u32>| async {
+            Err::<Page<u32, u32>, _>(Error::Other("rpc down".to_string()))
+        };
+        assert!(paginator.collect(failing).await.is_err());
+    }
+}
@@ -0,0 +1,371 @@
+//! Broadcast confirmation tracking per network and RPC endpoint
+//!
+//! Every broadcast that waits for inclusion records how long the endpoint took to
+//! return a confirmation, or that it failed to. The samples feed two consumers:
+//! endpoint scoring (used by connection pools to decide when to fail over) and
+//! reliability summaries for operators.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Samples kept per endpoint; older samples are discarded first
+pub const MAX_SAMPLES_PER_ENDPOINT: usize = 200;
+
+/// Default confirmation-time SLA
+pub const DEFAULT_CONFIRMATION_SLA: Duration = Duration::from_secs(10);
+
+/// A single broadcast confirmation observation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ConfirmationSample {
+    /// Unix timestamp (seconds) when the broadcast finished
+    pub timestamp: u64,
+    pub latency_ms: u64,
+    /// Whether the endpoint returned a confirmation (contract failures still count
+    /// as confirmed - only transport/RPC failures are endpoint failures)
+    pub success: bool,
+}
+
+/// Confirmation statistics for one endpoint over a sample window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointStats {
+    pub network: String,
+    pub endpoint: String,
+    pub samples: usize,
+    pub failures: usize,
+    pub success_rate: f64,
+    /// Fraction of successful confirmations that met the SLA
+    pub within_sla_rate: f64,
+    pub sla_ms: u64,
+    pub mean_latency_ms: Option<u64>,
+    pub p50_latency_ms: Option<u64>,
+    pub p95_latency_ms: Option<u64>,
+    pub last_sample_at: Option<u64>,
+    /// Quality score in `[0, 1]`, higher is better
+    pub score: f64,
+}
+
+/// Confirmation statistics for a network across all of its endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkStats {
+    pub network: String,
+    pub samples: usize,
+    pub failures: usize,
+    pub success_rate: f64,
+    pub within_sla_rate: f64,
+    pub p50_latency_ms: Option<u64>,
+    pub p95_latency_ms: Option<u64>,
+    /// Endpoints ordered from best to worst score
+    pub endpoints: Vec<EndpointStats>,
+}
+
+type EndpointKey = (String, String);
+
+/// Records broadcast confirmation times and scores endpoints
+#[derive(Debug)]
+pub struct ConfirmationTracker {
+    sla: Duration,
+    max_samples: usize,
+    samples: RwLock<HashMap<EndpointKey, VecDeque<ConfirmationSample>>>,
+}
+
+impl ConfirmationTracker {
+    /// Create a tracker with the given SLA and per-endpoint sample capacity
+    pub fn new(sla: Duration, max_samples: usize) -> Self {
+        Self {
+            sla,
+            max_samples: max_samples.max(1),
+            samples: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Process-wide tracker shared by all clients
+    pub fn global() -> &'static ConfirmationTracker {
+        static GLOBAL: OnceLock<ConfirmationTracker> = OnceLock::new();
+        GLOBAL.get_or_init(ConfirmationTracker::default)
+    }
+
+    /// Confirmation-time SLA
+    pub fn sla(&self) -> Duration {
+        self.sla
+    }
+
+    /// Record a broadcast outcome for `endpoint` on `network`
+    pub fn record(&self, network: &str, endpoint: &str, latency: Duration, success: bool) {
+        self.record_sample(
+            network,
+            endpoint,
+            ConfirmationSample {
+                timestamp: unix_now(),
+                latency_ms: latency.as_millis() as u64,
+                success,
+            },
+        );
+    }
+
+    /// Record a pre-built sample
+    pub fn record_sample(&self, network: &str, endpoint: &str, sample: ConfirmationSample) {
+        let mut samples = self.samples.write().unwrap_or_else(|e| e.into_inner());
+        let entry = samples
+            .entry((network.to_string(), endpoint.to_string()))
+            .or_default();
+        if entry.len() == self.max_samples {
+            entry.pop_front();
+        }
+        entry.push_back(sample);
+    }
+
+    /// Statistics for a single endpoint, optionally limited to the last `window`
+    pub fn endpoint_stats(
+        &self,
+        network: &str,
+        endpoint: &str,
+        window: Option<Duration>,
+    ) -> Option<EndpointStats> {
+        let samples = self.samples.read().unwrap_or_else(|e| e.into_inner());
+        let entry = samples.get(&(network.to_string(), endpoint.to_string()))?;
+        let recent = filter_window(entry, window);
+        (!recent.is_empty()).then(|| self.summarize(network, endpoint, &recent))
+    }
+
+    /// Statistics for a network, optionally limited to the last `window`
+    pub fn network_stats(&self, network: &str, window: Option<Duration>) -> Option<NetworkStats> {
+        let samples = self.samples.read().unwrap_or_else(|e| e.into_inner());
+
+        let mut all = Vec::new();
+        let mut endpoints = Vec::new();
+        for ((net, endpoint), entry) in samples.iter() {
+            if net != network {
+                continue;
+            }
+            let recent = filter_window(entry, window);
+            if recent.is_empty() {
+                continue;
+            }
+            endpoints.push(self.summarize(network, endpoint, &recent));
+            all.extend(recent);
+        }
+        if all.is_empty() {
+            return None;
+        }
+        endpoints.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let summary = self.summarize(network, "", &all);
+        Some(NetworkStats {
+            network: network.to_string(),
+            samples: summary.samples,
+            failures: summary.failures,
+            success_rate: summary.success_rate,
+            within_sla_rate: summary.within_sla_rate,
+            p50_latency_ms: summary.p50_latency_ms,
+            p95_latency_ms: summary.p95_latency_ms,
+            endpoints,
+        })
+    }
+
+    /// Statistics for every network with samples
+    pub fn all_network_stats(&self, window: Option<Duration>) -> Vec<NetworkStats> {
+        let mut networks: Vec<String> = {
+            let samples = self.samples.read().unwrap_or_else(|e| e.into_inner());
+            samples.keys().map(|(network, _)| network.clone()).collect()
+        };
+        networks.sort();
+        networks.dedup();
+        networks
+            .iter()
+            .filter_map(|network| self.network_stats(network, window))
+            .collect()
+    }
+
+    /// Quality score of an endpoint in `[0, 1]`
+    ///
+    /// Endpoints without samples score 1.0 so new endpoints are tried before
+    /// known-bad ones.
+    pub fn score(&self, network: &str, endpoint: &str) -> f64 {
+        self.endpoint_stats(network, endpoint, None)
+            .map(|stats| stats.score)
+            .unwrap_or(1.0)
+    }
+
+    /// Order candidate endpoints from best to worst score for failover
+    pub fn rank_endpoints<'a>(
+        &self,
+        network: &str,
+        endpoints: &'a [String],
+    ) -> Vec<(&'a str, f64)> {
+        let mut ranked: Vec<(&str, f64)> = endpoints
+            .iter()
+            .map(|endpoint| (endpoint.as_str(), self.score(network, endpoint)))
+            .collect();
+        // Stable sort keeps the configured order for equal scores
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked
+    }
+
+    /// Drop all recorded samples
+    pub fn clear(&self) {
+        self.samples
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    fn summarize(
+        &self,
+        network: &str,
+        endpoint: &str,
+        samples: &[ConfirmationSample],
+    ) -> EndpointStats {
+        let sla_ms = self.sla.as_millis() as u64;
+        let mut latencies: Vec<u64> = samples
+            .iter()
+            .filter(|s| s.success)
+            .map(|s| s.latency_ms)
+            .collect();
+        latencies.sort_unstable();
+
+        let failures = samples.len() - latencies.len();
+        let success_rate = latencies.len() as f64 / samples.len() as f64;
+        let within_sla = latencies.iter().filter(|&&l| l <= sla_ms).count();
+        let within_sla_rate = if latencies.is_empty() {
+            0.0
+        } else {
+            within_sla as f64 / latencies.len() as f64
+        };
+        let p95 = percentile(&latencies, 95);
+
+        // Success rate weighted by how far the tail latency is over the SLA
+        let latency_factor = match p95 {
+            Some(p95) if p95 > sla_ms && p95 > 0 => sla_ms as f64 / p95 as f64,
+            _ => 1.0,
+        };
+
+        EndpointStats {
+            network: network.to_string(),
+            endpoint: endpoint.to_string(),
+            samples: samples.len(),
+            failures,
+            success_rate,
+            within_sla_rate,
+            sla_ms,
+            mean_latency_ms: (!latencies.is_empty())
+                .then(|| latencies.iter().sum::<u64>() / latencies.len() as u64),
+            p50_latency_ms: percentile(&latencies, 50),
+            p95_latency_ms: p95,
+            last_sample_at: samples.iter().map(|s| s.timestamp).max(),
+            score: success_rate * latency_factor,
+        }
+    }
+}
+
+impl Default for ConfirmationTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONFIRMATION_SLA, MAX_SAMPLES_PER_ENDPOINT)
+    }
+}
+
+fn filter_window(
+    samples: &VecDeque<ConfirmationSample>,
+    window: Option<Duration>,
+) -> Vec<ConfirmationSample> {
+    let since = window
+        .map(|window| unix_now().saturating_sub(window.as_secs()))
+        .unwrap_or(0);
+    samples
+        .iter()
+        .filter(|s| s.timestamp >= since)
+        .copied()
+        .collect()
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[u64], pct: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    Some(sorted[rank - 1])
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_and_scoring() {
+        let tracker = ConfirmationTracker::new(Duration::from_secs(5), 10);
+        for _ in 0..9 {
+            tracker.record("mantra-1", "https://fast", Duration::from_secs(2), true);
+        }
+        tracker.record("mantra-1", "https://fast", Duration::from_secs(3), false);
+        for _ in 0..10 {
+            tracker.record("mantra-1", "https://slow", Duration::from_secs(20), true);
+        }
+
+        let fast = tracker
+            .endpoint_stats("mantra-1", "https://fast", None)
+            .unwrap();
+        assert_eq!(fast.samples, 10);
+        assert_eq!(fast.failures, 1);
+        assert_eq!(fast.p95_latency_ms, Some(2_000));
+        assert!((fast.score - 0.9).abs() < 1e-9);
+
+        let slow = tracker
+            .endpoint_stats("mantra-1", "https://slow", None)
+            .unwrap();
+        assert_eq!(slow.within_sla_rate, 0.0);
+        assert!((slow.score - 0.25).abs() < 1e-9);
+
+        let network = tracker.network_stats("mantra-1", None).unwrap();
+        assert_eq!(network.samples, 20);
+        assert_eq!(network.endpoints[0].endpoint, "https://fast");
+
+        let candidates = vec![
+            "https://slow".to_string(),
+            "https://new".to_string(),
+            "https://fast".to_string(),
+        ];
+        let ranked: Vec<&str> = tracker
+            .rank_endpoints("mantra-1", &candidates)
+            .into_iter()
+            .map(|(endpoint, _)| endpoint)
+            .collect();
+        assert_eq!(ranked, vec!["https://new", "https://fast", "https://slow"]);
+    }
+
+    #[test]
+    fn test_sample_capacity_and_window() {
+        let tracker = ConfirmationTracker::new(DEFAULT_CONFIRMATION_SLA, 3);
+        for _ in 0..5 {
+            tracker.record("net", "rpc", Duration::from_millis(100), true);
+        }
+        tracker.record_sample(
+            "net",
+            "rpc",
+            ConfirmationSample {
+                timestamp: unix_now() - 3_600,
+                latency_ms: 100,
+                success: false,
+            },
+        );
+
+        assert_eq!(
+            tracker.endpoint_stats("net", "rpc", None).unwrap().samples,
+            3
+        );
+        let recent = tracker
+            .endpoint_stats("net", "rpc", Some(Duration::from_secs(60)))
+            .unwrap();
+        assert_eq!(recent.samples, 2);
+        assert_eq!(recent.failures, 0);
+        assert!(tracker.network_stats("other", None).is_none());
+    }
+}
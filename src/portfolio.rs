@@ -0,0 +1,486 @@
+//! Portfolio of one address across bank, DEX, staking, ClaimDrop and EVM
+//!
+//! There is no price oracle on chain, so USD values come from the DEX itself:
+//! USD stablecoins are worth one dollar, and every other denom is priced
+//! through the spot price of a pool that pairs it with an already priced
+//! denom. Denoms no pool connects to a stablecoin are listed without a value.
+//! Each part of a [`Portfolio`] is fetched independently; a part that fails is
+//! recorded in [`Portfolio::errors`] rather than failing the whole portfolio.
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::config::contracts::{ContractRegistry, ContractType};
+use crate::error::Error;
+use crate::protocols::claimdrop::AggregatedRewards;
+use crate::protocols::dex::depth::pool_denom_price;
+use crate::protocols::dex::types::StakedBalance;
+use crate::protocols::dex::MantraDexClient;
+
+/// Decimals of DEX LP tokens
+pub const LP_TOKEN_DECIMALS: u8 = 6;
+
+/// Whether `denom` is a USD stablecoin, judged by its name
+///
+/// Only the last path segment is looked at, so `factory/<addr>/uusdc` counts
+/// but IBC hashes do not.
+pub fn is_usd_stablecoin(denom: &str) -> bool {
+    let name = denom.rsplit('/').next().unwrap_or(denom).to_lowercase();
+    let base = name.strip_prefix('u').unwrap_or(&name);
+    ["usdc", "usdt", "usd"].contains(&name.as_str()) || ["usdc", "usdt", "usd"].contains(&base)
+}
+
+/// USD price of every denom the DEX connects to a stablecoin
+#[derive(Debug, Clone, Default)]
+pub struct UsdPrices {
+    prices: HashMap<String, f64>,
+    decimals: HashMap<String, u8>,
+}
+
+impl UsdPrices {
+    /// Prices derived from the spot prices of `pools`
+    pub fn from_pools(pools: &[PoolInfoResponse]) -> Self {
+        let mut decimals = HashMap::new();
+        for pool in pools {
+            let info = &pool.pool_info;
+            for (denom, decimal) in info.asset_denoms.iter().zip(&info.asset_decimals) {
+                decimals.insert(denom.clone(), *decimal);
+            }
+        }
+
+        let mut prices: HashMap<String, f64> = decimals
+            .keys()
+            .filter(|denom| is_usd_stablecoin(denom))
+            .map(|denom| (denom.clone(), 1.0))
+            .collect();
+        let mut queue: VecDeque<String> = prices.keys().cloned().collect();
+        while let Some(quote) = queue.pop_front() {
+            let quote_price = prices[&quote];
+            for pool in pools {
+                let denoms = &pool.pool_info.asset_denoms;
+                if !denoms.contains(&quote) {
+                    continue;
+                }
+                for denom in denoms {
+                    if prices.contains_key(denom) {
+                        continue;
+                    }
+                    let Ok(price) = pool_denom_price(pool, denom, &quote) else {
+                        continue;
+                    };
+                    if price.is_finite() && price > 0.0 {
+                        prices.insert(denom.clone(), price * quote_price);
+                        queue.push_back(denom.clone());
+                    }
+                }
+            }
+        }
+        Self { prices, decimals }
+    }
+
+    /// USD price of one whole `denom`
+    pub fn price(&self, denom: &str) -> Option<f64> {
+        self.prices.get(denom).copied()
+    }
+
+    /// Decimals of `denom`, as declared by the pools it trades in
+    pub fn decimals(&self, denom: &str) -> Option<u8> {
+        self.decimals.get(denom).copied()
+    }
+
+    /// USD value of `coin`
+    pub fn usd_value(&self, coin: &Coin) -> Option<f64> {
+        let whole = coin.amount.u128() as f64 / 10f64.powi(self.decimals(&coin.denom)? as i32);
+        Some(whole * self.price(&coin.denom)?)
+    }
+}
+
+/// Where a portfolio entry is held
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PortfolioCategory {
+    Bank,
+    Liquidity,
+    Staked,
+    ClaimDrop,
+    Evm,
+}
+
+impl PortfolioCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            PortfolioCategory::Bank => "Bank",
+            PortfolioCategory::Liquidity => "Liquidity",
+            PortfolioCategory::Staked => "Staked",
+            PortfolioCategory::ClaimDrop => "ClaimDrop",
+            PortfolioCategory::Evm => "EVM",
+        }
+    }
+}
+
+/// One holding of a portfolio
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioEntry {
+    pub category: PortfolioCategory,
+    /// What the holding is, e.g. the pool or validator it sits in
+    pub label: String,
+    pub denom: String,
+    pub amount: Uint128,
+    /// Decimals of `denom`, when known
+    pub decimals: Option<u8>,
+    pub usd_value: Option<f64>,
+}
+
+impl PortfolioEntry {
+    /// Amount in whole units, or base units when the decimals are unknown
+    pub fn whole_amount(&self) -> f64 {
+        self.amount.u128() as f64 / 10f64.powi(self.decimals.unwrap_or(0) as i32)
+    }
+}
+
+/// Holdings of one address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Portfolio {
+    pub address: String,
+    pub entries: Vec<PortfolioEntry>,
+    pub fetched_at: DateTime<Utc>,
+    /// Parts of the portfolio that could not be fetched
+    pub errors: Vec<String>,
+}
+
+impl Portfolio {
+    /// Portfolio of `address` from already fetched holdings, valued through
+    /// `pools`
+    ///
+    /// Bank balances of a pool's LP denom are listed as liquidity, valued as
+    /// their share of the pool's reserves.
+    pub fn build(
+        address: &str,
+        balances: &[Coin],
+        pools: &[PoolInfoResponse],
+        staked: &[StakedBalance],
+        rewards: Option<&AggregatedRewards>,
+    ) -> Self {
+        let prices = UsdPrices::from_pools(pools);
+        let entry = |category, label: String, coin: &Coin| PortfolioEntry {
+            category,
+            label,
+            denom: coin.denom.clone(),
+            amount: coin.amount,
+            decimals: prices.decimals(&coin.denom),
+            usd_value: prices.usd_value(coin),
+        };
+
+        let mut entries = Vec::new();
+        for coin in balances.iter().filter(|coin| !coin.amount.is_zero()) {
+            let pool = pools
+                .iter()
+                .find(|pool| pool.pool_info.lp_denom == coin.denom);
+            match pool {
+                Some(pool) => entries.push(PortfolioEntry {
+                    category: PortfolioCategory::Liquidity,
+                    label: pool.pool_info.pool_identifier.clone(),
+                    denom: coin.denom.clone(),
+                    amount: coin.amount,
+                    decimals: Some(LP_TOKEN_DECIMALS),
+                    usd_value: lp_value(pool, coin.amount, &prices),
+                }),
+                None => entries.push(entry(PortfolioCategory::Bank, coin.denom.clone(), coin)),
+            }
+        }
+        for delegation in staked {
+            entries.push(entry(
+                PortfolioCategory::Staked,
+                delegation.validator.clone(),
+                &delegation.amount,
+            ));
+        }
+        for campaign in rewards.map(|r| r.campaigns.as_slice()).unwrap_or_default() {
+            for coin in campaign
+                .pending
+                .iter()
+                .filter(|coin| !coin.amount.is_zero())
+            {
+                entries.push(entry(
+                    PortfolioCategory::ClaimDrop,
+                    campaign.campaign_address.clone(),
+                    coin,
+                ));
+            }
+        }
+
+        Self {
+            address: address.to_string(),
+            entries,
+            fetched_at: Utc::now(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// USD value of every entry that has one
+    pub fn total_usd(&self) -> f64 {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.usd_value)
+            .sum()
+    }
+}
+
+/// USD value of `shares` LP tokens of `pool`, when every reserve is priced
+fn lp_value(pool: &PoolInfoResponse, shares: Uint128, prices: &UsdPrices) -> Option<f64> {
+    let total_share = pool.total_share.amount.u128();
+    if total_share == 0 {
+        return None;
+    }
+    let reserves = pool
+        .pool_info
+        .assets
+        .iter()
+        .map(|asset| prices.usd_value(asset))
+        .sum::<Option<f64>>()?;
+    Some(reserves * shares.u128() as f64 / total_share as f64)
+}
+
+/// Address of `contract_type` on `network` in the contract registry
+pub fn registry_address(network: &str, contract_type: &ContractType) -> Option<String> {
+    ContractRegistry::load()
+        .ok()?
+        .get_network(network)
+        .ok()?
+        .get_address(contract_type)
+        .cloned()
+}
+
+/// Fetch the portfolio of `address`
+///
+/// Pending ClaimDrop rewards are included when a `claimdrop_factory` is
+/// given. Fails only when the pools cannot be fetched, since nothing could be
+/// valued without them.
+pub async fn fetch_portfolio(
+    client: &MantraDexClient,
+    address: &str,
+    claimdrop_factory: Option<&str>,
+) -> Result<Portfolio, Error> {
+    let pools = client.get_pools(None).await?;
+    let mut errors = Vec::new();
+
+    let balances = client
+        .get_balances_for_address(address)
+        .await
+        .unwrap_or_else(|e| {
+            errors.push(format!("balances: {}", e));
+            Vec::new()
+        });
+    let staked = client.get_delegations(address).await.unwrap_or_else(|e| {
+        errors.push(format!("delegations: {}", e));
+        Vec::new()
+    });
+    let rewards = match claimdrop_factory {
+        Some(factory) => match client
+            .claimdrop_factory(factory.to_string())
+            .query_user_rewards(address)
+            .await
+        {
+            Ok(rewards) => Some(rewards),
+            Err(e) => {
+                errors.push(format!("claimdrop rewards: {}", e));
+                None
+            }
+        },
+        None => None,
+    };
+
+    let mut portfolio = Portfolio::build(address, &balances, &pools, &staked, rewards.as_ref());
+    portfolio.errors = errors;
+
+    #[cfg(feature = "evm")]
+    if let Ok(wallet) = client.wallet() {
+        match fetch_evm_entries(client, wallet, &pools).await {
+            Ok(entries) => portfolio.entries.extend(entries),
+            Err(e) => portfolio.errors.push(format!("evm: {}", e)),
+        }
+    }
+
+    Ok(portfolio)
+}
+
+/// Native and known ERC-20 balances of the wallet's EVM address
+///
+/// The native balance has 18 decimals but is the same token as the native
+/// denom, so it is priced as one.
+#[cfg(feature = "evm")]
+async fn fetch_evm_entries(
+    client: &MantraDexClient,
+    wallet: &crate::wallet::MantraWallet,
+    pools: &[PoolInfoResponse],
+) -> Result<Vec<PortfolioEntry>, Error> {
+    use crate::protocols::evm::client::EvmClient;
+    use crate::protocols::evm::types::EthAddress;
+
+    let config = client.config();
+    let (Some(rpc_url), Some(chain_id)) = (&config.evm_rpc_url, config.evm_chain_id) else {
+        return Ok(Vec::new());
+    };
+    let evm = EvmClient::new(rpc_url, chain_id).await?;
+    let owner = EthAddress(wallet.ethereum_address()?);
+    let prices = UsdPrices::from_pools(pools);
+    let to_uint =
+        |value: alloy_primitives::U256| Uint128::new(u128::try_from(value).unwrap_or(u128::MAX));
+    let whole_usd = |amount: Uint128, decimals: u8, price: Option<f64>| {
+        price.map(|price| amount.u128() as f64 / 10f64.powi(decimals as i32) * price)
+    };
+
+    let native = to_uint(evm.get_balance(owner.clone(), None).await?);
+    let mut entries = vec![PortfolioEntry {
+        category: PortfolioCategory::Evm,
+        label: owner.to_string(),
+        denom: config.native_denom.clone(),
+        amount: native,
+        decimals: Some(18),
+        usd_value: whole_usd(native, 18, prices.price(&config.native_denom)),
+    }];
+
+    for (contract_type, symbol) in [
+        (ContractType::EvmUsdc, "usdc"),
+        (ContractType::EvmWeth, "weth"),
+    ] {
+        let Some(address) = registry_address(&config.network_name, &contract_type) else {
+            continue;
+        };
+        let token = evm.erc20(EthAddress::from_str(&address)?.0);
+        let amount = to_uint(token.balance_of(owner.0).await?);
+        if amount.is_zero() {
+            continue;
+        }
+        let decimals = token.decimals().await?;
+        let price = if is_usd_stablecoin(symbol) {
+            Some(1.0)
+        } else {
+            prices.price(symbol)
+        };
+        entries.push(PortfolioEntry {
+            category: PortfolioCategory::Evm,
+            label: address,
+            denom: symbol.to_string(),
+            amount,
+            decimals: Some(decimals),
+            usd_value: whole_usd(amount, decimals, price),
+        });
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::claimdrop::types::CampaignReward;
+    use cosmwasm_std::Decimal;
+    use mantra_dex_std::{
+        fee::{Fee, PoolFee},
+        pool_manager::{PoolInfo, PoolStatus, PoolType},
+    };
+
+    fn pool(
+        id: &str,
+        denoms: [&str; 2],
+        reserves: [u128; 2],
+        total_share: u128,
+    ) -> PoolInfoResponse {
+        let fee = Fee {
+            share: Decimal::zero(),
+        };
+        let lp_denom = format!("{}.lp", id);
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: id.to_string(),
+                asset_denoms: denoms.iter().map(|d| d.to_string()).collect(),
+                lp_denom: lp_denom.clone(),
+                asset_decimals: vec![6, 6],
+                assets: vec![
+                    Coin::new(reserves[0], denoms[0]),
+                    Coin::new(reserves[1], denoms[1]),
+                ],
+                pool_type: PoolType::ConstantProduct,
+                pool_fees: PoolFee {
+                    protocol_fee: fee.clone(),
+                    swap_fee: fee.clone(),
+                    burn_fee: fee,
+                    extra_fees: vec![],
+                },
+                status: PoolStatus {
+                    swaps_enabled: true,
+                    deposits_enabled: true,
+                    withdrawals_enabled: true,
+                },
+            },
+            total_share: Coin::new(total_share, lp_denom),
+        }
+    }
+
+    #[test]
+    fn test_portfolio_values_holdings_through_pools() {
+        assert!(is_usd_stablecoin("factory/mantra1abc/uusdc"));
+        assert!(!is_usd_stablecoin("uom"));
+
+        // OM is priced against USDC, ATOM only through OM
+        let pools = vec![
+            pool(
+                "o.uom.uusdc",
+                ["uom", "uusdc"],
+                [1_000_000_000, 2_000_000_000],
+                1_000,
+            ),
+            pool("o.uatom.uom", ["uatom", "uom"], [1_000_000, 5_000_000], 100),
+        ];
+        let prices = UsdPrices::from_pools(&pools);
+        assert_eq!(prices.price("uom"), Some(2.0));
+        assert_eq!(prices.price("uatom"), Some(10.0));
+
+        let balances = vec![
+            Coin::new(3_000_000u128, "uom"),
+            Coin::new(100u128, "o.uom.uusdc.lp"),
+            Coin::new(0u128, "uatom"),
+            Coin::new(7u128, "unknown"),
+        ];
+        let staked = vec![StakedBalance {
+            validator: "mantravaloper1abc".to_string(),
+            amount: Coin::new(1_000_000u128, "uom"),
+        }];
+        let rewards = AggregatedRewards {
+            total_campaigns: 1,
+            total_claimed: vec![],
+            total_pending: vec![],
+            total_available: vec![],
+            campaigns: vec![CampaignReward {
+                campaign_address: "mantra1campaign".to_string(),
+                campaign_type: None,
+                claimed: vec![],
+                pending: vec![Coin::new(500_000u128, "uatom")],
+                available_to_claim: vec![],
+            }],
+        };
+        let portfolio = Portfolio::build("mantra1me", &balances, &pools, &staked, Some(&rewards));
+
+        let value = |category| {
+            portfolio
+                .entries
+                .iter()
+                .find(|entry| entry.category == category)
+                .and_then(|entry| entry.usd_value)
+        };
+        assert_eq!(portfolio.entries.len(), 5);
+        assert_eq!(value(PortfolioCategory::Bank), Some(6.0));
+        // A tenth of $4000 of reserves
+        assert_eq!(value(PortfolioCategory::Liquidity), Some(400.0));
+        assert_eq!(value(PortfolioCategory::Staked), Some(2.0));
+        assert_eq!(value(PortfolioCategory::ClaimDrop), Some(5.0));
+        assert!(portfolio
+            .entries
+            .iter()
+            .any(|entry| entry.denom == "unknown" && entry.usd_value.is_none()));
+        assert_eq!(portfolio.total_usd(), 413.0);
+    }
+}
@@ -0,0 +1,226 @@
+/// Signed eligibility attestations for ClaimDrop allocations
+///
+/// An attestation snapshots an address's allocations across campaigns (amounts,
+/// vesting and claim status) at a block height and is signed by the exporting
+/// wallet. Third-party frontends can check the signature offline and re-query
+/// the listed campaigns at `block_height` to confirm the figures against chain
+/// state, without running their own indexer.
+use bip32::secp256k1::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use cosmrs::{crypto::PublicKey, AccountId};
+use cosmwasm_std::Uint128;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::vesting::{VestingSchedule, VestingTranche};
+use crate::error::Error;
+use crate::wallet::MantraWallet;
+
+/// Format version of [`EligibilityAttestation`]
+pub const ATTESTATION_VERSION: u32 = 1;
+
+/// An address's allocation in one campaign
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CampaignEligibility {
+    pub campaign_address: String,
+    pub campaign_name: String,
+    pub denom: String,
+    pub allocated: Uint128,
+    pub claimed: Uint128,
+    /// Claimable at the attestation's `issued_at`
+    pub claimable: Uint128,
+    /// Not yet unlocked at the attestation's `issued_at`
+    pub locked: Uint128,
+    pub fully_claimed: bool,
+    pub next_unlock: Option<u64>,
+    pub fully_vested_at: Option<u64>,
+    pub closed_at: Option<u64>,
+    pub tranches: Vec<VestingTranche>,
+}
+
+impl CampaignEligibility {
+    /// Summarize a vesting schedule as of `timestamp`
+    pub fn from_schedule(
+        campaign_name: impl Into<String>,
+        schedule: &VestingSchedule,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            campaign_address: schedule.campaign_address.clone(),
+            campaign_name: campaign_name.into(),
+            denom: schedule.denom.clone(),
+            allocated: schedule.total_allocation,
+            claimed: schedule.claimed,
+            claimable: schedule.claimable_at(timestamp),
+            locked: schedule.locked_at(timestamp),
+            fully_claimed: schedule.claimed >= schedule.total_allocation,
+            next_unlock: schedule.next_unlock_after(timestamp),
+            fully_vested_at: schedule.fully_vested_at(),
+            closed_at: schedule.closed_at,
+            tranches: schedule.tranches.clone(),
+        }
+    }
+}
+
+/// Unsigned snapshot of an address's eligibility
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EligibilityAttestation {
+    pub version: u32,
+    pub chain_id: String,
+    /// Block height the campaign state was read at
+    pub block_height: u64,
+    /// Factory the campaigns were discovered from
+    pub factory_address: String,
+    pub address: String,
+    /// Unix timestamp (seconds) the claimable and locked amounts refer to
+    pub issued_at: u64,
+    /// Campaigns where the address has an allocation
+    pub campaigns: Vec<CampaignEligibility>,
+}
+
+impl EligibilityAttestation {
+    /// Sign the attestation with `wallet`
+    ///
+    /// The signature is a secp256k1 ECDSA signature over the SHA-256 digest of
+    /// `payload`, the compact JSON encoding of the attestation.
+    pub fn sign(self, wallet: &MantraWallet) -> Result<SignedAttestation, Error> {
+        let payload = serde_json::to_string(&self)?;
+        let signature = wallet
+            .signing_key()
+            .sign(payload.as_bytes())
+            .map_err(|e| Error::Wallet(format!("Signing error: {}", e)))?;
+
+        Ok(SignedAttestation {
+            signer: wallet.address()?.to_string(),
+            public_key: hex::encode(wallet.public_key().to_bytes()),
+            signature: base64::Engine::encode(
+                &base64::engine::general_purpose::STANDARD,
+                signature.to_bytes(),
+            ),
+            payload,
+            attestation: self,
+        })
+    }
+}
+
+/// Attestation with the signer's public key and signature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedAttestation {
+    pub attestation: EligibilityAttestation,
+    /// Exact bytes that were signed; verifiers must check this string rather
+    /// than re-serializing `attestation`
+    pub payload: String,
+    /// Bech32 address of the signing wallet
+    pub signer: String,
+    /// Hex-encoded compressed secp256k1 public key
+    pub public_key: String,
+    /// Base64-encoded 64-byte compact signature
+    pub signature: String,
+}
+
+impl SignedAttestation {
+    /// Check the signature, that `signer` matches the public key and that
+    /// `attestation` matches the signed payload
+    pub fn verify(&self) -> Result<(), Error> {
+        let key_bytes = hex::decode(&self.public_key)
+            .map_err(|e| Error::Other(format!("Invalid public key encoding: {}", e)))?;
+        let verifying_key = VerifyingKey::from_sec1_bytes(&key_bytes)
+            .map_err(|e| Error::Other(format!("Invalid public key: {}", e)))?;
+
+        let signature_bytes =
+            base64::Engine::decode(&base64::engine::general_purpose::STANDARD, &self.signature)
+                .map_err(|e| Error::Other(format!("Invalid signature encoding: {}", e)))?;
+        let signature = Signature::from_slice(&signature_bytes)
+            .map_err(|e| Error::Other(format!("Invalid signature: {}", e)))?;
+        verifying_key
+            .verify(self.payload.as_bytes(), &signature)
+            .map_err(|_| Error::Other("Attestation signature does not match".to_string()))?;
+
+        let signer = AccountId::from_str(&self.signer)
+            .map_err(|e| Error::Other(format!("Invalid signer address: {}", e)))?;
+        let expected = PublicKey::from(verifying_key)
+            .account_id(signer.prefix())
+            .map_err(|e| Error::Other(format!("Failed to derive signer address: {}", e)))?;
+        if expected != signer {
+            return Err(Error::Other(
+                "Attestation signer does not match the public key".to_string(),
+            ));
+        }
+
+        let signed: EligibilityAttestation = serde_json::from_str(&self.payload)?;
+        if signed != self.attestation {
+            return Err(Error::Other(
+                "Attestation does not match the signed payload".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::claimdrop::types::DistributionType;
+    use cosmwasm_std::Decimal;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    fn attestation() -> EligibilityAttestation {
+        let schedule = VestingSchedule::from_distribution(
+            "mantra1campaign",
+            "mantra1user",
+            "uom",
+            Uint128::new(1_000),
+            Uint128::new(200),
+            &[DistributionType::LinearVesting {
+                percentage: Decimal::one(),
+                start_time: 1_000,
+                end_time: 2_000,
+                cliff_duration: None,
+            }],
+            None,
+        );
+        EligibilityAttestation {
+            version: ATTESTATION_VERSION,
+            chain_id: "mantra-1".to_string(),
+            block_height: 42,
+            factory_address: "mantra1factory".to_string(),
+            address: "mantra1user".to_string(),
+            issued_at: 1_500,
+            campaigns: vec![CampaignEligibility::from_schedule(
+                "Genesis", &schedule, 1_500,
+            )],
+        }
+    }
+
+    #[test]
+    fn test_campaign_eligibility_from_schedule() {
+        let campaign = &attestation().campaigns[0];
+        assert_eq!(campaign.claimable, Uint128::new(300));
+        assert_eq!(campaign.locked, Uint128::new(500));
+        assert!(!campaign.fully_claimed);
+        assert_eq!(campaign.fully_vested_at, Some(2_000));
+    }
+
+    #[test]
+    fn test_signed_attestation_verifies_and_detects_tampering() {
+        let wallet = MantraWallet::from_mnemonic(MNEMONIC, 0).unwrap();
+        let signed = attestation().sign(&wallet).unwrap();
+        assert_eq!(signed.signer, wallet.address().unwrap().to_string());
+        assert!(signed.verify().is_ok());
+
+        let mut tampered = signed.clone();
+        tampered.attestation.campaigns[0].claimable = Uint128::new(1_000);
+        assert!(tampered.verify().is_err());
+
+        let mut tampered = signed.clone();
+        tampered.payload = tampered
+            .payload
+            .replace("\"block_height\":42", "\"block_height\":43");
+        assert!(tampered.verify().is_err());
+
+        let other = MantraWallet::from_mnemonic(MNEMONIC, 1).unwrap();
+        let mut tampered = signed;
+        tampered.signer = other.address().unwrap().to_string();
+        assert!(tampered.verify().is_err());
+    }
+}
@@ -0,0 +1,411 @@
+/// Campaign builder for ClaimDrop airdrop operators
+///
+/// Collects campaign parameters and allocations (typically from a CSV export),
+/// validates them together before anything is broadcast, and uploads large
+/// allocation lists in chunks so each `add_allocations` transaction stays within
+/// block gas limits.
+use crate::error::Error;
+use cosmwasm_std::{Timestamp, Uint128};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use super::client::ClaimdropClient;
+use super::factory::ClaimdropFactoryClient;
+use super::types::{Allocation, CampaignParams, ClaimdropOperationResult};
+
+/// Default number of allocations sent per `add_allocations` transaction
+pub const DEFAULT_ALLOCATION_CHUNK_SIZE: usize = 500;
+
+/// Outcome of validating a campaign before deployment
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CampaignValidationReport {
+    pub allocation_count: usize,
+    pub total_allocated: Uint128,
+    /// Problems that would make the campaign fail or be unclaimable
+    pub errors: Vec<String>,
+    /// Suspicious but not fatal conditions (e.g. unallocated rewards)
+    pub warnings: Vec<String>,
+}
+
+impl CampaignValidationReport {
+    /// Whether the campaign can be deployed
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Progress update emitted after each allocation chunk is submitted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllocationUploadProgress {
+    /// 1-based index of the chunk just submitted
+    pub chunk: usize,
+    pub total_chunks: usize,
+    pub uploaded: usize,
+    pub total: usize,
+    pub tx_hash: Option<String>,
+}
+
+/// Result of a chunked allocation upload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AllocationUploadReport {
+    pub campaign_address: String,
+    pub total_chunks: usize,
+    pub uploaded: usize,
+    pub total: usize,
+    /// Per-chunk transaction results, in submission order
+    pub chunks: Vec<ClaimdropOperationResult>,
+    /// Set when a chunk failed and the upload stopped early
+    pub failed_chunk: Option<usize>,
+}
+
+impl AllocationUploadReport {
+    /// Whether every allocation was uploaded
+    pub fn is_complete(&self) -> bool {
+        self.failed_chunk.is_none() && self.uploaded == self.total
+    }
+}
+
+/// Result of deploying a campaign through the builder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignDeployment {
+    pub creation: ClaimdropOperationResult,
+    pub upload: Option<AllocationUploadReport>,
+}
+
+/// Builder that validates and deploys a ClaimDrop campaign with its allocations
+#[derive(Debug, Clone)]
+pub struct CampaignBuilder {
+    params: CampaignParams,
+    allocations: Vec<Allocation>,
+    chunk_size: usize,
+}
+
+impl CampaignBuilder {
+    /// Start a builder from campaign parameters
+    pub fn new(params: CampaignParams) -> Self {
+        Self {
+            params,
+            allocations: Vec::new(),
+            chunk_size: DEFAULT_ALLOCATION_CHUNK_SIZE,
+        }
+    }
+
+    /// Append allocations
+    pub fn allocations(mut self, allocations: impl IntoIterator<Item = Allocation>) -> Self {
+        self.allocations.extend(allocations);
+        self
+    }
+
+    /// Append allocations parsed from CSV (see [`parse_allocations_csv`])
+    pub fn allocations_csv(self, csv: &str) -> Result<Self, Error> {
+        let allocations = parse_allocations_csv(csv)?;
+        Ok(self.allocations(allocations))
+    }
+
+    /// Set the number of allocations per `add_allocations` transaction
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Campaign parameters
+    pub fn params(&self) -> &CampaignParams {
+        &self.params
+    }
+
+    /// Allocations collected so far
+    pub fn allocation_list(&self) -> &[Allocation] {
+        &self.allocations
+    }
+
+    /// Allocations split into upload chunks
+    pub fn allocation_chunks(&self) -> Vec<Vec<Allocation>> {
+        self.allocations
+            .chunks(self.chunk_size)
+            .map(|chunk| chunk.to_vec())
+            .collect()
+    }
+
+    /// Validate parameters and allocations against the current time (unix seconds)
+    ///
+    /// Runs the same parameter checks as the campaign contract, plus funding
+    /// sufficiency and duplicate-user checks over the allocation list.
+    pub fn validate(&self, now: u64) -> CampaignValidationReport {
+        let mut report = CampaignValidationReport {
+            allocation_count: self.allocations.len(),
+            ..Default::default()
+        };
+
+        let params = &self.params;
+        let contract_checks = [
+            params.validate_campaign_name_description(),
+            params.validate_campaign_type(),
+            params.validate_campaign_times(Timestamp::from_seconds(now)),
+            params.validate_campaign_distribution(),
+            params.validate_rewards(),
+        ];
+        report.errors.extend(
+            contract_checks
+                .into_iter()
+                .filter_map(|check| check.err().map(|e| e.to_string())),
+        );
+
+        let mut seen = HashSet::with_capacity(self.allocations.len());
+        let mut total = Uint128::zero();
+        for allocation in &self.allocations {
+            if !seen.insert(allocation.user.as_str()) {
+                report
+                    .errors
+                    .push(format!("Duplicate allocation for {}", allocation.user));
+            }
+            if allocation.allocated_amount.is_zero() {
+                report
+                    .errors
+                    .push(format!("Zero allocation for {}", allocation.user));
+            }
+            match total.checked_add(allocation.allocated_amount) {
+                Ok(sum) => total = sum,
+                Err(_) => {
+                    report
+                        .errors
+                        .push("Total allocated amount overflows".to_string());
+                    break;
+                }
+            }
+        }
+        report.total_allocated = total;
+
+        let reward = params.total_reward.amount;
+        if total > reward {
+            report.errors.push(format!(
+                "Allocations total {} exceeds campaign reward {}{}",
+                total, reward, params.total_reward.denom
+            ));
+        } else if !self.allocations.is_empty() && total < reward {
+            report.warnings.push(format!(
+                "{}{} of the campaign reward is not allocated",
+                reward - total,
+                params.total_reward.denom
+            ));
+        }
+        if self.allocations.is_empty() {
+            report.warnings.push(
+                "No allocations provided; they must be added before the campaign starts"
+                    .to_string(),
+            );
+        }
+
+        report
+    }
+
+    /// Validate, create the campaign through the factory, then upload allocations
+    ///
+    /// Every transaction is signed by the executor attached to `factory`.
+    pub async fn deploy<F>(
+        &self,
+        factory: &ClaimdropFactoryClient,
+        now: u64,
+        on_progress: F,
+    ) -> Result<CampaignDeployment, Error>
+    where
+        F: FnMut(&AllocationUploadProgress),
+    {
+        let report = self.validate(now);
+        if !report.is_valid() {
+            return Err(Error::Other(format!(
+                "Campaign validation failed: {}",
+                report.errors.join("; ")
+            )));
+        }
+
+        let creation = factory.create_campaign(self.params.clone()).await?;
+        if !creation.success || self.allocations.is_empty() {
+            return Ok(CampaignDeployment {
+                creation,
+                upload: None,
+            });
+        }
+
+        let campaign_address = creation.campaign_address.clone().ok_or_else(|| {
+            Error::Contract("Campaign was created but its address is unknown".to_string())
+        })?;
+        let upload = factory
+            .campaign_client(campaign_address)
+            .add_allocations_chunked(&self.allocations, self.chunk_size, on_progress)
+            .await?;
+
+        Ok(CampaignDeployment {
+            creation,
+            upload: Some(upload),
+        })
+    }
+}
+
+impl ClaimdropClient {
+    /// Upload allocations in chunks of `chunk_size`, one transaction per chunk
+    ///
+    /// Stops at the first chunk that fails so the operator can resume from
+    /// `uploaded` without double-allocating.
+    pub async fn add_allocations_chunked<F>(
+        &self,
+        allocations: &[Allocation],
+        chunk_size: usize,
+        mut on_progress: F,
+    ) -> Result<AllocationUploadReport, Error>
+    where
+        F: FnMut(&AllocationUploadProgress),
+    {
+        let chunk_size = chunk_size.max(1);
+        let total_chunks = allocations.len().div_ceil(chunk_size);
+        let mut report = AllocationUploadReport {
+            campaign_address: self.contract_address().to_string(),
+            total_chunks,
+            uploaded: 0,
+            total: allocations.len(),
+            chunks: Vec::with_capacity(total_chunks),
+            failed_chunk: None,
+        };
+
+        for (i, chunk) in allocations.chunks(chunk_size).enumerate() {
+            let result = self.add_allocations(chunk.to_vec()).await?;
+            let success = result.success;
+            let tx_hash = result.tx_hash.clone();
+            report.chunks.push(result);
+
+            if !success {
+                report.failed_chunk = Some(i + 1);
+                break;
+            }
+
+            report.uploaded += chunk.len();
+            on_progress(&AllocationUploadProgress {
+                chunk: i + 1,
+                total_chunks,
+                uploaded: report.uploaded,
+                total: report.total,
+                tx_hash,
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+/// Parse allocations from CSV text with `address,amount` rows
+///
+/// A header row (any first row whose amount column is not a number) is skipped,
+/// as are blank lines and lines starting with `#`. Amounts are integers in the
+/// reward denom's base unit.
+pub fn parse_allocations_csv(input: &str) -> Result<Vec<Allocation>, Error> {
+    let mut allocations = Vec::new();
+
+    for (line_no, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line
+            .split(',')
+            .map(|field| field.trim().trim_matches('"'))
+            .collect();
+        if fields.len() != 2 {
+            return Err(Error::Other(format!(
+                "Line {}: expected 2 columns (address,amount), found {}",
+                line_no + 1,
+                fields.len()
+            )));
+        }
+
+        let amount = match fields[1].parse::<u128>() {
+            Ok(amount) => amount,
+            Err(_) if allocations.is_empty() && fields[1].parse::<f64>().is_err() => continue,
+            Err(_) => {
+                return Err(Error::Other(format!(
+                    "Line {}: invalid amount '{}'",
+                    line_no + 1,
+                    fields[1]
+                )))
+            }
+        };
+        if fields[0].is_empty() {
+            return Err(Error::Other(format!("Line {}: empty address", line_no + 1)));
+        }
+
+        allocations.push(Allocation {
+            user: fields[0].to_string(),
+            allocated_amount: Uint128::new(amount),
+        });
+    }
+
+    Ok(allocations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{Coin, Decimal};
+    use mantra_claimdrop_std::msg::DistributionType;
+
+    fn params(total: u128) -> CampaignParams {
+        CampaignParams {
+            name: "Airdrop".to_string(),
+            description: "Test airdrop".to_string(),
+            ty: "airdrop".to_string(),
+            total_reward: Coin::new(total, "uom"),
+            distribution_type: vec![DistributionType::LumpSum {
+                percentage: Decimal::percent(100),
+                start_time: 2_000,
+            }],
+            start_time: 2_000,
+            end_time: 3_000,
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_with_header_and_comments() {
+        let csv = "address,amount\n# team\nmantra1a, 100\n\n\"mantra1b\",250\n";
+        let allocations = parse_allocations_csv(csv).unwrap();
+        assert_eq!(allocations.len(), 2);
+        assert_eq!(allocations[1].user, "mantra1b");
+        assert_eq!(allocations[1].allocated_amount, Uint128::new(250));
+
+        assert!(parse_allocations_csv("mantra1a,100\nmantra1b,abc").is_err());
+        assert!(parse_allocations_csv("mantra1a,100,extra").is_err());
+    }
+
+    #[test]
+    fn test_validation_catches_funding_and_duplicates() {
+        let builder = CampaignBuilder::new(params(300))
+            .allocations_csv("mantra1a,100\nmantra1b,150\nmantra1a,100")
+            .unwrap();
+        let report = builder.validate(1_000);
+        assert!(!report.is_valid());
+        assert_eq!(report.total_allocated, Uint128::new(350));
+        assert!(report.errors.iter().any(|e| e.contains("Duplicate")));
+        assert!(report.errors.iter().any(|e| e.contains("exceeds")));
+
+        let report = CampaignBuilder::new(params(300))
+            .allocations_csv("mantra1a,100")
+            .unwrap()
+            .validate(1_000);
+        assert!(report.is_valid());
+        assert_eq!(report.warnings.len(), 1);
+
+        // Campaign start already passed
+        assert!(!CampaignBuilder::new(params(300)).validate(2_500).is_valid());
+    }
+
+    #[test]
+    fn test_allocation_chunks() {
+        let builder = CampaignBuilder::new(params(1_000))
+            .allocations((0..7).map(|i| Allocation {
+                user: format!("mantra1user{}", i),
+                allocated_amount: Uint128::new(10),
+            }))
+            .chunk_size(3);
+        let chunks = builder.allocation_chunks();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2].len(), 1);
+    }
+}
@@ -1,11 +1,13 @@
 /// ClaimDrop client for interacting with individual claimdrop campaigns
 use crate::error::Error;
+use crate::pagination::{Page, PageRequest, Paginator};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::protocols::dex::MantraDexClient;
+use crate::transport::{self, SharedTransport};
 use crate::wallet::MantraWallet;
-use cosmrs::rpc::{Client as RpcClient, HttpClient};
-use cosmrs::tx::Fee;
 use cosmwasm_std::{Coin, Uint128};
+use futures::stream::{self, Stream, TryStreamExt};
 use std::sync::Arc;
-use tokio::sync::Mutex;
 
 // Import ClaimDrop std types
 use mantra_claimdrop_std::msg::{
@@ -13,26 +15,43 @@ use mantra_claimdrop_std::msg::{
     ClaimedResponse, ExecuteMsg, QueryMsg, RewardsResponse,
 };
 
+use super::closure::{
+    recovered_amounts, sweepable_balances, ClosureReadiness, EndOfLifeReport, SweepOutcome,
+};
+use super::executor::{tx_result, ContractCall, SharedExecutor};
+use super::merkle::MerkleExecuteMsg;
+use super::stats::STATS_PAGE_SIZE;
 use super::types::*;
+use super::vesting::VestingSchedule;
+
+/// Campaigns claimed from in one transaction, so the batch stays within the
+/// DEX client's gas limit
+pub const MAX_BATCH_CLAIMS: usize = 10;
 
 /// Client for interacting with a specific ClaimDrop campaign contract
 pub struct ClaimdropClient {
-    rpc_client: Arc<Mutex<HttpClient>>,
+    transport: SharedTransport,
     contract_address: String,
     wallet: Option<Arc<MantraWallet>>,
+    /// Signs and broadcasts execute messages
+    executor: Option<SharedExecutor>,
+    /// Block height queries read state at, the latest when unset
+    query_height: Option<u64>,
 }
 
 impl ClaimdropClient {
     /// Create a new ClaimDrop client for a specific campaign
     pub fn new(
-        rpc_client: Arc<Mutex<HttpClient>>,
+        transport: SharedTransport,
         contract_address: String,
         wallet: Option<Arc<MantraWallet>>,
     ) -> Self {
         Self {
-            rpc_client,
+            transport,
             contract_address,
             wallet,
+            executor: None,
+            query_height: None,
         }
     }
 
@@ -46,62 +65,80 @@ impl ClaimdropClient {
         self.wallet = Some(wallet);
     }
 
+    /// Read contract state at `height` instead of the latest block
+    pub fn set_query_height(&mut self, height: Option<u64>) {
+        self.query_height = height;
+    }
+
+    /// Attach a DEX client with a wallet to sign transactions
+    ///
+    /// Every execute method signs and broadcasts through it at the network's
+    /// gas price, sharing the client's sequence handling, signing log and
+    /// transaction queue.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_dex_client(&mut self, dex_client: Arc<MantraDexClient>) {
+        self.executor = Some(dex_client);
+    }
+
+    /// Sign and broadcast execute messages through `executor`
+    pub fn set_executor(&mut self, executor: SharedExecutor) {
+        self.executor = Some(executor);
+    }
+
     /// Helper method to query the contract
     async fn query<R: serde::de::DeserializeOwned>(
         &self,
         query_msg: &QueryMsg,
     ) -> Result<R, Error> {
-        use cosmos_sdk_proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest;
-        use prost::Message;
-
-        let rpc_client = self.rpc_client.lock().await;
-        let query = QuerySmartContractStateRequest {
-            address: self.contract_address.clone(),
-            query_data: serde_json::to_vec(query_msg)?,
-        };
-
-        let data = query.encode_to_vec();
-        let result = rpc_client
-            .abci_query(
-                Some("/cosmwasm.wasm.v1.Query/SmartContractState".to_string()),
-                data,
-                None,
-                false,
-            )
-            .await
-            .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))?;
-
-        if !result.code.is_ok() {
-            return Err(Error::Contract(format!(
-                "Contract query failed with code {:?}: {}",
-                result.code, result.log
-            )));
-        }
-
-        let response_data: R =
-            serde_json::from_slice(&result.value).map_err(Error::Serialization)?;
-
-        Ok(response_data)
+        transport::smart_query(
+            self.transport.as_ref(),
+            &self.contract_address,
+            query_msg,
+            self.query_height,
+        )
+        .await
     }
 
     /// Helper method to execute a contract message
     async fn execute<T: serde::Serialize>(
         &self,
-        _msg: &T,
-        _funds: Vec<Coin>,
-        _fee: Fee,
+        msg: &T,
+        funds: Vec<Coin>,
     ) -> Result<ClaimdropOperationResult, Error> {
-        // This is a simplified implementation - a full implementation would handle
-        // transaction signing and broadcasting properly
+        let call = ContractCall::new(&self.contract_address, msg, funds)?;
+        let response = signer(&self.executor)?
+            .execute_contracts(vec![call])
+            .await?;
         Ok(ClaimdropOperationResult {
-            success: false,
-            tx_hash: None,
-            message: "Transaction execution not yet fully implemented".to_string(),
             campaign_address: Some(self.contract_address.clone()),
-            data: None,
+            ..tx_result(&response, None)
         })
     }
 
+    /// Helper method to execute several contract messages in a single transaction
+    async fn execute_batch<T: serde::Serialize>(
+        &self,
+        msgs: &[(String, T)],
+    ) -> Result<ClaimdropOperationResult, Error> {
+        let calls = msgs
+            .iter()
+            .map(|(contract, msg)| ContractCall::new(contract, msg, vec![]))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let response = signer(&self.executor)?.execute_contracts(calls).await?;
+        let contracts = msgs
+            .iter()
+            .map(|(contract, _)| contract)
+            .collect::<Vec<_>>();
+        Ok(tx_result(
+            &response,
+            Some(serde_json::json!({
+                "messages": msgs.len(),
+                "contracts": contracts,
+            })),
+        ))
+    }
+
     // ============ Query Methods ============
 
     /// Query campaign information
@@ -192,6 +229,121 @@ impl ClaimdropClient {
         Ok(result)
     }
 
+    /// Query an address's vesting schedule in this campaign
+    ///
+    /// Combines the campaign's distribution types with the address's allocation
+    /// and claimed amount, so claimable amounts can be projected at any time.
+    pub async fn query_vesting_schedule(&self, address: &str) -> Result<VestingSchedule, Error> {
+        let campaign = self.query_campaign().await?;
+        self.vesting_schedule_for(&campaign, address)
+            .await?
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "No allocation for {} in campaign {}",
+                    address, self.contract_address
+                ))
+            })
+    }
+
+    /// Query an address's vesting schedule given already-fetched campaign info
+    ///
+    /// Returns `None` when the address has no allocation in the campaign.
+    pub async fn vesting_schedule_for(
+        &self,
+        campaign: &CampaignInfo,
+        address: &str,
+    ) -> Result<Option<VestingSchedule>, Error> {
+        let Some(allocation) = self
+            .query_allocations(Some(address), None, None)
+            .await?
+            .into_iter()
+            .find(|allocation| allocation.user == address)
+        else {
+            return Ok(None);
+        };
+        let rewards = self.query_rewards(address).await?;
+
+        let denom = &campaign.total_reward.denom;
+        let claimed = rewards
+            .claimed
+            .iter()
+            .filter(|coin| &coin.denom == denom)
+            .map(|coin| coin.amount)
+            .sum();
+
+        Ok(Some(VestingSchedule::from_distribution(
+            self.contract_address.clone(),
+            address,
+            denom.clone(),
+            allocation.allocated_amount,
+            claimed,
+            &campaign.distribution_type,
+            campaign.closed_at,
+        )))
+    }
+
+    /// Query one page of allocations, in address order
+    pub async fn allocations_page(
+        &self,
+        request: PageRequest<String>,
+    ) -> Result<Page<Allocation, String>, Error> {
+        let limit = page_limit(request.limit);
+        let allocations = self
+            .query_allocations(None, request.cursor.as_deref(), Some(limit))
+            .await?;
+        Ok(Page::keyed(allocations, limit.into(), |allocation| {
+            allocation.user.clone()
+        }))
+    }
+
+    /// Stream every allocation in the campaign, `page_size` at a time
+    pub fn allocations_stream(
+        &self,
+        page_size: u16,
+    ) -> impl Stream<Item = Result<Allocation, Error>> + '_ {
+        Paginator::new(page_size.into()).stream(move |request| self.allocations_page(request))
+    }
+
+    /// Query every allocation in the campaign, following pagination
+    pub async fn query_all_allocations(&self, page_size: u16) -> Result<Vec<Allocation>, Error> {
+        Paginator::new(page_size.into())
+            .collect(|request| self.allocations_page(request))
+            .await
+    }
+
+    /// Query one page of claimed amounts, in address order
+    pub async fn claimed_page(
+        &self,
+        request: PageRequest<String>,
+    ) -> Result<Page<(String, Vec<Coin>), String>, Error> {
+        let limit = page_limit(request.limit);
+        let claimed = self
+            .query_claimed(None, request.cursor.as_deref(), Some(limit))
+            .await?;
+        Ok(Page::keyed(claimed, limit.into(), |(address, _)| {
+            address.clone()
+        }))
+    }
+
+    /// Stream claimed amounts for every address, `page_size` addresses at a
+    /// time
+    pub fn claimed_stream(
+        &self,
+        page_size: u16,
+    ) -> impl Stream<Item = Result<(String, Coin), Error>> + '_ {
+        Paginator::new(page_size.into())
+            .stream(move |request| self.claimed_page(request))
+            .map_ok(|(address, coins)| {
+                stream::iter(coins.into_iter().map(move |c| Ok((address.clone(), c))))
+            })
+            .try_flatten()
+    }
+
+    /// Query claimed amounts for every address, following pagination
+    pub async fn query_all_claimed(&self, page_size: u16) -> Result<Vec<(String, Coin)>, Error> {
+        self.claimed_stream(page_size).try_collect().await
+    }
+
     /// Check if an address is blacklisted
     pub async fn is_blacklisted(&self, address: &str) -> Result<bool, Error> {
         let query_msg = QueryMsg::IsBlacklisted {
@@ -217,17 +369,116 @@ impl ClaimdropClient {
         &self,
         amount: Option<Uint128>,
         receiver: Option<String>,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
         let msg = ExecuteMsg::Claim { amount, receiver };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
+    }
+
+    /// Work out which campaigns the active wallet can currently claim from
+    ///
+    /// Campaigns with nothing available, or whose rewards query fails, are reported
+    /// in `skipped` rather than aborting the whole plan.
+    pub async fn plan_claim_all(
+        &self,
+        campaign_addresses: &[String],
+    ) -> Result<ClaimAllPlan, Error> {
+        let wallet = self.wallet.as_ref().ok_or(Error::WalletNotSet)?;
+        let receiver = wallet.address()?.to_string();
+
+        let mut claims = Vec::new();
+        let mut skipped = Vec::new();
+        for campaign_address in campaign_addresses {
+            let campaign =
+                ClaimdropClient::new(self.transport.clone(), campaign_address.clone(), None);
+            match campaign.query_rewards(&receiver).await {
+                Ok(rewards) => {
+                    let available: Vec<Coin> = rewards
+                        .available_to_claim
+                        .into_iter()
+                        .filter(|coin| !coin.amount.is_zero())
+                        .collect();
+                    if available.is_empty() {
+                        skipped.push(SkippedClaim {
+                            campaign_address: campaign_address.clone(),
+                            reason: "nothing available to claim".to_string(),
+                        });
+                    } else {
+                        claims.push(PlannedClaim {
+                            campaign_address: campaign_address.clone(),
+                            available_to_claim: available,
+                        });
+                    }
+                }
+                Err(e) => skipped.push(SkippedClaim {
+                    campaign_address: campaign_address.clone(),
+                    reason: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(ClaimAllPlan {
+            receiver,
+            claims,
+            skipped,
+        })
+    }
+
+    /// Claim everything available across several campaigns in a single transaction
+    ///
+    /// Campaigns with zero claimable rewards are skipped; at most
+    /// [`MAX_BATCH_CLAIMS`] may be claimable. Returns the plan that was executed
+    /// together with the transaction result (`None` if nothing was claimable).
+    /// Requires a DEX client attached with [`ClaimdropClient::set_dex_client`].
+    pub async fn claim_all(
+        &self,
+        campaign_addresses: &[String],
+    ) -> Result<(ClaimAllPlan, Option<ClaimdropOperationResult>), Error> {
+        let plan = self.plan_claim_all(campaign_addresses).await?;
+        if plan.claims.is_empty() {
+            return Ok((plan, None));
+        }
+        if plan.claims.len() > MAX_BATCH_CLAIMS {
+            return Err(Error::Other(format!(
+                "{} campaigns have rewards to claim, more than the {} one transaction can claim",
+                plan.claims.len(),
+                MAX_BATCH_CLAIMS
+            )));
+        }
+
+        let result = self.execute_batch(&claim_all_msgs(&plan)).await?;
+        Ok((plan, Some(result)))
+    }
+
+    /// Claim rewards from a merkle-root campaign by presenting an allocation proof
+    ///
+    /// `amount` must be the full allocated amount the proof was generated for.
+    pub async fn claim_with_proof(
+        &self,
+        amount: Uint128,
+        proof: Vec<String>,
+        receiver: Option<String>,
+    ) -> Result<ClaimdropOperationResult, Error> {
+        if proof
+            .iter()
+            .any(|hash| hex::decode(hash).map(|b| b.len()) != Ok(32))
+        {
+            return Err(Error::Other(
+                "Merkle proof entries must be hex-encoded 32-byte hashes".to_string(),
+            ));
+        }
+
+        let msg = MerkleExecuteMsg::Claim {
+            amount,
+            proof,
+            receiver,
+        };
+        self.execute(&msg, vec![]).await
     }
 
     /// Add allocations (admin only, before campaign starts)
     pub async fn add_allocations(
         &self,
         allocations: Vec<Allocation>,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
         let allocations_array: Vec<(String, Uint128)> = allocations
             .into_iter()
@@ -237,7 +488,7 @@ impl ClaimdropClient {
         let msg = ExecuteMsg::AddAllocations {
             allocations: allocations_array,
         };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Replace an address in allocations (admin only)
@@ -245,32 +496,26 @@ impl ClaimdropClient {
         &self,
         old_address: &str,
         new_address: &str,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
         let msg = ExecuteMsg::ReplaceAddress {
             old_address: old_address.to_string(),
             new_address: new_address.to_string(),
         };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Remove an address from allocations (admin only)
-    pub async fn remove_address(
-        &self,
-        address: &str,
-        fee: Fee,
-    ) -> Result<ClaimdropOperationResult, Error> {
+    pub async fn remove_address(&self, address: &str) -> Result<ClaimdropOperationResult, Error> {
         let msg = ExecuteMsg::RemoveAddress {
             address: address.to_string(),
         };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Manage blacklist (admin only)
     pub async fn manage_blacklist(
         &self,
         action: BlacklistAction,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
         let msg = match action {
             BlacklistAction::AddToBlacklist { addresses } => {
@@ -286,15 +531,15 @@ impl ClaimdropClient {
             },
         };
 
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Close the campaign (admin only)
-    pub async fn close_campaign(&self, fee: Fee) -> Result<ClaimdropOperationResult, Error> {
+    pub async fn close_campaign(&self) -> Result<ClaimdropOperationResult, Error> {
         let msg = ExecuteMsg::ManageCampaign {
             action: CampaignAction::CloseCampaign {},
         };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Sweep non-reward tokens from the campaign (owner only)
@@ -302,13 +547,147 @@ impl ClaimdropClient {
         &self,
         denom: &str,
         amount: Option<Uint128>,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
         let msg = ExecuteMsg::Sweep {
             denom: denom.to_string(),
             amount,
         };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
+    }
+
+    /// Query the tokens held by the campaign contract
+    pub async fn query_contract_balances(&self) -> Result<Vec<Coin>, Error> {
+        use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+            QueryAllBalancesRequest, QueryAllBalancesResponse,
+        };
+
+        let request = QueryAllBalancesRequest {
+            address: self.contract_address.clone(),
+            pagination: None,
+            resolve_denom: false,
+        };
+        let balances: QueryAllBalancesResponse = transport::proto_query(
+            self.transport.as_ref(),
+            "/cosmos.bank.v1beta1.Query/AllBalances",
+            &request,
+            self.query_height,
+        )
+        .await?;
+
+        Ok(balances
+            .balances
+            .into_iter()
+            .map(|coin| Coin::new(coin.amount.parse::<u128>().unwrap_or_default(), coin.denom))
+            .collect())
+    }
+
+    /// Check whether the campaign can be closed by the configured wallet
+    ///
+    /// The campaign must have ended, `grace_period_secs` must have passed since
+    /// its end so late claimers are not cut off, and the wallet must be the owner
+    /// or an authorized wallet.
+    pub async fn closure_readiness(
+        &self,
+        grace_period_secs: u64,
+    ) -> Result<ClosureReadiness, Error> {
+        let wallet = self.wallet.as_ref().ok_or(Error::WalletNotSet)?;
+        let wallet_address = wallet.address()?.to_string();
+
+        let campaign = self.query_campaign().await?;
+        let allocated = self
+            .query_all_allocations(STATS_PAGE_SIZE)
+            .await?
+            .iter()
+            .map(|allocation| allocation.allocated_amount)
+            .sum();
+        let authorized = self.is_authorized(&wallet_address).await?;
+        let balances = self.query_contract_balances().await?;
+
+        Ok(ClosureReadiness::evaluate(
+            &campaign,
+            allocated,
+            authorized,
+            balances,
+            grace_period_secs,
+            chrono::Utc::now().timestamp().max(0) as u64,
+        ))
+    }
+
+    /// Close the campaign after the closure safety checks pass
+    ///
+    /// Fails with the list of blockers when the campaign is not ready.
+    pub async fn close_campaign_checked(
+        &self,
+        grace_period_secs: u64,
+    ) -> Result<ClaimdropOperationResult, Error> {
+        let readiness = self.closure_readiness(grace_period_secs).await?;
+        if !readiness.is_ready() {
+            return Err(Error::Other(format!(
+                "Campaign {} cannot be closed: {}",
+                self.contract_address,
+                readiness.blockers.join("; ")
+            )));
+        }
+        self.close_campaign().await
+    }
+
+    /// Sweep every non-reward token left in a closed campaign back to the owner
+    pub async fn withdraw_remaining_funds(&self) -> Result<Vec<SweepOutcome>, Error> {
+        let campaign = self.query_campaign().await?;
+        if campaign.closed_at.is_none() {
+            return Err(Error::Other(format!(
+                "Campaign {} must be closed before withdrawing remaining funds",
+                self.contract_address
+            )));
+        }
+
+        let balances = self.query_contract_balances().await?;
+        let mut outcomes = Vec::new();
+        for coin in sweepable_balances(&balances, &campaign.total_reward.denom) {
+            let result = self.sweep(&coin.denom, None).await?;
+            outcomes.push(SweepOutcome {
+                denom: coin.denom,
+                amount: coin.amount,
+                result,
+            });
+        }
+        Ok(outcomes)
+    }
+
+    /// Close the campaign, sweep leftover tokens and report what was recovered
+    ///
+    /// Nothing is executed if the closure checks fail; the report then only
+    /// carries the readiness blockers.
+    pub async fn end_of_life_campaign(
+        &self,
+        grace_period_secs: u64,
+    ) -> Result<EndOfLifeReport, Error> {
+        let readiness = self.closure_readiness(grace_period_secs).await?;
+        if !readiness.is_ready() {
+            return Ok(EndOfLifeReport {
+                balances_after: readiness.balances.clone(),
+                readiness,
+                close: None,
+                sweeps: Vec::new(),
+                recovered: Vec::new(),
+            });
+        }
+
+        let close = self.close_campaign().await?;
+        let sweeps = if close.success {
+            self.withdraw_remaining_funds().await?
+        } else {
+            Vec::new()
+        };
+
+        let balances_after = self.query_contract_balances().await?;
+        Ok(EndOfLifeReport {
+            recovered: recovered_amounts(&readiness.balances, &balances_after),
+            readiness,
+            close: Some(close),
+            sweeps,
+            balances_after,
+        })
     }
 
     /// Manage authorized wallets (owner only)
@@ -316,13 +695,12 @@ impl ClaimdropClient {
         &self,
         addresses: Vec<String>,
         authorized: bool,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
         let msg = ExecuteMsg::ManageAuthorizedWallets {
             addresses,
             authorized,
         };
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Query authorized wallets
@@ -338,3 +716,97 @@ impl ClaimdropClient {
         self.query(&query_msg).await
     }
 }
+
+/// ABCI query height for `height`, the latest block when `None`
+/// Page limit as the campaign's `u16` query limit, saturating
+pub(crate) fn page_limit(limit: u32) -> u16 {
+    u16::try_from(limit).unwrap_or(u16::MAX)
+}
+
+/// One full `Claim` for each campaign in `plan` with rewards available
+fn claim_all_msgs(plan: &ClaimAllPlan) -> Vec<(String, ExecuteMsg)> {
+    plan.claims
+        .iter()
+        .map(|claim| {
+            (
+                claim.campaign_address.clone(),
+                ExecuteMsg::Claim {
+                    amount: None,
+                    receiver: None,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Executor to sign with, failing when none is attached
+pub(crate) fn signer(executor: &Option<SharedExecutor>) -> Result<&SharedExecutor, Error> {
+    executor.as_ref().ok_or_else(|| {
+        Error::Wallet("No DEX client attached to sign ClaimDrop transactions".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{AbciResponse, AbciTransport};
+
+    #[test]
+    fn test_claim_all_msgs_claim_each_planned_campaign_in_full() {
+        let plan = ClaimAllPlan {
+            receiver: "mantra1receiver".to_string(),
+            claims: vec![
+                PlannedClaim {
+                    campaign_address: "mantra1campaigna".to_string(),
+                    available_to_claim: vec![Coin::new(10u128, "uom")],
+                },
+                PlannedClaim {
+                    campaign_address: "mantra1campaignb".to_string(),
+                    available_to_claim: vec![Coin::new(5u128, "uusdc")],
+                },
+            ],
+            skipped: vec![SkippedClaim {
+                campaign_address: "mantra1campaignc".to_string(),
+                reason: "nothing available to claim".to_string(),
+            }],
+        };
+
+        let msgs = claim_all_msgs(&plan);
+        let contracts: Vec<_> = msgs.iter().map(|(contract, _)| contract.as_str()).collect();
+        assert_eq!(contracts, ["mantra1campaigna", "mantra1campaignb"]);
+        for (_, msg) in &msgs {
+            assert_eq!(
+                serde_json::to_value(msg).unwrap(),
+                serde_json::json!({ "claim": { "amount": null, "receiver": null } })
+            );
+        }
+    }
+
+    /// Answers every query with an empty response, recording its height
+    #[derive(Default)]
+    struct HeightRecorder(std::sync::Mutex<Vec<Option<u64>>>);
+
+    #[async_trait::async_trait]
+    impl AbciTransport for HeightRecorder {
+        async fn abci_query(
+            &self,
+            _path: &str,
+            _data: Vec<u8>,
+            height: Option<u64>,
+        ) -> Result<AbciResponse, Error> {
+            self.0.lock().unwrap().push(height);
+            Ok(AbciResponse::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queries_read_state_at_the_query_height() {
+        let transport = Arc::new(HeightRecorder::default());
+        let mut client =
+            ClaimdropClient::new(transport.clone(), "mantra1campaign".to_string(), None);
+        assert!(client.query_contract_balances().await.unwrap().is_empty());
+        client.set_query_height(Some(42));
+        client.query_contract_balances().await.unwrap();
+        assert_eq!(*transport.0.lock().unwrap(), [None, Some(42)]);
+    }
+}
@@ -0,0 +1,200 @@
+/// Campaign closure and fund recovery for ClaimDrop campaigns
+///
+/// Closing a campaign returns its unclaimed reward tokens to the owner and ends
+/// all claims, so it is only allowed once the campaign has ended and a grace
+/// window for late claims has passed. Non-reward tokens left in the contract are
+/// recovered afterwards with sweeps.
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::types::{CampaignInfo, ClaimdropOperationResult};
+
+/// Default time after a campaign ends during which users can still claim
+/// before the campaign may be closed (7 days)
+pub const DEFAULT_CLAIM_GRACE_PERIOD_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Whether a campaign can be closed, and what closing would recover
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosureReadiness {
+    pub campaign_address: String,
+    /// Unix timestamp (seconds) the checks were evaluated at
+    pub checked_at: u64,
+    pub end_time: u64,
+    /// Late claims are protected until this timestamp
+    pub claim_window_ends_at: u64,
+    pub closed_at: Option<u64>,
+    /// Whether the signing wallet may close the campaign
+    pub authorized: bool,
+    pub reward_denom: String,
+    /// Allocated rewards nobody has claimed yet; forfeited on closure
+    pub unclaimed: Uint128,
+    /// Tokens currently held by the campaign contract
+    pub balances: Vec<Coin>,
+    /// Reasons the campaign cannot be closed yet; empty when ready
+    pub blockers: Vec<String>,
+}
+
+impl ClosureReadiness {
+    /// Evaluate the closure safety checks at `now`
+    pub fn evaluate(
+        campaign: &CampaignInfo,
+        allocated: Uint128,
+        authorized: bool,
+        balances: Vec<Coin>,
+        grace_period_secs: u64,
+        now: u64,
+    ) -> Self {
+        let claim_window_ends_at = campaign.end_time.saturating_add(grace_period_secs);
+
+        let mut blockers = Vec::new();
+        if let Some(closed_at) = campaign.closed_at {
+            blockers.push(format!("campaign was already closed at {}", closed_at));
+        }
+        if now < campaign.end_time {
+            blockers.push(format!(
+                "campaign has not ended (ends at {})",
+                campaign.end_time
+            ));
+        } else if now < claim_window_ends_at {
+            blockers.push(format!(
+                "claim grace window is still open until {}",
+                claim_window_ends_at
+            ));
+        }
+        if !authorized {
+            blockers.push("wallet is not authorized to close the campaign".to_string());
+        }
+
+        Self {
+            campaign_address: campaign.address.clone(),
+            checked_at: now,
+            end_time: campaign.end_time,
+            claim_window_ends_at,
+            closed_at: campaign.closed_at,
+            authorized,
+            reward_denom: campaign.total_reward.denom.clone(),
+            unclaimed: allocated.saturating_sub(campaign.claimed.amount),
+            balances,
+            blockers,
+        }
+    }
+
+    /// Whether all safety checks passed
+    pub fn is_ready(&self) -> bool {
+        self.blockers.is_empty()
+    }
+
+    /// Non-reward balances that must be swept after closure
+    pub fn sweepable(&self) -> Vec<Coin> {
+        sweepable_balances(&self.balances, &self.reward_denom)
+    }
+}
+
+/// Result of sweeping one denom out of a campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepOutcome {
+    pub denom: String,
+    pub amount: Uint128,
+    pub result: ClaimdropOperationResult,
+}
+
+/// Report of the end-of-life workflow for a campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndOfLifeReport {
+    pub readiness: ClosureReadiness,
+    /// Close transaction, if the checks passed
+    pub close: Option<ClaimdropOperationResult>,
+    pub sweeps: Vec<SweepOutcome>,
+    pub balances_after: Vec<Coin>,
+    /// Tokens that left the contract during the workflow
+    pub recovered: Vec<Coin>,
+}
+
+/// Non-zero balances other than the reward denom
+///
+/// The campaign returns its reward token on closure; sweeps only handle other
+/// tokens sent to the contract.
+pub fn sweepable_balances(balances: &[Coin], reward_denom: &str) -> Vec<Coin> {
+    balances
+        .iter()
+        .filter(|coin| coin.denom != reward_denom && !coin.amount.is_zero())
+        .cloned()
+        .collect()
+}
+
+/// Per-denom decrease from `before` to `after`
+pub fn recovered_amounts(before: &[Coin], after: &[Coin]) -> Vec<Coin> {
+    let mut remaining: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in after {
+        *remaining.entry(coin.denom.as_str()).or_default() += coin.amount;
+    }
+    before
+        .iter()
+        .map(|coin| {
+            let left = remaining
+                .get(coin.denom.as_str())
+                .copied()
+                .unwrap_or_default();
+            Coin::new(coin.amount.saturating_sub(left), coin.denom.clone())
+        })
+        .filter(|coin| !coin.amount.is_zero())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn campaign(closed_at: Option<u64>) -> CampaignInfo {
+        CampaignInfo {
+            address: "mantra1campaign".to_string(),
+            owner: String::new(),
+            name: "Genesis".to_string(),
+            description: String::new(),
+            campaign_type: "airdrop".to_string(),
+            start_time: 1_000,
+            end_time: 2_000,
+            total_reward: Coin::new(10_000u128, "uom"),
+            claimed: Coin::new(6_000u128, "uom"),
+            distribution_type: vec![],
+            is_active: closed_at.is_none(),
+            closed_at,
+        }
+    }
+
+    #[test]
+    fn test_readiness_requires_end_grace_window_and_authorization() {
+        let balances = vec![Coin::new(4_000u128, "uom"), Coin::new(50u128, "uusdc")];
+        let check = |closed_at, authorized, now| {
+            ClosureReadiness::evaluate(
+                &campaign(closed_at),
+                Uint128::new(9_000),
+                authorized,
+                balances.clone(),
+                100,
+                now,
+            )
+        };
+
+        let ready = check(None, true, 2_100);
+        assert!(ready.is_ready());
+        assert_eq!(ready.claim_window_ends_at, 2_100);
+        assert_eq!(ready.unclaimed, Uint128::new(3_000));
+        assert_eq!(ready.sweepable(), vec![Coin::new(50u128, "uusdc")]);
+
+        assert_eq!(check(None, true, 1_500).blockers.len(), 1);
+        assert_eq!(check(None, true, 2_050).blockers.len(), 1);
+        assert_eq!(check(Some(2_000), false, 2_100).blockers.len(), 2);
+    }
+
+    #[test]
+    fn test_recovered_amounts() {
+        let before = vec![Coin::new(4_000u128, "uom"), Coin::new(50u128, "uusdc")];
+        let after = vec![Coin::new(10u128, "uusdc")];
+        assert_eq!(
+            recovered_amounts(&before, &after),
+            vec![Coin::new(4_000u128, "uom"), Coin::new(40u128, "uusdc")]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/// Signing and broadcasting of ClaimDrop contract executions
+use crate::error::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::protocols::dex::MantraDexClient;
+use async_trait::async_trait;
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmwasm_std::Coin;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::types::ClaimdropOperationResult;
+
+/// Event a contract instantiation emits, with the new address in
+/// [`CONTRACT_ADDRESS_ATTRIBUTE`]
+const INSTANTIATE_EVENT: &str = "instantiate";
+const CONTRACT_ADDRESS_ATTRIBUTE: &str = "_contract_address";
+
+/// One contract execution message, before it is signed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractCall {
+    pub contract: String,
+    pub msg: Value,
+    pub funds: Vec<Coin>,
+}
+
+impl ContractCall {
+    pub fn new<T: Serialize>(contract: &str, msg: &T, funds: Vec<Coin>) -> Result<Self, Error> {
+        Ok(Self {
+            contract: contract.to_string(),
+            msg: serde_json::to_value(msg)?,
+            funds,
+        })
+    }
+}
+
+/// Signs contract executions and broadcasts them in a single transaction
+///
+/// Implementations choose the fee and fail when the transaction is rejected
+/// or its execution fails.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait ContractExecutor {
+    async fn execute_contracts(&self, calls: Vec<ContractCall>) -> Result<TxResponse, Error>;
+}
+
+pub type SharedExecutor = std::sync::Arc<dyn ContractExecutor + Send + Sync>;
+
+/// Signs with the client's wallet, at its network's gas price
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl ContractExecutor for MantraDexClient {
+    async fn execute_contracts(&self, calls: Vec<ContractCall>) -> Result<TxResponse, Error> {
+        let msgs = calls
+            .iter()
+            .map(|call| self.execute_contract_msg(&call.contract, &call.msg, call.funds.clone()))
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.broadcast_msgs(msgs).await
+    }
+}
+
+/// Outcome of a broadcast transaction; failed unless its code is 0
+pub(crate) fn tx_result(response: &TxResponse, data: Option<Value>) -> ClaimdropOperationResult {
+    let success = response.code == 0;
+    ClaimdropOperationResult {
+        success,
+        tx_hash: Some(response.txhash.clone()).filter(|hash| !hash.is_empty()),
+        message: if success {
+            "Transaction executed successfully".to_string()
+        } else {
+            format!(
+                "Transaction failed with code {}: {}",
+                response.code, response.raw_log
+            )
+        },
+        campaign_address: None,
+        data,
+    }
+}
+
+/// Addresses of the contracts the transaction instantiated, in event order
+pub fn instantiated_contracts(response: &TxResponse) -> Vec<String> {
+    response
+        .events
+        .iter()
+        .filter(|event| event.r#type == INSTANTIATE_EVENT)
+        .flat_map(|event| &event.attributes)
+        .filter(|attribute| attribute.key == CONTRACT_ADDRESS_ATTRIBUTE)
+        .map(|attribute| attribute.value.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::tendermint::abci::{Event, EventAttribute};
+
+    #[test]
+    fn test_tx_result_fails_unless_code_is_zero() {
+        let confirmed = TxResponse {
+            txhash: "ABC".to_string(),
+            ..Default::default()
+        };
+        let result = tx_result(&confirmed, Some(serde_json::json!({ "messages": 2 })));
+        assert!(result.success);
+        assert_eq!(result.tx_hash.as_deref(), Some("ABC"));
+
+        let failed = TxResponse {
+            txhash: "DEF".to_string(),
+            code: 5,
+            raw_log: "insufficient funds".to_string(),
+            ..Default::default()
+        };
+        let result = tx_result(&failed, None);
+        assert!(!result.success);
+        assert_eq!(result.tx_hash.as_deref(), Some("DEF"));
+        assert!(result.message.contains("insufficient funds"));
+    }
+
+    #[test]
+    fn test_instantiated_contracts_reads_instantiate_events() {
+        let attribute = |key: &str, value: &str| EventAttribute {
+            key: key.to_string(),
+            value: value.to_string(),
+            index: true,
+        };
+        let response = TxResponse {
+            events: vec![
+                Event {
+                    r#type: "execute".to_string(),
+                    attributes: vec![attribute("_contract_address", "mantra1factory")],
+                },
+                Event {
+                    r#type: "instantiate".to_string(),
+                    attributes: vec![
+                        attribute("_contract_address", "mantra1campaign"),
+                        attribute("code_id", "7"),
+                    ],
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(instantiated_contracts(&response), ["mantra1campaign"]);
+    }
+}
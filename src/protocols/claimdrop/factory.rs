@@ -1,14 +1,18 @@
 /// ClaimDrop Factory client for creating and managing claimdrop campaigns
 use crate::error::Error;
+use crate::pagination::{Page, PageRequest, Paginator};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::protocols::dex::MantraDexClient;
+use crate::transport::{self, SharedTransport};
 use crate::wallet::MantraWallet;
-use cosmrs::rpc::{Client as RpcClient, HttpClient};
-use cosmrs::tx::Fee;
+use futures::Stream;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
 
 // Import local factory types
 // Note: These are local types based on claimdrop-factory contract
 #[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
 pub enum FactoryExecuteMsg {
     CreateCampaign {
         params: mantra_claimdrop_std::msg::CampaignParams,
@@ -19,6 +23,7 @@ pub enum FactoryExecuteMsg {
 }
 
 #[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "snake_case")]
 pub enum FactoryQueryMsg {
     Allocations {
         address: String,
@@ -56,29 +61,45 @@ pub struct FactoryCampaignRewards {
     pub available_to_claim: Vec<cosmwasm_std::Coin>,
 }
 
-use super::client::ClaimdropClient;
+use super::attestation::{CampaignEligibility, EligibilityAttestation, ATTESTATION_VERSION};
+use super::client::{page_limit, signer, ClaimdropClient};
+use super::executor::{instantiated_contracts, tx_result, ContractCall, SharedExecutor};
+use super::stats::{
+    CampaignSnapshot, CampaignStatsCache, CampaignStatsError, GlobalCampaignStats,
+    DEFAULT_STATS_CACHE_TTL, STATS_PAGE_SIZE,
+};
 use super::types::*;
 
 /// Client for interacting with the ClaimDrop Factory contract
 pub struct ClaimdropFactoryClient {
-    rpc_client: Arc<Mutex<HttpClient>>,
+    transport: SharedTransport,
+    /// Chain ID recorded in eligibility attestations
+    chain_id: String,
     factory_address: String,
     wallet: Option<Arc<MantraWallet>>,
+    /// Signs and broadcasts execute messages, for campaign clients too
+    executor: Option<SharedExecutor>,
     claimdrop_code_id: Option<u64>,
+    /// Block height queries read state at, the latest when unset
+    query_height: Option<u64>,
 }
 
 impl ClaimdropFactoryClient {
     /// Create a new ClaimDrop Factory client
     pub fn new(
-        rpc_client: Arc<Mutex<HttpClient>>,
+        transport: SharedTransport,
+        chain_id: String,
         factory_address: String,
         wallet: Option<Arc<MantraWallet>>,
     ) -> Self {
         Self {
-            rpc_client,
+            transport,
+            chain_id,
             factory_address,
             wallet,
+            executor: None,
             claimdrop_code_id: None,
+            query_height: None,
         }
     }
 
@@ -92,64 +113,62 @@ impl ClaimdropFactoryClient {
         self.wallet = Some(wallet);
     }
 
+    /// Attach a DEX client with a wallet to sign transactions
+    ///
+    /// Factory executions and those of campaign clients created afterwards
+    /// sign and broadcast through it at the network's gas price.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_dex_client(&mut self, dex_client: Arc<MantraDexClient>) {
+        self.executor = Some(dex_client);
+    }
+
+    /// Sign and broadcast execute messages through `executor`
+    pub fn set_executor(&mut self, executor: SharedExecutor) {
+        self.executor = Some(executor);
+    }
+
     /// Set the claimdrop contract code ID
     pub fn set_claimdrop_code_id(&mut self, code_id: u64) {
         self.claimdrop_code_id = Some(code_id);
     }
 
+    /// Read contract state at `height` instead of the latest block
+    ///
+    /// Campaign clients created afterwards read at the same height.
+    pub fn set_query_height(&mut self, height: Option<u64>) {
+        self.query_height = height;
+    }
+
     /// Helper method to query the factory contract
     async fn query<R: serde::de::DeserializeOwned>(
         &self,
         query_msg: &FactoryQueryMsg,
     ) -> Result<R, Error> {
-        use cosmos_sdk_proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest;
-        use prost::Message;
-
-        let rpc_client = self.rpc_client.lock().await;
-        let query = QuerySmartContractStateRequest {
-            address: self.factory_address.clone(),
-            query_data: serde_json::to_vec(query_msg)?,
-        };
-
-        let data = query.encode_to_vec();
-        let result = rpc_client
-            .abci_query(
-                Some("/cosmwasm.wasm.v1.Query/SmartContractState".to_string()),
-                data,
-                None,
-                false,
-            )
-            .await
-            .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))?;
-
-        if !result.code.is_ok() {
-            return Err(Error::Contract(format!(
-                "Factory query failed with code {:?}: {}",
-                result.code, result.log
-            )));
-        }
-
-        let response_data: R =
-            serde_json::from_slice(&result.value).map_err(Error::Serialization)?;
-
-        Ok(response_data)
+        transport::smart_query(
+            self.transport.as_ref(),
+            &self.factory_address,
+            query_msg,
+            self.query_height,
+        )
+        .await
     }
 
     /// Helper method to execute a factory contract message
+    ///
+    /// The result carries the address of the campaign the transaction
+    /// instantiated, if any.
     async fn execute<T: serde::Serialize>(
         &self,
-        _msg: &T,
-        _funds: Vec<cosmwasm_std::Coin>,
-        _fee: Fee,
+        msg: &T,
+        funds: Vec<cosmwasm_std::Coin>,
     ) -> Result<ClaimdropOperationResult, Error> {
-        // This is a simplified implementation - a full implementation would handle
-        // transaction signing and broadcasting properly
+        let call = ContractCall::new(&self.factory_address, msg, funds)?;
+        let response = signer(&self.executor)?
+            .execute_contracts(vec![call])
+            .await?;
         Ok(ClaimdropOperationResult {
-            success: false,
-            tx_hash: None,
-            message: "Factory transaction execution not yet fully implemented".to_string(),
-            campaign_address: None,
-            data: None,
+            campaign_address: instantiated_contracts(&response).into_iter().next(),
+            ..tx_result(&response, None)
         })
     }
 
@@ -246,71 +265,196 @@ impl ClaimdropFactoryClient {
     pub async fn create_campaign(
         &self,
         params: mantra_claimdrop_std::msg::CampaignParams,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
-        self.wallet.as_ref().ok_or_else(|| Error::WalletNotSet)?;
-
         let msg = FactoryExecuteMsg::CreateCampaign { params };
 
         // Execute the create_campaign message on the factory
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Update the factory configuration (admin only)
     pub async fn update_config(
         &self,
         claimdrop_code_id: Option<u64>,
-        fee: Fee,
     ) -> Result<ClaimdropOperationResult, Error> {
-        self.wallet.as_ref().ok_or_else(|| Error::WalletNotSet)?;
-
         let msg = FactoryExecuteMsg::UpdateConfig { claimdrop_code_id };
 
         // Execute the update_config message on the factory
-        self.execute(&msg, vec![], fee).await
+        self.execute(&msg, vec![]).await
     }
 
     /// Create a ClaimdropClient for a specific campaign
     pub fn campaign_client(&self, campaign_address: String) -> ClaimdropClient {
-        ClaimdropClient::new(
-            self.rpc_client.clone(),
+        let mut client = ClaimdropClient::new(
+            self.transport.clone(),
             campaign_address,
             self.wallet.clone(),
-        )
+        );
+        client.set_query_height(self.query_height);
+        if let Some(executor) = &self.executor {
+            client.set_executor(executor.clone());
+        }
+        client
+    }
+
+    /// Query one page of the campaigns created by the factory
+    pub async fn campaigns_page(
+        &self,
+        request: PageRequest<String>,
+    ) -> Result<Page<String, String>, Error> {
+        let limit = page_limit(request.limit);
+        let campaigns = self
+            .query_campaigns(request.cursor.as_deref(), Some(limit))
+            .await?
+            .campaigns;
+        Ok(Page::keyed(campaigns, limit.into(), Clone::clone))
+    }
+
+    /// Stream every campaign created by the factory, `page_size` at a time
+    pub fn campaigns_stream(
+        &self,
+        page_size: u16,
+    ) -> impl Stream<Item = Result<String, Error>> + '_ {
+        Paginator::new(page_size.into()).stream(move |request| self.campaigns_page(request))
+    }
+
+    /// Query every campaign created by the factory, following pagination
+    pub async fn query_all_campaigns(&self) -> Result<Vec<String>, Error> {
+        Paginator::new(STATS_PAGE_SIZE.into())
+            .collect(|request| self.campaigns_page(request))
+            .await
     }
 
     /// Get aggregated statistics across all campaigns
+    ///
+    /// Totals are summed across denoms; use [`Self::get_global_stats`] for the
+    /// per-denom breakdown.
     pub async fn get_campaign_stats(&self) -> Result<CampaignStats, Error> {
-        // Query all campaigns first
-        let campaigns_response = self.query_campaigns(None, None).await?;
-
-        let total_campaigns = campaigns_response.campaigns.len() as u32;
-        let total_allocated = cosmwasm_std::Uint128::zero();
-        let total_claimed = cosmwasm_std::Uint128::zero();
+        let stats = self
+            .get_global_stats(DEFAULT_STATS_CACHE_TTL, false)
+            .await?;
 
-        // For now, return basic stats based on campaign count
-        // In a full implementation, we would query each campaign for detailed stats
         Ok(CampaignStats {
-            total_campaigns,
-            active_campaigns: total_campaigns, // Assume all are active for now
-            total_allocated,
-            total_claimed,
-            unique_participants: 0,
+            total_campaigns: stats.total_campaigns,
+            active_campaigns: stats.active_campaigns,
+            total_allocated: stats.totals.iter().map(|t| t.allocated).sum(),
+            total_claimed: stats.totals.iter().map(|t| t.claimed).sum(),
+            unique_participants: stats.unique_participants,
+        })
+    }
+
+    /// Global statistics across all campaigns, served from cache when fresh
+    ///
+    /// Cached results younger than `max_age` are returned unless `force_refresh`
+    /// is set.
+    pub async fn get_global_stats(
+        &self,
+        max_age: Duration,
+        force_refresh: bool,
+    ) -> Result<GlobalCampaignStats, Error> {
+        let cache = CampaignStatsCache::global();
+        if !force_refresh {
+            if let Some(stats) = cache.get(&self.factory_address, max_age) {
+                return Ok(stats);
+            }
+        }
+
+        let stats = self.collect_global_stats().await?;
+        cache.insert(stats.clone());
+        Ok(stats)
+    }
+
+    /// Collect an address's allocations across every campaign of the factory
+    ///
+    /// Every campaign and allocation is read at the latest block height, which
+    /// is recorded with the chain ID so the result can be signed and later
+    /// checked against chain state at that height.
+    pub async fn collect_eligibility(
+        &self,
+        address: &str,
+    ) -> Result<EligibilityAttestation, Error> {
+        let block_height =
+            transport::latest_height(self.transport.as_ref(), &self.factory_address).await?;
+        let issued_at = chrono::Utc::now().timestamp().max(0) as u64;
+
+        let mut pinned = ClaimdropFactoryClient::new(
+            self.transport.clone(),
+            self.chain_id.clone(),
+            self.factory_address.clone(),
+            None,
+        );
+        pinned.set_query_height(Some(block_height));
+
+        let mut campaigns = Vec::new();
+        for campaign_address in pinned.query_all_campaigns().await? {
+            let client = pinned.campaign_client(campaign_address);
+            let info = client.query_campaign().await?;
+            if let Some(schedule) = client.vesting_schedule_for(&info, address).await? {
+                campaigns.push(CampaignEligibility::from_schedule(
+                    info.name, &schedule, issued_at,
+                ));
+            }
+        }
+
+        Ok(EligibilityAttestation {
+            version: ATTESTATION_VERSION,
+            chain_id: self.chain_id.clone(),
+            block_height,
+            factory_address: self.factory_address.clone(),
+            address: address.to_string(),
+            issued_at,
+            campaigns,
         })
     }
 
+    /// Walk every campaign and compute global statistics, bypassing the cache
+    ///
+    /// Campaigns that fail to query are reported in `failed` instead of
+    /// aborting the whole walk.
+    pub async fn collect_global_stats(&self) -> Result<GlobalCampaignStats, Error> {
+        let campaign_addresses = self.query_all_campaigns().await?;
+
+        let mut snapshots = Vec::with_capacity(campaign_addresses.len());
+        let mut failed = Vec::new();
+        for campaign_address in campaign_addresses {
+            let client = self.campaign_client(campaign_address.clone());
+            let snapshot = async {
+                Ok::<_, Error>(CampaignSnapshot {
+                    info: client.query_campaign().await?,
+                    allocations: client.query_all_allocations(STATS_PAGE_SIZE).await?,
+                    claimed: client.query_all_claimed(STATS_PAGE_SIZE).await?,
+                })
+            }
+            .await;
+            match snapshot {
+                Ok(snapshot) => snapshots.push(snapshot),
+                Err(e) => failed.push(CampaignStatsError {
+                    campaign_address,
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        let computed_at = chrono::Utc::now().timestamp().max(0) as u64;
+        Ok(GlobalCampaignStats::from_snapshots(
+            self.factory_address.clone(),
+            &snapshots,
+            failed,
+            computed_at,
+        ))
+    }
+
     /// Helper method to claim from multiple campaigns at once
     pub async fn claim_from_multiple_campaigns(
         &self,
         campaign_addresses: Vec<String>,
         receiver: Option<String>,
-        fee: Fee,
     ) -> Result<Vec<ClaimdropOperationResult>, Error> {
         let mut results = Vec::new();
 
         for campaign_address in campaign_addresses {
             let client = self.campaign_client(campaign_address.clone());
-            match client.claim(None, receiver.clone(), fee.clone()).await {
+            match client.claim(None, receiver.clone()).await {
                 Ok(result) => results.push(result),
                 Err(e) => {
                     results.push(ClaimdropOperationResult {
@@ -0,0 +1,207 @@
+/// Merkle tree support for ClaimDrop campaigns using merkle-root allocations
+///
+/// Leaves are `sha256(address ++ amount)` and inner nodes hash the two children
+/// in sorted order, matching the cw20-merkle-airdrop convention, so a proof only
+/// needs the sibling hashes (no left/right flags). Hashes are hex-encoded.
+use crate::error::Error;
+use cosmwasm_std::Uint128;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+use super::types::Allocation;
+
+type Hash = [u8; 32];
+
+/// Execute message for campaigns using merkle-root allocations
+///
+/// Note: not part of mantra-claimdrop-std; mirrors the merkle claim entry point.
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MerkleExecuteMsg {
+    Claim {
+        amount: Uint128,
+        proof: Vec<String>,
+        receiver: Option<String>,
+    },
+}
+
+/// Merkle proof for a single allocation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    pub address: String,
+    pub amount: Uint128,
+    /// Hex-encoded sibling hashes from leaf to root
+    pub proof: Vec<String>,
+    /// Hex-encoded merkle root the proof resolves to
+    pub root: String,
+}
+
+/// Merkle tree built from a campaign allocation list
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    /// Tree levels, leaves first and root last
+    levels: Vec<Vec<Hash>>,
+    /// Address -> (leaf index, allocated amount)
+    index: HashMap<String, (usize, Uint128)>,
+}
+
+impl MerkleTree {
+    /// Build a tree from allocations
+    ///
+    /// Fails if the list is empty or contains the same address twice.
+    pub fn from_allocations(allocations: &[Allocation]) -> Result<Self, Error> {
+        if allocations.is_empty() {
+            return Err(Error::Other(
+                "Cannot build a merkle tree from an empty allocation list".to_string(),
+            ));
+        }
+
+        let mut index = HashMap::with_capacity(allocations.len());
+        let mut leaves = Vec::with_capacity(allocations.len());
+        for (i, allocation) in allocations.iter().enumerate() {
+            if index
+                .insert(allocation.user.clone(), (i, allocation.allocated_amount))
+                .is_some()
+            {
+                return Err(Error::Other(format!(
+                    "Duplicate allocation for address {}",
+                    allocation.user
+                )));
+            }
+            leaves.push(leaf_hash(&allocation.user, allocation.allocated_amount));
+        }
+
+        let mut levels = vec![leaves];
+        while levels.last().map(|level| level.len()).unwrap_or(0) > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    // Odd node out is promoted unchanged
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(Self { levels, index })
+    }
+
+    /// Hex-encoded merkle root
+    pub fn root(&self) -> String {
+        hex::encode(self.levels.last().expect("tree has at least one level")[0])
+    }
+
+    /// Number of allocations in the tree
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Whether the tree has no allocations (never true for a built tree)
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Generate the proof for an address, if it has an allocation
+    pub fn proof(&self, address: &str) -> Option<MerkleProof> {
+        let (mut position, amount) = *self.index.get(address)?;
+
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if let Some(hash) = level.get(sibling) {
+                proof.push(hex::encode(hash));
+            }
+            position /= 2;
+        }
+
+        Some(MerkleProof {
+            address: address.to_string(),
+            amount,
+            proof,
+            root: self.root(),
+        })
+    }
+
+    /// Verify a proof against a hex-encoded root
+    pub fn verify(root: &str, address: &str, amount: Uint128, proof: &[String]) -> bool {
+        let mut hash = leaf_hash(address, amount);
+        for sibling in proof {
+            let sibling: Hash = match hex::decode(sibling).ok().and_then(|b| b.try_into().ok()) {
+                Some(sibling) => sibling,
+                None => return false,
+            };
+            hash = node_hash(&hash, &sibling);
+        }
+        hex::encode(hash).eq_ignore_ascii_case(root)
+    }
+}
+
+fn leaf_hash(address: &str, amount: Uint128) -> Hash {
+    Sha256::digest(format!("{}{}", address, amount).as_bytes()).into()
+}
+
+fn node_hash(a: &Hash, b: &Hash) -> Hash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(first);
+    hasher.update(second);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allocations(count: usize) -> Vec<Allocation> {
+        (0..count)
+            .map(|i| Allocation {
+                user: format!("mantra1user{}", i),
+                allocated_amount: Uint128::new(1_000 * (i as u128 + 1)),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_proofs_verify_for_every_allocation() {
+        for count in [1, 2, 3, 7, 8] {
+            let allocations = allocations(count);
+            let tree = MerkleTree::from_allocations(&allocations).unwrap();
+            for allocation in &allocations {
+                let proof = tree.proof(&allocation.user).unwrap();
+                assert_eq!(proof.amount, allocation.allocated_amount);
+                assert!(MerkleTree::verify(
+                    &tree.root(),
+                    &proof.address,
+                    proof.amount,
+                    &proof.proof
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tampered_amount_fails_verification() {
+        let tree = MerkleTree::from_allocations(&allocations(5)).unwrap();
+        let proof = tree.proof("mantra1user2").unwrap();
+        assert!(!MerkleTree::verify(
+            &tree.root(),
+            &proof.address,
+            proof.amount + Uint128::one(),
+            &proof.proof
+        ));
+        assert!(tree.proof("mantra1unknown").is_none());
+    }
+
+    #[test]
+    fn test_rejects_duplicates_and_empty() {
+        let mut list = allocations(2);
+        list.push(list[0].clone());
+        assert!(MerkleTree::from_allocations(&list).is_err());
+        assert!(MerkleTree::from_allocations(&[]).is_err());
+    }
+}
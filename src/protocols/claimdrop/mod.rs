@@ -1,25 +1,44 @@
 /// ClaimDrop Protocol Module
 /// Handles claimdrop campaigns, allocations, and rewards distribution
+pub mod attestation;
+pub mod builder;
 pub mod client;
+pub mod closure;
+pub mod executor;
 pub mod factory;
+pub mod merkle;
+pub mod stats;
 pub mod types;
+pub mod vesting;
 
-pub use client::ClaimdropClient;
+pub use attestation::{CampaignEligibility, EligibilityAttestation, SignedAttestation};
+pub use builder::{
+    parse_allocations_csv, AllocationUploadProgress, AllocationUploadReport, CampaignBuilder,
+    CampaignDeployment, CampaignValidationReport,
+};
+pub use client::{ClaimdropClient, MAX_BATCH_CLAIMS};
+pub use closure::{ClosureReadiness, EndOfLifeReport, SweepOutcome};
+pub use executor::{instantiated_contracts, ContractCall, ContractExecutor, SharedExecutor};
 pub use factory::ClaimdropFactoryClient;
+pub use merkle::{MerkleProof, MerkleTree};
+pub use stats::{CampaignSummary, DenomTotals, GlobalCampaignStats};
 pub use types::*;
+pub use vesting::{VestingKind, VestingPoint, VestingSchedule, VestingTranche};
 
 use crate::error::Error;
+use crate::protocols::discovery::{discover_contract, ContractDiscovery};
 use crate::protocols::Protocol;
+use crate::transport::SharedTransport;
 use async_trait::async_trait;
-use cosmrs::rpc::HttpClient;
 use serde_json::{json, Value};
-use std::sync::Arc;
 
 /// ClaimDrop Protocol implementation
 #[derive(Clone)]
 pub struct ClaimdropProtocol {
     initialized: bool,
     factory_address: Option<String>,
+    expected_code_id: Option<u64>,
+    deployment: Option<ContractDiscovery>,
     campaigns: Vec<String>,
 }
 
@@ -29,6 +48,8 @@ impl ClaimdropProtocol {
         Self {
             initialized: false,
             factory_address: None,
+            expected_code_id: None,
+            deployment: None,
             campaigns: Vec::new(),
         }
     }
@@ -43,6 +64,16 @@ impl ClaimdropProtocol {
         self.factory_address = Some(address);
     }
 
+    /// Set the code ID the factory must be instantiated from
+    pub fn set_expected_code_id(&mut self, code_id: Option<u64>) {
+        self.expected_code_id = code_id;
+    }
+
+    /// What initialization found at the factory address
+    pub fn deployment(&self) -> Option<&ContractDiscovery> {
+        self.deployment.as_ref()
+    }
+
     /// Get list of known campaign addresses
     pub fn campaigns(&self) -> &[String] {
         &self.campaigns
@@ -56,7 +87,8 @@ impl ClaimdropProtocol {
     }
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl Protocol for ClaimdropProtocol {
     fn name(&self) -> &'static str {
         "claimdrop"
@@ -66,9 +98,24 @@ impl Protocol for ClaimdropProtocol {
         "2.0.0"
     }
 
-    async fn is_available(&self, _rpc_client: &HttpClient) -> Result<bool, Error> {
+    async fn is_available(&self, transport: &SharedTransport) -> Result<bool, Error> {
         // Check if ClaimDrop factory contract is deployed on the network
-        Ok(self.initialized && self.factory_address.is_some())
+        match &self.factory_address {
+            Some(address) => {
+                Ok(
+                    discover_contract(transport.as_ref(), address, self.expected_code_id)
+                        .await?
+                        .is_deployed(),
+                )
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn is_deployed(&self) -> bool {
+        self.deployment
+            .as_ref()
+            .is_some_and(ContractDiscovery::is_deployed)
     }
 
     fn get_config(&self) -> Result<Value, Error> {
@@ -77,13 +124,19 @@ impl Protocol for ClaimdropProtocol {
             "version": self.version(),
             "initialized": self.initialized,
             "factory_address": self.factory_address,
+            "deployment": self.deployment,
             "campaigns": self.campaigns,
         }))
     }
 
-    async fn initialize(&mut self, _rpc_client: Arc<HttpClient>) -> Result<(), Error> {
-        // Initialize the ClaimDrop protocol
-        // This would typically load the factory contract address from config
+    async fn initialize(&mut self, transport: SharedTransport) -> Result<(), Error> {
+        // Look up the factory contract on the connected chain
+        self.deployment = match &self.factory_address {
+            Some(address) => {
+                Some(discover_contract(transport.as_ref(), address, self.expected_code_id).await?)
+            }
+            None => None,
+        };
         self.initialized = true;
         Ok(())
     }
@@ -0,0 +1,298 @@
+/// Aggregated statistics across all campaigns of a ClaimDrop factory
+///
+/// Walking a factory means one campaign query plus paginated allocation and
+/// claim queries per campaign, so results are cached per factory and reused
+/// until they are older than the caller's freshness limit.
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+use web_time::Instant;
+
+use super::types::{Allocation, CampaignInfo};
+
+/// How long cached global stats are served by default
+pub const DEFAULT_STATS_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Page size used when walking campaigns, allocations and claims
+pub const STATS_PAGE_SIZE: u16 = 100;
+
+/// Raw data collected for one campaign
+#[derive(Debug, Clone)]
+pub struct CampaignSnapshot {
+    pub info: CampaignInfo,
+    pub allocations: Vec<Allocation>,
+    /// Claimed amount per address
+    pub claimed: Vec<(String, Coin)>,
+}
+
+/// Totals for a single campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignSummary {
+    pub address: String,
+    pub name: String,
+    pub denom: String,
+    pub is_active: bool,
+    pub total_reward: Uint128,
+    pub allocated: Uint128,
+    pub claimed: Uint128,
+    /// Allocated but not yet claimed
+    pub remaining: Uint128,
+    pub allocations: u32,
+    pub claimants: u32,
+    /// Claimed as a percentage of allocated
+    pub completion_percent: f64,
+}
+
+/// Totals across campaigns for one reward denom
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DenomTotals {
+    pub denom: String,
+    pub allocated: Uint128,
+    pub claimed: Uint128,
+    pub remaining: Uint128,
+    pub completion_percent: f64,
+}
+
+/// Campaign that could not be queried while collecting stats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignStatsError {
+    pub campaign_address: String,
+    pub error: String,
+}
+
+/// Statistics across every campaign of a factory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlobalCampaignStats {
+    pub factory_address: String,
+    pub total_campaigns: u32,
+    pub active_campaigns: u32,
+    /// Addresses with an allocation in at least one campaign
+    pub unique_participants: u32,
+    /// Addresses that claimed from at least one campaign
+    pub unique_claimants: u32,
+    /// Totals per reward denom (campaigns may pay out in different tokens)
+    pub totals: Vec<DenomTotals>,
+    pub campaigns: Vec<CampaignSummary>,
+    pub failed: Vec<CampaignStatsError>,
+    /// Unix timestamp (seconds) when the stats were collected
+    pub computed_at: u64,
+}
+
+impl GlobalCampaignStats {
+    /// Aggregate campaign snapshots into global statistics
+    pub fn from_snapshots(
+        factory_address: impl Into<String>,
+        snapshots: &[CampaignSnapshot],
+        failed: Vec<CampaignStatsError>,
+        computed_at: u64,
+    ) -> Self {
+        let mut participants = HashSet::new();
+        let mut claimants = HashSet::new();
+        let mut totals: BTreeMap<String, (Uint128, Uint128)> = BTreeMap::new();
+
+        let campaigns: Vec<CampaignSummary> = snapshots
+            .iter()
+            .map(|snapshot| {
+                let denom = snapshot.info.total_reward.denom.clone();
+                let allocated: Uint128 = snapshot
+                    .allocations
+                    .iter()
+                    .map(|allocation| allocation.allocated_amount)
+                    .sum();
+                let campaign_claimants: HashSet<&str> = snapshot
+                    .claimed
+                    .iter()
+                    .filter(|(_, coin)| !coin.amount.is_zero())
+                    .map(|(address, _)| address.as_str())
+                    .collect();
+                // Use the campaign's own running total rather than summing per-address claims
+                let claimed = snapshot.info.claimed.amount;
+
+                participants.extend(snapshot.allocations.iter().map(|a| a.user.clone()));
+                claimants.extend(campaign_claimants.iter().map(|a| a.to_string()));
+                let entry = totals.entry(denom.clone()).or_default();
+                entry.0 += allocated;
+                entry.1 += claimed;
+
+                CampaignSummary {
+                    address: snapshot.info.address.clone(),
+                    name: snapshot.info.name.clone(),
+                    denom,
+                    is_active: snapshot.info.is_active,
+                    total_reward: snapshot.info.total_reward.amount,
+                    allocated,
+                    claimed,
+                    remaining: allocated.saturating_sub(claimed),
+                    allocations: snapshot.allocations.len() as u32,
+                    claimants: campaign_claimants.len() as u32,
+                    completion_percent: percent(claimed, allocated),
+                }
+            })
+            .collect();
+
+        Self {
+            factory_address: factory_address.into(),
+            total_campaigns: (campaigns.len() + failed.len()) as u32,
+            active_campaigns: campaigns.iter().filter(|c| c.is_active).count() as u32,
+            unique_participants: participants.len() as u32,
+            unique_claimants: claimants.len() as u32,
+            totals: totals
+                .into_iter()
+                .map(|(denom, (allocated, claimed))| DenomTotals {
+                    denom,
+                    allocated,
+                    claimed,
+                    remaining: allocated.saturating_sub(claimed),
+                    completion_percent: percent(claimed, allocated),
+                })
+                .collect(),
+            campaigns,
+            failed,
+            computed_at,
+        }
+    }
+}
+
+fn percent(part: Uint128, whole: Uint128) -> f64 {
+    if whole.is_zero() {
+        return 0.0;
+    }
+    part.u128() as f64 / whole.u128() as f64 * 100.0
+}
+
+/// Process-wide cache of global stats keyed by factory address
+#[derive(Debug, Default)]
+pub struct CampaignStatsCache {
+    entries: RwLock<HashMap<String, (Instant, GlobalCampaignStats)>>,
+}
+
+impl CampaignStatsCache {
+    /// Cache shared by all factory clients
+    pub fn global() -> &'static CampaignStatsCache {
+        static GLOBAL: OnceLock<CampaignStatsCache> = OnceLock::new();
+        GLOBAL.get_or_init(CampaignStatsCache::default)
+    }
+
+    /// Cached stats for a factory if they are younger than `max_age`
+    pub fn get(&self, factory_address: &str, max_age: Duration) -> Option<GlobalCampaignStats> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(factory_address)
+            .filter(|(stored_at, _)| stored_at.elapsed() < max_age)
+            .map(|(_, stats)| stats.clone())
+    }
+
+    /// Store freshly computed stats
+    pub fn insert(&self, stats: GlobalCampaignStats) {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(stats.factory_address.clone(), (Instant::now(), stats));
+    }
+
+    /// Drop cached stats for a factory, or for all factories
+    pub fn invalidate(&self, factory_address: Option<&str>) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        match factory_address {
+            Some(address) => {
+                entries.remove(address);
+            }
+            None => entries.clear(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(
+        address: &str,
+        denom: &str,
+        allocations: &[(&str, u128)],
+        claimed: &[(&str, u128)],
+        active: bool,
+    ) -> CampaignSnapshot {
+        let total_claimed: u128 = claimed.iter().map(|(_, amount)| amount).sum();
+        CampaignSnapshot {
+            info: CampaignInfo {
+                address: address.to_string(),
+                owner: String::new(),
+                name: address.to_string(),
+                description: String::new(),
+                campaign_type: "airdrop".to_string(),
+                start_time: 0,
+                end_time: 0,
+                total_reward: Coin::new(10_000u128, denom),
+                claimed: Coin::new(total_claimed, denom),
+                distribution_type: vec![],
+                is_active: active,
+                closed_at: None,
+            },
+            allocations: allocations
+                .iter()
+                .map(|(user, amount)| Allocation {
+                    user: user.to_string(),
+                    allocated_amount: Uint128::new(*amount),
+                })
+                .collect(),
+            claimed: claimed
+                .iter()
+                .map(|(user, amount)| (user.to_string(), Coin::new(*amount, denom)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_aggregates_totals_per_denom_and_unique_addresses() {
+        let snapshots = vec![
+            snapshot("c1", "uom", &[("a", 100), ("b", 300)], &[("a", 100)], true),
+            snapshot("c2", "uom", &[("b", 600)], &[("b", 150)], false),
+            snapshot("c3", "uusdc", &[("c", 50)], &[], true),
+        ];
+        let stats = GlobalCampaignStats::from_snapshots(
+            "factory",
+            &snapshots,
+            vec![CampaignStatsError {
+                campaign_address: "c4".to_string(),
+                error: "timeout".to_string(),
+            }],
+            0,
+        );
+
+        assert_eq!(stats.total_campaigns, 4);
+        assert_eq!(stats.active_campaigns, 2);
+        assert_eq!(stats.unique_participants, 3);
+        assert_eq!(stats.unique_claimants, 2);
+
+        let uom = &stats.totals[0];
+        assert_eq!(uom.denom, "uom");
+        assert_eq!(uom.allocated, Uint128::new(1_000));
+        assert_eq!(uom.claimed, Uint128::new(250));
+        assert_eq!(uom.remaining, Uint128::new(750));
+        assert!((uom.completion_percent - 25.0).abs() < 1e-9);
+
+        assert_eq!(stats.campaigns[0].completion_percent, 25.0);
+        assert_eq!(stats.campaigns[2].completion_percent, 0.0);
+    }
+
+    #[test]
+    fn test_cache_respects_max_age_and_invalidation() {
+        let cache = CampaignStatsCache::default();
+        cache.insert(GlobalCampaignStats::from_snapshots(
+            "factory",
+            &[],
+            vec![],
+            0,
+        ));
+
+        assert!(cache.get("factory", Duration::from_secs(60)).is_some());
+        assert!(cache.get("factory", Duration::ZERO).is_none());
+        assert!(cache.get("other", Duration::from_secs(60)).is_none());
+
+        cache.invalidate(Some("factory"));
+        assert!(cache.get("factory", Duration::from_secs(60)).is_none());
+    }
+}
@@ -146,3 +146,39 @@ pub struct SweepParams {
     pub denom: String,
     pub amount: Option<Uint128>,
 }
+
+/// A single campaign claim included in a batch claim
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedClaim {
+    pub campaign_address: String,
+    pub available_to_claim: Vec<Coin>,
+}
+
+/// Campaign left out of a batch claim and why
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedClaim {
+    pub campaign_address: String,
+    pub reason: String,
+}
+
+/// Batch claim plan across multiple campaigns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimAllPlan {
+    pub receiver: String,
+    pub claims: Vec<PlannedClaim>,
+    pub skipped: Vec<SkippedClaim>,
+}
+
+impl ClaimAllPlan {
+    /// Total claimable amount per denom across all planned claims
+    pub fn totals(&self) -> Vec<Coin> {
+        let mut totals: Vec<Coin> = Vec::new();
+        for coin in self.claims.iter().flat_map(|c| &c.available_to_claim) {
+            match totals.iter_mut().find(|t| t.denom == coin.denom) {
+                Some(total) => total.amount += coin.amount,
+                None => totals.push(coin.clone()),
+            }
+        }
+        totals
+    }
+}
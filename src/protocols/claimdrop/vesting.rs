@@ -0,0 +1,280 @@
+/// Vesting schedules for ClaimDrop allocations
+///
+/// A campaign splits every allocation across its distribution types: lump sums
+/// unlock in full at their start time, linear vesting unlocks pro-rata between
+/// its start and end once the optional cliff has passed. These helpers turn a
+/// campaign's distribution types into a per-user schedule and project what is
+/// claimable at any timestamp, mirroring the campaign contract's arithmetic.
+use cosmwasm_std::{Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+
+use super::types::DistributionType;
+
+/// Default number of points in a vesting timeline
+pub const DEFAULT_TIMELINE_POINTS: usize = 12;
+
+/// How a tranche unlocks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VestingKind {
+    LinearVesting,
+    LumpSum,
+}
+
+/// Portion of an allocation governed by one distribution type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VestingTranche {
+    pub kind: VestingKind,
+    pub percentage: Decimal,
+    /// Amount of the allocation in this tranche
+    pub amount: Uint128,
+    pub start_time: u64,
+    /// End of vesting; equal to `start_time` for lump sums
+    pub end_time: u64,
+    /// Timestamp before which nothing in the tranche unlocks
+    pub cliff_end: Option<u64>,
+}
+
+impl VestingTranche {
+    /// Amount of the tranche unlocked at `timestamp`
+    pub fn vested_at(&self, timestamp: u64) -> Uint128 {
+        if timestamp < self.start_time || self.cliff_end.is_some_and(|cliff| timestamp < cliff) {
+            return Uint128::zero();
+        }
+        match self.kind {
+            VestingKind::LumpSum => self.amount,
+            VestingKind::LinearVesting => {
+                let duration = self.end_time.saturating_sub(self.start_time);
+                if duration == 0 || timestamp >= self.end_time {
+                    return self.amount;
+                }
+                self.amount
+                    .multiply_ratio(timestamp - self.start_time, duration)
+            }
+        }
+    }
+
+    /// Timestamp at which the tranche first unlocks anything
+    pub fn unlock_start(&self) -> u64 {
+        self.cliff_end
+            .unwrap_or(self.start_time)
+            .max(self.start_time)
+    }
+}
+
+/// Vested and claimable amounts at a point in time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VestingPoint {
+    pub timestamp: u64,
+    pub vested: Uint128,
+    pub claimable: Uint128,
+}
+
+/// A user's vesting schedule in a ClaimDrop campaign
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VestingSchedule {
+    pub campaign_address: String,
+    pub address: String,
+    pub denom: String,
+    pub total_allocation: Uint128,
+    /// Amount already claimed
+    pub claimed: Uint128,
+    pub tranches: Vec<VestingTranche>,
+    /// Set when the campaign was closed; nothing can be claimed afterwards
+    pub closed_at: Option<u64>,
+}
+
+impl VestingSchedule {
+    /// Build a schedule from a campaign's distribution types
+    pub fn from_distribution(
+        campaign_address: impl Into<String>,
+        address: impl Into<String>,
+        denom: impl Into<String>,
+        total_allocation: Uint128,
+        claimed: Uint128,
+        distribution: &[DistributionType],
+        closed_at: Option<u64>,
+    ) -> Self {
+        let tranches = distribution
+            .iter()
+            .map(|dist| match dist {
+                DistributionType::LinearVesting {
+                    percentage,
+                    start_time,
+                    end_time,
+                    cliff_duration,
+                } => VestingTranche {
+                    kind: VestingKind::LinearVesting,
+                    percentage: *percentage,
+                    amount: total_allocation.mul_floor(*percentage),
+                    start_time: *start_time,
+                    end_time: *end_time,
+                    cliff_end: cliff_duration.map(|cliff| start_time.saturating_add(cliff)),
+                },
+                DistributionType::LumpSum {
+                    percentage,
+                    start_time,
+                } => VestingTranche {
+                    kind: VestingKind::LumpSum,
+                    percentage: *percentage,
+                    amount: total_allocation.mul_floor(*percentage),
+                    start_time: *start_time,
+                    end_time: *start_time,
+                    cliff_end: None,
+                },
+            })
+            .collect();
+
+        Self {
+            campaign_address: campaign_address.into(),
+            address: address.into(),
+            denom: denom.into(),
+            total_allocation,
+            claimed,
+            tranches,
+            closed_at,
+        }
+    }
+
+    /// Total amount unlocked at `timestamp`, claimed or not
+    pub fn vested_at(&self, timestamp: u64) -> Uint128 {
+        self.tranches
+            .iter()
+            .map(|tranche| tranche.vested_at(timestamp))
+            .sum()
+    }
+
+    /// Amount that can be claimed at `timestamp`, assuming no further claims
+    pub fn claimable_at(&self, timestamp: u64) -> Uint128 {
+        if self.closed_at.is_some_and(|closed| timestamp >= closed) {
+            return Uint128::zero();
+        }
+        self.vested_at(timestamp).saturating_sub(self.claimed)
+    }
+
+    /// Amount that can be claimed right now
+    pub fn claimable_now(&self) -> Uint128 {
+        self.claimable_at(chrono::Utc::now().timestamp().max(0) as u64)
+    }
+
+    /// Amount not yet unlocked at `timestamp`
+    pub fn locked_at(&self, timestamp: u64) -> Uint128 {
+        self.tranches
+            .iter()
+            .map(|tranche| tranche.amount)
+            .sum::<Uint128>()
+            .saturating_sub(self.vested_at(timestamp))
+    }
+
+    /// Timestamp at which every tranche is fully unlocked
+    pub fn fully_vested_at(&self) -> Option<u64> {
+        self.tranches.iter().map(|tranche| tranche.end_time).max()
+    }
+
+    /// Next timestamp after `timestamp` at which a tranche starts unlocking
+    pub fn next_unlock_after(&self, timestamp: u64) -> Option<u64> {
+        self.tranches
+            .iter()
+            .map(VestingTranche::unlock_start)
+            .filter(|&unlock| unlock > timestamp)
+            .min()
+    }
+
+    /// Vesting projection over the schedule's lifetime
+    ///
+    /// Includes every tranche's start, cliff end and end, plus `points` evenly
+    /// spaced samples between the first start and the final unlock.
+    pub fn timeline(&self, points: usize) -> Vec<VestingPoint> {
+        let (Some(first), Some(last)) = (
+            self.tranches.iter().map(|tranche| tranche.start_time).min(),
+            self.fully_vested_at(),
+        ) else {
+            return Vec::new();
+        };
+
+        let mut timestamps: Vec<u64> = self
+            .tranches
+            .iter()
+            .flat_map(|tranche| {
+                [tranche.start_time, tranche.end_time]
+                    .into_iter()
+                    .chain(tranche.cliff_end)
+            })
+            .collect();
+        if points > 1 && last > first {
+            let span = last - first;
+            timestamps.extend((0..points).map(|i| first + span * i as u64 / (points as u64 - 1)));
+        }
+        timestamps.sort_unstable();
+        timestamps.dedup();
+
+        timestamps
+            .into_iter()
+            .map(|timestamp| VestingPoint {
+                timestamp,
+                vested: self.vested_at(timestamp),
+                claimable: self.claimable_at(timestamp),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(claimed: u128) -> VestingSchedule {
+        VestingSchedule::from_distribution(
+            "mantra1campaign",
+            "mantra1user",
+            "uom",
+            Uint128::new(1_000),
+            Uint128::new(claimed),
+            &[
+                DistributionType::LumpSum {
+                    percentage: Decimal::percent(20),
+                    start_time: 1_000,
+                },
+                DistributionType::LinearVesting {
+                    percentage: Decimal::percent(80),
+                    start_time: 1_000,
+                    end_time: 2_000,
+                    cliff_duration: Some(250),
+                },
+            ],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_projections_follow_lump_sum_cliff_and_linear_vesting() {
+        let schedule = schedule(0);
+        assert_eq!(schedule.claimable_at(999), Uint128::zero());
+        // Lump sum unlocked, linear tranche still behind the cliff
+        assert_eq!(schedule.claimable_at(1_100), Uint128::new(200));
+        // Past the cliff the linear tranche catches up to elapsed time
+        assert_eq!(schedule.claimable_at(1_250), Uint128::new(400));
+        assert_eq!(schedule.claimable_at(1_500), Uint128::new(600));
+        assert_eq!(schedule.claimable_at(5_000), Uint128::new(1_000));
+        assert_eq!(schedule.locked_at(1_500), Uint128::new(400));
+        assert_eq!(schedule.fully_vested_at(), Some(2_000));
+        assert_eq!(schedule.next_unlock_after(1_100), Some(1_250));
+
+        let claimed = self::schedule(500);
+        assert_eq!(claimed.claimable_at(1_500), Uint128::new(100));
+        assert_eq!(claimed.claimable_at(1_250), Uint128::zero());
+    }
+
+    #[test]
+    fn test_timeline_and_closed_campaign() {
+        let timeline = schedule(0).timeline(5);
+        let timestamps: Vec<u64> = timeline.iter().map(|p| p.timestamp).collect();
+        assert_eq!(timestamps, vec![1_000, 1_250, 1_500, 1_750, 2_000]);
+        assert!(timeline.windows(2).all(|w| w[0].vested <= w[1].vested));
+
+        let mut closed = schedule(0);
+        closed.closed_at = Some(1_500);
+        assert_eq!(closed.claimable_at(1_400), Uint128::new(520));
+        assert_eq!(closed.claimable_at(1_600), Uint128::zero());
+    }
+}
@@ -0,0 +1,682 @@
+/// Cosmos transaction analysis
+///
+/// The Cosmos counterpart of the EVM transaction decoder and narrative
+/// generator: messages of a transaction are decoded and classified by the
+/// contract they call (the pool manager, the farm manager, the Skip entry point
+/// or a ClaimDrop campaign, recognised by its messages), and each is told as a
+/// sentence such as "you swapped 5 OM for 1.2 USDC in pool o.uom.uusdc [tx:
+/// 4F2A...91C0]". Outcomes the messages do not carry, such as the amount a swap
+/// returned, are read from the contract's `wasm` events.
+///
+/// Only the transaction body is decoded, so transactions signed with key types
+/// this SDK does not know, such as `eth_secp256k1`, are still understood.
+use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
+use cosmos_sdk_proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::Any;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::config::ContractAddresses;
+use crate::error::Error;
+
+/// Type URL of a CosmWasm contract execution
+pub const MSG_EXECUTE_CONTRACT: &str = "/cosmwasm.wasm.v1.MsgExecuteContract";
+/// Type URL of a bank transfer
+pub const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+
+/// Messages only ClaimDrop campaign contracts accept
+const CLAIMDROP_ACTIONS: &[&str] = &[
+    "claim",
+    "add_allocations",
+    "replace_address",
+    "remove_address",
+    "blacklist_address",
+    "manage_campaign",
+    "manage_authorized_wallets",
+    "sweep",
+];
+
+/// Protocol a Cosmos message belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CosmosProtocol {
+    /// Pool manager swaps, liquidity and pool creation
+    Dex,
+    /// Farm manager positions and rewards
+    Farm,
+    /// ClaimDrop campaign claims and administration
+    ClaimDrop,
+    /// Skip entry point swaps and transfers
+    Skip,
+    /// Bank transfers
+    Bank,
+    /// Any other message or contract
+    Other,
+}
+
+/// Amount of one denom, in base units
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoinAmount {
+    pub denom: String,
+    pub amount: String,
+}
+
+/// One decoded message of a Cosmos transaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodedCosmosMsg {
+    pub protocol: CosmosProtocol,
+    /// Contract message name, e.g. `swap`, or the type URL of a non-contract message
+    pub action: String,
+    pub sender: String,
+    /// Contract called, for contract executions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// Funds sent along, or the amount of a bank transfer
+    #[serde(default)]
+    pub funds: Vec<CoinAmount>,
+    /// Body of the contract message, or the fields of other known messages
+    #[serde(default)]
+    pub parameters: Value,
+}
+
+/// Event emitted by a Cosmos transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CosmosTxEvent {
+    pub kind: String,
+    pub attributes: Vec<(String, String)>,
+}
+
+impl CosmosTxEvent {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A transaction decoded and told as a narrative
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyzedCosmosTx {
+    pub hash: String,
+    pub height: u64,
+    pub success: bool,
+    /// Result code, 0 on success
+    pub code: u32,
+    pub messages: Vec<DecodedCosmosMsg>,
+    pub narrative: String,
+}
+
+/// Decodes Cosmos transactions and tells them as narratives
+#[derive(Debug, Clone, Default)]
+pub struct CosmosTxAnalyzer {
+    pool_manager: String,
+    farm_manager: Option<String>,
+    skip_entry_point: Option<String>,
+    /// Wallet told as "you"
+    active_wallet: Option<String>,
+    /// Address book labels shown instead of abbreviated addresses
+    address_labels: HashMap<String, String>,
+}
+
+impl CosmosTxAnalyzer {
+    /// Analyzer recognising the contracts of a network
+    pub fn new(contracts: &ContractAddresses) -> Self {
+        Self {
+            pool_manager: contracts.pool_manager.clone(),
+            farm_manager: contracts.farm_manager.clone(),
+            skip_entry_point: contracts.skip_entry_point.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Tell messages of `wallet` as "you"
+    pub fn with_active_wallet(mut self, wallet: Option<String>) -> Self {
+        self.active_wallet = wallet;
+        self
+    }
+
+    /// Name labelled addresses by their address book label
+    pub fn with_address_labels(mut self, address_labels: HashMap<String, String>) -> Self {
+        self.address_labels = address_labels;
+        self
+    }
+
+    /// Decode and narrate a transaction from its raw bytes and events
+    pub fn analyze(
+        &self,
+        hash: &str,
+        height: u64,
+        code: u32,
+        tx_bytes: &[u8],
+        events: &[CosmosTxEvent],
+    ) -> Result<AnalyzedCosmosTx, Error> {
+        let messages = self.decode_tx(tx_bytes)?;
+        let success = code == 0;
+        let sentences: Vec<String> = messages
+            .iter()
+            .map(|msg| self.narrate(msg, events))
+            .collect();
+        let narrative = format!(
+            "{}{} [tx: {}]",
+            if sentences.is_empty() {
+                "empty transaction".to_string()
+            } else {
+                sentences.join(", then ")
+            },
+            if success {
+                String::new()
+            } else {
+                format!(" (transaction failed with code {})", code)
+            },
+            abbreviate(hash, 4, 4)
+        );
+
+        Ok(AnalyzedCosmosTx {
+            hash: hash.to_string(),
+            height,
+            success,
+            code,
+            messages,
+            narrative,
+        })
+    }
+
+    /// Messages of a raw transaction
+    pub fn decode_tx(&self, tx_bytes: &[u8]) -> Result<Vec<DecodedCosmosMsg>, Error> {
+        let raw = TxRaw::decode(tx_bytes)
+            .map_err(|e| Error::Other(format!("Failed to decode transaction: {}", e)))?;
+        let body = TxBody::decode(raw.body_bytes.as_slice())
+            .map_err(|e| Error::Other(format!("Failed to decode transaction body: {}", e)))?;
+        Ok(body
+            .messages
+            .iter()
+            .map(|any| self.decode_message(any))
+            .collect())
+    }
+
+    /// Decode and classify one message
+    pub fn decode_message(&self, any: &Any) -> DecodedCosmosMsg {
+        let other = || DecodedCosmosMsg {
+            protocol: CosmosProtocol::Other,
+            action: any.type_url.clone(),
+            sender: String::new(),
+            contract: None,
+            funds: Vec::new(),
+            parameters: Value::Null,
+        };
+        match any.type_url.as_str() {
+            MSG_EXECUTE_CONTRACT => match MsgExecuteContract::decode(any.value.as_slice()) {
+                Ok(msg) => self.decode_execute(msg),
+                Err(_) => other(),
+            },
+            MSG_SEND => match MsgSend::decode(any.value.as_slice()) {
+                Ok(msg) => DecodedCosmosMsg {
+                    protocol: CosmosProtocol::Bank,
+                    action: "send".to_string(),
+                    sender: msg.from_address,
+                    contract: None,
+                    funds: coins(msg.amount),
+                    parameters: serde_json::json!({ "to_address": msg.to_address }),
+                },
+                Err(_) => other(),
+            },
+            _ => other(),
+        }
+    }
+
+    fn decode_execute(&self, msg: MsgExecuteContract) -> DecodedCosmosMsg {
+        let body: Value = serde_json::from_slice(&msg.msg).unwrap_or(Value::Null);
+        let (action, parameters) = match &body {
+            Value::Object(map) if map.len() == 1 => map
+                .iter()
+                .next()
+                .map(|(action, parameters)| (action.clone(), parameters.clone()))
+                .unwrap_or_default(),
+            _ => ("execute".to_string(), body.clone()),
+        };
+
+        let contract = msg.contract.as_str();
+        let protocol = if contract == self.pool_manager {
+            CosmosProtocol::Dex
+        } else if self.farm_manager.as_deref() == Some(contract) {
+            CosmosProtocol::Farm
+        } else if self.skip_entry_point.as_deref() == Some(contract) {
+            CosmosProtocol::Skip
+        } else if CLAIMDROP_ACTIONS.contains(&action.as_str()) {
+            CosmosProtocol::ClaimDrop
+        } else {
+            CosmosProtocol::Other
+        };
+
+        DecodedCosmosMsg {
+            protocol,
+            action,
+            sender: msg.sender,
+            contract: Some(msg.contract),
+            funds: coins(msg.funds),
+            parameters,
+        }
+    }
+
+    /// `wasm` event the contract of `msg` emitted for its action
+    fn outcome<'a>(
+        &self,
+        msg: &DecodedCosmosMsg,
+        events: &'a [CosmosTxEvent],
+    ) -> Option<&'a CosmosTxEvent> {
+        events.iter().find(|event| {
+            event.kind == "wasm"
+                && event.attribute("_contract_address") == msg.contract.as_deref()
+                && event.attribute("action") == Some(msg.action.as_str())
+        })
+    }
+
+    /// One sentence telling what `msg` did
+    pub fn narrate(&self, msg: &DecodedCosmosMsg, events: &[CosmosTxEvent]) -> String {
+        let who = self.format_address(&msg.sender);
+        let funds = self.format_funds(&msg.funds);
+        let param = |key: &str| msg.parameters.get(key).and_then(Value::as_str);
+        let outcome = self.outcome(msg, events);
+        let contract = msg
+            .contract
+            .as_deref()
+            .map(|contract| self.format_address(contract))
+            .unwrap_or_default();
+
+        match (msg.protocol, msg.action.as_str()) {
+            (CosmosProtocol::Dex, "swap") => {
+                let ask = param("ask_asset_denom").unwrap_or("?");
+                let received = outcome
+                    .and_then(|event| event.attribute("return_amount"))
+                    .map(|amount| format_amount(amount, ask))
+                    .unwrap_or_else(|| denom_symbol(ask));
+                format!(
+                    "{} swapped {} for {} in pool {}",
+                    who,
+                    funds,
+                    received,
+                    param("pool_identifier").unwrap_or("?")
+                )
+            }
+            (CosmosProtocol::Dex, "execute_swap_operations") => {
+                let operations = msg
+                    .parameters
+                    .get("operations")
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                let ask = operations
+                    .last()
+                    .and_then(|op| op.pointer("/mantra_swap/token_out_denom"))
+                    .and_then(Value::as_str)
+                    .map(denom_symbol)
+                    .unwrap_or_else(|| "?".to_string());
+                format!(
+                    "{} swapped {} for {} through {} pools",
+                    who,
+                    funds,
+                    ask,
+                    operations.len()
+                )
+            }
+            (CosmosProtocol::Dex, "provide_liquidity") => {
+                let mut text = format!(
+                    "{} provided {} to pool {}",
+                    who,
+                    funds,
+                    param("pool_identifier").unwrap_or("?")
+                );
+                if let Some(shares) = outcome.and_then(|event| event.attribute("added_shares")) {
+                    text.push_str(&format!(" for {} LP shares", shares));
+                }
+                text
+            }
+            (CosmosProtocol::Dex, "withdraw_liquidity") => {
+                let mut text = format!(
+                    "{} withdrew {} from pool {}",
+                    who,
+                    funds,
+                    param("pool_identifier").unwrap_or("?")
+                );
+                if let Some(assets) = outcome.and_then(|event| event.attribute("return_assets")) {
+                    text.push_str(&format!(", receiving {}", assets));
+                }
+                text
+            }
+            (CosmosProtocol::Dex, "create_pool") => {
+                let denoms: Vec<String> = msg
+                    .parameters
+                    .get("asset_denoms")
+                    .and_then(Value::as_array)
+                    .map(|denoms| {
+                        denoms
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(denom_symbol)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                format!("{} created a pool of {}", who, denoms.join("/"))
+            }
+            (CosmosProtocol::Farm, "claim") => format!("{} claimed farm rewards", who),
+            (CosmosProtocol::Farm, "manage_position") => {
+                let action = first_key(msg.parameters.get("action")).unwrap_or("manage");
+                if msg.funds.is_empty() {
+                    format!("{} {} a farm position", who, past_tense(action))
+                } else {
+                    format!(
+                        "{} {} a farm position with {}",
+                        who,
+                        past_tense(action),
+                        funds
+                    )
+                }
+            }
+            (CosmosProtocol::Farm, "manage_farm") => {
+                let action = first_key(msg.parameters.get("action")).unwrap_or("manage");
+                format!("{} {} a farm", who, past_tense(action))
+            }
+            (CosmosProtocol::ClaimDrop, "claim") => {
+                let amount = msg
+                    .parameters
+                    .get("amount")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .or_else(|| {
+                        outcome
+                            .and_then(|event| event.attribute("amount"))
+                            .map(str::to_string)
+                    })
+                    .unwrap_or_else(|| "all available rewards".to_string());
+                let mut text = format!("{} claimed {} from campaign {}", who, amount, contract);
+                if let Some(receiver) = param("receiver") {
+                    text.push_str(&format!(" for {}", self.format_address(receiver)));
+                }
+                text
+            }
+            (CosmosProtocol::ClaimDrop, "add_allocations") => format!(
+                "{} added {} allocations to campaign {}",
+                who,
+                msg.parameters
+                    .get("allocations")
+                    .and_then(Value::as_array)
+                    .map(Vec::len)
+                    .unwrap_or_default(),
+                contract
+            ),
+            (CosmosProtocol::ClaimDrop, "blacklist_address") => format!(
+                "{} {} {} in campaign {}",
+                who,
+                if msg.parameters.get("blacklist") == Some(&Value::Bool(false)) {
+                    "unblacklisted"
+                } else {
+                    "blacklisted"
+                },
+                self.format_address(param("address").unwrap_or("?")),
+                contract
+            ),
+            (CosmosProtocol::ClaimDrop, "manage_campaign") => {
+                let action = first_key(msg.parameters.get("action")).unwrap_or("manage");
+                if msg.funds.is_empty() {
+                    format!("{} {} campaign {}", who, past_tense(action), contract)
+                } else {
+                    format!(
+                        "{} {} campaign {} with {}",
+                        who,
+                        past_tense(action),
+                        contract,
+                        funds
+                    )
+                }
+            }
+            (CosmosProtocol::ClaimDrop, "sweep") => format!(
+                "{} swept {} from campaign {}",
+                who,
+                denom_symbol(param("denom").unwrap_or("?")),
+                contract
+            ),
+            (CosmosProtocol::Skip, "swap_and_action") => {
+                let min_asset = skip_asset(msg.parameters.get("min_asset"));
+                let action = first_key(msg.parameters.get("post_swap_action"));
+                let mut text = format!("{} swapped {} through Skip", who, funds);
+                if let Some(min_asset) = min_asset {
+                    text.push_str(&format!(" for at least {}", min_asset));
+                }
+                match action {
+                    Some("ibc_transfer") => text.push_str(" and sent it over IBC"),
+                    Some("transfer") | Some("bank_send") => text.push_str(" and transferred it"),
+                    _ => {}
+                }
+                text
+            }
+            (CosmosProtocol::Skip, "user_swap") => {
+                let mut text = format!("{} swapped {} through Skip", who, funds);
+                if let Some(min_asset) = skip_asset(msg.parameters.get("min_asset")) {
+                    text.push_str(&format!(" for at least {}", min_asset));
+                }
+                text
+            }
+            (CosmosProtocol::Bank, _) => format!(
+                "{} sent {} to {}",
+                who,
+                funds,
+                self.format_address(param("to_address").unwrap_or("?"))
+            ),
+            (_, action) if msg.contract.is_some() => {
+                format!("{} called {} on contract {}", who, action, contract)
+            }
+            (_, type_url) => format!("sent a {} message", type_url.trim_start_matches('/')),
+        }
+    }
+
+    /// "you", the address book label, or the abbreviated address
+    fn format_address(&self, address: &str) -> String {
+        if self.active_wallet.as_deref() == Some(address) {
+            return "you".to_string();
+        }
+        if let Some(label) = self.address_labels.get(address) {
+            return label.clone();
+        }
+        abbreviate(address, 11, 4)
+    }
+
+    fn format_funds(&self, funds: &[CoinAmount]) -> String {
+        if funds.is_empty() {
+            return "nothing".to_string();
+        }
+        funds
+            .iter()
+            .map(|coin| format_amount(&coin.amount, &coin.denom))
+            .collect::<Vec<_>>()
+            .join(" and ")
+    }
+}
+
+/// Sentences of several transactions joined with "First", "Then" and "Finally"
+pub fn sequential_narrative(narratives: &[String]) -> String {
+    match narratives {
+        [] => "No transactions found.".to_string(),
+        [only] => only.clone(),
+        _ => narratives
+            .iter()
+            .enumerate()
+            .map(|(i, narrative)| {
+                let connector = match i {
+                    0 => "First",
+                    i if i == narratives.len() - 1 => "Finally",
+                    _ => "Then",
+                };
+                format!("{}. {}\n", connector, narrative)
+            })
+            .collect(),
+    }
+}
+
+fn coins(coins: Vec<cosmos_sdk_proto::cosmos::base::v1beta1::Coin>) -> Vec<CoinAmount> {
+    coins
+        .into_iter()
+        .map(|coin| CoinAmount {
+            denom: coin.denom,
+            amount: coin.amount,
+        })
+        .collect()
+}
+
+/// `text` shortened to its first `head` and last `tail` characters
+fn abbreviate(text: &str, head: usize, tail: usize) -> String {
+    if text.len() > head + tail + 3 && text.is_ascii() {
+        format!("{}...{}", &text[..head], &text[text.len() - tail..])
+    } else {
+        text.to_string()
+    }
+}
+
+/// Symbol of a micro-denominated native denom (`uom` is OM), else the
+/// abbreviated denom
+fn denom_symbol(denom: &str) -> String {
+    match micro_symbol(denom) {
+        Some(symbol) => symbol,
+        None => abbreviate(denom, 12, 4),
+    }
+}
+
+fn micro_symbol(denom: &str) -> Option<String> {
+    denom
+        .strip_prefix('u')
+        .filter(|symbol| !symbol.is_empty() && symbol.chars().all(|c| c.is_ascii_alphanumeric()))
+        .map(str::to_uppercase)
+}
+
+/// `amount` of `denom`, in whole tokens for micro-denominated native denoms
+fn format_amount(amount: &str, denom: &str) -> String {
+    match (micro_symbol(denom), amount.parse::<u128>()) {
+        (Some(symbol), Ok(base_units)) => {
+            let whole = base_units / 1_000_000;
+            let fraction = base_units % 1_000_000;
+            if fraction == 0 {
+                format!("{} {}", whole, symbol)
+            } else {
+                let fraction = format!("{:06}", fraction);
+                format!("{}.{} {}", whole, fraction.trim_end_matches('0'), symbol)
+            }
+        }
+        _ => format!("{} {}", amount, denom_symbol(denom)),
+    }
+}
+
+/// Name of the single variant of an externally tagged enum value
+fn first_key(value: Option<&Value>) -> Option<&str> {
+    match value? {
+        Value::Object(map) => map.keys().next().map(String::as_str),
+        Value::String(name) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+fn past_tense(action: &str) -> String {
+    match action {
+        "fill" => "filled".to_string(),
+        "create" => "created".to_string(),
+        "expand" => "expanded".to_string(),
+        "close" => "closed".to_string(),
+        "withdraw" => "withdrew".to_string(),
+        "top_up" => "topped up".to_string(),
+        other => format!("ran {} on", other),
+    }
+}
+
+/// Amount of a Skip `{native: {denom, amount}}` asset
+fn skip_asset(value: Option<&Value>) -> Option<String> {
+    let asset = value?.get("native")?;
+    Some(format_amount(
+        asset.get("amount")?.as_str()?,
+        asset.get("denom")?.as_str()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: &str = "mantra1wallet00000000000000000000000000000";
+    const POOL_MANAGER: &str = "mantra1poolmanager";
+    const CAMPAIGN: &str = "mantra1campaign0000000000000000000000000000";
+
+    fn execute(contract: &str, msg: Value, funds: &[(&str, &str)]) -> Any {
+        let msg = MsgExecuteContract {
+            sender: WALLET.to_string(),
+            contract: contract.to_string(),
+            msg: serde_json::to_vec(&msg).unwrap(),
+            funds: funds
+                .iter()
+                .map(
+                    |(denom, amount)| cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                        denom: denom.to_string(),
+                        amount: amount.to_string(),
+                    },
+                )
+                .collect(),
+        };
+        Any {
+            type_url: MSG_EXECUTE_CONTRACT.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_decodes_and_narrates_dex_and_claimdrop_messages() {
+        let analyzer = CosmosTxAnalyzer::new(&ContractAddresses {
+            pool_manager: POOL_MANAGER.to_string(),
+            ..Default::default()
+        })
+        .with_active_wallet(Some(WALLET.to_string()));
+
+        let swap = execute(
+            POOL_MANAGER,
+            serde_json::json!({"swap": {"ask_asset_denom": "uusdc", "pool_identifier": "o.uom.uusdc"}}),
+            &[("uom", "5000000")],
+        );
+        let claim = execute(CAMPAIGN, serde_json::json!({"claim": {}}), &[]);
+        let body = TxBody {
+            messages: vec![swap, claim],
+            ..Default::default()
+        };
+        let tx_bytes = TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: Vec::new(),
+            signatures: Vec::new(),
+        }
+        .encode_to_vec();
+        let events = vec![CosmosTxEvent {
+            kind: "wasm".to_string(),
+            attributes: vec![
+                ("_contract_address".to_string(), POOL_MANAGER.to_string()),
+                ("action".to_string(), "swap".to_string()),
+                ("return_amount".to_string(), "1250000".to_string()),
+            ],
+        }];
+
+        let analyzed = analyzer
+            .analyze("4F2A0000000000000000000091C0", 42, 0, &tx_bytes, &events)
+            .unwrap();
+        assert_eq!(analyzed.messages[0].protocol, CosmosProtocol::Dex);
+        assert_eq!(analyzed.messages[1].protocol, CosmosProtocol::ClaimDrop);
+        assert_eq!(
+            analyzed.narrative,
+            "you swapped 5 OM for 1.25 USDC in pool o.uom.uusdc, then you claimed all \
+             available rewards from campaign mantra1camp...0000 [tx: 4F2A...91C0]"
+        );
+
+        let failed = analyzer
+            .analyze("AB", 43, 5, &tx_bytes, &[])
+            .unwrap()
+            .narrative;
+        assert!(failed.contains("for USDC in pool"));
+        assert!(failed.ends_with("(transaction failed with code 5) [tx: AB]"));
+        assert!(analyzer.decode_tx(b"not a tx").is_err());
+    }
+}
@@ -1,36 +1,114 @@
+use std::collections::HashMap;
+use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
 
+#[cfg(not(target_arch = "wasm32"))]
 use base64::{engine::general_purpose, Engine};
+#[cfg(not(target_arch = "wasm32"))]
 use chrono;
 use cosmos_sdk_proto::{
     cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
-    cosmos::bank::v1beta1::{QueryAllBalancesRequest, QueryAllBalancesResponse},
-    cosmwasm::wasm::v1::QuerySmartContractStateResponse,
+    cosmos::bank::v1beta1::{
+        QueryAllBalancesRequest, QueryAllBalancesResponse, QueryDenomMetadataRequest,
+        QueryDenomMetadataResponse,
+    },
+    cosmos::staking::v1beta1::{
+        QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    },
+};
+#[cfg(not(target_arch = "wasm32"))]
+use cosmos_sdk_proto::{
+    cosmos::base::node::v1beta1::{ConfigRequest, ConfigResponse},
+    cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse},
 };
+#[cfg(not(target_arch = "wasm32"))]
 use cosmrs::{
-    proto::{
-        cosmos::base::{abci::v1beta1::TxResponse, v1beta1::Coin as CosmosCoin},
-        cosmwasm::wasm::v1::{MsgExecuteContract, QuerySmartContractStateRequest},
+    proto::cosmos::base::abci::v1beta1::TxResponse,
+    rpc::{
+        endpoint::tx::Response as TxQueryResponse, query::Query, Client as RpcClient, HttpClient,
+        Order, Paging,
     },
-    rpc::{Client as RpcClient, HttpClient},
     tendermint::{chain::Id, Hash},
-    tx::{Body, MessageExt, SignDoc, SignerInfo},
+    tx::{Body, SignDoc, SignerInfo},
+};
+use cosmrs::{
+    proto::{cosmos::base::v1beta1::Coin as CosmosCoin, cosmwasm::wasm::v1::MsgExecuteContract},
+    tx::MessageExt,
     Any,
 };
 use cosmwasm_std::{Coin, Decimal, Uint128};
+use futures::Stream;
+#[cfg(not(target_arch = "wasm32"))]
 use hex;
-use mantra_dex_std::pool_manager::{
-    self, PoolInfoResponse, PoolsResponse, SimulationResponse, SwapOperation,
-};
+#[cfg(not(target_arch = "wasm32"))]
+use mantra_dex_std::pool_manager::SwapOperation;
+use mantra_dex_std::pool_manager::{self, PoolInfoResponse, PoolsResponse, SimulationResponse};
 use prost::Message;
 use serde::de::DeserializeOwned;
 use tokio::sync::Mutex;
-
+use tracing::Instrument;
+use web_time::Instant;
+
+use super::depth;
+use super::dust::DustPolicy;
+#[cfg(not(target_arch = "wasm32"))]
+use super::dust::{
+    self, DustAction, DustConversion, DustLedger, DustReport, DustSweepReport, SkippedDust,
+};
+use super::snapshot::{PoolStateSnapshot, DEFAULT_SNAPSHOT_MAX_BLOCKS_BEHIND};
+use super::types::{
+    PoolDepthReport, StakedBalance, SwapImpactCheck, WithdrawalPreview, ZapInPreview, ZapOutPreview,
+};
+use super::withdrawal;
+use super::zap;
+use crate::amount::{Amount, DenomRegistry};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::chain_info::{BlockSummary, ChainParams, MempoolStatus, NodeStatus, ValidatorSet};
 use crate::config::MantraNetworkConfig;
+use crate::denom_metadata::{
+    DenomMetadata, DenomMetadataService, QueryDenomTraceRequest, QueryDenomTraceResponse,
+};
+use crate::endpoints::{CircuitBreakerConfig, EndpointHealth, EndpointPool};
 use crate::error::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::fees::{self, CostEstimate, CostOperation};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::gas_price::{GasPriceOracle, GasPriceQuote, UTILIZATION_BLOCKS};
+#[cfg(feature = "grpc")]
+use crate::grpc::{self, GrpcClient};
+use crate::pagination::{Page, PageRequest, Paginator};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::performance::ConfirmationTracker;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::protocols::cosmos_analyzer::CosmosTxAnalyzer;
+use crate::protocols::skip::affiliate::{
+    AffiliateFeeLedger, AffiliateFeeReport, SkipAffiliateConfig,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::protocols::skip::smart_swap::SmartSwapExecution;
+use crate::protocols::skip::smart_swap::{self, SmartSwapRouteResult, SmartSwapSimulation};
+use crate::retry::{Classify, RetryPolicy};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::signer_queue::{SignerKey, SignerQueue, SignerSlot};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::signing_log::{self, IntentMessage, SigningLog, SigningOutcome};
+use crate::trace;
+use crate::transport::{self, AbciResponse, AbciTransport, SharedTransport};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::tx_queue::{cosmos_tx_hash, TxQueue, TxVm};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::tx_tag;
+use crate::tx_tag::TxTagging;
 use crate::wallet::MantraWallet;
 
+/// Gas limit transactions are signed with; the fee pays for all of it
+pub const DEFAULT_GAS_LIMIT: u64 = 2_000_000;
+
+/// Blocks past the latest one a transaction stays valid for, about ten
+/// minutes on MANTRA Chain
+pub const DEFAULT_TIMEOUT_BLOCKS: u64 = 100;
+
 /// Pool status enum for validation
 #[derive(Debug, Clone, PartialEq)]
 pub enum PoolStatus {
@@ -51,18 +129,82 @@ impl PoolStatus {
     }
 }
 
+/// Client of one RPC endpoint: the Tendermint RPC client natively, the Fetch
+/// API in the browser
+#[cfg(not(target_arch = "wasm32"))]
+type EndpointClient = HttpClient;
+#[cfg(target_arch = "wasm32")]
+type EndpointClient = Arc<crate::transport::FetchTransport>;
+
+/// Connect to the RPC endpoint at `url`
+#[cfg(not(target_arch = "wasm32"))]
+fn connect(url: &str) -> Result<EndpointClient, Error> {
+    HttpClient::new(url)
+        .map_err(|e| Error::Rpc(format!("Failed to create RPC client for {}: {}", url, e)))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn connect(url: &str) -> Result<EndpointClient, Error> {
+    Ok(Arc::new(crate::transport::FetchTransport::new(url)))
+}
+
+/// Queries over the shared RPC connection follow it when it fails over
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl AbciTransport for Mutex<EndpointClient> {
+    async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<AbciResponse, Error> {
+        let client = self.lock().await.clone();
+        AbciTransport::abci_query(&client, path, data, height).await
+    }
+}
+
 /// Mantra DEX client for interacting with the network
 ///
 /// This client provides methods to interact with the Mantra DEX v3.0.0,
 /// including pool operations, swapping, liquidity provision, and rewards management.
 #[derive(Debug)]
 pub struct MantraDexClient {
-    /// RPC client for the Mantra network
-    rpc_client: Arc<Mutex<HttpClient>>,
+    /// RPC client for the Mantra network, connected to the preferred healthy
+    /// endpoint
+    rpc_client: Arc<Mutex<EndpointClient>>,
+    /// Endpoint `rpc_client` is connected to
+    rpc_endpoint: Arc<std::sync::Mutex<String>>,
+    /// RPC client of each configured endpoint
+    rpc_clients: HashMap<String, EndpointClient>,
+    /// Circuit breakers of the configured endpoints
+    endpoints: Arc<EndpointPool>,
+    /// gRPC client queries prefer, when the network has a gRPC endpoint
+    #[cfg(feature = "grpc")]
+    grpc: Option<GrpcClient>,
     /// Network configuration
     config: MantraNetworkConfig,
     /// Wallet for signing transactions
-    wallet: Option<MantraWallet>,
+    wallet: Option<Arc<MantraWallet>>,
+    /// How residual balances are handled after swaps and zaps
+    dust_policy: DustPolicy,
+    /// Affiliate paid out of Skip swaps that do not name their own
+    skip_affiliate: Option<SkipAffiliateConfig>,
+    /// Simulate signed transactions instead of broadcasting them
+    simulate_only: bool,
+    /// Retries of queries that fail transiently
+    retry_policy: RetryPolicy,
+    /// Queue signed transactions are persisted to before broadcast
+    #[cfg(not(target_arch = "wasm32"))]
+    tx_queue: Option<TxQueue>,
+    /// Memo of every transaction the client signs
+    memo: Option<String>,
+    /// Blocks past the latest one transactions are valid for; 0 signs them
+    /// without a timeout height
+    timeout_blocks: u64,
+    /// Pool state simulations of smart swaps run against
+    pool_snapshot: std::sync::RwLock<Option<Arc<PoolStateSnapshot>>>,
+    /// Symbols and decimals of the denoms the client has formatted
+    denom_metadata: DenomMetadataService,
 }
 
 impl MantraDexClient {
@@ -80,13 +222,48 @@ impl MantraDexClient {
     ///
     /// Returns an error if the RPC client cannot be created
     pub async fn new(config: MantraNetworkConfig) -> Result<Self, Error> {
-        let rpc_client = HttpClient::new(config.rpc_url.as_str())
-            .map_err(|e| Error::Rpc(format!("Failed to create RPC client: {}", e)))?;
+        let rpc_clients = config
+            .rpc_urls()
+            .into_iter()
+            .map(|url| Ok((url.clone(), connect(&url)?)))
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+        let rpc_client = rpc_clients[&config.rpc_url].clone();
+        let endpoints = EndpointPool::new(config.rpc_urls(), CircuitBreakerConfig::default());
+        #[cfg(feature = "grpc")]
+        let grpc = config
+            .grpc_url
+            .as_deref()
+            .and_then(|url| match GrpcClient::new(url) {
+                Ok(client) => Some(client),
+                Err(e) => {
+                    tracing::warn!("Querying over RPC only: {}", e);
+                    None
+                }
+            });
+        #[cfg(not(feature = "grpc"))]
+        if config.grpc_url.is_some() {
+            tracing::warn!("gRPC support not compiled in; querying over RPC only");
+        }
 
         Ok(Self {
             rpc_client: Arc::new(Mutex::new(rpc_client)),
+            rpc_endpoint: Arc::new(std::sync::Mutex::new(config.rpc_url.clone())),
+            rpc_clients,
+            endpoints: Arc::new(endpoints),
+            #[cfg(feature = "grpc")]
+            grpc,
             config,
             wallet: None,
+            dust_policy: DustPolicy::default(),
+            skip_affiliate: None,
+            simulate_only: false,
+            retry_policy: RetryPolicy::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            tx_queue: None,
+            memo: None,
+            timeout_blocks: DEFAULT_TIMEOUT_BLOCKS,
+            pool_snapshot: std::sync::RwLock::new(None),
+            denom_metadata: DenomMetadataService::new(),
         })
     }
 
@@ -100,26 +277,296 @@ impl MantraDexClient {
     ///
     /// The client instance with the wallet configured
     pub fn with_wallet(mut self, wallet: MantraWallet) -> Self {
+        self.wallet = Some(Arc::new(wallet));
+        self
+    }
+
+    /// Use the wallet of another client, without duplicating its keys
+    pub fn with_shared_wallet(mut self, wallet: Arc<MantraWallet>) -> Self {
         self.wallet = Some(wallet);
         self
     }
 
+    /// Stamp every transaction the client signs with `memo`, replacing the
+    /// memo of its tagging
+    pub fn with_memo(mut self, memo: Option<String>) -> Self {
+        self.memo = memo;
+        self
+    }
+
+    /// Stamp every transaction with the memo pattern of `tagging`, expanded
+    /// for the client's chain
+    pub fn with_tx_tagging(self, tagging: &TxTagging) -> Self {
+        let memo = tagging.memo_for(&self.config.chain_id);
+        self.with_memo(memo)
+    }
+
+    /// Memo of the transactions the client signs
+    pub fn memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+
+    /// Sign transactions valid until `blocks` past the latest block, after
+    /// which the chain drops them with [`Error::TxExpired`]; 0 lets them wait
+    /// in the mempool indefinitely
+    pub fn with_timeout_blocks(mut self, blocks: u64) -> Self {
+        self.timeout_blocks = blocks;
+        self
+    }
+
+    /// Blocks past the latest one transactions are valid for
+    pub fn timeout_blocks(&self) -> u64 {
+        self.timeout_blocks
+    }
+
     /// Set the wallet for signing transactions
     ///
     /// # Arguments
     ///
     /// * `wallet` - The wallet to use for signing transactions
     pub fn set_wallet(&mut self, wallet: MantraWallet) {
-        self.wallet = Some(wallet);
+        self.wallet = Some(Arc::new(wallet));
+    }
+
+    /// Set the policy applied to residual balances after swaps and zaps
+    pub fn with_dust_policy(mut self, policy: DustPolicy) -> Self {
+        self.dust_policy = policy;
+        self
+    }
+
+    /// Get the dust policy
+    pub fn dust_policy(&self) -> &DustPolicy {
+        &self.dust_policy
+    }
+
+    /// Simulate transactions instead of broadcasting them
+    ///
+    /// Transactions are still built and signed, then run through the chain's
+    /// simulation endpoint. The returned responses carry the simulated gas and
+    /// events but no hash or height.
+    pub fn with_simulation(mut self, simulate_only: bool) -> Self {
+        self.simulate_only = simulate_only;
+        self
+    }
+
+    /// Whether transactions are simulated instead of broadcast
+    pub fn is_simulating(&self) -> bool {
+        self.simulate_only
+    }
+
+    /// Set how queries that fail transiently are retried
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Get the retry policy
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Persist signed transactions to `queue` before broadcasting them, so
+    /// they can be monitored again after a restart
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_tx_queue(mut self, queue: TxQueue) -> Self {
+        self.tx_queue = Some(queue);
+        self
+    }
+
+    /// Queue signed transactions are persisted to, if any
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn tx_queue(&self) -> Option<&TxQueue> {
+        self.tx_queue.as_ref()
+    }
+
+    /// Cache denom metadata in `service`, such as one shared with other
+    /// clients of the same network
+    pub fn with_denom_metadata(mut self, service: DenomMetadataService) -> Self {
+        self.denom_metadata = service;
+        self
+    }
+
+    /// Cache of the denom metadata the client has resolved
+    pub fn denom_metadata_service(&self) -> &DenomMetadataService {
+        &self.denom_metadata
+    }
+
+    /// Set when an RPC endpoint's circuit opens and for how long
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.endpoints = Arc::new(EndpointPool::new(self.config.rpc_urls(), config));
+        self
+    }
+
+    /// Share the circuit breakers of `endpoints`, such as those of another
+    /// client of the same network
+    pub fn with_endpoint_pool(mut self, endpoints: Arc<EndpointPool>) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Circuit breakers of the RPC endpoints
+    pub fn endpoint_pool(&self) -> &Arc<EndpointPool> {
+        &self.endpoints
+    }
+
+    /// Health of each RPC endpoint, the primary first
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    /// gRPC client of the network's `grpc_url`, if it has one
+    #[cfg(feature = "grpc")]
+    pub fn grpc(&self) -> Option<&GrpcClient> {
+        self.grpc.as_ref()
+    }
+
+    /// Run `query` over gRPC when the network has a gRPC endpoint, reporting
+    /// `None` when there is none or it cannot be reached, so the caller falls
+    /// back to RPC
+    #[cfg(feature = "grpc")]
+    async fn try_grpc<'a, T, F, Fut>(&'a self, query: F) -> Option<Result<T, Error>>
+    where
+        F: FnOnce(&'a GrpcClient) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let client = self.grpc.as_ref()?;
+        match query(client).await {
+            Err(e) if grpc::falls_back(&e) => {
+                tracing::debug!("Falling back to RPC: {}", e);
+                None
+            }
+            result => Some(result),
+        }
+    }
+
+    /// Run `call` on the next healthy RPC endpoint, recording the outcome with
+    /// the endpoint's circuit breaker
+    ///
+    /// Only failures that are worth retrying count against the endpoint. When
+    /// a circuit opens, the shared connection moves to the preferred healthy
+    /// endpoint.
+    async fn on_endpoint<T, F, Fut>(&self, method: &str, call: F) -> Result<T, Error>
+    where
+        F: FnOnce(EndpointClient) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let url = self
+            .endpoints
+            .next()
+            .unwrap_or_else(|| self.config.rpc_url.clone());
+        let started = Instant::now();
+        let result = call(self.endpoint_client(&url)?)
+            .instrument(tracing::debug_span!("rpc", method, endpoint = %url))
+            .await
+            .map_err(|e| e.at_endpoint(&url));
+        trace::record_rpc(
+            method,
+            &url,
+            started,
+            result.as_ref().err().map(|e| e.to_string()),
+        );
+        match &result {
+            Ok(_) => self.endpoints.record_success(&url),
+            Err(e) if e.is_retryable() => {
+                if self.endpoints.record_failure(&url, &e.to_string()) {
+                    self.fail_over().await?;
+                }
+            }
+            Err(_) => {}
+        }
+        result
+    }
+
+    /// Connect the shared RPC client to the preferred healthy endpoint
+    async fn fail_over(&self) -> Result<(), Error> {
+        let Some(url) = self.endpoints.preferred() else {
+            return Ok(());
+        };
+        let client = self.endpoint_client(&url)?;
+        let mut rpc_client = self.rpc_client.lock().await;
+        let mut rpc_endpoint = self
+            .rpc_endpoint
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if *rpc_endpoint != url {
+            tracing::warn!("Failing over from RPC endpoint {} to {}", rpc_endpoint, url);
+            *rpc_client = client;
+            *rpc_endpoint = url;
+        }
+        Ok(())
+    }
+
+    fn endpoint_client(&self, url: &str) -> Result<EndpointClient, Error> {
+        match self.rpc_clients.get(url) {
+            Some(client) => Ok(client.clone()),
+            None => connect(url),
+        }
+    }
+
+    /// ABCI query transport of the shared RPC connection
+    pub fn transport(&self) -> SharedTransport {
+        self.rpc_client.clone()
+    }
+
+    /// Endpoint the shared RPC client is connected to
+    #[cfg(not(target_arch = "wasm32"))]
+    fn current_endpoint(&self) -> String {
+        self.rpc_endpoint
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Set the affiliate paid out of Skip swaps
+    ///
+    /// The affiliate is attached to every Skip swap executed without an
+    /// explicit affiliate list.
+    pub fn with_skip_affiliate(mut self, affiliate: SkipAffiliateConfig) -> Self {
+        self.skip_affiliate = Some(affiliate);
+        self
+    }
+
+    /// Get the Skip affiliate
+    pub fn skip_affiliate(&self) -> Option<&SkipAffiliateConfig> {
+        self.skip_affiliate.as_ref()
+    }
+
+    /// Read-only client for a ClaimDrop campaign sharing this client's RPC connection
+    ///
+    /// No wallet is attached, so only queries are available.
+    pub fn claimdrop_campaign(
+        &self,
+        campaign_address: String,
+    ) -> crate::protocols::claimdrop::ClaimdropClient {
+        crate::protocols::claimdrop::ClaimdropClient::new(self.transport(), campaign_address, None)
+    }
+
+    /// Read-only client for the ClaimDrop factory at `factory_address`
+    /// sharing this client's RPC connection
+    pub fn claimdrop_factory(
+        &self,
+        factory_address: String,
+    ) -> crate::protocols::claimdrop::ClaimdropFactoryClient {
+        crate::protocols::claimdrop::ClaimdropFactoryClient::new(
+            self.transport(),
+            self.config.chain_id.clone(),
+            factory_address,
+            None,
+        )
     }
 
     /// Get the wallet if available
     pub fn wallet(&self) -> Result<&MantraWallet, Error> {
         self.wallet
-            .as_ref()
+            .as_deref()
             .ok_or_else(|| Error::Wallet("No wallet configured".to_string()))
     }
 
+    /// Shared handle on the wallet, for a client that should sign with it too
+    pub fn shared_wallet(&self) -> Option<Arc<MantraWallet>> {
+        self.wallet.clone()
+    }
+
     /// Get the wallet address if wallet is configured
     pub async fn get_wallet_address(&self) -> Option<String> {
         match &self.wallet {
@@ -148,13 +595,249 @@ impl MantraDexClient {
     }
 
     /// Get last block height
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn get_last_block_height(&self) -> Result<u64, Error> {
-        let rpc_client = self.rpc_client.lock().await;
-        let height = rpc_client
-            .latest_block()
+        self.on_endpoint("latest_block", |rpc_client| async move {
+            let height = rpc_client
+                .latest_block()
+                .await
+                .map_err(|e| Error::Rpc(format!("Failed to get last block height: {}", e)))?;
+            Ok(height.block.header.height.value())
+        })
+        .await
+    }
+
+    /// Get last block height, as the node reports it answering a query of
+    /// the pool manager
+    #[cfg(target_arch = "wasm32")]
+    pub async fn get_last_block_height(&self) -> Result<u64, Error> {
+        self.on_endpoint("latest_block", |rpc_client| async move {
+            transport::latest_height(&rpc_client, &self.config.contracts.pool_manager).await
+        })
+        .await
+    }
+
+    /// Get the height and timestamp (unix seconds) of the latest block
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn get_latest_block_time(&self) -> Result<(u64, i64), Error> {
+        self.on_endpoint("latest_block", |rpc_client| async move {
+            let response = rpc_client
+                .latest_block()
+                .await
+                .map_err(|e| Error::Rpc(format!("Failed to get latest block: {}", e)))?;
+            let header = &response.block.header;
+            Ok((header.height.value(), header.time.unix_timestamp()))
+        })
+        .await
+    }
+
+    /// Get the timestamp (unix seconds) of the block at `height`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn get_block_time(&self, height: u64) -> Result<i64, Error> {
+        let height = cosmrs::tendermint::block::Height::try_from(height)
+            .map_err(|e| Error::Other(format!("Invalid block height: {}", e)))?;
+        self.on_endpoint("header", |rpc_client| async move {
+            let response = rpc_client
+                .header(height)
+                .await
+                .map_err(|e| Error::Rpc(format!("Failed to get block header: {}", e)))?;
+            Ok(response.header.time.unix_timestamp())
+        })
+        .await
+    }
+
+    /// Status of the node the client is connected to
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn node_status(&self) -> Result<NodeStatus, Error> {
+        self.on_endpoint("status", |rpc_client| async move {
+            let response = rpc_client
+                .status()
+                .await
+                .map_err(|e| Error::Rpc(format!("Failed to get node status: {}", e)))?;
+            Ok(NodeStatus::from_rpc(&response))
+        })
+        .await
+    }
+
+    /// Block at `height`, or the latest block
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn block_summary(&self, height: Option<u64>) -> Result<BlockSummary, Error> {
+        let height = height
+            .map(cosmrs::tendermint::block::Height::try_from)
+            .transpose()
+            .map_err(|e| Error::Other(format!("Invalid block height: {}", e)))?;
+        self.on_endpoint("block", |rpc_client| async move {
+            let response = match height {
+                Some(height) => rpc_client.block(height).await,
+                None => rpc_client.latest_block().await,
+            }
+            .map_err(|e| Error::Rpc(format!("Failed to get block: {}", e)))?;
+            Ok(BlockSummary::from_rpc(&response))
+        })
+        .await
+    }
+
+    /// Validator set at `height`, or at the latest block
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn validator_set(&self, height: Option<u64>) -> Result<ValidatorSet, Error> {
+        let height = match height {
+            Some(height) => height,
+            None => self.get_last_block_height().await?,
+        };
+        let block_height = cosmrs::tendermint::block::Height::try_from(height)
+            .map_err(|e| Error::Other(format!("Invalid block height: {}", e)))?;
+        self.on_endpoint("validators", |rpc_client| async move {
+            let response = rpc_client
+                .validators(block_height, Paging::All)
+                .await
+                .map_err(|e| Error::Rpc(format!("Failed to get validators: {}", e)))?;
+            Ok(ValidatorSet::from_rpc(
+                response.block_height.value(),
+                &response.validators,
+            ))
+        })
+        .await
+    }
+
+    /// Block limits of the chain, with the gas price the client pays and the
+    /// minimum the node accepts
+    ///
+    /// Nodes that do not expose their configuration leave the minimum unset.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn chain_params(&self) -> Result<ChainParams, Error> {
+        let consensus = self
+            .on_endpoint("consensus_params", |rpc_client| async move {
+                rpc_client
+                    .latest_consensus_params()
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get consensus params: {}", e)))
+            })
+            .await?;
+        let node_config = self
+            .abci_proto_query::<ConfigRequest, ConfigResponse>(
+                "/cosmos.base.node.v1beta1.Service/Config",
+                &ConfigRequest {},
+            )
+            .await
+            .unwrap_or_else(|e| {
+                tracing::debug!("Node configuration unavailable: {}", e);
+                None
+            });
+
+        let block = &consensus.consensus_params.block;
+        Ok(ChainParams {
+            chain_id: self.config.chain_id.clone(),
+            max_block_gas: u64::try_from(block.max_gas).ok(),
+            max_block_bytes: block.max_bytes,
+            native_denom: self.config.native_denom.clone(),
+            gas_price: self.config.gas_price,
+            node_minimum_gas_price: node_config
+                .map(|config| config.minimum_gas_price)
+                .filter(|price| !price.is_empty()),
+        })
+    }
+
+    /// Gas price fees are paid at on this network
+    ///
+    /// Served from the [`GasPriceOracle`] while fresh. Otherwise it is
+    /// derived from the node's minimum gas price and the gas use of the last
+    /// [`UTILIZATION_BLOCKS`] blocks; when the node cannot be read, the
+    /// configured gas price is used as is.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn gas_price_quote(&self) -> GasPriceQuote {
+        let oracle = GasPriceOracle::global();
+        if let Some(quote) = oracle.cached(&self.config.chain_id) {
+            return quote;
+        }
+        let quote = match self.fetch_gas_price_quote().await {
+            Ok(quote) => quote,
+            Err(e) => {
+                tracing::warn!(
+                    "Pricing gas at the configured {}{}: {}",
+                    self.config.gas_price,
+                    self.config.native_denom,
+                    e
+                );
+                GasPriceQuote::new(
+                    self.config.chain_id.clone(),
+                    self.config.native_denom.clone(),
+                    self.config.gas_price,
+                    self.config.gas_adjustment,
+                    None,
+                    None,
+                )
+            }
+        };
+        oracle.store(quote.clone());
+        quote
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn fetch_gas_price_quote(&self) -> Result<GasPriceQuote, Error> {
+        let params = self.chain_params().await?;
+        let utilization = match params.max_block_gas.filter(|max| *max > 0) {
+            Some(max_gas) => self
+                .recent_block_gas_used()
+                .await
+                .inspect_err(|e| tracing::debug!("Block gas use unavailable: {}", e))
+                .ok()
+                .map(|gas_used| gas_used / max_gas as f64),
+            None => None,
+        };
+        Ok(GasPriceQuote::new(
+            params.chain_id.clone(),
+            params.native_denom.clone(),
+            params.gas_price,
+            self.config.gas_adjustment,
+            params.node_minimum_native_gas_price(),
+            utilization,
+        ))
+    }
+
+    /// Average gas used by the last [`UTILIZATION_BLOCKS`] blocks
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn recent_block_gas_used(&self) -> Result<f64, Error> {
+        let latest = self.get_last_block_height().await?;
+        let first = latest.saturating_sub(UTILIZATION_BLOCKS - 1).max(1);
+        let blocks = (first..=latest).map(|height| async move {
+            let height = cosmrs::tendermint::block::Height::try_from(height)
+                .map_err(|e| Error::Other(format!("Invalid block height: {}", e)))?;
+            self.on_endpoint("block_results", |rpc_client| async move {
+                let response = rpc_client
+                    .block_results(height)
+                    .await
+                    .map_err(|e| Error::Rpc(format!("Failed to get block results: {}", e)))?;
+                Ok(response
+                    .txs_results
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|result| result.gas_used.max(0) as u64)
+                    .sum::<u64>())
+            })
+            .await
+        });
+        let gas_used = futures::future::try_join_all(blocks).await?;
+        Ok(gas_used.iter().sum::<u64>() as f64 / gas_used.len() as f64)
+    }
+
+    /// Transactions waiting in the mempool of the node the client is
+    /// connected to
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn mempool_status(&self) -> Result<MempoolStatus, Error> {
+        let url = format!(
+            "{}/num_unconfirmed_txs?limit=1",
+            self.current_endpoint().trim_end_matches('/')
+        );
+        let response: serde_json::Value = reqwest::Client::new()
+            .get(&url)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| Error::Rpc(format!("Failed to get mempool status: {}", e)))?
+            .json()
             .await
-            .map_err(|e| Error::Rpc(format!("Failed to get last block height: {}", e)))?;
-        Ok(height.block.header.height.value())
+            .map_err(|e| Error::Rpc(format!("Invalid mempool status: {}", e)))?;
+        MempoolStatus::from_rpc_json(&response)
     }
 
     /// Get the Wallet balances
@@ -166,8 +849,27 @@ impl MantraDexClient {
 
     /// Get balances for a specific address
     pub async fn get_balances_for_address(&self, address: &str) -> Result<Vec<Coin>, Error> {
-        let rpc_client = self.rpc_client.lock().await;
+        #[cfg(feature = "grpc")]
+        if let Some(result) = self.try_grpc(|grpc| grpc.all_balances(address)).await {
+            return result;
+        }
+        self.on_endpoint("bank/AllBalances", |rpc_client| {
+            Self::query_balances(rpc_client, address)
+        })
+        .await
+    }
 
+    /// Fund `address` from the testnet faucet and wait for the tokens to
+    /// arrive, see [`Faucet`](crate::faucet::Faucet)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn faucet_request(
+        &self,
+        address: &str,
+    ) -> Result<crate::faucet::FaucetReceipt, Error> {
+        crate::faucet::Faucet::global().request(self, address).await
+    }
+
+    async fn query_balances(rpc_client: EndpointClient, address: &str) -> Result<Vec<Coin>, Error> {
         // Create a request to get all balances
         let request = QueryAllBalancesRequest {
             address: address.to_string(),
@@ -175,27 +877,14 @@ impl MantraDexClient {
             resolve_denom: false,
         };
 
-        // Encode the request to protobuf
-        let encoded_request = request.encode_to_vec();
-
         // Execute the query
-        let response = rpc_client
-            .abci_query(
-                Some("/cosmos.bank.v1beta1.Query/AllBalances".to_string()),
-                encoded_request,
-                None,
-                false,
-            )
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to get balances: {}", e)))?;
-
-        if !response.code.is_ok() {
-            return Err(Error::Rpc(format!("Query failed: {}", response.log)));
-        }
-
-        // Decode the response
-        let balances_response = QueryAllBalancesResponse::decode(response.value.as_slice())
-            .map_err(|e| Error::Rpc(format!("Failed to decode balances response: {}", e)))?;
+        let balances_response: QueryAllBalancesResponse = transport::proto_query(
+            &rpc_client,
+            "/cosmos.bank.v1beta1.Query/AllBalances",
+            &request,
+            None,
+        )
+        .await?;
 
         // Convert from cosmos proto coins to cosmwasm coins
         let balances = balances_response
@@ -210,12 +899,52 @@ impl MantraDexClient {
         Ok(balances)
     }
 
+    /// Tokens `address` has staked, per validator
+    pub async fn get_delegations(&self, address: &str) -> Result<Vec<StakedBalance>, Error> {
+        self.on_endpoint("staking/DelegatorDelegations", |rpc_client| {
+            Self::query_delegations(rpc_client, address)
+        })
+        .await
+    }
+
+    async fn query_delegations(
+        rpc_client: EndpointClient,
+        address: &str,
+    ) -> Result<Vec<StakedBalance>, Error> {
+        let request = QueryDelegatorDelegationsRequest {
+            delegator_addr: address.to_string(),
+            pagination: None,
+        };
+        let delegations: QueryDelegatorDelegationsResponse = transport::proto_query(
+            &rpc_client,
+            "/cosmos.staking.v1beta1.Query/DelegatorDelegations",
+            &request,
+            None,
+        )
+        .await?;
+        Ok(delegations
+            .delegation_responses
+            .into_iter()
+            .filter_map(|delegation| {
+                let balance = delegation.balance?;
+                Some(StakedBalance {
+                    validator: delegation.delegation?.validator_address,
+                    amount: Coin {
+                        denom: balance.denom,
+                        amount: Uint128::from_str(&balance.amount).unwrap_or_default(),
+                    },
+                })
+            })
+            .collect())
+    }
+
     /// Get the network configuration
     pub fn config(&self) -> &MantraNetworkConfig {
         &self.config
     }
 
     /// Query a transaction by hash
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn query_transaction(&self, tx_hash: &str) -> Result<serde_json::Value, Error> {
         let rpc_client = self.rpc_client.lock().await;
 
@@ -266,50 +995,131 @@ impl MantraDexClient {
         Ok(result)
     }
 
-    /// Query a smart contract
+    /// Fetch a transaction and its result by hash
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn get_tx(&self, tx_hash: &str) -> Result<TxQueryResponse, Error> {
+        let hash = Hash::from_hex_upper(
+            cosmrs::tendermint::hash::Algorithm::Sha256,
+            &tx_hash.trim_start_matches("0x").to_uppercase(),
+        )
+        .map_err(|e| Error::Other(format!("Invalid transaction hash: {}", e)))?;
+
+        let rpc_client = self.rpc_client.lock().await;
+        rpc_client
+            .tx(hash, false)
+            .await
+            .map_err(|e| Error::Rpc(format!("Failed to query transaction: {}", e)))
+    }
+
+    /// Most recent transactions with a message signed by `sender`, newest first
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn search_txs_by_sender(
+        &self,
+        sender: &str,
+        limit: u8,
+    ) -> Result<Vec<TxQueryResponse>, Error> {
+        self.search_txs_by_sender_page(sender, 1, limit).await
+    }
+
+    /// Page `page` (from 1) of the transactions with a message signed by
+    /// `sender`, `per_page` at a time, newest first
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn search_txs_by_sender_page(
+        &self,
+        sender: &str,
+        page: u32,
+        per_page: u8,
+    ) -> Result<Vec<TxQueryResponse>, Error> {
+        let rpc_client = self.rpc_client.lock().await;
+        let response = rpc_client
+            .tx_search(
+                Query::eq("message.sender", sender),
+                false,
+                page.max(1),
+                per_page,
+                Order::Descending,
+            )
+            .await
+            .map_err(|e| Error::Rpc(format!("Failed to search transactions: {}", e)))?;
+        Ok(response.txs)
+    }
+
+    /// Query a smart contract, retrying transient failures
     pub async fn query<Q: serde::Serialize + Clone, R: DeserializeOwned>(
         &self,
         contract_addr: &str,
         query_msg: &Q,
     ) -> Result<R, Error> {
-        let rpc_client = self.rpc_client.lock().await;
-        let query = QuerySmartContractStateRequest {
-            address: contract_addr.to_string(),
-            query_data: serde_json::to_vec(query_msg)?,
-        };
-
-        // Now that we're using the same Prost version as cosmos-sdk-proto,
-        // we can use the Message trait directly
-        let data = query.encode_to_vec();
-        let result = rpc_client
-            .abci_query(
-                Some("/cosmwasm.wasm.v1.Query/SmartContractState".to_string()),
-                data,
-                None,
-                false,
-            )
+        self.retry_policy
+            .run("Contract query", || {
+                self.query_once(contract_addr, query_msg)
+            })
             .await
-            .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))?;
+    }
 
-        if !result.code.is_ok() {
-            return Err(Error::Contract(format!(
-                "Contract query failed: {}",
-                String::from_utf8_lossy(result.log.as_bytes())
-            )));
+    async fn query_once<Q: serde::Serialize, R: DeserializeOwned>(
+        &self,
+        contract_addr: &str,
+        query_msg: &Q,
+    ) -> Result<R, Error> {
+        #[cfg(feature = "grpc")]
+        if let Some(result) = self
+            .try_grpc(|grpc| grpc.smart_query(contract_addr, query_msg))
+            .await
+        {
+            return result;
         }
-        let resp: QuerySmartContractStateResponse =
-            QuerySmartContractStateResponse::decode(result.value.as_slice())
-                .map_err(|e| Error::Rpc(format!("Failed to decode query response: {}", e)))?;
-        serde_json::from_slice::<R>(resp.data.as_slice()).map_err(Into::into)
+        self.abci_smart_query(contract_addr, query_msg, None).await
+    }
+
+    /// Query a contract as of block `height`, over RPC
+    ///
+    /// Retries transient failures like [`MantraDexClient::query`]. Nodes that
+    /// prune state answer with an error for heights they no longer keep.
+    pub async fn query_at_height<Q: serde::Serialize, R: DeserializeOwned>(
+        &self,
+        contract_addr: &str,
+        query_msg: &Q,
+        height: u64,
+    ) -> Result<R, Error> {
+        self.retry_policy
+            .run("Contract query", || {
+                self.abci_smart_query(contract_addr, query_msg, Some(height))
+            })
+            .await
+    }
+
+    async fn abci_smart_query<Q: serde::Serialize, R: DeserializeOwned>(
+        &self,
+        contract_addr: &str,
+        query_msg: &Q,
+        height: Option<u64>,
+    ) -> Result<R, Error> {
+        self.on_endpoint("wasm/SmartContractState", |rpc_client| async move {
+            transport::smart_query(&rpc_client, contract_addr, query_msg, height).await
+        })
+        .await
     }
 
     /// Execute a contract message
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn execute<T: serde::Serialize>(
         &self,
         contract_addr: &str,
         msg: &T,
         funds: Vec<Coin>,
     ) -> Result<TxResponse, Error> {
+        let msg = self.execute_contract_msg(contract_addr, msg, funds)?;
+        self.broadcast_tx(vec![msg]).await
+    }
+
+    /// Contract execution message from the wallet's address, unsigned
+    pub fn execute_contract_msg<T: serde::Serialize>(
+        &self,
+        contract_addr: &str,
+        msg: &T,
+        funds: Vec<Coin>,
+    ) -> Result<Any, Error> {
         let wallet = self.wallet()?;
         let sender = wallet.address().unwrap().to_string();
 
@@ -321,47 +1131,103 @@ impl MantraDexClient {
             })
             .collect();
         let execute_msg = MsgExecuteContract {
-            sender: sender.clone(),
+            sender,
             contract: contract_addr.to_string(),
             msg: serde_json::to_vec(msg)?,
             funds: cosmos_coins,
         };
 
-        self.broadcast_tx(vec![Any {
+        Ok(Any {
             type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
             value: execute_msg.to_bytes().unwrap(),
-        }])
-        .await
+        })
     }
 
-    /// Broadcast a transaction to the network
-    async fn broadcast_tx(&self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
-        let _height = self.get_last_block_height().await?;
+    /// Execute several messages against one contract in a single, atomic transaction
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn execute_multi<T: serde::Serialize>(
+        &self,
+        contract_addr: &str,
+        msgs: Vec<(T, Vec<Coin>)>,
+    ) -> Result<TxResponse, Error> {
         let wallet = self.wallet()?;
-        let rpc_client = self.rpc_client.lock().await;
+        let sender = wallet.address().unwrap().to_string();
 
-        let tx_body = Body::new(msgs, String::new(), 0u32);
+        let mut anys = Vec::with_capacity(msgs.len());
+        for (msg, mut funds) in msgs {
+            // Funds must be sorted by denom as required by Cosmos SDK
+            funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+            let execute_msg = MsgExecuteContract {
+                sender: sender.clone(),
+                contract: contract_addr.to_string(),
+                msg: serde_json::to_vec(&msg)?,
+                funds: funds
+                    .iter()
+                    .map(|c| CosmosCoin {
+                        denom: c.denom.clone(),
+                        amount: c.amount.to_string(),
+                    })
+                    .collect(),
+            };
+            anys.push(Any {
+                type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+                value: execute_msg.to_bytes().unwrap(),
+            });
+        }
 
-        // Get account info for signing
-        let addr = wallet.address().unwrap().to_string();
+        self.broadcast_tx(anys).await
+    }
+
+    /// Sign and broadcast arbitrary messages in a single transaction
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn broadcast_msgs(&self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
+        self.broadcast_tx(msgs).await
+    }
+
+    /// Sign and broadcast `msgs` in a single transaction valid for
+    /// `timeout_blocks` past the latest block instead of the client's
+    /// setting; 0 signs it without a timeout height
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn broadcast_msgs_with_timeout(
+        &self,
+        msgs: Vec<Any>,
+        timeout_blocks: u64,
+    ) -> Result<TxResponse, Error> {
+        self.broadcast_tx_with_timeout(msgs, timeout_blocks).await
+    }
 
+    /// Account `addr`, over gRPC when the network has a gRPC endpoint
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn base_account(
+        &self,
+        rpc_client: &EndpointClient,
+        addr: String,
+    ) -> Result<BaseAccount, Error> {
+        #[cfg(feature = "grpc")]
+        if let Some(result) = self.try_grpc(|grpc| grpc.account(&addr)).await {
+            return result;
+        }
+        Self::query_account(rpc_client, addr).await
+    }
+
+    /// Account number and sequence of `address`, over RPC
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn query_account(
+        rpc_client: &EndpointClient,
+        addr: String,
+    ) -> Result<BaseAccount, Error> {
         // Create request using the proper protobuf type
         let request = QueryAccountRequest { address: addr };
 
-        // Encode the request to protobuf
-        let encoded_request = request.encode_to_vec();
-
-        let account_info = rpc_client
-            .abci_query(
-                Some("/cosmos.auth.v1beta1.Query/Account".to_string()),
-                encoded_request,
-                None,
-                false,
-            )
-            .await
-            .map_err(|e| Error::Rpc(format!("Failed to get account info: {}", e)))?;
+        let account_info = AbciTransport::abci_query(
+            rpc_client,
+            "/cosmos.auth.v1beta1.Query/Account",
+            request.encode_to_vec(),
+            None,
+        )
+        .await?;
 
-        if !account_info.code.is_ok() {
+        if !account_info.is_ok() {
             return Err(Error::Rpc(format!(
                 "Account query failed: {}",
                 account_info.log
@@ -376,13 +1242,79 @@ impl MantraDexClient {
         let account_any = account_response.account.unwrap();
 
         // Decode the BaseAccount from the Any object's value
-        let base_account = BaseAccount::decode(account_any.value.as_slice())
-            .map_err(|e| Error::Rpc(format!("Failed to decode BaseAccount: {}", e)))?;
+        BaseAccount::decode(account_any.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode BaseAccount: {}", e)))
+    }
+
+    /// Sequence of `address`, or `None` when the chain has no account for it
+    ///
+    /// An account comes into existence with its first incoming transfer; its
+    /// sequence counts the transactions it has signed.
+    pub async fn get_account_sequence(&self, address: &str) -> Result<Option<u64>, Error> {
+        let request = QueryAccountRequest {
+            address: address.to_string(),
+        };
+        let response = self
+            .rpc_client
+            .abci_query(
+                "/cosmos.auth.v1beta1.Query/Account",
+                request.encode_to_vec(),
+                None,
+            )
+            .await?;
+        if !response.is_ok() {
+            if response.log.contains("not found") {
+                return Ok(None);
+            }
+            return Err(Error::Rpc(format!(
+                "Account query failed: {}",
+                response.log
+            )));
+        }
+        let account = QueryAccountResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode account response: {}", e)))?;
+        account
+            .account
+            .map(|account| {
+                BaseAccount::decode(account.value.as_slice())
+                    .map(|account| account.sequence)
+                    .map_err(|e| Error::Rpc(format!("Failed to decode BaseAccount: {}", e)))
+            })
+            .transpose()
+    }
+
+    /// Sign `msgs` as one transaction at the wallet's current sequence,
+    /// returning its encoded bytes
+    ///
+    /// With the wallet's signing `slot` held, the sequence also accounts for
+    /// transactions the node has accepted but not counted yet.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn sign_tx(
+        &self,
+        rpc_client: &HttpClient,
+        msgs: Vec<Any>,
+        timeout_height: u64,
+        slot: Option<&mut SignerSlot>,
+    ) -> Result<Vec<u8>, Error> {
+        let wallet = self.wallet()?;
+        let memo = self.memo.clone().unwrap_or_default();
+        tx_tag::check_memo(&memo)?;
+        let timeout_height = cosmrs::tendermint::block::Height::try_from(timeout_height)
+            .map_err(|e| Error::Tx(format!("Invalid timeout height: {}", e)))?;
+        let tx_body = Body::new(msgs, memo, timeout_height);
+
+        // Get account info for signing
+        let addr = wallet.address().unwrap().to_string();
+
+        let base_account = self.base_account(rpc_client, addr).await?;
 
         let account_number = base_account.account_number;
-        let sequence = base_account.sequence;
-        // Create the fee
-        let fee = wallet.create_default_fee(2_000_000)?;
+        let sequence = match slot {
+            Some(slot) => slot.nonce(base_account.sequence),
+            None => base_account.sequence,
+        };
+        // Create the fee at this network's gas price
+        let fee = self.gas_price_quote().await.fee(DEFAULT_GAS_LIMIT)?;
 
         // Create signer info with sequence number
         let signer_info = SignerInfo::single_direct(Some(wallet.public_key()), sequence);
@@ -400,22 +1332,214 @@ impl MantraDexClient {
         let tx_raw = sign_doc
             .sign(wallet.signing_key())
             .map_err(|e| Error::Tx(format!("Failed to sign transaction: {}", e)))?;
-        // Broadcast the transaction
-        let response = rpc_client
-            .broadcast_tx_commit(tx_raw.to_bytes().unwrap())
+        tx_raw
+            .to_bytes()
+            .map_err(|e| Error::Tx(format!("Failed to encode transaction: {}", e)))
+    }
+
+    /// Run `msgs` through the chain's simulation endpoint as one transaction
+    ///
+    /// The transaction is signed by the wallet but never broadcast; the
+    /// response carries the gas it would use and its events.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn simulate_msgs(&self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
+        let rpc_client = self.rpc_client.lock().await;
+        let tx_bytes = self.sign_tx(&rpc_client, msgs, 0, None).await?;
+        Self::simulate_tx(&rpc_client, tx_bytes).await
+    }
+
+    /// Write the decoded intent of `msgs` to the signing log before they are
+    /// signed, returning its record id
+    #[cfg(not(target_arch = "wasm32"))]
+    fn begin_signing_intent(&self, msgs: &[Any]) -> Result<u64, Error> {
+        let signer = self.wallet()?.address()?.to_string();
+        let analyzer =
+            CosmosTxAnalyzer::new(&self.config.contracts).with_active_wallet(Some(signer.clone()));
+        let messages = msgs
+            .iter()
+            .map(|msg| {
+                let decoded = analyzer.decode_message(msg);
+                let summary = analyzer.narrate(&decoded, &[]);
+                IntentMessage::from_cosmos(&decoded, summary)
+            })
+            .collect();
+        Ok(SigningLog::global().begin(TxVm::Cosmos, &self.config.chain_id, &signer, messages))
+    }
+
+    /// Wait for the wallet's signing slot on this chain
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn signer_slot(&self) -> Result<SignerSlot, Error> {
+        let address = self.wallet()?.address()?.to_string();
+        Ok(SignerQueue::global()
+            .acquire(SignerKey::new(
+                TxVm::Cosmos,
+                self.config.chain_id.clone(),
+                &address,
+            ))
+            .await)
+    }
+
+    /// Broadcast a transaction to the network
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn broadcast_tx(&self, msgs: Vec<Any>) -> Result<TxResponse, Error> {
+        self.broadcast_tx_with_timeout(msgs, self.timeout_blocks)
             .await
-            .map_err(|e| Error::Rpc(format!("Failed to broadcast transaction: {}", e)))?;
+    }
+
+    /// Broadcast a transaction valid for `timeout_blocks` past the latest
+    /// block
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn broadcast_tx_with_timeout(
+        &self,
+        msgs: Vec<Any>,
+        timeout_blocks: u64,
+    ) -> Result<TxResponse, Error> {
+        let height = self.get_last_block_height().await?;
+        let timeout_height = match timeout_blocks {
+            0 => 0,
+            blocks => height + blocks,
+        };
+        // Held from reading the sequence until the node has answered, so
+        // concurrent broadcasts from the wallet never sign with the same one
+        let mut slot = if self.simulate_only {
+            None
+        } else {
+            Some(self.signer_slot().await?)
+        };
+        let rpc_client = self.rpc_client.lock().await;
+
+        let label = msgs
+            .iter()
+            .map(|msg| msg.type_url.rsplit('.').next().unwrap_or(&msg.type_url))
+            .collect::<Vec<_>>()
+            .join("+");
+        // What is about to be signed is written down for review first
+        let intent = if self.simulate_only {
+            None
+        } else {
+            Some(self.begin_signing_intent(&msgs)?)
+        };
+        let tx_bytes = match self
+            .sign_tx(&rpc_client, msgs, timeout_height, slot.as_mut())
+            .await
+        {
+            Ok(tx_bytes) => tx_bytes,
+            Err(e) => {
+                if let Some(id) = intent {
+                    SigningLog::global().complete(
+                        id,
+                        SigningOutcome {
+                            error: Some(e.to_string()),
+                            ..Default::default()
+                        },
+                    );
+                }
+                return Err(e);
+            }
+        };
+        if self.simulate_only {
+            return Self::simulate_tx(&rpc_client, tx_bytes).await;
+        }
+        let (nonce, signature) = signing_log::cosmos_signature(&tx_bytes);
+        let signed = SigningOutcome {
+            nonce,
+            signature,
+            tx_hash: Some(cosmos_tx_hash(&tx_bytes)),
+            error: None,
+        };
+        let finish_intent = |error: Option<String>| {
+            if let Some(id) = intent {
+                SigningLog::global().complete(
+                    id,
+                    SigningOutcome {
+                        error,
+                        ..signed.clone()
+                    },
+                );
+            }
+        };
+        // Persist the transaction before it leaves, so it is monitored again
+        // if the process dies before the outcome is known
+        let queued_hash = match &self.tx_queue {
+            Some(queue) => {
+                let hash = cosmos_tx_hash(&tx_bytes);
+                queue
+                    .enqueue(
+                        TxVm::Cosmos,
+                        &self.config.chain_id,
+                        &hash,
+                        &tx_bytes,
+                        &label,
+                    )
+                    .inspect_err(|e| finish_intent(Some(e.to_string())))?;
+                Some((queue, hash))
+            }
+            None => None,
+        };
+        // Broadcast the transaction, recording how long the endpoint takes to confirm
+        let started = Instant::now();
+        let response = rpc_client.broadcast_tx_commit(tx_bytes).await;
+        ConfirmationTracker::global().record(
+            &self.config.chain_id,
+            &self.config.rpc_url,
+            started.elapsed(),
+            response.is_ok(),
+        );
+        trace::record_rpc(
+            "broadcast_tx_commit",
+            &self.current_endpoint(),
+            started,
+            response.as_ref().err().map(|e| e.to_string()),
+        );
+        // Without a response the transaction stays queued; the queue's
+        // monitor finds out whether it landed
+        let response = response
+            .map_err(|e| Error::Rpc(format!("Failed to broadcast transaction: {}", e)))
+            .inspect_err(|e| finish_intent(Some(e.to_string())))?;
+        // A transaction past CheckTx uses its sequence even if it then fails
+        if let Some(slot) = slot.take() {
+            if response.check_tx.code.is_ok() {
+                slot.commit();
+            }
+        }
+        finish_intent(if response.check_tx.code.is_err() {
+            Some(response.check_tx.log.clone())
+        } else if response.tx_result.code.is_err() {
+            Some(response.tx_result.log.clone())
+        } else {
+            None
+        });
+        // The transaction may have moved pool reserves
+        self.invalidate_pool_snapshot();
+        if let Some((queue, hash)) = &queued_hash {
+            let outcome = if response.check_tx.code.is_err() {
+                queue.mark_failed(hash, &response.check_tx.log)
+            } else if response.tx_result.code.is_err() {
+                queue.mark_failed(hash, &response.tx_result.log)
+            } else {
+                queue.mark_confirmed(hash, response.height.value(), None)
+            };
+            if let Err(e) = outcome {
+                tracing::warn!(
+                    "Failed to record the outcome of transaction {}: {}",
+                    hash,
+                    e
+                );
+            }
+        }
         // Get the transaction response
         let tx_response = if response.check_tx.code.is_err() {
             return Err(Error::Contract(format!(
                 "Transaction check failed: {}",
                 response.check_tx.log
-            )));
+            ))
+            .refine());
         } else if response.tx_result.code.is_err() {
             return Err(Error::Contract(format!(
                 "Transaction execution failed: {}",
                 response.tx_result.log
-            )));
+            ))
+            .refine());
         } else {
             // Query the tx
             let tx_result = rpc_client
@@ -441,13 +1565,87 @@ impl MantraDexClient {
                 gas_used: tx_result.tx_result.gas_used,
                 tx: None,
                 timestamp: "".to_string(),
-                events: vec![],
+                events: tx_result
+                    .tx_result
+                    .events
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
             }
         };
 
         Ok(tx_response)
     }
 
+    /// Send signed transaction bytes without waiting for them to be included,
+    /// returning the transaction hash
+    ///
+    /// A node that already has the transaction in its mempool is not an error.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn broadcast_tx_bytes(&self, tx_bytes: Vec<u8>) -> Result<String, Error> {
+        let hash = cosmos_tx_hash(&tx_bytes);
+        let rpc_client = self.rpc_client.lock().await;
+        let response = rpc_client
+            .broadcast_tx_sync(tx_bytes)
+            .await
+            .map_err(|e| Error::Rpc(format!("Failed to broadcast transaction: {}", e)))?;
+        if response.code.is_err() && !response.log.contains("already exists") {
+            return Err(
+                Error::Contract(format!("Transaction check failed: {}", response.log)).refine(),
+            );
+        }
+        Ok(hash)
+    }
+
+    /// Run a signed transaction through the chain's simulation endpoint
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn simulate_tx(
+        rpc_client: &EndpointClient,
+        tx_bytes: Vec<u8>,
+    ) -> Result<TxResponse, Error> {
+        let request = SimulateRequest {
+            tx_bytes,
+            ..Default::default()
+        };
+        let result = AbciTransport::abci_query(
+            rpc_client,
+            "/cosmos.tx.v1beta1.Service/Simulate",
+            request.encode_to_vec(),
+            None,
+        )
+        .await?;
+
+        if !result.is_ok() {
+            return Err(Error::TxSimulation(format!(
+                "Transaction simulation failed: {}",
+                result.log
+            ))
+            .refine());
+        }
+        let response = SimulateResponse::decode(result.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode simulation response: {}", e)))?;
+        let gas_info = response.gas_info.unwrap_or_default();
+        let result = response.result.unwrap_or_default();
+
+        #[allow(deprecated)]
+        let data = general_purpose::STANDARD.encode(&result.data);
+        Ok(TxResponse {
+            height: 0,
+            txhash: String::new(),
+            codespace: "".to_string(),
+            code: 0,
+            data,
+            raw_log: result.log,
+            logs: vec![],
+            info: "simulated".to_string(),
+            gas_wanted: gas_info.gas_wanted as i64,
+            gas_used: gas_info.gas_used as i64,
+            tx: None,
+            timestamp: "".to_string(),
+            events: result.events,
+        })
+    }
+
     /// Query asset decimals for a specific asset in a pool
     ///
     /// This method uses the pool manager's AssetDecimals query to get accurate
@@ -554,6 +1752,161 @@ impl MantraDexClient {
         Ok(asset_decimals_map)
     }
 
+    /// Denominations of the native token and every pool asset
+    ///
+    /// Decimals come from the pools' own records, so this takes one paginated
+    /// pool query rather than one query per asset.
+    pub async fn denom_registry(&self) -> Result<DenomRegistry, Error> {
+        let pools = self.get_all_pools().await?;
+        let mut registry = DenomRegistry::from_pools(&self.config.native_denom, &pools);
+        self.denom_metadata.apply_to(&mut registry);
+        Ok(registry)
+    }
+
+    /// Symbol, name and decimals of `denom`
+    ///
+    /// See [`crate::denom_metadata`] for where they come from. A denom no
+    /// source knows is described from its name alone, so this never fails.
+    pub async fn denom_metadata(&self, denom: &str) -> DenomMetadata {
+        self.resolve_denom_metadata(&[denom.to_string()])
+            .await
+            .pop()
+            .unwrap_or_else(|| DenomMetadata::fallback(denom, None))
+    }
+
+    /// Metadata of each of `denoms`, in order
+    ///
+    /// Denoms already cached are not queried again, and the pools are only
+    /// read when a denom has neither bank metadata nor an IBC trace.
+    pub async fn resolve_denom_metadata(&self, denoms: &[String]) -> Vec<DenomMetadata> {
+        let mut pool_decimals: Option<HashMap<String, u8>> = None;
+        let mut resolved = Vec::with_capacity(denoms.len());
+        for denom in denoms {
+            if let Some(metadata) = self.denom_metadata.get(denom) {
+                resolved.push(metadata);
+                continue;
+            }
+
+            let mut metadata = match self.query_bank_denom_metadata(denom).await {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    tracing::debug!("No bank metadata for {}: {}", denom, e);
+                    None
+                }
+            };
+            if metadata.is_none() {
+                if pool_decimals.is_none() {
+                    pool_decimals = Some(
+                        self.get_asset_decimals_from_pools()
+                            .await
+                            .unwrap_or_else(|e| {
+                                tracing::debug!("Pool decimals unavailable: {}", e);
+                                HashMap::new()
+                            }),
+                    );
+                }
+                let decimals = pool_decimals
+                    .as_ref()
+                    .and_then(|decimals| decimals.get(denom).copied());
+                metadata = Some(match denom.strip_prefix("ibc/") {
+                    Some(hash) => match self.query_denom_trace(hash).await {
+                        Ok(Some((path, base_denom))) => {
+                            DenomMetadata::from_ibc_trace(denom, &path, &base_denom, decimals)
+                        }
+                        Ok(None) => DenomMetadata::fallback(denom, decimals),
+                        Err(e) => {
+                            tracing::debug!("No IBC trace for {}: {}", denom, e);
+                            DenomMetadata::fallback(denom, decimals)
+                        }
+                    },
+                    None => DenomMetadata::fallback(denom, decimals),
+                });
+            }
+
+            let metadata = metadata.unwrap_or_else(|| DenomMetadata::fallback(denom, None));
+            self.denom_metadata.insert(metadata);
+            // A registry entry cached meanwhile outranks the pools' decimals
+            resolved.push(self.denom_metadata.get_or_fallback(denom));
+        }
+        resolved
+    }
+
+    /// `coin` in whole tokens with its symbol, e.g. `1.5 USDC`
+    pub async fn format_coin(&self, coin: &Coin) -> String {
+        self.denom_metadata(&coin.denom).await.format_coin(coin)
+    }
+
+    /// Bank module metadata of `denom`, if the chain has any that names its
+    /// decimals or symbol
+    async fn query_bank_denom_metadata(&self, denom: &str) -> Result<Option<DenomMetadata>, Error> {
+        let request = QueryDenomMetadataRequest {
+            denom: denom.to_string(),
+        };
+        let response: Option<QueryDenomMetadataResponse> = self
+            .abci_proto_query("/cosmos.bank.v1beta1.Query/DenomMetadata", &request)
+            .await?;
+        Ok(response
+            .and_then(|response| response.metadata)
+            .and_then(|metadata| DenomMetadata::from_bank(denom, &metadata)))
+    }
+
+    /// IBC path and base denom of the trace hashed to `hash`
+    async fn query_denom_trace(&self, hash: &str) -> Result<Option<(String, String)>, Error> {
+        let request = QueryDenomTraceRequest {
+            hash: hash.to_string(),
+        };
+        let response: Option<QueryDenomTraceResponse> = self
+            .abci_proto_query("/ibc.applications.transfer.v1.Query/DenomTrace", &request)
+            .await?;
+        Ok(response
+            .and_then(|response| response.denom_trace)
+            .map(|trace| (trace.path, trace.base_denom)))
+    }
+
+    /// Run a protobuf ABCI query, reporting `None` when the chain answers with
+    /// an error code, as it does for unknown denoms
+    async fn abci_proto_query<Req: Message, Resp: Message + Default>(
+        &self,
+        path: &str,
+        request: &Req,
+    ) -> Result<Option<Resp>, Error> {
+        let data = request.encode_to_vec();
+        let result = self
+            .on_endpoint(path, |rpc_client| async move {
+                AbciTransport::abci_query(&rpc_client, path, data, None).await
+            })
+            .await?;
+        if !result.is_ok() {
+            return Ok(None);
+        }
+        Resp::decode(result.value.as_slice())
+            .map(Some)
+            .map_err(|e| Error::Rpc(format!("Failed to decode {} response: {}", path, e)))
+    }
+
+    /// Gas, network fee and dollar cost of `operation`, before anything is
+    /// signed
+    ///
+    /// See [`crate::fees`] for how Cosmos and EVM operations are priced.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn estimate_transaction_cost(
+        &self,
+        operation: &CostOperation,
+    ) -> Result<CostEstimate, Error> {
+        fees::estimate_transaction_cost(self, operation).await
+    }
+
+    /// Parse a human-readable amount such as `1.5 OM` or `100 USDC`
+    ///
+    /// # Errors
+    ///
+    /// Returns error if pools cannot be queried, or the amount is malformed or
+    /// names a token no pool holds
+    pub async fn parse_amount(&self, input: &str) -> Result<Amount, Error> {
+        let registry = self.denom_registry().await?;
+        Ok(Amount::parse(input, &registry)?)
+    }
+
     /// Get asset decimals for a specific denomination
     ///
     /// This method attempts to find a pool containing the asset and query its decimals.
@@ -616,9 +1969,96 @@ impl MantraDexClient {
         };
 
         let pool_manager_address = self.config.contracts.pool_manager.clone();
-        let response: PoolsResponse = self.query(&pool_manager_address, &query).await?;
+        let response: PoolsResponse = self.query(&pool_manager_address, &query).await?;
+
+        Ok(response.pools)
+    }
+
+    /// Get one page of pools, in pool identifier order
+    pub async fn get_pools_page(
+        &self,
+        request: PageRequest<String>,
+    ) -> Result<Page<PoolInfoResponse, String>, Error> {
+        let query = pool_manager::QueryMsg::Pools {
+            pool_identifier: None,
+            start_after: request.cursor,
+            limit: Some(request.limit),
+        };
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        let response: PoolsResponse = self.query(&pool_manager_address, &query).await?;
+
+        Ok(Page::keyed(response.pools, request.limit, |pool| {
+            pool.pool_info.pool_identifier.clone()
+        }))
+    }
+
+    /// Stream every pool, fetching `page_size` pools at a time
+    pub fn pools_stream(
+        &self,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<PoolInfoResponse, Error>> + '_ {
+        Paginator::new(page_size).stream(move |request| self.get_pools_page(request))
+    }
+
+    /// Get every pool, following the pool manager's pagination
+    pub async fn get_all_pools(&self) -> Result<Vec<PoolInfoResponse>, Error> {
+        Paginator::default()
+            .collect(|request| self.get_pools_page(request))
+            .await
+    }
+
+    /// Fetch every pool's reserves and fees as of the latest block
+    ///
+    /// All pages are read at the same height, so the snapshot is consistent
+    /// even when blocks are produced while it is fetched.
+    pub async fn fetch_pool_snapshot(&self) -> Result<PoolStateSnapshot, Error> {
+        let height = self.get_last_block_height().await?;
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        let pools = Paginator::default()
+            .collect(|request: PageRequest<String>| {
+                let pool_manager_address = pool_manager_address.clone();
+                async move {
+                    let query = pool_manager::QueryMsg::Pools {
+                        pool_identifier: None,
+                        start_after: request.cursor,
+                        limit: Some(request.limit),
+                    };
+                    let response: PoolsResponse = self
+                        .query_at_height(&pool_manager_address, &query, height)
+                        .await?;
+                    Ok(Page::keyed(response.pools, request.limit, |pool| {
+                        pool.pool_info.pool_identifier.clone()
+                    }))
+                }
+            })
+            .await?;
+        Ok(PoolStateSnapshot::new(height, pools))
+    }
 
-        Ok(response.pools)
+    /// Pool snapshot at most `max_blocks_behind` blocks old
+    ///
+    /// Returns the cached snapshot while it is recent enough, and fetches and
+    /// caches a new one otherwise.
+    pub async fn pool_snapshot(
+        &self,
+        max_blocks_behind: u64,
+    ) -> Result<Arc<PoolStateSnapshot>, Error> {
+        let cached = self.pool_snapshot.read().unwrap().clone();
+        if let Some(snapshot) = cached {
+            let latest = self.get_last_block_height().await?;
+            if snapshot.blocks_behind(latest) <= max_blocks_behind {
+                return Ok(snapshot);
+            }
+        }
+        let snapshot = Arc::new(self.fetch_pool_snapshot().await?);
+        *self.pool_snapshot.write().unwrap() = Some(snapshot.clone());
+        Ok(snapshot)
+    }
+
+    /// Drop the cached pool snapshot, e.g. after trading against the pools
+    pub fn invalidate_pool_snapshot(&self) {
+        *self.pool_snapshot.write().unwrap() = None;
     }
 
     /// Extract pool status from PoolInfoResponse
@@ -670,6 +2110,39 @@ impl MantraDexClient {
         self.query(&pool_manager_address, &query).await
     }
 
+    /// Analyze how much can be traded in a pool at each price impact level
+    ///
+    /// Defaults to 0.5%, 1% and 2% impact. Stable pools also report how far
+    /// their reserves have drifted apart.
+    pub async fn get_pool_depth(
+        &self,
+        pool_id: &str,
+        impact_levels: Option<Vec<Decimal>>,
+    ) -> Result<PoolDepthReport, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        let levels = impact_levels.unwrap_or_else(|| depth::DEFAULT_DEPTH_IMPACT_LEVELS.to_vec());
+        depth::analyze_pool_depth(&pool, &levels)
+    }
+
+    /// Estimate a swap's price impact and warn if it is large
+    ///
+    /// `threshold` defaults to [`depth::LARGE_SWAP_IMPACT_THRESHOLD`].
+    pub async fn check_swap_impact(
+        &self,
+        pool_id: &str,
+        offer_asset: &Coin,
+        ask_asset_denom: &str,
+        threshold: Option<Decimal>,
+    ) -> Result<SwapImpactCheck, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        depth::check_swap_impact(
+            &pool,
+            offer_asset,
+            ask_asset_denom,
+            threshold.unwrap_or(depth::LARGE_SWAP_IMPACT_THRESHOLD),
+        )
+    }
+
     /// Swap tokens
     /// Execute a swap operation on a pool
     ///
@@ -691,6 +2164,7 @@ impl MantraDexClient {
     /// * Returns error if pool status validation fails (pool must be Available)
     /// * Returns error if the swap transaction fails
     /// * Returns error if no wallet is configured
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn swap(
         &self,
         pool_id: &str,
@@ -758,6 +2232,7 @@ impl MantraDexClient {
     /// * Returns error if pool status validation fails (pool must be Available)
     /// * Returns error if the liquidity provision transaction fails
     /// * Returns error if no wallet is configured
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn provide_liquidity(
         &self,
         pool_id: &str,
@@ -822,6 +2297,7 @@ impl MantraDexClient {
     ///
     /// * Returns error if the liquidity provision transaction fails
     /// * Returns error if no wallet is configured
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn provide_liquidity_unchecked(
         &self,
         pool_id: &str,
@@ -862,6 +2338,7 @@ impl MantraDexClient {
     }
 
     /// Withdraw liquidity from a pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn withdraw_liquidity(
         &self,
         pool_id: &str,
@@ -890,6 +2367,408 @@ impl MantraDexClient {
         self.execute(&pool_manager_address, &msg, funds).await
     }
 
+    /// Preview withdrawing `lp_amount` LP tokens from a pool
+    ///
+    /// Reports the assets the pool manager would return, the fees taken and
+    /// how far the pool's spot price moves.
+    pub async fn simulate_withdraw_liquidity(
+        &self,
+        pool_id: &str,
+        lp_amount: Uint128,
+    ) -> Result<WithdrawalPreview, Error> {
+        let pool = self.get_pool(pool_id).await?;
+        withdrawal::preview_withdrawal(&pool, lp_amount)
+    }
+
+    /// Preview a single-asset liquidity deposit (zap in)
+    ///
+    /// Sizes the balancing swap from current reserves, simulates it, and estimates
+    /// the LP tokens minted for the resulting two-sided deposit.
+    pub async fn simulate_zap_in(
+        &self,
+        pool_id: &str,
+        offer: Coin,
+        max_slippage: Option<Decimal>,
+    ) -> Result<ZapInPreview, Error> {
+        if offer.amount.is_zero() {
+            return Err(Error::Other(
+                "Offer amount must be greater than zero".to_string(),
+            ));
+        }
+        let max_slippage =
+            max_slippage.unwrap_or_else(|| Decimal::percent(zap::DEFAULT_ZAP_SLIPPAGE_PERCENT));
+
+        let pool = self.get_pool(pool_id).await?;
+        let (offer_reserve, ask_asset) = Self::zap_pool_legs(&pool, &offer.denom)?;
+
+        let swap_amount = zap::balancing_swap_amount(
+            &pool.pool_info.pool_type,
+            offer.amount,
+            offer_reserve,
+            ask_asset.amount,
+            zap::total_swap_fee(&pool.pool_info.pool_fees),
+        )?;
+        if swap_amount.is_zero() || swap_amount >= offer.amount {
+            return Err(Error::Other(format!(
+                "Offer of {}{} is too small to zap into pool {}",
+                offer.amount, offer.denom, pool_id
+            )));
+        }
+
+        let swap_offer = Coin {
+            denom: offer.denom.clone(),
+            amount: swap_amount,
+        };
+        let swap_simulation = self
+            .simulate_swap(pool_id, swap_offer.clone(), &ask_asset.denom)
+            .await?;
+        let min_swap_return = Coin {
+            denom: ask_asset.denom.clone(),
+            amount: zap::apply_slippage(swap_simulation.return_amount, max_slippage),
+        };
+        let remaining_offer = Coin {
+            denom: offer.denom.clone(),
+            amount: offer.amount - swap_amount,
+        };
+
+        let expected_lp_tokens = zap::estimate_lp_shares(
+            &[
+                (remaining_offer.amount, offer_reserve + swap_amount),
+                (
+                    swap_simulation.return_amount,
+                    ask_asset
+                        .amount
+                        .saturating_sub(swap_simulation.return_amount),
+                ),
+            ],
+            pool.total_share.amount,
+        );
+
+        Ok(ZapInPreview {
+            pool_id: pool_id.to_string(),
+            lp_denom: pool.pool_info.lp_denom.clone(),
+            offer,
+            swap_offer,
+            swap_simulation,
+            min_swap_return: min_swap_return.clone(),
+            liquidity_assets: vec![remaining_offer, min_swap_return],
+            expected_lp_tokens,
+            max_slippage,
+        })
+    }
+
+    /// Provide liquidity from a single asset (zap in)
+    ///
+    /// The balancing swap and the deposit are broadcast as one transaction, so
+    /// either both succeed or neither does. The deposit uses the slippage-adjusted
+    /// swap output; any excess from a better-than-minimum swap stays in the wallet.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn zap_in(
+        &self,
+        pool_id: &str,
+        offer: Coin,
+        max_slippage: Option<Decimal>,
+    ) -> Result<(ZapInPreview, TxResponse), Error> {
+        self.validate_pool_status(pool_id).await?;
+        let preview = self.simulate_zap_in(pool_id, offer, max_slippage).await?;
+
+        let msgs = vec![
+            (
+                pool_manager::ExecuteMsg::Swap {
+                    pool_identifier: pool_id.to_string(),
+                    belief_price: None,
+                    receiver: None,
+                    ask_asset_denom: preview.min_swap_return.denom.clone(),
+                    max_slippage: Some(preview.max_slippage),
+                },
+                vec![preview.swap_offer.clone()],
+            ),
+            (
+                pool_manager::ExecuteMsg::ProvideLiquidity {
+                    pool_identifier: pool_id.to_string(),
+                    liquidity_max_slippage: Some(preview.max_slippage),
+                    swap_max_slippage: Some(preview.max_slippage),
+                    receiver: None,
+                    unlocking_duration: None,
+                    lock_position_identifier: None,
+                },
+                preview.liquidity_assets.clone(),
+            ),
+        ];
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        let tx = self.execute_multi(&pool_manager_address, msgs).await?;
+        Ok((preview, tx))
+    }
+
+    /// Preview withdrawing liquidity into a single asset (zap out)
+    pub async fn simulate_zap_out(
+        &self,
+        pool_id: &str,
+        lp_amount: Uint128,
+        target_denom: &str,
+        max_slippage: Option<Decimal>,
+    ) -> Result<ZapOutPreview, Error> {
+        if lp_amount.is_zero() {
+            return Err(Error::Other(
+                "LP amount must be greater than zero".to_string(),
+            ));
+        }
+        let max_slippage =
+            max_slippage.unwrap_or_else(|| Decimal::percent(zap::DEFAULT_ZAP_SLIPPAGE_PERCENT));
+
+        let pool = self.get_pool(pool_id).await?;
+        let (target_reserve, other_asset) = Self::zap_pool_legs(&pool, target_denom)?;
+        let total_share = pool.total_share.amount;
+
+        let target_withdrawn = zap::estimate_withdrawal(lp_amount, target_reserve, total_share);
+        let other_withdrawn = zap::estimate_withdrawal(lp_amount, other_asset.amount, total_share);
+        let withdrawn = vec![
+            Coin {
+                denom: target_denom.to_string(),
+                amount: target_withdrawn,
+            },
+            Coin {
+                denom: other_asset.denom.clone(),
+                amount: other_withdrawn,
+            },
+        ];
+
+        // Offer slightly less than the estimate so reserve moves before inclusion
+        // don't leave the swap underfunded
+        let swap_offer = Coin {
+            denom: other_asset.denom.clone(),
+            amount: zap::apply_slippage(other_withdrawn, max_slippage),
+        };
+        if swap_offer.amount.is_zero() {
+            return Err(Error::Other(format!(
+                "Withdrawal of {} LP tokens from pool {} is too small to consolidate",
+                lp_amount, pool_id
+            )));
+        }
+        let swap_simulation = self
+            .simulate_swap(pool_id, swap_offer.clone(), target_denom)
+            .await?;
+
+        let expected_return = target_withdrawn + swap_simulation.return_amount;
+        let min_return =
+            target_withdrawn + zap::apply_slippage(swap_simulation.return_amount, max_slippage);
+
+        Ok(ZapOutPreview {
+            pool_id: pool_id.to_string(),
+            lp_amount,
+            withdrawn,
+            swap_offer,
+            swap_simulation,
+            target_denom: target_denom.to_string(),
+            expected_return,
+            min_return,
+            max_slippage,
+        })
+    }
+
+    /// Withdraw liquidity and consolidate it into a single asset (zap out)
+    ///
+    /// The withdrawal and the consolidating swap are broadcast as one transaction.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn zap_out(
+        &self,
+        pool_id: &str,
+        lp_amount: Uint128,
+        target_denom: &str,
+        max_slippage: Option<Decimal>,
+    ) -> Result<(ZapOutPreview, TxResponse), Error> {
+        self.validate_pool_status(pool_id).await?;
+        let preview = self
+            .simulate_zap_out(pool_id, lp_amount, target_denom, max_slippage)
+            .await?;
+        let pool = self.get_pool(pool_id).await?;
+
+        let msgs = vec![
+            (
+                pool_manager::ExecuteMsg::WithdrawLiquidity {
+                    pool_identifier: pool_id.to_string(),
+                },
+                vec![Coin {
+                    denom: pool.pool_info.lp_denom,
+                    amount: lp_amount,
+                }],
+            ),
+            (
+                pool_manager::ExecuteMsg::Swap {
+                    pool_identifier: pool_id.to_string(),
+                    belief_price: None,
+                    receiver: None,
+                    ask_asset_denom: target_denom.to_string(),
+                    max_slippage: Some(preview.max_slippage),
+                },
+                vec![preview.swap_offer.clone()],
+            ),
+        ];
+
+        let pool_manager_address = self.config.contracts.pool_manager.clone();
+        let tx = self.execute_multi(&pool_manager_address, msgs).await?;
+        Ok((preview, tx))
+    }
+
+    /// Apply the dust policy to the wallet's balances of `denoms`
+    ///
+    /// Call after an operation with the denoms it touched. Under
+    /// [`DustAction::ConvertToNative`] this broadcasts a sweep transaction.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn settle_dust(&self, denoms: &[String]) -> Result<DustReport, Error> {
+        let address = self.wallet()?.address()?.to_string();
+        let balances = self.get_balances().await?;
+        let found = self.dust_policy.find_dust(&balances, Some(denoms));
+
+        let mut report = DustReport {
+            action: self.dust_policy.action,
+            dust: found.clone(),
+            sweep: None,
+            accumulated: Vec::new(),
+        };
+        match self.dust_policy.action {
+            DustAction::Ignore => {}
+            DustAction::Accumulate => {
+                let ledger = DustLedger::global();
+                ledger.record(&address, denoms, &found);
+                report.accumulated = ledger.outstanding(&address);
+            }
+            DustAction::ConvertToNative => {
+                if !found.is_empty() {
+                    report.sweep = Some(self.sweep_coins(found, false).await?);
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Consolidate dust balances into the native token
+    ///
+    /// Every balance the policy classifies as dust (optionally restricted to
+    /// `denoms`) is swapped through the deepest pool pairing it with the native
+    /// token, all in one transaction. Dust without such a pool, or too small to
+    /// return anything, is reported as skipped. `dry_run` only simulates.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn sweep_dust(
+        &self,
+        denoms: Option<&[String]>,
+        dry_run: bool,
+    ) -> Result<DustSweepReport, Error> {
+        let balances = self.get_balances().await?;
+        let found = self.dust_policy.find_dust(&balances, denoms);
+        self.sweep_coins(found, dry_run).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn sweep_coins(&self, coins: Vec<Coin>, dry_run: bool) -> Result<DustSweepReport, Error> {
+        let address = self.wallet()?.address()?.to_string();
+        let native_denom = self.dust_policy.native_denom.clone();
+        let pools = if coins.is_empty() {
+            Vec::new()
+        } else {
+            self.get_pools(None).await?
+        };
+
+        let mut converted = Vec::new();
+        let mut skipped = Vec::new();
+        for coin in coins {
+            let Some(pool) = dust::select_sweep_pool(&pools, &coin.denom, &native_denom) else {
+                skipped.push(SkippedDust {
+                    reason: format!("no pool pairs {} with {}", coin.denom, native_denom),
+                    coin,
+                });
+                continue;
+            };
+            let pool_id = pool.pool_info.pool_identifier.clone();
+            match self
+                .simulate_swap(&pool_id, coin.clone(), &native_denom)
+                .await
+            {
+                Ok(simulation) if simulation.return_amount.is_zero() => {
+                    skipped.push(SkippedDust {
+                        reason: format!("swap in pool {} returns nothing", pool_id),
+                        coin,
+                    });
+                }
+                Ok(simulation) => converted.push(DustConversion {
+                    offer: coin,
+                    pool_id,
+                    simulation,
+                }),
+                Err(e) => skipped.push(SkippedDust {
+                    reason: format!("simulation in pool {} failed: {}", pool_id, e),
+                    coin,
+                }),
+            }
+        }
+
+        let expected_return = converted
+            .iter()
+            .map(|conversion| conversion.simulation.return_amount)
+            .sum();
+        let mut tx_hash = None;
+        if !dry_run && !converted.is_empty() {
+            let max_slippage = Decimal::percent(dust::DEFAULT_DUST_SWEEP_SLIPPAGE_PERCENT);
+            let msgs = converted
+                .iter()
+                .map(|conversion| {
+                    (
+                        pool_manager::ExecuteMsg::Swap {
+                            pool_identifier: conversion.pool_id.clone(),
+                            belief_price: None,
+                            receiver: None,
+                            ask_asset_denom: native_denom.clone(),
+                            max_slippage: Some(max_slippage),
+                        },
+                        vec![conversion.offer.clone()],
+                    )
+                })
+                .collect();
+            let pool_manager_address = self.config.contracts.pool_manager.clone();
+            let tx = self.execute_multi(&pool_manager_address, msgs).await?;
+            if tx.code == 0 {
+                let swept: Vec<String> = converted
+                    .iter()
+                    .map(|conversion| conversion.offer.denom.clone())
+                    .collect();
+                DustLedger::global().clear(&address, &swept);
+            }
+            tx_hash = Some(tx.txhash);
+        }
+
+        Ok(DustSweepReport {
+            native_denom,
+            converted,
+            skipped,
+            expected_return,
+            tx_hash,
+            dry_run,
+        })
+    }
+
+    /// Split a two-asset pool into (reserve of `denom`, the other asset)
+    fn zap_pool_legs(pool: &PoolInfoResponse, denom: &str) -> Result<(Uint128, Coin), Error> {
+        let assets = &pool.pool_info.assets;
+        if assets.len() != 2 {
+            return Err(Error::Other(format!(
+                "Zaps are only supported for two-asset pools (pool {} has {})",
+                pool.pool_info.pool_identifier,
+                assets.len()
+            )));
+        }
+        let position = assets
+            .iter()
+            .position(|asset| asset.denom == denom)
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "Asset {} is not part of pool {}",
+                    denom, pool.pool_info.pool_identifier
+                ))
+            })?;
+        Ok((assets[position].amount, assets[1 - position].clone()))
+    }
+
     /// Query the pool manager configuration
     pub async fn get_pool_manager_config(
         &self,
@@ -933,6 +2812,7 @@ impl MantraDexClient {
     /// # Notes
     ///
     /// Pool creation requires a fee that is determined by querying the pool manager configuration
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn create_pool(
         &self,
         asset_denoms: Vec<String>,
@@ -973,6 +2853,7 @@ impl MantraDexClient {
     }
 
     /// Execute multiple swap operations
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn execute_swap_operations(
         &self,
         operations: Vec<SwapOperation>,
@@ -1031,6 +2912,7 @@ impl MantraDexClient {
     /// # Backward Compatibility
     ///
     /// When `until_epoch` is `None`, behaves like the v2.x parameterless claim
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn claim_rewards(&self, until_epoch: Option<u64>) -> Result<TxResponse, Error> {
         let farm_manager_address =
             self.config.contracts.farm_manager.as_ref().ok_or_else(|| {
@@ -1061,6 +2943,7 @@ impl MantraDexClient {
     /// # Returns
     ///
     /// Transaction response containing the claim result
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn claim_rewards_all(&self) -> Result<TxResponse, Error> {
         self.claim_rewards(None).await
     }
@@ -1076,6 +2959,7 @@ impl MantraDexClient {
     /// # Returns
     ///
     /// Transaction response containing the claim result
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn claim_rewards_until_epoch(&self, until_epoch: u64) -> Result<TxResponse, Error> {
         self.claim_rewards(Some(until_epoch)).await
     }
@@ -1210,6 +3094,7 @@ impl MantraDexClient {
     /// # Notes
     ///
     /// In v3.0.0, all feature toggles must target specific pools via pool_identifier
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn update_pool_features(
         &self,
         pool_identifier: &str,
@@ -1236,6 +3121,7 @@ impl MantraDexClient {
     }
 
     /// Enable withdrawals for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn enable_pool_withdrawals(
         &self,
         pool_identifier: &str,
@@ -1245,6 +3131,7 @@ impl MantraDexClient {
     }
 
     /// Disable withdrawals for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn disable_pool_withdrawals(
         &self,
         pool_identifier: &str,
@@ -1254,30 +3141,35 @@ impl MantraDexClient {
     }
 
     /// Enable deposits for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn enable_pool_deposits(&self, pool_identifier: &str) -> Result<TxResponse, Error> {
         self.update_pool_features(pool_identifier, None, Some(true), None)
             .await
     }
 
     /// Disable deposits for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn disable_pool_deposits(&self, pool_identifier: &str) -> Result<TxResponse, Error> {
         self.update_pool_features(pool_identifier, None, Some(false), None)
             .await
     }
 
     /// Enable swaps for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn enable_pool_swaps(&self, pool_identifier: &str) -> Result<TxResponse, Error> {
         self.update_pool_features(pool_identifier, None, None, Some(true))
             .await
     }
 
     /// Disable swaps for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn disable_pool_swaps(&self, pool_identifier: &str) -> Result<TxResponse, Error> {
         self.update_pool_features(pool_identifier, None, None, Some(false))
             .await
     }
 
     /// Enable all operations for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn enable_all_pool_operations(
         &self,
         pool_identifier: &str,
@@ -1287,6 +3179,7 @@ impl MantraDexClient {
     }
 
     /// Disable all operations for a specific pool
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn disable_all_pool_operations(
         &self,
         pool_identifier: &str,
@@ -1302,6 +3195,7 @@ impl MantraDexClient {
         since = "3.0.0",
         note = "Use update_pool_features for per-pool control"
     )]
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn update_global_features(
         &self,
         pool_identifier: &str, // Required in v3.0.0 - pools must be targeted specifically
@@ -1472,6 +3366,7 @@ impl MantraDexClient {
     }
 
     /// Internal helper method for executing Skip swaps with common logic
+    #[cfg(not(target_arch = "wasm32"))]
     async fn execute_skip_swap_internal(
         &self,
         operations: Vec<crate::protocols::skip::SkipSwapOperation>,
@@ -1480,15 +3375,6 @@ impl MantraDexClient {
         post_swap_action: crate::protocols::skip::SkipAction,
         affiliates: Vec<crate::protocols::skip::SkipAffiliate>,
     ) -> Result<TxResponse, Error> {
-        let skip_entry_point =
-            self.config
-                .contracts
-                .skip_entry_point
-                .as_ref()
-                .ok_or_else(|| {
-                    Error::Other("Skip entry point contract address not configured".to_string())
-                })?;
-
         // Get the output denom from the last operation
         let output_denom = operations
             .last()
@@ -1503,12 +3389,42 @@ impl MantraDexClient {
             },
         );
 
-        // Create assets
-        let remaining_asset = crate::protocols::skip::SkipAsset::Native(offer_coin.clone());
-        let min_asset = crate::protocols::skip::SkipAsset::Native(Coin {
+        let min_out = Coin {
             denom: output_denom,
             amount: min_receive_amount,
-        });
+        };
+        self.execute_skip_entry_point_swap(swap, offer_coin, min_out, post_swap_action, affiliates)
+            .await
+    }
+
+    /// Send a swap and its post-swap action to the Skip entry point
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn execute_skip_entry_point_swap(
+        &self,
+        swap: crate::protocols::skip::SkipSwap,
+        offer_coin: Coin,
+        min_out: Coin,
+        post_swap_action: crate::protocols::skip::SkipAction,
+        affiliates: Vec<crate::protocols::skip::SkipAffiliate>,
+    ) -> Result<TxResponse, Error> {
+        let skip_entry_point =
+            self.config
+                .contracts
+                .skip_entry_point
+                .as_ref()
+                .ok_or_else(|| {
+                    Error::Other("Skip entry point contract address not configured".to_string())
+                })?;
+
+        // Fall back to the configured affiliate
+        let affiliates = match (&self.skip_affiliate, affiliates.is_empty()) {
+            (Some(affiliate), true) => vec![affiliate.to_skip_affiliate()],
+            _ => affiliates,
+        };
+
+        // Create assets
+        let remaining_asset = crate::protocols::skip::SkipAsset::Native(offer_coin.clone());
+        let min_asset = crate::protocols::skip::SkipAsset::Native(min_out.clone());
 
         let msg = crate::protocols::skip::SkipEntryPointExecuteMsg::SwapAndAction {
             sent_asset: Some(remaining_asset),
@@ -1517,10 +3433,35 @@ impl MantraDexClient {
             // Timeout: current time + 15 minutes, converted to nanoseconds (improved UX)
             timeout_timestamp: (chrono::Utc::now().timestamp() as u64 + 900) * 1_000_000_000,
             post_swap_action,
-            affiliates,
+            affiliates: affiliates.clone(),
         };
 
-        self.execute(skip_entry_point, &msg, vec![offer_coin]).await
+        let response = self
+            .execute(skip_entry_point, &msg, vec![offer_coin])
+            .await?;
+        AffiliateFeeLedger::global().record_swap(&affiliates, &min_out);
+        Ok(response)
+    }
+
+    /// Affiliate fees accrued by the configured Skip affiliate
+    ///
+    /// Combines the fees owed by Skip swaps this process executed with the
+    /// affiliate address's current balances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no affiliate is configured or the balance query fails
+    pub async fn get_affiliate_fees(&self) -> Result<AffiliateFeeReport, Error> {
+        let affiliate = self
+            .skip_affiliate
+            .clone()
+            .ok_or_else(|| Error::Config("No Skip affiliate configured".to_string()))?;
+        let balances = self.get_balances_for_address(&affiliate.address).await?;
+        Ok(AffiliateFeeReport {
+            accrued: AffiliateFeeLedger::global().accrued(&affiliate.address),
+            affiliate,
+            balances,
+        })
     }
 
     /// Execute a swap through Skip Adapter
@@ -1544,6 +3485,7 @@ impl MantraDexClient {
     /// * Returns error if Skip adapter contracts are not configured
     /// * Returns error if the swap transaction fails
     /// * Returns error if no wallet is configured
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn execute_skip_swap(
         &self,
         operations: Vec<crate::protocols::skip::SkipSwapOperation>,
@@ -1608,11 +3550,13 @@ impl MantraDexClient {
     /// * `offer_coin` - The coin to offer for the swap
     /// * `min_receive_amount` - Minimum amount to receive
     /// * `action` - Post-swap action to execute
-    /// * `affiliates` - Optional affiliate addresses for fee sharing
+    /// * `affiliates` - Optional affiliate addresses for fee sharing; defaults
+    ///   to the configured Skip affiliate
     ///
     /// # Returns
     ///
     /// Transaction response containing the swap and action result
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn execute_skip_swap_and_action(
         &self,
         operations: Vec<crate::protocols::skip::SkipSwapOperation>,
@@ -1812,6 +3756,234 @@ impl MantraDexClient {
         self.query(skip_mantra_dex_adapter, &query).await
     }
 
+    /// Find the best split of `offer` across candidate swap paths
+    ///
+    /// Every path is simulated at `split_steps` evenly spaced input amounts
+    /// (10 by default), and the input is handed out one slice at a time to the
+    /// path gaining the most output from it. The resulting split is checked
+    /// with the entry point's smart swap simulation. A path whose simulation
+    /// fails only receives input up to the last amount that simulated.
+    ///
+    /// # Arguments
+    ///
+    /// * `offer` - The coin to swap
+    /// * `routes` - Candidate swap paths, all starting with the offer denom and
+    ///   ending in the same denom
+    /// * `split_steps` - Number of slices the input is split into
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the paths are inconsistent, the paths cannot take
+    /// the whole input, or the smart swap simulation fails
+    pub async fn simulate_smart_swap(
+        &self,
+        offer: Coin,
+        routes: Vec<Vec<crate::protocols::skip::SkipSwapOperation>>,
+        split_steps: Option<u32>,
+    ) -> Result<SmartSwapSimulation, Error> {
+        if offer.amount.is_zero() {
+            return Err(Error::Other(
+                "Offer amount must be greater than zero".to_string(),
+            ));
+        }
+        let output_denom = smart_swap::validate_routes(&offer.denom, &routes)?;
+        let steps = split_steps
+            .unwrap_or(smart_swap::DEFAULT_SMART_SWAP_SPLIT_STEPS)
+            .clamp(1, smart_swap::MAX_SMART_SWAP_SPLIT_STEPS);
+        let snapshot = match self.pool_snapshot(DEFAULT_SNAPSHOT_MAX_BLOCKS_BEHIND).await {
+            Ok(snapshot) => Some(snapshot),
+            Err(e) => {
+                tracing::warn!("Simulating smart swap routes on chain: {}", e);
+                None
+            }
+        };
+
+        // Output of each path for 0..=steps slices, cut at the first failure
+        let mut curves = Vec::with_capacity(routes.len());
+        for (index, operations) in routes.iter().enumerate() {
+            let mut curve = vec![Uint128::zero()];
+            for step in 1..=steps {
+                let amount = smart_swap::step_amount(offer.amount, step, steps);
+                let asset_in = Coin {
+                    denom: offer.denom.clone(),
+                    amount,
+                };
+                match self
+                    .simulate_route_output(snapshot.as_deref(), asset_in, operations)
+                    .await
+                {
+                    Ok(out) => curve.push(out),
+                    Err(e) => {
+                        tracing::warn!(
+                            "Smart swap route {} stops simulating at {}{}: {}",
+                            index,
+                            amount,
+                            offer.denom,
+                            e
+                        );
+                        break;
+                    }
+                }
+            }
+            curves.push(curve);
+        }
+
+        let allocation = smart_swap::allocate_steps(&curves, steps);
+        if allocation.iter().sum::<u32>() < steps {
+            return Err(Error::Skip(format!(
+                "The routes cannot absorb {}{}",
+                offer.amount, offer.denom
+            )));
+        }
+
+        // Slice boundaries are cumulative so the offers add up to the input
+        let mut split = Vec::new();
+        let mut assigned_steps = 0;
+        for (index, (operations, slices)) in routes.into_iter().zip(allocation).enumerate() {
+            if slices == 0 {
+                continue;
+            }
+            let start = smart_swap::step_amount(offer.amount, assigned_steps, steps);
+            assigned_steps += slices;
+            let end = smart_swap::step_amount(offer.amount, assigned_steps, steps);
+            let route_offer = Coin {
+                denom: offer.denom.clone(),
+                amount: end - start,
+            };
+            // Rounding can make the slice differ from the simulated amount
+            let expected_out = if route_offer.amount
+                == smart_swap::step_amount(offer.amount, slices, steps)
+            {
+                curves[index][slices as usize]
+            } else {
+                self.simulate_route_output(snapshot.as_deref(), route_offer.clone(), &operations)
+                    .await?
+            };
+            split.push(SmartSwapRouteResult {
+                route_index: index,
+                operations,
+                offer: route_offer,
+                expected_out,
+                min_out: None,
+            });
+        }
+
+        let expected_out = split
+            .iter()
+            .fold(Uint128::zero(), |sum, route| sum + route.expected_out);
+        let mut simulation = SmartSwapSimulation {
+            offer,
+            output_denom,
+            routes: split,
+            expected_out,
+            aggregate_out: Uint128::zero(),
+            split_steps: steps,
+        };
+        simulation.aggregate_out = self
+            .simulate_skip_smart_swap_exact_asset_in(
+                crate::protocols::skip::SkipAsset::Native(simulation.offer.clone()),
+                simulation.skip_routes(),
+            )
+            .await?
+            .amount();
+        Ok(simulation)
+    }
+
+    /// Output of one swap path, from the pool snapshot when it has every pool
+    async fn simulate_route_output(
+        &self,
+        snapshot: Option<&PoolStateSnapshot>,
+        offer: Coin,
+        operations: &[crate::protocols::skip::SkipSwapOperation],
+    ) -> Result<Uint128, Error> {
+        if let Some(snapshot) = snapshot.filter(|snapshot| snapshot.covers(operations)) {
+            match snapshot.simulate_route(&offer, operations) {
+                Ok(out) => return Ok(out),
+                Err(e) => tracing::debug!("Simulating route on chain: {}", e),
+            }
+        }
+        Ok(self
+            .simulate_skip_swap_exact_asset_in(
+                crate::protocols::skip::SkipAsset::Native(offer),
+                operations.to_vec(),
+            )
+            .await?
+            .amount())
+    }
+
+    /// Execute a split-route swap found by [`MantraDexClient::simulate_smart_swap`]
+    ///
+    /// All routes settle in one entry point message that reverts unless their
+    /// combined output reaches `min_receive`. Each route's result reports its
+    /// input, simulated output and share of the minimum.
+    ///
+    /// # Arguments
+    ///
+    /// * `simulation` - The split to execute
+    /// * `min_receive` - Minimum combined output
+    /// * `receiver` - Optional receiver address (defaults to sender if None)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without broadcasting if the simulated output is below
+    /// `min_receive`, and an error if the transaction fails
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn execute_smart_swap(
+        &self,
+        simulation: &SmartSwapSimulation,
+        min_receive: Uint128,
+        receiver: Option<String>,
+    ) -> Result<SmartSwapExecution, Error> {
+        if simulation.routes.is_empty() {
+            return Err(Error::Other("Smart swap has no routes".to_string()));
+        }
+        if simulation.aggregate_out < min_receive {
+            return Err(Error::Skip(format!(
+                "Smart swap simulates {}{}, below the minimum of {}{}",
+                simulation.aggregate_out,
+                simulation.output_denom,
+                min_receive,
+                simulation.output_denom
+            )));
+        }
+
+        let receiver = match receiver {
+            Some(addr) => addr,
+            None => self.wallet()?.address()?.to_string(),
+        };
+        let swap = crate::protocols::skip::SkipSwap::SmartSwapExactAssetIn(
+            crate::protocols::skip::SkipSmartSwapExactAssetIn {
+                swap_venue_name: "mantra-dex".to_string(),
+                routes: simulation.skip_routes(),
+            },
+        );
+        let min_out = Coin {
+            denom: simulation.output_denom.clone(),
+            amount: min_receive,
+        };
+
+        let tx = self
+            .execute_skip_entry_point_swap(
+                swap,
+                simulation.offer.clone(),
+                min_out.clone(),
+                crate::protocols::skip::SkipAction::Transfer {
+                    to_address: receiver,
+                },
+                vec![],
+            )
+            .await?;
+
+        let mut routes = simulation.routes.clone();
+        smart_swap::apportion_min_receive(&mut routes, min_receive);
+        Ok(SmartSwapExecution {
+            tx_hash: tx.txhash,
+            min_receive: min_out,
+            aggregate_out: simulation.aggregate_out,
+            routes,
+        })
+    }
+
     /// Check if Skip Adapter functionality is available
     ///
     /// This method checks if all required Skip adapter contract addresses are configured.
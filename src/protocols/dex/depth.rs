@@ -0,0 +1,520 @@
+/// Pool depth and imbalance analysis
+///
+/// Depth is reported as the largest offer that keeps the price impact at or
+/// below a set of levels, for every swap direction of a pool. Impact is the
+/// shortfall of the execution price against the spot price before fees, using
+/// the same curves as the pool manager: x*y=k for constant product pools and the
+/// StableSwap invariant for stable pools. Stable pools are also checked for
+/// reserves drifting far apart, which makes swaps into the scarce asset costly.
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType};
+use std::str::FromStr;
+
+use super::types::{
+    PoolDepthLevel, PoolDepthReport, StablePoolBalance, SwapDirectionDepth, SwapImpactCheck,
+};
+use crate::error::Error;
+
+/// Price impact levels reported by default (0.5%, 1% and 2%)
+pub const DEFAULT_DEPTH_IMPACT_LEVELS: [Decimal; 3] = [
+    Decimal::permille(5),
+    Decimal::percent(1),
+    Decimal::percent(2),
+];
+
+/// Swaps with a larger estimated price impact are flagged as large
+pub const LARGE_SWAP_IMPACT_THRESHOLD: Decimal = Decimal::percent(1);
+
+/// Stable pools whose smallest reserve is below this share of the largest are
+/// reported as imbalanced
+pub const STABLE_IMBALANCE_RATIO_THRESHOLD: Decimal = Decimal::percent(50);
+
+/// Pool reserves normalized to whole tokens for curve math
+struct PoolCurve {
+    amp: Option<u64>,
+    reserves: Vec<f64>,
+    scales: Vec<f64>,
+}
+
+impl PoolCurve {
+    fn from_pool(pool: &PoolInfoResponse) -> Result<Self, Error> {
+        let info = &pool.pool_info;
+        if info.assets.len() < 2 || info.assets.iter().any(|asset| asset.amount.is_zero()) {
+            return Err(Error::Other(format!(
+                "Pool {} has empty reserves",
+                info.pool_identifier
+            )));
+        }
+        // Without decimals, base units are compared directly
+        let scales: Vec<f64> = (0..info.assets.len())
+            .map(|i| {
+                let decimals = info.asset_decimals.get(i).copied().unwrap_or(0);
+                10f64.powi(decimals as i32)
+            })
+            .collect();
+        Ok(Self {
+            amp: match info.pool_type {
+                PoolType::StableSwap { amp } => Some(amp),
+                PoolType::ConstantProduct => None,
+            },
+            reserves: info
+                .assets
+                .iter()
+                .zip(&scales)
+                .map(|(asset, scale)| asset.amount.u128() as f64 / scale)
+                .collect(),
+            scales,
+        })
+    }
+
+    /// Output of asset `ask` for offering `offer_amount` of asset `offer`, before fees
+    fn return_amount(&self, offer: usize, ask: usize, offer_amount: f64) -> f64 {
+        match self.amp {
+            None => self.reserves[ask] * offer_amount / (self.reserves[offer] + offer_amount),
+            Some(amp) => {
+                let d = stableswap_d(amp, &self.reserves);
+                let mut balances = self.reserves.clone();
+                balances[offer] += offer_amount;
+                let y = stableswap_y(amp, &balances, ask, d);
+                (self.reserves[ask] - y).max(0.0)
+            }
+        }
+    }
+
+    /// Marginal price of `ask` per unit of `offer`
+    fn spot_price(&self, offer: usize, ask: usize) -> f64 {
+        match self.amp {
+            None => self.reserves[ask] / self.reserves[offer],
+            Some(_) => {
+                let probe = self.reserves[offer] * 1e-9;
+                self.return_amount(offer, ask, probe) / probe
+            }
+        }
+    }
+
+    fn price_impact(&self, offer: usize, ask: usize, offer_amount: f64) -> f64 {
+        if offer_amount <= 0.0 {
+            return 0.0;
+        }
+        let execution_price = self.return_amount(offer, ask, offer_amount) / offer_amount;
+        (1.0 - execution_price / self.spot_price(offer, ask)).clamp(0.0, 1.0)
+    }
+
+    /// Largest offer whose price impact stays within `max_impact`
+    fn max_offer_at_impact(&self, offer: usize, ask: usize, max_impact: f64) -> f64 {
+        if max_impact <= 0.0 {
+            return 0.0;
+        }
+        let (mut low, mut high) = (0.0, self.reserves[offer]);
+        for _ in 0..128 {
+            if self.price_impact(offer, ask, high) > max_impact {
+                break;
+            }
+            low = high;
+            high *= 2.0;
+        }
+        for _ in 0..100 {
+            let mid = (low + high) / 2.0;
+            if self.price_impact(offer, ask, mid) <= max_impact {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    fn to_base_units(&self, asset: usize, amount: f64) -> Uint128 {
+        Uint128::new((amount * self.scales[asset]).max(0.0).floor() as u128)
+    }
+
+    fn to_whole(&self, asset: usize, amount: Uint128) -> f64 {
+        amount.u128() as f64 / self.scales[asset]
+    }
+}
+
+/// StableSwap invariant D for the given balances
+fn stableswap_d(amp: u64, balances: &[f64]) -> f64 {
+    let n = balances.len() as f64;
+    let ann = amp as f64 * n.powf(n);
+    let sum: f64 = balances.iter().sum();
+    let mut d = sum;
+    for _ in 0..255 {
+        let d_p = balances.iter().fold(d, |d_p, x| d_p * d / (x * n));
+        let previous = d;
+        d = (ann * sum + d_p * n) * d / ((ann - 1.0) * d + (n + 1.0) * d_p);
+        if (d - previous).abs() <= d * 1e-15 {
+            break;
+        }
+    }
+    d
+}
+
+/// Balance of asset `index` that keeps the invariant at `d` given the others
+fn stableswap_y(amp: u64, balances: &[f64], index: usize, d: f64) -> f64 {
+    let n = balances.len() as f64;
+    let ann = amp as f64 * n.powf(n);
+    let (mut sum, mut c) = (0.0, d);
+    for (_, x) in balances.iter().enumerate().filter(|(i, _)| *i != index) {
+        sum += x;
+        c = c * d / (x * n);
+    }
+    c = c * d / (ann * n);
+    let b = sum + d / ann;
+    let mut y = d;
+    for _ in 0..255 {
+        let previous = y;
+        y = (y * y + c) / (2.0 * y + b - d);
+        if (y - previous).abs() <= y * 1e-15 {
+            break;
+        }
+    }
+    y
+}
+
+pub(super) fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_str(&format!("{:.18}", value.max(0.0))).unwrap_or_default()
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_string().parse().unwrap_or(0.0)
+}
+
+fn asset_index(pool: &PoolInfoResponse, denom: &str) -> Result<usize, Error> {
+    pool.pool_info
+        .assets
+        .iter()
+        .position(|asset| asset.denom == denom)
+        .ok_or_else(|| {
+            Error::Other(format!(
+                "Asset {} is not part of pool {}",
+                denom, pool.pool_info.pool_identifier
+            ))
+        })
+}
+
+/// Spot price of the pool's second asset per whole unit of its first, before
+/// fees
+pub fn pool_spot_price(pool: &PoolInfoResponse) -> Result<f64, Error> {
+    Ok(PoolCurve::from_pool(pool)?.spot_price(0, 1))
+}
+
+/// Spot price of one whole `denom` in whole units of `quote_denom`, before fees
+pub fn pool_denom_price(
+    pool: &PoolInfoResponse,
+    denom: &str,
+    quote_denom: &str,
+) -> Result<f64, Error> {
+    let curve = PoolCurve::from_pool(pool)?;
+    Ok(curve.spot_price(asset_index(pool, denom)?, asset_index(pool, quote_denom)?))
+}
+
+/// Output of a swap before fees, and what it would be at the spot price, in
+/// base units of `ask_denom`
+///
+/// Follows the pool's curve in floating point, so it approximates rather than
+/// reproduces the pool manager's integer math.
+pub fn curve_swap_output(
+    pool: &PoolInfoResponse,
+    offer_denom: &str,
+    ask_denom: &str,
+    offer_amount: Uint128,
+) -> Result<(Uint128, Uint128), Error> {
+    let curve = PoolCurve::from_pool(pool)?;
+    let (offer, ask) = (
+        asset_index(pool, offer_denom)?,
+        asset_index(pool, ask_denom)?,
+    );
+    let amount = curve.to_whole(offer, offer_amount);
+    Ok((
+        curve.to_base_units(ask, curve.return_amount(offer, ask, amount)),
+        curve.to_base_units(ask, amount * curve.spot_price(offer, ask)),
+    ))
+}
+
+/// Pool invariant in whole tokens: the geometric mean of the reserves for
+/// constant product pools and StableSwap D for stable pools
+///
+/// Swaps only grow the invariant through fees, so its growth per LP share
+/// measures the fees earned by liquidity providers.
+pub fn pool_invariant(pool: &PoolInfoResponse) -> Result<f64, Error> {
+    let curve = PoolCurve::from_pool(pool)?;
+    Ok(match curve.amp {
+        Some(amp) => stableswap_d(amp, &curve.reserves),
+        None => {
+            let n = curve.reserves.len() as f64;
+            (curve
+                .reserves
+                .iter()
+                .map(|reserve| reserve.ln())
+                .sum::<f64>()
+                / n)
+                .exp()
+        }
+    })
+}
+
+/// Reserve balance of a stable pool, or `None` for other pool types
+pub fn stable_pool_balance(pool: &PoolInfoResponse) -> Result<Option<StablePoolBalance>, Error> {
+    let curve = PoolCurve::from_pool(pool)?;
+    if curve.amp.is_none() {
+        return Ok(None);
+    }
+    let total: f64 = curve.reserves.iter().sum();
+    let min = curve.reserves.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = curve.reserves.iter().copied().fold(0.0, f64::max);
+    let max_peg_deviation = (1..curve.reserves.len())
+        .map(|i| (1.0 - curve.spot_price(0, i)).abs())
+        .fold(0.0, f64::max);
+
+    let balance_ratio = to_decimal(min / max);
+    Ok(Some(StablePoolBalance {
+        shares: pool
+            .pool_info
+            .assets
+            .iter()
+            .zip(&curve.reserves)
+            .map(|(asset, reserve)| (asset.denom.clone(), to_decimal(reserve / total)))
+            .collect(),
+        balance_ratio,
+        max_peg_deviation: to_decimal(max_peg_deviation),
+        is_imbalanced: balance_ratio < STABLE_IMBALANCE_RATIO_THRESHOLD,
+    }))
+}
+
+/// Depth at each impact level for every swap direction of a pool
+pub fn analyze_pool_depth(
+    pool: &PoolInfoResponse,
+    impact_levels: &[Decimal],
+) -> Result<PoolDepthReport, Error> {
+    let curve = PoolCurve::from_pool(pool)?;
+    let assets = &pool.pool_info.assets;
+
+    let mut directions = Vec::new();
+    for offer in 0..assets.len() {
+        for ask in (0..assets.len()).filter(|ask| *ask != offer) {
+            let levels = impact_levels
+                .iter()
+                .map(|level| {
+                    let offer_amount = curve.max_offer_at_impact(offer, ask, to_f64(*level));
+                    PoolDepthLevel {
+                        max_price_impact: *level,
+                        offer_amount: curve.to_base_units(offer, offer_amount),
+                        return_amount: curve
+                            .to_base_units(ask, curve.return_amount(offer, ask, offer_amount)),
+                    }
+                })
+                .collect();
+            directions.push(SwapDirectionDepth {
+                offer_denom: assets[offer].denom.clone(),
+                ask_denom: assets[ask].denom.clone(),
+                spot_price: to_decimal(curve.spot_price(offer, ask)),
+                levels,
+            });
+        }
+    }
+
+    let stable_balance = stable_pool_balance(pool)?;
+    let mut warnings = Vec::new();
+    if let Some(balance) = stable_balance.as_ref().filter(|b| b.is_imbalanced) {
+        warnings.push(imbalance_warning(balance));
+    }
+
+    Ok(PoolDepthReport {
+        pool_id: pool.pool_info.pool_identifier.clone(),
+        pool_type: match pool.pool_info.pool_type {
+            PoolType::ConstantProduct => "constant_product".to_string(),
+            PoolType::StableSwap { .. } => "stable_swap".to_string(),
+        },
+        reserves: assets.clone(),
+        directions,
+        stable_balance,
+        warnings,
+    })
+}
+
+/// Estimate the price impact of a swap and warn when it is large
+///
+/// A warning is raised when the impact exceeds `threshold`, and when the swap
+/// takes the scarce asset out of an imbalanced stable pool.
+pub fn check_swap_impact(
+    pool: &PoolInfoResponse,
+    offer: &Coin,
+    ask_denom: &str,
+    threshold: Decimal,
+) -> Result<SwapImpactCheck, Error> {
+    let curve = PoolCurve::from_pool(pool)?;
+    let offer_index = asset_index(pool, &offer.denom)?;
+    let ask_index = asset_index(pool, ask_denom)?;
+    if offer_index == ask_index {
+        return Err(Error::Other(
+            "Offer and ask assets must be different".to_string(),
+        ));
+    }
+
+    let offer_amount = curve.to_whole(offer_index, offer.amount);
+    let price_impact = to_decimal(curve.price_impact(offer_index, ask_index, offer_amount));
+    let max_offer = curve.max_offer_at_impact(offer_index, ask_index, to_f64(threshold));
+    let max_offer_within_threshold = curve.to_base_units(offer_index, max_offer);
+
+    let mut warnings = Vec::new();
+    if price_impact > threshold {
+        warnings.push(format!(
+            "Swapping {}{} in pool {} has an estimated price impact of {:.2}%, above the {:.2}% warning threshold; at most {}{} can be swapped within it",
+            offer.amount,
+            offer.denom,
+            pool.pool_info.pool_identifier,
+            to_f64(price_impact) * 100.0,
+            to_f64(threshold) * 100.0,
+            max_offer_within_threshold,
+            offer.denom
+        ));
+    }
+    if let Some(balance) = stable_pool_balance(pool)?.filter(|b| b.is_imbalanced) {
+        // Taking more of the scarce asset pushes the pool further off peg
+        let scarcest = curve
+            .reserves
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(i, _)| i);
+        if scarcest == Some(ask_index) {
+            warnings.push(imbalance_warning(&balance));
+        }
+    }
+
+    Ok(SwapImpactCheck {
+        pool_id: pool.pool_info.pool_identifier.clone(),
+        offer: offer.clone(),
+        ask_denom: ask_denom.to_string(),
+        price_impact,
+        expected_return: curve.to_base_units(
+            ask_index,
+            curve.return_amount(offer_index, ask_index, offer_amount),
+        ),
+        threshold,
+        max_offer_within_threshold,
+        warnings,
+    })
+}
+
+fn imbalance_warning(balance: &StablePoolBalance) -> String {
+    format!(
+        "Stable pool is imbalanced: the smallest reserve is {:.1}% of the largest and prices are up to {:.2}% off peg",
+        to_f64(balance.balance_ratio) * 100.0,
+        to_f64(balance.max_peg_deviation) * 100.0
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mantra_dex_std::{
+        fee::{Fee, PoolFee},
+        pool_manager::{PoolInfo, PoolStatus},
+    };
+
+    fn pool(pool_type: PoolType, reserves: &[(&str, u128)]) -> PoolInfoResponse {
+        let fee = Fee {
+            share: Decimal::zero(),
+        };
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: "p.1".to_string(),
+                asset_denoms: reserves.iter().map(|(d, _)| d.to_string()).collect(),
+                lp_denom: "lp".to_string(),
+                asset_decimals: vec![6; reserves.len()],
+                assets: reserves
+                    .iter()
+                    .map(|(denom, amount)| Coin::new(*amount, *denom))
+                    .collect(),
+                pool_type,
+                pool_fees: PoolFee {
+                    protocol_fee: fee.clone(),
+                    swap_fee: fee.clone(),
+                    burn_fee: fee,
+                    extra_fees: vec![],
+                },
+                status: PoolStatus {
+                    swaps_enabled: true,
+                    deposits_enabled: true,
+                    withdrawals_enabled: true,
+                },
+            },
+            total_share: Coin::new(1u128, "lp"),
+        }
+    }
+
+    #[test]
+    fn test_constant_product_depth_matches_closed_form() {
+        let pool = pool(
+            PoolType::ConstantProduct,
+            &[("uom", 1_000_000_000), ("uusdc", 4_000_000_000)],
+        );
+        let report = analyze_pool_depth(&pool, &DEFAULT_DEPTH_IMPACT_LEVELS).unwrap();
+        assert_eq!(report.directions.len(), 2);
+        assert!(report.stable_balance.is_none());
+
+        // Impact x / (R + x) = p gives x = R p / (1 - p)
+        let uom_in = &report.directions[0];
+        assert_eq!(uom_in.spot_price, Decimal::from_str("4").unwrap());
+        for (level, expected) in uom_in
+            .levels
+            .iter()
+            .zip([5_025_125u128, 10_101_010, 20_408_163])
+        {
+            assert!(level.offer_amount.u128().abs_diff(expected) <= 1);
+        }
+
+        let check = check_swap_impact(
+            &pool,
+            &Coin::new(50_000_000u128, "uom"),
+            "uusdc",
+            LARGE_SWAP_IMPACT_THRESHOLD,
+        )
+        .unwrap();
+        assert_eq!(check.warnings.len(), 1);
+        assert!(check.max_offer_within_threshold.u128().abs_diff(10_101_010) <= 1);
+    }
+
+    #[test]
+    fn test_stable_pool_depth_and_imbalance() {
+        let balanced = pool(
+            PoolType::StableSwap { amp: 100 },
+            &[("uusdc", 1_000_000_000), ("uusdt", 1_000_000_000)],
+        );
+        let report = analyze_pool_depth(&balanced, &[Decimal::percent(1)]).unwrap();
+        let balance = report.stable_balance.as_ref().unwrap();
+        assert!(!balance.is_imbalanced);
+        assert!(report.warnings.is_empty());
+        // The flat stable curve is far deeper than a constant product pool (~10.1M)
+        assert!(report.directions[0].levels[0].offer_amount > Uint128::new(100_000_000));
+
+        let skewed = pool(
+            PoolType::StableSwap { amp: 100 },
+            &[("uusdc", 1_800_000_000), ("uusdt", 200_000_000)],
+        );
+        let balance = stable_pool_balance(&skewed).unwrap().unwrap();
+        assert!(balance.is_imbalanced);
+        assert!(balance.max_peg_deviation > Decimal::zero());
+
+        // Buying the scarce asset is flagged even for a small trade
+        let check = check_swap_impact(
+            &skewed,
+            &Coin::new(1_000u128, "uusdc"),
+            "uusdt",
+            LARGE_SWAP_IMPACT_THRESHOLD,
+        )
+        .unwrap();
+        assert_eq!(check.warnings.len(), 1);
+        assert!(check_swap_impact(
+            &skewed,
+            &Coin::new(1_000u128, "uusdt"),
+            "uusdc",
+            LARGE_SWAP_IMPACT_THRESHOLD
+        )
+        .unwrap()
+        .warnings
+        .is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+/// Dust handling for residual balances
+///
+/// Zaps and swaps deposit or swap slippage-adjusted amounts, so small leftovers
+/// of the assets they touch stay in the wallet. A [`DustPolicy`] decides what
+/// happens to balances below a threshold once an operation completes: leave
+/// them alone, swap them into the native token, or keep a running record of
+/// them until they are swept.
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, SimulationResponse};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+
+use crate::error::Error;
+
+/// Balances below this many base units are dust unless a per-denom threshold applies
+pub const DEFAULT_DUST_THRESHOLD: u128 = 10_000;
+
+/// Maximum slippage accepted when swapping dust into the native token
+pub const DEFAULT_DUST_SWEEP_SLIPPAGE_PERCENT: u64 = 5;
+
+/// What to do with dust left after an operation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DustAction {
+    /// Leave dust in the wallet
+    #[default]
+    Ignore,
+    /// Swap dust into the native token right away
+    ConvertToNative,
+    /// Leave dust in the wallet and track it until it is swept
+    Accumulate,
+}
+
+impl FromStr for DustAction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ignore" => Ok(Self::Ignore),
+            "convert_to_native" | "convert" => Ok(Self::ConvertToNative),
+            "accumulate" => Ok(Self::Accumulate),
+            other => Err(Error::Other(format!(
+                "Unknown dust action '{}', expected ignore, convert_to_native or accumulate",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for DustAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Ignore => "ignore",
+            Self::ConvertToNative => "convert_to_native",
+            Self::Accumulate => "accumulate",
+        };
+        f.write_str(name)
+    }
+}
+
+/// How residual balances are classified and handled
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DustPolicy {
+    #[serde(default)]
+    pub action: DustAction,
+    /// Balances strictly below this amount are dust
+    #[serde(default = "default_dust_threshold")]
+    pub default_threshold: Uint128,
+    /// Per-denom overrides of `default_threshold`
+    #[serde(default)]
+    pub thresholds: BTreeMap<String, Uint128>,
+    /// Token dust is converted into; never treated as dust itself
+    #[serde(default = "default_native_denom")]
+    pub native_denom: String,
+}
+
+fn default_dust_threshold() -> Uint128 {
+    Uint128::new(DEFAULT_DUST_THRESHOLD)
+}
+
+fn default_native_denom() -> String {
+    "uom".to_string()
+}
+
+impl Default for DustPolicy {
+    fn default() -> Self {
+        Self {
+            action: DustAction::default(),
+            default_threshold: default_dust_threshold(),
+            thresholds: BTreeMap::new(),
+            native_denom: default_native_denom(),
+        }
+    }
+}
+
+impl DustPolicy {
+    /// Policy with the given action and default thresholds
+    pub fn new(action: DustAction) -> Self {
+        Self {
+            action,
+            ..Self::default()
+        }
+    }
+
+    /// Override the threshold for a single denom
+    pub fn with_threshold(mut self, denom: impl Into<String>, threshold: Uint128) -> Self {
+        self.thresholds.insert(denom.into(), threshold);
+        self
+    }
+
+    /// Threshold applied to `denom`
+    pub fn threshold_for(&self, denom: &str) -> Uint128 {
+        self.thresholds
+            .get(denom)
+            .copied()
+            .unwrap_or(self.default_threshold)
+    }
+
+    /// Whether a balance counts as dust
+    pub fn is_dust(&self, coin: &Coin) -> bool {
+        coin.denom != self.native_denom
+            && !coin.amount.is_zero()
+            && coin.amount < self.threshold_for(&coin.denom)
+    }
+
+    /// Dust among `balances`, optionally restricted to `denoms`
+    pub fn find_dust(&self, balances: &[Coin], denoms: Option<&[String]>) -> Vec<Coin> {
+        balances
+            .iter()
+            .filter(|coin| denoms.is_none_or(|denoms| denoms.contains(&coin.denom)))
+            .filter(|coin| self.is_dust(coin))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Dust swapped into the native token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DustConversion {
+    pub offer: Coin,
+    pub pool_id: String,
+    pub simulation: SimulationResponse,
+}
+
+/// Dust that could not be swept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedDust {
+    pub coin: Coin,
+    pub reason: String,
+}
+
+/// Result of consolidating dust into the native token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DustSweepReport {
+    pub native_denom: String,
+    pub converted: Vec<DustConversion>,
+    pub skipped: Vec<SkippedDust>,
+    /// Total native token expected from the conversions
+    pub expected_return: Uint128,
+    /// Set once the sweep transaction was broadcast
+    pub tx_hash: Option<String>,
+    pub dry_run: bool,
+}
+
+/// Outcome of applying the dust policy after an operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DustReport {
+    pub action: DustAction,
+    /// Dust found among the assets the operation touched
+    pub dust: Vec<Coin>,
+    /// Sweep performed under [`DustAction::ConvertToNative`]
+    pub sweep: Option<DustSweepReport>,
+    /// All dust tracked for the wallet under [`DustAction::Accumulate`]
+    pub accumulated: Vec<Coin>,
+}
+
+/// Pool used to swap `denom` into `native_denom`
+///
+/// Prefers the pool holding the most of the native token so small swaps move
+/// the price the least.
+pub fn select_sweep_pool<'a>(
+    pools: &'a [PoolInfoResponse],
+    denom: &str,
+    native_denom: &str,
+) -> Option<&'a PoolInfoResponse> {
+    pools
+        .iter()
+        .filter(|pool| pool.pool_info.status.swaps_enabled)
+        .filter(|pool| {
+            let denoms = &pool.pool_info.asset_denoms;
+            denoms.iter().any(|d| d == denom) && denoms.iter().any(|d| d == native_denom)
+        })
+        .max_by_key(|pool| {
+            pool.pool_info
+                .assets
+                .iter()
+                .find(|asset| asset.denom == native_denom)
+                .map(|asset| asset.amount)
+                .unwrap_or_default()
+        })
+}
+
+/// Dust tracked per wallet under [`DustAction::Accumulate`]
+#[derive(Debug, Default)]
+pub struct DustLedger {
+    entries: RwLock<HashMap<String, BTreeMap<String, Uint128>>>,
+}
+
+impl DustLedger {
+    /// Process-wide ledger shared by all DEX clients
+    pub fn global() -> &'static DustLedger {
+        static GLOBAL: OnceLock<DustLedger> = OnceLock::new();
+        GLOBAL.get_or_init(DustLedger::default)
+    }
+
+    /// Update the tracked dust of `address` for the `checked` denoms
+    ///
+    /// Checked denoms missing from `dust` are no longer dust (spent, swept or
+    /// grown past the threshold) and are dropped.
+    pub fn record(&self, address: &str, checked: &[String], dust: &[Coin]) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let tracked = entries.entry(address.to_string()).or_default();
+        for denom in checked {
+            tracked.remove(denom);
+        }
+        for coin in dust {
+            tracked.insert(coin.denom.clone(), coin.amount);
+        }
+        if tracked.is_empty() {
+            entries.remove(address);
+        }
+    }
+
+    /// Stop tracking `denoms` for `address`
+    pub fn clear(&self, address: &str, denoms: &[String]) {
+        self.record(address, denoms, &[]);
+    }
+
+    /// Dust currently tracked for `address`
+    pub fn outstanding(&self, address: &str) -> Vec<Coin> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(address)
+            .map(|tracked| {
+                tracked
+                    .iter()
+                    .map(|(denom, amount)| Coin::new(*amount, denom.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_dust_respects_thresholds_and_native_denom() {
+        let policy =
+            DustPolicy::new(DustAction::Accumulate).with_threshold("uusdc", Uint128::new(100));
+        let balances = vec![
+            Coin::new(5u128, "uom"),
+            Coin::new(99u128, "uusdc"),
+            Coin::new(9_999u128, "uatom"),
+            Coin::new(10_000u128, "uosmo"),
+            Coin::new(0u128, "uluna"),
+        ];
+
+        let dust = policy.find_dust(&balances, None);
+        assert_eq!(
+            dust,
+            vec![Coin::new(99u128, "uusdc"), Coin::new(9_999u128, "uatom")]
+        );
+
+        let only_usdc = policy.find_dust(&balances, Some(&["uusdc".to_string()]));
+        assert_eq!(only_usdc, vec![Coin::new(99u128, "uusdc")]);
+    }
+
+    #[test]
+    fn test_ledger_replaces_checked_denoms() {
+        let ledger = DustLedger::default();
+        let checked = vec!["uusdc".to_string(), "uatom".to_string()];
+        ledger.record(
+            "mantra1a",
+            &checked,
+            &[Coin::new(10u128, "uusdc"), Coin::new(20u128, "uatom")],
+        );
+        ledger.record("mantra1a", &checked[..1], &[Coin::new(15u128, "uusdc")]);
+        assert_eq!(
+            ledger.outstanding("mantra1a"),
+            vec![Coin::new(20u128, "uatom"), Coin::new(15u128, "uusdc")]
+        );
+
+        ledger.clear("mantra1a", &checked);
+        assert!(ledger.outstanding("mantra1a").is_empty());
+    }
+}
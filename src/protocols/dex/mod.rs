@@ -1,31 +1,67 @@
 /// DEX Protocol Module
 /// Handles all DEX-related operations including pools, swaps, liquidity, and farming
 pub mod client;
+pub mod depth;
+pub mod dust;
+pub mod position;
+pub mod price_history;
+pub mod snapshot;
 pub mod types;
+pub mod withdrawal;
+pub mod zap;
 
 pub use client::MantraDexClient;
 
 use crate::error::Error;
+use crate::protocols::discovery::{discover_contract, ContractDiscovery};
 use crate::protocols::Protocol;
+use crate::transport::SharedTransport;
 use async_trait::async_trait;
-use cosmrs::rpc::HttpClient;
 use serde_json::{json, Value};
-use std::sync::Arc;
 
 /// DEX Protocol implementation
 #[derive(Clone)]
 pub struct DexProtocol {
     initialized: bool,
+    pool_manager: Option<String>,
+    expected_code_id: Option<u64>,
+    deployment: Option<ContractDiscovery>,
 }
 
 impl DexProtocol {
     /// Create a new DEX protocol instance
     pub fn new() -> Self {
-        Self { initialized: false }
+        Self {
+            initialized: false,
+            pool_manager: None,
+            expected_code_id: None,
+            deployment: None,
+        }
+    }
+
+    /// Get the pool manager contract address
+    pub fn pool_manager(&self) -> Option<&str> {
+        self.pool_manager.as_deref()
+    }
+
+    /// Set the pool manager contract address
+    pub fn set_pool_manager(&mut self, address: String) {
+        self.pool_manager = Some(address);
+    }
+
+    /// Set the code ID the pool manager must be instantiated from
+    pub fn set_expected_code_id(&mut self, code_id: Option<u64>) {
+        self.expected_code_id = code_id;
+    }
+
+    /// What initialization found at the pool manager address
+    pub fn deployment(&self) -> Option<&ContractDiscovery> {
+        self.deployment.as_ref()
     }
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl Protocol for DexProtocol {
     fn name(&self) -> &'static str {
         "dex"
@@ -35,9 +71,24 @@ impl Protocol for DexProtocol {
         "3.0.0"
     }
 
-    async fn is_available(&self, _rpc_client: &HttpClient) -> Result<bool, Error> {
-        // Check if DEX contracts are deployed on the network
-        Ok(self.initialized)
+    async fn is_available(&self, transport: &SharedTransport) -> Result<bool, Error> {
+        // Check if the pool manager is deployed on the network
+        match &self.pool_manager {
+            Some(address) => {
+                Ok(
+                    discover_contract(transport.as_ref(), address, self.expected_code_id)
+                        .await?
+                        .is_deployed(),
+                )
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn is_deployed(&self) -> bool {
+        self.deployment
+            .as_ref()
+            .is_some_and(ContractDiscovery::is_deployed)
     }
 
     fn get_config(&self) -> Result<Value, Error> {
@@ -45,12 +96,19 @@ impl Protocol for DexProtocol {
             "name": self.name(),
             "version": self.version(),
             "initialized": self.initialized,
+            "pool_manager": self.pool_manager,
+            "deployment": self.deployment,
         }))
     }
 
-    async fn initialize(&mut self, _rpc_client: Arc<HttpClient>) -> Result<(), Error> {
-        // Initialize the DEX client with the RPC connection
-        // This would typically load contract addresses from config
+    async fn initialize(&mut self, transport: SharedTransport) -> Result<(), Error> {
+        // Look up the pool manager on the connected chain
+        self.deployment = match &self.pool_manager {
+            Some(address) => {
+                Some(discover_contract(transport.as_ref(), address, self.expected_code_id).await?)
+            }
+            None => None,
+        };
         self.initialized = true;
         Ok(())
     }
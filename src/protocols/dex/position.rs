@@ -0,0 +1,234 @@
+/// Liquidity positions: pool share, underlying assets and earned fees
+///
+/// The pool manager does not account fees per provider; they accrue to the
+/// reserves and so to every LP share. A position's earned fees are measured
+/// as the growth of the pool invariant per LP share since the position was
+/// entered, which swaps only change through fees. [`PositionTracker`] keeps
+/// the invariant per share each position was entered at, blending in later
+/// deposits by LP amount.
+use cosmwasm_std::{Coin, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::depth::pool_invariant;
+use super::zap::estimate_withdrawal;
+use crate::error::Error;
+
+/// Pool invariant per LP share, in whole tokens per base unit of LP
+pub fn invariant_per_share(pool: &PoolInfoResponse) -> Option<f64> {
+    let total_share = pool.total_share.amount.u128();
+    if total_share == 0 {
+        return None;
+    }
+    pool_invariant(pool)
+        .ok()
+        .map(|invariant| invariant / total_share as f64)
+}
+
+/// Where a position was entered
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PositionEntry {
+    pub lp_amount: Uint128,
+    /// Pool invariant per LP share at entry, averaged over deposits
+    pub invariant_per_share: f64,
+}
+
+/// LP holding of one pool
+#[derive(Debug, Clone, PartialEq)]
+pub struct LpPosition {
+    pub pool_id: String,
+    pub lp_denom: String,
+    pub lp_amount: Uint128,
+    /// Fraction of the pool's LP supply held
+    pub share: f64,
+    /// Reserves the LP tokens withdraw to
+    pub underlying: Vec<Coin>,
+    /// Part of `underlying` earned from fees, when the entry is known
+    pub earned_fees: Option<Vec<Coin>>,
+}
+
+impl LpPosition {
+    /// Position of `lp_amount` LP tokens of `pool`, entered at `entry`
+    pub fn new(pool: &PoolInfoResponse, lp_amount: Uint128, entry: Option<&PositionEntry>) -> Self {
+        let total_share = pool.total_share.amount;
+        let share = if total_share.is_zero() {
+            0.0
+        } else {
+            lp_amount.u128() as f64 / total_share.u128() as f64
+        };
+        let underlying: Vec<Coin> = pool
+            .pool_info
+            .assets
+            .iter()
+            .map(|asset| Coin {
+                denom: asset.denom.clone(),
+                amount: estimate_withdrawal(lp_amount, asset.amount, total_share),
+            })
+            .collect();
+
+        let fee_fraction = entry
+            .zip(invariant_per_share(pool))
+            .map(|(entry, current)| {
+                if current > 0.0 {
+                    (1.0 - entry.invariant_per_share / current).max(0.0)
+                } else {
+                    0.0
+                }
+            });
+        let earned_fees = fee_fraction.map(|fraction| {
+            underlying
+                .iter()
+                .map(|coin| Coin {
+                    denom: coin.denom.clone(),
+                    amount: Uint128::new((coin.amount.u128() as f64 * fraction).floor() as u128),
+                })
+                .collect()
+        });
+
+        Self {
+            pool_id: pool.pool_info.pool_identifier.clone(),
+            lp_denom: pool.pool_info.lp_denom.clone(),
+            lp_amount,
+            share,
+            underlying,
+            earned_fees,
+        }
+    }
+}
+
+/// Entries of a wallet's liquidity positions, by pool identifier
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PositionTracker {
+    entries: BTreeMap<String, PositionEntry>,
+}
+
+impl PositionTracker {
+    pub fn entry(&self, pool_id: &str) -> Option<&PositionEntry> {
+        self.entries.get(pool_id)
+    }
+
+    /// Record the wallet now holding `lp_amount` LP tokens of `pool`
+    ///
+    /// LP tokens added since the last observation are entered at the pool's
+    /// current invariant per share; withdrawals keep the entry of the rest,
+    /// and an empty position forgets it.
+    pub fn observe(&mut self, pool: &PoolInfoResponse, lp_amount: Uint128) {
+        let pool_id = &pool.pool_info.pool_identifier;
+        if lp_amount.is_zero() {
+            self.entries.remove(pool_id);
+            return;
+        }
+        let Some(current) = invariant_per_share(pool) else {
+            return;
+        };
+        let entry = match self.entries.get(pool_id) {
+            Some(entry) if lp_amount > entry.lp_amount => {
+                let (held, added) = (
+                    entry.lp_amount.u128() as f64,
+                    (lp_amount - entry.lp_amount).u128() as f64,
+                );
+                PositionEntry {
+                    lp_amount,
+                    invariant_per_share: (entry.invariant_per_share * held + current * added)
+                        / (held + added),
+                }
+            }
+            Some(entry) => PositionEntry {
+                lp_amount,
+                invariant_per_share: entry.invariant_per_share,
+            },
+            None => PositionEntry {
+                lp_amount,
+                invariant_per_share: current,
+            },
+        };
+        self.entries.insert(pool_id.clone(), entry);
+    }
+
+    /// Tracker saved at `path`, or an empty one when there is no file yet
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Decimal;
+    use mantra_dex_std::fee::{Fee, PoolFee};
+    use mantra_dex_std::pool_manager::{PoolInfo, PoolStatus, PoolType};
+
+    fn pool(reserves: [u128; 2], total_share: u128) -> PoolInfoResponse {
+        let fee = Fee {
+            share: Decimal::zero(),
+        };
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: "o.uom.uusdc".to_string(),
+                asset_denoms: vec!["uom".to_string(), "uusdc".to_string()],
+                lp_denom: "lp".to_string(),
+                asset_decimals: vec![6, 6],
+                assets: vec![
+                    Coin::new(reserves[0], "uom"),
+                    Coin::new(reserves[1], "uusdc"),
+                ],
+                pool_type: PoolType::ConstantProduct,
+                pool_fees: PoolFee {
+                    protocol_fee: fee.clone(),
+                    swap_fee: fee.clone(),
+                    burn_fee: fee,
+                    extra_fees: vec![],
+                },
+                status: PoolStatus {
+                    swaps_enabled: true,
+                    deposits_enabled: true,
+                    withdrawals_enabled: true,
+                },
+            },
+            total_share: Coin::new(total_share, "lp"),
+        }
+    }
+
+    #[test]
+    fn test_fee_growth_is_reported_as_earned_fees() {
+        let mut tracker = PositionTracker::default();
+        let entered = pool([1_000_000_000, 4_000_000_000], 2_000_000_000);
+        tracker.observe(&entered, Uint128::new(200_000_000));
+
+        // Fees grow both reserves by 1% with the LP supply unchanged
+        let later = pool([1_010_000_000, 4_040_000_000], 2_000_000_000);
+        let position = LpPosition::new(
+            &later,
+            Uint128::new(200_000_000),
+            tracker.entry("o.uom.uusdc"),
+        );
+        assert!((position.share - 0.1).abs() < 1e-12);
+        assert_eq!(position.underlying[0].amount, Uint128::new(101_000_000));
+        let fees = position.earned_fees.unwrap();
+        assert!(fees[0].amount.u128().abs_diff(1_000_000) <= 1, "{:?}", fees);
+        assert!(fees[1].amount.u128().abs_diff(4_000_000) <= 1, "{:?}", fees);
+
+        // A deposit at the later pool state only earns from then on
+        tracker.observe(&later, Uint128::new(400_000_000));
+        let entry = tracker.entry("o.uom.uusdc").unwrap();
+        let entered_at = invariant_per_share(&entered).unwrap();
+        let deposited_at = invariant_per_share(&later).unwrap();
+        assert!((entry.invariant_per_share - (entered_at + deposited_at) / 2.0).abs() < 1e-12);
+
+        tracker.observe(&later, Uint128::zero());
+        assert!(tracker.entry("o.uom.uusdc").is_none());
+    }
+}
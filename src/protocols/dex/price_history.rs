@@ -0,0 +1,329 @@
+/// Pool price history and candles
+///
+/// There is no indexer to ask for past prices, so history is collected as the
+/// client runs: the spot price of every pool snapshot it fetches, and the
+/// execution price of every swap an event subscription delivers. Prices are
+/// the pool's second asset per whole unit of its first, and are bucketed into
+/// OHLC candles of a [`Timeframe`] on demand. Buckets without samples are left
+/// out rather than filled.
+use chrono::{DateTime, Utc};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+use super::depth::pool_spot_price;
+use crate::events::{ChainEvent, ContractEvent};
+
+/// Samples kept per pool by default
+pub const DEFAULT_PRICE_HISTORY_CAPACITY: usize = 5_000;
+
+/// Width of a candle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Timeframe {
+    #[default]
+    OneMinute,
+    FiveMinutes,
+    FifteenMinutes,
+    OneHour,
+    FourHours,
+    OneDay,
+}
+
+impl Timeframe {
+    pub const ALL: [Timeframe; 6] = [
+        Timeframe::OneMinute,
+        Timeframe::FiveMinutes,
+        Timeframe::FifteenMinutes,
+        Timeframe::OneHour,
+        Timeframe::FourHours,
+        Timeframe::OneDay,
+    ];
+
+    pub fn seconds(self) -> i64 {
+        match self {
+            Timeframe::OneMinute => 60,
+            Timeframe::FiveMinutes => 5 * 60,
+            Timeframe::FifteenMinutes => 15 * 60,
+            Timeframe::OneHour => 60 * 60,
+            Timeframe::FourHours => 4 * 60 * 60,
+            Timeframe::OneDay => 24 * 60 * 60,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Timeframe::OneMinute => "1m",
+            Timeframe::FiveMinutes => "5m",
+            Timeframe::FifteenMinutes => "15m",
+            Timeframe::OneHour => "1h",
+            Timeframe::FourHours => "4h",
+            Timeframe::OneDay => "1d",
+        }
+    }
+
+    /// The next wider timeframe, wrapping around to the narrowest
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|t| *t == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Start of the candle `time` falls in
+    pub fn bucket_start(self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let seconds = time.timestamp();
+        let start = seconds - seconds.rem_euclid(self.seconds());
+        DateTime::from_timestamp(start, 0).unwrap_or(time)
+    }
+}
+
+/// One observed pool price
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub time: DateTime<Utc>,
+    pub price: f64,
+}
+
+/// Open, high, low and close price of one timeframe bucket
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Candle {
+    pub open_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    /// Price samples the candle was built from
+    pub samples: u32,
+}
+
+impl Candle {
+    fn new(open_time: DateTime<Utc>, price: f64) -> Self {
+        Self {
+            open_time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            samples: 1,
+        }
+    }
+
+    /// Close relative to open, as a fraction
+    pub fn change(&self) -> f64 {
+        if self.open == 0.0 {
+            0.0
+        } else {
+            self.close / self.open - 1.0
+        }
+    }
+}
+
+/// Price samples of every pool seen, in time order
+#[derive(Debug, Clone)]
+pub struct PriceHistory {
+    capacity: usize,
+    pools: HashMap<String, VecDeque<PricePoint>>,
+}
+
+impl Default for PriceHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_PRICE_HISTORY_CAPACITY)
+    }
+}
+
+impl PriceHistory {
+    /// History keeping the latest `capacity` samples of each pool
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            pools: HashMap::new(),
+        }
+    }
+
+    /// Record the price of `pool_id` at `time`; prices that are not positive
+    /// and finite are ignored
+    pub fn record(&mut self, pool_id: &str, time: DateTime<Utc>, price: f64) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+        let points = self.pools.entry(pool_id.to_string()).or_default();
+        // Swap events and snapshots can arrive slightly out of order
+        let index = points.partition_point(|point| point.time <= time);
+        points.insert(index, PricePoint { time, price });
+        while points.len() > self.capacity {
+            points.pop_front();
+        }
+    }
+
+    /// Record the spot price of a pool snapshot, returning it
+    pub fn record_pool(&mut self, pool: &PoolInfoResponse, time: DateTime<Utc>) -> Option<f64> {
+        let price = pool_spot_price(pool).ok()?;
+        self.record(&pool.pool_info.pool_identifier, time, price);
+        Some(price)
+    }
+
+    /// Record the execution price of a swap in `pool`, returning it
+    ///
+    /// Events of other pools or actions are ignored.
+    pub fn record_swap(
+        &mut self,
+        pool: &PoolInfoResponse,
+        event: &ChainEvent,
+        time: DateTime<Utc>,
+    ) -> Option<f64> {
+        let ContractEvent::Swap {
+            pool_identifier: Some(pool_identifier),
+            offer_denom: Some(offer_denom),
+            offer_amount: Some(offer_amount),
+            ask_denom: Some(ask_denom),
+            return_amount: Some(return_amount),
+            ..
+        } = &event.detail
+        else {
+            return None;
+        };
+        let info = &pool.pool_info;
+        if *pool_identifier != info.pool_identifier {
+            return None;
+        }
+        let whole = |denom: &str, amount: &str| -> Option<(usize, f64)> {
+            let index = info.asset_denoms.iter().position(|d| d == denom)?;
+            let decimals = info.asset_decimals.get(index).copied().unwrap_or(0);
+            let amount: u128 = amount.parse().ok()?;
+            Some((index, amount as f64 / 10f64.powi(decimals as i32)))
+        };
+        let (offer_index, offered) = whole(offer_denom, offer_amount)?;
+        let (ask_index, returned) = whole(ask_denom, return_amount)?;
+        let price = match (offer_index, ask_index) {
+            (0, 1) => returned / offered,
+            (1, 0) => offered / returned,
+            _ => return None,
+        };
+        if !price.is_finite() {
+            return None;
+        }
+        self.record(pool_identifier, time, price);
+        Some(price)
+    }
+
+    /// Samples of `pool_id`, oldest first
+    pub fn points(&self, pool_id: &str) -> impl Iterator<Item = &PricePoint> {
+        self.pools.get(pool_id).into_iter().flatten()
+    }
+
+    /// Latest sample of `pool_id`
+    pub fn latest(&self, pool_id: &str) -> Option<PricePoint> {
+        self.pools.get(pool_id)?.back().copied()
+    }
+
+    /// The latest `limit` candles of `pool_id`, oldest first
+    pub fn candles(&self, pool_id: &str, timeframe: Timeframe, limit: usize) -> Vec<Candle> {
+        let mut candles: Vec<Candle> = Vec::new();
+        for point in self.points(pool_id) {
+            let open_time = timeframe.bucket_start(point.time);
+            match candles.last_mut() {
+                Some(candle) if candle.open_time == open_time => {
+                    candle.high = candle.high.max(point.price);
+                    candle.low = candle.low.min(point.price);
+                    candle.close = point.price;
+                    candle.samples += 1;
+                }
+                _ => candles.push(Candle::new(open_time, point.price)),
+            }
+        }
+        let skip = candles.len().saturating_sub(limit);
+        candles.split_off(skip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::cosmos_analyzer::CosmosProtocol;
+    use cosmwasm_std::{Coin, Decimal};
+    use mantra_dex_std::{
+        fee::{Fee, PoolFee},
+        pool_manager::{PoolInfo, PoolStatus, PoolType},
+    };
+
+    fn pool(reserves: [u128; 2]) -> PoolInfoResponse {
+        let fee = Fee {
+            share: Decimal::zero(),
+        };
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: "o.uom.uusdc".to_string(),
+                asset_denoms: vec!["uom".to_string(), "uusdc".to_string()],
+                lp_denom: "lp".to_string(),
+                asset_decimals: vec![6, 6],
+                assets: vec![
+                    Coin::new(reserves[0], "uom"),
+                    Coin::new(reserves[1], "uusdc"),
+                ],
+                pool_type: PoolType::ConstantProduct,
+                pool_fees: PoolFee {
+                    protocol_fee: fee.clone(),
+                    swap_fee: fee.clone(),
+                    burn_fee: fee,
+                    extra_fees: vec![],
+                },
+                status: PoolStatus {
+                    swaps_enabled: true,
+                    deposits_enabled: true,
+                    withdrawals_enabled: true,
+                },
+            },
+            total_share: Coin::new(1u128, "lp"),
+        }
+    }
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn test_samples_become_candles() {
+        let mut history = PriceHistory::new(100);
+        let pool = pool([1_000_000_000, 2_000_000_000]);
+        assert_eq!(history.record_pool(&pool, at(0)), Some(2.0));
+
+        // Selling uusdc for uom is still priced in uusdc per uom
+        let swap = ChainEvent {
+            height: 10,
+            tx_hash: "AB".to_string(),
+            contract: "mantra1poolmanager".to_string(),
+            protocol: CosmosProtocol::Dex,
+            action: "swap".to_string(),
+            detail: ContractEvent::Swap {
+                sender: None,
+                pool_identifier: Some("o.uom.uusdc".to_string()),
+                offer_denom: Some("uusdc".to_string()),
+                offer_amount: Some("2100000".to_string()),
+                ask_denom: Some("uom".to_string()),
+                return_amount: Some("1000000".to_string()),
+            },
+            attributes: Default::default(),
+        };
+        assert_eq!(history.record_swap(&pool, &swap, at(70)), Some(2.1));
+        history.record(&pool.pool_info.pool_identifier, at(30), 1.9);
+        history.record(&pool.pool_info.pool_identifier, at(80), f64::NAN);
+
+        let minutes = history.candles("o.uom.uusdc", Timeframe::OneMinute, 10);
+        assert_eq!(minutes.len(), 2);
+        assert_eq!(
+            (minutes[0].open, minutes[0].low, minutes[0].close),
+            (2.0, 1.9, 1.9)
+        );
+        assert_eq!(minutes[1].samples, 1);
+        assert_eq!(
+            history.candles("o.uom.uusdc", Timeframe::OneMinute, 1)[0].close,
+            2.1
+        );
+
+        let hour = history.candles("o.uom.uusdc", Timeframe::OneHour, 10);
+        assert_eq!(hour.len(), 1);
+        assert_eq!((hour[0].high, hour[0].samples), (2.1, 3));
+        assert_eq!(Timeframe::OneDay.next(), Timeframe::OneMinute);
+        assert!(history
+            .candles("o.other", Timeframe::OneMinute, 10)
+            .is_empty());
+    }
+}
@@ -0,0 +1,302 @@
+/// Pool state snapshots for simulating swaps without a query per pool
+///
+/// A [`PoolStateSnapshot`] holds the reserves and fees of every pool as of one
+/// block height, fetched in a single paginated pass. Swaps and multi-hop
+/// routes are then simulated locally: constant product pools with the pool
+/// manager's integer math, so the result matches its simulation query at that
+/// height, and stable pools along the StableSwap curve in floating point. The
+/// snapshot goes stale as blocks are produced; [`MantraDexClient::pool_snapshot`]
+/// caches one and refreshes it once it falls too many blocks behind.
+///
+/// [`MantraDexClient::pool_snapshot`]: super::MantraDexClient::pool_snapshot
+use chrono::{DateTime, Utc};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::{PoolInfoResponse, PoolType, SimulationResponse};
+use std::collections::BTreeMap;
+
+use super::depth::curve_swap_output;
+use crate::error::Error;
+use crate::protocols::skip::SkipSwapOperation;
+
+/// Blocks a cached snapshot may trail the chain before it is fetched again
+pub const DEFAULT_SNAPSHOT_MAX_BLOCKS_BEHIND: u64 = 5;
+
+/// Reserves and fees of every pool at one block height
+#[derive(Debug, Clone)]
+pub struct PoolStateSnapshot {
+    height: u64,
+    taken_at: DateTime<Utc>,
+    pools: BTreeMap<String, PoolInfoResponse>,
+}
+
+impl PoolStateSnapshot {
+    pub fn new(height: u64, pools: Vec<PoolInfoResponse>) -> Self {
+        Self {
+            height,
+            taken_at: Utc::now(),
+            pools: pools
+                .into_iter()
+                .map(|pool| (pool.pool_info.pool_identifier.clone(), pool))
+                .collect(),
+        }
+    }
+
+    /// Block height the pools were read at
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+
+    pub fn taken_at(&self) -> DateTime<Utc> {
+        self.taken_at
+    }
+
+    /// Blocks the snapshot trails `latest_height` by
+    pub fn blocks_behind(&self, latest_height: u64) -> u64 {
+        latest_height.saturating_sub(self.height)
+    }
+
+    pub fn pool(&self, pool_id: &str) -> Option<&PoolInfoResponse> {
+        self.pools.get(pool_id)
+    }
+
+    pub fn pools(&self) -> impl Iterator<Item = &PoolInfoResponse> {
+        self.pools.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pools.is_empty()
+    }
+
+    /// Whether every pool of a route is in the snapshot
+    pub fn covers(&self, operations: &[SkipSwapOperation]) -> bool {
+        operations
+            .iter()
+            .all(|operation| self.pools.contains_key(&operation.pool))
+    }
+
+    /// Simulate swapping `offer` for `ask_denom` in one pool
+    pub fn simulate_swap(
+        &self,
+        pool_id: &str,
+        offer: &Coin,
+        ask_denom: &str,
+    ) -> Result<SimulationResponse, Error> {
+        let pool = self
+            .pool(pool_id)
+            .ok_or_else(|| Error::Other(format!("Pool {} is not in the snapshot", pool_id)))?;
+        simulate_pool_swap(pool, offer, ask_denom)
+    }
+
+    /// Output of swapping `offer` through every hop of a route in turn
+    ///
+    /// Each hop sees the reserves of the snapshot, so a route through the
+    /// same pool twice is not simulated exactly.
+    pub fn simulate_route(
+        &self,
+        offer: &Coin,
+        operations: &[SkipSwapOperation],
+    ) -> Result<Uint128, Error> {
+        let mut current = offer.clone();
+        for operation in operations {
+            if operation.denom_in != current.denom {
+                return Err(Error::Other(format!(
+                    "Route hop through {} takes {} but receives {}",
+                    operation.pool, operation.denom_in, current.denom
+                )));
+            }
+            let simulation = self.simulate_swap(&operation.pool, &current, &operation.denom_out)?;
+            current = Coin {
+                denom: operation.denom_out.clone(),
+                amount: simulation.return_amount,
+            };
+        }
+        Ok(current.amount)
+    }
+}
+
+/// Simulate swapping `offer` for `ask_denom` against `pool`'s reserves
+///
+/// Every fee is taken from the output, as the pool manager does.
+pub fn simulate_pool_swap(
+    pool: &PoolInfoResponse,
+    offer: &Coin,
+    ask_denom: &str,
+) -> Result<SimulationResponse, Error> {
+    let info = &pool.pool_info;
+    if offer.denom == ask_denom {
+        return Err(Error::Other(format!(
+            "Cannot swap {} for itself",
+            offer.denom
+        )));
+    }
+    let reserve = |denom: &str| {
+        info.assets
+            .iter()
+            .find(|asset| asset.denom == denom)
+            .map(|asset| asset.amount)
+            .ok_or_else(|| {
+                Error::Other(format!(
+                    "Asset {} is not part of pool {}",
+                    denom, info.pool_identifier
+                ))
+            })
+    };
+    let (offer_reserve, ask_reserve) = (reserve(&offer.denom)?, reserve(ask_denom)?);
+    if offer_reserve.is_zero() || ask_reserve.is_zero() {
+        return Err(Error::Other(format!(
+            "Pool {} has no liquidity",
+            info.pool_identifier
+        )));
+    }
+
+    let (gross, ideal) = match info.pool_type {
+        PoolType::ConstantProduct => (
+            ask_reserve.multiply_ratio(offer.amount, offer_reserve + offer.amount),
+            ask_reserve.multiply_ratio(offer.amount, offer_reserve),
+        ),
+        PoolType::StableSwap { .. } => {
+            curve_swap_output(pool, &offer.denom, ask_denom, offer.amount)?
+        }
+    };
+    if gross >= ask_reserve {
+        return Err(Error::Other(format!(
+            "Pool {} cannot pay out {}{}",
+            info.pool_identifier, gross, ask_denom
+        )));
+    }
+
+    let fees = &info.pool_fees;
+    let swap_fee_amount = gross.mul_floor(fees.swap_fee.share);
+    let protocol_fee_amount = gross.mul_floor(fees.protocol_fee.share);
+    let burn_fee_amount = gross.mul_floor(fees.burn_fee.share);
+    let extra_fees_amount = gross.mul_floor(
+        fees.extra_fees
+            .iter()
+            .fold(Decimal::zero(), |total, fee| total + fee.share),
+    );
+    let return_amount = gross
+        .checked_sub(swap_fee_amount + protocol_fee_amount + burn_fee_amount + extra_fees_amount)
+        .map_err(|_| {
+            Error::Other(format!(
+                "Fees of pool {} exceed the swap output",
+                info.pool_identifier
+            ))
+        })?;
+
+    Ok(SimulationResponse {
+        return_amount,
+        slippage_amount: ideal.saturating_sub(gross),
+        swap_fee_amount,
+        protocol_fee_amount,
+        burn_fee_amount,
+        extra_fees_amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mantra_dex_std::{
+        fee::{Fee, PoolFee},
+        pool_manager::{PoolInfo, PoolStatus},
+    };
+
+    fn pool(id: &str, pool_type: PoolType, reserves: [(&str, u128); 2]) -> PoolInfoResponse {
+        let fee = |permille| Fee {
+            share: Decimal::permille(permille),
+        };
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: id.to_string(),
+                asset_denoms: reserves.iter().map(|(d, _)| d.to_string()).collect(),
+                lp_denom: format!("{}.lp", id),
+                asset_decimals: vec![6, 6],
+                assets: reserves
+                    .iter()
+                    .map(|(denom, amount)| Coin::new(*amount, *denom))
+                    .collect(),
+                pool_type,
+                pool_fees: PoolFee {
+                    protocol_fee: fee(1),
+                    swap_fee: fee(2),
+                    burn_fee: fee(0),
+                    extra_fees: vec![],
+                },
+                status: PoolStatus {
+                    swaps_enabled: true,
+                    deposits_enabled: true,
+                    withdrawals_enabled: true,
+                },
+            },
+            total_share: Coin::new(1u128, format!("{}.lp", id)),
+        }
+    }
+
+    fn hop(pool: &str, denom_in: &str, denom_out: &str) -> SkipSwapOperation {
+        SkipSwapOperation {
+            pool: pool.to_string(),
+            denom_in: denom_in.to_string(),
+            denom_out: denom_out.to_string(),
+            interface: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_simulates_pools_and_routes() {
+        let snapshot = PoolStateSnapshot::new(
+            42,
+            vec![
+                pool(
+                    "o.uom.uusdc",
+                    PoolType::ConstantProduct,
+                    [("uom", 1_000_000_000_000), ("uusdc", 250_000_000_000)],
+                ),
+                pool(
+                    "o.uusdc.uusdt",
+                    PoolType::StableSwap { amp: 100 },
+                    [("uusdc", 500_000_000_000), ("uusdt", 500_000_000_000)],
+                ),
+            ],
+        );
+        assert_eq!(snapshot.height(), 42);
+        assert_eq!(snapshot.blocks_behind(50), 8);
+
+        // 1000 OM into 1,000,000 OM : 250,000 USDC
+        let simulation = snapshot
+            .simulate_swap("o.uom.uusdc", &Coin::new(1_000_000_000u128, "uom"), "uusdc")
+            .unwrap();
+        let gross = 249_750_249u128;
+        assert_eq!(simulation.swap_fee_amount.u128(), gross * 2 / 1000);
+        assert_eq!(simulation.protocol_fee_amount.u128(), gross / 1000);
+        assert_eq!(
+            simulation.return_amount.u128(),
+            gross - gross * 2 / 1000 - gross / 1000
+        );
+        assert_eq!(simulation.slippage_amount.u128(), 250_000_000 - gross);
+
+        // The stable hop keeps close to parity at this size
+        let route = [
+            hop("o.uom.uusdc", "uom", "uusdc"),
+            hop("o.uusdc.uusdt", "uusdc", "uusdt"),
+        ];
+        assert!(snapshot.covers(&route));
+        let out = snapshot
+            .simulate_route(&Coin::new(1_000_000_000u128, "uom"), &route)
+            .unwrap()
+            .u128();
+        let usdc = simulation.return_amount.u128();
+        assert!(out < usdc && out > usdc * 996 / 1000, "{} of {}", out, usdc);
+
+        assert!(!snapshot.covers(&[hop("o.other", "uom", "uatom")]));
+        assert!(snapshot
+            .simulate_route(
+                &Coin::new(1u128, "uusdt"),
+                &[hop("o.uom.uusdc", "uom", "uusdc")]
+            )
+            .is_err());
+    }
+}
@@ -1,6 +1,9 @@
 /// DEX-specific types and structures
 use cosmwasm_std::{Coin, Decimal, Uint128};
-use mantra_dex_std::{fee::PoolFee, pool_manager::PoolType};
+use mantra_dex_std::{
+    fee::PoolFee,
+    pool_manager::{PoolType, SimulationResponse},
+};
 use serde::{Deserialize, Serialize};
 
 // Re-export commonly used DEX types
@@ -78,3 +81,141 @@ pub struct DexStats {
     pub daily_volume_usd: Option<Decimal>,
     pub active_farms: u64,
 }
+
+/// Preview of a single-asset liquidity deposit (zap in)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZapInPreview {
+    pub pool_id: String,
+    pub lp_denom: String,
+    /// Asset offered by the user
+    pub offer: Coin,
+    /// Portion of the offer swapped into the other pool asset
+    pub swap_offer: Coin,
+    pub swap_simulation: SimulationResponse,
+    /// Swap output after applying the slippage tolerance
+    pub min_swap_return: Coin,
+    /// Assets deposited as liquidity (remaining offer plus the minimum swap return)
+    pub liquidity_assets: Vec<Coin>,
+    pub expected_lp_tokens: Uint128,
+    pub max_slippage: Decimal,
+}
+
+/// Preview of a withdrawal consolidated into a single asset (zap out)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZapOutPreview {
+    pub pool_id: String,
+    pub lp_amount: Uint128,
+    /// Assets expected from the withdrawal
+    pub withdrawn: Vec<Coin>,
+    /// Withdrawn leg swapped into the target asset
+    pub swap_offer: Coin,
+    pub swap_simulation: SimulationResponse,
+    pub target_denom: String,
+    /// Expected amount of the target asset after the swap
+    pub expected_return: Uint128,
+    /// Target asset amount guaranteed by the slippage tolerance
+    pub min_return: Uint128,
+    pub max_slippage: Decimal,
+}
+
+/// Preview of withdrawing liquidity from a pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawalPreview {
+    pub pool_id: String,
+    pub lp_denom: String,
+    pub lp_amount: Uint128,
+    /// Share of the pool's liquidity the LP tokens redeem
+    pub pool_share: Decimal,
+    /// Assets returned, rounded down as the pool manager does
+    pub assets: Vec<Coin>,
+    /// Fees taken from each returned asset; the pool manager charges none on
+    /// withdrawals
+    pub fees: Vec<Coin>,
+    /// Pool reserves left after the withdrawal
+    pub remaining_reserves: Vec<Coin>,
+    /// Spot price of the second asset per whole unit of the first, before fees
+    pub spot_price_before: Decimal,
+    /// Spot price once the withdrawal settles; `None` when it empties the pool
+    pub spot_price_after: Option<Decimal>,
+    /// Relative move of the spot price, one when the pool is emptied
+    pub price_impact: Decimal,
+    /// Returned assets valued in whole units of the first asset at the spot
+    /// price, along the pool's curve
+    pub value_in_first_asset: Decimal,
+    /// Whether the pool currently accepts withdrawals
+    pub withdrawals_enabled: bool,
+}
+
+/// Size tradable within a price impact level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolDepthLevel {
+    pub max_price_impact: Decimal,
+    /// Largest offer whose price impact stays within the level
+    pub offer_amount: Uint128,
+    /// Return for that offer, before fees
+    pub return_amount: Uint128,
+}
+
+/// Depth of one swap direction of a pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapDirectionDepth {
+    pub offer_denom: String,
+    pub ask_denom: String,
+    /// Units of the ask asset per unit of the offer asset, before fees
+    pub spot_price: Decimal,
+    pub levels: Vec<PoolDepthLevel>,
+}
+
+/// Reserve balance of a stable swap pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StablePoolBalance {
+    /// Share of the pool's value held in each asset, decimals normalized
+    pub shares: Vec<(String, Decimal)>,
+    /// Smallest reserve divided by the largest
+    pub balance_ratio: Decimal,
+    /// Largest deviation of a spot price from 1:1
+    pub max_peg_deviation: Decimal,
+    pub is_imbalanced: bool,
+}
+
+/// Depth and balance analysis of a pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolDepthReport {
+    pub pool_id: String,
+    pub pool_type: String,
+    pub reserves: Vec<Coin>,
+    pub directions: Vec<SwapDirectionDepth>,
+    /// Only set for stable swap pools
+    pub stable_balance: Option<StablePoolBalance>,
+    pub warnings: Vec<String>,
+}
+
+/// Estimated price impact of a swap checked against a warning threshold
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapImpactCheck {
+    pub pool_id: String,
+    pub offer: Coin,
+    pub ask_denom: String,
+    pub price_impact: Decimal,
+    /// Expected return before fees
+    pub expected_return: Uint128,
+    pub threshold: Decimal,
+    /// Largest offer whose price impact stays within the threshold
+    pub max_offer_within_threshold: Uint128,
+    /// Empty unless the swap is large or drains an imbalanced stable pool
+    pub warnings: Vec<String>,
+}
+
+impl SwapImpactCheck {
+    /// Whether the swap should be confirmed before executing
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+}
+
+/// Tokens an address has delegated to one validator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StakedBalance {
+    pub validator: String,
+    pub amount: Coin,
+}
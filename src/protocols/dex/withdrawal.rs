@@ -0,0 +1,191 @@
+/// Liquidity withdrawal previews
+///
+/// The pool manager returns every pool asset in proportion to the LP tokens
+/// burned, rounding each amount down, for constant product and stable pools
+/// alike. Reserves shrink in step, so the spot price barely moves; what moves
+/// it is rounding, and emptying the pool. Spot prices and the value of the
+/// returned assets follow each pool's curve, so stable pools are valued with
+/// the StableSwap invariant rather than their reserve ratio.
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
+
+use super::depth::{pool_denom_price, pool_spot_price, to_decimal};
+use super::types::WithdrawalPreview;
+use super::zap::estimate_withdrawal;
+use crate::error::Error;
+
+/// Preview burning `lp_amount` LP tokens of `pool`
+pub fn preview_withdrawal(
+    pool: &PoolInfoResponse,
+    lp_amount: Uint128,
+) -> Result<WithdrawalPreview, Error> {
+    let info = &pool.pool_info;
+    let total_share = pool.total_share.amount;
+    if lp_amount.is_zero() {
+        return Err(Error::Other(
+            "LP amount must be greater than zero".to_string(),
+        ));
+    }
+    if lp_amount > total_share {
+        return Err(Error::Other(format!(
+            "Pool {} has {} LP tokens outstanding, fewer than the {} withdrawn",
+            info.pool_identifier, total_share, lp_amount
+        )));
+    }
+
+    let assets: Vec<Coin> = info
+        .assets
+        .iter()
+        .map(|reserve| Coin {
+            denom: reserve.denom.clone(),
+            amount: estimate_withdrawal(lp_amount, reserve.amount, total_share),
+        })
+        .collect();
+    let fees = assets
+        .iter()
+        .map(|asset| Coin {
+            denom: asset.denom.clone(),
+            amount: Uint128::zero(),
+        })
+        .collect();
+    let remaining_reserves: Vec<Coin> = info
+        .assets
+        .iter()
+        .zip(&assets)
+        .map(|(reserve, asset)| Coin {
+            denom: reserve.denom.clone(),
+            amount: reserve.amount - asset.amount,
+        })
+        .collect();
+
+    let spot_price_before = pool_spot_price(pool)?;
+    let spot_price_after = if remaining_reserves
+        .iter()
+        .any(|asset| asset.amount.is_zero())
+    {
+        None
+    } else {
+        let mut after = pool.clone();
+        after.pool_info.assets = remaining_reserves.clone();
+        Some(pool_spot_price(&after)?)
+    };
+    let price_impact = match spot_price_after {
+        Some(after) => to_decimal((after - spot_price_before).abs() / spot_price_before),
+        None => Decimal::one(),
+    };
+
+    // Whole units of each asset, priced in the first asset
+    let first = &info.assets[0].denom;
+    let mut value_in_first_asset = 0f64;
+    for (index, asset) in assets.iter().enumerate() {
+        let decimals = info.asset_decimals.get(index).copied().unwrap_or(0);
+        let whole = asset.amount.u128() as f64 / 10f64.powi(decimals as i32);
+        let price = if asset.denom == *first {
+            1.0
+        } else {
+            pool_denom_price(pool, &asset.denom, first)?
+        };
+        value_in_first_asset += whole * price;
+    }
+
+    Ok(WithdrawalPreview {
+        pool_id: info.pool_identifier.clone(),
+        lp_denom: info.lp_denom.clone(),
+        lp_amount,
+        pool_share: Decimal::from_ratio(lp_amount, total_share),
+        assets,
+        fees,
+        remaining_reserves,
+        spot_price_before: to_decimal(spot_price_before),
+        spot_price_after: spot_price_after.map(to_decimal),
+        price_impact,
+        value_in_first_asset: to_decimal(value_in_first_asset),
+        withdrawals_enabled: info.status.withdrawals_enabled,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mantra_dex_std::{
+        fee::{Fee, PoolFee},
+        pool_manager::{PoolInfo, PoolStatus, PoolType},
+    };
+
+    fn pool(
+        pool_type: PoolType,
+        reserves: [(&str, u128); 2],
+        total_share: u128,
+    ) -> PoolInfoResponse {
+        let fee = Fee {
+            share: Decimal::permille(3),
+        };
+        PoolInfoResponse {
+            pool_info: PoolInfo {
+                pool_identifier: "p.1".to_string(),
+                asset_denoms: reserves.iter().map(|(d, _)| d.to_string()).collect(),
+                lp_denom: "lp".to_string(),
+                asset_decimals: vec![6, 6],
+                assets: reserves
+                    .iter()
+                    .map(|(denom, amount)| Coin::new(*amount, *denom))
+                    .collect(),
+                pool_type,
+                pool_fees: PoolFee {
+                    protocol_fee: fee.clone(),
+                    swap_fee: fee.clone(),
+                    burn_fee: fee,
+                    extra_fees: vec![],
+                },
+                status: PoolStatus {
+                    swaps_enabled: true,
+                    deposits_enabled: true,
+                    withdrawals_enabled: false,
+                },
+            },
+            total_share: Coin::new(total_share, "lp"),
+        }
+    }
+
+    #[test]
+    fn test_withdrawal_is_pro_rata_and_valued_along_the_curve() {
+        let cp = pool(
+            PoolType::ConstantProduct,
+            [("uom", 4_000_000_000), ("uusdc", 1_000_000_001)],
+            2_000_000_000,
+        );
+        let preview = preview_withdrawal(&cp, Uint128::new(500_000_000)).unwrap();
+        assert_eq!(preview.pool_share, Decimal::percent(25));
+        assert_eq!(
+            preview.assets,
+            vec![
+                Coin::new(1_000_000_000u128, "uom"),
+                Coin::new(250_000_000u128, "uusdc")
+            ]
+        );
+        assert_eq!(preview.remaining_reserves[1].amount.u128(), 750_000_001);
+        assert!(preview.fees.iter().all(|fee| fee.amount.is_zero()));
+        assert!(preview.price_impact < Decimal::permille(1));
+        // 1000 OM plus 250 USDC at 0.25 USDC per OM
+        let value = preview.value_in_first_asset;
+        assert!(
+            value > Decimal::from_ratio(1999u128, 1u128)
+                && value < Decimal::from_ratio(2001u128, 1u128)
+        );
+        assert!(!preview.withdrawals_enabled);
+
+        // A balanced stable pool is worth close to the sum of its reserves
+        let stable = pool(
+            PoolType::StableSwap { amp: 100 },
+            [("uusdc", 1_000_000_000), ("uusdt", 1_000_000_000)],
+            2_000_000_000,
+        );
+        let preview = preview_withdrawal(&stable, Uint128::new(2_000_000_000)).unwrap();
+        assert_eq!(preview.spot_price_after, None);
+        assert_eq!(preview.price_impact, Decimal::one());
+        assert!(preview.value_in_first_asset > Decimal::from_ratio(1999u128, 1u128));
+
+        assert!(preview_withdrawal(&stable, Uint128::new(2_000_000_001)).is_err());
+        assert!(preview_withdrawal(&stable, Uint128::zero()).is_err());
+    }
+}
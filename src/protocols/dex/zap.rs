@@ -0,0 +1,169 @@
+/// Pool math for single-asset liquidity operations (zaps)
+///
+/// Zapping in swaps part of a single asset into the pool's other asset so the two
+/// legs can be deposited in the pool ratio; zapping out withdraws both assets and
+/// swaps one leg into the other. These helpers size the balancing swap and
+/// estimate pro-rata amounts from pool reserves.
+use cosmwasm_std::{Decimal, Uint128};
+use mantra_dex_std::{fee::PoolFee, pool_manager::PoolType};
+
+use crate::error::Error;
+
+/// Default slippage tolerance for zap operations (1%)
+pub const DEFAULT_ZAP_SLIPPAGE_PERCENT: u64 = 1;
+
+/// Total fee share charged on a swap through the pool
+pub fn total_swap_fee(pool_fees: &PoolFee) -> Decimal {
+    pool_fees
+        .extra_fees
+        .iter()
+        .fold(
+            pool_fees.protocol_fee.share + pool_fees.swap_fee.share + pool_fees.burn_fee.share,
+            |total, fee| total + fee.share,
+        )
+        .min(Decimal::one())
+}
+
+/// Amount of the offered asset to swap so the remainder and the swap output
+/// match the pool ratio
+///
+/// Constant product pools use the closed-form solution with fees deducted from
+/// the swap output, as the pool manager does. Stable swap pools trade close to
+/// 1:1, so the split follows the reserve ratio directly.
+pub fn balancing_swap_amount(
+    pool_type: &PoolType,
+    amount: Uint128,
+    offer_reserve: Uint128,
+    ask_reserve: Uint128,
+    fee: Decimal,
+) -> Result<Uint128, Error> {
+    if offer_reserve.is_zero() || ask_reserve.is_zero() {
+        return Err(Error::Other(
+            "Cannot zap into a pool with empty reserves".to_string(),
+        ));
+    }
+
+    match pool_type {
+        PoolType::ConstantProduct => {
+            let a = amount.u128() as f64;
+            let r = offer_reserve.u128() as f64;
+            let f = decimal_to_f64(fee);
+            if f >= 1.0 {
+                return Err(Error::Other("Pool fee leaves nothing to swap".to_string()));
+            }
+            // Fees are taken from the swap output, which gives
+            // s^2 + s ((2 - f) r - a f) - a r = 0
+            let b = (2.0 - f) * r - a * f;
+            let swap = ((b * b + 4.0 * a * r).sqrt() - b) / 2.0;
+            let swap = Uint128::new(swap.max(0.0).floor() as u128);
+            Ok(swap.min(amount))
+        }
+        PoolType::StableSwap { .. } => {
+            Ok(amount.multiply_ratio(ask_reserve, offer_reserve + ask_reserve))
+        }
+    }
+}
+
+/// LP shares minted for a deposit, limited by the scarcest asset
+///
+/// `deposits` pairs each deposited amount with the pool reserve of that asset.
+pub fn estimate_lp_shares(deposits: &[(Uint128, Uint128)], total_share: Uint128) -> Uint128 {
+    deposits
+        .iter()
+        .filter(|(_, reserve)| !reserve.is_zero())
+        .map(|(amount, reserve)| amount.multiply_ratio(total_share, *reserve))
+        .min()
+        .unwrap_or_default()
+}
+
+/// Pro-rata share of a reserve returned for withdrawing `lp_amount`
+pub fn estimate_withdrawal(lp_amount: Uint128, reserve: Uint128, total_share: Uint128) -> Uint128 {
+    if total_share.is_zero() {
+        return Uint128::zero();
+    }
+    reserve.multiply_ratio(lp_amount, total_share)
+}
+
+/// Minimum amount accepted after applying a slippage tolerance
+pub fn apply_slippage(amount: Uint128, slippage: Decimal) -> Uint128 {
+    amount.mul_floor(Decimal::one() - slippage.min(Decimal::one()))
+}
+
+fn decimal_to_f64(value: Decimal) -> f64 {
+    value.to_string().parse().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Constant product swap output with fee, as the pool manager computes it
+    fn xyk_return(offer: u128, offer_reserve: u128, ask_reserve: u128, fee: f64) -> f64 {
+        let out = ask_reserve as f64 * offer as f64 / (offer_reserve + offer) as f64;
+        out * (1.0 - fee)
+    }
+
+    #[test]
+    fn test_constant_product_split_balances_deposit() {
+        let (ra, rb) = (1_000_000_000u128, 4_000_000_000u128);
+        let amount = Uint128::new(10_000_000);
+        let fee = Decimal::permille(3);
+
+        let swap = balancing_swap_amount(
+            &PoolType::ConstantProduct,
+            amount,
+            Uint128::new(ra),
+            Uint128::new(rb),
+            fee,
+        )
+        .unwrap();
+        // Roughly half is swapped; the exact split depends on price impact and fees
+        assert!(swap > amount.multiply_ratio(49u128, 100u128));
+        assert!(swap < amount.multiply_ratio(51u128, 100u128));
+
+        let out = xyk_return(swap.u128(), ra, rb, 0.003);
+        let remaining = (amount - swap).u128() as f64;
+        let deposit_ratio = remaining / out;
+        let pool_ratio = (ra + swap.u128()) as f64 / (rb as f64 - out);
+        assert!((deposit_ratio / pool_ratio - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_stable_swap_split_and_estimates() {
+        let swap = balancing_swap_amount(
+            &PoolType::StableSwap { amp: 100 },
+            Uint128::new(1_000),
+            Uint128::new(3_000),
+            Uint128::new(1_000),
+            Decimal::zero(),
+        )
+        .unwrap();
+        assert_eq!(swap, Uint128::new(250));
+
+        let shares = estimate_lp_shares(
+            &[
+                (Uint128::new(100), Uint128::new(1_000)),
+                (Uint128::new(100), Uint128::new(2_000)),
+            ],
+            Uint128::new(10_000),
+        );
+        assert_eq!(shares, Uint128::new(500));
+
+        assert_eq!(
+            estimate_withdrawal(Uint128::new(50), Uint128::new(2_000), Uint128::new(1_000)),
+            Uint128::new(100)
+        );
+        assert_eq!(
+            apply_slippage(Uint128::new(1_000), Decimal::percent(1)),
+            Uint128::new(990)
+        );
+        assert!(balancing_swap_amount(
+            &PoolType::ConstantProduct,
+            Uint128::new(1),
+            Uint128::zero(),
+            Uint128::new(1),
+            Decimal::zero()
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,184 @@
+/// Discovery of protocol contracts on the connected chain
+///
+/// A protocol is only usable where its entry contract is deployed. Before a
+/// protocol reports itself available it asks the chain for the contract info
+/// of its configured address and, when the contract registry records the
+/// code ID the contract was instantiated from, checks that it matches.
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{QueryContractInfoRequest, QueryContractInfoResponse};
+use prost::Message;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::transport::{AbciTransport, CONTRACT_INFO_PATH};
+
+/// Contract info the chain reports for an address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeployedContract {
+    pub code_id: u64,
+    pub creator: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub admin: String,
+    pub label: String,
+}
+
+/// What the chain holds at a protocol's configured contract address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ContractStatus {
+    /// A contract, instantiated from the expected code if one is known
+    Deployed,
+    /// No contract at the address
+    Missing,
+    /// A contract instantiated from other code than the registry records
+    CodeIdMismatch { expected: u64, found: u64 },
+}
+
+/// Result of looking up a protocol contract on chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractDiscovery {
+    pub address: String,
+    #[serde(flatten)]
+    pub status: ContractStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract: Option<DeployedContract>,
+}
+
+impl ContractDiscovery {
+    /// Judge what was found at `address` against the code ID expected there
+    pub fn evaluate(
+        address: &str,
+        expected_code_id: Option<u64>,
+        contract: Option<DeployedContract>,
+    ) -> Self {
+        let status = match (&contract, expected_code_id) {
+            (None, _) => ContractStatus::Missing,
+            (Some(found), Some(expected)) if found.code_id != expected => {
+                ContractStatus::CodeIdMismatch {
+                    expected,
+                    found: found.code_id,
+                }
+            }
+            (Some(_), _) => ContractStatus::Deployed,
+        };
+        Self {
+            address: address.to_string(),
+            status,
+            contract,
+        }
+    }
+
+    /// Whether the expected contract is deployed
+    pub fn is_deployed(&self) -> bool {
+        self.status == ContractStatus::Deployed
+    }
+
+    /// Why the contract cannot be used, if it cannot
+    pub fn problem(&self) -> Option<String> {
+        match &self.status {
+            ContractStatus::Deployed => None,
+            ContractStatus::Missing => Some(format!("no contract deployed at {}", self.address)),
+            ContractStatus::CodeIdMismatch { expected, found } => Some(format!(
+                "contract at {} has code ID {}, expected {}",
+                self.address, found, expected
+            )),
+        }
+    }
+}
+
+/// Contract info of `address`, or `None` when no contract is deployed there
+pub async fn query_contract_info<T: AbciTransport + ?Sized>(
+    transport: &T,
+    address: &str,
+) -> Result<Option<DeployedContract>, Error> {
+    let request = QueryContractInfoRequest {
+        address: address.to_string(),
+    };
+    let response = transport
+        .abci_query(CONTRACT_INFO_PATH, request.encode_to_vec(), None)
+        .await
+        .map_err(|e| {
+            Error::Rpc(format!(
+                "Failed to query contract info of {}: {}",
+                address, e
+            ))
+        })?;
+    if !response.is_ok() {
+        let log = response.log.to_lowercase();
+        if log.contains("not found") || log.contains("no such contract") {
+            return Ok(None);
+        }
+        return Err(Error::Rpc(format!(
+            "Contract info query for {} failed: {}",
+            address, response.log
+        )));
+    }
+
+    let info = QueryContractInfoResponse::decode(response.value.as_slice())
+        .map_err(|e| Error::Rpc(format!("Failed to decode contract info response: {}", e)))?
+        .contract_info;
+    Ok(info.map(|info| DeployedContract {
+        code_id: info.code_id,
+        creator: info.creator,
+        admin: info.admin,
+        label: info.label,
+    }))
+}
+
+/// Look up the contract at `address` and judge it against `expected_code_id`
+pub async fn discover_contract<T: AbciTransport + ?Sized>(
+    transport: &T,
+    address: &str,
+    expected_code_id: Option<u64>,
+) -> Result<ContractDiscovery, Error> {
+    let contract = query_contract_info(transport, address).await?;
+    Ok(ContractDiscovery::evaluate(
+        address,
+        expected_code_id,
+        contract,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::{dex::DexProtocol, ProtocolRegistry};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_only_deployed_contracts_make_a_protocol_available() {
+        let contract = DeployedContract {
+            code_id: 822,
+            creator: "mantra1creator".to_string(),
+            admin: String::new(),
+            label: "pool-manager".to_string(),
+        };
+        assert!(
+            ContractDiscovery::evaluate("mantra1pm", Some(822), Some(contract.clone()))
+                .is_deployed()
+        );
+        assert!(
+            ContractDiscovery::evaluate("mantra1pm", None, Some(contract.clone())).is_deployed()
+        );
+
+        let mismatch = ContractDiscovery::evaluate("mantra1pm", Some(900), Some(contract));
+        assert_eq!(
+            mismatch.status,
+            ContractStatus::CodeIdMismatch {
+                expected: 900,
+                found: 822
+            }
+        );
+        assert!(mismatch.problem().unwrap().contains("expected 900"));
+        let missing = ContractDiscovery::evaluate("mantra1pm", Some(822), None);
+        assert_eq!(missing.status, ContractStatus::Missing);
+
+        // A protocol that found no contract is registered but not listed
+        let mut registry = ProtocolRegistry::new();
+        let mut dex = DexProtocol::new();
+        dex.set_pool_manager("mantra1pm".to_string());
+        registry.register(Arc::new(dex));
+        assert!(!registry.get("dex").unwrap().is_deployed());
+        assert!(registry.list().is_empty());
+        assert_eq!(registry.list_registered(), vec!["dex"]);
+    }
+}
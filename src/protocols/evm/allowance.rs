@@ -0,0 +1,211 @@
+/// ERC-20 allowance hygiene
+///
+/// Every `approve` leaves a spender able to move the owner's tokens until the
+/// allowance is spent or set back to zero. The spenders an owner has approved
+/// are found from the `Approval` events naming the owner, and what each may
+/// still spend is read from the token's `allowance`, since transfers by the
+/// spender lower it without another event. Approvals of `2^255` or more are
+/// treated as unlimited: wallets and dApps conventionally approve `2^256 - 1`,
+/// and some tokens never lower such an allowance.
+use alloy_primitives::{Address, B256, U256};
+use alloy_rpc_types_eth::Log;
+use alloy_sol_types::SolEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::protocols::evm::client::EvmClient;
+use crate::protocols::evm::contracts::erc20::IERC20;
+use crate::protocols::evm::types::{EthAddress, EventFilter};
+
+/// Blocks covered by one `eth_getLogs` query of an approval scan
+pub const DEFAULT_APPROVAL_SCAN_CHUNK_BLOCKS: u64 = 10_000;
+
+/// Blocks an approval scan looks back by default
+pub const DEFAULT_APPROVAL_SCAN_BLOCKS: u64 = 200_000;
+
+/// Whether an approval of `amount` is effectively unlimited
+pub fn is_unlimited_approval(amount: U256) -> bool {
+    amount >= U256::MAX >> 1
+}
+
+/// What approving an unlimited amount does
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfiniteApprovalPolicy {
+    /// Approve it like any other amount
+    Allow,
+    /// Approve it, with a warning in the result
+    #[default]
+    Warn,
+    /// Refuse it
+    Block,
+}
+
+impl InfiniteApprovalPolicy {
+    /// Warning to report for approving `amount` to `spender`, or an error when
+    /// the policy blocks it
+    pub fn check(&self, spender: Address, amount: U256) -> Result<Option<String>, Error> {
+        if !is_unlimited_approval(amount) {
+            return Ok(None);
+        }
+        match self {
+            Self::Allow => Ok(None),
+            Self::Warn => Ok(Some(format!(
+                "Unlimited approval: {:#x} can spend every token of this kind the wallet ever \
+                 holds until the approval is revoked",
+                spender
+            ))),
+            Self::Block => Err(Error::Other(format!(
+                "Unlimited approvals are blocked by policy; approve {:#x} for the amount it needs",
+                spender
+            ))),
+        }
+    }
+}
+
+impl FromStr for InfiniteApprovalPolicy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "allow" => Ok(Self::Allow),
+            "warn" => Ok(Self::Warn),
+            "block" => Ok(Self::Block),
+            other => Err(Error::Other(format!(
+                "Unknown infinite approval policy '{}', expected allow, warn or block",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for InfiniteApprovalPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Block => "block",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Latest `Approval` event of one token and spender
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalEvent {
+    pub token: Address,
+    pub spender: Address,
+    /// Amount approved by the event
+    pub amount: U256,
+    pub block_number: Option<u64>,
+    pub tx_hash: Option<B256>,
+}
+
+/// ERC-20 `Approval` event in `log`
+///
+/// ERC-721 emits an event of the same signature with the token ID as a third
+/// indexed topic; those logs are skipped.
+pub fn decode_approval(log: &Log) -> Option<ApprovalEvent> {
+    let topics = log.topics();
+    if topics.len() != 3 || topics[0] != IERC20::Approval::SIGNATURE_HASH {
+        return None;
+    }
+    let data = &log.data().data;
+    if data.len() < 32 {
+        return None;
+    }
+    Some(ApprovalEvent {
+        token: log.address(),
+        spender: Address::from_word(topics[2]),
+        amount: U256::from_be_slice(&data[..32]),
+        block_number: log.block_number,
+        tx_hash: log.transaction_hash,
+    })
+}
+
+/// Latest approval of each token and spender among `logs`, oldest first
+pub fn latest_approvals(logs: &[Log]) -> Vec<ApprovalEvent> {
+    let mut latest: BTreeMap<(Address, Address), ApprovalEvent> = BTreeMap::new();
+    for event in logs.iter().filter_map(decode_approval) {
+        latest.insert((event.token, event.spender), event);
+    }
+    let mut events: Vec<_> = latest.into_values().collect();
+    events.sort_by_key(|event| event.block_number);
+    events
+}
+
+/// `Approval` events of `owner` between `from_block` and `to_block`, of
+/// `token` only when given, queried `chunk_blocks` blocks at a time
+///
+/// Scans of one token go through the client's log cache, so blocks an
+/// earlier scan covered are not queried again.
+pub async fn scan_approvals(
+    client: &EvmClient,
+    owner: Address,
+    token: Option<Address>,
+    from_block: u64,
+    to_block: u64,
+    chunk_blocks: u64,
+) -> Result<Vec<ApprovalEvent>, Error> {
+    let chunk_blocks = chunk_blocks.max(1);
+    let mut logs = Vec::new();
+    let mut start = from_block;
+    while start <= to_block {
+        let end = start.saturating_add(chunk_blocks - 1).min(to_block);
+        let filter = EventFilter::new()
+            .addresses(token.map(EthAddress).into_iter().collect())
+            .topics(vec![
+                Some(IERC20::Approval::SIGNATURE_HASH),
+                Some(owner.into_word()),
+            ])
+            .block_range(Some(format!("{:#x}", start)), Some(format!("{:#x}", end)));
+        logs.extend(client.get_logs_cached(filter).await?);
+        start = end + 1;
+    }
+    Ok(latest_approvals(&logs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infinite_approval_policy() {
+        let spender = Address::repeat_byte(0x11);
+        let finite = U256::from(1_000_000u64);
+        assert!(!is_unlimited_approval(finite));
+        assert!(is_unlimited_approval(U256::MAX));
+
+        for policy in [
+            InfiniteApprovalPolicy::Allow,
+            InfiniteApprovalPolicy::Warn,
+            InfiniteApprovalPolicy::Block,
+        ] {
+            assert_eq!(policy.check(spender, finite).unwrap(), None);
+            assert_eq!(
+                policy
+                    .to_string()
+                    .parse::<InfiniteApprovalPolicy>()
+                    .unwrap(),
+                policy
+            );
+        }
+        assert_eq!(
+            InfiniteApprovalPolicy::Allow
+                .check(spender, U256::MAX)
+                .unwrap(),
+            None
+        );
+        assert!(InfiniteApprovalPolicy::Warn
+            .check(spender, U256::MAX)
+            .unwrap()
+            .is_some());
+        assert!(InfiniteApprovalPolicy::Block
+            .check(spender, U256::MAX)
+            .is_err());
+        assert!("deny".parse::<InfiniteApprovalPolicy>().is_err());
+    }
+}
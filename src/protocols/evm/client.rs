@@ -1,12 +1,33 @@
 #[cfg(feature = "evm")]
+use crate::endpoints::{CircuitBreakerConfig, EndpointHealth, EndpointPool};
+#[cfg(feature = "evm")]
 use crate::error::Error;
 #[cfg(feature = "evm")]
+use crate::pagination::{Page, PageRequest, Paginator};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::log_cache::{
+    LogCache, LogCacheKey, LOG_SCAN_CHUNK_BLOCKS, REORG_SEARCH_BLOCKS,
+};
+#[cfg(feature = "evm")]
+use crate::protocols::evm::transaction_decoder::TransactionDecoder;
+#[cfg(feature = "evm")]
 use crate::protocols::evm::tx::{Eip1559Transaction, SignedEip1559Transaction};
 #[cfg(feature = "evm")]
 use crate::protocols::evm::types::{
-    Eip1559FeeSuggestion, EthAddress, EventFilter, EvmCallRequest, EvmError, EvmTransactionRequest,
+    Eip1559FeeSuggestion, EthAddress, EventFilter, EvmCallRequest, EvmError, EvmSyncStatus,
+    EvmTransactionRequest,
 };
 #[cfg(feature = "evm")]
+use crate::reorg::{ReorgDetected, ReorgLog};
+#[cfg(feature = "evm")]
+use crate::retry::{Classify, RetryPolicy};
+#[cfg(feature = "evm")]
+use crate::signing_log::{IntentMessage, SigningLog, SigningOutcome};
+#[cfg(feature = "evm")]
+use crate::trace;
+#[cfg(feature = "evm")]
+use crate::tx_queue::TxVm;
+#[cfg(feature = "evm")]
 use alloy_primitives::{Address, Bytes, B256, U256};
 /// EVM Client for MANTRA SDK
 ///
@@ -15,45 +36,213 @@ use alloy_primitives::{Address, Bytes, B256, U256};
 #[cfg(feature = "evm")]
 use alloy_provider::{PendingTransactionBuilder, Provider, ProviderBuilder};
 #[cfg(feature = "evm")]
-use alloy_rpc_types_eth::{BlockId, BlockNumberOrTag, Filter, Log, TransactionRequest};
+use alloy_rpc_types_eth::{
+    BlockId, BlockNumberOrTag, BlockTransactionsKind, Filter, Log, SyncStatus, TransactionRequest,
+};
 #[cfg(feature = "evm")]
 use alloy_sol_types::SolCall;
 #[cfg(feature = "evm")]
 use alloy_transport_http::{Client, Http};
 #[cfg(feature = "evm")]
-use std::time::Duration;
+use futures::Stream;
+#[cfg(feature = "evm")]
+use std::future::Future;
+#[cfg(feature = "evm")]
+use std::sync::Arc;
+#[cfg(feature = "evm")]
+use std::time::{Duration, Instant};
+#[cfg(feature = "evm")]
+use tracing::Instrument;
+
+#[cfg(feature = "evm")]
+type HttpProvider = alloy_provider::RootProvider<Http<Client>>;
 
 /// EVM Client for blockchain interactions
 #[cfg(feature = "evm")]
 #[derive(Clone)]
 pub struct EvmClient {
-    /// Alloy provider for RPC communication
-    provider: alloy_provider::RootProvider<Http<Client>>,
+    /// Alloy provider of each RPC endpoint, the primary first
+    providers: Vec<(String, HttpProvider)>,
+    /// Circuit breakers of the RPC endpoints
+    endpoints: Arc<EndpointPool>,
     /// Chain ID for transaction signing
     chain_id: u64,
     /// Token metadata cache (shared across clones)
     token_metadata_cache: std::sync::Arc<crate::protocols::evm::token_metadata::TokenMetadataCache>,
+    /// Retries of reads that fail transiently
+    retry_policy: RetryPolicy,
+    /// Queue signed transactions are persisted to before broadcast
+    tx_queue: Option<crate::tx_queue::TxQueue>,
+    /// Bytes appended to the calldata of every contract transaction
+    data_suffix: Option<Vec<u8>>,
+    /// Logs already scanned, reused by [`Self::get_logs_cached`]
+    log_cache: Option<Arc<LogCache>>,
 }
 
 #[cfg(feature = "evm")]
 impl EvmClient {
     /// Create a new EVM client with the given RPC endpoint and chain ID
     pub async fn new(rpc_url: &str, chain_id: u64) -> Result<Self, Error> {
-        let url = reqwest::Url::parse(rpc_url)
-            .map_err(|e| Error::Config(format!("Invalid RPC URL: {}", e)))?;
-        let provider = ProviderBuilder::new().on_http(url);
+        let provider = Self::connect(rpc_url)?;
 
         Ok(Self {
-            provider,
+            providers: vec![(rpc_url.to_string(), provider)],
+            endpoints: Arc::new(EndpointPool::new(
+                [rpc_url.to_string()],
+                CircuitBreakerConfig::default(),
+            )),
             chain_id,
             token_metadata_cache: std::sync::Arc::new(
                 crate::protocols::evm::token_metadata::TokenMetadataCache::new(),
             ),
+            retry_policy: RetryPolicy::default(),
+            tx_queue: None,
+            data_suffix: None,
+            log_cache: None,
         })
     }
 
-    /// Execute a read-only contract call
+    fn connect(rpc_url: &str) -> Result<HttpProvider, Error> {
+        let url = reqwest::Url::parse(rpc_url)
+            .map_err(|e| Error::Config(format!("Invalid RPC URL: {}", e)))?;
+        Ok(ProviderBuilder::new().on_http(url))
+    }
+
+    /// Set how reads that fail transiently are retried
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Persist signed transactions to `queue` before broadcasting them, so
+    /// they can be monitored again after a restart
+    pub fn with_tx_queue(mut self, queue: crate::tx_queue::TxQueue) -> Self {
+        self.tx_queue = Some(queue);
+        self
+    }
+
+    /// Append `suffix` to the calldata of every contract transaction sent
+    ///
+    /// ABI decoding ignores trailing bytes, so tagged calls execute as
+    /// untagged ones do.
+    pub fn with_data_suffix(mut self, suffix: Option<Vec<u8>>) -> Self {
+        self.data_suffix = suffix.filter(|suffix| !suffix.is_empty());
+        self
+    }
+
+    /// Keep scanned logs in `cache`, so [`Self::get_logs_cached`] only
+    /// fetches blocks it has not seen
+    pub fn with_log_cache(mut self, cache: Arc<LogCache>) -> Self {
+        self.log_cache = Some(cache);
+        self
+    }
+
+    /// Tag contract transactions as `tagging` asks
+    pub fn with_tx_tagging(self, tagging: &crate::tx_tag::TxTagging) -> Self {
+        let suffix = tagging.evm_suffix(self.chain_id);
+        self.with_data_suffix(suffix)
+    }
+
+    /// `data` with the configured suffix appended
+    fn tagged_calldata(&self, mut data: Vec<u8>) -> Vec<u8> {
+        if let Some(suffix) = &self.data_suffix {
+            data.extend_from_slice(suffix);
+        }
+        data
+    }
+
+    /// Add RPC endpoints to fail over to when the primary one is unhealthy
+    pub fn with_fallback_rpc_urls(mut self, urls: &[String]) -> Result<Self, Error> {
+        for url in urls {
+            if self.providers.iter().all(|(known, _)| known != url) {
+                self.providers.push((url.clone(), Self::connect(url)?));
+            }
+        }
+        let config = self.endpoints.config().clone();
+        Ok(self.with_circuit_breaker(config))
+    }
+
+    /// Set when an RPC endpoint's circuit opens and for how long
+    pub fn with_circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        let urls = self.providers.iter().map(|(url, _)| url.clone());
+        self.endpoints = Arc::new(EndpointPool::new(urls, config));
+        self
+    }
+
+    /// Share the circuit breakers of `endpoints`, such as those of another
+    /// client of the same chain
+    pub fn with_endpoint_pool(mut self, endpoints: Arc<EndpointPool>) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Circuit breakers of the RPC endpoints
+    pub fn endpoint_pool(&self) -> &Arc<EndpointPool> {
+        &self.endpoints
+    }
+
+    /// Health of each RPC endpoint, the primary first
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
+    /// Provider of the preferred healthy endpoint
+    fn provider(&self) -> &HttpProvider {
+        &self.preferred().1
+    }
+
+    /// Preferred endpoint and its provider
+    fn preferred(&self) -> &(String, HttpProvider) {
+        self.endpoints
+            .preferred()
+            .and_then(|url| self.providers.iter().find(|(known, _)| *known == url))
+            .unwrap_or(&self.providers[0])
+    }
+
+    /// Run `call` on the next healthy endpoint, recording the outcome with the
+    /// endpoint's circuit breaker
+    ///
+    /// Only failures that are worth retrying count against the endpoint.
+    async fn on_endpoint<T, F, Fut>(&self, method: &str, call: F) -> Result<T, Error>
+    where
+        F: FnOnce(HttpProvider) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let (url, provider) = self
+            .endpoints
+            .next()
+            .and_then(|url| self.providers.iter().find(|(known, _)| *known == url))
+            .unwrap_or(&self.providers[0])
+            .clone();
+        let started = Instant::now();
+        let result = call(provider)
+            .instrument(tracing::debug_span!("rpc", method, endpoint = %url))
+            .await
+            .map_err(|e| e.at_endpoint(&url));
+        trace::record_rpc(
+            method,
+            &url,
+            started,
+            result.as_ref().err().map(|e| e.to_string()),
+        );
+        match &result {
+            Ok(_) => self.endpoints.record_success(&url),
+            Err(e) if e.is_retryable() => {
+                self.endpoints.record_failure(&url, &e.to_string());
+            }
+            Err(_) => {}
+        }
+        result
+    }
+
+    /// Execute a read-only contract call, retrying transient failures
     pub async fn call(&self, request: EvmCallRequest) -> Result<Vec<u8>, Error> {
+        self.retry_policy
+            .run("EVM call", || self.call_once(request.clone()))
+            .await
+    }
+
+    async fn call_once(&self, request: EvmCallRequest) -> Result<Vec<u8>, Error> {
         let tx_request = TransactionRequest {
             to: Some(alloy_primitives::TxKind::Call(request.to.0)),
             input: request.data.into(),
@@ -66,14 +255,15 @@ impl EvmClient {
             .and_then(|b| b.parse::<BlockNumberOrTag>().ok())
             .unwrap_or(BlockNumberOrTag::Latest);
 
-        let result = self
-            .provider
-            .call(&tx_request)
-            .block(BlockId::Number(block))
-            .await
-            .map_err(|e| EvmError::RpcError(e.to_string()))?;
-
-        Ok(result.to_vec())
+        self.on_endpoint("eth_call", |provider| async move {
+            let result = provider
+                .call(&tx_request)
+                .block(BlockId::Number(block))
+                .await
+                .map_err(|e| EvmError::RpcError(e.to_string()))?;
+            Ok(result.to_vec())
+        })
+        .await
     }
 
     /// Estimate gas for a transaction
@@ -89,7 +279,7 @@ impl EvmClient {
         block: Option<BlockNumberOrTag>,
     ) -> Result<u64, Error> {
         let rpc_request = request.to_rpc_request(from);
-        let mut call = self.provider.estimate_gas(&rpc_request);
+        let mut call = self.provider().estimate_gas(&rpc_request);
         if let Some(block) = block {
             call = call.block(BlockId::Number(block));
         }
@@ -100,19 +290,85 @@ impl EvmClient {
 
     /// Get the current block number
     pub async fn get_block_number(&self) -> Result<u64, Error> {
-        let block_number = self
-            .provider
-            .get_block_number()
+        self.retry_policy
+            .run("EVM block number query", || {
+                self.on_endpoint("eth_blockNumber", |provider| async move {
+                    let block_number = provider
+                        .get_block_number()
+                        .await
+                        .map_err(|e| EvmError::RpcError(e.to_string()))?;
+                    Ok(block_number)
+                })
+            })
+            .await
+    }
+
+    /// Get the number and timestamp (unix seconds) of the latest block
+    pub async fn get_latest_block_time(&self) -> Result<(u64, i64), Error> {
+        let block = self
+            .provider()
+            .get_block_by_number(BlockNumberOrTag::Latest, false)
+            .await
+            .map_err(|e| EvmError::RpcError(e.to_string()))?
+            .ok_or_else(|| EvmError::RpcError("Latest block not found".to_string()))?;
+
+        Ok((block.header.number, block.header.timestamp as i64))
+    }
+
+    /// Get a block by number, tag or hash, with its full transactions when
+    /// `full` is set and only their hashes otherwise
+    pub async fn get_block(
+        &self,
+        block: BlockId,
+        full: bool,
+    ) -> Result<Option<alloy_rpc_types_eth::Block>, Error> {
+        let kind = if full {
+            BlockTransactionsKind::Full
+        } else {
+            BlockTransactionsKind::Hashes
+        };
+        let block = self
+            .provider()
+            .get_block(block, kind)
             .await
             .map_err(|e| EvmError::RpcError(e.to_string()))?;
 
-        Ok(block_number)
+        Ok(block)
+    }
+
+    /// Get the chain ID the node reports, which should match [`Self::chain_id`]
+    pub async fn get_node_chain_id(&self) -> Result<u64, Error> {
+        let chain_id = self
+            .provider()
+            .get_chain_id()
+            .await
+            .map_err(|e| EvmError::RpcError(e.to_string()))?;
+
+        Ok(chain_id)
+    }
+
+    /// Get the sync status of the node
+    pub async fn get_sync_status(&self) -> Result<EvmSyncStatus, Error> {
+        let status = self
+            .provider()
+            .syncing()
+            .await
+            .map_err(|e| EvmError::RpcError(e.to_string()))?;
+
+        Ok(match status {
+            SyncStatus::Info(info) => EvmSyncStatus {
+                syncing: true,
+                current_block: u64::try_from(info.current_block).ok(),
+                highest_block: u64::try_from(info.highest_block).ok(),
+            },
+            SyncStatus::None => EvmSyncStatus::default(),
+        })
     }
 
     /// Get the current gas price (legacy)
     pub async fn get_gas_price(&self) -> Result<U256, Error> {
         let gas_price = self
-            .provider
+            .provider()
             .get_gas_price()
             .await
             .map_err(|e| EvmError::RpcError(e.to_string()))?;
@@ -124,7 +380,7 @@ impl EvmClient {
     pub async fn get_fee_data(&self) -> Result<(U256, U256), Error> {
         // Get fee history for the last block
         let fee_history = self
-            .provider
+            .provider()
             .get_fee_history(1, BlockNumberOrTag::Latest, &[50.0])
             .await
             .map_err(|e| EvmError::RpcError(e.to_string()))?;
@@ -148,7 +404,7 @@ impl EvmClient {
     /// Provide EIP-1559 fee suggestions using provider heuristics.
     pub async fn fee_suggestion(&self) -> Result<Eip1559FeeSuggestion, Error> {
         let estimation = self
-            .provider
+            .provider()
             .estimate_eip1559_fees(None)
             .await
             .map_err(|e| EvmError::RpcError(e.to_string()))?;
@@ -169,8 +425,14 @@ impl EvmClient {
         })
     }
 
-    /// Query event logs
+    /// Query event logs, retrying transient failures
     pub async fn get_logs(&self, filter: EventFilter) -> Result<Vec<Log>, Error> {
+        self.retry_policy
+            .run("EVM log query", || self.get_logs_once(filter.clone()))
+            .await
+    }
+
+    async fn get_logs_once(&self, filter: EventFilter) -> Result<Vec<Log>, Error> {
         // Convert topics to alloy format
         let topics: [alloy_rpc_types_eth::Topic; 4] = {
             let mut topic_array = [
@@ -202,15 +464,163 @@ impl EvmClient {
             topics,
         };
 
-        let logs = self
-            .provider
-            .get_logs(&alloy_filter)
-            .await
-            .map_err(|e| EvmError::RpcError(e.to_string()))?;
+        self.on_endpoint("eth_getLogs", |provider| async move {
+            let logs = provider
+                .get_logs(&alloy_filter)
+                .await
+                .map_err(|e| EvmError::RpcError(e.to_string()))?;
+            Ok(logs)
+        })
+        .await
+    }
+
+    /// Query the event logs of one block range, `request.limit` blocks long
+    ///
+    /// The cursor is the last block already scanned; the first page starts at
+    /// the filter's `from_block` (genesis when unset) and no page runs past
+    /// `last_block`.
+    pub async fn get_logs_page(
+        &self,
+        filter: &EventFilter,
+        request: PageRequest<u64>,
+        last_block: u64,
+    ) -> Result<Page<Log, u64>, Error> {
+        let from = match request.cursor {
+            Some(scanned) => scanned + 1,
+            None => block_number(filter.from_block.as_deref()).unwrap_or(0),
+        };
+        if from > last_block {
+            return Ok(Page {
+                items: Vec::new(),
+                next_cursor: None,
+            });
+        }
+        let to = from
+            .saturating_add(u64::from(request.limit.max(1)) - 1)
+            .min(last_block);
+
+        let mut window = filter.clone();
+        window.from_block = Some(format!("{:#x}", from));
+        window.to_block = Some(format!("{:#x}", to));
+        let logs = self.get_logs(window).await?;
+
+        Ok(Page {
+            items: logs,
+            next_cursor: (to < last_block).then_some(to),
+        })
+    }
+
+    /// Stream the event logs matching `filter`, querying `blocks_per_page`
+    /// blocks at a time
+    ///
+    /// Ranges end at the filter's `to_block`, or at the latest block when it
+    /// is unset, which keeps each query under the node's log range limit.
+    pub async fn logs_stream(
+        &self,
+        filter: EventFilter,
+        blocks_per_page: u32,
+    ) -> Result<impl Stream<Item = Result<Log, Error>> + '_, Error> {
+        let last_block = match block_number(filter.to_block.as_deref()) {
+            Some(block) => block,
+            None => self.get_block_number().await?,
+        };
+        Ok(Paginator::new(blocks_per_page).stream(move |request| {
+            let filter = filter.clone();
+            async move { self.get_logs_page(&filter, request, last_block).await }
+        }))
+    }
+
+    /// Query event logs through the log cache, fetching only the blocks it
+    /// has not scanned yet
+    ///
+    /// Filters on one contract are cached by its address and the filter's
+    /// first topic; other filters, block tags other than the latest block,
+    /// and clients without a cache go to [`Self::get_logs`]. When the highest
+    /// scanned block was reorganized away, the entry is cut back to its
+    /// newest log still on chain, the reorg is recorded with the dropped
+    /// logs' transactions, and the rest is scanned again.
+    pub async fn get_logs_cached(&self, filter: EventFilter) -> Result<Vec<Log>, Error> {
+        let (Some(cache), Some(key)) = (&self.log_cache, LogCacheKey::of(self.chain_id, &filter))
+        else {
+            return self.get_logs(filter).await;
+        };
+        let from = match filter.from_block.as_deref() {
+            None => 0,
+            Some(block) => match block_number(Some(block)) {
+                Some(block) => block,
+                None => return self.get_logs(filter).await,
+            },
+        };
+        let to = match filter.to_block.as_deref() {
+            None | Some("latest") => self.get_block_number().await?,
+            Some(block) => match block_number(Some(block)) {
+                Some(block) => block,
+                None => return self.get_logs(filter).await,
+            },
+        };
+        if from > to {
+            return Ok(Vec::new());
+        }
+
+        let mut entry = cache.get(&key);
+        if let Some((number, hash)) = entry.tip {
+            let canonical = self.block_hash(number).await?;
+            if canonical != Some(hash) {
+                // Keep what is below the newest cached log still on chain
+                let mut kept = None;
+                for (block, block_hash) in entry.log_blocks().into_iter().take(REORG_SEARCH_BLOCKS)
+                {
+                    if self.block_hash(block).await? == Some(block_hash) {
+                        kept = Some((block, block_hash));
+                        break;
+                    }
+                }
+                let dropped = entry.rewind(kept);
+                let mut tx_hashes: Vec<B256> = dropped
+                    .iter()
+                    .filter_map(|log| log.transaction_hash)
+                    .collect();
+                tx_hashes.dedup();
+                self.report_reorg(number, hash, canonical, tx_hashes);
+            }
+        }
 
+        let mut scan = filter.clone();
+        scan.topics = vec![key.topic0];
+        for (start, end) in entry.missing(from, to) {
+            let mut chunk_start = start;
+            while chunk_start <= end {
+                let chunk_end = chunk_start
+                    .saturating_add(LOG_SCAN_CHUNK_BLOCKS - 1)
+                    .min(end);
+                scan.from_block = Some(format!("{:#x}", chunk_start));
+                scan.to_block = Some(format!("{:#x}", chunk_end));
+                let logs = self.get_logs(scan.clone()).await?;
+                entry.insert(chunk_start, chunk_end, logs);
+                chunk_start = chunk_end + 1;
+            }
+        }
+        if entry.tip.is_none_or(|(number, _)| to > number) {
+            if let Some(hash) = self.block_hash(to).await? {
+                entry.tip = Some((to, hash));
+            }
+        }
+
+        let logs = entry.matching(from, to, &filter.topics);
+        if let Err(e) = cache.put(key, entry) {
+            tracing::warn!("Failed to save the log cache: {}", e);
+        }
         Ok(logs)
     }
 
+    /// Hash of the canonical block at `number`
+    async fn block_hash(&self, number: u64) -> Result<Option<B256>, Error> {
+        let block = self
+            .get_block(BlockId::Number(BlockNumberOrTag::Number(number)), false)
+            .await?;
+        Ok(block.map(|block| block.header.hash))
+    }
+
     /// Get the balance of an address
     pub async fn get_balance(
         &self,
@@ -223,7 +633,7 @@ impl EvmClient {
             .unwrap_or(BlockNumberOrTag::Latest);
 
         let balance = self
-            .provider
+            .provider()
             .get_balance(address.0)
             .block_id(BlockId::Number(block_tag))
             .await
@@ -238,7 +648,7 @@ impl EvmClient {
         address: EthAddress,
         block: Option<BlockNumberOrTag>,
     ) -> Result<u64, Error> {
-        let mut call = self.provider.get_transaction_count(address.0);
+        let mut call = self.provider().get_transaction_count(address.0);
         if let Some(block) = block {
             call = call.block_id(BlockId::Number(block));
         }
@@ -259,7 +669,7 @@ impl EvmClient {
         tx_hash: B256,
     ) -> Result<Option<alloy_rpc_types_eth::TransactionReceipt>, Error> {
         let receipt = self
-            .provider
+            .provider()
             .get_transaction_receipt(tx_hash)
             .await
             .map_err(|e| EvmError::RpcError(e.to_string()))?;
@@ -273,13 +683,7 @@ impl EvmClient {
         signed_tx: &SignedEip1559Transaction,
     ) -> Result<B256, Error> {
         let raw = signed_tx.raw().clone();
-        let pending = self
-            .provider
-            .send_raw_transaction(raw.as_ref())
-            .await
-            .map_err(|e| EvmError::RpcError(e.to_string()))?;
-
-        Ok(*pending.tx_hash())
+        self.send_raw_bytes(raw.as_ref()).await
     }
 
     /// Broadcast a signed transaction and wait for the receipt.
@@ -297,26 +701,73 @@ impl EvmClient {
     }
 
     /// Wait for a transaction to be mined with optional timeout.
+    ///
+    /// Once the confirmations are in, the receipt's block is checked against
+    /// the chain. When a reorg replaced it, the reorg is recorded, the queued
+    /// transaction goes back to pending and the wait starts over, so a stale
+    /// receipt is never returned.
     pub async fn wait_for_receipt(
         &self,
         tx_hash: B256,
         confirmations: u64,
         timeout: Option<Duration>,
     ) -> Result<alloy_rpc_types_eth::TransactionReceipt, Error> {
-        let mut builder = PendingTransactionBuilder::new(&self.provider, tx_hash)
-            .with_required_confirmations(confirmations);
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let mut builder = PendingTransactionBuilder::new(self.provider(), tx_hash)
+                .with_required_confirmations(confirmations);
+
+            if let Some(deadline) = deadline {
+                builder =
+                    builder.with_timeout(Some(deadline.saturating_duration_since(Instant::now())));
+            }
 
-        if let Some(timeout) = timeout {
-            builder = builder.with_timeout(Some(timeout));
+            let receipt = builder.get_receipt().await.map_err(|e| {
+                Error::Evm(format!(
+                    "Failed to get receipt for transaction {}: {}. \
+                    Transaction may have timed out or failed to confirm after {} confirmations.",
+                    tx_hash, e, confirmations
+                ))
+            })?;
+
+            let (Some(number), Some(hash)) = (receipt.block_number, receipt.block_hash) else {
+                return Ok(receipt);
+            };
+            let canonical = self.block_hash(number).await?;
+            if canonical == Some(hash) {
+                return Ok(receipt);
+            }
+            self.report_reorg(number, hash, canonical, vec![tx_hash]);
         }
+    }
 
-        builder.get_receipt().await.map_err(|e| {
-            Error::Evm(format!(
-                "Failed to get receipt for transaction {}: {}. \
-                Transaction may have timed out or failed to confirm after {} confirmations.",
-                tx_hash, e, confirmations
-            ))
-        })
+    /// Record that the block `replaced` at `height` left the chain, putting
+    /// its queued transactions among `tx_hashes` back to pending
+    fn report_reorg(
+        &self,
+        height: u64,
+        replaced: B256,
+        canonical: Option<B256>,
+        tx_hashes: Vec<B256>,
+    ) {
+        let tx_hashes: Vec<String> = tx_hashes
+            .iter()
+            .map(|hash| format!("{:#x}", hash))
+            .collect();
+        if let Some(queue) = &self.tx_queue {
+            for hash in &tx_hashes {
+                if let Err(e) = queue.mark_reorged(hash) {
+                    tracing::warn!("Failed to record the reorg of transaction {}: {}", hash, e);
+                }
+            }
+        }
+        ReorgLog::global().record(ReorgDetected::new(
+            self.chain_id.to_string(),
+            height,
+            format!("{:#x}", replaced),
+            canonical.map(|hash| format!("{:#x}", hash)),
+            tx_hashes,
+        ));
     }
 
     /// Simulate an EIP-1559 transaction via eth_call.
@@ -329,7 +780,7 @@ impl EvmClient {
         let request =
             <EvmTransactionRequest as From<&Eip1559Transaction>>::from(tx).from(from.clone());
         let rpc_request = request.to_rpc_request(Some(from));
-        let mut call = self.provider.call(&rpc_request);
+        let mut call = self.provider().call(&rpc_request);
         if let Some(block) = block {
             call = call.block(BlockId::Number(block));
         }
@@ -343,7 +794,7 @@ impl EvmClient {
         tx_hash: B256,
     ) -> Result<Option<alloy_rpc_types_eth::Transaction>, Error> {
         let tx = self
-            .provider
+            .provider()
             .get_transaction_by_hash(tx_hash)
             .await
             .map_err(|e| EvmError::RpcError(e.to_string()))?;
@@ -404,7 +855,7 @@ impl EvmClient {
 
         // Create concurrent fetch tasks with timeout
         let fetch_tasks = tx_hashes.iter().map(|&hash| {
-            let provider = self.provider.clone();
+            let provider = self.provider().clone();
             async move {
                 let fetch_result =
                     timeout(REQUEST_TIMEOUT, provider.get_transaction_by_hash(hash)).await;
@@ -450,7 +901,7 @@ impl EvmClient {
         };
 
         let fetch_tasks = tx_hashes.iter().map(|&hash| {
-            let provider = self.provider.clone();
+            let provider = self.provider().clone();
             async move {
                 let fetch_result =
                     timeout(REQUEST_TIMEOUT, provider.get_transaction_receipt(hash)).await;
@@ -478,7 +929,7 @@ impl EvmClient {
             .unwrap_or(BlockNumberOrTag::Latest);
 
         let code = self
-            .provider
+            .provider()
             .get_code_at(address.0)
             .block_id(BlockId::Number(block_tag))
             .await
@@ -500,7 +951,7 @@ impl EvmClient {
             .unwrap_or(BlockNumberOrTag::Latest);
 
         let storage = self
-            .provider
+            .provider()
             .get_storage_at(address.0, slot)
             .block_id(BlockId::Number(block_tag))
             .await
@@ -523,13 +974,57 @@ impl EvmClient {
 
     /// Submit already-signed transaction bytes to the network.
     pub async fn send_raw_transaction_bytes(&self, signed_tx: Vec<u8>) -> Result<B256, Error> {
-        let pending = self
-            .provider
-            .send_raw_transaction(&signed_tx)
-            .await
-            .map_err(|e| EvmError::RpcError(e.to_string()))?;
+        self.send_raw_bytes(&signed_tx).await
+    }
 
-        Ok(*pending.tx_hash())
+    /// Send raw transaction bytes to the preferred endpoint, persisting them
+    /// to the transaction queue first when there is one
+    async fn send_raw_bytes(&self, raw: &[u8]) -> Result<B256, Error> {
+        let Some(queue) = &self.tx_queue else {
+            return self.resend_raw_bytes(raw).await;
+        };
+        let hash = format!("{:#x}", alloy_primitives::keccak256(raw));
+        queue.enqueue(
+            crate::tx_queue::TxVm::Evm,
+            &self.chain_id.to_string(),
+            &hash,
+            raw,
+            "evm transaction",
+        )?;
+        let result = self.resend_raw_bytes(raw).await;
+        // A refusal is final; a transaction that may not have reached the
+        // node stays queued for the queue's monitor
+        let recorded = match &result {
+            Ok(_) => queue.mark_broadcast(&hash),
+            Err(e) if !e.is_retryable() => queue.mark_failed(&hash, &e.to_string()),
+            Err(_) => Ok(()),
+        };
+        if let Err(e) = recorded {
+            tracing::warn!("Failed to record broadcast of transaction {}: {}", hash, e);
+        }
+        result
+    }
+
+    /// Send raw transaction bytes to the preferred endpoint without queueing them
+    pub(crate) async fn resend_raw_bytes(&self, raw: &[u8]) -> Result<B256, Error> {
+        let (url, provider) = self.preferred();
+        let started = Instant::now();
+        let result = provider
+            .send_raw_transaction(raw)
+            .instrument(tracing::debug_span!(
+                "rpc",
+                method = "eth_sendRawTransaction",
+                endpoint = %url
+            ))
+            .await
+            .map_err(|e| Error::from(EvmError::RpcError(e.to_string())));
+        trace::record_rpc(
+            "eth_sendRawTransaction",
+            url,
+            started,
+            result.as_ref().err().map(|e| e.to_string()),
+        );
+        Ok(*result?.tx_hash())
     }
 
     /// Call a contract method (read-only)
@@ -560,11 +1055,13 @@ impl EvmClient {
         gas_buffer_percent: Option<u64>,
     ) -> Result<B256, Error> {
         // 1. Encode contract call data
-        let data = call.abi_encode();
+        let data = self.tagged_calldata(call.abi_encode());
 
-        // 2. Get sender address and nonce
+        // 2. Get sender address and nonce, holding the sender's signing slot
+        // until the broadcast is answered
         let from = EthAddress(wallet.evm_address()?);
-        let nonce = self.get_pending_nonce(from.clone()).await?;
+        let mut slot = self.signer_slot(&from).await;
+        let nonce = slot.nonce(self.get_pending_nonce(from.clone()).await?);
 
         // 3. Estimate gas with proper from address
         let tx_request = EvmTransactionRequest {
@@ -580,7 +1077,7 @@ impl EvmClient {
             from: None,
         };
         let mut gas_limit = self
-            .estimate_gas_with_options(tx_request, Some(from), None)
+            .estimate_gas_with_options(tx_request, Some(from.clone()), None)
             .await?;
 
         // Apply gas buffer if specified
@@ -612,9 +1109,17 @@ impl EvmClient {
                 },
             )?);
 
-        // 6. Sign transaction
+        // 6. Sign transaction, recording what it does first
+        let intent = self.begin_signing_intent(
+            &from,
+            Some(contract_address),
+            &tx.data,
+            value.unwrap_or(U256::ZERO),
+        );
         let tx_hash = tx.signature_hash();
-        let (sig, recid) = wallet.sign_ethereum_tx(tx_hash.as_ref())?;
+        let (sig, recid) = wallet
+            .sign_ethereum_tx(tx_hash.as_ref())
+            .inspect_err(|e| complete_signing_intent(intent, nonce, None, Err(e)))?;
 
         // 7. Convert k256 signature to alloy format
         let signature = crate::wallet::MultiVMWallet::to_alloy_signature(&sig, recid);
@@ -626,7 +1131,11 @@ impl EvmClient {
         );
 
         // 9. Broadcast
-        self.send_raw_transaction(&signed_tx).await
+        let sent = self.send_raw_transaction(&signed_tx).await;
+        complete_signing_intent(intent, nonce, Some(&signature), sent.as_ref());
+        let tx_hash = sent?;
+        slot.commit();
+        Ok(tx_hash)
     }
 
     /// Call raw contract data
@@ -646,13 +1155,69 @@ impl EvmClient {
         data: Vec<u8>,
         value: U256,
         wallet: &crate::wallet::MultiVMWallet,
+    ) -> Result<B256, Error> {
+        let data = self.tagged_calldata(data);
+        self.send_transaction_data(Some(address), data, value, wallet)
+            .await
+    }
+
+    /// Deploy a contract from `init_code`, its creation bytecode followed by
+    /// the encoded constructor arguments, returning the transaction hash
+    pub async fn deploy_contract(
+        &self,
+        init_code: Vec<u8>,
+        value: U256,
+        wallet: &crate::wallet::MultiVMWallet,
+    ) -> Result<B256, Error> {
+        // Not tagged: bytes after the constructor arguments would be decoded
+        // as part of them
+        self.send_transaction_data(None, init_code, value, wallet)
+            .await
+    }
+
+    /// Deploy the standard mintable and burnable ERC-20 from `artifact`,
+    /// returning its address and the deployment hash once it is mined
+    pub async fn deploy_standard_erc20(
+        &self,
+        artifact: &crate::protocols::evm::contracts::StandardErc20Artifact,
+        params: &crate::protocols::evm::contracts::StandardErc20Params,
+        wallet: &crate::wallet::MultiVMWallet,
+        timeout: Option<Duration>,
+    ) -> Result<(Address, B256), Error> {
+        let tx_hash = self
+            .deploy_contract(params.init_code(&artifact.bytecode), U256::ZERO, wallet)
+            .await?;
+        let receipt = self.wait_for_receipt(tx_hash, 1, timeout).await?;
+        if !receipt.status() {
+            return Err(Error::Evm(format!(
+                "Deployment of {} reverted in transaction {}",
+                params.symbol, tx_hash
+            )));
+        }
+        let address = receipt.contract_address.ok_or_else(|| {
+            Error::Evm(format!(
+                "Receipt of deployment {} has no contract address",
+                tx_hash
+            ))
+        })?;
+        Ok((address, tx_hash))
+    }
+
+    /// Sign and send `data` to `to`, or as a contract creation when unset
+    async fn send_transaction_data(
+        &self,
+        to: Option<Address>,
+        data: Vec<u8>,
+        value: U256,
+        wallet: &crate::wallet::MultiVMWallet,
     ) -> Result<B256, Error> {
         // Similar flow to send_contract_call, but with raw data
         let from = EthAddress(wallet.evm_address()?);
-        let nonce = self.get_pending_nonce(from.clone()).await?;
+        let mut slot = self.signer_slot(&from).await;
+        let nonce = slot.nonce(self.get_pending_nonce(from.clone()).await?);
 
         let tx_request = EvmTransactionRequest {
-            to: Some(EthAddress(address)),
+            to: to.map(EthAddress),
             data: data.clone(),
             value,
             gas_limit: None,
@@ -664,12 +1229,12 @@ impl EvmClient {
             from: None,
         };
         let gas_limit = self
-            .estimate_gas_with_options(tx_request, Some(from), None)
+            .estimate_gas_with_options(tx_request, Some(from.clone()), None)
             .await?;
         let fee_data = self.fee_suggestion().await?;
 
         let tx = Eip1559Transaction::new(self.chain_id, nonce)
-            .to(Some(address))
+            .to(to)
             .data(Bytes::from(data))
             .value(value)
             .gas_limit(gas_limit)
@@ -688,8 +1253,11 @@ impl EvmClient {
                 },
             )?);
 
+        let intent = self.begin_signing_intent(&from, to, &tx.data, value);
         let tx_hash = tx.signature_hash();
-        let (sig, recid) = wallet.sign_ethereum_tx(tx_hash.as_ref())?;
+        let (sig, recid) = wallet
+            .sign_ethereum_tx(tx_hash.as_ref())
+            .inspect_err(|e| complete_signing_intent(intent, nonce, None, Err(e)))?;
 
         // Convert k256 signature to alloy format
         let signature = crate::wallet::MultiVMWallet::to_alloy_signature(&sig, recid);
@@ -699,7 +1267,63 @@ impl EvmClient {
             tx.encode_signed(&signature),
         );
 
-        self.send_raw_transaction(&signed_tx).await
+        let sent = self.send_raw_transaction(&signed_tx).await;
+        complete_signing_intent(intent, nonce, Some(&signature), sent.as_ref());
+        let tx_hash = sent?;
+        slot.commit();
+        Ok(tx_hash)
+    }
+
+    /// Record in the [`SigningLog`] what a transaction from `from` to `to`,
+    /// or a contract creation when unset, does before it is signed
+    pub fn begin_signing_intent(
+        &self,
+        from: &EthAddress,
+        to: Option<Address>,
+        data: &[u8],
+        value: U256,
+    ) -> u64 {
+        let (function, parameters) = if to.is_none() {
+            (
+                "deploy".to_string(),
+                serde_json::json!({ "init_code_bytes": data.len() }),
+            )
+        } else if data.is_empty() {
+            ("transfer".to_string(), serde_json::json!({}))
+        } else {
+            match TransactionDecoder::new().decode(data, to) {
+                Ok(decoded) if decoded.function_name != "unknown" => {
+                    (decoded.function_name, decoded.parameters)
+                }
+                Ok(decoded) => (decoded.selector, decoded.parameters),
+                Err(_) => (
+                    format!("0x{}", hex::encode(&data[..data.len().min(4)])),
+                    serde_json::json!({}),
+                ),
+            }
+        };
+        SigningLog::global().begin(
+            TxVm::Evm,
+            &self.chain_id.to_string(),
+            &format!("{:#x}", from.0),
+            vec![IntentMessage::from_evm(
+                to.map(|to| format!("{:#x}", to)),
+                &function,
+                parameters,
+                &value.to_string(),
+            )],
+        )
+    }
+
+    /// Wait for the signing slot of `from` on this chain
+    pub async fn signer_slot(&self, from: &EthAddress) -> crate::signer_queue::SignerSlot {
+        crate::signer_queue::SignerQueue::global()
+            .acquire(crate::signer_queue::SignerKey::new(
+                crate::tx_queue::TxVm::Evm,
+                self.chain_id.to_string(),
+                &format!("{:#x}", from.0),
+            ))
+            .await
     }
 
     /// Create an ERC-20 helper for the given contract address
@@ -718,6 +1342,38 @@ impl EvmClient {
     }
 }
 
+/// Complete the [`SigningLog`] record `intent` of a transaction signed with
+/// `nonce`, with the hash it was broadcast under or the error that stopped it
+#[cfg(feature = "evm")]
+#[allow(deprecated)]
+pub fn complete_signing_intent(
+    intent: u64,
+    nonce: u64,
+    signature: Option<&alloy_primitives::Signature>,
+    sent: Result<&B256, &Error>,
+) {
+    SigningLog::global().complete(
+        intent,
+        SigningOutcome {
+            nonce: Some(nonce),
+            signature: signature.map(|signature| hex::encode(signature.as_bytes())),
+            tx_hash: sent.ok().map(|hash| format!("{:#x}", hash)),
+            error: sent.err().map(|e| e.to_string()),
+        },
+    );
+}
+
+/// Block number of a filter bound given in decimal or `0x` hex; tags such as
+/// `latest` have none
+#[cfg(feature = "evm")]
+fn block_number(block: Option<&str>) -> Option<u64> {
+    let block = block?.trim();
+    match block.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => block.parse().ok(),
+    }
+}
+
 #[cfg(not(feature = "evm"))]
 /// Stub client when EVM feature is not enabled
 pub struct EvmClient;
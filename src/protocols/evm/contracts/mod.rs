@@ -9,6 +9,7 @@
 /// - **ERC-721**: Standard NFT interface
 /// - **PrimarySale**: MANTRA RWA token sale contract (v2.0)
 /// - **Allowlist**: KYC/AML compliance management for PrimarySale
+/// - **StandardErc20**: Mintable and burnable ERC-20 deployed for testnets
 /// - **Custom**: Generic custom contract interactions
 
 #[cfg(feature = "evm")]
@@ -21,6 +22,14 @@ pub mod erc20;
 pub mod erc721;
 #[cfg(feature = "evm")]
 pub mod primary_sale;
+#[cfg(feature = "evm")]
+pub mod primary_sale_eligibility;
+#[cfg(feature = "evm")]
+pub mod primary_sale_export;
+#[cfg(feature = "evm")]
+pub mod primary_sale_projection;
+#[cfg(feature = "evm")]
+pub mod standard_erc20;
 
 // Re-export commonly used types
 #[cfg(feature = "evm")]
@@ -33,3 +42,5 @@ pub use erc20::{Erc20, IERC20};
 pub use erc721::{Erc721, IERC721};
 #[cfg(feature = "evm")]
 pub use primary_sale::{IPrimarySale, PrimarySale};
+#[cfg(feature = "evm")]
+pub use standard_erc20::{IStandardErc20, StandardErc20Artifact, StandardErc20Params};
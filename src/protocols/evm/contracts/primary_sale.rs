@@ -35,9 +35,11 @@
 /// # }
 /// ```
 use crate::error::Error;
+use crate::pagination::{Page, PageRequest, Paginator};
 use crate::protocols::evm::client::EvmClient;
 use alloy_primitives::{Address, U256};
 use alloy_sol_types::sol;
+use futures::Stream;
 
 /// Maximum number of investors that can be processed in a single settlement transaction.
 ///
@@ -1126,6 +1128,29 @@ impl PrimarySale {
         Ok(investors)
     }
 
+    /// Get one page of investors; the cursor is how many come before it
+    pub async fn investors_page(
+        &self,
+        request: PageRequest<usize>,
+    ) -> Result<Page<Address, usize>, Error> {
+        let start = request.cursor.unwrap_or(0);
+        let limit = request.limit as usize;
+        let investors = self.get_investors(start, limit).await?;
+        let next_cursor = (investors.len() >= limit).then_some(start + investors.len());
+        Ok(Page {
+            items: investors,
+            next_cursor,
+        })
+    }
+
+    /// Stream every investor, `page_size` at a time
+    pub fn investors_stream(
+        &self,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<Address, Error>> + '_ {
+        Paginator::new(page_size).stream(move |request| self.investors_page(request))
+    }
+
     /// Get sale info summary
     ///
     /// # Updated for v2.0
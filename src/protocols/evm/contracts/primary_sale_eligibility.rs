@@ -0,0 +1,394 @@
+/// PrimarySale investment eligibility
+///
+/// An `invest` call reverts on the first requirement it fails: the sale must
+/// be active and inside its window, the investor allowlisted, the token
+/// accepted, the amount a whole number of `MIN_STEP`s within the remaining
+/// capacity, and the investor must hold and have approved the amount.
+/// [`InvestmentSnapshot::fetch`] reads everything those checks need in one go
+/// and [`InvestmentSnapshot::issues`] lists every requirement that is not
+/// met, so all of them can be fixed before the first attempt.
+use crate::error::Error;
+use crate::protocols::evm::client::EvmClient;
+use crate::protocols::evm::contracts::allowlist::IAllowlist;
+use crate::protocols::evm::types::EthAddress;
+use alloy_primitives::{Address, U256};
+use std::fmt;
+
+/// `Status.Active` of the sale contract
+const STATUS_ACTIVE: u8 = 1;
+
+/// Decimals of the contract's normalized amounts
+const NORMALIZED_DECIMALS: u8 = 18;
+
+/// A requirement of `invest` that is not met
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EligibilityIssueKind {
+    SaleNotActive,
+    OutsideSaleWindow,
+    NotAllowlisted,
+    TokenNotAccepted,
+    ZeroAmount,
+    NotMultipleOfMinStep,
+    ExceedsRemainingCapacity,
+    InsufficientBalance,
+    InsufficientAllowance,
+    NoGasBalance,
+}
+
+impl EligibilityIssueKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SaleNotActive => "sale_not_active",
+            Self::OutsideSaleWindow => "outside_sale_window",
+            Self::NotAllowlisted => "not_allowlisted",
+            Self::TokenNotAccepted => "token_not_accepted",
+            Self::ZeroAmount => "zero_amount",
+            Self::NotMultipleOfMinStep => "not_multiple_of_min_step",
+            Self::ExceedsRemainingCapacity => "exceeds_remaining_capacity",
+            Self::InsufficientBalance => "insufficient_balance",
+            Self::InsufficientAllowance => "insufficient_allowance",
+            Self::NoGasBalance => "no_gas_balance",
+        }
+    }
+}
+
+impl fmt::Display for EligibilityIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One unmet requirement and how to meet it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EligibilityIssue {
+    pub kind: EligibilityIssueKind,
+    pub message: String,
+    /// What the investor can do about it; none when only the sale admin can
+    pub fix: Option<String>,
+}
+
+/// What an investment's requirements are checked against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvestmentSnapshot {
+    pub sale: Address,
+    pub investor: Address,
+    pub token: Address,
+    /// Amount in the token's own decimals
+    pub amount: U256,
+    pub token_decimals: u8,
+    pub status: u8,
+    /// Whether the sale is active and inside its window
+    pub is_sale_active: bool,
+    pub start: u64,
+    pub end: u64,
+    pub is_allowlisted: bool,
+    pub token_accepted: bool,
+    pub accepted_tokens: Vec<Address>,
+    pub min_step: U256,
+    /// Zero when the sale has no hard cap
+    pub hard_cap: U256,
+    /// Normalized amount the sale can still take
+    pub remaining_capacity: U256,
+    pub balance: U256,
+    pub allowance: U256,
+    /// Native balance paying for gas
+    pub native_balance: U256,
+}
+
+impl InvestmentSnapshot {
+    /// Read the state `investor` investing `amount` of `token` in `sale`
+    /// depends on
+    pub async fn fetch(
+        client: &EvmClient,
+        sale: Address,
+        investor: Address,
+        token: Address,
+        amount: U256,
+    ) -> Result<Self, Error> {
+        let primary_sale = client.primary_sale(sale);
+        let erc20 = client.erc20(token);
+        let (
+            status,
+            is_sale_active,
+            start,
+            end,
+            allowlist,
+            accepted_tokens,
+            min_step,
+            hard_cap,
+            remaining_capacity,
+        ) = futures::try_join!(
+            primary_sale.status(),
+            primary_sale.is_sale_active(),
+            primary_sale.start(),
+            primary_sale.end(),
+            primary_sale.allowlist(),
+            primary_sale.get_accepted_tokens(),
+            primary_sale.min_step(),
+            primary_sale.hard_cap(),
+            primary_sale.get_remaining_capacity(),
+        )?;
+        let (is_allowlisted, token_decimals, balance, allowance, native_balance) = futures::try_join!(
+            async {
+                client
+                    .call_contract(allowlist, IAllowlist::isAllowedCall { addr: investor })
+                    .await
+                    .map(|result| result._0)
+            },
+            erc20.decimals(),
+            erc20.balance_of(investor),
+            erc20.allowance(investor, sale),
+            client.get_balance(EthAddress(investor), None),
+        )?;
+
+        Ok(Self {
+            sale,
+            investor,
+            token,
+            amount,
+            token_decimals,
+            status,
+            is_sale_active,
+            start,
+            end,
+            is_allowlisted,
+            token_accepted: accepted_tokens.contains(&token),
+            accepted_tokens,
+            min_step,
+            hard_cap,
+            remaining_capacity,
+            balance,
+            allowance,
+            native_balance,
+        })
+    }
+
+    /// `amount` in the contract's 18-decimal units
+    pub fn normalized_amount(&self) -> U256 {
+        if self.token_decimals <= NORMALIZED_DECIMALS {
+            self.amount.saturating_mul(
+                U256::from(10u64).pow(U256::from(NORMALIZED_DECIMALS - self.token_decimals)),
+            )
+        } else {
+            self.amount
+                / U256::from(10u64).pow(U256::from(self.token_decimals - NORMALIZED_DECIMALS))
+        }
+    }
+
+    /// Every requirement of the investment that is not met, given the time
+    /// `now` in Unix seconds
+    pub fn issues(&self, now: u64) -> Vec<EligibilityIssue> {
+        let mut issues = Vec::new();
+        let mut issue = |kind, message: String, fix: Option<String>| {
+            issues.push(EligibilityIssue { kind, message, fix })
+        };
+
+        if self.status != STATUS_ACTIVE {
+            issue(
+                EligibilityIssueKind::SaleNotActive,
+                format!("The sale is {}, not Active", status_name(self.status)),
+                None,
+            );
+        } else if !self.is_sale_active {
+            let message = if now < self.start {
+                format!("The sale opens in {}s", self.start - now)
+            } else if now >= self.end {
+                format!("The sale window closed {}s ago", now - self.end)
+            } else {
+                "The sale is not accepting investments".to_string()
+            };
+            issue(EligibilityIssueKind::OutsideSaleWindow, message, None);
+        }
+
+        if !self.is_allowlisted {
+            issue(
+                EligibilityIssueKind::NotAllowlisted,
+                format!("{:#x} is not on the sale's KYC allowlist", self.investor),
+                Some("Complete KYC with the issuer to be added to the allowlist".to_string()),
+            );
+        }
+
+        if !self.token_accepted {
+            let accepted: Vec<String> = self
+                .accepted_tokens
+                .iter()
+                .map(|token| format!("{:#x}", token))
+                .collect();
+            issue(
+                EligibilityIssueKind::TokenNotAccepted,
+                format!("The sale does not accept {:#x}", self.token),
+                Some(format!("Invest with one of: {}", accepted.join(", "))),
+            );
+        }
+
+        let normalized = self.normalized_amount();
+        if self.amount.is_zero() {
+            issue(
+                EligibilityIssueKind::ZeroAmount,
+                "The amount is zero".to_string(),
+                None,
+            );
+        } else if !self.min_step.is_zero() && normalized % self.min_step != U256::ZERO {
+            let step = self.min_step_in_token();
+            issue(
+                EligibilityIssueKind::NotMultipleOfMinStep,
+                format!(
+                    "The amount is not a multiple of the minimum step of {} token units",
+                    step
+                ),
+                Some(format!(
+                    "Invest {} or {} token units instead",
+                    round_down(self.amount, step),
+                    round_down(self.amount, step).saturating_add(step)
+                )),
+            );
+        }
+        if !self.hard_cap.is_zero() && normalized > self.remaining_capacity {
+            issue(
+                EligibilityIssueKind::ExceedsRemainingCapacity,
+                format!(
+                    "The amount is {} normalized units but the sale has {} left before its hard cap",
+                    normalized, self.remaining_capacity
+                ),
+                Some("Invest no more than the remaining capacity".to_string()),
+            );
+        }
+
+        if self.balance < self.amount {
+            issue(
+                EligibilityIssueKind::InsufficientBalance,
+                format!(
+                    "The investor holds {} token units of the {} needed",
+                    self.balance, self.amount
+                ),
+                Some(format!(
+                    "Acquire {} more token units",
+                    self.amount - self.balance
+                )),
+            );
+        }
+        if self.allowance < self.amount {
+            issue(
+                EligibilityIssueKind::InsufficientAllowance,
+                format!(
+                    "The sale may spend {} token units of the {} needed",
+                    self.allowance, self.amount
+                ),
+                Some(format!(
+                    "Approve {:#x} to spend at least {} token units",
+                    self.sale, self.amount
+                )),
+            );
+        }
+        if self.native_balance.is_zero() {
+            issue(
+                EligibilityIssueKind::NoGasBalance,
+                "The investor has no native balance to pay gas with".to_string(),
+                Some("Fund the investor's EVM address with OM".to_string()),
+            );
+        }
+        issues
+    }
+
+    /// `MIN_STEP` in the token's own decimals
+    fn min_step_in_token(&self) -> U256 {
+        if self.token_decimals <= NORMALIZED_DECIMALS {
+            let scale =
+                U256::from(10u64).pow(U256::from(NORMALIZED_DECIMALS - self.token_decimals));
+            self.min_step.div_ceil(scale)
+        } else {
+            self.min_step.saturating_mul(
+                U256::from(10u64).pow(U256::from(self.token_decimals - NORMALIZED_DECIMALS)),
+            )
+        }
+    }
+}
+
+/// Name of a sale `Status`
+pub fn status_name(status: u8) -> &'static str {
+    match status {
+        0 => "Pending",
+        1 => "Active",
+        2 => "Ended",
+        3 => "Failed",
+        4 => "Settled",
+        5 => "Cancelled",
+        _ => "Unknown",
+    }
+}
+
+fn round_down(amount: U256, step: U256) -> U256 {
+    if step.is_zero() {
+        amount
+    } else {
+        amount - amount % step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> InvestmentSnapshot {
+        let usdc = Address::repeat_byte(0xaa);
+        InvestmentSnapshot {
+            sale: Address::repeat_byte(1),
+            investor: Address::repeat_byte(2),
+            token: usdc,
+            // 100 USDC
+            amount: U256::from(100_000_000u64),
+            token_decimals: 6,
+            status: STATUS_ACTIVE,
+            is_sale_active: true,
+            start: 1_000,
+            end: 2_000,
+            is_allowlisted: true,
+            token_accepted: true,
+            accepted_tokens: vec![usdc],
+            // 10 units
+            min_step: U256::from(10u64) * U256::from(10u64).pow(U256::from(18)),
+            hard_cap: U256::ZERO,
+            remaining_capacity: U256::ZERO,
+            balance: U256::from(500_000_000u64),
+            allowance: U256::from(100_000_000u64),
+            native_balance: U256::from(1u64),
+        }
+    }
+
+    #[test]
+    fn test_eligibility_issues() {
+        let eligible = snapshot();
+        assert_eq!(
+            eligible.normalized_amount(),
+            U256::from(100u64) * U256::from(10u64).pow(U256::from(18))
+        );
+        assert!(eligible.issues(1_500).is_empty());
+
+        let blocked = InvestmentSnapshot {
+            is_sale_active: false,
+            is_allowlisted: false,
+            // 105 USDC
+            amount: U256::from(105_000_000u64),
+            hard_cap: U256::from(1u64),
+            remaining_capacity: U256::ZERO,
+            allowance: U256::ZERO,
+            ..snapshot()
+        };
+        let issues = blocked.issues(2_500);
+        let kinds: Vec<EligibilityIssueKind> = issues.iter().map(|issue| issue.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                EligibilityIssueKind::OutsideSaleWindow,
+                EligibilityIssueKind::NotAllowlisted,
+                EligibilityIssueKind::NotMultipleOfMinStep,
+                EligibilityIssueKind::ExceedsRemainingCapacity,
+                EligibilityIssueKind::InsufficientAllowance,
+            ]
+        );
+        assert_eq!(
+            issues[2].fix.as_deref(),
+            Some("Invest 100000000 or 110000000 token units instead")
+        );
+    }
+}
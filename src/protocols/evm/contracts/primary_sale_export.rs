@@ -0,0 +1,425 @@
+/// PrimarySale investor export and reconciliation
+///
+/// Issuers report on a sale from the full investor list: what each investor
+/// paid in every accepted token, their KYC and settlement state, and whether
+/// the per-investor figures add up to the totals the contract keeps.
+/// [`PrimarySale::export_investors`] pages through the investors with the
+/// batch distribution query, looks up each investor's per-token contributions
+/// and refund state concurrently under a request rate limit, and reconciles
+/// the sums against `getTotalContributedNormalized` and
+/// `getTotalContributedByToken`.
+use crate::amount::format_units_evm;
+use crate::error::Error;
+use crate::protocols::evm::contracts::primary_sale::PrimarySale;
+use crate::tx_history::csv_field;
+use alloy_primitives::{Address, U256};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{interval, Interval, MissedTickBehavior};
+
+/// Investors fetched per batch distribution query
+pub const DEFAULT_EXPORT_PAGE_SIZE: usize = 100;
+
+/// Investors whose details are fetched at once
+pub const DEFAULT_EXPORT_CONCURRENCY: usize = 8;
+
+/// Contract calls started per second across the whole export
+pub const DEFAULT_EXPORT_REQUESTS_PER_SECOND: u32 = 20;
+
+/// Decimals of the contract's normalized amounts
+const NORMALIZED_DECIMALS: u8 = 18;
+
+/// How fast an export queries the contract
+#[derive(Debug, Clone, Copy)]
+pub struct ExportOptions {
+    pub page_size: usize,
+    pub concurrency: usize,
+    /// Contract calls started per second; unlimited when zero
+    pub requests_per_second: u32,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_EXPORT_PAGE_SIZE,
+            concurrency: DEFAULT_EXPORT_CONCURRENCY,
+            requests_per_second: DEFAULT_EXPORT_REQUESTS_PER_SECOND,
+        }
+    }
+}
+
+/// One investor of a sale
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvestorRecord {
+    pub address: Address,
+    /// Contribution across all tokens, in 18 decimals
+    pub contribution_normalized: U256,
+    /// Raw contribution in each accepted token the investor paid with
+    pub contributions_by_token: BTreeMap<Address, U256>,
+    pub tokens_allocated: U256,
+    pub is_kyc_approved: bool,
+    pub has_received_settlement: bool,
+    pub has_claimed_refund: bool,
+}
+
+/// Sum of one token's investor contributions against the contract's total
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenReconciliation {
+    pub token: Address,
+    pub investor_sum: U256,
+    pub contract_total: U256,
+    pub matches: bool,
+}
+
+/// Whether the exported investors add up to the contract's totals
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reconciliation {
+    /// `investorCount` when the export started
+    pub investor_count: U256,
+    pub investors_exported: usize,
+    /// Investors whose details could not be fetched
+    pub investors_failed: usize,
+    pub sum_contributions_normalized: U256,
+    pub total_contributed_normalized: U256,
+    /// Total less the sum, e.g. `-5` when investors sum to more than the total
+    pub difference_normalized: String,
+    pub tokens: Vec<TokenReconciliation>,
+    /// Whether every investor was exported and every sum matches its total
+    pub balanced: bool,
+}
+
+impl Reconciliation {
+    /// Compare `investors` with the contract's totals
+    pub fn new(
+        investor_count: U256,
+        investors: &[InvestorRecord],
+        investors_failed: usize,
+        total_contributed_normalized: U256,
+        token_totals: &[(Address, U256)],
+    ) -> Self {
+        let sum_contributions_normalized = investors.iter().fold(U256::ZERO, |sum, investor| {
+            sum.saturating_add(investor.contribution_normalized)
+        });
+        let tokens: Vec<TokenReconciliation> = token_totals
+            .iter()
+            .map(|&(token, contract_total)| {
+                let investor_sum = investors
+                    .iter()
+                    .filter_map(|investor| investor.contributions_by_token.get(&token))
+                    .fold(U256::ZERO, |sum, amount| sum.saturating_add(*amount));
+                TokenReconciliation {
+                    token,
+                    investor_sum,
+                    contract_total,
+                    matches: investor_sum == contract_total,
+                }
+            })
+            .collect();
+        let difference_normalized = if total_contributed_normalized >= sum_contributions_normalized
+        {
+            (total_contributed_normalized - sum_contributions_normalized).to_string()
+        } else {
+            format!(
+                "-{}",
+                sum_contributions_normalized - total_contributed_normalized
+            )
+        };
+        let balanced = investors_failed == 0
+            && U256::from(investors.len()) == investor_count
+            && sum_contributions_normalized == total_contributed_normalized
+            && tokens.iter().all(|token| token.matches);
+
+        Self {
+            investor_count,
+            investors_exported: investors.len(),
+            investors_failed,
+            sum_contributions_normalized,
+            total_contributed_normalized,
+            difference_normalized,
+            tokens,
+            balanced,
+        }
+    }
+}
+
+/// An investor whose details could not be fetched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedInvestor {
+    pub address: Address,
+    pub error: String,
+}
+
+/// Every investor of a sale and how they reconcile with its totals
+#[derive(Debug, Clone)]
+pub struct InvestorExport {
+    pub contract: Address,
+    pub accepted_tokens: Vec<Address>,
+    pub investors: Vec<InvestorRecord>,
+    pub failed: Vec<FailedInvestor>,
+    pub reconciliation: Reconciliation,
+}
+
+impl InvestorExport {
+    /// Investors as CSV, one row per investor and one column per accepted
+    /// token
+    pub fn to_csv(&self) -> String {
+        let mut header = vec![
+            "address".to_string(),
+            "contribution_normalized".to_string(),
+            "contribution".to_string(),
+        ];
+        header.extend(
+            self.accepted_tokens
+                .iter()
+                .map(|token| format!("contributed_{:#x}", token)),
+        );
+        header.extend(
+            [
+                "tokens_allocated",
+                "kyc_approved",
+                "settled",
+                "refund_claimed",
+            ]
+            .map(String::from),
+        );
+
+        let mut csv = header.join(",");
+        csv.push('\n');
+        for investor in &self.investors {
+            let mut row = vec![
+                format!("{:#x}", investor.address),
+                investor.contribution_normalized.to_string(),
+                format_units_evm(investor.contribution_normalized, NORMALIZED_DECIMALS),
+            ];
+            row.extend(self.accepted_tokens.iter().map(|token| {
+                investor
+                    .contributions_by_token
+                    .get(token)
+                    .copied()
+                    .unwrap_or(U256::ZERO)
+                    .to_string()
+            }));
+            row.extend([
+                investor.tokens_allocated.to_string(),
+                investor.is_kyc_approved.to_string(),
+                investor.has_received_settlement.to_string(),
+                investor.has_claimed_refund.to_string(),
+            ]);
+            csv.push_str(
+                &row.iter()
+                    .map(|field| csv_field(field))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            );
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Spaces contract calls out to a maximum rate
+struct RateLimiter(Option<Mutex<Interval>>);
+
+impl RateLimiter {
+    fn new(requests_per_second: u32) -> Self {
+        Self((requests_per_second > 0).then(|| {
+            let period =
+                (Duration::from_secs(1) / requests_per_second).max(Duration::from_micros(1));
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            Mutex::new(ticker)
+        }))
+    }
+
+    /// Wait until another call may start
+    async fn acquire(&self) {
+        if let Some(ticker) = &self.0 {
+            ticker.lock().await.tick().await;
+        }
+    }
+}
+
+impl PrimarySale {
+    /// Export every investor with their contributions and reconcile the
+    /// sums against the contract's totals
+    ///
+    /// An investor whose details cannot be fetched is listed under `failed`
+    /// and leaves the reconciliation unbalanced rather than failing the
+    /// export.
+    pub async fn export_investors(&self, options: ExportOptions) -> Result<InvestorExport, Error> {
+        let limiter = RateLimiter::new(options.requests_per_second);
+        let page_size = options.page_size.max(1);
+
+        limiter.acquire().await;
+        let investor_count = self.investor_count().await?;
+        limiter.acquire().await;
+        let accepted_tokens = self.get_accepted_tokens().await?;
+        let total: usize = investor_count.try_into().map_err(|_| {
+            Error::Other(format!(
+                "Investor count overflow: {} exceeds maximum addressable size",
+                investor_count
+            ))
+        })?;
+
+        let mut investors = Vec::with_capacity(total);
+        let mut failed = Vec::new();
+        for start in (0..total).step_by(page_size) {
+            let end = (start + page_size).min(total);
+            limiter.acquire().await;
+            let (addresses, contributions, tokens, kyc, settled) = self
+                .get_investor_distribution_batch(U256::from(start), U256::from(end))
+                .await
+                .map_err(|e| {
+                    Error::Other(format!(
+                        "Failed to fetch investors {}..{} of {}: {}",
+                        start, end, total, e
+                    ))
+                })?;
+
+            let results: Vec<_> = stream::iter(addresses.into_iter().enumerate())
+                .map(|(i, address)| {
+                    let limiter = &limiter;
+                    let accepted_tokens = &accepted_tokens;
+                    let base = InvestorRecord {
+                        address,
+                        contribution_normalized: contributions.get(i).copied().unwrap_or_default(),
+                        contributions_by_token: BTreeMap::new(),
+                        tokens_allocated: tokens.get(i).copied().unwrap_or_default(),
+                        is_kyc_approved: kyc.get(i).copied().unwrap_or_default(),
+                        has_received_settlement: settled.get(i).copied().unwrap_or_default(),
+                        has_claimed_refund: false,
+                    };
+                    async move {
+                        self.investor_details(base, accepted_tokens, limiter)
+                            .await
+                            .map_err(|e| FailedInvestor {
+                                address,
+                                error: e.to_string(),
+                            })
+                    }
+                })
+                .buffered(options.concurrency.max(1))
+                .collect()
+                .await;
+            for result in results {
+                match result {
+                    Ok(record) => investors.push(record),
+                    Err(failure) => failed.push(failure),
+                }
+            }
+        }
+
+        limiter.acquire().await;
+        let total_contributed_normalized = self.total_contributed_normalized().await?;
+        let token_totals: Vec<(Address, U256)> = stream::iter(accepted_tokens.iter().copied())
+            .then(|token| {
+                let limiter = &limiter;
+                async move {
+                    limiter.acquire().await;
+                    Ok::<_, Error>((token, self.total_contributed_by_token(token).await?))
+                }
+            })
+            .try_collect()
+            .await?;
+
+        let reconciliation = Reconciliation::new(
+            investor_count,
+            &investors,
+            failed.len(),
+            total_contributed_normalized,
+            &token_totals,
+        );
+        Ok(InvestorExport {
+            contract: self.address(),
+            accepted_tokens,
+            investors,
+            failed,
+            reconciliation,
+        })
+    }
+
+    /// Fill in the per-token contributions and refund state of `record`
+    async fn investor_details(
+        &self,
+        mut record: InvestorRecord,
+        accepted_tokens: &[Address],
+        limiter: &RateLimiter,
+    ) -> Result<InvestorRecord, Error> {
+        for &token in accepted_tokens {
+            limiter.acquire().await;
+            let amount = self.contributed_by_token(record.address, token).await?;
+            if amount > U256::ZERO {
+                record.contributions_by_token.insert(token, amount);
+            }
+        }
+        limiter.acquire().await;
+        record.has_claimed_refund = self.refunded(record.address).await?;
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn investor(byte: u8, normalized: u64, by_token: &[(Address, u64)]) -> InvestorRecord {
+        InvestorRecord {
+            address: Address::repeat_byte(byte),
+            contribution_normalized: U256::from(normalized),
+            contributions_by_token: by_token
+                .iter()
+                .map(|&(token, amount)| (token, U256::from(amount)))
+                .collect(),
+            tokens_allocated: U256::from(normalized * 2),
+            is_kyc_approved: true,
+            has_received_settlement: false,
+            has_claimed_refund: false,
+        }
+    }
+
+    #[test]
+    fn test_reconciliation_and_csv() {
+        let usdc = Address::repeat_byte(0xaa);
+        let dai = Address::repeat_byte(0xbb);
+        let investors = vec![
+            investor(1, 300, &[(usdc, 3)]),
+            investor(2, 500, &[(usdc, 1), (dai, 400)]),
+        ];
+
+        let balanced = Reconciliation::new(
+            U256::from(2),
+            &investors,
+            0,
+            U256::from(800),
+            &[(usdc, U256::from(4)), (dai, U256::from(400))],
+        );
+        assert!(balanced.balanced);
+        assert_eq!(balanced.difference_normalized, "0");
+
+        let short = Reconciliation::new(
+            U256::from(3),
+            &investors,
+            1,
+            U256::from(750),
+            &[(usdc, U256::from(5)), (dai, U256::from(400))],
+        );
+        assert!(!short.balanced);
+        assert_eq!(short.difference_normalized, "-50");
+        assert!(!short.tokens[0].matches && short.tokens[1].matches);
+
+        let export = InvestorExport {
+            contract: Address::ZERO,
+            accepted_tokens: vec![usdc, dai],
+            investors,
+            failed: Vec::new(),
+            reconciliation: balanced,
+        };
+        let csv = export.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].split(',').count(), 9);
+        assert!(lines[2].contains(",1,400,"), "{}", lines[2]);
+    }
+}
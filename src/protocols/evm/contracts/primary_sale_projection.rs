@@ -0,0 +1,303 @@
+/// PrimarySale settlement projection
+///
+/// Settlement decides every investor's outcome and every token's proceeds
+/// at once. An investor who lost KYC approval is refunded to their own
+/// address, and a restricted wallet's contribution goes to the multisig; both
+/// allocations return to the issuer. The other investors receive their
+/// allocation. Of each payment token's remaining proceeds, `COMMISSION_BPS`
+/// goes to MANTRA and the rest to the issuer. A sale below its soft cap fails
+/// instead and refunds everyone. [`project_settlement`] applies those rules to
+/// the current contributions, so an issuer can see the outcome before ending
+/// the sale.
+use crate::error::Error;
+use crate::protocols::evm::contracts::primary_sale::PrimarySale;
+use crate::protocols::evm::contracts::primary_sale_export::{
+    ExportOptions, InvestorExport, InvestorRecord,
+};
+use alloy_primitives::{Address, U256};
+use std::collections::{BTreeMap, HashSet};
+
+/// Basis points in one whole
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// What settlement does with one investor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvestorOutcome {
+    /// The investor receives their allocation
+    Distribute,
+    /// KYC approval was revoked; the contribution goes back to the investor
+    RefundKycDelisted,
+    /// Restricted wallet; the contribution goes to the multisig
+    RefundRestricted,
+    /// The soft cap was missed; the investor claims a refund
+    RefundSaleFailed,
+}
+
+impl InvestorOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Distribute => "distribute",
+            Self::RefundKycDelisted => "refund_kyc_delisted",
+            Self::RefundRestricted => "refund_restricted",
+            Self::RefundSaleFailed => "refund_sale_failed",
+        }
+    }
+
+    pub fn is_refund(self) -> bool {
+        self != Self::Distribute
+    }
+}
+
+/// One investor's projected settlement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvestorProjection {
+    pub address: Address,
+    pub outcome: InvestorOutcome,
+    /// Asset tokens the investor receives; zero when refunded
+    pub tokens_allocated: U256,
+    /// Raw amounts refunded in each payment token; empty when distributed
+    pub refunds_by_token: BTreeMap<Address, U256>,
+}
+
+/// One payment token's projected proceeds, in its own decimals
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenProceeds {
+    pub token: Address,
+    pub total_contributed: U256,
+    pub refunded: U256,
+    /// Contributions kept after refunds
+    pub net: U256,
+    pub commission: U256,
+    pub issuer_proceeds: U256,
+}
+
+/// Projected outcome of settling a sale
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettlementProjection {
+    pub soft_cap: U256,
+    pub total_contributed_normalized: U256,
+    pub soft_cap_met: bool,
+    pub commission_bps: u16,
+    pub tokens: Vec<TokenProceeds>,
+    pub investors: Vec<InvestorProjection>,
+    /// Asset tokens distributed to investors
+    pub tokens_distributed: U256,
+    /// Allocations of refunded investors, returned to the issuer
+    pub tokens_returned_to_issuer: U256,
+}
+
+impl SettlementProjection {
+    /// Investors with `outcome`
+    pub fn count(&self, outcome: InvestorOutcome) -> usize {
+        self.investors
+            .iter()
+            .filter(|investor| investor.outcome == outcome)
+            .count()
+    }
+}
+
+/// Project settling a sale with `investors` contributing
+///
+/// `restricted` are the wallets that would be passed to `settleBatch` as
+/// restricted.
+pub fn project_settlement(
+    accepted_tokens: &[Address],
+    investors: &[InvestorRecord],
+    soft_cap: U256,
+    total_contributed_normalized: U256,
+    commission_bps: u16,
+    restricted: &HashSet<Address>,
+) -> SettlementProjection {
+    let soft_cap_met = total_contributed_normalized >= soft_cap;
+    let outcomes: Vec<InvestorOutcome> = investors
+        .iter()
+        .map(|investor| {
+            if !soft_cap_met {
+                InvestorOutcome::RefundSaleFailed
+            } else if restricted.contains(&investor.address) {
+                InvestorOutcome::RefundRestricted
+            } else if !investor.is_kyc_approved {
+                InvestorOutcome::RefundKycDelisted
+            } else {
+                InvestorOutcome::Distribute
+            }
+        })
+        .collect();
+
+    let tokens = accepted_tokens
+        .iter()
+        .map(|&token| {
+            let mut total_contributed = U256::ZERO;
+            let mut refunded = U256::ZERO;
+            for (investor, outcome) in investors.iter().zip(&outcomes) {
+                let amount = investor
+                    .contributions_by_token
+                    .get(&token)
+                    .copied()
+                    .unwrap_or_default();
+                total_contributed = total_contributed.saturating_add(amount);
+                if outcome.is_refund() {
+                    refunded = refunded.saturating_add(amount);
+                }
+            }
+            let net = total_contributed - refunded;
+            let commission =
+                net.saturating_mul(U256::from(commission_bps)) / U256::from(BPS_DENOMINATOR);
+            TokenProceeds {
+                token,
+                total_contributed,
+                refunded,
+                net,
+                commission,
+                issuer_proceeds: net - commission,
+            }
+        })
+        .collect();
+
+    let mut tokens_distributed = U256::ZERO;
+    let mut tokens_returned_to_issuer = U256::ZERO;
+    let investors = investors
+        .iter()
+        .zip(outcomes)
+        .map(|(investor, outcome)| match outcome {
+            InvestorOutcome::Distribute => {
+                tokens_distributed = tokens_distributed.saturating_add(investor.tokens_allocated);
+                InvestorProjection {
+                    address: investor.address,
+                    outcome,
+                    tokens_allocated: investor.tokens_allocated,
+                    refunds_by_token: BTreeMap::new(),
+                }
+            }
+            _ => {
+                // A failed sale never pulls the asset tokens, so there is
+                // nothing to return
+                if soft_cap_met {
+                    tokens_returned_to_issuer =
+                        tokens_returned_to_issuer.saturating_add(investor.tokens_allocated);
+                }
+                InvestorProjection {
+                    address: investor.address,
+                    outcome,
+                    tokens_allocated: U256::ZERO,
+                    refunds_by_token: investor.contributions_by_token.clone(),
+                }
+            }
+        })
+        .collect();
+
+    SettlementProjection {
+        soft_cap,
+        total_contributed_normalized,
+        soft_cap_met,
+        commission_bps,
+        tokens,
+        investors,
+        tokens_distributed,
+        tokens_returned_to_issuer,
+    }
+}
+
+impl PrimarySale {
+    /// Project settling the sale with its current contributions
+    ///
+    /// Reads every investor through [`PrimarySale::export_investors`]; an
+    /// investor that cannot be read fails the projection, since its outcome
+    /// would be missing from the totals.
+    pub async fn project_settlement(
+        &self,
+        restricted: &HashSet<Address>,
+        options: ExportOptions,
+    ) -> Result<(InvestorExport, SettlementProjection), Error> {
+        let export = self.export_investors(options).await?;
+        if let Some(failure) = export.failed.first() {
+            return Err(Error::Other(format!(
+                "Could not read investor {:#x} ({} failed): {}",
+                failure.address,
+                export.failed.len(),
+                failure.error
+            )));
+        }
+        let soft_cap = self.soft_cap().await?;
+        let commission_bps = self.commission_bps().await?;
+        let projection = project_settlement(
+            &export.accepted_tokens,
+            &export.investors,
+            soft_cap,
+            export.reconciliation.total_contributed_normalized,
+            commission_bps,
+            restricted,
+        );
+        Ok((export, projection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn investor(byte: u8, kyc: bool, usdc: u64, allocated: u64) -> InvestorRecord {
+        InvestorRecord {
+            address: Address::repeat_byte(byte),
+            contribution_normalized: U256::from(usdc),
+            contributions_by_token: [(Address::repeat_byte(0xaa), U256::from(usdc))]
+                .into_iter()
+                .collect(),
+            tokens_allocated: U256::from(allocated),
+            is_kyc_approved: kyc,
+            has_received_settlement: false,
+            has_claimed_refund: false,
+        }
+    }
+
+    #[test]
+    fn test_project_settlement() {
+        let usdc = Address::repeat_byte(0xaa);
+        let investors = vec![
+            investor(1, true, 6_000, 60),
+            investor(2, false, 1_000, 10),
+            investor(3, true, 3_000, 30),
+        ];
+        let restricted: HashSet<Address> = [Address::repeat_byte(3)].into_iter().collect();
+
+        // 2.5% commission on the 6,000 kept after two refunds
+        let projection = project_settlement(
+            &[usdc],
+            &investors,
+            U256::from(5_000),
+            U256::from(10_000),
+            250,
+            &restricted,
+        );
+        assert!(projection.soft_cap_met);
+        let outcomes: Vec<InvestorOutcome> =
+            projection.investors.iter().map(|i| i.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                InvestorOutcome::Distribute,
+                InvestorOutcome::RefundKycDelisted,
+                InvestorOutcome::RefundRestricted,
+            ]
+        );
+        let proceeds = &projection.tokens[0];
+        assert_eq!(proceeds.refunded, U256::from(4_000));
+        assert_eq!(proceeds.commission, U256::from(150));
+        assert_eq!(proceeds.issuer_proceeds, U256::from(5_850));
+        assert_eq!(projection.tokens_distributed, U256::from(60));
+        assert_eq!(projection.tokens_returned_to_issuer, U256::from(40));
+
+        // Below the soft cap everyone is refunded and nothing is kept
+        let failed = project_settlement(
+            &[usdc],
+            &investors,
+            U256::from(20_000),
+            U256::from(10_000),
+            250,
+            &HashSet::new(),
+        );
+        assert_eq!(failed.count(InvestorOutcome::RefundSaleFailed), 3);
+        assert_eq!(failed.tokens[0].net, U256::ZERO);
+        assert_eq!(failed.tokens_returned_to_issuer, U256::ZERO);
+    }
+}
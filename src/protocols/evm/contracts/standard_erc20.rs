@@ -0,0 +1,225 @@
+/// Standard mintable and burnable ERC-20 for testnet scenarios
+///
+/// The token is deployed from a compiled artifact of an audited
+/// implementation (OpenZeppelin `ERC20` and `ERC20Burnable`, with an
+/// owner-only `mint`) whose constructor takes the name, symbol, decimals,
+/// initial supply and owner. The artifact is read from
+/// [`StandardErc20Artifact::default_path`] unless another file is given, and
+/// holds either the bare creation bytecode in hex or a Foundry or Hardhat
+/// artifact JSON with the bytecode under `bytecode`. Its bytecode is only
+/// used when its sha256 matches the hash pinned for the audited build.
+use crate::error::Error;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolConstructor};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+sol! {
+    #[derive(Debug)]
+    interface IStandardErc20 {
+        function owner() external view returns (address);
+        function mint(address to, uint256 amount) external;
+        function burn(uint256 amount) external;
+        function burnFrom(address account, uint256 amount) external;
+    }
+
+    contract StandardErc20 {
+        constructor(
+            string name,
+            string symbol,
+            uint8 decimals,
+            uint256 initialSupply,
+            address owner
+        );
+    }
+}
+
+/// Creation bytecode of the standard token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardErc20Artifact {
+    pub bytecode: Vec<u8>,
+    /// File the bytecode was read from
+    pub path: PathBuf,
+    /// Hex sha256 of `bytecode`, equal to the pinned hash it was loaded with
+    pub sha256: String,
+}
+
+impl StandardErc20Artifact {
+    /// Default artifact file (`~/.mantra_dex/contracts/StandardErc20.json`)
+    pub fn default_path() -> Result<PathBuf, Error> {
+        let home_dir = dirs::home_dir()
+            .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+        Ok(home_dir
+            .join(".mantra_dex")
+            .join("contracts")
+            .join("StandardErc20.json"))
+    }
+
+    /// Read the artifact at `path`, refusing bytecode whose sha256 is not
+    /// `pinned_sha256` (hex, `0x` optional)
+    pub fn load(path: impl AsRef<Path>, pinned_sha256: &str) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            Error::Config(format!(
+                "Failed to read ERC-20 artifact {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let bytecode = Self::parse(&contents)?;
+        let sha256 = Self::bytecode_sha256(&bytecode);
+        let pinned = pinned_sha256.trim().trim_start_matches("0x");
+        if !sha256.eq_ignore_ascii_case(pinned) {
+            return Err(Error::Config(format!(
+                "ERC-20 artifact {} has bytecode sha256 {}, not the pinned {}",
+                path.display(),
+                sha256,
+                pinned
+            )));
+        }
+        Ok(Self {
+            bytecode,
+            path: path.to_path_buf(),
+            sha256,
+        })
+    }
+
+    /// Hex sha256 of creation `bytecode`, as pinned for an audited build
+    pub fn bytecode_sha256(bytecode: &[u8]) -> String {
+        hex::encode(Sha256::digest(bytecode))
+    }
+
+    /// Creation bytecode held by an artifact file's `contents`
+    pub fn parse(contents: &str) -> Result<Vec<u8>, Error> {
+        let contents = contents.trim();
+        let hex_code = if contents.starts_with('{') {
+            let artifact: serde_json::Value = serde_json::from_str(contents)?;
+            let bytecode = artifact.get("bytecode");
+            bytecode
+                .and_then(|v| v.as_str())
+                .or_else(|| {
+                    bytecode
+                        .and_then(|v| v.get("object"))
+                        .and_then(|v| v.as_str())
+                })
+                .ok_or_else(|| Error::Config("ERC-20 artifact has no bytecode".to_string()))?
+                .to_string()
+        } else {
+            contents.to_string()
+        };
+        let hex_code = hex_code.trim_start_matches("0x");
+        if hex_code.contains("__") {
+            return Err(Error::Config(
+                "ERC-20 artifact bytecode has unlinked libraries".to_string(),
+            ));
+        }
+        let bytecode = hex::decode(hex_code)
+            .map_err(|e| Error::Config(format!("Invalid ERC-20 artifact bytecode: {}", e)))?;
+        if bytecode.is_empty() {
+            return Err(Error::Config(
+                "ERC-20 artifact bytecode is empty".to_string(),
+            ));
+        }
+        Ok(bytecode)
+    }
+}
+
+/// Constructor arguments of a standard token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StandardErc20Params {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    /// Base units minted to `owner` on deployment
+    pub initial_supply: U256,
+    /// Account receiving the initial supply and allowed to mint
+    pub owner: Address,
+}
+
+impl StandardErc20Params {
+    /// ABI-encoded constructor arguments
+    pub fn constructor_args(&self) -> Vec<u8> {
+        StandardErc20::constructorCall {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            decimals: self.decimals,
+            initialSupply: self.initial_supply,
+            owner: self.owner,
+        }
+        .abi_encode()
+    }
+
+    /// Deployment data: `bytecode` followed by the constructor arguments
+    pub fn init_code(&self, bytecode: &[u8]) -> Vec<u8> {
+        let mut data = bytecode.to_vec();
+        data.extend(self.constructor_args());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artifact_and_constructor_encoding() {
+        assert_eq!(
+            StandardErc20Artifact::parse("0x6080604052\n").unwrap(),
+            vec![0x60, 0x80, 0x60, 0x40, 0x52]
+        );
+        assert_eq!(
+            StandardErc20Artifact::parse(r#"{"bytecode": {"object": "0x6080"}}"#).unwrap(),
+            vec![0x60, 0x80]
+        );
+        assert_eq!(
+            StandardErc20Artifact::parse(r#"{"abi": [], "bytecode": "6080"}"#).unwrap(),
+            vec![0x60, 0x80]
+        );
+        assert!(StandardErc20Artifact::parse(r#"{"abi": []}"#).is_err());
+        assert!(StandardErc20Artifact::parse("0x").is_err());
+        assert!(StandardErc20Artifact::parse("0x6080__$lib$__").is_err());
+
+        // Only bytecode matching the pinned hash is loaded
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("StandardErc20.json");
+        fs::write(&path, r#"{"bytecode": "0x6080"}"#).unwrap();
+        let pinned = StandardErc20Artifact::bytecode_sha256(&[0x60, 0x80]);
+        let artifact =
+            StandardErc20Artifact::load(&path, &format!("0x{}", pinned.to_uppercase())).unwrap();
+        assert_eq!(artifact.bytecode, vec![0x60, 0x80]);
+        assert_eq!(artifact.sha256, pinned);
+        let other = StandardErc20Artifact::bytecode_sha256(&[0x60]);
+        assert!(matches!(
+            StandardErc20Artifact::load(&path, &other),
+            Err(Error::Config(_))
+        ));
+
+        let params = StandardErc20Params {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            decimals: 6,
+            initial_supply: U256::from(1_000_000u64),
+            owner: Address::repeat_byte(0x11),
+        };
+        // Head of offsets and static values, then the two strings' lengths
+        // and padded bytes
+        let expected = [
+            "00000000000000000000000000000000000000000000000000000000000000a0",
+            "00000000000000000000000000000000000000000000000000000000000000e0",
+            "0000000000000000000000000000000000000000000000000000000000000006",
+            "00000000000000000000000000000000000000000000000000000000000f4240",
+            "0000000000000000000000001111111111111111111111111111111111111111",
+            "000000000000000000000000000000000000000000000000000000000000000a",
+            "5465737420546f6b656e00000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000004",
+            "5445535400000000000000000000000000000000000000000000000000000000",
+        ]
+        .concat();
+        assert_eq!(hex::encode(params.constructor_args()), expected);
+
+        let init_code = params.init_code(&[0x60, 0x80]);
+        assert_eq!(&init_code[..2], &[0x60, 0x80]);
+        assert_eq!(hex::encode(&init_code[2..]), expected);
+    }
+}
@@ -0,0 +1,439 @@
+/// Contract interface detection
+///
+/// Tells what a contract is from two sources. Contracts implementing
+/// ERC-165 answer `supportsInterface` for the interfaces they declare. For
+/// the others, and for interfaces without an ERC-165 identifier, the
+/// bytecode is searched for function selectors: Solidity and Vyper
+/// dispatchers compare the calldata selector against each external function
+/// with a `PUSH` of the selector, so an interface whose functions all appear
+/// is taken to be implemented. Behind an EIP-1967 proxy the selectors are
+/// searched in the implementation, since the proxy's own code only forwards
+/// calls.
+use alloy_primitives::{keccak256, Address, FixedBytes};
+use alloy_sol_types::sol;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::error::Error;
+use crate::protocols::evm::client::EvmClient;
+use crate::protocols::evm::proxy::{self, ProxyInfo, ProxyKind};
+use crate::protocols::evm::types::EthAddress;
+
+sol! {
+    #[derive(Debug)]
+    interface IERC165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
+
+/// An interface a contract may implement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractInterface {
+    Erc165,
+    Erc20,
+    Erc721,
+    Erc1155,
+    AccessControl,
+    Pausable,
+}
+
+impl ContractInterface {
+    /// Every interface the detector probes for
+    pub const ALL: [ContractInterface; 6] = [
+        ContractInterface::Erc165,
+        ContractInterface::Erc20,
+        ContractInterface::Erc721,
+        ContractInterface::Erc1155,
+        ContractInterface::AccessControl,
+        ContractInterface::Pausable,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContractInterface::Erc165 => "ERC-165",
+            ContractInterface::Erc20 => "ERC-20",
+            ContractInterface::Erc721 => "ERC-721",
+            ContractInterface::Erc1155 => "ERC-1155",
+            ContractInterface::AccessControl => "AccessControl",
+            ContractInterface::Pausable => "Pausable",
+        }
+    }
+
+    /// ERC-165 identifier of the interface; ERC-20 and Pausable have none
+    pub fn erc165_id(&self) -> Option<[u8; 4]> {
+        match self {
+            ContractInterface::Erc165 => Some([0x01, 0xff, 0xc9, 0xa7]),
+            ContractInterface::Erc721 => Some([0x80, 0xac, 0x58, 0xcd]),
+            ContractInterface::Erc1155 => Some([0xd9, 0xb6, 0x7a, 0x26]),
+            ContractInterface::AccessControl => Some([0x79, 0x65, 0xdb, 0x0b]),
+            ContractInterface::Erc20 | ContractInterface::Pausable => None,
+        }
+    }
+
+    /// Functions whose selectors must all be in the bytecode for the
+    /// interface to be detected from it
+    pub fn required_functions(&self) -> &'static [&'static str] {
+        match self {
+            ContractInterface::Erc165 => &["supportsInterface(bytes4)"],
+            // `transfer` and `allowance` keep ERC-721 contracts, which share
+            // the other selectors, from passing as ERC-20
+            ContractInterface::Erc20 => &[
+                "totalSupply()",
+                "balanceOf(address)",
+                "transfer(address,uint256)",
+                "transferFrom(address,address,uint256)",
+                "approve(address,uint256)",
+                "allowance(address,address)",
+            ],
+            ContractInterface::Erc721 => &[
+                "balanceOf(address)",
+                "ownerOf(uint256)",
+                "safeTransferFrom(address,address,uint256)",
+                "transferFrom(address,address,uint256)",
+                "approve(address,uint256)",
+                "getApproved(uint256)",
+                "setApprovalForAll(address,bool)",
+                "isApprovedForAll(address,address)",
+            ],
+            ContractInterface::Erc1155 => &[
+                "balanceOf(address,uint256)",
+                "balanceOfBatch(address[],uint256[])",
+                "setApprovalForAll(address,bool)",
+                "isApprovedForAll(address,address)",
+                "safeTransferFrom(address,address,uint256,uint256,bytes)",
+                "safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)",
+            ],
+            ContractInterface::AccessControl => &[
+                "hasRole(bytes32,address)",
+                "getRoleAdmin(bytes32)",
+                "grantRole(bytes32,address)",
+                "revokeRole(bytes32,address)",
+                "renounceRole(bytes32,address)",
+            ],
+            ContractInterface::Pausable => &["paused()"],
+        }
+    }
+
+    /// Further functions commonly found with the interface, named when a
+    /// call to them is decoded but not needed to detect it
+    pub fn optional_functions(&self) -> &'static [&'static str] {
+        match self {
+            ContractInterface::Erc20 => &["name()", "symbol()", "decimals()"],
+            ContractInterface::Erc721 => &[
+                "safeTransferFrom(address,address,uint256,bytes)",
+                "name()",
+                "symbol()",
+                "tokenURI(uint256)",
+            ],
+            ContractInterface::Erc1155 => &["uri(uint256)"],
+            ContractInterface::Pausable => &["pause()", "unpause()"],
+            ContractInterface::Erc165 | ContractInterface::AccessControl => &[],
+        }
+    }
+}
+
+/// Function selector of a signature such as `transfer(address,uint256)`
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Values of the `PUSH1` to `PUSH4` instructions in `code`, as 4-byte words
+///
+/// Selectors with leading zero bytes are pushed with fewer bytes, so shorter
+/// pushes are padded on the left. The data of longer pushes is skipped so it
+/// is not read as instructions.
+pub fn pushed_selectors(code: &[u8]) -> HashSet<[u8; 4]> {
+    let mut selectors = HashSet::new();
+    let mut pc = 0;
+    while pc < code.len() {
+        let opcode = code[pc];
+        pc += 1;
+        if (0x60..=0x7f).contains(&opcode) {
+            let size = (opcode - 0x5f) as usize;
+            if size <= 4 && pc + size <= code.len() {
+                let mut word = [0u8; 4];
+                word[4 - size..].copy_from_slice(&code[pc..pc + size]);
+                selectors.insert(word);
+            }
+            pc += size;
+        }
+    }
+    selectors
+}
+
+/// Interfaces whose required functions are all among `selectors`
+pub fn interfaces_in_selectors(selectors: &HashSet<[u8; 4]>) -> Vec<ContractInterface> {
+    ContractInterface::ALL
+        .into_iter()
+        .filter(|interface| {
+            interface
+                .required_functions()
+                .iter()
+                .all(|signature| selectors.contains(&selector(signature)))
+        })
+        .collect()
+}
+
+/// Name of the function of one of `interfaces` with `function_selector`,
+/// and the interface it belongs to
+pub fn known_function(
+    function_selector: [u8; 4],
+    interfaces: &[ContractInterface],
+) -> Option<(ContractInterface, &'static str)> {
+    interfaces.iter().find_map(|interface| {
+        interface
+            .required_functions()
+            .iter()
+            .chain(interface.optional_functions())
+            .find(|signature| selector(signature) == function_selector)
+            .map(|signature| {
+                let name = signature.split('(').next().unwrap_or(signature);
+                (*interface, name)
+            })
+    })
+}
+
+/// How an interface was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionSource {
+    /// The contract's `supportsInterface` declared it
+    Erc165,
+    /// All its functions were found in the bytecode
+    Bytecode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetectedInterface {
+    pub interface: ContractInterface,
+    pub source: DetectionSource,
+}
+
+/// What a contract is
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractInspection {
+    pub address: Address,
+    /// Whether any bytecode is deployed at the address
+    pub is_contract: bool,
+    /// Implementation behind the address when it is an EIP-1967 proxy
+    pub proxy: Option<ProxyInfo>,
+    /// Contract whose bytecode was searched for selectors: the innermost
+    /// implementation behind a proxy, else the address itself
+    pub code_address: Address,
+    pub code_size: usize,
+    pub interfaces: Vec<DetectedInterface>,
+}
+
+impl ContractInspection {
+    pub fn supports(&self, interface: ContractInterface) -> bool {
+        self.interfaces
+            .iter()
+            .any(|detected| detected.interface == interface)
+    }
+
+    /// The detected interfaces, without how they were detected
+    pub fn interface_list(&self) -> Vec<ContractInterface> {
+        self.interfaces
+            .iter()
+            .map(|detected| detected.interface)
+            .collect()
+    }
+
+    /// One-line description, such as "ERC-20 token, pausable, behind an
+    /// EIP-1967 proxy"
+    pub fn summary(&self) -> String {
+        if !self.is_contract {
+            return "Externally owned account (no contract code)".to_string();
+        }
+        let mut summary = if self.supports(ContractInterface::Erc1155) {
+            "ERC-1155 multi-token contract"
+        } else if self.supports(ContractInterface::Erc721) {
+            "ERC-721 NFT collection"
+        } else if self.supports(ContractInterface::Erc20) {
+            "ERC-20 token"
+        } else {
+            "Contract with no recognized token interface"
+        }
+        .to_string();
+        if self.supports(ContractInterface::AccessControl) {
+            summary.push_str(", with role-based access control");
+        }
+        if self.supports(ContractInterface::Pausable) {
+            summary.push_str(", pausable");
+        }
+        match self.proxy.as_ref().map(|proxy| proxy.kind) {
+            Some(ProxyKind::Eip1967) => summary.push_str(", behind an EIP-1967 proxy"),
+            Some(ProxyKind::Eip1967Beacon) => summary.push_str(", behind an EIP-1967 beacon proxy"),
+            None => {}
+        }
+        summary
+    }
+}
+
+/// Probe the contract at `address` for the interfaces it implements
+///
+/// A `supportsInterface` call that reverts counts as no support, so
+/// contracts without ERC-165 are judged by their bytecode alone.
+pub async fn inspect_contract(
+    client: &EvmClient,
+    address: Address,
+) -> Result<ContractInspection, Error> {
+    let code = client.get_code(EthAddress(address), None).await?;
+    if code.is_empty() {
+        return Ok(ContractInspection {
+            address,
+            is_contract: false,
+            proxy: None,
+            code_address: address,
+            code_size: 0,
+            interfaces: Vec::new(),
+        });
+    }
+
+    let proxy = proxy::detect_proxy(client, address).await?;
+    let (code_address, code) = match &proxy {
+        Some(_) => {
+            let implementation = proxy::resolve_implementation(client, address).await?;
+            let code = client.get_code(EthAddress(implementation), None).await?;
+            (implementation, code)
+        }
+        None => (address, code),
+    };
+
+    let mut declared = Vec::new();
+    if supports_interface(client, address, [0x01, 0xff, 0xc9, 0xa7]).await
+        && !supports_interface(client, address, [0xff; 4]).await
+    {
+        for interface in ContractInterface::ALL {
+            if let Some(id) = interface.erc165_id() {
+                if supports_interface(client, address, id).await {
+                    declared.push(interface);
+                }
+            }
+        }
+    }
+
+    let from_code = interfaces_in_selectors(&pushed_selectors(&code));
+    let interfaces = ContractInterface::ALL
+        .into_iter()
+        .filter_map(|interface| {
+            let source = if declared.contains(&interface) {
+                DetectionSource::Erc165
+            } else if from_code.contains(&interface) {
+                DetectionSource::Bytecode
+            } else {
+                return None;
+            };
+            Some(DetectedInterface { interface, source })
+        })
+        .collect();
+
+    Ok(ContractInspection {
+        address,
+        is_contract: true,
+        proxy,
+        code_address,
+        code_size: code.len(),
+        interfaces,
+    })
+}
+
+/// Whether `address` answers `supportsInterface(id)` with true
+async fn supports_interface(client: &EvmClient, address: Address, id: [u8; 4]) -> bool {
+    client
+        .call_contract(
+            address,
+            IERC165::supportsInterfaceCall {
+                interfaceId: FixedBytes(id),
+            },
+        )
+        .await
+        .map(|result| result._0)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bytecode of a dispatcher comparing the calldata selector against
+    /// each of `signatures`
+    fn dispatcher(signatures: &[&str]) -> Vec<u8> {
+        let mut code = vec![0x60, 0x80, 0x60, 0x40, 0x52];
+        for signature in signatures {
+            let selector = selector(signature);
+            if selector[0] == 0 {
+                code.push(0x62);
+                code.extend_from_slice(&selector[1..]);
+            } else {
+                code.push(0x63);
+                code.extend_from_slice(&selector);
+            }
+            code.extend_from_slice(&[0x14, 0x61, 0x01, 0x00, 0x57]);
+        }
+        code
+    }
+
+    #[test]
+    fn test_interfaces_from_bytecode() {
+        assert_eq!(
+            selector("transfer(address,uint256)"),
+            [0xa9, 0x05, 0x9c, 0xbb]
+        );
+        for interface in ContractInterface::ALL {
+            if let Some(id) = interface.erc165_id() {
+                // An ERC-165 identifier is the XOR of the interface's selectors;
+                // ERC-721's includes both `safeTransferFrom` overloads
+                let mut functions = interface.required_functions().to_vec();
+                if interface == ContractInterface::Erc721 {
+                    functions.push("safeTransferFrom(address,address,uint256,bytes)");
+                }
+                let xor = functions
+                    .iter()
+                    .map(|signature| selector(signature))
+                    .fold([0u8; 4], |acc, s| {
+                        [acc[0] ^ s[0], acc[1] ^ s[1], acc[2] ^ s[2], acc[3] ^ s[3]]
+                    });
+                assert_eq!(xor, id, "{}", interface.name());
+            }
+        }
+
+        let mut token = ContractInterface::Erc20.required_functions().to_vec();
+        token.extend(ContractInterface::Pausable.required_functions());
+        let found = interfaces_in_selectors(&pushed_selectors(&dispatcher(&token)));
+        assert_eq!(
+            found,
+            vec![ContractInterface::Erc20, ContractInterface::Pausable]
+        );
+
+        // ERC-721 shares approve, transferFrom and balanceOf with ERC-20
+        let nft = ContractInterface::Erc721.required_functions();
+        assert_eq!(
+            interfaces_in_selectors(&pushed_selectors(&dispatcher(nft))),
+            vec![ContractInterface::Erc721]
+        );
+
+        // balanceOf(address,uint256) is 0x00fdd58e, pushed with PUSH3
+        let multi = ContractInterface::Erc1155.required_functions();
+        assert_eq!(
+            interfaces_in_selectors(&pushed_selectors(&dispatcher(multi))),
+            vec![ContractInterface::Erc1155]
+        );
+
+        // Selectors inside the data of longer pushes are not read
+        let mut hidden = vec![0x65, 0x00, 0x00];
+        hidden.extend_from_slice(&selector("paused()"));
+        assert!(interfaces_in_selectors(&pushed_selectors(&hidden)).is_empty());
+
+        assert_eq!(
+            known_function(selector("pause()"), &[ContractInterface::Pausable]),
+            Some((ContractInterface::Pausable, "pause"))
+        );
+        assert_eq!(
+            known_function(selector("pause()"), &[ContractInterface::Erc20]),
+            None
+        );
+    }
+}
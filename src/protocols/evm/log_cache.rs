@@ -0,0 +1,345 @@
+//! Incremental cache of EVM log scans
+//!
+//! Scanning a contract's events from its deployment block takes many
+//! `eth_getLogs` calls, which `evm_get_logs` and the approval scan used to
+//! repeat on every call. [`LogCache`] keeps the logs of each (chain,
+//! contract, topic0) with the block ranges already scanned, so a later query
+//! only fetches the blocks it has not seen. Filters on the other topics are
+//! applied to the cached logs.
+//!
+//! Each entry also keeps the hash of its highest scanned block. When the
+//! chain has another block at that height, a reorg replaced blocks the entry
+//! covers: it is cut back to its newest log whose block is still on chain,
+//! and the blocks above are scanned again. The cache itself only drops the
+//! logs; `EvmClient::get_logs_cached` records the reorg in
+//! [`crate::reorg::ReorgLog`] with the transactions of the dropped logs.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use alloy_primitives::{Address, B256};
+use alloy_rpc_types_eth::Log;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::protocols::evm::types::EventFilter;
+
+/// Blocks fetched per `eth_getLogs` call when filling a gap
+pub const LOG_SCAN_CHUNK_BLOCKS: u64 = 10_000;
+
+/// Blocks holding cached logs checked for the point a reorg forked at,
+/// newest first; an entry with none of them left on chain is dropped
+pub const REORG_SEARCH_BLOCKS: usize = 16;
+
+/// What a cache entry holds the logs of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogCacheKey {
+    pub chain_id: u64,
+    pub contract: Address,
+    /// Event signature; every event of the contract when unset
+    pub topic0: Option<B256>,
+}
+
+impl LogCacheKey {
+    /// Key of the logs `filter` selects from, when it names exactly one
+    /// contract
+    pub fn of(chain_id: u64, filter: &EventFilter) -> Option<Self> {
+        match filter.addresses.as_slice() {
+            [contract] => Some(Self {
+                chain_id,
+                contract: contract.0,
+                topic0: filter.topics.first().copied().flatten(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn file_name(&self) -> String {
+        format!(
+            "{}-{:x}-{}.json",
+            self.chain_id,
+            self.contract,
+            self.topic0
+                .map_or_else(|| "any".to_string(), |topic| format!("{:x}", topic))
+        )
+    }
+}
+
+/// Logs of one key and the blocks they were scanned from
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CachedLogs {
+    /// Scanned block ranges, inclusive, sorted and disjoint
+    pub ranges: Vec<(u64, u64)>,
+    /// Number and hash of the highest scanned block
+    pub tip: Option<(u64, B256)>,
+    /// Logs of the scanned ranges in chain order
+    pub logs: Vec<Log>,
+}
+
+impl CachedLogs {
+    /// Parts of `from..=to` not scanned yet
+    pub fn missing(&self, from: u64, to: u64) -> Vec<(u64, u64)> {
+        let mut gaps = Vec::new();
+        let mut next = from;
+        for &(start, end) in &self.ranges {
+            if next > to {
+                break;
+            }
+            if end < next {
+                continue;
+            }
+            if start > next {
+                gaps.push((next, (start - 1).min(to)));
+            }
+            next = next.max(end.saturating_add(1));
+        }
+        if next <= to {
+            gaps.push((next, to));
+        }
+        gaps
+    }
+
+    /// Record that `from..=to` was scanned and held `logs`
+    pub fn insert(&mut self, from: u64, to: u64, logs: Vec<Log>) {
+        self.ranges.push((from, to));
+        self.ranges.sort_unstable();
+        let mut merged: Vec<(u64, u64)> = Vec::with_capacity(self.ranges.len());
+        for (start, end) in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        self.ranges = merged;
+
+        self.logs.extend(logs);
+        self.logs
+            .sort_by_key(|log| (log.block_number, log.transaction_index, log.log_index));
+        self.logs.dedup_by(|a, b| {
+            a.block_number == b.block_number
+                && a.log_index == b.log_index
+                && a.transaction_hash == b.transaction_hash
+        });
+    }
+
+    /// Number and hash of each block holding cached logs, newest first
+    pub fn log_blocks(&self) -> Vec<(u64, B256)> {
+        let mut blocks: Vec<(u64, B256)> = self
+            .logs
+            .iter()
+            .rev()
+            .filter_map(|log| Some((log.block_number?, log.block_hash?)))
+            .collect();
+        blocks.dedup();
+        blocks
+    }
+
+    /// Forget everything above `kept`, the newest block known to be on chain,
+    /// or everything when there is none, returning the logs dropped
+    pub fn rewind(&mut self, kept: Option<(u64, B256)>) -> Vec<Log> {
+        let Some((block, _)) = kept else {
+            self.ranges.clear();
+            self.tip = None;
+            return std::mem::take(&mut self.logs);
+        };
+        self.ranges.retain_mut(|(start, end)| {
+            *end = (*end).min(block);
+            *start <= block
+        });
+        self.tip = kept;
+        let dropped_from = self
+            .logs
+            .partition_point(|log| log.block_number.is_some_and(|number| number <= block));
+        self.logs.split_off(dropped_from)
+    }
+
+    /// Logs between `from` and `to` whose topics after the first match
+    /// `topics`, where an unset topic matches anything
+    pub fn matching(&self, from: u64, to: u64, topics: &[Option<B256>]) -> Vec<Log> {
+        self.logs
+            .iter()
+            .filter(|log| {
+                log.block_number
+                    .is_some_and(|block| (from..=to).contains(&block))
+            })
+            .filter(|log| {
+                topics
+                    .iter()
+                    .enumerate()
+                    .skip(1)
+                    .all(|(i, topic)| topic.is_none_or(|topic| log.topics().get(i) == Some(&topic)))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Scanned logs by key, written to one JSON file per key when the cache has
+/// a directory
+#[derive(Debug, Default)]
+pub struct LogCache {
+    dir: Option<PathBuf>,
+    entries: Mutex<HashMap<LogCacheKey, CachedLogs>>,
+}
+
+impl LogCache {
+    /// Cache forgotten on exit
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Default cache directory (`~/.mantra_dex/log_cache`)
+    pub fn default_dir() -> Result<PathBuf, Error> {
+        let home_dir = dirs::home_dir()
+            .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+        Ok(home_dir.join(".mantra_dex").join("log_cache"))
+    }
+
+    /// Cache kept in `dir`, which is created if needed; entries are read
+    /// from it when first used
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, Error> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: Some(dir),
+            entries: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Entry of `key`, empty when nothing was scanned for it yet
+    pub fn get(&self, key: &LogCacheKey) -> CachedLogs {
+        if let Some(entry) = self.lock().get(key) {
+            return entry.clone();
+        }
+        let Some(path) = self.dir.as_ref().map(|dir| dir.join(key.file_name())) else {
+            return CachedLogs::default();
+        };
+        let entry = fs::read(&path)
+            .ok()
+            .and_then(|data| match serde_json::from_slice::<CachedLogs>(&data) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    tracing::warn!("Ignoring log cache file {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+        self.lock().insert(*key, entry.clone());
+        entry
+    }
+
+    /// Keep `entry` for `key`
+    pub fn put(&self, key: LogCacheKey, entry: CachedLogs) -> Result<(), Error> {
+        if let Some(dir) = &self.dir {
+            // Written aside and renamed, so a crash never leaves half an entry
+            let path = dir.join(key.file_name());
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, serde_json::to_vec(&entry)?)?;
+            fs::rename(&tmp, &path)?;
+        }
+        self.lock().insert(key, entry);
+        Ok(())
+    }
+
+    /// Drop the entry of `key`, as after a reorg
+    pub fn invalidate(&self, key: &LogCacheKey) {
+        self.lock().remove(key);
+        if let Some(dir) = &self.dir {
+            let _ = fs::remove_file(dir.join(key.file_name()));
+        }
+    }
+
+    /// Cache snapshot for health and diagnostics output
+    pub fn snapshot(&self) -> serde_json::Value {
+        let entries = self.lock();
+        serde_json::json!({
+            "entries": entries.len(),
+            "logs": entries.values().map(|entry| entry.logs.len()).sum::<usize>(),
+            "dir": self.dir.as_ref().map(|dir| dir.display().to_string()),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<LogCacheKey, CachedLogs>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::evm::types::EthAddress;
+    use alloy_primitives::{Bytes, LogData};
+
+    fn log(block: u64, index: u64, topics: Vec<B256>) -> Log {
+        Log {
+            inner: alloy_primitives::Log {
+                address: Address::repeat_byte(0x11),
+                data: LogData::new_unchecked(topics, Bytes::new()),
+            },
+            block_hash: Some(B256::with_last_byte(block as u8)),
+            block_number: Some(block),
+            log_index: Some(index),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cached_ranges_only_leave_gaps_to_scan() {
+        let transfer = B256::repeat_byte(0xaa);
+        let alice = B256::repeat_byte(0x01);
+        let bob = B256::repeat_byte(0x02);
+        let filter = EventFilter::new()
+            .addresses(vec![EthAddress(Address::repeat_byte(0x11))])
+            .topics(vec![Some(transfer), None, Some(bob)]);
+        let key = LogCacheKey::of(5887, &filter).unwrap();
+        assert_eq!(key.topic0, Some(transfer));
+        assert!(LogCacheKey::of(5887, &EventFilter::new()).is_none());
+
+        let mut entry = CachedLogs::default();
+        assert_eq!(entry.missing(10, 20), vec![(10, 20)]);
+        entry.insert(10, 20, vec![log(12, 0, vec![transfer, alice, bob])]);
+        entry.insert(30, 40, vec![log(35, 1, vec![transfer, bob, alice])]);
+        assert_eq!(entry.missing(5, 45), vec![(5, 9), (21, 29), (41, 45)]);
+        assert!(entry.missing(12, 18).is_empty());
+
+        // Filling the gap merges the ranges; logs seen twice are kept once
+        entry.insert(21, 29, vec![log(35, 1, vec![transfer, bob, alice])]);
+        assert_eq!(entry.ranges, vec![(10, 40)]);
+        assert_eq!(entry.logs.len(), 2);
+
+        let matching = entry.matching(10, 40, &filter.topics);
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].block_number, Some(12));
+        assert_eq!(entry.matching(13, 40, &[Some(transfer)]).len(), 1);
+
+        // A reorg above block 12 drops the later log and the blocks scanned
+        // after it
+        let mut forked = entry.clone();
+        assert_eq!(
+            forked.log_blocks(),
+            vec![
+                (35, B256::with_last_byte(35)),
+                (12, B256::with_last_byte(12))
+            ]
+        );
+        let dropped = forked.rewind(Some((12, B256::with_last_byte(12))));
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(forked.ranges, vec![(10, 12)]);
+        assert_eq!(forked.missing(10, 40), vec![(13, 40)]);
+        assert_eq!(forked.rewind(None).len(), 1);
+        assert!(forked.ranges.is_empty());
+
+        let dir = std::env::temp_dir().join(format!("log-cache-{}", std::process::id()));
+        let cache = LogCache::open(&dir).unwrap();
+        cache.put(key, entry.clone()).unwrap();
+        assert_eq!(LogCache::open(&dir).unwrap().get(&key), entry);
+        cache.invalidate(&key);
+        assert_eq!(
+            LogCache::open(&dir).unwrap().get(&key),
+            CachedLogs::default()
+        );
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -33,6 +33,8 @@
 #[cfg(feature = "evm")]
 pub mod abi;
 #[cfg(feature = "evm")]
+pub mod allowance;
+#[cfg(feature = "evm")]
 pub mod client;
 #[cfg(feature = "evm")]
 pub mod contracts;
@@ -43,8 +45,14 @@ pub mod erc20;
 #[cfg(feature = "evm")]
 pub mod erc721;
 #[cfg(feature = "evm")]
+pub mod interfaces;
+#[cfg(feature = "evm")]
+pub mod log_cache;
+#[cfg(feature = "evm")]
 pub mod narrative_generator;
 #[cfg(feature = "evm")]
+pub mod proxy;
+#[cfg(feature = "evm")]
 pub mod token_metadata;
 #[cfg(feature = "evm")]
 pub mod transaction_decoder;
@@ -58,13 +66,11 @@ use crate::error::Error;
 #[cfg(feature = "evm")]
 use crate::protocols::Protocol;
 #[cfg(feature = "evm")]
-use async_trait::async_trait;
+use crate::transport::SharedTransport;
 #[cfg(feature = "evm")]
-use cosmrs::rpc::HttpClient;
+use async_trait::async_trait;
 #[cfg(feature = "evm")]
 use serde_json::{json, Value};
-#[cfg(feature = "evm")]
-use std::sync::Arc;
 
 /// EVM Protocol implementation
 ///
@@ -114,7 +120,8 @@ impl EvmProtocol {
 }
 
 #[cfg(feature = "evm")]
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl Protocol for EvmProtocol {
     fn name(&self) -> &'static str {
         "evm"
@@ -124,10 +131,15 @@ impl Protocol for EvmProtocol {
         "1.0.0"
     }
 
-    async fn is_available(&self, _rpc: &HttpClient) -> Result<bool, Error> {
+    async fn is_available(&self, _transport: &SharedTransport) -> Result<bool, Error> {
         Ok(self.initialized && self.rpc_http.is_some() && self.chain_id.is_some())
     }
 
+    fn is_deployed(&self) -> bool {
+        // EVM support is part of the chain rather than a deployed contract
+        self.initialized
+    }
+
     fn get_config(&self) -> Result<Value, Error> {
         Ok(json!({
             "name": self.name(),
@@ -138,7 +150,7 @@ impl Protocol for EvmProtocol {
         }))
     }
 
-    async fn initialize(&mut self, _rpc: Arc<HttpClient>) -> Result<(), Error> {
+    async fn initialize(&mut self, _transport: SharedTransport) -> Result<(), Error> {
         // Basic initialization - RPC connectivity could be tested here
         // but we defer actual provider creation to EvmClient
         self.initialized = true;
@@ -31,6 +31,7 @@
 use crate::protocols::evm::transaction_decoder::{ContractType, DecodedCall};
 use alloy_primitives::{Address, B256};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// Configuration for narrative generation
@@ -60,6 +61,10 @@ pub struct NarrativeGenerator {
     config: NarrativeConfig,
     /// Optional EVM client for querying token metadata (decimals)
     evm_client: Option<crate::protocols::evm::client::EvmClient>,
+    /// Address book labels shown instead of abbreviated addresses
+    address_labels: HashMap<Address, String>,
+    /// Implementations of the called contracts that are proxies
+    proxy_implementations: HashMap<Address, Address>,
 }
 
 impl NarrativeGenerator {
@@ -74,6 +79,8 @@ impl NarrativeGenerator {
                 active_wallet,
             },
             evm_client: None,
+            address_labels: HashMap::new(),
+            proxy_implementations: HashMap::new(),
         }
     }
 
@@ -92,9 +99,27 @@ impl NarrativeGenerator {
                 active_wallet,
             },
             evm_client: Some(evm_client),
+            address_labels: HashMap::new(),
+            proxy_implementations: HashMap::new(),
         }
     }
 
+    /// Name labelled addresses by their address book label
+    pub fn with_address_labels(mut self, address_labels: HashMap<Address, String>) -> Self {
+        self.address_labels = address_labels;
+        self
+    }
+
+    /// Name the implementation behind called contracts that are proxies,
+    /// keyed by proxy address
+    pub fn with_proxy_implementations(
+        mut self,
+        proxy_implementations: HashMap<Address, Address>,
+    ) -> Self {
+        self.proxy_implementations = proxy_implementations;
+        self
+    }
+
     /// Generate a narrative for a single transaction
     ///
     /// # Arguments
@@ -143,9 +168,22 @@ impl NarrativeGenerator {
             ContractType::Unknown => self.generate_unknown_narrative(decoded, &from_str, &to_str),
         };
 
+        let proxy_note = to
+            .and_then(|to| {
+                self.proxy_implementations.get(&to).map(|implementation| {
+                    format!(
+                        " ({} is a proxy of implementation {})",
+                        self.format_address(to),
+                        self.format_address(*implementation)
+                    )
+                })
+            })
+            .unwrap_or_default();
+
         format!(
-            "{}{} [tx: {}]",
+            "{}{}{} [tx: {}]",
             narrative,
+            proxy_note,
             status_suffix,
             self.format_hash(tx_hash)
         )
@@ -194,6 +232,10 @@ impl NarrativeGenerator {
             }
         }
 
+        if let Some(label) = self.address_labels.get(&addr) {
+            return label.clone();
+        }
+
         if self.config.show_full_addresses {
             format!("{:?}", addr)
         } else {
@@ -685,6 +727,15 @@ mod tests {
     use alloy_primitives::{address, U256};
     use alloy_sol_types::SolCall;
 
+    #[test]
+    fn test_format_address_uses_labels() {
+        let treasury = address!("1234567890123456789012345678901234567890");
+        let generator = NarrativeGenerator::new(None)
+            .with_address_labels(HashMap::from([(treasury, "treasury".to_string())]));
+        assert_eq!(generator.format_address(treasury), "treasury");
+        assert_eq!(generator.format_address(Address::ZERO), "0x0000...0000");
+    }
+
     #[test]
     fn test_format_address_abbreviation() {
         let generator = NarrativeGenerator::new(None);
@@ -747,6 +798,40 @@ mod tests {
         assert!(narrative.contains(&generator.abbreviate_address(recipient)));
     }
 
+    #[tokio::test]
+    async fn test_proxy_narrative_names_implementation() {
+        use crate::protocols::evm::contracts::erc20::IERC20;
+
+        let proxy = address!("2222222222222222222222222222222222222222");
+        let implementation = address!("6666666666666666666666666666666666666666");
+        let generator = NarrativeGenerator::new(None)
+            .with_proxy_implementations(HashMap::from([(proxy, implementation)]));
+
+        let input = IERC20::approveCall {
+            spender: Address::ZERO,
+            amount: U256::from(1u64),
+        }
+        .abi_encode();
+        let decoded = TransactionDecoder::new()
+            .decode(&input, Some(proxy))
+            .unwrap();
+        let from = address!("1111111111111111111111111111111111111111");
+
+        let narrative = generator
+            .generate_narrative(&decoded, from, Some(proxy), B256::ZERO, true)
+            .await;
+        assert!(
+            narrative.contains("(0x2222...2222 is a proxy of implementation 0x6666...6666)"),
+            "{}",
+            narrative
+        );
+
+        let direct = generator
+            .generate_narrative(&decoded, from, Some(implementation), B256::ZERO, true)
+            .await;
+        assert!(!direct.contains("proxy"), "{}", direct);
+    }
+
     #[test]
     fn test_sequential_narrative() {
         let generator = NarrativeGenerator::new(None);
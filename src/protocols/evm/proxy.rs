@@ -0,0 +1,154 @@
+/// EIP-1967 proxy detection
+///
+/// Many RWA tokens are deployed behind an EIP-1967 proxy. The proxy holds the
+/// state and delegates every call to an implementation contract, whose address
+/// it keeps in a fixed storage slot, or which a beacon contract named in
+/// another slot returns. The proxy's own bytecode only forwards calls, so
+/// what the contract does, and which functions and interfaces it has, is
+/// learned from the implementation.
+use alloy_primitives::{b256, Address, B256, U256};
+use alloy_sol_types::sol;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::protocols::evm::client::EvmClient;
+use crate::protocols::evm::types::EthAddress;
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)`
+pub const EIP1967_IMPLEMENTATION_SLOT: B256 =
+    b256!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.admin")) - 1)`
+pub const EIP1967_ADMIN_SLOT: B256 =
+    b256!("b53127684a568b3173ae13b9f8a6016e243e63b6e8ee1178d6a717850b5d6103");
+
+/// `bytes32(uint256(keccak256("eip1967.proxy.beacon")) - 1)`
+pub const EIP1967_BEACON_SLOT: B256 =
+    b256!("a3f0ad74e5423aebfd80d3ef4346578335a9a72aeaee59ff6cb3582b35133d50");
+
+/// Proxies followed at most when resolving a proxy of a proxy
+pub const MAX_PROXY_DEPTH: usize = 4;
+
+sol! {
+    #[derive(Debug)]
+    interface IBeacon {
+        function implementation() external view returns (address);
+    }
+}
+
+/// How a proxy finds its implementation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyKind {
+    /// Implementation address in the EIP-1967 implementation slot
+    Eip1967,
+    /// Implementation returned by the beacon in the EIP-1967 beacon slot
+    Eip1967Beacon,
+}
+
+/// A proxy and the implementation it delegates to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyInfo {
+    pub proxy: Address,
+    pub implementation: Address,
+    pub kind: ProxyKind,
+    /// Account allowed to upgrade the proxy, when it keeps one in the
+    /// EIP-1967 admin slot
+    pub admin: Option<Address>,
+    /// Beacon of a beacon proxy
+    pub beacon: Option<Address>,
+}
+
+/// Address held by an EIP-1967 slot, if it holds one
+///
+/// An address fills the low 20 bytes of the slot; a zero slot, or one with
+/// any of the high 12 bytes set, holds none.
+pub fn slot_address(value: U256) -> Option<Address> {
+    let word = B256::from(value);
+    if word.is_zero() || word[..12].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(Address::from_word(word))
+}
+
+/// Whether the contract at `address` is an EIP-1967 proxy, and what it
+/// delegates to
+///
+/// Accounts without code and contracts whose slots are empty are no proxies.
+pub async fn detect_proxy(
+    client: &EvmClient,
+    address: Address,
+) -> Result<Option<ProxyInfo>, Error> {
+    let read_slot = move |slot: B256| {
+        client.get_storage_at(EthAddress(address), U256::from_be_bytes(slot.0), None)
+    };
+
+    let (implementation, kind, beacon) =
+        match slot_address(read_slot(EIP1967_IMPLEMENTATION_SLOT).await?) {
+            Some(implementation) => (implementation, ProxyKind::Eip1967, None),
+            None => {
+                let Some(beacon) = slot_address(read_slot(EIP1967_BEACON_SLOT).await?) else {
+                    return Ok(None);
+                };
+                let implementation = client
+                    .call_contract(beacon, IBeacon::implementationCall {})
+                    .await?
+                    ._0;
+                (implementation, ProxyKind::Eip1967Beacon, Some(beacon))
+            }
+        };
+    let admin = slot_address(read_slot(EIP1967_ADMIN_SLOT).await?);
+
+    Ok(Some(ProxyInfo {
+        proxy: address,
+        implementation,
+        kind,
+        admin,
+        beacon,
+    }))
+}
+
+/// Contract holding the code behind `address`: the implementation of the
+/// innermost proxy when `address` is a proxy, else `address` itself
+pub async fn resolve_implementation(
+    client: &EvmClient,
+    address: Address,
+) -> Result<Address, Error> {
+    let mut current = address;
+    for _ in 0..MAX_PROXY_DEPTH {
+        match detect_proxy(client, current).await? {
+            Some(proxy) if proxy.implementation != current => current = proxy.implementation,
+            _ => return Ok(current),
+        }
+    }
+    Err(Error::Evm(format!(
+        "Proxy chain of {:#x} is longer than {} proxies",
+        address, MAX_PROXY_DEPTH
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::keccak256;
+
+    #[test]
+    fn test_slots_and_slot_addresses() {
+        for (slot, name) in [
+            (EIP1967_IMPLEMENTATION_SLOT, "eip1967.proxy.implementation"),
+            (EIP1967_ADMIN_SLOT, "eip1967.proxy.admin"),
+            (EIP1967_BEACON_SLOT, "eip1967.proxy.beacon"),
+        ] {
+            let expected = U256::from_be_bytes(keccak256(name).0) - U256::from(1);
+            assert_eq!(U256::from_be_bytes(slot.0), expected, "{}", name);
+        }
+
+        let implementation = Address::repeat_byte(0x42);
+        assert_eq!(
+            slot_address(U256::from_be_bytes(implementation.into_word().0)),
+            Some(implementation)
+        );
+        assert_eq!(slot_address(U256::ZERO), None);
+        assert_eq!(slot_address(U256::MAX), None);
+    }
+}
@@ -32,7 +32,9 @@
 use crate::error::Error;
 use crate::protocols::evm::contracts::allowlist::IAllowlist;
 use crate::protocols::evm::contracts::erc20::IERC20;
+use crate::protocols::evm::contracts::erc721::IERC721;
 use crate::protocols::evm::contracts::primary_sale::IPrimarySale;
+use crate::protocols::evm::interfaces::{self, ContractInterface};
 use alloy_primitives::{Address, FixedBytes};
 use alloy_sol_types::SolCall;
 use serde::{Deserialize, Serialize};
@@ -262,6 +264,57 @@ impl TransactionDecoder {
         }
     }
 
+    /// Decode transaction input sent to a contract implementing `interfaces`
+    ///
+    /// The interfaces, as detected by [`interfaces::inspect_contract`], settle
+    /// what selectors alone cannot: `approve` and `transferFrom` of an ERC-721
+    /// collection share their selectors with ERC-20 and are decoded as token
+    /// IDs, and a function of a detected interface is named after it even
+    /// where the selector alone was unknown or guessed another contract type,
+    /// such as `pause()` of a Pausable contract that is not a PrimarySale.
+    pub fn decode_with_interfaces(
+        &self,
+        input: &[u8],
+        to_address: Option<Address>,
+        interfaces: &[ContractInterface],
+    ) -> Result<DecodedCall, Error> {
+        let decoded = self.decode(input, to_address)?;
+
+        let nft = interfaces.contains(&ContractInterface::Erc721)
+            && !interfaces.contains(&ContractInterface::Erc20);
+        if nft && decoded.contract_type == ContractType::ERC20 {
+            match decoded.function_name.as_str() {
+                "approve" => return Self::decode_erc721_approve(input),
+                "transferFrom" => return Self::decode_erc721_transfer_from(input),
+                _ => {}
+            }
+        }
+
+        // A detected interface outranks a guess made from the selector alone,
+        // unless the interfaces confirm the guess
+        let selector = [input[0], input[1], input[2], input[3]];
+        if let Some((interface, name)) = interfaces::known_function(selector, interfaces) {
+            let confirmed = match decoded.contract_type {
+                ContractType::ERC20 => interfaces.contains(&ContractInterface::Erc20),
+                ContractType::ERC721 => interfaces.contains(&ContractInterface::Erc721),
+                _ => false,
+            };
+            if !confirmed {
+                return Ok(DecodedCall {
+                    function_name: name.to_string(),
+                    contract_type: match interface {
+                        ContractInterface::Erc721 => ContractType::ERC721,
+                        _ => ContractType::Unknown,
+                    },
+                    parameters: serde_json::json!({}),
+                    ..decoded
+                });
+            }
+        }
+
+        Ok(decoded)
+    }
+
     // ERC-20 Decoders
     // =========================================================================
 
@@ -330,6 +383,42 @@ impl TransactionDecoder {
         })
     }
 
+    // ERC-721 Decoders
+    // =========================================================================
+
+    fn decode_erc721_approve(input: &[u8]) -> Result<DecodedCall, Error> {
+        let call = IERC721::approveCall::abi_decode(input, true)
+            .map_err(|e| Error::Other(format!("Failed to decode approve: {}", e)))?;
+
+        Ok(DecodedCall {
+            function_name: "approve".to_string(),
+            contract_type: ContractType::ERC721,
+            selector: format!("0x{}", hex::encode(IERC721::approveCall::SELECTOR)),
+            parameters: serde_json::json!({
+                "to": format!("{:?}", call.to),
+                "tokenId": call.tokenId.to_string(),
+            }),
+            raw_input: input.to_vec(),
+        })
+    }
+
+    fn decode_erc721_transfer_from(input: &[u8]) -> Result<DecodedCall, Error> {
+        let call = IERC721::transferFromCall::abi_decode(input, true)
+            .map_err(|e| Error::Other(format!("Failed to decode transferFrom: {}", e)))?;
+
+        Ok(DecodedCall {
+            function_name: "transferFrom".to_string(),
+            contract_type: ContractType::ERC721,
+            selector: format!("0x{}", hex::encode(IERC721::transferFromCall::SELECTOR)),
+            parameters: serde_json::json!({
+                "from": format!("{:?}", call.from),
+                "to": format!("{:?}", call.to),
+                "tokenId": call.tokenId.to_string(),
+            }),
+            raw_input: input.to_vec(),
+        })
+    }
+
     // PrimarySale Decoders
     // =========================================================================
 
@@ -811,4 +900,49 @@ mod tests {
         // No parameters for this function
         assert!(decoded.parameters.as_object().unwrap().is_empty());
     }
+
+    #[test]
+    fn test_decode_with_interfaces() {
+        let decoder = TransactionDecoder::new();
+        let nft = [ContractInterface::Erc165, ContractInterface::Erc721];
+
+        // approve(0x1111...1111, 7) reads as a token ID on an ERC-721 collection
+        let input = IERC20::approveCall {
+            spender: address!("1111111111111111111111111111111111111111"),
+            amount: U256::from(7u64),
+        }
+        .abi_encode();
+        let decoded = decoder.decode_with_interfaces(&input, None, &nft).unwrap();
+        assert_eq!(decoded.contract_type, ContractType::ERC721);
+        assert_eq!(decoded.parameters["tokenId"], "7");
+        let decoded = decoder
+            .decode_with_interfaces(&input, None, &[ContractInterface::Erc20])
+            .unwrap();
+        assert_eq!(decoded.contract_type, ContractType::ERC20);
+        assert_eq!(decoded.parameters["amount"], "7");
+
+        // pause() alone reads as a PrimarySale call; a contract detected as
+        // Pausable is not taken for one
+        let input = interfaces::selector("pause()").to_vec();
+        assert_eq!(
+            decoder.decode(&input, None).unwrap().contract_type,
+            ContractType::PrimarySale
+        );
+        let decoded = decoder
+            .decode_with_interfaces(&input, None, &[ContractInterface::Pausable])
+            .unwrap();
+        assert_eq!(decoded.function_name, "pause");
+        assert_eq!(decoded.contract_type, ContractType::Unknown);
+
+        // Functions no decoder knows are named after the detected interface
+        let input = interfaces::selector("paused()").to_vec();
+        assert_eq!(
+            decoder.decode(&input, None).unwrap().function_name,
+            "unknown"
+        );
+        let decoded = decoder
+            .decode_with_interfaces(&input, None, &[ContractInterface::Pausable])
+            .unwrap();
+        assert_eq!(decoded.function_name, "paused");
+    }
 }
@@ -395,7 +395,14 @@ pub enum EvmError {
 #[cfg(feature = "evm")]
 impl From<EvmError> for Error {
     fn from(err: EvmError) -> Self {
-        Error::Other(format!("EVM error: {}", err))
+        match err {
+            EvmError::InsufficientFunds => Error::InsufficientFunds {
+                needed: None,
+                available: None,
+            },
+            EvmError::TransactionReverted(reason) => Error::EvmRevert { reason },
+            other => Error::Evm(other.to_string()).refine(),
+        }
     }
 }
 
@@ -407,6 +414,18 @@ pub struct Eip1559FeeSuggestion {
     pub max_priority_fee_per_gas: U256,
 }
 
+/// Sync status of an EVM node
+#[cfg(feature = "evm")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EvmSyncStatus {
+    /// Whether the node is still catching up with the chain
+    pub syncing: bool,
+    /// Block the node has reached, while syncing
+    pub current_block: Option<u64>,
+    /// Highest block the node knows of, while syncing
+    pub highest_block: Option<u64>,
+}
+
 /// Utility functions for EVM operations
 #[cfg(feature = "evm")]
 pub mod utils {
@@ -1,19 +1,22 @@
 /// Protocol modules for the Mantra SDK
 /// Each protocol represents a different contract or feature set on the MANTRA blockchain
 pub mod claimdrop;
+pub mod cosmos_analyzer;
 pub mod dex;
+pub mod discovery;
 #[cfg(feature = "evm")]
 pub mod evm;
 pub mod skip;
 
 use crate::error::Error;
+use crate::transport::SharedTransport;
 use async_trait::async_trait;
-use cosmrs::rpc::HttpClient;
 use serde_json::Value;
 use std::sync::Arc;
 
 /// Common trait for all protocol implementations
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait Protocol: Send + Sync {
     /// Get the protocol name
     fn name(&self) -> &'static str;
@@ -21,14 +24,21 @@ pub trait Protocol: Send + Sync {
     /// Get the protocol version
     fn version(&self) -> &'static str;
 
-    /// Check if the protocol is available on the current network
-    async fn is_available(&self, rpc_client: &HttpClient) -> Result<bool, Error>;
+    /// Check if the protocol is available on the current network, asking
+    /// the chain again
+    async fn is_available(&self, transport: &SharedTransport) -> Result<bool, Error>;
+
+    /// Whether initialization found the protocol deployed on the network
+    fn is_deployed(&self) -> bool;
 
     /// Get protocol-specific configuration
     fn get_config(&self) -> Result<Value, Error>;
 
-    /// Initialize the protocol (e.g., load contract addresses)
-    async fn initialize(&mut self, rpc_client: Arc<HttpClient>) -> Result<(), Error>;
+    /// Initialize the protocol, looking up its contracts on chain
+    ///
+    /// Fails only when the chain cannot be queried; a protocol whose
+    /// contracts are missing initializes but is not deployed.
+    async fn initialize(&mut self, transport: SharedTransport) -> Result<(), Error>;
 }
 
 /// Protocol registry for managing multiple protocols
@@ -73,8 +83,17 @@ impl ProtocolRegistry {
             })
     }
 
-    /// List all registered protocols
+    /// List the registered protocols deployed on the network
     pub fn list(&self) -> Vec<&str> {
+        self.protocols
+            .iter()
+            .filter(|p| p.is_deployed())
+            .map(|p| p.name())
+            .collect()
+    }
+
+    /// List all registered protocols, deployed or not
+    pub fn list_registered(&self) -> Vec<&str> {
         self.protocols.iter().map(|p| p.name()).collect()
     }
 }
@@ -0,0 +1,194 @@
+/// Skip affiliate fees
+///
+/// An integrator that routes swaps through Skip can take a share of each swap's
+/// output by listing itself as an affiliate on the entry point message. A
+/// [`SkipAffiliateConfig`] holds the integrator's fee address and rate; clients
+/// configured with one request routes net of the fee and attach the affiliate
+/// to every swap they execute. Fees the process expects to have earned are kept
+/// in the [`AffiliateFeeLedger`].
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{OnceLock, RwLock};
+
+use super::types::SkipAffiliate;
+use crate::error::Error;
+
+/// Denominator of fees expressed in basis points
+pub const BASIS_POINTS_DENOMINATOR: u128 = 10_000;
+
+/// Highest fee an affiliate may take (10% of the swap output)
+pub const MAX_AFFILIATE_FEE_BPS: u16 = 1_000;
+
+/// Affiliate receiving a share of every Skip swap output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkipAffiliateConfig {
+    /// Address receiving the fee on the chain the swap executes on
+    pub address: String,
+    /// Share of the minimum swap output, in basis points
+    pub basis_points_fee: u16,
+}
+
+impl SkipAffiliateConfig {
+    /// Affiliate taking `basis_points_fee` of each swap output
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the address is empty or the fee is zero or above
+    /// [`MAX_AFFILIATE_FEE_BPS`].
+    pub fn new(address: impl Into<String>, basis_points_fee: u16) -> Result<Self, Error> {
+        let config = Self {
+            address: address.into().trim().to_string(),
+            basis_points_fee,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check the address and fee rate
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.address.is_empty() {
+            return Err(Error::Config(
+                "Skip affiliate address cannot be empty".to_string(),
+            ));
+        }
+        if self.basis_points_fee == 0 || self.basis_points_fee > MAX_AFFILIATE_FEE_BPS {
+            return Err(Error::Config(format!(
+                "Skip affiliate fee must be between 1 and {} basis points, got {}",
+                MAX_AFFILIATE_FEE_BPS, self.basis_points_fee
+            )));
+        }
+        Ok(())
+    }
+
+    /// Entry point representation of the affiliate
+    pub fn to_skip_affiliate(&self) -> SkipAffiliate {
+        SkipAffiliate {
+            address: self.address.clone(),
+            basis_points_fee: self.basis_points_fee.to_string(),
+        }
+    }
+
+    /// Fee taken from a swap whose guaranteed output is `min_out`
+    pub fn fee_on(&self, min_out: Uint128) -> Uint128 {
+        fee_on(min_out, self.basis_points_fee.into())
+    }
+}
+
+/// Fee the entry point sends to `affiliate` out of a swap guaranteeing `min_out`
+///
+/// Returns `None` if the affiliate's fee rate is not a number.
+pub fn affiliate_fee(affiliate: &SkipAffiliate, min_out: &Coin) -> Option<Coin> {
+    let bps = affiliate.basis_points_fee.parse().ok()?;
+    Some(Coin {
+        denom: min_out.denom.clone(),
+        amount: fee_on(min_out.amount, bps),
+    })
+}
+
+fn fee_on(amount: Uint128, bps: u128) -> Uint128 {
+    // The entry point rounds the fee down
+    amount.multiply_ratio(bps, BASIS_POINTS_DENOMINATOR)
+}
+
+/// Affiliate fees earned by this process, per affiliate address
+#[derive(Debug, Default)]
+pub struct AffiliateFeeLedger {
+    entries: RwLock<HashMap<String, BTreeMap<String, Uint128>>>,
+}
+
+impl AffiliateFeeLedger {
+    /// Process-wide ledger shared by all DEX and Skip clients
+    pub fn global() -> &'static AffiliateFeeLedger {
+        static GLOBAL: OnceLock<AffiliateFeeLedger> = OnceLock::new();
+        GLOBAL.get_or_init(AffiliateFeeLedger::default)
+    }
+
+    /// Record the fees owed to each affiliate of a swap guaranteeing `min_out`
+    pub fn record_swap(&self, affiliates: &[SkipAffiliate], min_out: &Coin) {
+        for affiliate in affiliates {
+            if let Some(fee) = affiliate_fee(affiliate, min_out) {
+                self.record(&affiliate.address, &fee);
+            }
+        }
+    }
+
+    /// Add `fee` to the total of `address`
+    pub fn record(&self, address: &str, fee: &Coin) {
+        if fee.amount.is_zero() {
+            return;
+        }
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        let total = entries
+            .entry(address.to_string())
+            .or_default()
+            .entry(fee.denom.clone())
+            .or_default();
+        *total = total.saturating_add(fee.amount);
+    }
+
+    /// Fees recorded for `address`, by denom
+    pub fn accrued(&self, address: &str) -> Vec<Coin> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(address)
+            .map(|totals| {
+                totals
+                    .iter()
+                    .map(|(denom, amount)| Coin::new(*amount, denom.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Affiliate fees accrued by a configured affiliate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffiliateFeeReport {
+    pub affiliate: SkipAffiliateConfig,
+    /// Minimum fees owed by swaps this process executed
+    pub accrued: Vec<Coin>,
+    /// Current balances of the affiliate address, which include fees paid by
+    /// other integrations and exclude anything already withdrawn
+    pub balances: Vec<Coin>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_validation_and_fee() {
+        assert!(SkipAffiliateConfig::new(" ", 50).is_err());
+        assert!(SkipAffiliateConfig::new("mantra1fee", 0).is_err());
+        assert!(SkipAffiliateConfig::new("mantra1fee", MAX_AFFILIATE_FEE_BPS + 1).is_err());
+
+        let config = SkipAffiliateConfig::new("mantra1fee", 75).unwrap();
+        assert_eq!(config.fee_on(Uint128::new(1_000_000)), Uint128::new(7_500));
+        assert_eq!(config.fee_on(Uint128::new(133)), Uint128::zero());
+        assert_eq!(config.to_skip_affiliate().basis_points_fee, "75");
+    }
+
+    #[test]
+    fn test_ledger_accumulates_fees_per_denom() {
+        let ledger = AffiliateFeeLedger::default();
+        let affiliates = vec![
+            SkipAffiliateConfig::new("mantra1fee", 100)
+                .unwrap()
+                .to_skip_affiliate(),
+            SkipAffiliate {
+                address: "mantra1bad".to_string(),
+                basis_points_fee: "abc".to_string(),
+            },
+        ];
+        ledger.record_swap(&affiliates, &Coin::new(10_000u128, "uusdc"));
+        ledger.record_swap(&affiliates, &Coin::new(5_000u128, "uusdc"));
+        ledger.record_swap(&affiliates, &Coin::new(2_000u128, "uom"));
+
+        assert_eq!(
+            ledger.accrued("mantra1fee"),
+            vec![Coin::new(20u128, "uom"), Coin::new(150u128, "uusdc")]
+        );
+        assert!(ledger.accrued("mantra1bad").is_empty());
+    }
+}
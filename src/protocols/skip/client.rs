@@ -4,18 +4,32 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use cosmwasm_std::{Coin, Decimal, Uint128};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tokio::sync::Mutex;
 use uuid::Uuid;
 
 use crate::error::Error;
+use crate::protocols::dex::MantraDexClient;
+use crate::retry::RetryPolicy;
 use crate::wallet::MantraWallet;
 
+use super::affiliate::{AffiliateFeeLedger, SkipAffiliateConfig};
+use super::estimation::{ChannelLatencyTracker, RouteBreakdown};
+use super::execution::{CrossChainSwapPlan, IbcTimeout, DEFAULT_CROSS_CHAIN_TIMEOUT_SECS};
+use super::recovery::{
+    self, PendingTransfer, RecoveryState, TransferRecovery, TransferRecoveryRegistry,
+    MAX_RECOVERY_ATTEMPTS,
+};
+use super::registry;
 use super::types::*;
+use super::verification::{
+    self, DestinationExpectation, DestinationVerification, PendingVerifications,
+};
 
 /// Skip protocol client for cross-chain operations
 #[derive(Debug)]
@@ -30,6 +44,16 @@ pub struct SkipClient {
     skip_api_base_url: String,
     /// Active transfers tracking
     active_transfers: Arc<Mutex<HashMap<String, TransferResult>>>,
+    /// DEX client that signs and broadcasts source chain transactions
+    dex_client: Option<Arc<MantraDexClient>>,
+    /// Skip entry point contracts by chain id
+    entry_points: HashMap<String, String>,
+    /// Affiliate paid out of executed swaps
+    affiliate: Option<SkipAffiliateConfig>,
+    /// RPC endpoints by chain id, used to verify deliveries
+    chain_rpcs: HashMap<String, String>,
+    /// Retries of Skip API requests that fail transiently
+    retry_policy: RetryPolicy,
 }
 
 impl SkipClient {
@@ -46,9 +70,62 @@ impl SkipClient {
             http_client,
             skip_api_base_url: "https://api.skip.money".to_string(),
             active_transfers: Arc::new(Mutex::new(HashMap::new())),
+            dex_client: None,
+            entry_points: HashMap::new(),
+            affiliate: None,
+            chain_rpcs: HashMap::new(),
+            retry_policy: RetryPolicy::default(),
         })
     }
 
+    /// Set how Skip API requests that fail transiently are retried
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Send `request`, retrying timeouts, connection failures, rate limiting
+    /// and unavailable gateways under the client's retry policy
+    ///
+    /// Other error responses are returned for the caller to report.
+    async fn send_with_retry(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, Error> {
+        self.retry_policy
+            .run("Skip API request", || {
+                let attempt = request.try_clone();
+                async move {
+                    let attempt = attempt
+                        .ok_or_else(|| Error::Skip("Request body cannot be resent".to_string()))?;
+                    let response = attempt.send().await.map_err(|e| {
+                        if e.is_timeout() {
+                            Error::Timeout(e.to_string())
+                        } else if e.is_connect() {
+                            Error::Network(e.to_string())
+                        } else {
+                            Error::Skip(e.to_string())
+                        }
+                    })?;
+                    let status = response.status();
+                    if matches!(
+                        status,
+                        StatusCode::TOO_MANY_REQUESTS
+                            | StatusCode::BAD_GATEWAY
+                            | StatusCode::SERVICE_UNAVAILABLE
+                            | StatusCode::GATEWAY_TIMEOUT
+                    ) {
+                        let body = response.text().await.unwrap_or_default();
+                        return Err(Error::Skip(format!(
+                            "Skip API returned {}: {}",
+                            status, body
+                        )));
+                    }
+                    Ok(response)
+                }
+            })
+            .await
+    }
+
     /// Set the Skip adapter contract address
     pub fn set_adapter_contract(&mut self, address: String) {
         self.adapter_contract = Some(address);
@@ -64,6 +141,56 @@ impl SkipClient {
         self.wallet = Some(wallet);
     }
 
+    /// Attach a DEX client with a wallet to sign cross-chain swaps
+    ///
+    /// The client's chain becomes the source chain of executed swaps, and its
+    /// Skip entry point (if configured) is registered for that chain. The
+    /// client's affiliate is adopted unless one is already set.
+    pub fn set_dex_client(&mut self, dex_client: Arc<MantraDexClient>) {
+        let config = dex_client.config();
+        if let Some(entry_point) = &config.contracts.skip_entry_point {
+            self.entry_points
+                .insert(config.chain_id.clone(), entry_point.clone());
+        }
+        if self.affiliate.is_none() {
+            self.affiliate = dex_client.skip_affiliate().cloned();
+        }
+        self.dex_client = Some(dex_client);
+    }
+
+    /// Set the affiliate paid out of executed swaps
+    ///
+    /// Routes are then requested net of the affiliate fee, and the affiliate
+    /// is attached to the source chain swap of cross-chain swaps.
+    pub fn set_affiliate(&mut self, affiliate: SkipAffiliateConfig) {
+        self.affiliate = Some(affiliate);
+    }
+
+    /// Get the configured affiliate
+    pub fn affiliate(&self) -> Option<&SkipAffiliateConfig> {
+        self.affiliate.as_ref()
+    }
+
+    /// Register the RPC endpoint of a chain
+    ///
+    /// Cross-chain swaps to a chain with a known endpoint are verified against
+    /// the recipient's balance there once they settle.
+    pub fn set_chain_rpc(&mut self, chain_id: String, rpc_url: String) {
+        self.chain_rpcs.insert(chain_id, rpc_url);
+    }
+
+    /// RPC endpoint registered for a chain
+    pub fn chain_rpc(&self, chain_id: &str) -> Option<&str> {
+        self.chain_rpcs.get(chain_id).map(String::as_str)
+    }
+
+    /// Register the Skip entry point contract of a chain
+    ///
+    /// Needed for every chain a route swaps on after bridging.
+    pub fn set_entry_point(&mut self, chain_id: String, address: String) {
+        self.entry_points.insert(chain_id, address);
+    }
+
     /// Get the wallet if available
     pub fn wallet(&self) -> Result<&MantraWallet, Error> {
         self.wallet
@@ -86,7 +213,13 @@ impl SkipClient {
         target_asset: &CrossChainAsset,
         options: Option<RouteOptions>,
     ) -> Result<Vec<CrossChainRoute>, Error> {
-        let opts = options.unwrap_or_default();
+        let mut opts = options.unwrap_or_default();
+        if opts.affiliate_fee_bps.is_none() {
+            opts.affiliate_fee_bps = self
+                .affiliate
+                .as_ref()
+                .map(|affiliate| affiliate.basis_points_fee.to_string());
+        }
 
         // Build route request for Skip API
         let request = json!({
@@ -102,10 +235,11 @@ impl SkipClient {
         });
 
         let response = self
-            .http_client
-            .post(format!("{}/v1/fungible/route", self.skip_api_base_url))
-            .json(&request)
-            .send()
+            .send_with_retry(
+                self.http_client
+                    .post(format!("{}/v1/fungible/route", self.skip_api_base_url))
+                    .json(&request),
+            )
             .await
             .map_err(|e| Error::Skip(format!("Failed to get route: {}", e)))?;
 
@@ -122,33 +256,43 @@ impl SkipClient {
         self.parse_routes_from_response(route_response, source_asset, target_asset)
     }
 
-    /// Monitor cross-chain transfer status and progress
+    /// Fetch the current status of a cross-chain transfer
     ///
-    /// Tracks the status of a previously initiated transfer using its unique identifier.
-    pub async fn track_transfer(&self, transfer_id: &str) -> Result<TransferResult, Error> {
+    /// `id` is either the source chain transaction hash or the identifier
+    /// returned by [`SkipClient::execute_cross_chain_swap`]. Settled
+    /// transfers are served from the local cache.
+    pub async fn get_transfer_status(
+        &self,
+        id: &str,
+        chain_id: Option<&str>,
+    ) -> Result<TransferResult, Error> {
         // First check local cache
-        {
-            let transfers = self.active_transfers.lock().await;
-            if let Some(cached_result) = transfers.get(transfer_id) {
-                // If transfer is completed or failed, return cached result
-                match cached_result.status {
-                    TransferStatus::Completed
-                    | TransferStatus::Failed
-                    | TransferStatus::TimedOut
-                    | TransferStatus::Refunded => {
-                        return Ok(cached_result.clone());
-                    }
-                    _ => {}
+        let cached = self.active_transfers.lock().await.get(id).cloned();
+        let tx_hash = match cached {
+            Some(mut cached) if cached.status.is_terminal() => {
+                // A delivery check that failed earlier is retried
+                if self.verify_settled_transfer(&mut cached).await {
+                    let mut transfers = self.active_transfers.lock().await;
+                    transfers.insert(id.to_string(), cached.clone());
                 }
+                return Ok(cached);
             }
-        }
+            // Transfers started by this client are looked up by their source tx hash
+            Some(cached) => cached.source_tx_hash.unwrap_or_else(|| id.to_string()),
+            None => id.to_string(),
+        };
 
         // Query Skip API for transfer status
+        let mut query = vec![("tx_hash", tx_hash.as_str())];
+        if let Some(chain_id) = chain_id {
+            query.push(("chain_id", chain_id));
+        }
         let response = self
-            .http_client
-            .get(format!("{}/v1/tx/track", self.skip_api_base_url))
-            .query(&[("tx_id", transfer_id)])
-            .send()
+            .send_with_retry(
+                self.http_client
+                    .get(format!("{}/v2/tx/status", self.skip_api_base_url))
+                    .query(&query),
+            )
             .await
             .map_err(|e| Error::Skip(format!("Failed to track transfer: {}", e)))?;
 
@@ -165,26 +309,253 @@ impl SkipClient {
             .await
             .map_err(|e| Error::Skip(format!("Failed to parse tracking response: {}", e)))?;
 
-        let result = self.parse_transfer_status(transfer_id, tracking_response)?;
+        // Feed observed packet latencies into route ETA estimates
+        ChannelLatencyTracker::global().record_from_tracking(&tracking_response);
+
+        let mut result = self.parse_transfer_status(id, tracking_response)?;
+        result.source_tx_hash.get_or_insert(tx_hash);
+        self.verify_settled_transfer(&mut result).await;
 
         // Update local cache
         {
             let mut transfers = self.active_transfers.lock().await;
-            transfers.insert(transfer_id.to_string(), result.clone());
+            transfers.insert(id.to_string(), result.clone());
         }
 
         Ok(result)
     }
 
+    /// Check a settled transfer against the expectation registered for it
+    ///
+    /// Shortfalls are reported in `result.verification` and
+    /// `result.error_message`. Returns whether a verification was attached; a
+    /// failed balance query leaves the expectation registered for a retry.
+    async fn verify_settled_transfer(&self, result: &mut TransferResult) -> bool {
+        if result.status != TransferStatus::Completed || result.verification.is_some() {
+            return false;
+        }
+        let Some(tx_hash) = result.source_tx_hash.clone() else {
+            return false;
+        };
+        let Some(expectation) = PendingVerifications::global().get(&tx_hash) else {
+            return false;
+        };
+
+        match self.verify_destination(&expectation).await {
+            Ok(verification) => {
+                PendingVerifications::global().remove(&tx_hash);
+                if !verification.verified {
+                    let shortfalls = verification
+                        .shortfalls()
+                        .iter()
+                        .map(|coin| coin.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    result.error_message = Some(format!(
+                        "Recipient {} on {} is short {}",
+                        verification.recipient, verification.chain_id, shortfalls
+                    ));
+                }
+                result.verification = Some(verification);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to verify delivery of {}: {}", tx_hash, e);
+                false
+            }
+        }
+    }
+
+    /// Balances of `address` in `denoms` on another chain
+    ///
+    /// Requires the chain's RPC endpoint registered with
+    /// [`SkipClient::set_chain_rpc`].
+    pub async fn destination_balances(
+        &self,
+        chain_id: &str,
+        address: &str,
+        denoms: &[String],
+    ) -> Result<Vec<Coin>, Error> {
+        let rpc_url = self
+            .chain_rpc(chain_id)
+            .ok_or_else(|| Error::Config(format!("No RPC endpoint configured for {}", chain_id)))?;
+        verification::query_balances(rpc_url, address, denoms).await
+    }
+
+    /// Record the recipient's current balances as the baseline of `expectation`
+    pub async fn capture_baseline(
+        &self,
+        expectation: DestinationExpectation,
+    ) -> Result<DestinationExpectation, Error> {
+        let baseline = self
+            .destination_balances(
+                &expectation.chain_id,
+                &expectation.recipient,
+                &expectation.denoms(),
+            )
+            .await?;
+        Ok(expectation.with_baseline(baseline))
+    }
+
+    /// Check what the recipient of `expectation` has received so far
+    pub async fn verify_destination(
+        &self,
+        expectation: &DestinationExpectation,
+    ) -> Result<DestinationVerification, Error> {
+        let observed = self
+            .destination_balances(
+                &expectation.chain_id,
+                &expectation.recipient,
+                &expectation.denoms(),
+            )
+            .await?;
+        let checked_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        Ok(DestinationVerification::evaluate(
+            expectation,
+            &observed,
+            checked_at,
+        ))
+    }
+
+    /// Verify `expectation` once the transfer sent in `tx_hash` settles
+    ///
+    /// The check runs the first time [`SkipClient::get_transfer_status`] or
+    /// transfer tracking sees the transfer completed.
+    pub fn expect_at_destination(&self, tx_hash: &str, expectation: DestinationExpectation) {
+        PendingVerifications::global().register(tx_hash, expectation);
+    }
+
+    /// Register a transaction with Skip so its packets are followed
+    ///
+    /// Skip only reports status for transactions it has been asked to track.
+    pub async fn register_transfer(&self, tx_hash: &str, chain_id: &str) -> Result<(), Error> {
+        let response = self
+            .send_with_retry(
+                self.http_client
+                    .post(format!("{}/v2/tx/track", self.skip_api_base_url))
+                    .json(&json!({ "tx_hash": tx_hash, "chain_id": chain_id })),
+            )
+            .await
+            .map_err(|e| Error::Skip(format!("Failed to register transfer: {}", e)))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(Error::Skip(format!(
+                "Skip tracking API error: {}",
+                error_text
+            )));
+        }
+        Ok(())
+    }
+
+    /// Follow a transfer until it settles or `options.timeout` passes
+    pub async fn track_transfer(
+        &self,
+        tx_hash: &str,
+        options: TransferTrackingOptions,
+    ) -> Result<TransferTracking, Error> {
+        self.track_transfer_with(tx_hash, options, |_| {}).await
+    }
+
+    /// Follow a transfer, calling `on_transition` for every status change
+    ///
+    /// The status is polled every `options.poll_interval`. A change is reported
+    /// when the status moves (e.g. InProgress → Completed) or the destination tx
+    /// hash appears. Polling stops at a final status, after `options.timeout`,
+    /// or after `options.max_consecutive_errors` failed polls in a row.
+    pub async fn track_transfer_with<F>(
+        &self,
+        tx_hash: &str,
+        options: TransferTrackingOptions,
+        mut on_transition: F,
+    ) -> Result<TransferTracking, Error>
+    where
+        F: FnMut(&TransferTransition) + Send,
+    {
+        if let Some(chain_id) = options.chain_id.as_deref() {
+            self.register_transfer(tx_hash, chain_id).await?;
+        }
+
+        let started = Instant::now();
+        let mut last: Option<TransferResult> = None;
+        let mut transitions = Vec::new();
+        let mut consecutive_errors = 0;
+        let mut polls = 0;
+
+        let timed_out = loop {
+            polls += 1;
+            match self
+                .get_transfer_status(tx_hash, options.chain_id.as_deref())
+                .await
+            {
+                Ok(result) => {
+                    consecutive_errors = 0;
+                    let changed = last.as_ref().is_none_or(|previous| {
+                        previous.status != result.status
+                            || previous.dest_tx_hash != result.dest_tx_hash
+                    });
+                    if changed {
+                        let transition = TransferTransition {
+                            tx_hash: tx_hash.to_string(),
+                            from: last.as_ref().map(|previous| previous.status.clone()),
+                            to: result.status.clone(),
+                            dest_tx_hash: result.dest_tx_hash.clone(),
+                            observed_at: SystemTime::now()
+                                .duration_since(UNIX_EPOCH)
+                                .unwrap()
+                                .as_secs(),
+                        };
+                        on_transition(&transition);
+                        transitions.push(transition);
+                    }
+                    let settled = result.status.is_terminal();
+                    last = Some(result);
+                    if settled {
+                        break false;
+                    }
+                }
+                Err(e) => {
+                    consecutive_errors += 1;
+                    if consecutive_errors >= options.max_consecutive_errors {
+                        return Err(e);
+                    }
+                }
+            }
+
+            if started.elapsed() + options.poll_interval > options.timeout {
+                break true;
+            }
+            tokio::time::sleep(options.poll_interval).await;
+        };
+
+        let result = last.ok_or_else(|| {
+            Error::Skip(format!(
+                "No status received for transfer {} within {}s",
+                tx_hash,
+                options.timeout.as_secs()
+            ))
+        })?;
+        Ok(TransferTracking {
+            result,
+            transitions,
+            timed_out,
+            polls,
+        })
+    }
+
     /// List available chains and their configurations
     ///
     /// Returns information about all chains supported by Skip protocol,
     /// including available bridges and supported assets.
     pub async fn get_supported_chains(&self) -> Result<Vec<SupportedChain>, Error> {
         let response = self
-            .http_client
-            .get(format!("{}/v1/info/chains", self.skip_api_base_url))
-            .send()
+            .send_with_retry(
+                self.http_client
+                    .get(format!("{}/v1/info/chains", self.skip_api_base_url)),
+            )
             .await
             .map_err(|e| Error::Skip(format!("Failed to get supported chains: {}", e)))?;
 
@@ -204,6 +575,51 @@ impl SkipClient {
         self.parse_supported_chains(chains_response)
     }
 
+    /// Chains Skip can route between, ordered by chain ID
+    ///
+    /// Meant for populating chain pickers; with `only_available` set, chains
+    /// Skip has currently disabled are left out.
+    pub async fn list_supported_chains(
+        &self,
+        only_available: bool,
+    ) -> Result<Vec<SupportedChain>, Error> {
+        let mut chains = self.get_supported_chains().await?;
+        if only_available {
+            chains.retain(|chain| chain.is_available);
+        }
+        chains.sort_by(|a, b| a.chain_id.cmp(&b.chain_id));
+        Ok(chains)
+    }
+
+    /// Fungible assets Skip knows on `chain_id`, ordered by symbol
+    pub async fn list_chain_assets(&self, chain_id: &str) -> Result<Vec<ChainAsset>, Error> {
+        let response = self
+            .send_with_retry(
+                self.http_client
+                    .get(format!("{}/v2/fungible/assets", self.skip_api_base_url))
+                    .query(&[("chain_ids", chain_id)]),
+            )
+            .await
+            .map_err(|e| Error::Skip(format!("Failed to get assets of {}: {}", chain_id, e)))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(Error::Skip(format!(
+                "Skip assets API error: {}",
+                error_text
+            )));
+        }
+
+        let assets_response: Value = response
+            .json()
+            .await
+            .map_err(|e| Error::Skip(format!("Failed to parse assets response: {}", e)))?;
+
+        let mut assets = registry::parse_fungible_assets(chain_id, &assets_response);
+        assets.sort_by(|a, b| a.symbol.cmp(&b.symbol).then(a.denom.cmp(&b.denom)));
+        Ok(assets)
+    }
+
     /// Validate assets across different chains
     ///
     /// Verifies that the specified assets exist and are supported for cross-chain operations.
@@ -267,6 +683,7 @@ impl SkipClient {
                     .as_secs(),
             ),
             completed_at: None,
+            verification: None,
         };
 
         // Store in active transfers
@@ -296,6 +713,330 @@ impl SkipClient {
         Ok(result)
     }
 
+    /// Plan how a cross-chain swap would be executed without broadcasting
+    ///
+    /// Uses `request.route` or, if unset, the first route Skip returns.
+    pub async fn plan_cross_chain_swap(
+        &self,
+        request: &TransferRequest,
+        timeout: IbcTimeout,
+    ) -> Result<CrossChainSwapPlan, Error> {
+        let route = match &request.route {
+            Some(route) => route.clone(),
+            None => self
+                .get_route(&request.source_asset, &request.target_asset, None)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| Error::Skip("No route found for cross-chain swap".to_string()))?,
+        };
+        let plan = CrossChainSwapPlan::from_route(&route, request, &self.entry_points, timeout)?;
+        Ok(match &self.affiliate {
+            Some(affiliate) => plan.with_affiliates(vec![affiliate.to_skip_affiliate()]),
+            None => plan,
+        })
+    }
+
+    /// Execute a cross-chain swap in a single source chain transaction
+    ///
+    /// The swap on the source chain, the IBC transfers and the swap on the
+    /// destination chain are composed from the route: forwarding and IBC hooks
+    /// instructions travel in the packet memo. The packet times out after
+    /// `request.timeout_seconds` (15 minutes by default). Requires a DEX
+    /// client attached with [`SkipClient::set_dex_client`].
+    pub async fn execute_cross_chain_swap(
+        &self,
+        request: &TransferRequest,
+    ) -> Result<TransferResult, Error> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let timeout = IbcTimeout::after_seconds(
+            now,
+            request
+                .timeout_seconds
+                .unwrap_or(DEFAULT_CROSS_CHAIN_TIMEOUT_SECS),
+        );
+        self.execute_cross_chain_swap_with_timeout(request, timeout)
+            .await
+    }
+
+    /// Execute a cross-chain swap with an explicit timeout
+    ///
+    /// Use this to bound a route that starts with a plain IBC transfer by a
+    /// height on the first hop's destination chain as well as a timestamp.
+    pub async fn execute_cross_chain_swap_with_timeout(
+        &self,
+        request: &TransferRequest,
+        timeout: IbcTimeout,
+    ) -> Result<TransferResult, Error> {
+        self.submit_cross_chain_swap(request, timeout, 0).await
+    }
+
+    /// Submit a cross-chain swap and keep it for timeout recovery
+    ///
+    /// `attempt` counts the recoveries that led to this submission.
+    async fn submit_cross_chain_swap(
+        &self,
+        request: &TransferRequest,
+        timeout: IbcTimeout,
+        attempt: u32,
+    ) -> Result<TransferResult, Error> {
+        let dex_client = self.dex_client.as_ref().ok_or_else(|| {
+            Error::Skip("No DEX client attached to sign the cross-chain swap".to_string())
+        })?;
+        let source_chain = &dex_client.config().chain_id;
+        if &request.source_asset.chain != source_chain {
+            return Err(Error::Skip(format!(
+                "Cross-chain swaps must start on {}, not {}",
+                source_chain, request.source_asset.chain
+            )));
+        }
+        let sender = dex_client.wallet()?.address()?.to_string();
+
+        self.validate_transfer_request(request).await?;
+        let plan = self.plan_cross_chain_swap(request, timeout).await?;
+        let expectation = self.delivery_expectation(request, &plan).await;
+
+        let tx = match plan.entry_point_msg(&sender) {
+            Some(msg) => {
+                let entry_point = self.entry_points.get(source_chain).ok_or_else(|| {
+                    Error::Skip(format!("No Skip entry point known for {}", source_chain))
+                })?;
+                let tx = dex_client
+                    .execute(entry_point, &msg, vec![plan.offer.clone()])
+                    .await?;
+                if let Some(swap) = &plan.source_swap {
+                    AffiliateFeeLedger::global().record_swap(&plan.affiliates, &swap.min_out);
+                }
+                tx
+            }
+            None => {
+                dex_client
+                    .broadcast_msgs(vec![plan.msg_transfer(&sender)?])
+                    .await?
+            }
+        };
+
+        // Tracking re-registers the transaction when given a chain id, so a
+        // failed registration here does not fail the swap
+        let _ = self.register_transfer(&tx.txhash, source_chain).await;
+        if let Some(expectation) = expectation {
+            self.expect_at_destination(&tx.txhash, expectation);
+        }
+
+        // The first hop escrows the swap output, or the offer itself
+        let escrowed = plan
+            .source_swap
+            .as_ref()
+            .map(|swap| swap.min_out.clone())
+            .unwrap_or_else(|| plan.offer.clone());
+        let balance_after_send = match dex_client.get_balance(&escrowed.denom).await {
+            Ok(balance) => Some(balance.amount),
+            Err(e) => {
+                tracing::warn!("Failed to read sender balance after transfer: {}", e);
+                None
+            }
+        };
+
+        let result = TransferResult {
+            transfer_id: Uuid::new_v4().to_string(),
+            status: TransferStatus::InProgress,
+            source_tx_hash: Some(tx.txhash.clone()),
+            dest_tx_hash: None,
+            amount_transferred: None,
+            error_message: None,
+            initiated_at: Some(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_secs(),
+            ),
+            completed_at: None,
+            verification: None,
+        };
+        TransferRecoveryRegistry::global().register(PendingTransfer {
+            transfer_id: result.transfer_id.clone(),
+            source_tx_hash: tx.txhash.clone(),
+            source_chain: source_chain.clone(),
+            counterparty_chain: plan
+                .hops
+                .first()
+                .map(|hop| hop.to_chain.clone())
+                .unwrap_or_else(|| plan.dest_chain.clone()),
+            sender,
+            request: request.clone(),
+            timeout,
+            escrowed,
+            balance_after_send,
+            attempt,
+            refunded_at: None,
+            retried_as: None,
+        });
+        {
+            let mut transfers = self.active_transfers.lock().await;
+            transfers.insert(result.transfer_id.clone(), result.clone());
+        }
+        Ok(result)
+    }
+
+    /// Recover a cross-chain swap whose packet did not arrive
+    ///
+    /// `id` is the transfer id or source tx hash of a swap submitted by this
+    /// process. The transfer counts as timed out once its timeout timestamp
+    /// passed, its timeout height was reached on the first hop's destination
+    /// (when that chain's RPC endpoint is registered), or Skip reports it
+    /// failed or timed out. The refund is confirmed by Skip or by the escrowed
+    /// tokens reappearing in the sender's balance. With `retry` set, confirmed
+    /// refunds are resubmitted to the original target along a fresh route, up
+    /// to [`MAX_RECOVERY_ATTEMPTS`] times.
+    pub async fn recover_transfer(&self, id: &str, retry: bool) -> Result<TransferRecovery, Error> {
+        let registry = TransferRecoveryRegistry::global();
+        let mut pending = registry
+            .get(id)
+            .ok_or_else(|| Error::Skip(format!("No recoverable transfer {}", id)))?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let mut recovery = TransferRecovery {
+            transfer_id: pending.transfer_id.clone(),
+            source_tx_hash: pending.source_tx_hash.clone(),
+            state: RecoveryState::InFlight,
+            transfer_status: None,
+            past_timeout: false,
+            refund: None,
+            retry: None,
+            attempt: pending.attempt,
+            message: None,
+            checked_at: now,
+        };
+
+        if let Some(retried_as) = &pending.retried_as {
+            recovery.state = RecoveryState::Retried;
+            recovery.refund = Some(pending.escrowed.clone());
+            recovery.message = Some(format!("Already resubmitted as {}", retried_as));
+            return Ok(recovery);
+        }
+
+        match self
+            .get_transfer_status(&pending.source_tx_hash, Some(&pending.source_chain))
+            .await
+        {
+            Ok(status) => recovery.transfer_status = Some(status.status),
+            Err(e) => tracing::warn!("Failed to get status of {}: {}", pending.source_tx_hash, e),
+        }
+        if recovery.transfer_status == Some(TransferStatus::Completed) {
+            registry.remove(&pending.transfer_id);
+            recovery.state = RecoveryState::Delivered;
+            return Ok(recovery);
+        }
+
+        if pending.refunded_at.is_none() {
+            let counterparty_height = match (
+                pending.timeout.height,
+                self.chain_rpc(&pending.counterparty_chain),
+            ) {
+                (Some(_), Some(rpc_url)) => recovery::query_latest_height(rpc_url).await.ok(),
+                _ => None,
+            };
+            recovery.past_timeout =
+                recovery::is_past_timeout(&pending.timeout, now, counterparty_height)
+                    || matches!(
+                        recovery.transfer_status,
+                        Some(
+                            TransferStatus::TimedOut
+                                | TransferStatus::Failed
+                                | TransferStatus::Refunded
+                        )
+                    );
+            if !recovery.past_timeout {
+                return Ok(recovery);
+            }
+
+            let refunded = recovery.transfer_status == Some(TransferStatus::Refunded)
+                || match &self.dex_client {
+                    Some(dex_client) => dex_client
+                        .get_balance(&pending.escrowed.denom)
+                        .await
+                        .is_ok_and(|balance| pending.refund_observed(balance.amount)),
+                    None => false,
+                };
+            if !refunded {
+                recovery.state = RecoveryState::AwaitingRefund;
+                recovery.message = Some(format!(
+                    "Waiting for a relayer to return {} to {} on {}",
+                    pending.escrowed, pending.sender, pending.source_chain
+                ));
+                return Ok(recovery);
+            }
+            pending.refunded_at = Some(now);
+            registry.register(pending.clone());
+        }
+
+        recovery.past_timeout = true;
+        recovery.state = RecoveryState::Refunded;
+        recovery.refund = Some(pending.escrowed.clone());
+        if !retry {
+            return Ok(recovery);
+        }
+        if pending.attempt >= MAX_RECOVERY_ATTEMPTS {
+            recovery.message = Some(format!(
+                "Not resubmitting after {} attempts",
+                pending.attempt
+            ));
+            return Ok(recovery);
+        }
+
+        let request = pending.retry_request();
+        let timeout = IbcTimeout::after_seconds(
+            now,
+            request
+                .timeout_seconds
+                .unwrap_or(DEFAULT_CROSS_CHAIN_TIMEOUT_SECS),
+        );
+        let result = self
+            .submit_cross_chain_swap(&request, timeout, pending.attempt + 1)
+            .await?;
+        pending.retried_as = Some(result.transfer_id.clone());
+        registry.register(pending);
+        recovery.state = RecoveryState::Retried;
+        recovery.retry = Some(result);
+        Ok(recovery)
+    }
+
+    /// Minimum delivery of a cross-chain swap, with the recipient's balance
+    /// before it as the baseline
+    ///
+    /// `None` when the destination chain has no RPC endpoint registered.
+    async fn delivery_expectation(
+        &self,
+        request: &TransferRequest,
+        plan: &CrossChainSwapPlan,
+    ) -> Option<DestinationExpectation> {
+        self.chain_rpc(&plan.dest_chain)?;
+        let min_amount = plan
+            .dest_swap
+            .as_ref()
+            .or(plan.source_swap.as_ref())
+            .map(|swap| swap.min_out.amount)
+            .unwrap_or(plan.offer.amount);
+        let expectation = DestinationExpectation::new(
+            plan.dest_chain.clone(),
+            plan.recipient.clone(),
+            vec![Coin::new(min_amount, request.target_asset.denom.clone())],
+        );
+        match self.capture_baseline(expectation.clone()).await {
+            Ok(expectation) => Some(expectation),
+            Err(e) => {
+                // Without a baseline the recipient's whole balance counts
+                tracing::warn!("Failed to capture destination balances: {}", e);
+                Some(expectation)
+            }
+        }
+    }
+
     /// Estimate fees for cross-chain operations
     ///
     /// Calculates the total fees required for a cross-chain transfer including
@@ -406,10 +1147,18 @@ impl SkipClient {
                 },
                 estimated_time_seconds: op.get("estimated_time").and_then(|t| t.as_u64()),
                 fee: op.get("fee").and_then(|f| self.parse_fee(f).ok()),
+                channel: ["channel", "source_channel"]
+                    .iter()
+                    .find_map(|key| op.get(*key).and_then(|c| c.as_str()))
+                    .map(|c| c.to_string()),
+                swap: self.parse_swap_from_operation(op),
             };
             steps.push(step);
         }
 
+        let breakdown =
+            RouteBreakdown::from_route_data(route_data, &steps, ChannelLatencyTracker::global());
+
         Ok(CrossChainRoute {
             source_chain: source.chain.clone(),
             dest_chain: target.chain.clone(),
@@ -424,6 +1173,7 @@ impl SkipClient {
                 .get("price_impact")
                 .and_then(|p| p.as_str())
                 .and_then(|s| Decimal::from_str(s).ok()),
+            breakdown: Some(breakdown),
         })
     }
 
@@ -479,6 +1229,21 @@ impl SkipClient {
         })
     }
 
+    /// Parse the venue and pool operations of a swap operation
+    fn parse_swap_from_operation(&self, operation: &Value) -> Option<SkipSwapExactAssetIn> {
+        let venue = operation.get("swap_venue")?;
+        let swap_venue_name = venue.as_str().or_else(|| venue.get("name")?.as_str())?;
+        let operations: Vec<SkipSwapOperation> =
+            serde_json::from_value(operation.get("swap_operations")?.clone()).ok()?;
+        if operations.is_empty() {
+            return None;
+        }
+        Some(SkipSwapExactAssetIn {
+            swap_venue_name: swap_venue_name.to_string(),
+            operations,
+        })
+    }
+
     /// Parse fee from response data
     fn parse_fee(&self, fee_data: &Value) -> Result<Coin, Error> {
         let denom = fee_data
@@ -511,12 +1276,17 @@ impl SkipClient {
 
         let status = match state {
             "pending" => TransferStatus::Pending,
-            "submitted" | "broadcasted" => TransferStatus::InProgress,
-            "success" | "completed" => TransferStatus::Completed,
-            "failed" | "error" => TransferStatus::Failed,
-            "timeout" => TransferStatus::TimedOut,
+            "submitted" | "broadcasted" | "STATE_SUBMITTED" | "STATE_PENDING" => {
+                TransferStatus::InProgress
+            }
+            "success" | "completed" | "STATE_COMPLETED_SUCCESS" => TransferStatus::Completed,
+            "failed" | "error" | "STATE_COMPLETED_ERROR" | "STATE_PENDING_ERROR" => {
+                TransferStatus::Failed
+            }
+            "timeout" | "STATE_ABANDONED" => TransferStatus::TimedOut,
             "refunded" => TransferStatus::Refunded,
-            _ => TransferStatus::Pending,
+            // Without an overall state, fall back to the packet lifecycle
+            _ => packet_lifecycle_status(&response).unwrap_or(TransferStatus::Pending),
         };
 
         Ok(TransferResult {
@@ -525,21 +1295,24 @@ impl SkipClient {
             source_tx_hash: response
                 .get("source_tx_hash")
                 .and_then(|h| h.as_str())
-                .map(|s| s.to_string()),
+                .map(|s| s.to_string())
+                .or_else(|| packet_tx_hash(&response, "send_tx", false)),
             dest_tx_hash: response
                 .get("dest_tx_hash")
                 .and_then(|h| h.as_str())
-                .map(|s| s.to_string()),
+                .map(|s| s.to_string())
+                .or_else(|| packet_tx_hash(&response, "receive_tx", true)),
             amount_transferred: response
                 .get("amount_received")
                 .and_then(|a| a.as_str())
                 .and_then(|s| Uint128::from_str(s).ok()),
             error_message: response
                 .get("error")
-                .and_then(|e| e.as_str())
+                .and_then(|e| e.as_str().or_else(|| e.get("message")?.as_str()))
                 .map(|s| s.to_string()),
             initiated_at: response.get("initiated_at").and_then(|t| t.as_u64()),
             completed_at: response.get("completed_at").and_then(|t| t.as_u64()),
+            verification: None,
         })
     }
 
@@ -553,7 +1326,7 @@ impl SkipClient {
         let mut result = Vec::new();
 
         for chain_data in chains {
-            let chain = SupportedChain {
+            let mut chain = SupportedChain {
                 chain_id: chain_data
                     .get("chain_id")
                     .and_then(|c| c.as_str())
@@ -583,7 +1356,9 @@ impl SkipClient {
                     .and_then(|b| b.as_array())
                     .map(|bridges| self.parse_bridge_info(bridges))
                     .unwrap_or_default(),
+                rpc_endpoint: None,
             };
+            chain.rpc_endpoint = self.chain_rpc(&chain.chain_id).map(str::to_string);
             result.push(chain);
         }
 
@@ -604,6 +1379,10 @@ impl SkipClient {
                         .get("contract_address")
                         .and_then(|c| c.as_str())
                         .map(|s| s.to_string()),
+                    trace: asset
+                        .get("trace")
+                        .and_then(|t| t.as_str())
+                        .map(|s| s.to_string()),
                 })
             })
             .collect()
@@ -640,10 +1419,11 @@ impl SkipClient {
     async fn verify_single_asset(&self, asset: &CrossChainAsset) -> Result<VerifiedAsset, Error> {
         // Query Skip API for asset verification
         let response = self
-            .http_client
-            .get(format!("{}/v1/fungible/assets", self.skip_api_base_url))
-            .query(&[("chain_id", &asset.chain), ("denom", &asset.denom)])
-            .send()
+            .send_with_retry(
+                self.http_client
+                    .get(format!("{}/v1/fungible/assets", self.skip_api_base_url))
+                    .query(&[("chain_id", &asset.chain), ("denom", &asset.denom)]),
+            )
             .await
             .map_err(|e| Error::Skip(format!("Failed to verify asset: {}", e)))?;
 
@@ -744,6 +1524,58 @@ impl SkipClient {
     }
 }
 
+/// IBC packets of every hop in a tracking response
+fn ibc_packets(response: &Value) -> Vec<&Value> {
+    response
+        .get("transfer_sequence")
+        .and_then(|s| s.as_array())
+        .map(|sequence| {
+            sequence
+                .iter()
+                .filter_map(|hop| hop.get("ibc_transfer")?.get("packet"))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Hash of a packet transaction, from the first hop or the last one
+fn packet_tx_hash(response: &Value, tx: &str, last_hop: bool) -> Option<String> {
+    let packets = ibc_packets(response);
+    let packet = if last_hop {
+        packets.last()
+    } else {
+        packets.first()
+    }?;
+    packet
+        .get(tx)?
+        .get("tx_hash")?
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+/// Status implied by the IBC packet lifecycle (send → receive → acknowledge)
+fn packet_lifecycle_status(response: &Value) -> Option<TransferStatus> {
+    let packets = ibc_packets(response);
+    if packets.is_empty() {
+        return None;
+    }
+    let has = |packet: &Value, tx: &str| packet.get(tx).is_some_and(|t| !t.is_null());
+    if packets.iter().any(|packet| has(packet, "timeout_tx")) {
+        return Some(TransferStatus::TimedOut);
+    }
+    if packets.iter().any(|packet| has(packet, "error")) {
+        return Some(TransferStatus::Failed);
+    }
+    let settled = packets
+        .iter()
+        .all(|packet| has(packet, "receive_tx") && has(packet, "acknowledge_tx"));
+    Some(if settled {
+        TransferStatus::Completed
+    } else {
+        TransferStatus::InProgress
+    })
+}
+
 // ============================================================================
 // Supporting Types
 // ============================================================================
@@ -761,6 +1593,31 @@ pub struct RouteOptions {
     pub affiliate_fee_bps: Option<String>,
 }
 
+/// Polling settings for [`SkipClient::track_transfer`]
+#[derive(Debug, Clone)]
+pub struct TransferTrackingOptions {
+    /// Source chain of the transaction; when set the transaction is registered
+    /// with Skip before polling
+    pub chain_id: Option<String>,
+    /// Delay between status polls
+    pub poll_interval: Duration,
+    /// Give up after this long without a final status
+    pub timeout: Duration,
+    /// Failed polls in a row before tracking aborts
+    pub max_consecutive_errors: u32,
+}
+
+impl Default for TransferTrackingOptions {
+    fn default() -> Self {
+        Self {
+            chain_id: None,
+            poll_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(10 * 60),
+            max_consecutive_errors: 3,
+        }
+    }
+}
+
 /// Result of asset verification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetVerificationResult {
@@ -819,3 +1676,51 @@ pub struct GasEstimate {
     /// Estimated fee
     pub estimated_fee: Coin,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hop(receive: bool, acknowledge: bool) -> Value {
+        let tx = |hash: &str| json!({ "chain_id": "mantra-1", "tx_hash": hash });
+        json!({
+            "ibc_transfer": {
+                "packet": {
+                    "send_tx": tx("SEND"),
+                    "receive_tx": if receive { tx("RECV") } else { Value::Null },
+                    "acknowledge_tx": if acknowledge { tx("ACK") } else { Value::Null },
+                    "timeout_tx": null,
+                    "error": null
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_packet_lifecycle_status_and_hashes() {
+        let in_flight = json!({ "transfer_sequence": [hop(true, true), hop(false, false)] });
+        assert_eq!(
+            packet_lifecycle_status(&in_flight),
+            Some(TransferStatus::InProgress)
+        );
+        assert_eq!(packet_tx_hash(&in_flight, "receive_tx", true), None);
+
+        let settled = json!({ "transfer_sequence": [hop(true, true), hop(true, true)] });
+        assert_eq!(
+            packet_lifecycle_status(&settled),
+            Some(TransferStatus::Completed)
+        );
+        assert_eq!(
+            packet_tx_hash(&settled, "receive_tx", true).as_deref(),
+            Some("RECV")
+        );
+
+        let mut timed_out = hop(false, false);
+        timed_out["ibc_transfer"]["packet"]["timeout_tx"] = json!({ "tx_hash": "TIMEOUT" });
+        assert_eq!(
+            packet_lifecycle_status(&json!({ "transfer_sequence": [timed_out] })),
+            Some(TransferStatus::TimedOut)
+        );
+        assert_eq!(packet_lifecycle_status(&json!({})), None);
+    }
+}
@@ -0,0 +1,450 @@
+/// Fee and completion-time breakdown for Skip routes
+///
+/// Every leg of a route is annotated with the bridge, swap and relayer fees it
+/// charges and an estimated duration. Durations come from packet latencies
+/// observed on the leg's IBC channel when available, then from the Skip API's
+/// own estimate, then from a per-step-type default. Routes can then be ranked
+/// explicitly by cost or by speed.
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+use super::types::{CrossChainRoute, RouteStep, RouteStepType};
+use crate::error::Error;
+
+/// Latency samples kept per channel; older samples are discarded first
+pub const MAX_LATENCY_SAMPLES_PER_CHANNEL: usize = 100;
+
+/// Category of a fee charged by a route leg
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeKind {
+    Bridge,
+    Swap,
+    Relayer,
+}
+
+/// A single fee charged by a route leg
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegFee {
+    pub kind: FeeKind,
+    pub amount: Coin,
+    /// USD value reported by the Skip API, if any
+    pub usd_amount: Option<Decimal>,
+}
+
+/// Where a leg's duration estimate came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EtaSource {
+    /// Median of packet latencies observed on the leg's channel
+    Historical,
+    /// Estimate returned by the Skip API
+    Api,
+    /// Fallback for the step type
+    Default,
+}
+
+/// Fees and duration estimate for one route leg
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegBreakdown {
+    pub index: usize,
+    pub chain: String,
+    pub step_type: RouteStepType,
+    /// IBC channel used by the leg, if any
+    pub channel: Option<String>,
+    pub fees: Vec<LegFee>,
+    pub estimated_seconds: u64,
+    pub eta_source: EtaSource,
+}
+
+/// Per-leg fees and completion time for a route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteBreakdown {
+    pub legs: Vec<LegBreakdown>,
+    pub bridge_fees: Vec<Coin>,
+    pub swap_fees: Vec<Coin>,
+    pub relayer_fees: Vec<Coin>,
+    /// Total fees in USD; `None` unless every fee has a USD value
+    pub total_usd: Option<Decimal>,
+    pub estimated_completion_seconds: u64,
+}
+
+impl RouteBreakdown {
+    /// Build the breakdown for a route from the raw Skip API route data
+    pub fn from_route_data(
+        route_data: &Value,
+        steps: &[RouteStep],
+        latencies: &ChannelLatencyTracker,
+    ) -> Self {
+        let empty = vec![];
+        let operations = route_data
+            .get("operations")
+            .and_then(|ops| ops.as_array())
+            .unwrap_or(&empty);
+
+        let mut legs: Vec<LegBreakdown> = steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| {
+                let op = operations.get(index).unwrap_or(&Value::Null);
+                let channel = ["channel", "source_channel"]
+                    .iter()
+                    .find_map(|key| op.get(*key).and_then(|c| c.as_str()))
+                    .map(|c| c.to_string());
+
+                let mut fees = Vec::new();
+                if let Some(fee) = op.get("fee").and_then(parse_leg_fee_amount) {
+                    let kind = match step.step_type {
+                        RouteStepType::Swap => FeeKind::Swap,
+                        _ => FeeKind::Bridge,
+                    };
+                    fees.push(LegFee {
+                        kind,
+                        amount: fee.0,
+                        usd_amount: fee.1,
+                    });
+                }
+                for (key, kind) in [
+                    ("bridge_fee", FeeKind::Bridge),
+                    ("swap_fee", FeeKind::Swap),
+                    ("relayer_fee", FeeKind::Relayer),
+                ] {
+                    if let Some((amount, usd_amount)) = op.get(key).and_then(parse_leg_fee_amount) {
+                        fees.push(LegFee {
+                            kind,
+                            amount,
+                            usd_amount,
+                        });
+                    }
+                }
+
+                let historical = channel
+                    .as_deref()
+                    .and_then(|channel| latencies.estimate_seconds(&step.chain, channel));
+                let (estimated_seconds, eta_source) =
+                    match (historical, step.estimated_time_seconds) {
+                        (Some(seconds), _) => (seconds, EtaSource::Historical),
+                        (None, Some(seconds)) => (seconds, EtaSource::Api),
+                        (None, None) => (default_leg_seconds(&step.step_type), EtaSource::Default),
+                    };
+
+                LegBreakdown {
+                    index,
+                    chain: step.chain.clone(),
+                    step_type: step.step_type.clone(),
+                    channel,
+                    fees,
+                    estimated_seconds,
+                    eta_source,
+                }
+            })
+            .collect();
+
+        // Route-level fees (e.g. smart relay) point at the leg they belong to
+        if let Some(route_fees) = route_data.get("estimated_fees").and_then(|f| f.as_array()) {
+            for fee in route_fees {
+                let Some((amount, usd_amount)) = parse_leg_fee_amount(fee) else {
+                    continue;
+                };
+                let kind = match fee.get("fee_type").and_then(|t| t.as_str()) {
+                    Some(t) if t.eq_ignore_ascii_case("swap") => FeeKind::Swap,
+                    Some(t) if t.eq_ignore_ascii_case("bridge") => FeeKind::Bridge,
+                    _ => FeeKind::Relayer,
+                };
+                let index = fee
+                    .get("operation_index")
+                    .and_then(|i| i.as_u64())
+                    .map(|i| i as usize)
+                    .filter(|i| *i < legs.len())
+                    .unwrap_or(0);
+                if let Some(leg) = legs.get_mut(index) {
+                    leg.fees.push(LegFee {
+                        kind,
+                        amount,
+                        usd_amount,
+                    });
+                }
+            }
+        }
+
+        let legs_total: u64 = legs.iter().map(|leg| leg.estimated_seconds).sum();
+        let api_total = route_data.get("estimated_time").and_then(|t| t.as_u64());
+        // Observed latencies beat the API's route-wide estimate
+        let estimated_completion_seconds = if legs
+            .iter()
+            .any(|leg| leg.eta_source == EtaSource::Historical)
+        {
+            legs_total
+        } else {
+            api_total.unwrap_or(legs_total)
+        };
+
+        let fees: Vec<&LegFee> = legs.iter().flat_map(|leg| leg.fees.iter()).collect();
+        let total_usd = fees
+            .iter()
+            .map(|fee| fee.usd_amount)
+            .sum::<Option<Decimal>>();
+
+        Self {
+            bridge_fees: sum_fees(&fees, FeeKind::Bridge),
+            swap_fees: sum_fees(&fees, FeeKind::Swap),
+            relayer_fees: sum_fees(&fees, FeeKind::Relayer),
+            total_usd,
+            estimated_completion_seconds,
+            legs,
+        }
+    }
+}
+
+/// Parse `{denom, amount, usd_amount}` (or Skip's `origin_asset.denom`) into a fee
+fn parse_leg_fee_amount(fee: &Value) -> Option<(Coin, Option<Decimal>)> {
+    let denom = fee
+        .get("denom")
+        .or_else(|| fee.get("origin_asset").and_then(|a| a.get("denom")))
+        .and_then(|d| d.as_str())?;
+    let amount = fee
+        .get("amount")
+        .and_then(|a| a.as_str())
+        .and_then(|s| Uint128::from_str(s).ok())?;
+    let usd_amount = fee
+        .get("usd_amount")
+        .and_then(|u| u.as_str())
+        .and_then(|s| Decimal::from_str(s).ok());
+    Some((Coin::new(amount, denom), usd_amount))
+}
+
+fn sum_fees(fees: &[&LegFee], kind: FeeKind) -> Vec<Coin> {
+    let mut totals: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for fee in fees.iter().filter(|fee| fee.kind == kind) {
+        *totals.entry(fee.amount.denom.as_str()).or_default() += fee.amount.amount;
+    }
+    totals
+        .into_iter()
+        .map(|(denom, amount)| Coin::new(amount, denom))
+        .collect()
+}
+
+/// Fallback duration for a leg without API or historical estimates
+pub fn default_leg_seconds(step_type: &RouteStepType) -> u64 {
+    match step_type {
+        RouteStepType::Swap | RouteStepType::Transfer => 6,
+        RouteStepType::IbcTransfer => 30,
+        RouteStepType::Bridge => 600,
+    }
+}
+
+/// How routes should be ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutePreference {
+    /// Lowest total USD fees first; routes without USD pricing go last
+    Cheapest,
+    /// Shortest estimated completion time first
+    Fastest,
+}
+
+impl FromStr for RoutePreference {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "cheapest" => Ok(Self::Cheapest),
+            "fastest" => Ok(Self::Fastest),
+            other => Err(Error::Skip(format!(
+                "Unknown route preference '{}', expected cheapest or fastest",
+                other
+            ))),
+        }
+    }
+}
+
+/// Order routes by preference; the sort is stable so API order breaks ties
+pub fn rank_routes(routes: &mut [CrossChainRoute], preference: RoutePreference) {
+    match preference {
+        RoutePreference::Cheapest => routes.sort_by_key(|route| {
+            let usd = route.breakdown.as_ref().and_then(|b| b.total_usd);
+            (usd.is_none(), usd)
+        }),
+        RoutePreference::Fastest => routes.sort_by_key(|route| {
+            route
+                .breakdown
+                .as_ref()
+                .map(|b| b.estimated_completion_seconds)
+                .or(route.estimated_time_seconds)
+                .unwrap_or(u64::MAX)
+        }),
+    }
+}
+
+/// Observed IBC packet latencies per chain and channel
+#[derive(Debug, Default)]
+pub struct ChannelLatencyTracker {
+    samples: RwLock<HashMap<(String, String), VecDeque<u64>>>,
+}
+
+impl ChannelLatencyTracker {
+    /// Process-wide tracker shared by all Skip clients
+    pub fn global() -> &'static ChannelLatencyTracker {
+        static GLOBAL: OnceLock<ChannelLatencyTracker> = OnceLock::new();
+        GLOBAL.get_or_init(ChannelLatencyTracker::default)
+    }
+
+    /// Record how long a packet on `channel` of `chain` took to be received
+    pub fn record(&self, chain: &str, channel: &str, latency: Duration) {
+        let mut samples = self.samples.write().unwrap_or_else(|e| e.into_inner());
+        let entry = samples
+            .entry((chain.to_string(), channel.to_string()))
+            .or_default();
+        if entry.len() == MAX_LATENCY_SAMPLES_PER_CHANNEL {
+            entry.pop_front();
+        }
+        entry.push_back(latency.as_secs());
+    }
+
+    /// Record packet latencies from a Skip tracking response
+    ///
+    /// Each `transfer_sequence` entry with an `ibc_transfer` carrying
+    /// `src_chain_id`, `src_channel_id`, `send_time` and `receive_time` (unix
+    /// seconds) contributes one sample. Packets still in flight are skipped.
+    pub fn record_from_tracking(&self, tracking_response: &Value) {
+        let Some(sequence) = tracking_response
+            .get("transfer_sequence")
+            .and_then(|s| s.as_array())
+        else {
+            return;
+        };
+        for transfer in sequence.iter().filter_map(|t| t.get("ibc_transfer")) {
+            let field = |key: &str| transfer.get(key).and_then(|v| v.as_str());
+            let time = |key: &str| transfer.get(key).and_then(|v| v.as_u64());
+            if let (Some(chain), Some(channel), Some(sent), Some(received)) = (
+                field("src_chain_id"),
+                field("src_channel_id"),
+                time("send_time"),
+                time("receive_time"),
+            ) {
+                self.record(
+                    chain,
+                    channel,
+                    Duration::from_secs(received.saturating_sub(sent)),
+                );
+            }
+        }
+    }
+
+    /// Median observed latency in seconds, if the channel has samples
+    pub fn estimate_seconds(&self, chain: &str, channel: &str) -> Option<u64> {
+        let samples = self.samples.read().unwrap_or_else(|e| e.into_inner());
+        let entry = samples.get(&(chain.to_string(), channel.to_string()))?;
+        let mut sorted: Vec<u64> = entry.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted.get(sorted.len() / 2).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::skip::types::CrossChainAsset;
+    use serde_json::json;
+
+    fn step(chain: &str, step_type: RouteStepType, eta: Option<u64>) -> RouteStep {
+        let asset = CrossChainAsset {
+            denom: "uom".to_string(),
+            amount: Uint128::new(1_000),
+            chain: chain.to_string(),
+            decimals: None,
+            symbol: None,
+        };
+        RouteStep {
+            chain: chain.to_string(),
+            step_type,
+            asset_in: asset.clone(),
+            asset_out: asset,
+            estimated_time_seconds: eta,
+            fee: None,
+            channel: None,
+            swap: None,
+        }
+    }
+
+    #[test]
+    fn test_breakdown_classifies_fees_and_prefers_observed_latency() {
+        let latencies = ChannelLatencyTracker::default();
+        for secs in [20, 40, 25] {
+            latencies.record("mantra-1", "channel-0", Duration::from_secs(secs));
+        }
+
+        let route_data = json!({
+            "estimated_time": 300,
+            "operations": [
+                { "fee": { "denom": "uom", "amount": "30", "usd_amount": "0.03" } },
+                { "channel": "channel-0", "bridge_fee": { "denom": "uom", "amount": "10", "usd_amount": "0.01" } }
+            ],
+            "estimated_fees": [
+                { "fee_type": "SMART_RELAY", "operation_index": 1, "amount": "5", "origin_asset": { "denom": "uusdc" }, "usd_amount": "0.005" }
+            ]
+        });
+        let steps = vec![
+            step("mantra-1", RouteStepType::Swap, Some(6)),
+            step("mantra-1", RouteStepType::IbcTransfer, Some(120)),
+        ];
+
+        let breakdown = RouteBreakdown::from_route_data(&route_data, &steps, &latencies);
+        assert_eq!(breakdown.swap_fees, vec![Coin::new(30u128, "uom")]);
+        assert_eq!(breakdown.bridge_fees, vec![Coin::new(10u128, "uom")]);
+        assert_eq!(breakdown.relayer_fees, vec![Coin::new(5u128, "uusdc")]);
+        assert_eq!(
+            breakdown.total_usd,
+            Some(Decimal::from_str("0.045").unwrap())
+        );
+
+        assert_eq!(breakdown.legs[0].eta_source, EtaSource::Api);
+        assert_eq!(breakdown.legs[1].eta_source, EtaSource::Historical);
+        assert_eq!(breakdown.legs[1].estimated_seconds, 25);
+        assert_eq!(breakdown.estimated_completion_seconds, 31);
+    }
+
+    #[test]
+    fn test_rank_routes_by_preference() {
+        let route = |usd: Option<&str>, seconds: u64| CrossChainRoute {
+            source_chain: "a".to_string(),
+            dest_chain: "b".to_string(),
+            steps: vec![],
+            estimated_time_seconds: None,
+            estimated_fees: vec![],
+            price_impact: None,
+            breakdown: Some(RouteBreakdown {
+                legs: vec![],
+                bridge_fees: vec![],
+                swap_fees: vec![],
+                relayer_fees: vec![],
+                total_usd: usd.map(|u| Decimal::from_str(u).unwrap()),
+                estimated_completion_seconds: seconds,
+            }),
+        };
+        let mut routes = vec![route(None, 10), route(Some("2"), 60), route(Some("1"), 600)];
+
+        rank_routes(&mut routes, RoutePreference::Cheapest);
+        let order: Vec<u64> = routes
+            .iter()
+            .map(|r| r.breakdown.as_ref().unwrap().estimated_completion_seconds)
+            .collect();
+        assert_eq!(order, vec![600, 60, 10]);
+
+        rank_routes(&mut routes, RoutePreference::Fastest);
+        assert_eq!(
+            routes[0]
+                .breakdown
+                .as_ref()
+                .unwrap()
+                .estimated_completion_seconds,
+            10
+        );
+        assert!("slowest".parse::<RoutePreference>().is_err());
+    }
+}
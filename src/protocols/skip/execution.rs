@@ -0,0 +1,573 @@
+/// Cross-chain swap execution planning
+///
+/// Turns a Skip route into the messages that execute it in one source-chain
+/// transaction: a swap through the source chain's Skip entry point (or a plain
+/// IBC transfer when the route starts by bridging), packet-forward memos for
+/// intermediate hops, and an IBC hooks `wasm` memo that runs the destination
+/// swap through that chain's entry point.
+use std::collections::HashMap;
+
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::Any;
+use cosmwasm_std::{Coin, Decimal};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::types::{
+    CrossChainRoute, RouteStep, RouteStepType, SkipAction, SkipAffiliate, SkipAsset,
+    SkipEntryPointExecuteMsg, SkipIbcInfo, SkipSwap, SkipSwapExactAssetIn, TransferRequest,
+};
+use crate::error::Error;
+
+/// Timeout applied when a transfer request does not set one (15 minutes)
+pub const DEFAULT_CROSS_CHAIN_TIMEOUT_SECS: u64 = 900;
+
+/// Slippage applied to swap outputs when a transfer request does not set one
+pub const DEFAULT_CROSS_CHAIN_SLIPPAGE_PERCENT: u64 = 1;
+
+/// Receiver used on intermediate hops; packet-forward middleware ignores it
+pub const FORWARD_RECEIVER_PLACEHOLDER: &str = "pfm";
+
+/// Port of ICS-20 fungible token transfers
+pub const IBC_TRANSFER_PORT: &str = "transfer";
+
+/// Type URL of an ICS-20 transfer message
+pub const MSG_TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+
+/// Block height on a counterparty chain
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IbcHeight {
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl IbcHeight {
+    /// Revision number encoded in a chain id's `-{n}` suffix (`osmosis-1` → 1)
+    ///
+    /// Chain ids without a numeric suffix use revision 0.
+    pub fn revision_number(chain_id: &str) -> u64 {
+        chain_id
+            .rsplit_once('-')
+            .and_then(|(_, revision)| revision.parse().ok())
+            .unwrap_or(0)
+    }
+
+    /// Height `blocks` past `current_height` on `chain_id`
+    pub fn after(chain_id: &str, current_height: u64, blocks: u64) -> Self {
+        Self {
+            revision_number: Self::revision_number(chain_id),
+            revision_height: current_height.saturating_add(blocks),
+        }
+    }
+}
+
+/// When an IBC packet stops being deliverable
+///
+/// The height only applies to routes that start with a plain IBC transfer;
+/// Skip entry point messages carry the timestamp alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IbcTimeout {
+    /// Height on the first hop's destination chain, if bounded by height
+    pub height: Option<IbcHeight>,
+    /// Unix timestamp in nanoseconds
+    pub timestamp_nanos: u64,
+}
+
+impl IbcTimeout {
+    /// Timeout `secs` seconds after `now_secs` (unix seconds)
+    pub fn after_seconds(now_secs: u64, secs: u64) -> Self {
+        Self {
+            height: None,
+            timestamp_nanos: now_secs.saturating_add(secs).saturating_mul(1_000_000_000),
+        }
+    }
+
+    /// Also bound the timeout by a counterparty height
+    pub fn with_height(mut self, height: IbcHeight) -> Self {
+        self.height = Some(height);
+        self
+    }
+}
+
+/// One IBC hop of a route
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferHop {
+    pub from_chain: String,
+    pub to_chain: String,
+    /// Source channel on `from_chain`
+    pub channel: String,
+}
+
+/// Swap executed through a Skip entry point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedSwap {
+    pub chain: String,
+    pub swap: SkipSwapExactAssetIn,
+    /// Output reported by the route
+    pub expected_out: Coin,
+    /// Output guaranteed by the slippage tolerance
+    pub min_out: Coin,
+}
+
+/// Messages and memo that execute a cross-chain swap route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossChainSwapPlan {
+    pub source_chain: String,
+    pub dest_chain: String,
+    pub offer: Coin,
+    pub recipient: String,
+    pub source_swap: Option<PlannedSwap>,
+    pub hops: Vec<TransferHop>,
+    pub dest_swap: Option<PlannedSwap>,
+    /// Entry point running `dest_swap` through IBC hooks
+    pub dest_entry_point: Option<String>,
+    /// Receiver of the first hop's packet
+    pub first_hop_receiver: String,
+    /// Memo of the first hop's packet (forwarding and hooks)
+    pub memo: Option<String>,
+    pub timeout: IbcTimeout,
+    /// Affiliates paid out of the source chain swap
+    #[serde(default)]
+    pub affiliates: Vec<SkipAffiliate>,
+}
+
+impl CrossChainSwapPlan {
+    /// Plan the execution of `route` for `request`
+    ///
+    /// Supported routes are an optional swap on the source chain, one or more
+    /// IBC transfers, then an optional swap on the destination chain.
+    /// `entry_points` maps chain ids to Skip entry point contracts and must
+    /// contain the destination chain when the route swaps there.
+    pub fn from_route(
+        route: &CrossChainRoute,
+        request: &TransferRequest,
+        entry_points: &HashMap<String, String>,
+        timeout: IbcTimeout,
+    ) -> Result<Self, Error> {
+        let slippage = request
+            .slippage_tolerance
+            .unwrap_or(Decimal::percent(DEFAULT_CROSS_CHAIN_SLIPPAGE_PERCENT));
+        if slippage >= Decimal::one() {
+            return Err(Error::Skip(format!(
+                "Slippage tolerance {} must be below 1",
+                slippage
+            )));
+        }
+
+        let steps = &route.steps;
+        let mut index = 0;
+        let source_swap = collect_swap(steps, &mut index, &route.source_chain, slippage)?;
+
+        let mut hops = Vec::new();
+        while let Some(step) = steps.get(index) {
+            match step.step_type {
+                RouteStepType::IbcTransfer | RouteStepType::Transfer => {}
+                RouteStepType::Bridge => {
+                    return Err(Error::Skip(format!(
+                        "Bridge step on {} is not an IBC transfer and cannot be composed",
+                        step.chain
+                    )))
+                }
+                RouteStepType::Swap => break,
+            }
+            let channel = step.channel.clone().ok_or_else(|| {
+                Error::Skip(format!(
+                    "Transfer from {} to {} has no IBC channel",
+                    step.asset_in.chain, step.asset_out.chain
+                ))
+            })?;
+            hops.push(TransferHop {
+                from_chain: step.asset_in.chain.clone(),
+                to_chain: step.asset_out.chain.clone(),
+                channel,
+            });
+            index += 1;
+        }
+        let last_hop = hops
+            .last()
+            .ok_or_else(|| Error::Skip("Route has no IBC transfer".to_string()))?;
+
+        let dest_chain = last_hop.to_chain.clone();
+        let dest_swap = collect_swap(steps, &mut index, &dest_chain, slippage)?;
+        if index < steps.len() {
+            return Err(Error::Skip(
+                "Route continues after the destination swap; only swap, transfer, swap routes are supported"
+                    .to_string(),
+            ));
+        }
+
+        let dest_entry_point = match &dest_swap {
+            Some(_) => Some(entry_points.get(&dest_chain).cloned().ok_or_else(|| {
+                Error::Skip(format!(
+                    "No Skip entry point known for {}; register it with set_entry_point",
+                    dest_chain
+                ))
+            })?),
+            None => None,
+        };
+
+        // Build the memo inside out: destination hook first, then one forward
+        // per hop after the first
+        let final_receiver = dest_entry_point
+            .clone()
+            .unwrap_or_else(|| request.recipient.clone());
+        let mut next = match (&dest_swap, &dest_entry_point) {
+            (Some(swap), Some(contract)) => Some(wasm_hook_memo(
+                contract,
+                &SkipEntryPointExecuteMsg::SwapAndAction {
+                    sent_asset: None,
+                    user_swap: SkipSwap::SwapExactAssetIn(swap.swap.clone()),
+                    min_asset: SkipAsset::Native(swap.min_out.clone()),
+                    timeout_timestamp: timeout.timestamp_nanos,
+                    post_swap_action: SkipAction::Transfer {
+                        to_address: request.recipient.clone(),
+                    },
+                    affiliates: vec![],
+                },
+            )?),
+            _ => None,
+        };
+        for (position, hop) in hops.iter().enumerate().skip(1).rev() {
+            let receiver = if position == hops.len() - 1 {
+                final_receiver.as_str()
+            } else {
+                FORWARD_RECEIVER_PLACEHOLDER
+            };
+            next = Some(forward_memo(receiver, &hop.channel, next.take()));
+        }
+        let first_hop_receiver = if hops.len() == 1 {
+            final_receiver
+        } else {
+            FORWARD_RECEIVER_PLACEHOLDER.to_string()
+        };
+
+        Ok(Self {
+            source_chain: route.source_chain.clone(),
+            dest_chain,
+            offer: Coin {
+                denom: request.source_asset.denom.clone(),
+                amount: request.source_asset.amount,
+            },
+            recipient: request.recipient.clone(),
+            source_swap,
+            hops,
+            dest_swap,
+            dest_entry_point,
+            first_hop_receiver,
+            memo: next.map(|memo| memo.to_string()),
+            timeout,
+            affiliates: vec![],
+        })
+    }
+
+    /// Pay `affiliates` out of the source chain swap
+    ///
+    /// Destination swaps run on another chain where the affiliate addresses
+    /// are not valid, so they never carry affiliates.
+    pub fn with_affiliates(mut self, affiliates: Vec<SkipAffiliate>) -> Self {
+        self.affiliates = affiliates;
+        self
+    }
+
+    /// Entry point message for routes that swap on the source chain
+    ///
+    /// Returns `None` when the route starts with a transfer; use
+    /// [`CrossChainSwapPlan::msg_transfer`] instead.
+    pub fn entry_point_msg(&self, sender: &str) -> Option<SkipEntryPointExecuteMsg> {
+        let swap = self.source_swap.as_ref()?;
+        let first_hop = self.hops.first()?;
+        Some(SkipEntryPointExecuteMsg::SwapAndAction {
+            sent_asset: Some(SkipAsset::Native(self.offer.clone())),
+            user_swap: SkipSwap::SwapExactAssetIn(swap.swap.clone()),
+            min_asset: SkipAsset::Native(swap.min_out.clone()),
+            timeout_timestamp: self.timeout.timestamp_nanos,
+            post_swap_action: SkipAction::IbcTransfer {
+                ibc_info: SkipIbcInfo {
+                    source_channel: first_hop.channel.clone(),
+                    receiver: self.first_hop_receiver.clone(),
+                    memo: self.memo.clone(),
+                    recover_address: Some(sender.to_string()),
+                },
+                fee_swap: None,
+            },
+            affiliates: self.affiliates.clone(),
+        })
+    }
+
+    /// ICS-20 transfer message for routes that start with a transfer
+    pub fn msg_transfer(&self, sender: &str) -> Result<Any, Error> {
+        let first_hop = self
+            .hops
+            .first()
+            .ok_or_else(|| Error::Skip("Route has no IBC transfer".to_string()))?;
+        let height = self.timeout.height.unwrap_or_default();
+        let msg = MsgTransfer {
+            source_port: IBC_TRANSFER_PORT.to_string(),
+            source_channel: first_hop.channel.clone(),
+            token: Some(ProtoCoin {
+                denom: self.offer.denom.clone(),
+                amount: self.offer.amount.to_string(),
+            }),
+            sender: sender.to_string(),
+            receiver: self.first_hop_receiver.clone(),
+            timeout_height: Some(ProtoHeight {
+                revision_number: height.revision_number,
+                revision_height: height.revision_height,
+            }),
+            timeout_timestamp: self.timeout.timestamp_nanos,
+            memo: self.memo.clone().unwrap_or_default(),
+        };
+        Ok(Any {
+            type_url: MSG_TRANSFER_TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        })
+    }
+}
+
+/// IBC hooks memo executing `msg` on `contract` when the packet arrives
+pub fn wasm_hook_memo(contract: &str, msg: &SkipEntryPointExecuteMsg) -> Result<Value, Error> {
+    Ok(json!({
+        "wasm": {
+            "contract": contract,
+            "msg": serde_json::to_value(msg)?,
+        }
+    }))
+}
+
+/// Packet-forward memo sending the packet on over `channel`
+pub fn forward_memo(receiver: &str, channel: &str, next: Option<Value>) -> Value {
+    let mut forward = json!({
+        "receiver": receiver,
+        "port": IBC_TRANSFER_PORT,
+        "channel": channel,
+    });
+    if let Some(next) = next {
+        forward["next"] = next;
+    }
+    json!({ "forward": forward })
+}
+
+/// Merge consecutive swap steps on `chain` starting at `index`
+fn collect_swap(
+    steps: &[RouteStep],
+    index: &mut usize,
+    chain: &str,
+    slippage: Decimal,
+) -> Result<Option<PlannedSwap>, Error> {
+    let mut merged: Option<(SkipSwapExactAssetIn, &RouteStep)> = None;
+    while let Some(step) = steps.get(*index) {
+        if !matches!(step.step_type, RouteStepType::Swap) || step.chain != chain {
+            break;
+        }
+        let swap = step
+            .swap
+            .as_ref()
+            .ok_or_else(|| Error::Skip(format!("Swap step on {} has no pool operations", chain)))?;
+        match &mut merged {
+            Some((combined, last)) => {
+                if combined.swap_venue_name != swap.swap_venue_name {
+                    return Err(Error::Skip(format!(
+                        "Swaps on {} use several venues ({} and {})",
+                        chain, combined.swap_venue_name, swap.swap_venue_name
+                    )));
+                }
+                combined.operations.extend(swap.operations.iter().cloned());
+                *last = step;
+            }
+            None => merged = Some((swap.clone(), step)),
+        }
+        *index += 1;
+    }
+
+    let Some((swap, last)) = merged else {
+        return Ok(None);
+    };
+    if last.asset_out.amount.is_zero() {
+        return Err(Error::Skip(format!(
+            "Route does not report the expected output of the swap on {}",
+            chain
+        )));
+    }
+    let expected_out = Coin {
+        denom: last.asset_out.denom.clone(),
+        amount: last.asset_out.amount,
+    };
+    let min_out = Coin {
+        denom: expected_out.denom.clone(),
+        amount: expected_out.amount.mul_floor(Decimal::one() - slippage),
+    };
+    if min_out.amount.is_zero() {
+        return Err(Error::Skip(format!(
+            "Swap on {} is too small to guarantee any output",
+            chain
+        )));
+    }
+    Ok(Some(PlannedSwap {
+        chain: chain.to_string(),
+        swap,
+        expected_out,
+        min_out,
+    }))
+}
+
+/// `ibc.applications.transfer.v1.MsgTransfer`
+#[derive(Clone, PartialEq, prost::Message)]
+struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    source_port: String,
+    #[prost(string, tag = "2")]
+    source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    token: Option<ProtoCoin>,
+    #[prost(string, tag = "4")]
+    sender: String,
+    #[prost(string, tag = "5")]
+    receiver: String,
+    #[prost(message, optional, tag = "6")]
+    timeout_height: Option<ProtoHeight>,
+    #[prost(uint64, tag = "7")]
+    timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    memo: String,
+}
+
+/// `ibc.core.client.v1.Height`
+#[derive(Clone, PartialEq, prost::Message)]
+struct ProtoHeight {
+    #[prost(uint64, tag = "1")]
+    revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    revision_height: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::skip::types::{CrossChainAsset, SkipSwapOperation};
+    use cosmwasm_std::Uint128;
+
+    fn asset(denom: &str, amount: u128, chain: &str) -> CrossChainAsset {
+        CrossChainAsset {
+            denom: denom.to_string(),
+            amount: Uint128::new(amount),
+            chain: chain.to_string(),
+            decimals: None,
+            symbol: None,
+        }
+    }
+
+    fn swap_step(chain: &str, venue: &str, in_: &str, out: &str, amount_out: u128) -> RouteStep {
+        RouteStep {
+            chain: chain.to_string(),
+            step_type: RouteStepType::Swap,
+            asset_in: asset(in_, 0, chain),
+            asset_out: asset(out, amount_out, chain),
+            estimated_time_seconds: None,
+            fee: None,
+            channel: None,
+            swap: Some(SkipSwapExactAssetIn {
+                swap_venue_name: venue.to_string(),
+                operations: vec![SkipSwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: in_.to_string(),
+                    denom_out: out.to_string(),
+                    interface: None,
+                }],
+            }),
+        }
+    }
+
+    fn hop(from: &str, to: &str, channel: &str, denom: &str) -> RouteStep {
+        RouteStep {
+            chain: from.to_string(),
+            step_type: RouteStepType::IbcTransfer,
+            asset_in: asset(denom, 0, from),
+            asset_out: asset(denom, 0, to),
+            estimated_time_seconds: None,
+            fee: None,
+            channel: Some(channel.to_string()),
+            swap: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_composes_forward_and_wasm_hook_memos() {
+        let route = CrossChainRoute {
+            source_chain: "mantra-1".to_string(),
+            dest_chain: "osmosis-1".to_string(),
+            steps: vec![
+                swap_step("mantra-1", "mantra-dex", "uom", "ibc/usdc", 1_000),
+                hop("mantra-1", "noble-1", "channel-0", "ibc/usdc"),
+                hop("noble-1", "osmosis-1", "channel-1", "uusdc"),
+                swap_step("osmosis-1", "osmosis-poolmanager", "ibc/usdc", "uosmo", 500),
+            ],
+            estimated_time_seconds: None,
+            estimated_fees: vec![],
+            price_impact: None,
+            breakdown: None,
+        };
+        let request = TransferRequest {
+            source_asset: asset("uom", 2_000, "mantra-1"),
+            target_asset: asset("uosmo", 0, "osmosis-1"),
+            recipient: "osmo1recipient".to_string(),
+            timeout_seconds: None,
+            slippage_tolerance: Some(Decimal::percent(10)),
+            route: None,
+        };
+        let entry_points =
+            HashMap::from([("osmosis-1".to_string(), "osmo1entrypoint".to_string())]);
+        let timeout = IbcTimeout::after_seconds(100, 50);
+
+        let plan =
+            CrossChainSwapPlan::from_route(&route, &request, &entry_points, timeout).unwrap();
+        assert_eq!(plan.hops.len(), 2);
+        assert_eq!(plan.first_hop_receiver, FORWARD_RECEIVER_PLACEHOLDER);
+        assert_eq!(
+            plan.source_swap.as_ref().unwrap().min_out.amount.u128(),
+            900
+        );
+
+        let memo: Value = serde_json::from_str(plan.memo.as_deref().unwrap()).unwrap();
+        let forward = &memo["forward"];
+        assert_eq!(forward["channel"], "channel-1");
+        assert_eq!(forward["receiver"], "osmo1entrypoint");
+        let hook = &forward["next"]["wasm"];
+        assert_eq!(hook["contract"], "osmo1entrypoint");
+        let swap_and_action = &hook["msg"]["swap_and_action"];
+        assert_eq!(swap_and_action["min_asset"]["native"]["amount"], "450");
+        assert_eq!(swap_and_action["timeout_timestamp"], 150_000_000_000u64);
+        assert_eq!(
+            swap_and_action["post_swap_action"]["transfer"]["to_address"],
+            "osmo1recipient"
+        );
+
+        match plan.entry_point_msg("mantra1sender").unwrap() {
+            SkipEntryPointExecuteMsg::SwapAndAction {
+                post_swap_action: SkipAction::IbcTransfer { ibc_info, .. },
+                ..
+            } => {
+                assert_eq!(ibc_info.source_channel, "channel-0");
+                assert_eq!(ibc_info.recover_address.as_deref(), Some("mantra1sender"));
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+
+        let missing = CrossChainSwapPlan::from_route(&route, &request, &HashMap::new(), timeout);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_revision_number_from_chain_id() {
+        assert_eq!(IbcHeight::revision_number("osmosis-1"), 1);
+        assert_eq!(IbcHeight::revision_number("mantra-dukong-1"), 1);
+        assert_eq!(IbcHeight::revision_number("localnet"), 0);
+        assert_eq!(
+            IbcHeight::after("noble-1", 100, 20),
+            IbcHeight {
+                revision_number: 1,
+                revision_height: 120,
+            }
+        );
+    }
+}
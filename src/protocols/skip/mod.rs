@@ -1,33 +1,71 @@
 /// Skip Protocol Module
 /// Handles cross-chain routing and swaps via Skip Protocol
+pub mod affiliate;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod client;
+pub mod estimation;
+pub mod execution;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod recovery;
+pub mod registry;
+pub mod smart_swap;
 pub mod types;
+pub mod verification;
+
+// Re-export affiliate fee types
+pub use affiliate::{AffiliateFeeLedger, AffiliateFeeReport, SkipAffiliateConfig};
 
 // Re-export Skip client
-pub use client::SkipClient;
+#[cfg(not(target_arch = "wasm32"))]
+pub use client::{SkipClient, TransferTrackingOptions};
+
+// Re-export route estimation types
+pub use estimation::{
+    ChannelLatencyTracker, EtaSource, FeeKind, LegBreakdown, LegFee, RouteBreakdown,
+    RoutePreference,
+};
+
+// Re-export cross-chain swap execution types
+pub use execution::{CrossChainSwapPlan, IbcHeight, IbcTimeout, PlannedSwap, TransferHop};
+
+// Re-export timeout recovery types
+#[cfg(not(target_arch = "wasm32"))]
+pub use recovery::{PendingTransfer, RecoveryState, TransferRecovery, TransferRecoveryRegistry};
+
+// Re-export chain and asset registry types
+pub use registry::ChainRegistry;
+
+// Re-export smart swap types
+pub use smart_swap::{SmartSwapExecution, SmartSwapRouteResult, SmartSwapSimulation};
+
+// Re-export destination verification types
+pub use verification::{BalanceCheck, DestinationExpectation, DestinationVerification};
 
 // Re-export Skip types for convenience
 pub use types::{
     AssetPair, BridgeInfo, ChainAsset, CrossChainAsset, CrossChainRoute, RouteStep, RouteStepType,
     SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
     SimulateSwapExactAssetOutResponse, SkipAction, SkipAffiliate, SkipAsset,
-    SkipEntryPointExecuteMsg, SkipEntryPointQueryMsg, SkipIbcInfo, SkipRoute, SkipSwap,
-    SkipSwapExactAssetIn, SkipSwapExactAssetOut, SkipSwapOperation, SupportedChain,
-    TransferRequest, TransferResult, TransferStatus,
+    SkipEntryPointExecuteMsg, SkipEntryPointQueryMsg, SkipIbcInfo, SkipRoute,
+    SkipSmartSwapExactAssetIn, SkipSwap, SkipSwapExactAssetIn, SkipSwapExactAssetOut,
+    SkipSwapOperation, SupportedChain, TransferRequest, TransferResult, TransferStatus,
+    TransferTracking, TransferTransition,
 };
 
 use crate::error::Error;
+use crate::protocols::discovery::{discover_contract, ContractDiscovery};
 use crate::protocols::Protocol;
+use crate::transport::SharedTransport;
 use async_trait::async_trait;
-use cosmrs::rpc::HttpClient;
 use serde_json::{json, Value};
-use std::sync::Arc;
 
 /// Skip Protocol implementation
 #[derive(Clone)]
 pub struct SkipProtocol {
     initialized: bool,
     contract_address: Option<String>,
+    expected_code_id: Option<u64>,
+    deployment: Option<ContractDiscovery>,
 }
 
 impl SkipProtocol {
@@ -36,6 +74,8 @@ impl SkipProtocol {
         Self {
             initialized: false,
             contract_address: None,
+            expected_code_id: None,
+            deployment: None,
         }
     }
 
@@ -48,9 +88,20 @@ impl SkipProtocol {
     pub fn set_contract_address(&mut self, address: String) {
         self.contract_address = Some(address);
     }
+
+    /// Set the code ID the Skip contract must be instantiated from
+    pub fn set_expected_code_id(&mut self, code_id: Option<u64>) {
+        self.expected_code_id = code_id;
+    }
+
+    /// What initialization found at the Skip contract address
+    pub fn deployment(&self) -> Option<&ContractDiscovery> {
+        self.deployment.as_ref()
+    }
 }
 
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl Protocol for SkipProtocol {
     fn name(&self) -> &'static str {
         "skip"
@@ -60,9 +111,24 @@ impl Protocol for SkipProtocol {
         "1.0.0"
     }
 
-    async fn is_available(&self, _rpc_client: &HttpClient) -> Result<bool, Error> {
+    async fn is_available(&self, transport: &SharedTransport) -> Result<bool, Error> {
         // Check if Skip adapter contract is deployed on the network
-        Ok(self.initialized && self.contract_address.is_some())
+        match &self.contract_address {
+            Some(address) => {
+                Ok(
+                    discover_contract(transport.as_ref(), address, self.expected_code_id)
+                        .await?
+                        .is_deployed(),
+                )
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn is_deployed(&self) -> bool {
+        self.deployment
+            .as_ref()
+            .is_some_and(ContractDiscovery::is_deployed)
     }
 
     fn get_config(&self) -> Result<Value, Error> {
@@ -71,12 +137,18 @@ impl Protocol for SkipProtocol {
             "version": self.version(),
             "initialized": self.initialized,
             "contract_address": self.contract_address,
+            "deployment": self.deployment,
         }))
     }
 
-    async fn initialize(&mut self, _rpc_client: Arc<HttpClient>) -> Result<(), Error> {
-        // Initialize the Skip protocol
-        // This would typically load the Skip adapter contract address from config
+    async fn initialize(&mut self, transport: SharedTransport) -> Result<(), Error> {
+        // Look up the Skip adapter contract on the connected chain
+        self.deployment = match &self.contract_address {
+            Some(address) => {
+                Some(discover_contract(transport.as_ref(), address, self.expected_code_id).await?)
+            }
+            None => None,
+        };
         self.initialized = true;
         Ok(())
     }
@@ -0,0 +1,269 @@
+/// Recovery of timed out IBC transfers
+///
+/// A packet that is not received before its timeout height or timestamp is
+/// sent back by a relayer, and the source chain returns the escrowed tokens to
+/// the sender. Every cross-chain swap a [`super::SkipClient`] submits is kept
+/// as a [`PendingTransfer`]; once it is past its timeout, recovery confirms the
+/// refund on the source chain and can resubmit the refunded tokens along a
+/// freshly found route.
+use cosmrs::rpc::{Client as RpcClient, HttpClient};
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use super::execution::IbcTimeout;
+use super::types::{CrossChainAsset, TransferRequest, TransferResult, TransferStatus};
+use crate::error::Error;
+
+/// Retries allowed for one original transfer
+pub const MAX_RECOVERY_ATTEMPTS: u32 = 3;
+
+/// Cross-chain swap that may need recovering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTransfer {
+    pub transfer_id: String,
+    pub source_tx_hash: String,
+    pub source_chain: String,
+    /// Destination of the first hop, where a height timeout is measured
+    pub counterparty_chain: String,
+    pub sender: String,
+    pub request: TransferRequest,
+    pub timeout: IbcTimeout,
+    /// Tokens escrowed by the first hop and returned on timeout; after a
+    /// source chain swap this is the swap's minimum output
+    pub escrowed: Coin,
+    /// Sender balance of the escrowed denom once the transfer was sent
+    pub balance_after_send: Option<Uint128>,
+    /// Retries that led to this transfer
+    pub attempt: u32,
+    /// Unix timestamp the refund was confirmed at
+    pub refunded_at: Option<u64>,
+    /// Transfer that resubmitted the refunded tokens
+    pub retried_as: Option<String>,
+}
+
+impl PendingTransfer {
+    /// Whether the sender's balance shows the escrowed tokens back
+    ///
+    /// Spending from the same denom in the meantime hides the refund, so a
+    /// `false` is not conclusive.
+    pub fn refund_observed(&self, current_balance: Uint128) -> bool {
+        self.balance_after_send
+            .is_some_and(|balance| current_balance.saturating_sub(balance) >= self.escrowed.amount)
+    }
+
+    /// Request resubmitting the refunded tokens to the original target
+    ///
+    /// The route is left for Skip to find again.
+    pub fn retry_request(&self) -> TransferRequest {
+        TransferRequest {
+            source_asset: CrossChainAsset {
+                denom: self.escrowed.denom.clone(),
+                amount: self.escrowed.amount,
+                chain: self.source_chain.clone(),
+                decimals: None,
+                symbol: None,
+            },
+            target_asset: self.request.target_asset.clone(),
+            recipient: self.request.recipient.clone(),
+            timeout_seconds: self.request.timeout_seconds,
+            slippage_tolerance: self.request.slippage_tolerance,
+            route: None,
+        }
+    }
+}
+
+/// Whether a packet with `timeout` can no longer be received
+///
+/// The timestamp is compared with `now_secs`; the height only counts when the
+/// counterparty's latest height is known.
+pub fn is_past_timeout(
+    timeout: &IbcTimeout,
+    now_secs: u64,
+    counterparty_height: Option<u64>,
+) -> bool {
+    let past_timestamp = timeout.timestamp_nanos != 0
+        && now_secs.saturating_mul(1_000_000_000) >= timeout.timestamp_nanos;
+    let past_height = match (timeout.height, counterparty_height) {
+        (Some(height), Some(current)) => current >= height.revision_height,
+        _ => false,
+    };
+    past_timestamp || past_height
+}
+
+/// Where a recovery stands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryState {
+    /// Not past the timeout and not settled yet
+    InFlight,
+    /// The transfer arrived; nothing to recover
+    Delivered,
+    /// Past the timeout, the refund has not reached the sender yet
+    AwaitingRefund,
+    /// The escrowed tokens are back with the sender
+    Refunded,
+    /// The refunded tokens were resubmitted
+    Retried,
+}
+
+/// Outcome of [`super::SkipClient::recover_transfer`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferRecovery {
+    pub transfer_id: String,
+    pub source_tx_hash: String,
+    pub state: RecoveryState,
+    /// Status Skip reported, if it answered
+    pub transfer_status: Option<TransferStatus>,
+    pub past_timeout: bool,
+    /// Tokens returned to the sender
+    pub refund: Option<Coin>,
+    /// Transfer resubmitting the refund
+    pub retry: Option<TransferResult>,
+    pub attempt: u32,
+    pub message: Option<String>,
+    pub checked_at: u64,
+}
+
+/// Cross-chain swaps submitted by this process, keyed by transfer id
+#[derive(Debug, Default)]
+pub struct TransferRecoveryRegistry {
+    entries: RwLock<HashMap<String, PendingTransfer>>,
+}
+
+impl TransferRecoveryRegistry {
+    /// Process-wide registry shared by all Skip clients
+    pub fn global() -> &'static TransferRecoveryRegistry {
+        static GLOBAL: OnceLock<TransferRecoveryRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(TransferRecoveryRegistry::default)
+    }
+
+    /// Add or replace `pending`
+    pub fn register(&self, pending: PendingTransfer) {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(pending.transfer_id.clone(), pending);
+    }
+
+    /// Transfer with the given transfer id or source tx hash
+    pub fn get(&self, id: &str) -> Option<PendingTransfer> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries
+            .get(id)
+            .or_else(|| {
+                entries
+                    .values()
+                    .find(|pending| pending.source_tx_hash == id)
+            })
+            .cloned()
+    }
+
+    /// Stop tracking `transfer_id`
+    pub fn remove(&self, transfer_id: &str) -> Option<PendingTransfer> {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(transfer_id)
+    }
+
+    /// Transfers still tracked
+    pub fn pending(&self) -> Vec<PendingTransfer> {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Latest block height of the chain served by `rpc_url`
+pub async fn query_latest_height(rpc_url: &str) -> Result<u64, Error> {
+    let rpc_client = HttpClient::new(rpc_url).map_err(|e| {
+        Error::Rpc(format!(
+            "Failed to create RPC client for {}: {}",
+            rpc_url, e
+        ))
+    })?;
+    let block = rpc_client
+        .latest_block()
+        .await
+        .map_err(|e| Error::Rpc(format!("Failed to get latest block: {}", e)))?;
+    Ok(block.block.header.height.value())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::skip::execution::IbcHeight;
+
+    fn pending(timeout: IbcTimeout) -> PendingTransfer {
+        PendingTransfer {
+            transfer_id: "t1".to_string(),
+            source_tx_hash: "ABC".to_string(),
+            source_chain: "mantra-1".to_string(),
+            counterparty_chain: "osmosis-1".to_string(),
+            sender: "mantra1sender".to_string(),
+            request: TransferRequest {
+                source_asset: CrossChainAsset {
+                    denom: "uom".to_string(),
+                    amount: Uint128::new(1_000),
+                    chain: "mantra-1".to_string(),
+                    decimals: None,
+                    symbol: None,
+                },
+                target_asset: CrossChainAsset {
+                    denom: "uosmo".to_string(),
+                    amount: Uint128::zero(),
+                    chain: "osmosis-1".to_string(),
+                    decimals: None,
+                    symbol: None,
+                },
+                recipient: "osmo1recipient".to_string(),
+                timeout_seconds: Some(600),
+                slippage_tolerance: None,
+                route: None,
+            },
+            timeout,
+            escrowed: Coin::new(950u128, "uusdc"),
+            balance_after_send: Some(Uint128::new(100)),
+            attempt: 0,
+            refunded_at: None,
+            retried_as: None,
+        }
+    }
+
+    #[test]
+    fn test_timeout_detection_by_timestamp_and_height() {
+        let timeout = IbcTimeout::after_seconds(1_000, 600).with_height(IbcHeight {
+            revision_number: 1,
+            revision_height: 5_000,
+        });
+        assert!(!is_past_timeout(&timeout, 1_599, None));
+        assert!(is_past_timeout(&timeout, 1_600, None));
+        assert!(!is_past_timeout(&timeout, 1_100, Some(4_999)));
+        assert!(is_past_timeout(&timeout, 1_100, Some(5_000)));
+    }
+
+    #[test]
+    fn test_refund_detection_and_retry_request() {
+        let pending = pending(IbcTimeout::after_seconds(1_000, 600));
+        assert!(!pending.refund_observed(Uint128::new(1_000)));
+        assert!(pending.refund_observed(Uint128::new(1_050)));
+
+        // The refund is in the swapped denom, so the retry starts from it
+        let retry = pending.retry_request();
+        assert_eq!(retry.source_asset.denom, "uusdc");
+        assert_eq!(retry.source_asset.amount, Uint128::new(950));
+        assert_eq!(retry.target_asset.denom, "uosmo");
+        assert!(retry.route.is_none());
+
+        let registry = TransferRecoveryRegistry::default();
+        registry.register(pending);
+        assert!(registry.get("ABC").is_some());
+        assert!(registry.remove("t1").is_some());
+        assert!(registry.get("t1").is_none());
+    }
+}
@@ -0,0 +1,247 @@
+/// Supported chain and asset registry
+///
+/// Skip publishes the chains it can route between and the fungible assets it
+/// knows on each of them. Front ends use these lists to populate chain and
+/// asset pickers, and a [`ChainRegistry`] built from them rejects transfer
+/// requests naming an unknown chain or asset before anything is submitted.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use super::types::{ChainAsset, SupportedChain, TransferRequest};
+use crate::error::Error;
+
+/// Chains and per-chain assets known to Skip
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChainRegistry {
+    pub chains: Vec<SupportedChain>,
+    /// Assets by chain ID, for the chains whose assets were loaded
+    pub assets: HashMap<String, Vec<ChainAsset>>,
+}
+
+impl ChainRegistry {
+    /// Registry of `chains` without any asset lists
+    pub fn new(chains: Vec<SupportedChain>) -> Self {
+        Self {
+            chains,
+            assets: HashMap::new(),
+        }
+    }
+
+    /// Add the asset list of `chain_id`
+    pub fn with_assets(mut self, chain_id: impl Into<String>, assets: Vec<ChainAsset>) -> Self {
+        self.assets.insert(chain_id.into(), assets);
+        self
+    }
+
+    /// Chain with the given ID
+    pub fn chain(&self, chain_id: &str) -> Option<&SupportedChain> {
+        self.chains.iter().find(|chain| chain.chain_id == chain_id)
+    }
+
+    /// Asset `denom` on `chain_id`, looked up in the loaded asset list and
+    /// then in the assets listed with the chain itself
+    pub fn asset(&self, chain_id: &str, denom: &str) -> Option<&ChainAsset> {
+        self.assets
+            .get(chain_id)
+            .and_then(|assets| assets.iter().find(|asset| asset.denom == denom))
+            .or_else(|| {
+                self.chain(chain_id)?
+                    .supported_assets
+                    .iter()
+                    .find(|asset| asset.denom == denom)
+            })
+    }
+
+    /// Check that both ends of `request` name available chains and known assets
+    ///
+    /// Assets are only checked on chains whose asset list was loaded or that
+    /// list assets themselves.
+    pub fn validate_transfer(&self, request: &TransferRequest) -> Result<(), Error> {
+        for (side, asset) in [
+            ("Source", &request.source_asset),
+            ("Target", &request.target_asset),
+        ] {
+            let chain = self.chain(&asset.chain).ok_or_else(|| {
+                Error::Skip(format!("{} chain {} is not supported", side, asset.chain))
+            })?;
+            if !chain.is_available {
+                return Err(Error::Skip(format!(
+                    "{} chain {} is currently unavailable",
+                    side, asset.chain
+                )));
+            }
+
+            let assets_known =
+                self.assets.contains_key(&asset.chain) || !chain.supported_assets.is_empty();
+            if assets_known && self.asset(&asset.chain, &asset.denom).is_none() {
+                return Err(Error::Skip(format!(
+                    "{} asset {} is not supported on {}",
+                    side, asset.denom, asset.chain
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Full IBC path of an asset, its `trace` followed by its origin denom
+fn ibc_trace(asset: &Value) -> Option<String> {
+    let trace = asset
+        .get("trace")
+        .and_then(|t| t.as_str())
+        .filter(|t| !t.is_empty())?;
+    match asset.get("origin_denom").and_then(|d| d.as_str()) {
+        Some(origin_denom) if !trace.ends_with(origin_denom) => {
+            Some(format!("{}/{}", trace, origin_denom))
+        }
+        _ => Some(trace.to_string()),
+    }
+}
+
+/// Assets of `chain_id` in a Skip fungible assets response
+///
+/// Accepts both the flat `assets` list and the `chain_to_assets_map` keyed by
+/// chain ID. An asset is native when it originates on the chain it is listed
+/// on, unless the response says otherwise.
+pub fn parse_fungible_assets(chain_id: &str, response: &Value) -> Vec<ChainAsset> {
+    let assets = response
+        .get("chain_to_assets_map")
+        .and_then(|map| map.get(chain_id))
+        .and_then(|entry| entry.get("assets"))
+        .or_else(|| response.get("assets"))
+        .and_then(|assets| assets.as_array());
+
+    assets
+        .map(|assets| {
+            assets
+                .iter()
+                .filter(|asset| {
+                    asset
+                        .get("chain_id")
+                        .and_then(|c| c.as_str())
+                        .is_none_or(|c| c == chain_id)
+                })
+                .filter_map(|asset| {
+                    let denom = asset.get("denom")?.as_str()?.to_string();
+                    let is_native = asset
+                        .get("is_native")
+                        .and_then(|n| n.as_bool())
+                        .unwrap_or_else(|| {
+                            asset
+                                .get("origin_chain_id")
+                                .and_then(|c| c.as_str())
+                                .is_some_and(|origin| origin == chain_id)
+                        });
+                    Some(ChainAsset {
+                        symbol: asset
+                            .get("symbol")
+                            .and_then(|s| s.as_str())
+                            .unwrap_or(&denom)
+                            .to_string(),
+                        decimals: asset.get("decimals").and_then(|d| d.as_u64()).unwrap_or(6) as u8,
+                        is_native,
+                        contract_address: asset
+                            .get("contract_address")
+                            .or_else(|| asset.get("token_contract"))
+                            .and_then(|c| c.as_str())
+                            .filter(|c| !c.is_empty())
+                            .map(|c| c.to_string()),
+                        trace: ibc_trace(asset),
+                        denom,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::skip::types::CrossChainAsset;
+    use cosmwasm_std::Uint128;
+
+    fn chain(chain_id: &str, is_available: bool) -> SupportedChain {
+        SupportedChain {
+            chain_id: chain_id.to_string(),
+            chain_name: chain_id.to_string(),
+            chain_type: "cosmos".to_string(),
+            is_available,
+            supported_assets: vec![],
+            bridges: vec![],
+            rpc_endpoint: None,
+        }
+    }
+
+    fn request(source: (&str, &str), target: (&str, &str)) -> TransferRequest {
+        let asset = |(chain, denom): (&str, &str)| CrossChainAsset {
+            denom: denom.to_string(),
+            amount: Uint128::new(1_000),
+            chain: chain.to_string(),
+            decimals: None,
+            symbol: None,
+        };
+        TransferRequest {
+            source_asset: asset(source),
+            target_asset: asset(target),
+            recipient: "osmo1recipient".to_string(),
+            timeout_seconds: None,
+            slippage_tolerance: None,
+            route: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_fungible_assets_from_chain_map() {
+        let response = serde_json::json!({
+            "chain_to_assets_map": {
+                "osmosis-1": {
+                    "assets": [
+                        {"denom": "uosmo", "chain_id": "osmosis-1", "origin_chain_id": "osmosis-1",
+                         "symbol": "OSMO", "decimals": 6},
+                        {"denom": "ibc/ATOM", "chain_id": "osmosis-1", "origin_chain_id": "cosmoshub-4",
+                         "symbol": "ATOM", "decimals": 6, "token_contract": ""},
+                        {"symbol": "broken"}
+                    ]
+                }
+            }
+        });
+
+        let assets = parse_fungible_assets("osmosis-1", &response);
+        assert_eq!(assets.len(), 2);
+        assert!(assets[0].is_native);
+        assert!(!assets[1].is_native);
+        assert_eq!(assets[1].contract_address, None);
+        assert!(parse_fungible_assets("cosmoshub-4", &response).is_empty());
+    }
+
+    #[test]
+    fn test_registry_rejects_unknown_targets() {
+        let registry = ChainRegistry::new(vec![
+            chain("mantra-1", true),
+            chain("osmosis-1", true),
+            chain("noble-1", false),
+        ])
+        .with_assets(
+            "osmosis-1",
+            parse_fungible_assets(
+                "osmosis-1",
+                &serde_json::json!({"assets": [{"denom": "uosmo", "symbol": "OSMO", "decimals": 6}]}),
+            ),
+        );
+
+        assert!(registry
+            .validate_transfer(&request(("mantra-1", "uom"), ("osmosis-1", "uosmo")))
+            .is_ok());
+        assert!(registry
+            .validate_transfer(&request(("mantra-1", "uom"), ("osmosis-1", "uatom")))
+            .is_err());
+        assert!(registry
+            .validate_transfer(&request(("mantra-1", "uom"), ("noble-1", "uusdc")))
+            .is_err());
+        assert!(registry
+            .validate_transfer(&request(("juno-1", "ujuno"), ("osmosis-1", "uosmo")))
+            .is_err());
+    }
+}
@@ -0,0 +1,247 @@
+/// Split-route smart swaps through the Skip entry point
+///
+/// A smart swap sends slices of one input down several swap paths that end in
+/// the same asset and settles them in a single entry point message with one
+/// aggregate minimum output. The split is found by simulating every candidate
+/// path at evenly spaced input amounts and handing out the input one slice at a
+/// time to the path with the largest marginal output; AMM output curves are
+/// concave, so this greedy allocation is optimal at the chosen resolution.
+use cosmwasm_std::{Coin, Uint128};
+use serde::{Deserialize, Serialize};
+
+use super::types::{SkipAsset, SkipRoute, SkipSwapOperation};
+use crate::error::Error;
+
+/// Slices the input is split into when no resolution is given
+pub const DEFAULT_SMART_SWAP_SPLIT_STEPS: u32 = 10;
+
+/// Upper bound on the split resolution; each step costs one query per path
+pub const MAX_SMART_SWAP_SPLIT_STEPS: u32 = 100;
+
+/// Share of the input sent down one path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartSwapRouteResult {
+    /// Position of the path among the candidates
+    pub route_index: usize,
+    pub operations: Vec<SkipSwapOperation>,
+    /// Input sent down this path
+    pub offer: Coin,
+    /// Output of this path simulated on its own
+    pub expected_out: Uint128,
+    /// Share of the aggregate minimum attributable to this path
+    pub min_out: Option<Uint128>,
+}
+
+/// Split of an input across swap paths and its simulated output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartSwapSimulation {
+    pub offer: Coin,
+    pub output_denom: String,
+    /// Paths receiving part of the input
+    pub routes: Vec<SmartSwapRouteResult>,
+    /// Sum of the per-path simulations
+    pub expected_out: Uint128,
+    /// Output of the whole split as simulated by the entry point
+    pub aggregate_out: Uint128,
+    pub split_steps: u32,
+}
+
+impl SmartSwapSimulation {
+    /// Entry point routes for the split
+    pub fn skip_routes(&self) -> Vec<SkipRoute> {
+        self.routes
+            .iter()
+            .map(|route| SkipRoute {
+                offer_asset: SkipAsset::Native(route.offer.clone()),
+                operations: route.operations.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Executed smart swap with per-path results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartSwapExecution {
+    pub tx_hash: String,
+    /// Minimum aggregate output enforced by the entry point
+    pub min_receive: Coin,
+    pub aggregate_out: Uint128,
+    pub routes: Vec<SmartSwapRouteResult>,
+}
+
+/// Check candidate paths and return the denom they all end in
+pub fn validate_routes(
+    offer_denom: &str,
+    routes: &[Vec<SkipSwapOperation>],
+) -> Result<String, Error> {
+    if routes.is_empty() {
+        return Err(Error::Skip(
+            "Smart swap needs at least one route".to_string(),
+        ));
+    }
+
+    let mut output_denom: Option<&str> = None;
+    for (index, operations) in routes.iter().enumerate() {
+        let (first, last) = match (operations.first(), operations.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(Error::Skip(format!("Route {} has no operations", index))),
+        };
+        if first.denom_in != offer_denom {
+            return Err(Error::Skip(format!(
+                "Route {} starts with {} instead of {}",
+                index, first.denom_in, offer_denom
+            )));
+        }
+        if let Some(pair) = operations
+            .windows(2)
+            .find(|pair| pair[0].denom_out != pair[1].denom_in)
+        {
+            return Err(Error::Skip(format!(
+                "Route {} swaps into {} but continues from {}",
+                index, pair[0].denom_out, pair[1].denom_in
+            )));
+        }
+        match output_denom {
+            Some(denom) if denom != last.denom_out => {
+                return Err(Error::Skip(format!(
+                    "Route {} ends in {} while earlier routes end in {}",
+                    index, last.denom_out, denom
+                )))
+            }
+            _ => output_denom = Some(&last.denom_out),
+        }
+    }
+
+    Ok(output_denom.unwrap_or_default().to_string())
+}
+
+/// Input amount after `step` of `steps` slices of `total`
+pub fn step_amount(total: Uint128, step: u32, steps: u32) -> Uint128 {
+    if step >= steps {
+        total
+    } else {
+        total.multiply_ratio(step, steps)
+    }
+}
+
+/// Slices of the input given to each path
+///
+/// `curves[i][k]` is the output of path `i` for `k` slices of the input, with
+/// `curves[i][0]` the output for nothing. Every slice goes to the path whose
+/// output grows the most from it.
+pub fn allocate_steps(curves: &[Vec<Uint128>], steps: u32) -> Vec<u32> {
+    let mut allocation = vec![0u32; curves.len()];
+    for _ in 0..steps {
+        let best = curves
+            .iter()
+            .enumerate()
+            .filter_map(|(index, curve)| {
+                let taken = allocation[index] as usize;
+                let next = *curve.get(taken + 1)?;
+                let current = curve.get(taken).copied().unwrap_or_default();
+                Some((next.saturating_sub(current), index))
+            })
+            // Ties go to the earliest path
+            .max_by(|(a, ia), (b, ib)| a.cmp(b).then(ib.cmp(ia)));
+        match best {
+            Some((_, index)) => allocation[index] += 1,
+            None => break,
+        }
+    }
+    allocation
+}
+
+/// Split `min_receive` across paths in proportion to their expected output
+///
+/// Rounding leftovers go to the path with the largest expected output.
+pub fn apportion_min_receive(routes: &mut [SmartSwapRouteResult], min_receive: Uint128) {
+    let expected_total = routes
+        .iter()
+        .fold(Uint128::zero(), |sum, route| sum + route.expected_out);
+    if expected_total.is_zero() {
+        return;
+    }
+
+    let mut assigned = Uint128::zero();
+    for route in routes.iter_mut() {
+        let share = min_receive.multiply_ratio(route.expected_out, expected_total);
+        assigned += share;
+        route.min_out = Some(share);
+    }
+    if let Some(largest) = routes.iter_mut().max_by_key(|route| route.expected_out) {
+        let share = largest.min_out.unwrap_or_default();
+        largest.min_out = Some(share + min_receive.saturating_sub(assigned));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(pool: &str, denom_in: &str, denom_out: &str) -> SkipSwapOperation {
+        SkipSwapOperation {
+            pool: pool.to_string(),
+            denom_in: denom_in.to_string(),
+            denom_out: denom_out.to_string(),
+            interface: None,
+        }
+    }
+
+    fn curve(points: &[u128]) -> Vec<Uint128> {
+        points.iter().map(|p| Uint128::new(*p)).collect()
+    }
+
+    #[test]
+    fn test_allocation_follows_marginal_output() {
+        // A deep pool with slowly falling returns and a shallow one that
+        // saturates after two slices
+        let deep = curve(&[0, 100, 195, 285, 370]);
+        let shallow = curve(&[0, 98, 190, 200, 205]);
+        assert_eq!(
+            allocate_steps(&[deep.clone(), shallow.clone()], 4),
+            vec![2, 2]
+        );
+
+        // A path that fails to simulate contributes nothing
+        assert_eq!(allocate_steps(&[deep, curve(&[0])], 4), vec![4, 0]);
+        assert_eq!(step_amount(Uint128::new(1_001), 3, 4), Uint128::new(750));
+        assert_eq!(step_amount(Uint128::new(1_001), 4, 4), Uint128::new(1_001));
+    }
+
+    #[test]
+    fn test_route_validation_and_min_receive_split() {
+        let routes = vec![
+            vec![op("p1", "uom", "uusdc")],
+            vec![op("p2", "uom", "uatom"), op("p3", "uatom", "uusdc")],
+        ];
+        assert_eq!(validate_routes("uom", &routes).unwrap(), "uusdc");
+        assert!(validate_routes("uatom", &routes).is_err());
+        assert!(validate_routes(
+            "uom",
+            &[
+                vec![op("p1", "uom", "uusdc")],
+                vec![op("p2", "uom", "uatom")]
+            ]
+        )
+        .is_err());
+        assert!(validate_routes(
+            "uom",
+            &[vec![op("p2", "uom", "uatom"), op("p3", "uosmo", "uusdc")]]
+        )
+        .is_err());
+
+        let mut results: Vec<_> = [(0, 600u128), (1, 400u128)]
+            .into_iter()
+            .map(|(index, out)| SmartSwapRouteResult {
+                route_index: index,
+                operations: routes[index].clone(),
+                offer: Coin::new(500u128, "uom"),
+                expected_out: Uint128::new(out),
+                min_out: None,
+            })
+            .collect();
+        apportion_min_receive(&mut results, Uint128::new(991));
+        assert_eq!(results[0].min_out, Some(Uint128::new(595)));
+        assert_eq!(results[1].min_out, Some(Uint128::new(396)));
+    }
+}
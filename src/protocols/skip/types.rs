@@ -3,6 +3,9 @@
 use cosmwasm_std::{Coin, Decimal, Uint128};
 use serde::{Deserialize, Serialize};
 
+use super::estimation::RouteBreakdown;
+use super::verification::DestinationVerification;
+
 // ============================================================================
 // Cross-Chain Route Types
 // ============================================================================
@@ -22,6 +25,9 @@ pub struct CrossChainRoute {
     pub estimated_fees: Vec<Coin>,
     /// Price impact percentage
     pub price_impact: Option<Decimal>,
+    /// Per-leg fee and completion time breakdown
+    #[serde(default)]
+    pub breakdown: Option<RouteBreakdown>,
 }
 
 /// Individual step in a cross-chain route
@@ -39,6 +45,12 @@ pub struct RouteStep {
     pub estimated_time_seconds: Option<u64>,
     /// Fee for this specific step
     pub fee: Option<Coin>,
+    /// IBC channel the transfer leaves its source chain on
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Venue and pool operations of a swap step
+    #[serde(default)]
+    pub swap: Option<SkipSwapExactAssetIn>,
 }
 
 /// Type of operation in a route step
@@ -150,7 +162,7 @@ pub struct TransferRequest {
 }
 
 /// Status of a cross-chain transfer
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TransferStatus {
     /// Transfer is being prepared
@@ -186,6 +198,47 @@ pub struct TransferResult {
     pub initiated_at: Option<u64>,
     /// Timestamp when transfer was completed
     pub completed_at: Option<u64>,
+    /// Destination balance check, once the transfer settled
+    #[serde(default)]
+    pub verification: Option<DestinationVerification>,
+}
+
+impl TransferStatus {
+    /// Whether the transfer has reached a final state
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TransferStatus::Completed
+                | TransferStatus::Failed
+                | TransferStatus::TimedOut
+                | TransferStatus::Refunded
+        )
+    }
+}
+
+/// Status change observed while tracking a transfer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferTransition {
+    /// Source chain transaction hash being tracked
+    pub tx_hash: String,
+    /// Previous status; `None` for the first observation
+    pub from: Option<TransferStatus>,
+    pub to: TransferStatus,
+    /// Transaction hash on the destination chain, once the packet was received
+    pub dest_tx_hash: Option<String>,
+    /// Unix timestamp (seconds) the change was observed at
+    pub observed_at: u64,
+}
+
+/// Outcome of tracking a transfer until it settled or the timeout passed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferTracking {
+    /// Last status received
+    pub result: TransferResult,
+    pub transitions: Vec<TransferTransition>,
+    /// Tracking stopped before the transfer reached a final state
+    pub timed_out: bool,
+    pub polls: u32,
 }
 
 // ============================================================================
@@ -207,6 +260,9 @@ pub struct SupportedChain {
     pub supported_assets: Vec<ChainAsset>,
     /// Bridge configurations
     pub bridges: Vec<BridgeInfo>,
+    /// RPC endpoint used to verify deliveries, if configured
+    #[serde(default)]
+    pub rpc_endpoint: Option<String>,
 }
 
 /// Configuration for a specific chain
@@ -239,6 +295,9 @@ pub struct ChainAsset {
     pub is_native: bool,
     /// Contract address (for non-native assets)
     pub contract_address: Option<String>,
+    /// IBC path and base denom of an `ibc/` asset, e.g. `transfer/channel-0/uusdc`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<String>,
 }
 
 /// Bridge connection between two chains
@@ -326,6 +385,8 @@ pub enum SkipSwap {
     SwapExactAssetIn(SkipSwapExactAssetIn),
     /// Swap exact amount out
     SwapExactAssetOut(SkipSwapExactAssetOut),
+    /// Swap exact amount in split across several routes
+    SmartSwapExactAssetIn(SkipSmartSwapExactAssetIn),
 }
 
 /// Skip swap exact asset in
@@ -346,6 +407,15 @@ pub struct SkipSwapExactAssetOut {
     pub operations: Vec<SkipSwapOperation>,
 }
 
+/// Skip smart swap exact asset in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkipSmartSwapExactAssetIn {
+    /// Swap venue name (e.g., "mantra-dex")
+    pub swap_venue_name: String,
+    /// Routes with the share of the input each one receives
+    pub routes: Vec<SkipRoute>,
+}
+
 /// Skip affiliate for fee sharing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkipAffiliate {
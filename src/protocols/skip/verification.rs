@@ -0,0 +1,270 @@
+/// Destination balance verification
+///
+/// Skip reports a transfer as completed once its packets are acknowledged,
+/// which says nothing about what the recipient ended up with after swaps,
+/// fees and slippage on the way. A [`DestinationExpectation`] records what the
+/// recipient must receive and, when captured before broadcasting, its balances
+/// at that moment. Once the transfer settles the destination chain is queried
+/// over its own RPC endpoint and any shortfall is reported in a
+/// [`DestinationVerification`].
+#[cfg(not(target_arch = "wasm32"))]
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+#[cfg(not(target_arch = "wasm32"))]
+use cosmrs::rpc::{Client as RpcClient, HttpClient};
+use cosmwasm_std::{Coin, Uint128};
+#[cfg(not(target_arch = "wasm32"))]
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::str::FromStr;
+use std::sync::{OnceLock, RwLock};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::Error;
+
+/// What a recipient must receive on the destination chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DestinationExpectation {
+    pub chain_id: String,
+    pub recipient: String,
+    /// Minimum amount of each denom the transfer must deliver
+    pub expected: Vec<Coin>,
+    /// Recipient balances of the expected denoms before the transfer
+    pub baseline: Option<Vec<Coin>>,
+}
+
+impl DestinationExpectation {
+    /// Expect `recipient` on `chain_id` to receive at least `expected`
+    pub fn new(
+        chain_id: impl Into<String>,
+        recipient: impl Into<String>,
+        expected: Vec<Coin>,
+    ) -> Self {
+        Self {
+            chain_id: chain_id.into(),
+            recipient: recipient.into(),
+            expected,
+            baseline: None,
+        }
+    }
+
+    /// Compare against balances taken before the transfer
+    ///
+    /// Without a baseline the recipient's whole balance counts as received.
+    pub fn with_baseline(mut self, baseline: Vec<Coin>) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Denoms the expectation covers
+    pub fn denoms(&self) -> Vec<String> {
+        self.expected
+            .iter()
+            .map(|coin| coin.denom.clone())
+            .collect()
+    }
+}
+
+/// Outcome for one expected denom
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceCheck {
+    pub denom: String,
+    pub expected: Uint128,
+    pub baseline: Option<Uint128>,
+    /// Balance after the transfer settled
+    pub observed: Uint128,
+    /// Observed balance minus the baseline
+    pub received: Uint128,
+    /// How much less than expected arrived
+    pub shortfall: Uint128,
+}
+
+/// Result of checking the destination chain after a transfer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DestinationVerification {
+    pub chain_id: String,
+    pub recipient: String,
+    pub checks: Vec<BalanceCheck>,
+    /// Whether every expected amount arrived in full
+    pub verified: bool,
+    /// Unix timestamp of the balance query
+    pub checked_at: u64,
+}
+
+impl DestinationVerification {
+    /// Compare `observed` destination balances with `expectation`
+    pub fn evaluate(
+        expectation: &DestinationExpectation,
+        observed: &[Coin],
+        checked_at: u64,
+    ) -> Self {
+        let amount_of = |coins: &[Coin], denom: &str| {
+            coins
+                .iter()
+                .filter(|coin| coin.denom == denom)
+                .fold(Uint128::zero(), |sum, coin| sum + coin.amount)
+        };
+
+        let checks: Vec<BalanceCheck> = expectation
+            .expected
+            .iter()
+            .map(|expected| {
+                let observed = amount_of(observed, &expected.denom);
+                let baseline = expectation
+                    .baseline
+                    .as_deref()
+                    .map(|baseline| amount_of(baseline, &expected.denom));
+                let received = observed.saturating_sub(baseline.unwrap_or_default());
+                BalanceCheck {
+                    denom: expected.denom.clone(),
+                    expected: expected.amount,
+                    baseline,
+                    observed,
+                    received,
+                    shortfall: expected.amount.saturating_sub(received),
+                }
+            })
+            .collect();
+
+        Self {
+            chain_id: expectation.chain_id.clone(),
+            recipient: expectation.recipient.clone(),
+            verified: checks.iter().all(|check| check.shortfall.is_zero()),
+            checks,
+            checked_at,
+        }
+    }
+
+    /// Amounts that did not arrive
+    pub fn shortfalls(&self) -> Vec<Coin> {
+        self.checks
+            .iter()
+            .filter(|check| !check.shortfall.is_zero())
+            .map(|check| Coin::new(check.shortfall, check.denom.clone()))
+            .collect()
+    }
+}
+
+/// Expectations waiting for their transfer to settle, keyed by source tx hash
+#[derive(Debug, Default)]
+pub struct PendingVerifications {
+    entries: RwLock<HashMap<String, DestinationExpectation>>,
+}
+
+impl PendingVerifications {
+    /// Process-wide registry shared by all Skip clients
+    pub fn global() -> &'static PendingVerifications {
+        static GLOBAL: OnceLock<PendingVerifications> = OnceLock::new();
+        GLOBAL.get_or_init(PendingVerifications::default)
+    }
+
+    /// Verify `expectation` once the transfer sent in `tx_hash` settles
+    pub fn register(&self, tx_hash: &str, expectation: DestinationExpectation) {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(tx_hash.to_string(), expectation);
+    }
+
+    /// Expectation registered for `tx_hash`, if any
+    pub fn get(&self, tx_hash: &str) -> Option<DestinationExpectation> {
+        self.entries
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(tx_hash)
+            .cloned()
+    }
+
+    /// Stop waiting on `tx_hash`
+    pub fn remove(&self, tx_hash: &str) -> Option<DestinationExpectation> {
+        self.entries
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(tx_hash)
+    }
+}
+
+/// Balances of `address` in `denoms` on the chain served by `rpc_url`
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn query_balances(
+    rpc_url: &str,
+    address: &str,
+    denoms: &[String],
+) -> Result<Vec<Coin>, Error> {
+    let rpc_client = HttpClient::new(rpc_url).map_err(|e| {
+        Error::Rpc(format!(
+            "Failed to create RPC client for {}: {}",
+            rpc_url, e
+        ))
+    })?;
+
+    let mut balances = Vec::with_capacity(denoms.len());
+    for denom in denoms {
+        let request = QueryBalanceRequest {
+            address: address.to_string(),
+            denom: denom.clone(),
+        };
+        let response = rpc_client
+            .abci_query(
+                Some("/cosmos.bank.v1beta1.Query/Balance".to_string()),
+                request.encode_to_vec(),
+                None,
+                false,
+            )
+            .await
+            .map_err(|e| Error::Rpc(format!("Failed to query {} balance: {}", denom, e)))?;
+        if !response.code.is_ok() {
+            return Err(Error::Rpc(format!(
+                "Balance query failed: {}",
+                response.log
+            )));
+        }
+
+        let balance = QueryBalanceResponse::decode(response.value.as_slice())
+            .map_err(|e| Error::Rpc(format!("Failed to decode balance response: {}", e)))?
+            .balance
+            .map(|coin| Uint128::from_str(&coin.amount).unwrap_or_default())
+            .unwrap_or_default();
+        balances.push(Coin::new(balance, denom.clone()));
+    }
+    Ok(balances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_reports_shortfalls_against_baseline() {
+        let expectation = DestinationExpectation::new(
+            "osmosis-1",
+            "osmo1recipient",
+            vec![Coin::new(1_000u128, "uosmo"), Coin::new(50u128, "uatom")],
+        )
+        .with_baseline(vec![Coin::new(400u128, "uosmo")]);
+
+        let verification = DestinationVerification::evaluate(
+            &expectation,
+            &[Coin::new(1_300u128, "uosmo"), Coin::new(60u128, "uatom")],
+            1,
+        );
+        assert!(!verification.verified);
+        assert_eq!(verification.checks[0].received, Uint128::new(900));
+        assert_eq!(verification.checks[1].baseline, Some(Uint128::zero()));
+        assert_eq!(verification.shortfalls(), vec![Coin::new(100u128, "uosmo")]);
+
+        // Without a baseline the whole balance counts as received
+        let verification = DestinationVerification::evaluate(
+            &DestinationExpectation::new(
+                "osmosis-1",
+                "osmo1recipient",
+                expectation.expected.clone(),
+            ),
+            &[Coin::new(1_300u128, "uosmo"), Coin::new(60u128, "uatom")],
+            1,
+        );
+        assert!(verification.verified);
+        assert!(verification.shortfalls().is_empty());
+    }
+}
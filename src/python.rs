@@ -0,0 +1,409 @@
+//! Python Bindings
+//!
+//! Classes of the `mantra_dex_sdk` Python package, which the `python/` crate
+//! builds into an extension module with maturin. They wrap
+//! [`MantraClient`] and [`MantraWallet`] for scripts and notebooks: every
+//! network call is a coroutine driven by the SDK's tokio runtime, results come
+//! back as plain dicts and lists, and SDK failures raise `SdkError`.
+//!
+//! ```python
+//! import asyncio
+//! from mantra_dex_sdk import MantraClient, Wallet
+//!
+//! async def main():
+//!     wallet = Wallet.from_mnemonic("word1 word2 ...")
+//!     client = await MantraClient.connect("dukong", wallet)
+//!     quote = await client.simulate_swap("o.uom.uusdc", 1_000_000, "uom", "uusdc")
+//!     tx = await client.swap("o.uom.uusdc", 1_000_000, "uom", "uusdc", "0.01")
+//!
+//! asyncio.run(main())
+//! ```
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3_async_runtimes::tokio::future_into_py;
+use secrecy::{ExposeSecret, Secret};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::{MantraClient, MantraClientBuilder};
+use crate::error::Error;
+use crate::wallet::MantraWallet;
+
+create_exception!(mantra_dex_sdk, SdkError, PyException);
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> Self {
+        SdkError::new_err(error.to_string())
+    }
+}
+
+/// Python value of a JSON value: dicts, lists, str, int, float, bool or None
+fn json_to_py(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_py(py),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(value), _) => value.into_py(py),
+            (None, Some(value)) => value.into_py(py),
+            _ => number.as_f64().unwrap_or_default().into_py(py),
+        },
+        Value::String(value) => value.into_py(py),
+        Value::Array(items) => {
+            let list = PyList::empty_bound(py);
+            for item in items {
+                list.append(json_to_py(py, item)?)?;
+            }
+            list.into_py(py)
+        }
+        Value::Object(fields) => {
+            let dict = PyDict::new_bound(py);
+            for (key, value) in fields {
+                dict.set_item(key, json_to_py(py, value)?)?;
+            }
+            dict.into_py(py)
+        }
+    })
+}
+
+/// Python value of any serializable SDK result
+fn to_py<T: Serialize>(value: &T) -> PyResult<PyObject> {
+    let value = serde_json::to_value(value).map_err(Error::from)?;
+    Python::with_gil(|py| json_to_py(py, &value))
+}
+
+fn parse_decimal(value: &str) -> Result<Decimal, Error> {
+    Decimal::from_str(value).map_err(|e| Error::Other(format!("Invalid decimal {}: {}", value, e)))
+}
+
+/// A wallet derived from a BIP-39 mnemonic
+#[pyclass(name = "Wallet", module = "mantra_dex_sdk")]
+#[derive(Clone)]
+pub struct PyWallet {
+    mnemonic: Arc<Secret<String>>,
+    account_index: u32,
+    wallet: Arc<MantraWallet>,
+}
+
+impl PyWallet {
+    fn derive(mnemonic: String, account_index: u32) -> Result<Self, Error> {
+        let wallet = MantraWallet::from_mnemonic(&mnemonic, account_index)?;
+        Ok(Self {
+            mnemonic: Arc::new(Secret::new(mnemonic)),
+            account_index,
+            wallet: Arc::new(wallet),
+        })
+    }
+
+    /// Owned signer for clients that take the wallet by value
+    fn signer(&self) -> Result<MantraWallet, Error> {
+        MantraWallet::from_mnemonic(self.mnemonic.expose_secret(), self.account_index)
+    }
+}
+
+#[pymethods]
+impl PyWallet {
+    /// Wallet of account `account_index` of `mnemonic`
+    #[staticmethod]
+    #[pyo3(signature = (mnemonic, account_index = 0))]
+    fn from_mnemonic(mnemonic: String, account_index: u32) -> PyResult<Self> {
+        Ok(Self::derive(mnemonic, account_index)?)
+    }
+
+    /// A new random wallet and its mnemonic, as `(wallet, mnemonic)`
+    #[staticmethod]
+    fn generate() -> PyResult<(Self, String)> {
+        let (_, mnemonic) = MantraWallet::generate()?;
+        Ok((Self::derive(mnemonic.clone(), 0)?, mnemonic))
+    }
+
+    #[getter]
+    fn address(&self) -> PyResult<String> {
+        Ok(self.wallet.address()?.to_string())
+    }
+
+    #[getter]
+    fn account_index(&self) -> u32 {
+        self.account_index
+    }
+
+    /// EVM address of the same key, EIP-55 checksummed
+    #[cfg(feature = "evm")]
+    #[getter]
+    fn evm_address(&self) -> PyResult<String> {
+        Ok(self.wallet.ethereum_address()?.to_checksum(None))
+    }
+
+    fn __repr__(&self) -> String {
+        match self.wallet.address() {
+            Ok(address) => format!("Wallet('{}')", address),
+            Err(_) => "Wallet(<invalid>)".to_string(),
+        }
+    }
+}
+
+/// Client for a MANTRA network; every network call is a coroutine
+#[pyclass(name = "MantraClient", module = "mantra_dex_sdk")]
+pub struct PyMantraClient {
+    client: Arc<MantraClient>,
+    wallet: Option<PyWallet>,
+}
+
+impl PyMantraClient {
+    fn signer(&self) -> Result<MantraWallet, Error> {
+        self.wallet
+            .as_ref()
+            .ok_or_else(|| Error::Wallet("No wallet configured".to_string()))?
+            .signer()
+    }
+}
+
+#[pymethods]
+impl PyMantraClient {
+    /// Connect to the network profile `network` ("mainnet", "dukong" or
+    /// "local"), signing with `wallet` if given
+    #[staticmethod]
+    #[pyo3(signature = (network = "dukong".to_string(), wallet = None))]
+    fn connect(
+        py: Python<'_>,
+        network: String,
+        wallet: Option<PyWallet>,
+    ) -> PyResult<Bound<'_, PyAny>> {
+        future_into_py(py, async move {
+            let mut builder = MantraClientBuilder::new().with_profile(network);
+            if let Some(wallet) = &wallet {
+                builder = builder.with_wallet(wallet.wallet.clone());
+            }
+            let client = builder.build().await?;
+            Ok(PyMantraClient {
+                client: Arc::new(client),
+                wallet,
+            })
+        })
+    }
+
+    #[getter]
+    fn chain_id(&self) -> String {
+        self.client.network_config().chain_id.clone()
+    }
+
+    #[getter]
+    fn wallet(&self) -> Option<PyWallet> {
+        self.wallet.clone()
+    }
+
+    /// Enabled protocols
+    fn protocols(&self) -> Vec<String> {
+        self.client
+            .list_protocols()
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    // ============ DEX ============
+
+    /// Pools of the DEX, up to `limit`
+    #[pyo3(signature = (limit = None))]
+    fn pools<'py>(&self, py: Python<'py>, limit: Option<u32>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let pools = client.dex().await?.get_pools(limit).await?;
+            to_py(&pools)
+        })
+    }
+
+    fn pool<'py>(&self, py: Python<'py>, pool_id: String) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let pool = client.dex().await?.get_pool(&pool_id).await?;
+            to_py(&pool)
+        })
+    }
+
+    /// Balances of `address`, or of the wallet's address
+    #[pyo3(signature = (address = None))]
+    fn balances<'py>(
+        &self,
+        py: Python<'py>,
+        address: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let address = match address {
+            Some(address) => address,
+            None => self.signer()?.address()?.to_string(),
+        };
+        future_into_py(py, async move {
+            let balances = client
+                .dex()
+                .await?
+                .get_balances_for_address(&address)
+                .await?;
+            to_py(&balances)
+        })
+    }
+
+    /// Expected result of swapping `amount` of `denom` for `ask_denom`
+    fn simulate_swap<'py>(
+        &self,
+        py: Python<'py>,
+        pool_id: String,
+        amount: u128,
+        denom: String,
+        ask_denom: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            let simulation = client
+                .dex()
+                .await?
+                .simulate_swap(&pool_id, Coin::new(amount, denom), &ask_denom)
+                .await?;
+            to_py(&simulation)
+        })
+    }
+
+    /// Swap `amount` of `denom` for `ask_denom`, failing beyond
+    /// `max_slippage` (e.g. "0.01")
+    #[pyo3(signature = (pool_id, amount, denom, ask_denom, max_slippage = None))]
+    fn swap<'py>(
+        &self,
+        py: Python<'py>,
+        pool_id: String,
+        amount: u128,
+        denom: String,
+        ask_denom: String,
+        max_slippage: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let signer = self.signer()?;
+        let max_slippage = max_slippage.as_deref().map(parse_decimal).transpose()?;
+        future_into_py(py, async move {
+            let response = client
+                .dex()
+                .await?
+                .with_wallet(signer)
+                .swap(&pool_id, Coin::new(amount, denom), &ask_denom, max_slippage)
+                .await?;
+            to_py(&serde_json::json!({
+                "txhash": response.txhash,
+                "code": response.code,
+                "height": response.height,
+                "gas_used": response.gas_used,
+                "raw_log": response.raw_log,
+            }))
+        })
+    }
+
+    // ============ ClaimDrop ============
+
+    /// Rewards of `address`, or of the wallet, in the campaign at `campaign`
+    #[pyo3(signature = (campaign, address = None))]
+    fn claimdrop_rewards<'py>(
+        &self,
+        py: Python<'py>,
+        campaign: String,
+        address: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let address = match address {
+            Some(address) => address,
+            None => self.signer()?.address()?.to_string(),
+        };
+        future_into_py(py, async move {
+            let rewards = client
+                .claimdrop_campaign(campaign)
+                .query_rewards(&address)
+                .await?;
+            to_py(&rewards)
+        })
+    }
+
+    /// Claim `amount` (everything available if omitted) from the campaign at
+    /// `campaign`, paying out to `receiver` or the wallet
+    #[pyo3(signature = (campaign, amount = None, receiver = None))]
+    fn claim<'py>(
+        &self,
+        py: Python<'py>,
+        campaign: String,
+        amount: Option<u128>,
+        receiver: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        let signer = self.signer()?;
+        future_into_py(py, async move {
+            let mut campaign = client.claimdrop_campaign(campaign);
+            campaign.set_dex_client(Arc::new(client.dex().await?.with_wallet(signer)));
+            let result = campaign.claim(amount.map(Uint128::new), receiver).await?;
+            if !result.success {
+                return Err(Error::Tx(result.message).into());
+            }
+            to_py(&result)
+        })
+    }
+
+    // ============ EVM ============
+
+    #[cfg(feature = "evm")]
+    fn evm_block_number<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.client.clone();
+        future_into_py(py, async move {
+            Ok(client.evm().await?.get_block_number().await?)
+        })
+    }
+
+    /// Native balance of the EVM `address` in wei, as a decimal string
+    #[cfg(feature = "evm")]
+    fn evm_balance<'py>(&self, py: Python<'py>, address: String) -> PyResult<Bound<'py, PyAny>> {
+        use crate::protocols::evm::types::EthAddress;
+
+        let client = self.client.clone();
+        let address = EthAddress::from_str(&address)?;
+        future_into_py(py, async move {
+            let balance = client.evm().await?.get_balance(address, None).await?;
+            Ok(balance.to_string())
+        })
+    }
+
+    /// ERC-20 balance of `owner` at `token`, as a decimal string
+    #[cfg(feature = "evm")]
+    fn erc20_balance<'py>(
+        &self,
+        py: Python<'py>,
+        token: String,
+        owner: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        use crate::protocols::evm::types::EthAddress;
+
+        let client = self.client.clone();
+        let token = EthAddress::from_str(&token)?;
+        let owner = EthAddress::from_str(&owner)?;
+        future_into_py(py, async move {
+            let balance = client
+                .evm()
+                .await?
+                .erc20(token.0)
+                .balance_of(owner.0)
+                .await?;
+            Ok(balance.to_string())
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MantraClient('{}')", self.client.network_config().chain_id)
+    }
+}
+
+/// Add the classes and exception of the `mantra_dex_sdk` module to `m`
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyWallet>()?;
+    m.add_class::<PyMantraClient>()?;
+    m.add("SdkError", m.py().get_type_bound::<SdkError>())?;
+    Ok(())
+}
@@ -0,0 +1,148 @@
+//! Chain reorganizations noticed after a transaction counted as confirmed
+//!
+//! A receipt or log is only as final as the block it came from. When that
+//! block is replaced at the same height, its transactions are back in the
+//! mempool or in another block, and an earlier "confirmed" is stale. Receipt
+//! waiting, the transaction queue monitor and cached EVM log scans
+//! (`EvmClient::get_logs_cached`, which cuts the log cache back) compare the
+//! block hashes they saw with the chain, and each replaced block they find is
+//! recorded as a [`ReorgDetected`] with the transactions it held.
+//!
+//! The log is shared by every client in the process through
+//! [`ReorgLog::global`]. The most recent records are kept for queries, and
+//! [`ReorgLog::subscribe`] streams new ones as they are found.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+/// Records kept for queries
+pub const MAX_REORGS: usize = 100;
+
+/// A block a transaction or log was seen in that is no longer on chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReorgDetected {
+    /// Chain id as recorded in the transaction queue
+    pub chain_id: String,
+    /// Height of the replaced block
+    pub height: u64,
+    /// Hash of the block that was seen
+    pub replaced_block_hash: String,
+    /// Hash of the block now at that height, when known
+    pub canonical_block_hash: Option<String>,
+    /// Transactions reported as included in the replaced block
+    pub tx_hashes: Vec<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl ReorgDetected {
+    pub fn new(
+        chain_id: impl Into<String>,
+        height: u64,
+        replaced_block_hash: impl Into<String>,
+        canonical_block_hash: Option<String>,
+        tx_hashes: Vec<String>,
+    ) -> Self {
+        Self {
+            chain_id: chain_id.into(),
+            height,
+            replaced_block_hash: replaced_block_hash.into(),
+            canonical_block_hash,
+            tx_hashes,
+            detected_at: Utc::now(),
+        }
+    }
+}
+
+/// Recent reorgs, and the subscribers told about new ones
+#[derive(Debug)]
+pub struct ReorgLog {
+    recent: Mutex<VecDeque<ReorgDetected>>,
+    sender: broadcast::Sender<ReorgDetected>,
+}
+
+impl Default for ReorgLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReorgLog {
+    pub fn new() -> Self {
+        Self {
+            recent: Mutex::new(VecDeque::new()),
+            sender: broadcast::channel(MAX_REORGS).0,
+        }
+    }
+
+    /// Log shared by every client in the process
+    pub fn global() -> &'static ReorgLog {
+        static GLOBAL: OnceLock<ReorgLog> = OnceLock::new();
+        GLOBAL.get_or_init(ReorgLog::new)
+    }
+
+    /// Keep `reorg` and send it to the subscribers
+    pub fn record(&self, reorg: ReorgDetected) {
+        warn!(
+            "ReorgDetected: block {} at height {} on chain {} was replaced; {} transaction(s) are no longer confirmed: {}",
+            reorg.replaced_block_hash,
+            reorg.height,
+            reorg.chain_id,
+            reorg.tx_hashes.len(),
+            reorg.tx_hashes.join(", ")
+        );
+        {
+            let mut recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+            recent.push_back(reorg.clone());
+            while recent.len() > MAX_REORGS {
+                recent.pop_front();
+            }
+        }
+        // Nobody listening is not an error
+        let _ = self.sender.send(reorg);
+    }
+
+    /// Reorgs found from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<ReorgDetected> {
+        self.sender.subscribe()
+    }
+
+    /// Recorded reorgs, newest first
+    pub fn recent(&self) -> Vec<ReorgDetected> {
+        let recent = self.recent.lock().unwrap_or_else(|e| e.into_inner());
+        recent.iter().rev().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reorgs_are_kept_and_streamed() {
+        let log = ReorgLog::new();
+        let mut events = log.subscribe();
+        for height in 0..(MAX_REORGS as u64 + 2) {
+            log.record(ReorgDetected::new(
+                "5887",
+                height,
+                format!("0x{:064x}", height),
+                None,
+                vec![format!("0x{:064x}", height + 1000)],
+            ));
+        }
+
+        let recent = log.recent();
+        assert_eq!(recent.len(), MAX_REORGS);
+        assert_eq!(recent[0].height, MAX_REORGS as u64 + 1);
+        assert_eq!(recent.last().unwrap().height, 2);
+
+        // Subscribers see every reorg, including those no longer kept
+        assert_eq!(events.recv().await.unwrap().height, 0);
+        assert_eq!(events.recv().await.unwrap().tx_hashes.len(), 1);
+    }
+}
@@ -0,0 +1,334 @@
+//! Retry policy shared by Cosmos RPC, EVM RPC and HTTP calls
+//!
+//! Not every failure is worth repeating. Timeouts, rate limiting (HTTP 429)
+//! and account sequence mismatches usually clear up on their own, while
+//! invalid arguments, reverted calls and insufficient funds fail the same way
+//! every time. [`Classify`] sorts errors into the two, and a [`RetryPolicy`]
+//! retries only the retryable ones, waiting a jittered exponential backoff in
+//! between so that clients failing together do not retry together.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// Whether an error is worth retrying
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// Transient: a timeout, rate limiting, an unavailable endpoint or a
+    /// sequence mismatch
+    Retryable,
+    /// Fails the same way every time: bad input, a revert, missing funds
+    NonRetryable,
+}
+
+/// Errors that can tell whether they are worth retrying
+pub trait Classify {
+    fn error_class(&self) -> ErrorClass;
+
+    fn is_retryable(&self) -> bool {
+        self.error_class() == ErrorClass::Retryable
+    }
+}
+
+/// Phrases of failures that will not go away by themselves; checked first
+const NON_RETRYABLE_PHRASES: &[&str] = &[
+    "insufficient funds",
+    "insufficient balance",
+    "execution reverted",
+    "reverted",
+    "invalid argument",
+    "invalid params",
+    "invalid address",
+    "unauthorized",
+    "out of gas",
+];
+
+/// Phrases of transient failures
+const RETRYABLE_PHRASES: &[&str] = &[
+    "timeout",
+    "timed out",
+    "too many requests",
+    "rate limit",
+    "account sequence mismatch",
+    "incorrect account sequence",
+    "nonce too low",
+    "connection refused",
+    "connection reset",
+    "connection closed",
+    "broken pipe",
+    "unexpected eof",
+    "bad gateway",
+    "service unavailable",
+    "gateway timeout",
+    "temporarily unavailable",
+    "http error 429",
+    "http error 502",
+    "http error 503",
+    "http error 504",
+];
+
+/// Class of an error message, when it names a failure known to be transient
+/// or permanent
+pub fn classify_message(message: &str) -> Option<ErrorClass> {
+    let message = message.to_lowercase();
+    if NON_RETRYABLE_PHRASES
+        .iter()
+        .any(|phrase| message.contains(phrase))
+    {
+        Some(ErrorClass::NonRetryable)
+    } else if RETRYABLE_PHRASES
+        .iter()
+        .any(|phrase| message.contains(phrase))
+    {
+        Some(ErrorClass::Retryable)
+    } else {
+        None
+    }
+}
+
+impl Classify for Error {
+    /// Transport failures are retryable unless their message says otherwise;
+    /// anything else only when its message names a transient failure
+    fn error_class(&self) -> ErrorClass {
+        match self {
+            Error::Timeout(_)
+            | Error::Network(_)
+            | Error::RpcTimeout { .. }
+            | Error::AccountSequenceMismatch { .. }
+            | Error::TxExpired { .. } => ErrorClass::Retryable,
+            Error::Rpc(message) => classify_message(message).unwrap_or(ErrorClass::Retryable),
+            #[cfg(not(target_arch = "wasm32"))]
+            Error::TxBroadcast(response) => {
+                classify_message(&response.log).unwrap_or(ErrorClass::NonRetryable)
+            }
+            Error::Io(e) => match e.kind() {
+                std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::ConnectionRefused
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::Interrupted => ErrorClass::Retryable,
+                _ => ErrorClass::NonRetryable,
+            },
+            Error::Config(_)
+            | Error::Wallet(_)
+            | Error::WalletNotSet
+            | Error::Serialization(_)
+            | Error::FeeValidation(_)
+            | Error::NotImplemented(_)
+            | Error::InsufficientFunds { .. }
+            | Error::SlippageExceeded { .. }
+            | Error::EvmRevert { .. }
+            | Error::RateLimited { .. } => ErrorClass::NonRetryable,
+            other => classify_message(&other.to_string()).unwrap_or(ErrorClass::NonRetryable),
+        }
+    }
+}
+
+/// How often and how patiently to retry retryable errors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled before each further one
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    /// Longest wait between attempts
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+    /// Fraction of each wait drawn at random, from 0 (none) to 1 (anywhere
+    /// between no wait and the full backoff)
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_base_delay_ms() -> u64 {
+    200
+}
+
+fn default_max_delay_ms() -> u64 {
+    5_000
+}
+
+fn default_jitter() -> f64 {
+    0.5
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: default_max_retries(),
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            jitter: default_jitter(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Check the policy's settings
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.jitter) {
+            return Err(format!(
+                "Retry jitter must be between 0 and 1, got {}",
+                self.jitter
+            ));
+        }
+        if self.max_delay_ms < self.base_delay_ms {
+            return Err(format!(
+                "Retry max_delay_ms ({}) is below base_delay_ms ({})",
+                self.max_delay_ms, self.base_delay_ms
+            ));
+        }
+        Ok(())
+    }
+
+    /// Wait before retry `attempt` (0 for the first retry) without jitter
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let delay_ms = self
+            .base_delay_ms
+            .saturating_mul(2u64.saturating_pow(attempt))
+            .min(self.max_delay_ms);
+        Duration::from_millis(delay_ms)
+    }
+
+    /// Wait before retry `attempt`, with the policy's jitter drawn
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0) * rand::random::<f64>();
+        self.backoff(attempt).mul_f64(1.0 - jitter)
+    }
+
+    /// Run `operation` until it succeeds, fails with an error that is not
+    /// retryable, or runs out of retries
+    ///
+    /// `what` names the operation in the log line of each retry.
+    pub async fn run<T, E, F, Fut>(&self, what: &str, mut operation: F) -> Result<T, E>
+    where
+        E: Classify + Display,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < self.max_retries && e.is_retryable() => {
+                    let delay = self.delay(attempt);
+                    tracing::warn!(
+                        "{} failed (attempt {} of {}), retrying in {:?}: {}",
+                        what,
+                        attempt + 1,
+                        self.max_retries + 1,
+                        delay,
+                        e
+                    );
+                    crate::trace::record_retry(what, e.to_string());
+                    sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Wait for `duration` on the runtime of the target: tokio natively, the
+/// browser's timers on wasm
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn test_only_retryable_errors_are_retried_with_capped_backoff() {
+        assert!(Error::Timeout("query".to_string()).is_retryable());
+        assert!(Error::Rpc("ABCI query failed: connection refused".to_string()).is_retryable());
+        assert!(Error::Skip("Skip API error: 429 Too Many Requests".to_string()).is_retryable());
+        assert!(
+            Error::Tx("account sequence mismatch, expected 7, got 6".to_string()).is_retryable()
+        );
+        assert!(
+            !Error::Rpc("insufficient funds: 5uom is smaller than 10uom".to_string())
+                .is_retryable()
+        );
+        assert!(!Error::Evm("execution reverted: sale closed".to_string()).is_retryable());
+        assert!(!Error::Config("invalid argument".to_string()).is_retryable());
+        assert!(!Error::Contract("Contract query failed: unknown pool".to_string()).is_retryable());
+
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay_ms: 1,
+            max_delay_ms: 2,
+            jitter: 0.5,
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(1));
+        assert_eq!(policy.backoff(5), Duration::from_millis(2));
+        assert!(policy.delay(1) >= Duration::from_millis(1));
+        assert!(policy.validate().is_ok());
+        assert!(RetryPolicy {
+            jitter: 1.5,
+            ..policy.clone()
+        }
+        .validate()
+        .is_err());
+
+        let attempts = Cell::new(0);
+        let result: Result<u32, Error> = policy
+            .run("flaky query", || {
+                attempts.set(attempts.get() + 1);
+                async {
+                    match attempts.get() {
+                        1 | 2 => Err(Error::Timeout("query".to_string())),
+                        n => Ok(n),
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 3);
+
+        attempts.set(0);
+        let result: Result<(), Error> = policy
+            .run("transfer", || {
+                attempts.set(attempts.get() + 1);
+                async { Err(Error::Evm("execution reverted".to_string())) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+
+        attempts.set(0);
+        let result: Result<(), Error> = RetryPolicy::none()
+            .run("query", || {
+                attempts.set(attempts.get() + 1);
+                async { Err(Error::Timeout("query".to_string())) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+}
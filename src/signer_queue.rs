@@ -0,0 +1,203 @@
+//! Per-account signing lock shared by both VMs
+//!
+//! Signing reads the account's next sequence or nonce from the node, signs
+//! with it and broadcasts. Two calls for the same account running at once
+//! would read the same value and one of them would be rejected, so every
+//! signing path first takes the account's [`SignerSlot`] from the
+//! [`SignerQueue`]. Slots are handed out in request order and held until the
+//! broadcast is answered.
+//!
+//! A node may not count a transaction it just accepted yet, so the slot also
+//! remembers the next value after each committed broadcast and signs with
+//! the higher of the two. The remembered value is dropped when a signing
+//! attempt fails and after [`NONCE_MEMORY`] without use, so a transaction
+//! that never made it into a block cannot leave a lasting gap.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+use crate::tx_queue::TxVm;
+
+/// How long an idle account's next nonce is remembered
+pub const NONCE_MEMORY: Duration = Duration::from_secs(60);
+
+/// Account a signing slot belongs to
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignerKey {
+    pub vm: TxVm,
+    pub chain_id: String,
+    /// Lowercase address
+    pub address: String,
+}
+
+impl SignerKey {
+    pub fn new(vm: TxVm, chain_id: impl Into<String>, address: &str) -> Self {
+        Self {
+            vm,
+            chain_id: chain_id.into(),
+            address: address.to_lowercase(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct AccountState {
+    next_nonce: Option<u64>,
+    committed_at: Option<Instant>,
+}
+
+/// Exclusive right to sign for one account
+///
+/// Dropping it without [`SignerSlot::commit`] forgets the remembered nonce,
+/// so the next signer goes by the node's count.
+#[derive(Debug)]
+pub struct SignerSlot {
+    key: SignerKey,
+    state: OwnedMutexGuard<AccountState>,
+    nonce: Option<u64>,
+    committed: bool,
+}
+
+impl SignerSlot {
+    pub fn key(&self) -> &SignerKey {
+        &self.key
+    }
+
+    /// Nonce to sign with, given the next nonce reported by the node
+    pub fn nonce(&mut self, chain_nonce: u64) -> u64 {
+        let remembered = self
+            .state
+            .next_nonce
+            .filter(|_| {
+                self.state
+                    .committed_at
+                    .is_some_and(|at| at.elapsed() < NONCE_MEMORY)
+            })
+            .unwrap_or_default();
+        let nonce = chain_nonce.max(remembered);
+        self.nonce = Some(nonce);
+        nonce
+    }
+
+    /// Record that the transaction signed with [`SignerSlot::nonce`] was
+    /// accepted by the node
+    pub fn commit(mut self) {
+        if let Some(nonce) = self.nonce {
+            self.state.next_nonce = Some(nonce + 1);
+            self.state.committed_at = Some(Instant::now());
+        }
+        self.committed = true;
+    }
+
+    /// Give the slot up without broadcasting, as after a dry run, keeping
+    /// the remembered nonce
+    pub fn release(mut self) {
+        self.committed = true;
+    }
+}
+
+impl Drop for SignerSlot {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.state.next_nonce = None;
+            self.state.committed_at = None;
+        }
+    }
+}
+
+/// Signing slots by account
+#[derive(Debug, Default)]
+pub struct SignerQueue {
+    accounts: Mutex<HashMap<SignerKey, Arc<AsyncMutex<AccountState>>>>,
+}
+
+impl SignerQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue shared by every client in the process
+    pub fn global() -> &'static SignerQueue {
+        static GLOBAL: OnceLock<SignerQueue> = OnceLock::new();
+        GLOBAL.get_or_init(SignerQueue::new)
+    }
+
+    /// Wait for the signing slot of `key`, behind the callers already waiting
+    pub async fn acquire(&self, key: SignerKey) -> SignerSlot {
+        let account = self
+            .accounts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        SignerSlot {
+            key,
+            state: account.lock_owned().await,
+            nonce: None,
+            committed: false,
+        }
+    }
+
+    /// Accounts being signed for or waited on
+    pub fn busy_accounts(&self) -> usize {
+        self.accounts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .filter(|account| account.try_lock().is_err())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> SignerKey {
+        SignerKey::new(TxVm::Evm, "5887", "0xABCD")
+    }
+
+    #[tokio::test]
+    async fn test_slots_serialize_signing_and_remember_nonces() {
+        let queue = Arc::new(SignerQueue::new());
+        let mut slot = queue.acquire(key()).await;
+        assert_eq!(slot.key().address, "0xabcd");
+        assert_eq!(queue.busy_accounts(), 1);
+
+        // A second signer waits until the first one is done
+        let waiting = tokio::spawn({
+            let queue = queue.clone();
+            async move {
+                let mut slot = queue.acquire(key()).await;
+                // The node has not counted the first transaction yet
+                slot.nonce(7)
+            }
+        });
+        tokio::task::yield_now().await;
+        assert!(!waiting.is_finished());
+        assert_eq!(slot.nonce(7), 7);
+        slot.commit();
+        assert_eq!(waiting.await.unwrap(), 8);
+
+        // A failed attempt forgets the remembered nonce
+        let mut slot = queue.acquire(key()).await;
+        assert_eq!(slot.nonce(7), 7);
+        drop(slot);
+        let mut slot = queue.acquire(key()).await;
+        assert_eq!(slot.nonce(7), 7);
+        assert_eq!(queue.busy_accounts(), 1);
+        drop(slot);
+        assert_eq!(queue.busy_accounts(), 0);
+
+        // Other accounts are not held up
+        let _held = queue.acquire(key()).await;
+        let mut other = queue
+            .acquire(SignerKey::new(TxVm::Cosmos, "mantra-1", "mantra1abc"))
+            .await;
+        assert_eq!(other.nonce(3), 3);
+    }
+}
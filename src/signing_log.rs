@@ -0,0 +1,497 @@
+//! Signing history with the decoded intent of each transaction
+//!
+//! Before a transaction is signed, what it does is written down as a
+//! [`SignedIntent`]: every message decoded into its action, contract,
+//! recipients and amounts, with a one-line summary. Once it has been signed
+//! and broadcast the record is completed with the signature, nonce and
+//! transaction hash, or with the error that stopped it, so a reviewer can
+//! match each agent-initiated transaction on chain to what it was meant to do.
+//!
+//! The log is shared by every client in the process through
+//! [`SigningLog::global`]. The most recent records are kept in memory; once
+//! [`SigningLog::persist_to`] is called every record is also appended to a
+//! JSONL file, once when it is opened and again when it is completed.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{AuthInfo, TxRaw};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::error::Error;
+use crate::protocols::cosmos_analyzer::DecodedCosmosMsg;
+use crate::tx_queue::TxVm;
+
+/// Records kept in memory for queries
+pub const MAX_MEMORY_INTENTS: usize = 10_000;
+
+/// Records listed when a query gives no limit
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// Parameter names that hold a recipient address
+const RECIPIENT_KEYS: &[&str] = &[
+    "to",
+    "to_address",
+    "recipient",
+    "receiver",
+    "spender",
+    "investor",
+];
+
+/// Parameter names that hold an amount
+const AMOUNT_KEYS: &[&str] = &["amount", "value"];
+
+/// Where a signing attempt got to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentStatus {
+    /// Written down; the transaction is being signed or broadcast
+    Pending,
+    /// Signed and accepted by the node
+    Broadcast,
+    /// Signing or broadcasting failed
+    Failed,
+}
+
+/// One message of a transaction, decoded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntentMessage {
+    /// Contract message or function name, such as `swap` or `transfer`, or
+    /// the type URL of another Cosmos message
+    pub action: String,
+    /// Contract called, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<String>,
+    /// Addresses the message sends to or authorizes
+    #[serde(default)]
+    pub recipients: Vec<String>,
+    /// Amounts moved, as `<amount> <denom or token>`
+    #[serde(default)]
+    pub amounts: Vec<String>,
+    /// The message told in one sentence
+    pub summary: String,
+    /// Decoded message body
+    #[serde(default)]
+    pub parameters: Value,
+}
+
+impl IntentMessage {
+    /// Intent of a decoded Cosmos message, told as `summary`
+    pub fn from_cosmos(msg: &DecodedCosmosMsg, summary: String) -> Self {
+        let mut amounts: Vec<String> = msg
+            .funds
+            .iter()
+            .map(|coin| format!("{} {}", coin.amount, coin.denom))
+            .collect();
+        if amounts.is_empty() {
+            amounts = find_values(&msg.parameters, AMOUNT_KEYS);
+        }
+        Self {
+            action: msg.action.clone(),
+            contract: msg.contract.clone(),
+            recipients: find_values(&msg.parameters, RECIPIENT_KEYS),
+            amounts,
+            summary,
+            parameters: msg.parameters.clone(),
+        }
+    }
+
+    /// Intent of an EVM call of `function` on `contract` sending `value` wei
+    pub fn from_evm(
+        contract: Option<String>,
+        function: &str,
+        parameters: Value,
+        value: &str,
+    ) -> Self {
+        let mut amounts: Vec<String> = find_values(&parameters, AMOUNT_KEYS)
+            .into_iter()
+            .map(|amount| match &contract {
+                Some(token) => format!("{} {}", amount, token),
+                None => amount,
+            })
+            .collect();
+        if value != "0" {
+            amounts.push(format!("{} wei", value));
+        }
+        let summary = match &contract {
+            Some(contract) => format!("call {} on {}", function, contract),
+            None => format!("{} (contract creation)", function),
+        };
+        Self {
+            action: function.to_string(),
+            recipients: find_values(&parameters, RECIPIENT_KEYS),
+            contract,
+            amounts,
+            summary,
+            parameters,
+        }
+    }
+}
+
+/// What a transaction was signed to do, and what came of it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedIntent {
+    pub id: u64,
+    pub created_at: DateTime<Utc>,
+    pub vm: TxVm,
+    pub chain_id: String,
+    pub signer: String,
+    pub messages: Vec<IntentMessage>,
+    /// The whole transaction told in one line
+    pub summary: String,
+    pub status: IntentStatus,
+    /// Sequence or nonce signed with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    /// Hex signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// How a signing attempt ended
+#[derive(Debug, Clone, Default)]
+pub struct SigningOutcome {
+    pub nonce: Option<u64>,
+    pub signature: Option<String>,
+    pub tx_hash: Option<String>,
+    /// Set when signing or broadcasting failed
+    pub error: Option<String>,
+}
+
+/// Filters of [`SigningLog::history`]; unset fields match every record
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SigningQuery {
+    pub signer: Option<String>,
+    pub vm: Option<TxVm>,
+    pub chain_id: Option<String>,
+    pub status: Option<IntentStatus>,
+    /// Only records with a greater id
+    pub since_id: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct LogState {
+    intents: VecDeque<SignedIntent>,
+    next_id: u64,
+    path: Option<PathBuf>,
+}
+
+/// Intents of the transactions signed by this process
+#[derive(Debug, Default)]
+pub struct SigningLog {
+    state: Mutex<LogState>,
+}
+
+impl SigningLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Log shared by every client in the process
+    pub fn global() -> &'static SigningLog {
+        static GLOBAL: OnceLock<SigningLog> = OnceLock::new();
+        GLOBAL.get_or_init(SigningLog::new)
+    }
+
+    /// Append every record to the JSONL file at `path` from now on, after
+    /// loading the records it already holds
+    pub fn persist_to(&self, path: impl Into<PathBuf>) -> Result<(), Error> {
+        let path = path.into();
+        let loaded = if path.exists() {
+            read_intents(&path)?
+        } else {
+            Vec::new()
+        };
+        let mut state = self.lock();
+        for intent in loaded {
+            state.next_id = state.next_id.max(intent.id + 1);
+            // The last line of an id is its latest state
+            match state.intents.iter_mut().find(|known| known.id == intent.id) {
+                Some(known) => *known = intent,
+                None => state.intents.push_back(intent),
+            }
+        }
+        while state.intents.len() > MAX_MEMORY_INTENTS {
+            state.intents.pop_front();
+        }
+        state.path = Some(path);
+        Ok(())
+    }
+
+    /// Write down what a transaction about to be signed does, returning the
+    /// id to complete the record with
+    pub fn begin(
+        &self,
+        vm: TxVm,
+        chain_id: &str,
+        signer: &str,
+        messages: Vec<IntentMessage>,
+    ) -> u64 {
+        let mut state = self.lock();
+        let id = state.next_id;
+        state.next_id += 1;
+        let summary = match messages.len() {
+            0 => "empty transaction".to_string(),
+            _ => messages
+                .iter()
+                .map(|msg| msg.summary.as_str())
+                .collect::<Vec<_>>()
+                .join(", then "),
+        };
+        let intent = SignedIntent {
+            id,
+            created_at: Utc::now(),
+            vm,
+            chain_id: chain_id.to_string(),
+            signer: signer.to_string(),
+            messages,
+            summary,
+            status: IntentStatus::Pending,
+            nonce: None,
+            signature: None,
+            tx_hash: None,
+            error: None,
+            completed_at: None,
+        };
+        append(&state.path, &intent);
+        state.intents.push_back(intent);
+        if state.intents.len() > MAX_MEMORY_INTENTS {
+            state.intents.pop_front();
+        }
+        id
+    }
+
+    /// Complete the record `id` with how its signing attempt ended
+    pub fn complete(&self, id: u64, outcome: SigningOutcome) {
+        let mut state = self.lock();
+        let state = &mut *state;
+        let Some(intent) = state
+            .intents
+            .iter_mut()
+            .rev()
+            .find(|intent| intent.id == id)
+        else {
+            return;
+        };
+        intent.status = if outcome.error.is_some() {
+            IntentStatus::Failed
+        } else {
+            IntentStatus::Broadcast
+        };
+        intent.nonce = outcome.nonce;
+        intent.signature = outcome.signature;
+        intent.tx_hash = outcome.tx_hash;
+        intent.error = outcome.error;
+        intent.completed_at = Some(Utc::now());
+        append(&state.path, intent);
+    }
+
+    /// Records matching `query`, newest first
+    pub fn history(&self, query: &SigningQuery) -> Vec<SignedIntent> {
+        let signer = query.signer.as_deref().map(str::to_lowercase);
+        self.lock()
+            .intents
+            .iter()
+            .rev()
+            .filter(|intent| {
+                signer
+                    .as_ref()
+                    .is_none_or(|signer| intent.signer.to_lowercase() == *signer)
+                    && query.vm.is_none_or(|vm| intent.vm == vm)
+                    && query
+                        .chain_id
+                        .as_ref()
+                        .is_none_or(|chain_id| intent.chain_id == *chain_id)
+                    && query.status.is_none_or(|status| intent.status == status)
+                    && query.since_id.is_none_or(|since| intent.id > since)
+            })
+            .take(query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT))
+            .cloned()
+            .collect()
+    }
+
+    /// Log snapshot for health and diagnostics output
+    pub fn snapshot(&self) -> Value {
+        let state = self.lock();
+        serde_json::json!({
+            "records": state.intents.len(),
+            "pending": state
+                .intents
+                .iter()
+                .filter(|intent| intent.status == IntentStatus::Pending)
+                .count(),
+            "path": state.path.as_ref().map(|path| path.display().to_string()),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LogState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Sequence and hex signature of a signed Cosmos transaction, when its
+/// bytes can be read
+pub fn cosmos_signature(tx_bytes: &[u8]) -> (Option<u64>, Option<String>) {
+    let Ok(raw) = TxRaw::decode(tx_bytes) else {
+        return (None, None);
+    };
+    let sequence = AuthInfo::decode(raw.auth_info_bytes.as_slice())
+        .ok()
+        .and_then(|auth_info| auth_info.signer_infos.first().map(|info| info.sequence));
+    (sequence, raw.signatures.first().map(hex::encode))
+}
+
+/// Values of the `keys` fields anywhere in `value`, as strings
+fn find_values(value: &Value, keys: &[&str]) -> Vec<String> {
+    let mut found = Vec::new();
+    collect_values(value, keys, &mut found);
+    found
+}
+
+fn collect_values(value: &Value, keys: &[&str], found: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    Value::String(text) if keys.contains(&key.as_str()) => found.push(text.clone()),
+                    Value::Number(number) if keys.contains(&key.as_str()) => {
+                        found.push(number.to_string())
+                    }
+                    _ => collect_values(value, keys, found),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_values(item, keys, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn append(path: &Option<PathBuf>, intent: &SignedIntent) {
+    let Some(path) = path else {
+        return;
+    };
+    let result = (|| -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(intent)?)?;
+        Ok(())
+    })();
+    if let Err(e) = result {
+        warn!("Failed to write signing log {}: {}", path.display(), e);
+    }
+}
+
+fn read_intents(path: &Path) -> Result<Vec<SignedIntent>, Error> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut intents = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(intent) => intents.push(intent),
+            Err(e) => warn!("Skipping unreadable signing log line: {}", e),
+        }
+    }
+    Ok(intents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::cosmos_analyzer::{CoinAmount, CosmosProtocol};
+
+    #[test]
+    fn test_intents_are_recorded_completed_and_reloaded() {
+        let path = std::env::temp_dir().join(format!("signing_log_{}.jsonl", uuid::Uuid::new_v4()));
+        let log = SigningLog::new();
+        log.persist_to(&path).unwrap();
+
+        let send = DecodedCosmosMsg {
+            protocol: CosmosProtocol::Bank,
+            action: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            sender: "mantra1me".to_string(),
+            contract: None,
+            funds: vec![CoinAmount {
+                denom: "uom".to_string(),
+                amount: "5".to_string(),
+            }],
+            parameters: serde_json::json!({ "to_address": "mantra1you" }),
+        };
+        let message = IntentMessage::from_cosmos(&send, "you sent 5 OM".to_string());
+        assert_eq!(message.recipients, ["mantra1you"]);
+        assert_eq!(message.amounts, ["5 uom"]);
+        let cosmos = log.begin(TxVm::Cosmos, "mantra-1", "mantra1me", vec![message]);
+
+        let transfer = IntentMessage::from_evm(
+            Some("0xtoken".to_string()),
+            "transfer",
+            serde_json::json!({ "to": "0xyou", "amount": "7" }),
+            "0",
+        );
+        assert_eq!(transfer.amounts, ["7 0xtoken"]);
+        let evm = log.begin(TxVm::Evm, "5887", "0xME", vec![transfer]);
+        assert_eq!(
+            log.history(&SigningQuery::default())[0].status,
+            IntentStatus::Pending
+        );
+
+        log.complete(
+            cosmos,
+            SigningOutcome {
+                nonce: Some(3),
+                signature: Some("ab".to_string()),
+                tx_hash: Some("HASH".to_string()),
+                error: None,
+            },
+        );
+        log.complete(
+            evm,
+            SigningOutcome {
+                error: Some("nonce too low".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let failed = log.history(&SigningQuery {
+            signer: Some("0xme".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].status, IntentStatus::Failed);
+
+        // The file holds every state; reloading keeps the latest of each
+        let reloaded = SigningLog::new();
+        reloaded.persist_to(&path).unwrap();
+        let history = reloaded.history(&SigningQuery {
+            vm: Some(TxVm::Cosmos),
+            ..Default::default()
+        });
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].tx_hash.as_deref(), Some("HASH"));
+        assert_eq!(history[0].summary, "you sent 5 OM");
+        assert_eq!(reloaded.begin(TxVm::Evm, "5887", "0xme", Vec::new()), 2);
+        fs::remove_file(path).unwrap();
+    }
+}
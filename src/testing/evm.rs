@@ -0,0 +1,367 @@
+//! In-memory EVM chain with the query surface of [`EvmClient`](crate::protocols::evm::client::EvmClient)
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use alloy_primitives::{keccak256, Address, B256, U256};
+use alloy_sol_types::SolCall;
+use async_trait::async_trait;
+
+use crate::error::Error;
+use crate::protocols::evm::types::{EthAddress, EvmCallRequest};
+use crate::tx_queue::{TxBroadcaster, TxLookup, TxVm};
+
+const NAME: [u8; 4] = [0x06, 0xfd, 0xde, 0x03];
+const SYMBOL: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+const DECIMALS: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+const TOTAL_SUPPLY: [u8; 4] = [0x18, 0x16, 0x0d, 0xdd];
+const BALANCE_OF: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+const ALLOWANCE: [u8; 4] = [0xdd, 0x62, 0xed, 0x3e];
+
+/// Outcome of a mined transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockReceipt {
+    pub block_number: u64,
+    pub block_hash: B256,
+    /// `false` when the transaction reverted
+    pub status: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct MockErc20 {
+    name: String,
+    symbol: String,
+    decimals: u8,
+    balances: HashMap<Address, U256>,
+    allowances: HashMap<(Address, Address), U256>,
+}
+
+#[derive(Debug, Default)]
+struct EvmState {
+    block_number: u64,
+    balances: HashMap<Address, U256>,
+    nonces: HashMap<Address, u64>,
+    tokens: HashMap<Address, MockErc20>,
+    /// Call results by contract and selector; `Err` reverts with the reason
+    calls: HashMap<(Address, [u8; 4]), Result<Vec<u8>, String>>,
+    pending: Vec<B256>,
+    receipts: BTreeMap<B256, MockReceipt>,
+    dropped_broadcasts: u32,
+    reverting_transactions: u32,
+    /// Reorgs so far, which give the blocks mined after them new hashes
+    forks: u64,
+}
+
+/// EVM client answering from local state instead of an RPC endpoint
+///
+/// Native balances, nonces and ERC-20 tokens are held locally, and the token
+/// getters (`name`, `symbol`, `decimals`, `totalSupply`, `balanceOf`,
+/// `allowance`) are answered from them. Any other call returns what
+/// [`MockEvmClient::respond_call`] programmed. Broadcast transactions stay
+/// pending until [`MockEvmClient::mine`]. Clones share the same chain.
+#[derive(Debug, Clone)]
+pub struct MockEvmClient {
+    chain_id: u64,
+    state: Arc<Mutex<EvmState>>,
+}
+
+impl MockEvmClient {
+    pub fn new(chain_id: u64) -> Self {
+        Self {
+            chain_id,
+            state: Arc::new(Mutex::new(EvmState {
+                block_number: 1,
+                ..EvmState::default()
+            })),
+        }
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    pub fn set_balance(&self, address: Address, balance: U256) {
+        self.lock().balances.insert(address, balance);
+    }
+
+    pub fn set_nonce(&self, address: Address, nonce: u64) {
+        self.lock().nonces.insert(address, nonce);
+    }
+
+    /// Deploy an ERC-20 token at `token` with no holders
+    pub fn add_erc20(&self, token: Address, name: &str, symbol: &str, decimals: u8) {
+        self.lock().tokens.insert(
+            token,
+            MockErc20 {
+                name: name.to_string(),
+                symbol: symbol.to_string(),
+                decimals,
+                ..MockErc20::default()
+            },
+        );
+    }
+
+    /// Set the token balance of `holder`; the token must have been added
+    pub fn set_erc20_balance(&self, token: Address, holder: Address, amount: U256) {
+        if let Some(token) = self.lock().tokens.get_mut(&token) {
+            token.balances.insert(holder, amount);
+        }
+    }
+
+    /// Set what `spender` may spend of `owner`'s tokens
+    pub fn set_erc20_allowance(
+        &self,
+        token: Address,
+        owner: Address,
+        spender: Address,
+        amount: U256,
+    ) {
+        if let Some(token) = self.lock().tokens.get_mut(&token) {
+            token.allowances.insert((owner, spender), amount);
+        }
+    }
+
+    /// Return `data` from calls to `contract` with `selector`
+    pub fn respond_call(&self, contract: Address, selector: [u8; 4], data: Vec<u8>) {
+        self.lock().calls.insert((contract, selector), Ok(data));
+    }
+
+    /// Revert calls to `contract` with `selector`
+    pub fn revert_call(&self, contract: Address, selector: [u8; 4], reason: &str) {
+        self.lock()
+            .calls
+            .insert((contract, selector), Err(reason.to_string()));
+    }
+
+    /// Accept the next `count` broadcasts without ever mining them
+    pub fn drop_broadcasts(&self, count: u32) {
+        self.lock().dropped_broadcasts = count;
+    }
+
+    /// Revert the next `count` transactions mined
+    pub fn revert_transactions(&self, count: u32) {
+        self.lock().reverting_transactions = count;
+    }
+
+    /// Mine a block with every pending transaction, returning its number
+    pub fn mine(&self) -> u64 {
+        let mut state = self.lock();
+        state.block_number += 1;
+        let block_number = state.block_number;
+        let block_hash = keccak256(format!("{}:{}", state.forks, block_number));
+        for hash in std::mem::take(&mut state.pending) {
+            let status = if state.reverting_transactions > 0 {
+                state.reverting_transactions -= 1;
+                false
+            } else {
+                true
+            };
+            state.receipts.insert(
+                hash,
+                MockReceipt {
+                    block_number,
+                    block_hash,
+                    status,
+                },
+            );
+        }
+        block_number
+    }
+
+    /// Replace the last `depth` blocks: their transactions go back to
+    /// pending, and the blocks mined in their place get other hashes
+    pub fn reorg(&self, depth: u64) {
+        let mut state = self.lock();
+        let fork_point = state.block_number.saturating_sub(depth);
+        let orphaned: Vec<B256> = state
+            .receipts
+            .iter()
+            .filter(|(_, receipt)| receipt.block_number > fork_point)
+            .map(|(hash, _)| *hash)
+            .collect();
+        for hash in orphaned {
+            state.receipts.remove(&hash);
+            state.pending.push(hash);
+        }
+        state.block_number = fork_point;
+        state.forks += 1;
+    }
+
+    pub async fn get_block_number(&self) -> Result<u64, Error> {
+        Ok(self.lock().block_number)
+    }
+
+    /// Native balance; `block` is ignored, the mock keeps no history
+    pub async fn get_balance(
+        &self,
+        address: EthAddress,
+        _block: Option<String>,
+    ) -> Result<U256, Error> {
+        Ok(self
+            .lock()
+            .balances
+            .get(&address.0)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    pub async fn get_transaction_count(&self, address: EthAddress) -> Result<u64, Error> {
+        Ok(self
+            .lock()
+            .nonces
+            .get(&address.0)
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Receipt of a mined transaction
+    pub async fn get_transaction_receipt(
+        &self,
+        tx_hash: B256,
+    ) -> Result<Option<MockReceipt>, Error> {
+        Ok(self.lock().receipts.get(&tx_hash).copied())
+    }
+
+    /// Execute a read-only call
+    pub async fn call(&self, request: EvmCallRequest) -> Result<Vec<u8>, Error> {
+        let selector: [u8; 4] = request
+            .data
+            .get(..4)
+            .and_then(|s| s.try_into().ok())
+            .ok_or_else(|| Error::Evm("Call data has no function selector".to_string()))?;
+
+        let state = self.lock();
+        if let Some(result) = state.calls.get(&(request.to.0, selector)) {
+            return result
+                .clone()
+                .map_err(|reason| Error::Evm(format!("execution reverted: {}", reason)));
+        }
+        let token = state.tokens.get(&request.to.0).ok_or_else(|| {
+            Error::Evm(format!(
+                "execution reverted: no response programmed for {:#x} selector 0x{}",
+                request.to.0,
+                hex::encode(selector)
+            ))
+        })?;
+        let address_arg = |index: usize| {
+            let start = 4 + 32 * index;
+            request
+                .data
+                .get(start + 12..start + 32)
+                .map(Address::from_slice)
+                .ok_or_else(|| Error::Evm("Call data is too short".to_string()))
+        };
+        Ok(match selector {
+            NAME => encode_string(&token.name),
+            SYMBOL => encode_string(&token.symbol),
+            DECIMALS => encode_uint(U256::from(token.decimals)),
+            TOTAL_SUPPLY => encode_uint(token.balances.values().copied().sum()),
+            BALANCE_OF => encode_uint(
+                token
+                    .balances
+                    .get(&address_arg(0)?)
+                    .copied()
+                    .unwrap_or_default(),
+            ),
+            ALLOWANCE => encode_uint(
+                token
+                    .allowances
+                    .get(&(address_arg(0)?, address_arg(1)?))
+                    .copied()
+                    .unwrap_or_default(),
+            ),
+            _ => {
+                return Err(Error::Evm(format!(
+                    "execution reverted: no response programmed for {:#x} selector 0x{}",
+                    request.to.0,
+                    hex::encode(selector)
+                )))
+            }
+        })
+    }
+
+    pub async fn call_raw(&self, address: Address, data: Vec<u8>) -> Result<Vec<u8>, Error> {
+        self.call(EvmCallRequest {
+            to: EthAddress(address),
+            data,
+            block: None,
+        })
+        .await
+    }
+
+    /// Call a contract method (read-only)
+    pub async fn call_contract<T: SolCall>(
+        &self,
+        contract_address: Address,
+        call: T,
+    ) -> Result<T::Return, Error> {
+        let result = self.call_raw(contract_address, call.abi_encode()).await?;
+        T::abi_decode_returns(&result, false)
+            .map_err(|e| Error::Evm(format!("Failed to decode contract call result: {}", e)))
+    }
+
+    /// Broadcast signed transaction bytes, returning the keccak-256 hash
+    ///
+    /// The bytes are not decoded: the transaction is mined as successful by
+    /// the next [`MockEvmClient::mine`] unless told otherwise.
+    pub async fn send_raw_transaction_bytes(&self, signed_tx: Vec<u8>) -> Result<B256, Error> {
+        let hash = keccak256(&signed_tx);
+        let mut state = self.lock();
+        if state.dropped_broadcasts > 0 {
+            state.dropped_broadcasts -= 1;
+        } else if !state.receipts.contains_key(&hash) && !state.pending.contains(&hash) {
+            state.pending.push(hash);
+        }
+        Ok(hash)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, EvmState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn encode_uint(value: U256) -> Vec<u8> {
+    value.to_be_bytes::<32>().to_vec()
+}
+
+/// ABI encoding of a single returned string: offset, length, padded bytes
+fn encode_string(value: &str) -> Vec<u8> {
+    let mut encoded = encode_uint(U256::from(32u8));
+    encoded.extend(encode_uint(U256::from(value.len())));
+    encoded.extend(value.as_bytes());
+    encoded.resize(64 + value.len().div_ceil(32) * 32, 0);
+    encoded
+}
+
+#[async_trait]
+impl TxBroadcaster for MockEvmClient {
+    fn vm(&self) -> TxVm {
+        TxVm::Evm
+    }
+
+    fn chain_id(&self) -> String {
+        self.chain_id.to_string()
+    }
+
+    async fn lookup(&self, hash: &str) -> Result<TxLookup, Error> {
+        let hash: B256 = hash
+            .parse()
+            .map_err(|e| Error::Evm(format!("Invalid transaction hash {}: {}", hash, e)))?;
+        Ok(match self.get_transaction_receipt(hash).await? {
+            None => TxLookup::NotFound,
+            Some(receipt) if receipt.status => TxLookup::Confirmed {
+                height: receipt.block_number,
+                block_hash: Some(format!("{:#x}", receipt.block_hash)),
+            },
+            Some(_) => TxLookup::Failed {
+                reason: "Transaction reverted".to_string(),
+            },
+        })
+    }
+
+    async fn rebroadcast(&self, raw_tx: &[u8]) -> Result<(), Error> {
+        self.send_raw_transaction_bytes(raw_tx.to_vec())
+            .await
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,165 @@
+//! Canned pools, campaigns and accounts
+//!
+//! Every value is fixed, so tests built on them are deterministic: amounts,
+//! addresses and timestamps never depend on the clock or the network.
+
+use cosmwasm_std::{Coin, Decimal};
+use mantra_claimdrop_std::msg::{CampaignResponse, DistributionType, RewardsResponse};
+use mantra_dex_std::{
+    fee::{Fee, PoolFee},
+    pool_manager::{PoolInfo, PoolInfoResponse, PoolStatus, PoolType},
+};
+
+use super::MockRpc;
+
+/// Chain id of the mock chain
+pub const CHAIN_ID: &str = "mantra-mock-1";
+/// Address the mock chain answers pool manager queries at
+pub const POOL_MANAGER: &str = "mantra1mockpoolmanager";
+/// Address of the fixture ClaimDrop campaign
+pub const CAMPAIGN: &str = "mantra1mockcampaign";
+/// Address of the ClaimDrop factory, which has created no campaigns yet
+pub const FACTORY: &str = "mantra1mockfactory";
+/// Funded account
+pub const ALICE: &str = "mantra1mockalice";
+/// Funded account eligible for the fixture campaign
+pub const BOB: &str = "mantra1mockbob";
+
+/// OM/USDC constant product pool
+pub const OM_USDC_POOL: &str = "o.uom.uusdc";
+/// USDC/USDT stable swap pool
+pub const USDC_USDT_POOL: &str = "p.uusdc.uusdt";
+
+/// Start of the fixture campaign: 2024-01-01T00:00:00Z
+pub const CAMPAIGN_START: u64 = 1_704_067_200;
+/// End of the fixture campaign, 180 days after its start
+pub const CAMPAIGN_END: u64 = CAMPAIGN_START + 180 * 24 * 60 * 60;
+
+/// Pool with the fees MANTRA pools charge by default: 0.1% protocol fee and
+/// 0.2% swap fee, everything enabled and 6 decimals for every asset
+pub fn pool(pool_id: &str, pool_type: PoolType, reserves: &[(&str, u128)]) -> PoolInfoResponse {
+    let lp_denom = format!("factory/{}/{}.LP", POOL_MANAGER, pool_id);
+    // Geometric mean of the reserves, as minted for the first deposit
+    let total_share = reserves
+        .iter()
+        .map(|(_, amount)| (*amount as f64).ln())
+        .sum::<f64>()
+        / reserves.len().max(1) as f64;
+
+    PoolInfoResponse {
+        pool_info: PoolInfo {
+            pool_identifier: pool_id.to_string(),
+            asset_denoms: reserves
+                .iter()
+                .map(|(denom, _)| denom.to_string())
+                .collect(),
+            lp_denom: lp_denom.clone(),
+            asset_decimals: vec![6; reserves.len()],
+            assets: reserves
+                .iter()
+                .map(|(denom, amount)| Coin::new(*amount, *denom))
+                .collect(),
+            pool_type,
+            pool_fees: PoolFee {
+                protocol_fee: Fee {
+                    share: Decimal::permille(1),
+                },
+                swap_fee: Fee {
+                    share: Decimal::permille(2),
+                },
+                burn_fee: Fee {
+                    share: Decimal::zero(),
+                },
+                extra_fees: vec![],
+            },
+            status: PoolStatus {
+                swaps_enabled: true,
+                deposits_enabled: true,
+                withdrawals_enabled: true,
+            },
+        },
+        total_share: Coin::new(total_share.exp().round() as u128, lp_denom),
+    }
+}
+
+/// 1,000,000 OM against 250,000 USDC, pricing OM at 0.25 USDC
+pub fn om_usdc_pool() -> PoolInfoResponse {
+    pool(
+        OM_USDC_POOL,
+        PoolType::ConstantProduct,
+        &[("uom", 1_000_000_000_000), ("uusdc", 250_000_000_000)],
+    )
+}
+
+/// 500,000 USDC against 500,000 USDT with an amplification of 100
+pub fn usdc_usdt_pool() -> PoolInfoResponse {
+    pool(
+        USDC_USDT_POOL,
+        PoolType::StableSwap { amp: 100 },
+        &[("uusdc", 500_000_000_000), ("uusdt", 500_000_000_000)],
+    )
+}
+
+/// All fixture pools
+pub fn pools() -> Vec<PoolInfoResponse> {
+    vec![om_usdc_pool(), usdc_usdt_pool()]
+}
+
+/// Campaign distributing 1,000,000 OM: 25% at its start, the rest vested
+/// linearly until its end with a 30 day cliff
+pub fn campaign() -> CampaignResponse {
+    CampaignResponse {
+        name: "Mock airdrop".to_string(),
+        description: "Deterministic ClaimDrop campaign for tests".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: Coin::new(1_000_000_000_000u128, "uom"),
+        claimed: Coin::new(0u128, "uom"),
+        distribution_type: vec![
+            DistributionType::LumpSum {
+                percentage: Decimal::percent(25),
+                start_time: CAMPAIGN_START,
+            },
+            DistributionType::LinearVesting {
+                percentage: Decimal::percent(75),
+                start_time: CAMPAIGN_START,
+                end_time: CAMPAIGN_END,
+                cliff_duration: Some(30 * 24 * 60 * 60),
+            },
+        ],
+        start_time: CAMPAIGN_START,
+        end_time: CAMPAIGN_END,
+        closed: None,
+    }
+}
+
+/// Rewards of an allocation of `allocated` uom, nothing claimed yet, with
+/// `available` of it claimable now
+pub fn rewards(allocated: u128, available: u128) -> RewardsResponse {
+    RewardsResponse {
+        claimed: vec![],
+        pending: vec![Coin::new(allocated - available.min(allocated), "uom")],
+        available_to_claim: vec![Coin::new(available.min(allocated), "uom")],
+    }
+}
+
+/// Mock chain holding the fixture pools, campaign and factory
+///
+/// [`ALICE`] holds 10,000 OM, 1,000 USDC and 1,000 USDT; [`BOB`] holds 10 OM
+/// for fees and may claim 2,500 OM of a 10,000 OM allocation.
+pub fn mock_chain() -> MockRpc {
+    let rpc = MockRpc::new(CHAIN_ID, POOL_MANAGER);
+    for pool in pools() {
+        rpc.add_pool(pool);
+    }
+    rpc.add_campaign(CAMPAIGN, campaign());
+    rpc.set_rewards(CAMPAIGN, BOB, rewards(10_000_000_000, 2_500_000_000));
+    rpc.add_factory(FACTORY);
+
+    rpc.set_balance(ALICE, Coin::new(10_000_000_000u128, "uom"));
+    rpc.set_balance(ALICE, Coin::new(1_000_000_000u128, "uusdc"));
+    rpc.set_balance(ALICE, Coin::new(1_000_000_000u128, "uusdt"));
+    rpc.set_balance(BOB, Coin::new(10_000_000u128, "uom"));
+    // The campaign holds what it distributes
+    rpc.set_balance(CAMPAIGN, Coin::new(1_000_000_000_000u128, "uom"));
+    rpc
+}
@@ -0,0 +1,36 @@
+//! Mock chains and deterministic fixtures for testing without a network
+//!
+//! [`MockRpc`] is an in-memory chain behind the [`AbciTransport`](crate::transport::AbciTransport)
+//! trait: the ClaimDrop clients and [`smart_query`](crate::transport::smart_query)
+//! run against it unchanged. [`MockMantraClient`] adds swaps and claims executed on its
+//! state, and its [`MockSigner`] signs ClaimDrop factory and campaign
+//! executions for clients given it with `set_executor`. [`MockEvmClient`]
+//! (`evm` feature) does the same for the EVM side. Both implement [`TxBroadcaster`](crate::tx_queue::TxBroadcaster), so
+//! a [`TxQueue`](crate::tx_queue::TxQueue) can be driven through drops,
+//! resends and confirmations. [`fixtures`] holds canned pools, a ClaimDrop
+//! campaign and funded accounts.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), mantra_sdk::Error> {
+//! use mantra_sdk::testing::{fixtures, MockMantraClient};
+//! use mantra_sdk::Coin;
+//!
+//! let client = MockMantraClient::new(fixtures::mock_chain());
+//! let quote = client
+//!     .simulate_swap(fixtures::OM_USDC_POOL, Coin::new(1_000_000u128, "uom"), "uusdc")
+//!     .await?;
+//! client
+//!     .swap(fixtures::ALICE, fixtures::OM_USDC_POOL, Coin::new(1_000_000u128, "uom"), "uusdc", Some(quote.return_amount))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "evm")]
+mod evm;
+pub mod fixtures;
+mod rpc;
+
+#[cfg(feature = "evm")]
+pub use evm::{MockEvmClient, MockReceipt};
+pub use rpc::{MockMantraClient, MockRpc, MockSigner, MockTx, RecordedQuery};
@@ -0,0 +1,1178 @@
+//! In-memory chain answering ABCI queries like a MANTRA node
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse,
+};
+use cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
+use cosmos_sdk_proto::tendermint::abci::{Event, EventAttribute};
+use cosmwasm_std::{Coin, Decimal, Uint128};
+use mantra_claimdrop_std::msg::{
+    AllocationsResponse, CampaignParams, CampaignResponse, ExecuteMsg as ClaimdropExecuteMsg,
+    QueryMsg as ClaimdropQueryMsg, RewardsResponse,
+};
+use mantra_dex_std::pool_manager::{
+    self, PoolInfoResponse, PoolType, PoolsResponse, ReverseSimulationResponse, SimulationResponse,
+};
+use prost::Message;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::protocols::claimdrop::{ContractCall, ContractExecutor};
+use crate::protocols::dex::zap::total_swap_fee;
+use crate::transport::{self, AbciResponse, AbciTransport};
+use crate::tx_queue::{cosmos_tx_hash, TxBroadcaster, TxLookup, TxVm};
+
+const ALL_BALANCES_PATH: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
+const BALANCE_PATH: &str = "/cosmos.bank.v1beta1.Query/Balance";
+
+/// ABCI code of an unknown query path (`sdkerrors.ErrUnknownRequest`)
+const CODE_UNKNOWN_REQUEST: u32 = 6;
+/// ABCI code of a failed contract query (`wasmtypes.ErrQueryFailed`)
+const CODE_QUERY_FAILED: u32 = 9;
+/// ABCI code of a query to an address without a contract (`wasmtypes.ErrNoSuchContractFn`)
+const CODE_NO_SUCH_CONTRACT: u32 = 22;
+/// Transaction code of a spend beyond the balance (`sdkerrors.ErrInsufficientFunds`)
+const CODE_INSUFFICIENT_FUNDS: u32 = 5;
+/// Transaction code of a failed contract execution (`wasmtypes.ErrExecuteFailed`)
+const CODE_EXECUTE_FAILED: u32 = 5;
+
+/// A query the mock chain answered
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedQuery {
+    /// gRPC path of the query
+    pub path: String,
+    /// Contract of a smart query, or the address of a bank query
+    pub address: String,
+    /// Smart query message; `null` for bank queries
+    pub msg: Value,
+}
+
+/// A transaction the mock chain included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockTx {
+    pub hash: String,
+    pub height: u64,
+    /// 0 on success
+    pub code: u32,
+    pub log: String,
+}
+
+impl MockTx {
+    pub fn is_ok(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// Response programmed for one contract query
+#[derive(Debug, Clone)]
+struct ProgrammedResponse {
+    contract: String,
+    query: String,
+    result: Result<Value, (u32, String)>,
+    /// Answers left, `None` for every query
+    remaining: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct MockCampaign {
+    campaign: Option<CampaignResponse>,
+    rewards: BTreeMap<String, RewardsResponse>,
+    allocations: BTreeMap<String, Uint128>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct LocalChain {
+    chain_id: String,
+    pool_manager: String,
+    height: u64,
+    balances: BTreeMap<String, BTreeMap<String, Uint128>>,
+    pools: BTreeMap<String, PoolInfoResponse>,
+    campaigns: BTreeMap<String, MockCampaign>,
+    /// Campaigns each ClaimDrop factory created, oldest first
+    factories: BTreeMap<String, Vec<String>>,
+    responses: Vec<ProgrammedResponse>,
+    queries: Vec<RecordedQuery>,
+    txs: BTreeMap<String, MockTx>,
+    /// Broadcasts still to be accepted without being included
+    dropped_broadcasts: u32,
+}
+
+/// In-memory chain state behind an [`AbciTransport`]
+///
+/// Bank balances, pools and ClaimDrop campaigns are held locally: pool
+/// manager queries and constant product simulations are computed from them,
+/// and transactions run through [`MockMantraClient`] change them. Any
+/// contract query can be overridden with [`MockRpc::respond`] or made to fail
+/// with [`MockRpc::fail`]. Clones share the same chain.
+#[derive(Debug, Clone, Default)]
+pub struct MockRpc {
+    chain: Arc<Mutex<LocalChain>>,
+}
+
+impl MockRpc {
+    /// Empty chain answering DEX queries at `pool_manager`
+    pub fn new(chain_id: impl Into<String>, pool_manager: impl Into<String>) -> Self {
+        let chain = LocalChain {
+            chain_id: chain_id.into(),
+            pool_manager: pool_manager.into(),
+            height: 1,
+            ..LocalChain::default()
+        };
+        Self {
+            chain: Arc::new(Mutex::new(chain)),
+        }
+    }
+
+    pub fn chain_id(&self) -> String {
+        self.lock().chain_id.clone()
+    }
+
+    pub fn pool_manager(&self) -> String {
+        self.lock().pool_manager.clone()
+    }
+
+    /// Height of the last block, advanced by every included transaction
+    pub fn height(&self) -> u64 {
+        self.lock().height
+    }
+
+    /// Set the balance of `address` in `coin.denom` to `coin.amount`
+    pub fn set_balance(&self, address: &str, coin: Coin) {
+        self.lock()
+            .balances
+            .entry(address.to_string())
+            .or_default()
+            .insert(coin.denom, coin.amount);
+    }
+
+    pub fn balance(&self, address: &str, denom: &str) -> Uint128 {
+        self.lock().balance(address, denom)
+    }
+
+    /// Non-zero balances of `address`, sorted by denom
+    pub fn balances(&self, address: &str) -> Vec<Coin> {
+        self.lock().balances(address)
+    }
+
+    /// Add or replace a pool of the pool manager
+    pub fn add_pool(&self, pool: PoolInfoResponse) {
+        self.lock()
+            .pools
+            .insert(pool.pool_info.pool_identifier.clone(), pool);
+    }
+
+    /// Pool as it is now, reserves included
+    pub fn pool(&self, pool_id: &str) -> Option<PoolInfoResponse> {
+        self.lock().pools.get(pool_id).cloned()
+    }
+
+    /// Add or replace a ClaimDrop campaign contract at `address`
+    pub fn add_campaign(&self, address: &str, campaign: CampaignResponse) {
+        self.lock()
+            .campaigns
+            .entry(address.to_string())
+            .or_default()
+            .campaign = Some(campaign);
+    }
+
+    /// Add a ClaimDrop factory contract at `address`
+    ///
+    /// Campaigns it creates are instantiated at `{address}campaign{n}`.
+    pub fn add_factory(&self, address: &str) {
+        self.lock()
+            .factories
+            .entry(address.to_string())
+            .or_default();
+    }
+
+    /// Set the rewards of `receiver` in the campaign at `campaign`
+    pub fn set_rewards(&self, campaign: &str, receiver: &str, rewards: RewardsResponse) {
+        self.lock()
+            .campaigns
+            .entry(campaign.to_string())
+            .or_default()
+            .rewards
+            .insert(receiver.to_string(), rewards);
+    }
+
+    /// Answer every `query` (the message's top-level key, such as
+    /// `simulation`) to `contract` with `response` instead of the local state
+    pub fn respond<R: Serialize>(&self, contract: &str, query: &str, response: &R) {
+        self.program(contract, query, Ok(to_value(response)), None);
+    }
+
+    /// Answer the next `query` to `contract` with `response`, then fall back
+    /// to what was answered before
+    pub fn respond_once<R: Serialize>(&self, contract: &str, query: &str, response: &R) {
+        self.program(contract, query, Ok(to_value(response)), Some(1));
+    }
+
+    /// Fail the next `times` `query` queries to `contract` with `code` and `log`
+    pub fn fail(&self, contract: &str, query: &str, code: u32, log: &str, times: u32) {
+        if times == 0 {
+            return;
+        }
+        self.program(contract, query, Err((code, log.to_string())), Some(times));
+    }
+
+    fn program(
+        &self,
+        contract: &str,
+        query: &str,
+        result: Result<Value, (u32, String)>,
+        remaining: Option<u32>,
+    ) {
+        // Newest first, so a one-off answer takes precedence over a standing one
+        self.lock().responses.insert(
+            0,
+            ProgrammedResponse {
+                contract: contract.to_string(),
+                query: query.to_string(),
+                result,
+                remaining,
+            },
+        );
+    }
+
+    /// Queries answered so far, oldest first
+    pub fn queries(&self) -> Vec<RecordedQuery> {
+        self.lock().queries.clone()
+    }
+
+    /// Included transaction with `hash`
+    pub fn transaction(&self, hash: &str) -> Option<MockTx> {
+        self.lock().txs.get(hash).cloned()
+    }
+
+    /// Accept the next `count` broadcasts without ever including them, as a
+    /// node that drops transactions from its mempool does
+    pub fn drop_broadcasts(&self, count: u32) {
+        self.lock().dropped_broadcasts = count;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LocalChain> {
+        self.chain.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn to_value<R: Serialize>(response: &R) -> Value {
+    serde_json::to_value(response).expect("programmed response serializes to JSON")
+}
+
+#[async_trait]
+impl AbciTransport for MockRpc {
+    async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        _height: Option<u64>,
+    ) -> Result<AbciResponse, Error> {
+        let mut chain = self.lock();
+        let result = match path {
+            transport::SMART_QUERY_PATH => {
+                let request = QuerySmartContractStateRequest::decode(data.as_slice())
+                    .map_err(|e| Error::Rpc(format!("Invalid smart query: {}", e)))?;
+                let msg: Value = serde_json::from_slice(&request.query_data)?;
+                chain.queries.push(RecordedQuery {
+                    path: path.to_string(),
+                    address: request.address.clone(),
+                    msg: msg.clone(),
+                });
+                chain.smart_query(&request.address, &msg).map(|data| {
+                    QuerySmartContractStateResponse {
+                        data: serde_json::to_vec(&data).unwrap_or_default(),
+                    }
+                    .encode_to_vec()
+                })
+            }
+            ALL_BALANCES_PATH => {
+                let request = QueryAllBalancesRequest::decode(data.as_slice())
+                    .map_err(|e| Error::Rpc(format!("Invalid balances query: {}", e)))?;
+                chain.record_bank_query(path, &request.address);
+                let balances = chain.balances(&request.address);
+                Ok(QueryAllBalancesResponse {
+                    balances: balances.iter().map(proto_coin).collect(),
+                    pagination: None,
+                }
+                .encode_to_vec())
+            }
+            BALANCE_PATH => {
+                let request = QueryBalanceRequest::decode(data.as_slice())
+                    .map_err(|e| Error::Rpc(format!("Invalid balance query: {}", e)))?;
+                chain.record_bank_query(path, &request.address);
+                let amount = chain.balance(&request.address, &request.denom);
+                Ok(QueryBalanceResponse {
+                    balance: Some(proto_coin(&Coin::new(amount, request.denom))),
+                }
+                .encode_to_vec())
+            }
+            _ => Err((
+                CODE_UNKNOWN_REQUEST,
+                format!("unknown query path: {}", path),
+            )),
+        };
+
+        Ok(match result {
+            Ok(value) => AbciResponse {
+                code: 0,
+                log: String::new(),
+                value,
+                height: chain.height,
+            },
+            Err((code, log)) => AbciResponse {
+                code,
+                log,
+                value: Vec::new(),
+                height: chain.height,
+            },
+        })
+    }
+}
+
+fn proto_coin(coin: &Coin) -> ProtoCoin {
+    ProtoCoin {
+        denom: coin.denom.clone(),
+        amount: coin.amount.to_string(),
+    }
+}
+
+fn query_failed(message: impl std::fmt::Display) -> (u32, String) {
+    (
+        CODE_QUERY_FAILED,
+        format!("Generic error: Querier contract error: {}", message),
+    )
+}
+
+impl LocalChain {
+    fn balance(&self, address: &str, denom: &str) -> Uint128 {
+        self.balances
+            .get(address)
+            .and_then(|balances| balances.get(denom))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn balances(&self, address: &str) -> Vec<Coin> {
+        self.balances
+            .get(address)
+            .map(|balances| {
+                balances
+                    .iter()
+                    .filter(|(_, amount)| !amount.is_zero())
+                    .map(|(denom, amount)| Coin::new(*amount, denom))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn record_bank_query(&mut self, path: &str, address: &str) {
+        self.queries.push(RecordedQuery {
+            path: path.to_string(),
+            address: address.to_string(),
+            msg: Value::Null,
+        });
+    }
+
+    fn smart_query(&mut self, contract: &str, msg: &Value) -> Result<Value, (u32, String)> {
+        let query = msg
+            .as_object()
+            .and_then(|msg| msg.keys().next())
+            .cloned()
+            .unwrap_or_default();
+
+        if let Some(index) = self
+            .responses
+            .iter()
+            .position(|r| r.contract == contract && r.query == query)
+        {
+            let programmed = &mut self.responses[index];
+            let result = programmed.result.clone();
+            if let Some(remaining) = programmed.remaining.as_mut() {
+                *remaining -= 1;
+                if *remaining == 0 {
+                    self.responses.remove(index);
+                }
+            }
+            return result;
+        }
+
+        if contract == self.pool_manager {
+            let msg: pool_manager::QueryMsg =
+                serde_json::from_value(msg.clone()).map_err(query_failed)?;
+            self.pool_manager_query(msg)
+        } else if let Some(campaign) = self.campaigns.get(contract) {
+            let msg: ClaimdropQueryMsg =
+                serde_json::from_value(msg.clone()).map_err(query_failed)?;
+            campaign_query(campaign, msg)
+        } else if let Some(campaigns) = self.factories.get(contract) {
+            factory_query(campaigns, &query, msg)
+        } else {
+            Err((
+                CODE_NO_SUCH_CONTRACT,
+                format!("no such contract: {}", contract),
+            ))
+        }
+    }
+
+    fn pool_manager_query(&self, msg: pool_manager::QueryMsg) -> Result<Value, (u32, String)> {
+        match msg {
+            pool_manager::QueryMsg::Pools {
+                pool_identifier: Some(pool_id),
+                ..
+            } => {
+                let pool = self.pool(&pool_id).map_err(query_failed)?;
+                Ok(to_value(&PoolsResponse {
+                    pools: vec![pool.clone()],
+                }))
+            }
+            pool_manager::QueryMsg::Pools {
+                pool_identifier: None,
+                start_after,
+                limit,
+            } => {
+                let pools = self
+                    .pools
+                    .values()
+                    .filter(|pool| {
+                        start_after
+                            .as_ref()
+                            .is_none_or(|after| pool.pool_info.pool_identifier > *after)
+                    })
+                    .take(limit.unwrap_or(u32::MAX) as usize)
+                    .cloned()
+                    .collect();
+                Ok(to_value(&PoolsResponse { pools }))
+            }
+            pool_manager::QueryMsg::Simulation {
+                offer_asset,
+                ask_asset_denom,
+                pool_identifier,
+            } => {
+                let pool = self.pool(&pool_identifier).map_err(query_failed)?;
+                simulate_swap(pool, &offer_asset, &ask_asset_denom)
+                    .map(|(simulation, _)| to_value(&simulation))
+                    .map_err(query_failed)
+            }
+            pool_manager::QueryMsg::ReverseSimulation {
+                ask_asset,
+                offer_asset_denom,
+                pool_identifier,
+            } => {
+                let pool = self.pool(&pool_identifier).map_err(query_failed)?;
+                simulate_reverse_swap(pool, &ask_asset, &offer_asset_denom)
+                    .map(|simulation| to_value(&simulation))
+                    .map_err(query_failed)
+            }
+            other => Err(query_failed(format!(
+                "the mock chain does not answer {:?}; program it with MockRpc::respond",
+                other
+            ))),
+        }
+    }
+
+    fn pool(&self, pool_id: &str) -> Result<&PoolInfoResponse, String> {
+        self.pools
+            .get(pool_id)
+            .ok_or_else(|| format!("pool {} not found", pool_id))
+    }
+
+    /// Include a transaction, advancing the height
+    fn include(&mut self, hash: String, result: Result<(), (u32, String)>) -> MockTx {
+        self.height += 1;
+        let (code, log) = result.err().unwrap_or_default();
+        let tx = MockTx {
+            hash: hash.clone(),
+            height: self.height,
+            code,
+            log,
+        };
+        self.txs.insert(hash, tx.clone());
+        tx
+    }
+
+    fn transfer(&mut self, from: &str, to: &str, coin: &Coin) -> Result<(), (u32, String)> {
+        let available = self.balance(from, &coin.denom);
+        if available < coin.amount {
+            return Err((
+                CODE_INSUFFICIENT_FUNDS,
+                format!(
+                    "spendable balance {}{} is smaller than {}: insufficient funds",
+                    available, coin.denom, coin
+                ),
+            ));
+        }
+        self.adjust(from, &coin.denom, |amount| amount - coin.amount);
+        self.adjust(to, &coin.denom, |amount| amount + coin.amount);
+        Ok(())
+    }
+
+    fn adjust(&mut self, address: &str, denom: &str, f: impl FnOnce(Uint128) -> Uint128) {
+        let balance = self
+            .balances
+            .entry(address.to_string())
+            .or_default()
+            .entry(denom.to_string())
+            .or_default();
+        *balance = f(*balance);
+    }
+
+    fn swap(
+        &mut self,
+        sender: &str,
+        pool_id: &str,
+        offer: &Coin,
+        ask_denom: &str,
+        min_receive: Option<Uint128>,
+    ) -> Result<(), (u32, String)> {
+        let execute_failed = |e: String| (CODE_EXECUTE_FAILED, e);
+        let pool = self.pool(pool_id).map_err(execute_failed)?;
+        if !pool.pool_info.status.swaps_enabled {
+            return Err(execute_failed(format!("swaps are disabled in {}", pool_id)));
+        }
+        let (simulation, retained) =
+            simulate_swap(pool, offer, ask_denom).map_err(execute_failed)?;
+        if let Some(min_receive) = min_receive {
+            if simulation.return_amount < min_receive {
+                return Err(execute_failed(format!(
+                    "minimum receive amount {} not met: {}",
+                    min_receive, simulation.return_amount
+                )));
+            }
+        }
+
+        let pool_manager = self.pool_manager.clone();
+        self.transfer(sender, &pool_manager, offer)?;
+        self.adjust(&pool_manager, ask_denom, |amount| {
+            amount.saturating_sub(simulation.return_amount)
+        });
+        self.adjust(sender, ask_denom, |amount| {
+            amount + simulation.return_amount
+        });
+
+        let pool = self.pools.get_mut(pool_id).expect("pool exists");
+        for asset in pool.pool_info.assets.iter_mut() {
+            if asset.denom == offer.denom {
+                asset.amount += offer.amount;
+            } else if asset.denom == ask_denom {
+                // The swap fee stays in the pool for liquidity providers
+                asset.amount -= retained;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run every call or none, returning the events of the executions
+    fn execute(
+        &mut self,
+        sender: &str,
+        calls: &[ContractCall],
+    ) -> Result<Vec<Event>, (u32, String)> {
+        let mut state = self.clone();
+        let mut events = Vec::new();
+        for call in calls {
+            for coin in &call.funds {
+                state.transfer(sender, &call.contract, coin)?;
+            }
+            if state.factories.contains_key(&call.contract) {
+                events.extend(state.create_campaign(&call.contract, &call.msg)?);
+            } else if state.campaigns.contains_key(&call.contract) {
+                let msg: ClaimdropExecuteMsg = serde_json::from_value(call.msg.clone())
+                    .map_err(|e| (CODE_EXECUTE_FAILED, e.to_string()))?;
+                state.execute_campaign(&call.contract, sender, msg)?;
+            } else {
+                return Err((
+                    CODE_EXECUTE_FAILED,
+                    format!("no such contract: {}", call.contract),
+                ));
+            }
+        }
+        *self = state;
+        Ok(events)
+    }
+
+    fn create_campaign(&mut self, factory: &str, msg: &Value) -> Result<Vec<Event>, (u32, String)> {
+        let params: CampaignParams = msg
+            .get("create_campaign")
+            .and_then(|msg| msg.get("params"))
+            .cloned()
+            .ok_or_else(|| {
+                (
+                    CODE_EXECUTE_FAILED,
+                    format!("the mock chain does not execute {} on factories", msg),
+                )
+            })
+            .and_then(|params| {
+                serde_json::from_value(params).map_err(|e| (CODE_EXECUTE_FAILED, e.to_string()))
+            })?;
+
+        let campaigns = self.factories.get_mut(factory).expect("factory exists");
+        let address = format!("{}campaign{}", factory, campaigns.len() + 1);
+        campaigns.push(address.clone());
+        self.campaigns.insert(
+            address.clone(),
+            MockCampaign {
+                campaign: Some(CampaignResponse {
+                    name: params.name,
+                    description: params.description,
+                    ty: params.ty,
+                    claimed: Coin::new(0u128, params.total_reward.denom.clone()),
+                    total_reward: params.total_reward,
+                    distribution_type: params.distribution_type,
+                    start_time: params.start_time,
+                    end_time: params.end_time,
+                    closed: None,
+                }),
+                ..MockCampaign::default()
+            },
+        );
+
+        Ok(vec![Event {
+            r#type: "instantiate".to_string(),
+            attributes: vec![EventAttribute {
+                key: "_contract_address".to_string(),
+                value: address,
+                index: true,
+            }],
+        }])
+    }
+
+    fn execute_campaign(
+        &mut self,
+        campaign: &str,
+        sender: &str,
+        msg: ClaimdropExecuteMsg,
+    ) -> Result<(), (u32, String)> {
+        match msg {
+            ClaimdropExecuteMsg::AddAllocations { allocations } => {
+                let stored = &mut self
+                    .campaigns
+                    .get_mut(campaign)
+                    .expect("campaign exists")
+                    .allocations;
+                for (address, amount) in allocations {
+                    if stored.insert(address.clone(), amount).is_some() {
+                        return Err((
+                            CODE_EXECUTE_FAILED,
+                            format!("{} already has an allocation", address),
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            ClaimdropExecuteMsg::Claim {
+                receiver: None,
+                amount: None,
+            } => self.claim(campaign, sender).map(|_| ()),
+            other => Err((
+                CODE_EXECUTE_FAILED,
+                format!("the mock chain does not execute {:?}", other),
+            )),
+        }
+    }
+
+    fn claim(&mut self, campaign: &str, receiver: &str) -> Result<Vec<Coin>, (u32, String)> {
+        let rewards = self
+            .campaigns
+            .get_mut(campaign)
+            .ok_or_else(|| {
+                (
+                    CODE_EXECUTE_FAILED,
+                    format!("no such contract: {}", campaign),
+                )
+            })?
+            .rewards
+            .get_mut(receiver)
+            .ok_or_else(|| {
+                (
+                    CODE_EXECUTE_FAILED,
+                    format!("{} has no allocation in the campaign", receiver),
+                )
+            })?;
+        let claimed = std::mem::take(&mut rewards.available_to_claim);
+        if claimed.iter().all(|coin| coin.amount.is_zero()) {
+            return Err((CODE_EXECUTE_FAILED, "nothing to claim".to_string()));
+        }
+        for coin in &claimed {
+            match rewards.claimed.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(total) => total.amount += coin.amount,
+                None => rewards.claimed.push(coin.clone()),
+            }
+        }
+
+        for coin in &claimed {
+            self.transfer(campaign, receiver, coin)?;
+            if let Some(info) = self
+                .campaigns
+                .get_mut(campaign)
+                .and_then(|c| c.campaign.as_mut())
+            {
+                info.claimed.amount += coin.amount;
+            }
+        }
+        Ok(claimed)
+    }
+}
+
+fn campaign_query(campaign: &MockCampaign, msg: ClaimdropQueryMsg) -> Result<Value, (u32, String)> {
+    match msg {
+        ClaimdropQueryMsg::Campaign {} => campaign
+            .campaign
+            .as_ref()
+            .map(to_value)
+            .ok_or_else(|| query_failed("there's no campaign yet")),
+        ClaimdropQueryMsg::Rewards { receiver } => Ok(to_value(
+            &campaign
+                .rewards
+                .get(&receiver)
+                .cloned()
+                .unwrap_or(RewardsResponse {
+                    claimed: vec![],
+                    pending: vec![],
+                    available_to_claim: vec![],
+                }),
+        )),
+        ClaimdropQueryMsg::Allocations {
+            address,
+            start_after,
+            limit,
+        } => {
+            let denom = campaign
+                .campaign
+                .as_ref()
+                .map(|campaign| campaign.total_reward.denom.clone())
+                .ok_or_else(|| query_failed("there's no campaign yet"))?;
+            let allocations = campaign
+                .allocations
+                .iter()
+                .filter(|(user, _)| address.as_ref().is_none_or(|address| address == *user))
+                .filter(|(user, _)| start_after.as_ref().is_none_or(|after| *user > after))
+                .take(limit.map_or(usize::MAX, usize::from))
+                .map(|(user, amount)| (user.clone(), Coin::new(*amount, denom.clone())))
+                .collect();
+            Ok(to_value(&AllocationsResponse { allocations }))
+        }
+        other => Err(query_failed(format!(
+            "the mock chain does not answer {:?}; program it with MockRpc::respond",
+            other
+        ))),
+    }
+}
+
+fn factory_query(campaigns: &[String], query: &str, msg: &Value) -> Result<Value, (u32, String)> {
+    if query != "campaigns" {
+        return Err(query_failed(format!(
+            "the mock chain does not answer {} on factories; program it with MockRpc::respond",
+            msg
+        )));
+    }
+    let start_after = msg[query]["start_after"].as_str();
+    let limit = msg[query]["limit"]
+        .as_u64()
+        .map_or(usize::MAX, |l| l as usize);
+    let campaigns: Vec<&String> = campaigns
+        .iter()
+        .filter(|campaign| start_after.is_none_or(|after| campaign.as_str() > after))
+        .take(limit)
+        .collect();
+    Ok(serde_json::json!({ "campaigns": campaigns }))
+}
+
+/// Reserves of the offered and the asked denom
+fn reserves(
+    pool: &PoolInfoResponse,
+    offer_denom: &str,
+    ask_denom: &str,
+) -> Result<(Uint128, Uint128), String> {
+    if !matches!(pool.pool_info.pool_type, PoolType::ConstantProduct) {
+        return Err(format!(
+            "the mock chain only simulates constant product pools; program {} with MockRpc::respond",
+            pool.pool_info.pool_identifier
+        ));
+    }
+    let reserve = |denom: &str| {
+        pool.pool_info
+            .assets
+            .iter()
+            .find(|asset| asset.denom == denom)
+            .map(|asset| asset.amount)
+            .ok_or_else(|| {
+                format!(
+                    "{} is not in pool {}",
+                    denom, pool.pool_info.pool_identifier
+                )
+            })
+    };
+    Ok((reserve(offer_denom)?, reserve(ask_denom)?))
+}
+
+/// Constant product swap with every fee taken from the output, as the pool
+/// manager computes it, and how much of the asked asset leaves the pool
+fn simulate_swap(
+    pool: &PoolInfoResponse,
+    offer: &Coin,
+    ask_denom: &str,
+) -> Result<(SimulationResponse, Uint128), String> {
+    let (offer_reserve, ask_reserve) = reserves(pool, &offer.denom, ask_denom)?;
+    if offer_reserve.is_zero() || ask_reserve.is_zero() {
+        return Err("pool has no liquidity".to_string());
+    }
+
+    let ideal = ask_reserve.multiply_ratio(offer.amount, offer_reserve);
+    let gross = ask_reserve.multiply_ratio(offer.amount, offer_reserve + offer.amount);
+    let fees = &pool.pool_info.pool_fees;
+    let swap_fee_amount = gross.mul_floor(fees.swap_fee.share);
+    let protocol_fee_amount = gross.mul_floor(fees.protocol_fee.share);
+    let burn_fee_amount = gross.mul_floor(fees.burn_fee.share);
+    let extra_fees_amount = gross.mul_floor(
+        fees.extra_fees
+            .iter()
+            .fold(Decimal::zero(), |total, fee| total + fee.share),
+    );
+    let return_amount = gross
+        .checked_sub(swap_fee_amount + protocol_fee_amount + burn_fee_amount + extra_fees_amount)
+        .map_err(|_| "fees exceed the swap output".to_string())?;
+
+    Ok((
+        SimulationResponse {
+            return_amount,
+            slippage_amount: ideal.saturating_sub(gross),
+            swap_fee_amount,
+            protocol_fee_amount,
+            burn_fee_amount,
+            extra_fees_amount,
+        },
+        gross - swap_fee_amount,
+    ))
+}
+
+/// Offer needed for `ask` after fees, rounded up
+fn simulate_reverse_swap(
+    pool: &PoolInfoResponse,
+    ask: &Coin,
+    offer_denom: &str,
+) -> Result<ReverseSimulationResponse, String> {
+    let (offer_reserve, ask_reserve) = reserves(pool, offer_denom, &ask.denom)?;
+    let fee = total_swap_fee(&pool.pool_info.pool_fees);
+    if fee >= Decimal::one() {
+        return Err("fees exceed the swap output".to_string());
+    }
+    let one = Decimal::one().atomics();
+    let gross = ask.amount.multiply_ratio(one, one - fee.atomics());
+    if gross >= ask_reserve {
+        return Err("not enough liquidity for the asked amount".to_string());
+    }
+    let offer_amount = offer_reserve.multiply_ratio(gross, ask_reserve - gross) + Uint128::one();
+
+    let fees = &pool.pool_info.pool_fees;
+    Ok(ReverseSimulationResponse {
+        offer_amount,
+        slippage_amount: ask_reserve
+            .multiply_ratio(offer_amount, offer_reserve)
+            .saturating_sub(gross),
+        swap_fee_amount: gross.mul_floor(fees.swap_fee.share),
+        protocol_fee_amount: gross.mul_floor(fees.protocol_fee.share),
+        burn_fee_amount: gross.mul_floor(fees.burn_fee.share),
+        extra_fees_amount: gross.mul_floor(
+            fees.extra_fees
+                .iter()
+                .fold(Decimal::zero(), |total, fee| total + fee.share),
+        ),
+    })
+}
+
+/// DEX client running against a [`MockRpc`] chain
+///
+/// Queries go through [`transport::smart_query`] and
+/// [`transport::proto_query`] over the mock transport, so they are encoded
+/// and decoded exactly as against a node. Transactions execute
+/// directly on the local state: they take the sender's address instead of a
+/// wallet and return the included [`MockTx`], failing with [`Error::Tx`]
+/// when the chain rejects them.
+#[derive(Debug, Clone)]
+pub struct MockMantraClient {
+    rpc: MockRpc,
+}
+
+impl MockMantraClient {
+    pub fn new(rpc: MockRpc) -> Self {
+        Self { rpc }
+    }
+
+    /// The mock chain, to program responses or inspect its state
+    pub fn rpc(&self) -> &MockRpc {
+        &self.rpc
+    }
+
+    pub async fn get_pools(&self, limit: Option<u32>) -> Result<Vec<PoolInfoResponse>, Error> {
+        let query = pool_manager::QueryMsg::Pools {
+            pool_identifier: None,
+            start_after: None,
+            limit,
+        };
+        let response: PoolsResponse = self.pool_manager_query(&query).await?;
+        Ok(response.pools)
+    }
+
+    pub async fn get_pool(&self, pool_id: &str) -> Result<PoolInfoResponse, Error> {
+        let query = pool_manager::QueryMsg::Pools {
+            pool_identifier: Some(pool_id.to_string()),
+            start_after: None,
+            limit: None,
+        };
+        let response: PoolsResponse = self.pool_manager_query(&query).await?;
+        response
+            .pools
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::Other(format!("Pool {} not found", pool_id)))
+    }
+
+    pub async fn simulate_swap(
+        &self,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+    ) -> Result<SimulationResponse, Error> {
+        let query = pool_manager::QueryMsg::Simulation {
+            pool_identifier: pool_id.to_string(),
+            ask_asset_denom: ask_asset_denom.to_string(),
+            offer_asset,
+        };
+        self.pool_manager_query(&query).await
+    }
+
+    pub async fn get_balances(&self, address: &str) -> Result<Vec<Coin>, Error> {
+        let request = QueryAllBalancesRequest {
+            address: address.to_string(),
+            pagination: None,
+            resolve_denom: false,
+        };
+        let response: QueryAllBalancesResponse =
+            transport::proto_query(&self.rpc, ALL_BALANCES_PATH, &request, None).await?;
+        Ok(response
+            .balances
+            .into_iter()
+            .map(|coin| Coin::new(coin.amount.parse::<u128>().unwrap_or_default(), coin.denom))
+            .collect())
+    }
+
+    pub async fn get_campaign(&self, campaign: &str) -> Result<CampaignResponse, Error> {
+        transport::smart_query(&self.rpc, campaign, &ClaimdropQueryMsg::Campaign {}, None).await
+    }
+
+    pub async fn get_rewards(
+        &self,
+        campaign: &str,
+        receiver: &str,
+    ) -> Result<RewardsResponse, Error> {
+        let query = ClaimdropQueryMsg::Rewards {
+            receiver: receiver.to_string(),
+        };
+        transport::smart_query(&self.rpc, campaign, &query, None).await
+    }
+
+    async fn pool_manager_query<R: serde::de::DeserializeOwned>(
+        &self,
+        query: &pool_manager::QueryMsg,
+    ) -> Result<R, Error> {
+        transport::smart_query(&self.rpc, &self.rpc.pool_manager(), query, None).await
+    }
+
+    /// Swap `offer_asset` for `ask_asset_denom`, failing below `min_receive`
+    pub async fn swap(
+        &self,
+        sender: &str,
+        pool_id: &str,
+        offer_asset: Coin,
+        ask_asset_denom: &str,
+        min_receive: Option<Uint128>,
+    ) -> Result<MockTx, Error> {
+        let hash = mock_tx_hash(
+            "swap",
+            &[sender, pool_id, &offer_asset.to_string(), ask_asset_denom],
+            self.rpc().height(),
+        );
+        let mut chain = self.rpc().lock();
+        let result = chain.swap(sender, pool_id, &offer_asset, ask_asset_denom, min_receive);
+        into_result(chain.include(hash, result))
+    }
+
+    /// Claim everything `receiver` can claim from the campaign at `campaign`
+    pub async fn claim(&self, campaign: &str, receiver: &str) -> Result<MockTx, Error> {
+        let hash = mock_tx_hash("claim", &[campaign, receiver], self.rpc().height());
+        let mut chain = self.rpc().lock();
+        let result = chain.claim(campaign, receiver).map(|_| ());
+        into_result(chain.include(hash, result))
+    }
+
+    /// Executor signing contract executions as `sender`
+    pub fn signer(&self, sender: &str) -> MockSigner {
+        MockSigner {
+            rpc: self.rpc.clone(),
+            sender: sender.to_string(),
+        }
+    }
+
+    /// Broadcast signed transaction bytes, returning the transaction hash
+    ///
+    /// The bytes are not decoded: the transaction is included as successful
+    /// unless [`MockRpc::drop_broadcasts`] says to lose it.
+    pub async fn broadcast_tx_bytes(&self, tx_bytes: &[u8]) -> Result<String, Error> {
+        let hash = cosmos_tx_hash(tx_bytes);
+        let mut chain = self.rpc().lock();
+        if chain.dropped_broadcasts > 0 {
+            chain.dropped_broadcasts -= 1;
+        } else if !chain.txs.contains_key(&hash) {
+            chain.include(hash.clone(), Ok(()));
+        }
+        Ok(hash)
+    }
+}
+
+fn mock_tx_hash(action: &str, args: &[&str], height: u64) -> String {
+    cosmos_tx_hash(format!("{}:{}:{}", action, args.join(":"), height).as_bytes())
+}
+
+fn into_result(tx: MockTx) -> Result<MockTx, Error> {
+    if tx.is_ok() {
+        Ok(tx)
+    } else {
+        Err(Error::Tx(format!(
+            "Transaction {} failed with code {}: {}",
+            tx.hash, tx.code, tx.log
+        )))
+    }
+}
+
+/// Executes contract calls on the mock chain as one sender
+///
+/// All calls of a transaction succeed or none does, as on a node. Failures
+/// are included and returned as [`Error::Tx`].
+#[derive(Debug, Clone)]
+pub struct MockSigner {
+    rpc: MockRpc,
+    sender: String,
+}
+
+#[async_trait]
+impl ContractExecutor for MockSigner {
+    async fn execute_contracts(&self, calls: Vec<ContractCall>) -> Result<TxResponse, Error> {
+        let contracts: Vec<&str> = calls.iter().map(|call| call.contract.as_str()).collect();
+        let hash = mock_tx_hash(
+            "execute",
+            &[&[self.sender.as_str()], contracts.as_slice()].concat(),
+            self.rpc.height(),
+        );
+        let mut chain = self.rpc.lock();
+        let (result, events) = match chain.execute(&self.sender, &calls) {
+            Ok(events) => (Ok(()), events),
+            Err(e) => (Err(e), Vec::new()),
+        };
+        let tx = into_result(chain.include(hash, result))?;
+        Ok(TxResponse {
+            height: tx.height as i64,
+            txhash: tx.hash,
+            events,
+            ..TxResponse::default()
+        })
+    }
+}
+
+#[async_trait]
+impl TxBroadcaster for MockMantraClient {
+    fn vm(&self) -> TxVm {
+        TxVm::Cosmos
+    }
+
+    fn chain_id(&self) -> String {
+        self.rpc().chain_id()
+    }
+
+    async fn lookup(&self, hash: &str) -> Result<TxLookup, Error> {
+        Ok(match self.rpc().transaction(hash) {
+            None => TxLookup::NotFound,
+            Some(tx) if tx.is_ok() => TxLookup::Confirmed {
+                height: tx.height,
+                block_hash: None,
+            },
+            Some(tx) => TxLookup::Failed { reason: tx.log },
+        })
+    }
+
+    async fn rebroadcast(&self, raw_tx: &[u8]) -> Result<(), Error> {
+        self.broadcast_tx_bytes(raw_tx).await.map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures::{self, ALICE, OM_USDC_POOL};
+
+    #[tokio::test]
+    async fn test_swap_matches_simulation_and_moves_reserves() {
+        let client = MockMantraClient::new(fixtures::mock_chain());
+        let offer = Coin::new(1_000_000_000u128, "uom");
+
+        // 1,000 OM into 1,000,000 OM / 250,000 USDC: 249.75 USDC before fees
+        let simulation = client
+            .simulate_swap(OM_USDC_POOL, offer.clone(), "uusdc")
+            .await
+            .unwrap();
+        assert_eq!(simulation.swap_fee_amount.u128(), 499_500);
+        assert_eq!(simulation.protocol_fee_amount.u128(), 249_750);
+        assert_eq!(simulation.return_amount.u128(), 249_000_999);
+        assert_eq!(simulation.slippage_amount.u128(), 249_751);
+
+        let err = client
+            .swap(
+                ALICE,
+                OM_USDC_POOL,
+                offer.clone(),
+                "uusdc",
+                Some(Uint128::new(250_000_000)),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("minimum receive"), "{}", err);
+
+        let tx = client
+            .swap(
+                ALICE,
+                OM_USDC_POOL,
+                offer,
+                "uusdc",
+                Some(simulation.return_amount),
+            )
+            .await
+            .unwrap();
+        assert_eq!(tx.height, 3);
+        let balances = client.get_balances(ALICE).await.unwrap();
+        assert!(balances.contains(&Coin::new(1_249_000_999u128, "uusdc")));
+        assert!(balances.contains(&Coin::new(9_000_000_000u128, "uom")));
+
+        // The swap fee stays in the pool, the protocol fee leaves it
+        let pool = client.get_pool(OM_USDC_POOL).await.unwrap();
+        assert_eq!(pool.pool_info.assets[0].amount.u128(), 1_001_000_000_000);
+        assert_eq!(pool.pool_info.assets[1].amount.u128(), 249_750_749_251);
+
+        let stable = client
+            .simulate_swap(fixtures::USDC_USDT_POOL, Coin::new(1u128, "uusdc"), "uusdt")
+            .await
+            .unwrap_err();
+        assert!(
+            stable.to_string().contains("MockRpc::respond"),
+            "{}",
+            stable
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Correlation IDs for the SDK calls made on behalf of one request
+//!
+//! One agent request can fan out into many SDK calls, each making RPC
+//! requests and retries of its own. A caller runs the calls of one request
+//! inside [`TraceContext::scope`]: every RPC request and retry made within the
+//! scope is recorded against the context's correlation ID, and the scope's
+//! `tracing` span puts the ID on every log line. Outside a scope nothing is
+//! recorded, so clients record unconditionally.
+
+use serde::Serialize;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tracing::Instrument;
+use web_time::Instant;
+
+tokio::task_local! {
+    static TRACE: TraceContext;
+}
+
+/// Events kept per trace; later ones are counted but not kept
+pub const MAX_TRACE_EVENTS: usize = 256;
+
+/// What a trace event records
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceEventKind {
+    /// A request to a Cosmos or EVM RPC endpoint
+    Rpc,
+    /// A failed attempt that is about to be retried
+    Retry,
+}
+
+/// One step of a traced request
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceEvent {
+    pub kind: TraceEventKind,
+    /// RPC method or retried operation
+    pub name: String,
+    /// Endpoint the request went to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint: Option<String>,
+    /// Time from the start of the trace to the start of the event
+    pub offset_ms: u64,
+    pub duration_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Events {
+    kept: Vec<TraceEvent>,
+    dropped: usize,
+}
+
+/// Correlation ID of one request and the events recorded under it
+#[derive(Debug, Clone)]
+pub struct TraceContext {
+    correlation_id: String,
+    started: Instant,
+    events: Arc<Mutex<Events>>,
+}
+
+impl TraceContext {
+    /// Context with a fresh correlation ID
+    pub fn new() -> Self {
+        Self::with_correlation_id(new_correlation_id())
+    }
+
+    /// Context continuing a correlation ID chosen by the caller
+    pub fn with_correlation_id(correlation_id: impl Into<String>) -> Self {
+        Self {
+            correlation_id: correlation_id.into(),
+            started: Instant::now(),
+            events: Arc::new(Mutex::new(Events::default())),
+        }
+    }
+
+    pub fn correlation_id(&self) -> &str {
+        &self.correlation_id
+    }
+
+    /// Run `future` with its SDK calls traced under this context
+    pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+        let span = tracing::info_span!("trace", correlation_id = %self.correlation_id);
+        TRACE.scope(self.clone(), future.instrument(span)).await
+    }
+
+    /// Events recorded so far, oldest first
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.lock().kept.clone()
+    }
+
+    /// Events that did not fit in [`MAX_TRACE_EVENTS`]
+    pub fn dropped_events(&self) -> usize {
+        self.lock().dropped
+    }
+
+    fn record(&self, event: TraceEvent) {
+        let mut events = self.lock();
+        if events.kept.len() < MAX_TRACE_EVENTS {
+            events.kept.push(event);
+        } else {
+            events.dropped += 1;
+        }
+    }
+
+    fn offset_ms(&self, at: Instant) -> u64 {
+        at.saturating_duration_since(self.started).as_millis() as u64
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Events> {
+        self.events
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for TraceContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Random 16-digit hexadecimal correlation ID
+pub fn new_correlation_id() -> String {
+    format!("{:016x}", rand::random::<u64>())
+}
+
+/// Correlation ID of the request being traced, if any
+pub fn correlation_id() -> Option<String> {
+    TRACE.try_with(|trace| trace.correlation_id.clone()).ok()
+}
+
+/// Record an RPC request to `endpoint` that started at `started` and has just
+/// finished
+pub fn record_rpc(method: &str, endpoint: &str, started: Instant, error: Option<String>) {
+    let _ = TRACE.try_with(|trace| {
+        trace.record(TraceEvent {
+            kind: TraceEventKind::Rpc,
+            name: method.to_string(),
+            endpoint: Some(endpoint.to_string()),
+            offset_ms: trace.offset_ms(started),
+            duration_ms: started.elapsed().as_millis() as u64,
+            error,
+        })
+    });
+}
+
+/// Record a failed attempt of `operation` that is about to be retried
+pub fn record_retry(operation: &str, error: String) {
+    let _ = TRACE.try_with(|trace| {
+        trace.record(TraceEvent {
+            kind: TraceEventKind::Retry,
+            name: operation.to_string(),
+            endpoint: None,
+            offset_ms: trace.offset_ms(Instant::now()),
+            duration_ms: 0,
+            error: Some(error),
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_events_are_recorded_only_inside_a_scope() {
+        // Outside a scope nothing is recorded
+        record_rpc("latest_block", "https://rpc", Instant::now(), None);
+        assert!(correlation_id().is_none());
+
+        let trace = TraceContext::with_correlation_id("abc123");
+        let seen = trace
+            .scope(async {
+                let started = Instant::now();
+                record_retry("EVM call", "timed out".to_string());
+                record_rpc(
+                    "eth_call",
+                    "https://evm",
+                    started,
+                    Some("timed out".to_string()),
+                );
+                // Spawned tasks leave the scope unless they are scoped themselves
+                let inner = tokio::spawn(async { correlation_id() }).await.unwrap();
+                (correlation_id(), inner)
+            })
+            .await;
+        assert_eq!(seen, (Some("abc123".to_string()), None));
+
+        let events = trace.events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, TraceEventKind::Retry);
+        assert_eq!(events[1].name, "eth_call");
+        assert_eq!(events[1].endpoint.as_deref(), Some("https://evm"));
+        assert_eq!(trace.dropped_events(), 0);
+
+        assert_eq!(new_correlation_id().len(), 16);
+        assert_ne!(new_correlation_id(), new_correlation_id());
+    }
+}
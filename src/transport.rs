@@ -0,0 +1,460 @@
+//! ABCI query transport for read-only SDK usage
+//!
+//! Read-only queries only need one RPC call: Tendermint's `abci_query`. The
+//! [`AbciTransport`] trait abstracts that call so the DEX, Skip and ClaimDrop
+//! clients run their queries over whatever HTTP stack the target has:
+//! natively over the `cosmrs` RPC client, and in the browser (`wasm` feature
+//! on `wasm32-unknown-unknown`) over the Fetch API with [`FetchTransport`],
+//! which needs neither tokio nor native TLS. Both speak the same JSON-RPC,
+//! encoded and decoded by [`abci_query_request`] and
+//! [`parse_abci_query_response`]; [`smart_query`] and [`proto_query`] encode
+//! the queries themselves.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose, Engine};
+use cosmos_sdk_proto::cosmwasm::wasm::v1::{
+    QueryContractInfoRequest, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
+use prost::Message;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::Error;
+
+/// ABCI path of CosmWasm smart contract queries
+pub const SMART_QUERY_PATH: &str = "/cosmwasm.wasm.v1.Query/SmartContractState";
+
+/// ABCI path of CosmWasm contract info queries
+pub const CONTRACT_INFO_PATH: &str = "/cosmwasm.wasm.v1.Query/ContractInfo";
+
+/// Result of an ABCI query
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbciResponse {
+    /// 0 on success
+    pub code: u32,
+    pub log: String,
+    /// Protobuf-encoded response
+    pub value: Vec<u8>,
+    /// Block height the node answered at
+    pub height: u64,
+}
+
+impl AbciResponse {
+    pub fn is_ok(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// Sends ABCI queries to a node
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait AbciTransport {
+    /// Run the query at gRPC `path` with the protobuf-encoded `data`, as of
+    /// block `height` or the latest block
+    async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<AbciResponse, Error>;
+}
+
+/// Transport shared by the clients of one network
+pub type SharedTransport = Arc<dyn AbciTransport + Send + Sync>;
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T: AbciTransport + Send + Sync + ?Sized> AbciTransport for Arc<T> {
+    async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<AbciResponse, Error> {
+        (**self).abci_query(path, data, height).await
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl AbciTransport for cosmrs::rpc::HttpClient {
+    async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<AbciResponse, Error> {
+        use cosmrs::rpc::Client as RpcClient;
+        use cosmrs::tendermint::block::Height;
+
+        let height = height
+            .map(Height::try_from)
+            .transpose()
+            .map_err(|e| Error::Other(format!("Invalid block height: {}", e)))?;
+        let response = RpcClient::abci_query(self, Some(path.to_string()), data, height, false)
+            .await
+            .map_err(|e| Error::Rpc(format!("ABCI query failed: {}", e)))?;
+        Ok(AbciResponse {
+            code: response.code.value(),
+            log: response.log,
+            value: response.value,
+            height: response.height.value(),
+        })
+    }
+}
+
+/// Run a smart query against `contract_addr` over `transport`
+pub async fn smart_query<T, Q, R>(
+    transport: &T,
+    contract_addr: &str,
+    query_msg: &Q,
+    height: Option<u64>,
+) -> Result<R, Error>
+where
+    T: AbciTransport + ?Sized,
+    Q: Serialize + ?Sized,
+    R: DeserializeOwned,
+{
+    let query = QuerySmartContractStateRequest {
+        address: contract_addr.to_string(),
+        query_data: serde_json::to_vec(query_msg)?,
+    };
+    let result = transport
+        .abci_query(SMART_QUERY_PATH, query.encode_to_vec(), height)
+        .await?;
+    if !result.is_ok() {
+        return Err(Error::Contract(format!(
+            "Contract query failed: {}",
+            result.log
+        )));
+    }
+    let response = QuerySmartContractStateResponse::decode(result.value.as_slice())
+        .map_err(|e| Error::Rpc(format!("Failed to decode query response: {}", e)))?;
+    serde_json::from_slice(&response.data).map_err(Into::into)
+}
+
+/// Run the protobuf query at gRPC `path` over `transport`
+///
+/// Nodes answer unknown accounts and the like with a failing code, reported
+/// as an [`Error::Rpc`] carrying the node's log.
+pub async fn proto_query<T, Req, Resp>(
+    transport: &T,
+    path: &str,
+    request: &Req,
+    height: Option<u64>,
+) -> Result<Resp, Error>
+where
+    T: AbciTransport + ?Sized,
+    Req: Message,
+    Resp: Message + Default,
+{
+    let result = transport
+        .abci_query(path, request.encode_to_vec(), height)
+        .await?;
+    if !result.is_ok() {
+        return Err(Error::Rpc(format!("Query {} failed: {}", path, result.log)));
+    }
+    Resp::decode(result.value.as_slice())
+        .map_err(|e| Error::Rpc(format!("Failed to decode {} response: {}", path, e)))
+}
+
+/// Latest block height, as reported by a query of `contract_addr`'s contract
+/// info
+///
+/// Nodes answer ABCI queries at their latest committed height, so this works
+/// over any transport, not only those with Tendermint's `block` endpoint.
+pub async fn latest_height<T>(transport: &T, contract_addr: &str) -> Result<u64, Error>
+where
+    T: AbciTransport + ?Sized,
+{
+    let request = QueryContractInfoRequest {
+        address: contract_addr.to_string(),
+    };
+    let response = transport
+        .abci_query(CONTRACT_INFO_PATH, request.encode_to_vec(), None)
+        .await?;
+    if !response.is_ok() || response.height == 0 {
+        return Err(Error::Rpc(format!(
+            "Failed to get latest block height: {}",
+            response.log
+        )));
+    }
+    Ok(response.height)
+}
+
+/// JSON-RPC request running an ABCI query at `height`, or the latest height
+pub fn abci_query_request(id: u64, path: &str, data: &[u8], height: Option<u64>) -> Value {
+    let mut request = json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "abci_query",
+        "params": {
+            "path": path,
+            "data": hex::encode(data),
+            "prove": false,
+        },
+    });
+    if let Some(height) = height {
+        // Tendermint takes 64-bit integers as strings
+        request["params"]["height"] = Value::String(height.to_string());
+    }
+    request
+}
+
+/// ABCI query result of a JSON-RPC response
+pub fn parse_abci_query_response(response: &Value) -> Result<AbciResponse, Error> {
+    if let Some(error) = response.get("error") {
+        let message = error.get("message").and_then(Value::as_str).unwrap_or("");
+        let data = error.get("data").and_then(Value::as_str).unwrap_or("");
+        return Err(Error::Rpc(format!(
+            "ABCI query failed: {} {}",
+            message, data
+        )));
+    }
+    let result = response
+        .get("result")
+        .and_then(|result| result.get("response"))
+        .ok_or_else(|| Error::Rpc("ABCI query response has no result".to_string()))?;
+
+    // Tendermint encodes integers as JSON numbers and 64-bit ones as strings
+    let code = match result.get("code") {
+        Some(Value::Number(code)) => code.as_u64().unwrap_or_default() as u32,
+        Some(Value::String(code)) => code.parse().unwrap_or_default(),
+        _ => 0,
+    };
+    let value = match result.get("value").and_then(Value::as_str) {
+        Some(value) => general_purpose::STANDARD
+            .decode(value)
+            .map_err(|e| Error::Rpc(format!("Invalid ABCI query value: {}", e)))?,
+        None => Vec::new(),
+    };
+    let height = match result.get("height") {
+        Some(Value::Number(height)) => height.as_u64().unwrap_or_default(),
+        Some(Value::String(height)) => height.parse().unwrap_or_default(),
+        _ => 0,
+    };
+    Ok(AbciResponse {
+        code,
+        log: result
+            .get("log")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        value,
+        height,
+    })
+}
+
+/// ABCI transport over the browser's Fetch API
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct FetchTransport {
+    rpc_url: String,
+    next_id: std::sync::atomic::AtomicU64,
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl FetchTransport {
+    /// Transport to the Tendermint RPC endpoint at `rpc_url`, which must allow
+    /// cross-origin requests
+    pub fn new(rpc_url: impl Into<String>) -> Self {
+        Self {
+            rpc_url: rpc_url.into(),
+            next_id: std::sync::atomic::AtomicU64::new(1),
+        }
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    async fn post(&self, body: &Value) -> Result<Value, Error> {
+        use wasm_bindgen::{JsCast, JsValue};
+        use wasm_bindgen_futures::JsFuture;
+        use web_sys::{Request, RequestInit, RequestMode, Response};
+
+        let js_error =
+            |e: JsValue| Error::Network(format!("Fetch from {} failed: {:?}", self.rpc_url, e));
+
+        let init = RequestInit::new();
+        init.set_method("POST");
+        init.set_mode(RequestMode::Cors);
+        init.set_body(&JsValue::from_str(&body.to_string()));
+        let request = Request::new_with_str_and_init(&self.rpc_url, &init).map_err(js_error)?;
+        request
+            .headers()
+            .set("Content-Type", "application/json")
+            .map_err(js_error)?;
+
+        // Pages fetch through the window, web workers through their own scope
+        let global = js_sys::global();
+        let promise = if let Some(window) = global.dyn_ref::<web_sys::Window>() {
+            window.fetch_with_request(&request)
+        } else if let Some(worker) = global.dyn_ref::<web_sys::WorkerGlobalScope>() {
+            worker.fetch_with_request(&request)
+        } else {
+            return Err(Error::Network(
+                "The Fetch API is not available in this environment".to_string(),
+            ));
+        };
+
+        let response: Response = JsFuture::from(promise)
+            .await
+            .map_err(js_error)?
+            .dyn_into()
+            .map_err(js_error)?;
+        if !response.ok() {
+            return Err(Error::Rpc(format!(
+                "HTTP error {} from {}",
+                response.status(),
+                self.rpc_url
+            )));
+        }
+        let text = JsFuture::from(response.text().map_err(js_error)?)
+            .await
+            .map_err(js_error)?
+            .as_string()
+            .ok_or_else(|| Error::Rpc("RPC response is not text".to_string()))?;
+        serde_json::from_str(&text).map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[async_trait(?Send)]
+impl AbciTransport for FetchTransport {
+    async fn abci_query(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<AbciResponse, Error> {
+        let id = self
+            .next_id
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let response = self
+            .post(&abci_query_request(id, path, &data, height))
+            .await?;
+        parse_abci_query_response(&response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Answers every smart query with one canned contract response
+    struct CannedTransport {
+        response: Value,
+        queries: Mutex<Vec<(String, Value, Option<u64>)>>,
+    }
+
+    impl CannedTransport {
+        fn new(response: Value) -> Self {
+            Self {
+                response,
+                queries: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl AbciTransport for CannedTransport {
+        async fn abci_query(
+            &self,
+            path: &str,
+            data: Vec<u8>,
+            height: Option<u64>,
+        ) -> Result<AbciResponse, Error> {
+            if path == CONTRACT_INFO_PATH {
+                return Ok(AbciResponse {
+                    height: 120,
+                    ..AbciResponse::default()
+                });
+            }
+            assert_eq!(path, SMART_QUERY_PATH);
+            let request = QuerySmartContractStateRequest::decode(data.as_slice()).unwrap();
+            self.queries.lock().unwrap().push((
+                request.address,
+                serde_json::from_slice(&request.query_data).unwrap(),
+                height,
+            ));
+            if self.response.is_null() {
+                return Ok(AbciResponse {
+                    code: 5,
+                    log: "pool not found".to_string(),
+                    ..AbciResponse::default()
+                });
+            }
+            Ok(AbciResponse {
+                value: QuerySmartContractStateResponse {
+                    data: serde_json::to_vec(&self.response).unwrap(),
+                }
+                .encode_to_vec(),
+                ..AbciResponse::default()
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_smart_queries_run_over_any_transport() {
+        let transport = CannedTransport::new(json!({ "return_amount": "990" }));
+        let query = json!({ "simulation": { "pool_identifier": "o.uom.uusdc" } });
+        let simulation: Value = smart_query(&transport, "mantra1poolmanager", &query, Some(42))
+            .await
+            .unwrap();
+        assert_eq!(simulation["return_amount"], "990");
+        assert_eq!(
+            transport.queries.lock().unwrap()[0],
+            ("mantra1poolmanager".to_string(), query, Some(42))
+        );
+        assert_eq!(
+            latest_height(&transport, "mantra1poolmanager")
+                .await
+                .unwrap(),
+            120
+        );
+
+        let failing = CannedTransport::new(Value::Null);
+        let err = smart_query::<_, _, Value>(&failing, "mantra1poolmanager", &json!({}), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Contract(_)));
+        assert!(err.to_string().contains("pool not found"), "{}", err);
+    }
+
+    #[test]
+    fn test_abci_query_json_rpc_round_trip() {
+        let request = abci_query_request(7, SMART_QUERY_PATH, &[1, 2], None);
+        assert_eq!(request["method"], "abci_query");
+        assert_eq!(request["params"]["data"], "0102");
+        assert!(request["params"].get("height").is_none());
+        let request = abci_query_request(8, SMART_QUERY_PATH, &[1, 2], Some(120));
+        assert_eq!(request["params"]["height"], "120");
+
+        let response = json!({
+            "jsonrpc": "2.0",
+            "id": 7,
+            "result": { "response": { "code": 0, "log": "", "value": "AQI=", "height": "120" } }
+        });
+        let parsed = parse_abci_query_response(&response).unwrap();
+        assert!(parsed.is_ok());
+        assert_eq!(parsed.value, vec![1, 2]);
+        assert_eq!(parsed.height, 120);
+
+        let failed = json!({
+            "result": { "response": { "code": 6, "log": "unknown pool", "value": null } }
+        });
+        let parsed = parse_abci_query_response(&failed).unwrap();
+        assert!(!parsed.is_ok());
+        assert_eq!(parsed.log, "unknown pool");
+
+        let error = json!({ "error": { "code": -32603, "message": "Internal error", "data": "timed out" } });
+        assert!(parse_abci_query_response(&error)
+            .unwrap_err()
+            .to_string()
+            .contains("timed out"));
+    }
+}
@@ -3,10 +3,14 @@
 //! This module manages the global application state for the TUI, including
 //! screen navigation, data caching, and state transitions.
 
+#[cfg(feature = "tui-dex")]
+use crate::config::NetworkProfile;
+#[cfg(feature = "tui-dex")]
+use crate::fanout::{FanoutReport, NetworkFanout};
 #[cfg(feature = "tui-dex")]
 use crate::tui_dex::components::modals::{ErrorType, ModalState};
 #[cfg(feature = "tui-dex")]
-use crate::tui_dex::events::Event;
+use crate::tui_dex::events::{Event, FocusDirection};
 #[cfg(feature = "tui-dex")]
 use crate::tui_dex::screens::liquidity;
 #[cfg(feature = "tui-dex")]
@@ -16,9 +20,11 @@ use crate::{Error, MantraDexClient, MantraNetworkConfig};
 #[cfg(feature = "tui-dex")]
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 #[cfg(feature = "tui-dex")]
+use cosmwasm_std::Coin;
+#[cfg(feature = "tui-dex")]
 use cosmwasm_std::Uint128;
 #[cfg(feature = "tui-dex")]
-use mantra_dex_std::pool_manager::{PoolInfoResponse, SimulationResponse};
+use mantra_dex_std::pool_manager::PoolInfoResponse;
 #[cfg(feature = "tui-dex")]
 use std::collections::HashMap;
 #[cfg(feature = "tui-dex")]
@@ -41,6 +47,11 @@ pub enum Screen {
     Rewards,
     Admin,
     Settings,
+    Portfolio,
+    History,
+    Wallets,
+    ClaimDrop,
+    Notifications,
     TransactionDetails,
 }
 
@@ -66,6 +77,11 @@ impl Screen {
             Screen::Rewards => "Rewards",
             Screen::Admin => "Admin",
             Screen::Settings => "Settings",
+            Screen::Portfolio => "Portfolio",
+            Screen::History => "History",
+            Screen::Wallets => "Wallets",
+            Screen::ClaimDrop => "ClaimDrop",
+            Screen::Notifications => "Alerts",
             Screen::TransactionDetails => "Transaction",
         }
     }
@@ -81,6 +97,11 @@ impl Screen {
             Screen::Rewards,
             Screen::Admin,
             Screen::Settings,
+            Screen::Portfolio,
+            Screen::History,
+            Screen::Wallets,
+            Screen::ClaimDrop,
+            Screen::Notifications,
         ]
     }
 }
@@ -258,7 +279,8 @@ pub struct SwapState {
     pub to_asset: Option<String>,
     pub amount: String,
     pub slippage: String,
-    pub simulation_result: Option<SimulationResponse>,
+    /// Latest simulation of the swap form
+    pub quote: Option<crate::tui_dex::screens::swap::SwapQuote>,
     pub selected_pool_id: Option<String>,
 }
 
@@ -291,7 +313,7 @@ impl Default for SwapState {
             to_asset: None,
             amount: String::new(),
             slippage: "1.0".to_string(), // Default 1% slippage
-            simulation_result: None,
+            quote: None,
             selected_pool_id: None,
         }
     }
@@ -313,6 +335,9 @@ impl Default for LiquidityState {
     }
 }
 
+/// Network profiles whose wallet balances the dashboard shows side by side
+pub const COMPARED_NETWORKS: [&str; 2] = ["mainnet", "dukong"];
+
 /// Global application state
 pub struct AppState {
     /// Current active screen
@@ -331,6 +356,8 @@ pub struct AppState {
     pub selected_pool_id: Option<u64>,
     /// User token balances cache
     pub balances: HashMap<String, String>,
+    /// Wallet balances on each of the [`COMPARED_NETWORKS`], side by side
+    pub network_balances: Option<FanoutReport<Vec<Coin>>>,
     /// Recent transaction details with full info
     pub recent_transactions: Vec<TransactionInfo>,
     /// Network block height
@@ -343,6 +370,19 @@ pub struct AppState {
     pub current_tab: usize,
     /// Cached pool information
     pub pool_cache: HashMap<String, PoolCacheEntry>,
+    /// Pool prices sampled from refreshes and swap events, for the price charts
+    pub price_history: crate::protocols::dex::price_history::PriceHistory,
+    /// Pools screen state
+    pub pools_state: crate::tui_dex::screens::pools::PoolsState,
+    /// Portfolio screen state
+    pub portfolio_state: crate::tui_dex::screens::portfolio::PortfolioState,
+    /// On-chain transaction history screen state
+    pub history_state: crate::tui_dex::screens::history::HistoryState,
+    pub claimdrop_state: crate::tui_dex::screens::claimdrop::ClaimdropState,
+    /// Notifications and alert rules
+    pub notifications_state: crate::tui_dex::screens::notifications::NotificationsState,
+    /// Wallet manager screen state
+    pub wallets_state: crate::tui_dex::screens::wallets::WalletsState,
     /// Current swap operation state
     pub swap_state: SwapState,
     /// Current liquidity operation state
@@ -371,6 +411,8 @@ pub struct AppState {
     pub wizard_state: crate::tui_dex::screens::wizard::WizardState,
     /// Asset decimals cache (denom -> decimal places)
     pub asset_decimals_cache: HashMap<String, u8>,
+    /// Labelled addresses shared with the MCP server
+    pub address_book: crate::wallet::AddressBook,
 }
 
 /// Pending operation tracking for comprehensive loading states
@@ -421,12 +463,21 @@ impl Default for AppState {
             modal_state: None,
             selected_pool_id: None,
             balances: HashMap::new(),
+            network_balances: None,
             recent_transactions: Vec::new(),
             block_height: None,
             wallet_address: None,
             should_quit: false,
             current_tab: 0,
             pool_cache: HashMap::new(),
+            price_history: crate::protocols::dex::price_history::PriceHistory::default(),
+            pools_state: crate::tui_dex::screens::pools::PoolsState::default(),
+            portfolio_state: crate::tui_dex::screens::portfolio::PortfolioState::default(),
+            history_state: crate::tui_dex::screens::history::HistoryState::default(),
+            claimdrop_state: crate::tui_dex::screens::claimdrop::ClaimdropState::default(),
+            notifications_state:
+                crate::tui_dex::screens::notifications::NotificationsState::default(),
+            wallets_state: crate::tui_dex::screens::wallets::WalletsState::default(),
             swap_state: SwapState::default(),
             liquidity_state: LiquidityState::default(),
             current_epoch: None,
@@ -447,6 +498,7 @@ impl Default for AppState {
                 wizard
             },
             asset_decimals_cache: HashMap::new(),
+            address_book: crate::wallet::AddressBook::open_default().unwrap_or_default(),
         }
     }
 }
@@ -463,18 +515,45 @@ pub struct App {
     event_sender: Option<mpsc::UnboundedSender<Event>>,
     /// Enhanced background task coordinator
     background_coordinator: Option<crate::tui_dex::utils::async_ops::BackgroundTaskCoordinator>,
+    /// Signed transactions persisted before broadcast, shared by every client
+    tx_queue: crate::tx_queue::TxQueue,
+    /// Task resending and confirming the queued transactions
+    tx_queue_monitor: Option<tokio::task::JoinHandle<()>>,
 }
 
 impl App {
     /// Create a new application instance
     pub fn new(client: MantraDexClient, config: MantraNetworkConfig) -> Self {
-        Self {
+        let tx_queue = crate::tx_queue::TxQueue::open(crate::tx_queue::TxQueue::default_path())
+            .unwrap_or_else(|e| {
+                crate::tui_dex::utils::logger::log_warning(&format!(
+                    "Failed to open the transaction queue, keeping it in memory: {}",
+                    e
+                ));
+                crate::tx_queue::TxQueue::in_memory()
+            });
+        let mut app = Self {
             state: AppState::default(),
-            client: Arc::new(client),
+            client: Arc::new(client.with_tx_queue(tx_queue.clone())),
             config,
             event_sender: None,
             background_coordinator: None,
-        }
+            tx_queue,
+            tx_queue_monitor: None,
+        };
+        app.state.settings_state =
+            crate::tui_dex::screens::settings::SettingsState::new(app.settings_config());
+        app.apply_preferences();
+        // Warn when the wallet runs low on the gas token
+        app.state.notifications_state.add_rule(
+            crate::tui_dex::screens::notifications::AlertRule::new(
+                app.config.native_denom.clone(),
+                crate::tui_dex::screens::notifications::AlertCondition::BalanceBelow(
+                    crate::tui_dex::screens::notifications::DEFAULT_LOW_BALANCE,
+                ),
+            ),
+        );
+        app
     }
 
     /// Initialize background tasks for data synchronization with enhanced coordination
@@ -495,6 +574,17 @@ impl App {
         // Start background coordination
         coordinator.start();
 
+        // Pick up transactions a previous run left unconfirmed and keep
+        // watching new ones
+        let sender = event_sender.clone();
+        self.tx_queue_monitor = Some(self.tx_queue.spawn_monitor(
+            self.client.clone(),
+            crate::tx_queue::TxQueuePolicy::default(),
+            move |update| {
+                let _ = sender.send(Event::TxQueueUpdate(update));
+            },
+        ));
+
         self.background_coordinator = Some(coordinator);
         self.event_sender = Some(event_sender);
     }
@@ -504,6 +594,9 @@ impl App {
         if let Some(mut coordinator) = self.background_coordinator.take() {
             coordinator.stop();
         }
+        if let Some(monitor) = self.tx_queue_monitor.take() {
+            monitor.abort();
+        }
     }
 
     /// Update sync configuration
@@ -634,6 +727,15 @@ impl App {
 
     /// Enhanced error handling with comprehensive categorization
     fn categorize_error(&self, error: &Error) -> ErrorType {
+        match error {
+            Error::InsufficientFunds { .. } => return ErrorType::InsufficientFunds,
+            Error::RpcTimeout { .. } => return ErrorType::Timeout,
+            Error::AccountSequenceMismatch { .. } | Error::TxExpired { .. } => {
+                return ErrorType::Transaction
+            }
+            Error::SlippageExceeded { .. } | Error::EvmRevert { .. } => return ErrorType::Contract,
+            _ => {}
+        }
         let error_string = error.to_string().to_lowercase();
 
         if error_string.contains("connection")
@@ -657,7 +759,8 @@ impl App {
     /// Format insufficient funds error in a user-friendly way
     fn format_insufficient_funds_error(&self, error: &str) -> String {
         // Simple parsing to extract token symbols from insufficient funds error
-        if error.contains("insufficient funds") || error.contains("spendable balance") {
+        let lower = error.to_lowercase();
+        if lower.contains("insufficient funds") || lower.contains("spendable balance") {
             // Look for specific token denominations in the error message
             if error
                 .contains("ibc/8E27BA2D5493AF5636760E354E46004562C46AB7EC0CC4C1CA14E9E20E2545B5")
@@ -733,6 +836,94 @@ impl App {
             }
         }
 
+        // Record the execution price of swaps as they are committed, and
+        // refetch the portfolio when the wallet itself traded
+        if let Event::PoolActivity(chain_event) = &event {
+            if chain_event.account().is_some()
+                && chain_event.account() == self.state.wallet_address.as_deref()
+                && self.state.portfolio_state.portfolio.is_some()
+            {
+                self.request_portfolio_refresh();
+            }
+            if let crate::events::ContractEvent::Swap {
+                pool_identifier: Some(pool_id),
+                ..
+            } = &chain_event.detail
+            {
+                if let Some(entry) = self.state.pool_cache.get(pool_id) {
+                    self.state.price_history.record_swap(
+                        &entry.pool_info,
+                        chain_event,
+                        chrono::Utc::now(),
+                    );
+                }
+            }
+            return Ok(false);
+        }
+
+        // Apply swap simulations unless the form changed since they started
+        if let Event::SwapQuoteReady { seq, quote } = &event {
+            let swap_state = crate::tui_dex::screens::swap::get_swap_screen_state();
+            if *seq == swap_state.simulation_seq {
+                swap_state.simulation_pending = false;
+                match quote {
+                    Ok(quote) => self.state.swap_state.quote = Some(quote.clone()),
+                    Err(e) => {
+                        self.state.swap_state.quote = None;
+                        self.set_error(format!("Swap simulation failed: {}", e));
+                    }
+                }
+            }
+            return Ok(false);
+        }
+
+        // Report queued transactions that landed, failed or got stuck
+        if let Event::TxQueueUpdate(update) = &event {
+            self.handle_tx_queue_update(update);
+            return Ok(false);
+        }
+
+        // Apply liquidity form simulations unless the form changed since they started
+        if let Event::LiquidityPreviewReady { seq, preview } = &event {
+            let liquidity_state = liquidity::get_liquidity_screen_state();
+            if *seq == liquidity_state.simulation_seq {
+                match preview {
+                    Ok(preview) => liquidity_state.apply_preview(Some(preview.clone())),
+                    Err(e) => {
+                        liquidity_state.apply_preview(None);
+                        liquidity_state.simulation_note = Some(format!("Simulation failed: {}", e));
+                    }
+                }
+            }
+            return Ok(false);
+        }
+
+        // Handle cross-chain transfer tracking events
+        if let Event::TrackTransfer { tx_hash, chain_id } = &event {
+            self.start_transfer_tracking(tx_hash.clone(), chain_id.clone());
+            return Ok(false);
+        }
+        if let Event::TransferStatusUpdate {
+            tx_hash,
+            status,
+            dest_tx_hash,
+            detail,
+            finished,
+        } = &event
+        {
+            self.state.transaction_state.apply_transfer_update(
+                tx_hash,
+                status,
+                dest_tx_hash.clone(),
+                detail.clone(),
+                *finished,
+            );
+            if *finished {
+                self.set_status(format!("Transfer {} finished: {}", tx_hash, status));
+            }
+            return Ok(false);
+        }
+
         // Handle blockchain progress events
         if let Event::BlockchainProgress {
             operation,
@@ -774,6 +965,16 @@ impl App {
                     let details = self.create_basic_success_details(result, transaction_hash);
                     (title, details)
                 }
+                "zap_in" => {
+                    let title = "Zap Completed Successfully!".to_string();
+                    let details = self.create_basic_success_details(result, transaction_hash);
+                    (title, details)
+                }
+                "zap_out" => {
+                    let title = "Zap Withdrawal Completed Successfully!".to_string();
+                    let details = self.create_basic_success_details(result, transaction_hash);
+                    (title, details)
+                }
                 "execute_swap" => {
                     let title = "Swap Completed Successfully!".to_string();
                     let details = self.create_basic_success_details(result, transaction_hash);
@@ -784,6 +985,18 @@ impl App {
                     let details = self.create_basic_success_details(result, transaction_hash);
                     (title, details)
                 }
+                "claimdrop_claim" => {
+                    let title = "ClaimDrop Rewards Claimed Successfully!".to_string();
+                    let details = self.create_basic_success_details(result, transaction_hash);
+                    self.request_claimdrop_refresh();
+                    (title, details)
+                }
+                "faucet_request" => {
+                    let title = "Faucet Funds Requested".to_string();
+                    let details = self.create_basic_success_details(result, transaction_hash);
+                    self.request_wallets_refresh();
+                    (title, details)
+                }
                 "create_pool" => {
                     let title = "Pool Created Successfully!".to_string();
                     let details =
@@ -804,6 +1017,14 @@ impl App {
                 }
             };
 
+            self.state.notifications_state.push(
+                crate::tui_dex::screens::notifications::NotificationKind::TxConfirmed,
+                success_title.clone(),
+                transaction_hash
+                    .as_ref()
+                    .map(|hash| format!("{} ({})", result, hash))
+                    .unwrap_or_else(|| result.clone()),
+            );
             self.state.modal_state = Some(
                 crate::tui_dex::components::modals::ModalState::transaction_details(
                     transaction_hash.clone().unwrap_or_default(),
@@ -856,6 +1077,11 @@ impl App {
                 _ => &format!("{} Failed", operation.replace('_', " ")),
             };
 
+            self.state.notifications_state.push(
+                crate::tui_dex::screens::notifications::NotificationKind::TxFailed,
+                operation_title.to_string(),
+                user_friendly_error.clone(),
+            );
             self.state.modal_state = Some(crate::tui_dex::components::modals::ModalState::error(
                 operation_title.to_string(),
                 user_friendly_error.clone(),
@@ -1063,6 +1289,97 @@ impl App {
 
                 return Ok(false);
             }
+            Event::ZapIn {
+                pool_id,
+                asset_denom,
+                amount,
+                slippage_tolerance,
+            } => {
+                crate::tui_dex::utils::logger::log_info(&format!(
+                    "Zap in: pool {}, {} {}, slippage {:?}",
+                    pool_id, amount, asset_denom, slippage_tolerance
+                ));
+
+                self.set_loading_with_progress(
+                    format!("Zapping {} {} into pool {}", amount, asset_denom, pool_id),
+                    Some(5.0),
+                    true,
+                );
+
+                if let Some(event_sender) = &self.event_sender {
+                    let blockchain_processor =
+                        crate::tui_dex::events::AsyncBlockchainProcessor::with_client(
+                            event_sender.clone(),
+                            self.client.clone(),
+                        );
+
+                    let pool_id_clone = pool_id.clone();
+                    let asset_denom_clone = asset_denom.clone();
+                    let amount_clone = amount.clone();
+                    let slippage_clone = slippage_tolerance.clone();
+
+                    tokio::spawn(async move {
+                        blockchain_processor
+                            .zap_in(
+                                pool_id_clone,
+                                asset_denom_clone,
+                                amount_clone,
+                                slippage_clone,
+                            )
+                            .await;
+                    });
+                } else {
+                    crate::tui_dex::utils::logger::log_error(
+                        "No event sender available for async blockchain operation",
+                    );
+                    self.set_error("Failed to initiate zap: no event sender".to_string());
+                }
+
+                return Ok(false);
+            }
+            Event::ZapOut {
+                pool_id,
+                lp_token_amount,
+                target_denom,
+                slippage_tolerance,
+            } => {
+                crate::tui_dex::utils::logger::log_info(&format!(
+                    "Zap out: pool {}, LP amount {}, target {}, slippage {:?}",
+                    pool_id, lp_token_amount, target_denom, slippage_tolerance
+                ));
+
+                self.set_loading_with_progress(
+                    format!("Zapping out of pool {} into {}", pool_id, target_denom),
+                    Some(5.0),
+                    true,
+                );
+
+                if let Some(event_sender) = &self.event_sender {
+                    let blockchain_processor =
+                        crate::tui_dex::events::AsyncBlockchainProcessor::with_client(
+                            event_sender.clone(),
+                            self.client.clone(),
+                        );
+
+                    let pool_id_clone = pool_id.clone();
+                    let lp_amount_clone = lp_token_amount.clone();
+                    let target_clone = target_denom.clone();
+                    let slippage_clone = slippage_tolerance.clone();
+
+                    tokio::spawn(async move {
+                        blockchain_processor
+                            .zap_out(pool_id_clone, lp_amount_clone, target_clone, slippage_clone)
+                            .await;
+                    });
+                } else {
+                    crate::tui_dex::utils::logger::log_error(
+                        "No event sender available for async blockchain operation",
+                    );
+                    self.set_error("Failed to initiate zap: no event sender".to_string());
+                }
+
+                return Ok(false);
+            }
             Event::ClaimRewards {
                 pool_id,
                 epochs,
@@ -1352,6 +1669,10 @@ impl App {
                             swap_state.input_focus =
                                 crate::tui_dex::screens::swap::SwapInputFocus::Slippage
                         }
+                        "swap_min_receive" => {
+                            swap_state.input_focus =
+                                crate::tui_dex::screens::swap::SwapInputFocus::MinReceive
+                        }
                         _ => {}
                     },
                     FocusableComponent::Dropdown(id) => {
@@ -1428,6 +1749,11 @@ impl App {
                 // Ensure internal state knows which widget is focused so that render_* helpers style correctly
                 liquidity_state.apply_focus();
             }
+            Screen::Settings => {
+                if let FocusableComponent::TextInput(id) = focused_component {
+                    self.state.settings_state.focus_field(id);
+                }
+            }
             Screen::Admin => {
                 let admin_state = crate::tui_dex::screens::admin::get_admin_screen_state();
                 // Clear previous internal focus first
@@ -1520,6 +1846,7 @@ impl App {
                 swap_from_asset_dropdown(), // From token selection (maps to SwapInputFocus::FromToken)
                 swap_amount_input(), // From amount input (maps to SwapInputFocus::FromAmount)
                 swap_slippage_input(), // Slippage tolerance (maps to SwapInputFocus::Slippage)
+                swap_min_receive_input(), // Minimum output (maps to SwapInputFocus::MinReceive)
                 swap_execute_button(), // Execute button (maps to SwapInputFocus::Execute)
             ],
             Screen::Liquidity => {
@@ -1599,6 +1926,12 @@ impl App {
                 crate::tui_dex::events::FocusableComponent::TextInput(
                     "settings_gas_adjustment".to_string(),
                 ),
+                crate::tui_dex::events::FocusableComponent::TextInput(
+                    "settings_fee_denom".to_string(),
+                ),
+                crate::tui_dex::events::FocusableComponent::TextInput(
+                    "settings_grpc_url".to_string(),
+                ),
                 // Wallet section components
                 crate::tui_dex::events::FocusableComponent::Button(
                     "settings_wallet_import_mode".to_string(),
@@ -1620,6 +1953,9 @@ impl App {
                 crate::tui_dex::events::FocusableComponent::TextInput(
                     "settings_decimal_precision".to_string(),
                 ),
+                crate::tui_dex::events::FocusableComponent::TextInput(
+                    "settings_default_slippage".to_string(),
+                ),
                 crate::tui_dex::events::FocusableComponent::Button(
                     "settings_auto_refresh".to_string(),
                 ),
@@ -1653,6 +1989,11 @@ impl App {
                 // Refresh dashboard data
                 self.refresh_dashboard_data().await?;
             }
+            Screen::Portfolio => self.request_portfolio_refresh(),
+            Screen::History => self.request_history_refresh(),
+            Screen::Wallets => self.request_wallets_refresh(),
+            Screen::ClaimDrop => self.request_claimdrop_refresh(),
+            Screen::Notifications => self.state.notifications_state.mark_all_read(),
             Screen::Pools => {
                 // Refresh pool data
                 if let Some(sender) = &self.event_sender {
@@ -1689,6 +2030,7 @@ impl App {
                 }
                 // Update liquidity screen pool dropdown with cached pools
                 self.update_liquidity_screen_pools();
+                self.refresh_liquidity_positions();
             }
             Screen::Admin => {
                 // Initialize focus for admin screen
@@ -2082,13 +2424,773 @@ impl App {
         match self.state.current_screen {
             Screen::WalletSelection => self.handle_wallet_selection_event(event).await,
             Screen::Swap => self.handle_swap_screen_event(event).await,
+            Screen::Pools => self.handle_pools_screen_event(event),
             Screen::Liquidity => self.handle_liquidity_screen_event(event).await,
             Screen::Admin => self.handle_admin_screen_event(event).await,
             Screen::Settings => self.handle_settings_screen_event(event).await,
+            Screen::Rewards => self.handle_rewards_screen_event(event).await,
+            Screen::Portfolio => self.handle_portfolio_screen_event(event),
+            Screen::History => self.handle_history_screen_event(event),
+            Screen::ClaimDrop => self.handle_claimdrop_screen_event(event),
+            Screen::Notifications => self.handle_notifications_screen_event(event),
+            Screen::Wallets => self.handle_wallets_screen_event(event).await,
+            Screen::TransactionDetails => self.handle_transaction_screen_event(event).await,
+            _ => Ok(false),
+        }
+    }
+
+    /// Handle portfolio screen specific events. Returns `true` if the event was handled.
+    fn handle_portfolio_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+        let portfolio_state = &mut self.state.portfolio_state;
+        match event {
+            Event::MoveFocus(FocusDirection::Up) => portfolio_state.move_selection(-1),
+            Event::MoveFocus(FocusDirection::Down) => portfolio_state.move_selection(1),
+            Event::Char('s') | Event::Char('S') => portfolio_state.cycle_sort(),
+            Event::Char('h') | Event::Char('H') => portfolio_state.toggle_hide_small(),
+            Event::Char('r') | Event::Char('R') => self.request_portfolio_refresh(),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Ask for the portfolio to be refetched in the background
+    fn request_portfolio_refresh(&mut self) {
+        if self.state.wallet_address.is_none() {
+            return;
+        }
+        if let Some(sender) = &self.event_sender {
+            self.state.portfolio_state.loading = true;
+            let _ = sender.send(Event::DataRefresh {
+                data_type: "portfolio".to_string(),
+                success: true,
+                error: None,
+            });
+        }
+    }
+
+    /// Handle history screen specific events. Returns `true` if the event was handled.
+    fn handle_history_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+        let history_state = &mut self.state.history_state;
+        match event {
+            Event::MoveFocus(FocusDirection::Up) => history_state.move_selection(-1),
+            Event::MoveFocus(FocusDirection::Down) => history_state.move_selection(1),
+            Event::MoveFocus(FocusDirection::Right) | Event::Char('n') | Event::Char('N') => {
+                if history_state.entries.len()
+                    >= crate::tx_history::DEFAULT_HISTORY_PAGE_SIZE as usize
+                {
+                    history_state.page += 1;
+                    self.request_history_refresh();
+                }
+            }
+            Event::MoveFocus(FocusDirection::Left) | Event::Char('p') | Event::Char('P') => {
+                if history_state.page > 1 {
+                    history_state.page -= 1;
+                    self.request_history_refresh();
+                }
+            }
+            Event::Char('l') | Event::Char('L') => history_state.toggle_inspect(),
+            Event::Char('r') | Event::Char('R') => self.request_history_refresh(),
+            Event::Char('e') | Event::Char('E') => self.export_history_csv(),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Ask for the current history page to be refetched in the background
+    fn request_history_refresh(&mut self) {
+        if self.state.wallet_address.is_none() {
+            return;
+        }
+        if let Some(sender) = &self.event_sender {
+            self.state.history_state.loading = true;
+            let _ = sender.send(Event::DataRefresh {
+                data_type: "history".to_string(),
+                success: true,
+                error: None,
+            });
+        }
+    }
+
+    /// Write the shown history page as CSV into the working directory
+    fn export_history_csv(&mut self) {
+        let Some(address) = self.state.wallet_address.clone() else {
+            return;
+        };
+        if self.state.history_state.entries.is_empty() {
+            self.set_error("No transactions to export".to_string());
+            return;
+        }
+        match self
+            .state
+            .history_state
+            .export_csv(std::path::Path::new("."), &address)
+        {
+            Ok(path) => self.set_success(format!(
+                "Exported {} transactions to {}",
+                self.state.history_state.entries.len(),
+                path.display()
+            )),
+            Err(e) => self.set_error(format!("Failed to export history: {}", e)),
+        }
+    }
+
+    /// Handle ClaimDrop screen specific events. Returns `true` if the event was handled.
+    fn handle_claimdrop_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+        let claimdrop_state = &mut self.state.claimdrop_state;
+        match event {
+            Event::MoveFocus(FocusDirection::Up) => claimdrop_state.move_selection(-1),
+            Event::MoveFocus(FocusDirection::Down) => claimdrop_state.move_selection(1),
+            Event::Char('c') | Event::Char('C') => match claimdrop_state.selected_campaign() {
+                Some(campaign) if campaign.is_claimable() => {
+                    let address = campaign.address.clone();
+                    self.confirm_claimdrop_claim(vec![address]);
+                }
+                Some(_) => self.set_error("Nothing to claim from this campaign".to_string()),
+                None => {}
+            },
+            Event::Char('a') | Event::Char('A') => {
+                let campaigns = claimdrop_state.claimable_addresses();
+                if campaigns.is_empty() {
+                    self.set_error("Nothing to claim from any campaign".to_string());
+                } else {
+                    self.confirm_claimdrop_claim(campaigns);
+                }
+            }
+            Event::Char('r') | Event::Char('R') => self.request_claimdrop_refresh(),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Ask for the wallet's ClaimDrop campaigns to be refetched in the background
+    fn request_claimdrop_refresh(&mut self) {
+        if self.state.wallet_address.is_none() {
+            return;
+        }
+        if let Some(sender) = &self.event_sender {
+            self.state.claimdrop_state.loading = true;
+            let _ = sender.send(Event::DataRefresh {
+                data_type: "claimdrop".to_string(),
+                success: true,
+                error: None,
+            });
+        }
+    }
+
+    /// Ask for confirmation before claiming from `campaigns`
+    fn confirm_claimdrop_claim(&mut self, campaigns: Vec<String>) {
+        let claimdrop_state = &self.state.claimdrop_state;
+        let mut message = format!(
+            "Claim everything available from {} campaign(s) in one transaction:\n",
+            campaigns.len()
+        );
+        for campaign in claimdrop_state
+            .campaigns
+            .iter()
+            .filter(|campaign| campaigns.contains(&campaign.address))
+        {
+            let available: Vec<String> = campaign
+                .rewards
+                .available_to_claim
+                .iter()
+                .filter(|coin| !coin.amount.is_zero())
+                .map(|coin| format!("{} {}", coin.amount, self.denom_to_symbol(&coin.denom)))
+                .collect();
+            message.push_str(&format!("{}: {}\n", campaign.name, available.join(", ")));
+        }
+
+        self.state.claimdrop_state.pending_claim = Some(campaigns);
+        self.show_confirmation(
+            "Confirm ClaimDrop Claim".to_string(),
+            message,
+            Some("Claim (y)".to_string()),
+            Some("Cancel (n)".to_string()),
+        );
+    }
+
+    /// Claim from `campaigns` in the background, reporting through the
+    /// blockchain success and error events
+    fn execute_claimdrop_claim(&mut self, campaigns: Vec<String>) {
+        let Some(sender) = self.event_sender.clone() else {
+            self.set_error("No event sender available for the claim".to_string());
+            return;
+        };
+        self.set_loading_with_progress(
+            format!("Claiming from {} ClaimDrop campaign(s)", campaigns.len()),
+            None,
+            false,
+        );
+
+        let Some(first) = campaigns.first() else {
+            return;
+        };
+        // Any campaign works as the entry point; claim_all fans out to the others
+        let mut claimdrop = self.client.claimdrop_campaign(first.clone());
+        if let Some(wallet) = self.client.shared_wallet() {
+            claimdrop.set_wallet(wallet);
+        }
+        claimdrop.set_dex_client(self.client.clone());
+        tokio::spawn(async move {
+            let event = match claimdrop.claim_all(&campaigns).await {
+                Ok((plan, Some(result))) if result.success => {
+                    let claimed: Vec<&str> = plan
+                        .claims
+                        .iter()
+                        .map(|claim| claim.campaign_address.as_str())
+                        .collect();
+                    Event::BlockchainSuccess {
+                        operation: "claimdrop_claim".to_string(),
+                        result: format!("Claimed from {} campaign(s)", claimed.len()),
+                        transaction_hash: result.tx_hash,
+                        enhanced_data: Some(claimed.join(", ")),
+                    }
+                }
+                Ok((_, Some(result))) => Event::BlockchainError {
+                    operation: "claimdrop_claim".to_string(),
+                    error: result.message,
+                },
+                Ok((_, None)) => Event::BlockchainError {
+                    operation: "claimdrop_claim".to_string(),
+                    error: "Nothing left to claim in the selected campaigns".to_string(),
+                },
+                Err(e) => Event::BlockchainError {
+                    operation: "claimdrop_claim".to_string(),
+                    error: e.to_string(),
+                },
+            };
+            let _ = sender.send(event);
+        });
+    }
+
+    /// Handle notifications screen specific events. Returns `true` if the event was handled.
+    fn handle_notifications_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+        let notifications_state = &mut self.state.notifications_state;
+
+        // The rule form takes all typing until it is confirmed or cancelled
+        if notifications_state.editing_rule {
+            match event {
+                Event::Char(c) => notifications_state.buffer.push(c),
+                Event::ContextAction => notifications_state.buffer.push(' '),
+                Event::Paste(text) => notifications_state.buffer.push_str(&text),
+                Event::Backspace => {
+                    notifications_state.buffer.pop();
+                }
+                Event::Escape => notifications_state.cancel_rule(),
+                Event::Enter => {
+                    if let Err(e) = notifications_state.submit_rule() {
+                        self.set_error(e);
+                    }
+                }
+                _ => return Ok(false),
+            }
+            return Ok(true);
+        }
+
+        match event {
+            Event::MoveFocus(FocusDirection::Up) => notifications_state.move_selection(-1),
+            Event::MoveFocus(FocusDirection::Down) => notifications_state.move_selection(1),
+            Event::MoveFocus(FocusDirection::Left) | Event::MoveFocus(FocusDirection::Right) => {
+                notifications_state.toggle_pane()
+            }
+            Event::Delete | Event::Char('x') | Event::Char('X') => {
+                notifications_state.dismiss_selected()
+            }
+            Event::Char('c') | Event::Char('C') => notifications_state.clear(),
+            Event::Char('a') | Event::Char('A') => notifications_state.begin_rule(),
+            Event::Char('d') | Event::Char('D') => {
+                notifications_state.desktop_notifications =
+                    !notifications_state.desktop_notifications;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Notify about a queued transaction whose state changed in the background
+    fn handle_tx_queue_update(&mut self, update: &crate::tx_queue::TxQueueUpdate) {
+        use crate::tui_dex::screens::notifications::NotificationKind;
+        use crate::tx_queue::{QueuedTxStatus, TxQueueChange};
+
+        let tx = &update.tx;
+        let (kind, title, message) = match (&update.change, &tx.status) {
+            (TxQueueChange::Confirmed, QueuedTxStatus::Confirmed { height, .. }) => (
+                NotificationKind::TxConfirmed,
+                "Queued transaction confirmed".to_string(),
+                format!("{} ({}) at height {}", tx.label, tx.hash, height),
+            ),
+            (TxQueueChange::Failed, QueuedTxStatus::Failed { reason }) => (
+                NotificationKind::TxFailed,
+                "Queued transaction failed".to_string(),
+                format!("{} ({}): {}", tx.label, tx.hash, reason),
+            ),
+            (TxQueueChange::Stuck, _) => (
+                NotificationKind::TxFailed,
+                "Transaction stuck".to_string(),
+                format!(
+                    "{} ({}) is still not included after {} broadcasts{}",
+                    tx.label,
+                    tx.hash,
+                    tx.attempts,
+                    tx.last_error
+                        .as_ref()
+                        .map(|e| format!(": {}", e))
+                        .unwrap_or_default()
+                ),
+            ),
+            (TxQueueChange::Reorged, _) => (
+                NotificationKind::TxFailed,
+                "Confirmation reorganized away".to_string(),
+                format!(
+                    "{} ({}) is pending again: {}",
+                    tx.label,
+                    tx.hash,
+                    tx.last_error.as_deref().unwrap_or("its block was replaced")
+                ),
+            ),
+            _ => {
+                crate::tui_dex::utils::logger::log_info(&format!(
+                    "Resent queued transaction {} (attempt {})",
+                    tx.hash, tx.attempts
+                ));
+                return;
+            }
+        };
+        self.state.notifications_state.push(kind, title, message);
+    }
+
+    /// Check the alert rules against the latest balances
+    fn check_balance_alerts(&mut self) {
+        let prices = self.pool_prices();
+        // The gas token and most bank tokens use 6 decimals
+        self.state
+            .notifications_state
+            .check_balances(&self.state.balances, |denom| {
+                prices.decimals(denom).unwrap_or(6)
+            });
+    }
+
+    /// USD prices implied by the cached pools
+    fn pool_prices(&self) -> crate::portfolio::UsdPrices {
+        let pools: Vec<PoolInfoResponse> = self
+            .state
+            .pool_cache
+            .values()
+            .map(|entry| entry.pool_info.clone())
+            .collect();
+        crate::portfolio::UsdPrices::from_pools(&pools)
+    }
+
+    /// Handle wallets screen specific events. Returns `true` if the event was handled.
+    async fn handle_wallets_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        use crate::tui_dex::screens::wallets::WalletInput;
+
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+        let wallets_state = &mut self.state.wallets_state;
+
+        // An open form takes all typing until it is confirmed or cancelled
+        if wallets_state.input.is_some() {
+            match event {
+                Event::Char(c) => wallets_state.buffer.push(c),
+                Event::ContextAction => wallets_state.buffer.push(' '),
+                Event::Paste(text) => wallets_state.buffer.push_str(&text),
+                Event::Backspace => {
+                    wallets_state.buffer.pop();
+                }
+                Event::Escape => wallets_state.cancel(),
+                Event::Enter => {
+                    if let Some(action) = wallets_state.submit() {
+                        self.handle_wallets_action(action).await;
+                    }
+                }
+                _ => return Ok(false),
+            }
+            return Ok(true);
+        }
+
+        match event {
+            Event::MoveFocus(FocusDirection::Up) => wallets_state.move_selection(-1),
+            Event::MoveFocus(FocusDirection::Down) => wallets_state.move_selection(1),
+            Event::Enter => wallets_state.begin(WalletInput::UnlockPassword),
+            Event::Char('g') | Event::Char('G') => wallets_state.begin(WalletInput::GenerateName),
+            Event::Char('i') | Event::Char('I') => wallets_state.begin(WalletInput::ImportName),
+            Event::Char('k') | Event::Char('K') => wallets_state.begin(WalletInput::KeystoreName),
+            Event::Char('b') | Event::Char('B') => wallets_state.begin(WalletInput::Label),
+            Event::Char('r') | Event::Char('R') => self.request_wallets_refresh(),
+            Event::Char('f') | Event::Char('F') => self.execute_faucet_request(),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Fund the selected wallet from the testnet faucet in the background,
+    /// reporting through the blockchain success and error events
+    fn execute_faucet_request(&mut self) {
+        let Some(address) = self
+            .state
+            .wallets_state
+            .selected_wallet()
+            .map(|wallet| wallet.address.clone())
+        else {
+            return;
+        };
+        let Some(sender) = self.event_sender.clone() else {
+            self.set_error("No event sender available for the faucet request".to_string());
+            return;
+        };
+        self.set_loading_with_progress(
+            format!("Requesting faucet funds for {}", address),
+            None,
+            false,
+        );
+
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            let event = match client.faucet_request(&address).await {
+                Ok(receipt) => Event::BlockchainSuccess {
+                    operation: "faucet_request".to_string(),
+                    result: if receipt.funded {
+                        format!("Received {} {}", receipt.received(), receipt.denom)
+                    } else {
+                        format!(
+                            "The faucet accepted the request; the {} has not arrived yet",
+                            receipt.denom
+                        )
+                    },
+                    transaction_hash: None,
+                    enhanced_data: Some(address),
+                },
+                Err(e) => Event::BlockchainError {
+                    operation: "faucet_request".to_string(),
+                    error: e.to_string(),
+                },
+            };
+            let _ = sender.send(event);
+        });
+    }
+
+    /// Ask for the saved wallets and their balances to be reread in the background
+    fn request_wallets_refresh(&mut self) {
+        if let Some(sender) = &self.event_sender {
+            self.state.wallets_state.loading = true;
+            let _ = sender.send(Event::DataRefresh {
+                data_type: "wallets".to_string(),
+                success: true,
+                error: None,
+            });
+        }
+    }
+
+    /// Carry out a completed wallets screen form
+    async fn handle_wallets_action(
+        &mut self,
+        action: crate::tui_dex::screens::wallets::WalletsAction,
+    ) {
+        use crate::tui_dex::screens::wallets::WalletsAction;
+
+        let storage = match crate::wallet::WalletStorage::new() {
+            Ok(storage) => storage,
+            Err(e) => {
+                self.set_error(format!("Failed to initialize wallet storage: {}", e));
+                return;
+            }
+        };
+        let name_taken = |name: &str| {
+            storage
+                .list_wallets()
+                .map(|wallets| wallets.iter().any(|wallet| wallet.name == name))
+                .unwrap_or(false)
+        };
+
+        match action {
+            WalletsAction::Switch { name, password } => match storage.load_wallet(&name, &password)
+            {
+                Ok(mnemonic) => {
+                    if let Err(e) = self.switch_to_wallet(&name, &mnemonic).await {
+                        self.set_error(format!("Failed to switch to wallet '{}': {}", name, e));
+                    }
+                }
+                Err(e) => self.set_error(format!("Failed to unlock wallet '{}': {}", name, e)),
+            },
+            WalletsAction::SetLabel { name, label } => {
+                match storage.set_label(&name, Some(&label)) {
+                    Ok(()) => {
+                        self.set_success(format!("Label of wallet '{}' updated", name));
+                        self.request_wallets_refresh();
+                    }
+                    Err(e) => self.set_error(format!("Failed to label wallet '{}': {}", name, e)),
+                }
+            }
+            WalletsAction::Generate { name, password } => {
+                if name_taken(&name) {
+                    self.set_error(format!("Wallet '{}' already exists", name));
+                    return;
+                }
+                let saved =
+                    crate::wallet::MantraWallet::generate().and_then(|(wallet, mnemonic)| {
+                        storage.save_wallet(
+                            &name,
+                            &mnemonic,
+                            &password,
+                            wallet.address()?.as_ref(),
+                        )?;
+                        Ok(mnemonic)
+                    });
+                match saved {
+                    Ok(mnemonic) => {
+                        self.state.wallets_state.new_mnemonic = Some(mnemonic);
+                        self.set_success(format!(
+                            "Wallet '{}' created; back up its mnemonic before switching to it",
+                            name
+                        ));
+                        self.request_wallets_refresh();
+                    }
+                    Err(e) => self.set_error(format!("Failed to create wallet: {}", e)),
+                }
+            }
+            WalletsAction::ImportMnemonic {
+                name,
+                mnemonic,
+                password,
+            } => {
+                if name_taken(&name) {
+                    self.set_error(format!("Wallet '{}' already exists", name));
+                    return;
+                }
+                let saved =
+                    crate::wallet::MantraWallet::from_mnemonic(&mnemonic, 0).and_then(|wallet| {
+                        storage.save_wallet(&name, &mnemonic, &password, wallet.address()?.as_ref())
+                    });
+                match saved {
+                    Ok(()) => {
+                        self.set_success(format!("Wallet '{}' imported", name));
+                        self.request_wallets_refresh();
+                    }
+                    Err(e) => self.set_error(format!("Failed to import wallet: {}", e)),
+                }
+            }
+            WalletsAction::ImportKeystore { name, path } => {
+                match storage.import_wallet_file(std::path::Path::new(&path), &name) {
+                    Ok(metadata) => {
+                        self.set_success(format!(
+                            "Wallet '{}' imported for {}",
+                            name, metadata.address
+                        ));
+                        self.request_wallets_refresh();
+                    }
+                    Err(e) => self.set_error(format!("Failed to import keystore: {}", e)),
+                }
+            }
+        }
+    }
+
+    /// Make a saved wallet the client's signer and drop everything fetched
+    /// for the previous wallet
+    async fn switch_to_wallet(&mut self, name: &str, mnemonic: &str) -> Result<(), Error> {
+        let wallet = crate::wallet::MantraWallet::from_mnemonic(mnemonic, 0)?;
+        let address = wallet.address()?.to_string();
+
+        // Rebuild the client first so that a failure leaves the previous
+        // signer and address in place together
+        self.configure_client_wallet(wallet).await?;
+        self.set_wallet_address(address.clone());
+        self.state.wallets_state.active = Some(name.to_string());
+
+        self.state.balances.clear();
+        self.state.claimable_rewards.clear();
+        self.state.portfolio_state = crate::tui_dex::screens::portfolio::PortfolioState::default();
+        self.state.history_state = crate::tui_dex::screens::history::HistoryState::default();
+        self.state.claimdrop_state = crate::tui_dex::screens::claimdrop::ClaimdropState::default();
+        if let Some(sender) = &self.event_sender {
+            let _ = sender.send(Event::DataRefresh {
+                data_type: "balances".to_string(),
+                success: true,
+                error: None,
+            });
+        }
+
+        self.set_success(format!("Switched to wallet '{}' ({})", name, address));
+        Ok(())
+    }
+
+    /// Handle pools screen specific events. Returns `true` if the event was handled.
+    fn handle_pools_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        use crate::tui_dex::events::FocusDirection;
+
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+
+        let pool_count = self.state.pool_cache.len();
+        let pools_state = &mut self.state.pools_state;
+        match event {
+            Event::MoveFocus(FocusDirection::Up) => pools_state.move_selection(-1, pool_count),
+            Event::MoveFocus(FocusDirection::Down) => pools_state.move_selection(1, pool_count),
+            Event::MoveFocus(FocusDirection::Left) => pools_state.chart.move_crosshair(-1),
+            Event::MoveFocus(FocusDirection::Right) => pools_state.chart.move_crosshair(1),
+            Event::Char('t') | Event::Char('T') => pools_state.chart.cycle_timeframe(),
+            Event::Char('c') | Event::Char('C') => pools_state.chart.toggle_style(),
+            Event::Escape if pools_state.chart.crosshair.is_some() => {
+                pools_state.chart.crosshair = None;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Handle transaction screen specific events. Returns `true` if the event was handled.
+    async fn handle_transaction_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        use crate::tui_dex::screens::transaction::TransactionViewMode;
+
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+
+        // 'x' opens the cross-chain transfer tracking panel
+        if self.state.transaction_state.view_mode != TransactionViewMode::Transfers {
+            if let Event::Char('x') | Event::Char('X') = event {
+                self.state.transaction_state.view_mode = TransactionViewMode::Transfers;
+                return Ok(true);
+            }
+            return Ok(false);
+        }
+
+        match event {
+            Event::Char(c) => {
+                self.state.transaction_state.transfer_input.push(c);
+                Ok(true)
+            }
+            Event::Backspace => {
+                self.state.transaction_state.transfer_input.pop();
+                Ok(true)
+            }
+            Event::Enter => {
+                let input = std::mem::take(&mut self.state.transaction_state.transfer_input);
+                let mut parts = input.split_whitespace();
+                if let Some(tx_hash) = parts.next() {
+                    let chain_id = parts.next().map(|c| c.to_string());
+                    self.start_transfer_tracking(tx_hash.to_string(), chain_id);
+                }
+                Ok(true)
+            }
+            Event::Escape => {
+                self.state.transaction_state.view_mode = TransactionViewMode::History;
+                Ok(true)
+            }
             _ => Ok(false),
         }
     }
 
+    /// Follow a cross-chain transfer in the background; updates arrive as
+    /// `Event::TransferStatusUpdate`
+    fn start_transfer_tracking(&mut self, tx_hash: String, chain_id: Option<String>) {
+        let Some(event_sender) = self.event_sender.clone() else {
+            self.set_error("Failed to track transfer: no event sender".to_string());
+            return;
+        };
+
+        crate::tui_dex::utils::logger::log_info(&format!(
+            "Tracking cross-chain transfer {} (chain: {:?})",
+            tx_hash, chain_id
+        ));
+        self.state
+            .transaction_state
+            .start_tracking(tx_hash.clone(), chain_id.clone());
+        self.set_status(format!("Tracking transfer {}", tx_hash));
+
+        let processor = crate::tui_dex::events::AsyncBlockchainProcessor::new(event_sender);
+        tokio::spawn(async move {
+            processor.track_transfer(tx_hash, chain_id).await;
+        });
+    }
+
+    /// Handle rewards screen specific events. Returns `true` if the event was handled.
+    async fn handle_rewards_screen_event(&mut self, event: Event) -> Result<bool, Error> {
+        use crate::tui_dex::screens::rewards::RewardsMode;
+
+        if self.state.navigation_mode != NavigationMode::WithinScreen {
+            return Ok(false);
+        }
+
+        // 'v' switches the timeline panel to the vesting timeline
+        if self.state.rewards_state.mode != RewardsMode::VestingTimeline {
+            if let Event::Char('v') = event {
+                self.state.rewards_state.mode = RewardsMode::VestingTimeline;
+                return Ok(true);
+            }
+            return Ok(false);
+        }
+
+        match event {
+            Event::Char(c) => {
+                self.state.rewards_state.vesting_campaign_input.push(c);
+                Ok(true)
+            }
+            Event::Backspace => {
+                self.state.rewards_state.vesting_campaign_input.pop();
+                Ok(true)
+            }
+            Event::Enter => {
+                let campaign_address = self
+                    .state
+                    .rewards_state
+                    .vesting_campaign_input
+                    .trim()
+                    .to_string();
+                if !campaign_address.is_empty() {
+                    self.load_vesting_schedule(campaign_address).await;
+                }
+                Ok(true)
+            }
+            Event::Escape => {
+                self.state.rewards_state.mode = RewardsMode::Dashboard;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Load the connected wallet's vesting schedule for a ClaimDrop campaign
+    async fn load_vesting_schedule(&mut self, campaign_address: String) {
+        let Some(address) = self.state.wallet_address.clone() else {
+            self.set_error("Connect a wallet to view a vesting schedule".to_string());
+            return;
+        };
+
+        match self
+            .client
+            .claimdrop_campaign(campaign_address.clone())
+            .query_vesting_schedule(&address)
+            .await
+        {
+            Ok(schedule) => {
+                crate::tui_dex::utils::logger::log_info(&format!(
+                    "Loaded vesting schedule for {} in campaign {}",
+                    address, campaign_address
+                ));
+                self.state.rewards_state.vesting_schedule = Some(schedule);
+                self.set_status(format!("Vesting schedule loaded for {}", campaign_address));
+            }
+            Err(e) => {
+                self.state.rewards_state.vesting_schedule = None;
+                self.set_error(format!("Failed to load vesting schedule: {}", e));
+            }
+        }
+    }
+
     /// Handle wallet selection screen specific events. Returns `true` if the event was handled.
     async fn handle_wallet_selection_event(&mut self, event: Event) -> Result<bool, Error> {
         use crate::tui_dex::screens::wallet_selection::{
@@ -2218,6 +3320,7 @@ impl App {
                                 match wallet.address() {
                                     Ok(address) => {
                                         self.set_wallet_address(address.to_string());
+                                        self.state.wallets_state.active = Some(wallet_name.clone());
                                         // Reconfigure the client with the loaded wallet
                                         self.configure_client_wallet(wallet).await?;
 
@@ -2409,32 +3512,51 @@ impl App {
                 }
             }
             Event::TriggerSimulation => {
-                // Only run simulation if we have valid input
-                if !swap_state.from_amount_input.value().is_empty()
-                    && swap_state.pool_dropdown.get_selected_value().is_some()
-                    && swap_state
-                        .from_token_dropdown
-                        .get_selected_value()
-                        .is_some()
-                {
-                    // Trigger swap simulation
-                    let from_amount = swap_state.from_amount_input.value();
-                    let from_token = swap_state
-                        .from_token_dropdown
-                        .get_selected_value()
-                        .unwrap_or("");
-                    let pool_text = swap_state.pool_dropdown.get_selected_label().unwrap_or("");
-
-                    self.set_loading("Running swap simulation...".to_string());
-
-                    // For now, just simulate some delay and show success
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    self.set_success(format!(
-                        "Simulation complete: {} {} via {}",
-                        from_amount, from_token, pool_text
-                    ));
-
-                    swap_state.reset_simulation_timer();
+                // Simulate in the background so typing is never blocked; the
+                // result comes back as a SwapQuoteReady event
+                match self.swap_quote_request(swap_state) {
+                    Some((pool, offer, ask_denom)) => {
+                        let seq = swap_state.simulation_seq;
+                        let offer_symbol = self.denom_to_symbol(&offer.denom);
+                        let ask_symbol = self.denom_to_symbol(&ask_denom);
+                        let ask_decimals = self.get_token_decimals(&ask_denom);
+                        let client = self.client.clone();
+                        if let Some(sender) = self.event_sender.clone() {
+                            tokio::spawn(async move {
+                                let pool_id = pool.pool_info.pool_identifier.clone();
+                                let quote = async {
+                                    let simulation = client
+                                        .simulate_swap(&pool_id, offer.clone(), &ask_denom)
+                                        .await?;
+                                    let impact = crate::protocols::dex::depth::check_swap_impact(
+                                        &pool,
+                                        &offer,
+                                        &ask_denom,
+                                        crate::protocols::dex::depth::LARGE_SWAP_IMPACT_THRESHOLD,
+                                    )?;
+                                    Ok::<_, Error>(crate::tui_dex::screens::swap::SwapQuote {
+                                        pool_id,
+                                        offer,
+                                        ask_denom,
+                                        offer_symbol,
+                                        ask_symbol,
+                                        ask_decimals,
+                                        simulation,
+                                        price_impact: impact.price_impact,
+                                        warnings: impact.warnings,
+                                    })
+                                }
+                                .await
+                                .map_err(|e| e.to_string());
+                                let _ = sender.send(Event::SwapQuoteReady { seq, quote });
+                            });
+                        }
+                    }
+                    None => {
+                        // Incomplete form: nothing to preview
+                        swap_state.simulation_pending = false;
+                        self.state.swap_state.quote = None;
+                    }
                 }
                 return Ok(true);
             }
@@ -2444,6 +3566,28 @@ impl App {
         Ok(false)
     }
 
+    /// Pool, offered coin and asked denom of the swap form, when complete
+    fn swap_quote_request(
+        &self,
+        swap_state: &crate::tui_dex::screens::swap::SwapScreenState,
+    ) -> Option<(PoolInfoResponse, Coin, String)> {
+        let pool_id = swap_state.pool_dropdown.get_selected_value()?;
+        let from_token = swap_state.from_token_dropdown.get_selected_value()?;
+        let pool_label = swap_state.pool_dropdown.get_selected_label()?;
+        let pool = &self.state.pool_cache.get(pool_id)?.pool_info;
+        let to_token =
+            crate::tui_dex::screens::swap::determine_to_token_from_pool(pool_label, from_token);
+
+        let offer_denom = self.map_display_name_to_denom(from_token, &pool.pool_info.assets);
+        let ask_denom = self.map_display_name_to_denom(&to_token, &pool.pool_info.assets);
+        let amount = crate::tui_dex::screens::swap::parse_token_amount(
+            swap_state.from_amount_input.value(),
+            self.get_token_decimals(&offer_denom),
+        )
+        .filter(|amount| !amount.is_zero())?;
+        Some((pool.clone(), Coin::new(amount, offer_denom), ask_denom))
+    }
+
     /// Sync swap screen state back to app state
     fn sync_swap_state_to_app(
         &mut self,
@@ -2566,6 +3710,7 @@ impl App {
                     self.fetch_pool_reserves_for_liquidity(pool_id).await?;
                 }
             }
+            self.request_liquidity_preview();
             return Ok(true);
         }
 
@@ -2617,6 +3762,293 @@ impl App {
         Ok(false)
     }
 
+    /// Preview the liquidity form
+    ///
+    /// Two-sided deposits and plain withdrawals are estimated from the cached
+    /// reserves; zaps are simulated on chain in the background and come back
+    /// as a `LiquidityPreviewReady` event.
+    fn request_liquidity_preview(&mut self) {
+        use crate::protocols::dex::zap::estimate_lp_shares;
+        use crate::tui_dex::screens::liquidity::{LiquidityMode, LiquidityPreview};
+        use crate::tui_dex::screens::swap::parse_token_amount;
+        use std::str::FromStr;
+
+        let liquidity_state = liquidity::get_liquidity_screen_state();
+        liquidity_state.simulation_seq += 1;
+        let seq = liquidity_state.simulation_seq;
+        if liquidity_state.mode == LiquidityMode::Positions {
+            return;
+        }
+        let Some(pool) = liquidity_state
+            .pool_dropdown
+            .get_selected_value()
+            .and_then(|pool_id| self.state.pool_cache.get(pool_id))
+            .map(|entry| entry.pool_info.clone())
+        else {
+            liquidity_state.apply_preview(None);
+            return;
+        };
+        let pool_id = pool.pool_info.pool_identifier.clone();
+        let assets = &pool.pool_info.assets;
+        let total_share = pool.total_share.amount;
+        let slippage = liquidity_state
+            .slippage_input
+            .value()
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(|percent| {
+                cosmwasm_std::Decimal::from_str(&(percent / 100.0).to_string()).ok()
+            });
+        let pool_denoms: Vec<String> = assets.iter().map(|asset| asset.denom.clone()).collect();
+        let zap_denom = match liquidity_state.zap_asset().map(|asset| {
+            crate::tui_dex::events::AsyncBlockchainProcessor::match_pool_denom(asset, &pool_denoms)
+        }) {
+            Some(Ok(denom)) => Some(denom),
+            Some(Err(e)) => {
+                liquidity_state.apply_preview(None);
+                liquidity_state.simulation_note = Some(e);
+                return;
+            }
+            None => None,
+        };
+        let lp_amount = Uint128::from_str(liquidity_state.withdraw_amount_input.value().trim())
+            .ok()
+            .filter(|amount| !amount.is_zero());
+
+        match (liquidity_state.mode, zap_denom) {
+            (LiquidityMode::Provide, None) => {
+                let amounts = [
+                    liquidity_state.first_asset_input.value(),
+                    liquidity_state.second_asset_input.value(),
+                ];
+                let deposits: Option<Vec<(Uint128, Uint128)>> = assets
+                    .iter()
+                    .zip(amounts)
+                    .map(|(asset, amount)| {
+                        parse_token_amount(amount, self.get_token_decimals(&asset.denom))
+                            .filter(|amount| !amount.is_zero())
+                            .map(|deposit| (deposit, asset.amount))
+                    })
+                    .collect();
+                let preview = deposits
+                    .filter(|deposits| deposits.len() == assets.len())
+                    .map(|deposits| LiquidityPreview::Provide {
+                        lp_tokens: estimate_lp_shares(&deposits, total_share),
+                        note: None,
+                    });
+                liquidity_state.apply_preview(preview);
+            }
+            (LiquidityMode::Withdraw, None) => {
+                let Some(lp_amount) = lp_amount else {
+                    liquidity_state.apply_preview(None);
+                    return;
+                };
+                match crate::protocols::dex::withdrawal::preview_withdrawal(&pool, lp_amount) {
+                    Ok(preview) => {
+                        let number = |value: cosmwasm_std::Decimal| {
+                            value.to_string().parse::<f64>().unwrap_or(0.0)
+                        };
+                        let mut note = format!(
+                            "{:.4}% of the pool, worth {:.6} {} at the spot price; the price moves {:.4}%",
+                            number(preview.pool_share) * 100.0,
+                            number(preview.value_in_first_asset),
+                            assets[0].denom,
+                            number(preview.price_impact) * 100.0
+                        );
+                        if !preview.withdrawals_enabled {
+                            note.push_str("\nWithdrawals are currently disabled for this pool");
+                        }
+                        liquidity_state.apply_preview(Some(LiquidityPreview::Withdraw {
+                            assets: preview.assets,
+                            note: Some(note),
+                        }));
+                    }
+                    Err(e) => {
+                        liquidity_state.apply_preview(None);
+                        liquidity_state.simulation_note = Some(e.to_string());
+                    }
+                }
+            }
+            (LiquidityMode::Provide, Some(denom)) => {
+                let Some(amount) = parse_token_amount(
+                    &liquidity_state.zap_in_amount(),
+                    self.get_token_decimals(&denom),
+                )
+                .filter(|amount| !amount.is_zero()) else {
+                    liquidity_state.apply_preview(None);
+                    return;
+                };
+                let (client, Some(sender)) = (self.client.clone(), self.event_sender.clone())
+                else {
+                    return;
+                };
+                liquidity_state.apply_preview(None);
+                liquidity_state.simulation_note = Some("Simulating zap...".to_string());
+                tokio::spawn(async move {
+                    let preview = client
+                        .simulate_zap_in(&pool_id, Coin::new(amount, denom), slippage)
+                        .await
+                        .map(|preview| LiquidityPreview::Provide {
+                            lp_tokens: preview.expected_lp_tokens,
+                            note: Some(format!(
+                                "Swaps {} {} for at least {} {} before depositing",
+                                preview.swap_offer.amount,
+                                preview.swap_offer.denom,
+                                preview.min_swap_return.amount,
+                                preview.min_swap_return.denom
+                            )),
+                        })
+                        .map_err(|e| e.to_string());
+                    let _ = sender.send(Event::LiquidityPreviewReady { seq, preview });
+                });
+            }
+            (LiquidityMode::Withdraw, Some(denom)) => {
+                let Some(lp_amount) = lp_amount else {
+                    liquidity_state.apply_preview(None);
+                    return;
+                };
+                let (client, Some(sender)) = (self.client.clone(), self.event_sender.clone())
+                else {
+                    return;
+                };
+                liquidity_state.apply_preview(None);
+                liquidity_state.simulation_note = Some("Simulating zap...".to_string());
+                tokio::spawn(async move {
+                    let preview = client
+                        .simulate_zap_out(&pool_id, lp_amount, &denom, slippage)
+                        .await
+                        .map(|preview| LiquidityPreview::Withdraw {
+                            assets: vec![Coin::new(
+                                preview.expected_return,
+                                preview.target_denom.clone(),
+                            )],
+                            note: Some(format!(
+                                "Swaps the withdrawn {} {}; at least {} {} after slippage",
+                                preview.swap_offer.amount,
+                                preview.swap_offer.denom,
+                                preview.min_return,
+                                preview.target_denom
+                            )),
+                        })
+                        .map_err(|e| e.to_string());
+                    let _ = sender.send(Event::LiquidityPreviewReady { seq, preview });
+                });
+            }
+            (LiquidityMode::Positions, _) => {}
+        }
+    }
+
+    /// Rebuild the liquidity positions from the cached pools and LP balances
+    ///
+    /// Every pool is listed, held ones first. Entries are saved per wallet so
+    /// the fees earned since entering a position survive restarts.
+    fn refresh_liquidity_positions(&mut self) {
+        use crate::protocols::dex::position::{LpPosition, PositionTracker};
+        use std::str::FromStr;
+
+        let Some(address) = self.state.wallet_address.clone() else {
+            return;
+        };
+        // Without balances every position would look withdrawn
+        if self.state.balances.is_empty() {
+            return;
+        }
+        let path = crate::config::Config::default_path()
+            .with_file_name("positions")
+            .join(format!("{}.json", address));
+        let mut tracker = PositionTracker::load(&path).unwrap_or_else(|e| {
+            crate::tui_dex::utils::logger::log_warning(&format!(
+                "Failed to load liquidity positions: {}",
+                e
+            ));
+            PositionTracker::default()
+        });
+        let prices = self.pool_prices();
+
+        let mut positions: Vec<liquidity::LiquidityPosition> = self
+            .state
+            .pool_cache
+            .values()
+            .map(|entry| {
+                let pool = &entry.pool_info;
+                let lp_amount = self
+                    .state
+                    .balances
+                    .get(&pool.pool_info.lp_denom)
+                    .and_then(|amount| Uint128::from_str(amount).ok())
+                    .unwrap_or_default();
+                tracker.observe(pool, lp_amount);
+                let position = LpPosition::new(
+                    pool,
+                    lp_amount,
+                    tracker.entry(&pool.pool_info.pool_identifier),
+                );
+
+                let value: f64 = position
+                    .underlying
+                    .iter()
+                    .filter_map(|coin| prices.usd_value(coin))
+                    .sum();
+                let fees_usd: f64 = position
+                    .earned_fees
+                    .iter()
+                    .flatten()
+                    .filter_map(|coin| prices.usd_value(coin))
+                    .sum();
+                let value_without_fees = value - fees_usd;
+                let underlying = |index: usize| {
+                    position
+                        .underlying
+                        .get(index)
+                        .map(|coin| (coin.amount, coin.denom.clone()))
+                        .unwrap_or_default()
+                };
+                let (first_asset_amount, first_asset_denom) = underlying(0);
+                let (second_asset_amount, second_asset_denom) = underlying(1);
+
+                liquidity::LiquidityPosition {
+                    pool_id: position.pool_id.clone(),
+                    asset_pair: pool
+                        .pool_info
+                        .assets
+                        .iter()
+                        .map(|asset| self.denom_to_symbol(&asset.denom))
+                        .collect::<Vec<_>>()
+                        .join("/"),
+                    lp_token_amount: position.lp_amount,
+                    estimated_value_usd: value,
+                    initial_value_usd: value_without_fees,
+                    pnl_percentage: if value_without_fees > 0.0 {
+                        fees_usd / value_without_fees * 100.0
+                    } else {
+                        0.0
+                    },
+                    pnl_usd: fees_usd,
+                    share_percentage: position.share * 100.0,
+                    first_asset_amount,
+                    second_asset_amount,
+                    first_asset_denom,
+                    second_asset_denom,
+                    earned_fees: position.earned_fees,
+                }
+            })
+            .collect();
+        positions.sort_by(|a, b| {
+            b.is_held()
+                .cmp(&a.is_held())
+                .then_with(|| a.pool_id.cmp(&b.pool_id))
+        });
+        liquidity::update_liquidity_positions(positions);
+
+        if let Err(e) = tracker.save(&path) {
+            crate::tui_dex::utils::logger::log_warning(&format!(
+                "Failed to save liquidity positions: {}",
+                e
+            ));
+        }
+    }
+
     /// Handle admin screen specific events. Returns `true` if the event was handled.
     async fn handle_admin_screen_event(&mut self, event: Event) -> Result<bool, Error> {
         let admin_state = crate::tui_dex::screens::admin::get_admin_screen_state();
@@ -3078,7 +4510,9 @@ impl App {
                                 "settings_network_name"
                                 | "settings_network_rpc"
                                 | "settings_gas_price"
-                                | "settings_gas_adjustment" => {
+                                | "settings_gas_adjustment"
+                                | "settings_fee_denom"
+                                | "settings_grpc_url" => {
                                     let _ = self.state.settings_state.handle_backspace();
                                 }
                                 "settings_wallet_mnemonic" => {
@@ -3086,7 +4520,8 @@ impl App {
                                 }
                                 "settings_balance_refresh"
                                 | "settings_pool_refresh"
-                                | "settings_decimal_precision" => {
+                                | "settings_decimal_precision"
+                                | "settings_default_slippage" => {
                                     let _ = self.state.settings_state.handle_backspace();
                                 }
                                 _ => {}
@@ -3316,19 +4751,7 @@ impl App {
                                 // Handle confirmation actions (this will clear the modal)
                                 self.handle_confirmation();
                             } else {
-                                // User cancelled - just clear modal and handle cancellation
-                                self.state.modal_state = None;
-                                if self.state.current_screen == Screen::Swap {
-                                    let _ =
-                                        crate::tui_dex::screens::swap::handle_confirmation_response(
-                                            false,
-                                        );
-                                } else if self.state.current_screen == Screen::Liquidity {
-                                    let _ = crate::tui_dex::screens::liquidity::handle_liquidity_confirmation_response(
-                                        false,
-                                    );
-                                }
-                                self.set_status("Action cancelled".to_string());
+                                self.cancel_confirmation();
                             }
                         }
                         crate::tui_dex::components::modals::ModalType::Error {
@@ -3353,8 +4776,29 @@ impl App {
                     }
                     return true;
                 }
+                // y/n answer confirmation modals directly
+                Event::Char('y') | Event::Char('n')
+                    if matches!(
+                        modal.modal_type,
+                        crate::tui_dex::components::modals::ModalType::Confirmation { .. }
+                    ) =>
+                {
+                    if *event == Event::Char('y') {
+                        self.handle_confirmation();
+                    } else {
+                        self.cancel_confirmation();
+                    }
+                    return true;
+                }
                 Event::Escape => {
-                    self.state.modal_state = None;
+                    if matches!(
+                        modal.modal_type,
+                        crate::tui_dex::components::modals::ModalType::Confirmation { .. }
+                    ) {
+                        self.cancel_confirmation();
+                    } else {
+                        self.state.modal_state = None;
+                    }
                     return true;
                 }
                 _ => {}
@@ -3363,6 +4807,20 @@ impl App {
         false
     }
 
+    /// Close a confirmation modal without acting on it
+    fn cancel_confirmation(&mut self) {
+        self.state.modal_state = None;
+        if self.state.current_screen == Screen::Swap {
+            let _ = crate::tui_dex::screens::swap::handle_confirmation_response(false);
+        } else if self.state.current_screen == Screen::Liquidity {
+            let _ =
+                crate::tui_dex::screens::liquidity::handle_liquidity_confirmation_response(false);
+        } else if self.state.current_screen == Screen::ClaimDrop {
+            self.state.claimdrop_state.pending_claim = None;
+        }
+        self.set_status("Action cancelled".to_string());
+    }
+
     /// Retry the last failed operation
     fn retry_last_operation(&mut self) {
         // Check if the last error was slippage-related
@@ -3560,6 +5018,11 @@ impl App {
                         }
                     }
                 }
+            } else if self.state.current_screen == Screen::ClaimDrop {
+                self.state.modal_state = None;
+                if let Some(campaigns) = self.state.claimdrop_state.pending_claim.take() {
+                    self.execute_claimdrop_claim(campaigns);
+                }
             } else {
                 // Handle other confirmation types
                 self.state.modal_state = None;
@@ -3605,6 +5068,19 @@ impl App {
                 // Update admin screen pools when entering screen
                 self.update_admin_screen_pools();
             }
+            Screen::Portfolio if self.state.portfolio_state.portfolio.is_none() => {
+                self.request_portfolio_refresh();
+            }
+            Screen::History if !self.state.history_state.loaded => {
+                self.request_history_refresh();
+            }
+            Screen::Wallets if !self.state.wallets_state.loaded => {
+                self.request_wallets_refresh();
+            }
+            Screen::ClaimDrop if !self.state.claimdrop_state.loaded => {
+                self.request_claimdrop_refresh();
+            }
+            Screen::Notifications => self.state.notifications_state.mark_all_read(),
             _ => {}
         }
         // Don't initialize focus here - it will be done when user presses Enter
@@ -3639,6 +5115,19 @@ impl App {
                 // Update admin screen pools when entering screen
                 self.update_admin_screen_pools();
             }
+            Screen::Portfolio if self.state.portfolio_state.portfolio.is_none() => {
+                self.request_portfolio_refresh();
+            }
+            Screen::History if !self.state.history_state.loaded => {
+                self.request_history_refresh();
+            }
+            Screen::Wallets if !self.state.wallets_state.loaded => {
+                self.request_wallets_refresh();
+            }
+            Screen::ClaimDrop if !self.state.claimdrop_state.loaded => {
+                self.request_claimdrop_refresh();
+            }
+            Screen::Notifications => self.state.notifications_state.mark_all_read(),
             _ => {}
         }
         // Don't initialize focus here - it will be done when user presses Enter
@@ -3677,11 +5166,81 @@ impl App {
                 // Update admin screen pools when entering screen
                 self.update_admin_screen_pools();
             }
+            Screen::Portfolio if self.state.portfolio_state.portfolio.is_none() => {
+                self.request_portfolio_refresh();
+            }
+            Screen::History if !self.state.history_state.loaded => {
+                self.request_history_refresh();
+            }
+            Screen::Wallets if !self.state.wallets_state.loaded => {
+                self.request_wallets_refresh();
+            }
+            Screen::ClaimDrop if !self.state.claimdrop_state.loaded => {
+                self.request_claimdrop_refresh();
+            }
+            Screen::Notifications => self.state.notifications_state.mark_all_read(),
             _ => {}
         }
         // Don't initialize focus here - it will be done when user presses Enter
     }
 
+    /// ClaimDrop campaigns `address` has rewards in, from the network's
+    /// ClaimDrop factory, with the address's vesting schedule in each
+    async fn fetch_claimdrop_campaigns(
+        &self,
+        address: &str,
+    ) -> Result<Vec<crate::tui_dex::screens::claimdrop::ClaimdropCampaign>, Error> {
+        let factory = crate::portfolio::registry_address(
+            &self.client.config().network_name,
+            &crate::config::contracts::ContractType::ClaimdropFactory,
+        )
+        .ok_or_else(|| Error::Config("No ClaimDrop factory on this network".to_string()))?;
+        let rewards = self
+            .client
+            .claimdrop_factory(factory)
+            .query_user_rewards(address)
+            .await?;
+
+        let mut campaigns = Vec::with_capacity(rewards.campaigns.len());
+        for reward in rewards.campaigns {
+            let campaign_client = self
+                .client
+                .claimdrop_campaign(reward.campaign_address.clone());
+            // A campaign whose details fail to load is still listed, without
+            // its vesting schedule
+            let (name, is_active, schedule) = match campaign_client.query_campaign().await {
+                Ok(info) => {
+                    let schedule = campaign_client
+                        .vesting_schedule_for(&info, address)
+                        .await
+                        .unwrap_or_else(|e| {
+                            crate::tui_dex::utils::logger::log_warning(&format!(
+                                "Failed to load vesting schedule of {}: {}",
+                                reward.campaign_address, e
+                            ));
+                            None
+                        });
+                    (info.name, info.is_active, schedule)
+                }
+                Err(e) => {
+                    crate::tui_dex::utils::logger::log_warning(&format!(
+                        "Failed to load ClaimDrop campaign {}: {}",
+                        reward.campaign_address, e
+                    ));
+                    (reward.campaign_address.clone(), false, None)
+                }
+            };
+            campaigns.push(crate::tui_dex::screens::claimdrop::ClaimdropCampaign {
+                address: reward.campaign_address.clone(),
+                name,
+                is_active,
+                rewards: reward,
+                schedule,
+            });
+        }
+        Ok(campaigns)
+    }
+
     /// Handle background data refresh events
     async fn handle_data_refresh(
         &mut self,
@@ -3702,11 +5261,16 @@ impl App {
                 if let Some(address) = &self.state.wallet_address.clone() {
                     // Refresh balances
                     if let Ok(balances) = self.client.get_balances().await {
+                        let denoms: Vec<String> =
+                            balances.iter().map(|coin| coin.denom.clone()).collect();
+                        self.client.resolve_denom_metadata(&denoms).await;
                         for balance in balances {
                             self.state
                                 .balances
                                 .insert(balance.denom, balance.amount.to_string());
                         }
+                        self.check_balance_alerts();
+                        self.refresh_liquidity_positions();
                     }
 
                     // Note: Wallet address updated for future background tasks
@@ -3715,14 +5279,8 @@ impl App {
             "pools" => {
                 // Refresh pool data
                 if let Ok(pools) = self.client.get_pools(None).await {
-                    for pool in pools {
-                        let pool_id = pool.pool_info.pool_identifier.clone();
-                        let cache_entry = PoolCacheEntry {
-                            pool_info: pool,
-                            cached_at: chrono::Utc::now(),
-                        };
-                        self.state.pool_cache.insert(pool_id, cache_entry);
-                    }
+                    self.cache_pools(pools);
+                    self.refresh_liquidity_positions();
 
                     // Update swap screen pools if currently on swap screen
                     if self.state.current_screen == Screen::Swap {
@@ -3772,9 +5330,87 @@ impl App {
                 self.state.network_info.last_sync_time = Some(chrono::Utc::now());
                 self.state.network_info.is_syncing = false;
             }
+            "portfolio" => {
+                if let Some(address) = self.state.wallet_address.clone() {
+                    let factory = crate::portfolio::registry_address(
+                        &self.client.config().network_name,
+                        &crate::config::contracts::ContractType::ClaimdropFactory,
+                    );
+                    match crate::portfolio::fetch_portfolio(
+                        &self.client,
+                        &address,
+                        factory.as_deref(),
+                    )
+                    .await
+                    {
+                        Ok(portfolio) => self.state.portfolio_state.portfolio = Some(portfolio),
+                        Err(e) => self.set_error(format!("Failed to load portfolio: {}", e)),
+                    }
+                }
+                self.state.portfolio_state.loading = false;
+            }
+            "wallets" => {
+                match crate::wallet::WalletStorage::new().and_then(|storage| storage.list_wallets())
+                {
+                    Ok(wallets) => {
+                        for wallet in &wallets {
+                            match self.client.get_balances_for_address(&wallet.address).await {
+                                Ok(coins) => {
+                                    self.state
+                                        .wallets_state
+                                        .balances
+                                        .insert(wallet.address.clone(), coins);
+                                }
+                                Err(e) => crate::tui_dex::utils::logger::log_warning(&format!(
+                                    "Failed to fetch balances of wallet '{}': {}",
+                                    wallet.name, e
+                                )),
+                            }
+                        }
+                        self.state.wallets_state.set_wallets(wallets);
+                    }
+                    Err(e) => {
+                        self.state.wallets_state.loading = false;
+                        self.set_error(format!("Failed to list wallets: {}", e));
+                    }
+                }
+            }
+            "history" => {
+                if let Some(address) = self.state.wallet_address.clone() {
+                    let history = crate::tx_history::TxHistory::new(
+                        &self.client.config().contracts,
+                        &address,
+                    );
+                    match history
+                        .page(&self.client, self.state.history_state.page)
+                        .await
+                    {
+                        Ok(entries) => self.state.history_state.set_entries(entries),
+                        Err(e) => {
+                            self.state.history_state.loading = false;
+                            self.set_error(format!("Failed to load transaction history: {}", e));
+                        }
+                    }
+                }
+            }
+            "claimdrop" => {
+                if let Some(address) = self.state.wallet_address.clone() {
+                    match self.fetch_claimdrop_campaigns(&address).await {
+                        Ok(campaigns) => self.state.claimdrop_state.set_campaigns(campaigns),
+                        Err(e) => {
+                            self.state.claimdrop_state.loading = false;
+                            self.set_error(format!("Failed to load ClaimDrop campaigns: {}", e));
+                        }
+                    }
+                }
+            }
             "prices" => {
-                // Refresh price data - this would be implemented when price APIs are available
-                // For now, just update the last sync time
+                // Pool snapshots are the price source; each refresh adds a
+                // sample to every pool's chart
+                if let Ok(pools) = self.client.get_pools(None).await {
+                    self.cache_pools(pools);
+                    self.refresh_liquidity_positions();
+                }
                 self.state.network_info.last_sync_time = Some(chrono::Utc::now());
             }
             _ => {
@@ -3827,6 +5463,22 @@ impl App {
         self.add_transaction(tx_info);
     }
 
+    /// Cache pool snapshots, recording their spot prices for the price charts
+    fn cache_pools(&mut self, pools: Vec<PoolInfoResponse>) {
+        let now = chrono::Utc::now();
+        for pool in pools {
+            self.state.price_history.record_pool(&pool, now);
+            let pool_id = pool.pool_info.pool_identifier.clone();
+            let cache_entry = PoolCacheEntry {
+                pool_info: pool,
+                cached_at: now,
+            };
+            self.state.pool_cache.insert(pool_id, cache_entry);
+        }
+        let prices = self.pool_prices();
+        self.state.notifications_state.check_prices(&prices);
+    }
+
     /// Select a pool
     pub fn select_pool(&mut self, pool_id: u64) {
         self.state.selected_pool_id = Some(pool_id);
@@ -3943,16 +5595,7 @@ impl App {
 
             // Refresh pool data (limited to avoid overwhelming)
             match self.client.get_pools(Some(20)).await {
-                Ok(pools) => {
-                    for pool in pools {
-                        let pool_id = pool.pool_info.pool_identifier.clone();
-                        let cache_entry = PoolCacheEntry {
-                            pool_info: pool,
-                            cached_at: chrono::Utc::now(),
-                        };
-                        self.state.pool_cache.insert(pool_id, cache_entry);
-                    }
-                }
+                Ok(pools) => self.cache_pools(pools),
                 Err(e) => {
                     errors.push(format!("Failed to fetch pools: {}", e));
                 }
@@ -4005,6 +5648,11 @@ impl App {
         if let Some(address) = &self.state.wallet_address.clone() {
             match self.client.get_balances().await {
                 Ok(balances) => {
+                    // Symbols and decimals of held denoms, cached for display
+                    let denoms: Vec<String> =
+                        balances.iter().map(|coin| coin.denom.clone()).collect();
+                    self.client.resolve_denom_metadata(&denoms).await;
+
                     // Clear existing balances
                     self.state.balances.clear();
                     // Update with new balances
@@ -4018,6 +5666,20 @@ impl App {
                     errors.push(format!("Failed to fetch balances: {}", e));
                 }
             }
+
+            // The same wallet on the other networks; networks that do not
+            // answer are shown as such rather than failing the refresh
+            self.update_loading_progress(
+                55.0,
+                Some("Comparing balances across networks...".to_string()),
+            );
+            let networks = COMPARED_NETWORKS.map(String::from);
+            match NetworkFanout::from_profiles(&NetworkProfile::builtin(), &networks) {
+                Ok(fanout) => {
+                    self.state.network_balances = Some(fanout.balances(address).await);
+                }
+                Err(e) => errors.push(format!("Failed to compare networks: {}", e)),
+            }
         }
 
         // Update progress - fetching network info
@@ -4037,16 +5699,7 @@ impl App {
 
         // Refresh pool data (limited to avoid overwhelming)
         match self.client.get_pools(Some(20)).await {
-            Ok(pools) => {
-                for pool in pools {
-                    let pool_id = pool.pool_info.pool_identifier.clone();
-                    let cache_entry = PoolCacheEntry {
-                        pool_info: pool,
-                        cached_at: chrono::Utc::now(),
-                    };
-                    self.state.pool_cache.insert(pool_id, cache_entry);
-                }
-            }
+            Ok(pools) => self.cache_pools(pools),
             Err(e) => {
                 errors.push(format!("Failed to fetch pools: {}", e));
             }
@@ -4084,13 +5737,57 @@ impl App {
         // Initialize settings state with current config if needed
         if self.state.settings_state.current_config.mnemonic.is_none() {
             // Load current config into settings state
-            let current_config = crate::config::Config {
-                network: self.config.clone(),
-                mnemonic: None, // We don't store mnemonic in memory for security
-                tokens: std::collections::HashMap::new(),
-            };
             self.state.settings_state =
-                crate::tui_dex::screens::settings::SettingsState::new(current_config);
+                crate::tui_dex::screens::settings::SettingsState::new(self.settings_config());
+        }
+        Ok(())
+    }
+
+    /// Config file contents with the network the app is connected to
+    fn settings_config(&self) -> crate::config::Config {
+        let mut config =
+            crate::config::Config::load(&crate::config::Config::default_path()).unwrap_or_default();
+        config.network = self.config.clone();
+        config.mnemonic = None; // We don't store mnemonic in memory for security
+        config
+    }
+
+    /// Apply the saved preferences to the screens that use them
+    fn apply_preferences(&mut self) {
+        let default_slippage = self
+            .state
+            .settings_state
+            .current_config
+            .preferences
+            .default_slippage
+            .clone();
+        crate::tui_dex::screens::swap::get_swap_screen_state()
+            .slippage_input
+            .set_value(&default_slippage);
+        self.state.swap_state.slippage = default_slippage;
+    }
+
+    /// Rebuild the client for changed network settings, keeping the wallet
+    ///
+    /// `stale_data` drops cached pools and balances, for a new chain or node.
+    async fn apply_network_changes(&mut self, stale_data: bool) -> Result<(), Error> {
+        self.stop_background_tasks();
+
+        let mut new_client = MantraDexClient::new(self.config.clone())
+            .await?
+            .with_tx_queue(self.tx_queue.clone());
+        if let Some(wallet) = self.client.shared_wallet() {
+            new_client = new_client.with_shared_wallet(wallet);
+        }
+        self.client = std::sync::Arc::new(new_client);
+
+        if stale_data {
+            self.state.pool_cache.clear();
+            self.state.balances.clear();
+        }
+
+        if let Some(sender) = self.event_sender.clone() {
+            self.initialize_background_tasks(sender);
         }
         Ok(())
     }
@@ -4102,10 +5799,26 @@ impl App {
             // Save settings
             match self.state.settings_state.save_settings() {
                 Ok(new_config) => {
-                    // Update application config
+                    // Update application config and apply what changed
+                    let changes =
+                        crate::ConfigurationChanges::between(&self.config, &new_config.network);
+                    let stale_data = changes.rpc_url.is_some()
+                        || self.config.chain_id != new_config.network.chain_id;
                     self.config = new_config.network;
                     self.state.settings_state.show_confirmation = false;
-                    self.set_success("Settings saved successfully!".to_string());
+                    self.apply_preferences();
+                    if changes.is_empty() {
+                        self.set_success("Settings saved successfully!".to_string());
+                    } else {
+                        match self.apply_network_changes(stale_data).await {
+                            Ok(()) => {
+                                self.set_success("Settings saved and applied".to_string());
+                                self.refresh_current_screen_data().await?;
+                            }
+                            Err(e) => self
+                                .set_error(format!("Settings saved but could not connect: {}", e)),
+                        }
+                    }
                 }
                 Err(e) => {
                     self.set_error(format!("Failed to save settings: {}", e));
@@ -4525,7 +6238,9 @@ impl App {
         self.stop_background_tasks();
 
         // Re-create a fresh client instance that includes the wallet
-        let mut new_client = MantraDexClient::new(self.config.clone()).await?;
+        let mut new_client = MantraDexClient::new(self.config.clone())
+            .await?
+            .with_tx_queue(self.tx_queue.clone());
         new_client = new_client.with_wallet(wallet);
 
         // Replace the old Arc so all subsequent operations use the updated client
@@ -4587,6 +6302,11 @@ impl App {
     /// Convert token denomination to display symbol
     /// Maps micro denominations (uUSDC, uom) to their symbols (USDC, OM)
     pub fn denom_to_symbol(&self, denom: &str) -> String {
+        // Metadata resolved from the chain or the asset registry comes first
+        if let Some(metadata) = self.client.denom_metadata_service().get(denom) {
+            return metadata.symbol;
+        }
+
         // Handle common token mappings
         match denom {
             "uom" => "OM".to_string(),
@@ -4614,11 +6334,32 @@ impl App {
 
     /// Get token decimals for a given denomination
     /// Most Mantra tokens use 6 decimals
+    /// Base units of an amount typed for `denom`, such as `1.5` or `1.5 OM`
+    pub fn parse_amount_input(
+        &self,
+        input: &str,
+        denom: &str,
+    ) -> Result<cosmwasm_std::Uint128, crate::amount::AmountError> {
+        let mut registry =
+            crate::amount::DenomRegistry::from_decimals("uom", &self.state.asset_decimals_cache);
+        if registry.get(denom).is_none() {
+            registry.insert(crate::amount::Denomination::new(
+                denom,
+                self.denom_to_symbol(denom),
+                self.get_token_decimals(denom),
+            ));
+        }
+        crate::amount::Amount::parse_as(input, denom, &registry).map(|amount| amount.amount)
+    }
+
     pub fn get_token_decimals(&self, denom: &str) -> u8 {
         // Check cache first
         if let Some(&decimals) = self.state.asset_decimals_cache.get(denom) {
             return decimals;
         }
+        if let Some(metadata) = self.client.denom_metadata_service().get(denom) {
+            return metadata.decimals;
+        }
 
         // Fallback to hardcoded values if not in cache
         match denom {
@@ -4792,27 +6533,26 @@ impl App {
 
         // Parse amount
         crate::tui_dex::utils::logger::log_info(&format!("Parsing amount: {}", amount));
-        let amount_f64 = amount.parse::<f64>().map_err(|e| {
-            crate::tui_dex::utils::logger::log_error(&format!(
-                "SWAP FAILED: Invalid amount '{}': {}",
-                amount, e
-            ));
-            Error::Other(format!("Invalid amount: {}", amount))
-        })?;
-
-        // Convert to blockchain format (assuming 6 decimal places)
-        let amount_uint = cosmwasm_std::Uint128::new((amount_f64 * 1_000_000.0) as u128);
+        let amount_uint = self
+            .parse_amount_input(&amount, &actual_from_denom)
+            .map_err(|e| {
+                crate::tui_dex::utils::logger::log_error(&format!(
+                    "SWAP FAILED: Invalid amount '{}': {}",
+                    amount, e
+                ));
+                Error::from(e)
+            })?;
         crate::tui_dex::utils::logger::log_info(&format!(
             "Amount converted: {} -> {} (micro units)",
-            amount_f64, amount_uint
+            amount, amount_uint
         ));
 
         // Parse slippage tolerance
         let slippage = if let Some(slippage_str) = slippage_tolerance {
             let parsed_slippage = slippage_str
-                .parse::<f64>()
+                .parse::<cosmwasm_std::Decimal>()
                 .ok()
-                .map(|s| cosmwasm_std::Decimal::percent((s * 100.0) as u64));
+                .map(|s| s * cosmwasm_std::Decimal::percent(1));
             crate::tui_dex::utils::logger::log_info(&format!(
                 "Slippage parsed: {}% -> {:?}",
                 slippage_str, parsed_slippage
@@ -4820,7 +6560,7 @@ impl App {
             parsed_slippage
         } else {
             crate::tui_dex::utils::logger::log_info("Using default slippage: 1%");
-            Some(cosmwasm_std::Decimal::percent(100)) // 1% default slippage
+            Some(cosmwasm_std::Decimal::percent(1)) // 1% default slippage
         };
 
         // Create the offer asset coin using the actual denomination
@@ -5201,49 +6941,57 @@ impl App {
             return Ok(());
         }
 
-        // Get swap details for confirmation
+        // Only confirm against a simulation of the form as it is now
+        let quote = match &self.state.swap_state.quote {
+            Some(quote) if !swap_state.is_quote_outdated() => quote.clone(),
+            _ => {
+                self.show_validation_error(
+                    "Swap Simulation".to_string(),
+                    "The swap has not been simulated for the current inputs yet".to_string(),
+                    vec![
+                        "Wait for the simulation to finish".to_string(),
+                        "Check the simulation panel for errors".to_string(),
+                    ],
+                );
+                return Ok(());
+            }
+        };
+
         let from_amount = swap_state.from_amount_input.value();
         let from_token = swap_state
             .from_token_dropdown
             .get_selected_value()
             .unwrap_or_default();
-        let pool_id = swap_state
-            .pool_dropdown
-            .get_selected_value()
-            .unwrap_or_default();
         let slippage = swap_state.slippage_input.value();
+        let slippage_percent = slippage.parse().unwrap_or(cosmwasm_std::Decimal::one());
 
-        // Get the "to" token from the selected pool
-        let to_token = if let Some(pool_name) = swap_state.pool_dropdown.get_selected_label() {
-            crate::tui_dex::screens::swap::determine_to_token_from_pool(pool_name, from_token)
-        } else {
-            "Unknown".to_string()
-        };
-
-        // Calculate expected output (placeholder - would use simulation result)
-        let expected_output = format!("{:.6}", from_amount.parse::<f64>().unwrap_or(0.0) * 0.95);
-
-        // Calculate price impact (placeholder - would use real simulation data)
-        let price_impact = 0.05; // 0.05%
-
-        // Calculate fees (placeholder - would use real pool data)
-        let fee_amount = format!("{:.6}", from_amount.parse::<f64>().unwrap_or(0.0) * 0.003);
+        // An explicit minimum output overrides the slippage tolerance; the
+        // swap is then sent with the equivalent tolerance
+        let min_override = crate::tui_dex::screens::swap::parse_token_amount(
+            swap_state.min_receive_input.value(),
+            quote.ask_decimals,
+        );
+        let min_receive = quote.min_receive(slippage_percent, min_override);
+        swap_state.confirmed_slippage = min_override.map(|min| quote.slippage_for(min).to_string());
 
-        // Create swap details for confirmation
+        let expected_output = format!("{:.6}", quote.ask_units(quote.simulation.return_amount));
         let swap_details = crate::tui_dex::screens::swap::SwapDetails {
             from_amount: from_amount.to_string(),
             from_token: from_token.to_string(),
             to_amount: expected_output.clone(),
-            to_token: to_token.clone(),
-            pool_name: swap_state
-                .pool_dropdown
-                .get_selected_label()
-                .unwrap_or_default()
-                .to_string(),
-            slippage: slippage.to_string(),
-            expected_output: expected_output.clone(),
-            price_impact,
-            fee_amount,
+            to_token: quote.ask_symbol.clone(),
+            pool_name: format!(
+                "{} → {} via pool {}",
+                quote.offer_symbol, quote.ask_symbol, quote.pool_id
+            ),
+            slippage: swap_state
+                .confirmed_slippage
+                .clone()
+                .unwrap_or_else(|| slippage.to_string()),
+            expected_output,
+            min_receive: format!("{:.6}", quote.ask_units(min_receive)),
+            price_impact: quote.price_impact_percent(),
+            fee_amount: format!("{:.6}", quote.ask_units(quote.total_fees())),
         };
 
         // Show global confirmation modal
@@ -5252,8 +7000,8 @@ impl App {
         self.show_confirmation(
             "Confirm Swap".to_string(),
             confirmation_message,
-            Some("Execute Swap".to_string()),
-            Some("Cancel".to_string()),
+            Some("Execute Swap (y)".to_string()),
+            Some("Cancel (n)".to_string()),
         );
 
         Ok(())
@@ -5676,10 +7424,17 @@ impl App {
                 let (first_asset, second_asset) =
                     crate::tui_dex::screens::liquidity::extract_assets_from_pool_label(pool_name);
 
-                format!(
-                    "Confirm Provide Liquidity:\n\n• First Asset: {} {}\n• Second Asset: {} {}\n• Pool: {}\n• Slippage: {}%\n\nProceed with transaction?",
-                    first_amount, first_asset, second_amount, second_asset, pool_name, slippage
-                )
+                if let Some(zap_asset) = liquidity_state.zap_asset() {
+                    format!(
+                        "Confirm Zap In:\n\n• Deposit: {} {}\n• Pool: {}\n• Slippage: {}%\n\nPart of the deposit is swapped to balance the pool ratio in the same transaction.\n\nProceed with transaction?",
+                        liquidity_state.zap_in_amount(), zap_asset, pool_name, slippage
+                    )
+                } else {
+                    format!(
+                        "Confirm Provide Liquidity:\n\n• First Asset: {} {}\n• Second Asset: {} {}\n• Pool: {}\n• Slippage: {}%\n\nProceed with transaction?",
+                        first_amount, first_asset, second_amount, second_asset, pool_name, slippage
+                    )
+                }
             }
             crate::tui_dex::screens::liquidity::LiquidityMode::Withdraw => {
                 let lp_amount = liquidity_state.withdraw_amount_input.value();
@@ -5688,10 +7443,17 @@ impl App {
                     .get_selected_label()
                     .unwrap_or("Unknown Pool");
 
-                format!(
-                    "Confirm Withdraw Liquidity:\n\n• LP Token Amount: {}\n• Pool: {}\n\nProceed with transaction?",
-                    lp_amount, pool_name
-                )
+                if let Some(zap_asset) = liquidity_state.zap_asset() {
+                    format!(
+                        "Confirm Zap Out:\n\n• LP Token Amount: {}\n• Pool: {}\n• Receive: {} only\n\nProceed with transaction?",
+                        lp_amount, pool_name, zap_asset
+                    )
+                } else {
+                    format!(
+                        "Confirm Withdraw Liquidity:\n\n• LP Token Amount: {}\n• Pool: {}\n\nProceed with transaction?",
+                        lp_amount, pool_name
+                    )
+                }
             }
             crate::tui_dex::screens::liquidity::LiquidityMode::Positions => {
                 return Ok(()); // No operation for positions mode
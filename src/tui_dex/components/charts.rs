@@ -6,11 +6,17 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
-    widgets::{Block, Borders, Gauge, Paragraph},
+    symbols::Marker,
+    text::Span,
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine, Rectangle},
+        Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, Paragraph,
+    },
     Frame,
 };
 use std::time::SystemTime;
 
+use crate::protocols::dex::price_history::{Candle, PriceHistory, Timeframe};
 use crate::tui_dex::app::{AppState, LoadingState, TransactionInfo, TransactionStatus};
 
 /// Progress bar styles for different types of operations
@@ -353,11 +359,310 @@ pub fn render_loading_progress(f: &mut Frame, loading_state: &LoadingState, area
     render_enhanced_progress_bar(f, &config, area);
 }
 
+/// How the price chart draws its candles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceChartStyle {
+    /// Closing prices joined by a line
+    #[default]
+    Line,
+    /// Open, high, low and close of every candle
+    Candlestick,
+}
+
+/// Timeframe, style and crosshair of a price chart
+#[derive(Debug, Clone, Default)]
+pub struct PriceChartState {
+    pub timeframe: Timeframe,
+    pub style: PriceChartStyle,
+    /// Candles back from the latest the crosshair is on, if shown
+    pub crosshair: Option<usize>,
+}
+
+impl PriceChartState {
+    /// Switch to the next timeframe
+    pub fn cycle_timeframe(&mut self) {
+        self.timeframe = self.timeframe.next();
+        self.crosshair = None;
+    }
+
+    /// Switch between line and candlestick charts
+    pub fn toggle_style(&mut self) {
+        self.style = match self.style {
+            PriceChartStyle::Line => PriceChartStyle::Candlestick,
+            PriceChartStyle::Candlestick => PriceChartStyle::Line,
+        };
+    }
+
+    /// Move the crosshair `delta` candles forward in time, showing it on the
+    /// latest candle first
+    pub fn move_crosshair(&mut self, delta: isize) {
+        let back = match self.crosshair {
+            None => 0,
+            Some(back) => back.saturating_add_signed(-delta),
+        };
+        self.crosshair = Some(back);
+    }
+
+    /// Index into `candle_count` candles of the one under the crosshair
+    pub fn crosshair_index(&self, candle_count: usize) -> Option<usize> {
+        let back = self.crosshair?;
+        candle_count
+            .checked_sub(1)
+            .map(|last| last.saturating_sub(back))
+    }
+}
+
+/// Format a price with precision suited to its size
+pub fn format_price(price: f64) -> String {
+    if price >= 1_000.0 {
+        format!("{:.2}", price)
+    } else if price >= 1.0 {
+        format!("{:.4}", price)
+    } else {
+        format!("{:.8}", price)
+    }
+}
+
+/// One line describing a candle, for the crosshair readout
+pub fn candle_readout(candle: &Candle, timeframe: Timeframe) -> String {
+    format!(
+        "{}  O {}  H {}  L {}  C {}  {:+.2}%",
+        format_candle_time(candle, timeframe),
+        format_price(candle.open),
+        format_price(candle.high),
+        format_price(candle.low),
+        format_price(candle.close),
+        candle.change() * 100.0
+    )
+}
+
+fn format_candle_time(candle: &Candle, timeframe: Timeframe) -> String {
+    let local = candle.open_time.with_timezone(&chrono::Local);
+    match timeframe {
+        Timeframe::OneDay => local.format("%Y-%m-%d").to_string(),
+        Timeframe::FourHours | Timeframe::OneHour => local.format("%m-%d %H:%M").to_string(),
+        _ => local.format("%H:%M").to_string(),
+    }
+}
+
+/// Render the price history of `pool_id` as a line or candlestick chart with
+/// a crosshair readout underneath
+pub fn render_price_chart(
+    f: &mut Frame,
+    area: Rect,
+    title: &str,
+    history: &PriceHistory,
+    pool_id: &str,
+    state: &PriceChartState,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .title(format!(
+            "{} · {} · [t] timeframe [c] style [←/→] crosshair",
+            title,
+            state.timeframe.label()
+        ));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    // A candlestick needs two columns, a line point one
+    let columns = chunks[0].width.saturating_sub(10) as usize;
+    let limit = match state.style {
+        PriceChartStyle::Line => columns,
+        PriceChartStyle::Candlestick => columns / 2,
+    };
+    let candles = history.candles(pool_id, state.timeframe, limit.max(1));
+    if candles.is_empty() {
+        let message = Paragraph::new("Collecting prices... samples arrive with every pool refresh")
+            .style(Style::default().fg(Color::Gray))
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(message, chunks[0]);
+        return;
+    }
+
+    let low = candles.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+    let high = candles.iter().map(|c| c.high).fold(0.0, f64::max);
+    let padding = ((high - low) * 0.05).max(high * 0.001);
+    let y_bounds = [low - padding, high + padding];
+    let crosshair = state.crosshair_index(candles.len());
+
+    match state.style {
+        PriceChartStyle::Line => {
+            render_line_chart(f, chunks[0], &candles, y_bounds, crosshair, state.timeframe)
+        }
+        PriceChartStyle::Candlestick => {
+            render_candlestick_chart(f, chunks[0], &candles, y_bounds, crosshair)
+        }
+    }
+
+    let readout_candle = &candles[crosshair.unwrap_or(candles.len() - 1)];
+    let readout = Paragraph::new(candle_readout(readout_candle, state.timeframe)).style(
+        Style::default().fg(if readout_candle.change() >= 0.0 {
+            Color::Green
+        } else {
+            Color::Red
+        }),
+    );
+    f.render_widget(readout, chunks[1]);
+}
+
+fn render_line_chart(
+    f: &mut Frame,
+    area: Rect,
+    candles: &[Candle],
+    y_bounds: [f64; 2],
+    crosshair: Option<usize>,
+    timeframe: Timeframe,
+) {
+    let closes: Vec<(f64, f64)> = candles
+        .iter()
+        .enumerate()
+        .map(|(i, candle)| (i as f64, candle.close))
+        .collect();
+    let crosshair_points: Vec<(f64, f64)> = crosshair
+        .map(|i| vec![(i as f64, y_bounds[0]), (i as f64, y_bounds[1])])
+        .unwrap_or_default();
+
+    let mut datasets = vec![Dataset::default()
+        .marker(Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Cyan))
+        .data(&closes)];
+    if !crosshair_points.is_empty() {
+        datasets.push(
+            Dataset::default()
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Yellow))
+                .data(&crosshair_points),
+        );
+    }
+
+    let x_max = (candles.len().max(2) - 1) as f64;
+    let chart = Chart::new(datasets)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, x_max])
+                .labels(vec![
+                    Span::raw(format_candle_time(&candles[0], timeframe)),
+                    Span::raw(format_candle_time(&candles[candles.len() - 1], timeframe)),
+                ]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds(y_bounds)
+                .labels(vec![
+                    Span::raw(format_price(y_bounds[0])),
+                    Span::raw(format_price((y_bounds[0] + y_bounds[1]) / 2.0)),
+                    Span::raw(format_price(y_bounds[1])),
+                ]),
+        );
+    f.render_widget(chart, area);
+}
+
+fn render_candlestick_chart(
+    f: &mut Frame,
+    area: Rect,
+    candles: &[Candle],
+    y_bounds: [f64; 2],
+    crosshair: Option<usize>,
+) {
+    let canvas = Canvas::default()
+        .marker(Marker::Braille)
+        .x_bounds([0.0, candles.len() as f64])
+        .y_bounds(y_bounds)
+        .paint(|ctx| {
+            for (i, candle) in candles.iter().enumerate() {
+                let color = if candle.close >= candle.open {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+                let x = i as f64 + 0.5;
+                ctx.draw(&CanvasLine {
+                    x1: x,
+                    y1: candle.low,
+                    x2: x,
+                    y2: candle.high,
+                    color,
+                });
+                ctx.draw(&Rectangle {
+                    x: i as f64 + 0.2,
+                    y: candle.open.min(candle.close),
+                    width: 0.6,
+                    height: (candle.close - candle.open).abs(),
+                    color,
+                });
+            }
+            if let Some(i) = crosshair {
+                let x = i as f64 + 0.5;
+                ctx.draw(&CanvasLine {
+                    x1: x,
+                    y1: y_bounds[0],
+                    x2: x,
+                    y2: y_bounds[1],
+                    color: Color::Yellow,
+                });
+                ctx.draw(&CanvasLine {
+                    x1: 0.0,
+                    y1: candles[i].close,
+                    x2: candles.len() as f64,
+                    y2: candles[i].close,
+                    color: Color::DarkGray,
+                });
+            }
+        });
+    f.render_widget(canvas, area);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::time::Duration;
 
+    #[test]
+    fn test_price_chart_crosshair_and_readout() {
+        let mut state = PriceChartState::default();
+        assert_eq!(state.crosshair_index(10), None);
+
+        // The crosshair appears on the latest candle and stops at the oldest
+        state.move_crosshair(-1);
+        assert_eq!(state.crosshair_index(10), Some(9));
+        state.move_crosshair(-3);
+        assert_eq!(state.crosshair_index(10), Some(6));
+        state.move_crosshair(20);
+        assert_eq!(state.crosshair_index(10), Some(9));
+        state.crosshair = Some(50);
+        assert_eq!(state.crosshair_index(10), Some(0));
+
+        state.cycle_timeframe();
+        assert_eq!(state.timeframe, Timeframe::FiveMinutes);
+        assert_eq!(state.crosshair, None);
+        state.toggle_style();
+        assert_eq!(state.style, PriceChartStyle::Candlestick);
+
+        let candle = Candle {
+            open_time: chrono::Utc::now(),
+            open: 2.0,
+            high: 2.2,
+            low: 1.9,
+            close: 2.1,
+            samples: 4,
+        };
+        let readout = candle_readout(&candle, Timeframe::OneMinute);
+        assert!(readout.contains("H 2.2000"), "{}", readout);
+        assert!(readout.ends_with("+5.00%"), "{}", readout);
+        assert_eq!(format_price(0.000123), "0.00012300");
+    }
+
     #[test]
     fn test_progress_bar_config_creation() {
         let config = ProgressBarConfig::transaction_confirmation("0x1234567890abcdef", 3, 6);
@@ -202,7 +202,13 @@ impl TextInput {
     }
 
     fn validate_amount(&mut self, value: &str) -> bool {
-        match value.parse::<f64>() {
+        // A token may follow the number, as in "1.5 OM"; it is checked on submit
+        let number = value
+            .trim()
+            .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .next()
+            .unwrap_or_default();
+        match number.parse::<f64>() {
             Ok(amount) if amount >= 0.0 => true,
             Ok(_) => {
                 self.error = Some("Amount must be positive".to_string());
@@ -60,10 +60,14 @@ pub fn render_header(f: &mut Frame, app_state: &AppState, area: Rect) {
         .wallet_address
         .as_ref()
         .map(|addr| {
-            if addr.len() > 40 {
+            let short = if addr.len() > 40 {
                 format!("{}...{}", &addr[..8], &addr[addr.len() - 8..])
             } else {
                 addr.clone()
+            };
+            match app_state.address_book.label_for(addr) {
+                Some(label) => format!("{} ({})", label, short),
+                None => short,
             }
         })
         .unwrap_or_else(|| "Not Connected".to_string());
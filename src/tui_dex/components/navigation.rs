@@ -4,6 +4,7 @@
 //! in the TUI application.
 
 use crate::tui_dex::app::{AppState, Screen};
+use crate::tui_dex::screens::settings::Theme;
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Tabs},
@@ -18,6 +19,7 @@ pub fn render_navigation(f: &mut Frame, app_state: &AppState, area: Rect) {
 /// Create the navigation tabs widget
 fn create_navigation_tabs(app_state: &AppState) -> Tabs {
     let screens = Screen::all();
+    let theme = Theme::from_config_name(&app_state.settings_state.current_config.preferences.theme);
     let titles: Vec<Line> = screens
         .iter()
         .map(|screen| {
@@ -32,8 +34,17 @@ fn create_navigation_tabs(app_state: &AppState) -> Tabs {
                 Screen::Rewards => "6:Rewards",
                 Screen::Admin => "7:Admin",
                 Screen::Settings => "8:Settings",
+                Screen::Portfolio => "Portfolio",
+                Screen::History => "History",
+                Screen::Wallets => "Wallets",
+                Screen::ClaimDrop => "ClaimDrop",
+                Screen::Notifications => "Alerts",
                 Screen::TransactionDetails => "9:Transaction",
             };
+            let unread = app_state.notifications_state.unread();
+            if *screen == Screen::Notifications && unread > 0 {
+                return Line::from(format!("{} ({})", shortcut_name, unread));
+            }
             Line::from(shortcut_name)
         })
         .collect();
@@ -70,7 +81,7 @@ fn create_navigation_tabs(app_state: &AppState) -> Tabs {
         .style(Style::default().fg(Color::White))
         .highlight_style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.accent())
                 .add_modifier(Modifier::BOLD)
                 .add_modifier(Modifier::UNDERLINED),
         )
@@ -164,12 +164,25 @@ fn get_context_help(app_state: &AppState) -> String {
         }
         crate::tui_dex::app::Screen::Dashboard => "Enter:Refresh",
         crate::tui_dex::app::Screen::Pools => "↑↓:Select | Enter:Details | r:Refresh",
-        crate::tui_dex::app::Screen::Swap => "Enter:Execute | s:Simulate | r:Reset",
+        crate::tui_dex::app::Screen::Swap => "Enter:Execute | y/n:Confirm/Cancel | r:Reset",
         crate::tui_dex::app::Screen::MultiHop => "a:Add hop | d:Delete | Enter:Execute",
-        crate::tui_dex::app::Screen::Liquidity => "p:Provide | w:Withdraw | Enter:Execute",
+        crate::tui_dex::app::Screen::Liquidity => "p:Provide | w:Withdraw | l:Ratio lock | Enter:Execute",
         crate::tui_dex::app::Screen::Rewards => "c:Claim | a:Claim all | Enter:Details",
         crate::tui_dex::app::Screen::Admin => "n:New pool | e:Edit | t:Toggle",
         crate::tui_dex::app::Screen::Settings => "s:Save | r:Reset | Enter:Edit",
+        crate::tui_dex::app::Screen::Portfolio => "↑↓:Select | s:Sort | h:Hide small | r:Refresh",
+        crate::tui_dex::app::Screen::History => {
+            "↑↓:Select | n/p:Page | l:Log/Events | e:Export CSV | r:Refresh"
+        }
+        crate::tui_dex::app::Screen::Wallets => {
+            "↑↓:Select | Enter:Switch | g:Generate | i:Import mnemonic | k:Import keystore | b:Label | f:Faucet | r:Refresh"
+        }
+        crate::tui_dex::app::Screen::ClaimDrop => {
+            "↑↓:Select | c:Claim | a:Claim all | r:Refresh"
+        }
+        crate::tui_dex::app::Screen::Notifications => {
+            "↑↓:Select | ←→:Pane | x:Dismiss | c:Clear | a:Add rule | d:Desktop notifications"
+        }
         crate::tui_dex::app::Screen::TransactionDetails => "Esc:Back | r:Refresh",
     };
 
@@ -143,6 +143,20 @@ pub enum Event {
         lp_token_amount: String,
         slippage_tolerance: Option<String>,
     },
+    /// Provide liquidity from a single asset (swap and deposit in one transaction)
+    ZapIn {
+        pool_id: String,
+        asset_denom: String,
+        amount: String,
+        slippage_tolerance: Option<String>,
+    },
+    /// Withdraw liquidity into a single asset
+    ZapOut {
+        pool_id: String,
+        lp_token_amount: String,
+        target_denom: String,
+        slippage_tolerance: Option<String>,
+    },
     /// Claim rewards for specific epochs
     ClaimRewards {
         pool_id: Option<String>,
@@ -151,6 +165,11 @@ pub enum Event {
     },
     /// Execute multi-hop swap
     ExecuteMultiHopSwap { operations: Vec<SwapOperation> },
+    /// Follow a cross-chain transfer through its IBC packet lifecycle
+    TrackTransfer {
+        tx_hash: String,
+        chain_id: Option<String>,
+    },
     /// Create a new pool (admin)
     CreatePool {
         asset_1: String,
@@ -201,8 +220,35 @@ pub enum Event {
         success: bool,
         error: Option<String>,
     },
+    /// Status change reported while following a cross-chain transfer
+    TransferStatusUpdate {
+        tx_hash: String,
+        status: String,
+        dest_tx_hash: Option<String>,
+        detail: Option<String>,
+        /// Tracking has stopped; no further updates follow
+        finished: bool,
+    },
+    /// Swap or liquidity change committed in a pool, delivered by the chain
+    /// event subscription
+    PoolActivity(crate::events::ChainEvent),
     /// Trigger simulation based on input changes
     TriggerSimulation,
+    /// Background swap simulation finished; `seq` identifies the form edit
+    /// it was started for so stale quotes can be dropped
+    SwapQuoteReady {
+        seq: u64,
+        quote: Result<crate::tui_dex::screens::swap::SwapQuote, String>,
+    },
+    /// A transaction of the persistent queue was confirmed, failed, resent
+    /// or escalated as stuck
+    TxQueueUpdate(crate::tx_queue::TxQueueUpdate),
+    /// Background zap simulation for the liquidity form finished; stale
+    /// results are dropped like swap quotes
+    LiquidityPreviewReady {
+        seq: u64,
+        preview: Result<crate::tui_dex::screens::liquidity::LiquidityPreview, String>,
+    },
 
     /// Retry swap with increased slippage tolerance
     RetryWithIncreasedSlippage,
@@ -727,28 +773,39 @@ impl AsyncBlockchainProcessor {
         amount_str: &str,
         denom: &str,
     ) -> Result<cosmwasm_std::Uint128, String> {
-        let amount_f64 = amount_str
-            .parse::<f64>()
-            .map_err(|e| format!("Invalid amount format: {}", e))?;
-
         // Most tokens on Mantra use 6 decimals as the standard
         // This includes OM (uom), USDC (factory tokens), and most other assets
         let decimals = self.get_token_decimals_for_denom(denom);
 
-        // Convert to micro amount
-        let micro_amount = (amount_f64 * 10_f64.powi(decimals as i32)) as u128;
+        // Convert to micro amount; "1.5" and "1.5 OM" are both accepted
+        let mut registry = crate::amount::DenomRegistry::native("uom");
+        registry.insert(crate::amount::Denomination::new(
+            denom,
+            crate::amount::symbol_for_denom(denom),
+            decimals,
+        ));
+        let micro_amount = crate::amount::Amount::parse_as(amount_str, denom, &registry)
+            .map_err(|e| format!("Invalid amount format: {}", e))?
+            .amount;
 
         crate::tui_dex::utils::logger::log_debug(&format!(
             "Amount conversion: {} {} -> {} micro units (10^{})",
             amount_str, denom, micro_amount, decimals
         ));
 
-        Ok(cosmwasm_std::Uint128::new(micro_amount))
+        Ok(micro_amount)
     }
 
     /// Get the number of decimal places for a given denomination
     /// Most Mantra network tokens use 6 decimals
     fn get_token_decimals_for_denom(&self, denom: &str) -> u8 {
+        if let Some(metadata) = self
+            .client
+            .as_ref()
+            .and_then(|client| client.denom_metadata_service().get(denom))
+        {
+            return metadata.decimals;
+        }
         match denom {
             // Native OM token
             "uom" => 6,
@@ -904,6 +961,317 @@ impl AsyncBlockchainProcessor {
         }
     }
 
+    /// Follow a cross-chain transfer, sending a status update for every transition
+    pub async fn track_transfer(&self, tx_hash: String, chain_id: Option<String>) {
+        use crate::protocols::skip::{SkipClient, TransferTrackingOptions};
+
+        let skip_client = match SkipClient::new(None).await {
+            Ok(client) => client,
+            Err(e) => {
+                let _ = self.event_sender.send(Event::TransferStatusUpdate {
+                    tx_hash,
+                    status: "error".to_string(),
+                    dest_tx_hash: None,
+                    detail: Some(format!("Failed to create Skip client: {}", e)),
+                    finished: true,
+                });
+                return;
+            }
+        };
+
+        let options = TransferTrackingOptions {
+            chain_id,
+            ..TransferTrackingOptions::default()
+        };
+        let sender = self.event_sender.clone();
+        let result = skip_client
+            .track_transfer_with(&tx_hash, options, |transition| {
+                let _ = sender.send(Event::TransferStatusUpdate {
+                    tx_hash: transition.tx_hash.clone(),
+                    status: format!("{:?}", transition.to),
+                    dest_tx_hash: transition.dest_tx_hash.clone(),
+                    detail: None,
+                    finished: transition.to.is_terminal(),
+                });
+            })
+            .await;
+
+        let final_update = match result {
+            Ok(tracking) if tracking.timed_out => Some(Event::TransferStatusUpdate {
+                tx_hash,
+                status: format!("{:?}", tracking.result.status),
+                dest_tx_hash: tracking.result.dest_tx_hash,
+                detail: Some(format!(
+                    "Stopped tracking after {} polls without a final status",
+                    tracking.polls
+                )),
+                finished: true,
+            }),
+            Ok(tracking) => {
+                tracking
+                    .result
+                    .error_message
+                    .map(|error| Event::TransferStatusUpdate {
+                        tx_hash,
+                        status: format!("{:?}", tracking.result.status),
+                        dest_tx_hash: tracking.result.dest_tx_hash,
+                        detail: Some(error),
+                        finished: true,
+                    })
+            }
+            Err(e) => Some(Event::TransferStatusUpdate {
+                tx_hash,
+                status: "error".to_string(),
+                dest_tx_hash: None,
+                detail: Some(e.to_string()),
+                finished: true,
+            }),
+        };
+        if let Some(update) = final_update {
+            let _ = self.event_sender.send(update);
+        }
+    }
+
+    /// Provide liquidity from a single asset asynchronously
+    ///
+    /// The zap is simulated first so the progress output shows the balancing
+    /// swap and expected LP tokens before the transaction is broadcast.
+    pub async fn zap_in(
+        &self,
+        pool_id: String,
+        asset_denom: String,
+        amount: String,
+        slippage_tolerance: Option<String>,
+    ) {
+        let operation = "zap_in".to_string();
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: operation.clone(),
+            status: "Preparing zap transaction...".to_string(),
+            progress: Some(0.1),
+        });
+
+        let result = self
+            .execute_zap_in_transaction(&pool_id, &asset_denom, &amount, slippage_tolerance)
+            .await;
+
+        match result {
+            Ok((preview, txhash)) => {
+                let _ = self.event_sender.send(Event::BlockchainSuccess {
+                    operation: operation.clone(),
+                    result: format!(
+                        "Successfully zapped {} into pool {}. Expected LP tokens: {}",
+                        self.format_token_amount(&preview.offer.amount, 6),
+                        pool_id,
+                        self.format_token_amount(&preview.expected_lp_tokens, 6)
+                    ),
+                    transaction_hash: Some(txhash),
+                    enhanced_data: serde_json::to_string(&preview).ok(),
+                });
+            }
+            Err(e) => {
+                let _ = self.event_sender.send(Event::BlockchainError {
+                    operation: operation.clone(),
+                    error: format!("Failed to zap into pool: {}", e),
+                });
+            }
+        }
+    }
+
+    async fn execute_zap_in_transaction(
+        &self,
+        pool_id: &str,
+        asset_denom: &str,
+        amount: &str,
+        slippage_tolerance: Option<String>,
+    ) -> Result<(crate::protocols::dex::types::ZapInPreview, String), String> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| "No blockchain client available for zap".to_string())?;
+
+        let (denom_1, denom_2) = self.get_pool_denominations_from_cache(pool_id).await?;
+        let denom = Self::match_pool_denom(asset_denom, &[denom_1, denom_2])?;
+        let offer = cosmwasm_std::Coin {
+            amount: self.convert_to_micro_amount(amount, &denom)?,
+            denom,
+        };
+        let slippage = Self::parse_slippage_percent(slippage_tolerance)?;
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: "zap_in".to_string(),
+            status: "Simulating balancing swap...".to_string(),
+            progress: Some(0.3),
+        });
+
+        let preview = client
+            .simulate_zap_in(pool_id, offer.clone(), slippage)
+            .await
+            .map_err(|e| format!("Zap simulation failed: {}", e))?;
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: "zap_in".to_string(),
+            status: format!(
+                "Swapping {} {} and depositing (expected LP: {})...",
+                preview.swap_offer.amount, preview.swap_offer.denom, preview.expected_lp_tokens
+            ),
+            progress: Some(0.6),
+        });
+
+        let (preview, tx_response) = client
+            .zap_in(pool_id, offer, slippage)
+            .await
+            .map_err(|e| format!("Blockchain transaction failed: {}", e))?;
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: "zap_in".to_string(),
+            status: "Transaction confirmed, processing results...".to_string(),
+            progress: Some(0.9),
+        });
+
+        Ok((preview, tx_response.txhash))
+    }
+
+    /// Withdraw liquidity into a single asset asynchronously
+    pub async fn zap_out(
+        &self,
+        pool_id: String,
+        lp_token_amount: String,
+        target_denom: String,
+        slippage_tolerance: Option<String>,
+    ) {
+        let operation = "zap_out".to_string();
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: operation.clone(),
+            status: "Preparing zap withdrawal...".to_string(),
+            progress: Some(0.1),
+        });
+
+        let result = self
+            .execute_zap_out_transaction(
+                &pool_id,
+                &lp_token_amount,
+                &target_denom,
+                slippage_tolerance,
+            )
+            .await;
+
+        match result {
+            Ok((preview, txhash)) => {
+                let _ = self.event_sender.send(Event::BlockchainSuccess {
+                    operation: operation.clone(),
+                    result: format!(
+                        "Successfully zapped out of pool {}. Expected {}: {}",
+                        pool_id,
+                        preview.target_denom,
+                        self.format_token_amount(&preview.expected_return, 6)
+                    ),
+                    transaction_hash: Some(txhash),
+                    enhanced_data: serde_json::to_string(&preview).ok(),
+                });
+            }
+            Err(e) => {
+                let _ = self.event_sender.send(Event::BlockchainError {
+                    operation: operation.clone(),
+                    error: format!("Failed to zap out of pool: {}", e),
+                });
+            }
+        }
+    }
+
+    async fn execute_zap_out_transaction(
+        &self,
+        pool_id: &str,
+        lp_token_amount: &str,
+        target_denom: &str,
+        slippage_tolerance: Option<String>,
+    ) -> Result<(crate::protocols::dex::types::ZapOutPreview, String), String> {
+        use std::str::FromStr;
+
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| "No blockchain client available for zap".to_string())?;
+
+        let lp_amount = cosmwasm_std::Uint128::from_str(lp_token_amount)
+            .map_err(|e| format!("Invalid LP token amount: {}", e))?;
+        let (denom_1, denom_2) = self.get_pool_denominations_from_cache(pool_id).await?;
+        let target = Self::match_pool_denom(target_denom, &[denom_1, denom_2])?;
+        let slippage = Self::parse_slippage_percent(slippage_tolerance)?;
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: "zap_out".to_string(),
+            status: "Simulating withdrawal and swap...".to_string(),
+            progress: Some(0.3),
+        });
+
+        let preview = client
+            .simulate_zap_out(pool_id, lp_amount, &target, slippage)
+            .await
+            .map_err(|e| format!("Zap simulation failed: {}", e))?;
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: "zap_out".to_string(),
+            status: format!(
+                "Withdrawing and swapping into {} (expected: {})...",
+                preview.target_denom, preview.expected_return
+            ),
+            progress: Some(0.6),
+        });
+
+        let (preview, tx_response) = client
+            .zap_out(pool_id, lp_amount, &target, slippage)
+            .await
+            .map_err(|e| format!("Blockchain transaction failed: {}", e))?;
+
+        let _ = self.event_sender.send(Event::BlockchainProgress {
+            operation: "zap_out".to_string(),
+            status: "Transaction confirmed, processing results...".to_string(),
+            progress: Some(0.9),
+        });
+
+        Ok((preview, tx_response.txhash))
+    }
+
+    /// Resolve user input (full denom or a case-insensitive suffix such as a
+    /// symbol) to one of the pool's denominations
+    pub(crate) fn match_pool_denom(input: &str, pool_denoms: &[String]) -> Result<String, String> {
+        let input = input.trim();
+        if let Some(denom) = pool_denoms.iter().find(|d| d.as_str() == input) {
+            return Ok(denom.clone());
+        }
+        let lower = input.to_lowercase();
+        pool_denoms
+            .iter()
+            .find(|d| !lower.is_empty() && d.to_lowercase().ends_with(&lower))
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "Asset '{}' is not part of the pool ({})",
+                    input,
+                    pool_denoms.join(", ")
+                )
+            })
+    }
+
+    /// Parse a slippage percentage (e.g. "1.0") into a decimal fraction
+    fn parse_slippage_percent(
+        slippage_tolerance: Option<String>,
+    ) -> Result<Option<cosmwasm_std::Decimal>, String> {
+        use std::str::FromStr;
+
+        slippage_tolerance
+            .map(|slippage_str| {
+                let slippage_percent = slippage_str
+                    .parse::<f64>()
+                    .map_err(|e| format!("Invalid slippage percentage: {}", e))?;
+                cosmwasm_std::Decimal::from_str(&(slippage_percent / 100.0).to_string())
+                    .map_err(|e| format!("Invalid slippage decimal conversion: {}", e))
+            })
+            .transpose()
+    }
+
     /// Claim rewards asynchronously
     pub async fn claim_rewards(
         &self,
@@ -1464,6 +1832,30 @@ impl EventHandler {
                         .await;
                 });
             }
+            Event::ZapIn {
+                pool_id,
+                asset_denom,
+                amount,
+                slippage_tolerance,
+            } => {
+                tokio::spawn(async move {
+                    processor
+                        .zap_in(pool_id, asset_denom, amount, slippage_tolerance)
+                        .await;
+                });
+            }
+            Event::ZapOut {
+                pool_id,
+                lp_token_amount,
+                target_denom,
+                slippage_tolerance,
+            } => {
+                tokio::spawn(async move {
+                    processor
+                        .zap_out(pool_id, lp_token_amount, target_denom, slippage_tolerance)
+                        .await;
+                });
+            }
             Event::ClaimRewards {
                 pool_id,
                 epochs,
@@ -1506,6 +1898,8 @@ impl EventHandler {
                 | Event::ExecuteSwapAsync { .. }
                 | Event::ProvideLiquidity { .. }
                 | Event::WithdrawLiquidity { .. }
+                | Event::ZapIn { .. }
+                | Event::ZapOut { .. }
                 | Event::ClaimRewards { .. }
                 | Event::ExecuteMultiHopSwap { .. }
                 | Event::CreatePool { .. }
@@ -1523,6 +1917,11 @@ impl EventHandler {
                 | Event::BlockchainError { .. }
                 | Event::BlockchainProgress { .. }
                 | Event::DataRefresh { .. }
+                | Event::TransferStatusUpdate { .. }
+                | Event::PoolActivity(_)
+                | Event::SwapQuoteReady { .. }
+                | Event::LiquidityPreviewReady { .. }
+                | Event::TxQueueUpdate(_)
         )
     }
 }
@@ -0,0 +1,437 @@
+//! ClaimDrop Screen Implementation
+//!
+//! This module provides the ClaimDrop campaign browser for the MANTRA DEX SDK
+//! TUI: the campaigns the active wallet has rewards in, with its allocation,
+//! claimed and claimable amounts, vesting progress of the selected campaign,
+//! and claiming from one campaign or from all of them in a single transaction.
+
+use crate::protocols::claimdrop::{CampaignReward, VestingSchedule};
+use crate::tui_dex::{
+    app::App,
+    components::{
+        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+    },
+};
+use cosmwasm_std::{Coin, Uint128};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+
+/// Width of the vesting bars in the campaign table
+const PROGRESS_BAR_WIDTH: usize = 10;
+
+/// A campaign the active wallet has rewards in
+#[derive(Debug, Clone)]
+pub struct ClaimdropCampaign {
+    pub address: String,
+    pub name: String,
+    pub is_active: bool,
+    pub rewards: CampaignReward,
+    /// `None` when the campaign has no allocation for the wallet or the
+    /// schedule could not be queried
+    pub schedule: Option<VestingSchedule>,
+}
+
+impl ClaimdropCampaign {
+    /// Total allocated to the wallet
+    pub fn allocation(&self) -> Option<Uint128> {
+        self.schedule
+            .as_ref()
+            .map(|schedule| schedule.total_allocation)
+    }
+
+    /// Amount available to claim now, summed over denoms
+    pub fn claimable(&self) -> Uint128 {
+        self.rewards
+            .available_to_claim
+            .iter()
+            .map(|coin| coin.amount)
+            .sum()
+    }
+
+    pub fn is_claimable(&self) -> bool {
+        !self.claimable().is_zero()
+    }
+
+    /// Share of the allocation unlocked at `timestamp`, from 0 to 1
+    pub fn vested_ratio(&self, timestamp: u64) -> f64 {
+        self.schedule.as_ref().map_or(0.0, |schedule| {
+            ratio(schedule.vested_at(timestamp), schedule.total_allocation)
+        })
+    }
+
+    /// Share of the allocation already claimed, from 0 to 1
+    pub fn claimed_ratio(&self) -> f64 {
+        self.schedule.as_ref().map_or(0.0, |schedule| {
+            ratio(schedule.claimed, schedule.total_allocation)
+        })
+    }
+}
+
+/// ClaimDrop screen state
+#[derive(Debug, Clone, Default)]
+pub struct ClaimdropState {
+    pub campaigns: Vec<ClaimdropCampaign>,
+    pub selected: usize,
+    /// Campaigns awaiting confirmation of a claim
+    pub pending_claim: Option<Vec<String>>,
+    pub loading: bool,
+    /// Whether the campaigns have been fetched
+    pub loaded: bool,
+}
+
+impl ClaimdropState {
+    pub fn selected_campaign(&self) -> Option<&ClaimdropCampaign> {
+        self.campaigns.get(self.selected)
+    }
+
+    /// Move the selection by `delta` within the list
+    pub fn move_selection(&mut self, delta: isize) {
+        self.selected = self
+            .selected
+            .saturating_add_signed(delta)
+            .min(self.campaigns.len().saturating_sub(1));
+    }
+
+    /// Replace the campaigns, keeping the selection on the same campaign
+    pub fn set_campaigns(&mut self, campaigns: Vec<ClaimdropCampaign>) {
+        let selected_address = self
+            .selected_campaign()
+            .map(|campaign| campaign.address.clone());
+        self.campaigns = campaigns;
+        self.selected = selected_address
+            .and_then(|address| {
+                self.campaigns
+                    .iter()
+                    .position(|campaign| campaign.address == address)
+            })
+            .unwrap_or(0);
+        self.loading = false;
+        self.loaded = true;
+    }
+
+    /// Addresses of every campaign with something to claim
+    pub fn claimable_addresses(&self) -> Vec<String> {
+        self.campaigns
+            .iter()
+            .filter(|campaign| campaign.is_claimable())
+            .map(|campaign| campaign.address.clone())
+            .collect()
+    }
+}
+
+fn ratio(part: Uint128, total: Uint128) -> f64 {
+    if total.is_zero() {
+        return 0.0;
+    }
+    (part.u128() as f64 / total.u128() as f64).clamp(0.0, 1.0)
+}
+
+/// Text progress bar such as `█████░░░░░ 50%`
+pub fn progress_bar(ratio: f64, width: usize) -> String {
+    let filled = ((ratio * width as f64).round() as usize).min(width);
+    format!(
+        "{}{} {:>3.0}%",
+        "█".repeat(filled),
+        "░".repeat(width - filled),
+        ratio * 100.0
+    )
+}
+
+fn format_coins(coins: &[Coin]) -> String {
+    let coins: Vec<String> = coins
+        .iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .map(|coin| format!("{}{}", coin.amount, coin.denom))
+        .collect();
+    if coins.is_empty() {
+        "0".to_string()
+    } else {
+        coins.join(" ")
+    }
+}
+
+fn format_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
+/// Render the complete ClaimDrop screen
+pub fn render_claimdrop(f: &mut Frame, app: &App) {
+    let size = f.area();
+
+    // Create main layout: header, nav, content, status
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Navigation
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Status bar
+        ])
+        .split(size);
+
+    render_header(f, &app.state, chunks[0]);
+    render_navigation(f, &app.state, chunks[1]);
+
+    // Create horizontal layout: campaign list | vesting details
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[2]);
+    render_campaign_table(f, content_chunks[0], &app.state.claimdrop_state);
+    render_campaign_details(f, content_chunks[1], &app.state.claimdrop_state);
+
+    render_status_bar(f, &app.state, chunks[3]);
+}
+
+/// Render the campaigns with the wallet's amounts
+fn render_campaign_table(f: &mut Frame, area: Rect, state: &ClaimdropState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .title(format!(
+            "ClaimDrop Campaigns{}",
+            if state.loading { " (loading...)" } else { "" }
+        ));
+
+    if state.campaigns.is_empty() {
+        let text = if state.loading {
+            "Loading campaigns..."
+        } else if state.loaded {
+            "The wallet has no ClaimDrop rewards"
+        } else {
+            "Connect a wallet and press r to load its campaigns"
+        };
+        f.render_widget(
+            Paragraph::new(text)
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("Campaign").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Allocation").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Claimed").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Claimable").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Vested").style(Style::default().add_modifier(Modifier::BOLD)),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    let rows: Vec<Row> = state
+        .campaigns
+        .iter()
+        .enumerate()
+        .map(|(index, campaign)| {
+            let style = if index == state.selected {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else if index % 2 == 0 {
+                Style::default()
+            } else {
+                Style::default().bg(Color::DarkGray)
+            };
+            let claimable_style = if campaign.is_claimable() {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(campaign.name.clone()),
+                Cell::from(
+                    campaign
+                        .allocation()
+                        .map(|amount| amount.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Cell::from(format_coins(&campaign.rewards.claimed)),
+                Cell::from(format_coins(&campaign.rewards.available_to_claim))
+                    .style(claimable_style),
+                Cell::from(progress_bar(campaign.vested_ratio(now), PROGRESS_BAR_WIDTH)),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(30), // Campaign
+            Constraint::Length(16),     // Allocation
+            Constraint::Length(18),     // Claimed
+            Constraint::Length(18),     // Claimable
+            Constraint::Length(16),     // Vested
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    f.render_widget(table, area);
+}
+
+/// Render the selected campaign's vesting progress
+fn render_campaign_details(f: &mut Frame, area: Rect, state: &ClaimdropState) {
+    let block = Block::default()
+        .title("Vesting")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let Some(campaign) = state.selected_campaign() else {
+        f.render_widget(
+            Paragraph::new("No campaign selected")
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    };
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Vested gauge
+            Constraint::Length(3), // Claimed gauge
+            Constraint::Min(0),    // Details
+        ])
+        .split(inner);
+
+    let now = chrono::Utc::now().timestamp().max(0) as u64;
+    let vested = campaign.vested_ratio(now);
+    let claimed = campaign.claimed_ratio();
+    f.render_widget(
+        Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title("Unlocked"))
+            .gauge_style(Style::default().fg(Color::Green))
+            .ratio(vested)
+            .label(format!("{:.1}%", vested * 100.0)),
+        chunks[0],
+    );
+    f.render_widget(
+        Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title("Claimed"))
+            .gauge_style(Style::default().fg(Color::Magenta))
+            .ratio(claimed)
+            .label(format!("{:.1}%", claimed * 100.0)),
+        chunks[1],
+    );
+
+    let label = Style::default().fg(Color::Yellow);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Address: ", label),
+            Span::raw(&campaign.address),
+        ]),
+        Line::from(vec![
+            Span::styled("Status: ", label),
+            Span::raw(if campaign.is_active {
+                "active"
+            } else {
+                "inactive"
+            }),
+        ]),
+        Line::from(vec![
+            Span::styled("Claimable: ", label),
+            Span::raw(format_coins(&campaign.rewards.available_to_claim)),
+        ]),
+        Line::from(vec![
+            Span::styled("Pending: ", label),
+            Span::raw(format_coins(&campaign.rewards.pending)),
+        ]),
+    ];
+    match &campaign.schedule {
+        Some(schedule) => {
+            lines.push(Line::from(vec![
+                Span::styled("Locked: ", label),
+                Span::raw(format!("{}{}", schedule.locked_at(now), schedule.denom)),
+            ]));
+            if let Some(next) = schedule.next_unlock_after(now) {
+                lines.push(Line::from(vec![
+                    Span::styled("Next unlock: ", label),
+                    Span::raw(format_timestamp(next)),
+                ]));
+            }
+            if let Some(end) = schedule.fully_vested_at() {
+                lines.push(Line::from(vec![
+                    Span::styled("Fully vested: ", label),
+                    Span::raw(format_timestamp(end)),
+                ]));
+            }
+            if let Some(closed) = schedule.closed_at {
+                lines.push(Line::from(Span::styled(
+                    format!("Campaign closed at {}", format_timestamp(closed)),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+        None => lines.push(Line::from(Span::styled(
+            "No vesting schedule for this wallet",
+            Style::default().fg(Color::Gray),
+        ))),
+    }
+
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::claimdrop::DistributionType;
+
+    fn campaign(address: &str, available: u128) -> ClaimdropCampaign {
+        let schedule = VestingSchedule::from_distribution(
+            address,
+            "mantra1wallet",
+            "uom",
+            Uint128::new(1_000),
+            Uint128::new(250),
+            &[DistributionType::LinearVesting {
+                percentage: cosmwasm_std::Decimal::one(),
+                start_time: 1_000,
+                end_time: 2_000,
+                cliff_duration: None,
+            }],
+            None,
+        );
+        ClaimdropCampaign {
+            address: address.to_string(),
+            name: address.to_string(),
+            is_active: true,
+            rewards: CampaignReward {
+                campaign_address: address.to_string(),
+                campaign_type: None,
+                claimed: vec![Coin::new(250u128, "uom")],
+                pending: Vec::new(),
+                available_to_claim: vec![Coin::new(available, "uom")],
+            },
+            schedule: Some(schedule),
+        }
+    }
+
+    #[test]
+    fn test_progress_and_claimable_campaigns() {
+        let mut state = ClaimdropState::default();
+        state.set_campaigns(vec![campaign("mantra1a", 0), campaign("mantra1b", 250)]);
+        assert_eq!(state.claimable_addresses(), vec!["mantra1b".to_string()]);
+
+        state.move_selection(1);
+        let selected = state.selected_campaign().unwrap();
+        assert_eq!(selected.vested_ratio(1_500), 0.5);
+        assert_eq!(selected.claimed_ratio(), 0.25);
+        assert_eq!(progress_bar(0.5, 10), "█████░░░░░  50%");
+
+        // The selection follows its campaign across refreshes
+        state.set_campaigns(vec![campaign("mantra1b", 0), campaign("mantra1c", 0)]);
+        assert_eq!(state.selected_campaign().unwrap().address, "mantra1b");
+    }
+}
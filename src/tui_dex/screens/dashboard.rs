@@ -72,8 +72,14 @@ fn render_dashboard_content(f: &mut Frame, area: Rect, app: &App) {
     // Render components with focus awareness
     render_overview_panel(f, top_chunks[0], app);
     render_quick_stats(f, top_chunks[1], app);
+    let network_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(middle_chunks[1]);
+
     render_token_balances(f, middle_chunks[0], app);
-    render_network_health(f, middle_chunks[1], app);
+    render_network_health(f, network_chunks[0], app);
+    render_network_balances(f, network_chunks[1], app);
     render_recent_transactions(f, main_chunks[2], app);
 
     // Render focus indicators for dashboard elements
@@ -560,6 +566,59 @@ fn render_network_health(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(status_paragraph, health_chunks[2]);
 }
 
+/// Render the wallet's balances on each compared network, side by side
+fn render_network_balances(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .title("Balances Across Networks")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue));
+
+    let Some(report) = &app.state.network_balances else {
+        let paragraph = Paragraph::new("Refresh to compare networks")
+            .block(block)
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, area);
+        return;
+    };
+
+    let items: Vec<ListItem> = report
+        .results
+        .iter()
+        .map(|result| {
+            let (summary, style) = match (result.value(), result.error()) {
+                (Some(balances), _) => (
+                    format!("{} OM, {} tokens", native_balance(balances), balances.len()),
+                    Style::default().fg(Color::Green),
+                ),
+                (None, Some(error)) => (error, Style::default().fg(Color::Red)),
+                (None, None) => (String::new(), Style::default()),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{}: ", result.network),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(summary, style),
+            ]))
+        })
+        .collect();
+
+    f.render_widget(List::new(items).block(block), area);
+}
+
+/// OM held among `balances`, in whole tokens
+fn native_balance(balances: &[cosmwasm_std::Coin]) -> String {
+    let uom = balances
+        .iter()
+        .find(|coin| coin.denom == "uom")
+        .map(|coin| coin.amount.u128())
+        .unwrap_or(0);
+    format!("{:.2}", uom as f64 / 1_000_000.0)
+}
+
 /// Calculate total portfolio value from balances
 fn calculate_total_portfolio_value(balances: &HashMap<String, String>) -> f64 {
     // In a real implementation, you would fetch current prices and calculate
@@ -0,0 +1,324 @@
+//! History Screen Implementation
+//!
+//! This module provides the on-chain transaction history view for the
+//! MANTRA DEX SDK TUI: past transactions of the active wallet with decoded
+//! swap, liquidity and claim summaries, status and fees, an inspect pane with
+//! the selected transaction's raw log or events, and CSV export.
+
+use crate::tui_dex::{
+    app::App,
+    components::{
+        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+    },
+};
+use crate::tx_history::{to_csv, TxHistoryEntry};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+use std::path::{Path, PathBuf};
+
+/// What the inspect pane shows of the selected transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InspectView {
+    #[default]
+    Log,
+    Events,
+}
+
+/// History screen state
+#[derive(Debug, Clone)]
+pub struct HistoryState {
+    /// Transactions of the current page, newest first
+    pub entries: Vec<TxHistoryEntry>,
+    /// Page shown, from 1
+    pub page: u32,
+    pub selected: usize,
+    pub inspect: InspectView,
+    pub loading: bool,
+    /// Whether the current page has been fetched
+    pub loaded: bool,
+}
+
+impl Default for HistoryState {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            page: 1,
+            selected: 0,
+            inspect: InspectView::default(),
+            loading: false,
+            loaded: false,
+        }
+    }
+}
+
+impl HistoryState {
+    pub fn selected_entry(&self) -> Option<&TxHistoryEntry> {
+        self.entries.get(self.selected)
+    }
+
+    /// Move the selection by `delta` within the page
+    pub fn move_selection(&mut self, delta: isize) {
+        self.selected = self
+            .selected
+            .saturating_add_signed(delta)
+            .min(self.entries.len().saturating_sub(1));
+    }
+
+    /// Replace the page's transactions
+    pub fn set_entries(&mut self, entries: Vec<TxHistoryEntry>) {
+        self.entries = entries;
+        self.selected = 0;
+        self.loading = false;
+        self.loaded = true;
+    }
+
+    pub fn toggle_inspect(&mut self) {
+        self.inspect = match self.inspect {
+            InspectView::Log => InspectView::Events,
+            InspectView::Events => InspectView::Log,
+        };
+    }
+
+    /// Write the page's transactions as CSV into `dir`, returning the file
+    pub fn export_csv(&self, dir: &Path, address: &str) -> std::io::Result<PathBuf> {
+        let path = dir.join(format!(
+            "mantra-history-{}-p{}-{}.csv",
+            address.get(..14).unwrap_or(address),
+            self.page,
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::write(&path, to_csv(&self.entries))?;
+        Ok(path)
+    }
+}
+
+/// Render the complete history screen
+pub fn render_history(f: &mut Frame, app: &App) {
+    let size = f.area();
+
+    // Create main layout: header, nav, content, status
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Navigation
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Status bar
+        ])
+        .split(size);
+
+    render_header(f, &app.state, chunks[0]);
+    render_navigation(f, &app.state, chunks[1]);
+
+    // Create horizontal layout: transaction list | inspect pane
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[2]);
+    render_history_table(f, content_chunks[0], &app.state.history_state);
+    render_inspect_pane(f, content_chunks[1], &app.state.history_state);
+
+    render_status_bar(f, &app.state, chunks[3]);
+}
+
+/// Render the page of transactions
+fn render_history_table(f: &mut Frame, area: Rect, state: &HistoryState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .title(format!(
+            "Transaction History (page {}{})",
+            state.page,
+            if state.loading { ", loading..." } else { "" }
+        ));
+
+    if state.entries.is_empty() {
+        let text = if state.loading {
+            "Loading transactions..."
+        } else if state.loaded {
+            "No transactions on this page"
+        } else {
+            "Connect a wallet and press r to load its transactions"
+        };
+        f.render_widget(
+            Paragraph::new(text)
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("Time").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Action").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Summary").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Status").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Fee").style(Style::default().add_modifier(Modifier::BOLD)),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let rows: Vec<Row> = state
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let style = if index == state.selected {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else if index % 2 == 0 {
+                Style::default()
+            } else {
+                Style::default().bg(Color::DarkGray)
+            };
+            let time = entry
+                .timestamp
+                .map(|time| time.format("%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| format!("#{}", entry.height));
+            let (status, status_color) = if entry.success {
+                ("OK".to_string(), Color::Green)
+            } else {
+                (format!("Failed ({})", entry.code), Color::Red)
+            };
+
+            Row::new(vec![
+                Cell::from(time),
+                Cell::from(entry.action_names()),
+                Cell::from(entry.actions.join("; ")),
+                Cell::from(status).style(Style::default().fg(status_color)),
+                Cell::from(entry.fee_text()),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),     // Time
+            Constraint::Length(16),     // Action
+            Constraint::Percentage(50), // Summary
+            Constraint::Length(12),     // Status
+            Constraint::Length(14),     // Fee
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    f.render_widget(table, area);
+}
+
+/// Render the selected transaction's details with its raw log or events
+fn render_inspect_pane(f: &mut Frame, area: Rect, state: &HistoryState) {
+    let title = match state.inspect {
+        InspectView::Log => "Inspect: raw log (l: events)",
+        InspectView::Events => "Inspect: events (l: raw log)",
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let Some(entry) = state.selected_entry() else {
+        f.render_widget(
+            Paragraph::new("No transaction selected")
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    };
+
+    let label = Style::default().fg(Color::Yellow);
+    let mut lines = vec![
+        Line::from(vec![Span::styled("Hash: ", label), Span::raw(&entry.hash)]),
+        Line::from(vec![
+            Span::styled("Height: ", label),
+            Span::raw(entry.height.to_string()),
+            Span::styled("  Time: ", label),
+            Span::raw(
+                entry
+                    .timestamp
+                    .map(|time| time.to_rfc3339())
+                    .unwrap_or_else(|| "unknown".to_string()),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Gas: ", label),
+            Span::raw(format!("{} / {}", entry.gas_used, entry.gas_wanted)),
+            Span::styled("  Fee: ", label),
+            Span::raw(entry.fee_text()),
+        ]),
+        Line::from(Span::styled(entry.narrative.as_str(), Style::default())),
+        Line::from(""),
+    ];
+    match state.inspect {
+        InspectView::Log => {
+            let log = if entry.raw_log.is_empty() {
+                "(empty log)"
+            } else {
+                entry.raw_log.as_str()
+            };
+            lines.extend(log.lines().map(|line| Line::from(line.to_string())));
+        }
+        InspectView::Events => {
+            for event in &entry.events {
+                lines.push(Line::from(Span::styled(
+                    event.kind.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )));
+                for (key, value) in &event.attributes {
+                    lines.push(Line::from(format!("  {} = {}", key, value)));
+                }
+            }
+        }
+    }
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hash: &str) -> TxHistoryEntry {
+        TxHistoryEntry {
+            hash: hash.to_string(),
+            height: 42,
+            timestamp: None,
+            success: true,
+            code: 0,
+            fee: Vec::new(),
+            gas_wanted: 200_000,
+            gas_used: 150_000,
+            messages: Vec::new(),
+            actions: Vec::new(),
+            narrative: String::new(),
+            raw_log: String::new(),
+            events: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_selection_and_csv_export() {
+        let mut state = HistoryState::default();
+        state.set_entries(vec![entry("AA"), entry("BB")]);
+        state.move_selection(5);
+        assert_eq!(state.selected_entry().unwrap().hash, "BB");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = state.export_csv(dir.path(), "mantra1wallet").unwrap();
+        let csv = std::fs::read_to_string(path).unwrap();
+        assert_eq!(csv.lines().count(), 3);
+        assert!(csv.lines().nth(2).unwrap().starts_with("BB,42,"));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! This module provides the liquidity management interface for the MANTRA DEX SDK TUI,
 //! allowing users to provide liquidity to pools, withdraw liquidity, view current positions,
-//! and analyze position performance with PnL calculations.
+//! and see the share, underlying assets and fees earned of each position.
 
 use crate::tui_dex::{
     app::{App, LoadingState},
@@ -41,9 +41,23 @@ pub enum LiquidityInputFocus {
     SecondAssetAmount,
     SlippageAmount,
     WithdrawAmount,
+    ZapAsset,
     Execute,
 }
 
+/// Simulated outcome of the provide or withdraw form
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiquidityPreview {
+    Provide {
+        lp_tokens: Uint128,
+        note: Option<String>,
+    },
+    Withdraw {
+        assets: Vec<cosmwasm_std::Coin>,
+        note: Option<String>,
+    },
+}
+
 /// Current liquidity position information
 #[derive(Debug, Clone)]
 pub struct LiquidityPosition {
@@ -59,6 +73,15 @@ pub struct LiquidityPosition {
     pub second_asset_amount: Uint128,
     pub first_asset_denom: String,
     pub second_asset_denom: String,
+    /// Underlying amounts earned from swap fees, unknown until the entry is tracked
+    pub earned_fees: Option<Vec<cosmwasm_std::Coin>>,
+}
+
+impl LiquidityPosition {
+    /// Whether the wallet holds LP tokens of the pool
+    pub fn is_held(&self) -> bool {
+        !self.lp_token_amount.is_zero()
+    }
 }
 
 /// Liquidity screen state (simplified like swap screen)
@@ -78,6 +101,8 @@ pub struct LiquidityScreenState {
     pub slippage_input: TextInput,
     /// LP token amount input (for withdrawing liquidity)
     pub withdraw_amount_input: TextInput,
+    /// Single asset to zap in from / out to (optional)
+    pub zap_asset_input: TextInput,
 
     /// Available pools for liquidity operations
     pub available_pools: Vec<(String, String)>, // (pool_id, display_name)
@@ -95,6 +120,12 @@ pub struct LiquidityScreenState {
     pub current_pool_reserves: Option<Vec<(Uint128, String)>>, // Vec of (reserve_amount, denom)
     /// Flag to prevent infinite loops during proportional calculation
     pub updating_proportional_amount: bool,
+    /// Whether editing one provide amount fills the other at the pool ratio
+    pub ratio_locked: bool,
+    /// Zap simulation detail or error shown in the previews
+    pub simulation_note: Option<String>,
+    /// Bumped on every form edit so stale zap simulations are dropped
+    pub simulation_seq: u64,
 }
 
 impl Default for LiquidityScreenState {
@@ -121,6 +152,10 @@ impl Default for LiquidityScreenState {
             .required()
             .with_placeholder("0.0");
 
+        let zap_asset_input = TextInput::new("Single Asset (zap, optional)")
+            .with_type(InputType::Text)
+            .with_placeholder("e.g. OM or uom");
+
         let mut instance = Self {
             mode: LiquidityMode::Provide,
             input_focus: LiquidityInputFocus::Pool,
@@ -129,6 +164,7 @@ impl Default for LiquidityScreenState {
             second_asset_input,
             slippage_input,
             withdraw_amount_input,
+            zap_asset_input,
             available_pools: Vec::new(),
             positions: Vec::new(),
             selected_position: None,
@@ -137,6 +173,9 @@ impl Default for LiquidityScreenState {
             last_input_change: None,
             current_pool_reserves: None,
             updating_proportional_amount: false,
+            ratio_locked: true,
+            simulation_note: None,
+            simulation_seq: 0,
         };
 
         // Apply initial focus
@@ -206,14 +245,16 @@ impl LiquidityScreenState {
             LiquidityMode::Provide => match self.input_focus {
                 LiquidityInputFocus::Pool => LiquidityInputFocus::FirstAssetAmount,
                 LiquidityInputFocus::FirstAssetAmount => LiquidityInputFocus::SecondAssetAmount,
-                LiquidityInputFocus::SecondAssetAmount => LiquidityInputFocus::SlippageAmount,
+                LiquidityInputFocus::SecondAssetAmount => LiquidityInputFocus::ZapAsset,
+                LiquidityInputFocus::ZapAsset => LiquidityInputFocus::SlippageAmount,
                 LiquidityInputFocus::SlippageAmount => LiquidityInputFocus::Execute,
                 LiquidityInputFocus::Execute => LiquidityInputFocus::Pool,
                 _ => LiquidityInputFocus::Pool,
             },
             LiquidityMode::Withdraw => match self.input_focus {
                 LiquidityInputFocus::Pool => LiquidityInputFocus::WithdrawAmount,
-                LiquidityInputFocus::WithdrawAmount => LiquidityInputFocus::Execute,
+                LiquidityInputFocus::WithdrawAmount => LiquidityInputFocus::ZapAsset,
+                LiquidityInputFocus::ZapAsset => LiquidityInputFocus::Execute,
                 LiquidityInputFocus::Execute => LiquidityInputFocus::Pool,
                 _ => LiquidityInputFocus::Pool,
             },
@@ -230,14 +271,16 @@ impl LiquidityScreenState {
                 LiquidityInputFocus::Pool => LiquidityInputFocus::Execute,
                 LiquidityInputFocus::FirstAssetAmount => LiquidityInputFocus::Pool,
                 LiquidityInputFocus::SecondAssetAmount => LiquidityInputFocus::FirstAssetAmount,
-                LiquidityInputFocus::SlippageAmount => LiquidityInputFocus::SecondAssetAmount,
+                LiquidityInputFocus::ZapAsset => LiquidityInputFocus::SecondAssetAmount,
+                LiquidityInputFocus::SlippageAmount => LiquidityInputFocus::ZapAsset,
                 LiquidityInputFocus::Execute => LiquidityInputFocus::SlippageAmount,
                 _ => LiquidityInputFocus::Execute,
             },
             LiquidityMode::Withdraw => match self.input_focus {
                 LiquidityInputFocus::Pool => LiquidityInputFocus::Execute,
                 LiquidityInputFocus::WithdrawAmount => LiquidityInputFocus::Pool,
-                LiquidityInputFocus::Execute => LiquidityInputFocus::WithdrawAmount,
+                LiquidityInputFocus::ZapAsset => LiquidityInputFocus::WithdrawAmount,
+                LiquidityInputFocus::Execute => LiquidityInputFocus::ZapAsset,
                 _ => LiquidityInputFocus::Execute,
             },
             LiquidityMode::Positions => LiquidityInputFocus::Pool, // No navigation in positions mode
@@ -253,6 +296,7 @@ impl LiquidityScreenState {
         self.second_asset_input.set_focused(false);
         self.slippage_input.set_focused(false);
         self.withdraw_amount_input.set_focused(false);
+        self.zap_asset_input.set_focused(false);
     }
 
     /// Public wrapper to clear all focus states (used by external modules)
@@ -268,6 +312,7 @@ impl LiquidityScreenState {
             LiquidityInputFocus::SecondAssetAmount => self.second_asset_input.set_focused(true),
             LiquidityInputFocus::SlippageAmount => self.slippage_input.set_focused(true),
             LiquidityInputFocus::WithdrawAmount => self.withdraw_amount_input.set_focused(true),
+            LiquidityInputFocus::ZapAsset => self.zap_asset_input.set_focused(true),
             LiquidityInputFocus::Execute => {} // Button focus handled separately
         }
     }
@@ -303,7 +348,7 @@ impl LiquidityScreenState {
         }
 
         // Only calculate for provide mode with valid pool reserves
-        if self.mode != LiquidityMode::Provide {
+        if self.mode != LiquidityMode::Provide || !self.ratio_locked {
             return;
         }
 
@@ -396,6 +441,42 @@ impl LiquidityScreenState {
         self.updating_proportional_amount = false;
     }
 
+    /// Show a simulated outcome, or clear the preview when there is none
+    pub fn apply_preview(&mut self, preview: Option<LiquidityPreview>) {
+        self.expected_lp_tokens = None;
+        self.expected_assets = None;
+        self.simulation_note = None;
+        match preview {
+            Some(LiquidityPreview::Provide { lp_tokens, note }) => {
+                self.expected_lp_tokens = Some(lp_tokens);
+                self.simulation_note = note;
+            }
+            Some(LiquidityPreview::Withdraw { assets, note }) => {
+                let amount = |i: usize| {
+                    assets
+                        .get(i)
+                        .map(|coin| (coin.amount, coin.denom.clone()))
+                        .unwrap_or_default()
+                };
+                let ((amount1, denom1), (amount2, denom2)) = (amount(0), amount(1));
+                self.expected_assets = Some((amount1, amount2, denom1, denom2));
+                self.simulation_note = note;
+            }
+            None => {}
+        }
+    }
+
+    /// Lock or unlock the provide amounts to the pool ratio
+    ///
+    /// Locking recomputes the other amount from the field being edited.
+    pub fn toggle_ratio_lock(&mut self, from_field: LiquidityInputFocus) {
+        self.ratio_locked = !self.ratio_locked;
+        if self.ratio_locked {
+            self.calculate_proportional_amount(from_field);
+        }
+        self.mark_input_change();
+    }
+
     /// Find reserves for specific tokens in the reserve list
     fn find_reserves_for_tokens(
         &self,
@@ -524,6 +605,10 @@ impl LiquidityScreenState {
                 list_event != ListEvent::Ignored
             }
             LiquidityInputFocus::FirstAssetAmount => {
+                if matches!(key.code, KeyCode::Char('l') | KeyCode::Char('L')) {
+                    self.toggle_ratio_lock(LiquidityInputFocus::FirstAssetAmount);
+                    return true;
+                }
                 let input_request = match key.code {
                     KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
                     KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
@@ -546,6 +631,10 @@ impl LiquidityScreenState {
                 false
             }
             LiquidityInputFocus::SecondAssetAmount => {
+                if matches!(key.code, KeyCode::Char('l') | KeyCode::Char('L')) {
+                    self.toggle_ratio_lock(LiquidityInputFocus::SecondAssetAmount);
+                    return true;
+                }
                 let input_request = match key.code {
                     KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
                     KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
@@ -607,6 +696,26 @@ impl LiquidityScreenState {
                 }
                 false
             }
+            LiquidityInputFocus::ZapAsset => {
+                let input_request = match key.code {
+                    KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
+                    KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
+                    KeyCode::Delete => Some(InputRequest::DeleteNextChar),
+                    KeyCode::Left => Some(InputRequest::GoToPrevChar),
+                    KeyCode::Right => Some(InputRequest::GoToNextChar),
+                    KeyCode::Home => Some(InputRequest::GoToStart),
+                    KeyCode::End => Some(InputRequest::GoToEnd),
+                    _ => None,
+                };
+
+                if let Some(request) = input_request {
+                    if self.zap_asset_input.handle_input(request).is_some() {
+                        self.mark_input_change();
+                        return true;
+                    }
+                }
+                false
+            }
             LiquidityInputFocus::Execute => {
                 // Handle execute button activation
                 match key.code {
@@ -651,6 +760,10 @@ impl LiquidityScreenState {
                 self.withdraw_amount_input.handle_input(input);
                 true
             }
+            LiquidityInputFocus::ZapAsset => {
+                self.zap_asset_input.handle_input(input);
+                true
+            }
             _ => false,
         }
     }
@@ -688,7 +801,14 @@ impl LiquidityScreenState {
                 let second_valid = self.second_asset_input.validate();
                 let slippage_valid = self.slippage_input.validate();
 
-                pool_valid && first_valid && second_valid && slippage_valid
+                // Zapping only needs the amount of the single asset being deposited
+                let amounts_valid = if self.zap_asset().is_some() {
+                    !self.zap_in_amount().is_empty()
+                } else {
+                    first_valid && second_valid
+                };
+
+                pool_valid && amounts_valid && slippage_valid
             }
             LiquidityMode::Withdraw => {
                 let pool_valid = self.pool_dropdown.get_selected_value().is_some();
@@ -710,7 +830,11 @@ impl LiquidityScreenState {
                     errors.push("Please select a liquidity pool".to_string());
                 }
 
-                if !self.first_asset_input.validate() {
+                if self.zap_asset().is_some() {
+                    if self.zap_in_amount().is_empty() {
+                        errors.push("Please enter the amount of the zap asset".to_string());
+                    }
+                } else if !self.first_asset_input.validate() {
                     if self.first_asset_input.value().is_empty() {
                         errors.push("Please enter first asset amount".to_string());
                     } else {
@@ -718,7 +842,7 @@ impl LiquidityScreenState {
                     }
                 }
 
-                if !self.second_asset_input.validate() {
+                if self.zap_asset().is_none() && !self.second_asset_input.validate() {
                     if self.second_asset_input.value().is_empty() {
                         errors.push("Please enter second asset amount".to_string());
                     } else {
@@ -751,6 +875,30 @@ impl LiquidityScreenState {
         errors
     }
 
+    /// Single asset to zap with, if one was entered
+    pub fn zap_asset(&self) -> Option<&str> {
+        let asset = self.zap_asset_input.value().trim();
+        (!asset.is_empty()).then_some(asset)
+    }
+
+    /// Amount deposited when zapping in
+    ///
+    /// Uses the second amount field when the zap asset names the pool's second
+    /// token, otherwise the first.
+    pub fn zap_in_amount(&self) -> String {
+        let pool_label = self.pool_dropdown.get_selected_label().unwrap_or_default();
+        let (_, second_asset) = extract_assets_from_pool_label(pool_label);
+        let use_second = self
+            .zap_asset()
+            .is_some_and(|asset| asset.eq_ignore_ascii_case(&second_asset));
+        let amount = if use_second {
+            self.second_asset_input.value()
+        } else {
+            self.first_asset_input.value()
+        };
+        amount.trim().to_string()
+    }
+
     /// Show confirmation modal using global app state (like swap screen)
     pub fn show_confirmation_modal(
         &mut self,
@@ -906,6 +1054,7 @@ fn render_provide_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(8), // Pool selection (taller for SimpleList)
             Constraint::Length(5), // First asset (proper height for text input)
             Constraint::Length(5), // Second asset (proper height for text input)
+            Constraint::Length(5), // Zap asset (proper height for text input)
             Constraint::Length(5), // Slippage (proper height for text input)
             Constraint::Length(5), // Execute button (proper height for button)
             Constraint::Min(0),    // Spacer
@@ -915,7 +1064,11 @@ fn render_provide_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue))
-        .title("Provide Liquidity");
+        .title(if liquidity_state.ratio_locked {
+            "Provide Liquidity [ratio locked, l to unlock]"
+        } else {
+            "Provide Liquidity [ratio unlocked, l to lock]"
+        });
     f.render_widget(block, area);
 
     // Render input fields in order
@@ -928,11 +1081,14 @@ fn render_provide_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
     // Render second asset input with balance display (like swap screen)
     render_second_asset_input_with_balance(f, chunks[2], app);
 
+    // Render zap asset input (single-asset deposit when set)
+    liquidity_state.zap_asset_input.render(f, chunks[3]);
+
     // Render slippage input
-    liquidity_state.slippage_input.render(f, chunks[3]);
+    liquidity_state.slippage_input.render(f, chunks[4]);
 
     // Render execute button (fixed like swap screen)
-    render_provide_execute_button(f, chunks[4], app);
+    render_provide_execute_button(f, chunks[5], app);
 }
 
 /// Helper function to render asset input with balance display
@@ -1221,13 +1377,15 @@ fn render_provide_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    let preview_text = if liquidity_state.first_asset_input.value().is_empty()
-        || liquidity_state.second_asset_input.value().is_empty()
+    let preview_text = if (liquidity_state.first_asset_input.value().is_empty()
+        && liquidity_state.second_asset_input.value().is_empty())
         || liquidity_state.pool_dropdown.get_selected_value().is_none()
     {
         "Enter amounts and select pool to see preview"
+    } else if liquidity_state.zap_asset().is_some() {
+        "Single-sided deposit (zap), simulated on chain"
     } else {
-        "Calculating expected LP tokens..."
+        "Two-sided deposit at the current pool reserves"
     };
 
     let expected_lp = liquidity_state
@@ -1246,13 +1404,16 @@ fn render_provide_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
         .get_selected_label()
         .unwrap_or("No pool selected");
 
-    let preview_content = format!(
+    let mut preview_content = format!(
         "{}\n\n{}\n\nPool: {}\n\nSlippage Tolerance: {}%",
         preview_text,
         expected_lp,
         pool_name,
         liquidity_state.slippage_input.value(),
     );
+    if let Some(note) = &liquidity_state.simulation_note {
+        preview_content.push_str(&format!("\n\n{}", note));
+    }
 
     let paragraph = Paragraph::new(preview_content)
         .style(Style::default().fg(Color::White))
@@ -1285,6 +1446,7 @@ fn render_withdraw_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
         .constraints([
             Constraint::Length(8), // Pool selection (taller for SimpleList)
             Constraint::Length(5), // LP token amount (proper height for text input)
+            Constraint::Length(5), // Zap asset (proper height for text input)
             Constraint::Length(5), // Execute button (proper height for button)
             Constraint::Min(0),    // Spacer
         ])
@@ -1302,8 +1464,11 @@ fn render_withdraw_liquidity_form(f: &mut Frame, area: Rect, app: &App) {
 
     liquidity_state.withdraw_amount_input.render(f, chunks[1]);
 
+    // Render zap asset input (receive a single asset when set)
+    liquidity_state.zap_asset_input.render(f, chunks[2]);
+
     // Render execute button (fixed like swap screen)
-    render_withdraw_execute_button(f, chunks[2], app);
+    render_withdraw_execute_button(f, chunks[3], app);
 }
 
 /// Render the withdraw liquidity preview
@@ -1322,8 +1487,10 @@ fn render_withdraw_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
         || liquidity_state.pool_dropdown.get_selected_value().is_none()
     {
         "Enter LP token amount and select pool"
+    } else if liquidity_state.zap_asset().is_some() {
+        "Withdrawal to a single asset (zap), simulated on chain"
     } else {
-        "Calculating expected assets..."
+        "Withdrawal at the current pool reserves"
     };
 
     let expected_assets = liquidity_state
@@ -1345,10 +1512,13 @@ fn render_withdraw_liquidity_preview(f: &mut Frame, area: Rect, _app: &App) {
         .get_selected_label()
         .unwrap_or("No pool selected");
 
-    let preview_content = format!(
+    let mut preview_content = format!(
         "{}\n\n{}\n\nPool: {}",
         preview_text, expected_assets, pool_name,
     );
+    if let Some(note) = &liquidity_state.simulation_note {
+        preview_content.push_str(&format!("\n\n{}", note));
+    }
 
     let paragraph = Paragraph::new(preview_content)
         .style(Style::default().fg(Color::White))
@@ -1378,11 +1548,11 @@ fn render_positions_table(f: &mut Frame, area: Rect, _app: &App) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Blue))
-        .title("Current Positions");
+        .title("Pools & Positions");
 
     if liquidity_state.positions.is_empty() {
         let empty_msg =
-            Paragraph::new("No liquidity positions found\nProvide liquidity to see positions here")
+            Paragraph::new("No pools loaded yet\nPools and your positions in them appear here")
                 .style(Style::default().fg(Color::Gray))
                 .block(block)
                 .wrap(Wrap { trim: true });
@@ -1395,7 +1565,7 @@ fn render_positions_table(f: &mut Frame, area: Rect, _app: &App) {
         Cell::from("Asset Pair").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("LP Tokens").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Value (USD)").style(Style::default().add_modifier(Modifier::BOLD)),
-        Cell::from("PnL").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Fees Earned").style(Style::default().add_modifier(Modifier::BOLD)),
         Cell::from("Share %").style(Style::default().add_modifier(Modifier::BOLD)),
     ])
     .style(Style::default().bg(Color::DarkGray));
@@ -1405,23 +1575,32 @@ fn render_positions_table(f: &mut Frame, area: Rect, _app: &App) {
         .iter()
         .enumerate()
         .map(|(index, position)| {
-            let pnl_color = if position.pnl_percentage >= 0.0 {
-                Color::Green
+            let mut row = if position.is_held() {
+                let fees_text = if position.earned_fees.is_some() {
+                    format!("{:.2}% (${:.2})", position.pnl_percentage, position.pnl_usd)
+                } else {
+                    "tracking".to_string()
+                };
+                Row::new(vec![
+                    Cell::from(position.pool_id.clone()),
+                    Cell::from(position.asset_pair.clone()),
+                    Cell::from(format_large_number(&position.lp_token_amount.to_string())),
+                    Cell::from(format!("${:.2}", position.estimated_value_usd)),
+                    Cell::from(fees_text).style(Style::default().fg(Color::Green)),
+                    Cell::from(format!("{:.4}%", position.share_percentage)),
+                ])
             } else {
-                Color::Red
+                Row::new(vec![
+                    Cell::from(position.pool_id.clone()),
+                    Cell::from(position.asset_pair.clone()),
+                    Cell::from("-"),
+                    Cell::from("-"),
+                    Cell::from("-"),
+                    Cell::from("-"),
+                ])
+                .style(Style::default().fg(Color::Gray))
             };
 
-            let pnl_text = format!("{:.2}% (${:.2})", position.pnl_percentage, position.pnl_usd);
-
-            let mut row = Row::new(vec![
-                Cell::from(position.pool_id.clone()),
-                Cell::from(position.asset_pair.clone()),
-                Cell::from(format_large_number(&position.lp_token_amount.to_string())),
-                Cell::from(format!("${:.2}", position.estimated_value_usd)),
-                Cell::from(pnl_text).style(Style::default().fg(pnl_color)),
-                Cell::from(format!("{:.2}%", position.share_percentage)),
-            ]);
-
             // Highlight selected row
             if Some(index) == liquidity_state.selected_position {
                 row = row.style(Style::default().add_modifier(Modifier::REVERSED));
@@ -1459,20 +1638,42 @@ fn render_position_details(f: &mut Frame, area: Rect, _app: &App) {
 
     if let Some(selected_index) = liquidity_state.selected_position {
         if let Some(position) = liquidity_state.positions.get(selected_index) {
-            let details = format!(
-                "Pool ID: {}\n\nAsset Composition:\n• {}: {}\n• {}: {}\n\nPerformance:\n• Initial Value: ${:.2}\n• Current Value: ${:.2}\n• PnL: {:.2}% (${:.2})\n• Pool Share: {:.2}%\n\nLP Token Balance: {}",
-                position.pool_id,
-                position.first_asset_denom,
-                format_large_number(&position.first_asset_amount.to_string()),
-                position.second_asset_denom,
-                format_large_number(&position.second_asset_amount.to_string()),
-                position.initial_value_usd,
-                position.estimated_value_usd,
-                position.pnl_percentage,
-                position.pnl_usd,
-                position.share_percentage,
-                format_large_number(&position.lp_token_amount.to_string()),
-            );
+            let earned_fees = match &position.earned_fees {
+                Some(fees) => fees
+                    .iter()
+                    .map(|coin| {
+                        format!(
+                            "• {}: {}",
+                            coin.denom,
+                            format_large_number(&coin.amount.to_string())
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                None => "• Tracking from now on (entry not recorded yet)".to_string(),
+            };
+            let details = if position.is_held() {
+                format!(
+                    "Pool ID: {}\n\nUnderlying Assets:\n• {}: {}\n• {}: {}\n\nFees Earned:\n{}\n\nPerformance:\n• Value Without Fees: ${:.2}\n• Current Value: ${:.2}\n• Fees: {:.2}% (${:.2})\n• Pool Share: {:.4}%\n\nLP Token Balance: {}",
+                    position.pool_id,
+                    position.first_asset_denom,
+                    format_large_number(&position.first_asset_amount.to_string()),
+                    position.second_asset_denom,
+                    format_large_number(&position.second_asset_amount.to_string()),
+                    earned_fees,
+                    position.initial_value_usd,
+                    position.estimated_value_usd,
+                    position.pnl_percentage,
+                    position.pnl_usd,
+                    position.share_percentage,
+                    format_large_number(&position.lp_token_amount.to_string()),
+                )
+            } else {
+                format!(
+                    "Pool ID: {}\n\nNo position in this pool.\n\nProvide liquidity from the Provide tab to earn a share of its swap fees.",
+                    position.pool_id,
+                )
+            };
 
             let paragraph = Paragraph::new(details)
                 .style(Style::default().fg(Color::White))
@@ -1717,6 +1918,20 @@ pub fn handle_liquidity_confirmation_response(
                     return None;
                 }
 
+                if let Some(zap_asset) = liquidity_state.zap_asset() {
+                    let amount = liquidity_state.zap_in_amount();
+                    crate::tui_dex::utils::logger::log_info(&format!(
+                        "Created ZapIn event: {} {}, pool_id={}, slippage={:?}",
+                        amount, zap_asset, pool_id_str, slippage
+                    ));
+                    return Some(crate::tui_dex::events::Event::ZapIn {
+                        pool_id: pool_id_str.to_string(),
+                        asset_denom: zap_asset.to_string(),
+                        amount,
+                        slippage_tolerance: Some(slippage.to_string()),
+                    });
+                }
+
                 let event = crate::tui_dex::events::Event::ProvideLiquidity {
                     asset_1_amount: first_amount.to_string(),
                     asset_2_amount: second_amount.to_string(),
@@ -1747,6 +1962,17 @@ pub fn handle_liquidity_confirmation_response(
                     return None;
                 }
 
+                if let Some(zap_asset) = liquidity_state.zap_asset() {
+                    return Some(crate::tui_dex::events::Event::ZapOut {
+                        pool_id: pool_id_str.to_string(),
+                        lp_token_amount: lp_amount.to_string(),
+                        target_denom: zap_asset.to_string(),
+                        slippage_tolerance: Some(
+                            liquidity_state.slippage_input.value().to_string(),
+                        ),
+                    });
+                }
+
                 Some(crate::tui_dex::events::Event::WithdrawLiquidity {
                     lp_token_amount: lp_amount.to_string(),
                     pool_id: pool_id_str.to_string(),
@@ -1771,6 +1997,7 @@ pub fn reset_liquidity_forms() {
     liquidity_state.first_asset_input.clear();
     liquidity_state.second_asset_input.clear();
     liquidity_state.withdraw_amount_input.clear();
+    liquidity_state.zap_asset_input.clear();
     liquidity_state.expected_lp_tokens = None;
     liquidity_state.expected_assets = None;
 
@@ -1873,6 +2100,7 @@ mod tests {
                 second_asset_amount: Uint128::new(500),
                 first_asset_denom: "USDC".to_string(),
                 second_asset_denom: "USDT".to_string(),
+                earned_fees: None,
             },
             LiquidityPosition {
                 pool_id: "2".to_string(),
@@ -1887,6 +2115,7 @@ mod tests {
                 second_asset_amount: Uint128::new(1000),
                 first_asset_denom: "ATOM".to_string(),
                 second_asset_denom: "OSMO".to_string(),
+                earned_fees: None,
             },
         ];
 
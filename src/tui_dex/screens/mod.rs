@@ -5,26 +5,36 @@
 
 // Re-export screens when they are implemented
 pub mod admin;
+pub mod claimdrop;
 pub mod dashboard;
+pub mod history;
 pub mod liquidity;
 pub mod multihop;
+pub mod notifications;
 pub mod pools;
+pub mod portfolio;
 pub mod rewards;
 pub mod settings;
 pub mod swap;
 pub mod transaction;
 pub mod wallet_selection;
+pub mod wallets;
 pub mod wizard;
 
 pub use admin::*;
+pub use claimdrop::*;
 pub use dashboard::*;
+pub use history::*;
 pub use liquidity::*;
 pub use multihop::*;
+pub use notifications::*;
 pub use pools::*;
+pub use portfolio::*;
 pub use rewards::*;
 pub use settings::*;
 pub use swap::*;
 pub use transaction::*;
 pub use wallet_selection::*;
+pub use wallets::*;
 
 // Placeholder - screens will be implemented in future tasks
@@ -0,0 +1,572 @@
+//! Notifications Screen Implementation
+//!
+//! This module provides the alerts pane for the MANTRA DEX SDK TUI: a
+//! dismissible list of transaction confirmations, failed broadcasts, price
+//! alerts and low-balance warnings, the user's alert rules, and optional
+//! desktop notifications. Prices come from the DEX pools, the same source as
+//! the portfolio screen.
+
+use crate::portfolio::UsdPrices;
+use crate::tui_dex::{
+    app::App,
+    components::{
+        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+    },
+};
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// Notifications kept before the oldest are dropped
+pub const MAX_NOTIFICATIONS: usize = 200;
+/// Native token balance, in whole tokens, below which a warning is raised
+pub const DEFAULT_LOW_BALANCE: f64 = 1.0;
+
+/// What a notification reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    TxConfirmed,
+    TxFailed,
+    PriceAlert,
+    LowBalance,
+}
+
+impl NotificationKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            NotificationKind::TxConfirmed => "Confirmed",
+            NotificationKind::TxFailed => "Failed",
+            NotificationKind::PriceAlert => "Price",
+            NotificationKind::LowBalance => "Balance",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            NotificationKind::TxConfirmed => Color::Green,
+            NotificationKind::TxFailed => Color::Red,
+            NotificationKind::PriceAlert => Color::Cyan,
+            NotificationKind::LowBalance => Color::Yellow,
+        }
+    }
+}
+
+/// One entry of the notification list
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub title: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+    pub read: bool,
+}
+
+/// When an alert rule fires
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertCondition {
+    /// USD price at or above the threshold
+    PriceAbove(f64),
+    /// USD price at or below the threshold
+    PriceBelow(f64),
+    /// Wallet balance, in whole tokens, below the threshold
+    BalanceBelow(f64),
+}
+
+/// A user-defined alert on one denom
+///
+/// A rule fires once when its condition becomes true and is re-armed when
+/// the condition stops holding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertRule {
+    pub denom: String,
+    pub condition: AlertCondition,
+    triggered: bool,
+}
+
+impl AlertRule {
+    pub fn new(denom: impl Into<String>, condition: AlertCondition) -> Self {
+        Self {
+            denom: denom.into(),
+            condition,
+            triggered: false,
+        }
+    }
+
+    /// Parse a rule written as `<denom> > <usd>`, `<denom> < <usd>` or
+    /// `balance <denom> < <amount>`
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = spec.split_whitespace().collect();
+        let (is_balance, parts) = match parts.split_first() {
+            Some((&"balance", rest)) => (true, rest),
+            _ => (false, parts.as_slice()),
+        };
+        let [denom, operator, threshold] = parts else {
+            return Err(
+                "Expected '<denom> > <usd>', '<denom> < <usd>' or 'balance <denom> < <amount>'"
+                    .to_string(),
+            );
+        };
+        let threshold = threshold
+            .parse::<f64>()
+            .ok()
+            .filter(|threshold| threshold.is_finite() && *threshold >= 0.0)
+            .ok_or_else(|| format!("Invalid threshold '{}'", threshold))?;
+        let condition = match (is_balance, *operator) {
+            (false, ">") => AlertCondition::PriceAbove(threshold),
+            (false, "<") => AlertCondition::PriceBelow(threshold),
+            (true, "<") => AlertCondition::BalanceBelow(threshold),
+            _ => return Err(format!("Unsupported comparison '{}'", operator)),
+        };
+        Ok(Self::new(*denom, condition))
+    }
+
+    pub fn describe(&self) -> String {
+        match self.condition {
+            AlertCondition::PriceAbove(usd) => format!("{} price >= ${}", self.denom, usd),
+            AlertCondition::PriceBelow(usd) => format!("{} price <= ${}", self.denom, usd),
+            AlertCondition::BalanceBelow(amount) => {
+                format!("{} balance < {}", self.denom, amount)
+            }
+        }
+    }
+
+    /// Whether `value` newly meets the condition, re-arming the rule when it
+    /// does not
+    fn update(&mut self, value: f64) -> bool {
+        let holds = match self.condition {
+            AlertCondition::PriceAbove(threshold) => value >= threshold,
+            AlertCondition::PriceBelow(threshold) => value <= threshold,
+            AlertCondition::BalanceBelow(threshold) => value < threshold,
+        };
+        let fired = holds && !self.triggered;
+        self.triggered = holds;
+        fired
+    }
+}
+
+/// Pane of the notifications screen that has the selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotificationsPane {
+    #[default]
+    Notifications,
+    Rules,
+}
+
+/// Notifications screen state
+#[derive(Debug, Clone, Default)]
+pub struct NotificationsState {
+    /// Newest first
+    pub notifications: Vec<Notification>,
+    pub rules: Vec<AlertRule>,
+    pub pane: NotificationsPane,
+    pub selected: usize,
+    pub selected_rule: usize,
+    /// Whether the new-rule form is open
+    pub editing_rule: bool,
+    pub buffer: String,
+    /// Also show notifications on the desktop
+    pub desktop_notifications: bool,
+}
+
+impl NotificationsState {
+    /// Add a notification, also sending it to the desktop when enabled
+    pub fn push(&mut self, kind: NotificationKind, title: String, message: String) {
+        if self.desktop_notifications {
+            send_desktop_notification(&title, &message);
+        }
+        self.notifications.insert(
+            0,
+            Notification {
+                kind,
+                title,
+                message,
+                created_at: Utc::now(),
+                read: false,
+            },
+        );
+        self.notifications.truncate(MAX_NOTIFICATIONS);
+        // Keep the selected notification selected
+        if !self.notifications.is_empty() && self.selected > 0 {
+            self.selected = (self.selected + 1).min(self.notifications.len() - 1);
+        }
+    }
+
+    pub fn unread(&self) -> usize {
+        self.notifications.iter().filter(|n| !n.read).count()
+    }
+
+    pub fn mark_all_read(&mut self) {
+        for notification in &mut self.notifications {
+            notification.read = true;
+        }
+    }
+
+    pub fn move_selection(&mut self, delta: isize) {
+        let (selected, len) = match self.pane {
+            NotificationsPane::Notifications => (&mut self.selected, self.notifications.len()),
+            NotificationsPane::Rules => (&mut self.selected_rule, self.rules.len()),
+        };
+        if len == 0 {
+            return;
+        }
+        *selected = (*selected as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    pub fn toggle_pane(&mut self) {
+        self.pane = match self.pane {
+            NotificationsPane::Notifications => NotificationsPane::Rules,
+            NotificationsPane::Rules => NotificationsPane::Notifications,
+        };
+    }
+
+    /// Remove the selected notification or rule
+    pub fn dismiss_selected(&mut self) {
+        let (items, selected) = match self.pane {
+            NotificationsPane::Notifications => {
+                if self.selected < self.notifications.len() {
+                    self.notifications.remove(self.selected);
+                }
+                (self.notifications.len(), &mut self.selected)
+            }
+            NotificationsPane::Rules => {
+                if self.selected_rule < self.rules.len() {
+                    self.rules.remove(self.selected_rule);
+                }
+                (self.rules.len(), &mut self.selected_rule)
+            }
+        };
+        *selected = (*selected).min(items.saturating_sub(1));
+    }
+
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+        self.selected = 0;
+    }
+
+    pub fn add_rule(&mut self, rule: AlertRule) {
+        self.rules.push(rule);
+    }
+
+    pub fn begin_rule(&mut self) {
+        self.editing_rule = true;
+        self.buffer.clear();
+    }
+
+    pub fn cancel_rule(&mut self) {
+        self.editing_rule = false;
+        self.buffer.clear();
+    }
+
+    /// Add the rule typed in the form, leaving the form open when it is invalid
+    pub fn submit_rule(&mut self) -> Result<(), String> {
+        let rule = AlertRule::parse(&self.buffer)?;
+        self.add_rule(rule);
+        self.cancel_rule();
+        Ok(())
+    }
+
+    /// Fire the price rules whose condition `prices` newly meets
+    pub fn check_prices(&mut self, prices: &UsdPrices) {
+        let mut fired = Vec::new();
+        for rule in &mut self.rules {
+            if matches!(rule.condition, AlertCondition::BalanceBelow(_)) {
+                continue;
+            }
+            if let Some(price) = prices.price(&rule.denom) {
+                if rule.update(price) {
+                    fired.push(format!("{} is now ${:.4}", rule.describe(), price));
+                }
+            }
+        }
+        for message in fired {
+            self.push(
+                NotificationKind::PriceAlert,
+                "Price alert".to_string(),
+                message,
+            );
+        }
+    }
+
+    /// Fire the balance rules whose condition `balances`, in base units by
+    /// denom, newly meets; a denom missing from `balances` has none
+    pub fn check_balances(
+        &mut self,
+        balances: &HashMap<String, String>,
+        decimals: impl Fn(&str) -> u8,
+    ) {
+        let mut fired = Vec::new();
+        for rule in &mut self.rules {
+            if !matches!(rule.condition, AlertCondition::BalanceBelow(_)) {
+                continue;
+            }
+            let base_units = balances
+                .get(&rule.denom)
+                .and_then(|amount| amount.parse::<u128>().ok())
+                .unwrap_or(0);
+            let amount = base_units as f64 / 10f64.powi(i32::from(decimals(&rule.denom)));
+            if rule.update(amount) {
+                fired.push(format!("{} is now {}", rule.describe(), amount));
+            }
+        }
+        for message in fired {
+            self.push(
+                NotificationKind::LowBalance,
+                "Low balance".to_string(),
+                message,
+            );
+        }
+    }
+}
+
+/// Show a desktop notification through the platform's notifier, if any
+///
+/// Best effort: a missing notifier is ignored.
+pub fn send_desktop_notification(title: &str, message: &str) {
+    #[cfg(target_os = "linux")]
+    let child = std::process::Command::new("notify-send")
+        .args(["--app-name", "MANTRA DEX", title, message])
+        .spawn();
+    #[cfg(target_os = "macos")]
+    let child = std::process::Command::new("osascript")
+        .args([
+            "-e",
+            &format!("display notification {:?} with title {:?}", message, title),
+        ])
+        .spawn();
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    if let Ok(mut child) = child {
+        // Reap the notifier without blocking the UI
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    let _ = (title, message);
+}
+
+/// Render the notifications screen
+pub fn render_notifications(f: &mut Frame, app: &App) {
+    let size = f.area();
+
+    // Create main layout: header, nav, content, status
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Navigation
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Status bar
+        ])
+        .split(size);
+
+    render_header(f, &app.state, chunks[0]);
+    render_navigation(f, &app.state, chunks[1]);
+
+    // Create horizontal layout: notifications | alert rules
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(chunks[2]);
+    let state = &app.state.notifications_state;
+    render_notification_table(f, content_chunks[0], state);
+    render_alert_rules(f, content_chunks[1], state);
+
+    if state.editing_rule {
+        render_rule_form(f, chunks[2], &state.buffer);
+    }
+
+    render_status_bar(f, &app.state, chunks[3]);
+}
+
+fn pane_border(state: &NotificationsState, pane: NotificationsPane) -> Style {
+    if state.pane == pane {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Blue)
+    }
+}
+
+/// Render the notifications, newest first
+fn render_notification_table(f: &mut Frame, area: Rect, state: &NotificationsState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(pane_border(state, NotificationsPane::Notifications))
+        .title(format!("Notifications ({})", state.notifications.len()));
+
+    if state.notifications.is_empty() {
+        f.render_widget(
+            Paragraph::new("No notifications")
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let rows: Vec<Row> = state
+        .notifications
+        .iter()
+        .enumerate()
+        .map(|(index, notification)| {
+            let mut style = Style::default();
+            if !notification.read {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            if index == state.selected && state.pane == NotificationsPane::Notifications {
+                style = style.bg(Color::DarkGray);
+            }
+            Row::new(vec![
+                Cell::from(
+                    notification
+                        .created_at
+                        .with_timezone(&Local)
+                        .format("%H:%M:%S")
+                        .to_string(),
+                ),
+                Cell::from(Span::styled(
+                    notification.kind.label(),
+                    Style::default().fg(notification.kind.color()),
+                )),
+                Cell::from(format!("{}: {}", notification.title, notification.message)),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(9),
+            Constraint::Length(10),
+            Constraint::Min(20),
+        ],
+    )
+    .header(
+        Row::new(vec!["Time", "Kind", "Details"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(block);
+    f.render_widget(table, area);
+}
+
+/// Render the alert rules and the desktop notification setting
+fn render_alert_rules(f: &mut Frame, area: Rect, state: &NotificationsState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(6)])
+        .split(area);
+
+    let items: Vec<ListItem> = state
+        .rules
+        .iter()
+        .enumerate()
+        .map(|(index, rule)| {
+            let style = if index == state.selected_rule && state.pane == NotificationsPane::Rules {
+                Style::default().bg(Color::DarkGray)
+            } else {
+                Style::default()
+            };
+            ListItem::new(rule.describe()).style(style)
+        })
+        .collect();
+    f.render_widget(
+        List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(pane_border(state, NotificationsPane::Rules))
+                .title(format!("Alert Rules ({})", state.rules.len())),
+        ),
+        chunks[0],
+    );
+
+    let desktop = if state.desktop_notifications {
+        Span::styled("on", Style::default().fg(Color::Green))
+    } else {
+        Span::styled("off", Style::default().fg(Color::Gray))
+    };
+    let lines = vec![
+        Line::from(vec![Span::raw("Desktop notifications: "), desktop]),
+        Line::from(Span::styled(
+            "New rule: 'uom > 1.5', 'uom < 0.5' or 'balance uom < 2'",
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+    f.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL).title("Settings")),
+        chunks[1],
+    );
+}
+
+/// Render the new alert rule form over the content area
+fn render_rule_form(f: &mut Frame, area: Rect, buffer: &str) {
+    let width = area.width.saturating_sub(8).min(80);
+    let popup = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + area.height.saturating_sub(5) / 2,
+        width,
+        height: 5.min(area.height),
+    };
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(buffer.to_string()),
+            Span::styled("_", Style::default().fg(Color::Yellow)),
+        ]),
+        Line::from(Span::styled(
+            "Enter: add | Esc: cancel",
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+
+    f.render_widget(Clear, popup);
+    f.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .title("New alert rule")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        ),
+        popup,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_fire_once_until_rearmed() {
+        let mut state = NotificationsState::default();
+        state.add_rule(AlertRule::parse("uom > 1.5").unwrap());
+        state.add_rule(AlertRule::parse("balance uom < 2").unwrap());
+        assert!(AlertRule::parse("uom = 1").is_err());
+        assert!(AlertRule::parse("balance uom > 1").is_err());
+
+        let decimals = |_: &str| 6;
+        let mut balances = HashMap::from([("uom".to_string(), "1500000".to_string())]);
+        state.check_balances(&balances, decimals);
+        state.check_balances(&balances, decimals);
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notifications[0].kind, NotificationKind::LowBalance);
+
+        // Recovering re-arms the rule
+        balances.insert("uom".to_string(), "5000000".to_string());
+        state.check_balances(&balances, decimals);
+        balances.insert("uom".to_string(), "0".to_string());
+        state.check_balances(&balances, decimals);
+        assert_eq!(state.notifications.len(), 2);
+        assert_eq!(state.unread(), 2);
+
+        state.mark_all_read();
+        state.dismiss_selected();
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.unread(), 0);
+    }
+}
@@ -1,12 +1,16 @@
 //! Pools Screen Implementation
 //!
 //! This module provides the pools view for the MANTRA DEX SDK TUI,
-//! displaying pool listings, details, search functionality, and status indicators.
+//! displaying pool listings, a live price chart of the selected pool, search
+//! functionality, and status indicators.
 
 use crate::tui_dex::{
     app::{App, LoadingState, PoolCacheEntry},
     components::{
-        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+        charts::{render_price_chart, PriceChartState},
+        header::render_header,
+        navigation::render_navigation,
+        status_bar::render_status_bar,
     },
 };
 use mantra_dex_std::pool_manager::PoolInfoResponse;
@@ -47,6 +51,29 @@ pub struct PoolDisplayData {
     pub pool_info: PoolInfoResponse,
 }
 
+/// Pools screen state: the selected pool and its price chart
+#[derive(Debug, Clone, Default)]
+pub struct PoolsState {
+    /// Index of the selected pool in the sorted pool list
+    pub selected: usize,
+    pub chart: PriceChartState,
+}
+
+impl PoolsState {
+    /// Move the selection by `delta` within `pool_count` pools
+    pub fn move_selection(&mut self, delta: isize, pool_count: usize) {
+        if pool_count == 0 {
+            self.selected = 0;
+            return;
+        }
+        self.selected = self
+            .selected
+            .saturating_add_signed(delta)
+            .min(pool_count - 1);
+        self.chart.crosshair = None;
+    }
+}
+
 /// Pool status for display purposes
 #[derive(Debug, Clone, PartialEq)]
 pub enum PoolDisplayStatus {
@@ -172,6 +199,7 @@ fn render_pool_filters(f: &mut Frame, area: Rect, _app: &App) {
 fn render_pool_list_table(f: &mut Frame, area: Rect, app: &App) {
     // Prepare pool data
     let pool_data = prepare_pool_display_data(&app.state.pool_cache);
+    let selected = app.state.pools_state.selected;
 
     if pool_data.is_empty() {
         render_empty_pool_list(f, area, app);
@@ -190,7 +218,7 @@ fn render_pool_list_table(f: &mut Frame, area: Rect, app: &App) {
         .iter()
         .enumerate()
         .map(|(index, pool)| {
-            let style = if app.state.selected_pool_id == Some(pool.pool_id.parse().unwrap_or(0)) {
+            let style = if index == selected {
                 Style::default().bg(Color::Blue).fg(Color::White)
             } else if index % 2 == 0 {
                 Style::default()
@@ -261,185 +289,84 @@ fn render_empty_pool_list(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(empty_msg, area);
 }
 
-/// Render the pool details panel
+/// Render the price chart and details of the selected pool
 fn render_pool_details_panel(f: &mut Frame, area: Rect, app: &App) {
-    if let Some(pool_id) = app.state.selected_pool_id {
-        if let Some(pool_cache_entry) = app.state.pool_cache.get(&pool_id.to_string()) {
-            render_selected_pool_details(f, area, &pool_cache_entry.pool_info);
-        } else {
-            render_no_pool_details(f, area, "Pool details not available");
-        }
-    } else {
+    let pool_data = prepare_pool_display_data(&app.state.pool_cache);
+    let Some(pool) = pool_data.get(app.state.pools_state.selected) else {
         render_no_pool_details(f, area, "Select a pool to view details");
-    }
-}
+        return;
+    };
 
-/// Render details for the selected pool
-fn render_selected_pool_details(f: &mut Frame, area: Rect, pool_info: &PoolInfoResponse) {
-    // Split details panel into sections
-    let detail_chunks = Layout::default()
+    let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(8),  // Basic info
-            Constraint::Length(10), // Assets composition
-            Constraint::Min(0),     // Fee structure and features
-        ])
+        .constraints([Constraint::Min(10), Constraint::Length(9)])
         .split(area);
 
-    render_pool_basic_info(f, detail_chunks[0], pool_info);
-    render_pool_composition(f, detail_chunks[1], pool_info);
-    render_pool_features(f, detail_chunks[2], pool_info);
+    render_price_chart(
+        f,
+        chunks[0],
+        &pool.asset_pair,
+        &app.state.price_history,
+        &pool.pool_id,
+        &app.state.pools_state.chart,
+    );
+    render_pool_summary(f, chunks[1], &pool.pool_info);
 }
 
-/// Render basic pool information
-fn render_pool_basic_info(f: &mut Frame, area: Rect, pool_info: &PoolInfoResponse) {
-    let pool_type = determine_pool_type(&pool_info.pool_info.pool_type);
-    let total_shares = format_large_number(&pool_info.total_share.amount.to_string());
+/// Render type, reserves and enabled operations of a pool
+fn render_pool_summary(f: &mut Frame, area: Rect, pool_info: &PoolInfoResponse) {
+    let status = &pool_info.pool_info.status;
+    let flag = |label: &'static str, enabled: bool| {
+        Span::styled(
+            format!("{} {}  ", label, if enabled { "✓" } else { "✗" }),
+            Style::default().fg(if enabled { Color::Green } else { Color::Red }),
+        )
+    };
 
-    let content = vec![
+    let mut content = vec![
         Line::from(vec![
-            Span::styled("Pool ID: ", Style::default().fg(Color::White)),
+            Span::styled("Pool: ", Style::default().fg(Color::White)),
             Span::styled(
                 pool_info.pool_info.pool_identifier.to_string(),
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
             ),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Type: ", Style::default().fg(Color::White)),
-            Span::styled(pool_type, Style::default().fg(Color::Yellow)),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Total Shares: ", Style::default().fg(Color::White)),
-            Span::styled(total_shares, Style::default().fg(Color::Green)),
-        ]),
-    ];
-
-    let block = Block::default()
-        .title("Pool Information")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Green))
-        .padding(Padding::uniform(1));
-
-    let paragraph = Paragraph::new(Text::from(content))
-        .block(block)
-        .wrap(Wrap { trim: true });
-
-    f.render_widget(paragraph, area);
-}
-
-/// Render pool asset composition
-fn render_pool_composition(f: &mut Frame, area: Rect, pool_info: &PoolInfoResponse) {
-    let assets: Vec<String> = pool_info
-        .pool_info
-        .assets
-        .iter()
-        .map(|asset| {
-            let amount = format_large_number(&asset.amount.to_string());
-            format!("• {}: {}", asset.denom, amount)
-        })
-        .collect();
-
-    let content = if assets.is_empty() {
-        vec![Line::from("No assets available")]
-    } else {
-        assets.into_iter().map(Line::from).collect()
-    };
-
-    let block = Block::default()
-        .title("Asset Composition")
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Magenta))
-        .padding(Padding::uniform(1));
-
-    let paragraph = Paragraph::new(Text::from(content))
-        .block(block)
-        .wrap(Wrap { trim: true });
-
-    f.render_widget(paragraph, area);
-}
-
-/// Render pool features and fee structure
-fn render_pool_features(f: &mut Frame, area: Rect, pool_info: &PoolInfoResponse) {
-    let status = &pool_info.pool_info.status;
-
-    let features = vec![
-        Line::from(vec![Span::styled(
-            "Operations:",
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("• Deposits: ", Style::default().fg(Color::White)),
-            Span::styled(
-                if status.deposits_enabled {
-                    "Enabled ✓"
-                } else {
-                    "Disabled ✗"
-                },
-                Style::default().fg(if status.deposits_enabled {
-                    Color::Green
-                } else {
-                    Color::Red
-                }),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("• Withdrawals: ", Style::default().fg(Color::White)),
             Span::styled(
-                if status.withdrawals_enabled {
-                    "Enabled ✓"
-                } else {
-                    "Disabled ✗"
-                },
-                Style::default().fg(if status.withdrawals_enabled {
-                    Color::Green
-                } else {
-                    Color::Red
-                }),
+                format!(
+                    "  ({})",
+                    determine_pool_type(&pool_info.pool_info.pool_type)
+                ),
+                Style::default().fg(Color::Yellow),
             ),
         ]),
         Line::from(vec![
-            Span::styled("• Swaps: ", Style::default().fg(Color::White)),
+            Span::styled("Total Shares: ", Style::default().fg(Color::White)),
             Span::styled(
-                if status.swaps_enabled {
-                    "Enabled ✓"
-                } else {
-                    "Disabled ✗"
-                },
-                Style::default().fg(if status.swaps_enabled {
-                    Color::Green
-                } else {
-                    Color::Red
-                }),
+                format_large_number(&pool_info.total_share.amount.to_string()),
+                Style::default().fg(Color::Green),
             ),
         ]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Pool Features:",
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("• Fee Strategy: ", Style::default().fg(Color::White)),
-            Span::styled("Standard", Style::default().fg(Color::Cyan)), // Placeholder
-        ]),
     ];
+    content.extend(pool_info.pool_info.assets.iter().map(|asset| {
+        Line::from(format!(
+            "• {}: {}",
+            asset.denom,
+            format_large_number(&asset.amount.to_string())
+        ))
+    }));
+    content.push(Line::from(vec![
+        flag("Swaps", status.swaps_enabled),
+        flag("Deposits", status.deposits_enabled),
+        flag("Withdrawals", status.withdrawals_enabled),
+    ]));
 
     let block = Block::default()
-        .title("Features & Configuration")
+        .title("Pool Details")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Yellow))
-        .padding(Padding::uniform(1));
+        .border_style(Style::default().fg(Color::Green));
 
-    let paragraph = Paragraph::new(Text::from(features))
+    let paragraph = Paragraph::new(Text::from(content))
         .block(block)
         .wrap(Wrap { trim: true });
 
@@ -464,7 +391,9 @@ fn render_no_pool_details(f: &mut Frame, area: Rect, message: &str) {
 }
 
 /// Prepare pool data for display in the table
-fn prepare_pool_display_data(pool_cache: &HashMap<String, PoolCacheEntry>) -> Vec<PoolDisplayData> {
+pub(crate) fn prepare_pool_display_data(
+    pool_cache: &HashMap<String, PoolCacheEntry>,
+) -> Vec<PoolDisplayData> {
     let mut pools: Vec<PoolDisplayData> = pool_cache
         .values()
         .map(|cache_entry| {
@@ -484,12 +413,13 @@ fn prepare_pool_display_data(pool_cache: &HashMap<String, PoolCacheEntry>) -> Ve
         })
         .collect();
 
-    // Sort by pool ID by default
+    // Sort by pool ID by default; identifiers are not always numeric
     pools.sort_by(|a, b| {
         a.pool_id
             .parse::<u64>()
             .unwrap_or(0)
             .cmp(&b.pool_id.parse::<u64>().unwrap_or(0))
+            .then_with(|| a.pool_id.cmp(&b.pool_id))
     });
 
     pools
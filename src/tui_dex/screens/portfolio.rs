@@ -0,0 +1,373 @@
+//! Portfolio Screen Implementation
+//!
+//! This module provides the portfolio view for the MANTRA DEX SDK TUI,
+//! listing the wallet's bank balances, LP positions, staked tokens, pending
+//! ClaimDrop rewards and EVM token balances with their USD value. The
+//! portfolio is refetched when the wallet swaps or moves liquidity.
+
+use crate::portfolio::{Portfolio, PortfolioCategory, PortfolioEntry};
+use crate::tui_dex::{
+    app::App,
+    components::{
+        charts::format_price, header::render_header, navigation::render_navigation,
+        status_bar::render_status_bar,
+    },
+};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+use std::cmp::Ordering;
+
+/// USD value below which a balance counts as small
+pub const DEFAULT_SMALL_BALANCE_USD: f64 = 1.0;
+
+/// Portfolio sorting criteria
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PortfolioSort {
+    #[default]
+    Value,
+    Amount,
+    Denom,
+    Category,
+}
+
+impl PortfolioSort {
+    pub fn label(self) -> &'static str {
+        match self {
+            PortfolioSort::Value => "value",
+            PortfolioSort::Amount => "amount",
+            PortfolioSort::Denom => "denom",
+            PortfolioSort::Category => "category",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            PortfolioSort::Value => PortfolioSort::Amount,
+            PortfolioSort::Amount => PortfolioSort::Denom,
+            PortfolioSort::Denom => PortfolioSort::Category,
+            PortfolioSort::Category => PortfolioSort::Value,
+        }
+    }
+}
+
+/// Portfolio screen state
+#[derive(Debug, Clone)]
+pub struct PortfolioState {
+    /// Last fetched portfolio of the connected wallet
+    pub portfolio: Option<Portfolio>,
+    pub loading: bool,
+    pub sort: PortfolioSort,
+    pub hide_small: bool,
+    /// USD value below which balances are hidden when `hide_small` is set
+    pub small_threshold: f64,
+    pub selected: usize,
+}
+
+impl Default for PortfolioState {
+    fn default() -> Self {
+        Self {
+            portfolio: None,
+            loading: false,
+            sort: PortfolioSort::default(),
+            hide_small: false,
+            small_threshold: DEFAULT_SMALL_BALANCE_USD,
+            selected: 0,
+        }
+    }
+}
+
+impl PortfolioState {
+    /// Entries to show, sorted; balances without a price are never hidden
+    /// as small since their value is unknown
+    pub fn visible_entries(&self) -> Vec<&PortfolioEntry> {
+        let Some(portfolio) = &self.portfolio else {
+            return Vec::new();
+        };
+        let mut entries: Vec<&PortfolioEntry> = portfolio
+            .entries
+            .iter()
+            .filter(|entry| {
+                !self.hide_small
+                    || entry
+                        .usd_value
+                        .is_none_or(|value| value >= self.small_threshold)
+            })
+            .collect();
+        let by_value = |a: &PortfolioEntry, b: &PortfolioEntry| {
+            // Unpriced entries sort after priced ones
+            match (a.usd_value, b.usd_value) {
+                (Some(a), Some(b)) => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        };
+        entries.sort_by(|a, b| match self.sort {
+            PortfolioSort::Value => by_value(a, b),
+            PortfolioSort::Amount => b
+                .whole_amount()
+                .partial_cmp(&a.whole_amount())
+                .unwrap_or(Ordering::Equal),
+            PortfolioSort::Denom => a.denom.cmp(&b.denom),
+            PortfolioSort::Category => a.category.cmp(&b.category).then_with(|| by_value(a, b)),
+        });
+        entries
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.selected = 0;
+    }
+
+    pub fn toggle_hide_small(&mut self) {
+        self.hide_small = !self.hide_small;
+        self.selected = 0;
+    }
+
+    /// Move the selection by `delta` within the visible entries
+    pub fn move_selection(&mut self, delta: isize) {
+        let count = self.visible_entries().len();
+        self.selected = self
+            .selected
+            .saturating_add_signed(delta)
+            .min(count.saturating_sub(1));
+    }
+}
+
+/// Render the complete portfolio screen
+pub fn render_portfolio(f: &mut Frame, app: &App) {
+    let size = f.area();
+
+    // Create main layout: header, nav, content, status
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Navigation
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Status bar
+        ])
+        .split(size);
+
+    render_header(f, &app.state, chunks[0]);
+    render_navigation(f, &app.state, chunks[1]);
+
+    let content_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(chunks[2]);
+    render_portfolio_totals(f, content_chunks[0], &app.state.portfolio_state);
+    render_portfolio_table(f, content_chunks[1], &app.state.portfolio_state);
+
+    render_status_bar(f, &app.state, chunks[3]);
+}
+
+/// Render the total value, per-category values and fetch errors
+fn render_portfolio_totals(f: &mut Frame, area: Rect, state: &PortfolioState) {
+    let block = Block::default()
+        .title("Portfolio")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let Some(portfolio) = &state.portfolio else {
+        let text = if state.loading {
+            "Loading portfolio..."
+        } else {
+            "Connect a wallet and press r to load the portfolio"
+        };
+        f.render_widget(
+            Paragraph::new(text)
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    };
+
+    let mut categories = vec![Span::styled(
+        format!("Total ${:.2}", portfolio.total_usd()),
+        Style::default()
+            .fg(Color::Green)
+            .add_modifier(Modifier::BOLD),
+    )];
+    for category in [
+        PortfolioCategory::Bank,
+        PortfolioCategory::Liquidity,
+        PortfolioCategory::Staked,
+        PortfolioCategory::ClaimDrop,
+        PortfolioCategory::Evm,
+    ] {
+        let value: f64 = portfolio
+            .entries
+            .iter()
+            .filter(|entry| entry.category == category)
+            .filter_map(|entry| entry.usd_value)
+            .sum();
+        categories.push(Span::raw(format!("  {} ${:.2}", category.label(), value)));
+    }
+
+    let mut lines = vec![
+        Line::from(categories),
+        Line::from(Span::styled(
+            format!(
+                "Sort: {} (s) | Hide < ${:.2}: {} (h) | Updated {}{}",
+                state.sort.label(),
+                state.small_threshold,
+                if state.hide_small { "on" } else { "off" },
+                portfolio.fetched_at.format("%H:%M:%S"),
+                if state.loading {
+                    " | refreshing..."
+                } else {
+                    ""
+                }
+            ),
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+    if !portfolio.errors.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Incomplete: {}", portfolio.errors.join("; ")),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    f.render_widget(
+        Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+/// Render the table of holdings
+fn render_portfolio_table(f: &mut Frame, area: Rect, state: &PortfolioState) {
+    let entries = state.visible_entries();
+
+    let header = Row::new(vec![
+        Cell::from("Category").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Holding").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Denom").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Amount").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Value (USD)").style(Style::default().add_modifier(Modifier::BOLD)),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let rows: Vec<Row> = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let style = if index == state.selected {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else if index % 2 == 0 {
+                Style::default()
+            } else {
+                Style::default().bg(Color::DarkGray)
+            };
+            let amount = match entry.decimals {
+                Some(_) => format_price(entry.whole_amount()),
+                None => entry.amount.to_string(),
+            };
+            let value = entry
+                .usd_value
+                .map(|value| format!("${:.2}", value))
+                .unwrap_or_else(|| "-".to_string());
+
+            Row::new(vec![
+                Cell::from(entry.category.label()),
+                Cell::from(entry.label.clone()),
+                Cell::from(entry.denom.clone()),
+                Cell::from(amount),
+                Cell::from(value),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let hidden = state
+        .portfolio
+        .as_ref()
+        .map(|portfolio| portfolio.entries.len() - entries.len())
+        .unwrap_or(0);
+    let title = if hidden > 0 {
+        format!(
+            "Holdings ({} shown, {} small hidden)",
+            entries.len(),
+            hidden
+        )
+    } else {
+        format!("Holdings ({})", entries.len())
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),     // Category
+            Constraint::Percentage(35), // Holding
+            Constraint::Percentage(25), // Denom
+            Constraint::Length(18),     // Amount
+            Constraint::Length(14),     // Value
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue))
+            .title(title),
+    );
+
+    f.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint128;
+
+    fn entry(category: PortfolioCategory, denom: &str, usd_value: Option<f64>) -> PortfolioEntry {
+        PortfolioEntry {
+            category,
+            label: denom.to_string(),
+            denom: denom.to_string(),
+            amount: Uint128::new(1_000_000),
+            decimals: Some(6),
+            usd_value,
+        }
+    }
+
+    #[test]
+    fn test_sort_and_hide_small_balances() {
+        let mut state = PortfolioState {
+            portfolio: Some(Portfolio {
+                address: "mantra1me".to_string(),
+                entries: vec![
+                    entry(PortfolioCategory::Bank, "uom", Some(0.5)),
+                    entry(PortfolioCategory::Staked, "uom", Some(20.0)),
+                    entry(PortfolioCategory::Bank, "unknown", None),
+                ],
+                fetched_at: chrono::Utc::now(),
+                errors: Vec::new(),
+            }),
+            ..Default::default()
+        };
+        let values = |state: &PortfolioState| {
+            state
+                .visible_entries()
+                .iter()
+                .map(|entry| entry.usd_value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(&state), vec![Some(20.0), Some(0.5), None]);
+
+        state.toggle_hide_small();
+        assert_eq!(values(&state), vec![Some(20.0), None]);
+
+        state.cycle_sort();
+        state.cycle_sort();
+        assert_eq!(state.sort, PortfolioSort::Denom);
+        assert_eq!(state.visible_entries()[0].denom, "unknown");
+    }
+}
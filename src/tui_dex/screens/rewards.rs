@@ -2,7 +2,10 @@
 //!
 //! This module provides the rewards view for the MANTRA DEX SDK TUI,
 //! displaying claimable rewards, claim interface, rewards history, and epoch timeline.
+//! Pressing `v` switches the timeline panel to a ClaimDrop vesting timeline for a
+//! campaign address typed into the panel.
 
+use crate::protocols::claimdrop::VestingSchedule;
 use crate::tui_dex::{
     app::{App, LoadingState},
     components::{
@@ -26,6 +29,7 @@ pub enum RewardsMode {
     Claim,
     History,
     EpochTimeline,
+    VestingTimeline,
 }
 
 /// Rewards screen state
@@ -37,6 +41,10 @@ pub struct RewardsState {
     pub show_claim_confirmation: bool,
     pub table_selected: usize,
     pub history_scroll: usize,
+    /// Campaign address typed into the vesting timeline panel
+    pub vesting_campaign_input: String,
+    /// Vesting schedule loaded for the connected wallet
+    pub vesting_schedule: Option<VestingSchedule>,
 }
 
 impl Default for RewardsState {
@@ -48,6 +56,8 @@ impl Default for RewardsState {
             show_claim_confirmation: false,
             table_selected: 0,
             history_scroll: 0,
+            vesting_campaign_input: String::new(),
+            vesting_schedule: None,
         }
     }
 }
@@ -100,7 +110,11 @@ fn render_rewards_content(f: &mut Frame, area: Rect, app: &App) {
     render_rewards_dashboard(f, top_chunks[0], app);
     render_claim_interface(f, top_chunks[1], app);
     render_rewards_history(f, bottom_chunks[0], app);
-    render_epoch_timeline(f, bottom_chunks[1], app);
+    if app.state.rewards_state.mode == RewardsMode::VestingTimeline {
+        render_vesting_timeline(f, bottom_chunks[1], app);
+    } else {
+        render_epoch_timeline(f, bottom_chunks[1], app);
+    }
 }
 
 /// Render the rewards dashboard panel
@@ -366,6 +380,112 @@ fn render_epoch_timeline(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+/// Render the ClaimDrop vesting timeline panel
+fn render_vesting_timeline(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default()
+        .title("Vesting Timeline")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .padding(Padding::uniform(1));
+
+    let rewards_state = &app.state.rewards_state;
+    let mut content = vec![
+        Line::from(vec![
+            Span::styled("Campaign: ", Style::default().fg(Color::White)),
+            Span::styled(
+                if rewards_state.vesting_campaign_input.is_empty() {
+                    "<type address, Enter to load>".to_string()
+                } else {
+                    rewards_state.vesting_campaign_input.clone()
+                },
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    match &rewards_state.vesting_schedule {
+        Some(schedule) => {
+            let now = chrono::Utc::now().timestamp().max(0) as u64;
+            content.push(Line::from(vec![
+                Span::styled("Allocation: ", Style::default().fg(Color::White)),
+                Span::styled(
+                    format!(
+                        "{} {}",
+                        format_amount(schedule.total_allocation.u128() as u64),
+                        schedule.denom
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+            content.push(Line::from(vec![
+                Span::styled("Claimed: ", Style::default().fg(Color::White)),
+                Span::styled(
+                    format_amount(schedule.claimed.u128() as u64),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled("  Claimable now: ", Style::default().fg(Color::White)),
+                Span::styled(
+                    format_amount(schedule.claimable_at(now).u128() as u64),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            if let Some(next) = schedule.next_unlock_after(now) {
+                content.push(Line::from(vec![
+                    Span::styled("Next unlock: ", Style::default().fg(Color::White)),
+                    Span::styled(format_timestamp(next), Style::default().fg(Color::Cyan)),
+                ]));
+            }
+            content.push(Line::from(""));
+
+            let total = schedule.total_allocation.u128().max(1);
+            for point in
+                schedule.timeline(crate::protocols::claimdrop::vesting::DEFAULT_TIMELINE_POINTS)
+            {
+                let filled = (point.vested.u128() * 20 / total) as usize;
+                let color = if point.timestamp <= now {
+                    Color::Green
+                } else {
+                    Color::Gray
+                };
+                content.push(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", format_timestamp(point.timestamp)),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("{:<20}", "█".repeat(filled.min(20))),
+                        Style::default().fg(color),
+                    ),
+                    Span::styled(
+                        format!(" {}", format_amount(point.vested.u128() as u64)),
+                        Style::default().fg(color),
+                    ),
+                ]));
+            }
+        }
+        None => content.push(Line::from(vec![Span::styled(
+            "No vesting schedule loaded",
+            Style::default().fg(Color::Gray),
+        )])),
+    }
+
+    let paragraph = Paragraph::new(Text::from(content))
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
+
+/// Format a unix timestamp as a UTC date
+fn format_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
+
 /// Helper function to calculate total claimable rewards
 fn calculate_total_claimable_rewards(rewards: &HashMap<String, Uint128>) -> u64 {
     rewards.values().map(|amount| amount.u128() as u64).sum()
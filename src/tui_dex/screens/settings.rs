@@ -3,7 +3,7 @@
 //! This screen provides configuration options for network settings,
 //! wallet management, and display preferences.
 
-use crate::config::{Config, MantraNetworkConfig};
+use crate::config::{Config, MantraNetworkConfig, Preferences};
 use crate::Error;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
@@ -97,8 +97,41 @@ impl Theme {
             Theme::HighContrast,
         ]
     }
+
+    /// Name stored in the config file
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::Dark => "dark",
+            Theme::Light => "light",
+            Theme::HighContrast => "high-contrast",
+        }
+    }
+
+    /// Theme stored in the config file under `name`, or the default one
+    pub fn from_config_name(name: &str) -> Self {
+        Self::all()
+            .into_iter()
+            .find(|theme| theme.config_name() == name)
+            .unwrap_or(Theme::Default)
+    }
+
+    /// Color of the selected tab and other highlights
+    pub fn accent(&self) -> Color {
+        match self {
+            Theme::Default => Color::Yellow,
+            Theme::Dark => Color::Cyan,
+            Theme::Light => Color::Blue,
+            Theme::HighContrast => Color::LightRed,
+        }
+    }
 }
 
+/// Editable fields of the network section
+const NETWORK_FIELD_COUNT: usize = 6;
+/// Editable fields of the display section
+const DISPLAY_FIELD_COUNT: usize = 4;
+
 /// Simple form state for managing current field focus
 #[derive(Debug, Clone, Default)]
 pub struct FormState {
@@ -158,6 +191,8 @@ pub struct NetworkConfigForm {
     pub custom_rpc: InputField,
     pub gas_price: InputField,
     pub gas_adjustment: InputField,
+    pub fee_denom: InputField,
+    pub grpc_url: InputField,
     pub form_state: FormState,
 }
 
@@ -173,6 +208,8 @@ impl Default for NetworkConfigForm {
             ),
             gas_price: InputField::new("Gas Price", "0.025", false),
             gas_adjustment: InputField::new("Gas Adjustment", "1.3", false),
+            fee_denom: InputField::new("Fee Denom", "uom", false),
+            grpc_url: InputField::new("gRPC Endpoint (optional)", "", false),
             form_state: FormState::default(),
         }
     }
@@ -205,6 +242,7 @@ pub struct DisplayForm {
     pub refresh_interval_balances: InputField,
     pub refresh_interval_pools: InputField,
     pub decimal_precision: InputField,
+    pub default_slippage: InputField,
     pub auto_refresh: bool,
     pub form_state: FormState,
 }
@@ -216,6 +254,7 @@ impl Default for DisplayForm {
             refresh_interval_balances: InputField::new("Balance Refresh (seconds)", "30", false),
             refresh_interval_pools: InputField::new("Pool Refresh (seconds)", "60", false),
             decimal_precision: InputField::new("Decimal Precision", "6", false),
+            default_slippage: InputField::new("Default Slippage (%)", "1.0", false),
             auto_refresh: true,
             form_state: FormState::default(),
         }
@@ -289,6 +328,18 @@ impl SettingsState {
         self.network_form
             .gas_adjustment
             .set_value(&config.network.gas_adjustment.to_string());
+        self.network_form
+            .fee_denom
+            .set_value(&config.network.native_denom);
+        self.network_form
+            .grpc_url
+            .set_value(config.network.grpc_url.as_deref().unwrap_or_default());
+
+        // Load preferences
+        self.display_form
+            .default_slippage
+            .set_value(&config.preferences.default_slippage);
+        self.display_form.theme = Theme::from_config_name(&config.preferences.theme);
 
         // Determine environment based on network name
         self.network_form.environment = if config.network.network_name.contains("mainnet") {
@@ -350,6 +401,8 @@ impl SettingsState {
                         1 => self.network_form.custom_rpc.handle_char(c),
                         2 => self.network_form.gas_price.handle_char(c),
                         3 => self.network_form.gas_adjustment.handle_char(c),
+                        4 => self.network_form.fee_denom.handle_char(c),
+                        5 => self.network_form.grpc_url.handle_char(c),
                         _ => {}
                     }
                     self.has_changes = true;
@@ -367,6 +420,7 @@ impl SettingsState {
                         0 => self.display_form.refresh_interval_balances.handle_char(c),
                         1 => self.display_form.refresh_interval_pools.handle_char(c),
                         2 => self.display_form.decimal_precision.handle_char(c),
+                        3 => self.display_form.default_slippage.handle_char(c),
                         _ => {}
                     }
                     self.has_changes = true;
@@ -386,6 +440,8 @@ impl SettingsState {
                         1 => self.network_form.custom_rpc.handle_backspace(),
                         2 => self.network_form.gas_price.handle_backspace(),
                         3 => self.network_form.gas_adjustment.handle_backspace(),
+                        4 => self.network_form.fee_denom.handle_backspace(),
+                        5 => self.network_form.grpc_url.handle_backspace(),
                         _ => {}
                     }
                     self.has_changes = true;
@@ -406,6 +462,7 @@ impl SettingsState {
                             .handle_backspace(),
                         1 => self.display_form.refresh_interval_pools.handle_backspace(),
                         2 => self.display_form.decimal_precision.handle_backspace(),
+                        3 => self.display_form.default_slippage.handle_backspace(),
                         _ => {}
                     }
                     self.has_changes = true;
@@ -458,8 +515,12 @@ impl SettingsState {
         self.wallet_form.show_mnemonic = !self.wallet_form.show_mnemonic;
     }
 
-    /// Save current settings
-    pub fn save_settings(&mut self) -> Result<Config, Error> {
+    /// Configuration described by the forms
+    ///
+    /// Mainnet and testnet take their name and endpoint from the preset; gas,
+    /// fee denom, gRPC endpoint and preferences come from the forms for
+    /// every environment.
+    pub fn build_config(&self) -> Result<Config, Error> {
         let mut new_config = self.current_config.clone();
 
         // Update network configuration
@@ -469,6 +530,8 @@ impl SettingsState {
                     network_name: "mantra-mainnet".to_string(),
                     chain_id: "mantra-mainnet-1".to_string(),
                     rpc_url: "https://rpc.mantrachain.io/".to_string(),
+                    rpc_fallback_urls: Vec::new(),
+                    grpc_url: None,
                     gas_price: 0.025,
                     gas_adjustment: 1.3,
                     native_denom: "uom".to_string(),
@@ -477,6 +540,8 @@ impl SettingsState {
                     evm_rpc_url: None,
                     #[cfg(feature = "evm")]
                     evm_chain_id: None,
+                    #[cfg(feature = "evm")]
+                    evm_rpc_fallback_urls: Vec::new(),
                 };
             }
             NetworkEnvironment::Testnet => {
@@ -484,6 +549,8 @@ impl SettingsState {
                     network_name: "mantra-dukong".to_string(),
                     chain_id: "mantra-dukong-1".to_string(),
                     rpc_url: "https://rpc.dukong.mantrachain.io/".to_string(),
+                    rpc_fallback_urls: Vec::new(),
+                    grpc_url: None,
                     gas_price: 0.025,
                     gas_adjustment: 1.3,
                     native_denom: "uom".to_string(),
@@ -492,22 +559,40 @@ impl SettingsState {
                     evm_rpc_url: None,
                     #[cfg(feature = "evm")]
                     evm_chain_id: None,
+                    #[cfg(feature = "evm")]
+                    evm_rpc_fallback_urls: Vec::new(),
                 };
             }
             NetworkEnvironment::Custom => {
                 new_config.network.network_name = self.network_form.custom_name.value.clone();
                 new_config.network.rpc_url = self.network_form.custom_rpc.value.clone();
-
-                // Parse gas price and adjustment
-                if let Ok(gas_price) = self.network_form.gas_price.value.parse::<f64>() {
-                    new_config.network.gas_price = gas_price;
-                }
-                if let Ok(gas_adj) = self.network_form.gas_adjustment.value.parse::<f64>() {
-                    new_config.network.gas_adjustment = gas_adj;
-                }
             }
         }
 
+        new_config.network.gas_price = parse_positive(&self.network_form.gas_price)?;
+        new_config.network.gas_adjustment = parse_positive(&self.network_form.gas_adjustment)?;
+        let fee_denom = self.network_form.fee_denom.value.trim();
+        if fee_denom.is_empty() {
+            return Err(Error::Config("Fee Denom must not be empty".to_string()));
+        }
+        new_config.network.native_denom = fee_denom.to_string();
+        let grpc_url = self.network_form.grpc_url.value.trim();
+        new_config.network.grpc_url = (!grpc_url.is_empty()).then(|| grpc_url.to_string());
+
+        let slippage = self.display_form.default_slippage.value.trim();
+        if !slippage
+            .parse::<f64>()
+            .is_ok_and(|slippage| slippage > 0.0 && slippage <= 100.0)
+        {
+            return Err(Error::Config(
+                "Default Slippage must be a percentage between 0 and 100".to_string(),
+            ));
+        }
+        new_config.preferences = Preferences {
+            default_slippage: slippage.to_string(),
+            theme: self.display_form.theme.config_name().to_string(),
+        };
+
         // Update wallet configuration if in import mode and mnemonic is provided
         if self.wallet_form.import_mode
             && !self.wallet_form.mnemonic_input.value.is_empty()
@@ -516,7 +601,12 @@ impl SettingsState {
             new_config.mnemonic = Some(self.wallet_form.mnemonic_input.value.clone());
         }
 
-        // Save to file
+        Ok(new_config)
+    }
+
+    /// Save current settings to the config file
+    pub fn save_settings(&mut self) -> Result<Config, Error> {
+        let new_config = self.build_config()?;
         let config_path = Config::default_path();
         new_config.save(&config_path)?;
 
@@ -543,7 +633,7 @@ impl SettingsState {
         match self.current_section {
             SettingsSection::Network => {
                 self.network_form.form_state.current_field =
-                    (self.network_form.form_state.current_field + 1) % 4; // 4 fields in network section
+                    (self.network_form.form_state.current_field + 1) % NETWORK_FIELD_COUNT;
             }
             SettingsSection::Wallet => {
                 self.wallet_form.form_state.current_field =
@@ -551,7 +641,7 @@ impl SettingsState {
             }
             SettingsSection::Display => {
                 self.display_form.form_state.current_field =
-                    (self.display_form.form_state.current_field + 1) % 3; // 3 fields in display section
+                    (self.display_form.form_state.current_field + 1) % DISPLAY_FIELD_COUNT;
             }
         }
     }
@@ -561,7 +651,7 @@ impl SettingsState {
         match self.current_section {
             SettingsSection::Network => {
                 if self.network_form.form_state.current_field == 0 {
-                    self.network_form.form_state.current_field = 3; // wrap to last field
+                    self.network_form.form_state.current_field = NETWORK_FIELD_COUNT - 1;
                 } else {
                     self.network_form.form_state.current_field -= 1;
                 }
@@ -571,7 +661,7 @@ impl SettingsState {
             }
             SettingsSection::Display => {
                 if self.display_form.form_state.current_field == 0 {
-                    self.display_form.form_state.current_field = 2; // wrap to last field
+                    self.display_form.form_state.current_field = DISPLAY_FIELD_COUNT - 1;
                 } else {
                     self.display_form.form_state.current_field -= 1;
                 }
@@ -583,8 +673,9 @@ impl SettingsState {
     pub fn is_current_field_editable(&self) -> bool {
         match self.current_section {
             SettingsSection::Network => {
-                // Network fields are editable when custom environment is selected
+                // Name and RPC endpoint are only editable for a custom network
                 matches!(self.network_form.environment, NetworkEnvironment::Custom)
+                    || self.network_form.form_state.current_field >= 2
             }
             SettingsSection::Wallet => {
                 // Wallet field is editable when in import mode
@@ -605,6 +696,8 @@ impl SettingsState {
                 1 => Some("settings_network_rpc".to_string()),
                 2 => Some("settings_gas_price".to_string()),
                 3 => Some("settings_gas_adjustment".to_string()),
+                4 => Some("settings_fee_denom".to_string()),
+                5 => Some("settings_grpc_url".to_string()),
                 _ => None,
             },
             SettingsSection::Wallet => Some("settings_wallet_mnemonic".to_string()),
@@ -612,10 +705,51 @@ impl SettingsState {
                 0 => Some("settings_balance_refresh".to_string()),
                 1 => Some("settings_pool_refresh".to_string()),
                 2 => Some("settings_decimal_precision".to_string()),
+                3 => Some("settings_default_slippage".to_string()),
                 _ => None,
             },
         }
     }
+
+    /// Move the form focus to the field with the focus manager id `id`
+    pub fn focus_field(&mut self, id: &str) {
+        let (section, field) = match id {
+            "settings_network_name" => (SettingsSection::Network, 0),
+            "settings_network_rpc" => (SettingsSection::Network, 1),
+            "settings_gas_price" => (SettingsSection::Network, 2),
+            "settings_gas_adjustment" => (SettingsSection::Network, 3),
+            "settings_fee_denom" => (SettingsSection::Network, 4),
+            "settings_grpc_url" => (SettingsSection::Network, 5),
+            "settings_wallet_mnemonic" => (SettingsSection::Wallet, 0),
+            "settings_balance_refresh" => (SettingsSection::Display, 0),
+            "settings_pool_refresh" => (SettingsSection::Display, 1),
+            "settings_decimal_precision" => (SettingsSection::Display, 2),
+            "settings_default_slippage" => (SettingsSection::Display, 3),
+            _ => return,
+        };
+        self.current_section = section;
+        if let Some(index) = SettingsSection::all().iter().position(|&s| s == section) {
+            self.section_list_state.select(Some(index));
+        }
+        let form_state = match section {
+            SettingsSection::Network => &mut self.network_form.form_state,
+            SettingsSection::Wallet => &mut self.wallet_form.form_state,
+            SettingsSection::Display => &mut self.display_form.form_state,
+        };
+        form_state.current_field = field;
+        form_state.editing = false;
+    }
+}
+
+/// Parse a field that must hold a positive number
+fn parse_positive(field: &InputField) -> Result<f64, Error> {
+    field
+        .value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| *value > 0.0)
+        .ok_or_else(|| Error::Config(format!("{} must be a positive number", field.label)))
 }
 
 /// Render the settings screen with standard layout
@@ -775,71 +909,42 @@ fn render_network_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
         .wrap(Wrap { trim: true });
     frame.render_widget(env_paragraph, chunks[1]);
 
-    // Form fields (only show for custom environment)
-    if state.network_form.environment == NetworkEnvironment::Custom {
-        let form_chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),
-                Constraint::Length(3),
-                Constraint::Length(3),
-                Constraint::Length(3),
-            ])
-            .split(chunks[2]);
-
-        // Render input fields - note: these will be rendered by global focus system later
-        render_input_field(
-            frame,
-            form_chunks[0],
-            &state.network_form.custom_name,
-            state.network_form.form_state.current_field == 0
-                && state.network_form.form_state.is_editing(),
-        );
-        render_input_field(
-            frame,
-            form_chunks[1],
-            &state.network_form.custom_rpc,
-            state.network_form.form_state.current_field == 1
-                && state.network_form.form_state.is_editing(),
-        );
-        render_input_field(
-            frame,
-            form_chunks[2],
-            &state.network_form.gas_price,
-            state.network_form.form_state.current_field == 2
-                && state.network_form.form_state.is_editing(),
-        );
-        render_input_field(
-            frame,
-            form_chunks[3],
-            &state.network_form.gas_adjustment,
-            state.network_form.form_state.current_field == 3
-                && state.network_form.form_state.is_editing(),
-        );
-    } else {
-        // Show current network info for mainnet/testnet
-        let network_info = match state.network_form.environment {
-            NetworkEnvironment::Mainnet => {
-                vec![
-                    "Network: Mantra Mainnet",
-                    "RPC: https://rpc.mantrachain.io/",
-                    "Gas Price: 0.025 uom",
-                    "Gas Adjustment: 1.3",
-                ]
-            }
-            NetworkEnvironment::Testnet => {
-                vec![
-                    "Network: Mantra Dukong Testnet",
-                    "RPC: https://rpc.dukong.mantrachain.io/",
-                    "Gas Price: 0.025 uom",
-                    "Gas Adjustment: 1.3",
-                ]
-            }
-            NetworkEnvironment::Custom => vec![], // Should not reach here
+    // Form fields: a custom network edits every field, presets only the fee
+    // settings and gRPC endpoint
+    let is_custom = state.network_form.environment == NetworkEnvironment::Custom;
+    let fields: Vec<(usize, &InputField)> = [
+        &state.network_form.custom_name,
+        &state.network_form.custom_rpc,
+        &state.network_form.gas_price,
+        &state.network_form.gas_adjustment,
+        &state.network_form.fee_denom,
+        &state.network_form.grpc_url,
+    ]
+    .into_iter()
+    .enumerate()
+    .filter(|(index, _)| is_custom || *index >= 2)
+    .collect();
+
+    let mut constraints = Vec::new();
+    if !is_custom {
+        constraints.push(Constraint::Length(4));
+    }
+    constraints.extend(fields.iter().map(|_| Constraint::Length(3)));
+    let form_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(chunks[2]);
+
+    if !is_custom {
+        // Show the preset's network and endpoint
+        let (network, rpc) = match state.network_form.environment {
+            NetworkEnvironment::Mainnet => ("Mantra Mainnet", "https://rpc.mantrachain.io/"),
+            _ => (
+                "Mantra Dukong Testnet",
+                "https://rpc.dukong.mantrachain.io/",
+            ),
         };
-
-        let info_text = network_info.join("\n");
-        let info_paragraph = Paragraph::new(info_text)
+        let info_paragraph = Paragraph::new(format!("Network: {}\nRPC: {}", network, rpc))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -847,7 +952,18 @@ fn render_network_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
             )
             .style(Style::default().fg(Color::Green))
             .wrap(Wrap { trim: true });
-        frame.render_widget(info_paragraph, chunks[2]);
+        frame.render_widget(info_paragraph, form_chunks[0]);
+    }
+
+    let offset = usize::from(!is_custom);
+    for (chunk, (index, field)) in fields.into_iter().enumerate() {
+        render_input_field(
+            frame,
+            form_chunks[chunk + offset],
+            field,
+            state.network_form.form_state.current_field == index
+                && state.network_form.form_state.is_editing(),
+        );
     }
 
     // Actions
@@ -1068,6 +1184,7 @@ fn render_display_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
         ])
         .split(chunks[3]);
 
@@ -1092,6 +1209,13 @@ fn render_display_settings(frame: &mut Frame, area: Rect, state: &mut SettingsSt
         state.display_form.form_state.current_field == 2
             && state.display_form.form_state.is_editing(),
     );
+    render_input_field(
+        frame,
+        form_chunks[3],
+        &state.display_form.default_slippage,
+        state.display_form.form_state.current_field == 3
+            && state.display_form.form_state.is_editing(),
+    );
 
     // Actions
     let actions_text = if state.has_changes {
@@ -1414,6 +1538,8 @@ pub fn get_focusable_components_for_section(section: SettingsSection) -> Vec<Str
             "settings_network_rpc".to_string(),
             "settings_gas_price".to_string(),
             "settings_gas_adjustment".to_string(),
+            "settings_fee_denom".to_string(),
+            "settings_grpc_url".to_string(),
         ],
         SettingsSection::Wallet => vec![
             "settings_wallet_import_mode".to_string(),
@@ -1425,7 +1551,46 @@ pub fn get_focusable_components_for_section(section: SettingsSection) -> Vec<Str
             "settings_balance_refresh".to_string(),
             "settings_pool_refresh".to_string(),
             "settings_decimal_precision".to_string(),
+            "settings_default_slippage".to_string(),
             "settings_auto_refresh".to_string(),
         ],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forms_build_validated_config() {
+        let mut state = SettingsState::new(Config::default());
+        state.network_form.environment = NetworkEnvironment::Testnet;
+        state.network_form.fee_denom.set_value("uusdc");
+        state
+            .network_form
+            .grpc_url
+            .set_value("http://localhost:9090");
+        state.display_form.default_slippage.set_value("0.5");
+        state.display_form.theme = Theme::HighContrast;
+
+        let config = state.build_config().unwrap();
+        assert_eq!(config.network.chain_id, "mantra-dukong-1");
+        assert_eq!(config.network.native_denom, "uusdc");
+        assert_eq!(
+            config.network.grpc_url.as_deref(),
+            Some("http://localhost:9090")
+        );
+        assert_eq!(config.preferences.default_slippage, "0.5");
+
+        // Preferences survive a reload into the forms
+        let reloaded = SettingsState::new(config);
+        assert_eq!(reloaded.display_form.theme, Theme::HighContrast);
+        assert_eq!(reloaded.network_form.fee_denom.value, "uusdc");
+
+        state.display_form.default_slippage.set_value("150");
+        assert!(state.build_config().is_err());
+        state.display_form.default_slippage.set_value("1");
+        state.network_form.gas_adjustment.set_value("-1");
+        assert!(state.build_config().is_err());
+    }
+}
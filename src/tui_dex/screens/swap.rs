@@ -14,6 +14,7 @@ use crate::tui_dex::{
         status_bar::render_status_bar,
     },
 };
+use cosmwasm_std::{Coin, Decimal, Uint128};
 use mantra_dex_std::pool_manager::SimulationResponse;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -22,8 +23,13 @@ use ratatui::{
     widgets::{Block, Borders, Padding, Paragraph, Wrap},
     Frame,
 };
+use std::str::FromStr;
+use std::time::Duration;
 use tui_input::InputRequest;
 
+/// Quiet time after the last form change before the swap is simulated
+pub const SIMULATION_DEBOUNCE: Duration = Duration::from_millis(500);
+
 /// Input focus states for the swap screen
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SwapInputFocus {
@@ -31,6 +37,7 @@ pub enum SwapInputFocus {
     FromToken,
     FromAmount,
     Slippage,
+    MinReceive,
     Execute,
 }
 
@@ -47,6 +54,8 @@ pub struct SwapScreenState {
     pub from_amount_input: TextInput,
     /// Slippage tolerance input
     pub slippage_input: TextInput,
+    /// Optional minimum output, overriding the one derived from the slippage
+    pub min_receive_input: TextInput,
 
     /// Available tokens for selection
     pub available_tokens: Vec<String>,
@@ -56,6 +65,14 @@ pub struct SwapScreenState {
     pub simulation_timer: Option<std::time::Instant>,
     /// Last input change time for simulation delay
     pub last_input_change: Option<std::time::Instant>,
+    /// Bumped on every form change; a simulation result for an older value
+    /// is discarded
+    pub simulation_seq: u64,
+    /// A simulation has been requested and its result has not arrived yet
+    pub simulation_pending: bool,
+    /// Slippage tolerance agreed in the confirmation dialog, used instead of
+    /// the slippage field when a minimum output was entered
+    pub confirmed_slippage: Option<String>,
 }
 
 impl Default for SwapScreenState {
@@ -78,6 +95,10 @@ impl Default for SwapScreenState {
             .with_value("1.0")
             .with_placeholder("1.0");
 
+        let min_receive_input = TextInput::new("Min Receive (optional)")
+            .with_type(InputType::Amount)
+            .with_placeholder("from slippage");
+
         // Set initial focus
         from_amount_input.set_focused(false);
 
@@ -87,10 +108,14 @@ impl Default for SwapScreenState {
             from_token_dropdown,
             from_amount_input,
             slippage_input,
+            min_receive_input,
             available_tokens: Vec::new(), // Will be populated when pool is selected
             available_pools: Vec::new(),  // Will be populated from blockchain data
             simulation_timer: None,
             last_input_change: None,
+            simulation_seq: 0,
+            simulation_pending: false,
+            confirmed_slippage: None,
         };
 
         // Apply initial focus
@@ -255,6 +280,7 @@ impl SwapScreenState {
         self.from_token_dropdown.set_active(false);
         self.from_amount_input.set_focused(false);
         self.slippage_input.set_focused(false);
+        self.min_receive_input.set_focused(false);
     }
 
     /// Public wrapper to clear all focus states (used by external modules)
@@ -273,6 +299,7 @@ impl SwapScreenState {
             }
             SwapInputFocus::FromAmount => self.from_amount_input.set_focused(true),
             SwapInputFocus::Slippage => self.slippage_input.set_focused(true),
+            SwapInputFocus::MinReceive => self.min_receive_input.set_focused(true),
             SwapInputFocus::Execute => {} // Button focus handled separately
         }
     }
@@ -285,13 +312,14 @@ impl SwapScreenState {
     /// Mark input change for simulation trigger
     pub fn mark_input_change(&mut self) {
         self.last_input_change = Some(std::time::Instant::now());
+        self.simulation_seq += 1;
     }
 
-    /// Check if simulation should be triggered (after 5 seconds of inactivity)
+    /// Check if simulation should be triggered (after [`SIMULATION_DEBOUNCE`]
+    /// of inactivity on a complete form)
     pub fn should_trigger_simulation(&mut self) -> bool {
         if let Some(last_change) = self.last_input_change {
-            let elapsed = last_change.elapsed().as_secs();
-            elapsed >= 5 && self.validate()
+            last_change.elapsed() >= SIMULATION_DEBOUNCE && self.validate()
         } else {
             false
         }
@@ -303,6 +331,12 @@ impl SwapScreenState {
         self.simulation_timer = None;
     }
 
+    /// Whether a quote may not match the form any more, because the form
+    /// changed since or a newer simulation is running
+    pub fn is_quote_outdated(&self) -> bool {
+        self.last_input_change.is_some() || self.simulation_pending
+    }
+
     /// Check if any list is currently in editing mode
     pub fn is_any_list_editing(&self) -> bool {
         self.pool_dropdown.is_editing || self.from_token_dropdown.is_editing
@@ -402,7 +436,7 @@ impl SwapScreenState {
                 }
                 false
             }
-            SwapInputFocus::Slippage => {
+            SwapInputFocus::Slippage | SwapInputFocus::MinReceive => {
                 let input_request = match key.code {
                     KeyCode::Char(c) => Some(InputRequest::InsertChar(c)),
                     KeyCode::Backspace => Some(InputRequest::DeletePrevChar),
@@ -414,8 +448,13 @@ impl SwapScreenState {
                     _ => None,
                 };
 
+                let input = if self.input_focus == SwapInputFocus::Slippage {
+                    &mut self.slippage_input
+                } else {
+                    &mut self.min_receive_input
+                };
                 if let Some(request) = input_request {
-                    if self.slippage_input.handle_input(request).is_some() {
+                    if input.handle_input(request).is_some() {
                         self.mark_input_change();
                         return true;
                     }
@@ -461,7 +500,8 @@ impl SwapScreenState {
             SwapInputFocus::Pool => SwapInputFocus::FromToken,
             SwapInputFocus::FromToken => SwapInputFocus::FromAmount,
             SwapInputFocus::FromAmount => SwapInputFocus::Slippage,
-            SwapInputFocus::Slippage => SwapInputFocus::Execute,
+            SwapInputFocus::Slippage => SwapInputFocus::MinReceive,
+            SwapInputFocus::MinReceive => SwapInputFocus::Execute,
             SwapInputFocus::Execute => SwapInputFocus::Pool,
         };
         self.clear_focus();
@@ -475,7 +515,8 @@ impl SwapScreenState {
             SwapInputFocus::FromToken => SwapInputFocus::Pool,
             SwapInputFocus::FromAmount => SwapInputFocus::FromToken,
             SwapInputFocus::Slippage => SwapInputFocus::FromAmount,
-            SwapInputFocus::Execute => SwapInputFocus::Slippage,
+            SwapInputFocus::MinReceive => SwapInputFocus::Slippage,
+            SwapInputFocus::Execute => SwapInputFocus::MinReceive,
         };
         self.clear_focus();
         self.set_focus();
@@ -487,8 +528,9 @@ impl SwapScreenState {
         let from_token_valid = self.from_token_dropdown.get_selected_value().is_some();
         let amount_valid = self.from_amount_input.validate();
         let slippage_valid = self.slippage_input.validate();
+        let min_receive_valid = self.min_receive_input.validate();
 
-        pool_valid && from_token_valid && amount_valid && slippage_valid
+        pool_valid && from_token_valid && amount_valid && slippage_valid && min_receive_valid
     }
 
     /// Get detailed validation errors for user feedback
@@ -519,13 +561,17 @@ impl SwapScreenState {
             }
         }
 
+        if !self.min_receive_input.validate() {
+            errors.push("Please enter a valid minimum output or leave it empty".to_string());
+        }
+
         errors
     }
 
     /// Show confirmation modal using global app state
     pub fn show_confirmation_modal(&mut self, swap_details: &SwapDetails) -> String {
         let message = format!(
-            "Confirm swap:\n{} {} → {} {}\nPool: {}\nSlippage: {}%\nExpected output: {} {}\nPrice impact: {:.2}%\nTotal fees: {} {}",
+            "Confirm swap:\n{} {} → {} {}\nRoute: {}\nSlippage: {}%\nExpected output: {} {}\nMinimum received: {} {}\nPrice impact: {:.2}%\nTotal fees: {} {}",
             swap_details.from_amount,
             swap_details.from_token,
             swap_details.to_amount,
@@ -534,9 +580,11 @@ impl SwapScreenState {
             swap_details.slippage,
             swap_details.expected_output,
             swap_details.to_token,
+            swap_details.min_receive,
+            swap_details.to_token,
             swap_details.price_impact,
             swap_details.fee_amount,
-            swap_details.from_token
+            swap_details.to_token
         );
 
         // Return the message for the global app to handle
@@ -560,10 +608,77 @@ pub struct SwapDetails {
     pub pool_name: String,
     pub slippage: String,
     pub expected_output: String,
+    pub min_receive: String,
     pub price_impact: f64,
     pub fee_amount: String,
 }
 
+/// Simulated swap for the current form, with the price impact from the
+/// pool's curve
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapQuote {
+    pub pool_id: String,
+    pub offer: Coin,
+    pub ask_denom: String,
+    /// Display symbols of the offered and asked tokens
+    pub offer_symbol: String,
+    pub ask_symbol: String,
+    pub ask_decimals: u8,
+    pub simulation: SimulationResponse,
+    pub price_impact: Decimal,
+    /// Set when the swap is large or drains an imbalanced stable pool
+    pub warnings: Vec<String>,
+}
+
+impl SwapQuote {
+    /// Swap, protocol, burn and extra fees, in the asked token
+    pub fn total_fees(&self) -> Uint128 {
+        self.simulation.swap_fee_amount
+            + self.simulation.protocol_fee_amount
+            + self.simulation.burn_fee_amount
+            + self.simulation.extra_fees_amount
+    }
+
+    /// Price impact in percent
+    pub fn price_impact_percent(&self) -> f64 {
+        (self.price_impact * Decimal::percent(10_000))
+            .to_string()
+            .parse()
+            .unwrap_or_default()
+    }
+
+    /// An amount of the asked token in whole tokens
+    pub fn ask_units(&self, amount: Uint128) -> f64 {
+        amount.u128() as f64 / 10f64.powi(self.ask_decimals as i32)
+    }
+
+    /// Least output the swap accepts: `min_receive` when given, otherwise
+    /// the expected output less `slippage_percent`
+    pub fn min_receive(&self, slippage_percent: Decimal, min_receive: Option<Uint128>) -> Uint128 {
+        min_receive.unwrap_or_else(|| {
+            let tolerance = (slippage_percent * Decimal::percent(1)).min(Decimal::one());
+            self.simulation
+                .return_amount
+                .mul_floor(Decimal::one() - tolerance)
+        })
+    }
+
+    /// Slippage tolerance, in percent, under which the swap accepts no less
+    /// than `min_receive`
+    pub fn slippage_for(&self, min_receive: Uint128) -> Decimal {
+        let expected = self.simulation.return_amount;
+        if expected.is_zero() || min_receive >= expected {
+            return Decimal::zero();
+        }
+        Decimal::from_ratio(expected - min_receive, expected) * Decimal::percent(10_000)
+    }
+}
+
+/// Parse a whole-token amount such as `1.5` into base units
+pub fn parse_token_amount(amount: &str, decimals: u8) -> Option<Uint128> {
+    crate::amount::parse_units_u128(amount, decimals).ok()
+}
+
 // Global swap screen state - in a real implementation this would be part of the app state
 static mut SWAP_SCREEN_STATE: Option<SwapScreenState> = None;
 
@@ -599,8 +714,10 @@ pub fn render_swap(f: &mut Frame, app: &App) {
     // Check for simulation trigger (this should ideally be in the main event loop, but putting here for now)
     let swap_state = get_swap_screen_state();
     if swap_state.should_trigger_simulation() {
-        // Reset the timer to prevent repeated triggers
         if let Some(sender) = app.get_event_sender() {
+            // Reset the timer to prevent repeated triggers
+            swap_state.reset_simulation_timer();
+            swap_state.simulation_pending = true;
             let _ = sender.send(crate::tui_dex::events::Event::TriggerSimulation);
         }
     }
@@ -654,6 +771,7 @@ fn render_swap_interface(f: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(8), // Token selection list
             Constraint::Length(5), // From amount input (increased for better visibility)
             Constraint::Length(5), // Slippage tolerance (increased for better visibility)
+            Constraint::Length(5), // Minimum output
         ])
         .split(block.inner(area));
 
@@ -664,6 +782,7 @@ fn render_swap_interface(f: &mut Frame, area: Rect, app: &App) {
     render_from_token_input(f, input_chunks[1], app, swap_state);
     render_from_amount_input(f, input_chunks[2], app, swap_state);
     render_slippage_input(f, input_chunks[3], app, swap_state);
+    swap_state.min_receive_input.render(f, input_chunks[4]);
 
     f.render_widget(block, area);
 }
@@ -1002,8 +1121,18 @@ fn render_simulation_results(f: &mut Frame, area: Rect, app: &App) {
     let swap_state = get_swap_screen_state();
 
     // Enhanced loading state display for swap operations
-    let content = if let Some(ref simulation) = app.state.swap_state.simulation_result {
-        render_simulation_details(simulation)
+    let content = if let Some(ref quote) = app.state.swap_state.quote {
+        let mut lines = render_simulation_details(quote, swap_state);
+        if swap_state.is_quote_outdated() {
+            lines.insert(
+                0,
+                Line::from(vec![Span::styled(
+                    "⏳ Updating for the latest input...",
+                    Style::default().fg(Color::Yellow),
+                )]),
+            );
+        }
+        lines
     } else if matches!(app.state.loading_state, LoadingState::Loading { .. }) {
         // Show detailed loading information
         if let LoadingState::Loading { message, .. } = &app.state.loading_state {
@@ -1033,32 +1162,11 @@ fn render_simulation_results(f: &mut Frame, area: Rect, app: &App) {
                 Style::default().fg(Color::Yellow),
             )])]
         }
-    } else if swap_state.should_trigger_simulation() {
-        vec![
-            Line::from(vec![Span::styled(
-                "⏳ Auto-simulation will run soon...",
-                Style::default().fg(Color::Yellow),
-            )]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "Form completed - simulation triggered after 5s of inactivity",
-                Style::default().fg(Color::DarkGray),
-            )]),
-        ]
-    } else if swap_state.last_input_change.is_some() {
-        let elapsed = swap_state.last_input_change.unwrap().elapsed().as_secs();
-        let remaining = 5_u64.saturating_sub(elapsed);
-        vec![
-            Line::from(vec![Span::styled(
-                format!("⏱️  Simulation in {}s...", remaining),
-                Style::default().fg(Color::Yellow),
-            )]),
-            Line::from(""),
-            Line::from(vec![Span::styled(
-                "Waiting for form completion and input inactivity",
-                Style::default().fg(Color::DarkGray),
-            )]),
-        ]
+    } else if swap_state.is_quote_outdated() {
+        vec![Line::from(vec![Span::styled(
+            "⏳ Simulating...",
+            Style::default().fg(Color::Yellow),
+        )])]
     } else {
         vec![
             Line::from(vec![Span::styled(
@@ -1067,7 +1175,7 @@ fn render_simulation_results(f: &mut Frame, area: Rect, app: &App) {
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
-                "Complete the swap form to run simulation",
+                "Complete the swap form; it is simulated as you type",
                 Style::default().fg(Color::DarkGray),
             )]),
         ]
@@ -1081,17 +1189,63 @@ fn render_simulation_results(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render detailed simulation results
-fn render_simulation_details(simulation: &SimulationResponse) -> Vec<Line> {
-    vec![
+fn render_simulation_details<'a>(
+    quote: &'a SwapQuote,
+    swap_state: &SwapScreenState,
+) -> Vec<Line<'a>> {
+    let ask = |amount: Uint128| format!("{} {}", quote.ask_units(amount), quote.ask_symbol);
+    let slippage = Decimal::from_str(swap_state.slippage_input.value().trim()).unwrap_or_default();
+    let min_receive_override =
+        parse_token_amount(swap_state.min_receive_input.value(), quote.ask_decimals)
+            .filter(|amount| !amount.is_zero());
+    let min_receive = quote.min_receive(slippage, min_receive_override);
+    let impact_style = if quote.price_impact > Decimal::percent(5) {
+        Style::default().fg(Color::Red)
+    } else if quote.price_impact > Decimal::percent(1) {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Green)
+    };
+
+    let mut lines = vec![
         Line::from(vec![
             Span::styled("Expected Output: ", Style::default().fg(Color::White)),
             Span::styled(
-                format!("{}", simulation.return_amount),
+                ask(quote.simulation.return_amount),
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Minimum Received: ", Style::default().fg(Color::White)),
+            Span::styled(ask(min_receive), Style::default().fg(Color::Cyan)),
+            Span::styled(
+                if min_receive_override.is_some() {
+                    format!(" (≈{}% slippage)", quote.slippage_for(min_receive))
+                } else {
+                    format!(" ({}% slippage)", slippage)
+                },
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Price Impact: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format!("{:.2}%", quote.price_impact_percent()),
+                impact_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Route: ", Style::default().fg(Color::White)),
+            Span::styled(
+                format!(
+                    "{} → {} via pool {}",
+                    quote.offer_symbol, quote.ask_symbol, quote.pool_id
+                ),
+                Style::default().fg(Color::Cyan),
+            ),
+        ]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Fee Breakdown:",
@@ -1100,38 +1254,51 @@ fn render_simulation_details(simulation: &SimulationResponse) -> Vec<Line> {
         Line::from(vec![
             Span::styled("  • Swap Fee: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{}", simulation.swap_fee_amount),
+                ask(quote.simulation.swap_fee_amount),
                 Style::default().fg(Color::Yellow),
             ),
         ]),
         Line::from(vec![
             Span::styled("  • Protocol Fee: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{}", simulation.protocol_fee_amount),
+                ask(quote.simulation.protocol_fee_amount),
                 Style::default().fg(Color::Yellow),
             ),
         ]),
         Line::from(vec![
             Span::styled("  • Burn Fee: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{}", simulation.burn_fee_amount),
+                ask(quote.simulation.burn_fee_amount),
                 Style::default().fg(Color::Yellow),
             ),
         ]),
-        Line::from(""),
         Line::from(vec![
-            Span::styled("Total Fees: ", Style::default().fg(Color::White)),
+            Span::styled("  • Extra Fees: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!(
-                    "{}",
-                    simulation.swap_fee_amount
-                        + simulation.protocol_fee_amount
-                        + simulation.burn_fee_amount
-                ),
-                Style::default().fg(Color::Red),
+                ask(quote.simulation.extra_fees_amount),
+                Style::default().fg(Color::Yellow),
             ),
         ]),
-    ]
+        Line::from(vec![
+            Span::styled("Total Fees: ", Style::default().fg(Color::White)),
+            Span::styled(ask(quote.total_fees()), Style::default().fg(Color::Red)),
+        ]),
+    ];
+
+    if min_receive > quote.simulation.return_amount {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "⚠ Minimum received is above the expected output; the swap would fail",
+            Style::default().fg(Color::Red),
+        )));
+    }
+    for warning in &quote.warnings {
+        lines.push(Line::from(Span::styled(
+            format!("⚠ {}", warning),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+    lines
 }
 
 /// Calculate estimated output based on simulation or basic logic
@@ -1262,6 +1429,7 @@ pub fn execute_swap_with_confirmation() {
             .to_string(),
         slippage: slippage.to_string(),
         expected_output: expected_output.clone(),
+        min_receive: expected_output.clone(),
         price_impact,
         fee_amount,
     };
@@ -1314,7 +1482,11 @@ pub fn handle_confirmation_response(confirmed: bool) -> Option<crate::tui_dex::e
             return None;
         }
 
-        let slippage = swap_state.slippage_input.value();
+        // A minimum-output override was converted to a tolerance at confirmation
+        let slippage = swap_state
+            .confirmed_slippage
+            .take()
+            .unwrap_or_else(|| swap_state.slippage_input.value().to_string());
 
         // Get the "to" token from the selected pool
         let to_token = if let Some(pool_name) = swap_state.pool_dropdown.get_selected_label() {
@@ -1340,13 +1512,14 @@ pub fn handle_confirmation_response(confirmed: bool) -> Option<crate::tui_dex::e
             to_asset: to_token,
             amount: from_amount.to_string(),
             pool_id: Some(pool_id_str.to_string()),
-            slippage_tolerance: Some(slippage.to_string()),
+            slippage_tolerance: Some(slippage),
         };
 
         crate::tui_dex::utils::logger::log_info("ExecuteSwap event created successfully");
         Some(execute_event)
     } else {
         // User cancelled
+        swap_state.confirmed_slippage = None;
         crate::tui_dex::utils::logger::log_info("User cancelled swap execution");
         None
     }
@@ -1478,6 +1651,51 @@ mod tests {
         assert!(state.from_amount_input.is_valid());
     }
 
+    #[test]
+    fn test_quote_min_receive_and_debounce() {
+        let quote = SwapQuote {
+            pool_id: "o.uom.uusdc".to_string(),
+            offer: Coin::new(1_000_000u128, "uom"),
+            ask_denom: "uusdc".to_string(),
+            offer_symbol: "OM".to_string(),
+            ask_symbol: "USDC".to_string(),
+            ask_decimals: 6,
+            simulation: SimulationResponse {
+                return_amount: Uint128::new(2_000_000),
+                slippage_amount: Uint128::zero(),
+                swap_fee_amount: Uint128::new(1_000),
+                protocol_fee_amount: Uint128::new(500),
+                burn_fee_amount: Uint128::zero(),
+                extra_fees_amount: Uint128::new(100),
+            },
+            price_impact: Decimal::permille(12),
+            warnings: Vec::new(),
+        };
+        assert_eq!(quote.total_fees(), Uint128::new(1_600));
+        assert_eq!(quote.price_impact_percent(), 1.2);
+        assert_eq!(quote.ask_units(quote.simulation.return_amount), 2.0);
+
+        // 1% slippage, or an explicit minimum of 1.9 USDC (5%)
+        assert_eq!(
+            quote.min_receive(Decimal::one(), None),
+            Uint128::new(1_980_000)
+        );
+        let explicit = parse_token_amount("1.9", 6).unwrap();
+        assert_eq!(quote.min_receive(Decimal::one(), Some(explicit)), explicit);
+        assert_eq!(quote.slippage_for(explicit), Decimal::percent(5));
+        assert_eq!(quote.slippage_for(Uint128::new(3_000_000)), Decimal::zero());
+        assert_eq!(parse_token_amount("abc", 6), None);
+
+        let mut state = SwapScreenState::default();
+        assert!(!state.is_quote_outdated());
+        state.mark_input_change();
+        assert_eq!(state.simulation_seq, 1);
+        assert!(state.is_quote_outdated());
+        // The form is incomplete, so nothing is simulated
+        state.last_input_change = Some(std::time::Instant::now() - SIMULATION_DEBOUNCE);
+        assert!(!state.should_trigger_simulation());
+    }
+
     #[test]
     fn test_calculate_estimated_output() {
         let swap_state = SwapState::default();
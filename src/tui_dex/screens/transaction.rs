@@ -39,6 +39,25 @@ pub struct TransactionState {
     pub export_format: ExportFormat,
     /// Current input mode for forms
     pub input_mode: TransactionInputMode,
+    /// Cross-chain transfers being followed, most recent first
+    pub tracked_transfers: Vec<TrackedTransfer>,
+    /// Input for the transfer to track: `<tx_hash> [chain_id]`
+    pub transfer_input: String,
+}
+
+/// Cross-chain transfer followed in the transfers panel
+#[derive(Debug, Clone)]
+pub struct TrackedTransfer {
+    pub tx_hash: String,
+    pub chain_id: Option<String>,
+    pub status: String,
+    pub dest_tx_hash: Option<String>,
+    /// Error or timeout explanation
+    pub detail: Option<String>,
+    /// Tracking has stopped
+    pub finished: bool,
+    /// Observed statuses with the time they were seen
+    pub history: Vec<(chrono::DateTime<chrono::Utc>, String)>,
 }
 
 /// Transaction view modes
@@ -47,6 +66,7 @@ pub enum TransactionViewMode {
     History,
     Details,
     Export,
+    Transfers,
 }
 
 /// Transaction filtering options
@@ -99,10 +119,66 @@ impl Default for TransactionState {
             show_export_modal: false,
             export_format: ExportFormat::Json,
             input_mode: TransactionInputMode::None,
+            tracked_transfers: Vec::new(),
+            transfer_input: String::new(),
         }
     }
 }
 
+impl TransactionState {
+    /// Add a transfer to the panel, replacing an earlier entry for the same hash
+    pub fn start_tracking(&mut self, tx_hash: String, chain_id: Option<String>) {
+        self.tracked_transfers.retain(|t| t.tx_hash != tx_hash);
+        self.tracked_transfers.insert(
+            0,
+            TrackedTransfer {
+                tx_hash,
+                chain_id,
+                status: "Tracking".to_string(),
+                dest_tx_hash: None,
+                detail: None,
+                finished: false,
+                history: Vec::new(),
+            },
+        );
+    }
+
+    /// Record a status update from the tracking task
+    pub fn apply_transfer_update(
+        &mut self,
+        tx_hash: &str,
+        status: &str,
+        dest_tx_hash: Option<String>,
+        detail: Option<String>,
+        finished: bool,
+    ) {
+        let Some(transfer) = self
+            .tracked_transfers
+            .iter_mut()
+            .find(|t| t.tx_hash == tx_hash)
+        else {
+            return;
+        };
+        if transfer
+            .history
+            .last()
+            .is_none_or(|(_, last)| last != status)
+        {
+            transfer
+                .history
+                .push((chrono::Utc::now(), status.to_string()));
+        }
+        transfer.status = status.to_string();
+        if dest_tx_hash.is_some() {
+            transfer.dest_tx_hash = dest_tx_hash;
+        }
+        if detail.is_some() {
+            transfer.detail = detail;
+        }
+        transfer.finished = finished;
+    }
+}
+
 impl Default for TransactionFilters {
     fn default() -> Self {
         Self {
@@ -188,6 +264,9 @@ pub fn render_transaction_screen(f: &mut Frame, app: &App, transaction_state: &T
         TransactionViewMode::Export => {
             render_export_interface(f, chunks[2], app, transaction_state);
         }
+        TransactionViewMode::Transfers => {
+            render_transfer_tracking(f, chunks[2], transaction_state);
+        }
     }
 
     // Render status bar
@@ -318,13 +397,142 @@ fn render_transaction_history(
     };
 
     let instructions = Paragraph::new(
-        "↑/↓: Navigate | Enter: View Details | E: Export | /: Search | F: Filter | C: Clear",
+        "↑/↓: Navigate | Enter: View Details | E: Export | /: Search | F: Filter | C: Clear | X: Transfers",
     )
     .style(Style::default().fg(Color::Gray));
 
     f.render_widget(instructions, instructions_area);
 }
 
+/// Render the cross-chain transfer tracking panel
+fn render_transfer_tracking(f: &mut Frame, area: Rect, transaction_state: &TransactionState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(8),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+    let input = Paragraph::new(transaction_state.transfer_input.as_str())
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title("Track Transfer: <tx_hash> [chain_id]"),
+        );
+    f.render_widget(input, chunks[0]);
+
+    let status_color = |status: &str| match status {
+        "Completed" => Color::Green,
+        "Failed" | "TimedOut" | "Refunded" | "error" => Color::Red,
+        _ => Color::Yellow,
+    };
+
+    let header = Row::new(vec![
+        Cell::from("Source Tx").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Status").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Destination Tx").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Updated").style(Style::default().add_modifier(Modifier::BOLD)),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let rows: Vec<Row> = transaction_state
+        .tracked_transfers
+        .iter()
+        .map(|transfer| {
+            let status = if transfer.finished {
+                transfer.status.clone()
+            } else {
+                format!("{} …", transfer.status)
+            };
+            Row::new(vec![
+                Cell::from(short_hash(&transfer.tx_hash)),
+                Cell::from(status).style(Style::default().fg(status_color(&transfer.status))),
+                Cell::from(
+                    transfer
+                        .dest_tx_hash
+                        .as_deref()
+                        .map(short_hash)
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Cell::from(
+                    transfer
+                        .history
+                        .last()
+                        .map(|(at, _)| at.format("%H:%M:%S").to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(30),
+            Constraint::Percentage(20),
+            Constraint::Percentage(30),
+            Constraint::Percentage(20),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue))
+            .title(format!(
+                "Cross-chain Transfers ({})",
+                transaction_state.tracked_transfers.len()
+            )),
+    );
+    f.render_widget(table, chunks[1]);
+
+    // Transition history of the most recent transfer
+    let mut lines: Vec<Line> = Vec::new();
+    if let Some(transfer) = transaction_state.tracked_transfers.first() {
+        for (at, status) in &transfer.history {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ", at.format("%H:%M:%S")),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(status.clone(), Style::default().fg(status_color(status))),
+            ]));
+        }
+        if let Some(detail) = &transfer.detail {
+            lines.push(Line::from(Span::styled(
+                detail.clone(),
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+    let history = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Status Transitions"),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(history, chunks[2]);
+
+    let instructions = Paragraph::new("Type a tx hash | Enter: Track | Esc: Back to history")
+        .style(Style::default().fg(Color::Gray));
+    f.render_widget(instructions, chunks[3]);
+}
+
+/// Shorten a transaction hash for table display
+fn short_hash(hash: &str) -> String {
+    if hash.len() > 16 {
+        format!("{}…{}", &hash[..8], &hash[hash.len() - 6..])
+    } else {
+        hash.to_string()
+    }
+}
+
 /// Render detailed view of a selected transaction
 fn render_transaction_details(
     f: &mut Frame,
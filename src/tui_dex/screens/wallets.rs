@@ -0,0 +1,566 @@
+//! Wallets Screen Implementation
+//!
+//! This module provides the wallet manager for the MANTRA DEX SDK TUI:
+//! the saved wallets with their labels, addresses and balances, a QR code of
+//! the selected address, and forms to generate, import (mnemonic or
+//! encrypted keystore file), label and switch to a wallet.
+
+use crate::tui_dex::{
+    app::App,
+    components::{
+        header::render_header, navigation::render_navigation, status_bar::render_status_bar,
+    },
+};
+use crate::wallet::WalletMetadata;
+use cosmwasm_std::Coin;
+use qrcode::{render::unicode::Dense1x2, QrCode};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, Wrap},
+    Frame,
+};
+use std::collections::HashMap;
+
+/// Field being typed into on the wallets screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletInput {
+    /// Password of the selected wallet, to switch to it
+    UnlockPassword,
+    /// New label of the selected wallet
+    Label,
+    /// Name of a wallet being generated
+    GenerateName,
+    /// Password of a wallet being generated
+    GeneratePassword,
+    /// Name of a wallet being imported from a mnemonic
+    ImportName,
+    /// Mnemonic of a wallet being imported
+    ImportMnemonic,
+    /// Password of a wallet being imported from a mnemonic
+    ImportPassword,
+    /// Name of a wallet being imported from a keystore file
+    KeystoreName,
+    /// Path of the keystore file being imported
+    KeystorePath,
+}
+
+impl WalletInput {
+    pub fn prompt(self) -> &'static str {
+        match self {
+            WalletInput::UnlockPassword => "Password to unlock the wallet",
+            WalletInput::Label => "Label (empty to clear)",
+            WalletInput::GenerateName | WalletInput::ImportName | WalletInput::KeystoreName => {
+                "Wallet name"
+            }
+            WalletInput::GeneratePassword | WalletInput::ImportPassword => {
+                "Password to encrypt the wallet with"
+            }
+            WalletInput::ImportMnemonic => "Mnemonic phrase",
+            WalletInput::KeystorePath => "Path of the keystore file",
+        }
+    }
+
+    /// Whether the typed text is masked
+    pub fn is_secret(self) -> bool {
+        matches!(
+            self,
+            WalletInput::UnlockPassword
+                | WalletInput::GeneratePassword
+                | WalletInput::ImportPassword
+                | WalletInput::ImportMnemonic
+        )
+    }
+}
+
+/// Action requested by a completed wallets screen form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalletsAction {
+    Switch {
+        name: String,
+        password: String,
+    },
+    SetLabel {
+        name: String,
+        label: String,
+    },
+    Generate {
+        name: String,
+        password: String,
+    },
+    ImportMnemonic {
+        name: String,
+        mnemonic: String,
+        password: String,
+    },
+    ImportKeystore {
+        name: String,
+        path: String,
+    },
+}
+
+/// Wallets screen state
+#[derive(Debug, Clone, Default)]
+pub struct WalletsState {
+    /// Saved wallets, sorted by name
+    pub wallets: Vec<WalletMetadata>,
+    /// Bank balances by wallet address
+    pub balances: HashMap<String, Vec<Coin>>,
+    pub selected: usize,
+    /// Name of the wallet the client signs with
+    pub active: Option<String>,
+    /// Field being typed into, if a form is open
+    pub input: Option<WalletInput>,
+    pub buffer: String,
+    /// Name and mnemonic entered in earlier steps of the open form
+    pending_name: String,
+    pending_mnemonic: String,
+    /// Mnemonic of a just generated wallet, shown until the next action so
+    /// it can be written down
+    pub new_mnemonic: Option<String>,
+    pub loading: bool,
+    /// Whether the wallet list has been read
+    pub loaded: bool,
+}
+
+impl WalletsState {
+    pub fn selected_wallet(&self) -> Option<&WalletMetadata> {
+        self.wallets.get(self.selected)
+    }
+
+    /// Move the selection by `delta` within the list
+    pub fn move_selection(&mut self, delta: isize) {
+        self.selected = self
+            .selected
+            .saturating_add_signed(delta)
+            .min(self.wallets.len().saturating_sub(1));
+    }
+
+    /// Replace the wallet list, keeping the selected wallet selected
+    pub fn set_wallets(&mut self, mut wallets: Vec<WalletMetadata>) {
+        let selected = self.selected_wallet().map(|wallet| wallet.name.clone());
+        wallets.sort_by(|a, b| a.name.cmp(&b.name));
+        self.selected = selected
+            .and_then(|name| wallets.iter().position(|wallet| wallet.name == name))
+            .unwrap_or(0);
+        self.wallets = wallets;
+        self.loading = false;
+        self.loaded = true;
+    }
+
+    /// Open a form at its first field
+    pub fn begin(&mut self, input: WalletInput) {
+        if matches!(input, WalletInput::UnlockPassword | WalletInput::Label)
+            && self.selected_wallet().is_none()
+        {
+            return;
+        }
+        self.buffer = match input {
+            WalletInput::Label => self
+                .selected_wallet()
+                .and_then(|wallet| wallet.label.clone())
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+        self.new_mnemonic = None;
+        self.input = Some(input);
+    }
+
+    pub fn cancel(&mut self) {
+        self.input = None;
+        self.buffer.clear();
+        self.pending_name.clear();
+        self.pending_mnemonic.clear();
+    }
+
+    /// Complete the current field, moving to the form's next field or
+    /// returning the action once the form is filled in
+    pub fn submit(&mut self) -> Option<WalletsAction> {
+        let input = self.input?;
+        let value = std::mem::take(&mut self.buffer);
+        let value = if input.is_secret() {
+            value
+        } else {
+            value.trim().to_string()
+        };
+        if value.is_empty() && input != WalletInput::Label {
+            self.buffer = value;
+            return None;
+        }
+
+        let selected = self
+            .selected_wallet()
+            .map(|wallet| wallet.name.clone())
+            .unwrap_or_default();
+        let action = match input {
+            WalletInput::UnlockPassword => Some(WalletsAction::Switch {
+                name: selected,
+                password: value,
+            }),
+            WalletInput::Label => Some(WalletsAction::SetLabel {
+                name: selected,
+                label: value,
+            }),
+            WalletInput::GenerateName => {
+                self.pending_name = value;
+                self.input = Some(WalletInput::GeneratePassword);
+                None
+            }
+            WalletInput::GeneratePassword => Some(WalletsAction::Generate {
+                name: std::mem::take(&mut self.pending_name),
+                password: value,
+            }),
+            WalletInput::ImportName => {
+                self.pending_name = value;
+                self.input = Some(WalletInput::ImportMnemonic);
+                None
+            }
+            WalletInput::ImportMnemonic => {
+                self.pending_mnemonic = value.split_whitespace().collect::<Vec<_>>().join(" ");
+                self.input = Some(WalletInput::ImportPassword);
+                None
+            }
+            WalletInput::ImportPassword => Some(WalletsAction::ImportMnemonic {
+                name: std::mem::take(&mut self.pending_name),
+                mnemonic: std::mem::take(&mut self.pending_mnemonic),
+                password: value,
+            }),
+            WalletInput::KeystoreName => {
+                self.pending_name = value;
+                self.input = Some(WalletInput::KeystorePath);
+                None
+            }
+            WalletInput::KeystorePath => Some(WalletsAction::ImportKeystore {
+                name: std::mem::take(&mut self.pending_name),
+                path: value,
+            }),
+        };
+        if action.is_some() {
+            self.input = None;
+        }
+        action
+    }
+}
+
+/// Lines of a QR code of `data` drawn with half blocks, two modules per line,
+/// dark modules as filled blocks
+pub fn qr_lines(data: &str) -> Option<Vec<String>> {
+    let code = QrCode::new(data.as_bytes()).ok()?;
+    let rendered = code.render::<Dense1x2>().quiet_zone(true).build();
+    Some(rendered.lines().map(str::to_string).collect())
+}
+
+/// Render the complete wallets screen
+pub fn render_wallets(f: &mut Frame, app: &App) {
+    let size = f.area();
+
+    // Create main layout: header, nav, content, status
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Header
+            Constraint::Length(3), // Navigation
+            Constraint::Min(0),    // Content
+            Constraint::Length(3), // Status bar
+        ])
+        .split(size);
+
+    render_header(f, &app.state, chunks[0]);
+    render_navigation(f, &app.state, chunks[1]);
+
+    // Create horizontal layout: wallet list | selected wallet details
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(chunks[2]);
+    let state = &app.state.wallets_state;
+    render_wallet_table(f, content_chunks[0], state);
+    render_wallet_details(f, content_chunks[1], state);
+
+    if let Some(input) = state.input {
+        render_wallet_form(f, chunks[2], input, &state.buffer);
+    }
+
+    render_status_bar(f, &app.state, chunks[3]);
+}
+
+/// Render the saved wallets with their labels and balances
+fn render_wallet_table(f: &mut Frame, area: Rect, state: &WalletsState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Blue))
+        .title(format!(
+            "Wallets ({}{})",
+            state.wallets.len(),
+            if state.loading { ", loading..." } else { "" }
+        ));
+
+    if state.wallets.is_empty() {
+        let text = if state.loading {
+            "Loading wallets..."
+        } else {
+            "No saved wallets. Press g to generate one, i to import a mnemonic or k a keystore file"
+        };
+        f.render_widget(
+            Paragraph::new(text)
+                .style(Style::default().fg(Color::Gray))
+                .wrap(Wrap { trim: true })
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from("").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Name").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Label").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Address").style(Style::default().add_modifier(Modifier::BOLD)),
+        Cell::from("Balance").style(Style::default().add_modifier(Modifier::BOLD)),
+    ])
+    .style(Style::default().bg(Color::DarkGray));
+
+    let rows: Vec<Row> = state
+        .wallets
+        .iter()
+        .enumerate()
+        .map(|(index, wallet)| {
+            let style = if index == state.selected {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else if index % 2 == 0 {
+                Style::default()
+            } else {
+                Style::default().bg(Color::DarkGray)
+            };
+            let active = state.active.as_deref() == Some(wallet.name.as_str());
+            let address = if wallet.address.len() > 20 {
+                format!(
+                    "{}...{}",
+                    &wallet.address[..12],
+                    &wallet.address[wallet.address.len() - 6..]
+                )
+            } else {
+                wallet.address.clone()
+            };
+
+            Row::new(vec![
+                Cell::from(if active { "●" } else { "" }).style(Style::default().fg(Color::Green)),
+                Cell::from(wallet.name.clone()),
+                Cell::from(wallet.label.clone().unwrap_or_default()),
+                Cell::from(address),
+                Cell::from(balance_summary(state.balances.get(&wallet.address))),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(2),      // Active marker
+            Constraint::Percentage(20), // Name
+            Constraint::Percentage(20), // Label
+            Constraint::Length(24),     // Address
+            Constraint::Percentage(30), // Balance
+        ],
+    )
+    .header(header)
+    .block(block);
+
+    f.render_widget(table, area);
+}
+
+/// First balances of a wallet, or `-` when not fetched
+fn balance_summary(balances: Option<&Vec<Coin>>) -> String {
+    match balances {
+        None => "-".to_string(),
+        Some(coins) if coins.is_empty() => "empty".to_string(),
+        Some(coins) => {
+            let mut summary = coins
+                .iter()
+                .take(2)
+                .map(|coin| format!("{}{}", coin.amount, coin.denom))
+                .collect::<Vec<_>>()
+                .join(" ");
+            if coins.len() > 2 {
+                summary.push_str(&format!(" +{}", coins.len() - 2));
+            }
+            summary
+        }
+    }
+}
+
+/// Render the selected wallet's address, QR code and balances
+fn render_wallet_details(f: &mut Frame, area: Rect, state: &WalletsState) {
+    let block = Block::default()
+        .title("Address")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if let Some(mnemonic) = &state.new_mnemonic {
+        let lines = vec![
+            Line::from(Span::styled(
+                "Write down the mnemonic of the new wallet; it is shown only once:",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(mnemonic.as_str()),
+        ];
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+        return;
+    }
+
+    let Some(wallet) = state.selected_wallet() else {
+        f.render_widget(
+            Paragraph::new("No wallet selected")
+                .style(Style::default().fg(Color::Gray))
+                .block(block),
+            area,
+        );
+        return;
+    };
+
+    let label = Style::default().fg(Color::Yellow);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Name: ", label),
+            Span::raw(&wallet.name),
+            Span::raw(
+                wallet
+                    .label
+                    .as_ref()
+                    .map(|label| format!(" ({})", label))
+                    .unwrap_or_default(),
+            ),
+        ]),
+        Line::from(Span::raw(&wallet.address)),
+        Line::from(""),
+    ];
+    // Dark on light so that phones can scan the code on dark terminals
+    let qr_style = Style::default().fg(Color::Black).bg(Color::White);
+    match qr_lines(&wallet.address) {
+        Some(qr) => lines.extend(
+            qr.into_iter()
+                .map(|line| Line::from(Span::styled(line, qr_style))),
+        ),
+        None => lines.push(Line::from("(address too long for a QR code)")),
+    }
+    lines.push(Line::from(""));
+    match state.balances.get(&wallet.address) {
+        Some(coins) if !coins.is_empty() => {
+            lines.push(Line::from(Span::styled("Balances:", label)));
+            lines.extend(
+                coins
+                    .iter()
+                    .map(|coin| Line::from(format!("  {} {}", coin.amount, coin.denom))),
+            );
+        }
+        Some(_) => lines.push(Line::from(Span::styled("No balances", label))),
+        None => lines.push(Line::from(Span::styled("Balances not loaded", label))),
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Render the open form field over the content area
+fn render_wallet_form(f: &mut Frame, area: Rect, input: WalletInput, buffer: &str) {
+    let width = area.width.saturating_sub(8).min(80);
+    let popup = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + area.height.saturating_sub(5) / 2,
+        width,
+        height: 5.min(area.height),
+    };
+    let text = if input.is_secret() {
+        "*".repeat(buffer.chars().count())
+    } else {
+        buffer.to_string()
+    };
+    let lines = vec![
+        Line::from(vec![
+            Span::raw(text),
+            Span::styled("_", Style::default().fg(Color::Yellow)),
+        ]),
+        Line::from(Span::styled(
+            "Enter: confirm | Esc: cancel",
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+
+    f.render_widget(Clear, popup);
+    f.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .title(input.prompt())
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        ),
+        popup,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wallet(name: &str) -> WalletMetadata {
+        WalletMetadata {
+            name: name.to_string(),
+            address: format!("mantra1{}", name),
+            created_at: String::new(),
+            last_accessed: None,
+            label: Some("savings".to_string()),
+        }
+    }
+
+    fn type_text(state: &mut WalletsState, text: &str) {
+        state.buffer.push_str(text);
+    }
+
+    #[test]
+    fn test_forms_produce_actions() {
+        let mut state = WalletsState::default();
+        state.set_wallets(vec![wallet("zeta"), wallet("alpha")]);
+        state.move_selection(1);
+        assert_eq!(state.selected_wallet().unwrap().name, "zeta");
+
+        state.begin(WalletInput::Label);
+        assert_eq!(state.buffer, "savings");
+        state.buffer.clear();
+        assert_eq!(
+            state.submit(),
+            Some(WalletsAction::SetLabel {
+                name: "zeta".to_string(),
+                label: String::new(),
+            })
+        );
+
+        state.begin(WalletInput::ImportName);
+        assert_eq!(state.submit(), None);
+        assert_eq!(state.input, Some(WalletInput::ImportName));
+        type_text(&mut state, " restored ");
+        assert_eq!(state.submit(), None);
+        type_text(&mut state, "word1  word2\nword3");
+        assert_eq!(state.submit(), None);
+        type_text(&mut state, "Secret-Pass-123");
+        assert_eq!(
+            state.submit(),
+            Some(WalletsAction::ImportMnemonic {
+                name: "restored".to_string(),
+                mnemonic: "word1 word2 word3".to_string(),
+                password: "Secret-Pass-123".to_string(),
+            })
+        );
+        assert_eq!(state.input, None);
+
+        assert!(qr_lines("mantra1zeta").unwrap().len() > 10);
+    }
+}
@@ -71,6 +71,21 @@ pub fn render_ui(frame: &mut Frame, app: &mut App) -> Result<(), Error> {
             // Use enhanced settings screen with focus indicators
             crate::tui_dex::screens::settings::render_settings_screen_with_focus(frame, app);
         }
+        crate::tui_dex::app::Screen::Portfolio => {
+            crate::tui_dex::screens::portfolio::render_portfolio(frame, app)
+        }
+        crate::tui_dex::app::Screen::History => {
+            crate::tui_dex::screens::history::render_history(frame, app)
+        }
+        crate::tui_dex::app::Screen::Wallets => {
+            crate::tui_dex::screens::wallets::render_wallets(frame, app)
+        }
+        crate::tui_dex::app::Screen::ClaimDrop => {
+            crate::tui_dex::screens::claimdrop::render_claimdrop(frame, app)
+        }
+        crate::tui_dex::app::Screen::Notifications => {
+            crate::tui_dex::screens::notifications::render_notifications(frame, app)
+        }
         crate::tui_dex::app::Screen::TransactionDetails => {
             crate::tui_dex::screens::transaction::render_transaction_screen(
                 frame,
@@ -141,6 +156,11 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App, layout_config: &Layou
                 ("Rew", crate::tui_dex::app::Screen::Rewards),
                 ("Admin", crate::tui_dex::app::Screen::Admin),
                 ("Set", crate::tui_dex::app::Screen::Settings),
+                ("Port", crate::tui_dex::app::Screen::Portfolio),
+                ("Hist", crate::tui_dex::app::Screen::History),
+                ("Wal", crate::tui_dex::app::Screen::Wallets),
+                ("Drop", crate::tui_dex::app::Screen::ClaimDrop),
+                ("Alrt", crate::tui_dex::app::Screen::Notifications),
             ]
         } else {
             crate::tui_dex::app::Screen::all()
@@ -271,6 +291,21 @@ fn render_main_content(
             // Pass layout config to settings (will need updating)
             render_settings_screen(frame, app);
         }
+        crate::tui_dex::app::Screen::Portfolio => {
+            crate::tui_dex::screens::portfolio::render_portfolio(frame, app);
+        }
+        crate::tui_dex::app::Screen::History => {
+            crate::tui_dex::screens::history::render_history(frame, app);
+        }
+        crate::tui_dex::app::Screen::Wallets => {
+            crate::tui_dex::screens::wallets::render_wallets(frame, app);
+        }
+        crate::tui_dex::app::Screen::ClaimDrop => {
+            crate::tui_dex::screens::claimdrop::render_claimdrop(frame, app);
+        }
+        crate::tui_dex::app::Screen::Notifications => {
+            crate::tui_dex::screens::notifications::render_notifications(frame, app);
+        }
         crate::tui_dex::app::Screen::TransactionDetails => {
             // Pass layout config to transaction (will need updating)
             crate::tui_dex::screens::transaction::render_transaction_screen(
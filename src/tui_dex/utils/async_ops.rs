@@ -3,8 +3,11 @@
 //! This module provides utilities for handling background async operations
 //! and real-time data updates in the TUI application without blocking the UI.
 
+use crate::events::{ContractEvent, EventFilter, EventSubscriber};
+use crate::retry::{Classify, RetryPolicy};
 use crate::tui_dex::events::Event;
 use crate::{Error, MantraDexClient};
+use futures::StreamExt;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
@@ -47,6 +50,20 @@ impl Default for SyncConfig {
     }
 }
 
+impl SyncConfig {
+    /// Retry policy of background operations: jittered backoff starting at
+    /// `retry_delay` and growing to four times it
+    pub fn retry_policy(&self) -> RetryPolicy {
+        let base_delay_ms = self.retry_delay.as_millis() as u64;
+        RetryPolicy {
+            max_retries: self.retry_attempts,
+            base_delay_ms,
+            max_delay_ms: base_delay_ms.saturating_mul(4),
+            ..RetryPolicy::default()
+        }
+    }
+}
+
 /// Network connection state
 #[derive(Debug, Clone, PartialEq)]
 pub enum NetworkState {
@@ -134,6 +151,7 @@ impl SyncManager {
         self.start_transaction_status_sync();
         self.start_network_info_sync();
         self.start_price_sync();
+        self.start_swap_event_stream();
         self.start_network_health_monitor();
     }
 
@@ -258,7 +276,7 @@ impl SyncManager {
                 });
 
                 // Wait before retry
-                tokio::time::sleep(self.config.retry_delay).await;
+                tokio::time::sleep(self.config.retry_policy().delay(attempt - 1)).await;
             }
 
             // Execute the operation with timeout
@@ -267,6 +285,7 @@ impl SyncManager {
                     return OperationResult::success(start_time.elapsed());
                 }
                 Ok(Err(e)) => {
+                    let retryable = e.is_retryable();
                     last_error = Some(e);
 
                     // Update network state based on error type
@@ -280,6 +299,15 @@ impl SyncManager {
                     {
                         *state_guard = NetworkState::Disconnected;
                     }
+
+                    // Failures that would repeat are reported right away
+                    if !retryable {
+                        return OperationResult::error(
+                            last_error.map(|e| e.to_string()).unwrap_or_default(),
+                            attempt,
+                            state_guard.clone(),
+                        );
+                    }
                 }
                 Err(_) => {
                     last_error = Some(Error::Rpc("Operation timeout".to_string()));
@@ -306,7 +334,7 @@ impl SyncManager {
         let interval_duration = self.config.balance_refresh_interval;
         let cancellation_token = self.cancellation_token.clone();
         let retry_attempts = self.config.retry_attempts;
-        let retry_delay = self.config.retry_delay;
+        let retry_policy = self.config.retry_policy();
         let network_timeout = self.config.network_timeout;
 
         let handle = tokio::spawn(async move {
@@ -332,14 +360,17 @@ impl SyncManager {
                                 }
                                 Ok(Err(e)) => {
                                     error_message = Some(e.to_string());
+                                    if !e.is_retryable() {
+                                        break;
+                                    }
                                     if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
+                                        tokio::time::sleep(retry_policy.delay(attempt)).await;
                                     }
                                 }
                                 Err(_) => {
                                     error_message = Some("Operation timeout".to_string());
                                     if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
+                                        tokio::time::sleep(retry_policy.delay(attempt)).await;
                                     }
                                 }
                             }
@@ -370,7 +401,7 @@ impl SyncManager {
         let interval_duration = self.config.pool_data_refresh_interval;
         let cancellation_token = self.cancellation_token.clone();
         let retry_attempts = self.config.retry_attempts;
-        let retry_delay = self.config.retry_delay;
+        let retry_policy = self.config.retry_policy();
         let network_timeout = self.config.network_timeout;
 
         let handle = tokio::spawn(async move {
@@ -392,14 +423,17 @@ impl SyncManager {
                                 }
                                 Ok(Err(e)) => {
                                     error_message = Some(e.to_string());
+                                    if !e.is_retryable() {
+                                        break;
+                                    }
                                     if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
+                                        tokio::time::sleep(retry_policy.delay(attempt)).await;
                                     }
                                 }
                                 Err(_) => {
                                     error_message = Some("Operation timeout".to_string());
                                     if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
+                                        tokio::time::sleep(retry_policy.delay(attempt)).await;
                                     }
                                 }
                             }
@@ -461,7 +495,7 @@ impl SyncManager {
         let interval_duration = self.config.network_info_interval;
         let cancellation_token = self.cancellation_token.clone();
         let retry_attempts = self.config.retry_attempts;
-        let retry_delay = self.config.retry_delay;
+        let retry_policy = self.config.retry_policy();
         let network_timeout = self.config.network_timeout;
 
         let handle = tokio::spawn(async move {
@@ -483,14 +517,17 @@ impl SyncManager {
                                 }
                                 Ok(Err(e)) => {
                                     error_message = Some(e.to_string());
+                                    if !e.is_retryable() {
+                                        break;
+                                    }
                                     if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
+                                        tokio::time::sleep(retry_policy.delay(attempt)).await;
                                     }
                                 }
                                 Err(_) => {
                                     error_message = Some("Operation timeout".to_string());
                                     if attempt < retry_attempts - 1 {
-                                        tokio::time::sleep(retry_delay).await;
+                                        tokio::time::sleep(retry_policy.delay(attempt)).await;
                                     }
                                 }
                             }
@@ -527,8 +564,7 @@ impl SyncManager {
                 tokio::select! {
                     _ = cancellation_token.cancelled() => break,
                     _ = interval.tick() => {
-                        // For now, just send a placeholder price refresh event
-                        // This can be enhanced with actual price data sources
+                        // The app samples pool prices for its charts on each one
                         let event = Event::DataRefresh {
                             data_type: "prices".to_string(),
                             success: true,
@@ -546,6 +582,56 @@ impl SyncManager {
         self.task_handles.push(handle);
     }
 
+    /// Start forwarding pool manager swaps from the node's WebSocket, so
+    /// price charts move with every trade rather than every refresh
+    fn start_swap_event_stream(&mut self) {
+        let sender = self.event_sender.clone();
+        let config = self.client.config().clone();
+        let retry_delay = self.config.retry_delay;
+        let cancellation_token = self.cancellation_token.clone();
+
+        let handle = tokio::spawn(async move {
+            loop {
+                let stream_ended = async {
+                    let subscriber = EventSubscriber::connect(&config.rpc_url).await?;
+                    let filter = EventFilter::new().pool_manager(&config.contracts.pool_manager);
+                    let mut events = Box::pin(subscriber.subscribe(filter).await?);
+                    while let Some(event) = events.next().await {
+                        let event = event?;
+                        if matches!(
+                            event.detail,
+                            ContractEvent::Swap { .. } | ContractEvent::Liquidity { .. }
+                        ) && sender.send(Event::PoolActivity(event)).is_err()
+                        {
+                            return Ok(true);
+                        }
+                    }
+                    Ok::<bool, Error>(false)
+                };
+
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    result = stream_ended => match result {
+                        // The app stopped listening
+                        Ok(true) => break,
+                        Ok(false) => {}
+                        Err(e) => crate::tui_dex::utils::logger::log_warning(&format!(
+                            "Swap event stream failed, prices fall back to refreshes: {}",
+                            e
+                        )),
+                    },
+                }
+
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    _ = tokio::time::sleep(retry_delay) => {}
+                }
+            }
+        });
+
+        self.task_handles.push(handle);
+    }
+
     /// Stop all background sync tasks
     pub fn stop_background_sync(&mut self) {
         // Signal all tasks to stop
@@ -622,7 +708,7 @@ impl AsyncDataRefresher {
                     progress: Some(0.3 + (attempt as f32 * 0.2)),
                 });
 
-                tokio::time::sleep(self.config.retry_delay).await;
+                tokio::time::sleep(self.config.retry_policy().delay(attempt - 1)).await;
             }
 
             match tokio::time::timeout(self.config.network_timeout, operation()).await {
@@ -636,6 +722,10 @@ impl AsyncDataRefresher {
                     });
                     return Ok(result);
                 }
+                Ok(Err(e)) if !e.is_retryable() => {
+                    last_error = Some(e);
+                    break;
+                }
                 Ok(Err(e)) => {
                     last_error = Some(e);
                 }
@@ -367,6 +367,10 @@ pub mod component_ids {
         FocusableComponent::TextInput("swap_slippage".to_string())
     }
 
+    pub fn swap_min_receive_input() -> FocusableComponent {
+        FocusableComponent::TextInput("swap_min_receive".to_string())
+    }
+
     pub fn swap_execute_button() -> FocusableComponent {
         FocusableComponent::Button("swap_execute".to_string())
     }
@@ -0,0 +1,367 @@
+//! Transaction history of an address
+//!
+//! [`TxHistory`] pages through the transactions an address signed, found
+//! with the node's `tx_search` on `message.sender`, so it needs a node with
+//! transaction indexing enabled. Each transaction is decoded with the
+//! [`CosmosTxAnalyzer`] into one sentence per message, alongside its status,
+//! fee, gas, raw log and events, and can be exported as CSV.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{AuthInfo, TxRaw};
+use cosmrs::rpc::endpoint::tx::Response as TxQueryResponse;
+use cosmwasm_std::{Coin, Uint128};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ContractAddresses;
+use crate::error::Error;
+use crate::protocols::cosmos_analyzer::{CosmosTxAnalyzer, CosmosTxEvent, DecodedCosmosMsg};
+use crate::protocols::dex::MantraDexClient;
+
+/// Transactions fetched per page by default
+pub const DEFAULT_HISTORY_PAGE_SIZE: u8 = 20;
+
+/// Header row of [`to_csv`]
+const CSV_HEADER: &str = "hash,height,time,status,code,actions,fee,gas_used,gas_wanted,summary";
+
+/// One past transaction, decoded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TxHistoryEntry {
+    pub hash: String,
+    pub height: u64,
+    /// Time of the block the transaction is in, when it could be fetched
+    pub timestamp: Option<DateTime<Utc>>,
+    pub success: bool,
+    /// Result code, 0 on success
+    pub code: u32,
+    pub fee: Vec<Coin>,
+    pub gas_wanted: i64,
+    pub gas_used: i64,
+    pub messages: Vec<DecodedCosmosMsg>,
+    /// One sentence per message, such as "you swapped 5 OM for 1.2 USDC in
+    /// pool o.uom.uusdc"
+    pub actions: Vec<String>,
+    /// The whole transaction told in one line
+    pub narrative: String,
+    pub raw_log: String,
+    pub events: Vec<CosmosTxEvent>,
+}
+
+impl TxHistoryEntry {
+    /// Names of the message actions, such as `swap+provide_liquidity`
+    pub fn action_names(&self) -> String {
+        if self.messages.is_empty() {
+            return "empty".to_string();
+        }
+        self.messages
+            .iter()
+            .map(|msg| msg.action.as_str())
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+
+    /// Fee as `<amount><denom>` coins separated by spaces
+    pub fn fee_text(&self) -> String {
+        self.fee
+            .iter()
+            .map(|coin| format!("{}{}", coin.amount, coin.denom))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Pages through the transactions of one address
+#[derive(Debug, Clone)]
+pub struct TxHistory {
+    address: String,
+    analyzer: CosmosTxAnalyzer,
+    page_size: u8,
+}
+
+impl TxHistory {
+    /// History of `address`, recognising the contracts of a network and
+    /// telling the address's own messages as "you"
+    pub fn new(contracts: &ContractAddresses, address: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            analyzer: CosmosTxAnalyzer::new(contracts)
+                .with_active_wallet(Some(address.to_string())),
+            page_size: DEFAULT_HISTORY_PAGE_SIZE,
+        }
+    }
+
+    /// Name labelled addresses by their address book label
+    pub fn with_address_labels(mut self, address_labels: HashMap<String, String>) -> Self {
+        self.analyzer = self.analyzer.with_address_labels(address_labels);
+        self
+    }
+
+    pub fn with_page_size(mut self, page_size: u8) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn page_size(&self) -> u8 {
+        self.page_size
+    }
+
+    /// Page `page` (from 1) of the history, newest first
+    ///
+    /// Transactions that cannot be decoded are left out, and block times that
+    /// cannot be fetched are left unset.
+    pub async fn page(
+        &self,
+        client: &MantraDexClient,
+        page: u32,
+    ) -> Result<Vec<TxHistoryEntry>, Error> {
+        let responses = client
+            .search_txs_by_sender_page(&self.address, page, self.page_size)
+            .await?;
+
+        let mut entries: Vec<TxHistoryEntry> = responses
+            .iter()
+            .filter_map(|response| match self.decode(response) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    tracing::warn!("Skipping transaction {}: {}", response.hash, e);
+                    None
+                }
+            })
+            .collect();
+
+        let mut block_times: HashMap<u64, Option<DateTime<Utc>>> = HashMap::new();
+        for entry in &mut entries {
+            entry.timestamp = match block_times.get(&entry.height) {
+                Some(time) => *time,
+                None => {
+                    let time = client
+                        .get_block_time(entry.height)
+                        .await
+                        .ok()
+                        .and_then(|seconds| DateTime::from_timestamp(seconds, 0));
+                    block_times.insert(entry.height, time);
+                    time
+                }
+            };
+        }
+        Ok(entries)
+    }
+
+    /// Decode one `tx_search` result
+    pub fn decode(&self, response: &TxQueryResponse) -> Result<TxHistoryEntry, Error> {
+        let hash = response.hash.to_string();
+        let result = &response.tx_result;
+        let events: Vec<CosmosTxEvent> = result
+            .events
+            .iter()
+            .map(|event| CosmosTxEvent {
+                kind: event.kind.clone(),
+                attributes: event
+                    .attributes
+                    .iter()
+                    .map(|attr| {
+                        (
+                            attr.key_str().unwrap_or("").to_string(),
+                            attr.value_str().unwrap_or("").to_string(),
+                        )
+                    })
+                    .collect(),
+            })
+            .collect();
+        let analyzed = self.analyzer.analyze(
+            &hash,
+            response.height.value(),
+            result.code.value(),
+            &response.tx,
+            &events,
+        )?;
+        let actions = analyzed
+            .messages
+            .iter()
+            .map(|msg| self.analyzer.narrate(msg, &events))
+            .collect();
+
+        Ok(TxHistoryEntry {
+            hash,
+            height: analyzed.height,
+            timestamp: None,
+            success: analyzed.success,
+            code: analyzed.code,
+            fee: tx_fee(&response.tx),
+            gas_wanted: result.gas_wanted,
+            gas_used: result.gas_used,
+            messages: analyzed.messages,
+            actions,
+            narrative: analyzed.narrative,
+            raw_log: result.log.clone(),
+            events,
+        })
+    }
+}
+
+/// Fee paid by a raw transaction; empty when its auth info cannot be read
+pub fn tx_fee(tx_bytes: &[u8]) -> Vec<Coin> {
+    let Ok(raw) = TxRaw::decode(tx_bytes) else {
+        return Vec::new();
+    };
+    AuthInfo::decode(raw.auth_info_bytes.as_slice())
+        .ok()
+        .and_then(|auth_info| auth_info.fee)
+        .map(|fee| {
+            fee.amount
+                .into_iter()
+                .map(|coin| Coin {
+                    denom: coin.denom,
+                    amount: coin.amount.parse().unwrap_or(Uint128::zero()),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// History entries as CSV, one row per transaction
+pub fn to_csv(entries: &[TxHistoryEntry]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+    for entry in entries {
+        let row = [
+            entry.hash.clone(),
+            entry.height.to_string(),
+            entry
+                .timestamp
+                .map(|time| time.to_rfc3339())
+                .unwrap_or_default(),
+            if entry.success { "success" } else { "failed" }.to_string(),
+            entry.code.to_string(),
+            entry.action_names(),
+            entry.fee_text(),
+            entry.gas_used.to_string(),
+            entry.gas_wanted.to_string(),
+            entry.actions.join("; "),
+        ];
+        csv.push_str(
+            &row.iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quote a CSV field when it holds a separator, quote or line break
+pub(crate) fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use cosmos_sdk_proto::cosmos::tx::v1beta1::{Fee, TxBody};
+    use cosmos_sdk_proto::cosmwasm::wasm::v1::MsgExecuteContract;
+    use cosmrs::tendermint::{abci, block::Height, hash::Algorithm, Hash};
+    use cosmrs::Any;
+
+    const WALLET: &str = "mantra1wallet00000000000000000000000000000";
+    const POOL_MANAGER: &str = "mantra1poolmanager";
+
+    #[test]
+    fn test_decodes_search_results_and_exports_csv() {
+        let swap = MsgExecuteContract {
+            sender: WALLET.to_string(),
+            contract: POOL_MANAGER.to_string(),
+            msg: serde_json::to_vec(&serde_json::json!({
+                "swap": {"ask_asset_denom": "uusdc", "pool_identifier": "o.uom.uusdc"}
+            }))
+            .unwrap(),
+            funds: vec![ProtoCoin {
+                denom: "uom".to_string(),
+                amount: "5000000".to_string(),
+            }],
+        };
+        let auth_info = AuthInfo {
+            fee: Some(Fee {
+                amount: vec![ProtoCoin {
+                    denom: "uom".to_string(),
+                    amount: "2500".to_string(),
+                }],
+                gas_limit: 200_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let tx = TxRaw {
+            body_bytes: TxBody {
+                messages: vec![Any {
+                    type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+                    value: swap.encode_to_vec(),
+                }],
+                ..Default::default()
+            }
+            .encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: Vec::new(),
+        }
+        .encode_to_vec();
+        let response = TxQueryResponse {
+            hash: Hash::from_hex_upper(Algorithm::Sha256, &"4F".repeat(32)).unwrap(),
+            height: Height::from(42u32),
+            index: 0,
+            tx_result: abci::types::ExecTxResult {
+                gas_wanted: 200_000,
+                gas_used: 150_000,
+                events: vec![abci::Event::new(
+                    "wasm",
+                    [
+                        ("_contract_address", POOL_MANAGER),
+                        ("action", "swap"),
+                        ("return_amount", "1250000"),
+                    ],
+                )],
+                ..Default::default()
+            },
+            tx,
+            proof: None,
+        };
+
+        let history = TxHistory::new(
+            &ContractAddresses {
+                pool_manager: POOL_MANAGER.to_string(),
+                ..Default::default()
+            },
+            WALLET,
+        );
+        let entry = history.decode(&response).unwrap();
+        assert!(entry.success);
+        assert_eq!(entry.action_names(), "swap");
+        assert_eq!(
+            entry.actions,
+            vec!["you swapped 5 OM for 1.25 USDC in pool o.uom.uusdc"]
+        );
+        assert_eq!(entry.fee_text(), "2500uom");
+        assert_eq!(entry.events[0].attribute("action"), Some("swap"));
+
+        let csv = to_csv(&[entry]);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        let row = lines.next().unwrap();
+        assert!(row.starts_with(&format!(
+            "{},42,,success,0,swap,2500uom,150000,200000,",
+            "4F".repeat(32)
+        )));
+        assert_eq!(csv_field("a \"b\", c"), "\"a \"\"b\"\", c\"");
+        assert!(tx_fee(b"not a tx").is_empty());
+    }
+}
@@ -0,0 +1,804 @@
+//! Queue of outgoing transactions, persisted before broadcast
+//!
+//! A signed transaction is written to the [`TxQueue`] before it is sent, so
+//! a process that dies between signing and confirmation leaves a record of
+//! it. [`TxQueue::poll`] looks every unfinished transaction up through a
+//! [`TxBroadcaster`] of its VM and chain: found ones are marked confirmed or
+//! failed, ones still missing after [`TxQueuePolicy::retry_after`] are sent
+//! again with the same signed bytes, and ones missing after
+//! [`TxQueuePolicy::max_attempts`] broadcasts are escalated as stuck for the
+//! caller to surface. Resending identical bytes is safe on both VMs: the
+//! account sequence or nonce lets the transaction execute at most once.
+//!
+//! Confirmations on EVM chains keep the hash of their block. For
+//! [`TxQueuePolicy::reorg_window`] after confirming, a transaction is looked
+//! up again, and one whose block was replaced goes back to pending and is
+//! recorded as a [`ReorgDetected`].
+//!
+//! The queue file is rewritten and synced to disk on every change. Clones of
+//! a [`TxQueue`] share the same entries.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose, Engine};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::Error;
+use crate::protocols::dex::MantraDexClient;
+use crate::reorg::{ReorgDetected, ReorgLog};
+
+/// Finished transactions kept in the queue for reference
+pub const MAX_FINISHED_ENTRIES: usize = 200;
+
+/// Virtual machine a transaction was signed for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxVm {
+    Cosmos,
+    Evm,
+}
+
+/// Where a queued transaction stands
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum QueuedTxStatus {
+    /// Persisted; not known to have reached a node
+    Queued,
+    /// Accepted by a node, waiting to be included
+    Broadcast,
+    Confirmed {
+        height: u64,
+        /// Hash of the including block, on chains that can reorganize
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        block_hash: Option<String>,
+    },
+    Failed {
+        reason: String,
+    },
+    /// Still missing after every allowed broadcast; looked up but no longer sent
+    Stuck,
+}
+
+impl QueuedTxStatus {
+    /// Whether the transaction's outcome is known
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Confirmed { .. } | Self::Failed { .. })
+    }
+}
+
+/// A signed transaction in the queue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedTx {
+    /// Transaction hash: upper-case hex SHA-256 on Cosmos, `0x` keccak-256 on EVM
+    pub hash: String,
+    pub vm: TxVm,
+    /// Cosmos chain id, or the EVM chain id in decimal
+    pub chain_id: String,
+    /// What the transaction does, such as the message types it carries
+    pub label: String,
+    /// Signed transaction bytes, base64
+    pub raw_tx: String,
+    #[serde(flatten)]
+    pub status: QueuedTxStatus,
+    /// Broadcasts so far, including the first
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub queued_at: DateTime<Utc>,
+    pub last_broadcast_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl QueuedTx {
+    /// Signed transaction bytes
+    pub fn raw_bytes(&self) -> Result<Vec<u8>, Error> {
+        general_purpose::STANDARD
+            .decode(&self.raw_tx)
+            .map_err(|e| Error::Other(format!("Corrupt queued transaction {}: {}", self.hash, e)))
+    }
+}
+
+/// Result of looking a transaction up on chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxLookup {
+    /// Not included in a block, and possibly not known to the node
+    NotFound,
+    Confirmed {
+        height: u64,
+        /// Hash of the including block, on chains that can reorganize
+        block_hash: Option<String>,
+    },
+    Failed {
+        reason: String,
+    },
+}
+
+/// Looks up and resends the transactions of one VM and chain
+#[async_trait]
+pub trait TxBroadcaster: Send + Sync {
+    fn vm(&self) -> TxVm;
+
+    /// Chain id as recorded in [`QueuedTx::chain_id`]
+    fn chain_id(&self) -> String;
+
+    async fn lookup(&self, hash: &str) -> Result<TxLookup, Error>;
+
+    /// Send already-signed bytes again; a node that already has them is not an error
+    async fn rebroadcast(&self, raw_tx: &[u8]) -> Result<(), Error>;
+}
+
+/// When unconfirmed transactions are resent and given up on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TxQueuePolicy {
+    /// How long a broadcast transaction may stay missing before it is resent
+    pub retry_after: Duration,
+    /// Broadcasts after which a missing transaction is escalated as stuck
+    pub max_attempts: u32,
+    /// Time between polls of a monitor task
+    pub poll_interval: Duration,
+    /// How long after confirming a transaction its block is checked for
+    /// reorgs
+    #[serde(default = "default_reorg_window")]
+    pub reorg_window: Duration,
+}
+
+fn default_reorg_window() -> Duration {
+    Duration::from_secs(600)
+}
+
+impl Default for TxQueuePolicy {
+    fn default() -> Self {
+        Self {
+            retry_after: Duration::from_secs(60),
+            max_attempts: 3,
+            poll_interval: Duration::from_secs(15),
+            reorg_window: default_reorg_window(),
+        }
+    }
+}
+
+/// What happened to a transaction during a poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TxQueueChange {
+    Confirmed,
+    Failed,
+    Resent,
+    /// Escalated: it will not be sent again
+    Stuck,
+    /// Its block was replaced: it is pending again
+    Reorged,
+}
+
+/// A transaction whose state changed during a poll, as it is now
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxQueueUpdate {
+    pub change: TxQueueChange,
+    pub tx: QueuedTx,
+}
+
+/// Hash of a signed Cosmos transaction, as the chain reports it
+pub fn cosmos_tx_hash(tx_bytes: &[u8]) -> String {
+    hex::encode_upper(Sha256::digest(tx_bytes))
+}
+
+/// Persistent queue of signed transactions
+#[derive(Debug, Clone, Default)]
+pub struct TxQueue {
+    path: Option<Arc<PathBuf>>,
+    entries: Arc<Mutex<Vec<QueuedTx>>>,
+}
+
+impl TxQueue {
+    /// Queue that is not saved anywhere
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Queue saved at `path`, with the transactions a previous process left
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let entries = if path.exists() {
+            serde_json::from_str(&fs::read_to_string(&path)?)?
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            path: Some(Arc::new(path)),
+            entries: Arc::new(Mutex::new(entries)),
+        })
+    }
+
+    /// `tx_queue.json` in the user configuration directory
+    pub fn default_path() -> PathBuf {
+        let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+        path.push("mantra-dex");
+        path.push("tx_queue.json");
+        path
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref().map(PathBuf::as_path)
+    }
+
+    /// Record a signed transaction before it is broadcast
+    ///
+    /// Fails when the queue cannot be saved, so the caller does not send a
+    /// transaction that would not survive a crash.
+    pub fn enqueue(
+        &self,
+        vm: TxVm,
+        chain_id: &str,
+        hash: &str,
+        raw_tx: &[u8],
+        label: &str,
+    ) -> Result<(), Error> {
+        let now = Utc::now();
+        let mut entries = self.lock();
+        entries.retain(|tx| tx.hash != hash);
+        entries.push(QueuedTx {
+            hash: hash.to_string(),
+            vm,
+            chain_id: chain_id.to_string(),
+            label: label.to_string(),
+            raw_tx: general_purpose::STANDARD.encode(raw_tx),
+            status: QueuedTxStatus::Queued,
+            attempts: 0,
+            last_error: None,
+            queued_at: now,
+            last_broadcast_at: None,
+            updated_at: now,
+        });
+        Self::prune(&mut entries);
+        self.save(&entries)
+    }
+
+    /// Record that a node accepted the transaction
+    pub fn mark_broadcast(&self, hash: &str) -> Result<(), Error> {
+        self.update(hash, |tx| {
+            tx.status = QueuedTxStatus::Broadcast;
+            tx.attempts += 1;
+            tx.last_broadcast_at = Some(Utc::now());
+        })
+    }
+
+    pub fn mark_confirmed(
+        &self,
+        hash: &str,
+        height: u64,
+        block_hash: Option<&str>,
+    ) -> Result<(), Error> {
+        self.update(hash, |tx| {
+            tx.status = QueuedTxStatus::Confirmed {
+                height,
+                block_hash: block_hash.map(str::to_string),
+            }
+        })
+    }
+
+    /// Put a confirmed transaction whose block was replaced back to pending,
+    /// so it is looked up again and resent if it stays missing
+    pub fn mark_reorged(&self, hash: &str) -> Result<(), Error> {
+        self.update(hash, |tx| {
+            if let QueuedTxStatus::Confirmed { height, block_hash } = &tx.status {
+                tx.last_error = Some(format!(
+                    "Block {} at height {} was replaced by a reorg",
+                    block_hash.as_deref().unwrap_or("?"),
+                    height
+                ));
+                tx.status = QueuedTxStatus::Broadcast;
+                tx.last_broadcast_at = Some(Utc::now());
+            }
+        })
+    }
+
+    pub fn mark_failed(&self, hash: &str, reason: &str) -> Result<(), Error> {
+        self.update(hash, |tx| {
+            tx.status = QueuedTxStatus::Failed {
+                reason: reason.to_string(),
+            }
+        })
+    }
+
+    pub fn get(&self, hash: &str) -> Option<QueuedTx> {
+        self.lock().iter().find(|tx| tx.hash == hash).cloned()
+    }
+
+    /// Every queued transaction, oldest first
+    pub fn entries(&self) -> Vec<QueuedTx> {
+        self.lock().clone()
+    }
+
+    /// Transactions whose outcome is not known yet, oldest first
+    pub fn unfinished(&self) -> Vec<QueuedTx> {
+        self.lock()
+            .iter()
+            .filter(|tx| !tx.status.is_final())
+            .cloned()
+            .collect()
+    }
+
+    /// Transactions confirmed in a known block less than `window` ago, which
+    /// a reorg could still undo
+    pub fn recent_confirmations(&self, window: Duration) -> Vec<QueuedTx> {
+        self.lock()
+            .iter()
+            .filter(|tx| {
+                matches!(
+                    tx.status,
+                    QueuedTxStatus::Confirmed {
+                        block_hash: Some(_),
+                        ..
+                    }
+                )
+            })
+            .filter(|tx| {
+                Utc::now()
+                    .signed_duration_since(tx.updated_at)
+                    .to_std()
+                    .map_or(true, |elapsed| elapsed < window)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Look up the unfinished transactions of `broadcaster`'s VM and chain,
+    /// resending or escalating the ones still missing, and check the blocks
+    /// of the ones confirmed within the reorg window
+    ///
+    /// Transactions that cannot be looked up this time are left as they are.
+    pub async fn poll(
+        &self,
+        broadcaster: &dyn TxBroadcaster,
+        policy: &TxQueuePolicy,
+    ) -> Result<Vec<TxQueueUpdate>, Error> {
+        let (vm, chain_id) = (broadcaster.vm(), broadcaster.chain_id());
+        let pending: Vec<QueuedTx> = self
+            .unfinished()
+            .into_iter()
+            .filter(|tx| tx.vm == vm && tx.chain_id == chain_id)
+            .collect();
+
+        let mut updates = self.check_confirmations(broadcaster, policy).await?;
+        for tx in pending {
+            let change = match broadcaster.lookup(&tx.hash).await {
+                Ok(TxLookup::Confirmed { height, block_hash }) => {
+                    self.mark_confirmed(&tx.hash, height, block_hash.as_deref())?;
+                    Some(TxQueueChange::Confirmed)
+                }
+                Ok(TxLookup::Failed { reason }) => {
+                    self.mark_failed(&tx.hash, &reason)?;
+                    Some(TxQueueChange::Failed)
+                }
+                Ok(TxLookup::NotFound) => self.retry(broadcaster, &tx, policy).await?,
+                Err(e) => {
+                    tracing::debug!("Could not look up queued transaction {}: {}", tx.hash, e);
+                    None
+                }
+            };
+            if let (Some(change), Some(tx)) = (change, self.get(&tx.hash)) {
+                updates.push(TxQueueUpdate { change, tx });
+            }
+        }
+        Ok(updates)
+    }
+
+    /// Look up the transactions of `broadcaster`'s chain confirmed in a known
+    /// block within the reorg window, putting back to pending the ones no
+    /// longer in that block
+    async fn check_confirmations(
+        &self,
+        broadcaster: &dyn TxBroadcaster,
+        policy: &TxQueuePolicy,
+    ) -> Result<Vec<TxQueueUpdate>, Error> {
+        let (vm, chain_id) = (broadcaster.vm(), broadcaster.chain_id());
+        let recent: Vec<(QueuedTx, u64, String)> = self
+            .recent_confirmations(policy.reorg_window)
+            .into_iter()
+            .filter(|tx| tx.vm == vm && tx.chain_id == chain_id)
+            .filter_map(|tx| match &tx.status {
+                QueuedTxStatus::Confirmed {
+                    height,
+                    block_hash: Some(block_hash),
+                } => Some((tx.clone(), *height, block_hash.clone())),
+                _ => None,
+            })
+            .collect();
+
+        let mut updates = Vec::new();
+        for (tx, height, block_hash) in recent {
+            let canonical = match broadcaster.lookup(&tx.hash).await {
+                Ok(TxLookup::Confirmed {
+                    height: now_at,
+                    block_hash: Some(now_in),
+                }) => {
+                    if now_in == block_hash {
+                        continue;
+                    }
+                    (now_at == height).then_some(now_in)
+                }
+                Ok(TxLookup::Confirmed {
+                    block_hash: None, ..
+                }) => continue,
+                Ok(_) => None,
+                Err(e) => {
+                    tracing::debug!("Could not recheck transaction {}: {}", tx.hash, e);
+                    continue;
+                }
+            };
+            self.mark_reorged(&tx.hash)?;
+            ReorgLog::global().record(ReorgDetected::new(
+                &chain_id,
+                height,
+                block_hash,
+                canonical,
+                vec![tx.hash.clone()],
+            ));
+            if let Some(tx) = self.get(&tx.hash) {
+                updates.push(TxQueueUpdate {
+                    change: TxQueueChange::Reorged,
+                    tx,
+                });
+            }
+        }
+        Ok(updates)
+    }
+
+    /// Resend a missing transaction when it is due, or escalate it once it
+    /// has been sent as often as allowed
+    async fn retry(
+        &self,
+        broadcaster: &dyn TxBroadcaster,
+        tx: &QueuedTx,
+        policy: &TxQueuePolicy,
+    ) -> Result<Option<TxQueueChange>, Error> {
+        if tx.status == QueuedTxStatus::Stuck {
+            return Ok(None);
+        }
+        if tx.attempts >= policy.max_attempts {
+            self.update(&tx.hash, |tx| tx.status = QueuedTxStatus::Stuck)?;
+            return Ok(Some(TxQueueChange::Stuck));
+        }
+        let due = match tx.last_broadcast_at {
+            Some(at) => Utc::now()
+                .signed_duration_since(at)
+                .to_std()
+                .is_ok_and(|elapsed| elapsed >= policy.retry_after),
+            // Never reached a node, such as when the process died mid-broadcast
+            None => true,
+        };
+        if !due {
+            return Ok(None);
+        }
+
+        match broadcaster.rebroadcast(&tx.raw_bytes()?).await {
+            Ok(()) => {
+                self.mark_broadcast(&tx.hash)?;
+                Ok(Some(TxQueueChange::Resent))
+            }
+            Err(e) => {
+                self.update(&tx.hash, |tx| {
+                    tx.attempts += 1;
+                    tx.last_broadcast_at = Some(Utc::now());
+                    tx.last_error = Some(e.to_string());
+                })?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Poll every `policy.poll_interval` in the background, handing each
+    /// update to `on_update`
+    pub fn spawn_monitor<F>(
+        &self,
+        broadcaster: Arc<dyn TxBroadcaster>,
+        policy: TxQueuePolicy,
+        on_update: F,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn(TxQueueUpdate) + Send + 'static,
+    {
+        let queue = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(policy.poll_interval);
+            loop {
+                interval.tick().await;
+                match queue.poll(broadcaster.as_ref(), &policy).await {
+                    Ok(updates) => updates.into_iter().for_each(&on_update),
+                    Err(e) => tracing::warn!("Transaction queue poll failed: {}", e),
+                }
+            }
+        })
+    }
+
+    fn update(&self, hash: &str, change: impl FnOnce(&mut QueuedTx)) -> Result<(), Error> {
+        let mut entries = self.lock();
+        let Some(tx) = entries.iter_mut().find(|tx| tx.hash == hash) else {
+            return Ok(());
+        };
+        change(tx);
+        tx.updated_at = Utc::now();
+        self.save(&entries)
+    }
+
+    /// Drop the oldest finished transactions beyond [`MAX_FINISHED_ENTRIES`]
+    fn prune(entries: &mut Vec<QueuedTx>) {
+        let finished = entries.iter().filter(|tx| tx.status.is_final()).count();
+        let mut excess = finished.saturating_sub(MAX_FINISHED_ENTRIES);
+        entries.retain(|tx| {
+            if excess > 0 && tx.status.is_final() {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
+    }
+
+    /// Write the entries to a temporary file, sync it and move it over the queue
+    fn save(&self, entries: &[QueuedTx]) -> Result<(), Error> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let temp = path.with_extension("json.tmp");
+        let mut file = File::create(&temp)?;
+        file.write_all(serde_json::to_string_pretty(entries)?.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temp, path.as_path())?;
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<QueuedTx>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl TxBroadcaster for MantraDexClient {
+    fn vm(&self) -> TxVm {
+        TxVm::Cosmos
+    }
+
+    fn chain_id(&self) -> String {
+        self.config().chain_id.clone()
+    }
+
+    async fn lookup(&self, hash: &str) -> Result<TxLookup, Error> {
+        match self.get_tx(hash).await {
+            Ok(response) if response.tx_result.code.is_err() => Ok(TxLookup::Failed {
+                reason: response.tx_result.log.to_string(),
+            }),
+            Ok(response) => Ok(TxLookup::Confirmed {
+                height: response.height.value(),
+                block_hash: None,
+            }),
+            Err(e) if e.to_string().contains("not found") => Ok(TxLookup::NotFound),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn rebroadcast(&self, raw_tx: &[u8]) -> Result<(), Error> {
+        self.broadcast_tx_bytes(raw_tx.to_vec()).await.map(|_| ())
+    }
+}
+
+#[cfg(feature = "evm")]
+#[async_trait]
+impl TxBroadcaster for crate::protocols::evm::client::EvmClient {
+    fn vm(&self) -> TxVm {
+        TxVm::Evm
+    }
+
+    fn chain_id(&self) -> String {
+        crate::protocols::evm::client::EvmClient::chain_id(self).to_string()
+    }
+
+    async fn lookup(&self, hash: &str) -> Result<TxLookup, Error> {
+        let hash = hash
+            .parse()
+            .map_err(|e| Error::Evm(format!("Invalid transaction hash {}: {}", hash, e)))?;
+        Ok(match self.get_transaction_receipt(hash).await? {
+            None => TxLookup::NotFound,
+            Some(receipt) if receipt.status() => TxLookup::Confirmed {
+                height: receipt.block_number.unwrap_or_default(),
+                block_hash: receipt.block_hash.map(|hash| format!("{:#x}", hash)),
+            },
+            Some(_) => TxLookup::Failed {
+                reason: "Transaction reverted".to_string(),
+            },
+        })
+    }
+
+    async fn rebroadcast(&self, raw_tx: &[u8]) -> Result<(), Error> {
+        match self.resend_raw_bytes(raw_tx).await {
+            Err(e) if e.to_string().contains("already known") => Ok(()),
+            result => result.map(|_| ()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Chain on which one transaction is included after its second broadcast
+    struct FlakyChain {
+        broadcasts: AtomicU32,
+    }
+
+    #[async_trait]
+    impl TxBroadcaster for FlakyChain {
+        fn vm(&self) -> TxVm {
+            TxVm::Cosmos
+        }
+
+        fn chain_id(&self) -> String {
+            "mantra-dukong-1".to_string()
+        }
+
+        async fn lookup(&self, _hash: &str) -> Result<TxLookup, Error> {
+            Ok(if self.broadcasts.load(Ordering::SeqCst) >= 2 {
+                TxLookup::Confirmed {
+                    height: 42,
+                    block_hash: None,
+                }
+            } else {
+                TxLookup::NotFound
+            })
+        }
+
+        async fn rebroadcast(&self, _raw_tx: &[u8]) -> Result<(), Error> {
+            self.broadcasts.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// EVM chain answering every lookup with what the test set
+    struct ForkingChain {
+        lookup: Mutex<TxLookup>,
+    }
+
+    #[async_trait]
+    impl TxBroadcaster for ForkingChain {
+        fn vm(&self) -> TxVm {
+            TxVm::Evm
+        }
+
+        fn chain_id(&self) -> String {
+            "5887".to_string()
+        }
+
+        async fn lookup(&self, _hash: &str) -> Result<TxLookup, Error> {
+            Ok(self.lookup.lock().unwrap().clone())
+        }
+
+        async fn rebroadcast(&self, _raw_tx: &[u8]) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reorged_confirmation_goes_back_to_pending() {
+        let queue = TxQueue::in_memory();
+        let hash = format!("0x{}", "ab".repeat(32));
+        queue
+            .enqueue(TxVm::Evm, "5887", &hash, b"signed", "evm transaction")
+            .unwrap();
+        queue.mark_broadcast(&hash).unwrap();
+        let confirmed = |block: &str| TxLookup::Confirmed {
+            height: 7,
+            block_hash: Some(block.to_string()),
+        };
+        let chain = ForkingChain {
+            lookup: Mutex::new(confirmed("0x01")),
+        };
+        let policy = TxQueuePolicy::default();
+
+        let updates = queue.poll(&chain, &policy).await.unwrap();
+        assert_eq!(updates[0].change, TxQueueChange::Confirmed);
+        assert_eq!(queue.recent_confirmations(policy.reorg_window).len(), 1);
+        // Still in the same block: nothing to report
+        assert!(queue.poll(&chain, &policy).await.unwrap().is_empty());
+
+        // Another block took height 7
+        *chain.lookup.lock().unwrap() = confirmed("0x02");
+        let updates = queue.poll(&chain, &policy).await.unwrap();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].change, TxQueueChange::Reorged);
+        assert_eq!(updates[0].tx.status, QueuedTxStatus::Broadcast);
+        let reorg = ReorgLog::global()
+            .recent()
+            .into_iter()
+            .find(|reorg| reorg.tx_hashes == [hash.clone()])
+            .unwrap();
+        assert_eq!(reorg.replaced_block_hash, "0x01");
+        assert_eq!(reorg.canonical_block_hash.as_deref(), Some("0x02"));
+
+        // The next poll finds it in its new block
+        let updates = queue.poll(&chain, &policy).await.unwrap();
+        assert_eq!(updates[0].change, TxQueueChange::Confirmed);
+
+        // Confirmations older than the window are left alone
+        *chain.lookup.lock().unwrap() = TxLookup::NotFound;
+        let settled = TxQueuePolicy {
+            reorg_window: Duration::ZERO,
+            ..TxQueuePolicy::default()
+        };
+        assert!(queue.poll(&chain, &settled).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_queue_survives_restart_and_resends_until_confirmed() {
+        let dir = std::env::temp_dir().join(format!("tx-queue-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("tx_queue.json");
+        let raw = b"signed tx".to_vec();
+        let hash = cosmos_tx_hash(&raw);
+
+        // The process dies right after queueing the transaction
+        TxQueue::open(&path)
+            .unwrap()
+            .enqueue(TxVm::Cosmos, "mantra-dukong-1", &hash, &raw, "MsgSwap")
+            .unwrap();
+
+        let queue = TxQueue::open(&path).unwrap();
+        assert_eq!(queue.unfinished().len(), 1);
+        let chain = FlakyChain {
+            broadcasts: AtomicU32::new(0),
+        };
+        let policy = TxQueuePolicy {
+            retry_after: Duration::ZERO,
+            ..TxQueuePolicy::default()
+        };
+
+        let updates = queue.poll(&chain, &policy).await.unwrap();
+        assert_eq!(updates[0].change, TxQueueChange::Resent);
+        assert_eq!(updates[0].tx.raw_bytes().unwrap(), raw);
+        queue.poll(&chain, &policy).await.unwrap();
+        let updates = queue.poll(&chain, &policy).await.unwrap();
+        assert_eq!(updates[0].change, TxQueueChange::Confirmed);
+        assert_eq!(
+            TxQueue::open(&path).unwrap().get(&hash).unwrap().status,
+            QueuedTxStatus::Confirmed {
+                height: 42,
+                block_hash: None,
+            }
+        );
+
+        // A transaction that never lands is escalated once
+        queue
+            .enqueue(TxVm::Cosmos, "mantra-dukong-1", "AB", b"lost", "MsgSend")
+            .unwrap();
+        let lost_chain = FlakyChain {
+            broadcasts: AtomicU32::new(0),
+        };
+        let lost = async {
+            let mut changes = Vec::new();
+            for _ in 0..6 {
+                for update in queue.poll(&lost_chain, &policy).await.unwrap() {
+                    lost_chain.broadcasts.store(0, Ordering::SeqCst);
+                    changes.push(update.change);
+                }
+            }
+            changes
+        };
+        assert_eq!(
+            lost.await,
+            vec![
+                TxQueueChange::Resent,
+                TxQueueChange::Resent,
+                TxQueueChange::Resent,
+                TxQueueChange::Stuck
+            ]
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+}
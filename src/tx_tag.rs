@@ -0,0 +1,120 @@
+//! Attribution tags stamped on outgoing transactions
+//!
+//! Downstream analytics attribute transactions to the SDK and the integration
+//! that sent them by a tag in the transaction itself. Cosmos transactions
+//! carry the tag as their memo. EVM transactions can carry it as bytes
+//! appended to the calldata, which contracts decoding their ABI arguments
+//! ignore; this is off by default, since a contract reading `msg.data` in full
+//! would see the suffix.
+//!
+//! A memo pattern such as `mantra-sdk/{version} via mcp` is expanded per
+//! chain: `{version}` becomes the SDK version and `{chain_id}` the chain the
+//! transaction is signed for.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// Longest memo Cosmos SDK chains accept by default, in bytes
+pub const MAX_MEMO_BYTES: usize = 256;
+
+/// Tags stamped on every transaction a client sends
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TxTagging {
+    /// Memo pattern of Cosmos transactions; none leaves the memo empty
+    pub memo: Option<String>,
+    /// Append the expanded memo pattern to the calldata of EVM transactions
+    pub evm_data_suffix: bool,
+}
+
+impl TxTagging {
+    /// Tag Cosmos transactions with the memo pattern `memo`
+    pub fn new(memo: impl Into<String>) -> Self {
+        Self {
+            memo: Some(memo.into()),
+            evm_data_suffix: false,
+        }
+    }
+
+    /// Also append the tag to the calldata of EVM transactions
+    pub fn with_evm_data_suffix(mut self, enabled: bool) -> Self {
+        self.evm_data_suffix = enabled;
+        self
+    }
+
+    /// Memo of transactions signed for `chain_id`
+    pub fn memo_for(&self, chain_id: &str) -> Option<String> {
+        self.memo
+            .as_deref()
+            .map(|pattern| expand_pattern(pattern, chain_id))
+    }
+
+    /// Bytes appended to the calldata of transactions on EVM chain `chain_id`
+    pub fn evm_suffix(&self, chain_id: u64) -> Option<Vec<u8>> {
+        if !self.evm_data_suffix {
+            return None;
+        }
+        self.memo_for(&chain_id.to_string())
+            .filter(|memo| !memo.is_empty())
+            .map(String::into_bytes)
+    }
+
+    /// Check the pattern expands to a memo chains accept
+    pub fn validate(&self) -> Result<(), Error> {
+        match self.memo_for("") {
+            Some(memo) => check_memo(&memo),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Expand `{version}` and `{chain_id}` in a memo pattern
+pub fn expand_pattern(pattern: &str, chain_id: &str) -> String {
+    pattern
+        .replace("{version}", env!("CARGO_PKG_VERSION"))
+        .replace("{chain_id}", chain_id)
+}
+
+/// Check a memo fits in a Cosmos transaction
+pub fn check_memo(memo: &str) -> Result<(), Error> {
+    if memo.len() > MAX_MEMO_BYTES {
+        return Err(Error::Config(format!(
+            "Memo is {} bytes, more than the {} chains accept",
+            memo.len(),
+            MAX_MEMO_BYTES
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memo_pattern_expands_per_chain() {
+        let tagging = TxTagging::new("mantra-sdk/{version} via mcp on {chain_id}");
+        assert_eq!(
+            tagging.memo_for("mantra-1").unwrap(),
+            format!(
+                "mantra-sdk/{} via mcp on mantra-1",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+        assert!(tagging.validate().is_ok());
+
+        // EVM tagging is opt-in
+        assert_eq!(tagging.evm_suffix(5888), None);
+        let tagging = tagging.with_evm_data_suffix(true);
+        assert!(tagging
+            .evm_suffix(5888)
+            .unwrap()
+            .ends_with(b" via mcp on 5888"));
+        assert_eq!(TxTagging::default().evm_suffix(5888), None);
+
+        assert!(TxTagging::new("x".repeat(MAX_MEMO_BYTES + 1))
+            .validate()
+            .is_err());
+    }
+}
@@ -0,0 +1,297 @@
+//! Validation and conversion of Cosmos and EVM addresses
+//!
+//! MANTRA Chain keeps one 20-byte account per address and shows it in two
+//! forms: bech32 (`mantra1...`) to the Cosmos modules and hex (`0x...`) to
+//! the EVM. Converting between the forms keeps the bytes, so both name the
+//! same account. A wallet of this SDK derives its Cosmos and EVM keys on
+//! different HD paths, so its two addresses are distinct accounts, not two
+//! forms of one.
+//!
+//! 32-byte bech32 addresses belong to CosmWasm contracts and module accounts
+//! and have no EVM form.
+
+use cosmrs::AccountId;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::address_book::AddressKind;
+use crate::error::Error;
+
+/// Bech32 prefix of MANTRA Chain accounts
+pub const DEFAULT_ACCOUNT_PREFIX: &str = "mantra";
+
+/// What an address is and its form on the other VM
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressInfo {
+    pub input: String,
+    pub valid: bool,
+    /// VM the address is written for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<AddressKind>,
+    /// Bech32 form, with the prefix asked for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bech32: Option<String>,
+    /// EVM form, EIP-55 checksummed when built with EVM support
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm: Option<String>,
+    /// Bech32 prefix of the input
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Length of the account bytes: 20 for accounts, 32 for contracts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byte_length: Option<usize>,
+    /// Whether a mixed-case EVM input carries a valid EIP-55 checksum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum_valid: Option<bool>,
+    /// Whether the address is valid but written for the other VM than the
+    /// one asked for
+    pub wrong_vm: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl AddressInfo {
+    fn invalid(input: &str, error: String) -> Self {
+        Self {
+            input: input.to_string(),
+            valid: false,
+            kind: None,
+            bech32: None,
+            evm: None,
+            prefix: None,
+            byte_length: None,
+            checksum_valid: None,
+            wrong_vm: false,
+            error: Some(error),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Form of the address on `kind`'s VM
+    pub fn form(&self, kind: AddressKind) -> Option<&str> {
+        match kind {
+            AddressKind::Cosmos => self.bech32.as_deref(),
+            AddressKind::Evm => self.evm.as_deref(),
+        }
+    }
+}
+
+/// Validate `input` and convert it to both forms
+///
+/// `prefix` is the bech32 prefix of the chain, and `expected` the VM of the
+/// operation the address is for; an address written for the other VM is
+/// flagged with its form on the expected one.
+pub fn inspect_address(input: &str, prefix: &str, expected: Option<AddressKind>) -> AddressInfo {
+    let address = input.trim();
+    let mut info = match AddressKind::of(address) {
+        Some(AddressKind::Evm) => {
+            let bytes = match hex::decode(&address[2..]) {
+                Ok(bytes) => bytes,
+                Err(e) => return AddressInfo::invalid(input, format!("Invalid hex: {}", e)),
+            };
+            let evm = checksum_evm_address(&bytes);
+            let hex_digits = &address[2..];
+            let mixed_case = hex_digits.chars().any(|c| c.is_ascii_uppercase())
+                && hex_digits.chars().any(|c| c.is_ascii_lowercase());
+            // Without EVM support there is no Keccak-256 to check against
+            let checksum_valid =
+                (mixed_case && cfg!(feature = "evm")).then(|| evm[2..] == *hex_digits);
+            let mut info = AddressInfo {
+                input: input.to_string(),
+                valid: checksum_valid != Some(false),
+                kind: Some(AddressKind::Evm),
+                bech32: AccountId::new(prefix, &bytes)
+                    .ok()
+                    .map(|account| account.to_string()),
+                evm: Some(evm),
+                prefix: None,
+                byte_length: Some(bytes.len()),
+                checksum_valid,
+                wrong_vm: false,
+                error: None,
+                warnings: Vec::new(),
+            };
+            if checksum_valid == Some(false) {
+                info.error = Some(
+                    "The EIP-55 checksum does not match; the address may be mistyped".to_string(),
+                );
+            }
+            info
+        }
+        Some(AddressKind::Cosmos) => {
+            let account = match AccountId::from_str(address) {
+                Ok(account) => account,
+                Err(e) => return AddressInfo::invalid(input, format!("Invalid bech32: {}", e)),
+            };
+            let bytes = account.to_bytes();
+            let mut info = AddressInfo {
+                input: input.to_string(),
+                valid: true,
+                kind: Some(AddressKind::Cosmos),
+                bech32: AccountId::new(prefix, &bytes)
+                    .ok()
+                    .map(|account| account.to_string()),
+                evm: (bytes.len() == 20).then(|| checksum_evm_address(&bytes)),
+                prefix: Some(account.prefix().to_string()),
+                byte_length: Some(bytes.len()),
+                checksum_valid: None,
+                wrong_vm: false,
+                error: None,
+                warnings: Vec::new(),
+            };
+            if account.prefix() != prefix {
+                info.warnings.push(format!(
+                    "The address has prefix '{}', not '{}'; it is for another chain",
+                    account.prefix(),
+                    prefix
+                ));
+            }
+            if bytes.len() != 20 {
+                info.warnings.push(format!(
+                    "A {}-byte address is a contract or module account, which has no EVM form",
+                    bytes.len()
+                ));
+            }
+            info
+        }
+        None => {
+            return AddressInfo::invalid(
+                input,
+                "Neither a bech32 address nor a 0x-prefixed 20-byte EVM address".to_string(),
+            )
+        }
+    };
+
+    if let (Some(expected), Some(kind)) = (expected, info.kind) {
+        if info.valid && kind != expected {
+            info.wrong_vm = true;
+            let converted = match info.form(expected) {
+                Some(form) => format!("; its {} form is {}", expected.as_str(), form),
+                None => String::new(),
+            };
+            info.warnings.push(format!(
+                "This is a {} address but the operation needs a {} address{}",
+                kind.as_str(),
+                expected.as_str(),
+                converted
+            ));
+        }
+    }
+    info
+}
+
+/// Check `address` is a valid address for `expected`'s VM, naming its form on
+/// that VM when it was written for the other one
+pub fn require_address_kind(
+    address: &str,
+    expected: AddressKind,
+    prefix: &str,
+) -> Result<(), Error> {
+    let info = inspect_address(address, prefix, Some(expected));
+    if info.wrong_vm {
+        return Err(Error::Other(info.warnings.join("; ")));
+    }
+    match info.error {
+        Some(error) if !info.valid => Err(Error::Other(format!("{}: {}", address, error))),
+        _ => Ok(()),
+    }
+}
+
+/// EVM form of a 20-byte bech32 address
+pub fn bech32_to_evm(address: &str) -> Result<String, Error> {
+    let account = AccountId::from_str(address.trim())
+        .map_err(|e| Error::Other(format!("Invalid bech32 address {}: {}", address, e)))?;
+    let bytes = account.to_bytes();
+    if bytes.len() != 20 {
+        return Err(Error::Other(format!(
+            "{} is a {}-byte contract or module account and has no EVM form",
+            address,
+            bytes.len()
+        )));
+    }
+    Ok(checksum_evm_address(&bytes))
+}
+
+/// Bech32 form of an EVM address with `prefix`
+pub fn evm_to_bech32(address: &str, prefix: &str) -> Result<String, Error> {
+    let address = address.trim();
+    if AddressKind::of(address) != Some(AddressKind::Evm) {
+        return Err(Error::Other(format!(
+            "{} is not a 0x-prefixed 20-byte EVM address",
+            address
+        )));
+    }
+    let bytes = hex::decode(&address[2..])
+        .map_err(|e| Error::Other(format!("Invalid EVM address {}: {}", address, e)))?;
+    AccountId::new(prefix, &bytes)
+        .map(|account| account.to_string())
+        .map_err(|e| Error::Other(format!("Invalid bech32 prefix '{}': {}", prefix, e)))
+}
+
+/// `0x` hex of 20 address bytes, EIP-55 checksummed with EVM support
+#[cfg(feature = "evm")]
+pub fn checksum_evm_address(bytes: &[u8]) -> String {
+    crate::protocols::evm::types::utils::to_eip55_checksum(alloy_primitives::Address::from_slice(
+        bytes,
+    ))
+}
+
+/// `0x` hex of 20 address bytes, in lowercase without EVM support
+#[cfg(not(feature = "evm"))]
+pub fn checksum_evm_address(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_addresses_convert_between_forms() {
+        let bytes = [0x5a; 20];
+        let evm = format!("0x{}", hex::encode(bytes));
+        let bech32 = evm_to_bech32(&evm, DEFAULT_ACCOUNT_PREFIX).unwrap();
+        assert!(bech32.starts_with("mantra1"));
+        assert_eq!(bech32_to_evm(&bech32).unwrap().to_lowercase(), evm);
+
+        let info = inspect_address(&bech32, DEFAULT_ACCOUNT_PREFIX, Some(AddressKind::Evm));
+        assert!(info.valid && info.wrong_vm);
+        assert_eq!(info.kind, Some(AddressKind::Cosmos));
+        assert!(info.warnings[0].contains(info.evm.as_deref().unwrap()));
+        assert!(require_address_kind(&bech32, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX).is_err());
+        assert!(require_address_kind(&evm, AddressKind::Evm, DEFAULT_ACCOUNT_PREFIX).is_ok());
+
+        // Another chain's prefix is valid but flagged
+        let cosmos_hub = evm_to_bech32(&evm, "cosmos").unwrap();
+        let info = inspect_address(&cosmos_hub, DEFAULT_ACCOUNT_PREFIX, None);
+        assert_eq!(info.bech32.as_deref(), Some(bech32.as_str()));
+        assert_eq!(info.warnings.len(), 1);
+
+        // Contracts have no EVM form
+        let contract = AccountId::new(DEFAULT_ACCOUNT_PREFIX, &[7u8; 32])
+            .unwrap()
+            .to_string();
+        assert_eq!(inspect_address(&contract, "mantra", None).evm, None);
+        assert!(bech32_to_evm(&contract).is_err());
+
+        assert!(!inspect_address("mantra1nope", "mantra", None).valid);
+        assert!(!inspect_address("0x1234", "mantra", None).valid);
+    }
+
+    #[cfg(feature = "evm")]
+    #[test]
+    fn test_eip55_checksum() {
+        // EIP-55 test vector
+        let address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        assert_eq!(
+            inspect_address(address, "mantra", None).checksum_valid,
+            Some(true)
+        );
+        let mistyped = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD";
+        let info = inspect_address(mistyped, "mantra", None);
+        assert_eq!(info.checksum_valid, Some(false));
+        assert!(!info.valid);
+    }
+}
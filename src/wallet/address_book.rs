@@ -0,0 +1,376 @@
+//! Address book of labelled Cosmos and EVM addresses
+//!
+//! Labels such as `treasury`, `usdc` or `sale-q3` stand for an address
+//! anywhere one is expected: MCP tool arguments and the TUI. The book is kept
+//! as JSON in `~/.mantra_dex/address_book.json` by default, so every front end
+//! on the machine sees the same labels.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use cosmrs::AccountId;
+
+use crate::error::Error;
+
+/// Longest label accepted
+pub const MAX_LABEL_LEN: usize = 64;
+
+/// Chain family of a labelled address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressKind {
+    /// Bech32 account or contract address, e.g. `mantra1...`
+    Cosmos,
+    /// `0x`-prefixed 20-byte address
+    Evm,
+}
+
+impl AddressKind {
+    /// Kind of `address`, or `None` when it is neither a bech32 nor an EVM address
+    pub fn of(address: &str) -> Option<Self> {
+        let address = address.trim();
+        if let Some(hex) = address
+            .strip_prefix("0x")
+            .or_else(|| address.strip_prefix("0X"))
+        {
+            return (hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+                .then_some(Self::Evm);
+        }
+        AccountId::from_str(address).ok().map(|_| Self::Cosmos)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cosmos => "cosmos",
+            Self::Evm => "evm",
+        }
+    }
+}
+
+impl FromStr for AddressKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "cosmos" => Ok(Self::Cosmos),
+            "evm" => Ok(Self::Evm),
+            other => Err(Error::Other(format!(
+                "Unknown address kind '{}', expected cosmos or evm",
+                other
+            ))),
+        }
+    }
+}
+
+/// One labelled address
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressEntry {
+    pub label: String,
+    pub address: String,
+    pub kind: AddressKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// RFC 3339 time the label was last set
+    pub updated_at: String,
+}
+
+/// Labels mapped to addresses, persisted when opened from a file
+#[derive(Debug, Clone, Default)]
+pub struct AddressBook {
+    /// File the book is saved to after every change; in memory only when unset
+    path: Option<PathBuf>,
+    /// Entries by lowercase label
+    entries: BTreeMap<String, AddressEntry>,
+}
+
+impl AddressBook {
+    /// Book kept in memory only
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Default book file (`~/.mantra_dex/address_book.json`)
+    pub fn default_path() -> Result<PathBuf, Error> {
+        let home_dir = dirs::home_dir()
+            .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+        Ok(home_dir.join(".mantra_dex").join("address_book.json"))
+    }
+
+    /// Open the default book file
+    pub fn open_default() -> Result<Self, Error> {
+        Self::open(Self::default_path()?)
+    }
+
+    /// Open the book kept in `path`, which need not exist yet
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let entries = if path.exists() {
+            let data = fs::read_to_string(&path)?;
+            let list: Vec<AddressEntry> = serde_json::from_str(&data).map_err(|e| {
+                Error::Config(format!("Invalid address book {}: {}", path.display(), e))
+            })?;
+            list.into_iter()
+                .map(|entry| (entry.label.to_lowercase(), entry))
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            path: Some(path),
+            entries,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Label `address`, replacing what the label stood for before
+    ///
+    /// Labels are case-insensitive, made of letters, digits, `-`, `_` and `.`,
+    /// and may not themselves be addresses.
+    pub fn add(
+        &mut self,
+        label: &str,
+        address: &str,
+        note: Option<String>,
+    ) -> Result<AddressEntry, Error> {
+        let label = label.trim();
+        Self::validate_label(label)?;
+        let address = address.trim();
+        let kind = AddressKind::of(address).ok_or_else(|| {
+            Error::Config(format!("'{}' is not a Cosmos or EVM address", address))
+        })?;
+
+        let entry = AddressEntry {
+            label: label.to_string(),
+            address: address.to_string(),
+            kind,
+            note,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        self.entries.insert(label.to_lowercase(), entry.clone());
+        self.save()?;
+        Ok(entry)
+    }
+
+    /// Forget `label`, returning what it stood for
+    pub fn remove(&mut self, label: &str) -> Result<Option<AddressEntry>, Error> {
+        let removed = self.entries.remove(&label.trim().to_lowercase());
+        if removed.is_some() {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+
+    pub fn get(&self, label: &str) -> Option<&AddressEntry> {
+        self.entries.get(&label.trim().to_lowercase())
+    }
+
+    /// Entries ordered by label
+    pub fn list(&self) -> Vec<AddressEntry> {
+        self.entries.values().cloned().collect()
+    }
+
+    /// Address `input` stands for: the labelled address, or `input` itself
+    pub fn resolve<'a>(&'a self, input: &'a str) -> &'a str {
+        match self.get(input) {
+            Some(entry) => entry.address.as_str(),
+            None => input,
+        }
+    }
+
+    /// Label of `address`, compared case-insensitively for EVM addresses
+    pub fn label_for(&self, address: &str) -> Option<&str> {
+        self.entries
+            .values()
+            .find(|entry| match entry.kind {
+                AddressKind::Evm => entry.address.eq_ignore_ascii_case(address),
+                AddressKind::Cosmos => entry.address == address,
+            })
+            .map(|entry| entry.label.as_str())
+    }
+
+    /// `address` followed by its label in parentheses, when it has one
+    pub fn display(&self, address: &str) -> String {
+        match self.label_for(address) {
+            Some(label) => format!("{} ({})", address, label),
+            None => address.to_string(),
+        }
+    }
+
+    /// Replace labels with their addresses in the address fields of `value`,
+    /// at any depth, returning the labels resolved
+    ///
+    /// Address fields are those [`is_address_field`] names; a string array
+    /// under such a field has each element resolved.
+    pub fn resolve_labels_in(&self, value: &mut Value) -> Vec<String> {
+        let mut resolved = Vec::new();
+        if !self.is_empty() {
+            self.resolve_value(value, false, &mut resolved);
+        }
+        resolved
+    }
+
+    fn resolve_value(&self, value: &mut Value, address_field: bool, resolved: &mut Vec<String>) {
+        match value {
+            Value::String(text) if address_field => {
+                if let Some(entry) = self.get(text) {
+                    resolved.push(entry.label.clone());
+                    *text = entry.address.clone();
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.resolve_value(item, address_field, resolved);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    // Metadata such as the session id is never an address
+                    if key != "_meta" {
+                        self.resolve_value(item, is_address_field(key), resolved);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Labels of the addresses mentioned in `text`, by address
+    pub fn labels_in_text(&self, text: &str) -> BTreeMap<String, String> {
+        let lowercase = text.to_lowercase();
+        self.entries
+            .values()
+            .filter(|entry| match entry.kind {
+                AddressKind::Evm => lowercase.contains(&entry.address.to_lowercase()),
+                AddressKind::Cosmos => text.contains(&entry.address),
+            })
+            .map(|entry| (entry.address.clone(), entry.label.clone()))
+            .collect()
+    }
+
+    fn validate_label(label: &str) -> Result<(), Error> {
+        if label.is_empty() || label.len() > MAX_LABEL_LEN {
+            return Err(Error::Config(format!(
+                "Labels must be 1 to {} characters long",
+                MAX_LABEL_LEN
+            )));
+        }
+        if !label
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(Error::Config(format!(
+                "Label '{}' may only contain letters, digits, '-', '_' and '.'",
+                label
+            )));
+        }
+        if AddressKind::of(label).is_some() {
+            return Err(Error::Config(format!(
+                "Label '{}' is itself an address",
+                label
+            )));
+        }
+        Ok(())
+    }
+
+    /// Write the book to its file, if it has one
+    fn save(&self) -> Result<(), Error> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Written aside and renamed, so a crash never leaves half a book
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.list())?)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+/// Whether an argument named `key` holds an address: `*address`,
+/// `*addresses` and the usual counterparty names
+pub fn is_address_field(key: &str) -> bool {
+    key.ends_with("address")
+        || key.ends_with("addresses")
+        || matches!(
+            key,
+            "recipient"
+                | "recipients"
+                | "receiver"
+                | "sender"
+                | "spender"
+                | "owner"
+                | "asset_owner"
+                | "to"
+                | "from"
+                | "contract"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COSMOS: &str = "mantra1vwj600jud78djej7ttq44dktu4wr3t2yrrsjgmld8v3jq8mud68q5w7455";
+    const EVM: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    #[test]
+    fn test_labels_resolve_and_persist() {
+        let dir = std::env::temp_dir().join(format!("address_book_{}", uuid::Uuid::new_v4()));
+        let path = dir.join("address_book.json");
+
+        let mut book = AddressBook::open(&path).unwrap();
+        assert!(book.is_empty());
+        book.add("treasury", COSMOS, Some("multisig".to_string()))
+            .unwrap();
+        book.add("USDC", EVM, None).unwrap();
+        assert!(book.add("bad label", COSMOS, None).is_err());
+        assert!(book.add(EVM, COSMOS, None).is_err());
+        assert!(book.add("nowhere", "mantra1nope", None).is_err());
+
+        let book = AddressBook::open(&path).unwrap();
+        assert_eq!(book.get("Treasury").unwrap().kind, AddressKind::Cosmos);
+        assert_eq!(book.resolve("usdc"), EVM);
+        assert_eq!(book.resolve(COSMOS), COSMOS);
+        assert_eq!(book.label_for(&EVM.to_lowercase()), Some("USDC"));
+
+        let mut arguments = serde_json::json!({
+            "recipient": "treasury",
+            "amount": "treasury",
+            "token_addresses": ["usdc", EVM],
+            "allocations": [{"address": "treasury", "amount": "5"}],
+            "_meta": {"session_id": "treasury"}
+        });
+        let resolved = book.resolve_labels_in(&mut arguments);
+        assert_eq!(resolved, ["treasury", "treasury", "USDC"]);
+        assert_eq!(arguments["recipient"], COSMOS);
+        assert_eq!(arguments["amount"], "treasury");
+        assert_eq!(arguments["token_addresses"], serde_json::json!([EVM, EVM]));
+        assert_eq!(arguments["allocations"][0]["address"], COSMOS);
+        assert_eq!(arguments["_meta"]["session_id"], "treasury");
+
+        let labels = book.labels_in_text(&format!("sent to {}", EVM.to_lowercase()));
+        assert_eq!(labels.get(EVM).map(String::as_str), Some("USDC"));
+
+        let mut book = book;
+        assert!(book.remove("TREASURY").unwrap().is_some());
+        assert_eq!(AddressBook::open(&path).unwrap().len(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
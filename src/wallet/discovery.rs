@@ -0,0 +1,188 @@
+//! Discovery of the accounts a mnemonic has used
+//!
+//! Wallets restored from a mnemonic rarely know which derivation indices
+//! hold funds. [`discover_wallets`] derives the addresses of successive
+//! indices, looks up each one's balances and transaction counts through a
+//! caller-supplied probe, [`DISCOVERY_CONCURRENCY`] at a time, and stops once
+//! `gap_limit` unused indices follow the last used one, as BIP-44 account
+//! discovery does.
+
+use std::future::Future;
+
+use cosmwasm_std::Coin;
+use futures::future::try_join_all;
+use serde::Serialize;
+
+use super::hd_path::DerivationOptions;
+use super::multivm::{DerivedAddresses, MultiVMWallet, MAX_ADDRESS_EXPORT_BATCH};
+use crate::error::Error;
+
+/// Unused indices after the last used one before discovery stops
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// Largest gap limit accepted
+pub const MAX_GAP_LIMIT: u32 = 100;
+
+/// Indices probed at once
+pub const DISCOVERY_CONCURRENCY: u32 = 10;
+
+/// On-chain footprint of the addresses of one derivation index
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AccountActivity {
+    /// Non-zero bank balances of the Cosmos address
+    pub balances: Vec<Coin>,
+    /// Transactions signed by the Cosmos address
+    pub cosmos_sequence: u64,
+    /// Native balance of the EVM address in wei, when it was looked up
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_balance: Option<String>,
+    /// Transactions sent from the EVM address
+    pub evm_nonce: u64,
+}
+
+impl AccountActivity {
+    /// Whether the index holds funds or has ever signed a transaction
+    pub fn is_used(&self) -> bool {
+        !self.balances.is_empty()
+            || self.cosmos_sequence > 0
+            || self.evm_nonce > 0
+            || self
+                .evm_balance
+                .as_deref()
+                .is_some_and(|balance| balance != "0")
+    }
+}
+
+/// A derivation index found in use
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiscoveredWallet {
+    pub index: u32,
+    /// `account-<index>`
+    pub label: String,
+    pub cosmos_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_address: Option<String>,
+    #[serde(flatten)]
+    pub activity: AccountActivity,
+}
+
+/// Outcome of [`discover_wallets`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WalletDiscovery {
+    /// Used indices in ascending order
+    pub wallets: Vec<DiscoveredWallet>,
+    /// Indices looked at, from 0
+    pub scanned: u32,
+    pub gap_limit: u32,
+}
+
+/// Find the used derivation indices of `mnemonic`, derived with `options`
+///
+/// `probe` looks up the activity of one index's addresses. Probing stops
+/// after `gap_limit` consecutive unused indices, or at
+/// [`MAX_ADDRESS_EXPORT_BATCH`] indices; the first probe error fails the
+/// discovery, since skipping an index could hide funds.
+pub async fn discover_wallets<P, F>(
+    mnemonic: &str,
+    options: &DerivationOptions,
+    gap_limit: u32,
+    probe: P,
+) -> Result<WalletDiscovery, Error>
+where
+    P: Fn(DerivedAddresses) -> F,
+    F: Future<Output = Result<AccountActivity, Error>>,
+{
+    if gap_limit == 0 || gap_limit > MAX_GAP_LIMIT {
+        return Err(Error::Wallet(format!(
+            "Gap limit must be between 1 and {}",
+            MAX_GAP_LIMIT
+        )));
+    }
+    let wallet = MultiVMWallet::from_mnemonic_with_options(mnemonic, 0, options.clone())?;
+
+    let mut wallets = Vec::new();
+    let mut gap = 0;
+    let mut next = 0;
+    while gap < gap_limit && next < MAX_ADDRESS_EXPORT_BATCH {
+        let end = (next + DISCOVERY_CONCURRENCY).min(MAX_ADDRESS_EXPORT_BATCH);
+        let addresses = wallet.export_addresses(next..end)?;
+        let activities = try_join_all(addresses.iter().cloned().map(&probe)).await?;
+        for (addresses, activity) in addresses.into_iter().zip(activities) {
+            next = addresses.index + 1;
+            if activity.is_used() {
+                gap = 0;
+                wallets.push(DiscoveredWallet {
+                    index: addresses.index,
+                    label: format!("account-{}", addresses.index),
+                    cosmos_address: addresses.cosmos_address,
+                    evm_address: addresses.evm_address,
+                    activity,
+                });
+            } else {
+                gap += 1;
+                if gap == gap_limit {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(WalletDiscovery {
+        wallets,
+        scanned: next,
+        gap_limit,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Uint128;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[tokio::test]
+    async fn test_discovery_stops_after_the_gap() {
+        // Indices 0 and 4 hold funds, index 12 is beyond a gap of 5
+        let probe = |addresses: DerivedAddresses| async move {
+            Ok(match addresses.index {
+                0 => AccountActivity {
+                    balances: vec![Coin {
+                        denom: "uom".to_string(),
+                        amount: Uint128::new(5),
+                    }],
+                    ..Default::default()
+                },
+                4 | 12 => AccountActivity {
+                    cosmos_sequence: 3,
+                    ..Default::default()
+                },
+                _ => AccountActivity::default(),
+            })
+        };
+        let options = DerivationOptions::default();
+        let discovery = discover_wallets(MNEMONIC, &options, 5, probe)
+            .await
+            .unwrap();
+        let indices: Vec<u32> = discovery.wallets.iter().map(|w| w.index).collect();
+        assert_eq!(indices, vec![0, 4]);
+        assert_eq!(discovery.scanned, 10);
+        assert_eq!(discovery.wallets[1].label, "account-4");
+        assert_eq!(
+            discovery.wallets[0].cosmos_address,
+            MultiVMWallet::from_mnemonic(MNEMONIC, 0)
+                .unwrap()
+                .cosmos_address()
+                .unwrap()
+                .to_string()
+        );
+
+        let failing = |_: DerivedAddresses| async { Err(Error::Rpc("down".to_string())) };
+        assert!(discover_wallets(MNEMONIC, &options, 5, failing)
+            .await
+            .is_err());
+        assert!(discover_wallets(MNEMONIC, &options, 0, probe)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! BIP-39 passphrase and BIP-44 path of derived keys
+//!
+//! Keys are derived at `m/44'/{coin_type}'/{account}'/{change}/{index}` from
+//! the seed of a mnemonic and an optional BIP-39 passphrase, the "25th word".
+//! The default [`DerivationOptions`] give the keys this SDK has always
+//! derived: no passphrase, account 0, change 0 and each VM's registered coin
+//! type. Wallets created by other software under another coin type or
+//! account can be recovered by setting the matching options.
+
+use std::fmt;
+use std::str::FromStr;
+
+use bip32::{DerivationPath, Seed, XPrv};
+use bip39::Mnemonic;
+use secrecy::{ExposeSecret, Secret};
+
+use crate::error::Error;
+
+/// SLIP-44 coin type of Cosmos keys
+pub const COSMOS_COIN_TYPE: u32 = 118;
+
+/// SLIP-44 coin type of Ethereum keys
+pub const ETHEREUM_COIN_TYPE: u32 = 60;
+
+/// Largest value of a hardened path component
+const MAX_HARDENED: u32 = 0x7fff_ffff;
+
+/// How keys are derived from a mnemonic
+#[derive(Clone, Default)]
+pub struct DerivationOptions {
+    passphrase: Option<Secret<String>>,
+    coin_type: Option<u32>,
+    account: u32,
+    change: u32,
+}
+
+impl fmt::Debug for DerivationOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivationOptions")
+            .field(
+                "passphrase",
+                &self.passphrase.as_ref().map(|_| "<redacted>"),
+            )
+            .field("coin_type", &self.coin_type)
+            .field("account", &self.account)
+            .field("change", &self.change)
+            .finish()
+    }
+}
+
+impl DerivationOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed the keys with a BIP-39 passphrase; an empty one is no passphrase
+    pub fn with_passphrase(mut self, passphrase: impl Into<String>) -> Self {
+        let passphrase = passphrase.into();
+        self.passphrase = (!passphrase.is_empty()).then(|| Secret::new(passphrase));
+        self
+    }
+
+    /// Derive every key under `coin_type` instead of its VM's registered one
+    pub fn with_coin_type(mut self, coin_type: u32) -> Self {
+        self.coin_type = Some(coin_type);
+        self
+    }
+
+    pub fn with_account(mut self, account: u32) -> Self {
+        self.account = account;
+        self
+    }
+
+    pub fn with_change(mut self, change: u32) -> Self {
+        self.change = change;
+        self
+    }
+
+    pub fn has_passphrase(&self) -> bool {
+        self.passphrase.is_some()
+    }
+
+    /// Coin type set with [`DerivationOptions::with_coin_type`]
+    pub fn coin_type(&self) -> Option<u32> {
+        self.coin_type
+    }
+
+    pub fn account(&self) -> u32 {
+        self.account
+    }
+
+    pub fn change(&self) -> u32 {
+        self.change
+    }
+
+    /// Whether these options derive the SDK's standard keys
+    pub fn is_default(&self) -> bool {
+        self.passphrase.is_none()
+            && self.coin_type.is_none()
+            && self.account == 0
+            && self.change == 0
+    }
+
+    /// Seed of `mnemonic` under the passphrase
+    pub fn seed(&self, mnemonic: &str) -> Result<Seed, Error> {
+        let mnemonic = Mnemonic::from_str(mnemonic)
+            .map_err(|e| Error::Wallet(format!("Invalid mnemonic: {}", e)))?;
+        let passphrase = self
+            .passphrase
+            .as_ref()
+            .map_or("", |passphrase| passphrase.expose_secret().as_str());
+        Ok(Seed::new(mnemonic.to_seed(passphrase)))
+    }
+
+    /// Path of the key at `index` of a VM whose coin type is `vm_coin_type`
+    pub fn path(&self, vm_coin_type: u32, index: u32) -> String {
+        format!(
+            "m/44'/{}'/{}'/{}/{}",
+            self.coin_type.unwrap_or(vm_coin_type),
+            self.account,
+            self.change,
+            index
+        )
+    }
+
+    /// Private key at `index` of a VM whose coin type is `vm_coin_type`
+    pub fn derive_key(
+        &self,
+        seed: &Seed,
+        vm_coin_type: u32,
+        index: u32,
+    ) -> Result<[u8; 32], Error> {
+        let coin_type = self.coin_type.unwrap_or(vm_coin_type);
+        if coin_type > MAX_HARDENED || self.account > MAX_HARDENED {
+            return Err(Error::Wallet(format!(
+                "Coin type and account must be at most {}",
+                MAX_HARDENED
+            )));
+        }
+        let path = DerivationPath::from_str(&self.path(vm_coin_type, index))
+            .map_err(|e| Error::Wallet(format!("Invalid derivation path: {}", e)))?;
+        let derived_key = XPrv::derive_from_path(seed.as_bytes(), &path)
+            .map_err(|e| Error::Wallet(format!("Key derivation error: {}", e)))?;
+        Ok(derived_key.to_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_options_change_the_derived_key() {
+        let standard = DerivationOptions::new();
+        assert!(standard.is_default());
+        assert_eq!(standard.path(COSMOS_COIN_TYPE, 3), "m/44'/118'/0'/0/3");
+        let seed = standard.seed(MNEMONIC).unwrap();
+        let key = standard.derive_key(&seed, COSMOS_COIN_TYPE, 0).unwrap();
+
+        let custom = DerivationOptions::new()
+            .with_coin_type(60)
+            .with_account(2)
+            .with_change(1);
+        assert_eq!(custom.path(COSMOS_COIN_TYPE, 3), "m/44'/60'/2'/1/3");
+        assert_ne!(custom.derive_key(&seed, COSMOS_COIN_TYPE, 0).unwrap(), key);
+
+        // The passphrase changes the seed; an empty one is no passphrase
+        let protected = DerivationOptions::new().with_passphrase("TREZOR");
+        assert!(format!("{:?}", protected).contains("<redacted>"));
+        let other_seed = protected.seed(MNEMONIC).unwrap();
+        assert_ne!(
+            protected
+                .derive_key(&other_seed, COSMOS_COIN_TYPE, 0)
+                .unwrap(),
+            key
+        );
+        assert!(DerivationOptions::new().with_passphrase("").is_default());
+        assert!(DerivationOptions::new()
+            .with_account(1 << 31)
+            .derive_key(&seed, COSMOS_COIN_TYPE, 0)
+            .is_err());
+    }
+}
@@ -1,7 +1,6 @@
 // Allow deprecated Signature for compatibility with alloy-consensus ecosystem
 #![allow(deprecated)]
 
-use bip32::DerivationPath;
 use bip39::Mnemonic;
 use cosmrs::{
     crypto::secp256k1::{Signature as CosmosSignature, SigningKey},
@@ -24,17 +23,35 @@ use sha3::{digest::FixedOutput, Digest, Keccak256};
 use tiny_keccak::{Hasher, Keccak};
 
 use crate::error::Error;
+use crate::gas_price::GasPriceQuote;
 
 // Storage module for wallet persistence
 pub mod storage;
 pub use storage::*;
 
+// Labelled addresses shared by the MCP tools and the TUI
+pub mod address_book;
+pub use address_book::{AddressBook, AddressEntry, AddressKind};
+
+// Cosmos and EVM forms of an address
+pub mod address;
+pub use address::{inspect_address, AddressInfo};
+
+// Wallets settlement refunds instead of distributing to
+pub mod restricted_wallets;
+pub use restricted_wallets::{RestrictedWallet, RestrictedWalletList};
+
+// Used derivation indices of a mnemonic
+pub mod discovery;
+pub use discovery::{discover_wallets, DiscoveredWallet, WalletDiscovery};
+
+// BIP-39 passphrase and BIP-44 path of derived keys
+pub mod hd_path;
+pub use hd_path::DerivationOptions;
+
 // MultiVM wallet for Cosmos and EVM support
 pub mod multivm;
-pub use multivm::MultiVMWallet;
-
-/// HD Path prefix for Cosmos chains (BIP-44)
-const HD_PATH_PREFIX: &str = "m/44'/118'/0'/0/";
+pub use multivm::{export_addresses, DerivedAddresses, MultiVMWallet};
 
 /// Mantra wallet for managing key and signing transactions
 pub struct MantraWallet {
@@ -83,20 +100,18 @@ pub struct Eip712Signature {
 impl MantraWallet {
     /// Create a new wallet from a mnemonic
     pub fn from_mnemonic(mnemonic: &str, account_index: u32) -> Result<Self, Error> {
-        let mnemonic = Mnemonic::from_str(mnemonic)
-            .map_err(|e| Error::Wallet(format!("Invalid mnemonic: {}", e)))?;
-
-        let seed = mnemonic.to_seed("");
-        let seed = bip32::Seed::new(seed);
-
-        let path = format!("{}{}", HD_PATH_PREFIX, account_index);
-        let path = DerivationPath::from_str(&path)
-            .map_err(|e| Error::Wallet(format!("Invalid derivation path: {}", e)))?;
-
-        let derived_key = bip32::XPrv::derive_from_path(seed.as_bytes(), &path)
-            .map_err(|e| Error::Wallet(format!("Key derivation error: {}", e)))?;
+        Self::from_mnemonic_with_options(mnemonic, account_index, &DerivationOptions::default())
+    }
 
-        let derived_key_bytes = derived_key.to_bytes();
+    /// Create a wallet from a mnemonic with a passphrase or a custom HD path
+    pub fn from_mnemonic_with_options(
+        mnemonic: &str,
+        account_index: u32,
+        options: &DerivationOptions,
+    ) -> Result<Self, Error> {
+        let seed = options.seed(mnemonic)?;
+        let derived_key_bytes =
+            options.derive_key(&seed, hd_path::COSMOS_COIN_TYPE, account_index)?;
         let signing_account = SigningKey::from_slice(&derived_key_bytes)
             .map_err(|e| Error::Wallet(format!("Failed to create signing account: {}", e)))?;
         #[cfg(feature = "evm")]
@@ -159,6 +174,7 @@ impl MantraWallet {
     }
 
     /// Prepare and sign a transaction with body and auth info
+    #[allow(clippy::too_many_arguments)]
     pub fn sign_tx(
         &self,
         account_number: u64,
@@ -225,25 +241,23 @@ impl MantraWallet {
         Ok(Fee::from_amount_and_gas(coin, gas_limit))
     }
 
-    /// Create a default fee using the native token
+    /// Create a fee at the Dukong default gas price, for a wallet used
+    /// without a network
+    ///
+    /// Clients price their fees for the network they are connected to with
+    /// [`crate::gas_price::GasPriceQuote::fee`] instead.
     pub fn create_default_fee(&self, gas_limit: u64) -> Result<Fee, Error> {
-        let gas_price = self.compute_gas_price()?;
-        let amount = (gas_limit as f64 * gas_price) as u64;
-
-        // Load network constants
         let constants = crate::config::NetworkConstants::default_dukong()
             .map_err(|e| Error::Config(format!("Failed to load network constants: {}", e)))?;
-
-        self.create_fee(amount, gas_limit, &constants.native_denom)
-    }
-
-    /// Calculate gas price with adjustment
-    fn compute_gas_price(&self) -> Result<f64, Error> {
-        // Load network constants
-        let constants = crate::config::NetworkConstants::default_dukong()
-            .map_err(|e| Error::Config(format!("Failed to load network constants: {}", e)))?;
-
-        Ok(constants.default_gas_price * constants.default_gas_adjustment)
+        GasPriceQuote::new(
+            constants.chain_id,
+            constants.native_denom,
+            constants.default_gas_price,
+            constants.default_gas_adjustment,
+            None,
+            None,
+        )
+        .fee(gas_limit)
     }
 
     /// Derive Ethereum address from the wallet's public key
@@ -103,19 +103,32 @@
 // Allow deprecated Signature for compatibility with alloy-consensus ecosystem
 #![allow(deprecated)]
 
+#[cfg(feature = "evm")]
+use super::hd_path::ETHEREUM_COIN_TYPE;
+use super::hd_path::{DerivationOptions, COSMOS_COIN_TYPE};
 use crate::error::Error;
-use bip32::{DerivationPath, Seed, XPrv};
 use bip39::Mnemonic;
 use cosmrs::crypto::secp256k1::{Signature, SigningKey as CosmosSigningKey};
 use cosmrs::{tx::SignDoc, AccountId};
 use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use std::str::FromStr;
 
-/// HD Path for Cosmos chains (BIP-44)
-const COSMOS_HD_PATH: &str = "m/44'/118'/0'/0/";
-
-/// HD Path for Ethereum chains (BIP-44)
-const ETHEREUM_HD_PATH: &str = "m/44'/60'/0'/0/";
+/// Maximum number of derivation indices exported in a single batch
+pub const MAX_ADDRESS_EXPORT_BATCH: u32 = 10_000;
+
+/// Public addresses derived at a single derivation index (no key material)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DerivedAddresses {
+    /// Derivation index (last path component)
+    pub index: u32,
+    /// Cosmos address, derived from `m/44'/118'/0'/0/{index}` by default
+    pub cosmos_address: String,
+    /// EVM address, derived from `m/44'/60'/0'/0/{index}` by default (requires the `evm` feature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_address: Option<String>,
+}
 
 /// MultiVM wallet that supports both Cosmos and EVM chains
 /// Note: We store the mnemonic to recreate keys as needed since CosmosSigningKey
@@ -128,11 +141,25 @@ pub struct MultiVMWallet {
     account_prefix: String,
     /// Account index used for derivation
     account_index: u32,
+    /// Passphrase and HD path the keys are derived with
+    options: DerivationOptions,
 }
 
 impl MultiVMWallet {
     /// Create a new MultiVM wallet from mnemonic
     pub fn from_mnemonic(mnemonic: &str, account_index: u32) -> Result<Self, Error> {
+        Self::from_mnemonic_with_options(mnemonic, account_index, DerivationOptions::default())
+    }
+
+    /// Create a MultiVM wallet from mnemonic with a passphrase or a custom HD path
+    ///
+    /// A coin type set in `options` applies to both VMs, so the Cosmos and
+    /// EVM keys are then the same key.
+    pub fn from_mnemonic_with_options(
+        mnemonic: &str,
+        account_index: u32,
+        options: DerivationOptions,
+    ) -> Result<Self, Error> {
         // Validate the mnemonic
         let _ = Mnemonic::from_str(mnemonic)
             .map_err(|e| Error::Wallet(format!("Invalid mnemonic: {}", e)))?;
@@ -141,25 +168,16 @@ impl MultiVMWallet {
             mnemonic: Secret::new(mnemonic.to_string()),
             account_prefix: "mantra".to_string(),
             account_index,
+            options,
         })
     }
 
     /// Get the Cosmos signing key (recreated on demand)
     fn get_cosmos_signing_key(&self) -> Result<CosmosSigningKey, Error> {
-        let mnemonic = Mnemonic::from_str(self.mnemonic.expose_secret())
-            .map_err(|e| Error::Wallet(format!("Invalid stored mnemonic: {}", e)))?;
-
-        let seed = mnemonic.to_seed("");
-        let seed = Seed::new(seed);
-
-        let cosmos_path = format!("{}{}", COSMOS_HD_PATH, self.account_index);
-        let cosmos_path = DerivationPath::from_str(&cosmos_path)
-            .map_err(|e| Error::Wallet(format!("Invalid Cosmos derivation path: {}", e)))?;
-
-        let cosmos_derived_key = XPrv::derive_from_path(seed.as_bytes(), &cosmos_path)
-            .map_err(|e| Error::Wallet(format!("Cosmos key derivation error: {}", e)))?;
-
-        let cosmos_key_bytes = cosmos_derived_key.to_bytes();
+        let seed = self.options.seed(self.mnemonic.expose_secret())?;
+        let cosmos_key_bytes =
+            self.options
+                .derive_key(&seed, COSMOS_COIN_TYPE, self.account_index)?;
         CosmosSigningKey::from_slice(&cosmos_key_bytes)
             .map_err(|e| Error::Wallet(format!("Failed to create Cosmos signing key: {}", e)))
     }
@@ -167,20 +185,10 @@ impl MultiVMWallet {
     /// Get the EVM signing key (recreated on demand)
     #[cfg(feature = "evm")]
     fn get_evm_signing_key(&self) -> Result<k256::ecdsa::SigningKey, Error> {
-        let mnemonic = Mnemonic::from_str(self.mnemonic.expose_secret())
-            .map_err(|e| Error::Wallet(format!("Invalid stored mnemonic: {}", e)))?;
-
-        let seed = mnemonic.to_seed("");
-        let seed = Seed::new(seed);
-
-        let evm_path = format!("{}{}", ETHEREUM_HD_PATH, self.account_index);
-        let evm_path = DerivationPath::from_str(&evm_path)
-            .map_err(|e| Error::Wallet(format!("Invalid Ethereum derivation path: {}", e)))?;
-
-        let evm_derived_key = XPrv::derive_from_path(seed.as_bytes(), &evm_path)
-            .map_err(|e| Error::Wallet(format!("EVM key derivation error: {}", e)))?;
-
-        let evm_key_bytes = evm_derived_key.to_bytes();
+        let seed = self.options.seed(self.mnemonic.expose_secret())?;
+        let evm_key_bytes =
+            self.options
+                .derive_key(&seed, ETHEREUM_COIN_TYPE, self.account_index)?;
         k256::ecdsa::SigningKey::from_slice(&evm_key_bytes)
             .map_err(|e| Error::Wallet(format!("Failed to create EVM signing key: {}", e)))
     }
@@ -197,33 +205,8 @@ impl MultiVMWallet {
     /// Get the EVM address (Ethereum-compatible)
     #[cfg(feature = "evm")]
     pub fn evm_address(&self) -> Result<alloy_primitives::Address, Error> {
-        use tiny_keccak::{Hasher, Keccak};
-
         let evm_signing_key = self.get_evm_signing_key()?;
-
-        // Get the verifying key (public key) from the EVM signing key
-        let verifying_key = evm_signing_key.verifying_key();
-
-        // Encode as uncompressed point
-        let point = verifying_key.to_encoded_point(false); // false = uncompressed
-        let pubkey_bytes = point.as_bytes();
-
-        // The uncompressed key should be 65 bytes (0x04 prefix + 64 bytes)
-        if pubkey_bytes.len() != 65 || pubkey_bytes[0] != 0x04 {
-            return Err(Error::Wallet(
-                "Invalid public key format for Ethereum address derivation".to_string(),
-            ));
-        }
-
-        // Compute Keccak-256 hash of the public key (excluding the 0x04 prefix)
-        let mut hasher = Keccak::v256();
-        hasher.update(&pubkey_bytes[1..65]); // Skip the 0x04 prefix
-        let mut hash = [0u8; 32];
-        hasher.finalize(&mut hash);
-
-        // Take the last 20 bytes as the Ethereum address
-        let address = alloy_primitives::Address::from_slice(&hash[12..]);
-        Ok(address)
+        evm_address_from_key(&evm_signing_key)
     }
 
     /// Get the account index
@@ -231,6 +214,64 @@ impl MultiVMWallet {
         self.account_index
     }
 
+    /// Passphrase and HD path the keys are derived with
+    pub fn derivation_options(&self) -> &DerivationOptions {
+        &self.options
+    }
+
+    /// Export the public addresses for a range of derivation indices
+    ///
+    /// The wallet acts as a handle to the mnemonic: only addresses leave this call,
+    /// never keys. The seed is derived once for the whole batch, so this is suitable
+    /// for pre-generating large sets of deposit addresses. Ranges larger than
+    /// [`MAX_ADDRESS_EXPORT_BATCH`] are rejected.
+    pub fn export_addresses(&self, range: Range<u32>) -> Result<Vec<DerivedAddresses>, Error> {
+        if range.is_empty() {
+            return Ok(Vec::new());
+        }
+        if range.len() > MAX_ADDRESS_EXPORT_BATCH as usize {
+            return Err(Error::Wallet(format!(
+                "Cannot export {} addresses at once (maximum {})",
+                range.len(),
+                MAX_ADDRESS_EXPORT_BATCH
+            )));
+        }
+
+        let seed = self.options.seed(self.mnemonic.expose_secret())?;
+
+        range
+            .map(|index| {
+                let cosmos_key = self.options.derive_key(&seed, COSMOS_COIN_TYPE, index)?;
+                let cosmos_address = CosmosSigningKey::from_slice(&cosmos_key)
+                    .map_err(|e| {
+                        Error::Wallet(format!("Failed to create Cosmos signing key: {}", e))
+                    })?
+                    .public_key()
+                    .account_id(&self.account_prefix)
+                    .map_err(|e| Error::Wallet(format!("Failed to get Cosmos account ID: {}", e)))?
+                    .to_string();
+
+                #[cfg(feature = "evm")]
+                let evm_address = {
+                    let evm_key = self.options.derive_key(&seed, ETHEREUM_COIN_TYPE, index)?;
+                    let signing_key =
+                        k256::ecdsa::SigningKey::from_slice(&evm_key).map_err(|e| {
+                            Error::Wallet(format!("Failed to create EVM signing key: {}", e))
+                        })?;
+                    Some(evm_address_from_key(&signing_key)?.to_string())
+                };
+                #[cfg(not(feature = "evm"))]
+                let evm_address = None;
+
+                Ok(DerivedAddresses {
+                    index,
+                    cosmos_address,
+                    evm_address,
+                })
+            })
+            .collect()
+    }
+
     /// Sign a Cosmos transaction
     pub fn sign_cosmos_tx(&self, sign_doc: SignDoc) -> Result<Signature, Error> {
         let signing_key = self.get_cosmos_signing_key()?;
@@ -270,10 +311,59 @@ impl MultiVMWallet {
     }
 }
 
+/// Compute the Ethereum address of a secp256k1 signing key
+#[cfg(feature = "evm")]
+fn evm_address_from_key(
+    signing_key: &k256::ecdsa::SigningKey,
+) -> Result<alloy_primitives::Address, Error> {
+    use tiny_keccak::{Hasher, Keccak};
+
+    // Get the verifying key (public key) from the EVM signing key
+    let verifying_key = signing_key.verifying_key();
+
+    // Encode as uncompressed point
+    let point = verifying_key.to_encoded_point(false); // false = uncompressed
+    let pubkey_bytes = point.as_bytes();
+
+    // The uncompressed key should be 65 bytes (0x04 prefix + 64 bytes)
+    if pubkey_bytes.len() != 65 || pubkey_bytes[0] != 0x04 {
+        return Err(Error::Wallet(
+            "Invalid public key format for Ethereum address derivation".to_string(),
+        ));
+    }
+
+    // Compute Keccak-256 hash of the public key (excluding the 0x04 prefix)
+    let mut hasher = Keccak::v256();
+    hasher.update(&pubkey_bytes[1..65]); // Skip the 0x04 prefix
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+
+    // Take the last 20 bytes as the Ethereum address
+    Ok(alloy_primitives::Address::from_slice(&hash[12..]))
+}
+
+/// Export Cosmos and EVM addresses for a range of derivation indices
+///
+/// Convenience wrapper around [`MultiVMWallet::export_addresses`].
+pub fn export_addresses(
+    wallet: &MultiVMWallet,
+    range: Range<u32>,
+) -> Result<Vec<DerivedAddresses>, Error> {
+    wallet.export_addresses(range)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn entry_at(mnemonic: &str, index: u32) -> String {
+        MultiVMWallet::from_mnemonic(mnemonic, index)
+            .unwrap()
+            .cosmos_address()
+            .unwrap()
+            .to_string()
+    }
+
     #[test]
     fn test_multivm_wallet_creation() {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
@@ -292,4 +382,46 @@ mod tests {
             assert_ne!(cosmos_addr.to_string(), evm_addr.to_string());
         }
     }
+
+    #[test]
+    fn test_export_addresses_matches_single_derivation() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let wallet = MultiVMWallet::from_mnemonic(mnemonic, 0).unwrap();
+
+        let exported = export_addresses(&wallet, 0..3).unwrap();
+        assert_eq!(exported.len(), 3);
+        for entry in &exported {
+            let single = MultiVMWallet::from_mnemonic(mnemonic, entry.index).unwrap();
+            assert_eq!(
+                entry.cosmos_address,
+                single.cosmos_address().unwrap().to_string()
+            );
+            #[cfg(feature = "evm")]
+            assert_eq!(
+                entry.evm_address.as_deref(),
+                Some(single.evm_address().unwrap().to_string().as_str())
+            );
+        }
+
+        assert!(wallet.export_addresses(5..5).unwrap().is_empty());
+
+        // A passphrase and custom path give other addresses on both wallet types
+        let options = DerivationOptions::new()
+            .with_passphrase("TREZOR")
+            .with_account(1);
+        let protected =
+            MultiVMWallet::from_mnemonic_with_options(mnemonic, 0, options.clone()).unwrap();
+        let exported = protected.export_addresses(2..3).unwrap();
+        assert_ne!(exported[0].cosmos_address, entry_at(mnemonic, 2));
+        assert_eq!(
+            exported[0].cosmos_address,
+            crate::wallet::MantraWallet::from_mnemonic_with_options(mnemonic, 2, &options)
+                .unwrap()
+                .info()
+                .address
+        );
+        assert!(wallet
+            .export_addresses(0..MAX_ADDRESS_EXPORT_BATCH + 1)
+            .is_err());
+    }
 }
@@ -0,0 +1,338 @@
+//! Restricted wallets excluded from PrimarySale distributions
+//!
+//! `settleBatch` refunds the contributions of the restricted wallets passed to
+//! it to the sale's multisig instead of distributing to them, and takes at
+//! most [`MAX_RESTRICTED_PER_BATCH`] of them per call. The list is kept as
+//! JSON in `~/.mantra_dex/restricted_wallets.json` by default, filled by hand
+//! or imported from a CSV sanctions feed, and [`plan_settle_batch`] picks the
+//! listed wallets each batch needs.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::address::bech32_to_evm;
+use super::address_book::AddressKind;
+use crate::error::Error;
+
+/// Restricted wallets `settleBatch` accepts per call
+pub const MAX_RESTRICTED_PER_BATCH: usize = 50;
+
+/// One restricted wallet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestrictedWallet {
+    /// Lowercase `0x` address
+    pub address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Feed or file the wallet was imported from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// RFC 3339 time the wallet was last listed
+    pub updated_at: String,
+}
+
+/// Outcome of [`RestrictedWalletList::import_csv`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CsvImport {
+    pub added: usize,
+    /// Wallets already listed, whose reason and source were replaced
+    pub updated: usize,
+    /// Lines that were not a valid address, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// Listed wallets split by whether they invested in a sale
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RestrictedWalletValidation {
+    /// Listed wallets that are investors; settlement refunds them
+    pub investors: Vec<String>,
+    /// Listed wallets that did not invest; settlement ignores them
+    pub not_investors: Vec<String>,
+}
+
+/// The next `settleBatch` call of a settlement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettleBatchPlan {
+    /// Investors to settle, at most the batch size asked for
+    pub batch_size: usize,
+    /// Listed wallets among those investors, in investor order
+    pub restricted_wallets: Vec<String>,
+}
+
+/// Restricted wallets, persisted when opened from a file
+#[derive(Debug, Clone, Default)]
+pub struct RestrictedWalletList {
+    /// File the list is saved to after every change; in memory only when unset
+    path: Option<PathBuf>,
+    /// Entries by lowercase address
+    entries: BTreeMap<String, RestrictedWallet>,
+}
+
+impl RestrictedWalletList {
+    /// List kept in memory only
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Default list file (`~/.mantra_dex/restricted_wallets.json`)
+    pub fn default_path() -> Result<PathBuf, Error> {
+        let home_dir = dirs::home_dir()
+            .ok_or_else(|| Error::Config("Could not determine home directory".to_string()))?;
+        Ok(home_dir.join(".mantra_dex").join("restricted_wallets.json"))
+    }
+
+    /// Open the default list file
+    pub fn open_default() -> Result<Self, Error> {
+        Self::open(Self::default_path()?)
+    }
+
+    /// Open the list kept in `path`, which need not exist yet
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let entries = if path.exists() {
+            let data = fs::read_to_string(&path)?;
+            let list: Vec<RestrictedWallet> = serde_json::from_str(&data).map_err(|e| {
+                Error::Config(format!(
+                    "Invalid restricted wallet list {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            list.into_iter()
+                .map(|entry| (entry.address.clone(), entry))
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            path: Some(path),
+            entries,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// List `address`, given in either its EVM or its bech32 form, returning
+    /// the entry and whether it was listed before
+    pub fn add(
+        &mut self,
+        address: &str,
+        reason: Option<String>,
+        source: Option<String>,
+    ) -> Result<(RestrictedWallet, bool), Error> {
+        let (entry, replaced) = self.insert(address, reason, source)?;
+        self.save()?;
+        Ok((entry, replaced))
+    }
+
+    /// Unlist `address`, returning its entry
+    pub fn remove(&mut self, address: &str) -> Result<Option<RestrictedWallet>, Error> {
+        let removed = self.entries.remove(&normalize(address)?);
+        if removed.is_some() {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+
+    /// Whether `address` is listed; addresses that are not valid never are
+    pub fn contains(&self, address: &str) -> bool {
+        normalize(address).is_ok_and(|address| self.entries.contains_key(&address))
+    }
+
+    /// Entries ordered by address
+    pub fn list(&self) -> Vec<RestrictedWallet> {
+        self.entries.values().cloned().collect()
+    }
+
+    /// List the addresses of a CSV feed, one `address[,reason]` per line
+    ///
+    /// A header line, blank lines and `#` comments are skipped silently;
+    /// other lines without a valid address are reported and skipped. The
+    /// list is saved once, after the whole feed.
+    pub fn import_csv(&mut self, csv: &str, source: Option<&str>) -> Result<CsvImport, Error> {
+        let mut import = CsvImport::default();
+        for (number, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (address, reason) = match line.split_once(',') {
+                Some((address, reason)) => (address, reason),
+                None => (line, ""),
+            };
+            let address = address.trim().trim_matches('"');
+            if number == 0 && AddressKind::of(address).is_none() {
+                continue;
+            }
+            let reason = reason.trim().trim_matches('"');
+            let reason = (!reason.is_empty()).then(|| reason.to_string());
+            match self.insert(address, reason, source.map(str::to_string)) {
+                Ok((_, true)) => import.updated += 1,
+                Ok((_, false)) => import.added += 1,
+                Err(e) => import.skipped.push(format!("line {}: {}", number + 1, e)),
+            }
+        }
+        self.save()?;
+        Ok(import)
+    }
+
+    /// Split the listed wallets by whether they are among `investors`
+    pub fn validate_against<'a>(
+        &self,
+        investors: impl IntoIterator<Item = &'a str>,
+    ) -> RestrictedWalletValidation {
+        let investors: HashSet<String> = investors
+            .into_iter()
+            .map(|address| address.to_lowercase())
+            .collect();
+        let (investors, not_investors) = self
+            .entries
+            .keys()
+            .cloned()
+            .partition(|address| investors.contains(address));
+        RestrictedWalletValidation {
+            investors,
+            not_investors,
+        }
+    }
+
+    fn insert(
+        &mut self,
+        address: &str,
+        reason: Option<String>,
+        source: Option<String>,
+    ) -> Result<(RestrictedWallet, bool), Error> {
+        let address = normalize(address)?;
+        let entry = RestrictedWallet {
+            address: address.clone(),
+            reason,
+            source,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        let replaced = self.entries.insert(address, entry.clone()).is_some();
+        Ok((entry, replaced))
+    }
+
+    /// Write the list to its file, if it has one
+    fn save(&self) -> Result<(), Error> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Written aside and renamed, so a crash never leaves half a list
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.list())?)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+/// Plan the next `settleBatch` call over `batch`, the next `batch.len()`
+/// investors of the sale in settlement order
+///
+/// The restricted wallets of a call must be among the investors it settles,
+/// so when more than [`MAX_RESTRICTED_PER_BATCH`] of `batch` are listed the
+/// batch is cut short before the first one over the limit; the next call
+/// picks up from there.
+pub fn plan_settle_batch(list: &RestrictedWalletList, batch: &[String]) -> SettleBatchPlan {
+    let mut restricted_wallets = Vec::new();
+    for (index, investor) in batch.iter().enumerate() {
+        if list.contains(investor) {
+            if restricted_wallets.len() == MAX_RESTRICTED_PER_BATCH {
+                return SettleBatchPlan {
+                    batch_size: index,
+                    restricted_wallets,
+                };
+            }
+            restricted_wallets.push(investor.to_lowercase());
+        }
+    }
+    SettleBatchPlan {
+        batch_size: batch.len(),
+        restricted_wallets,
+    }
+}
+
+/// Lowercase `0x` form of an EVM or 20-byte bech32 address
+fn normalize(address: &str) -> Result<String, Error> {
+    let address = address.trim();
+    match AddressKind::of(address) {
+        Some(AddressKind::Evm) => Ok(address.to_lowercase()),
+        Some(AddressKind::Cosmos) => bech32_to_evm(address).map(|evm| evm.to_lowercase()),
+        None => Err(Error::Config(format!(
+            "'{}' is not a Cosmos or EVM address",
+            address
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::address::{evm_to_bech32, DEFAULT_ACCOUNT_PREFIX};
+
+    fn address(byte: u8) -> String {
+        format!("0x{}", hex::encode([byte; 20]))
+    }
+
+    #[test]
+    fn test_list_persists_imports_and_plans_batches() {
+        let dir = std::env::temp_dir().join(format!("restricted_wallets_{}", uuid::Uuid::new_v4()));
+        let path = dir.join("restricted_wallets.json");
+
+        let mut list = RestrictedWalletList::open(&path).unwrap();
+        let bech32 = evm_to_bech32(&address(1), DEFAULT_ACCOUNT_PREFIX).unwrap();
+        let (entry, replaced) = list.add(&bech32, Some("OFAC".to_string()), None).unwrap();
+        assert_eq!(entry.address, address(1));
+        assert!(!replaced);
+        assert!(list.add("mantra1nope", None, None).is_err());
+
+        let csv = format!(
+            "address,reason\n{},sanctioned\n\n# comment\n{}\nnot-an-address\n",
+            address(1).to_uppercase().replace("0X", "0x"),
+            address(2)
+        );
+        let import = list.import_csv(&csv, Some("feed.csv")).unwrap();
+        assert_eq!((import.added, import.updated), (1, 1));
+        assert_eq!(import.skipped.len(), 1);
+        assert!(import.skipped[0].starts_with("line 6"));
+
+        let list = RestrictedWalletList::open(&path).unwrap();
+        assert_eq!(list.len(), 2);
+        assert!(list.contains(&bech32));
+        assert_eq!(list.list()[0].reason.as_deref(), Some("sanctioned"));
+
+        let investors = [address(2), address(3)];
+        let validation = list.validate_against(investors.iter().map(String::as_str));
+        assert_eq!(validation.investors, [address(2)]);
+        assert_eq!(validation.not_investors, [address(1)]);
+
+        // 60 listed investors in a batch of 100 are split over two calls
+        let mut list = RestrictedWalletList::in_memory();
+        let batch: Vec<String> = (0..100u8).map(address).collect();
+        for investor in &batch[..60] {
+            list.add(investor, None, None).unwrap();
+        }
+        let plan = plan_settle_batch(&list, &batch);
+        assert_eq!(plan.batch_size, 50);
+        assert_eq!(plan.restricted_wallets.len(), MAX_RESTRICTED_PER_BATCH);
+        let plan = plan_settle_batch(&list, &batch[50..]);
+        assert_eq!(plan.batch_size, 50);
+        assert_eq!(plan.restricted_wallets, batch[50..60]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -30,6 +30,9 @@ pub struct WalletMetadata {
     pub address: String,
     pub created_at: String,
     pub last_accessed: Option<String>,
+    /// Free-form label shown next to the wallet name
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 /// Main wallet storage manager
@@ -41,8 +44,11 @@ pub struct WalletStorage {
 impl WalletStorage {
     /// Create a new WalletStorage instance
     pub fn new() -> Result<Self, Error> {
-        let storage_dir = Self::get_storage_directory()?;
+        Self::with_directory(Self::get_storage_directory()?)
+    }
 
+    /// Create a WalletStorage keeping its wallets in `storage_dir`
+    pub fn with_directory(storage_dir: PathBuf) -> Result<Self, Error> {
         // Create directory if it doesn't exist
         if !storage_dir.exists() {
             fs::create_dir_all(&storage_dir)
@@ -152,6 +158,7 @@ impl WalletStorage {
             address: address.to_string(),
             created_at: chrono::Utc::now().to_rfc3339(),
             last_accessed: None,
+            label: None,
         };
 
         // Create encrypted wallet data
@@ -225,6 +232,52 @@ impl WalletStorage {
         Ok(())
     }
 
+    /// Set or clear the label of a saved wallet
+    pub fn set_label(&self, name: &str, label: Option<&str>) -> Result<(), Error> {
+        let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
+
+        if !wallet_path.exists() {
+            return Err(Error::Wallet(format!("Wallet '{}' not found", name)));
+        }
+
+        let mut wallet_data = self.load_wallet_file(&wallet_path)?;
+        wallet_data.metadata.label = label
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string);
+        self.write_wallet_file(&wallet_path, &wallet_data)
+    }
+
+    /// Import an encrypted keystore file, as written by [`Self::save_wallet`]
+    /// or exported from another machine, under the name `name`
+    ///
+    /// The file keeps its password; it is only checked to be a well-formed
+    /// keystore before being copied in.
+    pub fn import_wallet_file(
+        &self,
+        path: &std::path::Path,
+        name: &str,
+    ) -> Result<WalletMetadata, Error> {
+        let wallet_path = self.storage_dir.join(format!("{}.wallet", name));
+
+        if wallet_path.exists() {
+            return Err(Error::Wallet(format!("Wallet '{}' already exists", name)));
+        }
+
+        let mut wallet_data = self.load_wallet_file(path)?;
+        PasswordHash::new(&wallet_data.password_hash)
+            .map_err(|e| Error::Wallet(format!("Invalid keystore password hash: {}", e)))?;
+        if wallet_data.nonce.len() != 12 {
+            return Err(Error::Wallet("Invalid keystore nonce".to_string()));
+        }
+
+        wallet_data.metadata.name = name.to_string();
+        wallet_data.metadata.last_accessed = None;
+        self.write_wallet_file(&wallet_path, &wallet_data)?;
+
+        Ok(wallet_data.metadata)
+    }
+
     /// Load wallet file and deserialize
     fn load_wallet_file(&self, path: &std::path::Path) -> Result<EncryptedWalletData, Error> {
         let wallet_content = fs::read_to_string(path)
@@ -236,6 +289,21 @@ impl WalletStorage {
         Ok(wallet_data)
     }
 
+    /// Serialize and write wallet data to a file
+    fn write_wallet_file(
+        &self,
+        path: &std::path::Path,
+        wallet_data: &EncryptedWalletData,
+    ) -> Result<(), Error> {
+        let wallet_json = serde_json::to_string_pretty(wallet_data)
+            .map_err(|e| Error::Wallet(format!("Failed to serialize wallet data: {}", e)))?;
+
+        fs::write(path, wallet_json)
+            .map_err(|e| Error::Wallet(format!("Failed to write wallet file: {}", e)))?;
+
+        Ok(())
+    }
+
     /// Derive encryption key from password hash
     fn derive_key_from_hash(
         &self,
@@ -313,3 +381,38 @@ impl Default for WalletStorage {
         Self::new().expect("Failed to initialize WalletStorage")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &str = "Correct-Horse-42";
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_label_and_keystore_import() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let source = WalletStorage::with_directory(source_dir.path().to_path_buf()).unwrap();
+        source
+            .save_wallet("main", MNEMONIC, PASSWORD, "mantra1main")
+            .unwrap();
+        source.set_label("main", Some(" trading ")).unwrap();
+        assert_eq!(
+            source.list_wallets().unwrap()[0].label.as_deref(),
+            Some("trading")
+        );
+
+        let target_dir = tempfile::tempdir().unwrap();
+        let target = WalletStorage::with_directory(target_dir.path().to_path_buf()).unwrap();
+        let keystore = source_dir.path().join("main.wallet");
+        let metadata = target.import_wallet_file(&keystore, "imported").unwrap();
+        assert_eq!(metadata.name, "imported");
+        assert_eq!(metadata.address, "mantra1main");
+        assert_eq!(target.load_wallet("imported", PASSWORD).unwrap(), MNEMONIC);
+        assert!(target.import_wallet_file(&keystore, "imported").is_err());
+
+        let bogus = source_dir.path().join("bogus.json");
+        fs::write(&bogus, "{}").unwrap();
+        assert!(target.import_wallet_file(&bogus, "bogus").is_err());
+    }
+}
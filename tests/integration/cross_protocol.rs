@@ -645,6 +645,7 @@ mod claimdrop_to_skip {
             error_message: None,
             initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
             completed_at: None,
+            verification: None,
         };
 
         println!("    Transfer ID: {}", transfer_result.transfer_id);
@@ -670,6 +671,7 @@ mod claimdrop_to_skip {
             error_message: None,
             initiated_at: transfer_result.initiated_at,
             completed_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 300), // 5 min later
+            verification: None,
         };
 
         println!("    Final Status: {:?}", completed_transfer.status);
@@ -818,6 +820,7 @@ mod claimdrop_to_skip {
                 error_message: None,
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: None,
+                verification: None,
             };
 
             println!("      Transfer ID: {}", batch_transfer_result.transfer_id);
@@ -859,6 +862,7 @@ mod claimdrop_to_skip {
                 error_message: None,
                 initiated_at: transfer.initiated_at,
                 completed_at: Some(transfer.initiated_at.unwrap() + completion_delay as u64),
+                verification: None,
             };
 
             let original_amount = Uint128::from(
@@ -1018,6 +1022,7 @@ mod claimdrop_to_skip {
                 error_message: None,
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: None,
+                verification: None,
             };
 
             println!("    Distribution to {}: {} ureward", target_chain, amount);
@@ -1066,6 +1071,7 @@ mod claimdrop_to_skip {
                     error_message: None,
                     initiated_at: transfer.initiated_at,
                     completed_at: Some(transfer.initiated_at.unwrap() + 420), // 7 minutes
+                    verification: None,
                 };
 
                 successful_distributions += 1;
@@ -1085,6 +1091,7 @@ mod claimdrop_to_skip {
                     error_message: Some("Destination chain congested".to_string()),
                     initiated_at: transfer.initiated_at,
                     completed_at: Some(transfer.initiated_at.unwrap() + 300), // 5 minutes
+                    verification: None,
                 };
 
                 failed_distributions += 1;
@@ -1258,6 +1265,7 @@ mod dex_to_skip {
                 error_message: None,
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: None,
+                verification: None,
             };
 
             println!("    Transfer {}: {} → {}", 
@@ -1301,6 +1309,7 @@ mod dex_to_skip {
                 error_message: None,
                 initiated_at: transfer.initiated_at,
                 completed_at: Some(transfer.initiated_at.unwrap() + 600), // 10 minutes
+                verification: None,
             };
 
             println!("    Transfer {} completed:", completed_transfer.transfer_id);
@@ -1547,6 +1556,7 @@ mod dex_to_skip {
                 error_message: None,
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 300),
+                verification: None,
             };
 
             println!("      Step 2 (Transfer): {} tokens transferred", 
@@ -100,6 +100,7 @@ mod test_utils {
                         decimals: 6,
                         is_native: true,
                         contract_address: None,
+                        trace: None,
                     },
                     ChainAsset {
                         denom: "factory/mantra1qwm8p82w0ygaz3duf0y56gjf8pwh5ykmgnqmtm/uUSDY".to_string(),
@@ -107,6 +108,7 @@ mod test_utils {
                         decimals: 6,
                         is_native: false,
                         contract_address: Some("mantra1qwm8p82w0ygaz3duf0y56gjf8pwh5ykmgnqmtm".to_string()),
+                        trace: None,
                     },
                 ],
                 bridges: vec![
@@ -120,6 +122,7 @@ mod test_utils {
                         max_amount: Some(Uint128::from(1000000000000u128)),
                     },
                 ],
+                rpc_endpoint: None,
             },
             SupportedChain {
                 chain_id: "osmosis-1".to_string(),
@@ -133,6 +136,7 @@ mod test_utils {
                         decimals: 6,
                         is_native: true,
                         contract_address: None,
+                        trace: None,
                     },
                     ChainAsset {
                         denom: "ibc/0471F1C4E7AFD3F07702BEF6DC365268D64570F7C1FDC98EA6098DD6DE59817B".to_string(),
@@ -140,6 +144,7 @@ mod test_utils {
                         decimals: 6,
                         is_native: false,
                         contract_address: None,
+                        trace: None,
                     },
                 ],
                 bridges: vec![
@@ -153,6 +158,7 @@ mod test_utils {
                         max_amount: Some(Uint128::from(1000000000000u128)),
                     },
                 ],
+                rpc_endpoint: None,
             },
         ]
     }
@@ -212,6 +218,8 @@ mod route_discovery {
                         },
                         estimated_time_seconds: Some(30),
                         fee: Some(Coin::new(50000u128, &source_asset.denom)),
+                        channel: None,
+                        swap: None,
                     },
                     RouteStep {
                         chain: target_asset.chain.clone(),
@@ -232,6 +240,8 @@ mod route_discovery {
                         },
                         estimated_time_seconds: Some(300),
                         fee: Some(Coin::new(10000u128, "ibc/intermediate_token")),
+                        channel: Some("channel-0".to_string()),
+                        swap: None,
                     },
                 ],
                 estimated_time_seconds: Some(330),
@@ -529,6 +539,7 @@ mod cross_chain_transfers {
                 error_message: None,
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: None,
+                verification: None,
             };
 
             println!("    Transfer ID: {}", mock_result.transfer_id);
@@ -583,6 +594,7 @@ mod cross_chain_transfers {
                 } else {
                     None
                 },
+                verification: None,
             };
 
             // Validate status progression logic
@@ -634,6 +646,7 @@ mod cross_chain_transfers {
                 error_message: Some(error_msg.to_string()),
                 initiated_at: Some(1640995200),
                 completed_at: Some(1640995500), // Completed with error
+                verification: None,
             };
 
             // Validate error handling
@@ -684,6 +697,7 @@ mod cross_chain_transfers {
                     error_message: Some(format!("Transfer timed out after {} seconds", timeout_secs)),
                     initiated_at: Some(1640995200),
                     completed_at: Some(1640995200 + timeout_secs as u64),
+                    verification: None,
                 }
             } else {
                 TransferResult {
@@ -695,6 +709,7 @@ mod cross_chain_transfers {
                     error_message: None,
                     initiated_at: Some(1640995200),
                     completed_at: Some(1640995200 + simulated_execution_time as u64),
+                    verification: None,
                 }
             };
 
@@ -1299,6 +1314,7 @@ mod error_handling {
                 } else {
                     None
                 },
+                verification: None,
             };
 
             // Validate error handling structure
@@ -1507,6 +1523,7 @@ mod error_handling {
                 error_message: Some(error_message.clone()),
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + 5),
+                verification: None,
             };
 
             // Validate error result structure
@@ -1629,6 +1646,7 @@ mod error_handling {
                 error_message: final_error,
                 initiated_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
                 completed_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() + (attempt_count * 30) as u64),
+                verification: None,
             };
 
             println!("    Transfer ID: {}", transfer_result.transfer_id);
@@ -0,0 +1,206 @@
+//! Tests against the in-memory chain of the `testing` feature
+//!
+//! Run with `cargo test --features testing --test mock_chain_test`.
+
+#![cfg(feature = "testing")]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use mantra_sdk::protocols::claimdrop::{
+    Allocation, CampaignBuilder, CampaignParams, ClaimdropFactoryClient, DistributionType,
+};
+use mantra_sdk::testing::{fixtures, MockMantraClient};
+use mantra_sdk::tx_queue::{QueuedTxStatus, TxQueue, TxQueueChange, TxQueuePolicy, TxVm};
+use mantra_sdk::{Coin, Decimal, Error, Uint128};
+
+#[tokio::test]
+async fn test_claim_moves_available_rewards() {
+    let client = MockMantraClient::new(fixtures::mock_chain());
+
+    let campaign = client.get_campaign(fixtures::CAMPAIGN).await.unwrap();
+    assert_eq!(campaign.start_time, fixtures::CAMPAIGN_START);
+
+    client
+        .claim(fixtures::CAMPAIGN, fixtures::BOB)
+        .await
+        .unwrap();
+    let rewards = client
+        .get_rewards(fixtures::CAMPAIGN, fixtures::BOB)
+        .await
+        .unwrap();
+    assert_eq!(rewards.claimed, vec![Coin::new(2_500_000_000u128, "uom")]);
+    assert!(rewards.available_to_claim.is_empty());
+    assert_eq!(
+        client.rpc().balance(fixtures::BOB, "uom").u128(),
+        2_510_000_000
+    );
+
+    // Nothing left to claim, and the failed attempt is still included
+    let height = client.rpc().height();
+    let err = client
+        .claim(fixtures::CAMPAIGN, fixtures::BOB)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Tx(_)), "{}", err);
+    assert_eq!(client.rpc().height(), height + 1);
+}
+
+#[tokio::test]
+async fn test_programmed_responses_take_precedence_once() {
+    let client = MockMantraClient::new(fixtures::mock_chain());
+    let rpc = client.rpc();
+
+    rpc.fail(fixtures::POOL_MANAGER, "pools", 9, "node is syncing", 1);
+    let err = client.get_pools(None).await.unwrap_err();
+    assert!(err.to_string().contains("node is syncing"), "{}", err);
+
+    let pools = client.get_pools(None).await.unwrap();
+    assert_eq!(pools.len(), 2);
+    assert_eq!(rpc.queries().len(), 2);
+    assert_eq!(
+        rpc.queries()[1].msg["pools"]["pool_identifier"],
+        serde_json::Value::Null
+    );
+
+    let err = client
+        .get_rewards("mantra1nocontract", fixtures::BOB)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no such contract"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_tx_queue_resends_dropped_transaction() {
+    let client = MockMantraClient::new(fixtures::mock_chain());
+    let queue = TxQueue::in_memory();
+    let raw = b"signed swap".to_vec();
+
+    // The node accepts the first broadcast but never includes it
+    client.rpc().drop_broadcasts(1);
+    let hash = client.broadcast_tx_bytes(&raw).await.unwrap();
+    queue
+        .enqueue(TxVm::Cosmos, fixtures::CHAIN_ID, &hash, &raw, "MsgSwap")
+        .unwrap();
+    queue.mark_broadcast(&hash).unwrap();
+
+    let policy = TxQueuePolicy {
+        retry_after: Duration::ZERO,
+        ..TxQueuePolicy::default()
+    };
+    let updates = queue.poll(&client, &policy).await.unwrap();
+    assert_eq!(updates[0].change, TxQueueChange::Resent);
+
+    let updates = queue.poll(&client, &policy).await.unwrap();
+    assert_eq!(updates[0].change, TxQueueChange::Confirmed);
+    let height = client.rpc().transaction(&hash).unwrap().height;
+    let status = queue.get(&hash).unwrap().status;
+    assert!(
+        matches!(status, QueuedTxStatus::Confirmed { height: h, .. } if h == height),
+        "{:?}",
+        status
+    );
+    // The mock Cosmos chain cannot reorganize, so it reports no block hash
+    if let QueuedTxStatus::Confirmed { block_hash, .. } = status {
+        assert_eq!(block_hash, None);
+    }
+}
+
+#[tokio::test]
+async fn test_campaign_builder_deploys_through_the_factory() {
+    let client = MockMantraClient::new(fixtures::mock_chain());
+    let mut factory = ClaimdropFactoryClient::new(
+        Arc::new(client.rpc().clone()),
+        fixtures::CHAIN_ID.to_string(),
+        fixtures::FACTORY.to_string(),
+        None,
+    );
+    let params = CampaignParams {
+        name: "Builder airdrop".to_string(),
+        description: "Deployed by the campaign builder".to_string(),
+        ty: "airdrop".to_string(),
+        total_reward: Coin::new(1_000u128, "uom"),
+        distribution_type: vec![DistributionType::LumpSum {
+            percentage: Decimal::percent(100),
+            start_time: fixtures::CAMPAIGN_END,
+        }],
+        start_time: fixtures::CAMPAIGN_END,
+        end_time: fixtures::CAMPAIGN_END + 24 * 60 * 60,
+    };
+    let allocations: Vec<Allocation> = (0..5)
+        .map(|i| Allocation {
+            user: format!("mantra1mockuser{}", i),
+            allocated_amount: Uint128::new(200),
+        })
+        .collect();
+    let builder = CampaignBuilder::new(params)
+        .allocations(allocations.clone())
+        .chunk_size(2);
+
+    // Nothing is broadcast without a signer
+    let err = builder
+        .deploy(&factory, fixtures::CAMPAIGN_START, |_| {})
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Wallet(_)), "{}", err);
+
+    factory.set_executor(Arc::new(client.signer(fixtures::ALICE)));
+    let mut uploaded = Vec::new();
+    let deployment = builder
+        .deploy(&factory, fixtures::CAMPAIGN_START, |update| {
+            uploaded.push(update.uploaded)
+        })
+        .await
+        .unwrap();
+
+    let campaign_address = "mantra1mockfactorycampaign1";
+    assert!(deployment.creation.success);
+    assert_eq!(
+        deployment.creation.campaign_address.as_deref(),
+        Some(campaign_address)
+    );
+    let creation_hash = deployment.creation.tx_hash.as_deref().unwrap();
+    assert!(client.rpc().transaction(creation_hash).unwrap().is_ok());
+    let upload = deployment.upload.unwrap();
+    assert!(upload.is_complete());
+    assert_eq!(upload.chunks.len(), 3);
+    assert_eq!(uploaded, [2, 4, 5]);
+
+    assert_eq!(
+        factory.query_all_campaigns().await.unwrap(),
+        [campaign_address]
+    );
+    let campaign = factory.campaign_client(campaign_address.to_string());
+    assert_eq!(
+        campaign.query_campaign().await.unwrap().name,
+        "Builder airdrop"
+    );
+    let stored: Vec<_> = campaign
+        .query_all_allocations(10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|a| (a.user, a.allocated_amount.u128()))
+        .collect();
+    assert_eq!(
+        stored,
+        (0..5)
+            .map(|i| (format!("mantra1mockuser{}", i), 200))
+            .collect::<Vec<_>>()
+    );
+
+    // A chunk repeating an allocation fails as a whole
+    let repeated = [
+        Allocation {
+            user: "mantra1mockuser9".to_string(),
+            allocated_amount: Uint128::new(1),
+        },
+        allocations[0].clone(),
+    ];
+    let err = campaign
+        .add_allocations_chunked(&repeated, 2, |_| {})
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Tx(_)), "{}", err);
+    assert_eq!(campaign.query_all_allocations(10).await.unwrap().len(), 5);
+}
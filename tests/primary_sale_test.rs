@@ -3,6 +3,8 @@
 //! This file serves as the test entry point for PrimarySale integration tests.
 //! The actual test modules are defined in tests/integration/primary_sale_test.rs
 
+#[cfg(feature = "evm")]
+#[cfg(feature = "mcp")]
 #[path = "integration/primary_sale_fixtures.rs"]
 mod primary_sale_fixtures;
 
@@ -101,12 +101,18 @@ pub mod test_utils {
                     network_name: network_constants.network_name.clone(),
                     chain_id: network_constants.chain_id.clone(),
                     rpc_url: network_constants.default_rpc.clone(),
+                    rpc_fallback_urls: Vec::new(),
+                    grpc_url: None,
                     gas_price: network_constants.default_gas_price,
                     gas_adjustment: network_constants.default_gas_adjustment,
                     native_denom: network_constants.native_denom.clone(),
                     contracts: Default::default(),
+                    #[cfg(feature = "evm")]
                     evm_rpc_url: None,
+                    #[cfg(feature = "evm")]
                     evm_chain_id: None,
+                    #[cfg(feature = "evm")]
+                    evm_rpc_fallback_urls: Vec::new(),
                 }
             });
 